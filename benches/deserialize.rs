@@ -0,0 +1,62 @@
+//! Deserialize an update stream.
+//!
+//! `benches/synthetic_updates.jsonl` is written by hand, not recorded: it has the shapes of common updates but not
+//! the mix or the sizes of a real account, take its numbers as relative ones. To measure real traffic, record a
+//! session with `rtdlib::record::Recorder`, which redacts secrets, and point `RTDLIB_BENCH_RECORDING` at the file.
+//!
+//! - `stream`: `TdType::from_json`, abstract enums dispatch on `@type` without buffering the object
+//! - `buffered`: the same json read into a `serde_json::Value` first, as every object was read before
+//! - `new_message/*`: the owned `UpdateNewMessage` against the borrowed view of `rtdlib::borrowed`
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use rtdlib::borrowed;
+use rtdlib::record::Replay;
+use rtdlib::types::*;
+
+const SYNTHETIC_UPDATES: &str = include_str!("synthetic_updates.jsonl");
+
+/// The objects received in the recording of `RTDLIB_BENCH_RECORDING`, or the synthetic updates
+fn updates() -> Vec<String> {
+  match std::env::var("RTDLIB_BENCH_RECORDING") {
+    Ok(path) => Replay::open(&path).unwrap_or_else(|e| panic!("Can't read the recording {}: {}", path, e)).incoming().collect(),
+    Err(_) => SYNTHETIC_UPDATES.lines().filter(|line| !line.is_empty()).map(|line| line.to_string()).collect(),
+  }
+}
+
+fn bench_stream(c: &mut Criterion) {
+  let lines = updates();
+  let mut group = c.benchmark_group("updates");
+  group.throughput(Throughput::Bytes(lines.iter().map(|line| line.len() as u64).sum()));
+  group.bench_function("stream", |b| b.iter(|| {
+    for line in &lines {
+      TdType::from_json(line).unwrap();
+    }
+  }));
+  group.bench_function("buffered", |b| b.iter(|| {
+    for line in &lines {
+      let value: serde_json::Value = serde_json::from_str(line).unwrap();
+      serde_json::from_value::<TdType>(value).unwrap();
+    }
+  }));
+  group.finish();
+}
+
+fn bench_new_message(c: &mut Criterion) {
+  let lines: Vec<String> = updates().into_iter().filter(|line| line.starts_with(r#"{"@type":"updateNewMessage""#)).collect();
+  let mut group = c.benchmark_group("new_message");
+  group.bench_function("owned", |b| b.iter(|| {
+    for line in &lines {
+      serde_json::from_str::<UpdateNewMessage>(line).unwrap();
+    }
+  }));
+  group.bench_function("borrowed", |b| b.iter(|| {
+    for line in &lines {
+      serde_json::from_str::<borrowed::UpdateNewMessage>(line).unwrap();
+    }
+  }));
+  group.finish();
+}
+
+criterion_group!(benches, bench_stream, bench_new_message);
+criterion_main!(benches);
@@ -0,0 +1,198 @@
+//! Borrowed views of the text heavy types, for reading the update stream without copying message text.
+//!
+//! Strings are `Cow<str>`, they borrow from the json unless it escapes them, so the views live as long as the json
+//! string. Fields which are not read on the hot path are left out, `into_owned` converts a view when it has to be kept.
+//!
+//! ```
+//! use rtdlib::borrowed::UpdateNewMessage;
+//! let json = r#"{"@type":"updateNewMessage","message":{"@type":"message","id":1,"sender":{"@type":"messageSenderUser","user_id":7},"chat_id":7,"is_outgoing":false,"is_pinned":false,"is_channel_post":false,"date":1600000000,"edit_date":0,"reply_in_chat_id":0,"reply_to_message_id":0,"message_thread_id":0,"via_bot_user_id":0,"author_signature":"","media_album_id":"0","content":{"@type":"messageText","text":{"@type":"formattedText","text":"hello","entities":[]}}}}"#;
+//! let update: UpdateNewMessage = serde_json::from_str(json).unwrap();
+//! assert_eq!(update.message().content().text(), Some("hello"));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+
+use crate::types::_common::Tagged;
+use crate::types;
+
+/// Borrowed [`FormattedText`](../types/struct.FormattedText.html)
+#[derive(Debug, Clone, Deserialize)]
+pub struct FormattedText<'a> {
+  /// The text
+  #[serde(borrow)]
+  text: Cow<'a, str>,
+  /// Entities contained in the text. Entities can be nested, but must not mutually intersect with each other
+  entities: Vec<types::TextEntity>,
+}
+
+impl<'a> FormattedText<'a> {
+  pub fn text(&self) -> &str { &self.text[..] }
+
+  pub fn entities(&self) -> &Vec<types::TextEntity> { &self.entities }
+
+  pub fn into_owned(self) -> types::FormattedText {
+    types::FormattedText::builder().text(self.text).entities(self.entities).build()
+  }
+}
+
+/// Borrowed [`MessageText`](../types/struct.MessageText.html)
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessageText<'a> {
+  /// Text of the message
+  #[serde(borrow)]
+  text: FormattedText<'a>,
+  /// A preview of the web page that's mentioned in the text; may be null
+  web_page: Option<Box<types::WebPage>>,
+}
+
+impl<'a> MessageText<'a> {
+  pub fn text(&self) -> &FormattedText<'a> { &self.text }
+
+  pub fn web_page(&self) -> Option<&types::WebPage> { self.web_page.as_deref() }
+
+  pub fn into_owned(self) -> types::MessageText {
+    let mut builder = types::MessageText::builder();
+    builder.text(self.text.into_owned());
+    if let Some(web_page) = &self.web_page { builder.web_page(web_page.as_ref()); }
+    builder.build()
+  }
+}
+
+/// Borrowed [`MessageContent`](../types/enum.MessageContent.html), only text messages are borrowed
+#[derive(Debug, Clone)]
+pub enum MessageContent<'a> {
+  Text(MessageText<'a>),
+  /// Any other content, read as the owned type
+  Other(Box<types::MessageContent>),
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for MessageContent<'a> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<MessageContent<'a>, D::Error> {
+    struct ContentVisitor<'a>(PhantomData<MessageContent<'a>>);
+
+    impl<'de: 'a, 'a> Visitor<'de> for ContentVisitor<'a> {
+      type Value = MessageContent<'a>;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a tdlib object of MessageContent")
+      }
+
+      fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<MessageContent<'a>, A::Error> {
+        let tagged = Tagged::read(map)?;
+        Ok(match tagged.td_type() {
+          "messageText" => MessageContent::Text(tagged.deserialize()?),
+          _ => MessageContent::Other(tagged.deserialize()?),
+        })
+      }
+    }
+
+    deserializer.deserialize_map(ContentVisitor(PhantomData))
+  }
+}
+
+impl<'a> MessageContent<'a> {
+  /// Text of a text message
+  pub fn text(&self) -> Option<&str> {
+    match self {
+      MessageContent::Text(t) => Some(t.text().text()),
+      MessageContent::Other(_) => None,
+    }
+  }
+
+  pub fn into_owned(self) -> types::MessageContent {
+    match self {
+      MessageContent::Text(t) => types::MessageContent::MessageText(t.into_owned()),
+      MessageContent::Other(content) => *content,
+    }
+  }
+}
+
+/// Borrowed [`Message`](../types/struct.Message.html), without the permission flags, interaction info and reply markup
+#[derive(Debug, Clone, Deserialize)]
+pub struct Message<'a> {
+  /// Message identifier; unique for the chat to which the message belongs
+  id: i64,
+  /// The sender of the message
+  sender: types::MessageSender,
+  /// Chat identifier
+  chat_id: i64,
+  /// True, if the message is outgoing
+  is_outgoing: bool,
+  /// True, if the message is pinned
+  is_pinned: bool,
+  /// True, if the message is a channel post. All messages to channels are channel posts, all other messages are not channel posts
+  is_channel_post: bool,
+  /// Point in time (Unix timestamp) when the message was sent
+  date: i64,
+  /// Point in time (Unix timestamp) when the message was last edited
+  edit_date: i64,
+  /// If non-zero, the identifier of the chat to which the replied message belongs
+  reply_in_chat_id: i64,
+  /// If non-zero, the identifier of the message this message is replying to; can be the identifier of a deleted message
+  reply_to_message_id: i64,
+  /// If non-zero, the identifier of the message thread the message belongs to; unique within the chat to which the message belongs
+  message_thread_id: i64,
+  /// If non-zero, the user identifier of the bot through which this message was sent
+  via_bot_user_id: i64,
+  /// For channel posts and anonymous group messages, optional author signature
+  #[serde(borrow)]
+  author_signature: Cow<'a, str>,
+  /// Unique identifier of an album this message belongs to
+  #[serde(with = "crate::types::_common::int64")]
+  media_album_id: i64,
+  /// Content of the message
+  #[serde(borrow)]
+  content: MessageContent<'a>,
+}
+
+impl<'a> Message<'a> {
+  pub fn id(&self) -> i64 { self.id }
+
+  pub fn sender(&self) -> &types::MessageSender { &self.sender }
+
+  pub fn chat_id(&self) -> i64 { self.chat_id }
+
+  pub fn is_outgoing(&self) -> bool { self.is_outgoing }
+
+  pub fn is_pinned(&self) -> bool { self.is_pinned }
+
+  pub fn is_channel_post(&self) -> bool { self.is_channel_post }
+
+  pub fn date(&self) -> i64 { self.date }
+
+  pub fn edit_date(&self) -> i64 { self.edit_date }
+
+  pub fn reply_in_chat_id(&self) -> i64 { self.reply_in_chat_id }
+
+  pub fn reply_to_message_id(&self) -> i64 { self.reply_to_message_id }
+
+  pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
+
+  pub fn via_bot_user_id(&self) -> i64 { self.via_bot_user_id }
+
+  pub fn author_signature(&self) -> &str { &self.author_signature[..] }
+
+  pub fn media_album_id(&self) -> i64 { self.media_album_id }
+
+  pub fn content(&self) -> &MessageContent<'a> { &self.content }
+
+  pub fn into_content(self) -> MessageContent<'a> { self.content }
+}
+
+/// Borrowed [`UpdateNewMessage`](../types/struct.UpdateNewMessage.html)
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateNewMessage<'a> {
+  /// The new message
+  #[serde(borrow)]
+  message: Message<'a>,
+}
+
+impl<'a> UpdateNewMessage<'a> {
+  pub fn message(&self) -> &Message<'a> { &self.message }
+
+  pub fn into_message(self) -> Message<'a> { self.message }
+}
@@ -0,0 +1,191 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use crate::client::router::{Reply, Router};
+use crate::client::Transport;
+use crate::errors::*;
+use crate::types::*;
+
+/// Asynchronous tdlib client, every `send` returns a future resolved by the reply with the same `@extra`.
+///
+/// The client does not depend on any async runtime, the receive loop runs on a dedicated thread.
+pub struct Client<T: Transport> {
+  router: Arc<Router<T>>,
+}
+
+impl<T: Transport> Client<T> {
+  /// Create a client over `transport`, updates and other objects not matched to a request are passed to `handler`
+  pub fn new<H>(transport: T, handler: H) -> Self where H: Fn(String) + Send + 'static {
+    Client { router: Router::start(transport, handler) }
+  }
+
+  /// Send `fnc` to tdlib, wait for the reply without time limit
+  pub fn send<F: RFunction>(&self, fnc: &F) -> ResponseFuture<F> {
+    self.send_request(fnc, None)
+  }
+
+  /// Send `fnc` to tdlib, the future fails with `Request timeout` if the reply does not arrive in `timeout`
+  pub fn send_timeout<F: RFunction>(&self, fnc: &F, timeout: Duration) -> ResponseFuture<F> {
+    self.send_request(fnc, Some(timeout))
+  }
+
+  /// Synchronously execute `fnc`, only a few functions can be executed synchronously
+  pub fn execute<F: RFunction>(&self, fnc: &F) -> RTDResult<Result<F::Response, Error>> {
+    self.router.execute(fnc)
+  }
+
+  /// Stop receiving, all pending futures fail with `Client closed`
+  pub fn close(&self) {
+    self.router.close()
+  }
+
+  pub fn is_closed(&self) -> bool {
+    self.router.is_closed()
+  }
+
+  /// Send `fnc` to tdlib, `reply` is called with its reply on the receive thread, or right away if it can not be sent
+  pub(crate) fn send_then<F, R>(&self, fnc: &F, reply: R)
+    where F: RFunction, R: FnOnce(RTDResult<Result<F::Response, Error>>) + Send + 'static {
+    let once = Arc::new(Mutex::new(Some(reply)));
+    let routed = once.clone();
+    let route: Reply = Box::new(move |result: RTDResult<String>| {
+      let reply = routed.lock().unwrap().take();
+      if let Some(reply) = reply { reply(result.and_then(parse_response::<F, _>)) }
+    });
+    if let Err(e) = self.router.send(fnc, None, route) {
+      let reply = once.lock().unwrap().take();
+      if let Some(reply) = reply { reply(Err(e)) }
+    }
+  }
+
+  fn send_request<F: RFunction>(&self, fnc: &F, timeout: Option<Duration>) -> ResponseFuture<F> {
+    let shared = Arc::new(Mutex::new(Shared::default()));
+    let reply_shared = shared.clone();
+    let reply = Box::new(move |result: RTDResult<String>| {
+      let mut shared = reply_shared.lock().unwrap();
+      shared.result = Some(result);
+      if let Some(waker) = shared.waker.take() { waker.wake() }
+    });
+    match self.router.send(fnc, timeout, reply) {
+      Ok(extra) => {
+        let router = Arc::downgrade(&self.router);
+        let cancel: Box<dyn FnOnce() + Send> = Box::new(move || {
+          if let Some(router) = router.upgrade() { router.cancel(&extra) }
+        });
+        ResponseFuture { shared, cancel: Some(cancel), _fnc: PhantomData }
+      }
+      Err(e) => {
+        shared.lock().unwrap().result = Some(Err(e));
+        ResponseFuture { shared, cancel: None, _fnc: PhantomData }
+      }
+    }
+  }
+}
+
+impl<T: Transport> Drop for Client<T> {
+  fn drop(&mut self) {
+    self.router.close()
+  }
+}
+
+#[derive(Default)]
+struct Shared {
+  result: Option<RTDResult<String>>,
+  waker: Option<Waker>,
+}
+
+/// Reply of `F`, dropping the future before it completes cancels the request
+pub struct ResponseFuture<F: RFunction> {
+  shared: Arc<Mutex<Shared>>,
+  cancel: Option<Box<dyn FnOnce() + Send>>,
+  _fnc: PhantomData<fn() -> F>,
+}
+
+impl<F: RFunction> Future for ResponseFuture<F> {
+  type Output = RTDResult<Result<F::Response, Error>>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let result = {
+      let mut shared = self.shared.lock().unwrap();
+      match shared.result.take() {
+        Some(result) => result,
+        None => {
+          shared.waker = Some(cx.waker().clone());
+          return Poll::Pending;
+        }
+      }
+    };
+    self.cancel = None;
+    Poll::Ready(result.and_then(parse_response::<F, _>))
+  }
+}
+
+impl<F: RFunction> Drop for ResponseFuture<F> {
+  fn drop(&mut self) {
+    if let Some(cancel) = self.cancel.take() { cancel() }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::sync::mpsc;
+  use std::task::{Context, Poll, Wake};
+  use std::thread::{self, Thread};
+  use std::time::Duration;
+  use std::future::Future;
+
+  use crate::client::Client;
+  use crate::client::tests::EchoTransport;
+  use crate::types::*;
+
+  struct ThreadWaker(Thread);
+
+  impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) { self.0.unpark() }
+  }
+
+  fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+      match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => return output,
+        Poll::Pending => thread::park(),
+      }
+    }
+  }
+
+  #[test]
+  fn test_send_receive() {
+    let transport = Arc::new(EchoTransport::default());
+    let (sender, updates) = mpsc::channel();
+    let client = Client::new(transport.clone(), move |json| sender.send(json).unwrap());
+
+    let reply = block_on(client.send(&DeleteMessages::builder().chat_id(1).message_ids(vec![1]).build()));
+    assert!(reply.unwrap().is_ok());
+
+    let update = r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"}}"#;
+    transport.push(update.to_string());
+    assert_eq!(updates.recv_timeout(Duration::from_secs(5)).unwrap(), update);
+  }
+
+  #[test]
+  fn test_timeout_and_close() {
+    let client = Client::new(Arc::new(EchoTransport::default()), |_| {});
+
+    let reply = block_on(client.send_timeout(&Close::builder().build(), Duration::from_millis(10)));
+    assert!(reply.is_err());
+
+    let pending = client.send(&Close::builder().build());
+    client.close();
+    assert!(block_on(pending).is_err());
+    assert!(block_on(client.send(&GetMe::builder().build())).is_err());
+  }
+}
@@ -0,0 +1,372 @@
+use std::sync::mpsc::Receiver;
+
+use crate::client::{SyncClient, Transport};
+use crate::errors::*;
+use crate::types::*;
+
+/// How to log in, asked when tdlib waits for a phone number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginMethod {
+  PhoneNumber(String),
+  BotToken(String),
+  /// Scan a QR code with another device which is already logged in
+  QrCode,
+}
+
+/// Progress of an [`Authenticator`](struct.Authenticator.html), the answer of each step is sent to tdlib
+#[derive(Debug, Clone)]
+pub enum AuthEvent {
+  TdlibParameters,
+  EncryptionKey,
+  PhoneNumber(String),
+  BotToken,
+  /// A QR code login was requested
+  QrCodeRequested,
+  /// The link of the QR code to confirm on another device, emitted again each time tdlib changes the link
+  QrCode(String),
+  /// The code sent to the user as described
+  Code(AuthenticationCodeInfo),
+  Password,
+  Registration,
+  /// tdlib rejected the answer of an interactive step, it is asked again
+  Rejected { code: i64, message: String },
+  Ready,
+}
+
+/// Times the answer of an interactive state is asked before a rejection stops the authorization
+const MAX_ATTEMPTS: usize = 5;
+
+/// Answers of an [`Authenticator`](struct.Authenticator.html), every method is called when tdlib waits for its value.
+///
+/// The steps a login method does not need fail by default, which stops the authorization.
+pub trait AuthHandler {
+  fn tdlib_parameters(&mut self) -> TdlibParameters;
+
+  fn login_method(&mut self) -> LoginMethod;
+
+  /// Key of the local database, empty if the database is not encrypted
+  fn encryption_key(&mut self) -> RTDResult<String> { Ok(String::new()) }
+
+  /// The code sent to the user as described by `info`
+  fn code(&mut self, _info: &AuthenticationCodeInfo) -> RTDResult<String> {
+    Err(RTDError::custom("Authentication code is not supported"))
+  }
+
+  /// Two-step verification password
+  fn password(&mut self, _hint: &str) -> RTDResult<String> {
+    Err(RTDError::custom("Password is not supported"))
+  }
+
+  /// First and last name of a new user, who accepted `terms`
+  fn registration(&mut self, _terms: &TermsOfService) -> RTDResult<(String, String)> {
+    Err(RTDError::custom("Registration is not supported"))
+  }
+
+  /// Show the QR code of `link`, called again each time tdlib changes the link
+  fn confirm_qr_code(&mut self, _link: &str) -> RTDResult<()> {
+    Err(RTDError::custom("QR code login is not supported"))
+  }
+
+  fn on_event(&mut self, _event: &AuthEvent) {}
+}
+
+/// A function answering an authorization state
+#[derive(Debug, Clone)]
+pub enum AuthRequest {
+  SetTdlibParameters(SetTdlibParameters),
+  CheckDatabaseEncryptionKey(CheckDatabaseEncryptionKey),
+  SetAuthenticationPhoneNumber(SetAuthenticationPhoneNumber),
+  CheckAuthenticationBotToken(CheckAuthenticationBotToken),
+  RequestQrCodeAuthentication(RequestQrCodeAuthentication),
+  CheckAuthenticationCode(CheckAuthenticationCode),
+  CheckAuthenticationPassword(CheckAuthenticationPassword),
+  RegisterUser(RegisterUser),
+}
+
+impl AuthRequest {
+  pub fn td_name(&self) -> &'static str {
+    match self {
+      AuthRequest::SetTdlibParameters(f) => f.td_name(),
+      AuthRequest::CheckDatabaseEncryptionKey(f) => f.td_name(),
+      AuthRequest::SetAuthenticationPhoneNumber(f) => f.td_name(),
+      AuthRequest::CheckAuthenticationBotToken(f) => f.td_name(),
+      AuthRequest::RequestQrCodeAuthentication(f) => f.td_name(),
+      AuthRequest::CheckAuthenticationCode(f) => f.td_name(),
+      AuthRequest::CheckAuthenticationPassword(f) => f.td_name(),
+      AuthRequest::RegisterUser(f) => f.td_name(),
+    }
+  }
+
+  pub fn to_json(&self) -> RTDResult<String> {
+    match self {
+      AuthRequest::SetTdlibParameters(f) => f.to_json(),
+      AuthRequest::CheckDatabaseEncryptionKey(f) => f.to_json(),
+      AuthRequest::SetAuthenticationPhoneNumber(f) => f.to_json(),
+      AuthRequest::CheckAuthenticationBotToken(f) => f.to_json(),
+      AuthRequest::RequestQrCodeAuthentication(f) => f.to_json(),
+      AuthRequest::CheckAuthenticationCode(f) => f.to_json(),
+      AuthRequest::CheckAuthenticationPassword(f) => f.to_json(),
+      AuthRequest::RegisterUser(f) => f.to_json(),
+    }
+  }
+
+  fn execute<T: Transport>(&self, client: &SyncClient<T>) -> RTDResult<Result<Ok, Error>> {
+    match self {
+      AuthRequest::SetTdlibParameters(f) => client.execute(f),
+      AuthRequest::CheckDatabaseEncryptionKey(f) => client.execute(f),
+      AuthRequest::SetAuthenticationPhoneNumber(f) => client.execute(f),
+      AuthRequest::CheckAuthenticationBotToken(f) => client.execute(f),
+      AuthRequest::RequestQrCodeAuthentication(f) => client.execute(f),
+      AuthRequest::CheckAuthenticationCode(f) => client.execute(f),
+      AuthRequest::CheckAuthenticationPassword(f) => client.execute(f),
+      AuthRequest::RegisterUser(f) => client.execute(f),
+    }
+  }
+}
+
+/// Drives the authorization of a client: every `updateAuthorizationState` is answered with the values of an
+/// [`AuthHandler`](trait.AuthHandler.html) until tdlib is ready.
+///
+/// `answer` only maps states to requests, so the flow can be checked against a scripted sequence of states, `run`
+/// sends the requests through a `SyncClient`.
+pub struct Authenticator<H: AuthHandler> {
+  handler: H,
+}
+
+impl<H: AuthHandler> Authenticator<H> {
+  pub fn new(handler: H) -> Self {
+    Authenticator { handler }
+  }
+
+  pub fn handler(&self) -> &H { &self.handler }
+
+  pub fn into_handler(self) -> H { self.handler }
+
+  /// The request answering `state`, `None` if tdlib does not wait for an answer
+  pub fn answer(&mut self, state: &AuthorizationState) -> RTDResult<Option<AuthRequest>> {
+    let (event, request) = match state {
+      AuthorizationState::WaitTdlibParameters(_) => {
+        let parameters = self.handler.tdlib_parameters();
+        (AuthEvent::TdlibParameters, AuthRequest::SetTdlibParameters(SetTdlibParameters::builder().parameters(parameters).build()))
+      }
+      AuthorizationState::WaitEncryptionKey(_) => {
+        let key = self.handler.encryption_key()?;
+        (AuthEvent::EncryptionKey, AuthRequest::CheckDatabaseEncryptionKey(CheckDatabaseEncryptionKey::builder().encryption_key(key).build()))
+      }
+      AuthorizationState::WaitPhoneNumber(_) => match self.handler.login_method() {
+        LoginMethod::PhoneNumber(phone_number) => {
+          let function = SetAuthenticationPhoneNumber::builder().phone_number(&phone_number).build();
+          (AuthEvent::PhoneNumber(phone_number), AuthRequest::SetAuthenticationPhoneNumber(function))
+        }
+        LoginMethod::BotToken(token) => {
+          (AuthEvent::BotToken, AuthRequest::CheckAuthenticationBotToken(CheckAuthenticationBotToken::builder().token(token).build()))
+        }
+        LoginMethod::QrCode => {
+          (AuthEvent::QrCodeRequested, AuthRequest::RequestQrCodeAuthentication(RequestQrCodeAuthentication::builder().build()))
+        }
+      },
+      AuthorizationState::WaitOtherDeviceConfirmation(state) => {
+        self.handler.confirm_qr_code(state.link())?;
+        self.handler.on_event(&AuthEvent::QrCode(state.link().clone()));
+        return Ok(None);
+      }
+      AuthorizationState::WaitCode(state) => {
+        let code = self.handler.code(state.code_info())?;
+        (AuthEvent::Code(state.code_info().clone()), AuthRequest::CheckAuthenticationCode(CheckAuthenticationCode::builder().code(code).build()))
+      }
+      AuthorizationState::WaitPassword(state) => {
+        let password = self.handler.password(state.password_hint())?;
+        (AuthEvent::Password, AuthRequest::CheckAuthenticationPassword(CheckAuthenticationPassword::builder().password(password).build()))
+      }
+      AuthorizationState::WaitRegistration(state) => {
+        let (first_name, last_name) = self.handler.registration(state.terms_of_service())?;
+        (AuthEvent::Registration, AuthRequest::RegisterUser(RegisterUser::builder().first_name(first_name).last_name(last_name).build()))
+      }
+      AuthorizationState::Ready(_) => {
+        self.handler.on_event(&AuthEvent::Ready);
+        return Ok(None);
+      }
+      AuthorizationState::Closing(_) | AuthorizationState::Closed(_) => return Err(RTDError::custom("Authorization closed")),
+      _ => return Ok(None),
+    };
+    self.handler.on_event(&event);
+    Ok(Some(request))
+  }
+
+  /// Answer the authorization states received from `updates` until tdlib is ready.
+  ///
+  /// Start it before the first update is taken from `updates`, tdlib sends the first state by itself. The answer of
+  /// an interactive state (phone number, code, password, registration) rejected as a bad request is asked again, up
+  /// to `MAX_ATTEMPTS` times. Any other error stops the authorization.
+  pub fn run<T: Transport>(&mut self, client: &SyncClient<T>, updates: &Receiver<Update>) -> RTDResult<()> {
+    loop {
+      let update = updates.recv().map_err(|_| RTDError::custom("Client closed"))?;
+      let state = match update {
+        Update::AuthorizationState(update) => update.authorization_state().clone(),
+        _ => continue,
+      };
+      let mut attempts = 0;
+      while let Some(request) = self.answer(&state)? {
+        let error = match request.execute(client)? {
+          Ok(_) => break,
+          Err(error) => RTDError::from(error),
+        };
+        attempts += 1;
+        match (&error, error.td_kind()) {
+          (RTDError::Td { code, message }, Some(TdErrorKind::BadRequest)) if is_interactive(&state) && attempts < MAX_ATTEMPTS => {
+            self.handler.on_event(&AuthEvent::Rejected { code: *code, message: message.clone() });
+          }
+          _ => return Err(error),
+        }
+      }
+      if state.is_ready() {
+        return Ok(());
+      }
+    }
+  }
+}
+
+/// Whether the answer of `state` comes from the user, who can correct a rejected one
+fn is_interactive(state: &AuthorizationState) -> bool {
+  matches!(state, AuthorizationState::WaitPhoneNumber(_) | AuthorizationState::WaitCode(_)
+    | AuthorizationState::WaitPassword(_) | AuthorizationState::WaitRegistration(_))
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::collections::VecDeque;
+  use std::sync::{Arc, Mutex};
+
+  use crate::client::*;
+  use crate::client::auth::MAX_ATTEMPTS;
+  use crate::client::tests::EchoTransport;
+  use crate::errors::*;
+  use crate::types::*;
+
+  /// Answers of a user with a password, the first code is wrong
+  #[derive(Default)]
+  struct Scripted {
+    codes: VecDeque<&'static str>,
+    events: Vec<AuthEvent>,
+  }
+
+  impl AuthHandler for Scripted {
+    fn tdlib_parameters(&mut self) -> TdlibParameters { TdlibParameters::builder().api_id(1).api_hash("hash").build() }
+
+    fn login_method(&mut self) -> LoginMethod { LoginMethod::PhoneNumber("+10000000000".to_string()) }
+
+    fn code(&mut self, _info: &AuthenticationCodeInfo) -> RTDResult<String> {
+      self.codes.pop_front().map(|c| c.to_string()).ok_or(RTDError::custom("No code"))
+    }
+
+    fn password(&mut self, hint: &str) -> RTDResult<String> { Ok(format!("{}!", hint)) }
+
+    fn on_event(&mut self, event: &AuthEvent) { self.events.push(event.clone()) }
+  }
+
+  fn state(json: &str) -> AuthorizationState {
+    AuthorizationState::from_json(json).unwrap()
+  }
+
+  fn update(state: &str) -> String {
+    format!(r#"{{"@type":"updateAuthorizationState","authorization_state":{}}}"#, state)
+  }
+
+  const STATES: &[&str] = &[
+    r#"{"@type":"authorizationStateWaitTdlibParameters"}"#,
+    r#"{"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}"#,
+    r#"{"@type":"authorizationStateWaitPhoneNumber"}"#,
+    r#"{"@type":"authorizationStateWaitCode","code_info":{"@type":"authenticationCodeInfo","phone_number":"+10000000000","type":{"@type":"authenticationCodeTypeSms","length":5},"timeout":60}}"#,
+    r#"{"@type":"authorizationStateWaitPassword","password_hint":"hint","has_recovery_email_address":false,"recovery_email_address_pattern":""}"#,
+    r#"{"@type":"authorizationStateReady"}"#,
+  ];
+
+  #[test]
+  fn test_answer() {
+    let handler = Scripted { codes: vec!["12345"].into(), ..Default::default() };
+    let mut authenticator = Authenticator::new(handler);
+    let requests: Vec<Option<&'static str>> = STATES.iter()
+      .map(|s| authenticator.answer(&state(s)).unwrap().map(|r| r.td_name()))
+      .collect();
+    assert_eq!(requests, vec![
+      Some("setTdlibParameters"),
+      Some("checkDatabaseEncryptionKey"),
+      Some("setAuthenticationPhoneNumber"),
+      Some("checkAuthenticationCode"),
+      Some("checkAuthenticationPassword"),
+      None,
+    ]);
+    let events = &authenticator.handler().events;
+    assert!(matches!(&events[2], AuthEvent::PhoneNumber(phone_number) if phone_number == "+10000000000"));
+    assert!(matches!(events.last(), Some(AuthEvent::Ready)));
+
+    let password = authenticator.answer(&state(STATES[4])).unwrap().unwrap();
+    assert!(password.to_json().unwrap().contains(r#""password":"hint!""#));
+    assert!(authenticator.answer(&state(STATES[3])).is_err());
+    assert!(authenticator.answer(&state(r#"{"@type":"authorizationStateClosed"}"#)).is_err());
+  }
+
+  /// Replies `ok` to every request, except the first `checkAuthenticationCode`
+  struct WrongCodeTransport {
+    echo: Arc<EchoTransport>,
+    rejected: Mutex<bool>,
+  }
+
+  impl Transport for Arc<WrongCodeTransport> {
+    fn send(&self, request: &str) {
+      let (td_type, extra) = detect_td_type_and_extra(request);
+      let mut rejected = self.rejected.lock().unwrap();
+      if td_type == Some("checkAuthenticationCode".to_string()) && !*rejected {
+        *rejected = true;
+        return self.echo.push(format!(r#"{{"@type":"error","code":400,"message":"PHONE_CODE_INVALID","@extra":"{}"}}"#, extra.unwrap()));
+      }
+      self.echo.send(request)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> { self.echo.receive(timeout) }
+
+    fn execute(&self, request: &str) -> Option<String> { self.echo.execute(request) }
+  }
+
+  #[test]
+  fn test_run() {
+    let transport = Arc::new(WrongCodeTransport { echo: Arc::new(EchoTransport::default()), rejected: Mutex::new(false) });
+    STATES.iter().for_each(|s| transport.echo.push(update(s)));
+    let (client, updates) = SyncClient::new(transport.clone());
+
+    let mut authenticator = Authenticator::new(Scripted { codes: vec!["11111", "12345"].into(), ..Default::default() });
+    authenticator.run(&client, &updates).unwrap();
+    let events = authenticator.into_handler().events;
+    assert_eq!(events.len(), 8);
+    assert!(matches!(&events[4], AuthEvent::Rejected { code: 400, message } if message == "PHONE_CODE_INVALID"));
+    assert!(matches!(events[7], AuthEvent::Ready));
+  }
+
+  #[test]
+  fn test_run_rejected_parameters() {
+    let td = MockTd::new();
+    td.update(&update(STATES[0]));
+    td.expect(r#"{"@type":"setTdlibParameters"}"#).respond_error(400, "Valid api_id must be provided");
+    let (client, updates) = SyncClient::new(td.clone());
+
+    let mut authenticator = Authenticator::new(Scripted::default());
+    let error = authenticator.run(&client, &updates).unwrap_err();
+    assert_eq!(error.to_string(), "Td error 400: Valid api_id must be provided");
+    td.verify();
+  }
+
+  #[test]
+  fn test_run_attempts() {
+    let td = MockTd::new();
+    td.update(&update(STATES[3]));
+    td.expect(r#"{"@type":"checkAuthenticationCode"}"#).respond_error(400, "PHONE_CODE_INVALID").times(MAX_ATTEMPTS);
+    let (client, updates) = SyncClient::new(td.clone());
+
+    let codes = vec!["11111"; MAX_ATTEMPTS].into();
+    let mut authenticator = Authenticator::new(Scripted { codes, ..Default::default() });
+    assert!(authenticator.run(&client, &updates).is_err());
+    assert_eq!(authenticator.into_handler().events.len(), MAX_ATTEMPTS * 2 - 1);
+    td.verify();
+  }
+}
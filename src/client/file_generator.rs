@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use crate::client::{Client, Transport};
+use crate::errors::*;
+use crate::types::*;
+
+/// Produces the files of an `InputFileGenerated` conversion, see `GeneratorRegistry`
+pub trait FileGenerator: Send + Sync + 'static {
+  /// Generate the file of `request`, either through `sink.write` or by writing `destination_path` and reporting
+  /// `sink.progress`. An error fails the generation.
+  fn generate(&self, request: &UpdateFileGenerationStart, sink: &mut GenerationSink) -> RTDResult<()>;
+}
+
+impl<F> FileGenerator for F where F: Fn(&UpdateFileGenerationStart, &mut GenerationSink) -> RTDResult<()> + Send + Sync + 'static {
+  fn generate(&self, request: &UpdateFileGenerationStart, sink: &mut GenerationSink) -> RTDResult<()> {
+    self(request, sink)
+  }
+}
+
+enum Call {
+  Write(WriteGeneratedFilePart),
+  Progress(SetFileGenerationProgress),
+  Finish(FinishFileGeneration),
+}
+
+/// Send a call to tdlib and wait for its reply
+type Caller = Arc<dyn Fn(Call) -> RTDResult<()> + Send + Sync>;
+
+fn caller<T: Transport>(client: Arc<Client<T>>) -> Caller {
+  Arc::new(move |call| match call {
+    Call::Write(fnc) => wait(&client, &fnc),
+    Call::Progress(fnc) => wait(&client, &fnc),
+    Call::Finish(fnc) => wait(&client, &fnc),
+  })
+}
+
+fn wait<T: Transport, F: RFunction>(client: &Client<T>, fnc: &F) -> RTDResult<()> {
+  let (sender, receiver) = mpsc::channel();
+  client.send_then(fnc, move |reply| {
+    let _ = sender.send(reply.and_then(|r| r.map(|_| ()).map_err(RTDError::from)));
+  });
+  receiver.recv().unwrap_or(Err(RTDError::custom("Client closed")))
+}
+
+/// Where a generator writes the file, every call waits for tdlib to accept it
+pub struct GenerationSink {
+  generation_id: i64,
+  offset: i64,
+  stopped: Arc<AtomicBool>,
+  caller: Caller,
+}
+
+impl GenerationSink {
+  /// Append `data` to the file
+  pub fn write(&mut self, data: &[u8]) -> RTDResult<()> {
+    self.write_at(self.offset, data)
+  }
+
+  /// Write `data` at `offset` of the file
+  pub fn write_at(&mut self, offset: i64, data: &[u8]) -> RTDResult<()> {
+    self.check()?;
+    let fnc = WriteGeneratedFilePart::builder()
+      .generation_id(self.generation_id)
+      .offset(offset)
+      .data(STANDARD.encode(data))
+      .build();
+    (self.caller)(Call::Write(fnc))?;
+    self.offset = self.offset.max(offset + data.len() as i64);
+    Ok(())
+  }
+
+  /// Report the progress of a file written to `destination_path` directly, `expected_size` is 0 if unknown
+  pub fn progress(&self, expected_size: i64, local_prefix_size: i64) -> RTDResult<()> {
+    self.check()?;
+    let fnc = SetFileGenerationProgress::builder()
+      .generation_id(self.generation_id)
+      .expected_size(expected_size)
+      .local_prefix_size(local_prefix_size)
+      .build();
+    (self.caller)(Call::Progress(fnc))
+  }
+
+  /// End of the data written so far
+  pub fn offset(&self) -> i64 { self.offset }
+
+  /// Whether tdlib stopped the generation, the generator should return as soon as it sees it
+  pub fn is_stopped(&self) -> bool { self.stopped.load(Ordering::SeqCst) }
+
+  fn check(&self) -> RTDResult<()> {
+    if self.is_stopped() { Err(RTDError::custom("Generation stopped")) } else { Ok(()) }
+  }
+
+  fn finish(&self, result: RTDResult<()>) {
+    let mut fnc = FinishFileGeneration::builder();
+    fnc.generation_id(self.generation_id);
+    if let Err(e) = result {
+      let code = match e { RTDError::Td { code, .. } => code, _ => 400 };
+      fnc.error(Error::builder().code(code).message(e.to_string()).build());
+    }
+    let _ = (self.caller)(Call::Finish(fnc.build()));
+  }
+}
+
+/// Generators of `InputFileGenerated` files by `conversion`.
+///
+/// Feed the registry every update with `apply`, each generation tdlib starts runs its generator on a thread of its
+/// own, and is finished with the result of the generator unless tdlib stopped it.
+pub struct GeneratorRegistry<T: Transport> {
+  client: Arc<Client<T>>,
+  generators: HashMap<String, Arc<dyn FileGenerator>>,
+  running: Arc<Mutex<HashMap<i64, Arc<AtomicBool>>>>,
+}
+
+impl<T: Transport> GeneratorRegistry<T> {
+  pub fn new(client: Arc<Client<T>>) -> Self {
+    GeneratorRegistry { client, generators: HashMap::new(), running: Arc::new(Mutex::new(HashMap::new())) }
+  }
+
+  /// Generate the files of `conversion` with `generator`, generations of an unknown conversion fail
+  pub fn register<S: AsRef<str>, G: FileGenerator>(&mut self, conversion: S, generator: G) -> &mut Self {
+    self.generators.insert(conversion.as_ref().to_string(), Arc::new(generator));
+    self
+  }
+
+  /// Number of generations in progress
+  pub fn running(&self) -> usize { self.running.lock().unwrap().len() }
+
+  /// Start or stop a generation, returns false for any other update
+  pub fn apply(&self, update: &Update) -> bool {
+    match update {
+      Update::FileGenerationStart(start) => self.start(start.clone()),
+      Update::FileGenerationStop(stop) => {
+        let stopped = self.running.lock().unwrap().remove(&stop.generation_id());
+        if let Some(stopped) = stopped { stopped.store(true, Ordering::SeqCst) }
+      }
+      _ => return false,
+    }
+    true
+  }
+
+  fn start(&self, request: UpdateFileGenerationStart) {
+    let generation_id = request.generation_id();
+    let generator = self.generators.get(request.conversion()).cloned();
+    let stopped = Arc::new(AtomicBool::new(false));
+    self.running.lock().unwrap().insert(generation_id, stopped.clone());
+    let running = self.running.clone();
+    let mut sink = GenerationSink { generation_id, offset: 0, stopped, caller: caller(self.client.clone()) };
+    // generators block, and so does every call of the sink, never on the thread the update came from
+    thread::Builder::new()
+      .name("rtdlib-generate".to_string())
+      .spawn(move || {
+        let result = match generator {
+          Some(generator) => generator.generate(&request, &mut sink),
+          None => Err(RTDError::custom("Unknown conversion")),
+        };
+        let stopped = running.lock().unwrap().remove(&generation_id).is_none();
+        if !stopped { sink.finish(result) }
+      })
+      .expect("Can not spawn generation thread");
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{Arc, Mutex};
+  use std::sync::mpsc;
+  use std::thread;
+  use std::time::{Duration, Instant};
+
+  use crate::client::*;
+  use crate::client::tests::EchoTransport;
+  use crate::types::*;
+
+  /// Answer `ok` to every request and keep them
+  #[derive(Default)]
+  struct RecordTransport {
+    echo: Arc<EchoTransport>,
+    requests: Mutex<Vec<serde_json::Value>>,
+  }
+
+  impl Transport for Arc<RecordTransport> {
+    fn send(&self, request: &str) {
+      self.requests.lock().unwrap().push(serde_json::from_str(request).unwrap());
+      self.echo.send(request)
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> { self.echo.receive(timeout) }
+
+    fn execute(&self, _request: &str) -> Option<String> { None }
+  }
+
+  impl RecordTransport {
+    /// Wait until `count` requests are sent
+    fn requests(&self, count: usize) -> Vec<serde_json::Value> {
+      let deadline = Instant::now() + Duration::from_secs(5);
+      while self.requests.lock().unwrap().len() < count && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(5));
+      }
+      self.requests.lock().unwrap().clone()
+    }
+  }
+
+  fn registry() -> (GeneratorRegistry<Arc<RecordTransport>>, Arc<RecordTransport>) {
+    let transport = Arc::new(RecordTransport::default());
+    (GeneratorRegistry::new(Arc::new(Client::new(transport.clone(), |_| {}))), transport)
+  }
+
+  fn start(generation_id: i64, conversion: &str) -> Update {
+    Update::file_generation_start(UpdateFileGenerationStart::builder()
+      .generation_id(generation_id)
+      .original_path("/photos/original.jpg")
+      .destination_path("/tmp/generated")
+      .conversion(conversion)
+      .build())
+  }
+
+  #[test]
+  fn test_generate() {
+    let (mut registry, transport) = registry();
+    registry.register("#thumbnail#", |_: &UpdateFileGenerationStart, sink: &mut GenerationSink| {
+      sink.write(b"hello")?;
+      sink.write(b" world")
+    });
+    assert!(registry.apply(&start(7, "#thumbnail#")));
+    assert!(registry.apply(&start(8, "#unknown#")));
+
+    let requests = transport.requests(4);
+    let of = |id: &str| requests.iter().filter(|r| r["generation_id"] == id).collect::<Vec<_>>();
+    let generated = of("7");
+    assert_eq!(generated.len(), 3);
+    assert_eq!((&generated[0]["offset"], &generated[0]["data"]), (&serde_json::json!(0), &serde_json::json!("aGVsbG8=")));
+    assert_eq!((&generated[1]["offset"], &generated[1]["data"]), (&serde_json::json!(5), &serde_json::json!("IHdvcmxk")));
+    assert_eq!(generated[2]["@type"], "finishFileGeneration");
+    assert!(generated[2]["error"].is_null());
+    let unknown = of("8");
+    assert_eq!(unknown[0]["error"]["message"], "Unknown conversion");
+  }
+
+  #[test]
+  fn test_stop() {
+    let (started, wait_started) = mpsc::channel();
+    let started = Mutex::new(started);
+    let (mut registry, transport) = registry();
+    registry.register("#slow#", move |_: &UpdateFileGenerationStart, sink: &mut GenerationSink| {
+      started.lock().unwrap().send(()).unwrap();
+      while !sink.is_stopped() { thread::sleep(Duration::from_millis(5)) }
+      sink.write(b"late")
+    });
+    registry.apply(&start(1, "#slow#"));
+    wait_started.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(registry.running(), 1);
+
+    registry.apply(&Update::file_generation_stop(UpdateFileGenerationStop::builder().generation_id(1).build()));
+    assert_eq!(registry.running(), 0);
+    thread::sleep(Duration::from_millis(50));
+    assert!(transport.requests(0).is_empty());
+  }
+}
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::task::{Context, Poll, Waker};
+
+use crate::client::{Client, Transport};
+use crate::errors::*;
+use crate::types::*;
+
+/// Highest priority tdlib accepts for a transfer
+const MAX_PRIORITY: i64 = 32;
+
+/// Progress of a transfer, as of the last `updateFile` of the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileProgress {
+  pub file_id: i64,
+  /// Bytes downloaded or uploaded so far
+  pub transferred: i64,
+  /// Size of the file, or its expected size if the exact one is unknown
+  pub size: i64,
+  pub is_completed: bool,
+}
+
+impl FileProgress {
+  fn of(file: &File, direction: Direction) -> Self {
+    let (transferred, is_completed) = match direction {
+      Direction::Download => (file.local().downloaded_size(), file.local().is_downloading_completed()),
+      Direction::Upload => (file.remote().uploaded_size(), file.remote().is_uploading_completed()),
+    };
+    let size = if file.size() > 0 { file.size() } else { file.expected_size() };
+    FileProgress { file_id: file.id(), transferred, size, is_completed }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+  Download,
+  Upload,
+}
+
+impl Direction {
+  /// Guess the direction of a file not transferred by the manager
+  fn of(file: &File) -> Self {
+    if file.remote().is_uploading_active() { Direction::Upload } else { Direction::Download }
+  }
+}
+
+#[derive(Default)]
+struct Slot {
+  result: Option<RTDResult<File>>,
+  waker: Option<Waker>,
+}
+
+type SharedSlot = Arc<Mutex<Slot>>;
+
+fn complete(slot: &SharedSlot, result: RTDResult<File>) {
+  let mut slot = slot.lock().unwrap();
+  slot.result = Some(result);
+  if let Some(waker) = slot.waker.take() { waker.wake() }
+}
+
+/// `RTDError` is not `Clone`, every waiter of a failed transfer but one gets an error of the same kind and message
+fn duplicate(error: &RTDError) -> RTDError {
+  match error {
+    RTDError::Io(e) => RTDError::Io(io::Error::new(e.kind(), e.to_string())),
+    RTDError::SerdeJson(e) => RTDError::SerdeJson(serde::de::Error::custom(e)),
+    RTDError::Custom(msg) => RTDError::Custom(msg),
+    RTDError::Td { code, message } => RTDError::td(*code, message),
+    RTDError::MissingFields { td_type, fields } => RTDError::MissingFields { td_type, fields: fields.clone() },
+  }
+}
+
+struct Transfer {
+  direction: Direction,
+  priority: i64,
+  waiters: Vec<SharedSlot>,
+}
+
+#[derive(Default)]
+struct State {
+  transfers: HashMap<i64, Transfer>,
+  subscribers: HashMap<i64, Vec<Sender<FileProgress>>>,
+  /// Last known state of every file seen
+  files: HashMap<i64, File>,
+}
+
+impl State {
+  fn update(&mut self, file: &File) {
+    let file_id = file.id();
+    self.files.insert(file_id, file.clone());
+    let direction = self.transfers.get(&file_id).map_or_else(|| Direction::of(file), |t| t.direction);
+    let progress = FileProgress::of(file, direction);
+    if let Some(subscribers) = self.subscribers.get_mut(&file_id) {
+      subscribers.retain(|s| s.send(progress.clone()).is_ok());
+    }
+    if progress.is_completed {
+      self.finish(file_id, Ok(file.clone()));
+    }
+  }
+
+  /// Resolve every waiter of the file and end its progress streams
+  fn finish(&mut self, file_id: i64, result: RTDResult<File>) {
+    self.subscribers.remove(&file_id);
+    if let Some(mut transfer) = self.transfers.remove(&file_id) {
+      let last = transfer.waiters.pop();
+      transfer.waiters.iter().for_each(|slot| complete(slot, result.as_ref().map(File::clone).map_err(duplicate)));
+      if let Some(slot) = last { complete(&slot, result) }
+    }
+  }
+
+  fn reply(state: &Weak<Mutex<State>>, file_id: i64, result: RTDResult<Result<File, Error>>) {
+    let state = match state.upgrade() {
+      Some(state) => state,
+      None => return,
+    };
+    let mut state = state.lock().unwrap();
+    match result.and_then(|r| r.map_err(RTDError::from)) {
+      Ok(file) => state.update(&file),
+      Err(e) => state.finish(file_id, Err(e)),
+    }
+  }
+}
+
+/// Downloads and uploads of files, tracked by file id.
+///
+/// Concurrent requests for the same file share one transfer. The manager learns about progress from `updateFile`,
+/// feed it every update with `apply`.
+pub struct FileManager<T: Transport> {
+  client: Arc<Client<T>>,
+  state: Arc<Mutex<State>>,
+}
+
+impl<T: Transport> Clone for FileManager<T> {
+  fn clone(&self) -> Self {
+    FileManager { client: self.client.clone(), state: self.state.clone() }
+  }
+}
+
+impl<T: Transport> FileManager<T> {
+  pub fn new(client: Arc<Client<T>>) -> Self {
+    FileManager { client, state: Arc::new(Mutex::new(State::default())) }
+  }
+
+  /// Download the file, the transfer resolves to its local path.
+  ///
+  /// `priority` is from 1 to 32, files with a higher one are downloaded first. A transfer of the file already in
+  /// progress is joined, and raised to `priority` if it is higher.
+  pub fn download(&self, file_id: i64, priority: i64) -> FileTransfer<String> {
+    let priority = priority.clamp(1, MAX_PRIORITY);
+    let slot = SharedSlot::default();
+    let send = {
+      let mut state = self.state.lock().unwrap();
+      match state.transfers.get_mut(&file_id) {
+        Some(transfer) => {
+          transfer.waiters.push(slot.clone());
+          let raise = priority > transfer.priority;
+          transfer.priority = transfer.priority.max(priority);
+          raise
+        }
+        None => {
+          state.transfers.insert(file_id, Transfer { direction: Direction::Download, priority, waiters: vec![slot.clone()] });
+          true
+        }
+      }
+    };
+    if send {
+      let state = Arc::downgrade(&self.state);
+      let fnc = DownloadFile::builder().file_id(file_id).priority(priority).build();
+      self.client.send_then(&fnc, move |result| State::reply(&state, file_id, result));
+    }
+    FileTransfer { slot, map: |file| file.local().path().clone() }
+  }
+
+  /// Upload `file` before it is sent in a message, the transfer resolves to the uploaded file
+  pub fn upload(&self, file: InputFile, file_type: FileType, priority: i64) -> FileTransfer<File> {
+    let priority = priority.clamp(1, MAX_PRIORITY);
+    let slot = SharedSlot::default();
+    let waiter = slot.clone();
+    let state = Arc::downgrade(&self.state);
+    let fnc = UploadFile::builder().file(file).file_type(file_type).priority(priority).build();
+    self.client.send_then(&fnc, move |result| {
+      let file = match result.and_then(|r| r.map_err(RTDError::from)) {
+        Ok(file) => file,
+        Err(e) => return complete(&waiter, Err(e)),
+      };
+      let state = match state.upgrade() {
+        Some(state) => state,
+        None => return complete(&waiter, Err(RTDError::custom("File manager dropped"))),
+      };
+      let mut state = state.lock().unwrap();
+      state.transfers.entry(file.id())
+        .or_insert_with(|| Transfer { direction: Direction::Upload, priority, waiters: vec![] })
+        .waiters.push(waiter);
+      // updates of the file may arrive before the reply
+      let file = match state.files.get(&file.id()) {
+        Some(known) if known.remote().is_uploading_completed() => known.clone(),
+        _ => file,
+      };
+      state.update(&file);
+    });
+    FileTransfer { slot, map: |file| file }
+  }
+
+  /// Stop the transfer of the file, its waiters fail with `Transfer cancelled`
+  pub fn cancel(&self, file_id: i64) {
+    let direction = {
+      let mut state = self.state.lock().unwrap();
+      let direction = state.transfers.get(&file_id).map(|t| t.direction);
+      state.finish(file_id, Err(RTDError::custom("Transfer cancelled")));
+      direction
+    };
+    match direction {
+      Some(Direction::Download) => {
+        let fnc = CancelDownloadFile::builder().file_id(file_id).only_if_pending(false).build();
+        self.client.send_then(&fnc, |_| {})
+      }
+      Some(Direction::Upload) => self.client.send_then(&CancelUploadFile::builder().file_id(file_id).build(), |_| {}),
+      None => {}
+    }
+  }
+
+  /// Progress of the file from now on, the stream ends when a transfer of the file completes or is cancelled
+  pub fn progress(&self, file_id: i64) -> Receiver<FileProgress> {
+    let (sender, receiver) = mpsc::channel();
+    self.state.lock().unwrap().subscribers.entry(file_id).or_default().push(sender);
+    receiver
+  }
+
+  /// Last known state of the file
+  pub fn file(&self, file_id: i64) -> Option<File> {
+    self.state.lock().unwrap().files.get(&file_id).cloned()
+  }
+
+  /// Apply an `updateFile`, returns false for any other update
+  pub fn apply(&self, update: &Update) -> bool {
+    match update {
+      Update::File(update) => {
+        self.state.lock().unwrap().update(update.file());
+        true
+      }
+      _ => false,
+    }
+  }
+}
+
+/// A download or an upload, dropping it does not stop the transfer, see `FileManager::cancel`
+pub struct FileTransfer<O> {
+  slot: SharedSlot,
+  map: fn(File) -> O,
+}
+
+impl<O> Future for FileTransfer<O> {
+  type Output = RTDResult<O>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut slot = self.slot.lock().unwrap();
+    match slot.result.take() {
+      Some(result) => Poll::Ready(result.map(self.map)),
+      None => {
+        slot.waker = Some(cx.waker().clone());
+        Poll::Pending
+      }
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::future::Future;
+  use std::sync::{Arc, Mutex};
+  use std::task::{Context, Poll, Wake};
+  use std::thread::{self, Thread};
+  use std::time::Duration;
+
+  use crate::client::*;
+  use crate::client::tests::EchoTransport;
+  use crate::errors::*;
+  use crate::types::*;
+
+  /// Reply to `downloadFile` and `uploadFile` with a file just started, `ok` to everything else
+  #[derive(Default)]
+  struct FileTransport {
+    echo: Arc<EchoTransport>,
+    requests: Mutex<Vec<String>>,
+  }
+
+  impl Transport for Arc<FileTransport> {
+    fn send(&self, request: &str) {
+      let (td_type, extra) = detect_td_type_and_extra(request);
+      let td_type = td_type.unwrap();
+      self.requests.lock().unwrap().push(td_type.clone());
+      let request: serde_json::Value = serde_json::from_str(request).unwrap();
+      let file = match td_type.as_str() {
+        // a file without fields, which fails to parse
+        "downloadFile" if request["file_id"] == 7 => {
+          return self.echo.push(format!(r#"{{"@type":"file","@extra":"{}"}}"#, extra.unwrap()));
+        }
+        "downloadFile" => file(request["file_id"].as_i64().unwrap(), 0, false),
+        "uploadFile" => file(9, 0, false),
+        _ => return self.echo.send(&request.to_string()),
+      };
+      let mut reply = serde_json::to_value(&file).unwrap();
+      reply["@extra"] = extra.unwrap().into();
+      self.echo.push(reply.to_string());
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> { self.echo.receive(timeout) }
+
+    fn execute(&self, _request: &str) -> Option<String> { None }
+  }
+
+  fn file(id: i64, transferred: i64, is_completed: bool) -> File {
+    File::builder()
+      .id(id)
+      .size(100)
+      .local(LocalFile::builder().path(format!("/files/{}", id)).downloaded_size(transferred).is_downloading_completed(is_completed).build())
+      .remote(RemoteFile::builder().uploaded_size(transferred).is_uploading_active(!is_completed).is_uploading_completed(is_completed).build())
+      .build()
+  }
+
+  fn update(file: File) -> Update {
+    Update::file(UpdateFile::builder().file(file).build())
+  }
+
+  struct ThreadWaker(Thread);
+
+  impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) { self.0.unpark() }
+  }
+
+  fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+      match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => return output,
+        Poll::Pending => thread::park_timeout(Duration::from_millis(100)),
+      }
+    }
+  }
+
+  fn manager() -> (FileManager<Arc<FileTransport>>, Arc<FileTransport>) {
+    let transport = Arc::new(FileTransport::default());
+    (FileManager::new(Arc::new(Client::new(transport.clone(), |_| {}))), transport)
+  }
+
+  #[test]
+  fn test_download() {
+    let (manager, transport) = manager();
+    let first = manager.download(1, 1);
+    let second = manager.download(1, 5);
+    let third = manager.download(1, 2);
+    assert_eq!(*transport.requests.lock().unwrap(), vec!["downloadFile", "downloadFile"]);
+
+    assert!(manager.apply(&update(file(1, 100, true))));
+    for transfer in [first, second, third] {
+      assert_eq!(block_on(transfer).unwrap(), "/files/1");
+    }
+    assert_eq!(manager.file(1).unwrap().id(), 1);
+  }
+
+  #[test]
+  fn test_download_shared_error() {
+    let (manager, _) = manager();
+    let first = manager.download(7, 1);
+    let second = manager.download(7, 1);
+    for transfer in [first, second] {
+      match block_on(transfer) {
+        Err(RTDError::SerdeJson(error)) => assert!(error.to_string().starts_with("missing field")),
+        result => panic!("{:?}", result),
+      }
+    }
+  }
+
+  #[test]
+  fn test_upload() {
+    let (manager, _) = manager();
+    let input = InputFile::local(InputFileLocal::builder().path("/tmp/photo.jpg").build());
+    let transfer = manager.upload(input, FileType::photo(FileTypePhoto::builder().build()), 1);
+    manager.apply(&update(file(9, 100, true)));
+    assert!(block_on(transfer).unwrap().remote().is_uploading_completed());
+  }
+
+  #[test]
+  fn test_progress_and_cancel() {
+    let (manager, transport) = manager();
+    let progress = manager.progress(3);
+    let transfer = manager.download(3, 1);
+    manager.apply(&update(file(3, 50, false)));
+    let seen = progress.iter().find(|p| p.transferred == 50).unwrap();
+    assert_eq!(seen, FileProgress { file_id: 3, transferred: 50, size: 100, is_completed: false });
+
+    manager.cancel(3);
+    assert!(block_on(transfer).is_err());
+    assert!(progress.iter().all(|p| !p.is_completed));
+    assert_eq!(transport.requests.lock().unwrap().last().unwrap(), "cancelDownloadFile");
+  }
+}
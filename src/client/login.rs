@@ -0,0 +1,199 @@
+use crate::client::{AuthHandler, LoginMethod};
+use crate::errors::*;
+use crate::types::*;
+
+/// Empty modules around the code, so scanners find its edges
+const QUIET_ZONE: usize = 2;
+
+/// Log in a bot with its token, no other answer is needed
+#[derive(Debug, Clone)]
+pub struct BotLogin {
+  parameters: TdlibParameters,
+  token: String,
+  encryption_key: String,
+}
+
+impl BotLogin {
+  pub fn new<S: AsRef<str>>(parameters: TdlibParameters, token: S) -> Self {
+    BotLogin { parameters, token: token.as_ref().to_string(), encryption_key: String::new() }
+  }
+
+  /// Key of the local database, empty by default
+  pub fn encryption_key<S: AsRef<str>>(mut self, encryption_key: S) -> Self {
+    self.encryption_key = encryption_key.as_ref().to_string();
+    self
+  }
+}
+
+impl AuthHandler for BotLogin {
+  fn tdlib_parameters(&mut self) -> TdlibParameters { self.parameters.clone() }
+
+  fn login_method(&mut self) -> LoginMethod { LoginMethod::BotToken(self.token.clone()) }
+
+  fn encryption_key(&mut self) -> RTDResult<String> { Ok(self.encryption_key.clone()) }
+}
+
+/// Log in by scanning a QR code with another device, `show` is called with the code of every link tdlib sends.
+///
+/// tdlib changes the link before it expires, each new link is shown again.
+pub struct QrLogin<F: FnMut(&QrCode)> {
+  parameters: TdlibParameters,
+  encryption_key: String,
+  password: Option<String>,
+  show: F,
+  link: Option<String>,
+}
+
+impl<F: FnMut(&QrCode)> QrLogin<F> {
+  pub fn new(parameters: TdlibParameters, show: F) -> Self {
+    QrLogin { parameters, encryption_key: String::new(), password: None, show, link: None }
+  }
+
+  /// Key of the local database, empty by default
+  pub fn encryption_key<S: AsRef<str>>(mut self, encryption_key: S) -> Self {
+    self.encryption_key = encryption_key.as_ref().to_string();
+    self
+  }
+
+  /// Two-step verification password of the account, if it has one
+  pub fn password<S: AsRef<str>>(mut self, password: S) -> Self {
+    self.password = Some(password.as_ref().to_string());
+    self
+  }
+
+  /// The link shown last
+  pub fn link(&self) -> Option<&str> { self.link.as_deref() }
+}
+
+impl<F: FnMut(&QrCode)> AuthHandler for QrLogin<F> {
+  fn tdlib_parameters(&mut self) -> TdlibParameters { self.parameters.clone() }
+
+  fn login_method(&mut self) -> LoginMethod { LoginMethod::QrCode }
+
+  fn encryption_key(&mut self) -> RTDResult<String> { Ok(self.encryption_key.clone()) }
+
+  fn password(&mut self, _hint: &str) -> RTDResult<String> {
+    self.password.clone().ok_or(RTDError::custom("Password is not supported"))
+  }
+
+  fn confirm_qr_code(&mut self, link: &str) -> RTDResult<()> {
+    if self.link.as_deref() == Some(link) {
+      return Ok(());
+    }
+    (self.show)(&QrCode::new(link)?);
+    self.link = Some(link.to_string());
+    Ok(())
+  }
+}
+
+/// QR code of a `tg://login?token=` link
+#[derive(Debug, Clone)]
+pub struct QrCode {
+  link: String,
+  width: usize,
+  modules: Vec<bool>,
+}
+
+impl QrCode {
+  pub fn new<S: AsRef<str>>(link: S) -> RTDResult<Self> {
+    let code = qrcode::QrCode::new(link.as_ref().as_bytes()).map_err(|_| RTDError::custom("Link is too long for a QR code"))?;
+    let modules = code.to_colors().into_iter().map(|color| color == qrcode::Color::Dark).collect();
+    Ok(QrCode { link: link.as_ref().to_string(), width: code.width(), modules })
+  }
+
+  pub fn link(&self) -> &String { &self.link }
+
+  /// Number of modules on a side, without the quiet zone
+  pub fn width(&self) -> usize { self.width }
+
+  /// Whether the module at column `x` and row `y` is dark
+  pub fn is_dark(&self, x: usize, y: usize) -> bool {
+    x < self.width && y < self.width && self.modules[y * self.width + x]
+  }
+
+  /// Dark modules as `##`, for terminals without unicode
+  pub fn to_ascii(&self) -> String {
+    self.render(1, |top, _| if top { "##" } else { "  " })
+  }
+
+  /// Two rows of modules per line with half blocks, dark modules are drawn in the foreground color
+  pub fn to_unicode(&self) -> String {
+    self.render(2, |top, bottom| match (top, bottom) {
+      (true, true) => "█",
+      (true, false) => "▀",
+      (false, true) => "▄",
+      (false, false) => " ",
+    })
+  }
+
+  fn render<C: Fn(bool, bool) -> &'static str>(&self, rows: usize, cell: C) -> String {
+    let size = self.width + 2 * QUIET_ZONE;
+    let dark = |x: usize, y: usize| x >= QUIET_ZONE && y >= QUIET_ZONE && self.is_dark(x - QUIET_ZONE, y - QUIET_ZONE);
+    let mut text = String::new();
+    for y in (0..size).step_by(rows) {
+      for x in 0..size {
+        text.push_str(cell(dark(x, y), rows > 1 && dark(x, y + 1)));
+      }
+      text.push('\n');
+    }
+    text
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::cell::RefCell;
+
+  use crate::client::*;
+  use crate::types::*;
+
+  #[test]
+  fn test_qr_code() {
+    let code = QrCode::new("tg://login?token=AQLR6sZfAAAAAH3a").unwrap();
+    assert_eq!(code.width(), 29);
+    // finder pattern in the top left corner
+    assert!((0..7).all(|i| code.is_dark(i, 0) && code.is_dark(0, i)));
+    assert!(!code.is_dark(1, 1));
+    assert!(!code.is_dark(29, 0));
+
+    let ascii = code.to_ascii();
+    assert_eq!(ascii.lines().count(), 33);
+    assert!(ascii.lines().all(|line| line.len() == 66));
+    assert!(ascii.lines().nth(2).unwrap().starts_with("    ##############"));
+    let unicode = code.to_unicode();
+    assert_eq!(unicode.lines().count(), 17);
+    assert!(unicode.lines().all(|line| line.chars().count() == 33));
+  }
+
+  #[test]
+  fn test_qr_login() {
+    let shown = RefCell::new(vec![]);
+    let login = QrLogin::new(TdlibParameters::default(), |code: &QrCode| shown.borrow_mut().push(code.link().clone()));
+    let mut authenticator = Authenticator::new(login);
+    let states = [
+      r#"{"@type":"authorizationStateWaitPhoneNumber"}"#,
+      r#"{"@type":"authorizationStateWaitOtherDeviceConfirmation","link":"tg://login?token=first"}"#,
+      r#"{"@type":"authorizationStateWaitOtherDeviceConfirmation","link":"tg://login?token=first"}"#,
+      r#"{"@type":"authorizationStateWaitOtherDeviceConfirmation","link":"tg://login?token=second"}"#,
+    ];
+    let requests: Vec<Option<&str>> = states.iter()
+      .map(|s| authenticator.answer(&AuthorizationState::from_json(s).unwrap()).unwrap().map(|r| r.td_name()))
+      .collect();
+    assert_eq!(requests, vec![Some("requestQrCodeAuthentication"), None, None, None]);
+    assert_eq!(authenticator.handler().link(), Some("tg://login?token=second"));
+    let password = r#"{"@type":"authorizationStateWaitPassword","password_hint":"","has_recovery_email_address":false,"recovery_email_address_pattern":""}"#;
+    assert!(authenticator.answer(&AuthorizationState::from_json(password).unwrap()).is_err());
+    drop(authenticator);
+    assert_eq!(*shown.borrow(), vec!["tg://login?token=first", "tg://login?token=second"]);
+  }
+
+  #[test]
+  fn test_bot_login() {
+    let mut authenticator = Authenticator::new(BotLogin::new(TdlibParameters::default(), "123:token"));
+    let state = AuthorizationState::from_json(r#"{"@type":"authorizationStateWaitPhoneNumber"}"#).unwrap();
+    let request = authenticator.answer(&state).unwrap().unwrap();
+    assert_eq!(request.td_name(), "checkAuthenticationBotToken");
+    assert!(request.to_json().unwrap().contains(r#""token":"123:token""#));
+  }
+}
@@ -0,0 +1,328 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::client::Transport;
+use crate::types::*;
+
+/// JSON a `MockTd` is scripted with: JSON text, a `serde_json::Value` or any td object
+pub trait MockObject {
+  fn to_value(&self) -> Value;
+}
+
+impl MockObject for str {
+  fn to_value(&self) -> Value {
+    serde_json::from_str(self).unwrap_or_else(|e| panic!("Invalid JSON in MockTd script: {}: {}", e, self))
+  }
+}
+
+impl MockObject for String {
+  fn to_value(&self) -> Value {
+    self.as_str().to_value()
+  }
+}
+
+impl MockObject for Value {
+  fn to_value(&self) -> Value {
+    self.clone()
+  }
+}
+
+impl<T: RObject> MockObject for T {
+  fn to_value(&self) -> Value {
+    let json = self.to_json().unwrap_or_else(|e| panic!("Can't serialize {:?} for MockTd: {}", self, e));
+    json.as_str().to_value()
+  }
+}
+
+enum Step {
+  Respond(Value),
+  Update(Value),
+}
+
+struct Expectation {
+  pattern: Value,
+  steps: Vec<Step>,
+  times: usize,
+  calls: usize,
+}
+
+#[derive(Default)]
+struct State {
+  expectations: Vec<Expectation>,
+  queue: VecDeque<String>,
+  received: Vec<String>,
+  unexpected: Vec<String>,
+}
+
+#[derive(Default)]
+struct Inner {
+  state: Mutex<State>,
+  ready: Condvar,
+}
+
+/// A tdlib without network for tests, with the send/receive/execute surface of `Tdlib`.
+///
+/// Requests are answered by the first expectation they match which has calls left. A request matches when its
+/// `@type` and every field of the pattern are equal, fields missing from the pattern are not compared. Responses
+/// carry the `@extra` of the request.
+///
+/// A request matching no expectation fails with a tdlib error and is reported by `verify`, or when the `MockTd`
+/// made by `new` is dropped. Clones, e.g. the one a client owns, do not check as they may be dropped on another
+/// thread.
+///
+/// ```
+/// use rtdlib::client::{MockTd, SyncClient};
+/// use rtdlib::types::*;
+///
+/// let td = MockTd::new();
+/// td.expect(r#"{"@type":"getChat","chat_id":1}"#).respond_error(400, "Chat not found");
+/// td.expect(&SetOption::builder().name("online").build())
+///   .respond(r#"{"@type":"ok"}"#)
+///   .update(r#"{"@type":"updateOption","name":"online","value":{"@type":"optionValueBoolean","value":true}}"#);
+///
+/// let (client, updates) = SyncClient::new(td.clone());
+/// let error = client.execute(&GetChat::builder().chat_id(1).build()).unwrap().unwrap_err();
+/// assert_eq!(error.message(), "Chat not found");
+/// assert!(client.execute(&SetOption::builder().name("online").build()).unwrap().is_ok());
+/// assert_eq!(updates.recv().unwrap().td_name(), "updateOption");
+/// td.verify();
+/// ```
+pub struct MockTd {
+  inner: Arc<Inner>,
+  /// Whether this is the `MockTd` made by `new`, which checks for unexpected requests when dropped
+  owner: bool,
+}
+
+impl Default for MockTd {
+  fn default() -> Self {
+    MockTd { inner: Arc::default(), owner: true }
+  }
+}
+
+impl Clone for MockTd {
+  fn clone(&self) -> Self {
+    MockTd { inner: self.inner.clone(), owner: false }
+  }
+}
+
+impl Drop for MockTd {
+  fn drop(&mut self) {
+    if !self.owner || thread::panicking() {
+      return;
+    }
+    let state = self.inner.state.lock().unwrap_or_else(|e| e.into_inner());
+    if !state.unexpected.is_empty() {
+      let unexpected = state.unexpected.join("\n");
+      drop(state);
+      panic!("MockTd received unexpected requests:\n{}", unexpected);
+    }
+  }
+}
+
+/// An expectation of a `MockTd`, the responses and updates are sent in the order they are added
+pub struct MockExpectation<'a> {
+  td: &'a MockTd,
+  index: usize,
+}
+
+impl<'a> MockExpectation<'a> {
+  /// Reply to the request with `response`
+  pub fn respond<R: MockObject + ?Sized>(self, response: &R) -> Self {
+    self.step(Step::Respond(response.to_value()))
+  }
+
+  /// Reply to the request with a tdlib error
+  pub fn respond_error<S: AsRef<str>>(self, code: i64, message: S) -> Self {
+    self.step(Step::Respond(serde_json::json!({"@type": "error", "code": code, "message": message.as_ref()})))
+  }
+
+  /// Send `update` after the request
+  pub fn update<U: MockObject + ?Sized>(self, update: &U) -> Self {
+    self.step(Step::Update(update.to_value()))
+  }
+
+  /// Expect the request `times` times instead of once
+  pub fn times(self, times: usize) -> Self {
+    self.td.inner.state.lock().unwrap().expectations[self.index].times = times;
+    self
+  }
+
+  fn step(self, step: Step) -> Self {
+    self.td.inner.state.lock().unwrap().expectations[self.index].steps.push(step);
+    self
+  }
+}
+
+impl MockTd {
+  pub fn new() -> Self {
+    MockTd::default()
+  }
+
+  /// Expect a request matching `pattern` once, `@extra` of the pattern is ignored
+  pub fn expect<P: MockObject + ?Sized>(&self, pattern: &P) -> MockExpectation<'_> {
+    let mut pattern = pattern.to_value();
+    if let Some(map) = pattern.as_object_mut() { map.remove("@extra"); }
+    let mut state = self.inner.state.lock().unwrap();
+    state.expectations.push(Expectation { pattern, steps: vec![], times: 1, calls: 0 });
+    MockExpectation { td: self, index: state.expectations.len() - 1 }
+  }
+
+  /// Send `update` now, e.g. the authorization state at start
+  pub fn update<U: MockObject + ?Sized>(&self, update: &U) {
+    let mut update = update.to_value();
+    if let Some(map) = update.as_object_mut() { map.remove("@extra"); }
+    self.push(Some(update.to_string()));
+  }
+
+  /// Every request sent or executed so far
+  pub fn received(&self) -> Vec<String> {
+    self.inner.state.lock().unwrap().received.clone()
+  }
+
+  /// Panic if a request was unexpected or an expectation has calls left
+  pub fn verify(&self) {
+    let mut state = self.inner.state.lock().unwrap();
+    let mut problems: Vec<String> = state.unexpected.drain(..).map(|request| format!("unexpected request {}", request)).collect();
+    problems.extend(state.expectations.iter()
+      .filter(|e| e.calls < e.times)
+      .map(|e| format!("expected {} {} more time(s)", e.pattern, e.times - e.calls)));
+    if !problems.is_empty() {
+      drop(state);
+      panic!("MockTd verification failed:\n{}", problems.join("\n"));
+    }
+  }
+
+  pub fn send(&self, request: &str) {
+    let replies = self.answer(request);
+    self.push(replies.into_iter().map(|(_, json)| json));
+  }
+
+  pub fn receive(&self, timeout: f64) -> Option<String> {
+    let state = self.inner.state.lock().unwrap();
+    let (mut state, _) = self.inner.ready.wait_timeout_while(state, Duration::from_secs_f64(timeout), |s| s.queue.is_empty()).unwrap();
+    state.queue.pop_front()
+  }
+
+  /// The first response is returned, the rest of the replies are received later
+  pub fn execute(&self, request: &str) -> Option<String> {
+    let mut replies = self.answer(request);
+    let response = replies.iter().position(|(is_response, _)| *is_response).map(|i| replies.remove(i).1);
+    self.push(replies.into_iter().map(|(_, json)| json));
+    response
+  }
+
+  fn push<I: IntoIterator<Item = String>>(&self, jsons: I) {
+    self.inner.state.lock().unwrap().queue.extend(jsons);
+    self.inner.ready.notify_all();
+  }
+
+  /// Replies to `request` in script order, and whether each is a response
+  fn answer(&self, request: &str) -> Vec<(bool, String)> {
+    let mut state = self.inner.state.lock().unwrap();
+    state.received.push(request.to_string());
+    let value: Value = serde_json::from_str(request).unwrap_or(Value::Null);
+    let extra = value.get("@extra").cloned();
+    let with_extra = |response: &Value, extra: Option<&Value>| {
+      let mut response = response.clone();
+      if let Some(map) = response.as_object_mut() {
+        map.remove("@extra");
+        if let Some(extra) = extra { map.insert("@extra".to_string(), extra.clone()); }
+      }
+      response.to_string()
+    };
+
+    let found = state.expectations.iter_mut().find(|e| e.calls < e.times && matches(&e.pattern, &value));
+    match found {
+      Some(expectation) => {
+        expectation.calls += 1;
+        expectation.steps.iter().map(|step| match step {
+          Step::Respond(response) => (true, with_extra(response, extra.as_ref())),
+          Step::Update(update) => (false, with_extra(update, None)),
+        }).collect()
+      }
+      None => {
+        state.unexpected.push(request.to_string());
+        let error = serde_json::json!({"@type": "error", "code": 400, "message": format!("Unexpected request {}", request)});
+        vec![(true, with_extra(&error, extra.as_ref()))]
+      }
+    }
+  }
+}
+
+impl Transport for MockTd {
+  fn send(&self, request: &str) { MockTd::send(self, request) }
+  fn receive(&self, timeout: f64) -> Option<String> { MockTd::receive(self, timeout) }
+  fn execute(&self, request: &str) -> Option<String> { MockTd::execute(self, request) }
+}
+
+/// Whether `value` has every field of `pattern`, numbers match their decimal strings as tdlib accepts both
+fn matches(pattern: &Value, value: &Value) -> bool {
+  match (pattern, value) {
+    (Value::Object(pattern), _) => pattern.iter()
+      .filter(|(key, _)| *key != "@extra")
+      .all(|(key, field)| matches(field, value.get(key).unwrap_or(&Value::Null))),
+    (Value::Array(pattern), Value::Array(values)) => pattern.len() == values.len()
+      && pattern.iter().zip(values).all(|(pattern, value)| matches(pattern, value)),
+    (Value::Number(number), Value::String(string)) | (Value::String(string), Value::Number(number)) => number.to_string() == *string,
+    (pattern, value) => pattern == value,
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use crate::client::{MockTd, SyncClient, Transport};
+  use crate::types::*;
+
+  #[test]
+  fn test_script() {
+    let td = MockTd::new();
+    td.update(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"}}"#);
+    td.expect(r#"{"@type":"deleteMessages","chat_id":"1"}"#)
+      .update(r#"{"@type":"updateDeleteMessages","chat_id":1,"message_ids":[2],"is_permanent":true,"from_cache":false}"#)
+      .respond(r#"{"@type":"ok","@extra":"ignored"}"#)
+      .times(2);
+    td.expect(&serde_json::json!({"@type": "getOption", "name": "version"}))
+      .respond(r#"{"@type":"optionValueString","value":"1.7.0"}"#);
+
+    // the raw surface echoes any `@extra`
+    td.send(r#"{"@type":"deleteMessages","chat_id":1,"message_ids":[2],"@extra":7}"#);
+    assert_eq!(td.receive(1.0).unwrap(), r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateReady"}}"#);
+    assert!(td.receive(1.0).unwrap().starts_with(r#"{"@type":"updateDeleteMessages""#));
+    assert_eq!(td.receive(1.0).unwrap(), r#"{"@extra":7,"@type":"ok"}"#);
+    assert_eq!(Transport::execute(&td, r#"{"@type":"getOption","name":"version"}"#).unwrap(), r#"{"@type":"optionValueString","value":"1.7.0"}"#);
+    assert!(td.receive(0.01).is_none());
+
+    let (client, updates) = SyncClient::new(td.clone());
+    assert!(client.execute(&DeleteMessages::builder().chat_id(1).message_ids(vec![2]).build()).unwrap().is_ok());
+    assert_eq!(updates.recv_timeout(Duration::from_secs(5)).unwrap().td_name(), "updateDeleteMessages");
+    assert_eq!(td.received().len(), 3);
+    td.verify();
+  }
+
+  #[test]
+  #[should_panic(expected = "MockTd verification failed:\nunexpected request {\"@type\":\"getMe\"}\nexpected {\"@type\":\"close\"} 1 more time(s)")]
+  fn test_unexpected() {
+    let td = MockTd::new();
+    td.expect(&Close::builder().build()).respond(r#"{"@type":"ok"}"#);
+    td.send(r#"{"@type":"getMe"}"#);
+    let error = Error::from_json(td.receive(1.0).unwrap()).unwrap();
+    assert_eq!(error.code(), 400);
+    td.verify();
+  }
+
+  #[test]
+  #[should_panic(expected = "MockTd received unexpected requests:\n{\"@extra\"")]
+  fn test_unexpected_without_verify() {
+    let td = MockTd::new();
+    let (client, _updates) = SyncClient::new(td.clone());
+    let error = client.execute(&GetMe::builder().build()).unwrap().unwrap_err();
+    assert_eq!(error.code(), 400);
+  }
+}
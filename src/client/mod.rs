@@ -0,0 +1,77 @@
+//! Clients talk to tdlib through a [`Transport`](trait.Transport.html), replies are matched to
+//! requests by the `@extra` field.
+
+pub use self::async_client::*;
+pub use self::auth::*;
+pub use self::file_generator::*;
+pub use self::file_manager::*;
+pub use self::login::*;
+pub use self::mock::*;
+pub use self::sync_client::*;
+
+mod async_client;
+mod auth;
+mod file_generator;
+mod file_manager;
+mod login;
+mod mock;
+mod router;
+mod sync_client;
+
+/// The send/receive/execute surface of tdjson
+pub trait Transport: Send + Sync + 'static {
+  /// Sends request to the TDLib client, the reply will be returned by `receive`
+  fn send(&self, request: &str);
+  /// Receives incoming updates and request responses, waits at most `timeout` seconds
+  fn receive(&self, timeout: f64) -> Option<String>;
+  /// Synchronously executes TDLib request, only a few requests can be executed synchronously
+  fn execute(&self, request: &str) -> Option<String>;
+}
+
+#[cfg(feature = "sys")]
+impl Transport for crate::Tdlib {
+  fn send(&self, request: &str) { crate::Tdlib::send(self, request) }
+  fn receive(&self, timeout: f64) -> Option<String> { crate::Tdlib::receive(self, timeout) }
+  fn execute(&self, request: &str) -> Option<String> { crate::Tdlib::execute(self, request) }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::collections::VecDeque;
+  use std::sync::{Arc, Condvar, Mutex};
+  use std::time::Duration;
+
+  use crate::client::Transport;
+  use crate::types::*;
+
+  /// Answer `ok` to every request except `close`, which is never answered
+  #[derive(Default)]
+  pub struct EchoTransport {
+    queue: Mutex<VecDeque<String>>,
+    ready: Condvar,
+  }
+
+  impl EchoTransport {
+    pub fn push(&self, json: String) {
+      self.queue.lock().unwrap().push_back(json);
+      self.ready.notify_all();
+    }
+  }
+
+  impl Transport for Arc<EchoTransport> {
+    fn send(&self, request: &str) {
+      let (td_type, extra) = detect_td_type_and_extra(request);
+      if td_type == Some("close".to_string()) { return }
+      self.push(format!(r#"{{"@type":"ok","@extra":"{}"}}"#, extra.unwrap()));
+    }
+
+    fn receive(&self, timeout: f64) -> Option<String> {
+      let queue = self.queue.lock().unwrap();
+      let (mut queue, _) = self.ready.wait_timeout_while(queue, Duration::from_secs_f64(timeout), |q| q.is_empty()).unwrap();
+      queue.pop_front()
+    }
+
+    fn execute(&self, _request: &str) -> Option<String> { None }
+  }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::client::Transport;
+use crate::errors::*;
+use crate::types::*;
+
+/// The longest time the receive loop waits in tdlib, so close and timeouts are noticed in time
+const RECEIVE_TIMEOUT: f64 = 1.0;
+
+/// Called once with the reply json, or with the reason the reply will never arrive
+pub(crate) type Reply = Box<dyn FnOnce(RTDResult<String>) + Send>;
+
+struct Pending {
+  reply: Reply,
+  deadline: Option<Instant>,
+}
+
+/// Dispatching core shared by clients, sends functions to tdlib and routes every received object either to the
+/// pending request with the same `@extra` or to the handler.
+pub(crate) struct Router<T: Transport> {
+  transport: T,
+  pending: Mutex<HashMap<String, Pending>>,
+  closed: AtomicBool,
+}
+
+impl<T: Transport> Router<T> {
+  /// Start the receive loop on a dedicated thread, objects not matched to a request are passed to `handler`
+  pub fn start<H>(transport: T, handler: H) -> Arc<Self> where H: Fn(String) + Send + 'static {
+    let router = Arc::new(Router {
+      transport,
+      pending: Mutex::new(HashMap::new()),
+      closed: AtomicBool::new(false),
+    });
+    let looper = router.clone();
+    thread::Builder::new()
+      .name("rtdlib-receive".to_string())
+      .spawn(move || looper.run(handler))
+      .expect("Can not spawn receive thread");
+    router
+  }
+
+  /// Send `fnc` to tdlib, return the `@extra` the reply will carry. If `fnc` has no `@extra`, or the same `@extra` is
+  /// already waiting for a reply, a new one is generated.
+  pub fn send<F: RFunction>(&self, fnc: &F, timeout: Option<Duration>, reply: Reply) -> RTDResult<String> {
+    let mut value: serde_json::Value = serde_json::from_str(&fnc.to_json()?)?;
+    let map = match value.as_object_mut() {
+      Some(map) => map,
+      None => return Err(RTDError::custom("Function is not a json object")),
+    };
+    let extra = {
+      let mut pending = self.pending.lock().unwrap();
+      if self.is_closed() {
+        return Err(RTDError::custom("Client closed"));
+      }
+      let extra = match map.get("@extra").and_then(|v| v.as_str()) {
+        Some(extra) if !pending.contains_key(extra) => extra.to_string(),
+        _ => Uuid::new_v4().to_string(),
+      };
+      map.insert("@extra".to_string(), serde_json::Value::String(extra.clone()));
+      let deadline = timeout.map(|timeout| Instant::now() + timeout);
+      pending.insert(extra.clone(), Pending { reply, deadline });
+      extra
+    };
+    self.transport.send(&serde_json::to_string(&value)?);
+    Ok(extra)
+  }
+
+  /// Synchronously execute `fnc`, only a few functions can be executed synchronously
+  pub fn execute<F: RFunction>(&self, fnc: &F) -> RTDResult<Result<F::Response, Error>> {
+    match self.transport.execute(&fnc.to_json()?) {
+      Some(json) => parse_response::<F, _>(json),
+      None => Err(RTDError::custom("Function can not be executed synchronously")),
+    }
+  }
+
+  /// Forget the request, its reply will be passed to the handler
+  pub fn cancel(&self, extra: &str) {
+    self.pending.lock().unwrap().remove(extra);
+  }
+
+  /// Stop the receive loop, all pending requests fail with `Client closed`
+  pub fn close(&self) {
+    self.closed.store(true, Ordering::SeqCst);
+    let pending: Vec<Pending> = self.pending.lock().unwrap().drain().map(|(_, p)| p).collect();
+    pending.into_iter().for_each(|p| (p.reply)(Err(RTDError::custom("Client closed"))));
+  }
+
+  pub fn is_closed(&self) -> bool {
+    self.closed.load(Ordering::SeqCst)
+  }
+
+  fn run<H: Fn(String)>(&self, handler: H) {
+    while !self.is_closed() {
+      if let Some(json) = self.transport.receive(self.receive_timeout()) {
+        self.dispatch(json, &handler);
+      }
+      self.expire();
+    }
+  }
+
+  fn dispatch<H: Fn(String)>(&self, json: String, handler: &H) {
+    let (_, extra) = detect_td_type_and_extra(&json);
+    let pending = extra.and_then(|extra| self.pending.lock().unwrap().remove(&extra));
+    match pending {
+      Some(p) => (p.reply)(Ok(json)),
+      None => handler(json),
+    }
+  }
+
+  fn receive_timeout(&self) -> f64 {
+    let now = Instant::now();
+    self.pending.lock().unwrap().values()
+      .filter_map(|p| p.deadline)
+      .map(|deadline| deadline.saturating_duration_since(now).as_secs_f64())
+      .fold(RECEIVE_TIMEOUT, f64::min)
+  }
+
+  fn expire(&self) {
+    let now = Instant::now();
+    let expired: Vec<Pending> = {
+      let mut pending = self.pending.lock().unwrap();
+      let extras: Vec<String> = pending.iter()
+        .filter(|(_, p)| p.deadline.is_some_and(|deadline| deadline <= now))
+        .map(|(extra, _)| extra.clone())
+        .collect();
+      extras.iter().filter_map(|extra| pending.remove(extra)).collect()
+    };
+    expired.into_iter().for_each(|p| (p.reply)(Err(RTDError::custom("Request timeout"))));
+  }
+}
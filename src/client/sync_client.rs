@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use crate::client::router::Router;
+use crate::client::Transport;
+use crate::errors::*;
+use crate::types::*;
+
+/// Blocking tdlib client, `execute` waits for the reply with the same `@extra`, every update is pushed to the
+/// `Update` channel returned by `new`.
+pub struct SyncClient<T: Transport> {
+  router: Arc<Router<T>>,
+}
+
+impl<T: Transport> SyncClient<T> {
+  /// Create a client over `transport`, objects which are neither a reply nor an update are dropped, updates unknown
+  /// to this version arrive as `Update::Unknown`
+  pub fn new(transport: T) -> (Self, Receiver<Update>) {
+    let (sender, updates) = mpsc::channel();
+    let router = Router::start(transport, move |json: String| {
+      let update = match Update::from_json(json) {
+        Ok(Update::Unknown { td_type, .. }) if !td_type.starts_with("update") => return,
+        Ok(update) => update,
+        Err(_) => return,
+      };
+      let _ = sender.send(update);
+    });
+    (SyncClient { router }, updates)
+  }
+
+  /// Send `fnc` to tdlib and block until the reply arrives
+  pub fn execute<F: RFunction>(&self, fnc: &F) -> RTDResult<Result<F::Response, Error>> {
+    self.execute_request(fnc, None)
+  }
+
+  /// Send `fnc` to tdlib and block until the reply arrives, fails with `Request timeout` after `timeout`
+  pub fn execute_timeout<F: RFunction>(&self, fnc: &F, timeout: Duration) -> RTDResult<Result<F::Response, Error>> {
+    self.execute_request(fnc, Some(timeout))
+  }
+
+  /// Stop receiving, all waiting `execute` fail with `Client closed`
+  pub fn close(&self) {
+    self.router.close()
+  }
+
+  pub fn is_closed(&self) -> bool {
+    self.router.is_closed()
+  }
+
+  fn execute_request<F: RFunction>(&self, fnc: &F, timeout: Option<Duration>) -> RTDResult<Result<F::Response, Error>> {
+    let (sender, receiver) = mpsc::channel();
+    self.router.send(fnc, timeout, Box::new(move |result| { let _ = sender.send(result); }))?;
+    match receiver.recv() {
+      Ok(result) => parse_response::<F, _>(result?),
+      Err(_) => Err(RTDError::custom("Client closed")),
+    }
+  }
+}
+
+impl<T: Transport> Drop for SyncClient<T> {
+  fn drop(&mut self) {
+    self.router.close()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::thread;
+  use std::time::Duration;
+
+  use crate::client::SyncClient;
+  use crate::client::tests::EchoTransport;
+  use crate::types::*;
+
+  #[test]
+  fn test_execute() {
+    let transport = Arc::new(EchoTransport::default());
+    let (client, updates) = SyncClient::new(transport.clone());
+
+    let reply = client.execute(&DeleteMessages::builder().chat_id(1).message_ids(vec![1]).build());
+    assert!(reply.unwrap().is_ok());
+
+    transport.push(r#"{"@type":"ok"}"#.to_string());
+    transport.push(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"}}"#.to_string());
+    let update = updates.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(update.td_name(), "updateOption");
+  }
+
+  #[test]
+  fn test_execute_timeout_and_close() {
+    let client = Arc::new(SyncClient::new(Arc::new(EchoTransport::default())).0);
+    assert!(client.execute_timeout(&Close::builder().build(), Duration::from_millis(10)).is_err());
+
+    let waiting = client.clone();
+    let handle = thread::spawn(move || waiting.execute(&Close::builder().build()));
+    thread::sleep(Duration::from_millis(50));
+    client.close();
+    assert!(handle.join().unwrap().is_err());
+  }
+}
@@ -0,0 +1,246 @@
+//! Talk to tdlib builds of other versions than the schema of `types`.
+//!
+//! Objects are translated between versions by the changes tdlib made to the schema, as json, before they are
+//! parsed: renamed types and fields, fields added, and user ids replaced by a `MessageSender`. `Compat` wraps a
+//! transport and translates everything it sends and receives, `translate` translates a single object.
+//!
+//! Only the changes listed here are known, a field which changed in another way is still an error to parse.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use serde_json::{Map, Value};
+
+use crate::client::Transport;
+use crate::errors::*;
+use crate::types::*;
+
+/// A tdlib version, `major.minor.patch`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TdVersion(pub u32, pub u32, pub u32);
+
+/// The tdlib version of the schema `types` are generated from
+pub const SCHEMA_VERSION: TdVersion = TdVersion(1, 7, 0);
+
+impl fmt::Display for TdVersion {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}.{}.{}", self.0, self.1, self.2)
+  }
+}
+
+impl FromStr for TdVersion {
+  type Err = RTDError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let parts: Vec<u32> = s.trim().split('.').map(|part| part.parse().ok()).collect::<Option<_>>()
+      .ok_or_else(|| RTDError::custom("Invalid tdlib version"))?;
+    match parts.as_slice() {
+      [major, minor] => Ok(TdVersion(*major, *minor, 0)),
+      [major, minor, patch] => Ok(TdVersion(*major, *minor, *patch)),
+      _ => Err(RTDError::custom("Invalid tdlib version")),
+    }
+  }
+}
+
+/// A change of the schema, from the older version to the newer one
+enum Change {
+  /// The type `old` was renamed `new`
+  Type { old: &'static str, new: &'static str },
+  /// The field `old` of `td_type` was renamed `new`
+  Field { td_type: &'static str, old: &'static str, new: &'static str },
+  /// `field` was added to `td_type`, `default` is its json value for objects of older versions
+  Added { td_type: &'static str, field: &'static str, default: &'static str },
+  /// The user id `old` of `td_type` was replaced by the `MessageSender` `new`. A user id 0 of an older version is
+  /// the chat `chat` of the object when it has one.
+  Sender { td_type: &'static str, old: &'static str, new: &'static str, chat: Option<&'static str> },
+}
+
+/// The changes of every version, in the order tdlib made them
+const CHANGES: &[(TdVersion, Change)] = &[
+  (TdVersion(1, 7, 0), Change::Sender { td_type: "message", old: "sender_user_id", new: "sender", chat: Some("chat_id") }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "message", field: "is_pinned", default: "false" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "message", field: "can_get_statistics", default: "false" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "message", field: "can_get_message_thread", default: "false" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "message", field: "reply_in_chat_id", default: "0" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "message", field: "message_thread_id", default: "0" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "chat", field: "voice_chat_group_call_id", default: "0" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "chat", field: "is_voice_chat_empty", default: "true" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "updateUserChatAction", field: "message_thread_id", default: "0" }),
+  (TdVersion(1, 7, 0), Change::Added { td_type: "sendChatAction", field: "message_thread_id", default: "0" }),
+  (TdVersion(1, 8, 0), Change::Field { td_type: "message", old: "sender", new: "sender_id" }),
+  (TdVersion(1, 8, 0), Change::Sender { td_type: "chatMember", old: "user_id", new: "member_id", chat: None }),
+  (TdVersion(1, 8, 0), Change::Sender { td_type: "getChatMember", old: "user_id", new: "member_id", chat: None }),
+  (TdVersion(1, 8, 0), Change::Sender { td_type: "chatEvent", old: "user_id", new: "member_id", chat: None }),
+  (TdVersion(1, 8, 0), Change::Type { old: "updateUserChatAction", new: "updateChatAction" }),
+  (TdVersion(1, 8, 0), Change::Sender { td_type: "updateChatAction", old: "user_id", new: "sender_id", chat: None }),
+];
+
+impl Change {
+  fn upgrade(&self, object: &mut Map<String, Value>) {
+    let td_type = object.get("@type").and_then(|t| t.as_str()).unwrap_or_default().to_string();
+    match *self {
+      Change::Type { old, new } => if td_type == old { object.insert("@type".to_string(), Value::from(new)); },
+      Change::Field { td_type: t, old, new } => if td_type == t {
+        if let Some(value) = object.remove(old) { object.insert(new.to_string(), value); }
+      },
+      Change::Added { td_type: t, field, default } => if td_type == t && !object.contains_key(field) {
+        object.insert(field.to_string(), serde_json::from_str(default).unwrap_or_default());
+      },
+      Change::Sender { td_type: t, old, new, chat } => if td_type == t {
+        if let Some(user_id) = object.remove(old) {
+          let sender = match chat.and_then(|chat| object.get(chat)).filter(|_| user_id == 0) {
+            Some(chat_id) => serde_json::json!({"@type": "messageSenderChat", "chat_id": chat_id}),
+            None => serde_json::json!({"@type": "messageSenderUser", "user_id": user_id}),
+          };
+          object.insert(new.to_string(), sender);
+        }
+      },
+    }
+  }
+
+  fn downgrade(&self, object: &mut Map<String, Value>) {
+    let td_type = object.get("@type").and_then(|t| t.as_str()).unwrap_or_default().to_string();
+    match *self {
+      Change::Type { old, new } => if td_type == new { object.insert("@type".to_string(), Value::from(old)); },
+      Change::Field { td_type: t, old, new } => if td_type == t {
+        if let Some(value) = object.remove(new) { object.insert(old.to_string(), value); }
+      },
+      Change::Added { td_type: t, field, .. } => if td_type == t { object.remove(field); },
+      // a chat is not a user, older versions show it as user id 0
+      Change::Sender { td_type: t, old, new, .. } => if td_type == t {
+        if let Some(sender) = object.remove(new) {
+          object.insert(old.to_string(), sender.get("user_id").cloned().unwrap_or_else(|| Value::from(0)));
+        }
+      },
+    }
+  }
+}
+
+/// Translate `object`, and every object inside it, from the schema of tdlib `from` to the schema of `to`
+pub fn translate(object: &mut Value, from: TdVersion, to: TdVersion) {
+  if from == to {
+    return;
+  }
+  let changes: Vec<&Change> = CHANGES.iter()
+    .filter(|(version, _)| *version > from.min(to) && *version <= from.max(to))
+    .map(|(_, change)| change)
+    .collect();
+  walk(object, &|object| if from < to {
+    changes.iter().for_each(|change| change.upgrade(object))
+  } else {
+    changes.iter().rev().for_each(|change| change.downgrade(object))
+  });
+}
+
+/// Translate the json text `json`, text which is not json is returned unchanged
+pub fn translate_json(json: &str, from: TdVersion, to: TdVersion) -> String {
+  if from == to {
+    return json.to_string();
+  }
+  match serde_json::from_str::<Value>(json) {
+    Ok(mut object) => {
+      translate(&mut object, from, to);
+      object.to_string()
+    }
+    Err(_) => json.to_string(),
+  }
+}
+
+/// Call `f` on every object, inner objects first
+fn walk<F: Fn(&mut Map<String, Value>)>(value: &mut Value, f: &F) {
+  match value {
+    Value::Object(object) => {
+      object.values_mut().for_each(|value| walk(value, f));
+      f(object);
+    }
+    Value::Array(values) => values.iter_mut().for_each(|value| walk(value, f)),
+    _ => {}
+  }
+}
+
+/// A `Transport` to a tdlib of another version than `SCHEMA_VERSION`, requests are translated to the version of
+/// tdlib and everything received to `SCHEMA_VERSION`.
+///
+/// `new` learns the version from the `version` option tdlib sends first, `with_version` is for a known version.
+pub struct Compat<T: Transport> {
+  transport: T,
+  version: Mutex<TdVersion>,
+  detect: bool,
+}
+
+impl<T: Transport> Compat<T> {
+  pub fn new(transport: T) -> Self {
+    Compat { transport, version: Mutex::new(SCHEMA_VERSION), detect: true }
+  }
+
+  pub fn with_version(transport: T, version: TdVersion) -> Self {
+    Compat { transport, version: Mutex::new(version), detect: false }
+  }
+
+  /// The version of tdlib, `SCHEMA_VERSION` until it is known
+  pub fn version(&self) -> TdVersion {
+    *self.version.lock().unwrap()
+  }
+
+  fn detect(&self, json: &str) {
+    if !self.detect || !json.contains("\"version\"") {
+      return;
+    }
+    if let Ok(Update::Option(update)) = Update::from_json(json) {
+      if let (true, OptionValue::String(value)) = (update.name() == "version", update.value()) {
+        if let Ok(version) = value.value().parse() { *self.version.lock().unwrap() = version }
+      }
+    }
+  }
+}
+
+impl<T: Transport> Transport for Compat<T> {
+  fn send(&self, request: &str) {
+    self.transport.send(&translate_json(request, SCHEMA_VERSION, self.version()))
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let json = self.transport.receive(timeout)?;
+    self.detect(&json);
+    Some(translate_json(&json, self.version(), SCHEMA_VERSION))
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    let version = self.version();
+    let json = self.transport.execute(&translate_json(request, SCHEMA_VERSION, version))?;
+    Some(translate_json(&json, version, SCHEMA_VERSION))
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::client::{MockTd, SyncClient};
+  use crate::compat::*;
+
+  #[test]
+  fn test_version() {
+    assert_eq!("1.6.0".parse::<TdVersion>().unwrap(), TdVersion(1, 6, 0));
+    assert_eq!("1.8".parse::<TdVersion>().unwrap(), TdVersion(1, 8, 0));
+    assert!("1.x".parse::<TdVersion>().is_err());
+    assert!(TdVersion(1, 6, 9) < SCHEMA_VERSION);
+    assert_eq!(SCHEMA_VERSION.to_string(), "1.7.0");
+  }
+
+  #[test]
+  fn test_compat_transport() {
+    let td = MockTd::new();
+    td.update(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.8.0"}}"#);
+    td.expect(r#"{"@type":"getChatMember","chat_id":1,"member_id":{"@type":"messageSenderUser","user_id":5}}"#)
+      .respond(r#"{"@type":"chatMember","member_id":{"@type":"messageSenderUser","user_id":5},"inviter_user_id":0,"joined_chat_date":0,"status":{"@type":"chatMemberStatusMember"}}"#);
+    let compat = Compat::new(td.clone());
+    assert!(compat.receive(1.0).is_some());
+    assert_eq!(compat.version(), TdVersion(1, 8, 0));
+
+    let (client, _updates) = SyncClient::new(compat);
+    let member = client.execute(&GetChatMember::builder().chat_id(1).user_id(5).build()).unwrap().unwrap();
+    assert_eq!(member.user_id(), 5);
+    td.verify();
+  }
+}
@@ -0,0 +1,301 @@
+//! Route updates to handlers registered per `Update` variant.
+//!
+//! The dispatcher does not receive anything itself, feed it from a client or from recorded json:
+//!
+//! ```
+//! use rtdlib::dispatcher::{Flow, UpdateDispatcher};
+//! use rtdlib::types::*;
+//!
+//! let mut dispatcher = UpdateDispatcher::new();
+//! dispatcher
+//!   .on(|update: &UpdateOption| println!("option {}", update.name()))
+//!   .on_when(|update| update.is_new_message(), |_| Flow::Stop)
+//!   .otherwise(|update| println!("unhandled {}", update.td_name()));
+//!
+//! let json = r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"}}"#;
+//! assert!(dispatcher.dispatch_json(json).unwrap());
+//! ```
+
+use std::future::Future;
+use std::io::BufRead;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Sender};
+use std::task::{Context, Wake, Waker};
+use std::thread;
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Whether the handlers after the current one see the update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+  Continue,
+  Stop,
+}
+
+impl From<()> for Flow {
+  fn from(_: ()) -> Self { Flow::Continue }
+}
+
+/// A future of an async handler
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// `None` if the handler does not take the update
+type Handler = Box<dyn Fn(&Update) -> Option<Flow> + Send + Sync>;
+
+type Otherwise = Box<dyn Fn(&Update) + Send + Sync>;
+
+struct Entry {
+  priority: i32,
+  handler: Handler,
+}
+
+/// Handlers of updates, grouped by `Update` variant or by a predicate.
+///
+/// For every update, the handlers taking it run by priority, higher first, and in registration order within a
+/// priority, until one returns `Flow::Stop`. The `otherwise` handler gets the updates no handler took.
+pub struct UpdateDispatcher {
+  entries: Vec<Entry>,
+  priority: i32,
+  otherwise: Option<Otherwise>,
+  spawner: Arc<dyn Fn(BoxFuture) + Send + Sync>,
+}
+
+impl Default for UpdateDispatcher {
+  fn default() -> Self { UpdateDispatcher::new() }
+}
+
+impl UpdateDispatcher {
+  /// A dispatcher without handlers, async handlers run one at a time on a worker thread until a `spawner` is set
+  pub fn new() -> Self {
+    let worker = Worker::default();
+    UpdateDispatcher { entries: vec![], priority: 0, otherwise: None, spawner: Arc::new(move |future| worker.spawn(future)) }
+  }
+
+  /// Priority of the handlers registered after this call, 0 by default
+  pub fn priority(&mut self, priority: i32) -> &mut Self {
+    self.priority = priority;
+    self
+  }
+
+  /// Run the futures of the async handlers registered after this call with `spawner`, e.g. `tokio::spawn`
+  pub fn spawner<S: Fn(BoxFuture) + Send + Sync + 'static>(&mut self, spawner: S) -> &mut Self {
+    self.spawner = Arc::new(spawner);
+    self
+  }
+
+  /// Handle every update of type `U`, the handler returns `()` or a `Flow`
+  pub fn on<U, F, R>(&mut self, handler: F) -> &mut Self
+    where U: RVariant<Update>, F: Fn(&U) -> R + Send + Sync + 'static, R: Into<Flow> {
+    self.push(Box::new(move |update| U::from_class(update).map(|u| handler(u).into())))
+  }
+
+  /// Handle every update matching `predicate`
+  pub fn on_when<P, F, R>(&mut self, predicate: P, handler: F) -> &mut Self
+    where P: Fn(&Update) -> bool + Send + Sync + 'static, F: Fn(&Update) -> R + Send + Sync + 'static, R: Into<Flow> {
+    self.push(Box::new(move |update| if predicate(update) { Some(handler(update).into()) } else { None }))
+  }
+
+  /// Handle every update of type `U` with an async handler, the dispatcher does not wait for the future
+  pub fn on_async<U, F, Fut>(&mut self, handler: F) -> &mut Self
+    where U: RVariant<Update> + Clone + Send + 'static, F: Fn(U) -> Fut + Send + Sync + 'static, Fut: Future<Output = ()> + Send + 'static {
+    let spawner = self.spawner.clone();
+    self.push(Box::new(move |update| U::from_class(update).map(|u| {
+      spawner(Box::pin(handler(u.clone())));
+      Flow::Continue
+    })))
+  }
+
+  /// Handle the updates no other handler took
+  pub fn otherwise<F: Fn(&Update) + Send + Sync + 'static>(&mut self, handler: F) -> &mut Self {
+    self.otherwise = Some(Box::new(handler));
+    self
+  }
+
+  /// Pass `update` to its handlers, returns whether any handler took it
+  pub fn dispatch(&self, update: &Update) -> bool {
+    let mut handled = false;
+    for entry in &self.entries {
+      match (entry.handler)(update) {
+        Some(Flow::Continue) => handled = true,
+        Some(Flow::Stop) => return true,
+        None => {}
+      }
+    }
+    if !handled {
+      if let Some(otherwise) = &self.otherwise { otherwise(update) }
+    }
+    handled
+  }
+
+  /// Dispatch an update json, an object which is not an update is ignored
+  pub fn dispatch_json<S: AsRef<str>>(&self, json: S) -> RTDResult<bool> {
+    match Update::from_json(json)? {
+      Update::Unknown { td_type, .. } if !td_type.starts_with("update") => Ok(false),
+      update => Ok(self.dispatch(&update)),
+    }
+  }
+
+  /// Dispatch every line of `reader`, e.g. a file of recorded updates, returns the number of handled updates
+  pub fn dispatch_lines<R: BufRead>(&self, reader: R) -> RTDResult<usize> {
+    let mut handled = 0;
+    for line in reader.lines() {
+      let line = line?;
+      if line.trim().is_empty() { continue }
+      if self.dispatch_json(&line)? { handled += 1 }
+    }
+    Ok(handled)
+  }
+
+  fn push(&mut self, handler: Handler) -> &mut Self {
+    // after every handler of the same or a higher priority
+    let priority = self.priority;
+    let index = self.entries.iter().position(|e| e.priority < priority).unwrap_or(self.entries.len());
+    self.entries.insert(index, Entry { priority, handler });
+    self
+  }
+}
+
+/// A future of an async handler, sent back to the worker each time it is woken
+struct Task {
+  future: Mutex<Option<BoxFuture>>,
+  worker: Mutex<Sender<Arc<Task>>>,
+}
+
+impl Wake for Task {
+  fn wake(self: Arc<Self>) {
+    let worker = self.worker.lock().unwrap().clone();
+    let _ = worker.send(self);
+  }
+}
+
+/// One thread polling the futures of the async handlers of a dispatcher, started by the first one
+#[derive(Default)]
+struct Worker {
+  sender: Mutex<Option<Sender<Arc<Task>>>>,
+}
+
+impl Worker {
+  fn spawn(&self, future: BoxFuture) {
+    let mut sender = self.sender.lock().unwrap();
+    let sender = sender.get_or_insert_with(|| {
+      let (sender, receiver) = mpsc::channel::<Arc<Task>>();
+      thread::Builder::new().name("rtdlib-dispatcher".to_string()).spawn(move || {
+        for task in receiver {
+          let mut future = task.future.lock().unwrap();
+          if let Some(mut pending) = future.take() {
+            let waker = Waker::from(task.clone());
+            if pending.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+              *future = Some(pending);
+            }
+          }
+        }
+      }).expect("Can't start the dispatcher thread");
+      sender
+    });
+    let task = Arc::new(Task { future: Mutex::new(Some(future)), worker: Mutex::new(sender.clone()) });
+    let _ = sender.send(task);
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::future::Future;
+  use std::io::Cursor;
+  use std::pin::Pin;
+  use std::sync::{Arc, Mutex};
+  use std::sync::mpsc;
+  use std::task::{Context, Poll};
+  use std::thread;
+  use std::time::Duration;
+
+  use crate::dispatcher::*;
+
+  const UPDATES: &str = r#"
+{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"}}
+{"@type":"updateUserStatus","user_id":7,"status":{"@type":"userStatusOnline","expires":1600000300}}
+{"@type":"updateFromTheFuture"}
+{"@type":"ok"}
+"#;
+
+  #[test]
+  fn test_dispatch() {
+    let calls = Arc::new(Mutex::new(vec![]));
+    let mut dispatcher = UpdateDispatcher::new();
+    let (c1, c2, c3, c4) = (calls.clone(), calls.clone(), calls.clone(), calls.clone());
+    dispatcher
+      .on(move |u: &UpdateOption| c1.lock().unwrap().push(format!("option {}", u.name())))
+      .on(move |_: &UpdateUserStatus| { c2.lock().unwrap().push("status".to_string()); Flow::Stop })
+      .on(move |_: &UpdateUserStatus| c3.lock().unwrap().push("never".to_string()))
+      .priority(10)
+      .on_when(|u| u.is_option(), |_| Flow::Continue)
+      .otherwise(move |u| if let Update::Unknown { td_type, .. } = u { c4.lock().unwrap().push(format!("otherwise {}", td_type)) });
+
+    assert_eq!(dispatcher.dispatch_lines(Cursor::new(UPDATES)).unwrap(), 2);
+    assert_eq!(*calls.lock().unwrap(), vec!["option version", "status", "otherwise updateFromTheFuture"]);
+  }
+
+  #[test]
+  fn test_priority() {
+    let calls = Arc::new(Mutex::new(vec![]));
+    let mut dispatcher = UpdateDispatcher::new();
+    let (c1, c2, c3) = (calls.clone(), calls.clone(), calls.clone());
+    dispatcher
+      .on(move |_: &UpdateOption| c1.lock().unwrap().push(1))
+      .priority(1)
+      .on(move |_: &UpdateOption| c2.lock().unwrap().push(2))
+      .priority(0)
+      .on(move |_: &UpdateOption| c3.lock().unwrap().push(3));
+    let update = r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueEmpty"}}"#;
+    assert!(dispatcher.dispatch_json(update).unwrap());
+    assert_eq!(*calls.lock().unwrap(), vec![2, 1, 3]);
+  }
+
+  #[test]
+  fn test_async() {
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    let mut dispatcher = UpdateDispatcher::new();
+    dispatcher.on_async(move |u: UpdateUserStatus| {
+      let sender = sender.lock().unwrap().clone();
+      async move { sender.send(u.user_id()).unwrap() }
+    });
+    dispatcher.dispatch_lines(Cursor::new(UPDATES)).unwrap();
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)).unwrap(), 7);
+  }
+
+  /// Pending once, woken at once
+  struct YieldOnce(bool);
+
+  impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+      if self.0 { return Poll::Ready(()) }
+      self.0 = true;
+      cx.waker().wake_by_ref();
+      Poll::Pending
+    }
+  }
+
+  #[test]
+  fn test_async_worker() {
+    let (sender, receiver) = mpsc::channel();
+    let sender = Mutex::new(sender);
+    let mut dispatcher = UpdateDispatcher::new();
+    dispatcher.on_async(move |_: UpdateUserStatus| {
+      let sender = sender.lock().unwrap().clone();
+      async move {
+        YieldOnce(false).await;
+        sender.send(thread::current().id()).unwrap()
+      }
+    });
+    for _ in 0..3 {
+      dispatcher.dispatch_lines(Cursor::new(UPDATES)).unwrap();
+    }
+    let threads: Vec<_> = (0..3).map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap()).collect();
+    assert!(threads.iter().all(|id| *id == threads[0] && *id != thread::current().id()));
+  }
+}
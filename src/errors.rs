@@ -1,17 +1,69 @@
 
 use std::{io, fmt, error};
+use std::time::Duration;
+
+use crate::types;
 
 #[derive(Debug)]
 pub enum RTDError {
   Io(io::Error),
   SerdeJson(serde_json::Error),
   Custom(&'static str),
+  /// An `error` object returned by tdlib
+  Td { code: i64, message: String },
+  /// `try_build` of a builder was called before these fields of `td_type` were set
+  MissingFields { td_type: &'static str, fields: Vec<String> },
+}
+
+/// What a tdlib error means to the caller, see [`RTDError::td_kind`](enum.RTDError.html#method.td_kind)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TdErrorKind {
+  /// 429 `Too Many Requests: retry after N`, the request can be repeated after the duration
+  FloodWait(Duration),
+  /// 400, the request is invalid
+  BadRequest,
+  /// 401, the session is not authorized
+  Unauthorized,
+  /// 404, the requested object does not exist
+  NotFound,
+  /// 406, the error is handled by tdlib and must not be displayed to the user
+  NotDisplayed,
+  Other,
 }
 
 pub type RTDResult<T> = Result<T, RTDError>;
 
 impl RTDError {
   pub fn custom(msg: &'static str) -> Self { RTDError::Custom(msg) }
+
+  pub fn td<S: AsRef<str>>(code: i64, message: S) -> Self { RTDError::Td { code, message: message.as_ref().to_string() } }
+
+  /// Classify a tdlib error, `None` if the error did not come from tdlib
+  pub fn td_kind(&self) -> Option<TdErrorKind> {
+    let (code, message) = match self {
+      RTDError::Td { code, message } => (*code, message),
+      _ => return None,
+    };
+    Some(match code {
+      400 => TdErrorKind::BadRequest,
+      401 => TdErrorKind::Unauthorized,
+      404 => TdErrorKind::NotFound,
+      406 => TdErrorKind::NotDisplayed,
+      429 => match message.strip_prefix("Too Many Requests: retry after ").and_then(|s| s.trim().parse().ok()) {
+        Some(seconds) => TdErrorKind::FloodWait(Duration::from_secs(seconds)),
+        None => TdErrorKind::Other,
+      },
+      _ => TdErrorKind::Other,
+    })
+  }
+
+  /// Time to wait before repeating the request, if tdlib asked to
+  pub fn flood_wait(&self) -> Option<Duration> {
+    match self.td_kind() {
+      Some(TdErrorKind::FloodWait(duration)) => Some(duration),
+      _ => None,
+    }
+  }
 }
 
 impl fmt::Display for RTDError {
@@ -20,6 +72,8 @@ impl fmt::Display for RTDError {
       RTDError::Io(ref err) => write!(f, "IO error: {}", err),
       RTDError::SerdeJson(ref err) => write!(f, "Serde json error: {}", err),
       RTDError::Custom(msg) => write!(f, "{}", msg),
+      RTDError::Td { code, ref message } => write!(f, "Td error {}: {}", code, message),
+      RTDError::MissingFields { td_type, ref fields } => write!(f, "Missing fields of {}: {}", td_type, fields.join(", ")),
     }
   }
 }
@@ -30,6 +84,8 @@ impl error::Error for RTDError {
       RTDError::Io(ref err) => err.description(),
       RTDError::SerdeJson(ref err) => err.description(),
       RTDError::Custom(msg) => msg,
+      RTDError::Td { ref message, .. } => message,
+      RTDError::MissingFields { .. } => "Missing fields",
     }
   }
 
@@ -37,7 +93,9 @@ impl error::Error for RTDError {
     match *self {
       RTDError::Io(ref err) => Some(err),
       RTDError::SerdeJson(ref err) => Some(err),
-      RTDError::Custom(_) => None
+      RTDError::Custom(_) => None,
+      RTDError::Td { .. } => None,
+      RTDError::MissingFields { .. } => None,
     }
   }
 }
@@ -54,6 +112,42 @@ impl From<serde_json::Error> for RTDError {
   }
 }
 
+impl From<types::Error> for RTDError {
+  fn from(err: types::Error) -> RTDError {
+    RTDError::from(&err)
+  }
+}
+
+impl From<&types::Error> for RTDError {
+  fn from(err: &types::Error) -> RTDError {
+    RTDError::td(err.code(), err.message())
+  }
+}
+
 //impl From<str> for
 
 
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use crate::errors::*;
+  use crate::types::*;
+
+  #[test]
+  fn test_td_kind() {
+    let error = Error::from_json(r#"{"@type":"error","code":429,"message":"Too Many Requests: retry after 35"}"#).unwrap();
+    let error = RTDError::from(error);
+    assert_eq!(error.td_kind(), Some(TdErrorKind::FloodWait(Duration::from_secs(35))));
+    assert_eq!(error.flood_wait(), Some(Duration::from_secs(35)));
+    assert_eq!(error.to_string(), "Td error 429: Too Many Requests: retry after 35");
+
+    assert_eq!(RTDError::td(429, "Too Many Requests").td_kind(), Some(TdErrorKind::Other));
+    assert_eq!(RTDError::td(400, "PHONE_NUMBER_INVALID").td_kind(), Some(TdErrorKind::BadRequest));
+    assert_eq!(RTDError::td(401, "Unauthorized").td_kind(), Some(TdErrorKind::Unauthorized));
+    assert_eq!(RTDError::td(404, "Not Found").td_kind(), Some(TdErrorKind::NotFound));
+    assert_eq!(RTDError::td(406, "CHANNEL_PRIVATE").td_kind(), Some(TdErrorKind::NotDisplayed));
+    assert_eq!(RTDError::td(500, "Internal").td_kind(), Some(TdErrorKind::Other));
+    assert_eq!(RTDError::custom("Client closed").td_kind(), None);
+  }
+}
@@ -0,0 +1,824 @@
+//! Instant View pages without tdlib: `WebPageInstantView::to_html` renders a standalone HTML document,
+//! `to_markdown` a Markdown version.
+//!
+//! Links to anchors on the page stay on the page, links to other anchors open their url. Media files are linked
+//! through a `MediaResolver`, e.g. `LocalFiles` for the files tdlib has downloaded.
+
+use std::collections::HashSet;
+
+use crate::types::*;
+
+/// Where a media file of a page can be found
+pub trait MediaResolver {
+  /// Url of `file`, `None` leaves the media out of the page
+  fn resolve(&self, file: &File) -> Option<String>;
+}
+
+impl<F: Fn(&File) -> Option<String>> MediaResolver for F {
+  fn resolve(&self, file: &File) -> Option<String> {
+    self(file)
+  }
+}
+
+/// Files which tdlib has downloaded, as `file://` urls
+pub struct LocalFiles;
+
+impl MediaResolver for LocalFiles {
+  fn resolve(&self, file: &File) -> Option<String> {
+    let local = file.local();
+    if local.is_downloading_completed() && !local.path().is_empty() { Some(format!("file://{}", local.path())) } else { None }
+  }
+}
+
+const STYLE: &str = "body{max-width:732px;margin:0 auto;padding:0 16px;font:17px/1.5 serif}\
+img,video,iframe{max-width:100%}figure{margin:1em 0}figcaption{color:#79828b;font-size:15px}\
+table{border-collapse:collapse}table.bordered td,table.bordered th{border:1px solid #ddd;padding:4px}\
+table.striped tr:nth-child(odd){background:#f7f7f7}.kicker{text-transform:uppercase}";
+
+/// Embedded pages run scripts in a unique origin, `allow-same-origin` would give a `srcdoc` the origin of the host page
+const SANDBOX: &str = " sandbox=\"allow-scripts allow-popups\"";
+
+impl WebPageInstantView {
+  /// The page as a standalone HTML document, the title is the text of the `PageBlockTitle`
+  pub fn to_html(&self, resolver: &dyn MediaResolver) -> String {
+    let page = Page::new(self, resolver);
+    let title = self.page_blocks().iter().find_map(|block| block.as_title()).map(|title| title.title().to_plain()).unwrap_or_default();
+    let mut out = String::from(if self.is_rtl() { "<!DOCTYPE html>\n<html dir=\"rtl\">\n" } else { "<!DOCTYPE html>\n<html>\n" });
+    out.push_str("<head>\n<meta charset=\"utf-8\">\n<title>");
+    escape(&mut out, &title);
+    out.push_str("</title>\n<style>");
+    out.push_str(STYLE);
+    out.push_str("</style>\n</head>\n<body>\n<article>\n");
+    for block in self.page_blocks() {
+      page.html(&mut out, block);
+    }
+    out.push_str("</article>\n</body>\n</html>\n");
+    out
+  }
+
+  /// The page in GitHub flavored Markdown, anchors and details are inline HTML
+  pub fn to_markdown(&self, resolver: &dyn MediaResolver) -> String {
+    let page = Page::new(self, resolver);
+    let mut out = page.markdown_blocks(self.page_blocks());
+    out.push('\n');
+    out
+  }
+}
+
+impl RichText {
+  /// The text without formatting
+  pub fn to_plain(&self) -> String {
+    match self {
+      RichText::Plain(t) => t.text().clone(),
+      RichText::RichTexts(t) => t.texts().iter().map(|text| text.to_plain()).collect(),
+      rich => inner(rich).map(|text| text.to_plain()).unwrap_or_default(),
+    }
+  }
+}
+
+/// The formatted text of a rich text
+fn inner(rich: &RichText) -> Option<&RichText> {
+  let text = match rich {
+    RichText::AnchorLink(t) => t.text(),
+    RichText::Bold(t) => t.text(),
+    RichText::EmailAddress(t) => t.text(),
+    RichText::Fixed(t) => t.text(),
+    RichText::Italic(t) => t.text(),
+    RichText::Marked(t) => t.text(),
+    RichText::PhoneNumber(t) => t.text(),
+    RichText::Reference(t) => t.text(),
+    RichText::Strikethrough(t) => t.text(),
+    RichText::Subscript(t) => t.text(),
+    RichText::Superscript(t) => t.text(),
+    RichText::Underline(t) => t.text(),
+    RichText::Url(t) => t.text(),
+    _ => return None,
+  };
+  Some(text.as_ref())
+}
+
+/// The rich texts of a block and the blocks inside it
+fn parts(block: &PageBlock) -> (Vec<&RichText>, Vec<&PageBlock>) {
+  fn caption(caption: &PageBlockCaption) -> Vec<&RichText> {
+    vec![caption.text(), caption.credit()]
+  }
+  match block {
+    PageBlock::Title(b) => (vec![b.title()], vec![]),
+    PageBlock::Subtitle(b) => (vec![b.subtitle()], vec![]),
+    PageBlock::AuthorDate(b) => (vec![b.author()], vec![]),
+    PageBlock::Header(b) => (vec![b.header()], vec![]),
+    PageBlock::Subheader(b) => (vec![b.subheader()], vec![]),
+    PageBlock::Kicker(b) => (vec![b.kicker()], vec![]),
+    PageBlock::Paragraph(b) => (vec![b.text()], vec![]),
+    PageBlock::Preformatted(b) => (vec![b.text()], vec![]),
+    PageBlock::Footer(b) => (vec![b.footer()], vec![]),
+    PageBlock::List(b) => (vec![], b.items().iter().flat_map(|item| item.page_blocks()).collect()),
+    PageBlock::BlockQuote(b) => (vec![b.text(), b.credit()], vec![]),
+    PageBlock::PullQuote(b) => (vec![b.text(), b.credit()], vec![]),
+    PageBlock::Animation(b) => (caption(b.caption()), vec![]),
+    PageBlock::Audio(b) => (caption(b.caption()), vec![]),
+    PageBlock::Photo(b) => (caption(b.caption()), vec![]),
+    PageBlock::Video(b) => (caption(b.caption()), vec![]),
+    PageBlock::VoiceNote(b) => (caption(b.caption()), vec![]),
+    PageBlock::Cover(b) => (vec![], vec![b.cover().as_ref()]),
+    PageBlock::Embedded(b) => (caption(b.caption()), vec![]),
+    PageBlock::EmbeddedPost(b) => (caption(b.caption()), b.page_blocks().iter().collect()),
+    PageBlock::Collage(b) => (caption(b.caption()), b.page_blocks().iter().collect()),
+    PageBlock::Slideshow(b) => (caption(b.caption()), b.page_blocks().iter().collect()),
+    PageBlock::Table(b) => {
+      let cells = b.cells().iter().flatten().filter_map(|cell| cell.text().as_ref());
+      (std::iter::once(b.caption()).chain(cells).collect(), vec![])
+    }
+    PageBlock::Details(b) => (vec![b.header()], b.page_blocks().iter().collect()),
+    PageBlock::RelatedArticles(b) => (vec![b.header()], vec![]),
+    PageBlock::Map(b) => (caption(b.caption()), vec![]),
+    _ => (vec![], vec![]),
+  }
+}
+
+fn collect_anchors(block: &PageBlock, anchors: &mut HashSet<String>) {
+  fn rich_anchors(rich: &RichText, anchors: &mut HashSet<String>) {
+    match rich {
+      RichText::Anchor(t) => { anchors.insert(t.name().clone()); }
+      RichText::RichTexts(t) => t.texts().iter().for_each(|text| rich_anchors(text, anchors)),
+      rich => if let Some(text) = inner(rich) { rich_anchors(text, anchors) },
+    }
+  }
+  if let PageBlock::Anchor(b) = block {
+    anchors.insert(b.name().clone());
+  }
+  let (texts, blocks) = parts(block);
+  texts.into_iter().for_each(|text| rich_anchors(text, anchors));
+  blocks.into_iter().for_each(|block| collect_anchors(block, anchors));
+}
+
+fn escape(out: &mut String, text: &str) {
+  for c in text.chars() {
+    match c {
+      '&' => out.push_str("&amp;"),
+      '<' => out.push_str("&lt;"),
+      '>' => out.push_str("&gt;"),
+      '"' => out.push_str("&quot;"),
+      c => out.push(c),
+    }
+  }
+}
+
+fn escape_markdown(out: &mut String, text: &str) {
+  for c in text.chars() {
+    if "\\`*_[]<>|~#".contains(c) { out.push('\\') }
+    out.push(c);
+  }
+}
+
+/// A url which ends where a Markdown link expects it to
+fn markdown_url(url: &str) -> String {
+  url.replace(' ', "%20").replace('(', "%28").replace(')', "%29")
+}
+
+fn is_empty(rich: &RichText) -> bool {
+  rich.to_plain().trim().is_empty() && !matches!(rich, RichText::Icon(_))
+}
+
+/// `YYYY-MM-DD` of a unix time in UTC
+fn date(timestamp: i64) -> String {
+  // days to the civil calendar, as in http://howardhinnant.github.io/date_algorithms.html
+  let days = timestamp.div_euclid(86400) + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days - era * 146_097;
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month + 2) / 5 + 1;
+  let month = if month < 10 { month + 3 } else { month - 9 };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// The number of an ordered list item, lists with other labels are bulleted
+fn number(item: &PageBlockListItem) -> Option<u32> {
+  item.label().trim().trim_end_matches(['.', ')']).parse().ok()
+}
+
+fn largest(photo: &Option<Photo>) -> Option<&File> {
+  photo.as_ref()?.sizes().iter().max_by_key(|size| size.width() * size.height()).map(|size| size.photo())
+}
+
+fn map_url(location: &Location, zoom: i64) -> String {
+  format!("https://www.openstreetmap.org/?mlat={lat}&mlon={lon}#map={zoom}/{lat}/{lon}", lat = location.latitude(), lon = location.longitude(), zoom = zoom)
+}
+
+/// A table with its spans expanded, cells covered by a span are empty, and the alignment of the columns in the first row
+fn grid(table: &PageBlockTable) -> (Vec<Vec<Option<&PageBlockTableCell>>>, Vec<&PageBlockHorizontalAlignment>) {
+  let mut grid: Vec<Vec<Option<Option<&PageBlockTableCell>>>> = vec![];
+  let mut align = vec![];
+  for (r, row) in table.cells().iter().enumerate() {
+    let mut c = 0;
+    for cell in row {
+      while grid.get(r).and_then(|row| row.get(c)).is_some_and(|taken| taken.is_some()) {
+        c += 1;
+      }
+      let (rows, columns) = (cell.rowspan().max(1) as usize, cell.colspan().max(1) as usize);
+      for i in r..r + rows {
+        for j in c..c + columns {
+          if grid.len() <= i { grid.resize(i + 1, vec![]) }
+          if grid[i].len() <= j { grid[i].resize(j + 1, None) }
+          grid[i][j] = Some(if (i, j) == (r, c) { Some(cell) } else { None });
+        }
+      }
+      if r == 0 {
+        align.extend(std::iter::repeat_n(cell.align(), columns));
+      }
+      c += columns;
+    }
+  }
+  let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+  let grid = grid.into_iter()
+    .map(|row| (0..width).map(|j| row.get(j).cloned().flatten().flatten()).collect())
+    .collect();
+  (grid, align)
+}
+
+struct Page<'a> {
+  resolver: &'a dyn MediaResolver,
+  anchors: HashSet<String>,
+}
+
+impl<'a> Page<'a> {
+  fn new(view: &WebPageInstantView, resolver: &'a dyn MediaResolver) -> Self {
+    let mut anchors = HashSet::new();
+    view.page_blocks().iter().for_each(|block| collect_anchors(block, &mut anchors));
+    Page { resolver, anchors }
+  }
+
+  /// Target of a link to `anchor`, its `url` when the anchor is not on the page
+  fn target(&self, anchor: &str, url: &str) -> String {
+    if self.anchors.contains(anchor) || url.is_empty() { format!("#{}", anchor) } else { url.to_string() }
+  }
+
+  fn link(&self, rich: &RichText) -> Option<String> {
+    match rich {
+      RichText::Url(t) => Some(t.url().clone()),
+      RichText::EmailAddress(t) => Some(format!("mailto:{}", t.email_address())),
+      RichText::PhoneNumber(t) => Some(format!("tel:{}", t.phone_number())),
+      RichText::AnchorLink(t) => Some(self.target(t.anchor_name(), t.url())),
+      RichText::Reference(t) => Some(self.target(t.anchor_name(), t.url())),
+      _ => None,
+    }
+  }
+
+  fn html_rich(&self, out: &mut String, rich: &RichText) {
+    let tag = match rich {
+      RichText::Bold(_) => "b",
+      RichText::Italic(_) => "i",
+      RichText::Underline(_) => "u",
+      RichText::Strikethrough(_) => "s",
+      RichText::Fixed(_) => "code",
+      RichText::Marked(_) => "mark",
+      RichText::Subscript(_) => "sub",
+      RichText::Superscript(_) => "sup",
+      _ => "a",
+    };
+    match rich {
+      RichText::Plain(t) => escape(out, t.text()),
+      RichText::RichTexts(t) => t.texts().iter().for_each(|text| self.html_rich(out, text)),
+      RichText::Anchor(t) => {
+        out.push_str("<a id=\"");
+        escape(out, t.name());
+        out.push_str("\"></a>");
+      }
+      RichText::Icon(t) => if let Some(url) = self.resolver.resolve(t.document().document()) {
+        out.push_str("<img src=\"");
+        escape(out, &url);
+        out.push_str(&format!("\" width=\"{}\" height=\"{}\" alt=\"\">", t.width(), t.height()));
+      },
+      rich => if let Some(text) = inner(rich) {
+        match self.link(rich) {
+          Some(url) => {
+            out.push_str("<a href=\"");
+            escape(out, &url);
+            out.push_str("\">");
+          }
+          None => out.push_str(&format!("<{}>", tag)),
+        }
+        self.html_rich(out, text);
+        out.push_str(&format!("</{}>", tag));
+      },
+    }
+  }
+
+  fn html_text(&self, out: &mut String, tag: &str, attributes: &str, rich: &RichText) {
+    out.push_str(&format!("<{}{}>", tag, attributes));
+    self.html_rich(out, rich);
+    out.push_str(&format!("</{}>\n", tag));
+  }
+
+  fn html_caption(&self, out: &mut String, caption: &PageBlockCaption) {
+    if is_empty(caption.text()) && is_empty(caption.credit()) {
+      return;
+    }
+    out.push_str("<figcaption>");
+    self.html_rich(out, caption.text());
+    if !is_empty(caption.credit()) {
+      self.html_text(out, "cite", "", caption.credit());
+    }
+    out.push_str("</figcaption>\n");
+  }
+
+  /// A media element for `file`, nothing when the resolver does not know the file
+  fn html_media(&self, out: &mut String, tag: &str, attributes: &str, file: Option<&File>) {
+    if let Some(url) = file.and_then(|file| self.resolver.resolve(file)) {
+      out.push_str(&format!("<{} src=\"", tag));
+      escape(out, &url);
+      out.push_str(&format!("\"{}>", attributes));
+      if tag != "img" { out.push_str(&format!("</{}>", tag)) }
+      out.push('\n');
+    }
+  }
+
+  fn html(&self, out: &mut String, block: &PageBlock) {
+    match block {
+      PageBlock::Title(b) => self.html_text(out, "h1", "", b.title()),
+      PageBlock::Subtitle(b) => self.html_text(out, "h2", "", b.subtitle()),
+      PageBlock::Header(b) => self.html_text(out, "h3", "", b.header()),
+      PageBlock::Subheader(b) => self.html_text(out, "h4", "", b.subheader()),
+      PageBlock::Kicker(b) => self.html_text(out, "p", " class=\"kicker\"", b.kicker()),
+      PageBlock::Paragraph(b) => self.html_text(out, "p", "", b.text()),
+      PageBlock::Footer(b) => self.html_text(out, "footer", "", b.footer()),
+      PageBlock::Divider(_) => out.push_str("<hr>\n"),
+      PageBlock::Anchor(b) => {
+        out.push_str("<a id=\"");
+        escape(out, b.name());
+        out.push_str("\"></a>\n");
+      }
+      PageBlock::AuthorDate(b) => {
+        out.push_str("<address>");
+        self.html_rich(out, b.author());
+        if b.publish_date() > 0 {
+          let date = date(b.publish_date());
+          if !is_empty(b.author()) { out.push_str(" • ") }
+          out.push_str(&format!("<time datetime=\"{}\">{}</time>", date, date));
+        }
+        out.push_str("</address>\n");
+      }
+      PageBlock::Preformatted(b) => {
+        out.push_str("<pre>");
+        if b.language().is_empty() {
+          self.html_text(out, "code", "", b.text());
+        } else {
+          out.push_str("<code class=\"language-");
+          escape(out, b.language());
+          out.push_str("\">");
+          self.html_rich(out, b.text());
+          out.push_str("</code>");
+        }
+        out.push_str("</pre>\n");
+      }
+      PageBlock::List(b) => {
+        let ordered = !b.items().is_empty() && b.items().iter().all(|item| number(item).is_some());
+        out.push_str(if ordered { "<ol>\n" } else { "<ul>\n" });
+        for item in b.items() {
+          match number(item) {
+            Some(number) if ordered => out.push_str(&format!("<li value=\"{}\">\n", number)),
+            _ => out.push_str("<li>\n"),
+          }
+          item.page_blocks().iter().for_each(|block| self.html(out, block));
+          out.push_str("</li>\n");
+        }
+        out.push_str(if ordered { "</ol>\n" } else { "</ul>\n" });
+      }
+      PageBlock::BlockQuote(b) => self.html_quote(out, "", b.text(), b.credit()),
+      PageBlock::PullQuote(b) => self.html_quote(out, " class=\"pull-quote\"", b.text(), b.credit()),
+      PageBlock::Photo(b) => {
+        out.push_str("<figure>\n");
+        if !b.url().is_empty() {
+          out.push_str("<a href=\"");
+          escape(out, b.url());
+          out.push_str("\">");
+        }
+        self.html_media(out, "img", " alt=\"\"", largest(b.photo()));
+        if !b.url().is_empty() { out.push_str("</a>\n") }
+        self.html_caption(out, b.caption());
+        out.push_str("</figure>\n");
+      }
+      PageBlock::Animation(b) => {
+        let attributes = if b.need_autoplay() { " autoplay loop muted playsinline" } else { " controls loop" };
+        self.html_figure(out, "video", attributes, b.animation().as_ref().map(|animation| animation.animation()), b.caption());
+      }
+      PageBlock::Video(b) => {
+        let attributes = format!(" controls{}{}", if b.need_autoplay() { " autoplay muted" } else { "" }, if b.is_looped() { " loop" } else { "" });
+        self.html_figure(out, "video", &attributes, b.video().as_ref().map(|video| video.video()), b.caption());
+      }
+      PageBlock::Audio(b) => self.html_figure(out, "audio", " controls", b.audio().as_ref().map(|audio| audio.audio()), b.caption()),
+      PageBlock::VoiceNote(b) => self.html_figure(out, "audio", " controls", b.voice_note().as_ref().map(|voice| voice.voice()), b.caption()),
+      PageBlock::Cover(b) => self.html(out, b.cover()),
+      PageBlock::Embedded(b) => {
+        out.push_str("<figure>\n");
+        let size = format!(" width=\"{}\" height=\"{}\"{}", b.width(), b.height(), if b.allow_scrolling() { "" } else { " scrolling=\"no\"" });
+        if !b.html().is_empty() {
+          out.push_str("<iframe srcdoc=\"");
+          escape(out, b.html());
+          out.push_str(&format!("\"{}{}></iframe>\n", SANDBOX, size));
+        } else if !b.url().is_empty() {
+          out.push_str("<iframe src=\"");
+          escape(out, b.url());
+          out.push_str(&format!("\"{}{}></iframe>\n", SANDBOX, size));
+        }
+        self.html_caption(out, b.caption());
+        out.push_str("</figure>\n");
+      }
+      PageBlock::EmbeddedPost(b) => {
+        out.push_str("<figure>\n<blockquote cite=\"");
+        escape(out, b.url());
+        out.push_str("\">\n<p>");
+        self.html_media(out, "img", " alt=\"\"", largest(b.author_photo()));
+        out.push_str("<a href=\"");
+        escape(out, b.url());
+        out.push_str("\">");
+        escape(out, b.author());
+        out.push_str("</a>");
+        if b.date() > 0 { out.push_str(&format!(" <time>{}</time>", date(b.date()))) }
+        out.push_str("</p>\n");
+        b.page_blocks().iter().for_each(|block| self.html(out, block));
+        out.push_str("</blockquote>\n");
+        self.html_caption(out, b.caption());
+        out.push_str("</figure>\n");
+      }
+      PageBlock::Collage(b) => self.html_gallery(out, "collage", b.page_blocks(), b.caption()),
+      PageBlock::Slideshow(b) => self.html_gallery(out, "slideshow", b.page_blocks(), b.caption()),
+      PageBlock::ChatLink(b) => {
+        out.push_str("<p><a href=\"https://t.me/");
+        escape(out, b.username());
+        out.push_str("\">");
+        self.html_media(out, "img", " alt=\"\"", b.photo().as_ref().map(|photo| photo.small()));
+        escape(out, b.title());
+        out.push_str("</a></p>\n");
+      }
+      PageBlock::Table(b) => self.html_table(out, b),
+      PageBlock::Details(b) => {
+        out.push_str(if b.is_open() { "<details open>\n" } else { "<details>\n" });
+        self.html_text(out, "summary", "", b.header());
+        b.page_blocks().iter().for_each(|block| self.html(out, block));
+        out.push_str("</details>\n");
+      }
+      PageBlock::RelatedArticles(b) => {
+        out.push_str("<section>\n");
+        self.html_text(out, "h3", "", b.header());
+        out.push_str("<ul>\n");
+        for article in b.articles() {
+          out.push_str("<li><a href=\"");
+          escape(out, article.url());
+          out.push_str("\">");
+          escape(out, if article.title().is_empty() { article.url() } else { article.title() });
+          out.push_str("</a>");
+          if !article.description().is_empty() {
+            out.push_str("<br>");
+            escape(out, article.description());
+          }
+          out.push_str("</li>\n");
+        }
+        out.push_str("</ul>\n</section>\n");
+      }
+      PageBlock::Map(b) => {
+        out.push_str("<figure>\n<a href=\"");
+        escape(out, &map_url(b.location(), b.zoom()));
+        out.push_str("\">Map</a>\n");
+        self.html_caption(out, b.caption());
+        out.push_str("</figure>\n");
+      }
+      _ => {}
+    }
+  }
+
+  fn html_quote(&self, out: &mut String, attributes: &str, text: &RichText, credit: &RichText) {
+    out.push_str(&format!("<blockquote{}>\n", attributes));
+    self.html_text(out, "p", "", text);
+    if !is_empty(credit) {
+      self.html_text(out, "cite", "", credit);
+    }
+    out.push_str("</blockquote>\n");
+  }
+
+  fn html_figure(&self, out: &mut String, tag: &str, attributes: &str, file: Option<&File>, caption: &PageBlockCaption) {
+    out.push_str("<figure>\n");
+    self.html_media(out, tag, attributes, file);
+    self.html_caption(out, caption);
+    out.push_str("</figure>\n");
+  }
+
+  fn html_gallery(&self, out: &mut String, class: &str, blocks: &[PageBlock], caption: &PageBlockCaption) {
+    out.push_str(&format!("<figure class=\"{}\">\n", class));
+    blocks.iter().for_each(|block| self.html(out, block));
+    self.html_caption(out, caption);
+    out.push_str("</figure>\n");
+  }
+
+  fn html_table(&self, out: &mut String, table: &PageBlockTable) {
+    let class = match (table.is_bordered(), table.is_striped()) {
+      (true, true) => " class=\"bordered striped\"",
+      (true, false) => " class=\"bordered\"",
+      (false, true) => " class=\"striped\"",
+      (false, false) => "",
+    };
+    out.push_str(&format!("<table{}>\n", class));
+    if !is_empty(table.caption()) {
+      self.html_text(out, "caption", "", table.caption());
+    }
+    for row in table.cells() {
+      out.push_str("<tr>");
+      for cell in row {
+        let tag = if cell.is_header() { "th" } else { "td" };
+        let mut attributes = String::new();
+        if cell.colspan() > 1 { attributes.push_str(&format!(" colspan=\"{}\"", cell.colspan())) }
+        if cell.rowspan() > 1 { attributes.push_str(&format!(" rowspan=\"{}\"", cell.rowspan())) }
+        let align = match cell.align() {
+          PageBlockHorizontalAlignment::Center(_) => "center",
+          PageBlockHorizontalAlignment::Right(_) => "right",
+          _ => "left",
+        };
+        let valign = match cell.valign() {
+          PageBlockVerticalAlignment::Middle(_) => "middle",
+          PageBlockVerticalAlignment::Bottom(_) => "bottom",
+          _ => "top",
+        };
+        attributes.push_str(&format!(" style=\"text-align:{};vertical-align:{}\"", align, valign));
+        out.push_str(&format!("<{}{}>", tag, attributes));
+        if let Some(text) = cell.text() { self.html_rich(out, text) }
+        out.push_str(&format!("</{}>", tag));
+      }
+      out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+  }
+
+  fn markdown_rich(&self, out: &mut String, rich: &RichText) {
+    match rich {
+      RichText::Plain(t) => escape_markdown(out, t.text()),
+      RichText::RichTexts(t) => t.texts().iter().for_each(|text| self.markdown_rich(out, text)),
+      RichText::Anchor(t) => {
+        out.push_str("<a id=\"");
+        escape(out, t.name());
+        out.push_str("\"></a>");
+      }
+      RichText::Icon(t) => if let Some(url) = self.resolver.resolve(t.document().document()) {
+        out.push_str(&format!("![]({})", markdown_url(&url)));
+      },
+      RichText::Fixed(t) => {
+        let code = t.text().to_plain();
+        if code.contains('`') { out.push_str(&format!("`` {} ``", code)) } else { out.push_str(&format!("`{}`", code)) }
+      }
+      rich => if let Some(text) = inner(rich) {
+        let mut formatted = String::new();
+        self.markdown_rich(&mut formatted, text);
+        let marker = match rich {
+          RichText::Bold(_) => "**",
+          RichText::Italic(_) => "_",
+          RichText::Strikethrough(_) => "~~",
+          _ => "",
+        };
+        match self.link(rich) {
+          Some(url) => out.push_str(&format!("[{}]({})", formatted, markdown_url(&url))),
+          None if formatted.trim().is_empty() => out.push_str(&formatted),
+          None => out.push_str(&format!("{}{}{}", marker, formatted, marker)),
+        }
+      },
+    }
+  }
+
+  fn markdown_text(&self, rich: &RichText) -> String {
+    let mut out = String::new();
+    self.markdown_rich(&mut out, rich);
+    out
+  }
+
+  fn markdown_caption(&self, caption: &PageBlockCaption) -> String {
+    match (is_empty(caption.text()), is_empty(caption.credit())) {
+      (true, true) => String::new(),
+      (false, true) => self.markdown_text(caption.text()),
+      (true, false) => self.markdown_text(caption.credit()),
+      _ => format!("{} — {}", self.markdown_text(caption.text()), self.markdown_text(caption.credit())),
+    }
+  }
+
+  /// A link to a media file, nothing when the resolver does not know the file
+  fn markdown_media(&self, name: &str, file: Option<&File>, caption: &PageBlockCaption) -> String {
+    let link = file.and_then(|file| self.resolver.resolve(file)).map(|url| format!("[{}]({})", name, markdown_url(&url)));
+    join(vec![link.unwrap_or_default(), self.markdown_caption(caption)])
+  }
+
+  fn markdown_blocks(&self, blocks: &[PageBlock]) -> String {
+    join(blocks.iter().map(|block| self.markdown(block)).collect())
+  }
+
+  fn markdown(&self, block: &PageBlock) -> String {
+    match block {
+      PageBlock::Title(b) => format!("# {}", self.markdown_text(b.title())),
+      PageBlock::Subtitle(b) => format!("## {}", self.markdown_text(b.subtitle())),
+      PageBlock::Header(b) => format!("### {}", self.markdown_text(b.header())),
+      PageBlock::Subheader(b) => format!("#### {}", self.markdown_text(b.subheader())),
+      PageBlock::Kicker(b) => self.markdown_text(b.kicker()),
+      PageBlock::Paragraph(b) => self.markdown_text(b.text()),
+      PageBlock::Footer(b) => self.markdown_text(b.footer()),
+      PageBlock::Divider(_) => "---".to_string(),
+      PageBlock::Anchor(b) => {
+        let mut out = String::from("<a id=\"");
+        escape(&mut out, b.name());
+        out.push_str("\"></a>");
+        out
+      }
+      PageBlock::AuthorDate(b) => {
+        let date = if b.publish_date() > 0 { date(b.publish_date()) } else { String::new() };
+        let author = self.markdown_text(b.author());
+        if author.is_empty() || date.is_empty() { format!("{}{}", author, date) } else { format!("{} • {}", author, date) }
+      }
+      PageBlock::Preformatted(b) => format!("```{}\n{}\n```", b.language(), b.text().to_plain()),
+      PageBlock::List(b) => {
+        let ordered = !b.items().is_empty() && b.items().iter().all(|item| number(item).is_some());
+        let items: Vec<String> = b.items().iter().map(|item| {
+          let marker = match number(item) {
+            Some(number) if ordered => format!("{}. ", number),
+            _ => "- ".to_string(),
+          };
+          let indent = " ".repeat(marker.len());
+          let body = self.markdown_blocks(item.page_blocks()).replace('\n', &format!("\n{}", indent));
+          format!("{}{}", marker, body).replace(&format!("\n{}\n", indent), "\n\n")
+        }).collect();
+        items.join("\n")
+      }
+      PageBlock::BlockQuote(b) => quote(&join(vec![self.markdown_text(b.text()), self.credit(b.credit())])),
+      PageBlock::PullQuote(b) => quote(&join(vec![self.markdown_text(b.text()), self.credit(b.credit())])),
+      PageBlock::Photo(b) => {
+        let image = largest(b.photo()).and_then(|file| self.resolver.resolve(file))
+          .map(|url| format!("![{}]({})", b.caption().text().to_plain().replace(['[', ']'], ""), markdown_url(&url)))
+          .map(|image| if b.url().is_empty() { image } else { format!("[{}]({})", image, markdown_url(b.url())) });
+        join(vec![image.unwrap_or_default(), self.markdown_caption(b.caption())])
+      }
+      PageBlock::Animation(b) => self.markdown_media("Animation", b.animation().as_ref().map(|animation| animation.animation()), b.caption()),
+      PageBlock::Video(b) => self.markdown_media("Video", b.video().as_ref().map(|video| video.video()), b.caption()),
+      PageBlock::Audio(b) => self.markdown_media("Audio", b.audio().as_ref().map(|audio| audio.audio()), b.caption()),
+      PageBlock::VoiceNote(b) => self.markdown_media("Voice note", b.voice_note().as_ref().map(|voice| voice.voice()), b.caption()),
+      PageBlock::Cover(b) => self.markdown(b.cover()),
+      PageBlock::Embedded(b) => {
+        let link = if b.url().is_empty() { String::new() } else { format!("[{}]({})", b.url(), markdown_url(b.url())) };
+        join(vec![link, self.markdown_caption(b.caption())])
+      }
+      PageBlock::EmbeddedPost(b) => {
+        let mut author = String::new();
+        escape_markdown(&mut author, b.author());
+        let mut header = format!("**[{}]({})**", author, markdown_url(b.url()));
+        if b.date() > 0 { header.push_str(&format!(" • {}", date(b.date()))) }
+        join(vec![quote(&join(vec![header, self.markdown_blocks(b.page_blocks())])), self.markdown_caption(b.caption())])
+      }
+      PageBlock::Collage(b) => join(vec![self.markdown_blocks(b.page_blocks()), self.markdown_caption(b.caption())]),
+      PageBlock::Slideshow(b) => join(vec![self.markdown_blocks(b.page_blocks()), self.markdown_caption(b.caption())]),
+      PageBlock::ChatLink(b) => {
+        let mut title = String::new();
+        escape_markdown(&mut title, b.title());
+        format!("[{}](https://t.me/{})", title, b.username())
+      }
+      PageBlock::Table(b) => self.markdown_table(b),
+      PageBlock::Details(b) => format!("<details{}>\n<summary>{}</summary>\n\n{}\n\n</details>",
+        if b.is_open() { " open" } else { "" }, self.markdown_text(b.header()), self.markdown_blocks(b.page_blocks())),
+      PageBlock::RelatedArticles(b) => {
+        let articles: Vec<String> = b.articles().iter().map(|article| {
+          let mut line = String::new();
+          escape_markdown(&mut line, if article.title().is_empty() { article.url() } else { article.title() });
+          let mut line = format!("- [{}]({})", line, markdown_url(article.url()));
+          if !article.description().is_empty() {
+            line.push_str(" — ");
+            escape_markdown(&mut line, article.description());
+          }
+          line
+        }).collect();
+        let header = if is_empty(b.header()) { String::new() } else { format!("### {}", self.markdown_text(b.header())) };
+        join(vec![header, articles.join("\n")])
+      }
+      PageBlock::Map(b) => join(vec![format!("[Map]({})", map_url(b.location(), b.zoom())), self.markdown_caption(b.caption())]),
+      _ => String::new(),
+    }
+  }
+
+  fn credit(&self, credit: &RichText) -> String {
+    if is_empty(credit) { String::new() } else { format!("— {}", self.markdown_text(credit)) }
+  }
+
+  /// A GitHub flavored table, spanned cells are repeated as empty cells and the first row is the header
+  fn markdown_table(&self, table: &PageBlockTable) -> String {
+    let (grid, align) = grid(table);
+    if grid.is_empty() {
+      return self.markdown_text(table.caption());
+    }
+    let row = |cells: &Vec<Option<&PageBlockTableCell>>| {
+      let cells: Vec<String> = cells.iter()
+        .map(|cell| cell.and_then(|cell| cell.text().as_ref()).map(|text| self.markdown_text(text).replace('\n', "<br>")).unwrap_or_default())
+        .collect();
+      format!("| {} |", cells.join(" | "))
+    };
+    let separator: Vec<&str> = (0..grid[0].len()).map(|j| match align.get(j) {
+      Some(PageBlockHorizontalAlignment::Center(_)) => ":---:",
+      Some(PageBlockHorizontalAlignment::Right(_)) => "---:",
+      _ => "---",
+    }).collect();
+    let mut lines = vec![row(&grid[0]), format!("| {} |", separator.join(" | "))];
+    lines.extend(grid[1..].iter().map(row));
+    join(vec![lines.join("\n"), self.markdown_text(table.caption())])
+  }
+}
+
+/// Markdown blocks separated by blank lines, empty blocks are left out
+fn join(blocks: Vec<String>) -> String {
+  blocks.into_iter().filter(|block| !block.is_empty()).collect::<Vec<_>>().join("\n\n")
+}
+
+fn quote(text: &str) -> String {
+  text.lines().map(|line| if line.is_empty() { ">".to_string() } else { format!("> {}", line) }).collect::<Vec<_>>().join("\n")
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::instant_view::*;
+
+  fn view() -> WebPageInstantView {
+    WebPageInstantView::from_json(r##"{"@type":"webPageInstantView","view_count":0,"version":2,"is_rtl":false,"is_full":true,"page_blocks":[
+      {"@type":"pageBlockTitle","title":{"@type":"richTextPlain","text":"A <title>"}},
+      {"@type":"pageBlockAuthorDate","author":{"@type":"richTextPlain","text":"Ann"},"publish_date":86400},
+      {"@type":"pageBlockParagraph","text":{"@type":"richTexts","texts":[
+        {"@type":"richTextBold","text":{"@type":"richTextPlain","text":"See"}},
+        {"@type":"richTextPlain","text":" "},
+        {"@type":"richTextAnchorLink","text":{"@type":"richTextPlain","text":"below"},"anchor_name":"end","url":"https://t.me/iv#end"},
+        {"@type":"richTextPlain","text":" and "},
+        {"@type":"richTextAnchorLink","text":{"@type":"richTextPlain","text":"elsewhere"},"anchor_name":"gone","url":"https://t.me/iv#gone"}
+      ]}},
+      {"@type":"pageBlockPhoto","url":"","photo":{"@type":"photo","has_stickers":false,"sizes":[
+        {"@type":"photoSize","type":"s","width":90,"height":90,"progressive_sizes":[],"photo":{"@type":"file","id":1,"size":0,"expected_size":0,
+          "local":{"@type":"localFile","path":"","can_be_downloaded":true,"can_be_deleted":false,"is_downloading_active":false,"is_downloading_completed":false,"download_offset":0,"downloaded_prefix_size":0,"downloaded_size":0},
+          "remote":{"@type":"remoteFile","id":"a","unique_id":"a","is_uploading_active":false,"is_uploading_completed":true,"uploaded_size":0}}},
+        {"@type":"photoSize","type":"x","width":800,"height":600,"progressive_sizes":[],"photo":{"@type":"file","id":2,"size":0,"expected_size":0,
+          "local":{"@type":"localFile","path":"/tmp/x.jpg","can_be_downloaded":true,"can_be_deleted":true,"is_downloading_active":false,"is_downloading_completed":true,"download_offset":0,"downloaded_prefix_size":0,"downloaded_size":0},
+          "remote":{"@type":"remoteFile","id":"b","unique_id":"b","is_uploading_active":false,"is_uploading_completed":true,"uploaded_size":0}}}
+      ]},"caption":{"@type":"pageBlockCaption","text":{"@type":"richTextPlain","text":"A photo"},"credit":{"@type":"richTextPlain","text":""}}},
+      {"@type":"pageBlockList","items":[
+        {"@type":"pageBlockListItem","label":"1.","page_blocks":[{"@type":"pageBlockParagraph","text":{"@type":"richTextPlain","text":"one"}}]},
+        {"@type":"pageBlockListItem","label":"2.","page_blocks":[{"@type":"pageBlockParagraph","text":{"@type":"richTextPlain","text":"two"}}]}
+      ]},
+      {"@type":"pageBlockTable","caption":{"@type":"richTextPlain","text":""},"is_bordered":true,"is_striped":false,"cells":[
+        [{"@type":"pageBlockTableCell","text":{"@type":"richTextPlain","text":"Wide"},"is_header":true,"colspan":2,"rowspan":1,
+          "align":{"@type":"pageBlockHorizontalAlignmentCenter"},"valign":{"@type":"pageBlockVerticalAlignmentTop"}},
+         {"@type":"pageBlockTableCell","text":{"@type":"richTextPlain","text":"C"},"is_header":true,"colspan":1,"rowspan":1,
+          "align":{"@type":"pageBlockHorizontalAlignmentRight"},"valign":{"@type":"pageBlockVerticalAlignmentTop"}}],
+        [{"@type":"pageBlockTableCell","text":{"@type":"richTextPlain","text":"Tall"},"is_header":false,"colspan":1,"rowspan":2,
+          "align":{"@type":"pageBlockHorizontalAlignmentLeft"},"valign":{"@type":"pageBlockVerticalAlignmentMiddle"}},
+         {"@type":"pageBlockTableCell","text":{"@type":"richTextPlain","text":"b|1"},"is_header":false,"colspan":1,"rowspan":1,
+          "align":{"@type":"pageBlockHorizontalAlignmentLeft"},"valign":{"@type":"pageBlockVerticalAlignmentTop"}},
+         {"@type":"pageBlockTableCell","is_header":false,"colspan":1,"rowspan":1,
+          "align":{"@type":"pageBlockHorizontalAlignmentLeft"},"valign":{"@type":"pageBlockVerticalAlignmentTop"}}],
+        [{"@type":"pageBlockTableCell","text":{"@type":"richTextPlain","text":"b2"},"is_header":false,"colspan":2,"rowspan":1,
+          "align":{"@type":"pageBlockHorizontalAlignmentLeft"},"valign":{"@type":"pageBlockVerticalAlignmentTop"}}]
+      ]},
+      {"@type":"pageBlockAnchor","name":"end"}
+    ]}"##).unwrap()
+  }
+
+  #[test]
+  fn test_html() {
+    let html = view().to_html(&LocalFiles);
+    assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>A &lt;title&gt;</title>"));
+    assert!(html.contains("<h1>A &lt;title&gt;</h1>\n<address>Ann • <time datetime=\"1970-01-02\">1970-01-02</time></address>\n"));
+    assert!(html.contains(r##"<p><b>See</b> <a href="#end">below</a> and <a href="https://t.me/iv#gone">elsewhere</a></p>"##));
+    assert!(html.contains("<figure>\n<img src=\"file:///tmp/x.jpg\" alt=\"\">\n<figcaption>A photo</figcaption>\n</figure>\n"));
+    assert!(html.contains("<ol>\n<li value=\"1\">\n<p>one</p>\n</li>\n<li value=\"2\">\n<p>two</p>\n</li>\n</ol>\n"));
+    assert!(html.contains(r#"<tr><th colspan="2" style="text-align:center;vertical-align:top">Wide</th>"#));
+    assert!(html.contains(r#"<td rowspan="2" style="text-align:left;vertical-align:middle">Tall</td>"#));
+    assert!(html.contains("<a id=\"end\"></a>\n</article>"));
+
+    // a resolver which does not know the photo leaves it out, the caption stays
+    let html = view().to_html(&|_: &File| None);
+    assert!(html.contains("<figure>\n<figcaption>A photo</figcaption>\n</figure>\n"));
+  }
+
+  #[test]
+  fn test_embedded_sandbox() {
+    let embedded = |url: &str, html: &str| format!(r#"{{"@type":"pageBlockEmbedded","url":"{}","html":"{}","width":640,"height":360,
+      "caption":{{"@type":"pageBlockCaption","text":{{"@type":"richTextPlain","text":""}},"credit":{{"@type":"richTextPlain","text":""}}}},
+      "is_full_width":false,"allow_scrolling":true}}"#, url, html);
+    let view = WebPageInstantView::from_json(format!(r#"{{"@type":"webPageInstantView","view_count":0,"version":2,"is_rtl":false,"is_full":true,
+      "page_blocks":[{},{}]}}"#, embedded("", "<script>parent.document.cookie</script>"), embedded("https://example.com/e", ""))).unwrap();
+    let html = view.to_html(&LocalFiles);
+    assert!(html.contains(r#"<iframe srcdoc="&lt;script&gt;parent.document.cookie&lt;/script&gt;" sandbox="allow-scripts allow-popups" width="640" height="360">"#));
+    assert!(html.contains(r#"<iframe src="https://example.com/e" sandbox="allow-scripts allow-popups" width="640" height="360">"#));
+    assert!(!html.contains("allow-same-origin"));
+  }
+
+  #[test]
+  fn test_markdown() {
+    let markdown = view().to_markdown(&|file: &File| Some(format!("https://cdn/{}", file.id())));
+    assert_eq!(markdown, "# A \\<title\\>\n\n\
+      Ann • 1970-01-02\n\n\
+      **See** [below](#end) and [elsewhere](https://t.me/iv#gone)\n\n\
+      ![A photo](https://cdn/2)\n\nA photo\n\n\
+      1. one\n2. two\n\n\
+      | Wide |  | C |\n| :---: | :---: | ---: |\n| Tall | b\\|1 |  |\n|  | b2 |  |\n\n\
+      <a id=\"end\"></a>\n");
+  }
+}
@@ -6,3 +6,11 @@ pub use rtdlib_sys::Tdlib;
 
 pub mod types;
 pub mod errors;
+pub mod client;
+pub mod borrowed;
+pub mod dispatcher;
+pub mod store;
+pub mod text;
+pub mod instant_view;
+pub mod record;
+pub mod compat;
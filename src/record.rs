@@ -0,0 +1,282 @@
+//! Record the json traffic of a client to a JSONL file, and replay a recording into the dispatcher and stores.
+//!
+//! `Recorder` wraps a `Transport`, every line of the recording is a `RecordEntry`. Secrets are replaced by the
+//! `Redactor` before anything is written, `DefaultRedactor` unless another one is set, so a recording can be
+//! attached to a bug report.
+//!
+//! ```
+//! use rtdlib::client::{MockTd, SyncClient};
+//! use rtdlib::record::{Recorder, Replay};
+//! use rtdlib::types::*;
+//!
+//! let td = MockTd::new();
+//! td.expect(r#"{"@type":"setAuthenticationPhoneNumber"}"#).respond(r#"{"@type":"ok"}"#);
+//! let path = std::env::temp_dir().join("rtdlib-record-doc.jsonl");
+//! let _ = std::fs::remove_file(&path);
+//! let (client, _updates) = SyncClient::new(Recorder::create(td, &path).unwrap());
+//! client.execute(&SetAuthenticationPhoneNumber::builder().phone_number("+10000000000").build()).unwrap().unwrap();
+//! drop(client);
+//!
+//! let replay = Replay::open(&path).unwrap();
+//! assert_eq!(replay.entries()[0].object["phone_number"], "[redacted]");
+//! ```
+
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+use crate::client::Transport;
+use crate::dispatcher::UpdateDispatcher;
+use crate::errors::*;
+use crate::types::*;
+
+/// What replaces a redacted value
+pub const REDACTED: &str = "[redacted]";
+
+/// Whether an object was sent to tdlib or received from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+  Out,
+  In,
+}
+
+/// A line of a recording
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordEntry {
+  /// Unix time in seconds
+  pub time: f64,
+  pub direction: Direction,
+  /// Whether the object is a request to `execute` or its result
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub sync: bool,
+  pub object: Value,
+}
+
+/// Removes secrets from an object before it is recorded
+pub trait Redactor: Send + Sync {
+  fn redact(&self, object: &mut Value);
+}
+
+impl<F: Fn(&mut Value) + Send + Sync> Redactor for F {
+  fn redact(&self, object: &mut Value) {
+    self(object)
+  }
+}
+
+/// Replaces phone numbers, authentication and verification codes, passwords, bot tokens, `api_hash`, database
+/// encryption keys and QR login links with `REDACTED`, at any depth
+pub struct DefaultRedactor;
+
+const SECRETS: &[&str] = &[
+  "phone_number", "code", "recovery_code", "password", "old_password", "new_password", "token", "api_hash",
+  "encryption_key", "new_encryption_key",
+];
+
+impl Redactor for DefaultRedactor {
+  fn redact(&self, object: &mut Value) {
+    match object {
+      Value::Object(map) => {
+        let is_qr_login = map.get("@type").and_then(|t| t.as_str()) == Some("authorizationStateWaitOtherDeviceConfirmation");
+        for (key, value) in map.iter_mut() {
+          // numeric codes are error codes
+          if value.is_string() && (SECRETS.contains(&key.as_str()) || (is_qr_login && key == "link")) {
+            *value = Value::String(REDACTED.to_string());
+          } else {
+            self.redact(value);
+          }
+        }
+      }
+      Value::Array(values) => values.iter_mut().for_each(|value| self.redact(value)),
+      _ => {}
+    }
+  }
+}
+
+/// A `Transport` which writes every object sent to and received from `transport` to `out`, one `RecordEntry` per
+/// line. Write errors are ignored, the recording never breaks the client.
+pub struct Recorder<T: Transport, W: Write + Send + 'static> {
+  transport: T,
+  out: Mutex<W>,
+  redactor: Box<dyn Redactor>,
+}
+
+impl<T: Transport> Recorder<T, BufWriter<fs::File>> {
+  /// Record to the file at `path`, an existing file is appended to
+  pub fn create<P: AsRef<Path>>(transport: T, path: P) -> RTDResult<Self> {
+    let file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    Ok(Recorder::new(transport, BufWriter::new(file)))
+  }
+}
+
+impl<T: Transport, W: Write + Send + 'static> Recorder<T, W> {
+  pub fn new(transport: T, out: W) -> Self {
+    Recorder { transport, out: Mutex::new(out), redactor: Box::new(DefaultRedactor) }
+  }
+
+  /// Redact with `redactor` instead of `DefaultRedactor`
+  pub fn redactor<R: Redactor + 'static>(mut self, redactor: R) -> Self {
+    self.redactor = Box::new(redactor);
+    self
+  }
+
+  fn record(&self, direction: Direction, sync: bool, json: &str) {
+    let mut object = serde_json::from_str(json).unwrap_or_else(|_| Value::String(json.to_string()));
+    self.redactor.redact(&mut object);
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or_default();
+    let entry = RecordEntry { time, direction, sync, object };
+    if let Ok(line) = serde_json::to_string(&entry) {
+      let mut out = self.out.lock().unwrap();
+      let _ = writeln!(out, "{}", line).and_then(|_| out.flush());
+    }
+  }
+}
+
+impl<T: Transport, W: Write + Send + 'static> Transport for Recorder<T, W> {
+  fn send(&self, request: &str) {
+    self.record(Direction::Out, false, request);
+    self.transport.send(request)
+  }
+
+  fn receive(&self, timeout: f64) -> Option<String> {
+    let json = self.transport.receive(timeout)?;
+    self.record(Direction::In, false, &json);
+    Some(json)
+  }
+
+  fn execute(&self, request: &str) -> Option<String> {
+    self.record(Direction::Out, true, request);
+    let json = self.transport.execute(request)?;
+    self.record(Direction::In, true, &json);
+    Some(json)
+  }
+}
+
+/// A recording read back, replayed in recorded order whatever the timestamps
+pub struct Replay {
+  entries: Vec<RecordEntry>,
+}
+
+impl Replay {
+  pub fn open<P: AsRef<Path>>(path: P) -> RTDResult<Self> {
+    Replay::from_reader(BufReader::new(fs::File::open(path)?))
+  }
+
+  pub fn from_reader<R: BufRead>(reader: R) -> RTDResult<Self> {
+    let mut entries = vec![];
+    for line in reader.lines() {
+      let line = line?;
+      if line.trim().is_empty() { continue }
+      entries.push(serde_json::from_str(&line)?);
+    }
+    Ok(Replay { entries })
+  }
+
+  pub fn entries(&self) -> &[RecordEntry] {
+    &self.entries
+  }
+
+  /// The json of every object received from tdlib
+  pub fn incoming(&self) -> impl Iterator<Item = String> + '_ {
+    self.entries.iter().filter(|entry| entry.direction == Direction::In).map(|entry| entry.object.to_string())
+  }
+
+  /// The updates received from tdlib, replies to requests are left out
+  pub fn updates(&self) -> impl Iterator<Item = Update> + '_ {
+    self.entries.iter()
+      .filter(|entry| entry.direction == Direction::In && !entry.sync)
+      .filter(|entry| entry.object.get("@type").and_then(|t| t.as_str()).is_some_and(|t| t.starts_with("update")))
+      .filter_map(|entry| Update::from_json(entry.object.to_string()).ok())
+  }
+
+  /// Pass every update to `dispatcher`, returns the number of handled updates
+  pub fn dispatch(&self, dispatcher: &UpdateDispatcher) -> usize {
+    self.updates().filter(|update| dispatcher.dispatch(update)).count()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::io::{self, Write};
+  use std::sync::{Arc, Mutex};
+
+  use crate::client::{MockTd, SyncClient, Transport};
+  use crate::dispatcher::UpdateDispatcher;
+  use crate::record::*;
+  use crate::store::ChatStore;
+
+  #[derive(Clone, Default)]
+  struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+  impl Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { self.0.lock().unwrap().write(buf) }
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+  }
+
+  fn record(redactor: Option<fn(&mut Value)>) -> Replay {
+    let td = MockTd::new();
+    td.update(r#"{"@type":"updateNewChat","chat":{"@type":"chat","id":1,"type":{"@type":"chatTypePrivate","user_id":1},"title":"Chat","permissions":{"@type":"chatPermissions","can_send_messages":true,"can_send_media_messages":true,"can_send_polls":true,"can_send_other_messages":true,"can_add_web_page_previews":true,"can_change_info":false,"can_invite_users":false,"can_pin_messages":true},"positions":[],"is_marked_as_unread":false,"is_blocked":false,"has_scheduled_messages":false,"can_be_deleted_only_for_self":true,"can_be_deleted_for_all_users":true,"can_be_reported":false,"default_disable_notification":false,"unread_count":0,"last_read_inbox_message_id":0,"last_read_outbox_message_id":0,"unread_mention_count":0,"notification_settings":{"@type":"chatNotificationSettings","use_default_mute_for":true,"mute_for":0,"use_default_sound":true,"sound":"default","use_default_show_preview":true,"show_preview":false,"use_default_disable_pinned_message_notifications":true,"disable_pinned_message_notifications":false,"use_default_disable_mention_notifications":true,"disable_mention_notifications":false},"voice_chat_group_call_id":0,"is_voice_chat_empty":true,"reply_markup_message_id":0,"client_data":""}}"#);
+    td.expect(r#"{"@type":"setTdlibParameters"}"#).respond(r#"{"@type":"ok"}"#);
+    td.expect(r#"{"@type":"checkAuthenticationCode"}"#).respond(r#"{"@type":"error","code":400,"message":"PHONE_CODE_INVALID"}"#);
+    let buffer = Buffer::default();
+    let mut recorder = Recorder::new(td.clone(), buffer.clone());
+    if let Some(redactor) = redactor { recorder = recorder.redactor(redactor) }
+    let (client, updates) = SyncClient::new(recorder);
+
+    updates.recv().unwrap();
+    let parameters = TdlibParameters::builder().api_id(1).api_hash("secret").build();
+    assert!(client.execute(&SetTdlibParameters::builder().parameters(parameters).build()).unwrap().is_ok());
+    assert!(client.execute(&CheckAuthenticationCode::builder().code("12345").build()).unwrap().is_err());
+    drop(client);
+    td.verify();
+    let recording = buffer.0.lock().unwrap().clone();
+    Replay::from_reader(recording.as_slice()).unwrap()
+  }
+
+  #[test]
+  fn test_record_and_redact() {
+    let replay = record(None);
+    let entries = replay.entries();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[0].direction, Direction::In);
+    assert_eq!(entries[1].direction, Direction::Out);
+    assert_eq!(entries[1].object["parameters"]["api_hash"], REDACTED);
+    assert_eq!(entries[3].object["code"], REDACTED);
+    // error codes are kept
+    assert_eq!(entries[4].object["code"], 400);
+    assert_eq!(entries[4].object["@extra"], entries[3].object["@extra"]);
+    assert!(entries.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+    let replay = record(Some(|_: &mut Value| {}));
+    assert_eq!(replay.entries()[1].object["parameters"]["api_hash"], "secret");
+  }
+
+  #[test]
+  fn test_replay() {
+    let replay = record(None);
+    assert_eq!(replay.incoming().count(), 3);
+
+    let mut chats = ChatStore::new();
+    replay.updates().for_each(|update| { chats.apply(&update); });
+    assert_eq!(chats.chat(1).map(|chat| chat.title().as_str()), Some("Chat"));
+
+    let mut dispatcher = UpdateDispatcher::new();
+    dispatcher.on(|_: &UpdateNewChat| {});
+    assert_eq!(replay.dispatch(&dispatcher), 1);
+
+    // a sync execute is recorded on both ways
+    let td = MockTd::new();
+    td.expect(r#"{"@type":"getTextEntities"}"#).respond(r#"{"@type":"textEntities","entities":[]}"#);
+    let buffer = Buffer::default();
+    let recorder = Recorder::new(td, buffer.clone());
+    assert!(recorder.execute(r#"{"@type":"getTextEntities","text":"+10000000000"}"#).is_some());
+    let replay = Replay::from_reader(buffer.0.lock().unwrap().as_slice()).unwrap();
+    assert!(replay.entries().iter().all(|entry| entry.sync));
+    assert_eq!(replay.updates().count(), 0);
+  }
+}
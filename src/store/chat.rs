@@ -0,0 +1,258 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::types::*;
+
+/// A chat list as a map key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ChatListKey {
+  Main,
+  Archive,
+  Filter(i64),
+}
+
+impl ChatListKey {
+  /// Key of `list`, `None` if the list is unknown to this version
+  pub fn of(list: &ChatList) -> Option<Self> {
+    match list {
+      ChatList::Main(_) => Some(ChatListKey::Main),
+      ChatList::Archive(_) => Some(ChatListKey::Archive),
+      ChatList::Filter(filter) => Some(ChatListKey::Filter(filter.chat_filter_id())),
+      _ => None,
+    }
+  }
+}
+
+/// Chats known to the application, kept up to date from updates, and their order in every chat list.
+///
+/// Chats of a list are sorted by the pair (order, chat id) in descending order, as tdlib asks.
+#[derive(Debug, Clone, Default)]
+pub struct ChatStore {
+  chats: HashMap<i64, Chat>,
+  lists: HashMap<ChatListKey, BTreeSet<(i64, i64)>>,
+}
+
+impl ChatStore {
+  pub fn new() -> Self { ChatStore::default() }
+
+  pub fn chat(&self, chat_id: i64) -> Option<&Chat> { self.chats.get(&chat_id) }
+
+  pub fn chats(&self) -> impl Iterator<Item = &Chat> { self.chats.values() }
+
+  pub fn len(&self) -> usize { self.chats.len() }
+
+  pub fn is_empty(&self) -> bool { self.chats.is_empty() }
+
+  /// The first `limit` chats of `list`
+  pub fn top(&self, list: ChatListKey, limit: usize) -> Vec<&Chat> {
+    self.lists.get(&list).into_iter()
+      .flat_map(|chats| chats.iter().rev())
+      .take(limit)
+      .filter_map(|(_, chat_id)| self.chats.get(chat_id))
+      .collect()
+  }
+
+  /// Number of chats in `list`
+  pub fn list_len(&self, list: ChatListKey) -> usize {
+    self.lists.get(&list).map_or(0, |chats| chats.len())
+  }
+
+  /// Position of a chat in `list`
+  pub fn position(&self, chat_id: i64, list: ChatListKey) -> Option<&ChatPosition> {
+    self.chats.get(&chat_id)?.positions().iter().flatten()
+      .find(|position| ChatListKey::of(position.list()) == Some(list))
+  }
+
+  /// Apply `update` to its chat, returns whether the store changed. Updates of chats which were not announced by
+  /// `updateNewChat` are ignored.
+  pub fn apply(&mut self, update: &Update) -> bool {
+    if let Update::NewChat(update) = update {
+      let mut chat = update.chat().clone();
+      let positions = chat.positions_mut().take().unwrap_or_default();
+      let chat_id = chat.id();
+      self.chats.insert(chat_id, chat);
+      self.set_positions(chat_id, positions);
+      return true;
+    }
+    let chat_id = match chat_id(update) {
+      Some(chat_id) if self.chats.contains_key(&chat_id) => chat_id,
+      _ => return false,
+    };
+    match update {
+      Update::ChatPosition(update) => self.set_position(chat_id, update.position().clone()),
+      Update::ChatLastMessage(update) => {
+        *self.chat_mut(chat_id).last_message_mut() = update.last_message().clone();
+        if let Some(positions) = update.positions() {
+          self.set_positions(chat_id, positions.clone());
+        }
+      }
+      Update::ChatDraftMessage(update) => {
+        *self.chat_mut(chat_id).draft_message_mut() = update.draft_message().clone();
+        self.set_positions(chat_id, update.positions().clone());
+      }
+      _ => apply_to_chat(self.chat_mut(chat_id), update),
+    }
+    true
+  }
+
+  fn chat_mut(&mut self, chat_id: i64) -> &mut Chat {
+    self.chats.get_mut(&chat_id).expect("Chat not found")
+  }
+
+  /// Replace every position of a chat
+  fn set_positions(&mut self, chat_id: i64, positions: Vec<ChatPosition>) {
+    let old = self.chat_mut(chat_id).positions_mut().take().unwrap_or_default();
+    for position in &old {
+      if let Some(chats) = ChatListKey::of(position.list()).and_then(|list| self.lists.get_mut(&list)) {
+        chats.remove(&(position.order(), chat_id));
+      }
+    }
+    self.chat_mut(chat_id).positions_mut().replace(vec![]);
+    positions.into_iter().for_each(|position| self.set_position(chat_id, position));
+  }
+
+  /// Replace the position of a chat in the list of `position`, order 0 removes the chat from the list
+  fn set_position(&mut self, chat_id: i64, position: ChatPosition) {
+    let list = match ChatListKey::of(position.list()) {
+      Some(list) => list,
+      None => return,
+    };
+    let positions = self.chats.get_mut(&chat_id).expect("Chat not found").positions_mut().get_or_insert_with(Vec::new);
+    let chats = self.lists.entry(list).or_default();
+    positions.retain(|old| {
+      let same = ChatListKey::of(old.list()) == Some(list);
+      if same { chats.remove(&(old.order(), chat_id)); }
+      !same
+    });
+    if position.order() != 0 {
+      chats.insert((position.order(), chat_id));
+      positions.push(position);
+    }
+  }
+}
+
+/// Chat of the updates which change a single chat
+fn chat_id(update: &Update) -> Option<i64> {
+  Some(match update {
+    Update::ChatActionBar(u) => u.chat_id(),
+    Update::ChatDefaultDisableNotification(u) => u.chat_id(),
+    Update::ChatDraftMessage(u) => u.chat_id(),
+    Update::ChatHasScheduledMessages(u) => u.chat_id(),
+    Update::ChatIsBlocked(u) => u.chat_id(),
+    Update::ChatIsMarkedAsUnread(u) => u.chat_id(),
+    Update::ChatLastMessage(u) => u.chat_id(),
+    Update::ChatNotificationSettings(u) => u.chat_id(),
+    Update::ChatPermissions(u) => u.chat_id(),
+    Update::ChatPhoto(u) => u.chat_id(),
+    Update::ChatPosition(u) => u.chat_id(),
+    Update::ChatReadInbox(u) => u.chat_id(),
+    Update::ChatReadOutbox(u) => u.chat_id(),
+    Update::ChatReplyMarkup(u) => u.chat_id(),
+    Update::ChatTitle(u) => u.chat_id(),
+    Update::ChatUnreadMentionCount(u) => u.chat_id(),
+    Update::ChatVoiceChat(u) => u.chat_id(),
+    Update::MessageMentionRead(u) => u.chat_id(),
+    _ => return None,
+  })
+}
+
+/// Apply an update which changes only the fields of `chat`
+fn apply_to_chat(chat: &mut Chat, update: &Update) {
+  match update {
+    Update::ChatActionBar(u) => *chat.action_bar_mut() = u.action_bar().clone(),
+    Update::ChatDefaultDisableNotification(u) => *chat.default_disable_notification_mut() = u.default_disable_notification(),
+    Update::ChatHasScheduledMessages(u) => *chat.has_scheduled_messages_mut() = u.has_scheduled_messages(),
+    Update::ChatIsBlocked(u) => *chat.is_blocked_mut() = u.is_blocked(),
+    Update::ChatIsMarkedAsUnread(u) => *chat.is_marked_as_unread_mut() = u.is_marked_as_unread(),
+    Update::ChatNotificationSettings(u) => *chat.notification_settings_mut() = u.notification_settings().clone(),
+    Update::ChatPermissions(u) => *chat.permissions_mut() = u.permissions().clone(),
+    Update::ChatPhoto(u) => *chat.photo_mut() = u.photo().clone(),
+    Update::ChatReadInbox(u) => {
+      *chat.last_read_inbox_message_id_mut() = u.last_read_inbox_message_id();
+      *chat.unread_count_mut() = u.unread_count();
+    }
+    Update::ChatReadOutbox(u) => *chat.last_read_outbox_message_id_mut() = u.last_read_outbox_message_id(),
+    Update::ChatReplyMarkup(u) => *chat.reply_markup_message_id_mut() = u.reply_markup_message_id(),
+    Update::ChatTitle(u) => *chat.title_mut() = u.title().clone(),
+    Update::ChatUnreadMentionCount(u) => *chat.unread_mention_count_mut() = u.unread_mention_count(),
+    Update::ChatVoiceChat(u) => {
+      *chat.voice_chat_group_call_id_mut() = u.voice_chat_group_call_id();
+      *chat.is_voice_chat_empty_mut() = u.is_voice_chat_empty();
+    }
+    Update::MessageMentionRead(u) => *chat.unread_mention_count_mut() = u.unread_mention_count(),
+    _ => {}
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::store::*;
+  use crate::types::*;
+
+  fn new_chat(id: i64, title: &str, positions: &str) -> String {
+    format!(r#"{{"@type":"updateNewChat","chat":{{"@type":"chat","id":{},"type":{{"@type":"chatTypePrivate","user_id":{}}},"title":"{}","permissions":{{"@type":"chatPermissions","can_send_messages":true,"can_send_media_messages":true,"can_send_polls":true,"can_send_other_messages":true,"can_add_web_page_previews":true,"can_change_info":false,"can_invite_users":false,"can_pin_messages":true}},"positions":[{}],"is_marked_as_unread":false,"is_blocked":false,"has_scheduled_messages":false,"can_be_deleted_only_for_self":true,"can_be_deleted_for_all_users":true,"can_be_reported":false,"default_disable_notification":false,"unread_count":0,"last_read_inbox_message_id":0,"last_read_outbox_message_id":0,"unread_mention_count":0,"notification_settings":{{"@type":"chatNotificationSettings","use_default_mute_for":true,"mute_for":0,"use_default_sound":true,"sound":"default","use_default_show_preview":true,"show_preview":false,"use_default_disable_pinned_message_notifications":true,"disable_pinned_message_notifications":false,"use_default_disable_mention_notifications":true,"disable_mention_notifications":false}},"voice_chat_group_call_id":0,"is_voice_chat_empty":true,"reply_markup_message_id":0,"client_data":""}}}}"#, id, id, title, positions)
+  }
+
+  fn position(list: &str, order: i64) -> String {
+    format!(r#"{{"@type":"chatPosition","list":{},"order":"{}","is_pinned":false}}"#, list, order)
+  }
+
+  const MAIN: &str = r#"{"@type":"chatListMain"}"#;
+  const ARCHIVE: &str = r#"{"@type":"chatListArchive"}"#;
+
+  fn apply(store: &mut ChatStore, json: &str) -> bool {
+    store.apply(&Update::from_json(json).unwrap())
+  }
+
+  fn titles(store: &ChatStore, list: ChatListKey) -> Vec<&str> {
+    store.top(list, 10).iter().map(|chat| &chat.title()[..]).collect()
+  }
+
+  #[test]
+  fn test_chat_lists() {
+    let mut store = ChatStore::new();
+    apply(&mut store, &new_chat(1, "one", &position(MAIN, 100)));
+    apply(&mut store, &new_chat(2, "two", &position(MAIN, 300)));
+    apply(&mut store, &new_chat(3, "three", &format!("{},{}", position(MAIN, 100), position(ARCHIVE, 5))));
+    apply(&mut store, &new_chat(4, "four", ""));
+    assert_eq!(store.len(), 4);
+    assert_eq!(titles(&store, ChatListKey::Main), vec!["two", "three", "one"]);
+    assert_eq!(titles(&store, ChatListKey::Archive), vec!["three"]);
+    assert_eq!(store.top(ChatListKey::Main, 1).len(), 1);
+
+    apply(&mut store, &format!(r#"{{"@type":"updateChatPosition","chat_id":1,"position":{}}}"#, position(MAIN, 400)));
+    apply(&mut store, &format!(r#"{{"@type":"updateChatPosition","chat_id":3,"position":{}}}"#, position(ARCHIVE, 0)));
+    assert_eq!(titles(&store, ChatListKey::Main), vec!["one", "two", "three"]);
+    assert_eq!(store.list_len(ChatListKey::Archive), 0);
+    assert_eq!(store.position(1, ChatListKey::Main).unwrap().order(), 400);
+    assert_eq!(store.chat(1).unwrap().positions().as_ref().unwrap().len(), 1);
+
+    let filter = r#"{"@type":"chatListFilter","chat_filter_id":7}"#;
+    apply(&mut store, &format!(r#"{{"@type":"updateChatLastMessage","chat_id":4,"positions":[{},{}]}}"#, position(MAIN, 200), position(filter, 1)));
+    assert_eq!(titles(&store, ChatListKey::Main), vec!["one", "two", "four", "three"]);
+    assert_eq!(titles(&store, ChatListKey::Filter(7)), vec!["four"]);
+    apply(&mut store, r#"{"@type":"updateChatDraftMessage","chat_id":4,"positions":[]}"#);
+    assert_eq!(titles(&store, ChatListKey::Main), vec!["one", "two", "three"]);
+    assert_eq!(store.list_len(ChatListKey::Filter(7)), 0);
+  }
+
+  #[test]
+  fn test_chat_fields() {
+    let mut store = ChatStore::new();
+    assert!(!apply(&mut store, r#"{"@type":"updateChatTitle","chat_id":1,"title":"lost"}"#));
+    apply(&mut store, &new_chat(1, "one", ""));
+    assert!(apply(&mut store, r#"{"@type":"updateChatTitle","chat_id":1,"title":"first"}"#));
+    apply(&mut store, r#"{"@type":"updateChatReadInbox","chat_id":1,"last_read_inbox_message_id":10,"unread_count":3}"#);
+    apply(&mut store, r#"{"@type":"updateChatIsMarkedAsUnread","chat_id":1,"is_marked_as_unread":true}"#);
+    apply(&mut store, r#"{"@type":"updateMessageMentionRead","chat_id":1,"message_id":9,"unread_mention_count":2}"#);
+    apply(&mut store, r#"{"@type":"updateChatPhoto","chat_id":1}"#);
+    let chat = store.chat(1).unwrap();
+    assert_eq!(chat.title(), "first");
+    assert_eq!(chat.last_read_inbox_message_id(), 10);
+    assert_eq!(chat.unread_count(), 3);
+    assert_eq!(chat.unread_mention_count(), 2);
+    assert!(chat.is_marked_as_unread());
+    assert!(chat.photo().is_none());
+    assert!(!apply(&mut store, r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueEmpty"}}"#));
+  }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::types::*;
+
+/// An entity of an [`EntityStore`](struct.EntityStore.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EntityId {
+  User(i64),
+  BasicGroup(i64),
+  Supergroup(i64),
+}
+
+/// What the current user can do in a chat
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PermissionSummary {
+  pub is_member: bool,
+  /// The owner or an administrator of the chat
+  pub is_administrator: bool,
+  pub can_send_messages: bool,
+  pub can_send_media_messages: bool,
+  pub can_change_info: bool,
+  pub can_invite_users: bool,
+  pub can_pin_messages: bool,
+  pub can_delete_messages: bool,
+  pub can_restrict_members: bool,
+}
+
+impl PermissionSummary {
+  /// Permissions of a member with `status` in a group whose members have `permissions`
+  pub fn of(status: &ChatMemberStatus, permissions: &ChatPermissions, is_channel: bool) -> Self {
+    match status {
+      ChatMemberStatus::Creator(creator) => PermissionSummary {
+        is_member: creator.is_member(),
+        is_administrator: true,
+        can_send_messages: true,
+        can_send_media_messages: true,
+        can_change_info: true,
+        can_invite_users: true,
+        can_pin_messages: true,
+        can_delete_messages: true,
+        can_restrict_members: true,
+      },
+      ChatMemberStatus::Administrator(admin) => PermissionSummary {
+        is_member: true,
+        is_administrator: true,
+        can_send_messages: !is_channel || admin.can_post_messages(),
+        can_send_media_messages: !is_channel || admin.can_post_messages(),
+        can_change_info: admin.can_change_info(),
+        can_invite_users: admin.can_invite_users(),
+        can_pin_messages: admin.can_pin_messages(),
+        can_delete_messages: admin.can_delete_messages(),
+        can_restrict_members: admin.can_restrict_members(),
+      },
+      ChatMemberStatus::Member(_) if is_channel => PermissionSummary { is_member: true, ..Default::default() },
+      ChatMemberStatus::Member(_) => PermissionSummary::member(true, permissions),
+      ChatMemberStatus::Restricted(restricted) => PermissionSummary::member(restricted.is_member(), restricted.permissions()),
+      _ => PermissionSummary::default(),
+    }
+  }
+
+  fn member(is_member: bool, permissions: &ChatPermissions) -> Self {
+    PermissionSummary {
+      is_member,
+      can_send_messages: is_member && permissions.can_send_messages(),
+      can_send_media_messages: is_member && permissions.can_send_media_messages(),
+      can_change_info: is_member && permissions.can_change_info(),
+      can_invite_users: is_member && permissions.can_invite_users(),
+      can_pin_messages: is_member && permissions.can_pin_messages(),
+      ..Default::default()
+    }
+  }
+}
+
+/// A user and its full info, if tdlib sent it
+#[derive(Debug, Clone, Copy)]
+pub struct UserView<'a> {
+  user: &'a User,
+  full_info: Option<&'a UserFullInfo>,
+}
+
+impl<'a> UserView<'a> {
+  pub fn user(&self) -> &'a User { self.user }
+
+  pub fn full_info(&self) -> Option<&'a UserFullInfo> { self.full_info }
+
+  /// First and last name, `Deleted Account` for deleted users
+  pub fn display_name(&self) -> String {
+    if self.user.type_().is_deleted() {
+      return "Deleted Account".to_string();
+    }
+    format!("{} {}", self.user.first_name(), self.user.last_name()).trim().to_string()
+  }
+
+  pub fn is_online(&self) -> bool { self.user.status().is_online() }
+
+  pub fn bio(&self) -> Option<&'a str> { self.full_info.map(|info| &info.bio()[..]) }
+
+  pub fn is_blocked(&self) -> Option<bool> { self.full_info.map(|info| info.is_blocked()) }
+}
+
+/// Users, basic groups and supergroups, with their full info, kept up to date from updates.
+///
+/// `subscribe` returns a channel receiving the id of an entity each time an update changes it.
+#[derive(Debug, Clone, Default)]
+pub struct EntityStore {
+  users: HashMap<i64, User>,
+  user_full_infos: HashMap<i64, UserFullInfo>,
+  basic_groups: HashMap<i64, BasicGroup>,
+  basic_group_full_infos: HashMap<i64, BasicGroupFullInfo>,
+  supergroups: HashMap<i64, Supergroup>,
+  supergroup_full_infos: HashMap<i64, SupergroupFullInfo>,
+  subscribers: HashMap<Option<EntityId>, Vec<Sender<EntityId>>>,
+}
+
+impl EntityStore {
+  pub fn new() -> Self { EntityStore::default() }
+
+  pub fn user(&self, user_id: i64) -> Option<UserView<'_>> {
+    self.users.get(&user_id).map(|user| UserView { user, full_info: self.user_full_infos.get(&user_id) })
+  }
+
+  pub fn basic_group(&self, basic_group_id: i64) -> Option<&BasicGroup> { self.basic_groups.get(&basic_group_id) }
+
+  pub fn basic_group_full_info(&self, basic_group_id: i64) -> Option<&BasicGroupFullInfo> { self.basic_group_full_infos.get(&basic_group_id) }
+
+  pub fn supergroup(&self, supergroup_id: i64) -> Option<&Supergroup> { self.supergroups.get(&supergroup_id) }
+
+  pub fn supergroup_full_info(&self, supergroup_id: i64) -> Option<&SupergroupFullInfo> { self.supergroup_full_infos.get(&supergroup_id) }
+
+  /// Name of `chat`: the name of the user of a private or secret chat, the title of a group
+  pub fn display_name(&self, chat: &Chat) -> String {
+    match user_id(chat.type_()).and_then(|user_id| self.user(user_id)) {
+      Some(user) => user.display_name(),
+      None => chat.title().clone(),
+    }
+  }
+
+  /// What the current user can do in `chat`, nothing if its user or group is unknown
+  pub fn permissions(&self, chat: &Chat) -> PermissionSummary {
+    if let Some(user_id) = user_id(chat.type_()) {
+      let can_send = self.users.get(&user_id).is_some_and(|user| user.have_access() && !user.type_().is_deleted());
+      return PermissionSummary { is_member: true, can_send_messages: can_send, can_send_media_messages: can_send, ..Default::default() };
+    }
+    match chat.type_() {
+      ChatType::BasicGroup(group) => self.basic_groups.get(&group.basic_group_id())
+        .map(|group| PermissionSummary::of(group.status(), chat.permissions(), false))
+        .unwrap_or_default(),
+      ChatType::Supergroup(group) => self.supergroups.get(&group.supergroup_id())
+        .map(|group| PermissionSummary::of(group.status(), chat.permissions(), group.is_channel()))
+        .unwrap_or_default(),
+      _ => PermissionSummary::default(),
+    }
+  }
+
+  /// Receive the id of `entity` each time it changes
+  pub fn subscribe(&mut self, entity: EntityId) -> Receiver<EntityId> {
+    self.add_subscriber(Some(entity))
+  }
+
+  /// Receive the id of every entity which changes
+  pub fn subscribe_all(&mut self) -> Receiver<EntityId> {
+    self.add_subscriber(None)
+  }
+
+  /// Apply `update`, returns the entity it changed
+  pub fn apply(&mut self, update: &Update) -> Option<EntityId> {
+    let changed = match update {
+      Update::User(u) => {
+        self.users.insert(u.user().id(), u.user().clone());
+        EntityId::User(u.user().id())
+      }
+      Update::UserStatus(u) => {
+        *self.users.get_mut(&u.user_id())?.status_mut() = u.status().clone();
+        EntityId::User(u.user_id())
+      }
+      Update::UserFullInfo(u) => {
+        self.user_full_infos.insert(u.user_id(), u.user_full_info().clone());
+        EntityId::User(u.user_id())
+      }
+      Update::BasicGroup(u) => {
+        self.basic_groups.insert(u.basic_group().id(), u.basic_group().clone());
+        EntityId::BasicGroup(u.basic_group().id())
+      }
+      Update::BasicGroupFullInfo(u) => {
+        self.basic_group_full_infos.insert(u.basic_group_id(), u.basic_group_full_info().clone());
+        EntityId::BasicGroup(u.basic_group_id())
+      }
+      Update::Supergroup(u) => {
+        self.supergroups.insert(u.supergroup().id(), u.supergroup().clone());
+        EntityId::Supergroup(u.supergroup().id())
+      }
+      Update::SupergroupFullInfo(u) => {
+        self.supergroup_full_infos.insert(u.supergroup_id(), u.supergroup_full_info().clone());
+        EntityId::Supergroup(u.supergroup_id())
+      }
+      _ => return None,
+    };
+    self.notify(changed);
+    Some(changed)
+  }
+
+  fn add_subscriber(&mut self, entity: Option<EntityId>) -> Receiver<EntityId> {
+    let (sender, receiver) = mpsc::channel();
+    self.subscribers.entry(entity).or_default().push(sender);
+    receiver
+  }
+
+  /// Send `entity` to its subscribers, dropping the ones whose receiver is gone
+  fn notify(&mut self, entity: EntityId) {
+    for key in &[Some(entity), None] {
+      if let Some(senders) = self.subscribers.get_mut(key) {
+        senders.retain(|sender| sender.send(entity).is_ok());
+      }
+    }
+  }
+}
+
+/// The other user of a private or secret chat
+fn user_id(chat_type: &ChatType) -> Option<i64> {
+  match chat_type {
+    ChatType::Private(private) => Some(private.user_id()),
+    ChatType::Secret(secret) => Some(secret.user_id()),
+    _ => None,
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::store::*;
+  use crate::types::*;
+
+  const USER: &str = r#"{"@type":"updateUser","user":{"@type":"user","id":7,"first_name":"Ada","last_name":"","username":"ada","phone_number":"","status":{"@type":"userStatusOffline","was_online":1600000000},"is_contact":false,"is_mutual_contact":false,"is_verified":false,"is_support":false,"restriction_reason":"","is_scam":false,"is_fake":false,"have_access":true,"type":{"@type":"userTypeRegular"},"language_code":"en"}}"#;
+  const FULL_INFO: &str = r#"{"@type":"updateUserFullInfo","user_id":7,"user_full_info":{"@type":"userFullInfo","is_blocked":false,"can_be_called":true,"supports_video_calls":true,"has_private_calls":false,"need_phone_number_privacy_exception":false,"bio":"math","share_text":"","group_in_common_count":1}}"#;
+  const SUPERGROUP: &str = r#"{"@type":"updateSupergroup","supergroup":{"@type":"supergroup","id":9,"username":"","date":1600000000,"status":{"@type":"chatMemberStatusAdministrator","custom_title":"","can_be_edited":false,"can_change_info":true,"can_post_messages":false,"can_edit_messages":false,"can_delete_messages":true,"can_invite_users":true,"can_restrict_members":false,"can_pin_messages":false,"can_promote_members":false,"can_manage_voice_chats":false,"is_anonymous":false},"member_count":10,"has_linked_chat":false,"has_location":false,"sign_messages":false,"is_slow_mode_enabled":false,"is_channel":true,"is_verified":false,"restriction_reason":"","is_scam":false,"is_fake":false}}"#;
+
+  fn apply(store: &mut EntityStore, json: &str) -> Option<EntityId> {
+    store.apply(&Update::from_json(json).unwrap())
+  }
+
+  fn chat(type_: ChatType, title: &str) -> Chat {
+    let permissions = ChatPermissions::builder().can_send_messages(true).build();
+    Chat::builder().id(1).type_(type_).title(title).permissions(permissions).build()
+  }
+
+  #[test]
+  fn test_user_view() {
+    let mut store = EntityStore::new();
+    let user = store.subscribe(EntityId::User(7));
+    let all = store.subscribe_all();
+    assert_eq!(apply(&mut store, r#"{"@type":"updateUserStatus","user_id":7,"status":{"@type":"userStatusOnline","expires":1}}"#), None);
+    assert_eq!(apply(&mut store, USER), Some(EntityId::User(7)));
+    assert!(!store.user(7).unwrap().is_online());
+    assert_eq!(store.user(7).unwrap().bio(), None);
+
+    apply(&mut store, FULL_INFO);
+    apply(&mut store, r#"{"@type":"updateUserStatus","user_id":7,"status":{"@type":"userStatusOnline","expires":1}}"#);
+    let view = store.user(7).unwrap();
+    assert!(view.is_online());
+    assert_eq!(view.bio(), Some("math"));
+    assert_eq!(view.display_name(), "Ada");
+    assert_eq!(user.try_iter().count(), 3);
+
+    apply(&mut store, SUPERGROUP);
+    assert_eq!(all.try_iter().collect::<Vec<_>>(), vec![EntityId::User(7), EntityId::User(7), EntityId::User(7), EntityId::Supergroup(9)]);
+    assert_eq!(user.try_iter().count(), 0);
+  }
+
+  #[test]
+  fn test_chat_summary() {
+    let mut store = EntityStore::new();
+    apply(&mut store, USER);
+    apply(&mut store, SUPERGROUP);
+
+    let private = chat(ChatType::private(ChatTypePrivate::builder().user_id(7)), "");
+    assert_eq!(store.display_name(&private), "Ada");
+    assert!(store.permissions(&private).can_send_messages);
+
+    let channel = chat(ChatType::supergroup(ChatTypeSupergroup::builder().supergroup_id(9).is_channel(true)), "News");
+    assert_eq!(store.display_name(&channel), "News");
+    let permissions = store.permissions(&channel);
+    assert!(permissions.is_administrator);
+    assert!(!permissions.can_send_messages);
+    assert!(permissions.can_delete_messages);
+
+    let group = chat(ChatType::basic_group(ChatTypeBasicGroup::builder().basic_group_id(3)), "Group");
+    assert_eq!(store.permissions(&group), PermissionSummary::default());
+
+    let status = ChatMemberStatus::member(ChatMemberStatusMember::builder());
+    let member = PermissionSummary::of(&status, channel.permissions(), false);
+    assert!(member.can_send_messages);
+    assert!(!member.can_pin_messages);
+  }
+}
@@ -0,0 +1,9 @@
+//! Caches of the objects tdlib expects the application to keep up to date from updates.
+//!
+//! Stores are plain data, feed them every update with `apply`, they never send anything to tdlib.
+
+pub use self::chat::*;
+pub use self::entity::*;
+
+mod chat;
+mod entity;
@@ -0,0 +1,184 @@
+use crate::text::{link, Output, ParseError};
+use crate::types::*;
+
+const TAGS: &[&str] = &["a", "b", "strong", "i", "em", "u", "ins", "s", "strike", "del", "code", "pre"];
+
+struct Open {
+  tag: String,
+  /// Byte offset of the tag in the markup
+  position: usize,
+  offset: i64,
+  /// Byte offset of the text inside the tag in the output
+  text_start: usize,
+  /// `href` of a link, language of a code block from `class="language-..."`
+  attribute: String,
+}
+
+/// Parse tdlib's HTML, the `textParseModeHTML`
+pub fn parse_html(markup: &str) -> Result<FormattedText, ParseError> {
+  let bytes = markup.as_bytes();
+  let mut output = Output::default();
+  let mut stack: Vec<Open> = vec![];
+  let mut i = 0;
+  while i < markup.len() {
+    match bytes[i] {
+      b'&' => {
+        let (c, end) = entity(markup, i);
+        output.push(c);
+        i = end;
+      }
+      b'<' if bytes.get(i + 1) == Some(&b'/') => {
+        let position = i;
+        let (tag, end) = name(markup, i + 2);
+        let end = skip_spaces(markup, end);
+        if bytes.get(end) != Some(&b'>') {
+          return Err(ParseError::new(position, "Unclosed end tag"));
+        }
+        i = end + 1;
+        let open = match stack.pop() {
+          Some(open) => open,
+          None => return Err(ParseError::new(position, format!("Unexpected end tag \"</{}>\"", tag))),
+        };
+        if open.tag != tag {
+          return Err(ParseError::new(position, format!("Unmatched end tag, expected \"</{}>\", found \"</{}>\"", open.tag, tag)));
+        }
+        close(&mut output, &mut stack, open);
+      }
+      b'<' => {
+        let position = i;
+        let (tag, end) = name(markup, i + 1);
+        if !TAGS.contains(&tag.as_str()) {
+          return Err(ParseError::new(position, format!("Unsupported start tag \"{}\"", tag)));
+        }
+        let mut attribute = String::new();
+        i = end;
+        loop {
+          i = skip_spaces(markup, i);
+          match bytes.get(i) {
+            Some(b'>') => break,
+            None => return Err(ParseError::new(position, "Unclosed start tag")),
+            _ => {}
+          }
+          let (key, end) = name(markup, i);
+          if key.is_empty() {
+            return Err(ParseError::new(i, "Empty attribute name"));
+          }
+          i = skip_spaces(markup, end);
+          let value = if bytes.get(i) == Some(&b'=') {
+            let (value, end) = value(markup, skip_spaces(markup, i + 1)).ok_or_else(|| ParseError::new(position, "Unclosed start tag"))?;
+            i = end;
+            value
+          } else {
+            String::new()
+          };
+          match (tag.as_str(), key.as_str()) {
+            ("a", "href") => attribute = value,
+            ("code", "class") => attribute = value.strip_prefix("language-").unwrap_or_default().to_string(),
+            _ => {}
+          }
+        }
+        i += 1;
+        stack.push(Open { tag, position, offset: output.offset, text_start: output.text.len(), attribute });
+      }
+      _ => {
+        let c = markup[i..].chars().next().unwrap();
+        output.push(c);
+        i += c.len_utf8();
+      }
+    }
+  }
+
+  match stack.last() {
+    Some(open) => Err(ParseError::new(open.position, format!("Can't find end tag corresponding to start tag \"{}\"", open.tag))),
+    None => Ok(output.finish()),
+  }
+}
+
+fn close(output: &mut Output, stack: &mut [Open], open: Open) {
+  let type_ = match open.tag.as_str() {
+    "b" | "strong" => TextEntityType::bold(TextEntityTypeBold::builder().build()),
+    "i" | "em" => TextEntityType::italic(TextEntityTypeItalic::builder().build()),
+    "u" | "ins" => TextEntityType::underline(TextEntityTypeUnderline::builder().build()),
+    "s" | "strike" | "del" => TextEntityType::strikethrough(TextEntityTypeStrikethrough::builder().build()),
+    // as in tdlib, a link without `href` goes to its text when the text is a url
+    "a" => {
+      let text = &output.text[open.text_start..];
+      let url = if !open.attribute.is_empty() { &open.attribute[..] } else if is_url(text) { text } else { "" };
+      match link(url) {
+        Some(type_) => type_,
+        None => return,
+      }
+    }
+    "code" => match stack.last_mut() {
+      // <pre><code class="language-..."> is a single code block
+      Some(pre) if pre.tag == "pre" && pre.offset == open.offset => {
+        pre.attribute = open.attribute;
+        return;
+      }
+      _ => TextEntityType::code(TextEntityTypeCode::builder().build()),
+    },
+    _ if open.attribute.is_empty() => TextEntityType::pre(TextEntityTypePre::builder().build()),
+    _ => TextEntityType::pre_code(TextEntityTypePreCode::builder().language(&open.attribute).build()),
+  };
+  output.entity(open.offset, type_);
+}
+
+/// Whether `text` looks like an url: no spaces, and a host with a dot
+fn is_url(text: &str) -> bool {
+  let rest = text.split_once("://").map_or(text, |(_, rest)| rest);
+  let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+  !text.contains(char::is_whitespace) && host.trim_matches('.').contains('.')
+}
+
+fn skip_spaces(markup: &str, i: usize) -> usize {
+  markup[i..].find(|c: char| !c.is_ascii_whitespace()).map_or(markup.len(), |end| i + end)
+}
+
+/// A lowercase tag or attribute name at `i`, and its end
+fn name(markup: &str, i: usize) -> (String, usize) {
+  let end = markup[i..].find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_')).map_or(markup.len(), |end| i + end);
+  (markup[i..end].to_ascii_lowercase(), end)
+}
+
+/// A quoted or unquoted attribute value at `i`, and its end
+fn value(markup: &str, i: usize) -> Option<(String, usize)> {
+  let (quote, start) = match markup[i..].chars().next()? {
+    c @ '"' | c @ '\'' => (Some(c), i + 1),
+    _ => (None, i),
+  };
+  let end = match quote {
+    Some(quote) => start + markup[start..].find(quote)?,
+    None => markup[start..].find(|c: char| c.is_ascii_whitespace() || c == '>').map_or(markup.len(), |end| start + end),
+  };
+  let mut value = String::new();
+  let mut j = start;
+  while j < end {
+    let (c, next) = if markup.as_bytes()[j] == b'&' { entity(&markup[..end], j) } else {
+      let c = markup[j..].chars().next().unwrap();
+      (c, j + c.len_utf8())
+    };
+    value.push(c);
+    j = next;
+  }
+  Some((value, if quote.is_some() { end + 1 } else { end }))
+}
+
+/// The character of an HTML entity at `i` and the end of the entity, unknown entities are a plain `&`
+fn entity(markup: &str, i: usize) -> (char, usize) {
+  let decoded = markup[i + 1..].find(';').filter(|end| *end <= 10).and_then(|end| {
+    let name = &markup[i + 1..i + 1 + end];
+    let c = match name {
+      "lt" => Some('<'),
+      "gt" => Some('>'),
+      "amp" => Some('&'),
+      "quot" => Some('"'),
+      _ => match name.strip_prefix('#') {
+        Some(hex) if hex.starts_with('x') || hex.starts_with('X') => u32::from_str_radix(&hex[1..], 16).ok().and_then(char::from_u32),
+        Some(decimal) => decimal.parse().ok().and_then(char::from_u32),
+        None => None,
+      },
+    };
+    c.map(|c| (c, i + 1 + end + 1))
+  });
+  decoded.unwrap_or(('&', i + 1))
+}
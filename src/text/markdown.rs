@@ -0,0 +1,182 @@
+use crate::text::{link, Output, ParseError};
+use crate::types::*;
+
+/// Characters which must be escaped with `\` outside of entity markers
+pub(super) const RESERVED: &str = "_*[]()~`>#+-=|{}.!";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+  Bold,
+  Italic,
+  Underline,
+  Strikethrough,
+  TextUrl,
+  Code,
+  Pre,
+}
+
+impl Kind {
+  fn name(self) -> &'static str {
+    match self {
+      Kind::Bold => "Bold",
+      Kind::Italic => "Italic",
+      Kind::Underline => "Underline",
+      Kind::Strikethrough => "Strikethrough",
+      Kind::TextUrl => "TextUrl",
+      Kind::Code => "Code",
+      Kind::Pre => "Pre",
+    }
+  }
+
+  /// Marker closing the entity
+  fn end(self) -> &'static str {
+    match self {
+      Kind::Bold => "*",
+      Kind::Italic => "_",
+      Kind::Underline => "__",
+      Kind::Strikethrough => "~",
+      Kind::TextUrl => "]",
+      Kind::Code => "`",
+      Kind::Pre => "```",
+    }
+  }
+}
+
+struct Open {
+  kind: Kind,
+  /// Byte offset of the marker in the markup
+  position: usize,
+  /// Start of the entity in the text, in bytes and in UTF-16 code units
+  text_start: usize,
+  offset: i64,
+  language: String,
+}
+
+/// Parse tdlib's MarkdownV2, the `textParseModeMarkdown` of version 2.
+///
+/// `__` is read greedily as underline, as in the Bot API: `___italic underline_\r__` ends both.
+pub fn parse_markdown_v2(markup: &str) -> Result<FormattedText, ParseError> {
+  let bytes = markup.as_bytes();
+  let mut output = Output::default();
+  let mut stack: Vec<Open> = vec![];
+  let mut i = 0;
+  while i < markup.len() {
+    let c = markup[i..].chars().next().unwrap();
+    if c == '\\' && i + 1 < bytes.len() && bytes[i + 1] > 0 && bytes[i + 1] < 127 {
+      output.push(bytes[i + 1] as char);
+      i += 2;
+      continue;
+    }
+
+    // only the end marker means something inside code
+    let top = stack.last().map(|open| open.kind);
+    if let Some(kind @ Kind::Code) | Some(kind @ Kind::Pre) = top {
+      if markup[i..].starts_with(kind.end()) {
+        let open = stack.pop().unwrap();
+        close(&mut output, open);
+        i += kind.end().len();
+      } else {
+        output.push(c);
+        i += c.len_utf8();
+      }
+      continue;
+    }
+
+    // `\r` separates markers, e.g. the end of italic from the end of underline in `_\r__`
+    if c == '\r' {
+      i += 1;
+      continue;
+    }
+
+    if !RESERVED.contains(c) {
+      output.push(c);
+      i += c.len_utf8();
+      continue;
+    }
+
+    // `__` is always underline, never the end of italic
+    let is_end = |kind: &Kind| markup[i..].starts_with(kind.end()) && !(*kind == Kind::Italic && markup[i..].starts_with("__"));
+    if let Some(kind) = top.filter(is_end) {
+      let open = stack.pop().unwrap();
+      i += kind.end().len();
+      if kind == Kind::TextUrl {
+        let url = if bytes.get(i) == Some(&b'(') {
+          let (url, end) = url(markup, i)?;
+          i = end;
+          url
+        } else {
+          output.text[open.text_start..].to_string()
+        };
+        if let Some(type_) = link(&url) { output.entity(open.offset, type_) }
+      } else {
+        close(&mut output, open);
+      }
+      continue;
+    }
+
+    let position = i;
+    let mut language = String::new();
+    let kind = match c {
+      '_' if markup[i..].starts_with("__") => Kind::Underline,
+      '_' => Kind::Italic,
+      '*' => Kind::Bold,
+      '~' => Kind::Strikethrough,
+      '[' => Kind::TextUrl,
+      '`' if markup[i..].starts_with("```") => Kind::Pre,
+      '`' => Kind::Code,
+      _ => return Err(ParseError::new(i, format!("Character '{}' is reserved and must be escaped with the preceding '\\'", c))),
+    };
+    i += kind.end().len();
+    if kind == Kind::Pre {
+      // a word right after the marker, followed by a space, is the language of the code
+      let word = markup[i..].find(|c: char| c.is_whitespace() || c == '`').map_or(markup.len(), |end| i + end);
+      if word > i && word < markup.len() && bytes[word] != b'`' {
+        language = markup[i..word].to_string();
+        i = word;
+      }
+      // one line break after the language is not a part of the code
+      match (bytes.get(i), bytes.get(i + 1)) {
+        (Some(b'\r'), Some(b'\n')) | (Some(b'\n'), Some(b'\r')) => i += 2,
+        (Some(b'\n'), _) | (Some(b'\r'), _) => i += 1,
+        _ => {}
+      }
+    }
+    stack.push(Open { kind, position, text_start: output.text.len(), offset: output.offset, language });
+  }
+
+  match stack.last() {
+    Some(open) => Err(ParseError::new(open.position, format!("Can't find end of {} entity", open.kind.name()))),
+    None => Ok(output.finish()),
+  }
+}
+
+fn close(output: &mut Output, open: Open) {
+  let type_ = match open.kind {
+    Kind::Bold => TextEntityType::bold(TextEntityTypeBold::builder().build()),
+    Kind::Italic => TextEntityType::italic(TextEntityTypeItalic::builder().build()),
+    Kind::Underline => TextEntityType::underline(TextEntityTypeUnderline::builder().build()),
+    Kind::Strikethrough => TextEntityType::strikethrough(TextEntityTypeStrikethrough::builder().build()),
+    Kind::Code => TextEntityType::code(TextEntityTypeCode::builder().build()),
+    Kind::Pre if open.language.is_empty() => TextEntityType::pre(TextEntityTypePre::builder().build()),
+    Kind::Pre => TextEntityType::pre_code(TextEntityTypePreCode::builder().language(&open.language).build()),
+    Kind::TextUrl => unreachable!("links are closed with their url"),
+  };
+  output.entity(open.offset, type_);
+}
+
+/// The url of a link in `(...)` at `start`, and the end of the parentheses
+fn url(markup: &str, start: usize) -> Result<(String, usize), ParseError> {
+  let mut url = String::new();
+  let mut chars = markup[start + 1..].char_indices();
+  while let Some((i, c)) = chars.next() {
+    match c {
+      ')' => return Ok((url, start + 1 + i + 1)),
+      '\\' => match chars.next() {
+        Some((_, escaped)) => url.push(escaped),
+        None => break,
+      },
+      c => url.push(c),
+    }
+  }
+  Err(ParseError::new(start, "Can't find end of a URL"))
+}
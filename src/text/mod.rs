@@ -0,0 +1,116 @@
+//! `FormattedText` without tdlib: markup parsers, `FormattedText::to_html`, `to_markdown_v2` and `to_plain`, and
+//! editing of texts with their entities.
+//!
+//! Entity offsets and lengths are in UTF-16 code units, as tdlib counts them.
+
+use std::{error, fmt};
+
+use crate::errors::*;
+use crate::types::*;
+
+pub use self::html::*;
+pub use self::markdown::*;
+pub use self::utf16::*;
+
+mod html;
+mod markdown;
+mod render;
+mod utf16;
+
+/// Malformed markup, `offset` is in bytes of the markup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  pub offset: usize,
+  pub message: String,
+}
+
+impl ParseError {
+  fn new<S: AsRef<str>>(offset: usize, message: S) -> Self {
+    ParseError { offset, message: message.as_ref().to_string() }
+  }
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} at byte offset {}", self.message, self.offset)
+  }
+}
+
+impl error::Error for ParseError {}
+
+/// The error tdlib returns for the same markup
+impl From<ParseError> for RTDError {
+  fn from(err: ParseError) -> Self {
+    RTDError::td(400, format!("Can't parse entities: {}", err))
+  }
+}
+
+/// The target of a link, `tg://user?id=` links mention a user
+fn link(url: &str) -> Option<TextEntityType> {
+  if url.is_empty() {
+    return None;
+  }
+  Some(match url.strip_prefix("tg://user?id=").and_then(|id| id.parse().ok()) {
+    Some(user_id) => TextEntityType::mention_name(TextEntityTypeMentionName::builder().user_id(user_id).build()),
+    None => TextEntityType::text_url(TextEntityTypeTextUrl::builder().url(url).build()),
+  })
+}
+
+/// Order of entities with the same range, as in tdlib
+fn priority(type_: &TextEntityType) -> i32 {
+  match type_ {
+    TextEntityType::PreCode(_) => 10,
+    TextEntityType::Pre(_) => 11,
+    TextEntityType::Code(_) => 20,
+    TextEntityType::TextUrl(_) | TextEntityType::MentionName(_) => 49,
+    TextEntityType::Bold(_) => 90,
+    TextEntityType::Italic(_) => 91,
+    TextEntityType::Underline(_) => 92,
+    TextEntityType::Strikethrough(_) => 93,
+    _ => 50,
+  }
+}
+
+/// Text and entities of a parser
+#[derive(Default)]
+struct Output {
+  text: String,
+  /// Length of `text` in UTF-16 code units
+  offset: i64,
+  entities: Vec<TextEntity>,
+}
+
+impl Output {
+  fn push(&mut self, c: char) {
+    self.text.push(c);
+    self.offset += c.len_utf16() as i64;
+  }
+
+  /// Add an entity from `offset` to the end of the text, empty entities are dropped
+  fn entity(&mut self, offset: i64, type_: TextEntityType) {
+    if self.offset > offset {
+      self.entities.push(TextEntity::builder().offset(offset).length(self.offset - offset).type_(type_).build());
+    }
+  }
+
+  /// Entities sorted the way tdlib sorts them, by offset, outer ones first
+  fn finish(mut self) -> FormattedText {
+    self.entities.sort_by_key(|e| (e.offset(), -e.length(), priority(e.type_())));
+    FormattedText::builder().text(self.text).entities(self.entities).build()
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::text::*;
+
+  #[test]
+  fn test_parse_error() {
+    let err = parse_html("<b>bold</i>").unwrap_err();
+    assert_eq!(err.offset, 7);
+    let err = RTDError::from(err);
+    assert_eq!(err.td_kind(), Some(TdErrorKind::BadRequest));
+    assert_eq!(err.to_string(), r#"Td error 400: Can't parse entities: Unmatched end tag, expected "</b>", found "</i>" at byte offset 7"#);
+  }
+}
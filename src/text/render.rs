@@ -0,0 +1,246 @@
+use std::collections::BTreeSet;
+
+use crate::text::markdown::RESERVED;
+use crate::text::priority;
+use crate::text::utf16::utf16_to_byte_ceil;
+use crate::types::*;
+
+impl FormattedText {
+  /// The text in tdlib's HTML, overlapping entities are split so the tags nest
+  pub fn to_html(&self) -> String {
+    render(self, &Html)
+  }
+
+  /// The text in tdlib's MarkdownV2, overlapping entities are split so the markers nest
+  pub fn to_markdown_v2(&self) -> String {
+    render(self, &MarkdownV2)
+  }
+
+  /// The text without formatting, the url of a text link follows it in parentheses
+  pub fn to_plain(&self) -> String {
+    render(self, &Plain)
+  }
+}
+
+/// How a markup writes text and entities
+trait Markup {
+  fn text(&self, out: &mut String, text: &str, is_code: bool);
+  fn open(&self, out: &mut String, type_: &TextEntityType);
+  fn close(&self, out: &mut String, type_: &TextEntityType, text: &str);
+}
+
+fn is_code(type_: &TextEntityType) -> bool {
+  matches!(type_, TextEntityType::Code(_) | TextEntityType::Pre(_) | TextEntityType::PreCode(_))
+}
+
+fn url(type_: &TextEntityType) -> Option<String> {
+  match type_ {
+    TextEntityType::TextUrl(t) => Some(t.url().clone()),
+    TextEntityType::MentionName(t) => Some(format!("tg://user?id={}", t.user_id())),
+    _ => None,
+  }
+}
+
+struct Span<'a> {
+  start: usize,
+  end: usize,
+  type_: &'a TextEntityType,
+}
+
+fn render<M: Markup>(formatted: &FormattedText, markup: &M) -> String {
+  let text = formatted.text();
+  let mut spans: Vec<Span> = formatted.entities().iter()
+    .filter(|e| is_code(e.type_()) || url(e.type_()).is_some() || matches!(e.type_(),
+      TextEntityType::Bold(_) | TextEntityType::Italic(_) | TextEntityType::Underline(_) | TextEntityType::Strikethrough(_)))
+    .map(|e| Span { start: utf16_to_byte_ceil(text, e.offset()), end: utf16_to_byte_ceil(text, e.offset() + e.length()), type_: e.type_() })
+    .filter(|span| span.start < span.end)
+    .collect();
+  spans.sort_by_key(|span| (span.start, std::cmp::Reverse(span.end), priority(span.type_)));
+  // nothing is formatted inside code
+  let code: Vec<(usize, usize)> = spans.iter().filter(|s| is_code(s.type_)).map(|s| (s.start, s.end)).collect();
+  spans.retain(|s| is_code(s.type_) || !code.iter().any(|&(start, end)| start <= s.start && s.end <= end));
+
+  let boundaries: BTreeSet<usize> = spans.iter().flat_map(|s| vec![s.start, s.end]).chain(vec![text.len()]).collect();
+  let mut out = String::new();
+  let mut stack: Vec<&Span> = vec![];
+  let mut next = 0;
+  let mut position = 0;
+  for boundary in boundaries {
+    let is_code = stack.iter().any(|s| is_code(s.type_));
+    markup.text(&mut out, &text[position..boundary], is_code);
+    position = boundary;
+
+    // close the entities ending here, and reopen the ones inside them which do not
+    if stack.iter().any(|s| s.end == boundary) {
+      let mut reopen = vec![];
+      while stack.iter().any(|s| s.end == boundary) {
+        let span = stack.pop().unwrap();
+        markup.close(&mut out, span.type_, &text[span.start..span.end]);
+        if span.end != boundary { reopen.push(span) }
+      }
+      for span in reopen.into_iter().rev() {
+        markup.open(&mut out, span.type_);
+        stack.push(span);
+      }
+    }
+    while next < spans.len() && spans[next].start == boundary {
+      markup.open(&mut out, spans[next].type_);
+      stack.push(&spans[next]);
+      next += 1;
+    }
+  }
+  out
+}
+
+struct Html;
+
+impl Html {
+  fn escape(out: &mut String, text: &str) {
+    for c in text.chars() {
+      match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        c => out.push(c),
+      }
+    }
+  }
+}
+
+impl Markup for Html {
+  fn text(&self, out: &mut String, text: &str, _is_code: bool) {
+    Html::escape(out, text)
+  }
+
+  fn open(&self, out: &mut String, type_: &TextEntityType) {
+    match type_ {
+      TextEntityType::Bold(_) => out.push_str("<b>"),
+      TextEntityType::Italic(_) => out.push_str("<i>"),
+      TextEntityType::Underline(_) => out.push_str("<u>"),
+      TextEntityType::Strikethrough(_) => out.push_str("<s>"),
+      TextEntityType::Code(_) => out.push_str("<code>"),
+      TextEntityType::Pre(_) => out.push_str("<pre>"),
+      TextEntityType::PreCode(t) => {
+        out.push_str("<pre><code class=\"language-");
+        Html::escape(out, t.language());
+        out.push_str("\">");
+      }
+      type_ => if let Some(url) = url(type_) {
+        out.push_str("<a href=\"");
+        Html::escape(out, &url);
+        out.push_str("\">");
+      },
+    }
+  }
+
+  fn close(&self, out: &mut String, type_: &TextEntityType, _text: &str) {
+    out.push_str(match type_ {
+      TextEntityType::Bold(_) => "</b>",
+      TextEntityType::Italic(_) => "</i>",
+      TextEntityType::Underline(_) => "</u>",
+      TextEntityType::Strikethrough(_) => "</s>",
+      TextEntityType::Code(_) => "</code>",
+      TextEntityType::Pre(_) => "</pre>",
+      TextEntityType::PreCode(_) => "</code></pre>",
+      _ => "</a>",
+    })
+  }
+}
+
+struct MarkdownV2;
+
+impl MarkdownV2 {
+  /// Markers next to each other are separated by `\r`, which the parser skips, so `_` and `__` are not merged
+  fn marker(out: &mut String, marker: &str) {
+    if marker.starts_with('_') && out.ends_with('_') {
+      out.push('\r');
+    }
+    out.push_str(marker);
+  }
+}
+
+impl Markup for MarkdownV2 {
+  fn text(&self, out: &mut String, text: &str, is_code: bool) {
+    for c in text.chars() {
+      let escape = if is_code { c == '`' || c == '\\' } else { c == '\\' || c == '\r' || RESERVED.contains(c) };
+      if escape { out.push('\\') }
+      out.push(c);
+    }
+  }
+
+  fn open(&self, out: &mut String, type_: &TextEntityType) {
+    match type_ {
+      TextEntityType::Bold(_) => MarkdownV2::marker(out, "*"),
+      TextEntityType::Italic(_) => MarkdownV2::marker(out, "_"),
+      TextEntityType::Underline(_) => MarkdownV2::marker(out, "__"),
+      TextEntityType::Strikethrough(_) => MarkdownV2::marker(out, "~"),
+      TextEntityType::Code(_) => MarkdownV2::marker(out, "`"),
+      // the line break keeps the first word of the code from being read as its language
+      TextEntityType::Pre(_) => MarkdownV2::marker(out, "```\n"),
+      TextEntityType::PreCode(t) => MarkdownV2::marker(out, &format!("```{}\n", t.language())),
+      _ => MarkdownV2::marker(out, "["),
+    }
+  }
+
+  fn close(&self, out: &mut String, type_: &TextEntityType, _text: &str) {
+    match type_ {
+      TextEntityType::Bold(_) => MarkdownV2::marker(out, "*"),
+      TextEntityType::Italic(_) => MarkdownV2::marker(out, "_"),
+      TextEntityType::Underline(_) => MarkdownV2::marker(out, "__"),
+      TextEntityType::Strikethrough(_) => MarkdownV2::marker(out, "~"),
+      TextEntityType::Code(_) => MarkdownV2::marker(out, "`"),
+      TextEntityType::Pre(_) | TextEntityType::PreCode(_) => MarkdownV2::marker(out, "```"),
+      type_ => {
+        out.push_str("](");
+        for c in url(type_).unwrap_or_default().chars() {
+          if c == ')' || c == '\\' { out.push('\\') }
+          out.push(c);
+        }
+        out.push(')');
+      }
+    }
+  }
+}
+
+struct Plain;
+
+impl Markup for Plain {
+  fn text(&self, out: &mut String, text: &str, _is_code: bool) {
+    out.push_str(text)
+  }
+
+  fn open(&self, _out: &mut String, _type_: &TextEntityType) {}
+
+  fn close(&self, out: &mut String, type_: &TextEntityType, text: &str) {
+    if let TextEntityType::TextUrl(t) = type_ {
+      if t.url() != text { out.push_str(&format!(" ({})", t.url())) }
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::text::*;
+
+  #[test]
+  fn test_overlapping() {
+    // bold "Hello wo", italic "world"
+    let text = FormattedText::from_json(r#"{"@type":"formattedText","text":"Hello world!","entities":[
+      {"@type":"textEntity","offset":0,"length":8,"type":{"@type":"textEntityTypeBold"}},
+      {"@type":"textEntity","offset":6,"length":5,"type":{"@type":"textEntityTypeItalic"}},
+      {"@type":"textEntity","offset":0,"length":5,"type":{"@type":"textEntityTypeTextUrl","url":"https://t.me/a_(b)"}}
+    ]}"#).unwrap();
+    assert_eq!(text.to_html(), r#"<b><a href="https://t.me/a_(b)">Hello</a> <i>wo</i></b><i>rld</i>!"#);
+    assert_eq!(text.to_markdown_v2(), r"*[Hello](https://t.me/a_(b\)) _wo_*_rld_\!");
+    assert_eq!(text.to_plain(), "Hello (https://t.me/a_(b)) world!");
+  }
+
+  #[test]
+  fn test_code_and_escapes() {
+    let text = parse_html(r#"<pre><code class="language-rust">let a = `b` * 2;</code></pre> <b>1 &lt; 2</b> <i><u>x</u></i>"#).unwrap();
+    assert_eq!(text.to_markdown_v2(), "```rust\nlet a = \\`b\\` * 2;``` *1 < 2* _\r__x__\r_");
+    assert_eq!(text.to_html(), r#"<pre><code class="language-rust">let a = `b` * 2;</code></pre> <b>1 &lt; 2</b> <i><u>x</u></i>"#);
+  }
+}
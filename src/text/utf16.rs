@@ -0,0 +1,221 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::errors::*;
+use crate::types::*;
+
+/// Most UTF-16 code units in the text of a message
+pub const MAX_MESSAGE_LENGTH: i64 = 4096;
+
+/// Number of UTF-16 code units of `text`
+pub fn utf16_len(text: &str) -> i64 {
+  text.chars().map(|c| c.len_utf16() as i64).sum()
+}
+
+/// UTF-16 offset of the byte offset `byte`, `None` if it is not on a character boundary
+pub fn byte_to_utf16(text: &str, byte: usize) -> Option<i64> {
+  if text.is_char_boundary(byte) { Some(utf16_len(&text[..byte])) } else { None }
+}
+
+/// Byte offset of the UTF-16 offset `offset`, `None` if it is past the end or between the halves of a surrogate pair
+pub fn utf16_to_byte(text: &str, offset: i64) -> Option<usize> {
+  let mut utf16 = 0;
+  for (i, c) in text.char_indices() {
+    if utf16 >= offset {
+      return if utf16 == offset { Some(i) } else { None };
+    }
+    utf16 += c.len_utf16() as i64;
+  }
+  if utf16 == offset { Some(text.len()) } else { None }
+}
+
+/// Byte offset of the UTF-16 offset `offset`, an offset in the middle of a character moves to its end
+pub(super) fn utf16_to_byte_ceil(text: &str, offset: i64) -> usize {
+  let mut utf16 = 0;
+  for (i, c) in text.char_indices() {
+    if utf16 >= offset {
+      return i;
+    }
+    utf16 += c.len_utf16() as i64;
+  }
+  text.len()
+}
+
+fn entity(offset: i64, length: i64, type_: &TextEntityType) -> TextEntity {
+  TextEntity::builder().offset(offset).length(length).type_(type_).build()
+}
+
+impl FormattedText {
+  /// The text of `entity`, `None` if its range is not in the text
+  pub fn entity_text(&self, entity: &TextEntity) -> Option<&str> {
+    let start = utf16_to_byte(self.text(), entity.offset())?;
+    let end = utf16_to_byte(self.text(), entity.offset() + entity.length())?;
+    self.text().get(start..end)
+  }
+
+  /// The text from UTF-16 `offset`, `length` code units long, with the parts of the entities inside it
+  pub fn slice(&self, offset: i64, length: i64) -> Option<FormattedText> {
+    let start = utf16_to_byte(self.text(), offset)?;
+    let end = utf16_to_byte(self.text(), offset + length)?;
+    let entities = self.entities().iter()
+      .filter_map(|e| {
+        let (from, to) = (e.offset().max(offset), (e.offset() + e.length()).min(offset + length));
+        if from < to { Some(entity(from - offset, to - from, e.type_())) } else { None }
+      })
+      .collect();
+    Some(FormattedText::builder().text(self.text().get(start..end)?).entities(entities).build())
+  }
+
+  /// This text followed by `other`, the entities of `other` are shifted after this text
+  pub fn concat(&self, other: &FormattedText) -> FormattedText {
+    let shift = utf16_len(self.text());
+    let entities = self.entities().iter().cloned()
+      .chain(other.entities().iter().map(|e| entity(e.offset() + shift, e.length(), e.type_())))
+      .collect();
+    FormattedText::builder().text(format!("{}{}", self.text(), other.text())).entities(entities).build()
+  }
+
+  /// Insert `text` at UTF-16 `offset`, entities around the offset grow, entities starting at it are shifted
+  pub fn insert(&self, offset: i64, text: &str) -> RTDResult<FormattedText> {
+    self.replace(offset, 0, text)
+  }
+
+  /// Replace `length` UTF-16 code units at `offset` with `text`.
+  ///
+  /// Entities containing the range grow or shrink with it, entities partly in the range lose that part, entities
+  /// inside it are removed.
+  pub fn replace(&self, offset: i64, length: i64, text: &str) -> RTDResult<FormattedText> {
+    let invalid = || RTDError::custom("Range is not on character boundaries of the text");
+    let start = utf16_to_byte(self.text(), offset).ok_or_else(invalid)?;
+    let end = utf16_to_byte(self.text(), offset + length).filter(|_| length >= 0).ok_or_else(invalid)?;
+    let inserted = utf16_len(text);
+    let (range_end, delta) = (offset + length, inserted - length);
+    let entities = self.entities().iter()
+      .filter_map(|e| {
+        let (from, to) = (e.offset(), e.offset() + e.length());
+        let (from, to) = if to <= offset {
+          (from, to)
+        } else if from >= range_end {
+          (from + delta, to + delta)
+        } else if from <= offset && range_end <= to {
+          (from, to + delta)
+        } else if from < offset {
+          (from, offset)
+        } else {
+          (offset + inserted, to + delta)
+        };
+        if from < to { Some(entity(from, to - from, e.type_())) } else { None }
+      })
+      .collect();
+    let replaced = format!("{}{}{}", &self.text()[..start], text, &self.text()[end..]);
+    Ok(FormattedText::builder().text(replaced).entities(entities).build())
+  }
+
+  /// Split into texts of at most `max_length` UTF-16 code units, e.g. `MAX_MESSAGE_LENGTH`.
+  ///
+  /// Cuts are never inside a grapheme cluster, and outside of entities at a line break or a space when possible.
+  /// An entity which does not fit is split between the texts.
+  pub fn split(&self, max_length: i64) -> Vec<FormattedText> {
+    let text = self.text();
+    let total = utf16_len(text);
+    if total <= max_length || max_length <= 0 {
+      return vec![self.clone()];
+    }
+    // UTF-16 offsets of the grapheme cluster boundaries, with the character before them
+    let mut boundaries = vec![];
+    let mut utf16 = 0;
+    for grapheme in text.graphemes(true) {
+      utf16 += utf16_len(grapheme);
+      boundaries.push((utf16, grapheme.chars().last().unwrap()));
+    }
+    let inside_entity = |cut: i64| self.entities().iter().any(|e| e.offset() < cut && cut < e.offset() + e.length());
+
+    let mut chunks = vec![];
+    let mut start = 0;
+    while total - start > max_length {
+      let limit = start + max_length;
+      let candidates: Vec<(i64, char)> = boundaries.iter().cloned().filter(|&(cut, _)| cut > start && cut <= limit).collect();
+      let best = |outside: bool| {
+        let fits: Vec<&(i64, char)> = candidates.iter().filter(|&&(cut, _)| !outside || !inside_entity(cut)).collect();
+        fits.iter().rev().find(|(_, c)| *c == '\n')
+          .or_else(|| fits.iter().rev().find(|(_, c)| c.is_whitespace()))
+          .or_else(|| fits.last())
+          .map(|&&(cut, _)| cut)
+      };
+      // a grapheme cluster longer than the limit is cut between characters, a character longer than it is kept whole
+      let cut = best(true).or_else(|| best(false)).unwrap_or_else(|| {
+        let mut cut = start;
+        for c in text[utf16_to_byte_ceil(text, start)..].chars() {
+          if cut > start && cut + c.len_utf16() as i64 > limit { break }
+          cut += c.len_utf16() as i64;
+        }
+        cut
+      });
+      chunks.push(self.slice(start, cut - start).expect("cut on a char boundary"));
+      start = cut;
+    }
+    chunks.push(self.slice(start, total - start).expect("cut on a char boundary"));
+    chunks
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::text::*;
+
+  #[test]
+  fn test_offsets() {
+    // 👍🏽 is a surrogate pair and a modifier, é is e and a combining accent
+    let text = "a👍🏽e\u{301}!";
+    assert_eq!(utf16_len(text), 8);
+    assert_eq!(byte_to_utf16(text, 1), Some(1));
+    assert_eq!(byte_to_utf16(text, 2), None);
+    assert_eq!(byte_to_utf16(text, 9), Some(5));
+    assert_eq!(byte_to_utf16(text, text.len()), Some(8));
+    assert_eq!(utf16_to_byte(text, 3), Some(5));
+    assert_eq!(utf16_to_byte(text, 2), None);
+    assert_eq!(utf16_to_byte(text, 6), Some(10));
+    assert_eq!(utf16_to_byte(text, 9), None);
+  }
+
+  #[test]
+  fn test_entity_text_and_edit() {
+    let text = parse_markdown_v2("👍🏽 *bold e\u{301}* _x_").unwrap();
+    let bold = &text.entities()[0];
+    assert_eq!(text.entity_text(bold), Some("bold e\u{301}"));
+
+    let inserted = text.insert(9, "ly").unwrap();
+    assert_eq!(inserted.text(), "👍🏽 boldly e\u{301} x");
+    assert_eq!(inserted.entity_text(&inserted.entities()[0]), Some("boldly e\u{301}"));
+    assert_eq!(inserted.entity_text(&inserted.entities()[1]), Some("x"));
+    assert!(text.insert(1, "a").is_err());
+
+    let replaced = text.replace(7, 6, "😀").unwrap();
+    assert_eq!(replaced.to_markdown_v2(), "👍🏽 *bo*😀_x_");
+    let joined = replaced.concat(&text);
+    assert_eq!(joined.to_markdown_v2(), "👍🏽 *bo*😀_x_👍🏽 *bold e\u{301}* _x_");
+    assert_eq!(joined.slice(7, 11).unwrap().to_markdown_v2(), "😀_x_👍🏽 *bol*");
+    assert!(joined.slice(8, 1).is_none());
+  }
+
+  #[test]
+  fn test_split() {
+    let text = parse_markdown_v2("first line\n*bold words* plain 👍🏽👍🏽\u{301}").unwrap();
+    let chunks = text.split(16);
+    let rendered: Vec<String> = chunks.iter().map(|chunk| chunk.to_markdown_v2()).collect();
+    assert_eq!(rendered, vec!["first line\n", "*bold words* ", "plain 👍🏽👍🏽\u{301}"]);
+    assert!(chunks.iter().all(|chunk| utf16_len(chunk.text()) <= 16));
+
+    // an entity longer than the limit is split, never a grapheme cluster
+    let long = parse_html("<b>e\u{301}e\u{301}e\u{301}</b>").unwrap();
+    let rendered: Vec<String> = long.split(5).iter().map(|chunk| chunk.to_markdown_v2()).collect();
+    assert_eq!(rendered, vec!["*e\u{301}e\u{301}*", "*e\u{301}*"]);
+    let joined = long.split(5).iter().fold(FormattedText::default(), |all, chunk| all.concat(chunk));
+    assert_eq!(joined.text(), long.text());
+
+    // a surrogate pair longer than the limit is a text of its own, no text is lost
+    let emoji = FormattedText::builder().text("a👍🏽b").build();
+    let texts: Vec<String> = emoji.split(1).iter().map(|chunk| chunk.text().clone()).collect();
+    assert_eq!(texts, vec!["a", "👍", "🏽", "b"]);
+  }
+}
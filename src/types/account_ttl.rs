@@ -34,7 +34,16 @@ impl AccountTtl {
     let mut inner = AccountTtl::default();
     inner.td_name = "accountTtl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAccountTtlBuilder { inner }
+    RTDAccountTtlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("accountTtl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.days == 0 { missing.push("days".to_string()); }
+    missing
   }
 
   pub fn days(&self) -> i64 { self.days }
@@ -43,15 +52,25 @@ impl AccountTtl {
 
 #[doc(hidden)]
 pub struct RTDAccountTtlBuilder {
-  inner: AccountTtl
+  inner: AccountTtl,
+  set: [bool; 1]
 }
 
 impl RTDAccountTtlBuilder {
   pub fn build(&self) -> AccountTtl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AccountTtl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("days".to_string()); }
+    crate::types::_common::check_missing("accountTtl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn days(&mut self, days: i64) -> &mut Self {
     self.inner.days = days;
+    self.set[0] = true;
     self
   }
 
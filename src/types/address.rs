@@ -44,7 +44,21 @@ impl Address {
     let mut inner = Address::default();
     inner.td_name = "address".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddressBuilder { inner }
+    RTDAddressBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("address", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.country_code.is_empty() { missing.push("country_code".to_string()); }
+    if self.state.is_empty() { missing.push("state".to_string()); }
+    if self.city.is_empty() { missing.push("city".to_string()); }
+    if self.street_line1.is_empty() { missing.push("street_line1".to_string()); }
+    if self.street_line2.is_empty() { missing.push("street_line2".to_string()); }
+    if self.postal_code.is_empty() { missing.push("postal_code".to_string()); }
+    missing
   }
 
   pub fn country_code(&self) -> &String { &self.country_code }
@@ -63,45 +77,65 @@ impl Address {
 
 #[doc(hidden)]
 pub struct RTDAddressBuilder {
-  inner: Address
+  inner: Address,
+  set: [bool; 6]
 }
 
 impl RTDAddressBuilder {
   pub fn build(&self) -> Address { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Address> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("country_code".to_string()); }
+    if !self.set[1] { missing.push("state".to_string()); }
+    if !self.set[2] { missing.push("city".to_string()); }
+    if !self.set[3] { missing.push("street_line1".to_string()); }
+    if !self.set[4] { missing.push("street_line2".to_string()); }
+    if !self.set[5] { missing.push("postal_code".to_string()); }
+    crate::types::_common::check_missing("address", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn state<T: AsRef<str>>(&mut self, state: T) -> &mut Self {
     self.inner.state = state.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn city<T: AsRef<str>>(&mut self, city: T) -> &mut Self {
     self.inner.city = city.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn street_line1<T: AsRef<str>>(&mut self, street_line1: T) -> &mut Self {
     self.inner.street_line1 = street_line1.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn street_line2<T: AsRef<str>>(&mut self, street_line2: T) -> &mut Self {
     self.inner.street_line2 = street_line2.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn postal_code<T: AsRef<str>>(&mut self, postal_code: T) -> &mut Self {
     self.inner.postal_code = postal_code.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
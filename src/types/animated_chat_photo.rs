@@ -38,7 +38,18 @@ impl AnimatedChatPhoto {
     let mut inner = AnimatedChatPhoto::default();
     inner.td_name = "animatedChatPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnimatedChatPhotoBuilder { inner }
+    RTDAnimatedChatPhotoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("animatedChatPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.length == 0 { missing.push("length".to_string()); }
+    if false { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.file._missing_fields())); }
+    if self.main_frame_timestamp == 0.0 { missing.push("main_frame_timestamp".to_string()); }
+    missing
   }
 
   pub fn length(&self) -> i64 { self.length }
@@ -51,27 +62,41 @@ impl AnimatedChatPhoto {
 
 #[doc(hidden)]
 pub struct RTDAnimatedChatPhotoBuilder {
-  inner: AnimatedChatPhoto
+  inner: AnimatedChatPhoto,
+  set: [bool; 3]
 }
 
 impl RTDAnimatedChatPhotoBuilder {
   pub fn build(&self) -> AnimatedChatPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnimatedChatPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("length".to_string()); }
+    if !self.set[1] { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.inner.file._missing_fields())); }
+    if !self.set[2] { missing.push("main_frame_timestamp".to_string()); }
+    crate::types::_common::check_missing("animatedChatPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[0] = true;
     self
   }
 
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn main_frame_timestamp(&mut self, main_frame_timestamp: f32) -> &mut Self {
     self.inner.main_frame_timestamp = main_frame_timestamp;
+    self.set[2] = true;
     self
   }
 
@@ -50,7 +50,21 @@ impl Animation {
     let mut inner = Animation::default();
     inner.td_name = "animation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnimationBuilder { inner }
+    RTDAnimationBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("animation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if self.file_name.is_empty() { missing.push("file_name".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if false { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    missing
   }
 
   pub fn duration(&self) -> i64 { self.duration }
@@ -75,39 +89,58 @@ impl Animation {
 
 #[doc(hidden)]
 pub struct RTDAnimationBuilder {
-  inner: Animation
+  inner: Animation,
+  set: [bool; 6]
 }
 
 impl RTDAnimationBuilder {
   pub fn build(&self) -> Animation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Animation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("duration".to_string()); }
+    if !self.set[1] { missing.push("width".to_string()); }
+    if !self.set[2] { missing.push("height".to_string()); }
+    if !self.set[3] { missing.push("file_name".to_string()); }
+    if !self.set[4] { missing.push("mime_type".to_string()); }
+    if !self.set[5] { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    crate::types::_common::check_missing("animation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[1] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[2] = true;
     self
   }
 
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -132,6 +165,7 @@ impl RTDAnimationBuilder {
    
   pub fn animation<T: AsRef<File>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
+    self.set[5] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl Animations {
     RTDAnimationsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("animations", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn animations(&self) -> &Vec<Animation> { &self.animations }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDAnimationsBuilder {
 impl RTDAnimationsBuilder {
   pub fn build(&self) -> Animations { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Animations> { Ok(self.build()) }
+
    
   pub fn animations(&mut self, animations: Vec<Animation>) -> &mut Self {
     self.inner.animations = animations;
@@ -48,7 +48,21 @@ impl Audio {
     let mut inner = Audio::default();
     inner.td_name = "audio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAudioBuilder { inner }
+    RTDAudioBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("audio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.performer.is_empty() { missing.push("performer".to_string()); }
+    if self.file_name.is_empty() { missing.push("file_name".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if false { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.audio._missing_fields())); }
+    missing
   }
 
   pub fn duration(&self) -> i64 { self.duration }
@@ -71,39 +85,58 @@ impl Audio {
 
 #[doc(hidden)]
 pub struct RTDAudioBuilder {
-  inner: Audio
+  inner: Audio,
+  set: [bool; 6]
 }
 
 impl RTDAudioBuilder {
   pub fn build(&self) -> Audio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Audio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("duration".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("performer".to_string()); }
+    if !self.set[3] { missing.push("file_name".to_string()); }
+    if !self.set[4] { missing.push("mime_type".to_string()); }
+    if !self.set[5] { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.inner.audio._missing_fields())); }
+    crate::types::_common::check_missing("audio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn performer<T: AsRef<str>>(&mut self, performer: T) -> &mut Self {
     self.inner.performer = performer.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -122,6 +155,7 @@ impl RTDAudioBuilder {
    
   pub fn audio<T: AsRef<File>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
+    self.set[5] = true;
     self
   }
 
@@ -40,7 +40,18 @@ impl AuthenticationCodeInfo {
     let mut inner = AuthenticationCodeInfo::default();
     inner.td_name = "authenticationCodeInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthenticationCodeInfoBuilder { inner }
+    RTDAuthenticationCodeInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authenticationCodeInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.timeout == 0 { missing.push("timeout".to_string()); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -55,15 +66,27 @@ impl AuthenticationCodeInfo {
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeInfoBuilder {
-  inner: AuthenticationCodeInfo
+  inner: AuthenticationCodeInfo,
+  set: [bool; 2]
 }
 
 impl RTDAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> AuthenticationCodeInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthenticationCodeInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("timeout".to_string()); }
+    crate::types::_common::check_missing("authenticationCodeInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -82,6 +105,7 @@ impl RTDAuthenticationCodeInfoBuilder {
    
   pub fn timeout(&mut self, timeout: i64) -> &mut Self {
     self.inner.timeout = timeout;
+    self.set[1] = true;
     self
   }
 
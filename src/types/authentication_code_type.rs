@@ -19,6 +19,9 @@ pub trait TDAuthenticationCodeType: Debug + RObject {}
 #[serde(untagged)]
 pub enum AuthenticationCodeType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An authentication code is delivered via a phone call to the specified phone number
   Call(AuthenticationCodeTypeCall),
   /// An authentication code is delivered by an immediately cancelled call to the specified phone number. The number from which the call was made is the code
@@ -36,7 +39,6 @@ impl Default for AuthenticationCodeType {
 
 impl<'de> Deserialize<'de> for AuthenticationCodeType {
   fn deserialize<D>(deserializer: D) -> Result<AuthenticationCodeType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       AuthenticationCodeType,
       (authenticationCodeTypeCall, Call);
@@ -65,6 +67,7 @@ impl RObject for AuthenticationCodeType {
       AuthenticationCodeType::FlashCall(t) => t.extra(),
       AuthenticationCodeType::Sms(t) => t.extra(),
       AuthenticationCodeType::TelegramMessage(t) => t.extra(),
+      AuthenticationCodeType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for AuthenticationCodeType {
 impl AuthenticationCodeType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthenticationCodeType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      AuthenticationCodeType::Call(t) => t._missing_fields(),
+      AuthenticationCodeType::FlashCall(t) => t._missing_fields(),
+      AuthenticationCodeType::Sms(t) => t._missing_fields(),
+      AuthenticationCodeType::TelegramMessage(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_call(&self) -> bool { if let AuthenticationCodeType::Call(_) = self { true } else { false } }
   pub fn is_flash_call(&self) -> bool { if let AuthenticationCodeType::FlashCall(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<AuthenticationCodeType> for AuthenticationCodeType {
   fn as_ref(&self) -> &AuthenticationCodeType { self }
 }
 
+impl RVariant<AuthenticationCodeType> for AuthenticationCodeTypeCall {
+  fn from_class(class: &AuthenticationCodeType) -> Option<&Self> { match class { AuthenticationCodeType::Call(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthenticationCodeType> for AuthenticationCodeTypeFlashCall {
+  fn from_class(class: &AuthenticationCodeType) -> Option<&Self> { match class { AuthenticationCodeType::FlashCall(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthenticationCodeType> for AuthenticationCodeTypeSms {
+  fn from_class(class: &AuthenticationCodeType) -> Option<&Self> { match class { AuthenticationCodeType::Sms(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthenticationCodeType> for AuthenticationCodeTypeTelegramMessage {
+  fn from_class(class: &AuthenticationCodeType) -> Option<&Self> { match class { AuthenticationCodeType::TelegramMessage(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +172,16 @@ impl AuthenticationCodeTypeCall {
     let mut inner = AuthenticationCodeTypeCall::default();
     inner.td_name = "authenticationCodeTypeCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthenticationCodeTypeCallBuilder { inner }
+    RTDAuthenticationCodeTypeCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authenticationCodeTypeCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.length == 0 { missing.push("length".to_string()); }
+    missing
   }
 
   pub fn length(&self) -> i64 { self.length }
@@ -153,15 +190,25 @@ impl AuthenticationCodeTypeCall {
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeCallBuilder {
-  inner: AuthenticationCodeTypeCall
+  inner: AuthenticationCodeTypeCall,
+  set: [bool; 1]
 }
 
 impl RTDAuthenticationCodeTypeCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("length".to_string()); }
+    crate::types::_common::check_missing("authenticationCodeTypeCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[0] = true;
     self
   }
 
@@ -212,7 +259,16 @@ impl AuthenticationCodeTypeFlashCall {
     let mut inner = AuthenticationCodeTypeFlashCall::default();
     inner.td_name = "authenticationCodeTypeFlashCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthenticationCodeTypeFlashCallBuilder { inner }
+    RTDAuthenticationCodeTypeFlashCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authenticationCodeTypeFlashCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.pattern.is_empty() { missing.push("pattern".to_string()); }
+    missing
   }
 
   pub fn pattern(&self) -> &String { &self.pattern }
@@ -221,15 +277,25 @@ impl AuthenticationCodeTypeFlashCall {
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeFlashCallBuilder {
-  inner: AuthenticationCodeTypeFlashCall
+  inner: AuthenticationCodeTypeFlashCall,
+  set: [bool; 1]
 }
 
 impl RTDAuthenticationCodeTypeFlashCallBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeFlashCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeFlashCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("pattern".to_string()); }
+    crate::types::_common::check_missing("authenticationCodeTypeFlashCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn pattern<T: AsRef<str>>(&mut self, pattern: T) -> &mut Self {
     self.inner.pattern = pattern.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -280,7 +346,16 @@ impl AuthenticationCodeTypeSms {
     let mut inner = AuthenticationCodeTypeSms::default();
     inner.td_name = "authenticationCodeTypeSms".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthenticationCodeTypeSmsBuilder { inner }
+    RTDAuthenticationCodeTypeSmsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authenticationCodeTypeSms", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.length == 0 { missing.push("length".to_string()); }
+    missing
   }
 
   pub fn length(&self) -> i64 { self.length }
@@ -289,15 +364,25 @@ impl AuthenticationCodeTypeSms {
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeSmsBuilder {
-  inner: AuthenticationCodeTypeSms
+  inner: AuthenticationCodeTypeSms,
+  set: [bool; 1]
 }
 
 impl RTDAuthenticationCodeTypeSmsBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeSms { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeSms> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("length".to_string()); }
+    crate::types::_common::check_missing("authenticationCodeTypeSms", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[0] = true;
     self
   }
 
@@ -348,7 +433,16 @@ impl AuthenticationCodeTypeTelegramMessage {
     let mut inner = AuthenticationCodeTypeTelegramMessage::default();
     inner.td_name = "authenticationCodeTypeTelegramMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthenticationCodeTypeTelegramMessageBuilder { inner }
+    RTDAuthenticationCodeTypeTelegramMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authenticationCodeTypeTelegramMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.length == 0 { missing.push("length".to_string()); }
+    missing
   }
 
   pub fn length(&self) -> i64 { self.length }
@@ -357,15 +451,25 @@ impl AuthenticationCodeTypeTelegramMessage {
 
 #[doc(hidden)]
 pub struct RTDAuthenticationCodeTypeTelegramMessageBuilder {
-  inner: AuthenticationCodeTypeTelegramMessage
+  inner: AuthenticationCodeTypeTelegramMessage,
+  set: [bool; 1]
 }
 
 impl RTDAuthenticationCodeTypeTelegramMessageBuilder {
   pub fn build(&self) -> AuthenticationCodeTypeTelegramMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthenticationCodeTypeTelegramMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("length".to_string()); }
+    crate::types::_common::check_missing("authenticationCodeTypeTelegramMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[0] = true;
     self
   }
 
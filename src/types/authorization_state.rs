@@ -19,6 +19,9 @@ pub trait TDAuthorizationState: Debug + RObject {}
 #[serde(untagged)]
 pub enum AuthorizationState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client
   Closed(AuthorizationStateClosed),
   /// TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received
@@ -41,8 +44,6 @@ pub enum AuthorizationState {
   WaitRegistration(AuthorizationStateWaitRegistration),
   /// TDLib needs TdlibParameters for initialization
   WaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
-  /// Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state. Can be called before initialization
-  GetAuthorizationState(GetAuthorizationState),
 
 }
 
@@ -52,7 +53,6 @@ impl Default for AuthorizationState {
 
 impl<'de> Deserialize<'de> for AuthorizationState {
   fn deserialize<D>(deserializer: D) -> Result<AuthorizationState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       AuthorizationState,
       (authorizationStateClosed, Closed);
@@ -66,7 +66,6 @@ impl<'de> Deserialize<'de> for AuthorizationState {
       (authorizationStateWaitPhoneNumber, WaitPhoneNumber);
       (authorizationStateWaitRegistration, WaitRegistration);
       (authorizationStateWaitTdlibParameters, WaitTdlibParameters);
-      (getAuthorizationState, GetAuthorizationState);
 
     )(deserializer)
   }
@@ -86,7 +85,6 @@ impl RObject for AuthorizationState {
       AuthorizationState::WaitPhoneNumber(t) => t.td_name(),
       AuthorizationState::WaitRegistration(t) => t.td_name(),
       AuthorizationState::WaitTdlibParameters(t) => t.td_name(),
-      AuthorizationState::GetAuthorizationState(t) => t.td_name(),
 
       _ => "-1",
     }
@@ -104,7 +102,7 @@ impl RObject for AuthorizationState {
       AuthorizationState::WaitPhoneNumber(t) => t.extra(),
       AuthorizationState::WaitRegistration(t) => t.extra(),
       AuthorizationState::WaitTdlibParameters(t) => t.extra(),
-      AuthorizationState::GetAuthorizationState(t) => t.extra(),
+      AuthorizationState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -115,6 +113,22 @@ impl RObject for AuthorizationState {
 impl AuthorizationState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let AuthorizationState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      AuthorizationState::Closed(t) => t._missing_fields(),
+      AuthorizationState::Closing(t) => t._missing_fields(),
+      AuthorizationState::LoggingOut(t) => t._missing_fields(),
+      AuthorizationState::Ready(t) => t._missing_fields(),
+      AuthorizationState::WaitCode(t) => t._missing_fields(),
+      AuthorizationState::WaitEncryptionKey(t) => t._missing_fields(),
+      AuthorizationState::WaitOtherDeviceConfirmation(t) => t._missing_fields(),
+      AuthorizationState::WaitPassword(t) => t._missing_fields(),
+      AuthorizationState::WaitPhoneNumber(t) => t._missing_fields(),
+      AuthorizationState::WaitRegistration(t) => t._missing_fields(),
+      AuthorizationState::WaitTdlibParameters(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_closed(&self) -> bool { if let AuthorizationState::Closed(_) = self { true } else { false } }
   pub fn is_closing(&self) -> bool { if let AuthorizationState::Closing(_) = self { true } else { false } }
@@ -127,7 +141,6 @@ impl AuthorizationState {
   pub fn is_wait_phone_number(&self) -> bool { if let AuthorizationState::WaitPhoneNumber(_) = self { true } else { false } }
   pub fn is_wait_registration(&self) -> bool { if let AuthorizationState::WaitRegistration(_) = self { true } else { false } }
   pub fn is_wait_tdlib_parameters(&self) -> bool { if let AuthorizationState::WaitTdlibParameters(_) = self { true } else { false } }
-  pub fn is_get_authorization_state(&self) -> bool { if let AuthorizationState::GetAuthorizationState(_) = self { true } else { false } }
 
   pub fn on_closed<F: FnOnce(&AuthorizationStateClosed)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closed(t) = self { fnc(t) }; self }
   pub fn on_closing<F: FnOnce(&AuthorizationStateClosing)>(&self, fnc: F) -> &Self { if let AuthorizationState::Closing(t) = self { fnc(t) }; self }
@@ -140,7 +153,6 @@ impl AuthorizationState {
   pub fn on_wait_phone_number<F: FnOnce(&AuthorizationStateWaitPhoneNumber)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitPhoneNumber(t) = self { fnc(t) }; self }
   pub fn on_wait_registration<F: FnOnce(&AuthorizationStateWaitRegistration)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitRegistration(t) = self { fnc(t) }; self }
   pub fn on_wait_tdlib_parameters<F: FnOnce(&AuthorizationStateWaitTdlibParameters)>(&self, fnc: F) -> &Self { if let AuthorizationState::WaitTdlibParameters(t) = self { fnc(t) }; self }
-  pub fn on_get_authorization_state<F: FnOnce(&GetAuthorizationState)>(&self, fnc: F) -> &Self { if let AuthorizationState::GetAuthorizationState(t) = self { fnc(t) }; self }
 
   pub fn as_closed(&self) -> Option<&AuthorizationStateClosed> { if let AuthorizationState::Closed(t) = self { return Some(t) } None }
   pub fn as_closing(&self) -> Option<&AuthorizationStateClosing> { if let AuthorizationState::Closing(t) = self { return Some(t) } None }
@@ -153,7 +165,6 @@ impl AuthorizationState {
   pub fn as_wait_phone_number(&self) -> Option<&AuthorizationStateWaitPhoneNumber> { if let AuthorizationState::WaitPhoneNumber(t) = self { return Some(t) } None }
   pub fn as_wait_registration(&self) -> Option<&AuthorizationStateWaitRegistration> { if let AuthorizationState::WaitRegistration(t) = self { return Some(t) } None }
   pub fn as_wait_tdlib_parameters(&self) -> Option<&AuthorizationStateWaitTdlibParameters> { if let AuthorizationState::WaitTdlibParameters(t) = self { return Some(t) } None }
-  pub fn as_get_authorization_state(&self) -> Option<&GetAuthorizationState> { if let AuthorizationState::GetAuthorizationState(t) = self { return Some(t) } None }
 
 
 
@@ -179,14 +190,56 @@ impl AuthorizationState {
 
   pub fn wait_tdlib_parameters<T: AsRef<AuthorizationStateWaitTdlibParameters>>(t: T) -> Self { AuthorizationState::WaitTdlibParameters(t.as_ref().clone()) }
 
-  pub fn get_authorization_state<T: AsRef<GetAuthorizationState>>(t: T) -> Self { AuthorizationState::GetAuthorizationState(t.as_ref().clone()) }
-
 }
 
 impl AsRef<AuthorizationState> for AuthorizationState {
   fn as_ref(&self) -> &AuthorizationState { self }
 }
 
+impl RVariant<AuthorizationState> for AuthorizationStateClosed {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::Closed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateClosing {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::Closing(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateLoggingOut {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::LoggingOut(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateReady {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::Ready(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitCode {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitCode(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitEncryptionKey {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitEncryptionKey(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitOtherDeviceConfirmation {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitOtherDeviceConfirmation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitPassword {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitPassword(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitPhoneNumber {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitPhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitRegistration {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<AuthorizationState> for AuthorizationStateWaitTdlibParameters {
+  fn from_class(class: &AuthorizationState) -> Option<&Self> { match class { AuthorizationState::WaitTdlibParameters(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -225,6 +278,11 @@ impl AuthorizationStateClosed {
     RTDAuthorizationStateClosedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateClosed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -235,6 +293,9 @@ pub struct RTDAuthorizationStateClosedBuilder {
 impl RTDAuthorizationStateClosedBuilder {
   pub fn build(&self) -> AuthorizationStateClosed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateClosed> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateClosed> for AuthorizationStateClosed {
@@ -283,6 +344,11 @@ impl AuthorizationStateClosing {
     RTDAuthorizationStateClosingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateClosing", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -293,6 +359,9 @@ pub struct RTDAuthorizationStateClosingBuilder {
 impl RTDAuthorizationStateClosingBuilder {
   pub fn build(&self) -> AuthorizationStateClosing { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateClosing> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateClosing> for AuthorizationStateClosing {
@@ -341,6 +410,11 @@ impl AuthorizationStateLoggingOut {
     RTDAuthorizationStateLoggingOutBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateLoggingOut", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -351,6 +425,9 @@ pub struct RTDAuthorizationStateLoggingOutBuilder {
 impl RTDAuthorizationStateLoggingOutBuilder {
   pub fn build(&self) -> AuthorizationStateLoggingOut { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateLoggingOut> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateLoggingOut> for AuthorizationStateLoggingOut {
@@ -399,6 +476,11 @@ impl AuthorizationStateReady {
     RTDAuthorizationStateReadyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateReady", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -409,6 +491,9 @@ pub struct RTDAuthorizationStateReadyBuilder {
 impl RTDAuthorizationStateReadyBuilder {
   pub fn build(&self) -> AuthorizationStateReady { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateReady> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateReady> for AuthorizationStateReady {
@@ -456,7 +541,16 @@ impl AuthorizationStateWaitCode {
     let mut inner = AuthorizationStateWaitCode::default();
     inner.td_name = "authorizationStateWaitCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthorizationStateWaitCodeBuilder { inner }
+    RTDAuthorizationStateWaitCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("code_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("code_info", self.code_info._missing_fields())); }
+    missing
   }
 
   pub fn code_info(&self) -> &AuthenticationCodeInfo { &self.code_info }
@@ -465,15 +559,25 @@ impl AuthorizationStateWaitCode {
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitCodeBuilder {
-  inner: AuthorizationStateWaitCode
+  inner: AuthorizationStateWaitCode,
+  set: [bool; 1]
 }
 
 impl RTDAuthorizationStateWaitCodeBuilder {
   pub fn build(&self) -> AuthorizationStateWaitCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("code_info", self.inner.code_info._missing_fields())); }
+    crate::types::_common::check_missing("authorizationStateWaitCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code_info<T: AsRef<AuthenticationCodeInfo>>(&mut self, code_info: T) -> &mut Self {
     self.inner.code_info = code_info.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -527,6 +631,11 @@ impl AuthorizationStateWaitEncryptionKey {
     RTDAuthorizationStateWaitEncryptionKeyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitEncryptionKey", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_encrypted(&self) -> bool { self.is_encrypted }
 
 }
@@ -539,6 +648,9 @@ pub struct RTDAuthorizationStateWaitEncryptionKeyBuilder {
 impl RTDAuthorizationStateWaitEncryptionKeyBuilder {
   pub fn build(&self) -> AuthorizationStateWaitEncryptionKey { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitEncryptionKey> { Ok(self.build()) }
+
    
   pub fn is_encrypted(&mut self, is_encrypted: bool) -> &mut Self {
     self.inner.is_encrypted = is_encrypted;
@@ -592,7 +704,16 @@ impl AuthorizationStateWaitOtherDeviceConfirmation {
     let mut inner = AuthorizationStateWaitOtherDeviceConfirmation::default();
     inner.td_name = "authorizationStateWaitOtherDeviceConfirmation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder { inner }
+    RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitOtherDeviceConfirmation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.link.is_empty() { missing.push("link".to_string()); }
+    missing
   }
 
   pub fn link(&self) -> &String { &self.link }
@@ -601,15 +722,25 @@ impl AuthorizationStateWaitOtherDeviceConfirmation {
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
-  inner: AuthorizationStateWaitOtherDeviceConfirmation
+  inner: AuthorizationStateWaitOtherDeviceConfirmation,
+  set: [bool; 1]
 }
 
 impl RTDAuthorizationStateWaitOtherDeviceConfirmationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitOtherDeviceConfirmation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitOtherDeviceConfirmation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("link".to_string()); }
+    crate::types::_common::check_missing("authorizationStateWaitOtherDeviceConfirmation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -664,7 +795,16 @@ impl AuthorizationStateWaitPassword {
     let mut inner = AuthorizationStateWaitPassword::default();
     inner.td_name = "authorizationStateWaitPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthorizationStateWaitPasswordBuilder { inner }
+    RTDAuthorizationStateWaitPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.recovery_email_address_pattern.is_empty() { missing.push("recovery_email_address_pattern".to_string()); }
+    missing
   }
 
   pub fn password_hint(&self) -> &String { &self.password_hint }
@@ -677,12 +817,21 @@ impl AuthorizationStateWaitPassword {
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitPasswordBuilder {
-  inner: AuthorizationStateWaitPassword
+  inner: AuthorizationStateWaitPassword,
+  set: [bool; 1]
 }
 
 impl RTDAuthorizationStateWaitPasswordBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("recovery_email_address_pattern".to_string()); }
+    crate::types::_common::check_missing("authorizationStateWaitPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password_hint<T: AsRef<str>>(&mut self, password_hint: T) -> &mut Self {
     self.inner.password_hint = password_hint.as_ref().to_string();
@@ -698,6 +847,7 @@ impl RTDAuthorizationStateWaitPasswordBuilder {
    
   pub fn recovery_email_address_pattern<T: AsRef<str>>(&mut self, recovery_email_address_pattern: T) -> &mut Self {
     self.inner.recovery_email_address_pattern = recovery_email_address_pattern.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -749,6 +899,11 @@ impl AuthorizationStateWaitPhoneNumber {
     RTDAuthorizationStateWaitPhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -759,6 +914,9 @@ pub struct RTDAuthorizationStateWaitPhoneNumberBuilder {
 impl RTDAuthorizationStateWaitPhoneNumberBuilder {
   pub fn build(&self) -> AuthorizationStateWaitPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitPhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateWaitPhoneNumber> for AuthorizationStateWaitPhoneNumber {
@@ -806,7 +964,16 @@ impl AuthorizationStateWaitRegistration {
     let mut inner = AuthorizationStateWaitRegistration::default();
     inner.td_name = "authorizationStateWaitRegistration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAuthorizationStateWaitRegistrationBuilder { inner }
+    RTDAuthorizationStateWaitRegistrationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("terms_of_service".to_string()); } else { missing.extend(crate::types::_common::missing_in("terms_of_service", self.terms_of_service._missing_fields())); }
+    missing
   }
 
   pub fn terms_of_service(&self) -> &TermsOfService { &self.terms_of_service }
@@ -815,15 +982,25 @@ impl AuthorizationStateWaitRegistration {
 
 #[doc(hidden)]
 pub struct RTDAuthorizationStateWaitRegistrationBuilder {
-  inner: AuthorizationStateWaitRegistration
+  inner: AuthorizationStateWaitRegistration,
+  set: [bool; 1]
 }
 
 impl RTDAuthorizationStateWaitRegistrationBuilder {
   pub fn build(&self) -> AuthorizationStateWaitRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitRegistration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("terms_of_service".to_string()); } else { missing.extend(crate::types::_common::missing_in("terms_of_service", self.inner.terms_of_service._missing_fields())); }
+    crate::types::_common::check_missing("authorizationStateWaitRegistration", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
     self.inner.terms_of_service = terms_of_service.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -875,6 +1052,11 @@ impl AuthorizationStateWaitTdlibParameters {
     RTDAuthorizationStateWaitTdlibParametersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("authorizationStateWaitTdlibParameters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -885,6 +1067,9 @@ pub struct RTDAuthorizationStateWaitTdlibParametersBuilder {
 impl RTDAuthorizationStateWaitTdlibParametersBuilder {
   pub fn build(&self) -> AuthorizationStateWaitTdlibParameters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AuthorizationStateWaitTdlibParameters> { Ok(self.build()) }
+
 }
 
 impl AsRef<AuthorizationStateWaitTdlibParameters> for AuthorizationStateWaitTdlibParameters {
@@ -48,7 +48,19 @@ impl AutoDownloadSettings {
     let mut inner = AutoDownloadSettings::default();
     inner.td_name = "autoDownloadSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAutoDownloadSettingsBuilder { inner }
+    RTDAutoDownloadSettingsBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("autoDownloadSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.max_photo_file_size == 0 { missing.push("max_photo_file_size".to_string()); }
+    if self.max_video_file_size == 0 { missing.push("max_video_file_size".to_string()); }
+    if self.max_other_file_size == 0 { missing.push("max_other_file_size".to_string()); }
+    if self.video_upload_bitrate == 0 { missing.push("video_upload_bitrate".to_string()); }
+    missing
   }
 
   pub fn is_auto_download_enabled(&self) -> bool { self.is_auto_download_enabled }
@@ -71,12 +83,24 @@ impl AutoDownloadSettings {
 
 #[doc(hidden)]
 pub struct RTDAutoDownloadSettingsBuilder {
-  inner: AutoDownloadSettings
+  inner: AutoDownloadSettings,
+  set: [bool; 4]
 }
 
 impl RTDAutoDownloadSettingsBuilder {
   pub fn build(&self) -> AutoDownloadSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AutoDownloadSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("max_photo_file_size".to_string()); }
+    if !self.set[1] { missing.push("max_video_file_size".to_string()); }
+    if !self.set[2] { missing.push("max_other_file_size".to_string()); }
+    if !self.set[3] { missing.push("video_upload_bitrate".to_string()); }
+    crate::types::_common::check_missing("autoDownloadSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_auto_download_enabled(&mut self, is_auto_download_enabled: bool) -> &mut Self {
     self.inner.is_auto_download_enabled = is_auto_download_enabled;
@@ -86,24 +110,28 @@ impl RTDAutoDownloadSettingsBuilder {
    
   pub fn max_photo_file_size(&mut self, max_photo_file_size: i64) -> &mut Self {
     self.inner.max_photo_file_size = max_photo_file_size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn max_video_file_size(&mut self, max_video_file_size: i64) -> &mut Self {
     self.inner.max_video_file_size = max_video_file_size;
+    self.set[1] = true;
     self
   }
 
    
   pub fn max_other_file_size(&mut self, max_other_file_size: i64) -> &mut Self {
     self.inner.max_other_file_size = max_other_file_size;
+    self.set[2] = true;
     self
   }
 
    
   pub fn video_upload_bitrate(&mut self, video_upload_bitrate: i64) -> &mut Self {
     self.inner.video_upload_bitrate = video_upload_bitrate;
+    self.set[3] = true;
     self
   }
 
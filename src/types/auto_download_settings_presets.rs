@@ -38,7 +38,18 @@ impl AutoDownloadSettingsPresets {
     let mut inner = AutoDownloadSettingsPresets::default();
     inner.td_name = "autoDownloadSettingsPresets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAutoDownloadSettingsPresetsBuilder { inner }
+    RTDAutoDownloadSettingsPresetsBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("autoDownloadSettingsPresets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("low".to_string()); } else { missing.extend(crate::types::_common::missing_in("low", self.low._missing_fields())); }
+    if false { missing.push("medium".to_string()); } else { missing.extend(crate::types::_common::missing_in("medium", self.medium._missing_fields())); }
+    if false { missing.push("high".to_string()); } else { missing.extend(crate::types::_common::missing_in("high", self.high._missing_fields())); }
+    missing
   }
 
   pub fn low(&self) -> &AutoDownloadSettings { &self.low }
@@ -51,27 +62,41 @@ impl AutoDownloadSettingsPresets {
 
 #[doc(hidden)]
 pub struct RTDAutoDownloadSettingsPresetsBuilder {
-  inner: AutoDownloadSettingsPresets
+  inner: AutoDownloadSettingsPresets,
+  set: [bool; 3]
 }
 
 impl RTDAutoDownloadSettingsPresetsBuilder {
   pub fn build(&self) -> AutoDownloadSettingsPresets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AutoDownloadSettingsPresets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("low".to_string()); } else { missing.extend(crate::types::_common::missing_in("low", self.inner.low._missing_fields())); }
+    if !self.set[1] { missing.push("medium".to_string()); } else { missing.extend(crate::types::_common::missing_in("medium", self.inner.medium._missing_fields())); }
+    if !self.set[2] { missing.push("high".to_string()); } else { missing.extend(crate::types::_common::missing_in("high", self.inner.high._missing_fields())); }
+    crate::types::_common::check_missing("autoDownloadSettingsPresets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn low<T: AsRef<AutoDownloadSettings>>(&mut self, low: T) -> &mut Self {
     self.inner.low = low.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn medium<T: AsRef<AutoDownloadSettings>>(&mut self, medium: T) -> &mut Self {
     self.inner.medium = medium.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn high<T: AsRef<AutoDownloadSettings>>(&mut self, high: T) -> &mut Self {
     self.inner.high = high.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -16,7 +16,7 @@ pub struct Background {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique background identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// True, if this is one of default backgrounds
   is_default: bool,
   /// True, if the background is dark and is recommended to be used with dark theme
@@ -44,10 +44,21 @@ impl Background {
     let mut inner = Background::default();
     inner.td_name = "background".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBackgroundBuilder { inner }
+    RTDBackgroundBuilder { inner, set: [false; 2] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("background", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_default(&self) -> bool { self.is_default }
 
@@ -63,15 +74,27 @@ impl Background {
 
 #[doc(hidden)]
 pub struct RTDBackgroundBuilder {
-  inner: Background
+  inner: Background,
+  set: [bool; 2]
 }
 
 impl RTDBackgroundBuilder {
   pub fn build(&self) -> Background { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Background> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("name".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("background", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -90,6 +113,7 @@ impl RTDBackgroundBuilder {
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
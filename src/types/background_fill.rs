@@ -19,6 +19,9 @@ pub trait TDBackgroundFill: Debug + RObject {}
 #[serde(untagged)]
 pub enum BackgroundFill {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Describes a gradient fill of a background
   Gradient(BackgroundFillGradient),
   /// Describes a solid fill of a background
@@ -32,7 +35,6 @@ impl Default for BackgroundFill {
 
 impl<'de> Deserialize<'de> for BackgroundFill {
   fn deserialize<D>(deserializer: D) -> Result<BackgroundFill, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       BackgroundFill,
       (backgroundFillGradient, Gradient);
@@ -55,6 +57,7 @@ impl RObject for BackgroundFill {
     match self {
       BackgroundFill::Gradient(t) => t.extra(),
       BackgroundFill::Solid(t) => t.extra(),
+      BackgroundFill::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for BackgroundFill {
 impl BackgroundFill {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundFill::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      BackgroundFill::Gradient(t) => t._missing_fields(),
+      BackgroundFill::Solid(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_gradient(&self) -> bool { if let BackgroundFill::Gradient(_) = self { true } else { false } }
   pub fn is_solid(&self) -> bool { if let BackgroundFill::Solid(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<BackgroundFill> for BackgroundFill {
   fn as_ref(&self) -> &BackgroundFill { self }
 }
 
+impl RVariant<BackgroundFill> for BackgroundFillGradient {
+  fn from_class(class: &BackgroundFill) -> Option<&Self> { match class { BackgroundFill::Gradient(t) => Some(t), _ => None } }
+}
+
+impl RVariant<BackgroundFill> for BackgroundFillSolid {
+  fn from_class(class: &BackgroundFill) -> Option<&Self> { match class { BackgroundFill::Solid(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -128,7 +146,18 @@ impl BackgroundFillGradient {
     let mut inner = BackgroundFillGradient::default();
     inner.td_name = "backgroundFillGradient".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBackgroundFillGradientBuilder { inner }
+    RTDBackgroundFillGradientBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgroundFillGradient", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.top_color == 0 { missing.push("top_color".to_string()); }
+    if self.bottom_color == 0 { missing.push("bottom_color".to_string()); }
+    if self.rotation_angle == 0 { missing.push("rotation_angle".to_string()); }
+    missing
   }
 
   pub fn top_color(&self) -> i64 { self.top_color }
@@ -141,27 +170,41 @@ impl BackgroundFillGradient {
 
 #[doc(hidden)]
 pub struct RTDBackgroundFillGradientBuilder {
-  inner: BackgroundFillGradient
+  inner: BackgroundFillGradient,
+  set: [bool; 3]
 }
 
 impl RTDBackgroundFillGradientBuilder {
   pub fn build(&self) -> BackgroundFillGradient { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BackgroundFillGradient> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("top_color".to_string()); }
+    if !self.set[1] { missing.push("bottom_color".to_string()); }
+    if !self.set[2] { missing.push("rotation_angle".to_string()); }
+    crate::types::_common::check_missing("backgroundFillGradient", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn top_color(&mut self, top_color: i64) -> &mut Self {
     self.inner.top_color = top_color;
+    self.set[0] = true;
     self
   }
 
    
   pub fn bottom_color(&mut self, bottom_color: i64) -> &mut Self {
     self.inner.bottom_color = bottom_color;
+    self.set[1] = true;
     self
   }
 
    
   pub fn rotation_angle(&mut self, rotation_angle: i64) -> &mut Self {
     self.inner.rotation_angle = rotation_angle;
+    self.set[2] = true;
     self
   }
 
@@ -212,7 +255,16 @@ impl BackgroundFillSolid {
     let mut inner = BackgroundFillSolid::default();
     inner.td_name = "backgroundFillSolid".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBackgroundFillSolidBuilder { inner }
+    RTDBackgroundFillSolidBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgroundFillSolid", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.color == 0 { missing.push("color".to_string()); }
+    missing
   }
 
   pub fn color(&self) -> i64 { self.color }
@@ -221,15 +273,25 @@ impl BackgroundFillSolid {
 
 #[doc(hidden)]
 pub struct RTDBackgroundFillSolidBuilder {
-  inner: BackgroundFillSolid
+  inner: BackgroundFillSolid,
+  set: [bool; 1]
 }
 
 impl RTDBackgroundFillSolidBuilder {
   pub fn build(&self) -> BackgroundFillSolid { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BackgroundFillSolid> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("color".to_string()); }
+    crate::types::_common::check_missing("backgroundFillSolid", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn color(&mut self, color: i64) -> &mut Self {
     self.inner.color = color;
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDBackgroundType: Debug + RObject {}
 #[serde(untagged)]
 pub enum BackgroundType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A filled background
   Fill(BackgroundTypeFill),
   /// A PNG or TGV (gzipped subset of SVG with MIME type "application/x-tgwallpattern") pattern to be combined with the background fill chosen by the user
@@ -34,7 +37,6 @@ impl Default for BackgroundType {
 
 impl<'de> Deserialize<'de> for BackgroundType {
   fn deserialize<D>(deserializer: D) -> Result<BackgroundType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       BackgroundType,
       (backgroundTypeFill, Fill);
@@ -60,6 +62,7 @@ impl RObject for BackgroundType {
       BackgroundType::Fill(t) => t.extra(),
       BackgroundType::Pattern(t) => t.extra(),
       BackgroundType::Wallpaper(t) => t.extra(),
+      BackgroundType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for BackgroundType {
 impl BackgroundType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let BackgroundType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      BackgroundType::Fill(t) => t._missing_fields(),
+      BackgroundType::Pattern(t) => t._missing_fields(),
+      BackgroundType::Wallpaper(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_fill(&self) -> bool { if let BackgroundType::Fill(_) = self { true } else { false } }
   pub fn is_pattern(&self) -> bool { if let BackgroundType::Pattern(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<BackgroundType> for BackgroundType {
   fn as_ref(&self) -> &BackgroundType { self }
 }
 
+impl RVariant<BackgroundType> for BackgroundTypeFill {
+  fn from_class(class: &BackgroundType) -> Option<&Self> { match class { BackgroundType::Fill(t) => Some(t), _ => None } }
+}
+
+impl RVariant<BackgroundType> for BackgroundTypePattern {
+  fn from_class(class: &BackgroundType) -> Option<&Self> { match class { BackgroundType::Pattern(t) => Some(t), _ => None } }
+}
+
+impl RVariant<BackgroundType> for BackgroundTypeWallpaper {
+  fn from_class(class: &BackgroundType) -> Option<&Self> { match class { BackgroundType::Wallpaper(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -137,6 +160,15 @@ impl BackgroundTypeFill {
     RTDBackgroundTypeFillBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgroundTypeFill", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.fill._is_default() { missing.push("fill".to_string()); } else { missing.extend(crate::types::_common::missing_in("fill", self.fill._missing_fields())); }
+    missing
+  }
+
   pub fn fill(&self) -> &BackgroundFill { &self.fill }
 
 }
@@ -149,6 +181,14 @@ pub struct RTDBackgroundTypeFillBuilder {
 impl RTDBackgroundTypeFillBuilder {
   pub fn build(&self) -> BackgroundTypeFill { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BackgroundTypeFill> {
+    let mut missing = vec![];
+    if self.inner.fill._is_default() { missing.push("fill".to_string()); } else { missing.extend(crate::types::_common::missing_in("fill", self.inner.fill._missing_fields())); }
+    crate::types::_common::check_missing("backgroundTypeFill", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -206,7 +246,17 @@ impl BackgroundTypePattern {
     let mut inner = BackgroundTypePattern::default();
     inner.td_name = "backgroundTypePattern".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBackgroundTypePatternBuilder { inner }
+    RTDBackgroundTypePatternBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgroundTypePattern", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.fill._is_default() { missing.push("fill".to_string()); } else { missing.extend(crate::types::_common::missing_in("fill", self.fill._missing_fields())); }
+    if self.intensity == 0 { missing.push("intensity".to_string()); }
+    missing
   }
 
   pub fn fill(&self) -> &BackgroundFill { &self.fill }
@@ -219,12 +269,22 @@ impl BackgroundTypePattern {
 
 #[doc(hidden)]
 pub struct RTDBackgroundTypePatternBuilder {
-  inner: BackgroundTypePattern
+  inner: BackgroundTypePattern,
+  set: [bool; 1]
 }
 
 impl RTDBackgroundTypePatternBuilder {
   pub fn build(&self) -> BackgroundTypePattern { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BackgroundTypePattern> {
+    let mut missing = vec![];
+    if self.inner.fill._is_default() { missing.push("fill".to_string()); } else { missing.extend(crate::types::_common::missing_in("fill", self.inner.fill._missing_fields())); }
+    if !self.set[0] { missing.push("intensity".to_string()); }
+    crate::types::_common::check_missing("backgroundTypePattern", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn fill<T: AsRef<BackgroundFill>>(&mut self, fill: T) -> &mut Self {
     self.inner.fill = fill.as_ref().clone();
@@ -234,6 +294,7 @@ impl RTDBackgroundTypePatternBuilder {
    
   pub fn intensity(&mut self, intensity: i64) -> &mut Self {
     self.inner.intensity = intensity;
+    self.set[0] = true;
     self
   }
 
@@ -295,6 +356,11 @@ impl BackgroundTypeWallpaper {
     RTDBackgroundTypeWallpaperBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgroundTypeWallpaper", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_blurred(&self) -> bool { self.is_blurred }
 
   pub fn is_moving(&self) -> bool { self.is_moving }
@@ -309,6 +375,9 @@ pub struct RTDBackgroundTypeWallpaperBuilder {
 impl RTDBackgroundTypeWallpaperBuilder {
   pub fn build(&self) -> BackgroundTypeWallpaper { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BackgroundTypeWallpaper> { Ok(self.build()) }
+
    
   pub fn is_blurred(&mut self, is_blurred: bool) -> &mut Self {
     self.inner.is_blurred = is_blurred;
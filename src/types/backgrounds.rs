@@ -37,6 +37,11 @@ impl Backgrounds {
     RTDBackgroundsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("backgrounds", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn backgrounds(&self) -> &Vec<Background> { &self.backgrounds }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDBackgroundsBuilder {
 impl RTDBackgroundsBuilder {
   pub fn build(&self) -> Backgrounds { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Backgrounds> { Ok(self.build()) }
+
    
   pub fn backgrounds(&mut self, backgrounds: Vec<Background>) -> &mut Self {
     self.inner.backgrounds = backgrounds;
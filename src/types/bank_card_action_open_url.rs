@@ -36,7 +36,17 @@ impl BankCardActionOpenUrl {
     let mut inner = BankCardActionOpenUrl::default();
     inner.td_name = "bankCardActionOpenUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBankCardActionOpenUrlBuilder { inner }
+    RTDBankCardActionOpenUrlBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("bankCardActionOpenUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -47,21 +57,33 @@ impl BankCardActionOpenUrl {
 
 #[doc(hidden)]
 pub struct RTDBankCardActionOpenUrlBuilder {
-  inner: BankCardActionOpenUrl
+  inner: BankCardActionOpenUrl,
+  set: [bool; 2]
 }
 
 impl RTDBankCardActionOpenUrlBuilder {
   pub fn build(&self) -> BankCardActionOpenUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BankCardActionOpenUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("bankCardActionOpenUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl BankCardInfo {
     let mut inner = BankCardInfo::default();
     inner.td_name = "bankCardInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBankCardInfoBuilder { inner }
+    RTDBankCardInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("bankCardInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -47,15 +56,25 @@ impl BankCardInfo {
 
 #[doc(hidden)]
 pub struct RTDBankCardInfoBuilder {
-  inner: BankCardInfo
+  inner: BankCardInfo,
+  set: [bool; 1]
 }
 
 impl RTDBankCardInfoBuilder {
   pub fn build(&self) -> BankCardInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BankCardInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("bankCardInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
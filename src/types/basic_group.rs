@@ -42,7 +42,18 @@ impl BasicGroup {
     let mut inner = BasicGroup::default();
     inner.td_name = "basicGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBasicGroupBuilder { inner }
+    RTDBasicGroupBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("basicGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.member_count == 0 { missing.push("member_count".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -59,21 +70,34 @@ impl BasicGroup {
 
 #[doc(hidden)]
 pub struct RTDBasicGroupBuilder {
-  inner: BasicGroup
+  inner: BasicGroup,
+  set: [bool; 2]
 }
 
 impl RTDBasicGroupBuilder {
   pub fn build(&self) -> BasicGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BasicGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("member_count".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    crate::types::_common::check_missing("basicGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn member_count(&mut self, member_count: i64) -> &mut Self {
     self.inner.member_count = member_count;
+    self.set[1] = true;
     self
   }
 
@@ -42,7 +42,16 @@ impl BasicGroupFullInfo {
     let mut inner = BasicGroupFullInfo::default();
     inner.td_name = "basicGroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBasicGroupFullInfoBuilder { inner }
+    RTDBasicGroupFullInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("basicGroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
@@ -59,12 +68,21 @@ impl BasicGroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDBasicGroupFullInfoBuilder {
-  inner: BasicGroupFullInfo
+  inner: BasicGroupFullInfo,
+  set: [bool; 1]
 }
 
 impl RTDBasicGroupFullInfoBuilder {
   pub fn build(&self) -> BasicGroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BasicGroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("basicGroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -74,6 +92,7 @@ impl RTDBasicGroupFullInfoBuilder {
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
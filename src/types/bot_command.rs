@@ -36,7 +36,17 @@ impl BotCommand {
     let mut inner = BotCommand::default();
     inner.td_name = "botCommand".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBotCommandBuilder { inner }
+    RTDBotCommandBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("botCommand", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.command.is_empty() { missing.push("command".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn command(&self) -> &String { &self.command }
@@ -47,21 +57,33 @@ impl BotCommand {
 
 #[doc(hidden)]
 pub struct RTDBotCommandBuilder {
-  inner: BotCommand
+  inner: BotCommand,
+  set: [bool; 2]
 }
 
 impl RTDBotCommandBuilder {
   pub fn build(&self) -> BotCommand { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BotCommand> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("command".to_string()); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("botCommand", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn command<T: AsRef<str>>(&mut self, command: T) -> &mut Self {
     self.inner.command = command.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
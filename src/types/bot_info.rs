@@ -36,7 +36,16 @@ impl BotInfo {
     let mut inner = BotInfo::default();
     inner.td_name = "botInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBotInfoBuilder { inner }
+    RTDBotInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("botInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn description(&self) -> &String { &self.description }
@@ -47,15 +56,25 @@ impl BotInfo {
 
 #[doc(hidden)]
 pub struct RTDBotInfoBuilder {
-  inner: BotInfo
+  inner: BotInfo,
+  set: [bool; 1]
 }
 
 impl RTDBotInfoBuilder {
   pub fn build(&self) -> BotInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BotInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("botInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
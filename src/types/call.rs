@@ -42,7 +42,18 @@ impl Call {
     let mut inner = Call::default();
     inner.td_name = "call".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallBuilder { inner }
+    RTDCallBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("call", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.state._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -59,21 +70,34 @@ impl Call {
 
 #[doc(hidden)]
 pub struct RTDCallBuilder {
-  inner: Call
+  inner: Call,
+  set: [bool; 2]
 }
 
 impl RTDCallBuilder {
   pub fn build(&self) -> Call { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Call> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if self.inner.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.inner.state._missing_fields())); }
+    crate::types::_common::check_missing("call", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
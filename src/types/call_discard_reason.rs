@@ -19,6 +19,9 @@ pub trait TDCallDiscardReason: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallDiscardReason {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The call was ended before the conversation started. It was declined by the other party
   Declined(CallDiscardReasonDeclined),
   /// The call was ended during the conversation because the users were disconnected
@@ -38,7 +41,6 @@ impl Default for CallDiscardReason {
 
 impl<'de> Deserialize<'de> for CallDiscardReason {
   fn deserialize<D>(deserializer: D) -> Result<CallDiscardReason, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CallDiscardReason,
       (callDiscardReasonDeclined, Declined);
@@ -70,6 +72,7 @@ impl RObject for CallDiscardReason {
       CallDiscardReason::Empty(t) => t.extra(),
       CallDiscardReason::HungUp(t) => t.extra(),
       CallDiscardReason::Missed(t) => t.extra(),
+      CallDiscardReason::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,6 +83,16 @@ impl RObject for CallDiscardReason {
 impl CallDiscardReason {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallDiscardReason::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CallDiscardReason::Declined(t) => t._missing_fields(),
+      CallDiscardReason::Disconnected(t) => t._missing_fields(),
+      CallDiscardReason::Empty(t) => t._missing_fields(),
+      CallDiscardReason::HungUp(t) => t._missing_fields(),
+      CallDiscardReason::Missed(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_declined(&self) -> bool { if let CallDiscardReason::Declined(_) = self { true } else { false } }
   pub fn is_disconnected(&self) -> bool { if let CallDiscardReason::Disconnected(_) = self { true } else { false } }
@@ -117,6 +130,26 @@ impl AsRef<CallDiscardReason> for CallDiscardReason {
   fn as_ref(&self) -> &CallDiscardReason { self }
 }
 
+impl RVariant<CallDiscardReason> for CallDiscardReasonDeclined {
+  fn from_class(class: &CallDiscardReason) -> Option<&Self> { match class { CallDiscardReason::Declined(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallDiscardReason> for CallDiscardReasonDisconnected {
+  fn from_class(class: &CallDiscardReason) -> Option<&Self> { match class { CallDiscardReason::Disconnected(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallDiscardReason> for CallDiscardReasonEmpty {
+  fn from_class(class: &CallDiscardReason) -> Option<&Self> { match class { CallDiscardReason::Empty(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallDiscardReason> for CallDiscardReasonHungUp {
+  fn from_class(class: &CallDiscardReason) -> Option<&Self> { match class { CallDiscardReason::HungUp(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallDiscardReason> for CallDiscardReasonMissed {
+  fn from_class(class: &CallDiscardReason) -> Option<&Self> { match class { CallDiscardReason::Missed(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -155,6 +188,11 @@ impl CallDiscardReasonDeclined {
     RTDCallDiscardReasonDeclinedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callDiscardReasonDeclined", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +203,9 @@ pub struct RTDCallDiscardReasonDeclinedBuilder {
 impl RTDCallDiscardReasonDeclinedBuilder {
   pub fn build(&self) -> CallDiscardReasonDeclined { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonDeclined> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallDiscardReasonDeclined> for CallDiscardReasonDeclined {
@@ -213,6 +254,11 @@ impl CallDiscardReasonDisconnected {
     RTDCallDiscardReasonDisconnectedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callDiscardReasonDisconnected", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +269,9 @@ pub struct RTDCallDiscardReasonDisconnectedBuilder {
 impl RTDCallDiscardReasonDisconnectedBuilder {
   pub fn build(&self) -> CallDiscardReasonDisconnected { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonDisconnected> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallDiscardReasonDisconnected> for CallDiscardReasonDisconnected {
@@ -271,6 +320,11 @@ impl CallDiscardReasonEmpty {
     RTDCallDiscardReasonEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callDiscardReasonEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +335,9 @@ pub struct RTDCallDiscardReasonEmptyBuilder {
 impl RTDCallDiscardReasonEmptyBuilder {
   pub fn build(&self) -> CallDiscardReasonEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallDiscardReasonEmpty> for CallDiscardReasonEmpty {
@@ -329,6 +386,11 @@ impl CallDiscardReasonHungUp {
     RTDCallDiscardReasonHungUpBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callDiscardReasonHungUp", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +401,9 @@ pub struct RTDCallDiscardReasonHungUpBuilder {
 impl RTDCallDiscardReasonHungUpBuilder {
   pub fn build(&self) -> CallDiscardReasonHungUp { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonHungUp> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallDiscardReasonHungUp> for CallDiscardReasonHungUp {
@@ -387,6 +452,11 @@ impl CallDiscardReasonMissed {
     RTDCallDiscardReasonMissedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callDiscardReasonMissed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +467,9 @@ pub struct RTDCallDiscardReasonMissedBuilder {
 impl RTDCallDiscardReasonMissedBuilder {
   pub fn build(&self) -> CallDiscardReasonMissed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallDiscardReasonMissed> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallDiscardReasonMissed> for CallDiscardReasonMissed {
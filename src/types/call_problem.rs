@@ -19,6 +19,9 @@ pub trait TDCallProblem: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallProblem {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The speech was distorted
   DistortedSpeech(CallProblemDistortedSpeech),
   /// The video was distorted
@@ -46,7 +49,6 @@ impl Default for CallProblem {
 
 impl<'de> Deserialize<'de> for CallProblem {
   fn deserialize<D>(deserializer: D) -> Result<CallProblem, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CallProblem,
       (callProblemDistortedSpeech, DistortedSpeech);
@@ -90,6 +92,7 @@ impl RObject for CallProblem {
       CallProblem::PixelatedVideo(t) => t.extra(),
       CallProblem::SilentLocal(t) => t.extra(),
       CallProblem::SilentRemote(t) => t.extra(),
+      CallProblem::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -100,6 +103,20 @@ impl RObject for CallProblem {
 impl CallProblem {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallProblem::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CallProblem::DistortedSpeech(t) => t._missing_fields(),
+      CallProblem::DistortedVideo(t) => t._missing_fields(),
+      CallProblem::Dropped(t) => t._missing_fields(),
+      CallProblem::Echo(t) => t._missing_fields(),
+      CallProblem::Interruptions(t) => t._missing_fields(),
+      CallProblem::Noise(t) => t._missing_fields(),
+      CallProblem::PixelatedVideo(t) => t._missing_fields(),
+      CallProblem::SilentLocal(t) => t._missing_fields(),
+      CallProblem::SilentRemote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_distorted_speech(&self) -> bool { if let CallProblem::DistortedSpeech(_) = self { true } else { false } }
   pub fn is_distorted_video(&self) -> bool { if let CallProblem::DistortedVideo(_) = self { true } else { false } }
@@ -157,6 +174,42 @@ impl AsRef<CallProblem> for CallProblem {
   fn as_ref(&self) -> &CallProblem { self }
 }
 
+impl RVariant<CallProblem> for CallProblemDistortedSpeech {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::DistortedSpeech(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemDistortedVideo {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::DistortedVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemDropped {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::Dropped(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemEcho {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::Echo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemInterruptions {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::Interruptions(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemNoise {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::Noise(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemPixelatedVideo {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::PixelatedVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemSilentLocal {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::SilentLocal(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallProblem> for CallProblemSilentRemote {
+  fn from_class(class: &CallProblem) -> Option<&Self> { match class { CallProblem::SilentRemote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -195,6 +248,11 @@ impl CallProblemDistortedSpeech {
     RTDCallProblemDistortedSpeechBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemDistortedSpeech", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -205,6 +263,9 @@ pub struct RTDCallProblemDistortedSpeechBuilder {
 impl RTDCallProblemDistortedSpeechBuilder {
   pub fn build(&self) -> CallProblemDistortedSpeech { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemDistortedSpeech> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemDistortedSpeech> for CallProblemDistortedSpeech {
@@ -253,6 +314,11 @@ impl CallProblemDistortedVideo {
     RTDCallProblemDistortedVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemDistortedVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -263,6 +329,9 @@ pub struct RTDCallProblemDistortedVideoBuilder {
 impl RTDCallProblemDistortedVideoBuilder {
   pub fn build(&self) -> CallProblemDistortedVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemDistortedVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemDistortedVideo> for CallProblemDistortedVideo {
@@ -311,6 +380,11 @@ impl CallProblemDropped {
     RTDCallProblemDroppedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemDropped", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +395,9 @@ pub struct RTDCallProblemDroppedBuilder {
 impl RTDCallProblemDroppedBuilder {
   pub fn build(&self) -> CallProblemDropped { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemDropped> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemDropped> for CallProblemDropped {
@@ -369,6 +446,11 @@ impl CallProblemEcho {
     RTDCallProblemEchoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemEcho", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -379,6 +461,9 @@ pub struct RTDCallProblemEchoBuilder {
 impl RTDCallProblemEchoBuilder {
   pub fn build(&self) -> CallProblemEcho { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemEcho> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemEcho> for CallProblemEcho {
@@ -427,6 +512,11 @@ impl CallProblemInterruptions {
     RTDCallProblemInterruptionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemInterruptions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -437,6 +527,9 @@ pub struct RTDCallProblemInterruptionsBuilder {
 impl RTDCallProblemInterruptionsBuilder {
   pub fn build(&self) -> CallProblemInterruptions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemInterruptions> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemInterruptions> for CallProblemInterruptions {
@@ -485,6 +578,11 @@ impl CallProblemNoise {
     RTDCallProblemNoiseBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemNoise", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -495,6 +593,9 @@ pub struct RTDCallProblemNoiseBuilder {
 impl RTDCallProblemNoiseBuilder {
   pub fn build(&self) -> CallProblemNoise { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemNoise> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemNoise> for CallProblemNoise {
@@ -543,6 +644,11 @@ impl CallProblemPixelatedVideo {
     RTDCallProblemPixelatedVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemPixelatedVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -553,6 +659,9 @@ pub struct RTDCallProblemPixelatedVideoBuilder {
 impl RTDCallProblemPixelatedVideoBuilder {
   pub fn build(&self) -> CallProblemPixelatedVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemPixelatedVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemPixelatedVideo> for CallProblemPixelatedVideo {
@@ -601,6 +710,11 @@ impl CallProblemSilentLocal {
     RTDCallProblemSilentLocalBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemSilentLocal", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -611,6 +725,9 @@ pub struct RTDCallProblemSilentLocalBuilder {
 impl RTDCallProblemSilentLocalBuilder {
   pub fn build(&self) -> CallProblemSilentLocal { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemSilentLocal> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemSilentLocal> for CallProblemSilentLocal {
@@ -659,6 +776,11 @@ impl CallProblemSilentRemote {
     RTDCallProblemSilentRemoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProblemSilentRemote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -669,6 +791,9 @@ pub struct RTDCallProblemSilentRemoteBuilder {
 impl RTDCallProblemSilentRemoteBuilder {
   pub fn build(&self) -> CallProblemSilentRemote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProblemSilentRemote> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallProblemSilentRemote> for CallProblemSilentRemote {
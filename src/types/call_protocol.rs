@@ -42,7 +42,17 @@ impl CallProtocol {
     let mut inner = CallProtocol::default();
     inner.td_name = "callProtocol".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallProtocolBuilder { inner }
+    RTDCallProtocolBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callProtocol", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.min_layer == 0 { missing.push("min_layer".to_string()); }
+    if self.max_layer == 0 { missing.push("max_layer".to_string()); }
+    missing
   }
 
   pub fn udp_p2p(&self) -> bool { self.udp_p2p }
@@ -59,12 +69,22 @@ impl CallProtocol {
 
 #[doc(hidden)]
 pub struct RTDCallProtocolBuilder {
-  inner: CallProtocol
+  inner: CallProtocol,
+  set: [bool; 2]
 }
 
 impl RTDCallProtocolBuilder {
   pub fn build(&self) -> CallProtocol { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallProtocol> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("min_layer".to_string()); }
+    if !self.set[1] { missing.push("max_layer".to_string()); }
+    crate::types::_common::check_missing("callProtocol", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn udp_p2p(&mut self, udp_p2p: bool) -> &mut Self {
     self.inner.udp_p2p = udp_p2p;
@@ -80,12 +100,14 @@ impl RTDCallProtocolBuilder {
    
   pub fn min_layer(&mut self, min_layer: i64) -> &mut Self {
     self.inner.min_layer = min_layer;
+    self.set[0] = true;
     self
   }
 
    
   pub fn max_layer(&mut self, max_layer: i64) -> &mut Self {
     self.inner.max_layer = max_layer;
+    self.set[1] = true;
     self
   }
 
@@ -16,7 +16,7 @@ pub struct CallServer {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Server identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Server IPv4 address
   ip_address: String,
   /// Server IPv6 address
@@ -42,10 +42,23 @@ impl CallServer {
     let mut inner = CallServer::default();
     inner.td_name = "callServer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallServerBuilder { inner }
+    RTDCallServerBuilder { inner, set: [false; 4] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callServer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.ip_address.is_empty() { missing.push("ip_address".to_string()); }
+    if self.ipv6_address.is_empty() { missing.push("ipv6_address".to_string()); }
+    if self.port == 0 { missing.push("port".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn ip_address(&self) -> &String { &self.ip_address }
 
@@ -59,33 +72,50 @@ impl CallServer {
 
 #[doc(hidden)]
 pub struct RTDCallServerBuilder {
-  inner: CallServer
+  inner: CallServer,
+  set: [bool; 4]
 }
 
 impl RTDCallServerBuilder {
   pub fn build(&self) -> CallServer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallServer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("ip_address".to_string()); }
+    if !self.set[2] { missing.push("ipv6_address".to_string()); }
+    if !self.set[3] { missing.push("port".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("callServer", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn ip_address<T: AsRef<str>>(&mut self, ip_address: T) -> &mut Self {
     self.inner.ip_address = ip_address.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn ipv6_address<T: AsRef<str>>(&mut self, ipv6_address: T) -> &mut Self {
     self.inner.ipv6_address = ipv6_address.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn port(&mut self, port: i64) -> &mut Self {
     self.inner.port = port;
+    self.set[3] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDCallServerType: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallServerType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram call reflector
   TelegramReflector(CallServerTypeTelegramReflector),
   /// A WebRTC server
@@ -32,7 +35,6 @@ impl Default for CallServerType {
 
 impl<'de> Deserialize<'de> for CallServerType {
   fn deserialize<D>(deserializer: D) -> Result<CallServerType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CallServerType,
       (callServerTypeTelegramReflector, TelegramReflector);
@@ -55,6 +57,7 @@ impl RObject for CallServerType {
     match self {
       CallServerType::TelegramReflector(t) => t.extra(),
       CallServerType::Webrtc(t) => t.extra(),
+      CallServerType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for CallServerType {
 impl CallServerType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallServerType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CallServerType::TelegramReflector(t) => t._missing_fields(),
+      CallServerType::Webrtc(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_telegram_reflector(&self) -> bool { if let CallServerType::TelegramReflector(_) = self { true } else { false } }
   pub fn is_webrtc(&self) -> bool { if let CallServerType::Webrtc(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<CallServerType> for CallServerType {
   fn as_ref(&self) -> &CallServerType { self }
 }
 
+impl RVariant<CallServerType> for CallServerTypeTelegramReflector {
+  fn from_class(class: &CallServerType) -> Option<&Self> { match class { CallServerType::TelegramReflector(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallServerType> for CallServerTypeWebrtc {
+  fn from_class(class: &CallServerType) -> Option<&Self> { match class { CallServerType::Webrtc(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -124,7 +142,16 @@ impl CallServerTypeTelegramReflector {
     let mut inner = CallServerTypeTelegramReflector::default();
     inner.td_name = "callServerTypeTelegramReflector".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallServerTypeTelegramReflectorBuilder { inner }
+    RTDCallServerTypeTelegramReflectorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callServerTypeTelegramReflector", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.peer_tag.is_empty() { missing.push("peer_tag".to_string()); }
+    missing
   }
 
   pub fn peer_tag(&self) -> &String { &self.peer_tag }
@@ -133,15 +160,25 @@ impl CallServerTypeTelegramReflector {
 
 #[doc(hidden)]
 pub struct RTDCallServerTypeTelegramReflectorBuilder {
-  inner: CallServerTypeTelegramReflector
+  inner: CallServerTypeTelegramReflector,
+  set: [bool; 1]
 }
 
 impl RTDCallServerTypeTelegramReflectorBuilder {
   pub fn build(&self) -> CallServerTypeTelegramReflector { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallServerTypeTelegramReflector> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("peer_tag".to_string()); }
+    crate::types::_common::check_missing("callServerTypeTelegramReflector", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn peer_tag<T: AsRef<str>>(&mut self, peer_tag: T) -> &mut Self {
     self.inner.peer_tag = peer_tag.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -198,7 +235,17 @@ impl CallServerTypeWebrtc {
     let mut inner = CallServerTypeWebrtc::default();
     inner.td_name = "callServerTypeWebrtc".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallServerTypeWebrtcBuilder { inner }
+    RTDCallServerTypeWebrtcBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callServerTypeWebrtc", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn username(&self) -> &String { &self.username }
@@ -213,21 +260,33 @@ impl CallServerTypeWebrtc {
 
 #[doc(hidden)]
 pub struct RTDCallServerTypeWebrtcBuilder {
-  inner: CallServerTypeWebrtc
+  inner: CallServerTypeWebrtc,
+  set: [bool; 2]
 }
 
 impl RTDCallServerTypeWebrtcBuilder {
   pub fn build(&self) -> CallServerTypeWebrtc { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallServerTypeWebrtc> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("username".to_string()); }
+    if !self.set[1] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("callServerTypeWebrtc", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
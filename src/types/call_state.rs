@@ -19,6 +19,9 @@ pub trait TDCallState: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The call has ended successfully
   Discarded(CallStateDiscarded),
   /// The call has ended with an error
@@ -40,7 +43,6 @@ impl Default for CallState {
 
 impl<'de> Deserialize<'de> for CallState {
   fn deserialize<D>(deserializer: D) -> Result<CallState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CallState,
       (callStateDiscarded, Discarded);
@@ -75,6 +77,7 @@ impl RObject for CallState {
       CallState::HangingUp(t) => t.extra(),
       CallState::Pending(t) => t.extra(),
       CallState::Ready(t) => t.extra(),
+      CallState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,6 +88,17 @@ impl RObject for CallState {
 impl CallState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CallState::Discarded(t) => t._missing_fields(),
+      CallState::Error(t) => t._missing_fields(),
+      CallState::ExchangingKeys(t) => t._missing_fields(),
+      CallState::HangingUp(t) => t._missing_fields(),
+      CallState::Pending(t) => t._missing_fields(),
+      CallState::Ready(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_discarded(&self) -> bool { if let CallState::Discarded(_) = self { true } else { false } }
   pub fn is_error(&self) -> bool { if let CallState::Error(_) = self { true } else { false } }
@@ -127,6 +141,30 @@ impl AsRef<CallState> for CallState {
   fn as_ref(&self) -> &CallState { self }
 }
 
+impl RVariant<CallState> for CallStateDiscarded {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::Discarded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallState> for CallStateError {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::Error(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallState> for CallStateExchangingKeys {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::ExchangingKeys(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallState> for CallStateHangingUp {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::HangingUp(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallState> for CallStatePending {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::Pending(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallState> for CallStateReady {
+  fn from_class(class: &CallState) -> Option<&Self> { match class { CallState::Ready(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -171,6 +209,15 @@ impl CallStateDiscarded {
     RTDCallStateDiscardedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStateDiscarded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.reason._is_default() { missing.push("reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("reason", self.reason._missing_fields())); }
+    missing
+  }
+
   pub fn reason(&self) -> &CallDiscardReason { &self.reason }
 
   pub fn need_rating(&self) -> bool { self.need_rating }
@@ -187,6 +234,14 @@ pub struct RTDCallStateDiscardedBuilder {
 impl RTDCallStateDiscardedBuilder {
   pub fn build(&self) -> CallStateDiscarded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStateDiscarded> {
+    let mut missing = vec![];
+    if self.inner.reason._is_default() { missing.push("reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("reason", self.inner.reason._missing_fields())); }
+    crate::types::_common::check_missing("callStateDiscarded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn reason<T: AsRef<CallDiscardReason>>(&mut self, reason: T) -> &mut Self {
     self.inner.reason = reason.as_ref().clone();
@@ -252,7 +307,16 @@ impl CallStateError {
     let mut inner = CallStateError::default();
     inner.td_name = "callStateError".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallStateErrorBuilder { inner }
+    RTDCallStateErrorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStateError", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("error".to_string()); } else { missing.extend(crate::types::_common::missing_in("error", self.error._missing_fields())); }
+    missing
   }
 
   pub fn error(&self) -> &Error { &self.error }
@@ -261,15 +325,25 @@ impl CallStateError {
 
 #[doc(hidden)]
 pub struct RTDCallStateErrorBuilder {
-  inner: CallStateError
+  inner: CallStateError,
+  set: [bool; 1]
 }
 
 impl RTDCallStateErrorBuilder {
   pub fn build(&self) -> CallStateError { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStateError> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("error".to_string()); } else { missing.extend(crate::types::_common::missing_in("error", self.inner.error._missing_fields())); }
+    crate::types::_common::check_missing("callStateError", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
     self.inner.error = error.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -321,6 +395,11 @@ impl CallStateExchangingKeys {
     RTDCallStateExchangingKeysBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStateExchangingKeys", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -331,6 +410,9 @@ pub struct RTDCallStateExchangingKeysBuilder {
 impl RTDCallStateExchangingKeysBuilder {
   pub fn build(&self) -> CallStateExchangingKeys { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStateExchangingKeys> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallStateExchangingKeys> for CallStateExchangingKeys {
@@ -379,6 +461,11 @@ impl CallStateHangingUp {
     RTDCallStateHangingUpBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStateHangingUp", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -389,6 +476,9 @@ pub struct RTDCallStateHangingUpBuilder {
 impl RTDCallStateHangingUpBuilder {
   pub fn build(&self) -> CallStateHangingUp { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStateHangingUp> { Ok(self.build()) }
+
 }
 
 impl AsRef<CallStateHangingUp> for CallStateHangingUp {
@@ -441,6 +531,11 @@ impl CallStatePending {
     RTDCallStatePendingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStatePending", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_created(&self) -> bool { self.is_created }
 
   pub fn is_received(&self) -> bool { self.is_received }
@@ -455,6 +550,9 @@ pub struct RTDCallStatePendingBuilder {
 impl RTDCallStatePendingBuilder {
   pub fn build(&self) -> CallStatePending { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStatePending> { Ok(self.build()) }
+
    
   pub fn is_created(&mut self, is_created: bool) -> &mut Self {
     self.inner.is_created = is_created;
@@ -524,7 +622,18 @@ impl CallStateReady {
     let mut inner = CallStateReady::default();
     inner.td_name = "callStateReady".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallStateReadyBuilder { inner }
+    RTDCallStateReadyBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callStateReady", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.protocol._missing_fields())); }
+    if self.config.is_empty() { missing.push("config".to_string()); }
+    if self.encryption_key.is_empty() { missing.push("encryption_key".to_string()); }
+    missing
   }
 
   pub fn protocol(&self) -> &CallProtocol { &self.protocol }
@@ -543,15 +652,27 @@ impl CallStateReady {
 
 #[doc(hidden)]
 pub struct RTDCallStateReadyBuilder {
-  inner: CallStateReady
+  inner: CallStateReady,
+  set: [bool; 3]
 }
 
 impl RTDCallStateReadyBuilder {
   pub fn build(&self) -> CallStateReady { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallStateReady> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.inner.protocol._missing_fields())); }
+    if !self.set[1] { missing.push("config".to_string()); }
+    if !self.set[2] { missing.push("encryption_key".to_string()); }
+    crate::types::_common::check_missing("callStateReady", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -564,12 +685,14 @@ impl RTDCallStateReadyBuilder {
    
   pub fn config<T: AsRef<str>>(&mut self, config: T) -> &mut Self {
     self.inner.config = config.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
     self.inner.encryption_key = encryption_key.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
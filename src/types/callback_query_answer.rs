@@ -38,7 +38,17 @@ impl CallbackQueryAnswer {
     let mut inner = CallbackQueryAnswer::default();
     inner.td_name = "callbackQueryAnswer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallbackQueryAnswerBuilder { inner }
+    RTDCallbackQueryAnswerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callbackQueryAnswer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -51,15 +61,26 @@ impl CallbackQueryAnswer {
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryAnswerBuilder {
-  inner: CallbackQueryAnswer
+  inner: CallbackQueryAnswer,
+  set: [bool; 2]
 }
 
 impl RTDCallbackQueryAnswerBuilder {
   pub fn build(&self) -> CallbackQueryAnswer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallbackQueryAnswer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("callbackQueryAnswer", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -72,6 +93,7 @@ impl RTDCallbackQueryAnswerBuilder {
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
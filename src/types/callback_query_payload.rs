@@ -19,6 +19,9 @@ pub trait TDCallbackQueryPayload: Debug + RObject {}
 #[serde(untagged)]
 pub enum CallbackQueryPayload {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The payload for a general callback button
   Data(CallbackQueryPayloadData),
   /// The payload for a callback button requiring password
@@ -34,7 +37,6 @@ impl Default for CallbackQueryPayload {
 
 impl<'de> Deserialize<'de> for CallbackQueryPayload {
   fn deserialize<D>(deserializer: D) -> Result<CallbackQueryPayload, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CallbackQueryPayload,
       (callbackQueryPayloadData, Data);
@@ -60,6 +62,7 @@ impl RObject for CallbackQueryPayload {
       CallbackQueryPayload::Data(t) => t.extra(),
       CallbackQueryPayload::DataWithPassword(t) => t.extra(),
       CallbackQueryPayload::Game(t) => t.extra(),
+      CallbackQueryPayload::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for CallbackQueryPayload {
 impl CallbackQueryPayload {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CallbackQueryPayload::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CallbackQueryPayload::Data(t) => t._missing_fields(),
+      CallbackQueryPayload::DataWithPassword(t) => t._missing_fields(),
+      CallbackQueryPayload::Game(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_data(&self) -> bool { if let CallbackQueryPayload::Data(_) = self { true } else { false } }
   pub fn is_data_with_password(&self) -> bool { if let CallbackQueryPayload::DataWithPassword(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<CallbackQueryPayload> for CallbackQueryPayload {
   fn as_ref(&self) -> &CallbackQueryPayload { self }
 }
 
+impl RVariant<CallbackQueryPayload> for CallbackQueryPayloadData {
+  fn from_class(class: &CallbackQueryPayload) -> Option<&Self> { match class { CallbackQueryPayload::Data(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallbackQueryPayload> for CallbackQueryPayloadDataWithPassword {
+  fn from_class(class: &CallbackQueryPayload) -> Option<&Self> { match class { CallbackQueryPayload::DataWithPassword(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CallbackQueryPayload> for CallbackQueryPayloadGame {
+  fn from_class(class: &CallbackQueryPayload) -> Option<&Self> { match class { CallbackQueryPayload::Game(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -134,7 +157,16 @@ impl CallbackQueryPayloadData {
     let mut inner = CallbackQueryPayloadData::default();
     inner.td_name = "callbackQueryPayloadData".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallbackQueryPayloadDataBuilder { inner }
+    RTDCallbackQueryPayloadDataBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callbackQueryPayloadData", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -143,15 +175,25 @@ impl CallbackQueryPayloadData {
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryPayloadDataBuilder {
-  inner: CallbackQueryPayloadData
+  inner: CallbackQueryPayloadData,
+  set: [bool; 1]
 }
 
 impl RTDCallbackQueryPayloadDataBuilder {
   pub fn build(&self) -> CallbackQueryPayloadData { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallbackQueryPayloadData> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("callbackQueryPayloadData", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -204,7 +246,17 @@ impl CallbackQueryPayloadDataWithPassword {
     let mut inner = CallbackQueryPayloadDataWithPassword::default();
     inner.td_name = "callbackQueryPayloadDataWithPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallbackQueryPayloadDataWithPasswordBuilder { inner }
+    RTDCallbackQueryPayloadDataWithPasswordBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callbackQueryPayloadDataWithPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -215,21 +267,33 @@ impl CallbackQueryPayloadDataWithPassword {
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryPayloadDataWithPasswordBuilder {
-  inner: CallbackQueryPayloadDataWithPassword
+  inner: CallbackQueryPayloadDataWithPassword,
+  set: [bool; 2]
 }
 
 impl RTDCallbackQueryPayloadDataWithPasswordBuilder {
   pub fn build(&self) -> CallbackQueryPayloadDataWithPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallbackQueryPayloadDataWithPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    if !self.set[1] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("callbackQueryPayloadDataWithPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -280,7 +344,16 @@ impl CallbackQueryPayloadGame {
     let mut inner = CallbackQueryPayloadGame::default();
     inner.td_name = "callbackQueryPayloadGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCallbackQueryPayloadGameBuilder { inner }
+    RTDCallbackQueryPayloadGameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("callbackQueryPayloadGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.game_short_name.is_empty() { missing.push("game_short_name".to_string()); }
+    missing
   }
 
   pub fn game_short_name(&self) -> &String { &self.game_short_name }
@@ -289,15 +362,25 @@ impl CallbackQueryPayloadGame {
 
 #[doc(hidden)]
 pub struct RTDCallbackQueryPayloadGameBuilder {
-  inner: CallbackQueryPayloadGame
+  inner: CallbackQueryPayloadGame,
+  set: [bool; 1]
 }
 
 impl RTDCallbackQueryPayloadGameBuilder {
   pub fn build(&self) -> CallbackQueryPayloadGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CallbackQueryPayloadGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("game_short_name".to_string()); }
+    crate::types::_common::check_missing("callbackQueryPayloadGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
     self.inner.game_short_name = game_short_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
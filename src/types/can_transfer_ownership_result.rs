@@ -19,8 +19,9 @@ pub trait TDCanTransferOwnershipResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum CanTransferOwnershipResult {
   #[doc(hidden)] _Default(()),
-  /// Checks whether the current session can be used to transfer a chat ownership to another user
-  CanTransferOwnership(CanTransferOwnership),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The session can be used
   Ok(CanTransferOwnershipResultOk),
   /// The 2-step verification needs to be enabled first
@@ -38,10 +39,8 @@ impl Default for CanTransferOwnershipResult {
 
 impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
   fn deserialize<D>(deserializer: D) -> Result<CanTransferOwnershipResult, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CanTransferOwnershipResult,
-      (canTransferOwnership, CanTransferOwnership);
       (canTransferOwnershipResultOk, Ok);
       (canTransferOwnershipResultPasswordNeeded, PasswordNeeded);
       (canTransferOwnershipResultPasswordTooFresh, PasswordTooFresh);
@@ -54,7 +53,6 @@ impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
 impl RObject for CanTransferOwnershipResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      CanTransferOwnershipResult::CanTransferOwnership(t) => t.td_name(),
       CanTransferOwnershipResult::Ok(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordNeeded(t) => t.td_name(),
       CanTransferOwnershipResult::PasswordTooFresh(t) => t.td_name(),
@@ -65,11 +63,11 @@ impl RObject for CanTransferOwnershipResult {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      CanTransferOwnershipResult::CanTransferOwnership(t) => t.extra(),
       CanTransferOwnershipResult::Ok(t) => t.extra(),
       CanTransferOwnershipResult::PasswordNeeded(t) => t.extra(),
       CanTransferOwnershipResult::PasswordTooFresh(t) => t.extra(),
       CanTransferOwnershipResult::SessionTooFresh(t) => t.extra(),
+      CanTransferOwnershipResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,20 +78,26 @@ impl RObject for CanTransferOwnershipResult {
 impl CanTransferOwnershipResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CanTransferOwnershipResult::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CanTransferOwnershipResult::Ok(t) => t._missing_fields(),
+      CanTransferOwnershipResult::PasswordNeeded(t) => t._missing_fields(),
+      CanTransferOwnershipResult::PasswordTooFresh(t) => t._missing_fields(),
+      CanTransferOwnershipResult::SessionTooFresh(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_can_transfer_ownership(&self) -> bool { if let CanTransferOwnershipResult::CanTransferOwnership(_) = self { true } else { false } }
   pub fn is_ok(&self) -> bool { if let CanTransferOwnershipResult::Ok(_) = self { true } else { false } }
   pub fn is_password_needed(&self) -> bool { if let CanTransferOwnershipResult::PasswordNeeded(_) = self { true } else { false } }
   pub fn is_password_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::PasswordTooFresh(_) = self { true } else { false } }
   pub fn is_session_too_fresh(&self) -> bool { if let CanTransferOwnershipResult::SessionTooFresh(_) = self { true } else { false } }
 
-  pub fn on_can_transfer_ownership<F: FnOnce(&CanTransferOwnership)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CanTransferOwnershipResultOk)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_password_needed<F: FnOnce(&CanTransferOwnershipResultPasswordNeeded)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { fnc(t) }; self }
   pub fn on_password_too_fresh<F: FnOnce(&CanTransferOwnershipResultPasswordTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { fnc(t) }; self }
   pub fn on_session_too_fresh<F: FnOnce(&CanTransferOwnershipResultSessionTooFresh)>(&self, fnc: F) -> &Self { if let CanTransferOwnershipResult::SessionTooFresh(t) = self { fnc(t) }; self }
 
-  pub fn as_can_transfer_ownership(&self) -> Option<&CanTransferOwnership> { if let CanTransferOwnershipResult::CanTransferOwnership(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CanTransferOwnershipResultOk> { if let CanTransferOwnershipResult::Ok(t) = self { return Some(t) } None }
   pub fn as_password_needed(&self) -> Option<&CanTransferOwnershipResultPasswordNeeded> { if let CanTransferOwnershipResult::PasswordNeeded(t) = self { return Some(t) } None }
   pub fn as_password_too_fresh(&self) -> Option<&CanTransferOwnershipResultPasswordTooFresh> { if let CanTransferOwnershipResult::PasswordTooFresh(t) = self { return Some(t) } None }
@@ -101,8 +105,6 @@ impl CanTransferOwnershipResult {
 
 
 
-  pub fn can_transfer_ownership<T: AsRef<CanTransferOwnership>>(t: T) -> Self { CanTransferOwnershipResult::CanTransferOwnership(t.as_ref().clone()) }
-
   pub fn ok<T: AsRef<CanTransferOwnershipResultOk>>(t: T) -> Self { CanTransferOwnershipResult::Ok(t.as_ref().clone()) }
 
   pub fn password_needed<T: AsRef<CanTransferOwnershipResultPasswordNeeded>>(t: T) -> Self { CanTransferOwnershipResult::PasswordNeeded(t.as_ref().clone()) }
@@ -117,6 +119,22 @@ impl AsRef<CanTransferOwnershipResult> for CanTransferOwnershipResult {
   fn as_ref(&self) -> &CanTransferOwnershipResult { self }
 }
 
+impl RVariant<CanTransferOwnershipResult> for CanTransferOwnershipResultOk {
+  fn from_class(class: &CanTransferOwnershipResult) -> Option<&Self> { match class { CanTransferOwnershipResult::Ok(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CanTransferOwnershipResult> for CanTransferOwnershipResultPasswordNeeded {
+  fn from_class(class: &CanTransferOwnershipResult) -> Option<&Self> { match class { CanTransferOwnershipResult::PasswordNeeded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CanTransferOwnershipResult> for CanTransferOwnershipResultPasswordTooFresh {
+  fn from_class(class: &CanTransferOwnershipResult) -> Option<&Self> { match class { CanTransferOwnershipResult::PasswordTooFresh(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CanTransferOwnershipResult> for CanTransferOwnershipResultSessionTooFresh {
+  fn from_class(class: &CanTransferOwnershipResult) -> Option<&Self> { match class { CanTransferOwnershipResult::SessionTooFresh(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -155,6 +173,11 @@ impl CanTransferOwnershipResultOk {
     RTDCanTransferOwnershipResultOkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("canTransferOwnershipResultOk", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +188,9 @@ pub struct RTDCanTransferOwnershipResultOkBuilder {
 impl RTDCanTransferOwnershipResultOkBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultOk { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultOk> { Ok(self.build()) }
+
 }
 
 impl AsRef<CanTransferOwnershipResultOk> for CanTransferOwnershipResultOk {
@@ -213,6 +239,11 @@ impl CanTransferOwnershipResultPasswordNeeded {
     RTDCanTransferOwnershipResultPasswordNeededBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("canTransferOwnershipResultPasswordNeeded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +254,9 @@ pub struct RTDCanTransferOwnershipResultPasswordNeededBuilder {
 impl RTDCanTransferOwnershipResultPasswordNeededBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordNeeded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultPasswordNeeded> { Ok(self.build()) }
+
 }
 
 impl AsRef<CanTransferOwnershipResultPasswordNeeded> for CanTransferOwnershipResultPasswordNeeded {
@@ -270,7 +304,16 @@ impl CanTransferOwnershipResultPasswordTooFresh {
     let mut inner = CanTransferOwnershipResultPasswordTooFresh::default();
     inner.td_name = "canTransferOwnershipResultPasswordTooFresh".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCanTransferOwnershipResultPasswordTooFreshBuilder { inner }
+    RTDCanTransferOwnershipResultPasswordTooFreshBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("canTransferOwnershipResultPasswordTooFresh", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.retry_after == 0 { missing.push("retry_after".to_string()); }
+    missing
   }
 
   pub fn retry_after(&self) -> i64 { self.retry_after }
@@ -279,15 +322,25 @@ impl CanTransferOwnershipResultPasswordTooFresh {
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
-  inner: CanTransferOwnershipResultPasswordTooFresh
+  inner: CanTransferOwnershipResultPasswordTooFresh,
+  set: [bool; 1]
 }
 
 impl RTDCanTransferOwnershipResultPasswordTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultPasswordTooFresh { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultPasswordTooFresh> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("retry_after".to_string()); }
+    crate::types::_common::check_missing("canTransferOwnershipResultPasswordTooFresh", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
+    self.set[0] = true;
     self
   }
 
@@ -338,7 +391,16 @@ impl CanTransferOwnershipResultSessionTooFresh {
     let mut inner = CanTransferOwnershipResultSessionTooFresh::default();
     inner.td_name = "canTransferOwnershipResultSessionTooFresh".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCanTransferOwnershipResultSessionTooFreshBuilder { inner }
+    RTDCanTransferOwnershipResultSessionTooFreshBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("canTransferOwnershipResultSessionTooFresh", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.retry_after == 0 { missing.push("retry_after".to_string()); }
+    missing
   }
 
   pub fn retry_after(&self) -> i64 { self.retry_after }
@@ -347,15 +409,25 @@ impl CanTransferOwnershipResultSessionTooFresh {
 
 #[doc(hidden)]
 pub struct RTDCanTransferOwnershipResultSessionTooFreshBuilder {
-  inner: CanTransferOwnershipResultSessionTooFresh
+  inner: CanTransferOwnershipResultSessionTooFresh,
+  set: [bool; 1]
 }
 
 impl RTDCanTransferOwnershipResultSessionTooFreshBuilder {
   pub fn build(&self) -> CanTransferOwnershipResultSessionTooFresh { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CanTransferOwnershipResultSessionTooFresh> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("retry_after".to_string()); }
+    crate::types::_common::check_missing("canTransferOwnershipResultSessionTooFresh", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn retry_after(&mut self, retry_after: i64) -> &mut Self {
     self.inner.retry_after = retry_after;
+    self.set[0] = true;
     self
   }
 
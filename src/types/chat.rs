@@ -82,7 +82,25 @@ impl Chat {
     let mut inner = Chat::default();
     inner.td_name = "chat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatBuilder { inner }
+    RTDChatBuilder { inner, set: [false; 9] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if false { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.permissions._missing_fields())); }
+    if self.unread_count == 0 { missing.push("unread_count".to_string()); }
+    if self.last_read_inbox_message_id == 0 { missing.push("last_read_inbox_message_id".to_string()); }
+    if self.last_read_outbox_message_id == 0 { missing.push("last_read_outbox_message_id".to_string()); }
+    if self.unread_mention_count == 0 { missing.push("unread_mention_count".to_string()); }
+    if false { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.notification_settings._missing_fields())); }
+    if self.client_data.is_empty() { missing.push("client_data".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -135,19 +153,88 @@ impl Chat {
 
   pub fn client_data(&self) -> &String { &self.client_data }
 
+  pub fn id_mut(&mut self) -> &mut i64 { &mut self.id }
+
+  pub fn type_mut(&mut self) -> &mut ChatType { &mut self.type_ }
+
+  pub fn title_mut(&mut self) -> &mut String { &mut self.title }
+
+  pub fn photo_mut(&mut self) -> &mut Option<ChatPhotoInfo> { &mut self.photo }
+
+  pub fn permissions_mut(&mut self) -> &mut ChatPermissions { &mut self.permissions }
+
+  pub fn last_message_mut(&mut self) -> &mut Option<Message> { &mut self.last_message }
+
+  pub fn positions_mut(&mut self) -> &mut Option<Vec<ChatPosition>> { &mut self.positions }
+
+  pub fn is_marked_as_unread_mut(&mut self) -> &mut bool { &mut self.is_marked_as_unread }
+
+  pub fn is_blocked_mut(&mut self) -> &mut bool { &mut self.is_blocked }
+
+  pub fn has_scheduled_messages_mut(&mut self) -> &mut bool { &mut self.has_scheduled_messages }
+
+  pub fn can_be_deleted_only_for_self_mut(&mut self) -> &mut bool { &mut self.can_be_deleted_only_for_self }
+
+  pub fn can_be_deleted_for_all_users_mut(&mut self) -> &mut bool { &mut self.can_be_deleted_for_all_users }
+
+  pub fn can_be_reported_mut(&mut self) -> &mut bool { &mut self.can_be_reported }
+
+  pub fn default_disable_notification_mut(&mut self) -> &mut bool { &mut self.default_disable_notification }
+
+  pub fn unread_count_mut(&mut self) -> &mut i64 { &mut self.unread_count }
+
+  pub fn last_read_inbox_message_id_mut(&mut self) -> &mut i64 { &mut self.last_read_inbox_message_id }
+
+  pub fn last_read_outbox_message_id_mut(&mut self) -> &mut i64 { &mut self.last_read_outbox_message_id }
+
+  pub fn unread_mention_count_mut(&mut self) -> &mut i64 { &mut self.unread_mention_count }
+
+  pub fn notification_settings_mut(&mut self) -> &mut ChatNotificationSettings { &mut self.notification_settings }
+
+  pub fn action_bar_mut(&mut self) -> &mut Option<ChatActionBar> { &mut self.action_bar }
+
+  pub fn voice_chat_group_call_id_mut(&mut self) -> &mut i64 { &mut self.voice_chat_group_call_id }
+
+  pub fn is_voice_chat_empty_mut(&mut self) -> &mut bool { &mut self.is_voice_chat_empty }
+
+  pub fn reply_markup_message_id_mut(&mut self) -> &mut i64 { &mut self.reply_markup_message_id }
+
+  pub fn draft_message_mut(&mut self) -> &mut Option<DraftMessage> { &mut self.draft_message }
+
+  pub fn client_data_mut(&mut self) -> &mut String { &mut self.client_data }
+
 }
 
 #[doc(hidden)]
 pub struct RTDChatBuilder {
-  inner: Chat
+  inner: Chat,
+  set: [bool; 9]
 }
 
 impl RTDChatBuilder {
   pub fn build(&self) -> Chat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Chat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.inner.permissions._missing_fields())); }
+    if !self.set[3] { missing.push("unread_count".to_string()); }
+    if !self.set[4] { missing.push("last_read_inbox_message_id".to_string()); }
+    if !self.set[5] { missing.push("last_read_outbox_message_id".to_string()); }
+    if !self.set[6] { missing.push("unread_mention_count".to_string()); }
+    if !self.set[7] { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.inner.notification_settings._missing_fields())); }
+    if !self.set[8] { missing.push("client_data".to_string()); }
+    crate::types::_common::check_missing("chat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -160,6 +247,7 @@ impl RTDChatBuilder {
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -172,6 +260,7 @@ impl RTDChatBuilder {
    
   pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
     self.inner.permissions = permissions.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -232,30 +321,35 @@ impl RTDChatBuilder {
    
   pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
     self.inner.unread_count = unread_count;
+    self.set[3] = true;
     self
   }
 
    
   pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
+    self.set[4] = true;
     self
   }
 
    
   pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
+    self.set[5] = true;
     self
   }
 
    
   pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
     self.inner.unread_mention_count = unread_mention_count;
+    self.set[6] = true;
     self
   }
 
    
   pub fn notification_settings<T: AsRef<ChatNotificationSettings>>(&mut self, notification_settings: T) -> &mut Self {
     self.inner.notification_settings = notification_settings.as_ref().clone();
+    self.set[7] = true;
     self
   }
 
@@ -292,6 +386,7 @@ impl RTDChatBuilder {
    
   pub fn client_data<T: AsRef<str>>(&mut self, client_data: T) -> &mut Self {
     self.inner.client_data = client_data.as_ref().to_string();
+    self.set[8] = true;
     self
   }
 
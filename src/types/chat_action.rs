@@ -19,6 +19,9 @@ pub trait TDChatAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatAction {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user has cancelled the previous action
   Cancel(ChatActionCancel),
   /// The user is picking a contact to send
@@ -54,7 +57,6 @@ impl Default for ChatAction {
 
 impl<'de> Deserialize<'de> for ChatAction {
   fn deserialize<D>(deserializer: D) -> Result<ChatAction, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatAction,
       (chatActionCancel, Cancel);
@@ -110,6 +112,7 @@ impl RObject for ChatAction {
       ChatAction::UploadingVideo(t) => t.extra(),
       ChatAction::UploadingVideoNote(t) => t.extra(),
       ChatAction::UploadingVoiceNote(t) => t.extra(),
+      ChatAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -120,6 +123,24 @@ impl RObject for ChatAction {
 impl ChatAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatAction::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatAction::Cancel(t) => t._missing_fields(),
+      ChatAction::ChoosingContact(t) => t._missing_fields(),
+      ChatAction::ChoosingLocation(t) => t._missing_fields(),
+      ChatAction::RecordingVideo(t) => t._missing_fields(),
+      ChatAction::RecordingVideoNote(t) => t._missing_fields(),
+      ChatAction::RecordingVoiceNote(t) => t._missing_fields(),
+      ChatAction::StartPlayingGame(t) => t._missing_fields(),
+      ChatAction::Typing(t) => t._missing_fields(),
+      ChatAction::UploadingDocument(t) => t._missing_fields(),
+      ChatAction::UploadingPhoto(t) => t._missing_fields(),
+      ChatAction::UploadingVideo(t) => t._missing_fields(),
+      ChatAction::UploadingVideoNote(t) => t._missing_fields(),
+      ChatAction::UploadingVoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_cancel(&self) -> bool { if let ChatAction::Cancel(_) = self { true } else { false } }
   pub fn is_choosing_contact(&self) -> bool { if let ChatAction::ChoosingContact(_) = self { true } else { false } }
@@ -197,6 +218,58 @@ impl AsRef<ChatAction> for ChatAction {
   fn as_ref(&self) -> &ChatAction { self }
 }
 
+impl RVariant<ChatAction> for ChatActionCancel {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::Cancel(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionChoosingContact {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::ChoosingContact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionChoosingLocation {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::ChoosingLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionRecordingVideo {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::RecordingVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionRecordingVideoNote {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::RecordingVideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionRecordingVoiceNote {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::RecordingVoiceNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionStartPlayingGame {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::StartPlayingGame(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionTyping {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::Typing(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionUploadingDocument {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::UploadingDocument(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionUploadingPhoto {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::UploadingPhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionUploadingVideo {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::UploadingVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionUploadingVideoNote {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::UploadingVideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatAction> for ChatActionUploadingVoiceNote {
+  fn from_class(class: &ChatAction) -> Option<&Self> { match class { ChatAction::UploadingVoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -235,6 +308,11 @@ impl ChatActionCancel {
     RTDChatActionCancelBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionCancel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -245,6 +323,9 @@ pub struct RTDChatActionCancelBuilder {
 impl RTDChatActionCancelBuilder {
   pub fn build(&self) -> ChatActionCancel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionCancel> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionCancel> for ChatActionCancel {
@@ -293,6 +374,11 @@ impl ChatActionChoosingContact {
     RTDChatActionChoosingContactBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionChoosingContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -303,6 +389,9 @@ pub struct RTDChatActionChoosingContactBuilder {
 impl RTDChatActionChoosingContactBuilder {
   pub fn build(&self) -> ChatActionChoosingContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionChoosingContact> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionChoosingContact> for ChatActionChoosingContact {
@@ -351,6 +440,11 @@ impl ChatActionChoosingLocation {
     RTDChatActionChoosingLocationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionChoosingLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -361,6 +455,9 @@ pub struct RTDChatActionChoosingLocationBuilder {
 impl RTDChatActionChoosingLocationBuilder {
   pub fn build(&self) -> ChatActionChoosingLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionChoosingLocation> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionChoosingLocation> for ChatActionChoosingLocation {
@@ -409,6 +506,11 @@ impl ChatActionRecordingVideo {
     RTDChatActionRecordingVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionRecordingVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -419,6 +521,9 @@ pub struct RTDChatActionRecordingVideoBuilder {
 impl RTDChatActionRecordingVideoBuilder {
   pub fn build(&self) -> ChatActionRecordingVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionRecordingVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionRecordingVideo> for ChatActionRecordingVideo {
@@ -467,6 +572,11 @@ impl ChatActionRecordingVideoNote {
     RTDChatActionRecordingVideoNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionRecordingVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -477,6 +587,9 @@ pub struct RTDChatActionRecordingVideoNoteBuilder {
 impl RTDChatActionRecordingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionRecordingVideoNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionRecordingVideoNote> for ChatActionRecordingVideoNote {
@@ -525,6 +638,11 @@ impl ChatActionRecordingVoiceNote {
     RTDChatActionRecordingVoiceNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionRecordingVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -535,6 +653,9 @@ pub struct RTDChatActionRecordingVoiceNoteBuilder {
 impl RTDChatActionRecordingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionRecordingVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionRecordingVoiceNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionRecordingVoiceNote> for ChatActionRecordingVoiceNote {
@@ -583,6 +704,11 @@ impl ChatActionStartPlayingGame {
     RTDChatActionStartPlayingGameBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionStartPlayingGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -593,6 +719,9 @@ pub struct RTDChatActionStartPlayingGameBuilder {
 impl RTDChatActionStartPlayingGameBuilder {
   pub fn build(&self) -> ChatActionStartPlayingGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionStartPlayingGame> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionStartPlayingGame> for ChatActionStartPlayingGame {
@@ -641,6 +770,11 @@ impl ChatActionTyping {
     RTDChatActionTypingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionTyping", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -651,6 +785,9 @@ pub struct RTDChatActionTypingBuilder {
 impl RTDChatActionTypingBuilder {
   pub fn build(&self) -> ChatActionTyping { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionTyping> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionTyping> for ChatActionTyping {
@@ -698,7 +835,16 @@ impl ChatActionUploadingDocument {
     let mut inner = ChatActionUploadingDocument::default();
     inner.td_name = "chatActionUploadingDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionUploadingDocumentBuilder { inner }
+    RTDChatActionUploadingDocumentBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionUploadingDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.progress == 0 { missing.push("progress".to_string()); }
+    missing
   }
 
   pub fn progress(&self) -> i64 { self.progress }
@@ -707,15 +853,25 @@ impl ChatActionUploadingDocument {
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingDocumentBuilder {
-  inner: ChatActionUploadingDocument
+  inner: ChatActionUploadingDocument,
+  set: [bool; 1]
 }
 
 impl RTDChatActionUploadingDocumentBuilder {
   pub fn build(&self) -> ChatActionUploadingDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionUploadingDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("progress".to_string()); }
+    crate::types::_common::check_missing("chatActionUploadingDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
+    self.set[0] = true;
     self
   }
 
@@ -766,7 +922,16 @@ impl ChatActionUploadingPhoto {
     let mut inner = ChatActionUploadingPhoto::default();
     inner.td_name = "chatActionUploadingPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionUploadingPhotoBuilder { inner }
+    RTDChatActionUploadingPhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionUploadingPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.progress == 0 { missing.push("progress".to_string()); }
+    missing
   }
 
   pub fn progress(&self) -> i64 { self.progress }
@@ -775,15 +940,25 @@ impl ChatActionUploadingPhoto {
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingPhotoBuilder {
-  inner: ChatActionUploadingPhoto
+  inner: ChatActionUploadingPhoto,
+  set: [bool; 1]
 }
 
 impl RTDChatActionUploadingPhotoBuilder {
   pub fn build(&self) -> ChatActionUploadingPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionUploadingPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("progress".to_string()); }
+    crate::types::_common::check_missing("chatActionUploadingPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
+    self.set[0] = true;
     self
   }
 
@@ -834,7 +1009,16 @@ impl ChatActionUploadingVideo {
     let mut inner = ChatActionUploadingVideo::default();
     inner.td_name = "chatActionUploadingVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionUploadingVideoBuilder { inner }
+    RTDChatActionUploadingVideoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionUploadingVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.progress == 0 { missing.push("progress".to_string()); }
+    missing
   }
 
   pub fn progress(&self) -> i64 { self.progress }
@@ -843,15 +1027,25 @@ impl ChatActionUploadingVideo {
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVideoBuilder {
-  inner: ChatActionUploadingVideo
+  inner: ChatActionUploadingVideo,
+  set: [bool; 1]
 }
 
 impl RTDChatActionUploadingVideoBuilder {
   pub fn build(&self) -> ChatActionUploadingVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionUploadingVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("progress".to_string()); }
+    crate::types::_common::check_missing("chatActionUploadingVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
+    self.set[0] = true;
     self
   }
 
@@ -902,7 +1096,16 @@ impl ChatActionUploadingVideoNote {
     let mut inner = ChatActionUploadingVideoNote::default();
     inner.td_name = "chatActionUploadingVideoNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionUploadingVideoNoteBuilder { inner }
+    RTDChatActionUploadingVideoNoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionUploadingVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.progress == 0 { missing.push("progress".to_string()); }
+    missing
   }
 
   pub fn progress(&self) -> i64 { self.progress }
@@ -911,15 +1114,25 @@ impl ChatActionUploadingVideoNote {
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVideoNoteBuilder {
-  inner: ChatActionUploadingVideoNote
+  inner: ChatActionUploadingVideoNote,
+  set: [bool; 1]
 }
 
 impl RTDChatActionUploadingVideoNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionUploadingVideoNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("progress".to_string()); }
+    crate::types::_common::check_missing("chatActionUploadingVideoNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
+    self.set[0] = true;
     self
   }
 
@@ -970,7 +1183,16 @@ impl ChatActionUploadingVoiceNote {
     let mut inner = ChatActionUploadingVoiceNote::default();
     inner.td_name = "chatActionUploadingVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionUploadingVoiceNoteBuilder { inner }
+    RTDChatActionUploadingVoiceNoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionUploadingVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.progress == 0 { missing.push("progress".to_string()); }
+    missing
   }
 
   pub fn progress(&self) -> i64 { self.progress }
@@ -979,15 +1201,25 @@ impl ChatActionUploadingVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDChatActionUploadingVoiceNoteBuilder {
-  inner: ChatActionUploadingVoiceNote
+  inner: ChatActionUploadingVoiceNote,
+  set: [bool; 1]
 }
 
 impl RTDChatActionUploadingVoiceNoteBuilder {
   pub fn build(&self) -> ChatActionUploadingVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionUploadingVoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("progress".to_string()); }
+    crate::types::_common::check_missing("chatActionUploadingVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn progress(&mut self, progress: i64) -> &mut Self {
     self.inner.progress = progress;
+    self.set[0] = true;
     self
   }
 
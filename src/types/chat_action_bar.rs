@@ -19,6 +19,9 @@ pub trait TDChatActionBar: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatActionBar {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is a private or secret chat and the other user can be added to the contact list using the method addContact
   AddContact(ChatActionBarAddContact),
   /// The chat is a recently created group chat, to which new members can be invited
@@ -40,7 +43,6 @@ impl Default for ChatActionBar {
 
 impl<'de> Deserialize<'de> for ChatActionBar {
   fn deserialize<D>(deserializer: D) -> Result<ChatActionBar, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatActionBar,
       (chatActionBarAddContact, AddContact);
@@ -75,6 +77,7 @@ impl RObject for ChatActionBar {
       ChatActionBar::ReportSpam(t) => t.extra(),
       ChatActionBar::ReportUnrelatedLocation(t) => t.extra(),
       ChatActionBar::SharePhoneNumber(t) => t.extra(),
+      ChatActionBar::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,6 +88,17 @@ impl RObject for ChatActionBar {
 impl ChatActionBar {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatActionBar::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatActionBar::AddContact(t) => t._missing_fields(),
+      ChatActionBar::InviteMembers(t) => t._missing_fields(),
+      ChatActionBar::ReportAddBlock(t) => t._missing_fields(),
+      ChatActionBar::ReportSpam(t) => t._missing_fields(),
+      ChatActionBar::ReportUnrelatedLocation(t) => t._missing_fields(),
+      ChatActionBar::SharePhoneNumber(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_add_contact(&self) -> bool { if let ChatActionBar::AddContact(_) = self { true } else { false } }
   pub fn is_invite_members(&self) -> bool { if let ChatActionBar::InviteMembers(_) = self { true } else { false } }
@@ -127,6 +141,30 @@ impl AsRef<ChatActionBar> for ChatActionBar {
   fn as_ref(&self) -> &ChatActionBar { self }
 }
 
+impl RVariant<ChatActionBar> for ChatActionBarAddContact {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::AddContact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatActionBar> for ChatActionBarInviteMembers {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::InviteMembers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatActionBar> for ChatActionBarReportAddBlock {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::ReportAddBlock(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatActionBar> for ChatActionBarReportSpam {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::ReportSpam(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatActionBar> for ChatActionBarReportUnrelatedLocation {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::ReportUnrelatedLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatActionBar> for ChatActionBarSharePhoneNumber {
+  fn from_class(class: &ChatActionBar) -> Option<&Self> { match class { ChatActionBar::SharePhoneNumber(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -165,6 +203,11 @@ impl ChatActionBarAddContact {
     RTDChatActionBarAddContactBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarAddContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +218,9 @@ pub struct RTDChatActionBarAddContactBuilder {
 impl RTDChatActionBarAddContactBuilder {
   pub fn build(&self) -> ChatActionBarAddContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarAddContact> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionBarAddContact> for ChatActionBarAddContact {
@@ -223,6 +269,11 @@ impl ChatActionBarInviteMembers {
     RTDChatActionBarInviteMembersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarInviteMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +284,9 @@ pub struct RTDChatActionBarInviteMembersBuilder {
 impl RTDChatActionBarInviteMembersBuilder {
   pub fn build(&self) -> ChatActionBarInviteMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarInviteMembers> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionBarInviteMembers> for ChatActionBarInviteMembers {
@@ -282,7 +336,16 @@ impl ChatActionBarReportAddBlock {
     let mut inner = ChatActionBarReportAddBlock::default();
     inner.td_name = "chatActionBarReportAddBlock".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatActionBarReportAddBlockBuilder { inner }
+    RTDChatActionBarReportAddBlockBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarReportAddBlock", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.distance == 0 { missing.push("distance".to_string()); }
+    missing
   }
 
   pub fn can_unarchive(&self) -> bool { self.can_unarchive }
@@ -293,12 +356,21 @@ impl ChatActionBarReportAddBlock {
 
 #[doc(hidden)]
 pub struct RTDChatActionBarReportAddBlockBuilder {
-  inner: ChatActionBarReportAddBlock
+  inner: ChatActionBarReportAddBlock,
+  set: [bool; 1]
 }
 
 impl RTDChatActionBarReportAddBlockBuilder {
   pub fn build(&self) -> ChatActionBarReportAddBlock { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarReportAddBlock> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("distance".to_string()); }
+    crate::types::_common::check_missing("chatActionBarReportAddBlock", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -308,6 +380,7 @@ impl RTDChatActionBarReportAddBlockBuilder {
    
   pub fn distance(&mut self, distance: i64) -> &mut Self {
     self.inner.distance = distance;
+    self.set[0] = true;
     self
   }
 
@@ -361,6 +434,11 @@ impl ChatActionBarReportSpam {
     RTDChatActionBarReportSpamBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarReportSpam", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn can_unarchive(&self) -> bool { self.can_unarchive }
 
 }
@@ -373,6 +451,9 @@ pub struct RTDChatActionBarReportSpamBuilder {
 impl RTDChatActionBarReportSpamBuilder {
   pub fn build(&self) -> ChatActionBarReportSpam { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarReportSpam> { Ok(self.build()) }
+
    
   pub fn can_unarchive(&mut self, can_unarchive: bool) -> &mut Self {
     self.inner.can_unarchive = can_unarchive;
@@ -427,6 +508,11 @@ impl ChatActionBarReportUnrelatedLocation {
     RTDChatActionBarReportUnrelatedLocationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarReportUnrelatedLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -437,6 +523,9 @@ pub struct RTDChatActionBarReportUnrelatedLocationBuilder {
 impl RTDChatActionBarReportUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatActionBarReportUnrelatedLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarReportUnrelatedLocation> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionBarReportUnrelatedLocation> for ChatActionBarReportUnrelatedLocation {
@@ -485,6 +574,11 @@ impl ChatActionBarSharePhoneNumber {
     RTDChatActionBarSharePhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatActionBarSharePhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -495,6 +589,9 @@ pub struct RTDChatActionBarSharePhoneNumberBuilder {
 impl RTDChatActionBarSharePhoneNumberBuilder {
   pub fn build(&self) -> ChatActionBarSharePhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatActionBarSharePhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatActionBarSharePhoneNumber> for ChatActionBarSharePhoneNumber {
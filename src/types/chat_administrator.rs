@@ -38,7 +38,17 @@ impl ChatAdministrator {
     let mut inner = ChatAdministrator::default();
     inner.td_name = "chatAdministrator".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatAdministratorBuilder { inner }
+    RTDChatAdministratorBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatAdministrator", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.custom_title.is_empty() { missing.push("custom_title".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -51,21 +61,33 @@ impl ChatAdministrator {
 
 #[doc(hidden)]
 pub struct RTDChatAdministratorBuilder {
-  inner: ChatAdministrator
+  inner: ChatAdministrator,
+  set: [bool; 2]
 }
 
 impl RTDChatAdministratorBuilder {
   pub fn build(&self) -> ChatAdministrator { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatAdministrator> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("custom_title".to_string()); }
+    crate::types::_common::check_missing("chatAdministrator", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl ChatAdministrators {
     RTDChatAdministratorsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatAdministrators", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn administrators(&self) -> &Vec<ChatAdministrator> { &self.administrators }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDChatAdministratorsBuilder {
 impl RTDChatAdministratorsBuilder {
   pub fn build(&self) -> ChatAdministrators { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatAdministrators> { Ok(self.build()) }
+
    
   pub fn administrators(&mut self, administrators: Vec<ChatAdministrator>) -> &mut Self {
     self.inner.administrators = administrators;
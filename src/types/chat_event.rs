@@ -16,7 +16,7 @@ pub struct ChatEvent {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Chat event identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Point in time (Unix timestamp) when the event happened
   date: i64,
   /// Identifier of the user who performed the action that triggered the event
@@ -40,10 +40,22 @@ impl ChatEvent {
     let mut inner = ChatEvent::default();
     inner.td_name = "chatEvent".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventBuilder { inner }
+    RTDChatEventBuilder { inner, set: [false; 3] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEvent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.action._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn date(&self) -> i64 { self.date }
 
@@ -55,27 +67,42 @@ impl ChatEvent {
 
 #[doc(hidden)]
 pub struct RTDChatEventBuilder {
-  inner: ChatEvent
+  inner: ChatEvent,
+  set: [bool; 3]
 }
 
 impl RTDChatEventBuilder {
   pub fn build(&self) -> ChatEvent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEvent> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("date".to_string()); }
+    if !self.set[2] { missing.push("user_id".to_string()); }
+    if self.inner.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.inner.action._missing_fields())); }
+    crate::types::_common::check_missing("chatEvent", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[1] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDChatEventAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatEventAction {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat description was changed
   ChatEventDescriptionChanged(ChatEventDescriptionChanged),
   /// The can_invite_users permission of a supergroup chat was toggled
@@ -80,7 +83,6 @@ impl Default for ChatEventAction {
 
 impl<'de> Deserialize<'de> for ChatEventAction {
   fn deserialize<D>(deserializer: D) -> Result<ChatEventAction, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatEventAction,
       (chatEventDescriptionChanged, ChatEventDescriptionChanged);
@@ -175,6 +177,7 @@ impl RObject for ChatEventAction {
       ChatEventAction::ChatEventVoiceChatDiscarded(t) => t.extra(),
       ChatEventAction::ChatEventVoiceChatMuteNewParticipantsToggled(t) => t.extra(),
       ChatEventAction::ChatEventVoiceChatParticipantIsMutedToggled(t) => t.extra(),
+      ChatEventAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -185,6 +188,37 @@ impl RObject for ChatEventAction {
 impl ChatEventAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatEventAction::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatEventAction::ChatEventDescriptionChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventInvitesToggled(t) => t._missing_fields(),
+      ChatEventAction::ChatEventIsAllHistoryAvailableToggled(t) => t._missing_fields(),
+      ChatEventAction::ChatEventLinkedChatChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventLocationChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMemberInvited(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMemberJoined(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMemberLeft(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMemberPromoted(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMemberRestricted(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMessageDeleted(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMessageEdited(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMessagePinned(t) => t._missing_fields(),
+      ChatEventAction::ChatEventMessageUnpinned(t) => t._missing_fields(),
+      ChatEventAction::ChatEventPermissionsChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventPhotoChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventPollStopped(t) => t._missing_fields(),
+      ChatEventAction::ChatEventSignMessagesToggled(t) => t._missing_fields(),
+      ChatEventAction::ChatEventSlowModeDelayChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventStickerSetChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventTitleChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventUsernameChanged(t) => t._missing_fields(),
+      ChatEventAction::ChatEventVoiceChatCreated(t) => t._missing_fields(),
+      ChatEventAction::ChatEventVoiceChatDiscarded(t) => t._missing_fields(),
+      ChatEventAction::ChatEventVoiceChatMuteNewParticipantsToggled(t) => t._missing_fields(),
+      ChatEventAction::ChatEventVoiceChatParticipantIsMutedToggled(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_chat_event_description_changed(&self) -> bool { if let ChatEventAction::ChatEventDescriptionChanged(_) = self { true } else { false } }
   pub fn is_chat_event_invites_toggled(&self) -> bool { if let ChatEventAction::ChatEventInvitesToggled(_) = self { true } else { false } }
@@ -327,6 +361,110 @@ impl AsRef<ChatEventAction> for ChatEventAction {
   fn as_ref(&self) -> &ChatEventAction { self }
 }
 
+impl RVariant<ChatEventAction> for ChatEventDescriptionChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventDescriptionChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventInvitesToggled {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventInvitesToggled(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventIsAllHistoryAvailableToggled {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventIsAllHistoryAvailableToggled(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventLinkedChatChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventLinkedChatChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventLocationChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventLocationChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMemberInvited {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMemberInvited(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMemberJoined {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMemberJoined(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMemberLeft {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMemberLeft(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMemberPromoted {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMemberPromoted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMemberRestricted {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMemberRestricted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMessageDeleted {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMessageDeleted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMessageEdited {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMessageEdited(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMessagePinned {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMessagePinned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventMessageUnpinned {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventMessageUnpinned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventPermissionsChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventPermissionsChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventPhotoChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventPhotoChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventPollStopped {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventPollStopped(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventSignMessagesToggled {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventSignMessagesToggled(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventSlowModeDelayChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventSlowModeDelayChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventStickerSetChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventStickerSetChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventTitleChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventTitleChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventUsernameChanged {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventUsernameChanged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventVoiceChatCreated {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventVoiceChatCreated(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventVoiceChatDiscarded {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventVoiceChatDiscarded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventVoiceChatMuteNewParticipantsToggled {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventVoiceChatMuteNewParticipantsToggled(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatEventAction> for ChatEventVoiceChatParticipantIsMutedToggled {
+  fn from_class(class: &ChatEventAction) -> Option<&Self> { match class { ChatEventAction::ChatEventVoiceChatParticipantIsMutedToggled(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -366,7 +504,17 @@ impl ChatEventDescriptionChanged {
     let mut inner = ChatEventDescriptionChanged::default();
     inner.td_name = "chatEventDescriptionChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventDescriptionChangedBuilder { inner }
+    RTDChatEventDescriptionChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventDescriptionChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_description.is_empty() { missing.push("old_description".to_string()); }
+    if self.new_description.is_empty() { missing.push("new_description".to_string()); }
+    missing
   }
 
   pub fn old_description(&self) -> &String { &self.old_description }
@@ -377,21 +525,33 @@ impl ChatEventDescriptionChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventDescriptionChangedBuilder {
-  inner: ChatEventDescriptionChanged
+  inner: ChatEventDescriptionChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventDescriptionChangedBuilder {
   pub fn build(&self) -> ChatEventDescriptionChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventDescriptionChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_description".to_string()); }
+    if !self.set[1] { missing.push("new_description".to_string()); }
+    crate::types::_common::check_missing("chatEventDescriptionChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_description<T: AsRef<str>>(&mut self, old_description: T) -> &mut Self {
     self.inner.old_description = old_description.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_description<T: AsRef<str>>(&mut self, new_description: T) -> &mut Self {
     self.inner.new_description = new_description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -445,6 +605,11 @@ impl ChatEventInvitesToggled {
     RTDChatEventInvitesToggledBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventInvitesToggled", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn can_invite_users(&self) -> bool { self.can_invite_users }
 
 }
@@ -457,6 +622,9 @@ pub struct RTDChatEventInvitesToggledBuilder {
 impl RTDChatEventInvitesToggledBuilder {
   pub fn build(&self) -> ChatEventInvitesToggled { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventInvitesToggled> { Ok(self.build()) }
+
    
   pub fn can_invite_users(&mut self, can_invite_users: bool) -> &mut Self {
     self.inner.can_invite_users = can_invite_users;
@@ -513,6 +681,11 @@ impl ChatEventIsAllHistoryAvailableToggled {
     RTDChatEventIsAllHistoryAvailableToggledBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventIsAllHistoryAvailableToggled", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_all_history_available(&self) -> bool { self.is_all_history_available }
 
 }
@@ -525,6 +698,9 @@ pub struct RTDChatEventIsAllHistoryAvailableToggledBuilder {
 impl RTDChatEventIsAllHistoryAvailableToggledBuilder {
   pub fn build(&self) -> ChatEventIsAllHistoryAvailableToggled { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventIsAllHistoryAvailableToggled> { Ok(self.build()) }
+
    
   pub fn is_all_history_available(&mut self, is_all_history_available: bool) -> &mut Self {
     self.inner.is_all_history_available = is_all_history_available;
@@ -580,7 +756,17 @@ impl ChatEventLinkedChatChanged {
     let mut inner = ChatEventLinkedChatChanged::default();
     inner.td_name = "chatEventLinkedChatChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventLinkedChatChangedBuilder { inner }
+    RTDChatEventLinkedChatChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventLinkedChatChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_linked_chat_id == 0 { missing.push("old_linked_chat_id".to_string()); }
+    if self.new_linked_chat_id == 0 { missing.push("new_linked_chat_id".to_string()); }
+    missing
   }
 
   pub fn old_linked_chat_id(&self) -> i64 { self.old_linked_chat_id }
@@ -591,21 +777,33 @@ impl ChatEventLinkedChatChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventLinkedChatChangedBuilder {
-  inner: ChatEventLinkedChatChanged
+  inner: ChatEventLinkedChatChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventLinkedChatChangedBuilder {
   pub fn build(&self) -> ChatEventLinkedChatChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventLinkedChatChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_linked_chat_id".to_string()); }
+    if !self.set[1] { missing.push("new_linked_chat_id".to_string()); }
+    crate::types::_common::check_missing("chatEventLinkedChatChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_linked_chat_id(&mut self, old_linked_chat_id: i64) -> &mut Self {
     self.inner.old_linked_chat_id = old_linked_chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_linked_chat_id(&mut self, new_linked_chat_id: i64) -> &mut Self {
     self.inner.new_linked_chat_id = new_linked_chat_id;
+    self.set[1] = true;
     self
   }
 
@@ -661,6 +859,11 @@ impl ChatEventLocationChanged {
     RTDChatEventLocationChangedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventLocationChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn old_location(&self) -> &Option<ChatLocation> { &self.old_location }
 
   pub fn new_location(&self) -> &Option<ChatLocation> { &self.new_location }
@@ -675,6 +878,9 @@ pub struct RTDChatEventLocationChangedBuilder {
 impl RTDChatEventLocationChangedBuilder {
   pub fn build(&self) -> ChatEventLocationChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventLocationChanged> { Ok(self.build()) }
+
    
   pub fn old_location<T: AsRef<ChatLocation>>(&mut self, old_location: T) -> &mut Self {
     self.inner.old_location = Some(old_location.as_ref().clone());
@@ -736,7 +942,17 @@ impl ChatEventMemberInvited {
     let mut inner = ChatEventMemberInvited::default();
     inner.td_name = "chatEventMemberInvited".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMemberInvitedBuilder { inner }
+    RTDChatEventMemberInvitedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMemberInvited", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -747,15 +963,26 @@ impl ChatEventMemberInvited {
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberInvitedBuilder {
-  inner: ChatEventMemberInvited
+  inner: ChatEventMemberInvited,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMemberInvitedBuilder {
   pub fn build(&self) -> ChatEventMemberInvited { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMemberInvited> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMemberInvited", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -813,6 +1040,11 @@ impl ChatEventMemberJoined {
     RTDChatEventMemberJoinedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMemberJoined", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -823,6 +1055,9 @@ pub struct RTDChatEventMemberJoinedBuilder {
 impl RTDChatEventMemberJoinedBuilder {
   pub fn build(&self) -> ChatEventMemberJoined { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMemberJoined> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatEventMemberJoined> for ChatEventMemberJoined {
@@ -871,6 +1106,11 @@ impl ChatEventMemberLeft {
     RTDChatEventMemberLeftBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMemberLeft", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -881,6 +1121,9 @@ pub struct RTDChatEventMemberLeftBuilder {
 impl RTDChatEventMemberLeftBuilder {
   pub fn build(&self) -> ChatEventMemberLeft { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMemberLeft> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatEventMemberLeft> for ChatEventMemberLeft {
@@ -932,7 +1175,18 @@ impl ChatEventMemberPromoted {
     let mut inner = ChatEventMemberPromoted::default();
     inner.td_name = "chatEventMemberPromoted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMemberPromotedBuilder { inner }
+    RTDChatEventMemberPromotedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMemberPromoted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.old_status._is_default() { missing.push("old_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_status", self.old_status._missing_fields())); }
+    if self.new_status._is_default() { missing.push("new_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_status", self.new_status._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -945,15 +1199,27 @@ impl ChatEventMemberPromoted {
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberPromotedBuilder {
-  inner: ChatEventMemberPromoted
+  inner: ChatEventMemberPromoted,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMemberPromotedBuilder {
   pub fn build(&self) -> ChatEventMemberPromoted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMemberPromoted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if self.inner.old_status._is_default() { missing.push("old_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_status", self.inner.old_status._missing_fields())); }
+    if self.inner.new_status._is_default() { missing.push("new_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_status", self.inner.new_status._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMemberPromoted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -1020,7 +1286,18 @@ impl ChatEventMemberRestricted {
     let mut inner = ChatEventMemberRestricted::default();
     inner.td_name = "chatEventMemberRestricted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMemberRestrictedBuilder { inner }
+    RTDChatEventMemberRestrictedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMemberRestricted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.old_status._is_default() { missing.push("old_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_status", self.old_status._missing_fields())); }
+    if self.new_status._is_default() { missing.push("new_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_status", self.new_status._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -1033,15 +1310,27 @@ impl ChatEventMemberRestricted {
 
 #[doc(hidden)]
 pub struct RTDChatEventMemberRestrictedBuilder {
-  inner: ChatEventMemberRestricted
+  inner: ChatEventMemberRestricted,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMemberRestrictedBuilder {
   pub fn build(&self) -> ChatEventMemberRestricted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMemberRestricted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if self.inner.old_status._is_default() { missing.push("old_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_status", self.inner.old_status._missing_fields())); }
+    if self.inner.new_status._is_default() { missing.push("new_status".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_status", self.inner.new_status._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMemberRestricted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -1104,7 +1393,16 @@ impl ChatEventMessageDeleted {
     let mut inner = ChatEventMessageDeleted::default();
     inner.td_name = "chatEventMessageDeleted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMessageDeletedBuilder { inner }
+    RTDChatEventMessageDeletedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMessageDeleted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -1113,15 +1411,25 @@ impl ChatEventMessageDeleted {
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageDeletedBuilder {
-  inner: ChatEventMessageDeleted
+  inner: ChatEventMessageDeleted,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMessageDeletedBuilder {
   pub fn build(&self) -> ChatEventMessageDeleted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMessageDeleted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMessageDeleted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1174,7 +1482,17 @@ impl ChatEventMessageEdited {
     let mut inner = ChatEventMessageEdited::default();
     inner.td_name = "chatEventMessageEdited".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMessageEditedBuilder { inner }
+    RTDChatEventMessageEditedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMessageEdited", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("old_message".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_message", self.old_message._missing_fields())); }
+    if false { missing.push("new_message".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_message", self.new_message._missing_fields())); }
+    missing
   }
 
   pub fn old_message(&self) -> &Message { &self.old_message }
@@ -1185,21 +1503,33 @@ impl ChatEventMessageEdited {
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageEditedBuilder {
-  inner: ChatEventMessageEdited
+  inner: ChatEventMessageEdited,
+  set: [bool; 2]
 }
 
 impl RTDChatEventMessageEditedBuilder {
   pub fn build(&self) -> ChatEventMessageEdited { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMessageEdited> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_message".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_message", self.inner.old_message._missing_fields())); }
+    if !self.set[1] { missing.push("new_message".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_message", self.inner.new_message._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMessageEdited", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_message<T: AsRef<Message>>(&mut self, old_message: T) -> &mut Self {
     self.inner.old_message = old_message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_message<T: AsRef<Message>>(&mut self, new_message: T) -> &mut Self {
     self.inner.new_message = new_message.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1250,7 +1580,16 @@ impl ChatEventMessagePinned {
     let mut inner = ChatEventMessagePinned::default();
     inner.td_name = "chatEventMessagePinned".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMessagePinnedBuilder { inner }
+    RTDChatEventMessagePinnedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMessagePinned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -1259,15 +1598,25 @@ impl ChatEventMessagePinned {
 
 #[doc(hidden)]
 pub struct RTDChatEventMessagePinnedBuilder {
-  inner: ChatEventMessagePinned
+  inner: ChatEventMessagePinned,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMessagePinnedBuilder {
   pub fn build(&self) -> ChatEventMessagePinned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMessagePinned> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMessagePinned", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1318,7 +1667,16 @@ impl ChatEventMessageUnpinned {
     let mut inner = ChatEventMessageUnpinned::default();
     inner.td_name = "chatEventMessageUnpinned".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventMessageUnpinnedBuilder { inner }
+    RTDChatEventMessageUnpinnedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventMessageUnpinned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -1327,15 +1685,25 @@ impl ChatEventMessageUnpinned {
 
 #[doc(hidden)]
 pub struct RTDChatEventMessageUnpinnedBuilder {
-  inner: ChatEventMessageUnpinned
+  inner: ChatEventMessageUnpinned,
+  set: [bool; 1]
 }
 
 impl RTDChatEventMessageUnpinnedBuilder {
   pub fn build(&self) -> ChatEventMessageUnpinned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventMessageUnpinned> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("chatEventMessageUnpinned", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1388,7 +1756,17 @@ impl ChatEventPermissionsChanged {
     let mut inner = ChatEventPermissionsChanged::default();
     inner.td_name = "chatEventPermissionsChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventPermissionsChangedBuilder { inner }
+    RTDChatEventPermissionsChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventPermissionsChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("old_permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_permissions", self.old_permissions._missing_fields())); }
+    if false { missing.push("new_permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_permissions", self.new_permissions._missing_fields())); }
+    missing
   }
 
   pub fn old_permissions(&self) -> &ChatPermissions { &self.old_permissions }
@@ -1399,21 +1777,33 @@ impl ChatEventPermissionsChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventPermissionsChangedBuilder {
-  inner: ChatEventPermissionsChanged
+  inner: ChatEventPermissionsChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventPermissionsChangedBuilder {
   pub fn build(&self) -> ChatEventPermissionsChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventPermissionsChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("old_permissions", self.inner.old_permissions._missing_fields())); }
+    if !self.set[1] { missing.push("new_permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_permissions", self.inner.new_permissions._missing_fields())); }
+    crate::types::_common::check_missing("chatEventPermissionsChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_permissions<T: AsRef<ChatPermissions>>(&mut self, old_permissions: T) -> &mut Self {
     self.inner.old_permissions = old_permissions.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_permissions<T: AsRef<ChatPermissions>>(&mut self, new_permissions: T) -> &mut Self {
     self.inner.new_permissions = new_permissions.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1469,6 +1859,11 @@ impl ChatEventPhotoChanged {
     RTDChatEventPhotoChangedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventPhotoChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn old_photo(&self) -> &Option<ChatPhoto> { &self.old_photo }
 
   pub fn new_photo(&self) -> &Option<ChatPhoto> { &self.new_photo }
@@ -1483,6 +1878,9 @@ pub struct RTDChatEventPhotoChangedBuilder {
 impl RTDChatEventPhotoChangedBuilder {
   pub fn build(&self) -> ChatEventPhotoChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventPhotoChanged> { Ok(self.build()) }
+
    
   pub fn old_photo<T: AsRef<ChatPhoto>>(&mut self, old_photo: T) -> &mut Self {
     self.inner.old_photo = Some(old_photo.as_ref().clone());
@@ -1542,7 +1940,16 @@ impl ChatEventPollStopped {
     let mut inner = ChatEventPollStopped::default();
     inner.td_name = "chatEventPollStopped".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventPollStoppedBuilder { inner }
+    RTDChatEventPollStoppedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventPollStopped", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -1551,15 +1958,25 @@ impl ChatEventPollStopped {
 
 #[doc(hidden)]
 pub struct RTDChatEventPollStoppedBuilder {
-  inner: ChatEventPollStopped
+  inner: ChatEventPollStopped,
+  set: [bool; 1]
 }
 
 impl RTDChatEventPollStoppedBuilder {
   pub fn build(&self) -> ChatEventPollStopped { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventPollStopped> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("chatEventPollStopped", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1613,6 +2030,11 @@ impl ChatEventSignMessagesToggled {
     RTDChatEventSignMessagesToggledBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventSignMessagesToggled", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn sign_messages(&self) -> bool { self.sign_messages }
 
 }
@@ -1625,6 +2047,9 @@ pub struct RTDChatEventSignMessagesToggledBuilder {
 impl RTDChatEventSignMessagesToggledBuilder {
   pub fn build(&self) -> ChatEventSignMessagesToggled { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventSignMessagesToggled> { Ok(self.build()) }
+
    
   pub fn sign_messages(&mut self, sign_messages: bool) -> &mut Self {
     self.inner.sign_messages = sign_messages;
@@ -1680,7 +2105,17 @@ impl ChatEventSlowModeDelayChanged {
     let mut inner = ChatEventSlowModeDelayChanged::default();
     inner.td_name = "chatEventSlowModeDelayChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventSlowModeDelayChangedBuilder { inner }
+    RTDChatEventSlowModeDelayChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventSlowModeDelayChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_slow_mode_delay == 0 { missing.push("old_slow_mode_delay".to_string()); }
+    if self.new_slow_mode_delay == 0 { missing.push("new_slow_mode_delay".to_string()); }
+    missing
   }
 
   pub fn old_slow_mode_delay(&self) -> i64 { self.old_slow_mode_delay }
@@ -1691,21 +2126,33 @@ impl ChatEventSlowModeDelayChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventSlowModeDelayChangedBuilder {
-  inner: ChatEventSlowModeDelayChanged
+  inner: ChatEventSlowModeDelayChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventSlowModeDelayChangedBuilder {
   pub fn build(&self) -> ChatEventSlowModeDelayChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventSlowModeDelayChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_slow_mode_delay".to_string()); }
+    if !self.set[1] { missing.push("new_slow_mode_delay".to_string()); }
+    crate::types::_common::check_missing("chatEventSlowModeDelayChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_slow_mode_delay(&mut self, old_slow_mode_delay: i64) -> &mut Self {
     self.inner.old_slow_mode_delay = old_slow_mode_delay;
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_slow_mode_delay(&mut self, new_slow_mode_delay: i64) -> &mut Self {
     self.inner.new_slow_mode_delay = new_slow_mode_delay;
+    self.set[1] = true;
     self
   }
 
@@ -1735,9 +2182,9 @@ pub struct ChatEventStickerSetChanged {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Previous identifier of the chat sticker set; 0 if none
-  old_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] old_sticker_set_id: i64,
   /// New identifier of the chat sticker set; 0 if none
-  new_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] new_sticker_set_id: i64,
   
 }
 
@@ -1761,9 +2208,14 @@ impl ChatEventStickerSetChanged {
     RTDChatEventStickerSetChangedBuilder { inner }
   }
 
-  pub fn old_sticker_set_id(&self) -> isize { self.old_sticker_set_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventStickerSetChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
 
-  pub fn new_sticker_set_id(&self) -> isize { self.new_sticker_set_id }
+  pub fn old_sticker_set_id(&self) -> i64 { self.old_sticker_set_id }
+
+  pub fn new_sticker_set_id(&self) -> i64 { self.new_sticker_set_id }
 
 }
 
@@ -1775,14 +2227,17 @@ pub struct RTDChatEventStickerSetChangedBuilder {
 impl RTDChatEventStickerSetChangedBuilder {
   pub fn build(&self) -> ChatEventStickerSetChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventStickerSetChanged> { Ok(self.build()) }
+
    
-  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: isize) -> &mut Self {
+  pub fn old_sticker_set_id(&mut self, old_sticker_set_id: i64) -> &mut Self {
     self.inner.old_sticker_set_id = old_sticker_set_id;
     self
   }
 
    
-  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: isize) -> &mut Self {
+  pub fn new_sticker_set_id(&mut self, new_sticker_set_id: i64) -> &mut Self {
     self.inner.new_sticker_set_id = new_sticker_set_id;
     self
   }
@@ -1836,7 +2291,17 @@ impl ChatEventTitleChanged {
     let mut inner = ChatEventTitleChanged::default();
     inner.td_name = "chatEventTitleChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventTitleChangedBuilder { inner }
+    RTDChatEventTitleChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventTitleChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_title.is_empty() { missing.push("old_title".to_string()); }
+    if self.new_title.is_empty() { missing.push("new_title".to_string()); }
+    missing
   }
 
   pub fn old_title(&self) -> &String { &self.old_title }
@@ -1847,21 +2312,33 @@ impl ChatEventTitleChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventTitleChangedBuilder {
-  inner: ChatEventTitleChanged
+  inner: ChatEventTitleChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventTitleChangedBuilder {
   pub fn build(&self) -> ChatEventTitleChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventTitleChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_title".to_string()); }
+    if !self.set[1] { missing.push("new_title".to_string()); }
+    crate::types::_common::check_missing("chatEventTitleChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_title<T: AsRef<str>>(&mut self, old_title: T) -> &mut Self {
     self.inner.old_title = old_title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_title<T: AsRef<str>>(&mut self, new_title: T) -> &mut Self {
     self.inner.new_title = new_title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1914,7 +2391,17 @@ impl ChatEventUsernameChanged {
     let mut inner = ChatEventUsernameChanged::default();
     inner.td_name = "chatEventUsernameChanged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventUsernameChangedBuilder { inner }
+    RTDChatEventUsernameChangedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventUsernameChanged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_username.is_empty() { missing.push("old_username".to_string()); }
+    if self.new_username.is_empty() { missing.push("new_username".to_string()); }
+    missing
   }
 
   pub fn old_username(&self) -> &String { &self.old_username }
@@ -1925,21 +2412,33 @@ impl ChatEventUsernameChanged {
 
 #[doc(hidden)]
 pub struct RTDChatEventUsernameChangedBuilder {
-  inner: ChatEventUsernameChanged
+  inner: ChatEventUsernameChanged,
+  set: [bool; 2]
 }
 
 impl RTDChatEventUsernameChangedBuilder {
   pub fn build(&self) -> ChatEventUsernameChanged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventUsernameChanged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_username".to_string()); }
+    if !self.set[1] { missing.push("new_username".to_string()); }
+    crate::types::_common::check_missing("chatEventUsernameChanged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_username<T: AsRef<str>>(&mut self, old_username: T) -> &mut Self {
     self.inner.old_username = old_username.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_username<T: AsRef<str>>(&mut self, new_username: T) -> &mut Self {
     self.inner.new_username = new_username.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1990,7 +2489,16 @@ impl ChatEventVoiceChatCreated {
     let mut inner = ChatEventVoiceChatCreated::default();
     inner.td_name = "chatEventVoiceChatCreated".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventVoiceChatCreatedBuilder { inner }
+    RTDChatEventVoiceChatCreatedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventVoiceChatCreated", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -1999,15 +2507,25 @@ impl ChatEventVoiceChatCreated {
 
 #[doc(hidden)]
 pub struct RTDChatEventVoiceChatCreatedBuilder {
-  inner: ChatEventVoiceChatCreated
+  inner: ChatEventVoiceChatCreated,
+  set: [bool; 1]
 }
 
 impl RTDChatEventVoiceChatCreatedBuilder {
   pub fn build(&self) -> ChatEventVoiceChatCreated { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventVoiceChatCreated> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("chatEventVoiceChatCreated", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -2058,7 +2576,16 @@ impl ChatEventVoiceChatDiscarded {
     let mut inner = ChatEventVoiceChatDiscarded::default();
     inner.td_name = "chatEventVoiceChatDiscarded".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventVoiceChatDiscardedBuilder { inner }
+    RTDChatEventVoiceChatDiscardedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventVoiceChatDiscarded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -2067,15 +2594,25 @@ impl ChatEventVoiceChatDiscarded {
 
 #[doc(hidden)]
 pub struct RTDChatEventVoiceChatDiscardedBuilder {
-  inner: ChatEventVoiceChatDiscarded
+  inner: ChatEventVoiceChatDiscarded,
+  set: [bool; 1]
 }
 
 impl RTDChatEventVoiceChatDiscardedBuilder {
   pub fn build(&self) -> ChatEventVoiceChatDiscarded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventVoiceChatDiscarded> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("chatEventVoiceChatDiscarded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -2129,6 +2666,11 @@ impl ChatEventVoiceChatMuteNewParticipantsToggled {
     RTDChatEventVoiceChatMuteNewParticipantsToggledBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventVoiceChatMuteNewParticipantsToggled", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn mute_new_participants(&self) -> bool { self.mute_new_participants }
 
 }
@@ -2141,6 +2683,9 @@ pub struct RTDChatEventVoiceChatMuteNewParticipantsToggledBuilder {
 impl RTDChatEventVoiceChatMuteNewParticipantsToggledBuilder {
   pub fn build(&self) -> ChatEventVoiceChatMuteNewParticipantsToggled { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventVoiceChatMuteNewParticipantsToggled> { Ok(self.build()) }
+
    
   pub fn mute_new_participants(&mut self, mute_new_participants: bool) -> &mut Self {
     self.inner.mute_new_participants = mute_new_participants;
@@ -2196,7 +2741,16 @@ impl ChatEventVoiceChatParticipantIsMutedToggled {
     let mut inner = ChatEventVoiceChatParticipantIsMutedToggled::default();
     inner.td_name = "chatEventVoiceChatParticipantIsMutedToggled".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatEventVoiceChatParticipantIsMutedToggledBuilder { inner }
+    RTDChatEventVoiceChatParticipantIsMutedToggledBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventVoiceChatParticipantIsMutedToggled", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -2207,15 +2761,25 @@ impl ChatEventVoiceChatParticipantIsMutedToggled {
 
 #[doc(hidden)]
 pub struct RTDChatEventVoiceChatParticipantIsMutedToggledBuilder {
-  inner: ChatEventVoiceChatParticipantIsMutedToggled
+  inner: ChatEventVoiceChatParticipantIsMutedToggled,
+  set: [bool; 1]
 }
 
 impl RTDChatEventVoiceChatParticipantIsMutedToggledBuilder {
   pub fn build(&self) -> ChatEventVoiceChatParticipantIsMutedToggled { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventVoiceChatParticipantIsMutedToggled> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("chatEventVoiceChatParticipantIsMutedToggled", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
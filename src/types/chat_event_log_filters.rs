@@ -57,6 +57,11 @@ impl ChatEventLogFilters {
     RTDChatEventLogFiltersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEventLogFilters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn message_edits(&self) -> bool { self.message_edits }
 
   pub fn message_deletions(&self) -> bool { self.message_deletions }
@@ -89,6 +94,9 @@ pub struct RTDChatEventLogFiltersBuilder {
 impl RTDChatEventLogFiltersBuilder {
   pub fn build(&self) -> ChatEventLogFilters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEventLogFilters> { Ok(self.build()) }
+
    
   pub fn message_edits(&mut self, message_edits: bool) -> &mut Self {
     self.inner.message_edits = message_edits;
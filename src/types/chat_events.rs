@@ -37,6 +37,11 @@ impl ChatEvents {
     RTDChatEventsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatEvents", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn events(&self) -> &Vec<ChatEvent> { &self.events }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDChatEventsBuilder {
 impl RTDChatEventsBuilder {
   pub fn build(&self) -> ChatEvents { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatEvents> { Ok(self.build()) }
+
    
   pub fn events(&mut self, events: Vec<ChatEvent>) -> &mut Self {
     self.inner.events = events;
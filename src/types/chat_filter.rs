@@ -58,7 +58,16 @@ impl ChatFilter {
     let mut inner = ChatFilter::default();
     inner.td_name = "chatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatFilterBuilder { inner }
+    RTDChatFilterBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -91,15 +100,25 @@ impl ChatFilter {
 
 #[doc(hidden)]
 pub struct RTDChatFilterBuilder {
-  inner: ChatFilter
+  inner: ChatFilter,
+  set: [bool; 1]
 }
 
 impl RTDChatFilterBuilder {
   pub fn build(&self) -> ChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("chatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -38,7 +38,18 @@ impl ChatFilterInfo {
     let mut inner = ChatFilterInfo::default();
     inner.td_name = "chatFilterInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatFilterInfoBuilder { inner }
+    RTDChatFilterInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatFilterInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.icon_name.is_empty() { missing.push("icon_name".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -51,27 +62,41 @@ impl ChatFilterInfo {
 
 #[doc(hidden)]
 pub struct RTDChatFilterInfoBuilder {
-  inner: ChatFilterInfo
+  inner: ChatFilterInfo,
+  set: [bool; 3]
 }
 
 impl RTDChatFilterInfoBuilder {
   pub fn build(&self) -> ChatFilterInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatFilterInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("icon_name".to_string()); }
+    crate::types::_common::check_missing("chatFilterInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn icon_name<T: AsRef<str>>(&mut self, icon_name: T) -> &mut Self {
     self.inner.icon_name = icon_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -52,7 +52,19 @@ impl ChatInviteLink {
     let mut inner = ChatInviteLink::default();
     inner.td_name = "chatInviteLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatInviteLinkBuilder { inner }
+    RTDChatInviteLinkBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatInviteLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.invite_link.is_empty() { missing.push("invite_link".to_string()); }
+    if self.administrator_user_id == 0 { missing.push("administrator_user_id".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.member_count == 0 { missing.push("member_count".to_string()); }
+    missing
   }
 
   pub fn invite_link(&self) -> &String { &self.invite_link }
@@ -79,27 +91,42 @@ impl ChatInviteLink {
 
 #[doc(hidden)]
 pub struct RTDChatInviteLinkBuilder {
-  inner: ChatInviteLink
+  inner: ChatInviteLink,
+  set: [bool; 4]
 }
 
 impl RTDChatInviteLinkBuilder {
   pub fn build(&self) -> ChatInviteLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatInviteLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invite_link".to_string()); }
+    if !self.set[1] { missing.push("administrator_user_id".to_string()); }
+    if !self.set[2] { missing.push("date".to_string()); }
+    if !self.set[3] { missing.push("member_count".to_string()); }
+    crate::types::_common::check_missing("chatInviteLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn administrator_user_id(&mut self, administrator_user_id: i64) -> &mut Self {
     self.inner.administrator_user_id = administrator_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[2] = true;
     self
   }
 
@@ -124,6 +151,7 @@ impl RTDChatInviteLinkBuilder {
    
   pub fn member_count(&mut self, member_count: i64) -> &mut Self {
     self.inner.member_count = member_count;
+    self.set[3] = true;
     self
   }
 
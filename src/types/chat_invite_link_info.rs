@@ -48,7 +48,19 @@ impl ChatInviteLinkInfo {
     let mut inner = ChatInviteLinkInfo::default();
     inner.td_name = "chatInviteLinkInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatInviteLinkInfoBuilder { inner }
+    RTDChatInviteLinkInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatInviteLinkInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.accessible_for == 0 { missing.push("accessible_for".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.member_count == 0 { missing.push("member_count".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -71,12 +83,24 @@ impl ChatInviteLinkInfo {
 
 #[doc(hidden)]
 pub struct RTDChatInviteLinkInfoBuilder {
-  inner: ChatInviteLinkInfo
+  inner: ChatInviteLinkInfo,
+  set: [bool; 3]
 }
 
 impl RTDChatInviteLinkInfoBuilder {
   pub fn build(&self) -> ChatInviteLinkInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatInviteLinkInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("accessible_for".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("member_count".to_string()); }
+    crate::types::_common::check_missing("chatInviteLinkInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -86,6 +110,7 @@ impl RTDChatInviteLinkInfoBuilder {
    
   pub fn accessible_for(&mut self, accessible_for: i64) -> &mut Self {
     self.inner.accessible_for = accessible_for;
+    self.set[0] = true;
     self
   }
 
@@ -98,6 +123,7 @@ impl RTDChatInviteLinkInfoBuilder {
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -110,6 +136,7 @@ impl RTDChatInviteLinkInfoBuilder {
    
   pub fn member_count(&mut self, member_count: i64) -> &mut Self {
     self.inner.member_count = member_count;
+    self.set[2] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl ChatInviteLinkMember {
     let mut inner = ChatInviteLinkMember::default();
     inner.td_name = "chatInviteLinkMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatInviteLinkMemberBuilder { inner }
+    RTDChatInviteLinkMemberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatInviteLinkMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.joined_chat_date == 0 { missing.push("joined_chat_date".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -47,21 +57,33 @@ impl ChatInviteLinkMember {
 
 #[doc(hidden)]
 pub struct RTDChatInviteLinkMemberBuilder {
-  inner: ChatInviteLinkMember
+  inner: ChatInviteLinkMember,
+  set: [bool; 2]
 }
 
 impl RTDChatInviteLinkMemberBuilder {
   pub fn build(&self) -> ChatInviteLinkMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatInviteLinkMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("joined_chat_date".to_string()); }
+    crate::types::_common::check_missing("chatInviteLinkMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn joined_chat_date(&mut self, joined_chat_date: i64) -> &mut Self {
     self.inner.joined_chat_date = joined_chat_date;
+    self.set[1] = true;
     self
   }
 
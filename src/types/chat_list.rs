@@ -19,6 +19,9 @@ pub trait TDChatList: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatList {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives
   Archive(ChatListArchive),
   /// A list of chats belonging to a chat filter
@@ -34,7 +37,6 @@ impl Default for ChatList {
 
 impl<'de> Deserialize<'de> for ChatList {
   fn deserialize<D>(deserializer: D) -> Result<ChatList, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatList,
       (chatListArchive, Archive);
@@ -60,6 +62,7 @@ impl RObject for ChatList {
       ChatList::Archive(t) => t.extra(),
       ChatList::Filter(t) => t.extra(),
       ChatList::Main(t) => t.extra(),
+      ChatList::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for ChatList {
 impl ChatList {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatList::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatList::Archive(t) => t._missing_fields(),
+      ChatList::Filter(t) => t._missing_fields(),
+      ChatList::Main(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_archive(&self) -> bool { if let ChatList::Archive(_) = self { true } else { false } }
   pub fn is_filter(&self) -> bool { if let ChatList::Filter(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<ChatList> for ChatList {
   fn as_ref(&self) -> &ChatList { self }
 }
 
+impl RVariant<ChatList> for ChatListArchive {
+  fn from_class(class: &ChatList) -> Option<&Self> { match class { ChatList::Archive(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatList> for ChatListFilter {
+  fn from_class(class: &ChatList) -> Option<&Self> { match class { ChatList::Filter(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatList> for ChatListMain {
+  fn from_class(class: &ChatList) -> Option<&Self> { match class { ChatList::Main(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl ChatListArchive {
     RTDChatListArchiveBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatListArchive", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDChatListArchiveBuilder {
 impl RTDChatListArchiveBuilder {
   pub fn build(&self) -> ChatListArchive { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatListArchive> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatListArchive> for ChatListArchive {
@@ -192,7 +223,16 @@ impl ChatListFilter {
     let mut inner = ChatListFilter::default();
     inner.td_name = "chatListFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatListFilterBuilder { inner }
+    RTDChatListFilterBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatListFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_filter_id == 0 { missing.push("chat_filter_id".to_string()); }
+    missing
   }
 
   pub fn chat_filter_id(&self) -> i64 { self.chat_filter_id }
@@ -201,15 +241,25 @@ impl ChatListFilter {
 
 #[doc(hidden)]
 pub struct RTDChatListFilterBuilder {
-  inner: ChatListFilter
+  inner: ChatListFilter,
+  set: [bool; 1]
 }
 
 impl RTDChatListFilterBuilder {
   pub fn build(&self) -> ChatListFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatListFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_filter_id".to_string()); }
+    crate::types::_common::check_missing("chatListFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
+    self.set[0] = true;
     self
   }
 
@@ -261,6 +311,11 @@ impl ChatListMain {
     RTDChatListMainBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatListMain", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +326,9 @@ pub struct RTDChatListMainBuilder {
 impl RTDChatListMainBuilder {
   pub fn build(&self) -> ChatListMain { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatListMain> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatListMain> for ChatListMain {
@@ -37,6 +37,11 @@ impl ChatLists {
     RTDChatListsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatLists", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_lists(&self) -> &Vec<ChatList> { &self.chat_lists }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDChatListsBuilder {
 impl RTDChatListsBuilder {
   pub fn build(&self) -> ChatLists { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatLists> { Ok(self.build()) }
+
    
   pub fn chat_lists(&mut self, chat_lists: Vec<ChatList>) -> &mut Self {
     self.inner.chat_lists = chat_lists;
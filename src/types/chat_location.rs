@@ -36,7 +36,17 @@ impl ChatLocation {
     let mut inner = ChatLocation::default();
     inner.td_name = "chatLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatLocationBuilder { inner }
+    RTDChatLocationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.address.is_empty() { missing.push("address".to_string()); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -47,21 +57,33 @@ impl ChatLocation {
 
 #[doc(hidden)]
 pub struct RTDChatLocationBuilder {
-  inner: ChatLocation
+  inner: ChatLocation,
+  set: [bool; 2]
 }
 
 impl RTDChatLocationBuilder {
   pub fn build(&self) -> ChatLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("address".to_string()); }
+    crate::types::_common::check_missing("chatLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn address<T: AsRef<str>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
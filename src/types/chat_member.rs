@@ -42,7 +42,18 @@ impl ChatMember {
     let mut inner = ChatMember::default();
     inner.td_name = "chatMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatMemberBuilder { inner }
+    RTDChatMemberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.joined_chat_date == 0 { missing.push("joined_chat_date".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -59,15 +70,27 @@ impl ChatMember {
 
 #[doc(hidden)]
 pub struct RTDChatMemberBuilder {
-  inner: ChatMember
+  inner: ChatMember,
+  set: [bool; 2]
 }
 
 impl RTDChatMemberBuilder {
   pub fn build(&self) -> ChatMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("joined_chat_date".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    crate::types::_common::check_missing("chatMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -80,6 +103,7 @@ impl RTDChatMemberBuilder {
    
   pub fn joined_chat_date(&mut self, joined_chat_date: i64) -> &mut Self {
     self.inner.joined_chat_date = joined_chat_date;
+    self.set[1] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDChatMemberStatus: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatMemberStatus {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user is a member of a chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, ban unprivileged members, and manage voice chats. In supergroups and channels, there are more detailed options for administrator privileges
   Administrator(ChatMemberStatusAdministrator),
   /// The user was banned (and hence is not a member of the chat). Implies the user can't return to the chat or view messages
@@ -40,7 +43,6 @@ impl Default for ChatMemberStatus {
 
 impl<'de> Deserialize<'de> for ChatMemberStatus {
   fn deserialize<D>(deserializer: D) -> Result<ChatMemberStatus, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatMemberStatus,
       (chatMemberStatusAdministrator, Administrator);
@@ -75,6 +77,7 @@ impl RObject for ChatMemberStatus {
       ChatMemberStatus::Left(t) => t.extra(),
       ChatMemberStatus::Member(t) => t.extra(),
       ChatMemberStatus::Restricted(t) => t.extra(),
+      ChatMemberStatus::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,6 +88,17 @@ impl RObject for ChatMemberStatus {
 impl ChatMemberStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMemberStatus::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatMemberStatus::Administrator(t) => t._missing_fields(),
+      ChatMemberStatus::Banned(t) => t._missing_fields(),
+      ChatMemberStatus::Creator(t) => t._missing_fields(),
+      ChatMemberStatus::Left(t) => t._missing_fields(),
+      ChatMemberStatus::Member(t) => t._missing_fields(),
+      ChatMemberStatus::Restricted(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_administrator(&self) -> bool { if let ChatMemberStatus::Administrator(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let ChatMemberStatus::Banned(_) = self { true } else { false } }
@@ -127,6 +141,30 @@ impl AsRef<ChatMemberStatus> for ChatMemberStatus {
   fn as_ref(&self) -> &ChatMemberStatus { self }
 }
 
+impl RVariant<ChatMemberStatus> for ChatMemberStatusAdministrator {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Administrator(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMemberStatus> for ChatMemberStatusBanned {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Banned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMemberStatus> for ChatMemberStatusCreator {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Creator(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMemberStatus> for ChatMemberStatusLeft {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Left(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMemberStatus> for ChatMemberStatusMember {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Member(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMemberStatus> for ChatMemberStatusRestricted {
+  fn from_class(class: &ChatMemberStatus) -> Option<&Self> { match class { ChatMemberStatus::Restricted(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -186,7 +224,16 @@ impl ChatMemberStatusAdministrator {
     let mut inner = ChatMemberStatusAdministrator::default();
     inner.td_name = "chatMemberStatusAdministrator".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatMemberStatusAdministratorBuilder { inner }
+    RTDChatMemberStatusAdministratorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusAdministrator", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.custom_title.is_empty() { missing.push("custom_title".to_string()); }
+    missing
   }
 
   pub fn custom_title(&self) -> &String { &self.custom_title }
@@ -217,15 +264,25 @@ impl ChatMemberStatusAdministrator {
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusAdministratorBuilder {
-  inner: ChatMemberStatusAdministrator
+  inner: ChatMemberStatusAdministrator,
+  set: [bool; 1]
 }
 
 impl RTDChatMemberStatusAdministratorBuilder {
   pub fn build(&self) -> ChatMemberStatusAdministrator { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusAdministrator> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("custom_title".to_string()); }
+    crate::types::_common::check_missing("chatMemberStatusAdministrator", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -345,6 +402,11 @@ impl ChatMemberStatusBanned {
     RTDChatMemberStatusBannedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusBanned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn banned_until_date(&self) -> i64 { self.banned_until_date }
 
 }
@@ -357,6 +419,9 @@ pub struct RTDChatMemberStatusBannedBuilder {
 impl RTDChatMemberStatusBannedBuilder {
   pub fn build(&self) -> ChatMemberStatusBanned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusBanned> { Ok(self.build()) }
+
    
   pub fn banned_until_date(&mut self, banned_until_date: i64) -> &mut Self {
     self.inner.banned_until_date = banned_until_date;
@@ -414,7 +479,16 @@ impl ChatMemberStatusCreator {
     let mut inner = ChatMemberStatusCreator::default();
     inner.td_name = "chatMemberStatusCreator".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatMemberStatusCreatorBuilder { inner }
+    RTDChatMemberStatusCreatorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusCreator", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.custom_title.is_empty() { missing.push("custom_title".to_string()); }
+    missing
   }
 
   pub fn custom_title(&self) -> &String { &self.custom_title }
@@ -427,15 +501,25 @@ impl ChatMemberStatusCreator {
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusCreatorBuilder {
-  inner: ChatMemberStatusCreator
+  inner: ChatMemberStatusCreator,
+  set: [bool; 1]
 }
 
 impl RTDChatMemberStatusCreatorBuilder {
   pub fn build(&self) -> ChatMemberStatusCreator { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusCreator> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("custom_title".to_string()); }
+    crate::types::_common::check_missing("chatMemberStatusCreator", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn custom_title<T: AsRef<str>>(&mut self, custom_title: T) -> &mut Self {
     self.inner.custom_title = custom_title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -499,6 +583,11 @@ impl ChatMemberStatusLeft {
     RTDChatMemberStatusLeftBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusLeft", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -509,6 +598,9 @@ pub struct RTDChatMemberStatusLeftBuilder {
 impl RTDChatMemberStatusLeftBuilder {
   pub fn build(&self) -> ChatMemberStatusLeft { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusLeft> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMemberStatusLeft> for ChatMemberStatusLeft {
@@ -557,6 +649,11 @@ impl ChatMemberStatusMember {
     RTDChatMemberStatusMemberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -567,6 +664,9 @@ pub struct RTDChatMemberStatusMemberBuilder {
 impl RTDChatMemberStatusMemberBuilder {
   pub fn build(&self) -> ChatMemberStatusMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusMember> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMemberStatusMember> for ChatMemberStatusMember {
@@ -618,7 +718,16 @@ impl ChatMemberStatusRestricted {
     let mut inner = ChatMemberStatusRestricted::default();
     inner.td_name = "chatMemberStatusRestricted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatMemberStatusRestrictedBuilder { inner }
+    RTDChatMemberStatusRestrictedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMemberStatusRestricted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.permissions._missing_fields())); }
+    missing
   }
 
   pub fn is_member(&self) -> bool { self.is_member }
@@ -631,12 +740,21 @@ impl ChatMemberStatusRestricted {
 
 #[doc(hidden)]
 pub struct RTDChatMemberStatusRestrictedBuilder {
-  inner: ChatMemberStatusRestricted
+  inner: ChatMemberStatusRestricted,
+  set: [bool; 1]
 }
 
 impl RTDChatMemberStatusRestrictedBuilder {
   pub fn build(&self) -> ChatMemberStatusRestricted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMemberStatusRestricted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.inner.permissions._missing_fields())); }
+    crate::types::_common::check_missing("chatMemberStatusRestricted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_member(&mut self, is_member: bool) -> &mut Self {
     self.inner.is_member = is_member;
@@ -652,6 +770,7 @@ impl RTDChatMemberStatusRestrictedBuilder {
    
   pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
     self.inner.permissions = permissions.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDChatMembersFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatMembersFilter {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns the owner and administrators
   Administrators(ChatMembersFilterAdministrators),
   /// Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel
@@ -42,7 +45,6 @@ impl Default for ChatMembersFilter {
 
 impl<'de> Deserialize<'de> for ChatMembersFilter {
   fn deserialize<D>(deserializer: D) -> Result<ChatMembersFilter, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatMembersFilter,
       (chatMembersFilterAdministrators, Administrators);
@@ -80,6 +82,7 @@ impl RObject for ChatMembersFilter {
       ChatMembersFilter::Members(t) => t.extra(),
       ChatMembersFilter::Mention(t) => t.extra(),
       ChatMembersFilter::Restricted(t) => t.extra(),
+      ChatMembersFilter::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -90,6 +93,18 @@ impl RObject for ChatMembersFilter {
 impl ChatMembersFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatMembersFilter::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatMembersFilter::Administrators(t) => t._missing_fields(),
+      ChatMembersFilter::Banned(t) => t._missing_fields(),
+      ChatMembersFilter::Bots(t) => t._missing_fields(),
+      ChatMembersFilter::Contacts(t) => t._missing_fields(),
+      ChatMembersFilter::Members(t) => t._missing_fields(),
+      ChatMembersFilter::Mention(t) => t._missing_fields(),
+      ChatMembersFilter::Restricted(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_administrators(&self) -> bool { if let ChatMembersFilter::Administrators(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let ChatMembersFilter::Banned(_) = self { true } else { false } }
@@ -137,6 +152,34 @@ impl AsRef<ChatMembersFilter> for ChatMembersFilter {
   fn as_ref(&self) -> &ChatMembersFilter { self }
 }
 
+impl RVariant<ChatMembersFilter> for ChatMembersFilterAdministrators {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Administrators(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterBanned {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Banned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterBots {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Bots(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterContacts {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Contacts(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterMembers {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Members(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterMention {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Mention(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatMembersFilter> for ChatMembersFilterRestricted {
+  fn from_class(class: &ChatMembersFilter) -> Option<&Self> { match class { ChatMembersFilter::Restricted(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -175,6 +218,11 @@ impl ChatMembersFilterAdministrators {
     RTDChatMembersFilterAdministratorsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterAdministrators", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +233,9 @@ pub struct RTDChatMembersFilterAdministratorsBuilder {
 impl RTDChatMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> ChatMembersFilterAdministrators { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterAdministrators> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterAdministrators> for ChatMembersFilterAdministrators {
@@ -233,6 +284,11 @@ impl ChatMembersFilterBanned {
     RTDChatMembersFilterBannedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterBanned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -243,6 +299,9 @@ pub struct RTDChatMembersFilterBannedBuilder {
 impl RTDChatMembersFilterBannedBuilder {
   pub fn build(&self) -> ChatMembersFilterBanned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterBanned> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterBanned> for ChatMembersFilterBanned {
@@ -291,6 +350,11 @@ impl ChatMembersFilterBots {
     RTDChatMembersFilterBotsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterBots", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -301,6 +365,9 @@ pub struct RTDChatMembersFilterBotsBuilder {
 impl RTDChatMembersFilterBotsBuilder {
   pub fn build(&self) -> ChatMembersFilterBots { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterBots> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterBots> for ChatMembersFilterBots {
@@ -349,6 +416,11 @@ impl ChatMembersFilterContacts {
     RTDChatMembersFilterContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -359,6 +431,9 @@ pub struct RTDChatMembersFilterContactsBuilder {
 impl RTDChatMembersFilterContactsBuilder {
   pub fn build(&self) -> ChatMembersFilterContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterContacts> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterContacts> for ChatMembersFilterContacts {
@@ -407,6 +482,11 @@ impl ChatMembersFilterMembers {
     RTDChatMembersFilterMembersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -417,6 +497,9 @@ pub struct RTDChatMembersFilterMembersBuilder {
 impl RTDChatMembersFilterMembersBuilder {
   pub fn build(&self) -> ChatMembersFilterMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterMembers> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterMembers> for ChatMembersFilterMembers {
@@ -464,7 +547,16 @@ impl ChatMembersFilterMention {
     let mut inner = ChatMembersFilterMention::default();
     inner.td_name = "chatMembersFilterMention".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatMembersFilterMentionBuilder { inner }
+    RTDChatMembersFilterMentionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterMention", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_thread_id == 0 { missing.push("message_thread_id".to_string()); }
+    missing
   }
 
   pub fn message_thread_id(&self) -> i64 { self.message_thread_id }
@@ -473,15 +565,25 @@ impl ChatMembersFilterMention {
 
 #[doc(hidden)]
 pub struct RTDChatMembersFilterMentionBuilder {
-  inner: ChatMembersFilterMention
+  inner: ChatMembersFilterMention,
+  set: [bool; 1]
 }
 
 impl RTDChatMembersFilterMentionBuilder {
   pub fn build(&self) -> ChatMembersFilterMention { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterMention> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message_thread_id".to_string()); }
+    crate::types::_common::check_missing("chatMembersFilterMention", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_thread_id(&mut self, message_thread_id: i64) -> &mut Self {
     self.inner.message_thread_id = message_thread_id;
+    self.set[0] = true;
     self
   }
 
@@ -533,6 +635,11 @@ impl ChatMembersFilterRestricted {
     RTDChatMembersFilterRestrictedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatMembersFilterRestricted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -543,6 +650,9 @@ pub struct RTDChatMembersFilterRestrictedBuilder {
 impl RTDChatMembersFilterRestrictedBuilder {
   pub fn build(&self) -> ChatMembersFilterRestricted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatMembersFilterRestricted> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatMembersFilterRestricted> for ChatMembersFilterRestricted {
@@ -36,7 +36,17 @@ impl ChatNearby {
     let mut inner = ChatNearby::default();
     inner.td_name = "chatNearby".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatNearbyBuilder { inner }
+    RTDChatNearbyBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatNearby", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.distance == 0 { missing.push("distance".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -47,21 +57,33 @@ impl ChatNearby {
 
 #[doc(hidden)]
 pub struct RTDChatNearbyBuilder {
-  inner: ChatNearby
+  inner: ChatNearby,
+  set: [bool; 2]
 }
 
 impl RTDChatNearbyBuilder {
   pub fn build(&self) -> ChatNearby { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatNearby> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("distance".to_string()); }
+    crate::types::_common::check_missing("chatNearby", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn distance(&mut self, distance: i64) -> &mut Self {
     self.inner.distance = distance;
+    self.set[1] = true;
     self
   }
 
@@ -52,7 +52,17 @@ impl ChatNotificationSettings {
     let mut inner = ChatNotificationSettings::default();
     inner.td_name = "chatNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatNotificationSettingsBuilder { inner }
+    RTDChatNotificationSettingsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.mute_for == 0 { missing.push("mute_for".to_string()); }
+    if self.sound.is_empty() { missing.push("sound".to_string()); }
+    missing
   }
 
   pub fn use_default_mute_for(&self) -> bool { self.use_default_mute_for }
@@ -79,12 +89,22 @@ impl ChatNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDChatNotificationSettingsBuilder {
-  inner: ChatNotificationSettings
+  inner: ChatNotificationSettings,
+  set: [bool; 2]
 }
 
 impl RTDChatNotificationSettingsBuilder {
   pub fn build(&self) -> ChatNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatNotificationSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("mute_for".to_string()); }
+    if !self.set[1] { missing.push("sound".to_string()); }
+    crate::types::_common::check_missing("chatNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn use_default_mute_for(&mut self, use_default_mute_for: bool) -> &mut Self {
     self.inner.use_default_mute_for = use_default_mute_for;
@@ -94,6 +114,7 @@ impl RTDChatNotificationSettingsBuilder {
    
   pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
     self.inner.mute_for = mute_for;
+    self.set[0] = true;
     self
   }
 
@@ -106,6 +127,7 @@ impl RTDChatNotificationSettingsBuilder {
    
   pub fn sound<T: AsRef<str>>(&mut self, sound: T) -> &mut Self {
     self.inner.sound = sound.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -51,6 +51,11 @@ impl ChatPermissions {
     RTDChatPermissionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatPermissions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn can_send_messages(&self) -> bool { self.can_send_messages }
 
   pub fn can_send_media_messages(&self) -> bool { self.can_send_media_messages }
@@ -77,6 +82,9 @@ pub struct RTDChatPermissionsBuilder {
 impl RTDChatPermissionsBuilder {
   pub fn build(&self) -> ChatPermissions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatPermissions> { Ok(self.build()) }
+
    
   pub fn can_send_messages(&mut self, can_send_messages: bool) -> &mut Self {
     self.inner.can_send_messages = can_send_messages;
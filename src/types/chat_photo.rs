@@ -16,7 +16,7 @@ pub struct ChatPhoto {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique photo identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Point in time (Unix timestamp) when the photo has been added
   added_date: i64,
   /// Photo minithumbnail; may be null
@@ -42,10 +42,20 @@ impl ChatPhoto {
     let mut inner = ChatPhoto::default();
     inner.td_name = "chatPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatPhotoBuilder { inner }
+    RTDChatPhotoBuilder { inner, set: [false; 2] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.added_date == 0 { missing.push("added_date".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn added_date(&self) -> i64 { self.added_date }
 
@@ -59,21 +69,33 @@ impl ChatPhoto {
 
 #[doc(hidden)]
 pub struct RTDChatPhotoBuilder {
-  inner: ChatPhoto
+  inner: ChatPhoto,
+  set: [bool; 2]
 }
 
 impl RTDChatPhotoBuilder {
   pub fn build(&self) -> ChatPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("added_date".to_string()); }
+    crate::types::_common::check_missing("chatPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn added_date(&mut self, added_date: i64) -> &mut Self {
     self.inner.added_date = added_date;
+    self.set[1] = true;
     self
   }
 
@@ -38,7 +38,17 @@ impl ChatPhotoInfo {
     let mut inner = ChatPhotoInfo::default();
     inner.td_name = "chatPhotoInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatPhotoInfoBuilder { inner }
+    RTDChatPhotoInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatPhotoInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("small".to_string()); } else { missing.extend(crate::types::_common::missing_in("small", self.small._missing_fields())); }
+    if false { missing.push("big".to_string()); } else { missing.extend(crate::types::_common::missing_in("big", self.big._missing_fields())); }
+    missing
   }
 
   pub fn small(&self) -> &File { &self.small }
@@ -51,21 +61,33 @@ impl ChatPhotoInfo {
 
 #[doc(hidden)]
 pub struct RTDChatPhotoInfoBuilder {
-  inner: ChatPhotoInfo
+  inner: ChatPhotoInfo,
+  set: [bool; 2]
 }
 
 impl RTDChatPhotoInfoBuilder {
   pub fn build(&self) -> ChatPhotoInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatPhotoInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("small".to_string()); } else { missing.extend(crate::types::_common::missing_in("small", self.inner.small._missing_fields())); }
+    if !self.set[1] { missing.push("big".to_string()); } else { missing.extend(crate::types::_common::missing_in("big", self.inner.big._missing_fields())); }
+    crate::types::_common::check_missing("chatPhotoInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
     self.inner.small = small.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn big<T: AsRef<File>>(&mut self, big: T) -> &mut Self {
     self.inner.big = big.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
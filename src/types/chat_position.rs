@@ -18,7 +18,7 @@ pub struct ChatPosition {
   /// The chat list
   list: ChatList,
   /// A parameter used to determine order of the chat in the chat list. Chats must be sorted by the pair (order, chat.id) in descending order
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] order: isize,
+  #[serde(with = "crate::types::_common::int64")] order: i64,
   /// True, if the chat is pinned in the chat list
   is_pinned: bool,
   /// Source of the chat in the chat list; may be null
@@ -40,12 +40,22 @@ impl ChatPosition {
     let mut inner = ChatPosition::default();
     inner.td_name = "chatPosition".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatPositionBuilder { inner }
+    RTDChatPositionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatPosition", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.list._is_default() { missing.push("list".to_string()); } else { missing.extend(crate::types::_common::missing_in("list", self.list._missing_fields())); }
+    if self.order == 0 { missing.push("order".to_string()); }
+    missing
   }
 
   pub fn list(&self) -> &ChatList { &self.list }
 
-  pub fn order(&self) -> isize { self.order }
+  pub fn order(&self) -> i64 { self.order }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
@@ -55,12 +65,22 @@ impl ChatPosition {
 
 #[doc(hidden)]
 pub struct RTDChatPositionBuilder {
-  inner: ChatPosition
+  inner: ChatPosition,
+  set: [bool; 1]
 }
 
 impl RTDChatPositionBuilder {
   pub fn build(&self) -> ChatPosition { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatPosition> {
+    let mut missing = vec![];
+    if self.inner.list._is_default() { missing.push("list".to_string()); } else { missing.extend(crate::types::_common::missing_in("list", self.inner.list._missing_fields())); }
+    if !self.set[0] { missing.push("order".to_string()); }
+    crate::types::_common::check_missing("chatPosition", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn list<T: AsRef<ChatList>>(&mut self, list: T) -> &mut Self {
     self.inner.list = list.as_ref().clone();
@@ -68,8 +88,9 @@ impl RTDChatPositionBuilder {
   }
 
    
-  pub fn order(&mut self, order: isize) -> &mut Self {
+  pub fn order(&mut self, order: i64) -> &mut Self {
     self.inner.order = order;
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDChatReportReason: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatReportReason {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat has child abuse related content
   ChildAbuse(ChatReportReasonChildAbuse),
   /// The chat contains copyrighted content
@@ -44,7 +47,6 @@ impl Default for ChatReportReason {
 
 impl<'de> Deserialize<'de> for ChatReportReason {
   fn deserialize<D>(deserializer: D) -> Result<ChatReportReason, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatReportReason,
       (chatReportReasonChildAbuse, ChildAbuse);
@@ -85,6 +87,7 @@ impl RObject for ChatReportReason {
       ChatReportReason::Spam(t) => t.extra(),
       ChatReportReason::UnrelatedLocation(t) => t.extra(),
       ChatReportReason::Violence(t) => t.extra(),
+      ChatReportReason::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -95,6 +98,19 @@ impl RObject for ChatReportReason {
 impl ChatReportReason {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatReportReason::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatReportReason::ChildAbuse(t) => t._missing_fields(),
+      ChatReportReason::Copyright(t) => t._missing_fields(),
+      ChatReportReason::Custom(t) => t._missing_fields(),
+      ChatReportReason::Fake(t) => t._missing_fields(),
+      ChatReportReason::Pornography(t) => t._missing_fields(),
+      ChatReportReason::Spam(t) => t._missing_fields(),
+      ChatReportReason::UnrelatedLocation(t) => t._missing_fields(),
+      ChatReportReason::Violence(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_child_abuse(&self) -> bool { if let ChatReportReason::ChildAbuse(_) = self { true } else { false } }
   pub fn is_copyright(&self) -> bool { if let ChatReportReason::Copyright(_) = self { true } else { false } }
@@ -147,6 +163,38 @@ impl AsRef<ChatReportReason> for ChatReportReason {
   fn as_ref(&self) -> &ChatReportReason { self }
 }
 
+impl RVariant<ChatReportReason> for ChatReportReasonChildAbuse {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::ChildAbuse(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonCopyright {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Copyright(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonCustom {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Custom(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonFake {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Fake(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonPornography {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Pornography(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonSpam {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Spam(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonUnrelatedLocation {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::UnrelatedLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatReportReason> for ChatReportReasonViolence {
+  fn from_class(class: &ChatReportReason) -> Option<&Self> { match class { ChatReportReason::Violence(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -185,6 +233,11 @@ impl ChatReportReasonChildAbuse {
     RTDChatReportReasonChildAbuseBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonChildAbuse", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +248,9 @@ pub struct RTDChatReportReasonChildAbuseBuilder {
 impl RTDChatReportReasonChildAbuseBuilder {
   pub fn build(&self) -> ChatReportReasonChildAbuse { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonChildAbuse> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonChildAbuse> for ChatReportReasonChildAbuse {
@@ -243,6 +299,11 @@ impl ChatReportReasonCopyright {
     RTDChatReportReasonCopyrightBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonCopyright", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -253,6 +314,9 @@ pub struct RTDChatReportReasonCopyrightBuilder {
 impl RTDChatReportReasonCopyrightBuilder {
   pub fn build(&self) -> ChatReportReasonCopyright { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonCopyright> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonCopyright> for ChatReportReasonCopyright {
@@ -300,7 +364,16 @@ impl ChatReportReasonCustom {
     let mut inner = ChatReportReasonCustom::default();
     inner.td_name = "chatReportReasonCustom".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatReportReasonCustomBuilder { inner }
+    RTDChatReportReasonCustomBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonCustom", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -309,15 +382,25 @@ impl ChatReportReasonCustom {
 
 #[doc(hidden)]
 pub struct RTDChatReportReasonCustomBuilder {
-  inner: ChatReportReasonCustom
+  inner: ChatReportReasonCustom,
+  set: [bool; 1]
 }
 
 impl RTDChatReportReasonCustomBuilder {
   pub fn build(&self) -> ChatReportReasonCustom { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonCustom> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("chatReportReasonCustom", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -369,6 +452,11 @@ impl ChatReportReasonFake {
     RTDChatReportReasonFakeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonFake", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -379,6 +467,9 @@ pub struct RTDChatReportReasonFakeBuilder {
 impl RTDChatReportReasonFakeBuilder {
   pub fn build(&self) -> ChatReportReasonFake { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonFake> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonFake> for ChatReportReasonFake {
@@ -427,6 +518,11 @@ impl ChatReportReasonPornography {
     RTDChatReportReasonPornographyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonPornography", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -437,6 +533,9 @@ pub struct RTDChatReportReasonPornographyBuilder {
 impl RTDChatReportReasonPornographyBuilder {
   pub fn build(&self) -> ChatReportReasonPornography { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonPornography> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonPornography> for ChatReportReasonPornography {
@@ -485,6 +584,11 @@ impl ChatReportReasonSpam {
     RTDChatReportReasonSpamBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonSpam", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -495,6 +599,9 @@ pub struct RTDChatReportReasonSpamBuilder {
 impl RTDChatReportReasonSpamBuilder {
   pub fn build(&self) -> ChatReportReasonSpam { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonSpam> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonSpam> for ChatReportReasonSpam {
@@ -543,6 +650,11 @@ impl ChatReportReasonUnrelatedLocation {
     RTDChatReportReasonUnrelatedLocationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonUnrelatedLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -553,6 +665,9 @@ pub struct RTDChatReportReasonUnrelatedLocationBuilder {
 impl RTDChatReportReasonUnrelatedLocationBuilder {
   pub fn build(&self) -> ChatReportReasonUnrelatedLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonUnrelatedLocation> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonUnrelatedLocation> for ChatReportReasonUnrelatedLocation {
@@ -601,6 +716,11 @@ impl ChatReportReasonViolence {
     RTDChatReportReasonViolenceBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatReportReasonViolence", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -611,6 +731,9 @@ pub struct RTDChatReportReasonViolenceBuilder {
 impl RTDChatReportReasonViolenceBuilder {
   pub fn build(&self) -> ChatReportReasonViolence { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatReportReasonViolence> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatReportReasonViolence> for ChatReportReasonViolence {
@@ -19,6 +19,9 @@ pub trait TDChatSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatSource {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is sponsored by the user's MTProxy server
   MtprotoProxy(ChatSourceMtprotoProxy),
   /// The chat contains a public service announcement
@@ -32,7 +35,6 @@ impl Default for ChatSource {
 
 impl<'de> Deserialize<'de> for ChatSource {
   fn deserialize<D>(deserializer: D) -> Result<ChatSource, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatSource,
       (chatSourceMtprotoProxy, MtprotoProxy);
@@ -55,6 +57,7 @@ impl RObject for ChatSource {
     match self {
       ChatSource::MtprotoProxy(t) => t.extra(),
       ChatSource::PublicServiceAnnouncement(t) => t.extra(),
+      ChatSource::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for ChatSource {
 impl ChatSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatSource::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatSource::MtprotoProxy(t) => t._missing_fields(),
+      ChatSource::PublicServiceAnnouncement(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_mtproto_proxy(&self) -> bool { if let ChatSource::MtprotoProxy(_) = self { true } else { false } }
   pub fn is_public_service_announcement(&self) -> bool { if let ChatSource::PublicServiceAnnouncement(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<ChatSource> for ChatSource {
   fn as_ref(&self) -> &ChatSource { self }
 }
 
+impl RVariant<ChatSource> for ChatSourceMtprotoProxy {
+  fn from_class(class: &ChatSource) -> Option<&Self> { match class { ChatSource::MtprotoProxy(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatSource> for ChatSourcePublicServiceAnnouncement {
+  fn from_class(class: &ChatSource) -> Option<&Self> { match class { ChatSource::PublicServiceAnnouncement(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -125,6 +143,11 @@ impl ChatSourceMtprotoProxy {
     RTDChatSourceMtprotoProxyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatSourceMtprotoProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +158,9 @@ pub struct RTDChatSourceMtprotoProxyBuilder {
 impl RTDChatSourceMtprotoProxyBuilder {
   pub fn build(&self) -> ChatSourceMtprotoProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatSourceMtprotoProxy> { Ok(self.build()) }
+
 }
 
 impl AsRef<ChatSourceMtprotoProxy> for ChatSourceMtprotoProxy {
@@ -184,7 +210,17 @@ impl ChatSourcePublicServiceAnnouncement {
     let mut inner = ChatSourcePublicServiceAnnouncement::default();
     inner.td_name = "chatSourcePublicServiceAnnouncement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatSourcePublicServiceAnnouncementBuilder { inner }
+    RTDChatSourcePublicServiceAnnouncementBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatSourcePublicServiceAnnouncement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn type_(&self) -> &String { &self.type_ }
@@ -195,21 +231,33 @@ impl ChatSourcePublicServiceAnnouncement {
 
 #[doc(hidden)]
 pub struct RTDChatSourcePublicServiceAnnouncementBuilder {
-  inner: ChatSourcePublicServiceAnnouncement
+  inner: ChatSourcePublicServiceAnnouncement,
+  set: [bool; 2]
 }
 
 impl RTDChatSourcePublicServiceAnnouncementBuilder {
   pub fn build(&self) -> ChatSourcePublicServiceAnnouncement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatSourcePublicServiceAnnouncement> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("type".to_string()); }
+    if !self.set[1] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("chatSourcePublicServiceAnnouncement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -19,12 +19,13 @@ pub trait TDChatStatistics: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatStatistics {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A detailed statistics about a channel chat
   Channel(ChatStatisticsChannel),
   /// A detailed statistics about a supergroup chat
   Supergroup(ChatStatisticsSupergroup),
-  /// Returns detailed statistics about a chat. Currently this method can be used only for supergroups and channels. Can be used only if SupergroupFullInfo.can_get_statistics == true
-  GetChatStatistics(GetChatStatistics),
 
 }
 
@@ -34,12 +35,10 @@ impl Default for ChatStatistics {
 
 impl<'de> Deserialize<'de> for ChatStatistics {
   fn deserialize<D>(deserializer: D) -> Result<ChatStatistics, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatStatistics,
       (chatStatisticsChannel, Channel);
       (chatStatisticsSupergroup, Supergroup);
-      (getChatStatistics, GetChatStatistics);
 
     )(deserializer)
   }
@@ -50,7 +49,6 @@ impl RObject for ChatStatistics {
     match self {
       ChatStatistics::Channel(t) => t.td_name(),
       ChatStatistics::Supergroup(t) => t.td_name(),
-      ChatStatistics::GetChatStatistics(t) => t.td_name(),
 
       _ => "-1",
     }
@@ -59,7 +57,7 @@ impl RObject for ChatStatistics {
     match self {
       ChatStatistics::Channel(t) => t.extra(),
       ChatStatistics::Supergroup(t) => t.extra(),
-      ChatStatistics::GetChatStatistics(t) => t.extra(),
+      ChatStatistics::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,18 +68,22 @@ impl RObject for ChatStatistics {
 impl ChatStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatStatistics::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatStatistics::Channel(t) => t._missing_fields(),
+      ChatStatistics::Supergroup(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_channel(&self) -> bool { if let ChatStatistics::Channel(_) = self { true } else { false } }
   pub fn is_supergroup(&self) -> bool { if let ChatStatistics::Supergroup(_) = self { true } else { false } }
-  pub fn is_get_chat_statistics(&self) -> bool { if let ChatStatistics::GetChatStatistics(_) = self { true } else { false } }
 
   pub fn on_channel<F: FnOnce(&ChatStatisticsChannel)>(&self, fnc: F) -> &Self { if let ChatStatistics::Channel(t) = self { fnc(t) }; self }
   pub fn on_supergroup<F: FnOnce(&ChatStatisticsSupergroup)>(&self, fnc: F) -> &Self { if let ChatStatistics::Supergroup(t) = self { fnc(t) }; self }
-  pub fn on_get_chat_statistics<F: FnOnce(&GetChatStatistics)>(&self, fnc: F) -> &Self { if let ChatStatistics::GetChatStatistics(t) = self { fnc(t) }; self }
 
   pub fn as_channel(&self) -> Option<&ChatStatisticsChannel> { if let ChatStatistics::Channel(t) = self { return Some(t) } None }
   pub fn as_supergroup(&self) -> Option<&ChatStatisticsSupergroup> { if let ChatStatistics::Supergroup(t) = self { return Some(t) } None }
-  pub fn as_get_chat_statistics(&self) -> Option<&GetChatStatistics> { if let ChatStatistics::GetChatStatistics(t) = self { return Some(t) } None }
 
 
 
@@ -89,14 +91,20 @@ impl ChatStatistics {
 
   pub fn supergroup<T: AsRef<ChatStatisticsSupergroup>>(t: T) -> Self { ChatStatistics::Supergroup(t.as_ref().clone()) }
 
-  pub fn get_chat_statistics<T: AsRef<GetChatStatistics>>(t: T) -> Self { ChatStatistics::GetChatStatistics(t.as_ref().clone()) }
-
 }
 
 impl AsRef<ChatStatistics> for ChatStatistics {
   fn as_ref(&self) -> &ChatStatistics { self }
 }
 
+impl RVariant<ChatStatistics> for ChatStatisticsChannel {
+  fn from_class(class: &ChatStatistics) -> Option<&Self> { match class { ChatStatistics::Channel(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatStatistics> for ChatStatisticsSupergroup {
+  fn from_class(class: &ChatStatistics) -> Option<&Self> { match class { ChatStatistics::Supergroup(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -162,7 +170,29 @@ impl ChatStatisticsChannel {
     let mut inner = ChatStatisticsChannel::default();
     inner.td_name = "chatStatisticsChannel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsChannelBuilder { inner }
+    RTDChatStatisticsChannelBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsChannel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("period".to_string()); } else { missing.extend(crate::types::_common::missing_in("period", self.period._missing_fields())); }
+    if false { missing.push("member_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count", self.member_count._missing_fields())); }
+    if false { missing.push("mean_view_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("mean_view_count", self.mean_view_count._missing_fields())); }
+    if false { missing.push("mean_share_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("mean_share_count", self.mean_share_count._missing_fields())); }
+    if self.enabled_notifications_percentage == 0.0 { missing.push("enabled_notifications_percentage".to_string()); }
+    if self.member_count_graph._is_default() { missing.push("member_count_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count_graph", self.member_count_graph._missing_fields())); }
+    if self.join_graph._is_default() { missing.push("join_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_graph", self.join_graph._missing_fields())); }
+    if self.mute_graph._is_default() { missing.push("mute_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("mute_graph", self.mute_graph._missing_fields())); }
+    if self.view_count_by_hour_graph._is_default() { missing.push("view_count_by_hour_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("view_count_by_hour_graph", self.view_count_by_hour_graph._missing_fields())); }
+    if self.view_count_by_source_graph._is_default() { missing.push("view_count_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("view_count_by_source_graph", self.view_count_by_source_graph._missing_fields())); }
+    if self.join_by_source_graph._is_default() { missing.push("join_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_by_source_graph", self.join_by_source_graph._missing_fields())); }
+    if self.language_graph._is_default() { missing.push("language_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("language_graph", self.language_graph._missing_fields())); }
+    if self.message_interaction_graph._is_default() { missing.push("message_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_interaction_graph", self.message_interaction_graph._missing_fields())); }
+    if self.instant_view_interaction_graph._is_default() { missing.push("instant_view_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("instant_view_interaction_graph", self.instant_view_interaction_graph._missing_fields())); }
+    missing
   }
 
   pub fn period(&self) -> &DateRange { &self.period }
@@ -199,39 +229,66 @@ impl ChatStatisticsChannel {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsChannelBuilder {
-  inner: ChatStatisticsChannel
+  inner: ChatStatisticsChannel,
+  set: [bool; 5]
 }
 
 impl RTDChatStatisticsChannelBuilder {
   pub fn build(&self) -> ChatStatisticsChannel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsChannel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("period".to_string()); } else { missing.extend(crate::types::_common::missing_in("period", self.inner.period._missing_fields())); }
+    if !self.set[1] { missing.push("member_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count", self.inner.member_count._missing_fields())); }
+    if !self.set[2] { missing.push("mean_view_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("mean_view_count", self.inner.mean_view_count._missing_fields())); }
+    if !self.set[3] { missing.push("mean_share_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("mean_share_count", self.inner.mean_share_count._missing_fields())); }
+    if !self.set[4] { missing.push("enabled_notifications_percentage".to_string()); }
+    if self.inner.member_count_graph._is_default() { missing.push("member_count_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count_graph", self.inner.member_count_graph._missing_fields())); }
+    if self.inner.join_graph._is_default() { missing.push("join_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_graph", self.inner.join_graph._missing_fields())); }
+    if self.inner.mute_graph._is_default() { missing.push("mute_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("mute_graph", self.inner.mute_graph._missing_fields())); }
+    if self.inner.view_count_by_hour_graph._is_default() { missing.push("view_count_by_hour_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("view_count_by_hour_graph", self.inner.view_count_by_hour_graph._missing_fields())); }
+    if self.inner.view_count_by_source_graph._is_default() { missing.push("view_count_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("view_count_by_source_graph", self.inner.view_count_by_source_graph._missing_fields())); }
+    if self.inner.join_by_source_graph._is_default() { missing.push("join_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_by_source_graph", self.inner.join_by_source_graph._missing_fields())); }
+    if self.inner.language_graph._is_default() { missing.push("language_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("language_graph", self.inner.language_graph._missing_fields())); }
+    if self.inner.message_interaction_graph._is_default() { missing.push("message_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_interaction_graph", self.inner.message_interaction_graph._missing_fields())); }
+    if self.inner.instant_view_interaction_graph._is_default() { missing.push("instant_view_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("instant_view_interaction_graph", self.inner.instant_view_interaction_graph._missing_fields())); }
+    crate::types::_common::check_missing("chatStatisticsChannel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn member_count<T: AsRef<StatisticalValue>>(&mut self, member_count: T) -> &mut Self {
     self.inner.member_count = member_count.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn mean_view_count<T: AsRef<StatisticalValue>>(&mut self, mean_view_count: T) -> &mut Self {
     self.inner.mean_view_count = mean_view_count.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
    
   pub fn mean_share_count<T: AsRef<StatisticalValue>>(&mut self, mean_share_count: T) -> &mut Self {
     self.inner.mean_share_count = mean_share_count.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
    
   pub fn enabled_notifications_percentage(&mut self, enabled_notifications_percentage: f32) -> &mut Self {
     self.inner.enabled_notifications_percentage = enabled_notifications_percentage;
+    self.set[4] = true;
     self
   }
 
@@ -372,7 +429,28 @@ impl ChatStatisticsSupergroup {
     let mut inner = ChatStatisticsSupergroup::default();
     inner.td_name = "chatStatisticsSupergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsSupergroupBuilder { inner }
+    RTDChatStatisticsSupergroupBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsSupergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("period".to_string()); } else { missing.extend(crate::types::_common::missing_in("period", self.period._missing_fields())); }
+    if false { missing.push("member_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count", self.member_count._missing_fields())); }
+    if false { missing.push("message_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_count", self.message_count._missing_fields())); }
+    if false { missing.push("viewer_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("viewer_count", self.viewer_count._missing_fields())); }
+    if false { missing.push("sender_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender_count", self.sender_count._missing_fields())); }
+    if self.member_count_graph._is_default() { missing.push("member_count_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count_graph", self.member_count_graph._missing_fields())); }
+    if self.join_graph._is_default() { missing.push("join_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_graph", self.join_graph._missing_fields())); }
+    if self.join_by_source_graph._is_default() { missing.push("join_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_by_source_graph", self.join_by_source_graph._missing_fields())); }
+    if self.language_graph._is_default() { missing.push("language_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("language_graph", self.language_graph._missing_fields())); }
+    if self.message_content_graph._is_default() { missing.push("message_content_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_content_graph", self.message_content_graph._missing_fields())); }
+    if self.action_graph._is_default() { missing.push("action_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("action_graph", self.action_graph._missing_fields())); }
+    if self.day_graph._is_default() { missing.push("day_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("day_graph", self.day_graph._missing_fields())); }
+    if self.week_graph._is_default() { missing.push("week_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("week_graph", self.week_graph._missing_fields())); }
+    missing
   }
 
   pub fn period(&self) -> &DateRange { &self.period }
@@ -411,39 +489,65 @@ impl ChatStatisticsSupergroup {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsSupergroupBuilder {
-  inner: ChatStatisticsSupergroup
+  inner: ChatStatisticsSupergroup,
+  set: [bool; 5]
 }
 
 impl RTDChatStatisticsSupergroupBuilder {
   pub fn build(&self) -> ChatStatisticsSupergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsSupergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("period".to_string()); } else { missing.extend(crate::types::_common::missing_in("period", self.inner.period._missing_fields())); }
+    if !self.set[1] { missing.push("member_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count", self.inner.member_count._missing_fields())); }
+    if !self.set[2] { missing.push("message_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_count", self.inner.message_count._missing_fields())); }
+    if !self.set[3] { missing.push("viewer_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("viewer_count", self.inner.viewer_count._missing_fields())); }
+    if !self.set[4] { missing.push("sender_count".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender_count", self.inner.sender_count._missing_fields())); }
+    if self.inner.member_count_graph._is_default() { missing.push("member_count_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("member_count_graph", self.inner.member_count_graph._missing_fields())); }
+    if self.inner.join_graph._is_default() { missing.push("join_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_graph", self.inner.join_graph._missing_fields())); }
+    if self.inner.join_by_source_graph._is_default() { missing.push("join_by_source_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("join_by_source_graph", self.inner.join_by_source_graph._missing_fields())); }
+    if self.inner.language_graph._is_default() { missing.push("language_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("language_graph", self.inner.language_graph._missing_fields())); }
+    if self.inner.message_content_graph._is_default() { missing.push("message_content_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_content_graph", self.inner.message_content_graph._missing_fields())); }
+    if self.inner.action_graph._is_default() { missing.push("action_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("action_graph", self.inner.action_graph._missing_fields())); }
+    if self.inner.day_graph._is_default() { missing.push("day_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("day_graph", self.inner.day_graph._missing_fields())); }
+    if self.inner.week_graph._is_default() { missing.push("week_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("week_graph", self.inner.week_graph._missing_fields())); }
+    crate::types::_common::check_missing("chatStatisticsSupergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn period<T: AsRef<DateRange>>(&mut self, period: T) -> &mut Self {
     self.inner.period = period.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn member_count<T: AsRef<StatisticalValue>>(&mut self, member_count: T) -> &mut Self {
     self.inner.member_count = member_count.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn message_count<T: AsRef<StatisticalValue>>(&mut self, message_count: T) -> &mut Self {
     self.inner.message_count = message_count.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
    
   pub fn viewer_count<T: AsRef<StatisticalValue>>(&mut self, viewer_count: T) -> &mut Self {
     self.inner.viewer_count = viewer_count.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
    
   pub fn sender_count<T: AsRef<StatisticalValue>>(&mut self, sender_count: T) -> &mut Self {
     self.inner.sender_count = sender_count.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -40,7 +40,19 @@ impl ChatStatisticsAdministratorActionsInfo {
     let mut inner = ChatStatisticsAdministratorActionsInfo::default();
     inner.td_name = "chatStatisticsAdministratorActionsInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsAdministratorActionsInfoBuilder { inner }
+    RTDChatStatisticsAdministratorActionsInfoBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsAdministratorActionsInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.deleted_message_count == 0 { missing.push("deleted_message_count".to_string()); }
+    if self.banned_user_count == 0 { missing.push("banned_user_count".to_string()); }
+    if self.restricted_user_count == 0 { missing.push("restricted_user_count".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -55,33 +67,49 @@ impl ChatStatisticsAdministratorActionsInfo {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsAdministratorActionsInfoBuilder {
-  inner: ChatStatisticsAdministratorActionsInfo
+  inner: ChatStatisticsAdministratorActionsInfo,
+  set: [bool; 4]
 }
 
 impl RTDChatStatisticsAdministratorActionsInfoBuilder {
   pub fn build(&self) -> ChatStatisticsAdministratorActionsInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsAdministratorActionsInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("deleted_message_count".to_string()); }
+    if !self.set[2] { missing.push("banned_user_count".to_string()); }
+    if !self.set[3] { missing.push("restricted_user_count".to_string()); }
+    crate::types::_common::check_missing("chatStatisticsAdministratorActionsInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn deleted_message_count(&mut self, deleted_message_count: i64) -> &mut Self {
     self.inner.deleted_message_count = deleted_message_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn banned_user_count(&mut self, banned_user_count: i64) -> &mut Self {
     self.inner.banned_user_count = banned_user_count;
+    self.set[2] = true;
     self
   }
 
    
   pub fn restricted_user_count(&mut self, restricted_user_count: i64) -> &mut Self {
     self.inner.restricted_user_count = restricted_user_count;
+    self.set[3] = true;
     self
   }
 
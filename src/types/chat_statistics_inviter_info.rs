@@ -36,7 +36,17 @@ impl ChatStatisticsInviterInfo {
     let mut inner = ChatStatisticsInviterInfo::default();
     inner.td_name = "chatStatisticsInviterInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsInviterInfoBuilder { inner }
+    RTDChatStatisticsInviterInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsInviterInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.added_member_count == 0 { missing.push("added_member_count".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -47,21 +57,33 @@ impl ChatStatisticsInviterInfo {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsInviterInfoBuilder {
-  inner: ChatStatisticsInviterInfo
+  inner: ChatStatisticsInviterInfo,
+  set: [bool; 2]
 }
 
 impl RTDChatStatisticsInviterInfoBuilder {
   pub fn build(&self) -> ChatStatisticsInviterInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsInviterInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("added_member_count".to_string()); }
+    crate::types::_common::check_missing("chatStatisticsInviterInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn added_member_count(&mut self, added_member_count: i64) -> &mut Self {
     self.inner.added_member_count = added_member_count;
+    self.set[1] = true;
     self
   }
 
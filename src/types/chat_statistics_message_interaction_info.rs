@@ -38,7 +38,18 @@ impl ChatStatisticsMessageInteractionInfo {
     let mut inner = ChatStatisticsMessageInteractionInfo::default();
     inner.td_name = "chatStatisticsMessageInteractionInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsMessageInteractionInfoBuilder { inner }
+    RTDChatStatisticsMessageInteractionInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsMessageInteractionInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.view_count == 0 { missing.push("view_count".to_string()); }
+    if self.forward_count == 0 { missing.push("forward_count".to_string()); }
+    missing
   }
 
   pub fn message_id(&self) -> i64 { self.message_id }
@@ -51,27 +62,41 @@ impl ChatStatisticsMessageInteractionInfo {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsMessageInteractionInfoBuilder {
-  inner: ChatStatisticsMessageInteractionInfo
+  inner: ChatStatisticsMessageInteractionInfo,
+  set: [bool; 3]
 }
 
 impl RTDChatStatisticsMessageInteractionInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageInteractionInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsMessageInteractionInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message_id".to_string()); }
+    if !self.set[1] { missing.push("view_count".to_string()); }
+    if !self.set[2] { missing.push("forward_count".to_string()); }
+    crate::types::_common::check_missing("chatStatisticsMessageInteractionInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn view_count(&mut self, view_count: i64) -> &mut Self {
     self.inner.view_count = view_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn forward_count(&mut self, forward_count: i64) -> &mut Self {
     self.inner.forward_count = forward_count;
+    self.set[2] = true;
     self
   }
 
@@ -38,7 +38,18 @@ impl ChatStatisticsMessageSenderInfo {
     let mut inner = ChatStatisticsMessageSenderInfo::default();
     inner.td_name = "chatStatisticsMessageSenderInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatStatisticsMessageSenderInfoBuilder { inner }
+    RTDChatStatisticsMessageSenderInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatStatisticsMessageSenderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.sent_message_count == 0 { missing.push("sent_message_count".to_string()); }
+    if self.average_character_count == 0 { missing.push("average_character_count".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -51,27 +62,41 @@ impl ChatStatisticsMessageSenderInfo {
 
 #[doc(hidden)]
 pub struct RTDChatStatisticsMessageSenderInfoBuilder {
-  inner: ChatStatisticsMessageSenderInfo
+  inner: ChatStatisticsMessageSenderInfo,
+  set: [bool; 3]
 }
 
 impl RTDChatStatisticsMessageSenderInfoBuilder {
   pub fn build(&self) -> ChatStatisticsMessageSenderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatStatisticsMessageSenderInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("sent_message_count".to_string()); }
+    if !self.set[2] { missing.push("average_character_count".to_string()); }
+    crate::types::_common::check_missing("chatStatisticsMessageSenderInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sent_message_count(&mut self, sent_message_count: i64) -> &mut Self {
     self.inner.sent_message_count = sent_message_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn average_character_count(&mut self, average_character_count: i64) -> &mut Self {
     self.inner.average_character_count = average_character_count;
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDChatType: Debug + RObject {}
 #[serde(untagged)]
 pub enum ChatType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A basic group (i.e., a chat with 0-200 other users)
   BasicGroup(ChatTypeBasicGroup),
   /// An ordinary chat with a user
@@ -36,7 +39,6 @@ impl Default for ChatType {
 
 impl<'de> Deserialize<'de> for ChatType {
   fn deserialize<D>(deserializer: D) -> Result<ChatType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ChatType,
       (chatTypeBasicGroup, BasicGroup);
@@ -65,6 +67,7 @@ impl RObject for ChatType {
       ChatType::Private(t) => t.extra(),
       ChatType::Secret(t) => t.extra(),
       ChatType::Supergroup(t) => t.extra(),
+      ChatType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for ChatType {
 impl ChatType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ChatType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ChatType::BasicGroup(t) => t._missing_fields(),
+      ChatType::Private(t) => t._missing_fields(),
+      ChatType::Secret(t) => t._missing_fields(),
+      ChatType::Supergroup(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_basic_group(&self) -> bool { if let ChatType::BasicGroup(_) = self { true } else { false } }
   pub fn is_private(&self) -> bool { if let ChatType::Private(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<ChatType> for ChatType {
   fn as_ref(&self) -> &ChatType { self }
 }
 
+impl RVariant<ChatType> for ChatTypeBasicGroup {
+  fn from_class(class: &ChatType) -> Option<&Self> { match class { ChatType::BasicGroup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatType> for ChatTypePrivate {
+  fn from_class(class: &ChatType) -> Option<&Self> { match class { ChatType::Private(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatType> for ChatTypeSecret {
+  fn from_class(class: &ChatType) -> Option<&Self> { match class { ChatType::Secret(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ChatType> for ChatTypeSupergroup {
+  fn from_class(class: &ChatType) -> Option<&Self> { match class { ChatType::Supergroup(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +172,16 @@ impl ChatTypeBasicGroup {
     let mut inner = ChatTypeBasicGroup::default();
     inner.td_name = "chatTypeBasicGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatTypeBasicGroupBuilder { inner }
+    RTDChatTypeBasicGroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatTypeBasicGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    missing
   }
 
   pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
@@ -153,15 +190,25 @@ impl ChatTypeBasicGroup {
 
 #[doc(hidden)]
 pub struct RTDChatTypeBasicGroupBuilder {
-  inner: ChatTypeBasicGroup
+  inner: ChatTypeBasicGroup,
+  set: [bool; 1]
 }
 
 impl RTDChatTypeBasicGroupBuilder {
   pub fn build(&self) -> ChatTypeBasicGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatTypeBasicGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group_id".to_string()); }
+    crate::types::_common::check_missing("chatTypeBasicGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[0] = true;
     self
   }
 
@@ -212,7 +259,16 @@ impl ChatTypePrivate {
     let mut inner = ChatTypePrivate::default();
     inner.td_name = "chatTypePrivate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatTypePrivateBuilder { inner }
+    RTDChatTypePrivateBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatTypePrivate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -221,15 +277,25 @@ impl ChatTypePrivate {
 
 #[doc(hidden)]
 pub struct RTDChatTypePrivateBuilder {
-  inner: ChatTypePrivate
+  inner: ChatTypePrivate,
+  set: [bool; 1]
 }
 
 impl RTDChatTypePrivateBuilder {
   pub fn build(&self) -> ChatTypePrivate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatTypePrivate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("chatTypePrivate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -282,7 +348,17 @@ impl ChatTypeSecret {
     let mut inner = ChatTypeSecret::default();
     inner.td_name = "chatTypeSecret".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatTypeSecretBuilder { inner }
+    RTDChatTypeSecretBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatTypeSecret", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.secret_chat_id == 0 { missing.push("secret_chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn secret_chat_id(&self) -> i64 { self.secret_chat_id }
@@ -293,21 +369,33 @@ impl ChatTypeSecret {
 
 #[doc(hidden)]
 pub struct RTDChatTypeSecretBuilder {
-  inner: ChatTypeSecret
+  inner: ChatTypeSecret,
+  set: [bool; 2]
 }
 
 impl RTDChatTypeSecretBuilder {
   pub fn build(&self) -> ChatTypeSecret { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatTypeSecret> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret_chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("chatTypeSecret", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -360,7 +448,16 @@ impl ChatTypeSupergroup {
     let mut inner = ChatTypeSupergroup::default();
     inner.td_name = "chatTypeSupergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChatTypeSupergroupBuilder { inner }
+    RTDChatTypeSupergroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatTypeSupergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -371,15 +468,25 @@ impl ChatTypeSupergroup {
 
 #[doc(hidden)]
 pub struct RTDChatTypeSupergroupBuilder {
-  inner: ChatTypeSupergroup
+  inner: ChatTypeSupergroup,
+  set: [bool; 1]
 }
 
 impl RTDChatTypeSupergroupBuilder {
   pub fn build(&self) -> ChatTypeSupergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatTypeSupergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("chatTypeSupergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -39,6 +39,11 @@ impl ChatsNearby {
     RTDChatsNearbyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("chatsNearby", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn users_nearby(&self) -> &Vec<ChatNearby> { &self.users_nearby }
 
   pub fn supergroups_nearby(&self) -> &Vec<ChatNearby> { &self.supergroups_nearby }
@@ -53,6 +58,9 @@ pub struct RTDChatsNearbyBuilder {
 impl RTDChatsNearbyBuilder {
   pub fn build(&self) -> ChatsNearby { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChatsNearby> { Ok(self.build()) }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
@@ -19,8 +19,9 @@ pub trait TDCheckChatUsernameResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum CheckChatUsernameResult {
   #[doc(hidden)] _Default(()),
-  /// Checks whether a username can be set for a chat
-  CheckChatUsername(CheckChatUsername),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The username can be set
   Ok(CheckChatUsernameResultOk),
   /// The user has too much chats with username, one of them should be made private first
@@ -40,10 +41,8 @@ impl Default for CheckChatUsernameResult {
 
 impl<'de> Deserialize<'de> for CheckChatUsernameResult {
   fn deserialize<D>(deserializer: D) -> Result<CheckChatUsernameResult, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       CheckChatUsernameResult,
-      (checkChatUsername, CheckChatUsername);
       (checkChatUsernameResultOk, Ok);
       (checkChatUsernameResultPublicChatsTooMuch, PublicChatsTooMuch);
       (checkChatUsernameResultPublicGroupsUnavailable, PublicGroupsUnavailable);
@@ -57,7 +56,6 @@ impl<'de> Deserialize<'de> for CheckChatUsernameResult {
 impl RObject for CheckChatUsernameResult {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      CheckChatUsernameResult::CheckChatUsername(t) => t.td_name(),
       CheckChatUsernameResult::Ok(t) => t.td_name(),
       CheckChatUsernameResult::PublicChatsTooMuch(t) => t.td_name(),
       CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.td_name(),
@@ -69,12 +67,12 @@ impl RObject for CheckChatUsernameResult {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      CheckChatUsernameResult::CheckChatUsername(t) => t.extra(),
       CheckChatUsernameResult::Ok(t) => t.extra(),
       CheckChatUsernameResult::PublicChatsTooMuch(t) => t.extra(),
       CheckChatUsernameResult::PublicGroupsUnavailable(t) => t.extra(),
       CheckChatUsernameResult::UsernameInvalid(t) => t.extra(),
       CheckChatUsernameResult::UsernameOccupied(t) => t.extra(),
+      CheckChatUsernameResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,22 +83,29 @@ impl RObject for CheckChatUsernameResult {
 impl CheckChatUsernameResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let CheckChatUsernameResult::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      CheckChatUsernameResult::Ok(t) => t._missing_fields(),
+      CheckChatUsernameResult::PublicChatsTooMuch(t) => t._missing_fields(),
+      CheckChatUsernameResult::PublicGroupsUnavailable(t) => t._missing_fields(),
+      CheckChatUsernameResult::UsernameInvalid(t) => t._missing_fields(),
+      CheckChatUsernameResult::UsernameOccupied(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_check_chat_username(&self) -> bool { if let CheckChatUsernameResult::CheckChatUsername(_) = self { true } else { false } }
   pub fn is_ok(&self) -> bool { if let CheckChatUsernameResult::Ok(_) = self { true } else { false } }
   pub fn is_public_chats_too_much(&self) -> bool { if let CheckChatUsernameResult::PublicChatsTooMuch(_) = self { true } else { false } }
   pub fn is_public_groups_unavailable(&self) -> bool { if let CheckChatUsernameResult::PublicGroupsUnavailable(_) = self { true } else { false } }
   pub fn is_username_invalid(&self) -> bool { if let CheckChatUsernameResult::UsernameInvalid(_) = self { true } else { false } }
   pub fn is_username_occupied(&self) -> bool { if let CheckChatUsernameResult::UsernameOccupied(_) = self { true } else { false } }
 
-  pub fn on_check_chat_username<F: FnOnce(&CheckChatUsername)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::CheckChatUsername(t) = self { fnc(t) }; self }
   pub fn on_ok<F: FnOnce(&CheckChatUsernameResultOk)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::Ok(t) = self { fnc(t) }; self }
   pub fn on_public_chats_too_much<F: FnOnce(&CheckChatUsernameResultPublicChatsTooMuch)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::PublicChatsTooMuch(t) = self { fnc(t) }; self }
   pub fn on_public_groups_unavailable<F: FnOnce(&CheckChatUsernameResultPublicGroupsUnavailable)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { fnc(t) }; self }
   pub fn on_username_invalid<F: FnOnce(&CheckChatUsernameResultUsernameInvalid)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameInvalid(t) = self { fnc(t) }; self }
   pub fn on_username_occupied<F: FnOnce(&CheckChatUsernameResultUsernameOccupied)>(&self, fnc: F) -> &Self { if let CheckChatUsernameResult::UsernameOccupied(t) = self { fnc(t) }; self }
 
-  pub fn as_check_chat_username(&self) -> Option<&CheckChatUsername> { if let CheckChatUsernameResult::CheckChatUsername(t) = self { return Some(t) } None }
   pub fn as_ok(&self) -> Option<&CheckChatUsernameResultOk> { if let CheckChatUsernameResult::Ok(t) = self { return Some(t) } None }
   pub fn as_public_chats_too_much(&self) -> Option<&CheckChatUsernameResultPublicChatsTooMuch> { if let CheckChatUsernameResult::PublicChatsTooMuch(t) = self { return Some(t) } None }
   pub fn as_public_groups_unavailable(&self) -> Option<&CheckChatUsernameResultPublicGroupsUnavailable> { if let CheckChatUsernameResult::PublicGroupsUnavailable(t) = self { return Some(t) } None }
@@ -109,8 +114,6 @@ impl CheckChatUsernameResult {
 
 
 
-  pub fn check_chat_username<T: AsRef<CheckChatUsername>>(t: T) -> Self { CheckChatUsernameResult::CheckChatUsername(t.as_ref().clone()) }
-
   pub fn ok<T: AsRef<CheckChatUsernameResultOk>>(t: T) -> Self { CheckChatUsernameResult::Ok(t.as_ref().clone()) }
 
   pub fn public_chats_too_much<T: AsRef<CheckChatUsernameResultPublicChatsTooMuch>>(t: T) -> Self { CheckChatUsernameResult::PublicChatsTooMuch(t.as_ref().clone()) }
@@ -127,6 +130,26 @@ impl AsRef<CheckChatUsernameResult> for CheckChatUsernameResult {
   fn as_ref(&self) -> &CheckChatUsernameResult { self }
 }
 
+impl RVariant<CheckChatUsernameResult> for CheckChatUsernameResultOk {
+  fn from_class(class: &CheckChatUsernameResult) -> Option<&Self> { match class { CheckChatUsernameResult::Ok(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CheckChatUsernameResult> for CheckChatUsernameResultPublicChatsTooMuch {
+  fn from_class(class: &CheckChatUsernameResult) -> Option<&Self> { match class { CheckChatUsernameResult::PublicChatsTooMuch(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CheckChatUsernameResult> for CheckChatUsernameResultPublicGroupsUnavailable {
+  fn from_class(class: &CheckChatUsernameResult) -> Option<&Self> { match class { CheckChatUsernameResult::PublicGroupsUnavailable(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CheckChatUsernameResult> for CheckChatUsernameResultUsernameInvalid {
+  fn from_class(class: &CheckChatUsernameResult) -> Option<&Self> { match class { CheckChatUsernameResult::UsernameInvalid(t) => Some(t), _ => None } }
+}
+
+impl RVariant<CheckChatUsernameResult> for CheckChatUsernameResultUsernameOccupied {
+  fn from_class(class: &CheckChatUsernameResult) -> Option<&Self> { match class { CheckChatUsernameResult::UsernameOccupied(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -165,6 +188,11 @@ impl CheckChatUsernameResultOk {
     RTDCheckChatUsernameResultOkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsernameResultOk", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +203,9 @@ pub struct RTDCheckChatUsernameResultOkBuilder {
 impl RTDCheckChatUsernameResultOkBuilder {
   pub fn build(&self) -> CheckChatUsernameResultOk { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultOk> { Ok(self.build()) }
+
 }
 
 impl AsRef<CheckChatUsernameResultOk> for CheckChatUsernameResultOk {
@@ -223,6 +254,11 @@ impl CheckChatUsernameResultPublicChatsTooMuch {
     RTDCheckChatUsernameResultPublicChatsTooMuchBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsernameResultPublicChatsTooMuch", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +269,9 @@ pub struct RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
 impl RTDCheckChatUsernameResultPublicChatsTooMuchBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicChatsTooMuch { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultPublicChatsTooMuch> { Ok(self.build()) }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicChatsTooMuch> for CheckChatUsernameResultPublicChatsTooMuch {
@@ -281,6 +320,11 @@ impl CheckChatUsernameResultPublicGroupsUnavailable {
     RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsernameResultPublicGroupsUnavailable", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +335,9 @@ pub struct RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
 impl RTDCheckChatUsernameResultPublicGroupsUnavailableBuilder {
   pub fn build(&self) -> CheckChatUsernameResultPublicGroupsUnavailable { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultPublicGroupsUnavailable> { Ok(self.build()) }
+
 }
 
 impl AsRef<CheckChatUsernameResultPublicGroupsUnavailable> for CheckChatUsernameResultPublicGroupsUnavailable {
@@ -339,6 +386,11 @@ impl CheckChatUsernameResultUsernameInvalid {
     RTDCheckChatUsernameResultUsernameInvalidBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsernameResultUsernameInvalid", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +401,9 @@ pub struct RTDCheckChatUsernameResultUsernameInvalidBuilder {
 impl RTDCheckChatUsernameResultUsernameInvalidBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameInvalid { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultUsernameInvalid> { Ok(self.build()) }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameInvalid> for CheckChatUsernameResultUsernameInvalid {
@@ -397,6 +452,11 @@ impl CheckChatUsernameResultUsernameOccupied {
     RTDCheckChatUsernameResultUsernameOccupiedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsernameResultUsernameOccupied", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -407,6 +467,9 @@ pub struct RTDCheckChatUsernameResultUsernameOccupiedBuilder {
 impl RTDCheckChatUsernameResultUsernameOccupiedBuilder {
   pub fn build(&self) -> CheckChatUsernameResultUsernameOccupied { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsernameResultUsernameOccupied> { Ok(self.build()) }
+
 }
 
 impl AsRef<CheckChatUsernameResultUsernameOccupied> for CheckChatUsernameResultUsernameOccupied {
@@ -37,6 +37,11 @@ impl ClosedVectorPath {
     RTDClosedVectorPathBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("closedVectorPath", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn commands(&self) -> &Vec<VectorPathCommand> { &self.commands }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDClosedVectorPathBuilder {
 impl RTDClosedVectorPathBuilder {
   pub fn build(&self) -> ClosedVectorPath { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ClosedVectorPath> { Ok(self.build()) }
+
    
   pub fn commands(&mut self, commands: Vec<VectorPathCommand>) -> &mut Self {
     self.inner.commands = commands;
@@ -16,7 +16,7 @@ pub struct ConnectedWebsite {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Website identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// The domain name of the website
   domain_name: String,
   /// User identifier of a bot linked with the website
@@ -50,10 +50,27 @@ impl ConnectedWebsite {
     let mut inner = ConnectedWebsite::default();
     inner.td_name = "connectedWebsite".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDConnectedWebsiteBuilder { inner }
+    RTDConnectedWebsiteBuilder { inner, set: [false; 9] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectedWebsite", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.domain_name.is_empty() { missing.push("domain_name".to_string()); }
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    if self.browser.is_empty() { missing.push("browser".to_string()); }
+    if self.platform.is_empty() { missing.push("platform".to_string()); }
+    if self.log_in_date == 0 { missing.push("log_in_date".to_string()); }
+    if self.last_active_date == 0 { missing.push("last_active_date".to_string()); }
+    if self.ip.is_empty() { missing.push("ip".to_string()); }
+    if self.location.is_empty() { missing.push("location".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn domain_name(&self) -> &String { &self.domain_name }
 
@@ -75,63 +92,89 @@ impl ConnectedWebsite {
 
 #[doc(hidden)]
 pub struct RTDConnectedWebsiteBuilder {
-  inner: ConnectedWebsite
+  inner: ConnectedWebsite,
+  set: [bool; 9]
 }
 
 impl RTDConnectedWebsiteBuilder {
   pub fn build(&self) -> ConnectedWebsite { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectedWebsite> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("domain_name".to_string()); }
+    if !self.set[2] { missing.push("bot_user_id".to_string()); }
+    if !self.set[3] { missing.push("browser".to_string()); }
+    if !self.set[4] { missing.push("platform".to_string()); }
+    if !self.set[5] { missing.push("log_in_date".to_string()); }
+    if !self.set[6] { missing.push("last_active_date".to_string()); }
+    if !self.set[7] { missing.push("ip".to_string()); }
+    if !self.set[8] { missing.push("location".to_string()); }
+    crate::types::_common::check_missing("connectedWebsite", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
     self.inner.domain_name = domain_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn browser<T: AsRef<str>>(&mut self, browser: T) -> &mut Self {
     self.inner.browser = browser.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn platform<T: AsRef<str>>(&mut self, platform: T) -> &mut Self {
     self.inner.platform = platform.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn log_in_date(&mut self, log_in_date: i64) -> &mut Self {
     self.inner.log_in_date = log_in_date;
+    self.set[5] = true;
     self
   }
 
    
   pub fn last_active_date(&mut self, last_active_date: i64) -> &mut Self {
     self.inner.last_active_date = last_active_date;
+    self.set[6] = true;
     self
   }
 
    
   pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
     self.inner.ip = ip.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
    
   pub fn location<T: AsRef<str>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().to_string();
+    self.set[8] = true;
     self
   }
 
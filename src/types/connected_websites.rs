@@ -37,6 +37,11 @@ impl ConnectedWebsites {
     RTDConnectedWebsitesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectedWebsites", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn websites(&self) -> &Vec<ConnectedWebsite> { &self.websites }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDConnectedWebsitesBuilder {
 impl RTDConnectedWebsitesBuilder {
   pub fn build(&self) -> ConnectedWebsites { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectedWebsites> { Ok(self.build()) }
+
    
   pub fn websites(&mut self, websites: Vec<ConnectedWebsite>) -> &mut Self {
     self.inner.websites = websites;
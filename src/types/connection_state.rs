@@ -19,6 +19,9 @@ pub trait TDConnectionState: Debug + RObject {}
 #[serde(untagged)]
 pub enum ConnectionState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Currently establishing a connection to the Telegram servers
   Connecting(ConnectionStateConnecting),
   /// Currently establishing a connection with a proxy server
@@ -38,7 +41,6 @@ impl Default for ConnectionState {
 
 impl<'de> Deserialize<'de> for ConnectionState {
   fn deserialize<D>(deserializer: D) -> Result<ConnectionState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ConnectionState,
       (connectionStateConnecting, Connecting);
@@ -70,6 +72,7 @@ impl RObject for ConnectionState {
       ConnectionState::Ready(t) => t.extra(),
       ConnectionState::Updating(t) => t.extra(),
       ConnectionState::WaitingForNetwork(t) => t.extra(),
+      ConnectionState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,6 +83,16 @@ impl RObject for ConnectionState {
 impl ConnectionState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ConnectionState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ConnectionState::Connecting(t) => t._missing_fields(),
+      ConnectionState::ConnectingToProxy(t) => t._missing_fields(),
+      ConnectionState::Ready(t) => t._missing_fields(),
+      ConnectionState::Updating(t) => t._missing_fields(),
+      ConnectionState::WaitingForNetwork(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_connecting(&self) -> bool { if let ConnectionState::Connecting(_) = self { true } else { false } }
   pub fn is_connecting_to_proxy(&self) -> bool { if let ConnectionState::ConnectingToProxy(_) = self { true } else { false } }
@@ -117,6 +130,26 @@ impl AsRef<ConnectionState> for ConnectionState {
   fn as_ref(&self) -> &ConnectionState { self }
 }
 
+impl RVariant<ConnectionState> for ConnectionStateConnecting {
+  fn from_class(class: &ConnectionState) -> Option<&Self> { match class { ConnectionState::Connecting(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ConnectionState> for ConnectionStateConnectingToProxy {
+  fn from_class(class: &ConnectionState) -> Option<&Self> { match class { ConnectionState::ConnectingToProxy(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ConnectionState> for ConnectionStateReady {
+  fn from_class(class: &ConnectionState) -> Option<&Self> { match class { ConnectionState::Ready(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ConnectionState> for ConnectionStateUpdating {
+  fn from_class(class: &ConnectionState) -> Option<&Self> { match class { ConnectionState::Updating(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ConnectionState> for ConnectionStateWaitingForNetwork {
+  fn from_class(class: &ConnectionState) -> Option<&Self> { match class { ConnectionState::WaitingForNetwork(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -155,6 +188,11 @@ impl ConnectionStateConnecting {
     RTDConnectionStateConnectingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectionStateConnecting", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +203,9 @@ pub struct RTDConnectionStateConnectingBuilder {
 impl RTDConnectionStateConnectingBuilder {
   pub fn build(&self) -> ConnectionStateConnecting { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectionStateConnecting> { Ok(self.build()) }
+
 }
 
 impl AsRef<ConnectionStateConnecting> for ConnectionStateConnecting {
@@ -213,6 +254,11 @@ impl ConnectionStateConnectingToProxy {
     RTDConnectionStateConnectingToProxyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectionStateConnectingToProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +269,9 @@ pub struct RTDConnectionStateConnectingToProxyBuilder {
 impl RTDConnectionStateConnectingToProxyBuilder {
   pub fn build(&self) -> ConnectionStateConnectingToProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectionStateConnectingToProxy> { Ok(self.build()) }
+
 }
 
 impl AsRef<ConnectionStateConnectingToProxy> for ConnectionStateConnectingToProxy {
@@ -271,6 +320,11 @@ impl ConnectionStateReady {
     RTDConnectionStateReadyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectionStateReady", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +335,9 @@ pub struct RTDConnectionStateReadyBuilder {
 impl RTDConnectionStateReadyBuilder {
   pub fn build(&self) -> ConnectionStateReady { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectionStateReady> { Ok(self.build()) }
+
 }
 
 impl AsRef<ConnectionStateReady> for ConnectionStateReady {
@@ -329,6 +386,11 @@ impl ConnectionStateUpdating {
     RTDConnectionStateUpdatingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectionStateUpdating", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +401,9 @@ pub struct RTDConnectionStateUpdatingBuilder {
 impl RTDConnectionStateUpdatingBuilder {
   pub fn build(&self) -> ConnectionStateUpdating { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectionStateUpdating> { Ok(self.build()) }
+
 }
 
 impl AsRef<ConnectionStateUpdating> for ConnectionStateUpdating {
@@ -387,6 +452,11 @@ impl ConnectionStateWaitingForNetwork {
     RTDConnectionStateWaitingForNetworkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("connectionStateWaitingForNetwork", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +467,9 @@ pub struct RTDConnectionStateWaitingForNetworkBuilder {
 impl RTDConnectionStateWaitingForNetworkBuilder {
   pub fn build(&self) -> ConnectionStateWaitingForNetwork { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConnectionStateWaitingForNetwork> { Ok(self.build()) }
+
 }
 
 impl AsRef<ConnectionStateWaitingForNetwork> for ConnectionStateWaitingForNetwork {
@@ -42,7 +42,20 @@ impl Contact {
     let mut inner = Contact::default();
     inner.td_name = "contact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDContactBuilder { inner }
+    RTDContactBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("contact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if self.first_name.is_empty() { missing.push("first_name".to_string()); }
+    if self.last_name.is_empty() { missing.push("last_name".to_string()); }
+    if self.vcard.is_empty() { missing.push("vcard".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -59,39 +72,57 @@ impl Contact {
 
 #[doc(hidden)]
 pub struct RTDContactBuilder {
-  inner: Contact
+  inner: Contact,
+  set: [bool; 5]
 }
 
 impl RTDContactBuilder {
   pub fn build(&self) -> Contact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Contact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    if !self.set[1] { missing.push("first_name".to_string()); }
+    if !self.set[2] { missing.push("last_name".to_string()); }
+    if !self.set[3] { missing.push("vcard".to_string()); }
+    if !self.set[4] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("contact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
     self.inner.last_name = last_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn vcard<T: AsRef<str>>(&mut self, vcard: T) -> &mut Self {
     self.inner.vcard = vcard.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[4] = true;
     self
   }
 
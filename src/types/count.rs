@@ -34,7 +34,16 @@ impl Count {
     let mut inner = Count::default();
     inner.td_name = "count".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCountBuilder { inner }
+    RTDCountBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("count", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.count == 0 { missing.push("count".to_string()); }
+    missing
   }
 
   pub fn count(&self) -> i64 { self.count }
@@ -43,15 +52,25 @@ impl Count {
 
 #[doc(hidden)]
 pub struct RTDCountBuilder {
-  inner: Count
+  inner: Count,
+  set: [bool; 1]
 }
 
 impl RTDCountBuilder {
   pub fn build(&self) -> Count { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Count> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("count".to_string()); }
+    crate::types::_common::check_missing("count", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
+    self.set[0] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl Countries {
     RTDCountriesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("countries", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn countries(&self) -> &Vec<CountryInfo> { &self.countries }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDCountriesBuilder {
 impl RTDCountriesBuilder {
   pub fn build(&self) -> Countries { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Countries> { Ok(self.build()) }
+
    
   pub fn countries(&mut self, countries: Vec<CountryInfo>) -> &mut Self {
     self.inner.countries = countries;
@@ -42,7 +42,18 @@ impl CountryInfo {
     let mut inner = CountryInfo::default();
     inner.td_name = "countryInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCountryInfoBuilder { inner }
+    RTDCountryInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("countryInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.country_code.is_empty() { missing.push("country_code".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.english_name.is_empty() { missing.push("english_name".to_string()); }
+    missing
   }
 
   pub fn country_code(&self) -> &String { &self.country_code }
@@ -59,27 +70,41 @@ impl CountryInfo {
 
 #[doc(hidden)]
 pub struct RTDCountryInfoBuilder {
-  inner: CountryInfo
+  inner: CountryInfo,
+  set: [bool; 3]
 }
 
 impl RTDCountryInfoBuilder {
   pub fn build(&self) -> CountryInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CountryInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("country_code".to_string()); }
+    if !self.set[1] { missing.push("name".to_string()); }
+    if !self.set[2] { missing.push("english_name".to_string()); }
+    crate::types::_common::check_missing("countryInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn english_name<T: AsRef<str>>(&mut self, english_name: T) -> &mut Self {
     self.inner.english_name = english_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -34,7 +34,16 @@ impl CustomRequestResult {
     let mut inner = CustomRequestResult::default();
     inner.td_name = "customRequestResult".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCustomRequestResultBuilder { inner }
+    RTDCustomRequestResultBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("customRequestResult", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.result.is_empty() { missing.push("result".to_string()); }
+    missing
   }
 
   pub fn result(&self) -> &String { &self.result }
@@ -43,15 +52,25 @@ impl CustomRequestResult {
 
 #[doc(hidden)]
 pub struct RTDCustomRequestResultBuilder {
-  inner: CustomRequestResult
+  inner: CustomRequestResult,
+  set: [bool; 1]
 }
 
 impl RTDCustomRequestResultBuilder {
   pub fn build(&self) -> CustomRequestResult { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CustomRequestResult> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("result".to_string()); }
+    crate::types::_common::check_missing("customRequestResult", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn result<T: AsRef<str>>(&mut self, result: T) -> &mut Self {
     self.inner.result = result.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
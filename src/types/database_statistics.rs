@@ -34,7 +34,16 @@ impl DatabaseStatistics {
     let mut inner = DatabaseStatistics::default();
     inner.td_name = "databaseStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDatabaseStatisticsBuilder { inner }
+    RTDDatabaseStatisticsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("databaseStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.statistics.is_empty() { missing.push("statistics".to_string()); }
+    missing
   }
 
   pub fn statistics(&self) -> &String { &self.statistics }
@@ -43,15 +52,25 @@ impl DatabaseStatistics {
 
 #[doc(hidden)]
 pub struct RTDDatabaseStatisticsBuilder {
-  inner: DatabaseStatistics
+  inner: DatabaseStatistics,
+  set: [bool; 1]
 }
 
 impl RTDDatabaseStatisticsBuilder {
   pub fn build(&self) -> DatabaseStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DatabaseStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("statistics".to_string()); }
+    crate::types::_common::check_missing("databaseStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn statistics<T: AsRef<str>>(&mut self, statistics: T) -> &mut Self {
     self.inner.statistics = statistics.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -38,7 +38,18 @@ impl Date {
     let mut inner = Date::default();
     inner.td_name = "date".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDateBuilder { inner }
+    RTDDateBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("date", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.day == 0 { missing.push("day".to_string()); }
+    if self.month == 0 { missing.push("month".to_string()); }
+    if self.year == 0 { missing.push("year".to_string()); }
+    missing
   }
 
   pub fn day(&self) -> i64 { self.day }
@@ -51,27 +62,41 @@ impl Date {
 
 #[doc(hidden)]
 pub struct RTDDateBuilder {
-  inner: Date
+  inner: Date,
+  set: [bool; 3]
 }
 
 impl RTDDateBuilder {
   pub fn build(&self) -> Date { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Date> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("day".to_string()); }
+    if !self.set[1] { missing.push("month".to_string()); }
+    if !self.set[2] { missing.push("year".to_string()); }
+    crate::types::_common::check_missing("date", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn day(&mut self, day: i64) -> &mut Self {
     self.inner.day = day;
+    self.set[0] = true;
     self
   }
 
    
   pub fn month(&mut self, month: i64) -> &mut Self {
     self.inner.month = month;
+    self.set[1] = true;
     self
   }
 
    
   pub fn year(&mut self, year: i64) -> &mut Self {
     self.inner.year = year;
+    self.set[2] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl DateRange {
     let mut inner = DateRange::default();
     inner.td_name = "dateRange".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDateRangeBuilder { inner }
+    RTDDateRangeBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("dateRange", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.start_date == 0 { missing.push("start_date".to_string()); }
+    if self.end_date == 0 { missing.push("end_date".to_string()); }
+    missing
   }
 
   pub fn start_date(&self) -> i64 { self.start_date }
@@ -47,21 +57,33 @@ impl DateRange {
 
 #[doc(hidden)]
 pub struct RTDDateRangeBuilder {
-  inner: DateRange
+  inner: DateRange,
+  set: [bool; 2]
 }
 
 impl RTDDateRangeBuilder {
   pub fn build(&self) -> DateRange { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DateRange> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("start_date".to_string()); }
+    if !self.set[1] { missing.push("end_date".to_string()); }
+    crate::types::_common::check_missing("dateRange", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn start_date(&mut self, start_date: i64) -> &mut Self {
     self.inner.start_date = start_date;
+    self.set[0] = true;
     self
   }
 
    
   pub fn end_date(&mut self, end_date: i64) -> &mut Self {
     self.inner.end_date = end_date;
+    self.set[1] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl DatedFile {
     let mut inner = DatedFile::default();
     inner.td_name = "datedFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDatedFileBuilder { inner }
+    RTDDatedFileBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("datedFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.file._missing_fields())); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    missing
   }
 
   pub fn file(&self) -> &File { &self.file }
@@ -47,21 +57,33 @@ impl DatedFile {
 
 #[doc(hidden)]
 pub struct RTDDatedFileBuilder {
-  inner: DatedFile
+  inner: DatedFile,
+  set: [bool; 2]
 }
 
 impl RTDDatedFileBuilder {
   pub fn build(&self) -> DatedFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DatedFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.inner.file._missing_fields())); }
+    if !self.set[1] { missing.push("date".to_string()); }
+    crate::types::_common::check_missing("datedFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[1] = true;
     self
   }
 
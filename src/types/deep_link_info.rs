@@ -36,7 +36,16 @@ impl DeepLinkInfo {
     let mut inner = DeepLinkInfo::default();
     inner.td_name = "deepLinkInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeepLinkInfoBuilder { inner }
+    RTDDeepLinkInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deepLinkInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -47,15 +56,25 @@ impl DeepLinkInfo {
 
 #[doc(hidden)]
 pub struct RTDDeepLinkInfoBuilder {
-  inner: DeepLinkInfo
+  inner: DeepLinkInfo,
+  set: [bool; 1]
 }
 
 impl RTDDeepLinkInfoBuilder {
   pub fn build(&self) -> DeepLinkInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeepLinkInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("deepLinkInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDDeviceToken: Debug + RObject {}
 #[serde(untagged)]
 pub enum DeviceToken {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A token for Apple Push Notification service
   ApplePush(DeviceTokenApplePush),
   /// A token for Apple Push Notification service VoIP notifications
@@ -50,7 +53,6 @@ impl Default for DeviceToken {
 
 impl<'de> Deserialize<'de> for DeviceToken {
   fn deserialize<D>(deserializer: D) -> Result<DeviceToken, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       DeviceToken,
       (deviceTokenApplePush, ApplePush);
@@ -100,6 +102,7 @@ impl RObject for DeviceToken {
       DeviceToken::UbuntuPush(t) => t.extra(),
       DeviceToken::WebPush(t) => t.extra(),
       DeviceToken::WindowsPush(t) => t.extra(),
+      DeviceToken::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -110,6 +113,22 @@ impl RObject for DeviceToken {
 impl DeviceToken {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let DeviceToken::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      DeviceToken::ApplePush(t) => t._missing_fields(),
+      DeviceToken::ApplePushVoIP(t) => t._missing_fields(),
+      DeviceToken::BlackBerryPush(t) => t._missing_fields(),
+      DeviceToken::FirebaseCloudMessaging(t) => t._missing_fields(),
+      DeviceToken::MicrosoftPush(t) => t._missing_fields(),
+      DeviceToken::MicrosoftPushVoIP(t) => t._missing_fields(),
+      DeviceToken::SimplePush(t) => t._missing_fields(),
+      DeviceToken::TizenPush(t) => t._missing_fields(),
+      DeviceToken::UbuntuPush(t) => t._missing_fields(),
+      DeviceToken::WebPush(t) => t._missing_fields(),
+      DeviceToken::WindowsPush(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_apple_push(&self) -> bool { if let DeviceToken::ApplePush(_) = self { true } else { false } }
   pub fn is_apple_push_vo_i_p(&self) -> bool { if let DeviceToken::ApplePushVoIP(_) = self { true } else { false } }
@@ -177,6 +196,50 @@ impl AsRef<DeviceToken> for DeviceToken {
   fn as_ref(&self) -> &DeviceToken { self }
 }
 
+impl RVariant<DeviceToken> for DeviceTokenApplePush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::ApplePush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenApplePushVoIP {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::ApplePushVoIP(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenBlackBerryPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::BlackBerryPush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenFirebaseCloudMessaging {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::FirebaseCloudMessaging(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenMicrosoftPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::MicrosoftPush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenMicrosoftPushVoIP {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::MicrosoftPushVoIP(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenSimplePush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::SimplePush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenTizenPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::TizenPush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenUbuntuPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::UbuntuPush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenWebPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::WebPush(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DeviceToken> for DeviceTokenWindowsPush {
+  fn from_class(class: &DeviceToken) -> Option<&Self> { match class { DeviceToken::WindowsPush(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -219,6 +282,11 @@ impl DeviceTokenApplePush {
     RTDDeviceTokenApplePushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenApplePush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn device_token(&self) -> &String { &self.device_token }
 
   pub fn is_app_sandbox(&self) -> bool { self.is_app_sandbox }
@@ -233,6 +301,9 @@ pub struct RTDDeviceTokenApplePushBuilder {
 impl RTDDeviceTokenApplePushBuilder {
   pub fn build(&self) -> DeviceTokenApplePush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenApplePush> { Ok(self.build()) }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -299,6 +370,11 @@ impl DeviceTokenApplePushVoIP {
     RTDDeviceTokenApplePushVoIPBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenApplePushVoIP", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn device_token(&self) -> &String { &self.device_token }
 
   pub fn is_app_sandbox(&self) -> bool { self.is_app_sandbox }
@@ -315,6 +391,9 @@ pub struct RTDDeviceTokenApplePushVoIPBuilder {
 impl RTDDeviceTokenApplePushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenApplePushVoIP { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenApplePushVoIP> { Ok(self.build()) }
+
    
   pub fn device_token<T: AsRef<str>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().to_string();
@@ -383,6 +462,11 @@ impl DeviceTokenBlackBerryPush {
     RTDDeviceTokenBlackBerryPushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenBlackBerryPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn token(&self) -> &String { &self.token }
 
 }
@@ -395,6 +479,9 @@ pub struct RTDDeviceTokenBlackBerryPushBuilder {
 impl RTDDeviceTokenBlackBerryPushBuilder {
   pub fn build(&self) -> DeviceTokenBlackBerryPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenBlackBerryPush> { Ok(self.build()) }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -453,6 +540,11 @@ impl DeviceTokenFirebaseCloudMessaging {
     RTDDeviceTokenFirebaseCloudMessagingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenFirebaseCloudMessaging", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn token(&self) -> &String { &self.token }
 
   pub fn encrypt(&self) -> bool { self.encrypt }
@@ -467,6 +559,9 @@ pub struct RTDDeviceTokenFirebaseCloudMessagingBuilder {
 impl RTDDeviceTokenFirebaseCloudMessagingBuilder {
   pub fn build(&self) -> DeviceTokenFirebaseCloudMessaging { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenFirebaseCloudMessaging> { Ok(self.build()) }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -529,6 +624,11 @@ impl DeviceTokenMicrosoftPush {
     RTDDeviceTokenMicrosoftPushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenMicrosoftPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn channel_uri(&self) -> &String { &self.channel_uri }
 
 }
@@ -541,6 +641,9 @@ pub struct RTDDeviceTokenMicrosoftPushBuilder {
 impl RTDDeviceTokenMicrosoftPushBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenMicrosoftPush> { Ok(self.build()) }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -597,6 +700,11 @@ impl DeviceTokenMicrosoftPushVoIP {
     RTDDeviceTokenMicrosoftPushVoIPBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenMicrosoftPushVoIP", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn channel_uri(&self) -> &String { &self.channel_uri }
 
 }
@@ -609,6 +717,9 @@ pub struct RTDDeviceTokenMicrosoftPushVoIPBuilder {
 impl RTDDeviceTokenMicrosoftPushVoIPBuilder {
   pub fn build(&self) -> DeviceTokenMicrosoftPushVoIP { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenMicrosoftPushVoIP> { Ok(self.build()) }
+
    
   pub fn channel_uri<T: AsRef<str>>(&mut self, channel_uri: T) -> &mut Self {
     self.inner.channel_uri = channel_uri.as_ref().to_string();
@@ -665,6 +776,11 @@ impl DeviceTokenSimplePush {
     RTDDeviceTokenSimplePushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenSimplePush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn endpoint(&self) -> &String { &self.endpoint }
 
 }
@@ -677,6 +793,9 @@ pub struct RTDDeviceTokenSimplePushBuilder {
 impl RTDDeviceTokenSimplePushBuilder {
   pub fn build(&self) -> DeviceTokenSimplePush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenSimplePush> { Ok(self.build()) }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -733,6 +852,11 @@ impl DeviceTokenTizenPush {
     RTDDeviceTokenTizenPushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenTizenPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn reg_id(&self) -> &String { &self.reg_id }
 
 }
@@ -745,6 +869,9 @@ pub struct RTDDeviceTokenTizenPushBuilder {
 impl RTDDeviceTokenTizenPushBuilder {
   pub fn build(&self) -> DeviceTokenTizenPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenTizenPush> { Ok(self.build()) }
+
    
   pub fn reg_id<T: AsRef<str>>(&mut self, reg_id: T) -> &mut Self {
     self.inner.reg_id = reg_id.as_ref().to_string();
@@ -801,6 +928,11 @@ impl DeviceTokenUbuntuPush {
     RTDDeviceTokenUbuntuPushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenUbuntuPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn token(&self) -> &String { &self.token }
 
 }
@@ -813,6 +945,9 @@ pub struct RTDDeviceTokenUbuntuPushBuilder {
 impl RTDDeviceTokenUbuntuPushBuilder {
   pub fn build(&self) -> DeviceTokenUbuntuPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenUbuntuPush> { Ok(self.build()) }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
@@ -870,7 +1005,17 @@ impl DeviceTokenWebPush {
     let mut inner = DeviceTokenWebPush::default();
     inner.td_name = "deviceTokenWebPush".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeviceTokenWebPushBuilder { inner }
+    RTDDeviceTokenWebPushBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenWebPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.p256dh_base64url.is_empty() { missing.push("p256dh_base64url".to_string()); }
+    if self.auth_base64url.is_empty() { missing.push("auth_base64url".to_string()); }
+    missing
   }
 
   pub fn endpoint(&self) -> &String { &self.endpoint }
@@ -883,12 +1028,22 @@ impl DeviceTokenWebPush {
 
 #[doc(hidden)]
 pub struct RTDDeviceTokenWebPushBuilder {
-  inner: DeviceTokenWebPush
+  inner: DeviceTokenWebPush,
+  set: [bool; 2]
 }
 
 impl RTDDeviceTokenWebPushBuilder {
   pub fn build(&self) -> DeviceTokenWebPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenWebPush> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("p256dh_base64url".to_string()); }
+    if !self.set[1] { missing.push("auth_base64url".to_string()); }
+    crate::types::_common::check_missing("deviceTokenWebPush", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn endpoint<T: AsRef<str>>(&mut self, endpoint: T) -> &mut Self {
     self.inner.endpoint = endpoint.as_ref().to_string();
@@ -898,12 +1053,14 @@ impl RTDDeviceTokenWebPushBuilder {
    
   pub fn p256dh_base64url<T: AsRef<str>>(&mut self, p256dh_base64url: T) -> &mut Self {
     self.inner.p256dh_base64url = p256dh_base64url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn auth_base64url<T: AsRef<str>>(&mut self, auth_base64url: T) -> &mut Self {
     self.inner.auth_base64url = auth_base64url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -957,6 +1114,11 @@ impl DeviceTokenWindowsPush {
     RTDDeviceTokenWindowsPushBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deviceTokenWindowsPush", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn access_token(&self) -> &String { &self.access_token }
 
 }
@@ -969,6 +1131,9 @@ pub struct RTDDeviceTokenWindowsPushBuilder {
 impl RTDDeviceTokenWindowsPushBuilder {
   pub fn build(&self) -> DeviceTokenWindowsPush { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeviceTokenWindowsPush> { Ok(self.build()) }
+
    
   pub fn access_token<T: AsRef<str>>(&mut self, access_token: T) -> &mut Self {
     self.inner.access_token = access_token.as_ref().to_string();
@@ -19,6 +19,9 @@ pub trait TDDiceStickers: Debug + RObject {}
 #[serde(untagged)]
 pub enum DiceStickers {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A regular animated sticker
   Regular(DiceStickersRegular),
   /// Animated stickers to be combined into a slot machine
@@ -32,7 +35,6 @@ impl Default for DiceStickers {
 
 impl<'de> Deserialize<'de> for DiceStickers {
   fn deserialize<D>(deserializer: D) -> Result<DiceStickers, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       DiceStickers,
       (diceStickersRegular, Regular);
@@ -55,6 +57,7 @@ impl RObject for DiceStickers {
     match self {
       DiceStickers::Regular(t) => t.extra(),
       DiceStickers::SlotMachine(t) => t.extra(),
+      DiceStickers::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for DiceStickers {
 impl DiceStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let DiceStickers::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      DiceStickers::Regular(t) => t._missing_fields(),
+      DiceStickers::SlotMachine(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_regular(&self) -> bool { if let DiceStickers::Regular(_) = self { true } else { false } }
   pub fn is_slot_machine(&self) -> bool { if let DiceStickers::SlotMachine(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<DiceStickers> for DiceStickers {
   fn as_ref(&self) -> &DiceStickers { self }
 }
 
+impl RVariant<DiceStickers> for DiceStickersRegular {
+  fn from_class(class: &DiceStickers) -> Option<&Self> { match class { DiceStickers::Regular(t) => Some(t), _ => None } }
+}
+
+impl RVariant<DiceStickers> for DiceStickersSlotMachine {
+  fn from_class(class: &DiceStickers) -> Option<&Self> { match class { DiceStickers::SlotMachine(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -124,7 +142,16 @@ impl DiceStickersRegular {
     let mut inner = DiceStickersRegular::default();
     inner.td_name = "diceStickersRegular".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDiceStickersRegularBuilder { inner }
+    RTDDiceStickersRegularBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("diceStickersRegular", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
   }
 
   pub fn sticker(&self) -> &Sticker { &self.sticker }
@@ -133,15 +160,25 @@ impl DiceStickersRegular {
 
 #[doc(hidden)]
 pub struct RTDDiceStickersRegularBuilder {
-  inner: DiceStickersRegular
+  inner: DiceStickersRegular,
+  set: [bool; 1]
 }
 
 impl RTDDiceStickersRegularBuilder {
   pub fn build(&self) -> DiceStickersRegular { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DiceStickersRegular> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("diceStickersRegular", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -200,7 +237,20 @@ impl DiceStickersSlotMachine {
     let mut inner = DiceStickersSlotMachine::default();
     inner.td_name = "diceStickersSlotMachine".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDiceStickersSlotMachineBuilder { inner }
+    RTDDiceStickersSlotMachineBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("diceStickersSlotMachine", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.background._missing_fields())); }
+    if false { missing.push("lever".to_string()); } else { missing.extend(crate::types::_common::missing_in("lever", self.lever._missing_fields())); }
+    if false { missing.push("left_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("left_reel", self.left_reel._missing_fields())); }
+    if false { missing.push("center_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("center_reel", self.center_reel._missing_fields())); }
+    if false { missing.push("right_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("right_reel", self.right_reel._missing_fields())); }
+    missing
   }
 
   pub fn background(&self) -> &Sticker { &self.background }
@@ -217,39 +267,57 @@ impl DiceStickersSlotMachine {
 
 #[doc(hidden)]
 pub struct RTDDiceStickersSlotMachineBuilder {
-  inner: DiceStickersSlotMachine
+  inner: DiceStickersSlotMachine,
+  set: [bool; 5]
 }
 
 impl RTDDiceStickersSlotMachineBuilder {
   pub fn build(&self) -> DiceStickersSlotMachine { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DiceStickersSlotMachine> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.inner.background._missing_fields())); }
+    if !self.set[1] { missing.push("lever".to_string()); } else { missing.extend(crate::types::_common::missing_in("lever", self.inner.lever._missing_fields())); }
+    if !self.set[2] { missing.push("left_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("left_reel", self.inner.left_reel._missing_fields())); }
+    if !self.set[3] { missing.push("center_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("center_reel", self.inner.center_reel._missing_fields())); }
+    if !self.set[4] { missing.push("right_reel".to_string()); } else { missing.extend(crate::types::_common::missing_in("right_reel", self.inner.right_reel._missing_fields())); }
+    crate::types::_common::check_missing("diceStickersSlotMachine", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn background<T: AsRef<Sticker>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn lever<T: AsRef<Sticker>>(&mut self, lever: T) -> &mut Self {
     self.inner.lever = lever.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn left_reel<T: AsRef<Sticker>>(&mut self, left_reel: T) -> &mut Self {
     self.inner.left_reel = left_reel.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
    
   pub fn center_reel<T: AsRef<Sticker>>(&mut self, center_reel: T) -> &mut Self {
     self.inner.center_reel = center_reel.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
    
   pub fn right_reel<T: AsRef<Sticker>>(&mut self, right_reel: T) -> &mut Self {
     self.inner.right_reel = right_reel.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -42,7 +42,18 @@ impl Document {
     let mut inner = Document::default();
     inner.td_name = "document".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDocumentBuilder { inner }
+    RTDDocumentBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("document", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_name.is_empty() { missing.push("file_name".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if false { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.document._missing_fields())); }
+    missing
   }
 
   pub fn file_name(&self) -> &String { &self.file_name }
@@ -59,21 +70,34 @@ impl Document {
 
 #[doc(hidden)]
 pub struct RTDDocumentBuilder {
-  inner: Document
+  inner: Document,
+  set: [bool; 3]
 }
 
 impl RTDDocumentBuilder {
   pub fn build(&self) -> Document { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Document> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_name".to_string()); }
+    if !self.set[1] { missing.push("mime_type".to_string()); }
+    if !self.set[2] { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.inner.document._missing_fields())); }
+    crate::types::_common::check_missing("document", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -92,6 +116,7 @@ impl RTDDocumentBuilder {
    
   pub fn document<T: AsRef<File>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
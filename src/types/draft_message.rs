@@ -38,7 +38,17 @@ impl DraftMessage {
     let mut inner = DraftMessage::default();
     inner.td_name = "draftMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDraftMessageBuilder { inner }
+    RTDDraftMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("draftMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.input_message_text._is_default() { missing.push("input_message_text".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_text", self.input_message_text._missing_fields())); }
+    missing
   }
 
   pub fn reply_to_message_id(&self) -> i64 { self.reply_to_message_id }
@@ -51,12 +61,22 @@ impl DraftMessage {
 
 #[doc(hidden)]
 pub struct RTDDraftMessageBuilder {
-  inner: DraftMessage
+  inner: DraftMessage,
+  set: [bool; 1]
 }
 
 impl RTDDraftMessageBuilder {
   pub fn build(&self) -> DraftMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DraftMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("date".to_string()); }
+    if self.inner.input_message_text._is_default() { missing.push("input_message_text".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_text", self.inner.input_message_text._missing_fields())); }
+    crate::types::_common::check_missing("draftMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
     self.inner.reply_to_message_id = reply_to_message_id;
@@ -66,6 +86,7 @@ impl RTDDraftMessageBuilder {
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[0] = true;
     self
   }
 
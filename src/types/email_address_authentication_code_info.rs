@@ -36,7 +36,16 @@ impl EmailAddressAuthenticationCodeInfo {
     let mut inner = EmailAddressAuthenticationCodeInfo::default();
     inner.td_name = "emailAddressAuthenticationCodeInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEmailAddressAuthenticationCodeInfoBuilder { inner }
+    RTDEmailAddressAuthenticationCodeInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("emailAddressAuthenticationCodeInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.email_address_pattern.is_empty() { missing.push("email_address_pattern".to_string()); }
+    missing
   }
 
   pub fn email_address_pattern(&self) -> &String { &self.email_address_pattern }
@@ -47,15 +56,25 @@ impl EmailAddressAuthenticationCodeInfo {
 
 #[doc(hidden)]
 pub struct RTDEmailAddressAuthenticationCodeInfoBuilder {
-  inner: EmailAddressAuthenticationCodeInfo
+  inner: EmailAddressAuthenticationCodeInfo,
+  set: [bool; 1]
 }
 
 impl RTDEmailAddressAuthenticationCodeInfoBuilder {
   pub fn build(&self) -> EmailAddressAuthenticationCodeInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EmailAddressAuthenticationCodeInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("email_address_pattern".to_string()); }
+    crate::types::_common::check_missing("emailAddressAuthenticationCodeInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn email_address_pattern<T: AsRef<str>>(&mut self, email_address_pattern: T) -> &mut Self {
     self.inner.email_address_pattern = email_address_pattern.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
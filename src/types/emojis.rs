@@ -37,6 +37,11 @@ impl Emojis {
     RTDEmojisBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("emojis", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDEmojisBuilder {
 impl RTDEmojisBuilder {
   pub fn build(&self) -> Emojis { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Emojis> { Ok(self.build()) }
+
    
   pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
     self.inner.emojis = emojis;
@@ -38,7 +38,18 @@ impl EncryptedCredentials {
     let mut inner = EncryptedCredentials::default();
     inner.td_name = "encryptedCredentials".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEncryptedCredentialsBuilder { inner }
+    RTDEncryptedCredentialsBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("encryptedCredentials", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    if self.hash.is_empty() { missing.push("hash".to_string()); }
+    if self.secret.is_empty() { missing.push("secret".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -51,27 +62,41 @@ impl EncryptedCredentials {
 
 #[doc(hidden)]
 pub struct RTDEncryptedCredentialsBuilder {
-  inner: EncryptedCredentials
+  inner: EncryptedCredentials,
+  set: [bool; 3]
 }
 
 impl RTDEncryptedCredentialsBuilder {
   pub fn build(&self) -> EncryptedCredentials { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EncryptedCredentials> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    if !self.set[1] { missing.push("hash".to_string()); }
+    if !self.set[2] { missing.push("secret".to_string()); }
+    crate::types::_common::check_missing("encryptedCredentials", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
     self.inner.secret = secret.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
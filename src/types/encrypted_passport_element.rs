@@ -50,7 +50,20 @@ impl EncryptedPassportElement {
     let mut inner = EncryptedPassportElement::default();
     inner.td_name = "encryptedPassportElement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEncryptedPassportElementBuilder { inner }
+    RTDEncryptedPassportElementBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("encryptedPassportElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    if false { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.front_side._missing_fields())); }
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    if self.hash.is_empty() { missing.push("hash".to_string()); }
+    missing
   }
 
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
@@ -75,12 +88,25 @@ impl EncryptedPassportElement {
 
 #[doc(hidden)]
 pub struct RTDEncryptedPassportElementBuilder {
-  inner: EncryptedPassportElement
+  inner: EncryptedPassportElement,
+  set: [bool; 4]
 }
 
 impl RTDEncryptedPassportElementBuilder {
   pub fn build(&self) -> EncryptedPassportElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EncryptedPassportElement> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[0] { missing.push("data".to_string()); }
+    if !self.set[1] { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.inner.front_side._missing_fields())); }
+    if !self.set[2] { missing.push("value".to_string()); }
+    if !self.set[3] { missing.push("hash".to_string()); }
+    crate::types::_common::check_missing("encryptedPassportElement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -90,12 +116,14 @@ impl RTDEncryptedPassportElementBuilder {
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn front_side<T: AsRef<DatedFile>>(&mut self, front_side: T) -> &mut Self {
     self.inner.front_side = front_side.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -126,12 +154,14 @@ impl RTDEncryptedPassportElementBuilder {
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
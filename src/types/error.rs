@@ -36,7 +36,17 @@ impl Error {
     let mut inner = Error::default();
     inner.td_name = "error".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDErrorBuilder { inner }
+    RTDErrorBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("error", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code == 0 { missing.push("code".to_string()); }
+    if self.message.is_empty() { missing.push("message".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> i64 { self.code }
@@ -47,21 +57,33 @@ impl Error {
 
 #[doc(hidden)]
 pub struct RTDErrorBuilder {
-  inner: Error
+  inner: Error,
+  set: [bool; 2]
 }
 
 impl RTDErrorBuilder {
   pub fn build(&self) -> Error { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Error> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    if !self.set[1] { missing.push("message".to_string()); }
+    crate::types::_common::check_missing("error", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code(&mut self, code: i64) -> &mut Self {
     self.inner.code = code;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message<T: AsRef<str>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
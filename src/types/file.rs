@@ -42,7 +42,19 @@ impl File {
     let mut inner = File::default();
     inner.td_name = "file".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDFileBuilder { inner }
+    RTDFileBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("file", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.expected_size == 0 { missing.push("expected_size".to_string()); }
+    if false { missing.push("local".to_string()); } else { missing.extend(crate::types::_common::missing_in("local", self.local._missing_fields())); }
+    if false { missing.push("remote".to_string()); } else { missing.extend(crate::types::_common::missing_in("remote", self.remote._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -59,15 +71,28 @@ impl File {
 
 #[doc(hidden)]
 pub struct RTDFileBuilder {
-  inner: File
+  inner: File,
+  set: [bool; 4]
 }
 
 impl RTDFileBuilder {
   pub fn build(&self) -> File { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<File> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("expected_size".to_string()); }
+    if !self.set[2] { missing.push("local".to_string()); } else { missing.extend(crate::types::_common::missing_in("local", self.inner.local._missing_fields())); }
+    if !self.set[3] { missing.push("remote".to_string()); } else { missing.extend(crate::types::_common::missing_in("remote", self.inner.remote._missing_fields())); }
+    crate::types::_common::check_missing("file", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -80,18 +105,21 @@ impl RTDFileBuilder {
    
   pub fn expected_size(&mut self, expected_size: i64) -> &mut Self {
     self.inner.expected_size = expected_size;
+    self.set[1] = true;
     self
   }
 
    
   pub fn local<T: AsRef<LocalFile>>(&mut self, local: T) -> &mut Self {
     self.inner.local = local.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
    
   pub fn remote<T: AsRef<RemoteFile>>(&mut self, remote: T) -> &mut Self {
     self.inner.remote = remote.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
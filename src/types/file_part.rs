@@ -34,7 +34,16 @@ impl FilePart {
     let mut inner = FilePart::default();
     inner.td_name = "filePart".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDFilePartBuilder { inner }
+    RTDFilePartBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("filePart", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -43,15 +52,25 @@ impl FilePart {
 
 #[doc(hidden)]
 pub struct RTDFilePartBuilder {
-  inner: FilePart
+  inner: FilePart,
+  set: [bool; 1]
 }
 
 impl RTDFilePartBuilder {
   pub fn build(&self) -> FilePart { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FilePart> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("filePart", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
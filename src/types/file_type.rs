@@ -19,6 +19,9 @@ pub trait TDFileType: Debug + RObject {}
 #[serde(untagged)]
 pub enum FileType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The file is an animation
   Animation(FileTypeAnimation),
   /// The file is an audio file
@@ -42,7 +45,7 @@ pub enum FileType {
   /// The file is a thumbnail of another file
   Thumbnail(FileTypeThumbnail),
   /// The file type is not yet known
-  Unknown(FileTypeUnknown),
+  FileTypeUnknown(FileTypeUnknown),
   /// The file is a video
   Video(FileTypeVideo),
   /// The file is a video note
@@ -60,7 +63,6 @@ impl Default for FileType {
 
 impl<'de> Deserialize<'de> for FileType {
   fn deserialize<D>(deserializer: D) -> Result<FileType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       FileType,
       (fileTypeAnimation, Animation);
@@ -74,7 +76,7 @@ impl<'de> Deserialize<'de> for FileType {
       (fileTypeSecure, Secure);
       (fileTypeSticker, Sticker);
       (fileTypeThumbnail, Thumbnail);
-      (fileTypeUnknown, Unknown);
+      (fileTypeUnknown, FileTypeUnknown);
       (fileTypeVideo, Video);
       (fileTypeVideoNote, VideoNote);
       (fileTypeVoiceNote, VoiceNote);
@@ -98,7 +100,7 @@ impl RObject for FileType {
       FileType::Secure(t) => t.td_name(),
       FileType::Sticker(t) => t.td_name(),
       FileType::Thumbnail(t) => t.td_name(),
-      FileType::Unknown(t) => t.td_name(),
+      FileType::FileTypeUnknown(t) => t.td_name(),
       FileType::Video(t) => t.td_name(),
       FileType::VideoNote(t) => t.td_name(),
       FileType::VoiceNote(t) => t.td_name(),
@@ -120,11 +122,12 @@ impl RObject for FileType {
       FileType::Secure(t) => t.extra(),
       FileType::Sticker(t) => t.extra(),
       FileType::Thumbnail(t) => t.extra(),
-      FileType::Unknown(t) => t.extra(),
+      FileType::FileTypeUnknown(t) => t.extra(),
       FileType::Video(t) => t.extra(),
       FileType::VideoNote(t) => t.extra(),
       FileType::VoiceNote(t) => t.extra(),
       FileType::Wallpaper(t) => t.extra(),
+      FileType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -135,6 +138,27 @@ impl RObject for FileType {
 impl FileType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let FileType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      FileType::Animation(t) => t._missing_fields(),
+      FileType::Audio(t) => t._missing_fields(),
+      FileType::Document(t) => t._missing_fields(),
+      FileType::None(t) => t._missing_fields(),
+      FileType::Photo(t) => t._missing_fields(),
+      FileType::ProfilePhoto(t) => t._missing_fields(),
+      FileType::Secret(t) => t._missing_fields(),
+      FileType::SecretThumbnail(t) => t._missing_fields(),
+      FileType::Secure(t) => t._missing_fields(),
+      FileType::Sticker(t) => t._missing_fields(),
+      FileType::Thumbnail(t) => t._missing_fields(),
+      FileType::FileTypeUnknown(t) => t._missing_fields(),
+      FileType::Video(t) => t._missing_fields(),
+      FileType::VideoNote(t) => t._missing_fields(),
+      FileType::VoiceNote(t) => t._missing_fields(),
+      FileType::Wallpaper(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let FileType::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let FileType::Audio(_) = self { true } else { false } }
@@ -147,7 +171,7 @@ impl FileType {
   pub fn is_secure(&self) -> bool { if let FileType::Secure(_) = self { true } else { false } }
   pub fn is_sticker(&self) -> bool { if let FileType::Sticker(_) = self { true } else { false } }
   pub fn is_thumbnail(&self) -> bool { if let FileType::Thumbnail(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let FileType::Unknown(_) = self { true } else { false } }
+  pub fn is_file_type_unknown(&self) -> bool { if let FileType::FileTypeUnknown(_) = self { true } else { false } }
   pub fn is_video(&self) -> bool { if let FileType::Video(_) = self { true } else { false } }
   pub fn is_video_note(&self) -> bool { if let FileType::VideoNote(_) = self { true } else { false } }
   pub fn is_voice_note(&self) -> bool { if let FileType::VoiceNote(_) = self { true } else { false } }
@@ -164,7 +188,7 @@ impl FileType {
   pub fn on_secure<F: FnOnce(&FileTypeSecure)>(&self, fnc: F) -> &Self { if let FileType::Secure(t) = self { fnc(t) }; self }
   pub fn on_sticker<F: FnOnce(&FileTypeSticker)>(&self, fnc: F) -> &Self { if let FileType::Sticker(t) = self { fnc(t) }; self }
   pub fn on_thumbnail<F: FnOnce(&FileTypeThumbnail)>(&self, fnc: F) -> &Self { if let FileType::Thumbnail(t) = self { fnc(t) }; self }
-  pub fn on_unknown<F: FnOnce(&FileTypeUnknown)>(&self, fnc: F) -> &Self { if let FileType::Unknown(t) = self { fnc(t) }; self }
+  pub fn on_file_type_unknown<F: FnOnce(&FileTypeUnknown)>(&self, fnc: F) -> &Self { if let FileType::FileTypeUnknown(t) = self { fnc(t) }; self }
   pub fn on_video<F: FnOnce(&FileTypeVideo)>(&self, fnc: F) -> &Self { if let FileType::Video(t) = self { fnc(t) }; self }
   pub fn on_video_note<F: FnOnce(&FileTypeVideoNote)>(&self, fnc: F) -> &Self { if let FileType::VideoNote(t) = self { fnc(t) }; self }
   pub fn on_voice_note<F: FnOnce(&FileTypeVoiceNote)>(&self, fnc: F) -> &Self { if let FileType::VoiceNote(t) = self { fnc(t) }; self }
@@ -181,7 +205,7 @@ impl FileType {
   pub fn as_secure(&self) -> Option<&FileTypeSecure> { if let FileType::Secure(t) = self { return Some(t) } None }
   pub fn as_sticker(&self) -> Option<&FileTypeSticker> { if let FileType::Sticker(t) = self { return Some(t) } None }
   pub fn as_thumbnail(&self) -> Option<&FileTypeThumbnail> { if let FileType::Thumbnail(t) = self { return Some(t) } None }
-  pub fn as_unknown(&self) -> Option<&FileTypeUnknown> { if let FileType::Unknown(t) = self { return Some(t) } None }
+  pub fn as_file_type_unknown(&self) -> Option<&FileTypeUnknown> { if let FileType::FileTypeUnknown(t) = self { return Some(t) } None }
   pub fn as_video(&self) -> Option<&FileTypeVideo> { if let FileType::Video(t) = self { return Some(t) } None }
   pub fn as_video_note(&self) -> Option<&FileTypeVideoNote> { if let FileType::VideoNote(t) = self { return Some(t) } None }
   pub fn as_voice_note(&self) -> Option<&FileTypeVoiceNote> { if let FileType::VoiceNote(t) = self { return Some(t) } None }
@@ -211,7 +235,7 @@ impl FileType {
 
   pub fn thumbnail<T: AsRef<FileTypeThumbnail>>(t: T) -> Self { FileType::Thumbnail(t.as_ref().clone()) }
 
-  pub fn unknown<T: AsRef<FileTypeUnknown>>(t: T) -> Self { FileType::Unknown(t.as_ref().clone()) }
+  pub fn file_type_unknown<T: AsRef<FileTypeUnknown>>(t: T) -> Self { FileType::FileTypeUnknown(t.as_ref().clone()) }
 
   pub fn video<T: AsRef<FileTypeVideo>>(t: T) -> Self { FileType::Video(t.as_ref().clone()) }
 
@@ -227,6 +251,70 @@ impl AsRef<FileType> for FileType {
   fn as_ref(&self) -> &FileType { self }
 }
 
+impl RVariant<FileType> for FileTypeAnimation {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeAudio {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeDocument {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Document(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeNone {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::None(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypePhoto {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeProfilePhoto {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::ProfilePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeSecret {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Secret(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeSecretThumbnail {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::SecretThumbnail(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeSecure {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Secure(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeSticker {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Sticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeThumbnail {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Thumbnail(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeUnknown {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::FileTypeUnknown(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeVideo {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeVideoNote {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::VideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeVoiceNote {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::VoiceNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<FileType> for FileTypeWallpaper {
+  fn from_class(class: &FileType) -> Option<&Self> { match class { FileType::Wallpaper(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -265,6 +353,11 @@ impl FileTypeAnimation {
     RTDFileTypeAnimationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -275,6 +368,9 @@ pub struct RTDFileTypeAnimationBuilder {
 impl RTDFileTypeAnimationBuilder {
   pub fn build(&self) -> FileTypeAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeAnimation> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeAnimation> for FileTypeAnimation {
@@ -323,6 +419,11 @@ impl FileTypeAudio {
     RTDFileTypeAudioBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -333,6 +434,9 @@ pub struct RTDFileTypeAudioBuilder {
 impl RTDFileTypeAudioBuilder {
   pub fn build(&self) -> FileTypeAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeAudio> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeAudio> for FileTypeAudio {
@@ -381,6 +485,11 @@ impl FileTypeDocument {
     RTDFileTypeDocumentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -391,6 +500,9 @@ pub struct RTDFileTypeDocumentBuilder {
 impl RTDFileTypeDocumentBuilder {
   pub fn build(&self) -> FileTypeDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeDocument> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeDocument> for FileTypeDocument {
@@ -439,6 +551,11 @@ impl FileTypeNone {
     RTDFileTypeNoneBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeNone", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -449,6 +566,9 @@ pub struct RTDFileTypeNoneBuilder {
 impl RTDFileTypeNoneBuilder {
   pub fn build(&self) -> FileTypeNone { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeNone> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeNone> for FileTypeNone {
@@ -497,6 +617,11 @@ impl FileTypePhoto {
     RTDFileTypePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -507,6 +632,9 @@ pub struct RTDFileTypePhotoBuilder {
 impl RTDFileTypePhotoBuilder {
   pub fn build(&self) -> FileTypePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypePhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypePhoto> for FileTypePhoto {
@@ -555,6 +683,11 @@ impl FileTypeProfilePhoto {
     RTDFileTypeProfilePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeProfilePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -565,6 +698,9 @@ pub struct RTDFileTypeProfilePhotoBuilder {
 impl RTDFileTypeProfilePhotoBuilder {
   pub fn build(&self) -> FileTypeProfilePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeProfilePhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeProfilePhoto> for FileTypeProfilePhoto {
@@ -613,6 +749,11 @@ impl FileTypeSecret {
     RTDFileTypeSecretBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeSecret", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -623,6 +764,9 @@ pub struct RTDFileTypeSecretBuilder {
 impl RTDFileTypeSecretBuilder {
   pub fn build(&self) -> FileTypeSecret { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeSecret> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeSecret> for FileTypeSecret {
@@ -671,6 +815,11 @@ impl FileTypeSecretThumbnail {
     RTDFileTypeSecretThumbnailBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeSecretThumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -681,6 +830,9 @@ pub struct RTDFileTypeSecretThumbnailBuilder {
 impl RTDFileTypeSecretThumbnailBuilder {
   pub fn build(&self) -> FileTypeSecretThumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeSecretThumbnail> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeSecretThumbnail> for FileTypeSecretThumbnail {
@@ -729,6 +881,11 @@ impl FileTypeSecure {
     RTDFileTypeSecureBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeSecure", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -739,6 +896,9 @@ pub struct RTDFileTypeSecureBuilder {
 impl RTDFileTypeSecureBuilder {
   pub fn build(&self) -> FileTypeSecure { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeSecure> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeSecure> for FileTypeSecure {
@@ -787,6 +947,11 @@ impl FileTypeSticker {
     RTDFileTypeStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -797,6 +962,9 @@ pub struct RTDFileTypeStickerBuilder {
 impl RTDFileTypeStickerBuilder {
   pub fn build(&self) -> FileTypeSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeSticker> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeSticker> for FileTypeSticker {
@@ -845,6 +1013,11 @@ impl FileTypeThumbnail {
     RTDFileTypeThumbnailBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeThumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -855,6 +1028,9 @@ pub struct RTDFileTypeThumbnailBuilder {
 impl RTDFileTypeThumbnailBuilder {
   pub fn build(&self) -> FileTypeThumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeThumbnail> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeThumbnail> for FileTypeThumbnail {
@@ -903,6 +1079,11 @@ impl FileTypeUnknown {
     RTDFileTypeUnknownBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeUnknown", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -913,6 +1094,9 @@ pub struct RTDFileTypeUnknownBuilder {
 impl RTDFileTypeUnknownBuilder {
   pub fn build(&self) -> FileTypeUnknown { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeUnknown> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeUnknown> for FileTypeUnknown {
@@ -961,6 +1145,11 @@ impl FileTypeVideo {
     RTDFileTypeVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -971,6 +1160,9 @@ pub struct RTDFileTypeVideoBuilder {
 impl RTDFileTypeVideoBuilder {
   pub fn build(&self) -> FileTypeVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeVideo> for FileTypeVideo {
@@ -1019,6 +1211,11 @@ impl FileTypeVideoNote {
     RTDFileTypeVideoNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1029,6 +1226,9 @@ pub struct RTDFileTypeVideoNoteBuilder {
 impl RTDFileTypeVideoNoteBuilder {
   pub fn build(&self) -> FileTypeVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeVideoNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeVideoNote> for FileTypeVideoNote {
@@ -1077,6 +1277,11 @@ impl FileTypeVoiceNote {
     RTDFileTypeVoiceNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1087,6 +1292,9 @@ pub struct RTDFileTypeVoiceNoteBuilder {
 impl RTDFileTypeVoiceNoteBuilder {
   pub fn build(&self) -> FileTypeVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeVoiceNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeVoiceNote> for FileTypeVoiceNote {
@@ -1135,6 +1343,11 @@ impl FileTypeWallpaper {
     RTDFileTypeWallpaperBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("fileTypeWallpaper", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1145,6 +1358,9 @@ pub struct RTDFileTypeWallpaperBuilder {
 impl RTDFileTypeWallpaperBuilder {
   pub fn build(&self) -> FileTypeWallpaper { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FileTypeWallpaper> { Ok(self.build()) }
+
 }
 
 impl AsRef<FileTypeWallpaper> for FileTypeWallpaper {
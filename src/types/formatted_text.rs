@@ -36,7 +36,16 @@ impl FormattedText {
     let mut inner = FormattedText::default();
     inner.td_name = "formattedText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDFormattedTextBuilder { inner }
+    RTDFormattedTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("formattedText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -47,15 +56,25 @@ impl FormattedText {
 
 #[doc(hidden)]
 pub struct RTDFormattedTextBuilder {
-  inner: FormattedText
+  inner: FormattedText,
+  set: [bool; 1]
 }
 
 impl RTDFormattedTextBuilder {
   pub fn build(&self) -> FormattedText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FormattedText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("formattedText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
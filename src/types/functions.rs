@@ -31,7 +31,9 @@ impl RObject for AcceptCall {
 
 
 
-impl RFunction for AcceptCall {}
+impl RFunction for AcceptCall {
+  type Response = Ok;
+}
 
 impl AcceptCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -39,7 +41,17 @@ impl AcceptCall {
     let mut inner = AcceptCall::default();
     inner.td_name = "acceptCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAcceptCallBuilder { inner }
+    RTDAcceptCallBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("acceptCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if false { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.protocol._missing_fields())); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -50,21 +62,33 @@ impl AcceptCall {
 
 #[doc(hidden)]
 pub struct RTDAcceptCallBuilder {
-  inner: AcceptCall
+  inner: AcceptCall,
+  set: [bool; 2]
 }
 
 impl RTDAcceptCallBuilder {
   pub fn build(&self) -> AcceptCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AcceptCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.inner.protocol._missing_fields())); }
+    crate::types::_common::check_missing("acceptCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -107,7 +131,9 @@ impl RObject for AcceptTermsOfService {
 
 
 
-impl RFunction for AcceptTermsOfService {}
+impl RFunction for AcceptTermsOfService {
+  type Response = Ok;
+}
 
 impl AcceptTermsOfService {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -115,7 +141,16 @@ impl AcceptTermsOfService {
     let mut inner = AcceptTermsOfService::default();
     inner.td_name = "acceptTermsOfService".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAcceptTermsOfServiceBuilder { inner }
+    RTDAcceptTermsOfServiceBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("acceptTermsOfService", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.terms_of_service_id.is_empty() { missing.push("terms_of_service_id".to_string()); }
+    missing
   }
 
   pub fn terms_of_service_id(&self) -> &String { &self.terms_of_service_id }
@@ -124,15 +159,25 @@ impl AcceptTermsOfService {
 
 #[doc(hidden)]
 pub struct RTDAcceptTermsOfServiceBuilder {
-  inner: AcceptTermsOfService
+  inner: AcceptTermsOfService,
+  set: [bool; 1]
 }
 
 impl RTDAcceptTermsOfServiceBuilder {
   pub fn build(&self) -> AcceptTermsOfService { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AcceptTermsOfService> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("terms_of_service_id".to_string()); }
+    crate::types::_common::check_missing("acceptTermsOfService", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
     self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -179,7 +224,9 @@ impl RObject for AddChatMember {
 
 
 
-impl RFunction for AddChatMember {}
+impl RFunction for AddChatMember {
+  type Response = Ok;
+}
 
 impl AddChatMember {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -187,7 +234,18 @@ impl AddChatMember {
     let mut inner = AddChatMember::default();
     inner.td_name = "addChatMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddChatMemberBuilder { inner }
+    RTDAddChatMemberBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addChatMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.forward_limit == 0 { missing.push("forward_limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -200,27 +258,41 @@ impl AddChatMember {
 
 #[doc(hidden)]
 pub struct RTDAddChatMemberBuilder {
-  inner: AddChatMember
+  inner: AddChatMember,
+  set: [bool; 3]
 }
 
 impl RTDAddChatMemberBuilder {
   pub fn build(&self) -> AddChatMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddChatMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if !self.set[2] { missing.push("forward_limit".to_string()); }
+    crate::types::_common::check_missing("addChatMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn forward_limit(&mut self, forward_limit: i64) -> &mut Self {
     self.inner.forward_limit = forward_limit;
+    self.set[2] = true;
     self
   }
 
@@ -265,7 +337,9 @@ impl RObject for AddChatMembers {
 
 
 
-impl RFunction for AddChatMembers {}
+impl RFunction for AddChatMembers {
+  type Response = Ok;
+}
 
 impl AddChatMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -273,7 +347,16 @@ impl AddChatMembers {
     let mut inner = AddChatMembers::default();
     inner.td_name = "addChatMembers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddChatMembersBuilder { inner }
+    RTDAddChatMembersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addChatMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -284,15 +367,25 @@ impl AddChatMembers {
 
 #[doc(hidden)]
 pub struct RTDAddChatMembersBuilder {
-  inner: AddChatMembers
+  inner: AddChatMembers,
+  set: [bool; 1]
 }
 
 impl RTDAddChatMembersBuilder {
   pub fn build(&self) -> AddChatMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddChatMembers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("addChatMembers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -343,7 +436,9 @@ impl RObject for AddChatToList {
 
 
 
-impl RFunction for AddChatToList {}
+impl RFunction for AddChatToList {
+  type Response = Ok;
+}
 
 impl AddChatToList {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -351,7 +446,17 @@ impl AddChatToList {
     let mut inner = AddChatToList::default();
     inner.td_name = "addChatToList".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddChatToListBuilder { inner }
+    RTDAddChatToListBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addChatToList", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -362,15 +467,26 @@ impl AddChatToList {
 
 #[doc(hidden)]
 pub struct RTDAddChatToListBuilder {
-  inner: AddChatToList
+  inner: AddChatToList,
+  set: [bool; 1]
 }
 
 impl RTDAddChatToListBuilder {
   pub fn build(&self) -> AddChatToList { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddChatToList> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    crate::types::_common::check_missing("addChatToList", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -421,7 +537,9 @@ impl RObject for AddContact {
 
 
 
-impl RFunction for AddContact {}
+impl RFunction for AddContact {
+  type Response = Ok;
+}
 
 impl AddContact {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -432,6 +550,11 @@ impl AddContact {
     RTDAddContactBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn contact(&self) -> &Contact { &self.contact }
 
   pub fn share_phone_number(&self) -> bool { self.share_phone_number }
@@ -446,6 +569,9 @@ pub struct RTDAddContactBuilder {
 impl RTDAddContactBuilder {
   pub fn build(&self) -> AddContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddContact> { Ok(self.build()) }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
@@ -497,7 +623,9 @@ impl RObject for AddCustomServerLanguagePack {
 
 
 
-impl RFunction for AddCustomServerLanguagePack {}
+impl RFunction for AddCustomServerLanguagePack {
+  type Response = Ok;
+}
 
 impl AddCustomServerLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -505,7 +633,16 @@ impl AddCustomServerLanguagePack {
     let mut inner = AddCustomServerLanguagePack::default();
     inner.td_name = "addCustomServerLanguagePack".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddCustomServerLanguagePackBuilder { inner }
+    RTDAddCustomServerLanguagePackBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addCustomServerLanguagePack", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -514,15 +651,25 @@ impl AddCustomServerLanguagePack {
 
 #[doc(hidden)]
 pub struct RTDAddCustomServerLanguagePackBuilder {
-  inner: AddCustomServerLanguagePack
+  inner: AddCustomServerLanguagePack,
+  set: [bool; 1]
 }
 
 impl RTDAddCustomServerLanguagePackBuilder {
   pub fn build(&self) -> AddCustomServerLanguagePack { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddCustomServerLanguagePack> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("addCustomServerLanguagePack", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -565,7 +712,9 @@ impl RObject for AddFavoriteSticker {
 
 
 
-impl RFunction for AddFavoriteSticker {}
+impl RFunction for AddFavoriteSticker {
+  type Response = Ok;
+}
 
 impl AddFavoriteSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -576,6 +725,15 @@ impl AddFavoriteSticker {
     RTDAddFavoriteStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addFavoriteSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
 }
@@ -588,6 +746,14 @@ pub struct RTDAddFavoriteStickerBuilder {
 impl RTDAddFavoriteStickerBuilder {
   pub fn build(&self) -> AddFavoriteSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddFavoriteSticker> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("addFavoriteSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -641,7 +807,9 @@ impl RObject for AddLocalMessage {
 
 
 
-impl RFunction for AddLocalMessage {}
+impl RFunction for AddLocalMessage {
+  type Response = Message;
+}
 
 impl AddLocalMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -649,7 +817,18 @@ impl AddLocalMessage {
     let mut inner = AddLocalMessage::default();
     inner.td_name = "addLocalMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddLocalMessageBuilder { inner }
+    RTDAddLocalMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addLocalMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.sender._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -666,15 +845,27 @@ impl AddLocalMessage {
 
 #[doc(hidden)]
 pub struct RTDAddLocalMessageBuilder {
-  inner: AddLocalMessage
+  inner: AddLocalMessage,
+  set: [bool; 1]
 }
 
 impl RTDAddLocalMessageBuilder {
   pub fn build(&self) -> AddLocalMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddLocalMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.inner.sender._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("addLocalMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -743,7 +934,9 @@ impl RObject for AddLogMessage {
 
 
 
-impl RFunction for AddLogMessage {}
+impl RFunction for AddLogMessage {
+  type Response = Ok;
+}
 
 impl AddLogMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -751,7 +944,17 @@ impl AddLogMessage {
     let mut inner = AddLogMessage::default();
     inner.td_name = "addLogMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddLogMessageBuilder { inner }
+    RTDAddLogMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addLogMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.verbosity_level == 0 { missing.push("verbosity_level".to_string()); }
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn verbosity_level(&self) -> i64 { self.verbosity_level }
@@ -762,21 +965,33 @@ impl AddLogMessage {
 
 #[doc(hidden)]
 pub struct RTDAddLogMessageBuilder {
-  inner: AddLogMessage
+  inner: AddLogMessage,
+  set: [bool; 2]
 }
 
 impl RTDAddLogMessageBuilder {
   pub fn build(&self) -> AddLogMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddLogMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("verbosity_level".to_string()); }
+    if !self.set[1] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("addLogMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
     self.inner.verbosity_level = verbosity_level;
+    self.set[0] = true;
     self
   }
 
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -819,7 +1034,9 @@ impl RObject for AddNetworkStatistics {
 
 
 
-impl RFunction for AddNetworkStatistics {}
+impl RFunction for AddNetworkStatistics {
+  type Response = Ok;
+}
 
 impl AddNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -830,6 +1047,15 @@ impl AddNetworkStatistics {
     RTDAddNetworkStatisticsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addNetworkStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.entry._is_default() { missing.push("entry".to_string()); } else { missing.extend(crate::types::_common::missing_in("entry", self.entry._missing_fields())); }
+    missing
+  }
+
   pub fn entry(&self) -> &NetworkStatisticsEntry { &self.entry }
 
 }
@@ -842,6 +1068,14 @@ pub struct RTDAddNetworkStatisticsBuilder {
 impl RTDAddNetworkStatisticsBuilder {
   pub fn build(&self) -> AddNetworkStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddNetworkStatistics> {
+    let mut missing = vec![];
+    if self.inner.entry._is_default() { missing.push("entry".to_string()); } else { missing.extend(crate::types::_common::missing_in("entry", self.inner.entry._missing_fields())); }
+    crate::types::_common::check_missing("addNetworkStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn entry<T: AsRef<NetworkStatisticsEntry>>(&mut self, entry: T) -> &mut Self {
     self.inner.entry = entry.as_ref().clone();
@@ -893,7 +1127,9 @@ impl RObject for AddProxy {
 
 
 
-impl RFunction for AddProxy {}
+impl RFunction for AddProxy {
+  type Response = Proxy;
+}
 
 impl AddProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -901,7 +1137,18 @@ impl AddProxy {
     let mut inner = AddProxy::default();
     inner.td_name = "addProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddProxyBuilder { inner }
+    RTDAddProxyBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.server.is_empty() { missing.push("server".to_string()); }
+    if self.port == 0 { missing.push("port".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn server(&self) -> &String { &self.server }
@@ -916,21 +1163,34 @@ impl AddProxy {
 
 #[doc(hidden)]
 pub struct RTDAddProxyBuilder {
-  inner: AddProxy
+  inner: AddProxy,
+  set: [bool; 2]
 }
 
 impl RTDAddProxyBuilder {
   pub fn build(&self) -> AddProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("server".to_string()); }
+    if !self.set[1] { missing.push("port".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("addProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn port(&mut self, port: i64) -> &mut Self {
     self.inner.port = port;
+    self.set[1] = true;
     self
   }
 
@@ -987,7 +1247,9 @@ impl RObject for AddRecentSticker {
 
 
 
-impl RFunction for AddRecentSticker {}
+impl RFunction for AddRecentSticker {
+  type Response = Stickers;
+}
 
 impl AddRecentSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -998,6 +1260,15 @@ impl AddRecentSticker {
     RTDAddRecentStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addRecentSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -1012,6 +1283,14 @@ pub struct RTDAddRecentStickerBuilder {
 impl RTDAddRecentStickerBuilder {
   pub fn build(&self) -> AddRecentSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddRecentSticker> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("addRecentSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -1063,7 +1342,9 @@ impl RObject for AddRecentlyFoundChat {
 
 
 
-impl RFunction for AddRecentlyFoundChat {}
+impl RFunction for AddRecentlyFoundChat {
+  type Response = Ok;
+}
 
 impl AddRecentlyFoundChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1071,7 +1352,16 @@ impl AddRecentlyFoundChat {
     let mut inner = AddRecentlyFoundChat::default();
     inner.td_name = "addRecentlyFoundChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddRecentlyFoundChatBuilder { inner }
+    RTDAddRecentlyFoundChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addRecentlyFoundChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1080,15 +1370,25 @@ impl AddRecentlyFoundChat {
 
 #[doc(hidden)]
 pub struct RTDAddRecentlyFoundChatBuilder {
-  inner: AddRecentlyFoundChat
+  inner: AddRecentlyFoundChat,
+  set: [bool; 1]
 }
 
 impl RTDAddRecentlyFoundChatBuilder {
   pub fn build(&self) -> AddRecentlyFoundChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddRecentlyFoundChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("addRecentlyFoundChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1131,7 +1431,9 @@ impl RObject for AddSavedAnimation {
 
 
 
-impl RFunction for AddSavedAnimation {}
+impl RFunction for AddSavedAnimation {
+  type Response = Ok;
+}
 
 impl AddSavedAnimation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1142,6 +1444,15 @@ impl AddSavedAnimation {
     RTDAddSavedAnimationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addSavedAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    missing
+  }
+
   pub fn animation(&self) -> &InputFile { &self.animation }
 
 }
@@ -1154,6 +1465,14 @@ pub struct RTDAddSavedAnimationBuilder {
 impl RTDAddSavedAnimationBuilder {
   pub fn build(&self) -> AddSavedAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddSavedAnimation> {
+    let mut missing = vec![];
+    if self.inner.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    crate::types::_common::check_missing("addSavedAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -1203,7 +1522,9 @@ impl RObject for AddStickerToSet {
 
 
 
-impl RFunction for AddStickerToSet {}
+impl RFunction for AddStickerToSet {
+  type Response = StickerSet;
+}
 
 impl AddStickerToSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1211,7 +1532,18 @@ impl AddStickerToSet {
     let mut inner = AddStickerToSet::default();
     inner.td_name = "addStickerToSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAddStickerToSetBuilder { inner }
+    RTDAddStickerToSetBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("addStickerToSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -1224,21 +1556,34 @@ impl AddStickerToSet {
 
 #[doc(hidden)]
 pub struct RTDAddStickerToSetBuilder {
-  inner: AddStickerToSet
+  inner: AddStickerToSet,
+  set: [bool; 2]
 }
 
 impl RTDAddStickerToSetBuilder {
   pub fn build(&self) -> AddStickerToSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AddStickerToSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("name".to_string()); }
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("addStickerToSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1274,7 +1619,7 @@ pub struct AnswerCallbackQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the callback query
-  callback_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] callback_query_id: i64,
   /// Text of the answer
   text: String,
   /// If true, an alert should be shown to the user instead of a toast notification
@@ -1295,7 +1640,9 @@ impl RObject for AnswerCallbackQuery {
 
 
 
-impl RFunction for AnswerCallbackQuery {}
+impl RFunction for AnswerCallbackQuery {
+  type Response = Ok;
+}
 
 impl AnswerCallbackQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1303,10 +1650,22 @@ impl AnswerCallbackQuery {
     let mut inner = AnswerCallbackQuery::default();
     inner.td_name = "answerCallbackQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnswerCallbackQueryBuilder { inner }
+    RTDAnswerCallbackQueryBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("answerCallbackQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.callback_query_id == 0 { missing.push("callback_query_id".to_string()); }
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.cache_time == 0 { missing.push("cache_time".to_string()); }
+    missing
   }
 
-  pub fn callback_query_id(&self) -> isize { self.callback_query_id }
+  pub fn callback_query_id(&self) -> i64 { self.callback_query_id }
 
   pub fn text(&self) -> &String { &self.text }
 
@@ -1320,21 +1679,35 @@ impl AnswerCallbackQuery {
 
 #[doc(hidden)]
 pub struct RTDAnswerCallbackQueryBuilder {
-  inner: AnswerCallbackQuery
+  inner: AnswerCallbackQuery,
+  set: [bool; 4]
 }
 
 impl RTDAnswerCallbackQueryBuilder {
   pub fn build(&self) -> AnswerCallbackQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnswerCallbackQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("callback_query_id".to_string()); }
+    if !self.set[1] { missing.push("text".to_string()); }
+    if !self.set[2] { missing.push("url".to_string()); }
+    if !self.set[3] { missing.push("cache_time".to_string()); }
+    crate::types::_common::check_missing("answerCallbackQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn callback_query_id(&mut self, callback_query_id: isize) -> &mut Self {
+  pub fn callback_query_id(&mut self, callback_query_id: i64) -> &mut Self {
     self.inner.callback_query_id = callback_query_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1347,12 +1720,14 @@ impl RTDAnswerCallbackQueryBuilder {
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn cache_time(&mut self, cache_time: i64) -> &mut Self {
     self.inner.cache_time = cache_time;
+    self.set[3] = true;
     self
   }
 
@@ -1382,7 +1757,7 @@ pub struct AnswerCustomQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of a custom query
-  custom_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] custom_query_id: i64,
   /// JSON-serialized answer to the query
   data: String,
   
@@ -1397,7 +1772,9 @@ impl RObject for AnswerCustomQuery {
 
 
 
-impl RFunction for AnswerCustomQuery {}
+impl RFunction for AnswerCustomQuery {
+  type Response = Ok;
+}
 
 impl AnswerCustomQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1405,10 +1782,20 @@ impl AnswerCustomQuery {
     let mut inner = AnswerCustomQuery::default();
     inner.td_name = "answerCustomQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnswerCustomQueryBuilder { inner }
+    RTDAnswerCustomQueryBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("answerCustomQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.custom_query_id == 0 { missing.push("custom_query_id".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
-  pub fn custom_query_id(&self) -> isize { self.custom_query_id }
+  pub fn custom_query_id(&self) -> i64 { self.custom_query_id }
 
   pub fn data(&self) -> &String { &self.data }
 
@@ -1416,21 +1803,33 @@ impl AnswerCustomQuery {
 
 #[doc(hidden)]
 pub struct RTDAnswerCustomQueryBuilder {
-  inner: AnswerCustomQuery
+  inner: AnswerCustomQuery,
+  set: [bool; 2]
 }
 
 impl RTDAnswerCustomQueryBuilder {
   pub fn build(&self) -> AnswerCustomQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnswerCustomQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("custom_query_id".to_string()); }
+    if !self.set[1] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("answerCustomQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn custom_query_id(&mut self, custom_query_id: isize) -> &mut Self {
+  pub fn custom_query_id(&mut self, custom_query_id: i64) -> &mut Self {
     self.inner.custom_query_id = custom_query_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1460,7 +1859,7 @@ pub struct AnswerInlineQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the inline query
-  inline_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] inline_query_id: i64,
   /// True, if the result of the query can be cached for the specified user
   is_personal: bool,
   /// The results of the query
@@ -1485,7 +1884,9 @@ impl RObject for AnswerInlineQuery {
 
 
 
-impl RFunction for AnswerInlineQuery {}
+impl RFunction for AnswerInlineQuery {
+  type Response = Ok;
+}
 
 impl AnswerInlineQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1493,10 +1894,23 @@ impl AnswerInlineQuery {
     let mut inner = AnswerInlineQuery::default();
     inner.td_name = "answerInlineQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnswerInlineQueryBuilder { inner }
+    RTDAnswerInlineQueryBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("answerInlineQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_query_id == 0 { missing.push("inline_query_id".to_string()); }
+    if self.cache_time == 0 { missing.push("cache_time".to_string()); }
+    if self.next_offset.is_empty() { missing.push("next_offset".to_string()); }
+    if self.switch_pm_text.is_empty() { missing.push("switch_pm_text".to_string()); }
+    if self.switch_pm_parameter.is_empty() { missing.push("switch_pm_parameter".to_string()); }
+    missing
   }
 
-  pub fn inline_query_id(&self) -> isize { self.inline_query_id }
+  pub fn inline_query_id(&self) -> i64 { self.inline_query_id }
 
   pub fn is_personal(&self) -> bool { self.is_personal }
 
@@ -1514,15 +1928,29 @@ impl AnswerInlineQuery {
 
 #[doc(hidden)]
 pub struct RTDAnswerInlineQueryBuilder {
-  inner: AnswerInlineQuery
+  inner: AnswerInlineQuery,
+  set: [bool; 5]
 }
 
 impl RTDAnswerInlineQueryBuilder {
   pub fn build(&self) -> AnswerInlineQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnswerInlineQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_query_id".to_string()); }
+    if !self.set[1] { missing.push("cache_time".to_string()); }
+    if !self.set[2] { missing.push("next_offset".to_string()); }
+    if !self.set[3] { missing.push("switch_pm_text".to_string()); }
+    if !self.set[4] { missing.push("switch_pm_parameter".to_string()); }
+    crate::types::_common::check_missing("answerInlineQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+  pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
     self.inner.inline_query_id = inline_query_id;
+    self.set[0] = true;
     self
   }
 
@@ -1541,24 +1969,28 @@ impl RTDAnswerInlineQueryBuilder {
    
   pub fn cache_time(&mut self, cache_time: i64) -> &mut Self {
     self.inner.cache_time = cache_time;
+    self.set[1] = true;
     self
   }
 
    
   pub fn next_offset<T: AsRef<str>>(&mut self, next_offset: T) -> &mut Self {
     self.inner.next_offset = next_offset.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn switch_pm_text<T: AsRef<str>>(&mut self, switch_pm_text: T) -> &mut Self {
     self.inner.switch_pm_text = switch_pm_text.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn switch_pm_parameter<T: AsRef<str>>(&mut self, switch_pm_parameter: T) -> &mut Self {
     self.inner.switch_pm_parameter = switch_pm_parameter.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -1588,7 +2020,7 @@ pub struct AnswerPreCheckoutQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the pre-checkout query
-  pre_checkout_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] pre_checkout_query_id: i64,
   /// An error message, empty on success
   error_message: String,
   
@@ -1603,7 +2035,9 @@ impl RObject for AnswerPreCheckoutQuery {
 
 
 
-impl RFunction for AnswerPreCheckoutQuery {}
+impl RFunction for AnswerPreCheckoutQuery {
+  type Response = Ok;
+}
 
 impl AnswerPreCheckoutQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1611,10 +2045,20 @@ impl AnswerPreCheckoutQuery {
     let mut inner = AnswerPreCheckoutQuery::default();
     inner.td_name = "answerPreCheckoutQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnswerPreCheckoutQueryBuilder { inner }
+    RTDAnswerPreCheckoutQueryBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("answerPreCheckoutQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.pre_checkout_query_id == 0 { missing.push("pre_checkout_query_id".to_string()); }
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    missing
   }
 
-  pub fn pre_checkout_query_id(&self) -> isize { self.pre_checkout_query_id }
+  pub fn pre_checkout_query_id(&self) -> i64 { self.pre_checkout_query_id }
 
   pub fn error_message(&self) -> &String { &self.error_message }
 
@@ -1622,21 +2066,33 @@ impl AnswerPreCheckoutQuery {
 
 #[doc(hidden)]
 pub struct RTDAnswerPreCheckoutQueryBuilder {
-  inner: AnswerPreCheckoutQuery
+  inner: AnswerPreCheckoutQuery,
+  set: [bool; 2]
 }
 
 impl RTDAnswerPreCheckoutQueryBuilder {
   pub fn build(&self) -> AnswerPreCheckoutQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnswerPreCheckoutQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("pre_checkout_query_id".to_string()); }
+    if !self.set[1] { missing.push("error_message".to_string()); }
+    crate::types::_common::check_missing("answerPreCheckoutQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn pre_checkout_query_id(&mut self, pre_checkout_query_id: isize) -> &mut Self {
+  pub fn pre_checkout_query_id(&mut self, pre_checkout_query_id: i64) -> &mut Self {
     self.inner.pre_checkout_query_id = pre_checkout_query_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1666,7 +2122,7 @@ pub struct AnswerShippingQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the shipping query
-  shipping_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] shipping_query_id: i64,
   /// Available shipping options
   shipping_options: Vec<ShippingOption>,
   /// An error message, empty on success
@@ -1683,7 +2139,9 @@ impl RObject for AnswerShippingQuery {
 
 
 
-impl RFunction for AnswerShippingQuery {}
+impl RFunction for AnswerShippingQuery {
+  type Response = Ok;
+}
 
 impl AnswerShippingQuery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1691,10 +2149,20 @@ impl AnswerShippingQuery {
     let mut inner = AnswerShippingQuery::default();
     inner.td_name = "answerShippingQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDAnswerShippingQueryBuilder { inner }
+    RTDAnswerShippingQueryBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("answerShippingQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.shipping_query_id == 0 { missing.push("shipping_query_id".to_string()); }
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    missing
   }
 
-  pub fn shipping_query_id(&self) -> isize { self.shipping_query_id }
+  pub fn shipping_query_id(&self) -> i64 { self.shipping_query_id }
 
   pub fn shipping_options(&self) -> &Vec<ShippingOption> { &self.shipping_options }
 
@@ -1704,15 +2172,26 @@ impl AnswerShippingQuery {
 
 #[doc(hidden)]
 pub struct RTDAnswerShippingQueryBuilder {
-  inner: AnswerShippingQuery
+  inner: AnswerShippingQuery,
+  set: [bool; 2]
 }
 
 impl RTDAnswerShippingQueryBuilder {
   pub fn build(&self) -> AnswerShippingQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<AnswerShippingQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("shipping_query_id".to_string()); }
+    if !self.set[1] { missing.push("error_message".to_string()); }
+    crate::types::_common::check_missing("answerShippingQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn shipping_query_id(&mut self, shipping_query_id: isize) -> &mut Self {
+  pub fn shipping_query_id(&mut self, shipping_query_id: i64) -> &mut Self {
     self.inner.shipping_query_id = shipping_query_id;
+    self.set[0] = true;
     self
   }
 
@@ -1725,6 +2204,7 @@ impl RTDAnswerShippingQueryBuilder {
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1773,7 +2253,9 @@ impl RObject for BanChatMember {
 
 
 
-impl RFunction for BanChatMember {}
+impl RFunction for BanChatMember {
+  type Response = Ok;
+}
 
 impl BanChatMember {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1781,7 +2263,17 @@ impl BanChatMember {
     let mut inner = BanChatMember::default();
     inner.td_name = "banChatMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBanChatMemberBuilder { inner }
+    RTDBanChatMemberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("banChatMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1796,21 +2288,33 @@ impl BanChatMember {
 
 #[doc(hidden)]
 pub struct RTDBanChatMemberBuilder {
-  inner: BanChatMember
+  inner: BanChatMember,
+  set: [bool; 2]
 }
 
 impl RTDBanChatMemberBuilder {
   pub fn build(&self) -> BanChatMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BanChatMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("banChatMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -1871,7 +2375,9 @@ impl RObject for BlockMessageSenderFromReplies {
 
 
 
-impl RFunction for BlockMessageSenderFromReplies {}
+impl RFunction for BlockMessageSenderFromReplies {
+  type Response = Ok;
+}
 
 impl BlockMessageSenderFromReplies {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1879,7 +2385,16 @@ impl BlockMessageSenderFromReplies {
     let mut inner = BlockMessageSenderFromReplies::default();
     inner.td_name = "blockMessageSenderFromReplies".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDBlockMessageSenderFromRepliesBuilder { inner }
+    RTDBlockMessageSenderFromRepliesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("blockMessageSenderFromReplies", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn message_id(&self) -> i64 { self.message_id }
@@ -1894,15 +2409,25 @@ impl BlockMessageSenderFromReplies {
 
 #[doc(hidden)]
 pub struct RTDBlockMessageSenderFromRepliesBuilder {
-  inner: BlockMessageSenderFromReplies
+  inner: BlockMessageSenderFromReplies,
+  set: [bool; 1]
 }
 
 impl RTDBlockMessageSenderFromRepliesBuilder {
   pub fn build(&self) -> BlockMessageSenderFromReplies { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<BlockMessageSenderFromReplies> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("blockMessageSenderFromReplies", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[0] = true;
     self
   }
 
@@ -1959,9 +2484,11 @@ impl RObject for CanTransferOwnership {
 }
 
 
-impl TDCanTransferOwnershipResult for CanTransferOwnership {}
 
-impl RFunction for CanTransferOwnership {}
+
+impl RFunction for CanTransferOwnership {
+  type Response = CanTransferOwnershipResult;
+}
 
 impl CanTransferOwnership {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -1972,6 +2499,11 @@ impl CanTransferOwnership {
     RTDCanTransferOwnershipBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("canTransferOwnership", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1982,6 +2514,9 @@ pub struct RTDCanTransferOwnershipBuilder {
 impl RTDCanTransferOwnershipBuilder {
   pub fn build(&self) -> CanTransferOwnership { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CanTransferOwnership> { Ok(self.build()) }
+
 }
 
 impl AsRef<CanTransferOwnership> for CanTransferOwnership {
@@ -2023,7 +2558,9 @@ impl RObject for CancelDownloadFile {
 
 
 
-impl RFunction for CancelDownloadFile {}
+impl RFunction for CancelDownloadFile {
+  type Response = Ok;
+}
 
 impl CancelDownloadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2031,7 +2568,16 @@ impl CancelDownloadFile {
     let mut inner = CancelDownloadFile::default();
     inner.td_name = "cancelDownloadFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCancelDownloadFileBuilder { inner }
+    RTDCancelDownloadFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("cancelDownloadFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -2042,15 +2588,25 @@ impl CancelDownloadFile {
 
 #[doc(hidden)]
 pub struct RTDCancelDownloadFileBuilder {
-  inner: CancelDownloadFile
+  inner: CancelDownloadFile,
+  set: [bool; 1]
 }
 
 impl RTDCancelDownloadFileBuilder {
   pub fn build(&self) -> CancelDownloadFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CancelDownloadFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    crate::types::_common::check_missing("cancelDownloadFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
@@ -2099,7 +2655,9 @@ impl RObject for CancelUploadFile {
 
 
 
-impl RFunction for CancelUploadFile {}
+impl RFunction for CancelUploadFile {
+  type Response = Ok;
+}
 
 impl CancelUploadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2107,7 +2665,16 @@ impl CancelUploadFile {
     let mut inner = CancelUploadFile::default();
     inner.td_name = "cancelUploadFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCancelUploadFileBuilder { inner }
+    RTDCancelUploadFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("cancelUploadFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -2116,15 +2683,25 @@ impl CancelUploadFile {
 
 #[doc(hidden)]
 pub struct RTDCancelUploadFileBuilder {
-  inner: CancelUploadFile
+  inner: CancelUploadFile,
+  set: [bool; 1]
 }
 
 impl RTDCancelUploadFileBuilder {
   pub fn build(&self) -> CancelUploadFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CancelUploadFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    crate::types::_common::check_missing("cancelUploadFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
@@ -2167,7 +2744,9 @@ impl RObject for ChangeImportedContacts {
 
 
 
-impl RFunction for ChangeImportedContacts {}
+impl RFunction for ChangeImportedContacts {
+  type Response = ImportedContacts;
+}
 
 impl ChangeImportedContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2178,6 +2757,11 @@ impl ChangeImportedContacts {
     RTDChangeImportedContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("changeImportedContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn contacts(&self) -> &Vec<Contact> { &self.contacts }
 
 }
@@ -2190,6 +2774,9 @@ pub struct RTDChangeImportedContactsBuilder {
 impl RTDChangeImportedContactsBuilder {
   pub fn build(&self) -> ChangeImportedContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChangeImportedContacts> { Ok(self.build()) }
+
    
   pub fn contacts(&mut self, contacts: Vec<Contact>) -> &mut Self {
     self.inner.contacts = contacts;
@@ -2237,7 +2824,9 @@ impl RObject for ChangePhoneNumber {
 
 
 
-impl RFunction for ChangePhoneNumber {}
+impl RFunction for ChangePhoneNumber {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ChangePhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2245,7 +2834,17 @@ impl ChangePhoneNumber {
     let mut inner = ChangePhoneNumber::default();
     inner.td_name = "changePhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChangePhoneNumberBuilder { inner }
+    RTDChangePhoneNumberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("changePhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if false { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.settings._missing_fields())); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -2256,21 +2855,33 @@ impl ChangePhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDChangePhoneNumberBuilder {
-  inner: ChangePhoneNumber
+  inner: ChangePhoneNumber,
+  set: [bool; 2]
 }
 
 impl RTDChangePhoneNumberBuilder {
   pub fn build(&self) -> ChangePhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChangePhoneNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    if !self.set[1] { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.inner.settings._missing_fields())); }
+    crate::types::_common::check_missing("changePhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn settings<T: AsRef<PhoneNumberAuthenticationSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -2300,7 +2911,7 @@ pub struct ChangeStickerSet {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the sticker set
-  set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] set_id: i64,
   /// The new value of is_installed
   is_installed: bool,
   /// The new value of is_archived. A sticker set can't be installed and archived simultaneously
@@ -2317,7 +2928,9 @@ impl RObject for ChangeStickerSet {
 
 
 
-impl RFunction for ChangeStickerSet {}
+impl RFunction for ChangeStickerSet {
+  type Response = Ok;
+}
 
 impl ChangeStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2325,10 +2938,19 @@ impl ChangeStickerSet {
     let mut inner = ChangeStickerSet::default();
     inner.td_name = "changeStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDChangeStickerSetBuilder { inner }
+    RTDChangeStickerSetBuilder { inner, set: [false; 1] }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("changeStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.set_id == 0 { missing.push("set_id".to_string()); }
+    missing
+  }
+
+  pub fn set_id(&self) -> i64 { self.set_id }
 
   pub fn is_installed(&self) -> bool { self.is_installed }
 
@@ -2338,15 +2960,25 @@ impl ChangeStickerSet {
 
 #[doc(hidden)]
 pub struct RTDChangeStickerSetBuilder {
-  inner: ChangeStickerSet
+  inner: ChangeStickerSet,
+  set: [bool; 1]
 }
 
 impl RTDChangeStickerSetBuilder {
   pub fn build(&self) -> ChangeStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ChangeStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("set_id".to_string()); }
+    crate::types::_common::check_missing("changeStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
+    self.set[0] = true;
     self
   }
 
@@ -2401,7 +3033,9 @@ impl RObject for CheckAuthenticationBotToken {
 
 
 
-impl RFunction for CheckAuthenticationBotToken {}
+impl RFunction for CheckAuthenticationBotToken {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationBotToken {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2409,7 +3043,16 @@ impl CheckAuthenticationBotToken {
     let mut inner = CheckAuthenticationBotToken::default();
     inner.td_name = "checkAuthenticationBotToken".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckAuthenticationBotTokenBuilder { inner }
+    RTDCheckAuthenticationBotTokenBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkAuthenticationBotToken", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.token.is_empty() { missing.push("token".to_string()); }
+    missing
   }
 
   pub fn token(&self) -> &String { &self.token }
@@ -2418,15 +3061,25 @@ impl CheckAuthenticationBotToken {
 
 #[doc(hidden)]
 pub struct RTDCheckAuthenticationBotTokenBuilder {
-  inner: CheckAuthenticationBotToken
+  inner: CheckAuthenticationBotToken,
+  set: [bool; 1]
 }
 
 impl RTDCheckAuthenticationBotTokenBuilder {
   pub fn build(&self) -> CheckAuthenticationBotToken { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckAuthenticationBotToken> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("token".to_string()); }
+    crate::types::_common::check_missing("checkAuthenticationBotToken", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2469,7 +3122,9 @@ impl RObject for CheckAuthenticationCode {
 
 
 
-impl RFunction for CheckAuthenticationCode {}
+impl RFunction for CheckAuthenticationCode {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2477,7 +3132,16 @@ impl CheckAuthenticationCode {
     let mut inner = CheckAuthenticationCode::default();
     inner.td_name = "checkAuthenticationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckAuthenticationCodeBuilder { inner }
+    RTDCheckAuthenticationCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkAuthenticationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -2486,15 +3150,25 @@ impl CheckAuthenticationCode {
 
 #[doc(hidden)]
 pub struct RTDCheckAuthenticationCodeBuilder {
-  inner: CheckAuthenticationCode
+  inner: CheckAuthenticationCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckAuthenticationCodeBuilder {
   pub fn build(&self) -> CheckAuthenticationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckAuthenticationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkAuthenticationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2537,7 +3211,9 @@ impl RObject for CheckAuthenticationPassword {
 
 
 
-impl RFunction for CheckAuthenticationPassword {}
+impl RFunction for CheckAuthenticationPassword {
+  type Response = Ok;
+}
 
 impl CheckAuthenticationPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2545,7 +3221,16 @@ impl CheckAuthenticationPassword {
     let mut inner = CheckAuthenticationPassword::default();
     inner.td_name = "checkAuthenticationPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckAuthenticationPasswordBuilder { inner }
+    RTDCheckAuthenticationPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkAuthenticationPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -2554,15 +3239,25 @@ impl CheckAuthenticationPassword {
 
 #[doc(hidden)]
 pub struct RTDCheckAuthenticationPasswordBuilder {
-  inner: CheckAuthenticationPassword
+  inner: CheckAuthenticationPassword,
+  set: [bool; 1]
 }
 
 impl RTDCheckAuthenticationPasswordBuilder {
   pub fn build(&self) -> CheckAuthenticationPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckAuthenticationPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("checkAuthenticationPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2605,7 +3300,9 @@ impl RObject for CheckChangePhoneNumberCode {
 
 
 
-impl RFunction for CheckChangePhoneNumberCode {}
+impl RFunction for CheckChangePhoneNumberCode {
+  type Response = Ok;
+}
 
 impl CheckChangePhoneNumberCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2613,7 +3310,16 @@ impl CheckChangePhoneNumberCode {
     let mut inner = CheckChangePhoneNumberCode::default();
     inner.td_name = "checkChangePhoneNumberCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckChangePhoneNumberCodeBuilder { inner }
+    RTDCheckChangePhoneNumberCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChangePhoneNumberCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -2622,15 +3328,25 @@ impl CheckChangePhoneNumberCode {
 
 #[doc(hidden)]
 pub struct RTDCheckChangePhoneNumberCodeBuilder {
-  inner: CheckChangePhoneNumberCode
+  inner: CheckChangePhoneNumberCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckChangePhoneNumberCodeBuilder {
   pub fn build(&self) -> CheckChangePhoneNumberCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChangePhoneNumberCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkChangePhoneNumberCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2673,7 +3389,9 @@ impl RObject for CheckChatInviteLink {
 
 
 
-impl RFunction for CheckChatInviteLink {}
+impl RFunction for CheckChatInviteLink {
+  type Response = ChatInviteLinkInfo;
+}
 
 impl CheckChatInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2681,7 +3399,16 @@ impl CheckChatInviteLink {
     let mut inner = CheckChatInviteLink::default();
     inner.td_name = "checkChatInviteLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckChatInviteLinkBuilder { inner }
+    RTDCheckChatInviteLinkBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatInviteLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.invite_link.is_empty() { missing.push("invite_link".to_string()); }
+    missing
   }
 
   pub fn invite_link(&self) -> &String { &self.invite_link }
@@ -2690,15 +3417,25 @@ impl CheckChatInviteLink {
 
 #[doc(hidden)]
 pub struct RTDCheckChatInviteLinkBuilder {
-  inner: CheckChatInviteLink
+  inner: CheckChatInviteLink,
+  set: [bool; 1]
 }
 
 impl RTDCheckChatInviteLinkBuilder {
   pub fn build(&self) -> CheckChatInviteLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatInviteLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invite_link".to_string()); }
+    crate::types::_common::check_missing("checkChatInviteLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2741,9 +3478,11 @@ impl RObject for CheckChatUsername {
 }
 
 
-impl TDCheckChatUsernameResult for CheckChatUsername {}
 
-impl RFunction for CheckChatUsername {}
+
+impl RFunction for CheckChatUsername {
+  type Response = CheckChatUsernameResult;
+}
 
 impl CheckChatUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2751,7 +3490,17 @@ impl CheckChatUsername {
     let mut inner = CheckChatUsername::default();
     inner.td_name = "checkChatUsername".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckChatUsernameBuilder { inner }
+    RTDCheckChatUsernameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkChatUsername", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2762,21 +3511,33 @@ impl CheckChatUsername {
 
 #[doc(hidden)]
 pub struct RTDCheckChatUsernameBuilder {
-  inner: CheckChatUsername
+  inner: CheckChatUsername,
+  set: [bool; 2]
 }
 
 impl RTDCheckChatUsernameBuilder {
   pub fn build(&self) -> CheckChatUsername { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckChatUsername> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("username".to_string()); }
+    crate::types::_common::check_missing("checkChatUsername", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -2819,7 +3580,9 @@ impl RObject for CheckCreatedPublicChatsLimit {
 
 
 
-impl RFunction for CheckCreatedPublicChatsLimit {}
+impl RFunction for CheckCreatedPublicChatsLimit {
+  type Response = Ok;
+}
 
 impl CheckCreatedPublicChatsLimit {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2830,6 +3593,15 @@ impl CheckCreatedPublicChatsLimit {
     RTDCheckCreatedPublicChatsLimitBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkCreatedPublicChatsLimit", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn type_(&self) -> &PublicChatType { &self.type_ }
 
 }
@@ -2842,6 +3614,14 @@ pub struct RTDCheckCreatedPublicChatsLimitBuilder {
 impl RTDCheckCreatedPublicChatsLimitBuilder {
   pub fn build(&self) -> CheckCreatedPublicChatsLimit { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckCreatedPublicChatsLimit> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("checkCreatedPublicChatsLimit", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PublicChatType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -2887,7 +3667,9 @@ impl RObject for CheckDatabaseEncryptionKey {
 
 
 
-impl RFunction for CheckDatabaseEncryptionKey {}
+impl RFunction for CheckDatabaseEncryptionKey {
+  type Response = Ok;
+}
 
 impl CheckDatabaseEncryptionKey {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2895,7 +3677,16 @@ impl CheckDatabaseEncryptionKey {
     let mut inner = CheckDatabaseEncryptionKey::default();
     inner.td_name = "checkDatabaseEncryptionKey".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckDatabaseEncryptionKeyBuilder { inner }
+    RTDCheckDatabaseEncryptionKeyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkDatabaseEncryptionKey", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.encryption_key.is_empty() { missing.push("encryption_key".to_string()); }
+    missing
   }
 
   pub fn encryption_key(&self) -> &String { &self.encryption_key }
@@ -2904,15 +3695,25 @@ impl CheckDatabaseEncryptionKey {
 
 #[doc(hidden)]
 pub struct RTDCheckDatabaseEncryptionKeyBuilder {
-  inner: CheckDatabaseEncryptionKey
+  inner: CheckDatabaseEncryptionKey,
+  set: [bool; 1]
 }
 
 impl RTDCheckDatabaseEncryptionKeyBuilder {
   pub fn build(&self) -> CheckDatabaseEncryptionKey { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckDatabaseEncryptionKey> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("encryption_key".to_string()); }
+    crate::types::_common::check_missing("checkDatabaseEncryptionKey", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn encryption_key<T: AsRef<str>>(&mut self, encryption_key: T) -> &mut Self {
     self.inner.encryption_key = encryption_key.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2955,7 +3756,9 @@ impl RObject for CheckEmailAddressVerificationCode {
 
 
 
-impl RFunction for CheckEmailAddressVerificationCode {}
+impl RFunction for CheckEmailAddressVerificationCode {
+  type Response = Ok;
+}
 
 impl CheckEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -2963,7 +3766,16 @@ impl CheckEmailAddressVerificationCode {
     let mut inner = CheckEmailAddressVerificationCode::default();
     inner.td_name = "checkEmailAddressVerificationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckEmailAddressVerificationCodeBuilder { inner }
+    RTDCheckEmailAddressVerificationCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkEmailAddressVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -2972,15 +3784,25 @@ impl CheckEmailAddressVerificationCode {
 
 #[doc(hidden)]
 pub struct RTDCheckEmailAddressVerificationCodeBuilder {
-  inner: CheckEmailAddressVerificationCode
+  inner: CheckEmailAddressVerificationCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> CheckEmailAddressVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckEmailAddressVerificationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkEmailAddressVerificationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3023,7 +3845,9 @@ impl RObject for CheckPhoneNumberConfirmationCode {
 
 
 
-impl RFunction for CheckPhoneNumberConfirmationCode {}
+impl RFunction for CheckPhoneNumberConfirmationCode {
+  type Response = Ok;
+}
 
 impl CheckPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3031,7 +3855,16 @@ impl CheckPhoneNumberConfirmationCode {
     let mut inner = CheckPhoneNumberConfirmationCode::default();
     inner.td_name = "checkPhoneNumberConfirmationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckPhoneNumberConfirmationCodeBuilder { inner }
+    RTDCheckPhoneNumberConfirmationCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkPhoneNumberConfirmationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -3040,15 +3873,25 @@ impl CheckPhoneNumberConfirmationCode {
 
 #[doc(hidden)]
 pub struct RTDCheckPhoneNumberConfirmationCodeBuilder {
-  inner: CheckPhoneNumberConfirmationCode
+  inner: CheckPhoneNumberConfirmationCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> CheckPhoneNumberConfirmationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckPhoneNumberConfirmationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkPhoneNumberConfirmationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3091,7 +3934,9 @@ impl RObject for CheckPhoneNumberVerificationCode {
 
 
 
-impl RFunction for CheckPhoneNumberVerificationCode {}
+impl RFunction for CheckPhoneNumberVerificationCode {
+  type Response = Ok;
+}
 
 impl CheckPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3099,7 +3944,16 @@ impl CheckPhoneNumberVerificationCode {
     let mut inner = CheckPhoneNumberVerificationCode::default();
     inner.td_name = "checkPhoneNumberVerificationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckPhoneNumberVerificationCodeBuilder { inner }
+    RTDCheckPhoneNumberVerificationCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkPhoneNumberVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -3108,15 +3962,25 @@ impl CheckPhoneNumberVerificationCode {
 
 #[doc(hidden)]
 pub struct RTDCheckPhoneNumberVerificationCodeBuilder {
-  inner: CheckPhoneNumberVerificationCode
+  inner: CheckPhoneNumberVerificationCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> CheckPhoneNumberVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckPhoneNumberVerificationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkPhoneNumberVerificationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3159,7 +4023,9 @@ impl RObject for CheckRecoveryEmailAddressCode {
 
 
 
-impl RFunction for CheckRecoveryEmailAddressCode {}
+impl RFunction for CheckRecoveryEmailAddressCode {
+  type Response = PasswordState;
+}
 
 impl CheckRecoveryEmailAddressCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3167,7 +4033,16 @@ impl CheckRecoveryEmailAddressCode {
     let mut inner = CheckRecoveryEmailAddressCode::default();
     inner.td_name = "checkRecoveryEmailAddressCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCheckRecoveryEmailAddressCodeBuilder { inner }
+    RTDCheckRecoveryEmailAddressCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("checkRecoveryEmailAddressCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.code.is_empty() { missing.push("code".to_string()); }
+    missing
   }
 
   pub fn code(&self) -> &String { &self.code }
@@ -3176,15 +4051,25 @@ impl CheckRecoveryEmailAddressCode {
 
 #[doc(hidden)]
 pub struct RTDCheckRecoveryEmailAddressCodeBuilder {
-  inner: CheckRecoveryEmailAddressCode
+  inner: CheckRecoveryEmailAddressCode,
+  set: [bool; 1]
 }
 
 impl RTDCheckRecoveryEmailAddressCodeBuilder {
   pub fn build(&self) -> CheckRecoveryEmailAddressCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CheckRecoveryEmailAddressCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("code".to_string()); }
+    crate::types::_common::check_missing("checkRecoveryEmailAddressCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn code<T: AsRef<str>>(&mut self, code: T) -> &mut Self {
     self.inner.code = code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3227,7 +4112,9 @@ impl RObject for CleanFileName {
 
 
 
-impl RFunction for CleanFileName {}
+impl RFunction for CleanFileName {
+  type Response = Text;
+}
 
 impl CleanFileName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3235,7 +4122,16 @@ impl CleanFileName {
     let mut inner = CleanFileName::default();
     inner.td_name = "cleanFileName".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCleanFileNameBuilder { inner }
+    RTDCleanFileNameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("cleanFileName", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_name.is_empty() { missing.push("file_name".to_string()); }
+    missing
   }
 
   pub fn file_name(&self) -> &String { &self.file_name }
@@ -3244,15 +4140,25 @@ impl CleanFileName {
 
 #[doc(hidden)]
 pub struct RTDCleanFileNameBuilder {
-  inner: CleanFileName
+  inner: CleanFileName,
+  set: [bool; 1]
 }
 
 impl RTDCleanFileNameBuilder {
   pub fn build(&self) -> CleanFileName { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CleanFileName> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_name".to_string()); }
+    crate::types::_common::check_missing("cleanFileName", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3295,7 +4201,9 @@ impl RObject for ClearAllDraftMessages {
 
 
 
-impl RFunction for ClearAllDraftMessages {}
+impl RFunction for ClearAllDraftMessages {
+  type Response = Ok;
+}
 
 impl ClearAllDraftMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3306,6 +4214,11 @@ impl ClearAllDraftMessages {
     RTDClearAllDraftMessagesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("clearAllDraftMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn exclude_secret_chats(&self) -> bool { self.exclude_secret_chats }
 
 }
@@ -3318,6 +4231,9 @@ pub struct RTDClearAllDraftMessagesBuilder {
 impl RTDClearAllDraftMessagesBuilder {
   pub fn build(&self) -> ClearAllDraftMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ClearAllDraftMessages> { Ok(self.build()) }
+
    
   pub fn exclude_secret_chats(&mut self, exclude_secret_chats: bool) -> &mut Self {
     self.inner.exclude_secret_chats = exclude_secret_chats;
@@ -3361,7 +4277,9 @@ impl RObject for ClearImportedContacts {
 
 
 
-impl RFunction for ClearImportedContacts {}
+impl RFunction for ClearImportedContacts {
+  type Response = Ok;
+}
 
 impl ClearImportedContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3372,6 +4290,11 @@ impl ClearImportedContacts {
     RTDClearImportedContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("clearImportedContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -3382,6 +4305,9 @@ pub struct RTDClearImportedContactsBuilder {
 impl RTDClearImportedContactsBuilder {
   pub fn build(&self) -> ClearImportedContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ClearImportedContacts> { Ok(self.build()) }
+
 }
 
 impl AsRef<ClearImportedContacts> for ClearImportedContacts {
@@ -3421,7 +4347,9 @@ impl RObject for ClearRecentStickers {
 
 
 
-impl RFunction for ClearRecentStickers {}
+impl RFunction for ClearRecentStickers {
+  type Response = Ok;
+}
 
 impl ClearRecentStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3432,6 +4360,11 @@ impl ClearRecentStickers {
     RTDClearRecentStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("clearRecentStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
 }
@@ -3444,6 +4377,9 @@ pub struct RTDClearRecentStickersBuilder {
 impl RTDClearRecentStickersBuilder {
   pub fn build(&self) -> ClearRecentStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ClearRecentStickers> { Ok(self.build()) }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -3487,7 +4423,9 @@ impl RObject for ClearRecentlyFoundChats {
 
 
 
-impl RFunction for ClearRecentlyFoundChats {}
+impl RFunction for ClearRecentlyFoundChats {
+  type Response = Ok;
+}
 
 impl ClearRecentlyFoundChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3498,6 +4436,11 @@ impl ClearRecentlyFoundChats {
     RTDClearRecentlyFoundChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("clearRecentlyFoundChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -3508,6 +4451,9 @@ pub struct RTDClearRecentlyFoundChatsBuilder {
 impl RTDClearRecentlyFoundChatsBuilder {
   pub fn build(&self) -> ClearRecentlyFoundChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ClearRecentlyFoundChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<ClearRecentlyFoundChats> for ClearRecentlyFoundChats {
@@ -3545,7 +4491,9 @@ impl RObject for Close {
 
 
 
-impl RFunction for Close {}
+impl RFunction for Close {
+  type Response = Ok;
+}
 
 impl Close {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3556,6 +4504,11 @@ impl Close {
     RTDCloseBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("close", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -3566,6 +4519,9 @@ pub struct RTDCloseBuilder {
 impl RTDCloseBuilder {
   pub fn build(&self) -> Close { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Close> { Ok(self.build()) }
+
 }
 
 impl AsRef<Close> for Close {
@@ -3605,7 +4561,9 @@ impl RObject for CloseChat {
 
 
 
-impl RFunction for CloseChat {}
+impl RFunction for CloseChat {
+  type Response = Ok;
+}
 
 impl CloseChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3613,7 +4571,16 @@ impl CloseChat {
     let mut inner = CloseChat::default();
     inner.td_name = "closeChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCloseChatBuilder { inner }
+    RTDCloseChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("closeChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -3622,15 +4589,25 @@ impl CloseChat {
 
 #[doc(hidden)]
 pub struct RTDCloseChatBuilder {
-  inner: CloseChat
+  inner: CloseChat,
+  set: [bool; 1]
 }
 
 impl RTDCloseChatBuilder {
   pub fn build(&self) -> CloseChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CloseChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("closeChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -3673,7 +4650,9 @@ impl RObject for CloseSecretChat {
 
 
 
-impl RFunction for CloseSecretChat {}
+impl RFunction for CloseSecretChat {
+  type Response = Ok;
+}
 
 impl CloseSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3681,7 +4660,16 @@ impl CloseSecretChat {
     let mut inner = CloseSecretChat::default();
     inner.td_name = "closeSecretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCloseSecretChatBuilder { inner }
+    RTDCloseSecretChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("closeSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.secret_chat_id == 0 { missing.push("secret_chat_id".to_string()); }
+    missing
   }
 
   pub fn secret_chat_id(&self) -> i64 { self.secret_chat_id }
@@ -3690,15 +4678,25 @@ impl CloseSecretChat {
 
 #[doc(hidden)]
 pub struct RTDCloseSecretChatBuilder {
-  inner: CloseSecretChat
+  inner: CloseSecretChat,
+  set: [bool; 1]
 }
 
 impl RTDCloseSecretChatBuilder {
   pub fn build(&self) -> CloseSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CloseSecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret_chat_id".to_string()); }
+    crate::types::_common::check_missing("closeSecretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -3741,7 +4739,9 @@ impl RObject for ConfirmQrCodeAuthentication {
 
 
 
-impl RFunction for ConfirmQrCodeAuthentication {}
+impl RFunction for ConfirmQrCodeAuthentication {
+  type Response = Session;
+}
 
 impl ConfirmQrCodeAuthentication {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3749,7 +4749,16 @@ impl ConfirmQrCodeAuthentication {
     let mut inner = ConfirmQrCodeAuthentication::default();
     inner.td_name = "confirmQrCodeAuthentication".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDConfirmQrCodeAuthenticationBuilder { inner }
+    RTDConfirmQrCodeAuthenticationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("confirmQrCodeAuthentication", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.link.is_empty() { missing.push("link".to_string()); }
+    missing
   }
 
   pub fn link(&self) -> &String { &self.link }
@@ -3758,15 +4767,25 @@ impl ConfirmQrCodeAuthentication {
 
 #[doc(hidden)]
 pub struct RTDConfirmQrCodeAuthenticationBuilder {
-  inner: ConfirmQrCodeAuthentication
+  inner: ConfirmQrCodeAuthentication,
+  set: [bool; 1]
 }
 
 impl RTDConfirmQrCodeAuthenticationBuilder {
   pub fn build(&self) -> ConfirmQrCodeAuthentication { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ConfirmQrCodeAuthentication> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("link".to_string()); }
+    crate::types::_common::check_missing("confirmQrCodeAuthentication", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3811,7 +4830,9 @@ impl RObject for CreateBasicGroupChat {
 
 
 
-impl RFunction for CreateBasicGroupChat {}
+impl RFunction for CreateBasicGroupChat {
+  type Response = Chat;
+}
 
 impl CreateBasicGroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3819,7 +4840,16 @@ impl CreateBasicGroupChat {
     let mut inner = CreateBasicGroupChat::default();
     inner.td_name = "createBasicGroupChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateBasicGroupChatBuilder { inner }
+    RTDCreateBasicGroupChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createBasicGroupChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    missing
   }
 
   pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
@@ -3830,15 +4860,25 @@ impl CreateBasicGroupChat {
 
 #[doc(hidden)]
 pub struct RTDCreateBasicGroupChatBuilder {
-  inner: CreateBasicGroupChat
+  inner: CreateBasicGroupChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateBasicGroupChatBuilder {
   pub fn build(&self) -> CreateBasicGroupChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateBasicGroupChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group_id".to_string()); }
+    crate::types::_common::check_missing("createBasicGroupChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[0] = true;
     self
   }
 
@@ -3891,7 +4931,9 @@ impl RObject for CreateCall {
 
 
 
-impl RFunction for CreateCall {}
+impl RFunction for CreateCall {
+  type Response = CallId;
+}
 
 impl CreateCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3899,7 +4941,17 @@ impl CreateCall {
     let mut inner = CreateCall::default();
     inner.td_name = "createCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateCallBuilder { inner }
+    RTDCreateCallBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if false { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.protocol._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -3912,21 +4964,33 @@ impl CreateCall {
 
 #[doc(hidden)]
 pub struct RTDCreateCallBuilder {
-  inner: CreateCall
+  inner: CreateCall,
+  set: [bool; 2]
 }
 
 impl RTDCreateCallBuilder {
   pub fn build(&self) -> CreateCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("protocol".to_string()); } else { missing.extend(crate::types::_common::missing_in("protocol", self.inner.protocol._missing_fields())); }
+    crate::types::_common::check_missing("createCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn protocol<T: AsRef<CallProtocol>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -3975,7 +5039,9 @@ impl RObject for CreateChatFilter {
 
 
 
-impl RFunction for CreateChatFilter {}
+impl RFunction for CreateChatFilter {
+  type Response = ChatFilterInfo;
+}
 
 impl CreateChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -3983,7 +5049,16 @@ impl CreateChatFilter {
     let mut inner = CreateChatFilter::default();
     inner.td_name = "createChatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateChatFilterBuilder { inner }
+    RTDCreateChatFilterBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createChatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn filter(&self) -> &ChatFilter { &self.filter }
@@ -3992,15 +5067,25 @@ impl CreateChatFilter {
 
 #[doc(hidden)]
 pub struct RTDCreateChatFilterBuilder {
-  inner: CreateChatFilter
+  inner: CreateChatFilter,
+  set: [bool; 1]
 }
 
 impl RTDCreateChatFilterBuilder {
   pub fn build(&self) -> CreateChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("createChatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -4045,7 +5130,9 @@ impl RObject for CreateNewBasicGroupChat {
 
 
 
-impl RFunction for CreateNewBasicGroupChat {}
+impl RFunction for CreateNewBasicGroupChat {
+  type Response = Chat;
+}
 
 impl CreateNewBasicGroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4053,7 +5140,16 @@ impl CreateNewBasicGroupChat {
     let mut inner = CreateNewBasicGroupChat::default();
     inner.td_name = "createNewBasicGroupChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateNewBasicGroupChatBuilder { inner }
+    RTDCreateNewBasicGroupChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createNewBasicGroupChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
@@ -4064,12 +5160,21 @@ impl CreateNewBasicGroupChat {
 
 #[doc(hidden)]
 pub struct RTDCreateNewBasicGroupChatBuilder {
-  inner: CreateNewBasicGroupChat
+  inner: CreateNewBasicGroupChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateNewBasicGroupChatBuilder {
   pub fn build(&self) -> CreateNewBasicGroupChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateNewBasicGroupChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("createNewBasicGroupChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -4079,6 +5184,7 @@ impl RTDCreateNewBasicGroupChatBuilder {
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -4121,7 +5227,9 @@ impl RObject for CreateNewSecretChat {
 
 
 
-impl RFunction for CreateNewSecretChat {}
+impl RFunction for CreateNewSecretChat {
+  type Response = Chat;
+}
 
 impl CreateNewSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4129,7 +5237,16 @@ impl CreateNewSecretChat {
     let mut inner = CreateNewSecretChat::default();
     inner.td_name = "createNewSecretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateNewSecretChatBuilder { inner }
+    RTDCreateNewSecretChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createNewSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -4138,15 +5255,25 @@ impl CreateNewSecretChat {
 
 #[doc(hidden)]
 pub struct RTDCreateNewSecretChatBuilder {
-  inner: CreateNewSecretChat
+  inner: CreateNewSecretChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateNewSecretChatBuilder {
   pub fn build(&self) -> CreateNewSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateNewSecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("createNewSecretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -4197,7 +5324,9 @@ impl RObject for CreateNewStickerSet {
 
 
 
-impl RFunction for CreateNewStickerSet {}
+impl RFunction for CreateNewStickerSet {
+  type Response = StickerSet;
+}
 
 impl CreateNewStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4205,7 +5334,18 @@ impl CreateNewStickerSet {
     let mut inner = CreateNewStickerSet::default();
     inner.td_name = "createNewStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateNewStickerSetBuilder { inner }
+    RTDCreateNewStickerSetBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createNewStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -4222,27 +5362,41 @@ impl CreateNewStickerSet {
 
 #[doc(hidden)]
 pub struct RTDCreateNewStickerSetBuilder {
-  inner: CreateNewStickerSet
+  inner: CreateNewStickerSet,
+  set: [bool; 3]
 }
 
 impl RTDCreateNewStickerSetBuilder {
   pub fn build(&self) -> CreateNewStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateNewStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("createNewStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -4305,7 +5459,9 @@ impl RObject for CreateNewSupergroupChat {
 
 
 
-impl RFunction for CreateNewSupergroupChat {}
+impl RFunction for CreateNewSupergroupChat {
+  type Response = Chat;
+}
 
 impl CreateNewSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4313,7 +5469,18 @@ impl CreateNewSupergroupChat {
     let mut inner = CreateNewSupergroupChat::default();
     inner.td_name = "createNewSupergroupChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateNewSupergroupChatBuilder { inner }
+    RTDCreateNewSupergroupChatBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createNewSupergroupChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -4330,15 +5497,27 @@ impl CreateNewSupergroupChat {
 
 #[doc(hidden)]
 pub struct RTDCreateNewSupergroupChatBuilder {
-  inner: CreateNewSupergroupChat
+  inner: CreateNewSupergroupChat,
+  set: [bool; 3]
 }
 
 impl RTDCreateNewSupergroupChatBuilder {
   pub fn build(&self) -> CreateNewSupergroupChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateNewSupergroupChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    if !self.set[2] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    crate::types::_common::check_missing("createNewSupergroupChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -4351,12 +5530,14 @@ impl RTDCreateNewSupergroupChatBuilder {
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn location<T: AsRef<ChatLocation>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -4407,7 +5588,9 @@ impl RObject for CreatePrivateChat {
 
 
 
-impl RFunction for CreatePrivateChat {}
+impl RFunction for CreatePrivateChat {
+  type Response = Chat;
+}
 
 impl CreatePrivateChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4415,7 +5598,16 @@ impl CreatePrivateChat {
     let mut inner = CreatePrivateChat::default();
     inner.td_name = "createPrivateChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreatePrivateChatBuilder { inner }
+    RTDCreatePrivateChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createPrivateChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -4426,15 +5618,25 @@ impl CreatePrivateChat {
 
 #[doc(hidden)]
 pub struct RTDCreatePrivateChatBuilder {
-  inner: CreatePrivateChat
+  inner: CreatePrivateChat,
+  set: [bool; 1]
 }
 
 impl RTDCreatePrivateChatBuilder {
   pub fn build(&self) -> CreatePrivateChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreatePrivateChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("createPrivateChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -4483,7 +5685,9 @@ impl RObject for CreateSecretChat {
 
 
 
-impl RFunction for CreateSecretChat {}
+impl RFunction for CreateSecretChat {
+  type Response = Chat;
+}
 
 impl CreateSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4491,7 +5695,16 @@ impl CreateSecretChat {
     let mut inner = CreateSecretChat::default();
     inner.td_name = "createSecretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateSecretChatBuilder { inner }
+    RTDCreateSecretChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.secret_chat_id == 0 { missing.push("secret_chat_id".to_string()); }
+    missing
   }
 
   pub fn secret_chat_id(&self) -> i64 { self.secret_chat_id }
@@ -4500,15 +5713,25 @@ impl CreateSecretChat {
 
 #[doc(hidden)]
 pub struct RTDCreateSecretChatBuilder {
-  inner: CreateSecretChat
+  inner: CreateSecretChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateSecretChatBuilder {
   pub fn build(&self) -> CreateSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateSecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret_chat_id".to_string()); }
+    crate::types::_common::check_missing("createSecretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -4553,7 +5776,9 @@ impl RObject for CreateSupergroupChat {
 
 
 
-impl RFunction for CreateSupergroupChat {}
+impl RFunction for CreateSupergroupChat {
+  type Response = Chat;
+}
 
 impl CreateSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4561,7 +5786,16 @@ impl CreateSupergroupChat {
     let mut inner = CreateSupergroupChat::default();
     inner.td_name = "createSupergroupChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateSupergroupChatBuilder { inner }
+    RTDCreateSupergroupChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createSupergroupChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -4572,15 +5806,25 @@ impl CreateSupergroupChat {
 
 #[doc(hidden)]
 pub struct RTDCreateSupergroupChatBuilder {
-  inner: CreateSupergroupChat
+  inner: CreateSupergroupChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateSupergroupChatBuilder {
   pub fn build(&self) -> CreateSupergroupChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateSupergroupChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("createSupergroupChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -4631,7 +5875,9 @@ impl RObject for CreateTemporaryPassword {
 
 
 
-impl RFunction for CreateTemporaryPassword {}
+impl RFunction for CreateTemporaryPassword {
+  type Response = TemporaryPasswordState;
+}
 
 impl CreateTemporaryPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4639,7 +5885,17 @@ impl CreateTemporaryPassword {
     let mut inner = CreateTemporaryPassword::default();
     inner.td_name = "createTemporaryPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateTemporaryPasswordBuilder { inner }
+    RTDCreateTemporaryPasswordBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createTemporaryPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    if self.valid_for == 0 { missing.push("valid_for".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -4650,21 +5906,33 @@ impl CreateTemporaryPassword {
 
 #[doc(hidden)]
 pub struct RTDCreateTemporaryPasswordBuilder {
-  inner: CreateTemporaryPassword
+  inner: CreateTemporaryPassword,
+  set: [bool; 2]
 }
 
 impl RTDCreateTemporaryPasswordBuilder {
   pub fn build(&self) -> CreateTemporaryPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateTemporaryPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    if !self.set[1] { missing.push("valid_for".to_string()); }
+    crate::types::_common::check_missing("createTemporaryPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn valid_for(&mut self, valid_for: i64) -> &mut Self {
     self.inner.valid_for = valid_for;
+    self.set[1] = true;
     self
   }
 
@@ -4707,7 +5975,9 @@ impl RObject for CreateVoiceChat {
 
 
 
-impl RFunction for CreateVoiceChat {}
+impl RFunction for CreateVoiceChat {
+  type Response = GroupCallId;
+}
 
 impl CreateVoiceChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4715,7 +5985,16 @@ impl CreateVoiceChat {
     let mut inner = CreateVoiceChat::default();
     inner.td_name = "createVoiceChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDCreateVoiceChatBuilder { inner }
+    RTDCreateVoiceChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("createVoiceChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4724,15 +6003,25 @@ impl CreateVoiceChat {
 
 #[doc(hidden)]
 pub struct RTDCreateVoiceChatBuilder {
-  inner: CreateVoiceChat
+  inner: CreateVoiceChat,
+  set: [bool; 1]
 }
 
 impl RTDCreateVoiceChatBuilder {
   pub fn build(&self) -> CreateVoiceChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<CreateVoiceChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("createVoiceChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -4775,7 +6064,9 @@ impl RObject for DeleteAccount {
 
 
 
-impl RFunction for DeleteAccount {}
+impl RFunction for DeleteAccount {
+  type Response = Ok;
+}
 
 impl DeleteAccount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4783,7 +6074,16 @@ impl DeleteAccount {
     let mut inner = DeleteAccount::default();
     inner.td_name = "deleteAccount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteAccountBuilder { inner }
+    RTDDeleteAccountBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteAccount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.reason.is_empty() { missing.push("reason".to_string()); }
+    missing
   }
 
   pub fn reason(&self) -> &String { &self.reason }
@@ -4792,15 +6092,25 @@ impl DeleteAccount {
 
 #[doc(hidden)]
 pub struct RTDDeleteAccountBuilder {
-  inner: DeleteAccount
+  inner: DeleteAccount,
+  set: [bool; 1]
 }
 
 impl RTDDeleteAccountBuilder {
   pub fn build(&self) -> DeleteAccount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteAccount> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("reason".to_string()); }
+    crate::types::_common::check_missing("deleteAccount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn reason<T: AsRef<str>>(&mut self, reason: T) -> &mut Self {
     self.inner.reason = reason.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -4843,7 +6153,9 @@ impl RObject for DeleteAllCallMessages {
 
 
 
-impl RFunction for DeleteAllCallMessages {}
+impl RFunction for DeleteAllCallMessages {
+  type Response = Ok;
+}
 
 impl DeleteAllCallMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4854,6 +6166,11 @@ impl DeleteAllCallMessages {
     RTDDeleteAllCallMessagesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteAllCallMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn revoke(&self) -> bool { self.revoke }
 
 }
@@ -4866,6 +6183,9 @@ pub struct RTDDeleteAllCallMessagesBuilder {
 impl RTDDeleteAllCallMessagesBuilder {
   pub fn build(&self) -> DeleteAllCallMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteAllCallMessages> { Ok(self.build()) }
+
    
   pub fn revoke(&mut self, revoke: bool) -> &mut Self {
     self.inner.revoke = revoke;
@@ -4911,7 +6231,9 @@ impl RObject for DeleteChat {
 
 
 
-impl RFunction for DeleteChat {}
+impl RFunction for DeleteChat {
+  type Response = Ok;
+}
 
 impl DeleteChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4919,7 +6241,16 @@ impl DeleteChat {
     let mut inner = DeleteChat::default();
     inner.td_name = "deleteChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteChatBuilder { inner }
+    RTDDeleteChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4928,15 +6259,25 @@ impl DeleteChat {
 
 #[doc(hidden)]
 pub struct RTDDeleteChatBuilder {
-  inner: DeleteChat
+  inner: DeleteChat,
+  set: [bool; 1]
 }
 
 impl RTDDeleteChatBuilder {
   pub fn build(&self) -> DeleteChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("deleteChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -4979,7 +6320,9 @@ impl RObject for DeleteChatFilter {
 
 
 
-impl RFunction for DeleteChatFilter {}
+impl RFunction for DeleteChatFilter {
+  type Response = Ok;
+}
 
 impl DeleteChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -4987,7 +6330,16 @@ impl DeleteChatFilter {
     let mut inner = DeleteChatFilter::default();
     inner.td_name = "deleteChatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteChatFilterBuilder { inner }
+    RTDDeleteChatFilterBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteChatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_filter_id == 0 { missing.push("chat_filter_id".to_string()); }
+    missing
   }
 
   pub fn chat_filter_id(&self) -> i64 { self.chat_filter_id }
@@ -4996,15 +6348,25 @@ impl DeleteChatFilter {
 
 #[doc(hidden)]
 pub struct RTDDeleteChatFilterBuilder {
-  inner: DeleteChatFilter
+  inner: DeleteChatFilter,
+  set: [bool; 1]
 }
 
 impl RTDDeleteChatFilterBuilder {
   pub fn build(&self) -> DeleteChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_filter_id".to_string()); }
+    crate::types::_common::check_missing("deleteChatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
+    self.set[0] = true;
     self
   }
 
@@ -5051,7 +6413,9 @@ impl RObject for DeleteChatHistory {
 
 
 
-impl RFunction for DeleteChatHistory {}
+impl RFunction for DeleteChatHistory {
+  type Response = Ok;
+}
 
 impl DeleteChatHistory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5059,7 +6423,16 @@ impl DeleteChatHistory {
     let mut inner = DeleteChatHistory::default();
     inner.td_name = "deleteChatHistory".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteChatHistoryBuilder { inner }
+    RTDDeleteChatHistoryBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteChatHistory", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -5072,15 +6445,25 @@ impl DeleteChatHistory {
 
 #[doc(hidden)]
 pub struct RTDDeleteChatHistoryBuilder {
-  inner: DeleteChatHistory
+  inner: DeleteChatHistory,
+  set: [bool; 1]
 }
 
 impl RTDDeleteChatHistoryBuilder {
   pub fn build(&self) -> DeleteChatHistory { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteChatHistory> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("deleteChatHistory", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -5137,7 +6520,9 @@ impl RObject for DeleteChatMessagesFromUser {
 
 
 
-impl RFunction for DeleteChatMessagesFromUser {}
+impl RFunction for DeleteChatMessagesFromUser {
+  type Response = Ok;
+}
 
 impl DeleteChatMessagesFromUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5145,7 +6530,17 @@ impl DeleteChatMessagesFromUser {
     let mut inner = DeleteChatMessagesFromUser::default();
     inner.td_name = "deleteChatMessagesFromUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteChatMessagesFromUserBuilder { inner }
+    RTDDeleteChatMessagesFromUserBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteChatMessagesFromUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -5156,21 +6551,33 @@ impl DeleteChatMessagesFromUser {
 
 #[doc(hidden)]
 pub struct RTDDeleteChatMessagesFromUserBuilder {
-  inner: DeleteChatMessagesFromUser
+  inner: DeleteChatMessagesFromUser,
+  set: [bool; 2]
 }
 
 impl RTDDeleteChatMessagesFromUserBuilder {
   pub fn build(&self) -> DeleteChatMessagesFromUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteChatMessagesFromUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("deleteChatMessagesFromUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -5215,7 +6622,9 @@ impl RObject for DeleteChatReplyMarkup {
 
 
 
-impl RFunction for DeleteChatReplyMarkup {}
+impl RFunction for DeleteChatReplyMarkup {
+  type Response = Ok;
+}
 
 impl DeleteChatReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5223,7 +6632,17 @@ impl DeleteChatReplyMarkup {
     let mut inner = DeleteChatReplyMarkup::default();
     inner.td_name = "deleteChatReplyMarkup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteChatReplyMarkupBuilder { inner }
+    RTDDeleteChatReplyMarkupBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteChatReplyMarkup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -5234,21 +6653,33 @@ impl DeleteChatReplyMarkup {
 
 #[doc(hidden)]
 pub struct RTDDeleteChatReplyMarkupBuilder {
-  inner: DeleteChatReplyMarkup
+  inner: DeleteChatReplyMarkup,
+  set: [bool; 2]
 }
 
 impl RTDDeleteChatReplyMarkupBuilder {
   pub fn build(&self) -> DeleteChatReplyMarkup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteChatReplyMarkup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("deleteChatReplyMarkup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -5291,7 +6722,9 @@ impl RObject for DeleteFile {
 
 
 
-impl RFunction for DeleteFile {}
+impl RFunction for DeleteFile {
+  type Response = Ok;
+}
 
 impl DeleteFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5299,7 +6732,16 @@ impl DeleteFile {
     let mut inner = DeleteFile::default();
     inner.td_name = "deleteFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteFileBuilder { inner }
+    RTDDeleteFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -5308,15 +6750,25 @@ impl DeleteFile {
 
 #[doc(hidden)]
 pub struct RTDDeleteFileBuilder {
-  inner: DeleteFile
+  inner: DeleteFile,
+  set: [bool; 1]
 }
 
 impl RTDDeleteFileBuilder {
   pub fn build(&self) -> DeleteFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    crate::types::_common::check_missing("deleteFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
@@ -5359,7 +6811,9 @@ impl RObject for DeleteLanguagePack {
 
 
 
-impl RFunction for DeleteLanguagePack {}
+impl RFunction for DeleteLanguagePack {
+  type Response = Ok;
+}
 
 impl DeleteLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5367,7 +6821,16 @@ impl DeleteLanguagePack {
     let mut inner = DeleteLanguagePack::default();
     inner.td_name = "deleteLanguagePack".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteLanguagePackBuilder { inner }
+    RTDDeleteLanguagePackBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteLanguagePack", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -5376,15 +6839,25 @@ impl DeleteLanguagePack {
 
 #[doc(hidden)]
 pub struct RTDDeleteLanguagePackBuilder {
-  inner: DeleteLanguagePack
+  inner: DeleteLanguagePack,
+  set: [bool; 1]
 }
 
 impl RTDDeleteLanguagePackBuilder {
   pub fn build(&self) -> DeleteLanguagePack { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteLanguagePack> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("deleteLanguagePack", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -5431,7 +6904,9 @@ impl RObject for DeleteMessages {
 
 
 
-impl RFunction for DeleteMessages {}
+impl RFunction for DeleteMessages {
+  type Response = Ok;
+}
 
 impl DeleteMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5439,7 +6914,16 @@ impl DeleteMessages {
     let mut inner = DeleteMessages::default();
     inner.td_name = "deleteMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteMessagesBuilder { inner }
+    RTDDeleteMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -5452,15 +6936,25 @@ impl DeleteMessages {
 
 #[doc(hidden)]
 pub struct RTDDeleteMessagesBuilder {
-  inner: DeleteMessages
+  inner: DeleteMessages,
+  set: [bool; 1]
 }
 
 impl RTDDeleteMessagesBuilder {
   pub fn build(&self) -> DeleteMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("deleteMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -5515,7 +7009,9 @@ impl RObject for DeletePassportElement {
 
 
 
-impl RFunction for DeletePassportElement {}
+impl RFunction for DeletePassportElement {
+  type Response = Ok;
+}
 
 impl DeletePassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5526,6 +7022,15 @@ impl DeletePassportElement {
     RTDDeletePassportElementBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deletePassportElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
 }
@@ -5538,6 +7043,14 @@ pub struct RTDDeletePassportElementBuilder {
 impl RTDDeletePassportElementBuilder {
   pub fn build(&self) -> DeletePassportElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeletePassportElement> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("deletePassportElement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -5570,7 +7083,7 @@ pub struct DeleteProfilePhoto {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the profile photo to delete
-  profile_photo_id: isize,
+  #[serde(with = "crate::types::_common::int64")] profile_photo_id: i64,
   
 }
 
@@ -5583,7 +7096,9 @@ impl RObject for DeleteProfilePhoto {
 
 
 
-impl RFunction for DeleteProfilePhoto {}
+impl RFunction for DeleteProfilePhoto {
+  type Response = Ok;
+}
 
 impl DeleteProfilePhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5591,24 +7106,43 @@ impl DeleteProfilePhoto {
     let mut inner = DeleteProfilePhoto::default();
     inner.td_name = "deleteProfilePhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDeleteProfilePhotoBuilder { inner }
+    RTDDeleteProfilePhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteProfilePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.profile_photo_id == 0 { missing.push("profile_photo_id".to_string()); }
+    missing
   }
 
-  pub fn profile_photo_id(&self) -> isize { self.profile_photo_id }
+  pub fn profile_photo_id(&self) -> i64 { self.profile_photo_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDDeleteProfilePhotoBuilder {
-  inner: DeleteProfilePhoto
+  inner: DeleteProfilePhoto,
+  set: [bool; 1]
 }
 
 impl RTDDeleteProfilePhotoBuilder {
   pub fn build(&self) -> DeleteProfilePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteProfilePhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("profile_photo_id".to_string()); }
+    crate::types::_common::check_missing("deleteProfilePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn profile_photo_id(&mut self, profile_photo_id: isize) -> &mut Self {
+  pub fn profile_photo_id(&mut self, profile_photo_id: i64) -> &mut Self {
     self.inner.profile_photo_id = profile_photo_id;
+    self.set[0] = true;
     self
   }
 
@@ -5649,7 +7183,9 @@ impl RObject for DeleteSavedCredentials {
 
 
 
-impl RFunction for DeleteSavedCredentials {}
+impl RFunction for DeleteSavedCredentials {
+  type Response = Ok;
+}
 
 impl DeleteSavedCredentials {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5660,6 +7196,11 @@ impl DeleteSavedCredentials {
     RTDDeleteSavedCredentialsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteSavedCredentials", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -5670,6 +7211,9 @@ pub struct RTDDeleteSavedCredentialsBuilder {
 impl RTDDeleteSavedCredentialsBuilder {
   pub fn build(&self) -> DeleteSavedCredentials { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteSavedCredentials> { Ok(self.build()) }
+
 }
 
 impl AsRef<DeleteSavedCredentials> for DeleteSavedCredentials {
@@ -5707,7 +7251,9 @@ impl RObject for DeleteSavedOrderInfo {
 
 
 
-impl RFunction for DeleteSavedOrderInfo {}
+impl RFunction for DeleteSavedOrderInfo {
+  type Response = Ok;
+}
 
 impl DeleteSavedOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5718,6 +7264,11 @@ impl DeleteSavedOrderInfo {
     RTDDeleteSavedOrderInfoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("deleteSavedOrderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -5728,6 +7279,9 @@ pub struct RTDDeleteSavedOrderInfoBuilder {
 impl RTDDeleteSavedOrderInfoBuilder {
   pub fn build(&self) -> DeleteSavedOrderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DeleteSavedOrderInfo> { Ok(self.build()) }
+
 }
 
 impl AsRef<DeleteSavedOrderInfo> for DeleteSavedOrderInfo {
@@ -5765,7 +7319,9 @@ impl RObject for Destroy {
 
 
 
-impl RFunction for Destroy {}
+impl RFunction for Destroy {
+  type Response = Ok;
+}
 
 impl Destroy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5776,6 +7332,11 @@ impl Destroy {
     RTDDestroyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("destroy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -5786,6 +7347,9 @@ pub struct RTDDestroyBuilder {
 impl RTDDestroyBuilder {
   pub fn build(&self) -> Destroy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Destroy> { Ok(self.build()) }
+
 }
 
 impl AsRef<Destroy> for Destroy {
@@ -5823,7 +7387,9 @@ impl RObject for DisableProxy {
 
 
 
-impl RFunction for DisableProxy {}
+impl RFunction for DisableProxy {
+  type Response = Ok;
+}
 
 impl DisableProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5834,6 +7400,11 @@ impl DisableProxy {
     RTDDisableProxyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("disableProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -5844,6 +7415,9 @@ pub struct RTDDisableProxyBuilder {
 impl RTDDisableProxyBuilder {
   pub fn build(&self) -> DisableProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DisableProxy> { Ok(self.build()) }
+
 }
 
 impl AsRef<DisableProxy> for DisableProxy {
@@ -5878,7 +7452,7 @@ pub struct DiscardCall {
   /// True, if the call was a video call
   is_video: bool,
   /// Identifier of the connection used during the call
-  connection_id: isize,
+  #[serde(with = "crate::types::_common::int64")] connection_id: i64,
   
 }
 
@@ -5891,7 +7465,9 @@ impl RObject for DiscardCall {
 
 
 
-impl RFunction for DiscardCall {}
+impl RFunction for DiscardCall {
+  type Response = Ok;
+}
 
 impl DiscardCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5899,7 +7475,18 @@ impl DiscardCall {
     let mut inner = DiscardCall::default();
     inner.td_name = "discardCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDiscardCallBuilder { inner }
+    RTDDiscardCallBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("discardCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.connection_id == 0 { missing.push("connection_id".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -5910,21 +7497,33 @@ impl DiscardCall {
 
   pub fn is_video(&self) -> bool { self.is_video }
 
-  pub fn connection_id(&self) -> isize { self.connection_id }
+  pub fn connection_id(&self) -> i64 { self.connection_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDDiscardCallBuilder {
-  inner: DiscardCall
+  inner: DiscardCall,
+  set: [bool; 3]
 }
 
 impl RTDDiscardCallBuilder {
   pub fn build(&self) -> DiscardCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DiscardCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("duration".to_string()); }
+    if !self.set[2] { missing.push("connection_id".to_string()); }
+    crate::types::_common::check_missing("discardCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
@@ -5937,6 +7536,7 @@ impl RTDDiscardCallBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[1] = true;
     self
   }
 
@@ -5947,8 +7547,9 @@ impl RTDDiscardCallBuilder {
   }
 
    
-  pub fn connection_id(&mut self, connection_id: isize) -> &mut Self {
+  pub fn connection_id(&mut self, connection_id: i64) -> &mut Self {
     self.inner.connection_id = connection_id;
+    self.set[2] = true;
     self
   }
 
@@ -5991,7 +7592,9 @@ impl RObject for DiscardGroupCall {
 
 
 
-impl RFunction for DiscardGroupCall {}
+impl RFunction for DiscardGroupCall {
+  type Response = Ok;
+}
 
 impl DiscardGroupCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -5999,7 +7602,16 @@ impl DiscardGroupCall {
     let mut inner = DiscardGroupCall::default();
     inner.td_name = "discardGroupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDiscardGroupCallBuilder { inner }
+    RTDDiscardGroupCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("discardGroupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -6008,15 +7620,25 @@ impl DiscardGroupCall {
 
 #[doc(hidden)]
 pub struct RTDDiscardGroupCallBuilder {
-  inner: DiscardGroupCall
+  inner: DiscardGroupCall,
+  set: [bool; 1]
 }
 
 impl RTDDiscardGroupCallBuilder {
   pub fn build(&self) -> DiscardGroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DiscardGroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("discardGroupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -6057,7 +7679,9 @@ impl RObject for DisconnectAllWebsites {
 
 
 
-impl RFunction for DisconnectAllWebsites {}
+impl RFunction for DisconnectAllWebsites {
+  type Response = Ok;
+}
 
 impl DisconnectAllWebsites {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6068,6 +7692,11 @@ impl DisconnectAllWebsites {
     RTDDisconnectAllWebsitesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("disconnectAllWebsites", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -6078,6 +7707,9 @@ pub struct RTDDisconnectAllWebsitesBuilder {
 impl RTDDisconnectAllWebsitesBuilder {
   pub fn build(&self) -> DisconnectAllWebsites { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DisconnectAllWebsites> { Ok(self.build()) }
+
 }
 
 impl AsRef<DisconnectAllWebsites> for DisconnectAllWebsites {
@@ -6104,7 +7736,7 @@ pub struct DisconnectWebsite {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Website identifier
-  website_id: isize,
+  #[serde(with = "crate::types::_common::int64")] website_id: i64,
   
 }
 
@@ -6117,7 +7749,9 @@ impl RObject for DisconnectWebsite {
 
 
 
-impl RFunction for DisconnectWebsite {}
+impl RFunction for DisconnectWebsite {
+  type Response = Ok;
+}
 
 impl DisconnectWebsite {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6125,24 +7759,43 @@ impl DisconnectWebsite {
     let mut inner = DisconnectWebsite::default();
     inner.td_name = "disconnectWebsite".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDisconnectWebsiteBuilder { inner }
+    RTDDisconnectWebsiteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("disconnectWebsite", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.website_id == 0 { missing.push("website_id".to_string()); }
+    missing
   }
 
-  pub fn website_id(&self) -> isize { self.website_id }
+  pub fn website_id(&self) -> i64 { self.website_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDDisconnectWebsiteBuilder {
-  inner: DisconnectWebsite
+  inner: DisconnectWebsite,
+  set: [bool; 1]
 }
 
 impl RTDDisconnectWebsiteBuilder {
   pub fn build(&self) -> DisconnectWebsite { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DisconnectWebsite> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("website_id".to_string()); }
+    crate::types::_common::check_missing("disconnectWebsite", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn website_id(&mut self, website_id: isize) -> &mut Self {
+  pub fn website_id(&mut self, website_id: i64) -> &mut Self {
     self.inner.website_id = website_id;
+    self.set[0] = true;
     self
   }
 
@@ -6193,7 +7846,9 @@ impl RObject for DownloadFile {
 
 
 
-impl RFunction for DownloadFile {}
+impl RFunction for DownloadFile {
+  type Response = File;
+}
 
 impl DownloadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6201,7 +7856,19 @@ impl DownloadFile {
     let mut inner = DownloadFile::default();
     inner.td_name = "downloadFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDDownloadFileBuilder { inner }
+    RTDDownloadFileBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("downloadFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    if self.priority == 0 { missing.push("priority".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -6218,33 +7885,49 @@ impl DownloadFile {
 
 #[doc(hidden)]
 pub struct RTDDownloadFileBuilder {
-  inner: DownloadFile
+  inner: DownloadFile,
+  set: [bool; 4]
 }
 
 impl RTDDownloadFileBuilder {
   pub fn build(&self) -> DownloadFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<DownloadFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    if !self.set[1] { missing.push("priority".to_string()); }
+    if !self.set[2] { missing.push("offset".to_string()); }
+    if !self.set[3] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("downloadFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn priority(&mut self, priority: i64) -> &mut Self {
     self.inner.priority = priority;
+    self.set[1] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[2] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[3] = true;
     self
   }
 
@@ -6295,7 +7978,9 @@ impl RObject for EditChatFilter {
 
 
 
-impl RFunction for EditChatFilter {}
+impl RFunction for EditChatFilter {
+  type Response = ChatFilterInfo;
+}
 
 impl EditChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6303,7 +7988,17 @@ impl EditChatFilter {
     let mut inner = EditChatFilter::default();
     inner.td_name = "editChatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditChatFilterBuilder { inner }
+    RTDEditChatFilterBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editChatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_filter_id == 0 { missing.push("chat_filter_id".to_string()); }
+    if false { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_filter_id(&self) -> i64 { self.chat_filter_id }
@@ -6314,21 +8009,33 @@ impl EditChatFilter {
 
 #[doc(hidden)]
 pub struct RTDEditChatFilterBuilder {
-  inner: EditChatFilter
+  inner: EditChatFilter,
+  set: [bool; 2]
 }
 
 impl RTDEditChatFilterBuilder {
   pub fn build(&self) -> EditChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_filter_id".to_string()); }
+    if !self.set[1] { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("editChatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -6371,7 +8078,9 @@ impl RObject for EditCustomLanguagePackInfo {
 
 
 
-impl RFunction for EditCustomLanguagePackInfo {}
+impl RFunction for EditCustomLanguagePackInfo {
+  type Response = Ok;
+}
 
 impl EditCustomLanguagePackInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6379,7 +8088,16 @@ impl EditCustomLanguagePackInfo {
     let mut inner = EditCustomLanguagePackInfo::default();
     inner.td_name = "editCustomLanguagePackInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditCustomLanguagePackInfoBuilder { inner }
+    RTDEditCustomLanguagePackInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editCustomLanguagePackInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.info._missing_fields())); }
+    missing
   }
 
   pub fn info(&self) -> &LanguagePackInfo { &self.info }
@@ -6388,15 +8106,25 @@ impl EditCustomLanguagePackInfo {
 
 #[doc(hidden)]
 pub struct RTDEditCustomLanguagePackInfoBuilder {
-  inner: EditCustomLanguagePackInfo
+  inner: EditCustomLanguagePackInfo,
+  set: [bool; 1]
 }
 
 impl RTDEditCustomLanguagePackInfoBuilder {
   pub fn build(&self) -> EditCustomLanguagePackInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditCustomLanguagePackInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.inner.info._missing_fields())); }
+    crate::types::_common::check_missing("editCustomLanguagePackInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn info<T: AsRef<LanguagePackInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6443,7 +8171,9 @@ impl RObject for EditInlineMessageCaption {
 
 
 
-impl RFunction for EditInlineMessageCaption {}
+impl RFunction for EditInlineMessageCaption {
+  type Response = Ok;
+}
 
 impl EditInlineMessageCaption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6451,7 +8181,17 @@ impl EditInlineMessageCaption {
     let mut inner = EditInlineMessageCaption::default();
     inner.td_name = "editInlineMessageCaption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditInlineMessageCaptionBuilder { inner }
+    RTDEditInlineMessageCaptionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editInlineMessageCaption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -6464,15 +8204,26 @@ impl EditInlineMessageCaption {
 
 #[doc(hidden)]
 pub struct RTDEditInlineMessageCaptionBuilder {
-  inner: EditInlineMessageCaption
+  inner: EditInlineMessageCaption,
+  set: [bool; 2]
 }
 
 impl RTDEditInlineMessageCaptionBuilder {
   pub fn build(&self) -> EditInlineMessageCaption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditInlineMessageCaption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("editInlineMessageCaption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6485,6 +8236,7 @@ impl RTDEditInlineMessageCaptionBuilder {
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -6535,7 +8287,9 @@ impl RObject for EditInlineMessageLiveLocation {
 
 
 
-impl RFunction for EditInlineMessageLiveLocation {}
+impl RFunction for EditInlineMessageLiveLocation {
+  type Response = Ok;
+}
 
 impl EditInlineMessageLiveLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6543,7 +8297,16 @@ impl EditInlineMessageLiveLocation {
     let mut inner = EditInlineMessageLiveLocation::default();
     inner.td_name = "editInlineMessageLiveLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditInlineMessageLiveLocationBuilder { inner }
+    RTDEditInlineMessageLiveLocationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editInlineMessageLiveLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -6560,15 +8323,25 @@ impl EditInlineMessageLiveLocation {
 
 #[doc(hidden)]
 pub struct RTDEditInlineMessageLiveLocationBuilder {
-  inner: EditInlineMessageLiveLocation
+  inner: EditInlineMessageLiveLocation,
+  set: [bool; 1]
 }
 
 impl RTDEditInlineMessageLiveLocationBuilder {
   pub fn build(&self) -> EditInlineMessageLiveLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditInlineMessageLiveLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    crate::types::_common::check_missing("editInlineMessageLiveLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6639,7 +8412,9 @@ impl RObject for EditInlineMessageMedia {
 
 
 
-impl RFunction for EditInlineMessageMedia {}
+impl RFunction for EditInlineMessageMedia {
+  type Response = Ok;
+}
 
 impl EditInlineMessageMedia {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6647,7 +8422,17 @@ impl EditInlineMessageMedia {
     let mut inner = EditInlineMessageMedia::default();
     inner.td_name = "editInlineMessageMedia".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditInlineMessageMediaBuilder { inner }
+    RTDEditInlineMessageMediaBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editInlineMessageMedia", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -6660,15 +8445,26 @@ impl EditInlineMessageMedia {
 
 #[doc(hidden)]
 pub struct RTDEditInlineMessageMediaBuilder {
-  inner: EditInlineMessageMedia
+  inner: EditInlineMessageMedia,
+  set: [bool; 1]
 }
 
 impl RTDEditInlineMessageMediaBuilder {
   pub fn build(&self) -> EditInlineMessageMedia { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditInlineMessageMedia> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("editInlineMessageMedia", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6725,7 +8521,9 @@ impl RObject for EditInlineMessageReplyMarkup {
 
 
 
-impl RFunction for EditInlineMessageReplyMarkup {}
+impl RFunction for EditInlineMessageReplyMarkup {
+  type Response = Ok;
+}
 
 impl EditInlineMessageReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6733,7 +8531,16 @@ impl EditInlineMessageReplyMarkup {
     let mut inner = EditInlineMessageReplyMarkup::default();
     inner.td_name = "editInlineMessageReplyMarkup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditInlineMessageReplyMarkupBuilder { inner }
+    RTDEditInlineMessageReplyMarkupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editInlineMessageReplyMarkup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -6744,15 +8551,25 @@ impl EditInlineMessageReplyMarkup {
 
 #[doc(hidden)]
 pub struct RTDEditInlineMessageReplyMarkupBuilder {
-  inner: EditInlineMessageReplyMarkup
+  inner: EditInlineMessageReplyMarkup,
+  set: [bool; 1]
 }
 
 impl RTDEditInlineMessageReplyMarkupBuilder {
   pub fn build(&self) -> EditInlineMessageReplyMarkup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditInlineMessageReplyMarkup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    crate::types::_common::check_missing("editInlineMessageReplyMarkup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6805,7 +8622,9 @@ impl RObject for EditInlineMessageText {
 
 
 
-impl RFunction for EditInlineMessageText {}
+impl RFunction for EditInlineMessageText {
+  type Response = Ok;
+}
 
 impl EditInlineMessageText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6813,7 +8632,17 @@ impl EditInlineMessageText {
     let mut inner = EditInlineMessageText::default();
     inner.td_name = "editInlineMessageText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditInlineMessageTextBuilder { inner }
+    RTDEditInlineMessageTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editInlineMessageText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -6826,15 +8655,26 @@ impl EditInlineMessageText {
 
 #[doc(hidden)]
 pub struct RTDEditInlineMessageTextBuilder {
-  inner: EditInlineMessageText
+  inner: EditInlineMessageText,
+  set: [bool; 1]
 }
 
 impl RTDEditInlineMessageTextBuilder {
   pub fn build(&self) -> EditInlineMessageText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditInlineMessageText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("editInlineMessageText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6895,7 +8735,9 @@ impl RObject for EditMessageCaption {
 
 
 
-impl RFunction for EditMessageCaption {}
+impl RFunction for EditMessageCaption {
+  type Response = Message;
+}
 
 impl EditMessageCaption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -6903,7 +8745,18 @@ impl EditMessageCaption {
     let mut inner = EditMessageCaption::default();
     inner.td_name = "editMessageCaption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageCaptionBuilder { inner }
+    RTDEditMessageCaptionBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageCaption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -6918,21 +8771,34 @@ impl EditMessageCaption {
 
 #[doc(hidden)]
 pub struct RTDEditMessageCaptionBuilder {
-  inner: EditMessageCaption
+  inner: EditMessageCaption,
+  set: [bool; 3]
 }
 
 impl RTDEditMessageCaptionBuilder {
   pub fn build(&self) -> EditMessageCaption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageCaption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("editMessageCaption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -6945,6 +8811,7 @@ impl RTDEditMessageCaptionBuilder {
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -6997,7 +8864,9 @@ impl RObject for EditMessageLiveLocation {
 
 
 
-impl RFunction for EditMessageLiveLocation {}
+impl RFunction for EditMessageLiveLocation {
+  type Response = Message;
+}
 
 impl EditMessageLiveLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7005,7 +8874,17 @@ impl EditMessageLiveLocation {
     let mut inner = EditMessageLiveLocation::default();
     inner.td_name = "editMessageLiveLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageLiveLocationBuilder { inner }
+    RTDEditMessageLiveLocationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageLiveLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7024,21 +8903,33 @@ impl EditMessageLiveLocation {
 
 #[doc(hidden)]
 pub struct RTDEditMessageLiveLocationBuilder {
-  inner: EditMessageLiveLocation
+  inner: EditMessageLiveLocation,
+  set: [bool; 2]
 }
 
 impl RTDEditMessageLiveLocationBuilder {
   pub fn build(&self) -> EditMessageLiveLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageLiveLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("editMessageLiveLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -7111,7 +9002,9 @@ impl RObject for EditMessageMedia {
 
 
 
-impl RFunction for EditMessageMedia {}
+impl RFunction for EditMessageMedia {
+  type Response = Message;
+}
 
 impl EditMessageMedia {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7119,7 +9012,18 @@ impl EditMessageMedia {
     let mut inner = EditMessageMedia::default();
     inner.td_name = "editMessageMedia".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageMediaBuilder { inner }
+    RTDEditMessageMediaBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageMedia", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7134,21 +9038,34 @@ impl EditMessageMedia {
 
 #[doc(hidden)]
 pub struct RTDEditMessageMediaBuilder {
-  inner: EditMessageMedia
+  inner: EditMessageMedia,
+  set: [bool; 2]
 }
 
 impl RTDEditMessageMediaBuilder {
   pub fn build(&self) -> EditMessageMedia { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageMedia> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("editMessageMedia", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -7207,7 +9124,9 @@ impl RObject for EditMessageReplyMarkup {
 
 
 
-impl RFunction for EditMessageReplyMarkup {}
+impl RFunction for EditMessageReplyMarkup {
+  type Response = Message;
+}
 
 impl EditMessageReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7215,7 +9134,17 @@ impl EditMessageReplyMarkup {
     let mut inner = EditMessageReplyMarkup::default();
     inner.td_name = "editMessageReplyMarkup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageReplyMarkupBuilder { inner }
+    RTDEditMessageReplyMarkupBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageReplyMarkup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7228,21 +9157,33 @@ impl EditMessageReplyMarkup {
 
 #[doc(hidden)]
 pub struct RTDEditMessageReplyMarkupBuilder {
-  inner: EditMessageReplyMarkup
+  inner: EditMessageReplyMarkup,
+  set: [bool; 2]
 }
 
 impl RTDEditMessageReplyMarkupBuilder {
   pub fn build(&self) -> EditMessageReplyMarkup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageReplyMarkup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("editMessageReplyMarkup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -7295,7 +9236,9 @@ impl RObject for EditMessageSchedulingState {
 
 
 
-impl RFunction for EditMessageSchedulingState {}
+impl RFunction for EditMessageSchedulingState {
+  type Response = Ok;
+}
 
 impl EditMessageSchedulingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7303,7 +9246,18 @@ impl EditMessageSchedulingState {
     let mut inner = EditMessageSchedulingState::default();
     inner.td_name = "editMessageSchedulingState".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageSchedulingStateBuilder { inner }
+    RTDEditMessageSchedulingStateBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageSchedulingState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.scheduling_state._is_default() { missing.push("scheduling_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("scheduling_state", self.scheduling_state._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7316,21 +9270,34 @@ impl EditMessageSchedulingState {
 
 #[doc(hidden)]
 pub struct RTDEditMessageSchedulingStateBuilder {
-  inner: EditMessageSchedulingState
+  inner: EditMessageSchedulingState,
+  set: [bool; 2]
 }
 
 impl RTDEditMessageSchedulingStateBuilder {
   pub fn build(&self) -> EditMessageSchedulingState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageSchedulingState> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if self.inner.scheduling_state._is_default() { missing.push("scheduling_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("scheduling_state", self.inner.scheduling_state._missing_fields())); }
+    crate::types::_common::check_missing("editMessageSchedulingState", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -7385,7 +9352,9 @@ impl RObject for EditMessageText {
 
 
 
-impl RFunction for EditMessageText {}
+impl RFunction for EditMessageText {
+  type Response = Message;
+}
 
 impl EditMessageText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7393,7 +9362,18 @@ impl EditMessageText {
     let mut inner = EditMessageText::default();
     inner.td_name = "editMessageText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditMessageTextBuilder { inner }
+    RTDEditMessageTextBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editMessageText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7408,21 +9388,34 @@ impl EditMessageText {
 
 #[doc(hidden)]
 pub struct RTDEditMessageTextBuilder {
-  inner: EditMessageText
+  inner: EditMessageText,
+  set: [bool; 2]
 }
 
 impl RTDEditMessageTextBuilder {
   pub fn build(&self) -> EditMessageText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditMessageText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("editMessageText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -7485,7 +9478,9 @@ impl RObject for EditProxy {
 
 
 
-impl RFunction for EditProxy {}
+impl RFunction for EditProxy {
+  type Response = Proxy;
+}
 
 impl EditProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7493,7 +9488,19 @@ impl EditProxy {
     let mut inner = EditProxy::default();
     inner.td_name = "editProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEditProxyBuilder { inner }
+    RTDEditProxyBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("editProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.proxy_id == 0 { missing.push("proxy_id".to_string()); }
+    if self.server.is_empty() { missing.push("server".to_string()); }
+    if self.port == 0 { missing.push("port".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn proxy_id(&self) -> i64 { self.proxy_id }
@@ -7510,27 +9517,42 @@ impl EditProxy {
 
 #[doc(hidden)]
 pub struct RTDEditProxyBuilder {
-  inner: EditProxy
+  inner: EditProxy,
+  set: [bool; 3]
 }
 
 impl RTDEditProxyBuilder {
   pub fn build(&self) -> EditProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EditProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("proxy_id".to_string()); }
+    if !self.set[1] { missing.push("server".to_string()); }
+    if !self.set[2] { missing.push("port".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("editProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn port(&mut self, port: i64) -> &mut Self {
     self.inner.port = port;
+    self.set[2] = true;
     self
   }
 
@@ -7585,7 +9607,9 @@ impl RObject for EnableProxy {
 
 
 
-impl RFunction for EnableProxy {}
+impl RFunction for EnableProxy {
+  type Response = Ok;
+}
 
 impl EnableProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7593,7 +9617,16 @@ impl EnableProxy {
     let mut inner = EnableProxy::default();
     inner.td_name = "enableProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDEnableProxyBuilder { inner }
+    RTDEnableProxyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("enableProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.proxy_id == 0 { missing.push("proxy_id".to_string()); }
+    missing
   }
 
   pub fn proxy_id(&self) -> i64 { self.proxy_id }
@@ -7602,15 +9635,25 @@ impl EnableProxy {
 
 #[doc(hidden)]
 pub struct RTDEnableProxyBuilder {
-  inner: EnableProxy
+  inner: EnableProxy,
+  set: [bool; 1]
 }
 
 impl RTDEnableProxyBuilder {
   pub fn build(&self) -> EnableProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<EnableProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("proxy_id".to_string()); }
+    crate::types::_common::check_missing("enableProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
+    self.set[0] = true;
     self
   }
 
@@ -7640,9 +9683,9 @@ pub struct FinishFileGeneration {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   /// If set, means that file generation has failed and should be terminated
-  error: Error,
+  error: Option<Error>,
   
 }
 
@@ -7655,7 +9698,9 @@ impl RObject for FinishFileGeneration {
 
 
 
-impl RFunction for FinishFileGeneration {}
+impl RFunction for FinishFileGeneration {
+  type Response = Ok;
+}
 
 impl FinishFileGeneration {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7663,32 +9708,51 @@ impl FinishFileGeneration {
     let mut inner = FinishFileGeneration::default();
     inner.td_name = "finishFileGeneration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDFinishFileGenerationBuilder { inner }
+    RTDFinishFileGenerationBuilder { inner, set: [false; 1] }
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("finishFileGeneration", self._missing_fields()) }
 
-  pub fn error(&self) -> &Error { &self.error }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.generation_id == 0 { missing.push("generation_id".to_string()); }
+    missing
+  }
+
+  pub fn generation_id(&self) -> i64 { self.generation_id }
+
+  pub fn error(&self) -> &Option<Error> { &self.error }
 
 }
 
 #[doc(hidden)]
 pub struct RTDFinishFileGenerationBuilder {
-  inner: FinishFileGeneration
+  inner: FinishFileGeneration,
+  set: [bool; 1]
 }
 
 impl RTDFinishFileGenerationBuilder {
   pub fn build(&self) -> FinishFileGeneration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<FinishFileGeneration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("generation_id".to_string()); }
+    crate::types::_common::check_missing("finishFileGeneration", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
-    self.inner.error = error.as_ref().clone();
+    self.inner.error = Some(error.as_ref().clone());
     self
   }
 
@@ -7741,7 +9805,9 @@ impl RObject for ForwardMessages {
 
 
 
-impl RFunction for ForwardMessages {}
+impl RFunction for ForwardMessages {
+  type Response = Messages;
+}
 
 impl ForwardMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7749,7 +9815,17 @@ impl ForwardMessages {
     let mut inner = ForwardMessages::default();
     inner.td_name = "forwardMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDForwardMessagesBuilder { inner }
+    RTDForwardMessagesBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("forwardMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.from_chat_id == 0 { missing.push("from_chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7768,21 +9844,33 @@ impl ForwardMessages {
 
 #[doc(hidden)]
 pub struct RTDForwardMessagesBuilder {
-  inner: ForwardMessages
+  inner: ForwardMessages,
+  set: [bool; 2]
 }
 
 impl RTDForwardMessagesBuilder {
   pub fn build(&self) -> ForwardMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ForwardMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("from_chat_id".to_string()); }
+    crate::types::_common::check_missing("forwardMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
     self.inner.from_chat_id = from_chat_id;
+    self.set[1] = true;
     self
   }
 
@@ -7847,7 +9935,9 @@ impl RObject for GetAccountTtl {
 
 
 
-impl RFunction for GetAccountTtl {}
+impl RFunction for GetAccountTtl {
+  type Response = AccountTtl;
+}
 
 impl GetAccountTtl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7858,6 +9948,11 @@ impl GetAccountTtl {
     RTDGetAccountTtlBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getAccountTtl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -7868,6 +9963,9 @@ pub struct RTDGetAccountTtlBuilder {
 impl RTDGetAccountTtlBuilder {
   pub fn build(&self) -> GetAccountTtl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetAccountTtl> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetAccountTtl> for GetAccountTtl {
@@ -7905,7 +10003,9 @@ impl RObject for GetActiveLiveLocationMessages {
 
 
 
-impl RFunction for GetActiveLiveLocationMessages {}
+impl RFunction for GetActiveLiveLocationMessages {
+  type Response = Messages;
+}
 
 impl GetActiveLiveLocationMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7916,6 +10016,11 @@ impl GetActiveLiveLocationMessages {
     RTDGetActiveLiveLocationMessagesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getActiveLiveLocationMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -7926,6 +10031,9 @@ pub struct RTDGetActiveLiveLocationMessagesBuilder {
 impl RTDGetActiveLiveLocationMessagesBuilder {
   pub fn build(&self) -> GetActiveLiveLocationMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetActiveLiveLocationMessages> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetActiveLiveLocationMessages> for GetActiveLiveLocationMessages {
@@ -7963,7 +10071,9 @@ impl RObject for GetActiveSessions {
 
 
 
-impl RFunction for GetActiveSessions {}
+impl RFunction for GetActiveSessions {
+  type Response = Sessions;
+}
 
 impl GetActiveSessions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -7974,6 +10084,11 @@ impl GetActiveSessions {
     RTDGetActiveSessionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getActiveSessions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -7984,6 +10099,9 @@ pub struct RTDGetActiveSessionsBuilder {
 impl RTDGetActiveSessionsBuilder {
   pub fn build(&self) -> GetActiveSessions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetActiveSessions> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetActiveSessions> for GetActiveSessions {
@@ -8023,7 +10141,9 @@ impl RObject for GetAllPassportElements {
 
 
 
-impl RFunction for GetAllPassportElements {}
+impl RFunction for GetAllPassportElements {
+  type Response = PassportElements;
+}
 
 impl GetAllPassportElements {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8031,7 +10151,16 @@ impl GetAllPassportElements {
     let mut inner = GetAllPassportElements::default();
     inner.td_name = "getAllPassportElements".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetAllPassportElementsBuilder { inner }
+    RTDGetAllPassportElementsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getAllPassportElements", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -8040,15 +10169,25 @@ impl GetAllPassportElements {
 
 #[doc(hidden)]
 pub struct RTDGetAllPassportElementsBuilder {
-  inner: GetAllPassportElements
+  inner: GetAllPassportElements,
+  set: [bool; 1]
 }
 
 impl RTDGetAllPassportElementsBuilder {
   pub fn build(&self) -> GetAllPassportElements { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetAllPassportElements> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("getAllPassportElements", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -8087,9 +10226,11 @@ impl RObject for GetApplicationConfig {
 }
 
 
-impl TDJsonValue for GetApplicationConfig {}
 
-impl RFunction for GetApplicationConfig {}
+
+impl RFunction for GetApplicationConfig {
+  type Response = JsonValue;
+}
 
 impl GetApplicationConfig {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8100,6 +10241,11 @@ impl GetApplicationConfig {
     RTDGetApplicationConfigBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getApplicationConfig", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -8110,6 +10256,9 @@ pub struct RTDGetApplicationConfigBuilder {
 impl RTDGetApplicationConfigBuilder {
   pub fn build(&self) -> GetApplicationConfig { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetApplicationConfig> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetApplicationConfig> for GetApplicationConfig {
@@ -8138,7 +10287,7 @@ pub struct GetArchivedStickerSets {
   /// Pass true to return mask stickers sets; pass false to return ordinary sticker sets
   is_masks: bool,
   /// Identifier of the sticker set from which to return the result
-  offset_sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] offset_sticker_set_id: i64,
   /// The maximum number of sticker sets to return
   limit: i64,
   
@@ -8153,7 +10302,9 @@ impl RObject for GetArchivedStickerSets {
 
 
 
-impl RFunction for GetArchivedStickerSets {}
+impl RFunction for GetArchivedStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetArchivedStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8161,12 +10312,22 @@ impl GetArchivedStickerSets {
     let mut inner = GetArchivedStickerSets::default();
     inner.td_name = "getArchivedStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetArchivedStickerSetsBuilder { inner }
+    RTDGetArchivedStickerSetsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getArchivedStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.offset_sticker_set_id == 0 { missing.push("offset_sticker_set_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn offset_sticker_set_id(&self) -> isize { self.offset_sticker_set_id }
+  pub fn offset_sticker_set_id(&self) -> i64 { self.offset_sticker_set_id }
 
   pub fn limit(&self) -> i64 { self.limit }
 
@@ -8174,12 +10335,22 @@ impl GetArchivedStickerSets {
 
 #[doc(hidden)]
 pub struct RTDGetArchivedStickerSetsBuilder {
-  inner: GetArchivedStickerSets
+  inner: GetArchivedStickerSets,
+  set: [bool; 2]
 }
 
 impl RTDGetArchivedStickerSetsBuilder {
   pub fn build(&self) -> GetArchivedStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetArchivedStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("offset_sticker_set_id".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getArchivedStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -8187,14 +10358,16 @@ impl RTDGetArchivedStickerSetsBuilder {
   }
 
    
-  pub fn offset_sticker_set_id(&mut self, offset_sticker_set_id: isize) -> &mut Self {
+  pub fn offset_sticker_set_id(&mut self, offset_sticker_set_id: i64) -> &mut Self {
     self.inner.offset_sticker_set_id = offset_sticker_set_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -8237,7 +10410,9 @@ impl RObject for GetAttachedStickerSets {
 
 
 
-impl RFunction for GetAttachedStickerSets {}
+impl RFunction for GetAttachedStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetAttachedStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8245,7 +10420,16 @@ impl GetAttachedStickerSets {
     let mut inner = GetAttachedStickerSets::default();
     inner.td_name = "getAttachedStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetAttachedStickerSetsBuilder { inner }
+    RTDGetAttachedStickerSetsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getAttachedStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -8254,15 +10438,25 @@ impl GetAttachedStickerSets {
 
 #[doc(hidden)]
 pub struct RTDGetAttachedStickerSetsBuilder {
-  inner: GetAttachedStickerSets
+  inner: GetAttachedStickerSets,
+  set: [bool; 1]
 }
 
 impl RTDGetAttachedStickerSetsBuilder {
   pub fn build(&self) -> GetAttachedStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetAttachedStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    crate::types::_common::check_missing("getAttachedStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
@@ -8301,9 +10495,11 @@ impl RObject for GetAuthorizationState {
 }
 
 
-impl TDAuthorizationState for GetAuthorizationState {}
 
-impl RFunction for GetAuthorizationState {}
+
+impl RFunction for GetAuthorizationState {
+  type Response = AuthorizationState;
+}
 
 impl GetAuthorizationState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8314,6 +10510,11 @@ impl GetAuthorizationState {
     RTDGetAuthorizationStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getAuthorizationState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -8324,6 +10525,9 @@ pub struct RTDGetAuthorizationStateBuilder {
 impl RTDGetAuthorizationStateBuilder {
   pub fn build(&self) -> GetAuthorizationState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetAuthorizationState> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetAuthorizationState> for GetAuthorizationState {
@@ -8361,7 +10565,9 @@ impl RObject for GetAutoDownloadSettingsPresets {
 
 
 
-impl RFunction for GetAutoDownloadSettingsPresets {}
+impl RFunction for GetAutoDownloadSettingsPresets {
+  type Response = AutoDownloadSettingsPresets;
+}
 
 impl GetAutoDownloadSettingsPresets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8372,6 +10578,11 @@ impl GetAutoDownloadSettingsPresets {
     RTDGetAutoDownloadSettingsPresetsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getAutoDownloadSettingsPresets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -8382,6 +10593,9 @@ pub struct RTDGetAutoDownloadSettingsPresetsBuilder {
 impl RTDGetAutoDownloadSettingsPresetsBuilder {
   pub fn build(&self) -> GetAutoDownloadSettingsPresets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetAutoDownloadSettingsPresets> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetAutoDownloadSettingsPresets> for GetAutoDownloadSettingsPresets {
@@ -8423,7 +10637,9 @@ impl RObject for GetBackgroundUrl {
 
 
 
-impl RFunction for GetBackgroundUrl {}
+impl RFunction for GetBackgroundUrl {
+  type Response = HttpUrl;
+}
 
 impl GetBackgroundUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8431,7 +10647,17 @@ impl GetBackgroundUrl {
     let mut inner = GetBackgroundUrl::default();
     inner.td_name = "getBackgroundUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetBackgroundUrlBuilder { inner }
+    RTDGetBackgroundUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBackgroundUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -8442,15 +10668,26 @@ impl GetBackgroundUrl {
 
 #[doc(hidden)]
 pub struct RTDGetBackgroundUrlBuilder {
-  inner: GetBackgroundUrl
+  inner: GetBackgroundUrl,
+  set: [bool; 1]
 }
 
 impl RTDGetBackgroundUrlBuilder {
   pub fn build(&self) -> GetBackgroundUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBackgroundUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("getBackgroundUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -8499,7 +10736,9 @@ impl RObject for GetBackgrounds {
 
 
 
-impl RFunction for GetBackgrounds {}
+impl RFunction for GetBackgrounds {
+  type Response = Backgrounds;
+}
 
 impl GetBackgrounds {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8510,6 +10749,11 @@ impl GetBackgrounds {
     RTDGetBackgroundsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBackgrounds", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn for_dark_theme(&self) -> bool { self.for_dark_theme }
 
 }
@@ -8522,6 +10766,9 @@ pub struct RTDGetBackgroundsBuilder {
 impl RTDGetBackgroundsBuilder {
   pub fn build(&self) -> GetBackgrounds { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBackgrounds> { Ok(self.build()) }
+
    
   pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
     self.inner.for_dark_theme = for_dark_theme;
@@ -8567,7 +10814,9 @@ impl RObject for GetBankCardInfo {
 
 
 
-impl RFunction for GetBankCardInfo {}
+impl RFunction for GetBankCardInfo {
+  type Response = BankCardInfo;
+}
 
 impl GetBankCardInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8575,7 +10824,16 @@ impl GetBankCardInfo {
     let mut inner = GetBankCardInfo::default();
     inner.td_name = "getBankCardInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetBankCardInfoBuilder { inner }
+    RTDGetBankCardInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBankCardInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bank_card_number.is_empty() { missing.push("bank_card_number".to_string()); }
+    missing
   }
 
   pub fn bank_card_number(&self) -> &String { &self.bank_card_number }
@@ -8584,15 +10842,25 @@ impl GetBankCardInfo {
 
 #[doc(hidden)]
 pub struct RTDGetBankCardInfoBuilder {
-  inner: GetBankCardInfo
+  inner: GetBankCardInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetBankCardInfoBuilder {
   pub fn build(&self) -> GetBankCardInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBankCardInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bank_card_number".to_string()); }
+    crate::types::_common::check_missing("getBankCardInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bank_card_number<T: AsRef<str>>(&mut self, bank_card_number: T) -> &mut Self {
     self.inner.bank_card_number = bank_card_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -8635,7 +10903,9 @@ impl RObject for GetBasicGroup {
 
 
 
-impl RFunction for GetBasicGroup {}
+impl RFunction for GetBasicGroup {
+  type Response = BasicGroup;
+}
 
 impl GetBasicGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8643,7 +10913,16 @@ impl GetBasicGroup {
     let mut inner = GetBasicGroup::default();
     inner.td_name = "getBasicGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetBasicGroupBuilder { inner }
+    RTDGetBasicGroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBasicGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    missing
   }
 
   pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
@@ -8652,15 +10931,25 @@ impl GetBasicGroup {
 
 #[doc(hidden)]
 pub struct RTDGetBasicGroupBuilder {
-  inner: GetBasicGroup
+  inner: GetBasicGroup,
+  set: [bool; 1]
 }
 
 impl RTDGetBasicGroupBuilder {
   pub fn build(&self) -> GetBasicGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBasicGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group_id".to_string()); }
+    crate::types::_common::check_missing("getBasicGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[0] = true;
     self
   }
 
@@ -8703,7 +10992,9 @@ impl RObject for GetBasicGroupFullInfo {
 
 
 
-impl RFunction for GetBasicGroupFullInfo {}
+impl RFunction for GetBasicGroupFullInfo {
+  type Response = BasicGroupFullInfo;
+}
 
 impl GetBasicGroupFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8711,7 +11002,16 @@ impl GetBasicGroupFullInfo {
     let mut inner = GetBasicGroupFullInfo::default();
     inner.td_name = "getBasicGroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetBasicGroupFullInfoBuilder { inner }
+    RTDGetBasicGroupFullInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBasicGroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    missing
   }
 
   pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
@@ -8720,15 +11020,25 @@ impl GetBasicGroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDGetBasicGroupFullInfoBuilder {
-  inner: GetBasicGroupFullInfo
+  inner: GetBasicGroupFullInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetBasicGroupFullInfoBuilder {
   pub fn build(&self) -> GetBasicGroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBasicGroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group_id".to_string()); }
+    crate::types::_common::check_missing("getBasicGroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[0] = true;
     self
   }
 
@@ -8773,7 +11083,9 @@ impl RObject for GetBlockedMessageSenders {
 
 
 
-impl RFunction for GetBlockedMessageSenders {}
+impl RFunction for GetBlockedMessageSenders {
+  type Response = MessageSenders;
+}
 
 impl GetBlockedMessageSenders {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8781,7 +11093,17 @@ impl GetBlockedMessageSenders {
     let mut inner = GetBlockedMessageSenders::default();
     inner.td_name = "getBlockedMessageSenders".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetBlockedMessageSendersBuilder { inner }
+    RTDGetBlockedMessageSendersBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getBlockedMessageSenders", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn offset(&self) -> i64 { self.offset }
@@ -8792,21 +11114,33 @@ impl GetBlockedMessageSenders {
 
 #[doc(hidden)]
 pub struct RTDGetBlockedMessageSendersBuilder {
-  inner: GetBlockedMessageSenders
+  inner: GetBlockedMessageSenders,
+  set: [bool; 2]
 }
 
 impl RTDGetBlockedMessageSendersBuilder {
   pub fn build(&self) -> GetBlockedMessageSenders { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetBlockedMessageSenders> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("offset".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getBlockedMessageSenders", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -8853,7 +11187,9 @@ impl RObject for GetCallbackQueryAnswer {
 
 
 
-impl RFunction for GetCallbackQueryAnswer {}
+impl RFunction for GetCallbackQueryAnswer {
+  type Response = CallbackQueryAnswer;
+}
 
 impl GetCallbackQueryAnswer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8861,7 +11197,18 @@ impl GetCallbackQueryAnswer {
     let mut inner = GetCallbackQueryAnswer::default();
     inner.td_name = "getCallbackQueryAnswer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetCallbackQueryAnswerBuilder { inner }
+    RTDGetCallbackQueryAnswerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCallbackQueryAnswer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.payload._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -8874,21 +11221,34 @@ impl GetCallbackQueryAnswer {
 
 #[doc(hidden)]
 pub struct RTDGetCallbackQueryAnswerBuilder {
-  inner: GetCallbackQueryAnswer
+  inner: GetCallbackQueryAnswer,
+  set: [bool; 2]
 }
 
 impl RTDGetCallbackQueryAnswerBuilder {
   pub fn build(&self) -> GetCallbackQueryAnswer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCallbackQueryAnswer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if self.inner.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.inner.payload._missing_fields())); }
+    crate::types::_common::check_missing("getCallbackQueryAnswer", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -8928,7 +11288,7 @@ pub struct GetCallbackQueryMessage {
   /// Message identifier
   message_id: i64,
   /// Identifier of the callback query
-  callback_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] callback_query_id: i64,
   
 }
 
@@ -8941,7 +11301,9 @@ impl RObject for GetCallbackQueryMessage {
 
 
 
-impl RFunction for GetCallbackQueryMessage {}
+impl RFunction for GetCallbackQueryMessage {
+  type Response = Message;
+}
 
 impl GetCallbackQueryMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -8949,40 +11311,65 @@ impl GetCallbackQueryMessage {
     let mut inner = GetCallbackQueryMessage::default();
     inner.td_name = "getCallbackQueryMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetCallbackQueryMessageBuilder { inner }
+    RTDGetCallbackQueryMessageBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCallbackQueryMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.callback_query_id == 0 { missing.push("callback_query_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
 
   pub fn message_id(&self) -> i64 { self.message_id }
 
-  pub fn callback_query_id(&self) -> isize { self.callback_query_id }
+  pub fn callback_query_id(&self) -> i64 { self.callback_query_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDGetCallbackQueryMessageBuilder {
-  inner: GetCallbackQueryMessage
+  inner: GetCallbackQueryMessage,
+  set: [bool; 3]
 }
 
 impl RTDGetCallbackQueryMessageBuilder {
   pub fn build(&self) -> GetCallbackQueryMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCallbackQueryMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("callback_query_id".to_string()); }
+    crate::types::_common::check_missing("getCallbackQueryMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
-  pub fn callback_query_id(&mut self, callback_query_id: isize) -> &mut Self {
+  pub fn callback_query_id(&mut self, callback_query_id: i64) -> &mut Self {
     self.inner.callback_query_id = callback_query_id;
+    self.set[2] = true;
     self
   }
 
@@ -9025,7 +11412,9 @@ impl RObject for GetChat {
 
 
 
-impl RFunction for GetChat {}
+impl RFunction for GetChat {
+  type Response = Chat;
+}
 
 impl GetChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9033,7 +11422,16 @@ impl GetChat {
     let mut inner = GetChat::default();
     inner.td_name = "getChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatBuilder { inner }
+    RTDGetChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9042,15 +11440,25 @@ impl GetChat {
 
 #[doc(hidden)]
 pub struct RTDGetChatBuilder {
-  inner: GetChat
+  inner: GetChat,
+  set: [bool; 1]
 }
 
 impl RTDGetChatBuilder {
   pub fn build(&self) -> GetChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -9093,7 +11501,9 @@ impl RObject for GetChatAdministrators {
 
 
 
-impl RFunction for GetChatAdministrators {}
+impl RFunction for GetChatAdministrators {
+  type Response = ChatAdministrators;
+}
 
 impl GetChatAdministrators {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9101,7 +11511,16 @@ impl GetChatAdministrators {
     let mut inner = GetChatAdministrators::default();
     inner.td_name = "getChatAdministrators".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatAdministratorsBuilder { inner }
+    RTDGetChatAdministratorsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatAdministrators", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9110,15 +11529,25 @@ impl GetChatAdministrators {
 
 #[doc(hidden)]
 pub struct RTDGetChatAdministratorsBuilder {
-  inner: GetChatAdministrators
+  inner: GetChatAdministrators,
+  set: [bool; 1]
 }
 
 impl RTDGetChatAdministratorsBuilder {
   pub fn build(&self) -> GetChatAdministrators { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatAdministrators> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChatAdministrators", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -9152,7 +11581,7 @@ pub struct GetChatEventLog {
   /// Search query by which to filter events
   query: String,
   /// Identifier of an event from which to return results. Use 0 to get results from the latest events
-  from_event_id: isize,
+  #[serde(with = "crate::types::_common::int64")] from_event_id: i64,
   /// The maximum number of events to return; up to 100
   limit: i64,
   /// The types of events to return. By default, all types will be returned
@@ -9171,7 +11600,9 @@ impl RObject for GetChatEventLog {
 
 
 
-impl RFunction for GetChatEventLog {}
+impl RFunction for GetChatEventLog {
+  type Response = ChatEvents;
+}
 
 impl GetChatEventLog {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9179,14 +11610,27 @@ impl GetChatEventLog {
     let mut inner = GetChatEventLog::default();
     inner.td_name = "getChatEventLog".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatEventLogBuilder { inner }
+    RTDGetChatEventLogBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatEventLog", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.from_event_id == 0 { missing.push("from_event_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    if false { missing.push("filters".to_string()); } else { missing.extend(crate::types::_common::missing_in("filters", self.filters._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
 
   pub fn query(&self) -> &String { &self.query }
 
-  pub fn from_event_id(&self) -> isize { self.from_event_id }
+  pub fn from_event_id(&self) -> i64 { self.from_event_id }
 
   pub fn limit(&self) -> i64 { self.limit }
 
@@ -9198,39 +11642,57 @@ impl GetChatEventLog {
 
 #[doc(hidden)]
 pub struct RTDGetChatEventLogBuilder {
-  inner: GetChatEventLog
+  inner: GetChatEventLog,
+  set: [bool; 5]
 }
 
 impl RTDGetChatEventLogBuilder {
   pub fn build(&self) -> GetChatEventLog { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatEventLog> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("query".to_string()); }
+    if !self.set[2] { missing.push("from_event_id".to_string()); }
+    if !self.set[3] { missing.push("limit".to_string()); }
+    if !self.set[4] { missing.push("filters".to_string()); } else { missing.extend(crate::types::_common::missing_in("filters", self.inner.filters._missing_fields())); }
+    crate::types::_common::check_missing("getChatEventLog", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
-  pub fn from_event_id(&mut self, from_event_id: isize) -> &mut Self {
+  pub fn from_event_id(&mut self, from_event_id: i64) -> &mut Self {
     self.inner.from_event_id = from_event_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[3] = true;
     self
   }
 
    
   pub fn filters<T: AsRef<ChatEventLogFilters>>(&mut self, filters: T) -> &mut Self {
     self.inner.filters = filters.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -9279,7 +11741,9 @@ impl RObject for GetChatFilter {
 
 
 
-impl RFunction for GetChatFilter {}
+impl RFunction for GetChatFilter {
+  type Response = ChatFilter;
+}
 
 impl GetChatFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9287,7 +11751,16 @@ impl GetChatFilter {
     let mut inner = GetChatFilter::default();
     inner.td_name = "getChatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatFilterBuilder { inner }
+    RTDGetChatFilterBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_filter_id == 0 { missing.push("chat_filter_id".to_string()); }
+    missing
   }
 
   pub fn chat_filter_id(&self) -> i64 { self.chat_filter_id }
@@ -9296,15 +11769,25 @@ impl GetChatFilter {
 
 #[doc(hidden)]
 pub struct RTDGetChatFilterBuilder {
-  inner: GetChatFilter
+  inner: GetChatFilter,
+  set: [bool; 1]
 }
 
 impl RTDGetChatFilterBuilder {
   pub fn build(&self) -> GetChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_filter_id".to_string()); }
+    crate::types::_common::check_missing("getChatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_filter_id(&mut self, chat_filter_id: i64) -> &mut Self {
     self.inner.chat_filter_id = chat_filter_id;
+    self.set[0] = true;
     self
   }
 
@@ -9347,7 +11830,9 @@ impl RObject for GetChatFilterDefaultIconName {
 
 
 
-impl RFunction for GetChatFilterDefaultIconName {}
+impl RFunction for GetChatFilterDefaultIconName {
+  type Response = Text;
+}
 
 impl GetChatFilterDefaultIconName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9355,7 +11840,16 @@ impl GetChatFilterDefaultIconName {
     let mut inner = GetChatFilterDefaultIconName::default();
     inner.td_name = "getChatFilterDefaultIconName".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatFilterDefaultIconNameBuilder { inner }
+    RTDGetChatFilterDefaultIconNameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatFilterDefaultIconName", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn filter(&self) -> &ChatFilter { &self.filter }
@@ -9364,15 +11858,25 @@ impl GetChatFilterDefaultIconName {
 
 #[doc(hidden)]
 pub struct RTDGetChatFilterDefaultIconNameBuilder {
-  inner: GetChatFilterDefaultIconName
+  inner: GetChatFilterDefaultIconName,
+  set: [bool; 1]
 }
 
 impl RTDGetChatFilterDefaultIconNameBuilder {
   pub fn build(&self) -> GetChatFilterDefaultIconName { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatFilterDefaultIconName> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("getChatFilterDefaultIconName", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -9423,7 +11927,9 @@ impl RObject for GetChatHistory {
 
 
 
-impl RFunction for GetChatHistory {}
+impl RFunction for GetChatHistory {
+  type Response = Messages;
+}
 
 impl GetChatHistory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9431,7 +11937,19 @@ impl GetChatHistory {
     let mut inner = GetChatHistory::default();
     inner.td_name = "getChatHistory".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatHistoryBuilder { inner }
+    RTDGetChatHistoryBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatHistory", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.from_message_id == 0 { missing.push("from_message_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9448,33 +11966,49 @@ impl GetChatHistory {
 
 #[doc(hidden)]
 pub struct RTDGetChatHistoryBuilder {
-  inner: GetChatHistory
+  inner: GetChatHistory,
+  set: [bool; 4]
 }
 
 impl RTDGetChatHistoryBuilder {
   pub fn build(&self) -> GetChatHistory { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatHistory> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("from_message_id".to_string()); }
+    if !self.set[2] { missing.push("offset".to_string()); }
+    if !self.set[3] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getChatHistory", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
     self.inner.from_message_id = from_message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[2] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[3] = true;
     self
   }
 
@@ -9523,7 +12057,9 @@ impl RObject for GetChatListsToAddChat {
 
 
 
-impl RFunction for GetChatListsToAddChat {}
+impl RFunction for GetChatListsToAddChat {
+  type Response = ChatLists;
+}
 
 impl GetChatListsToAddChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9531,7 +12067,16 @@ impl GetChatListsToAddChat {
     let mut inner = GetChatListsToAddChat::default();
     inner.td_name = "getChatListsToAddChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatListsToAddChatBuilder { inner }
+    RTDGetChatListsToAddChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatListsToAddChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9540,15 +12085,25 @@ impl GetChatListsToAddChat {
 
 #[doc(hidden)]
 pub struct RTDGetChatListsToAddChatBuilder {
-  inner: GetChatListsToAddChat
+  inner: GetChatListsToAddChat,
+  set: [bool; 1]
 }
 
 impl RTDGetChatListsToAddChatBuilder {
   pub fn build(&self) -> GetChatListsToAddChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatListsToAddChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChatListsToAddChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -9593,7 +12148,9 @@ impl RObject for GetChatMember {
 
 
 
-impl RFunction for GetChatMember {}
+impl RFunction for GetChatMember {
+  type Response = ChatMember;
+}
 
 impl GetChatMember {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9601,7 +12158,17 @@ impl GetChatMember {
     let mut inner = GetChatMember::default();
     inner.td_name = "getChatMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatMemberBuilder { inner }
+    RTDGetChatMemberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9612,21 +12179,33 @@ impl GetChatMember {
 
 #[doc(hidden)]
 pub struct RTDGetChatMemberBuilder {
-  inner: GetChatMember
+  inner: GetChatMember,
+  set: [bool; 2]
 }
 
 impl RTDGetChatMemberBuilder {
   pub fn build(&self) -> GetChatMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("getChatMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -9671,7 +12250,9 @@ impl RObject for GetChatMessageByDate {
 
 
 
-impl RFunction for GetChatMessageByDate {}
+impl RFunction for GetChatMessageByDate {
+  type Response = Message;
+}
 
 impl GetChatMessageByDate {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9679,7 +12260,17 @@ impl GetChatMessageByDate {
     let mut inner = GetChatMessageByDate::default();
     inner.td_name = "getChatMessageByDate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatMessageByDateBuilder { inner }
+    RTDGetChatMessageByDateBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatMessageByDate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9690,21 +12281,33 @@ impl GetChatMessageByDate {
 
 #[doc(hidden)]
 pub struct RTDGetChatMessageByDateBuilder {
-  inner: GetChatMessageByDate
+  inner: GetChatMessageByDate,
+  set: [bool; 2]
 }
 
 impl RTDGetChatMessageByDateBuilder {
   pub fn build(&self) -> GetChatMessageByDate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatMessageByDate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("date".to_string()); }
+    crate::types::_common::check_missing("getChatMessageByDate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[1] = true;
     self
   }
 
@@ -9751,7 +12354,9 @@ impl RObject for GetChatMessageCount {
 
 
 
-impl RFunction for GetChatMessageCount {}
+impl RFunction for GetChatMessageCount {
+  type Response = Count;
+}
 
 impl GetChatMessageCount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9759,7 +12364,17 @@ impl GetChatMessageCount {
     let mut inner = GetChatMessageCount::default();
     inner.td_name = "getChatMessageCount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatMessageCountBuilder { inner }
+    RTDGetChatMessageCountBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatMessageCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9772,15 +12387,26 @@ impl GetChatMessageCount {
 
 #[doc(hidden)]
 pub struct RTDGetChatMessageCountBuilder {
-  inner: GetChatMessageCount
+  inner: GetChatMessageCount,
+  set: [bool; 1]
 }
 
 impl RTDGetChatMessageCountBuilder {
   pub fn build(&self) -> GetChatMessageCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatMessageCount> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("getChatMessageCount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -9837,7 +12463,9 @@ impl RObject for GetChatNotificationSettingsExceptions {
 
 
 
-impl RFunction for GetChatNotificationSettingsExceptions {}
+impl RFunction for GetChatNotificationSettingsExceptions {
+  type Response = Chats;
+}
 
 impl GetChatNotificationSettingsExceptions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9848,6 +12476,15 @@ impl GetChatNotificationSettingsExceptions {
     RTDGetChatNotificationSettingsExceptionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatNotificationSettingsExceptions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.scope._missing_fields())); }
+    missing
+  }
+
   pub fn scope(&self) -> &NotificationSettingsScope { &self.scope }
 
   pub fn compare_sound(&self) -> bool { self.compare_sound }
@@ -9862,6 +12499,14 @@ pub struct RTDGetChatNotificationSettingsExceptionsBuilder {
 impl RTDGetChatNotificationSettingsExceptionsBuilder {
   pub fn build(&self) -> GetChatNotificationSettingsExceptions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatNotificationSettingsExceptions> {
+    let mut missing = vec![];
+    if self.inner.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.inner.scope._missing_fields())); }
+    crate::types::_common::check_missing("getChatNotificationSettingsExceptions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -9913,7 +12558,9 @@ impl RObject for GetChatPinnedMessage {
 
 
 
-impl RFunction for GetChatPinnedMessage {}
+impl RFunction for GetChatPinnedMessage {
+  type Response = Message;
+}
 
 impl GetChatPinnedMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9921,7 +12568,16 @@ impl GetChatPinnedMessage {
     let mut inner = GetChatPinnedMessage::default();
     inner.td_name = "getChatPinnedMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatPinnedMessageBuilder { inner }
+    RTDGetChatPinnedMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatPinnedMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9930,15 +12586,25 @@ impl GetChatPinnedMessage {
 
 #[doc(hidden)]
 pub struct RTDGetChatPinnedMessageBuilder {
-  inner: GetChatPinnedMessage
+  inner: GetChatPinnedMessage,
+  set: [bool; 1]
 }
 
 impl RTDGetChatPinnedMessageBuilder {
   pub fn build(&self) -> GetChatPinnedMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatPinnedMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChatPinnedMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -9981,7 +12647,9 @@ impl RObject for GetChatScheduledMessages {
 
 
 
-impl RFunction for GetChatScheduledMessages {}
+impl RFunction for GetChatScheduledMessages {
+  type Response = Messages;
+}
 
 impl GetChatScheduledMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -9989,7 +12657,16 @@ impl GetChatScheduledMessages {
     let mut inner = GetChatScheduledMessages::default();
     inner.td_name = "getChatScheduledMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatScheduledMessagesBuilder { inner }
+    RTDGetChatScheduledMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatScheduledMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -9998,15 +12675,25 @@ impl GetChatScheduledMessages {
 
 #[doc(hidden)]
 pub struct RTDGetChatScheduledMessagesBuilder {
-  inner: GetChatScheduledMessages
+  inner: GetChatScheduledMessages,
+  set: [bool; 1]
 }
 
 impl RTDGetChatScheduledMessagesBuilder {
   pub fn build(&self) -> GetChatScheduledMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatScheduledMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChatScheduledMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -10049,9 +12736,11 @@ impl RObject for GetChatStatistics {
 }
 
 
-impl TDChatStatistics for GetChatStatistics {}
 
-impl RFunction for GetChatStatistics {}
+
+impl RFunction for GetChatStatistics {
+  type Response = ChatStatistics;
+}
 
 impl GetChatStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10059,7 +12748,16 @@ impl GetChatStatistics {
     let mut inner = GetChatStatistics::default();
     inner.td_name = "getChatStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatStatisticsBuilder { inner }
+    RTDGetChatStatisticsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -10070,15 +12768,25 @@ impl GetChatStatistics {
 
 #[doc(hidden)]
 pub struct RTDGetChatStatisticsBuilder {
-  inner: GetChatStatistics
+  inner: GetChatStatistics,
+  set: [bool; 1]
 }
 
 impl RTDGetChatStatisticsBuilder {
   pub fn build(&self) -> GetChatStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getChatStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -10131,7 +12839,9 @@ impl RObject for GetChatStatisticsUrl {
 
 
 
-impl RFunction for GetChatStatisticsUrl {}
+impl RFunction for GetChatStatisticsUrl {
+  type Response = HttpUrl;
+}
 
 impl GetChatStatisticsUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10139,7 +12849,17 @@ impl GetChatStatisticsUrl {
     let mut inner = GetChatStatisticsUrl::default();
     inner.td_name = "getChatStatisticsUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatStatisticsUrlBuilder { inner }
+    RTDGetChatStatisticsUrlBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChatStatisticsUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.parameters.is_empty() { missing.push("parameters".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -10152,21 +12872,33 @@ impl GetChatStatisticsUrl {
 
 #[doc(hidden)]
 pub struct RTDGetChatStatisticsUrlBuilder {
-  inner: GetChatStatisticsUrl
+  inner: GetChatStatisticsUrl,
+  set: [bool; 2]
 }
 
 impl RTDGetChatStatisticsUrlBuilder {
   pub fn build(&self) -> GetChatStatisticsUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChatStatisticsUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("parameters".to_string()); }
+    crate::types::_common::check_missing("getChatStatisticsUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn parameters<T: AsRef<str>>(&mut self, parameters: T) -> &mut Self {
     self.inner.parameters = parameters.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -10204,7 +12936,7 @@ pub struct GetChats {
   /// The chat list in which to return chats
   chat_list: ChatList,
   /// Chat order to return chats from
-  offset_order: isize,
+  #[serde(with = "crate::types::_common::int64")] offset_order: i64,
   /// Chat identifier to return chats from
   offset_chat_id: i64,
   /// The maximum number of chats to be returned. It is possible that fewer chats than the limit are returned even if the end of the list is not reached
@@ -10221,7 +12953,9 @@ impl RObject for GetChats {
 
 
 
-impl RFunction for GetChats {}
+impl RFunction for GetChats {
+  type Response = Chats;
+}
 
 impl GetChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10229,12 +12963,24 @@ impl GetChats {
     let mut inner = GetChats::default();
     inner.td_name = "getChats".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetChatsBuilder { inner }
+    RTDGetChatsBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    if self.offset_order == 0 { missing.push("offset_order".to_string()); }
+    if self.offset_chat_id == 0 { missing.push("offset_chat_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
 
-  pub fn offset_order(&self) -> isize { self.offset_order }
+  pub fn offset_order(&self) -> i64 { self.offset_order }
 
   pub fn offset_chat_id(&self) -> i64 { self.offset_chat_id }
 
@@ -10244,12 +12990,24 @@ impl GetChats {
 
 #[doc(hidden)]
 pub struct RTDGetChatsBuilder {
-  inner: GetChats
+  inner: GetChats,
+  set: [bool; 3]
 }
 
 impl RTDGetChatsBuilder {
   pub fn build(&self) -> GetChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetChats> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    if !self.set[0] { missing.push("offset_order".to_string()); }
+    if !self.set[1] { missing.push("offset_chat_id".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -10257,20 +13015,23 @@ impl RTDGetChatsBuilder {
   }
 
    
-  pub fn offset_order(&mut self, offset_order: isize) -> &mut Self {
+  pub fn offset_order(&mut self, offset_order: i64) -> &mut Self {
     self.inner.offset_order = offset_order;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset_chat_id(&mut self, offset_chat_id: i64) -> &mut Self {
     self.inner.offset_chat_id = offset_chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -10311,7 +13072,9 @@ impl RObject for GetConnectedWebsites {
 
 
 
-impl RFunction for GetConnectedWebsites {}
+impl RFunction for GetConnectedWebsites {
+  type Response = ConnectedWebsites;
+}
 
 impl GetConnectedWebsites {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10322,6 +13085,11 @@ impl GetConnectedWebsites {
     RTDGetConnectedWebsitesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getConnectedWebsites", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10332,6 +13100,9 @@ pub struct RTDGetConnectedWebsitesBuilder {
 impl RTDGetConnectedWebsitesBuilder {
   pub fn build(&self) -> GetConnectedWebsites { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetConnectedWebsites> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetConnectedWebsites> for GetConnectedWebsites {
@@ -10369,7 +13140,9 @@ impl RObject for GetContacts {
 
 
 
-impl RFunction for GetContacts {}
+impl RFunction for GetContacts {
+  type Response = Users;
+}
 
 impl GetContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10380,6 +13153,11 @@ impl GetContacts {
     RTDGetContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10390,6 +13168,9 @@ pub struct RTDGetContactsBuilder {
 impl RTDGetContactsBuilder {
   pub fn build(&self) -> GetContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetContacts> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetContacts> for GetContacts {
@@ -10427,7 +13208,9 @@ impl RObject for GetCountries {
 
 
 
-impl RFunction for GetCountries {}
+impl RFunction for GetCountries {
+  type Response = Countries;
+}
 
 impl GetCountries {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10438,6 +13221,11 @@ impl GetCountries {
     RTDGetCountriesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCountries", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10448,6 +13236,9 @@ pub struct RTDGetCountriesBuilder {
 impl RTDGetCountriesBuilder {
   pub fn build(&self) -> GetCountries { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCountries> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetCountries> for GetCountries {
@@ -10485,7 +13276,9 @@ impl RObject for GetCountryCode {
 
 
 
-impl RFunction for GetCountryCode {}
+impl RFunction for GetCountryCode {
+  type Response = Text;
+}
 
 impl GetCountryCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10496,6 +13289,11 @@ impl GetCountryCode {
     RTDGetCountryCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCountryCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10506,6 +13304,9 @@ pub struct RTDGetCountryCodeBuilder {
 impl RTDGetCountryCodeBuilder {
   pub fn build(&self) -> GetCountryCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCountryCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetCountryCode> for GetCountryCode {
@@ -10545,7 +13346,9 @@ impl RObject for GetCreatedPublicChats {
 
 
 
-impl RFunction for GetCreatedPublicChats {}
+impl RFunction for GetCreatedPublicChats {
+  type Response = Chats;
+}
 
 impl GetCreatedPublicChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10556,6 +13359,15 @@ impl GetCreatedPublicChats {
     RTDGetCreatedPublicChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCreatedPublicChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn type_(&self) -> &PublicChatType { &self.type_ }
 
 }
@@ -10568,6 +13380,14 @@ pub struct RTDGetCreatedPublicChatsBuilder {
 impl RTDGetCreatedPublicChatsBuilder {
   pub fn build(&self) -> GetCreatedPublicChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCreatedPublicChats> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("getCreatedPublicChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PublicChatType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -10611,7 +13431,9 @@ impl RObject for GetCurrentState {
 
 
 
-impl RFunction for GetCurrentState {}
+impl RFunction for GetCurrentState {
+  type Response = Updates;
+}
 
 impl GetCurrentState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10622,6 +13444,11 @@ impl GetCurrentState {
     RTDGetCurrentStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getCurrentState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10632,6 +13459,9 @@ pub struct RTDGetCurrentStateBuilder {
 impl RTDGetCurrentStateBuilder {
   pub fn build(&self) -> GetCurrentState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetCurrentState> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetCurrentState> for GetCurrentState {
@@ -10669,7 +13499,9 @@ impl RObject for GetDatabaseStatistics {
 
 
 
-impl RFunction for GetDatabaseStatistics {}
+impl RFunction for GetDatabaseStatistics {
+  type Response = DatabaseStatistics;
+}
 
 impl GetDatabaseStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10680,6 +13512,11 @@ impl GetDatabaseStatistics {
     RTDGetDatabaseStatisticsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getDatabaseStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10690,6 +13527,9 @@ pub struct RTDGetDatabaseStatisticsBuilder {
 impl RTDGetDatabaseStatisticsBuilder {
   pub fn build(&self) -> GetDatabaseStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetDatabaseStatistics> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetDatabaseStatistics> for GetDatabaseStatistics {
@@ -10729,7 +13569,9 @@ impl RObject for GetDeepLinkInfo {
 
 
 
-impl RFunction for GetDeepLinkInfo {}
+impl RFunction for GetDeepLinkInfo {
+  type Response = DeepLinkInfo;
+}
 
 impl GetDeepLinkInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10737,7 +13579,16 @@ impl GetDeepLinkInfo {
     let mut inner = GetDeepLinkInfo::default();
     inner.td_name = "getDeepLinkInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetDeepLinkInfoBuilder { inner }
+    RTDGetDeepLinkInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getDeepLinkInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.link.is_empty() { missing.push("link".to_string()); }
+    missing
   }
 
   pub fn link(&self) -> &String { &self.link }
@@ -10746,15 +13597,25 @@ impl GetDeepLinkInfo {
 
 #[doc(hidden)]
 pub struct RTDGetDeepLinkInfoBuilder {
-  inner: GetDeepLinkInfo
+  inner: GetDeepLinkInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetDeepLinkInfoBuilder {
   pub fn build(&self) -> GetDeepLinkInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetDeepLinkInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("link".to_string()); }
+    crate::types::_common::check_missing("getDeepLinkInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -10797,7 +13658,9 @@ impl RObject for GetEmojiSuggestionsUrl {
 
 
 
-impl RFunction for GetEmojiSuggestionsUrl {}
+impl RFunction for GetEmojiSuggestionsUrl {
+  type Response = HttpUrl;
+}
 
 impl GetEmojiSuggestionsUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10805,7 +13668,16 @@ impl GetEmojiSuggestionsUrl {
     let mut inner = GetEmojiSuggestionsUrl::default();
     inner.td_name = "getEmojiSuggestionsUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetEmojiSuggestionsUrlBuilder { inner }
+    RTDGetEmojiSuggestionsUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getEmojiSuggestionsUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_code.is_empty() { missing.push("language_code".to_string()); }
+    missing
   }
 
   pub fn language_code(&self) -> &String { &self.language_code }
@@ -10814,15 +13686,25 @@ impl GetEmojiSuggestionsUrl {
 
 #[doc(hidden)]
 pub struct RTDGetEmojiSuggestionsUrlBuilder {
-  inner: GetEmojiSuggestionsUrl
+  inner: GetEmojiSuggestionsUrl,
+  set: [bool; 1]
 }
 
 impl RTDGetEmojiSuggestionsUrlBuilder {
   pub fn build(&self) -> GetEmojiSuggestionsUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetEmojiSuggestionsUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_code".to_string()); }
+    crate::types::_common::check_missing("getEmojiSuggestionsUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_code<T: AsRef<str>>(&mut self, language_code: T) -> &mut Self {
     self.inner.language_code = language_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -10863,7 +13745,9 @@ impl RObject for GetFavoriteStickers {
 
 
 
-impl RFunction for GetFavoriteStickers {}
+impl RFunction for GetFavoriteStickers {
+  type Response = Stickers;
+}
 
 impl GetFavoriteStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10874,6 +13758,11 @@ impl GetFavoriteStickers {
     RTDGetFavoriteStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getFavoriteStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -10884,6 +13773,9 @@ pub struct RTDGetFavoriteStickersBuilder {
 impl RTDGetFavoriteStickersBuilder {
   pub fn build(&self) -> GetFavoriteStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetFavoriteStickers> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetFavoriteStickers> for GetFavoriteStickers {
@@ -10923,7 +13815,9 @@ impl RObject for GetFile {
 
 
 
-impl RFunction for GetFile {}
+impl RFunction for GetFile {
+  type Response = File;
+}
 
 impl GetFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -10931,7 +13825,16 @@ impl GetFile {
     let mut inner = GetFile::default();
     inner.td_name = "getFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetFileBuilder { inner }
+    RTDGetFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -10940,15 +13843,25 @@ impl GetFile {
 
 #[doc(hidden)]
 pub struct RTDGetFileBuilder {
-  inner: GetFile
+  inner: GetFile,
+  set: [bool; 1]
 }
 
 impl RTDGetFileBuilder {
   pub fn build(&self) -> GetFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    crate::types::_common::check_missing("getFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
@@ -10993,7 +13906,9 @@ impl RObject for GetFileDownloadedPrefixSize {
 
 
 
-impl RFunction for GetFileDownloadedPrefixSize {}
+impl RFunction for GetFileDownloadedPrefixSize {
+  type Response = Count;
+}
 
 impl GetFileDownloadedPrefixSize {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11001,7 +13916,17 @@ impl GetFileDownloadedPrefixSize {
     let mut inner = GetFileDownloadedPrefixSize::default();
     inner.td_name = "getFileDownloadedPrefixSize".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetFileDownloadedPrefixSizeBuilder { inner }
+    RTDGetFileDownloadedPrefixSizeBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getFileDownloadedPrefixSize", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -11012,21 +13937,33 @@ impl GetFileDownloadedPrefixSize {
 
 #[doc(hidden)]
 pub struct RTDGetFileDownloadedPrefixSizeBuilder {
-  inner: GetFileDownloadedPrefixSize
+  inner: GetFileDownloadedPrefixSize,
+  set: [bool; 2]
 }
 
 impl RTDGetFileDownloadedPrefixSizeBuilder {
   pub fn build(&self) -> GetFileDownloadedPrefixSize { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetFileDownloadedPrefixSize> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    crate::types::_common::check_missing("getFileDownloadedPrefixSize", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[1] = true;
     self
   }
 
@@ -11069,7 +14006,9 @@ impl RObject for GetFileExtension {
 
 
 
-impl RFunction for GetFileExtension {}
+impl RFunction for GetFileExtension {
+  type Response = Text;
+}
 
 impl GetFileExtension {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11077,7 +14016,16 @@ impl GetFileExtension {
     let mut inner = GetFileExtension::default();
     inner.td_name = "getFileExtension".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetFileExtensionBuilder { inner }
+    RTDGetFileExtensionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getFileExtension", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    missing
   }
 
   pub fn mime_type(&self) -> &String { &self.mime_type }
@@ -11086,15 +14034,25 @@ impl GetFileExtension {
 
 #[doc(hidden)]
 pub struct RTDGetFileExtensionBuilder {
-  inner: GetFileExtension
+  inner: GetFileExtension,
+  set: [bool; 1]
 }
 
 impl RTDGetFileExtensionBuilder {
   pub fn build(&self) -> GetFileExtension { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetFileExtension> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("mime_type".to_string()); }
+    crate::types::_common::check_missing("getFileExtension", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -11137,7 +14095,9 @@ impl RObject for GetFileMimeType {
 
 
 
-impl RFunction for GetFileMimeType {}
+impl RFunction for GetFileMimeType {
+  type Response = Text;
+}
 
 impl GetFileMimeType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11145,7 +14105,16 @@ impl GetFileMimeType {
     let mut inner = GetFileMimeType::default();
     inner.td_name = "getFileMimeType".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetFileMimeTypeBuilder { inner }
+    RTDGetFileMimeTypeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getFileMimeType", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_name.is_empty() { missing.push("file_name".to_string()); }
+    missing
   }
 
   pub fn file_name(&self) -> &String { &self.file_name }
@@ -11154,15 +14123,25 @@ impl GetFileMimeType {
 
 #[doc(hidden)]
 pub struct RTDGetFileMimeTypeBuilder {
-  inner: GetFileMimeType
+  inner: GetFileMimeType,
+  set: [bool; 1]
 }
 
 impl RTDGetFileMimeTypeBuilder {
   pub fn build(&self) -> GetFileMimeType { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetFileMimeType> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_name".to_string()); }
+    crate::types::_common::check_missing("getFileMimeType", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_name<T: AsRef<str>>(&mut self, file_name: T) -> &mut Self {
     self.inner.file_name = file_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -11209,7 +14188,9 @@ impl RObject for GetGameHighScores {
 
 
 
-impl RFunction for GetGameHighScores {}
+impl RFunction for GetGameHighScores {
+  type Response = GameHighScores;
+}
 
 impl GetGameHighScores {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11217,7 +14198,18 @@ impl GetGameHighScores {
     let mut inner = GetGameHighScores::default();
     inner.td_name = "getGameHighScores".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetGameHighScoresBuilder { inner }
+    RTDGetGameHighScoresBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getGameHighScores", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -11230,27 +14222,41 @@ impl GetGameHighScores {
 
 #[doc(hidden)]
 pub struct RTDGetGameHighScoresBuilder {
-  inner: GetGameHighScores
+  inner: GetGameHighScores,
+  set: [bool; 3]
 }
 
 impl RTDGetGameHighScoresBuilder {
   pub fn build(&self) -> GetGameHighScores { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetGameHighScores> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("getGameHighScores", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[2] = true;
     self
   }
 
@@ -11293,7 +14299,9 @@ impl RObject for GetGroupCall {
 
 
 
-impl RFunction for GetGroupCall {}
+impl RFunction for GetGroupCall {
+  type Response = GroupCall;
+}
 
 impl GetGroupCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11301,7 +14309,16 @@ impl GetGroupCall {
     let mut inner = GetGroupCall::default();
     inner.td_name = "getGroupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetGroupCallBuilder { inner }
+    RTDGetGroupCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getGroupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -11310,15 +14327,25 @@ impl GetGroupCall {
 
 #[doc(hidden)]
 pub struct RTDGetGroupCallBuilder {
-  inner: GetGroupCall
+  inner: GetGroupCall,
+  set: [bool; 1]
 }
 
 impl RTDGetGroupCallBuilder {
   pub fn build(&self) -> GetGroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetGroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("getGroupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -11365,7 +14392,9 @@ impl RObject for GetGroupsInCommon {
 
 
 
-impl RFunction for GetGroupsInCommon {}
+impl RFunction for GetGroupsInCommon {
+  type Response = Chats;
+}
 
 impl GetGroupsInCommon {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11373,7 +14402,18 @@ impl GetGroupsInCommon {
     let mut inner = GetGroupsInCommon::default();
     inner.td_name = "getGroupsInCommon".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetGroupsInCommonBuilder { inner }
+    RTDGetGroupsInCommonBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getGroupsInCommon", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.offset_chat_id == 0 { missing.push("offset_chat_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -11386,27 +14426,41 @@ impl GetGroupsInCommon {
 
 #[doc(hidden)]
 pub struct RTDGetGroupsInCommonBuilder {
-  inner: GetGroupsInCommon
+  inner: GetGroupsInCommon,
+  set: [bool; 3]
 }
 
 impl RTDGetGroupsInCommonBuilder {
   pub fn build(&self) -> GetGroupsInCommon { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetGroupsInCommon> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("offset_chat_id".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getGroupsInCommon", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset_chat_id(&mut self, offset_chat_id: i64) -> &mut Self {
     self.inner.offset_chat_id = offset_chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -11447,7 +14501,9 @@ impl RObject for GetImportedContactCount {
 
 
 
-impl RFunction for GetImportedContactCount {}
+impl RFunction for GetImportedContactCount {
+  type Response = Count;
+}
 
 impl GetImportedContactCount {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11458,6 +14514,11 @@ impl GetImportedContactCount {
     RTDGetImportedContactCountBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getImportedContactCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -11468,6 +14529,9 @@ pub struct RTDGetImportedContactCountBuilder {
 impl RTDGetImportedContactCountBuilder {
   pub fn build(&self) -> GetImportedContactCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetImportedContactCount> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetImportedContactCount> for GetImportedContactCount {
@@ -11505,7 +14569,9 @@ impl RObject for GetInactiveSupergroupChats {
 
 
 
-impl RFunction for GetInactiveSupergroupChats {}
+impl RFunction for GetInactiveSupergroupChats {
+  type Response = Chats;
+}
 
 impl GetInactiveSupergroupChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11516,6 +14582,11 @@ impl GetInactiveSupergroupChats {
     RTDGetInactiveSupergroupChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getInactiveSupergroupChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -11526,6 +14597,9 @@ pub struct RTDGetInactiveSupergroupChatsBuilder {
 impl RTDGetInactiveSupergroupChatsBuilder {
   pub fn build(&self) -> GetInactiveSupergroupChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetInactiveSupergroupChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetInactiveSupergroupChats> for GetInactiveSupergroupChats {
@@ -11567,7 +14641,9 @@ impl RObject for GetInlineGameHighScores {
 
 
 
-impl RFunction for GetInlineGameHighScores {}
+impl RFunction for GetInlineGameHighScores {
+  type Response = GameHighScores;
+}
 
 impl GetInlineGameHighScores {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11575,7 +14651,17 @@ impl GetInlineGameHighScores {
     let mut inner = GetInlineGameHighScores::default();
     inner.td_name = "getInlineGameHighScores".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetInlineGameHighScoresBuilder { inner }
+    RTDGetInlineGameHighScoresBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getInlineGameHighScores", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -11586,21 +14672,33 @@ impl GetInlineGameHighScores {
 
 #[doc(hidden)]
 pub struct RTDGetInlineGameHighScoresBuilder {
-  inner: GetInlineGameHighScores
+  inner: GetInlineGameHighScores,
+  set: [bool; 2]
 }
 
 impl RTDGetInlineGameHighScoresBuilder {
   pub fn build(&self) -> GetInlineGameHighScores { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetInlineGameHighScores> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("getInlineGameHighScores", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -11651,7 +14749,9 @@ impl RObject for GetInlineQueryResults {
 
 
 
-impl RFunction for GetInlineQueryResults {}
+impl RFunction for GetInlineQueryResults {
+  type Response = InlineQueryResults;
+}
 
 impl GetInlineQueryResults {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11659,7 +14759,20 @@ impl GetInlineQueryResults {
     let mut inner = GetInlineQueryResults::default();
     inner.td_name = "getInlineQueryResults".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetInlineQueryResultsBuilder { inner }
+    RTDGetInlineQueryResultsBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getInlineQueryResults", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("user_location".to_string()); } else { missing.extend(crate::types::_common::missing_in("user_location", self.user_location._missing_fields())); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.offset.is_empty() { missing.push("offset".to_string()); }
+    missing
   }
 
   pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
@@ -11676,39 +14789,57 @@ impl GetInlineQueryResults {
 
 #[doc(hidden)]
 pub struct RTDGetInlineQueryResultsBuilder {
-  inner: GetInlineQueryResults
+  inner: GetInlineQueryResults,
+  set: [bool; 5]
 }
 
 impl RTDGetInlineQueryResultsBuilder {
   pub fn build(&self) -> GetInlineQueryResults { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetInlineQueryResults> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bot_user_id".to_string()); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if !self.set[2] { missing.push("user_location".to_string()); } else { missing.extend(crate::types::_common::missing_in("user_location", self.inner.user_location._missing_fields())); }
+    if !self.set[3] { missing.push("query".to_string()); }
+    if !self.set[4] { missing.push("offset".to_string()); }
+    crate::types::_common::check_missing("getInlineQueryResults", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn user_location<T: AsRef<Location>>(&mut self, user_location: T) -> &mut Self {
     self.inner.user_location = user_location.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn offset<T: AsRef<str>>(&mut self, offset: T) -> &mut Self {
     self.inner.offset = offset.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -11751,7 +14882,9 @@ impl RObject for GetInstalledStickerSets {
 
 
 
-impl RFunction for GetInstalledStickerSets {}
+impl RFunction for GetInstalledStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11762,6 +14895,11 @@ impl GetInstalledStickerSets {
     RTDGetInstalledStickerSetsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getInstalledStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_masks(&self) -> bool { self.is_masks }
 
 }
@@ -11774,6 +14912,9 @@ pub struct RTDGetInstalledStickerSetsBuilder {
 impl RTDGetInstalledStickerSetsBuilder {
   pub fn build(&self) -> GetInstalledStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetInstalledStickerSets> { Ok(self.build()) }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -11817,7 +14958,9 @@ impl RObject for GetInviteText {
 
 
 
-impl RFunction for GetInviteText {}
+impl RFunction for GetInviteText {
+  type Response = Text;
+}
 
 impl GetInviteText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11828,6 +14971,11 @@ impl GetInviteText {
     RTDGetInviteTextBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getInviteText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -11838,6 +14986,9 @@ pub struct RTDGetInviteTextBuilder {
 impl RTDGetInviteTextBuilder {
   pub fn build(&self) -> GetInviteText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetInviteText> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetInviteText> for GetInviteText {
@@ -11877,7 +15028,9 @@ impl RObject for GetJsonString {
 
 
 
-impl RFunction for GetJsonString {}
+impl RFunction for GetJsonString {
+  type Response = Text;
+}
 
 impl GetJsonString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11888,6 +15041,15 @@ impl GetJsonString {
     RTDGetJsonStringBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getJsonString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.json_value._is_default() { missing.push("json_value".to_string()); } else { missing.extend(crate::types::_common::missing_in("json_value", self.json_value._missing_fields())); }
+    missing
+  }
+
   pub fn json_value(&self) -> &JsonValue { &self.json_value }
 
 }
@@ -11900,6 +15062,14 @@ pub struct RTDGetJsonStringBuilder {
 impl RTDGetJsonStringBuilder {
   pub fn build(&self) -> GetJsonString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetJsonString> {
+    let mut missing = vec![];
+    if self.inner.json_value._is_default() { missing.push("json_value".to_string()); } else { missing.extend(crate::types::_common::missing_in("json_value", self.inner.json_value._missing_fields())); }
+    crate::types::_common::check_missing("getJsonString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn json_value<T: AsRef<JsonValue>>(&mut self, json_value: T) -> &mut Self {
     self.inner.json_value = json_value.as_ref().clone();
@@ -11943,9 +15113,11 @@ impl RObject for GetJsonValue {
 }
 
 
-impl TDJsonValue for GetJsonValue {}
 
-impl RFunction for GetJsonValue {}
+
+impl RFunction for GetJsonValue {
+  type Response = JsonValue;
+}
 
 impl GetJsonValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -11953,7 +15125,16 @@ impl GetJsonValue {
     let mut inner = GetJsonValue::default();
     inner.td_name = "getJsonValue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetJsonValueBuilder { inner }
+    RTDGetJsonValueBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getJsonValue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.json.is_empty() { missing.push("json".to_string()); }
+    missing
   }
 
   pub fn json(&self) -> &String { &self.json }
@@ -11962,15 +15143,25 @@ impl GetJsonValue {
 
 #[doc(hidden)]
 pub struct RTDGetJsonValueBuilder {
-  inner: GetJsonValue
+  inner: GetJsonValue,
+  set: [bool; 1]
 }
 
 impl RTDGetJsonValueBuilder {
   pub fn build(&self) -> GetJsonValue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetJsonValue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("json".to_string()); }
+    crate::types::_common::check_missing("getJsonValue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn json<T: AsRef<str>>(&mut self, json: T) -> &mut Self {
     self.inner.json = json.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -12013,7 +15204,9 @@ impl RObject for GetLanguagePackInfo {
 
 
 
-impl RFunction for GetLanguagePackInfo {}
+impl RFunction for GetLanguagePackInfo {
+  type Response = LanguagePackInfo;
+}
 
 impl GetLanguagePackInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12021,7 +15214,16 @@ impl GetLanguagePackInfo {
     let mut inner = GetLanguagePackInfo::default();
     inner.td_name = "getLanguagePackInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLanguagePackInfoBuilder { inner }
+    RTDGetLanguagePackInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLanguagePackInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -12030,15 +15232,25 @@ impl GetLanguagePackInfo {
 
 #[doc(hidden)]
 pub struct RTDGetLanguagePackInfoBuilder {
-  inner: GetLanguagePackInfo
+  inner: GetLanguagePackInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetLanguagePackInfoBuilder {
   pub fn build(&self) -> GetLanguagePackInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLanguagePackInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("getLanguagePackInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -12085,9 +15297,11 @@ impl RObject for GetLanguagePackString {
 }
 
 
-impl TDLanguagePackStringValue for GetLanguagePackString {}
 
-impl RFunction for GetLanguagePackString {}
+
+impl RFunction for GetLanguagePackString {
+  type Response = LanguagePackStringValue;
+}
 
 impl GetLanguagePackString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12095,7 +15309,19 @@ impl GetLanguagePackString {
     let mut inner = GetLanguagePackString::default();
     inner.td_name = "getLanguagePackString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLanguagePackStringBuilder { inner }
+    RTDGetLanguagePackStringBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLanguagePackString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_database_path.is_empty() { missing.push("language_pack_database_path".to_string()); }
+    if self.localization_target.is_empty() { missing.push("localization_target".to_string()); }
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    if self.key.is_empty() { missing.push("key".to_string()); }
+    missing
   }
 
   pub fn language_pack_database_path(&self) -> &String { &self.language_pack_database_path }
@@ -12110,33 +15336,49 @@ impl GetLanguagePackString {
 
 #[doc(hidden)]
 pub struct RTDGetLanguagePackStringBuilder {
-  inner: GetLanguagePackString
+  inner: GetLanguagePackString,
+  set: [bool; 4]
 }
 
 impl RTDGetLanguagePackStringBuilder {
   pub fn build(&self) -> GetLanguagePackString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLanguagePackString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_database_path".to_string()); }
+    if !self.set[1] { missing.push("localization_target".to_string()); }
+    if !self.set[2] { missing.push("language_pack_id".to_string()); }
+    if !self.set[3] { missing.push("key".to_string()); }
+    crate::types::_common::check_missing("getLanguagePackString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_database_path<T: AsRef<str>>(&mut self, language_pack_database_path: T) -> &mut Self {
     self.inner.language_pack_database_path = language_pack_database_path.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn localization_target<T: AsRef<str>>(&mut self, localization_target: T) -> &mut Self {
     self.inner.localization_target = localization_target.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
     self.inner.key = key.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -12181,7 +15423,9 @@ impl RObject for GetLanguagePackStrings {
 
 
 
-impl RFunction for GetLanguagePackStrings {}
+impl RFunction for GetLanguagePackStrings {
+  type Response = LanguagePackStrings;
+}
 
 impl GetLanguagePackStrings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12189,7 +15433,16 @@ impl GetLanguagePackStrings {
     let mut inner = GetLanguagePackStrings::default();
     inner.td_name = "getLanguagePackStrings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLanguagePackStringsBuilder { inner }
+    RTDGetLanguagePackStringsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLanguagePackStrings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -12200,15 +15453,25 @@ impl GetLanguagePackStrings {
 
 #[doc(hidden)]
 pub struct RTDGetLanguagePackStringsBuilder {
-  inner: GetLanguagePackStrings
+  inner: GetLanguagePackStrings,
+  set: [bool; 1]
 }
 
 impl RTDGetLanguagePackStringsBuilder {
   pub fn build(&self) -> GetLanguagePackStrings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLanguagePackStrings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("getLanguagePackStrings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -12257,7 +15520,9 @@ impl RObject for GetLocalizationTargetInfo {
 
 
 
-impl RFunction for GetLocalizationTargetInfo {}
+impl RFunction for GetLocalizationTargetInfo {
+  type Response = LocalizationTargetInfo;
+}
 
 impl GetLocalizationTargetInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12268,6 +15533,11 @@ impl GetLocalizationTargetInfo {
     RTDGetLocalizationTargetInfoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLocalizationTargetInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn only_local(&self) -> bool { self.only_local }
 
 }
@@ -12280,6 +15550,9 @@ pub struct RTDGetLocalizationTargetInfoBuilder {
 impl RTDGetLocalizationTargetInfoBuilder {
   pub fn build(&self) -> GetLocalizationTargetInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLocalizationTargetInfo> { Ok(self.build()) }
+
    
   pub fn only_local(&mut self, only_local: bool) -> &mut Self {
     self.inner.only_local = only_local;
@@ -12321,9 +15594,11 @@ impl RObject for GetLogStream {
 }
 
 
-impl TDLogStream for GetLogStream {}
 
-impl RFunction for GetLogStream {}
+
+impl RFunction for GetLogStream {
+  type Response = LogStream;
+}
 
 impl GetLogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12334,6 +15609,11 @@ impl GetLogStream {
     RTDGetLogStreamBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLogStream", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -12344,6 +15624,9 @@ pub struct RTDGetLogStreamBuilder {
 impl RTDGetLogStreamBuilder {
   pub fn build(&self) -> GetLogStream { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLogStream> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetLogStream> for GetLogStream {
@@ -12383,7 +15666,9 @@ impl RObject for GetLogTagVerbosityLevel {
 
 
 
-impl RFunction for GetLogTagVerbosityLevel {}
+impl RFunction for GetLogTagVerbosityLevel {
+  type Response = LogVerbosityLevel;
+}
 
 impl GetLogTagVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12391,7 +15676,16 @@ impl GetLogTagVerbosityLevel {
     let mut inner = GetLogTagVerbosityLevel::default();
     inner.td_name = "getLogTagVerbosityLevel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLogTagVerbosityLevelBuilder { inner }
+    RTDGetLogTagVerbosityLevelBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLogTagVerbosityLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.tag.is_empty() { missing.push("tag".to_string()); }
+    missing
   }
 
   pub fn tag(&self) -> &String { &self.tag }
@@ -12400,15 +15694,25 @@ impl GetLogTagVerbosityLevel {
 
 #[doc(hidden)]
 pub struct RTDGetLogTagVerbosityLevelBuilder {
-  inner: GetLogTagVerbosityLevel
+  inner: GetLogTagVerbosityLevel,
+  set: [bool; 1]
 }
 
 impl RTDGetLogTagVerbosityLevelBuilder {
   pub fn build(&self) -> GetLogTagVerbosityLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLogTagVerbosityLevel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("tag".to_string()); }
+    crate::types::_common::check_missing("getLogTagVerbosityLevel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn tag<T: AsRef<str>>(&mut self, tag: T) -> &mut Self {
     self.inner.tag = tag.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -12449,7 +15753,9 @@ impl RObject for GetLogTags {
 
 
 
-impl RFunction for GetLogTags {}
+impl RFunction for GetLogTags {
+  type Response = LogTags;
+}
 
 impl GetLogTags {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12460,6 +15766,11 @@ impl GetLogTags {
     RTDGetLogTagsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLogTags", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -12470,6 +15781,9 @@ pub struct RTDGetLogTagsBuilder {
 impl RTDGetLogTagsBuilder {
   pub fn build(&self) -> GetLogTags { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLogTags> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetLogTags> for GetLogTags {
@@ -12507,7 +15821,9 @@ impl RObject for GetLogVerbosityLevel {
 
 
 
-impl RFunction for GetLogVerbosityLevel {}
+impl RFunction for GetLogVerbosityLevel {
+  type Response = LogVerbosityLevel;
+}
 
 impl GetLogVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12518,6 +15834,11 @@ impl GetLogVerbosityLevel {
     RTDGetLogVerbosityLevelBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLogVerbosityLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -12528,6 +15849,9 @@ pub struct RTDGetLogVerbosityLevelBuilder {
 impl RTDGetLogVerbosityLevelBuilder {
   pub fn build(&self) -> GetLogVerbosityLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLogVerbosityLevel> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetLogVerbosityLevel> for GetLogVerbosityLevel {
@@ -12573,7 +15897,9 @@ impl RObject for GetLoginUrl {
 
 
 
-impl RFunction for GetLoginUrl {}
+impl RFunction for GetLoginUrl {
+  type Response = HttpUrl;
+}
 
 impl GetLoginUrl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12581,7 +15907,18 @@ impl GetLoginUrl {
     let mut inner = GetLoginUrl::default();
     inner.td_name = "getLoginUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLoginUrlBuilder { inner }
+    RTDGetLoginUrlBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLoginUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.button_id == 0 { missing.push("button_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -12596,27 +15933,41 @@ impl GetLoginUrl {
 
 #[doc(hidden)]
 pub struct RTDGetLoginUrlBuilder {
-  inner: GetLoginUrl
+  inner: GetLoginUrl,
+  set: [bool; 3]
 }
 
 impl RTDGetLoginUrlBuilder {
   pub fn build(&self) -> GetLoginUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLoginUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("button_id".to_string()); }
+    crate::types::_common::check_missing("getLoginUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn button_id(&mut self, button_id: i64) -> &mut Self {
     self.inner.button_id = button_id;
+    self.set[2] = true;
     self
   }
 
@@ -12667,9 +16018,11 @@ impl RObject for GetLoginUrlInfo {
 }
 
 
-impl TDLoginUrlInfo for GetLoginUrlInfo {}
 
-impl RFunction for GetLoginUrlInfo {}
+
+impl RFunction for GetLoginUrlInfo {
+  type Response = LoginUrlInfo;
+}
 
 impl GetLoginUrlInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12677,7 +16030,18 @@ impl GetLoginUrlInfo {
     let mut inner = GetLoginUrlInfo::default();
     inner.td_name = "getLoginUrlInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetLoginUrlInfoBuilder { inner }
+    RTDGetLoginUrlInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getLoginUrlInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.button_id == 0 { missing.push("button_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -12690,27 +16054,41 @@ impl GetLoginUrlInfo {
 
 #[doc(hidden)]
 pub struct RTDGetLoginUrlInfoBuilder {
-  inner: GetLoginUrlInfo
+  inner: GetLoginUrlInfo,
+  set: [bool; 3]
 }
 
 impl RTDGetLoginUrlInfoBuilder {
   pub fn build(&self) -> GetLoginUrlInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetLoginUrlInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("button_id".to_string()); }
+    crate::types::_common::check_missing("getLoginUrlInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn button_id(&mut self, button_id: i64) -> &mut Self {
     self.inner.button_id = button_id;
+    self.set[2] = true;
     self
   }
 
@@ -12763,7 +16141,9 @@ impl RObject for GetMapThumbnailFile {
 
 
 
-impl RFunction for GetMapThumbnailFile {}
+impl RFunction for GetMapThumbnailFile {
+  type Response = File;
+}
 
 impl GetMapThumbnailFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12771,7 +16151,20 @@ impl GetMapThumbnailFile {
     let mut inner = GetMapThumbnailFile::default();
     inner.td_name = "getMapThumbnailFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMapThumbnailFileBuilder { inner }
+    RTDGetMapThumbnailFileBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMapThumbnailFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.zoom == 0 { missing.push("zoom".to_string()); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if self.scale == 0 { missing.push("scale".to_string()); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -12790,39 +16183,57 @@ impl GetMapThumbnailFile {
 
 #[doc(hidden)]
 pub struct RTDGetMapThumbnailFileBuilder {
-  inner: GetMapThumbnailFile
+  inner: GetMapThumbnailFile,
+  set: [bool; 5]
 }
 
 impl RTDGetMapThumbnailFileBuilder {
   pub fn build(&self) -> GetMapThumbnailFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMapThumbnailFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("zoom".to_string()); }
+    if !self.set[2] { missing.push("width".to_string()); }
+    if !self.set[3] { missing.push("height".to_string()); }
+    if !self.set[4] { missing.push("scale".to_string()); }
+    crate::types::_common::check_missing("getMapThumbnailFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn zoom(&mut self, zoom: i64) -> &mut Self {
     self.inner.zoom = zoom;
+    self.set[1] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[2] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[3] = true;
     self
   }
 
    
   pub fn scale(&mut self, scale: i64) -> &mut Self {
     self.inner.scale = scale;
+    self.set[4] = true;
     self
   }
 
@@ -12871,7 +16282,9 @@ impl RObject for GetMarkdownText {
 
 
 
-impl RFunction for GetMarkdownText {}
+impl RFunction for GetMarkdownText {
+  type Response = FormattedText;
+}
 
 impl GetMarkdownText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12879,7 +16292,16 @@ impl GetMarkdownText {
     let mut inner = GetMarkdownText::default();
     inner.td_name = "getMarkdownText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMarkdownTextBuilder { inner }
+    RTDGetMarkdownTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMarkdownText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -12888,15 +16310,25 @@ impl GetMarkdownText {
 
 #[doc(hidden)]
 pub struct RTDGetMarkdownTextBuilder {
-  inner: GetMarkdownText
+  inner: GetMarkdownText,
+  set: [bool; 1]
 }
 
 impl RTDGetMarkdownTextBuilder {
   pub fn build(&self) -> GetMarkdownText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMarkdownText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("getMarkdownText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -12937,7 +16369,9 @@ impl RObject for GetMe {
 
 
 
-impl RFunction for GetMe {}
+impl RFunction for GetMe {
+  type Response = User;
+}
 
 impl GetMe {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -12948,6 +16382,11 @@ impl GetMe {
     RTDGetMeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMe", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -12958,6 +16397,9 @@ pub struct RTDGetMeBuilder {
 impl RTDGetMeBuilder {
   pub fn build(&self) -> GetMe { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMe> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetMe> for GetMe {
@@ -12999,7 +16441,9 @@ impl RObject for GetMessage {
 
 
 
-impl RFunction for GetMessage {}
+impl RFunction for GetMessage {
+  type Response = Message;
+}
 
 impl GetMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13007,7 +16451,17 @@ impl GetMessage {
     let mut inner = GetMessage::default();
     inner.td_name = "getMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageBuilder { inner }
+    RTDGetMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13018,21 +16472,33 @@ impl GetMessage {
 
 #[doc(hidden)]
 pub struct RTDGetMessageBuilder {
-  inner: GetMessage
+  inner: GetMessage,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageBuilder {
   pub fn build(&self) -> GetMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13079,7 +16545,9 @@ impl RObject for GetMessageEmbeddingCode {
 
 
 
-impl RFunction for GetMessageEmbeddingCode {}
+impl RFunction for GetMessageEmbeddingCode {
+  type Response = Text;
+}
 
 impl GetMessageEmbeddingCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13087,7 +16555,17 @@ impl GetMessageEmbeddingCode {
     let mut inner = GetMessageEmbeddingCode::default();
     inner.td_name = "getMessageEmbeddingCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageEmbeddingCodeBuilder { inner }
+    RTDGetMessageEmbeddingCodeBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageEmbeddingCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13100,21 +16578,33 @@ impl GetMessageEmbeddingCode {
 
 #[doc(hidden)]
 pub struct RTDGetMessageEmbeddingCodeBuilder {
-  inner: GetMessageEmbeddingCode
+  inner: GetMessageEmbeddingCode,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageEmbeddingCodeBuilder {
   pub fn build(&self) -> GetMessageEmbeddingCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageEmbeddingCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessageEmbeddingCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13161,9 +16651,11 @@ impl RObject for GetMessageFileType {
 }
 
 
-impl TDMessageFileType for GetMessageFileType {}
 
-impl RFunction for GetMessageFileType {}
+
+impl RFunction for GetMessageFileType {
+  type Response = MessageFileType;
+}
 
 impl GetMessageFileType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13171,7 +16663,16 @@ impl GetMessageFileType {
     let mut inner = GetMessageFileType::default();
     inner.td_name = "getMessageFileType".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageFileTypeBuilder { inner }
+    RTDGetMessageFileTypeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageFileType", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_file_head.is_empty() { missing.push("message_file_head".to_string()); }
+    missing
   }
 
   pub fn message_file_head(&self) -> &String { &self.message_file_head }
@@ -13180,15 +16681,25 @@ impl GetMessageFileType {
 
 #[doc(hidden)]
 pub struct RTDGetMessageFileTypeBuilder {
-  inner: GetMessageFileType
+  inner: GetMessageFileType,
+  set: [bool; 1]
 }
 
 impl RTDGetMessageFileTypeBuilder {
   pub fn build(&self) -> GetMessageFileType { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageFileType> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message_file_head".to_string()); }
+    crate::types::_common::check_missing("getMessageFileType", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_file_head<T: AsRef<str>>(&mut self, message_file_head: T) -> &mut Self {
     self.inner.message_file_head = message_file_head.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -13237,7 +16748,9 @@ impl RObject for GetMessageLink {
 
 
 
-impl RFunction for GetMessageLink {}
+impl RFunction for GetMessageLink {
+  type Response = MessageLink;
+}
 
 impl GetMessageLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13245,7 +16758,17 @@ impl GetMessageLink {
     let mut inner = GetMessageLink::default();
     inner.td_name = "getMessageLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageLinkBuilder { inner }
+    RTDGetMessageLinkBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13260,21 +16783,33 @@ impl GetMessageLink {
 
 #[doc(hidden)]
 pub struct RTDGetMessageLinkBuilder {
-  inner: GetMessageLink
+  inner: GetMessageLink,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageLinkBuilder {
   pub fn build(&self) -> GetMessageLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessageLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13329,7 +16864,9 @@ impl RObject for GetMessageLinkInfo {
 
 
 
-impl RFunction for GetMessageLinkInfo {}
+impl RFunction for GetMessageLinkInfo {
+  type Response = MessageLinkInfo;
+}
 
 impl GetMessageLinkInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13337,7 +16874,16 @@ impl GetMessageLinkInfo {
     let mut inner = GetMessageLinkInfo::default();
     inner.td_name = "getMessageLinkInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageLinkInfoBuilder { inner }
+    RTDGetMessageLinkInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageLinkInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -13346,15 +16892,25 @@ impl GetMessageLinkInfo {
 
 #[doc(hidden)]
 pub struct RTDGetMessageLinkInfoBuilder {
-  inner: GetMessageLinkInfo
+  inner: GetMessageLinkInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetMessageLinkInfoBuilder {
   pub fn build(&self) -> GetMessageLinkInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageLinkInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("getMessageLinkInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -13399,7 +16955,9 @@ impl RObject for GetMessageLocally {
 
 
 
-impl RFunction for GetMessageLocally {}
+impl RFunction for GetMessageLocally {
+  type Response = Message;
+}
 
 impl GetMessageLocally {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13407,7 +16965,17 @@ impl GetMessageLocally {
     let mut inner = GetMessageLocally::default();
     inner.td_name = "getMessageLocally".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageLocallyBuilder { inner }
+    RTDGetMessageLocallyBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageLocally", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13418,21 +16986,33 @@ impl GetMessageLocally {
 
 #[doc(hidden)]
 pub struct RTDGetMessageLocallyBuilder {
-  inner: GetMessageLocally
+  inner: GetMessageLocally,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageLocallyBuilder {
   pub fn build(&self) -> GetMessageLocally { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageLocally> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessageLocally", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13481,7 +17061,9 @@ impl RObject for GetMessagePublicForwards {
 
 
 
-impl RFunction for GetMessagePublicForwards {}
+impl RFunction for GetMessagePublicForwards {
+  type Response = FoundMessages;
+}
 
 impl GetMessagePublicForwards {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13489,7 +17071,19 @@ impl GetMessagePublicForwards {
     let mut inner = GetMessagePublicForwards::default();
     inner.td_name = "getMessagePublicForwards".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessagePublicForwardsBuilder { inner }
+    RTDGetMessagePublicForwardsBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessagePublicForwards", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.offset.is_empty() { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13504,33 +17098,49 @@ impl GetMessagePublicForwards {
 
 #[doc(hidden)]
 pub struct RTDGetMessagePublicForwardsBuilder {
-  inner: GetMessagePublicForwards
+  inner: GetMessagePublicForwards,
+  set: [bool; 4]
 }
 
 impl RTDGetMessagePublicForwardsBuilder {
   pub fn build(&self) -> GetMessagePublicForwards { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessagePublicForwards> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("offset".to_string()); }
+    if !self.set[3] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getMessagePublicForwards", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn offset<T: AsRef<str>>(&mut self, offset: T) -> &mut Self {
     self.inner.offset = offset.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[3] = true;
     self
   }
 
@@ -13577,7 +17187,9 @@ impl RObject for GetMessageStatistics {
 
 
 
-impl RFunction for GetMessageStatistics {}
+impl RFunction for GetMessageStatistics {
+  type Response = MessageStatistics;
+}
 
 impl GetMessageStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13585,7 +17197,17 @@ impl GetMessageStatistics {
     let mut inner = GetMessageStatistics::default();
     inner.td_name = "getMessageStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageStatisticsBuilder { inner }
+    RTDGetMessageStatisticsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13598,21 +17220,33 @@ impl GetMessageStatistics {
 
 #[doc(hidden)]
 pub struct RTDGetMessageStatisticsBuilder {
-  inner: GetMessageStatistics
+  inner: GetMessageStatistics,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageStatisticsBuilder {
   pub fn build(&self) -> GetMessageStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessageStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13663,7 +17297,9 @@ impl RObject for GetMessageThread {
 
 
 
-impl RFunction for GetMessageThread {}
+impl RFunction for GetMessageThread {
+  type Response = MessageThreadInfo;
+}
 
 impl GetMessageThread {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13671,7 +17307,17 @@ impl GetMessageThread {
     let mut inner = GetMessageThread::default();
     inner.td_name = "getMessageThread".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageThreadBuilder { inner }
+    RTDGetMessageThreadBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageThread", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13682,21 +17328,33 @@ impl GetMessageThread {
 
 #[doc(hidden)]
 pub struct RTDGetMessageThreadBuilder {
-  inner: GetMessageThread
+  inner: GetMessageThread,
+  set: [bool; 2]
 }
 
 impl RTDGetMessageThreadBuilder {
   pub fn build(&self) -> GetMessageThread { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageThread> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getMessageThread", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -13747,7 +17405,9 @@ impl RObject for GetMessageThreadHistory {
 
 
 
-impl RFunction for GetMessageThreadHistory {}
+impl RFunction for GetMessageThreadHistory {
+  type Response = Messages;
+}
 
 impl GetMessageThreadHistory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13755,7 +17415,20 @@ impl GetMessageThreadHistory {
     let mut inner = GetMessageThreadHistory::default();
     inner.td_name = "getMessageThreadHistory".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessageThreadHistoryBuilder { inner }
+    RTDGetMessageThreadHistoryBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessageThreadHistory", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.from_message_id == 0 { missing.push("from_message_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13772,39 +17445,57 @@ impl GetMessageThreadHistory {
 
 #[doc(hidden)]
 pub struct RTDGetMessageThreadHistoryBuilder {
-  inner: GetMessageThreadHistory
+  inner: GetMessageThreadHistory,
+  set: [bool; 5]
 }
 
 impl RTDGetMessageThreadHistoryBuilder {
   pub fn build(&self) -> GetMessageThreadHistory { self.inner.clone() }
 
-   
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessageThreadHistory> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("from_message_id".to_string()); }
+    if !self.set[3] { missing.push("offset".to_string()); }
+    if !self.set[4] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getMessageThreadHistory", missing)?;
+    Ok(self.build())
+  }
+
+   
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
     self.inner.from_message_id = from_message_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[3] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[4] = true;
     self
   }
 
@@ -13849,7 +17540,9 @@ impl RObject for GetMessages {
 
 
 
-impl RFunction for GetMessages {}
+impl RFunction for GetMessages {
+  type Response = Messages;
+}
 
 impl GetMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13857,7 +17550,16 @@ impl GetMessages {
     let mut inner = GetMessages::default();
     inner.td_name = "getMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetMessagesBuilder { inner }
+    RTDGetMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -13868,15 +17570,25 @@ impl GetMessages {
 
 #[doc(hidden)]
 pub struct RTDGetMessagesBuilder {
-  inner: GetMessages
+  inner: GetMessages,
+  set: [bool; 1]
 }
 
 impl RTDGetMessagesBuilder {
   pub fn build(&self) -> GetMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("getMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -13925,7 +17637,9 @@ impl RObject for GetNetworkStatistics {
 
 
 
-impl RFunction for GetNetworkStatistics {}
+impl RFunction for GetNetworkStatistics {
+  type Response = NetworkStatistics;
+}
 
 impl GetNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -13936,6 +17650,11 @@ impl GetNetworkStatistics {
     RTDGetNetworkStatisticsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getNetworkStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn only_current(&self) -> bool { self.only_current }
 
 }
@@ -13948,6 +17667,9 @@ pub struct RTDGetNetworkStatisticsBuilder {
 impl RTDGetNetworkStatisticsBuilder {
   pub fn build(&self) -> GetNetworkStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetNetworkStatistics> { Ok(self.build()) }
+
    
   pub fn only_current(&mut self, only_current: bool) -> &mut Self {
     self.inner.only_current = only_current;
@@ -13991,9 +17713,11 @@ impl RObject for GetOption {
 }
 
 
-impl TDOptionValue for GetOption {}
 
-impl RFunction for GetOption {}
+
+impl RFunction for GetOption {
+  type Response = OptionValue;
+}
 
 impl GetOption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14001,7 +17725,16 @@ impl GetOption {
     let mut inner = GetOption::default();
     inner.td_name = "getOption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetOptionBuilder { inner }
+    RTDGetOptionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getOption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -14010,15 +17743,25 @@ impl GetOption {
 
 #[doc(hidden)]
 pub struct RTDGetOptionBuilder {
-  inner: GetOption
+  inner: GetOption,
+  set: [bool; 1]
 }
 
 impl RTDGetOptionBuilder {
   pub fn build(&self) -> GetOption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetOption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("getOption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -14067,7 +17810,9 @@ impl RObject for GetPassportAuthorizationForm {
 
 
 
-impl RFunction for GetPassportAuthorizationForm {}
+impl RFunction for GetPassportAuthorizationForm {
+  type Response = PassportAuthorizationForm;
+}
 
 impl GetPassportAuthorizationForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14075,7 +17820,19 @@ impl GetPassportAuthorizationForm {
     let mut inner = GetPassportAuthorizationForm::default();
     inner.td_name = "getPassportAuthorizationForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPassportAuthorizationFormBuilder { inner }
+    RTDGetPassportAuthorizationFormBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPassportAuthorizationForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    if self.scope.is_empty() { missing.push("scope".to_string()); }
+    if self.public_key.is_empty() { missing.push("public_key".to_string()); }
+    if self.nonce.is_empty() { missing.push("nonce".to_string()); }
+    missing
   }
 
   pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
@@ -14090,33 +17847,49 @@ impl GetPassportAuthorizationForm {
 
 #[doc(hidden)]
 pub struct RTDGetPassportAuthorizationFormBuilder {
-  inner: GetPassportAuthorizationForm
+  inner: GetPassportAuthorizationForm,
+  set: [bool; 4]
 }
 
 impl RTDGetPassportAuthorizationFormBuilder {
   pub fn build(&self) -> GetPassportAuthorizationForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPassportAuthorizationForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bot_user_id".to_string()); }
+    if !self.set[1] { missing.push("scope".to_string()); }
+    if !self.set[2] { missing.push("public_key".to_string()); }
+    if !self.set[3] { missing.push("nonce".to_string()); }
+    crate::types::_common::check_missing("getPassportAuthorizationForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn scope<T: AsRef<str>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn public_key<T: AsRef<str>>(&mut self, public_key: T) -> &mut Self {
     self.inner.public_key = public_key.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn nonce<T: AsRef<str>>(&mut self, nonce: T) -> &mut Self {
     self.inner.nonce = nonce.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -14161,7 +17934,9 @@ impl RObject for GetPassportAuthorizationFormAvailableElements {
 
 
 
-impl RFunction for GetPassportAuthorizationFormAvailableElements {}
+impl RFunction for GetPassportAuthorizationFormAvailableElements {
+  type Response = PassportElementsWithErrors;
+}
 
 impl GetPassportAuthorizationFormAvailableElements {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14169,7 +17944,17 @@ impl GetPassportAuthorizationFormAvailableElements {
     let mut inner = GetPassportAuthorizationFormAvailableElements::default();
     inner.td_name = "getPassportAuthorizationFormAvailableElements".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPassportAuthorizationFormAvailableElementsBuilder { inner }
+    RTDGetPassportAuthorizationFormAvailableElementsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPassportAuthorizationFormAvailableElements", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.autorization_form_id == 0 { missing.push("autorization_form_id".to_string()); }
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn autorization_form_id(&self) -> i64 { self.autorization_form_id }
@@ -14180,21 +17965,33 @@ impl GetPassportAuthorizationFormAvailableElements {
 
 #[doc(hidden)]
 pub struct RTDGetPassportAuthorizationFormAvailableElementsBuilder {
-  inner: GetPassportAuthorizationFormAvailableElements
+  inner: GetPassportAuthorizationFormAvailableElements,
+  set: [bool; 2]
 }
 
 impl RTDGetPassportAuthorizationFormAvailableElementsBuilder {
   pub fn build(&self) -> GetPassportAuthorizationFormAvailableElements { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPassportAuthorizationFormAvailableElements> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("autorization_form_id".to_string()); }
+    if !self.set[1] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("getPassportAuthorizationFormAvailableElements", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn autorization_form_id(&mut self, autorization_form_id: i64) -> &mut Self {
     self.inner.autorization_form_id = autorization_form_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -14237,9 +18034,11 @@ impl RObject for GetPassportElement {
 }
 
 
-impl TDPassportElement for GetPassportElement {}
 
-impl RFunction for GetPassportElement {}
+
+impl RFunction for GetPassportElement {
+  type Response = PassportElement;
+}
 
 impl GetPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14247,7 +18046,17 @@ impl GetPassportElement {
     let mut inner = GetPassportElement::default();
     inner.td_name = "getPassportElement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPassportElementBuilder { inner }
+    RTDGetPassportElementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPassportElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
@@ -14258,12 +18067,22 @@ impl GetPassportElement {
 
 #[doc(hidden)]
 pub struct RTDGetPassportElementBuilder {
-  inner: GetPassportElement
+  inner: GetPassportElement,
+  set: [bool; 1]
 }
 
 impl RTDGetPassportElementBuilder {
   pub fn build(&self) -> GetPassportElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPassportElement> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[0] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("getPassportElement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -14273,6 +18092,7 @@ impl RTDGetPassportElementBuilder {
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -14313,7 +18133,9 @@ impl RObject for GetPasswordState {
 
 
 
-impl RFunction for GetPasswordState {}
+impl RFunction for GetPasswordState {
+  type Response = PasswordState;
+}
 
 impl GetPasswordState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14324,6 +18146,11 @@ impl GetPasswordState {
     RTDGetPasswordStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPasswordState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -14334,6 +18161,9 @@ pub struct RTDGetPasswordStateBuilder {
 impl RTDGetPasswordStateBuilder {
   pub fn build(&self) -> GetPasswordState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPasswordState> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetPasswordState> for GetPasswordState {
@@ -14375,7 +18205,9 @@ impl RObject for GetPaymentForm {
 
 
 
-impl RFunction for GetPaymentForm {}
+impl RFunction for GetPaymentForm {
+  type Response = PaymentForm;
+}
 
 impl GetPaymentForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14383,7 +18215,17 @@ impl GetPaymentForm {
     let mut inner = GetPaymentForm::default();
     inner.td_name = "getPaymentForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPaymentFormBuilder { inner }
+    RTDGetPaymentFormBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPaymentForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -14394,21 +18236,33 @@ impl GetPaymentForm {
 
 #[doc(hidden)]
 pub struct RTDGetPaymentFormBuilder {
-  inner: GetPaymentForm
+  inner: GetPaymentForm,
+  set: [bool; 2]
 }
 
 impl RTDGetPaymentFormBuilder {
   pub fn build(&self) -> GetPaymentForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPaymentForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getPaymentForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -14453,7 +18307,9 @@ impl RObject for GetPaymentReceipt {
 
 
 
-impl RFunction for GetPaymentReceipt {}
+impl RFunction for GetPaymentReceipt {
+  type Response = PaymentReceipt;
+}
 
 impl GetPaymentReceipt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14461,7 +18317,17 @@ impl GetPaymentReceipt {
     let mut inner = GetPaymentReceipt::default();
     inner.td_name = "getPaymentReceipt".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPaymentReceiptBuilder { inner }
+    RTDGetPaymentReceiptBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPaymentReceipt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -14472,21 +18338,33 @@ impl GetPaymentReceipt {
 
 #[doc(hidden)]
 pub struct RTDGetPaymentReceiptBuilder {
-  inner: GetPaymentReceipt
+  inner: GetPaymentReceipt,
+  set: [bool; 2]
 }
 
 impl RTDGetPaymentReceiptBuilder {
   pub fn build(&self) -> GetPaymentReceipt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPaymentReceipt> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getPaymentReceipt", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -14529,7 +18407,9 @@ impl RObject for GetPhoneNumberInfo {
 
 
 
-impl RFunction for GetPhoneNumberInfo {}
+impl RFunction for GetPhoneNumberInfo {
+  type Response = PhoneNumberInfo;
+}
 
 impl GetPhoneNumberInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14537,7 +18417,16 @@ impl GetPhoneNumberInfo {
     let mut inner = GetPhoneNumberInfo::default();
     inner.td_name = "getPhoneNumberInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPhoneNumberInfoBuilder { inner }
+    RTDGetPhoneNumberInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPhoneNumberInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number_prefix.is_empty() { missing.push("phone_number_prefix".to_string()); }
+    missing
   }
 
   pub fn phone_number_prefix(&self) -> &String { &self.phone_number_prefix }
@@ -14546,15 +18435,25 @@ impl GetPhoneNumberInfo {
 
 #[doc(hidden)]
 pub struct RTDGetPhoneNumberInfoBuilder {
-  inner: GetPhoneNumberInfo
+  inner: GetPhoneNumberInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetPhoneNumberInfoBuilder {
   pub fn build(&self) -> GetPhoneNumberInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPhoneNumberInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number_prefix".to_string()); }
+    crate::types::_common::check_missing("getPhoneNumberInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number_prefix<T: AsRef<str>>(&mut self, phone_number_prefix: T) -> &mut Self {
     self.inner.phone_number_prefix = phone_number_prefix.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -14605,7 +18504,9 @@ impl RObject for GetPollVoters {
 
 
 
-impl RFunction for GetPollVoters {}
+impl RFunction for GetPollVoters {
+  type Response = Users;
+}
 
 impl GetPollVoters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14613,7 +18514,20 @@ impl GetPollVoters {
     let mut inner = GetPollVoters::default();
     inner.td_name = "getPollVoters".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPollVotersBuilder { inner }
+    RTDGetPollVotersBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPollVoters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.option_id == 0 { missing.push("option_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -14630,39 +18544,57 @@ impl GetPollVoters {
 
 #[doc(hidden)]
 pub struct RTDGetPollVotersBuilder {
-  inner: GetPollVoters
+  inner: GetPollVoters,
+  set: [bool; 5]
 }
 
 impl RTDGetPollVotersBuilder {
   pub fn build(&self) -> GetPollVoters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPollVoters> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("option_id".to_string()); }
+    if !self.set[3] { missing.push("offset".to_string()); }
+    if !self.set[4] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getPollVoters", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn option_id(&mut self, option_id: i64) -> &mut Self {
     self.inner.option_id = option_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[3] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[4] = true;
     self
   }
 
@@ -14705,7 +18637,9 @@ impl RObject for GetPreferredCountryLanguage {
 
 
 
-impl RFunction for GetPreferredCountryLanguage {}
+impl RFunction for GetPreferredCountryLanguage {
+  type Response = Text;
+}
 
 impl GetPreferredCountryLanguage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14713,7 +18647,16 @@ impl GetPreferredCountryLanguage {
     let mut inner = GetPreferredCountryLanguage::default();
     inner.td_name = "getPreferredCountryLanguage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPreferredCountryLanguageBuilder { inner }
+    RTDGetPreferredCountryLanguageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPreferredCountryLanguage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.country_code.is_empty() { missing.push("country_code".to_string()); }
+    missing
   }
 
   pub fn country_code(&self) -> &String { &self.country_code }
@@ -14722,15 +18665,25 @@ impl GetPreferredCountryLanguage {
 
 #[doc(hidden)]
 pub struct RTDGetPreferredCountryLanguageBuilder {
-  inner: GetPreferredCountryLanguage
+  inner: GetPreferredCountryLanguage,
+  set: [bool; 1]
 }
 
 impl RTDGetPreferredCountryLanguageBuilder {
   pub fn build(&self) -> GetPreferredCountryLanguage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPreferredCountryLanguage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("country_code".to_string()); }
+    crate::types::_common::check_missing("getPreferredCountryLanguage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -14771,7 +18724,9 @@ impl RObject for GetProxies {
 
 
 
-impl RFunction for GetProxies {}
+impl RFunction for GetProxies {
+  type Response = Proxies;
+}
 
 impl GetProxies {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14782,6 +18737,11 @@ impl GetProxies {
     RTDGetProxiesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getProxies", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -14792,6 +18752,9 @@ pub struct RTDGetProxiesBuilder {
 impl RTDGetProxiesBuilder {
   pub fn build(&self) -> GetProxies { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetProxies> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetProxies> for GetProxies {
@@ -14831,7 +18794,9 @@ impl RObject for GetProxyLink {
 
 
 
-impl RFunction for GetProxyLink {}
+impl RFunction for GetProxyLink {
+  type Response = Text;
+}
 
 impl GetProxyLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14839,7 +18804,16 @@ impl GetProxyLink {
     let mut inner = GetProxyLink::default();
     inner.td_name = "getProxyLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetProxyLinkBuilder { inner }
+    RTDGetProxyLinkBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getProxyLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.proxy_id == 0 { missing.push("proxy_id".to_string()); }
+    missing
   }
 
   pub fn proxy_id(&self) -> i64 { self.proxy_id }
@@ -14848,15 +18822,25 @@ impl GetProxyLink {
 
 #[doc(hidden)]
 pub struct RTDGetProxyLinkBuilder {
-  inner: GetProxyLink
+  inner: GetProxyLink,
+  set: [bool; 1]
 }
 
 impl RTDGetProxyLinkBuilder {
   pub fn build(&self) -> GetProxyLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetProxyLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("proxy_id".to_string()); }
+    crate::types::_common::check_missing("getProxyLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
+    self.set[0] = true;
     self
   }
 
@@ -14899,7 +18883,9 @@ impl RObject for GetPushReceiverId {
 
 
 
-impl RFunction for GetPushReceiverId {}
+impl RFunction for GetPushReceiverId {
+  type Response = PushReceiverId;
+}
 
 impl GetPushReceiverId {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14907,7 +18893,16 @@ impl GetPushReceiverId {
     let mut inner = GetPushReceiverId::default();
     inner.td_name = "getPushReceiverId".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetPushReceiverIdBuilder { inner }
+    RTDGetPushReceiverIdBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getPushReceiverId", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.payload.is_empty() { missing.push("payload".to_string()); }
+    missing
   }
 
   pub fn payload(&self) -> &String { &self.payload }
@@ -14916,15 +18911,25 @@ impl GetPushReceiverId {
 
 #[doc(hidden)]
 pub struct RTDGetPushReceiverIdBuilder {
-  inner: GetPushReceiverId
+  inner: GetPushReceiverId,
+  set: [bool; 1]
 }
 
 impl RTDGetPushReceiverIdBuilder {
   pub fn build(&self) -> GetPushReceiverId { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetPushReceiverId> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("payload".to_string()); }
+    crate::types::_common::check_missing("getPushReceiverId", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -14965,7 +18970,9 @@ impl RObject for GetRecentInlineBots {
 
 
 
-impl RFunction for GetRecentInlineBots {}
+impl RFunction for GetRecentInlineBots {
+  type Response = Users;
+}
 
 impl GetRecentInlineBots {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -14976,6 +18983,11 @@ impl GetRecentInlineBots {
     RTDGetRecentInlineBotsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRecentInlineBots", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -14986,6 +18998,9 @@ pub struct RTDGetRecentInlineBotsBuilder {
 impl RTDGetRecentInlineBotsBuilder {
   pub fn build(&self) -> GetRecentInlineBots { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRecentInlineBots> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetRecentInlineBots> for GetRecentInlineBots {
@@ -15025,7 +19040,9 @@ impl RObject for GetRecentStickers {
 
 
 
-impl RFunction for GetRecentStickers {}
+impl RFunction for GetRecentStickers {
+  type Response = Stickers;
+}
 
 impl GetRecentStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15036,6 +19053,11 @@ impl GetRecentStickers {
     RTDGetRecentStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRecentStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
 }
@@ -15048,6 +19070,9 @@ pub struct RTDGetRecentStickersBuilder {
 impl RTDGetRecentStickersBuilder {
   pub fn build(&self) -> GetRecentStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRecentStickers> { Ok(self.build()) }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -15093,7 +19118,9 @@ impl RObject for GetRecentlyVisitedTMeUrls {
 
 
 
-impl RFunction for GetRecentlyVisitedTMeUrls {}
+impl RFunction for GetRecentlyVisitedTMeUrls {
+  type Response = TMeUrls;
+}
 
 impl GetRecentlyVisitedTMeUrls {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15101,7 +19128,16 @@ impl GetRecentlyVisitedTMeUrls {
     let mut inner = GetRecentlyVisitedTMeUrls::default();
     inner.td_name = "getRecentlyVisitedTMeUrls".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetRecentlyVisitedTMeUrlsBuilder { inner }
+    RTDGetRecentlyVisitedTMeUrlsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRecentlyVisitedTMeUrls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.referrer.is_empty() { missing.push("referrer".to_string()); }
+    missing
   }
 
   pub fn referrer(&self) -> &String { &self.referrer }
@@ -15110,15 +19146,25 @@ impl GetRecentlyVisitedTMeUrls {
 
 #[doc(hidden)]
 pub struct RTDGetRecentlyVisitedTMeUrlsBuilder {
-  inner: GetRecentlyVisitedTMeUrls
+  inner: GetRecentlyVisitedTMeUrls,
+  set: [bool; 1]
 }
 
 impl RTDGetRecentlyVisitedTMeUrlsBuilder {
   pub fn build(&self) -> GetRecentlyVisitedTMeUrls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRecentlyVisitedTMeUrls> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("referrer".to_string()); }
+    crate::types::_common::check_missing("getRecentlyVisitedTMeUrls", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn referrer<T: AsRef<str>>(&mut self, referrer: T) -> &mut Self {
     self.inner.referrer = referrer.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -15159,7 +19205,9 @@ impl RObject for GetRecommendedChatFilters {
 
 
 
-impl RFunction for GetRecommendedChatFilters {}
+impl RFunction for GetRecommendedChatFilters {
+  type Response = RecommendedChatFilters;
+}
 
 impl GetRecommendedChatFilters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15170,6 +19218,11 @@ impl GetRecommendedChatFilters {
     RTDGetRecommendedChatFiltersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRecommendedChatFilters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -15180,6 +19233,9 @@ pub struct RTDGetRecommendedChatFiltersBuilder {
 impl RTDGetRecommendedChatFiltersBuilder {
   pub fn build(&self) -> GetRecommendedChatFilters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRecommendedChatFilters> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetRecommendedChatFilters> for GetRecommendedChatFilters {
@@ -15219,7 +19275,9 @@ impl RObject for GetRecoveryEmailAddress {
 
 
 
-impl RFunction for GetRecoveryEmailAddress {}
+impl RFunction for GetRecoveryEmailAddress {
+  type Response = RecoveryEmailAddress;
+}
 
 impl GetRecoveryEmailAddress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15227,7 +19285,16 @@ impl GetRecoveryEmailAddress {
     let mut inner = GetRecoveryEmailAddress::default();
     inner.td_name = "getRecoveryEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetRecoveryEmailAddressBuilder { inner }
+    RTDGetRecoveryEmailAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRecoveryEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -15236,15 +19303,25 @@ impl GetRecoveryEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDGetRecoveryEmailAddressBuilder {
-  inner: GetRecoveryEmailAddress
+  inner: GetRecoveryEmailAddress,
+  set: [bool; 1]
 }
 
 impl RTDGetRecoveryEmailAddressBuilder {
   pub fn build(&self) -> GetRecoveryEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRecoveryEmailAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("getRecoveryEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -15289,7 +19366,9 @@ impl RObject for GetRemoteFile {
 
 
 
-impl RFunction for GetRemoteFile {}
+impl RFunction for GetRemoteFile {
+  type Response = File;
+}
 
 impl GetRemoteFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15297,7 +19376,17 @@ impl GetRemoteFile {
     let mut inner = GetRemoteFile::default();
     inner.td_name = "getRemoteFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetRemoteFileBuilder { inner }
+    RTDGetRemoteFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRemoteFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.remote_file_id.is_empty() { missing.push("remote_file_id".to_string()); }
+    if self.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.file_type._missing_fields())); }
+    missing
   }
 
   pub fn remote_file_id(&self) -> &String { &self.remote_file_id }
@@ -15308,15 +19397,26 @@ impl GetRemoteFile {
 
 #[doc(hidden)]
 pub struct RTDGetRemoteFileBuilder {
-  inner: GetRemoteFile
+  inner: GetRemoteFile,
+  set: [bool; 1]
 }
 
 impl RTDGetRemoteFileBuilder {
   pub fn build(&self) -> GetRemoteFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRemoteFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("remote_file_id".to_string()); }
+    if self.inner.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.inner.file_type._missing_fields())); }
+    crate::types::_common::check_missing("getRemoteFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn remote_file_id<T: AsRef<str>>(&mut self, remote_file_id: T) -> &mut Self {
     self.inner.remote_file_id = remote_file_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -15367,7 +19467,9 @@ impl RObject for GetRepliedMessage {
 
 
 
-impl RFunction for GetRepliedMessage {}
+impl RFunction for GetRepliedMessage {
+  type Response = Message;
+}
 
 impl GetRepliedMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15375,7 +19477,17 @@ impl GetRepliedMessage {
     let mut inner = GetRepliedMessage::default();
     inner.td_name = "getRepliedMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetRepliedMessageBuilder { inner }
+    RTDGetRepliedMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getRepliedMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -15386,21 +19498,33 @@ impl GetRepliedMessage {
 
 #[doc(hidden)]
 pub struct RTDGetRepliedMessageBuilder {
-  inner: GetRepliedMessage
+  inner: GetRepliedMessage,
+  set: [bool; 2]
 }
 
 impl RTDGetRepliedMessageBuilder {
   pub fn build(&self) -> GetRepliedMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetRepliedMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("getRepliedMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -15441,7 +19565,9 @@ impl RObject for GetSavedAnimations {
 
 
 
-impl RFunction for GetSavedAnimations {}
+impl RFunction for GetSavedAnimations {
+  type Response = Animations;
+}
 
 impl GetSavedAnimations {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15452,6 +19578,11 @@ impl GetSavedAnimations {
     RTDGetSavedAnimationsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSavedAnimations", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -15462,6 +19593,9 @@ pub struct RTDGetSavedAnimationsBuilder {
 impl RTDGetSavedAnimationsBuilder {
   pub fn build(&self) -> GetSavedAnimations { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSavedAnimations> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetSavedAnimations> for GetSavedAnimations {
@@ -15499,7 +19633,9 @@ impl RObject for GetSavedOrderInfo {
 
 
 
-impl RFunction for GetSavedOrderInfo {}
+impl RFunction for GetSavedOrderInfo {
+  type Response = OrderInfo;
+}
 
 impl GetSavedOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15510,6 +19646,11 @@ impl GetSavedOrderInfo {
     RTDGetSavedOrderInfoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSavedOrderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -15520,6 +19661,9 @@ pub struct RTDGetSavedOrderInfoBuilder {
 impl RTDGetSavedOrderInfoBuilder {
   pub fn build(&self) -> GetSavedOrderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSavedOrderInfo> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetSavedOrderInfo> for GetSavedOrderInfo {
@@ -15559,7 +19703,9 @@ impl RObject for GetScopeNotificationSettings {
 
 
 
-impl RFunction for GetScopeNotificationSettings {}
+impl RFunction for GetScopeNotificationSettings {
+  type Response = ScopeNotificationSettings;
+}
 
 impl GetScopeNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15570,6 +19716,15 @@ impl GetScopeNotificationSettings {
     RTDGetScopeNotificationSettingsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getScopeNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.scope._missing_fields())); }
+    missing
+  }
+
   pub fn scope(&self) -> &NotificationSettingsScope { &self.scope }
 
 }
@@ -15582,6 +19737,14 @@ pub struct RTDGetScopeNotificationSettingsBuilder {
 impl RTDGetScopeNotificationSettingsBuilder {
   pub fn build(&self) -> GetScopeNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetScopeNotificationSettings> {
+    let mut missing = vec![];
+    if self.inner.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.inner.scope._missing_fields())); }
+    crate::types::_common::check_missing("getScopeNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -15627,7 +19790,9 @@ impl RObject for GetSecretChat {
 
 
 
-impl RFunction for GetSecretChat {}
+impl RFunction for GetSecretChat {
+  type Response = SecretChat;
+}
 
 impl GetSecretChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15635,7 +19800,16 @@ impl GetSecretChat {
     let mut inner = GetSecretChat::default();
     inner.td_name = "getSecretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetSecretChatBuilder { inner }
+    RTDGetSecretChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.secret_chat_id == 0 { missing.push("secret_chat_id".to_string()); }
+    missing
   }
 
   pub fn secret_chat_id(&self) -> i64 { self.secret_chat_id }
@@ -15644,15 +19818,25 @@ impl GetSecretChat {
 
 #[doc(hidden)]
 pub struct RTDGetSecretChatBuilder {
-  inner: GetSecretChat
+  inner: GetSecretChat,
+  set: [bool; 1]
 }
 
 impl RTDGetSecretChatBuilder {
   pub fn build(&self) -> GetSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret_chat_id".to_string()); }
+    crate::types::_common::check_missing("getSecretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret_chat_id(&mut self, secret_chat_id: i64) -> &mut Self {
     self.inner.secret_chat_id = secret_chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -15697,9 +19881,11 @@ impl RObject for GetStatisticalGraph {
 }
 
 
-impl TDStatisticalGraph for GetStatisticalGraph {}
 
-impl RFunction for GetStatisticalGraph {}
+
+impl RFunction for GetStatisticalGraph {
+  type Response = StatisticalGraph;
+}
 
 impl GetStatisticalGraph {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15707,7 +19893,17 @@ impl GetStatisticalGraph {
     let mut inner = GetStatisticalGraph::default();
     inner.td_name = "getStatisticalGraph".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetStatisticalGraphBuilder { inner }
+    RTDGetStatisticalGraphBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStatisticalGraph", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.token.is_empty() { missing.push("token".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -15720,21 +19916,33 @@ impl GetStatisticalGraph {
 
 #[doc(hidden)]
 pub struct RTDGetStatisticalGraphBuilder {
-  inner: GetStatisticalGraph
+  inner: GetStatisticalGraph,
+  set: [bool; 2]
 }
 
 impl RTDGetStatisticalGraphBuilder {
   pub fn build(&self) -> GetStatisticalGraph { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStatisticalGraph> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("token".to_string()); }
+    crate::types::_common::check_missing("getStatisticalGraph", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -15783,7 +19991,9 @@ impl RObject for GetStickerEmojis {
 
 
 
-impl RFunction for GetStickerEmojis {}
+impl RFunction for GetStickerEmojis {
+  type Response = Emojis;
+}
 
 impl GetStickerEmojis {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15794,6 +20004,15 @@ impl GetStickerEmojis {
     RTDGetStickerEmojisBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStickerEmojis", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
 }
@@ -15806,6 +20025,14 @@ pub struct RTDGetStickerEmojisBuilder {
 impl RTDGetStickerEmojisBuilder {
   pub fn build(&self) -> GetStickerEmojis { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStickerEmojis> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("getStickerEmojis", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -15838,7 +20065,7 @@ pub struct GetStickerSet {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the sticker set
-  set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] set_id: i64,
   
 }
 
@@ -15851,7 +20078,9 @@ impl RObject for GetStickerSet {
 
 
 
-impl RFunction for GetStickerSet {}
+impl RFunction for GetStickerSet {
+  type Response = StickerSet;
+}
 
 impl GetStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15859,24 +20088,43 @@ impl GetStickerSet {
     let mut inner = GetStickerSet::default();
     inner.td_name = "getStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetStickerSetBuilder { inner }
+    RTDGetStickerSetBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.set_id == 0 { missing.push("set_id".to_string()); }
+    missing
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  pub fn set_id(&self) -> i64 { self.set_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDGetStickerSetBuilder {
-  inner: GetStickerSet
+  inner: GetStickerSet,
+  set: [bool; 1]
 }
 
 impl RTDGetStickerSetBuilder {
   pub fn build(&self) -> GetStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("set_id".to_string()); }
+    crate::types::_common::check_missing("getStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
+    self.set[0] = true;
     self
   }
 
@@ -15921,7 +20169,9 @@ impl RObject for GetStickers {
 
 
 
-impl RFunction for GetStickers {}
+impl RFunction for GetStickers {
+  type Response = Stickers;
+}
 
 impl GetStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -15929,7 +20179,16 @@ impl GetStickers {
     let mut inner = GetStickers::default();
     inner.td_name = "getStickers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetStickersBuilder { inner }
+    RTDGetStickersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -15940,12 +20199,21 @@ impl GetStickers {
 
 #[doc(hidden)]
 pub struct RTDGetStickersBuilder {
-  inner: GetStickers
+  inner: GetStickers,
+  set: [bool; 1]
 }
 
 impl RTDGetStickersBuilder {
   pub fn build(&self) -> GetStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStickers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getStickers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
@@ -15955,6 +20223,7 @@ impl RTDGetStickersBuilder {
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[0] = true;
     self
   }
 
@@ -15997,7 +20266,9 @@ impl RObject for GetStorageStatistics {
 
 
 
-impl RFunction for GetStorageStatistics {}
+impl RFunction for GetStorageStatistics {
+  type Response = StorageStatistics;
+}
 
 impl GetStorageStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16005,7 +20276,16 @@ impl GetStorageStatistics {
     let mut inner = GetStorageStatistics::default();
     inner.td_name = "getStorageStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetStorageStatisticsBuilder { inner }
+    RTDGetStorageStatisticsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStorageStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_limit == 0 { missing.push("chat_limit".to_string()); }
+    missing
   }
 
   pub fn chat_limit(&self) -> i64 { self.chat_limit }
@@ -16014,15 +20294,25 @@ impl GetStorageStatistics {
 
 #[doc(hidden)]
 pub struct RTDGetStorageStatisticsBuilder {
-  inner: GetStorageStatistics
+  inner: GetStorageStatistics,
+  set: [bool; 1]
 }
 
 impl RTDGetStorageStatisticsBuilder {
   pub fn build(&self) -> GetStorageStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStorageStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_limit".to_string()); }
+    crate::types::_common::check_missing("getStorageStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_limit(&mut self, chat_limit: i64) -> &mut Self {
     self.inner.chat_limit = chat_limit;
+    self.set[0] = true;
     self
   }
 
@@ -16063,7 +20353,9 @@ impl RObject for GetStorageStatisticsFast {
 
 
 
-impl RFunction for GetStorageStatisticsFast {}
+impl RFunction for GetStorageStatisticsFast {
+  type Response = StorageStatisticsFast;
+}
 
 impl GetStorageStatisticsFast {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16074,6 +20366,11 @@ impl GetStorageStatisticsFast {
     RTDGetStorageStatisticsFastBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getStorageStatisticsFast", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -16084,6 +20381,9 @@ pub struct RTDGetStorageStatisticsFastBuilder {
 impl RTDGetStorageStatisticsFastBuilder {
   pub fn build(&self) -> GetStorageStatisticsFast { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetStorageStatisticsFast> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetStorageStatisticsFast> for GetStorageStatisticsFast {
@@ -16121,7 +20421,9 @@ impl RObject for GetSuitableDiscussionChats {
 
 
 
-impl RFunction for GetSuitableDiscussionChats {}
+impl RFunction for GetSuitableDiscussionChats {
+  type Response = Chats;
+}
 
 impl GetSuitableDiscussionChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16132,6 +20434,11 @@ impl GetSuitableDiscussionChats {
     RTDGetSuitableDiscussionChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSuitableDiscussionChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -16142,6 +20449,9 @@ pub struct RTDGetSuitableDiscussionChatsBuilder {
 impl RTDGetSuitableDiscussionChatsBuilder {
   pub fn build(&self) -> GetSuitableDiscussionChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSuitableDiscussionChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetSuitableDiscussionChats> for GetSuitableDiscussionChats {
@@ -16181,7 +20491,9 @@ impl RObject for GetSupergroup {
 
 
 
-impl RFunction for GetSupergroup {}
+impl RFunction for GetSupergroup {
+  type Response = Supergroup;
+}
 
 impl GetSupergroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16189,7 +20501,16 @@ impl GetSupergroup {
     let mut inner = GetSupergroup::default();
     inner.td_name = "getSupergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetSupergroupBuilder { inner }
+    RTDGetSupergroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSupergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -16198,15 +20519,25 @@ impl GetSupergroup {
 
 #[doc(hidden)]
 pub struct RTDGetSupergroupBuilder {
-  inner: GetSupergroup
+  inner: GetSupergroup,
+  set: [bool; 1]
 }
 
 impl RTDGetSupergroupBuilder {
   pub fn build(&self) -> GetSupergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSupergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("getSupergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -16249,7 +20580,9 @@ impl RObject for GetSupergroupFullInfo {
 
 
 
-impl RFunction for GetSupergroupFullInfo {}
+impl RFunction for GetSupergroupFullInfo {
+  type Response = SupergroupFullInfo;
+}
 
 impl GetSupergroupFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16257,7 +20590,16 @@ impl GetSupergroupFullInfo {
     let mut inner = GetSupergroupFullInfo::default();
     inner.td_name = "getSupergroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetSupergroupFullInfoBuilder { inner }
+    RTDGetSupergroupFullInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSupergroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -16266,15 +20608,25 @@ impl GetSupergroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDGetSupergroupFullInfoBuilder {
-  inner: GetSupergroupFullInfo
+  inner: GetSupergroupFullInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetSupergroupFullInfoBuilder {
   pub fn build(&self) -> GetSupergroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSupergroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("getSupergroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -16323,7 +20675,9 @@ impl RObject for GetSupergroupMembers {
 
 
 
-impl RFunction for GetSupergroupMembers {}
+impl RFunction for GetSupergroupMembers {
+  type Response = ChatMembers;
+}
 
 impl GetSupergroupMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16331,7 +20685,19 @@ impl GetSupergroupMembers {
     let mut inner = GetSupergroupMembers::default();
     inner.td_name = "getSupergroupMembers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetSupergroupMembersBuilder { inner }
+    RTDGetSupergroupMembersBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSupergroupMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -16346,15 +20712,28 @@ impl GetSupergroupMembers {
 
 #[doc(hidden)]
 pub struct RTDGetSupergroupMembersBuilder {
-  inner: GetSupergroupMembers
+  inner: GetSupergroupMembers,
+  set: [bool; 3]
 }
 
 impl RTDGetSupergroupMembersBuilder {
   pub fn build(&self) -> GetSupergroupMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSupergroupMembers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getSupergroupMembers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -16367,12 +20746,14 @@ impl RTDGetSupergroupMembersBuilder {
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -16413,7 +20794,9 @@ impl RObject for GetSupportUser {
 
 
 
-impl RFunction for GetSupportUser {}
+impl RFunction for GetSupportUser {
+  type Response = User;
+}
 
 impl GetSupportUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16424,6 +20807,11 @@ impl GetSupportUser {
     RTDGetSupportUserBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getSupportUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -16434,6 +20822,9 @@ pub struct RTDGetSupportUserBuilder {
 impl RTDGetSupportUserBuilder {
   pub fn build(&self) -> GetSupportUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetSupportUser> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetSupportUser> for GetSupportUser {
@@ -16471,7 +20862,9 @@ impl RObject for GetTemporaryPasswordState {
 
 
 
-impl RFunction for GetTemporaryPasswordState {}
+impl RFunction for GetTemporaryPasswordState {
+  type Response = TemporaryPasswordState;
+}
 
 impl GetTemporaryPasswordState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16482,6 +20875,11 @@ impl GetTemporaryPasswordState {
     RTDGetTemporaryPasswordStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getTemporaryPasswordState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -16492,6 +20890,9 @@ pub struct RTDGetTemporaryPasswordStateBuilder {
 impl RTDGetTemporaryPasswordStateBuilder {
   pub fn build(&self) -> GetTemporaryPasswordState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetTemporaryPasswordState> { Ok(self.build()) }
+
 }
 
 impl AsRef<GetTemporaryPasswordState> for GetTemporaryPasswordState {
@@ -16531,7 +20932,9 @@ impl RObject for GetTextEntities {
 
 
 
-impl RFunction for GetTextEntities {}
+impl RFunction for GetTextEntities {
+  type Response = TextEntities;
+}
 
 impl GetTextEntities {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16539,7 +20942,16 @@ impl GetTextEntities {
     let mut inner = GetTextEntities::default();
     inner.td_name = "getTextEntities".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetTextEntitiesBuilder { inner }
+    RTDGetTextEntitiesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getTextEntities", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -16548,15 +20960,25 @@ impl GetTextEntities {
 
 #[doc(hidden)]
 pub struct RTDGetTextEntitiesBuilder {
-  inner: GetTextEntities
+  inner: GetTextEntities,
+  set: [bool; 1]
 }
 
 impl RTDGetTextEntitiesBuilder {
   pub fn build(&self) -> GetTextEntities { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetTextEntities> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("getTextEntities", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -16601,7 +21023,9 @@ impl RObject for GetTopChats {
 
 
 
-impl RFunction for GetTopChats {}
+impl RFunction for GetTopChats {
+  type Response = Chats;
+}
 
 impl GetTopChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16609,7 +21033,17 @@ impl GetTopChats {
     let mut inner = GetTopChats::default();
     inner.td_name = "getTopChats".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetTopChatsBuilder { inner }
+    RTDGetTopChatsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getTopChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.category._is_default() { missing.push("category".to_string()); } else { missing.extend(crate::types::_common::missing_in("category", self.category._missing_fields())); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn category(&self) -> &TopChatCategory { &self.category }
@@ -16620,12 +21054,22 @@ impl GetTopChats {
 
 #[doc(hidden)]
 pub struct RTDGetTopChatsBuilder {
-  inner: GetTopChats
+  inner: GetTopChats,
+  set: [bool; 1]
 }
 
 impl RTDGetTopChatsBuilder {
   pub fn build(&self) -> GetTopChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetTopChats> {
+    let mut missing = vec![];
+    if self.inner.category._is_default() { missing.push("category".to_string()); } else { missing.extend(crate::types::_common::missing_in("category", self.inner.category._missing_fields())); }
+    if !self.set[0] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getTopChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn category<T: AsRef<TopChatCategory>>(&mut self, category: T) -> &mut Self {
     self.inner.category = category.as_ref().clone();
@@ -16635,6 +21079,7 @@ impl RTDGetTopChatsBuilder {
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[0] = true;
     self
   }
 
@@ -16679,7 +21124,9 @@ impl RObject for GetTrendingStickerSets {
 
 
 
-impl RFunction for GetTrendingStickerSets {}
+impl RFunction for GetTrendingStickerSets {
+  type Response = StickerSets;
+}
 
 impl GetTrendingStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16687,7 +21134,17 @@ impl GetTrendingStickerSets {
     let mut inner = GetTrendingStickerSets::default();
     inner.td_name = "getTrendingStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetTrendingStickerSetsBuilder { inner }
+    RTDGetTrendingStickerSetsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getTrendingStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn offset(&self) -> i64 { self.offset }
@@ -16698,21 +21155,33 @@ impl GetTrendingStickerSets {
 
 #[doc(hidden)]
 pub struct RTDGetTrendingStickerSetsBuilder {
-  inner: GetTrendingStickerSets
+  inner: GetTrendingStickerSets,
+  set: [bool; 2]
 }
 
 impl RTDGetTrendingStickerSetsBuilder {
   pub fn build(&self) -> GetTrendingStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetTrendingStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("offset".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getTrendingStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -16755,7 +21224,9 @@ impl RObject for GetUser {
 
 
 
-impl RFunction for GetUser {}
+impl RFunction for GetUser {
+  type Response = User;
+}
 
 impl GetUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16763,7 +21234,16 @@ impl GetUser {
     let mut inner = GetUser::default();
     inner.td_name = "getUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetUserBuilder { inner }
+    RTDGetUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -16772,15 +21252,25 @@ impl GetUser {
 
 #[doc(hidden)]
 pub struct RTDGetUserBuilder {
-  inner: GetUser
+  inner: GetUser,
+  set: [bool; 1]
 }
 
 impl RTDGetUserBuilder {
   pub fn build(&self) -> GetUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("getUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -16823,7 +21313,9 @@ impl RObject for GetUserFullInfo {
 
 
 
-impl RFunction for GetUserFullInfo {}
+impl RFunction for GetUserFullInfo {
+  type Response = UserFullInfo;
+}
 
 impl GetUserFullInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16831,7 +21323,16 @@ impl GetUserFullInfo {
     let mut inner = GetUserFullInfo::default();
     inner.td_name = "getUserFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetUserFullInfoBuilder { inner }
+    RTDGetUserFullInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getUserFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -16840,15 +21341,25 @@ impl GetUserFullInfo {
 
 #[doc(hidden)]
 pub struct RTDGetUserFullInfoBuilder {
-  inner: GetUserFullInfo
+  inner: GetUserFullInfo,
+  set: [bool; 1]
 }
 
 impl RTDGetUserFullInfoBuilder {
   pub fn build(&self) -> GetUserFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetUserFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("getUserFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -16891,7 +21402,9 @@ impl RObject for GetUserPrivacySettingRules {
 
 
 
-impl RFunction for GetUserPrivacySettingRules {}
+impl RFunction for GetUserPrivacySettingRules {
+  type Response = UserPrivacySettingRules;
+}
 
 impl GetUserPrivacySettingRules {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16902,6 +21415,15 @@ impl GetUserPrivacySettingRules {
     RTDGetUserPrivacySettingRulesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getUserPrivacySettingRules", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.setting._missing_fields())); }
+    missing
+  }
+
   pub fn setting(&self) -> &UserPrivacySetting { &self.setting }
 
 }
@@ -16914,6 +21436,14 @@ pub struct RTDGetUserPrivacySettingRulesBuilder {
 impl RTDGetUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> GetUserPrivacySettingRules { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetUserPrivacySettingRules> {
+    let mut missing = vec![];
+    if self.inner.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.inner.setting._missing_fields())); }
+    crate::types::_common::check_missing("getUserPrivacySettingRules", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -16963,7 +21493,9 @@ impl RObject for GetUserProfilePhotos {
 
 
 
-impl RFunction for GetUserProfilePhotos {}
+impl RFunction for GetUserProfilePhotos {
+  type Response = ChatPhotos;
+}
 
 impl GetUserProfilePhotos {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -16971,7 +21503,18 @@ impl GetUserProfilePhotos {
     let mut inner = GetUserProfilePhotos::default();
     inner.td_name = "getUserProfilePhotos".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetUserProfilePhotosBuilder { inner }
+    RTDGetUserProfilePhotosBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getUserProfilePhotos", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -16984,27 +21527,41 @@ impl GetUserProfilePhotos {
 
 #[doc(hidden)]
 pub struct RTDGetUserProfilePhotosBuilder {
-  inner: GetUserProfilePhotos
+  inner: GetUserProfilePhotos,
+  set: [bool; 3]
 }
 
 impl RTDGetUserProfilePhotosBuilder {
   pub fn build(&self) -> GetUserProfilePhotos { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetUserProfilePhotos> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("getUserProfilePhotos", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -17049,7 +21606,9 @@ impl RObject for GetWebPageInstantView {
 
 
 
-impl RFunction for GetWebPageInstantView {}
+impl RFunction for GetWebPageInstantView {
+  type Response = WebPageInstantView;
+}
 
 impl GetWebPageInstantView {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17057,7 +21616,16 @@ impl GetWebPageInstantView {
     let mut inner = GetWebPageInstantView::default();
     inner.td_name = "getWebPageInstantView".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetWebPageInstantViewBuilder { inner }
+    RTDGetWebPageInstantViewBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getWebPageInstantView", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -17068,15 +21636,25 @@ impl GetWebPageInstantView {
 
 #[doc(hidden)]
 pub struct RTDGetWebPageInstantViewBuilder {
-  inner: GetWebPageInstantView
+  inner: GetWebPageInstantView,
+  set: [bool; 1]
 }
 
 impl RTDGetWebPageInstantViewBuilder {
   pub fn build(&self) -> GetWebPageInstantView { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetWebPageInstantView> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("getWebPageInstantView", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -17125,7 +21703,9 @@ impl RObject for GetWebPagePreview {
 
 
 
-impl RFunction for GetWebPagePreview {}
+impl RFunction for GetWebPagePreview {
+  type Response = WebPage;
+}
 
 impl GetWebPagePreview {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17133,7 +21713,16 @@ impl GetWebPagePreview {
     let mut inner = GetWebPagePreview::default();
     inner.td_name = "getWebPagePreview".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGetWebPagePreviewBuilder { inner }
+    RTDGetWebPagePreviewBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("getWebPagePreview", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -17142,15 +21731,25 @@ impl GetWebPagePreview {
 
 #[doc(hidden)]
 pub struct RTDGetWebPagePreviewBuilder {
-  inner: GetWebPagePreview
+  inner: GetWebPagePreview,
+  set: [bool; 1]
 }
 
 impl RTDGetWebPagePreviewBuilder {
   pub fn build(&self) -> GetWebPagePreview { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GetWebPagePreview> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("getWebPagePreview", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -17193,7 +21792,9 @@ impl RObject for HideSuggestedAction {
 
 
 
-impl RFunction for HideSuggestedAction {}
+impl RFunction for HideSuggestedAction {
+  type Response = Ok;
+}
 
 impl HideSuggestedAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17204,6 +21805,15 @@ impl HideSuggestedAction {
     RTDHideSuggestedActionBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("hideSuggestedAction", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.action._missing_fields())); }
+    missing
+  }
+
   pub fn action(&self) -> &SuggestedAction { &self.action }
 
 }
@@ -17216,6 +21826,14 @@ pub struct RTDHideSuggestedActionBuilder {
 impl RTDHideSuggestedActionBuilder {
   pub fn build(&self) -> HideSuggestedAction { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<HideSuggestedAction> {
+    let mut missing = vec![];
+    if self.inner.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.inner.action._missing_fields())); }
+    crate::types::_common::check_missing("hideSuggestedAction", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn action<T: AsRef<SuggestedAction>>(&mut self, action: T) -> &mut Self {
     self.inner.action = action.as_ref().clone();
@@ -17261,7 +21879,9 @@ impl RObject for ImportContacts {
 
 
 
-impl RFunction for ImportContacts {}
+impl RFunction for ImportContacts {
+  type Response = ImportedContacts;
+}
 
 impl ImportContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17272,6 +21892,11 @@ impl ImportContacts {
     RTDImportContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("importContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn contacts(&self) -> &Vec<Contact> { &self.contacts }
 
 }
@@ -17284,6 +21909,9 @@ pub struct RTDImportContactsBuilder {
 impl RTDImportContactsBuilder {
   pub fn build(&self) -> ImportContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ImportContacts> { Ok(self.build()) }
+
    
   pub fn contacts(&mut self, contacts: Vec<Contact>) -> &mut Self {
     self.inner.contacts = contacts;
@@ -17333,7 +21961,9 @@ impl RObject for ImportMessages {
 
 
 
-impl RFunction for ImportMessages {}
+impl RFunction for ImportMessages {
+  type Response = Ok;
+}
 
 impl ImportMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17341,7 +21971,17 @@ impl ImportMessages {
     let mut inner = ImportMessages::default();
     inner.td_name = "importMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDImportMessagesBuilder { inner }
+    RTDImportMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("importMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_file._is_default() { missing.push("message_file".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_file", self.message_file._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -17354,15 +21994,26 @@ impl ImportMessages {
 
 #[doc(hidden)]
 pub struct RTDImportMessagesBuilder {
-  inner: ImportMessages
+  inner: ImportMessages,
+  set: [bool; 1]
 }
 
 impl RTDImportMessagesBuilder {
   pub fn build(&self) -> ImportMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ImportMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.message_file._is_default() { missing.push("message_file".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_file", self.inner.message_file._missing_fields())); }
+    crate::types::_common::check_missing("importMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -17419,7 +22070,9 @@ impl RObject for InviteGroupCallParticipants {
 
 
 
-impl RFunction for InviteGroupCallParticipants {}
+impl RFunction for InviteGroupCallParticipants {
+  type Response = Ok;
+}
 
 impl InviteGroupCallParticipants {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17427,7 +22080,16 @@ impl InviteGroupCallParticipants {
     let mut inner = InviteGroupCallParticipants::default();
     inner.td_name = "inviteGroupCallParticipants".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInviteGroupCallParticipantsBuilder { inner }
+    RTDInviteGroupCallParticipantsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inviteGroupCallParticipants", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -17438,15 +22100,25 @@ impl InviteGroupCallParticipants {
 
 #[doc(hidden)]
 pub struct RTDInviteGroupCallParticipantsBuilder {
-  inner: InviteGroupCallParticipants
+  inner: InviteGroupCallParticipants,
+  set: [bool; 1]
 }
 
 impl RTDInviteGroupCallParticipantsBuilder {
   pub fn build(&self) -> InviteGroupCallParticipants { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InviteGroupCallParticipants> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("inviteGroupCallParticipants", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -17495,7 +22167,9 @@ impl RObject for JoinChat {
 
 
 
-impl RFunction for JoinChat {}
+impl RFunction for JoinChat {
+  type Response = Ok;
+}
 
 impl JoinChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17503,7 +22177,16 @@ impl JoinChat {
     let mut inner = JoinChat::default();
     inner.td_name = "joinChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDJoinChatBuilder { inner }
+    RTDJoinChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("joinChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -17512,15 +22195,25 @@ impl JoinChat {
 
 #[doc(hidden)]
 pub struct RTDJoinChatBuilder {
-  inner: JoinChat
+  inner: JoinChat,
+  set: [bool; 1]
 }
 
 impl RTDJoinChatBuilder {
   pub fn build(&self) -> JoinChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JoinChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("joinChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -17563,7 +22256,9 @@ impl RObject for JoinChatByInviteLink {
 
 
 
-impl RFunction for JoinChatByInviteLink {}
+impl RFunction for JoinChatByInviteLink {
+  type Response = Chat;
+}
 
 impl JoinChatByInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17571,7 +22266,16 @@ impl JoinChatByInviteLink {
     let mut inner = JoinChatByInviteLink::default();
     inner.td_name = "joinChatByInviteLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDJoinChatByInviteLinkBuilder { inner }
+    RTDJoinChatByInviteLinkBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("joinChatByInviteLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.invite_link.is_empty() { missing.push("invite_link".to_string()); }
+    missing
   }
 
   pub fn invite_link(&self) -> &String { &self.invite_link }
@@ -17580,15 +22284,25 @@ impl JoinChatByInviteLink {
 
 #[doc(hidden)]
 pub struct RTDJoinChatByInviteLinkBuilder {
-  inner: JoinChatByInviteLink
+  inner: JoinChatByInviteLink,
+  set: [bool; 1]
 }
 
 impl RTDJoinChatByInviteLinkBuilder {
   pub fn build(&self) -> JoinChatByInviteLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JoinChatByInviteLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invite_link".to_string()); }
+    crate::types::_common::check_missing("joinChatByInviteLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invite_link<T: AsRef<str>>(&mut self, invite_link: T) -> &mut Self {
     self.inner.invite_link = invite_link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -17637,7 +22351,9 @@ impl RObject for JoinGroupCall {
 
 
 
-impl RFunction for JoinGroupCall {}
+impl RFunction for JoinGroupCall {
+  type Response = GroupCallJoinResponse;
+}
 
 impl JoinGroupCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17645,7 +22361,18 @@ impl JoinGroupCall {
     let mut inner = JoinGroupCall::default();
     inner.td_name = "joinGroupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDJoinGroupCallBuilder { inner }
+    RTDJoinGroupCallBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("joinGroupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    if false { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.payload._missing_fields())); }
+    if self.source == 0 { missing.push("source".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -17660,27 +22387,41 @@ impl JoinGroupCall {
 
 #[doc(hidden)]
 pub struct RTDJoinGroupCallBuilder {
-  inner: JoinGroupCall
+  inner: JoinGroupCall,
+  set: [bool; 3]
 }
 
 impl RTDJoinGroupCallBuilder {
   pub fn build(&self) -> JoinGroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JoinGroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    if !self.set[1] { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.inner.payload._missing_fields())); }
+    if !self.set[2] { missing.push("source".to_string()); }
+    crate::types::_common::check_missing("joinGroupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn payload<T: AsRef<GroupCallPayload>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn source(&mut self, source: i64) -> &mut Self {
     self.inner.source = source;
+    self.set[2] = true;
     self
   }
 
@@ -17729,7 +22470,9 @@ impl RObject for LeaveChat {
 
 
 
-impl RFunction for LeaveChat {}
+impl RFunction for LeaveChat {
+  type Response = Ok;
+}
 
 impl LeaveChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17737,7 +22480,16 @@ impl LeaveChat {
     let mut inner = LeaveChat::default();
     inner.td_name = "leaveChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLeaveChatBuilder { inner }
+    RTDLeaveChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("leaveChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -17746,15 +22498,25 @@ impl LeaveChat {
 
 #[doc(hidden)]
 pub struct RTDLeaveChatBuilder {
-  inner: LeaveChat
+  inner: LeaveChat,
+  set: [bool; 1]
 }
 
 impl RTDLeaveChatBuilder {
   pub fn build(&self) -> LeaveChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LeaveChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("leaveChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -17797,7 +22559,9 @@ impl RObject for LeaveGroupCall {
 
 
 
-impl RFunction for LeaveGroupCall {}
+impl RFunction for LeaveGroupCall {
+  type Response = Ok;
+}
 
 impl LeaveGroupCall {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17805,7 +22569,16 @@ impl LeaveGroupCall {
     let mut inner = LeaveGroupCall::default();
     inner.td_name = "leaveGroupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLeaveGroupCallBuilder { inner }
+    RTDLeaveGroupCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("leaveGroupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -17814,15 +22587,25 @@ impl LeaveGroupCall {
 
 #[doc(hidden)]
 pub struct RTDLeaveGroupCallBuilder {
-  inner: LeaveGroupCall
+  inner: LeaveGroupCall,
+  set: [bool; 1]
 }
 
 impl RTDLeaveGroupCallBuilder {
   pub fn build(&self) -> LeaveGroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LeaveGroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("leaveGroupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -17867,7 +22650,9 @@ impl RObject for LoadGroupCallParticipants {
 
 
 
-impl RFunction for LoadGroupCallParticipants {}
+impl RFunction for LoadGroupCallParticipants {
+  type Response = Ok;
+}
 
 impl LoadGroupCallParticipants {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17875,7 +22660,17 @@ impl LoadGroupCallParticipants {
     let mut inner = LoadGroupCallParticipants::default();
     inner.td_name = "loadGroupCallParticipants".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLoadGroupCallParticipantsBuilder { inner }
+    RTDLoadGroupCallParticipantsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("loadGroupCallParticipants", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -17886,21 +22681,33 @@ impl LoadGroupCallParticipants {
 
 #[doc(hidden)]
 pub struct RTDLoadGroupCallParticipantsBuilder {
-  inner: LoadGroupCallParticipants
+  inner: LoadGroupCallParticipants,
+  set: [bool; 2]
 }
 
 impl RTDLoadGroupCallParticipantsBuilder {
   pub fn build(&self) -> LoadGroupCallParticipants { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LoadGroupCallParticipants> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("loadGroupCallParticipants", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -17941,7 +22748,9 @@ impl RObject for LogOut {
 
 
 
-impl RFunction for LogOut {}
+impl RFunction for LogOut {
+  type Response = Ok;
+}
 
 impl LogOut {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -17952,6 +22761,11 @@ impl LogOut {
     RTDLogOutBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logOut", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -17962,6 +22776,9 @@ pub struct RTDLogOutBuilder {
 impl RTDLogOutBuilder {
   pub fn build(&self) -> LogOut { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogOut> { Ok(self.build()) }
+
 }
 
 impl AsRef<LogOut> for LogOut {
@@ -18001,7 +22818,9 @@ impl RObject for OpenChat {
 
 
 
-impl RFunction for OpenChat {}
+impl RFunction for OpenChat {
+  type Response = Ok;
+}
 
 impl OpenChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18009,7 +22828,16 @@ impl OpenChat {
     let mut inner = OpenChat::default();
     inner.td_name = "openChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOpenChatBuilder { inner }
+    RTDOpenChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("openChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -18018,15 +22846,25 @@ impl OpenChat {
 
 #[doc(hidden)]
 pub struct RTDOpenChatBuilder {
-  inner: OpenChat
+  inner: OpenChat,
+  set: [bool; 1]
 }
 
 impl RTDOpenChatBuilder {
   pub fn build(&self) -> OpenChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OpenChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("openChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -18071,7 +22909,9 @@ impl RObject for OpenMessageContent {
 
 
 
-impl RFunction for OpenMessageContent {}
+impl RFunction for OpenMessageContent {
+  type Response = Ok;
+}
 
 impl OpenMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18079,7 +22919,17 @@ impl OpenMessageContent {
     let mut inner = OpenMessageContent::default();
     inner.td_name = "openMessageContent".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOpenMessageContentBuilder { inner }
+    RTDOpenMessageContentBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("openMessageContent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -18090,21 +22940,33 @@ impl OpenMessageContent {
 
 #[doc(hidden)]
 pub struct RTDOpenMessageContentBuilder {
-  inner: OpenMessageContent
+  inner: OpenMessageContent,
+  set: [bool; 2]
 }
 
 impl RTDOpenMessageContentBuilder {
   pub fn build(&self) -> OpenMessageContent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OpenMessageContent> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("openMessageContent", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -18163,7 +23025,9 @@ impl RObject for OptimizeStorage {
 
 
 
-impl RFunction for OptimizeStorage {}
+impl RFunction for OptimizeStorage {
+  type Response = StorageStatistics;
+}
 
 impl OptimizeStorage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18171,7 +23035,20 @@ impl OptimizeStorage {
     let mut inner = OptimizeStorage::default();
     inner.td_name = "optimizeStorage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOptimizeStorageBuilder { inner }
+    RTDOptimizeStorageBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("optimizeStorage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.size == 0 { missing.push("size".to_string()); }
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    if self.count == 0 { missing.push("count".to_string()); }
+    if self.immunity_delay == 0 { missing.push("immunity_delay".to_string()); }
+    if self.chat_limit == 0 { missing.push("chat_limit".to_string()); }
+    missing
   }
 
   pub fn size(&self) -> i64 { self.size }
@@ -18196,33 +23073,50 @@ impl OptimizeStorage {
 
 #[doc(hidden)]
 pub struct RTDOptimizeStorageBuilder {
-  inner: OptimizeStorage
+  inner: OptimizeStorage,
+  set: [bool; 5]
 }
 
 impl RTDOptimizeStorageBuilder {
   pub fn build(&self) -> OptimizeStorage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OptimizeStorage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("size".to_string()); }
+    if !self.set[1] { missing.push("ttl".to_string()); }
+    if !self.set[2] { missing.push("count".to_string()); }
+    if !self.set[3] { missing.push("immunity_delay".to_string()); }
+    if !self.set[4] { missing.push("chat_limit".to_string()); }
+    crate::types::_common::check_missing("optimizeStorage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[1] = true;
     self
   }
 
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
+    self.set[2] = true;
     self
   }
 
    
   pub fn immunity_delay(&mut self, immunity_delay: i64) -> &mut Self {
     self.inner.immunity_delay = immunity_delay;
+    self.set[3] = true;
     self
   }
 
@@ -18253,6 +23147,7 @@ impl RTDOptimizeStorageBuilder {
    
   pub fn chat_limit(&mut self, chat_limit: i64) -> &mut Self {
     self.inner.chat_limit = chat_limit;
+    self.set[4] = true;
     self
   }
 
@@ -18295,7 +23190,9 @@ impl RObject for ParseMarkdown {
 
 
 
-impl RFunction for ParseMarkdown {}
+impl RFunction for ParseMarkdown {
+  type Response = FormattedText;
+}
 
 impl ParseMarkdown {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18303,7 +23200,16 @@ impl ParseMarkdown {
     let mut inner = ParseMarkdown::default();
     inner.td_name = "parseMarkdown".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDParseMarkdownBuilder { inner }
+    RTDParseMarkdownBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("parseMarkdown", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -18312,15 +23218,25 @@ impl ParseMarkdown {
 
 #[doc(hidden)]
 pub struct RTDParseMarkdownBuilder {
-  inner: ParseMarkdown
+  inner: ParseMarkdown,
+  set: [bool; 1]
 }
 
 impl RTDParseMarkdownBuilder {
   pub fn build(&self) -> ParseMarkdown { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ParseMarkdown> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("parseMarkdown", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -18365,7 +23281,9 @@ impl RObject for ParseTextEntities {
 
 
 
-impl RFunction for ParseTextEntities {}
+impl RFunction for ParseTextEntities {
+  type Response = FormattedText;
+}
 
 impl ParseTextEntities {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18373,7 +23291,17 @@ impl ParseTextEntities {
     let mut inner = ParseTextEntities::default();
     inner.td_name = "parseTextEntities".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDParseTextEntitiesBuilder { inner }
+    RTDParseTextEntitiesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("parseTextEntities", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.parse_mode._is_default() { missing.push("parse_mode".to_string()); } else { missing.extend(crate::types::_common::missing_in("parse_mode", self.parse_mode._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -18384,15 +23312,26 @@ impl ParseTextEntities {
 
 #[doc(hidden)]
 pub struct RTDParseTextEntitiesBuilder {
-  inner: ParseTextEntities
+  inner: ParseTextEntities,
+  set: [bool; 1]
 }
 
 impl RTDParseTextEntitiesBuilder {
   pub fn build(&self) -> ParseTextEntities { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ParseTextEntities> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    if self.inner.parse_mode._is_default() { missing.push("parse_mode".to_string()); } else { missing.extend(crate::types::_common::missing_in("parse_mode", self.inner.parse_mode._missing_fields())); }
+    crate::types::_common::check_missing("parseTextEntities", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -18447,7 +23386,9 @@ impl RObject for PinChatMessage {
 
 
 
-impl RFunction for PinChatMessage {}
+impl RFunction for PinChatMessage {
+  type Response = Ok;
+}
 
 impl PinChatMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18455,7 +23396,17 @@ impl PinChatMessage {
     let mut inner = PinChatMessage::default();
     inner.td_name = "pinChatMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPinChatMessageBuilder { inner }
+    RTDPinChatMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pinChatMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -18470,21 +23421,33 @@ impl PinChatMessage {
 
 #[doc(hidden)]
 pub struct RTDPinChatMessageBuilder {
-  inner: PinChatMessage
+  inner: PinChatMessage,
+  set: [bool; 2]
 }
 
 impl RTDPinChatMessageBuilder {
   pub fn build(&self) -> PinChatMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PinChatMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("pinChatMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -18539,7 +23502,9 @@ impl RObject for PingProxy {
 
 
 
-impl RFunction for PingProxy {}
+impl RFunction for PingProxy {
+  type Response = Seconds;
+}
 
 impl PingProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18547,7 +23512,16 @@ impl PingProxy {
     let mut inner = PingProxy::default();
     inner.td_name = "pingProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPingProxyBuilder { inner }
+    RTDPingProxyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pingProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.proxy_id == 0 { missing.push("proxy_id".to_string()); }
+    missing
   }
 
   pub fn proxy_id(&self) -> i64 { self.proxy_id }
@@ -18556,15 +23530,25 @@ impl PingProxy {
 
 #[doc(hidden)]
 pub struct RTDPingProxyBuilder {
-  inner: PingProxy
+  inner: PingProxy,
+  set: [bool; 1]
 }
 
 impl RTDPingProxyBuilder {
   pub fn build(&self) -> PingProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PingProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("proxy_id".to_string()); }
+    crate::types::_common::check_missing("pingProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
+    self.set[0] = true;
     self
   }
 
@@ -18607,7 +23591,9 @@ impl RObject for ProcessPushNotification {
 
 
 
-impl RFunction for ProcessPushNotification {}
+impl RFunction for ProcessPushNotification {
+  type Response = Ok;
+}
 
 impl ProcessPushNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18615,7 +23601,16 @@ impl ProcessPushNotification {
     let mut inner = ProcessPushNotification::default();
     inner.td_name = "processPushNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDProcessPushNotificationBuilder { inner }
+    RTDProcessPushNotificationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("processPushNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.payload.is_empty() { missing.push("payload".to_string()); }
+    missing
   }
 
   pub fn payload(&self) -> &String { &self.payload }
@@ -18624,15 +23619,25 @@ impl ProcessPushNotification {
 
 #[doc(hidden)]
 pub struct RTDProcessPushNotificationBuilder {
-  inner: ProcessPushNotification
+  inner: ProcessPushNotification,
+  set: [bool; 1]
 }
 
 impl RTDProcessPushNotificationBuilder {
   pub fn build(&self) -> ProcessPushNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ProcessPushNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("payload".to_string()); }
+    crate::types::_common::check_missing("processPushNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -18675,7 +23680,9 @@ impl RObject for ReadAllChatMentions {
 
 
 
-impl RFunction for ReadAllChatMentions {}
+impl RFunction for ReadAllChatMentions {
+  type Response = Ok;
+}
 
 impl ReadAllChatMentions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18683,7 +23690,16 @@ impl ReadAllChatMentions {
     let mut inner = ReadAllChatMentions::default();
     inner.td_name = "readAllChatMentions".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDReadAllChatMentionsBuilder { inner }
+    RTDReadAllChatMentionsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("readAllChatMentions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -18692,15 +23708,25 @@ impl ReadAllChatMentions {
 
 #[doc(hidden)]
 pub struct RTDReadAllChatMentionsBuilder {
-  inner: ReadAllChatMentions
+  inner: ReadAllChatMentions,
+  set: [bool; 1]
 }
 
 impl RTDReadAllChatMentionsBuilder {
   pub fn build(&self) -> ReadAllChatMentions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReadAllChatMentions> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("readAllChatMentions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -18747,7 +23773,9 @@ impl RObject for ReadFilePart {
 
 
 
-impl RFunction for ReadFilePart {}
+impl RFunction for ReadFilePart {
+  type Response = FilePart;
+}
 
 impl ReadFilePart {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18755,7 +23783,17 @@ impl ReadFilePart {
     let mut inner = ReadFilePart::default();
     inner.td_name = "readFilePart".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDReadFilePartBuilder { inner }
+    RTDReadFilePartBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("readFilePart", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_id == 0 { missing.push("file_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    missing
   }
 
   pub fn file_id(&self) -> i64 { self.file_id }
@@ -18768,21 +23806,33 @@ impl ReadFilePart {
 
 #[doc(hidden)]
 pub struct RTDReadFilePartBuilder {
-  inner: ReadFilePart
+  inner: ReadFilePart,
+  set: [bool; 2]
 }
 
 impl RTDReadFilePartBuilder {
   pub fn build(&self) -> ReadFilePart { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReadFilePart> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_id".to_string()); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    crate::types::_common::check_missing("readFilePart", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_id(&mut self, file_id: i64) -> &mut Self {
     self.inner.file_id = file_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[1] = true;
     self
   }
 
@@ -18831,7 +23881,9 @@ impl RObject for RecoverAuthenticationPassword {
 
 
 
-impl RFunction for RecoverAuthenticationPassword {}
+impl RFunction for RecoverAuthenticationPassword {
+  type Response = Ok;
+}
 
 impl RecoverAuthenticationPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18839,7 +23891,16 @@ impl RecoverAuthenticationPassword {
     let mut inner = RecoverAuthenticationPassword::default();
     inner.td_name = "recoverAuthenticationPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRecoverAuthenticationPasswordBuilder { inner }
+    RTDRecoverAuthenticationPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("recoverAuthenticationPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.recovery_code.is_empty() { missing.push("recovery_code".to_string()); }
+    missing
   }
 
   pub fn recovery_code(&self) -> &String { &self.recovery_code }
@@ -18848,15 +23909,25 @@ impl RecoverAuthenticationPassword {
 
 #[doc(hidden)]
 pub struct RTDRecoverAuthenticationPasswordBuilder {
-  inner: RecoverAuthenticationPassword
+  inner: RecoverAuthenticationPassword,
+  set: [bool; 1]
 }
 
 impl RTDRecoverAuthenticationPasswordBuilder {
   pub fn build(&self) -> RecoverAuthenticationPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RecoverAuthenticationPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("recovery_code".to_string()); }
+    crate::types::_common::check_missing("recoverAuthenticationPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn recovery_code<T: AsRef<str>>(&mut self, recovery_code: T) -> &mut Self {
     self.inner.recovery_code = recovery_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -18899,7 +23970,9 @@ impl RObject for RecoverPassword {
 
 
 
-impl RFunction for RecoverPassword {}
+impl RFunction for RecoverPassword {
+  type Response = PasswordState;
+}
 
 impl RecoverPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18907,7 +23980,16 @@ impl RecoverPassword {
     let mut inner = RecoverPassword::default();
     inner.td_name = "recoverPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRecoverPasswordBuilder { inner }
+    RTDRecoverPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("recoverPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.recovery_code.is_empty() { missing.push("recovery_code".to_string()); }
+    missing
   }
 
   pub fn recovery_code(&self) -> &String { &self.recovery_code }
@@ -18916,15 +23998,25 @@ impl RecoverPassword {
 
 #[doc(hidden)]
 pub struct RTDRecoverPasswordBuilder {
-  inner: RecoverPassword
+  inner: RecoverPassword,
+  set: [bool; 1]
 }
 
 impl RTDRecoverPasswordBuilder {
   pub fn build(&self) -> RecoverPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RecoverPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("recovery_code".to_string()); }
+    crate::types::_common::check_missing("recoverPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn recovery_code<T: AsRef<str>>(&mut self, recovery_code: T) -> &mut Self {
     self.inner.recovery_code = recovery_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -18969,7 +24061,9 @@ impl RObject for RegisterDevice {
 
 
 
-impl RFunction for RegisterDevice {}
+impl RFunction for RegisterDevice {
+  type Response = PushReceiverId;
+}
 
 impl RegisterDevice {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -18980,6 +24074,15 @@ impl RegisterDevice {
     RTDRegisterDeviceBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("registerDevice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.device_token._is_default() { missing.push("device_token".to_string()); } else { missing.extend(crate::types::_common::missing_in("device_token", self.device_token._missing_fields())); }
+    missing
+  }
+
   pub fn device_token(&self) -> &DeviceToken { &self.device_token }
 
   pub fn other_user_ids(&self) -> &Vec<i64> { &self.other_user_ids }
@@ -18994,6 +24097,14 @@ pub struct RTDRegisterDeviceBuilder {
 impl RTDRegisterDeviceBuilder {
   pub fn build(&self) -> RegisterDevice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RegisterDevice> {
+    let mut missing = vec![];
+    if self.inner.device_token._is_default() { missing.push("device_token".to_string()); } else { missing.extend(crate::types::_common::missing_in("device_token", self.inner.device_token._missing_fields())); }
+    crate::types::_common::check_missing("registerDevice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn device_token<T: AsRef<DeviceToken>>(&mut self, device_token: T) -> &mut Self {
     self.inner.device_token = device_token.as_ref().clone();
@@ -19047,7 +24158,9 @@ impl RObject for RegisterUser {
 
 
 
-impl RFunction for RegisterUser {}
+impl RFunction for RegisterUser {
+  type Response = Ok;
+}
 
 impl RegisterUser {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19055,7 +24168,17 @@ impl RegisterUser {
     let mut inner = RegisterUser::default();
     inner.td_name = "registerUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRegisterUserBuilder { inner }
+    RTDRegisterUserBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("registerUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.first_name.is_empty() { missing.push("first_name".to_string()); }
+    if self.last_name.is_empty() { missing.push("last_name".to_string()); }
+    missing
   }
 
   pub fn first_name(&self) -> &String { &self.first_name }
@@ -19066,21 +24189,33 @@ impl RegisterUser {
 
 #[doc(hidden)]
 pub struct RTDRegisterUserBuilder {
-  inner: RegisterUser
+  inner: RegisterUser,
+  set: [bool; 2]
 }
 
 impl RTDRegisterUserBuilder {
   pub fn build(&self) -> RegisterUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RegisterUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("first_name".to_string()); }
+    if !self.set[1] { missing.push("last_name".to_string()); }
+    crate::types::_common::check_missing("registerUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
     self.inner.last_name = last_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -19110,7 +24245,7 @@ pub struct RemoveBackground {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The background identifier
-  background_id: isize,
+  #[serde(with = "crate::types::_common::int64")] background_id: i64,
   
 }
 
@@ -19123,7 +24258,9 @@ impl RObject for RemoveBackground {
 
 
 
-impl RFunction for RemoveBackground {}
+impl RFunction for RemoveBackground {
+  type Response = Ok;
+}
 
 impl RemoveBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19131,24 +24268,43 @@ impl RemoveBackground {
     let mut inner = RemoveBackground::default();
     inner.td_name = "removeBackground".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveBackgroundBuilder { inner }
+    RTDRemoveBackgroundBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeBackground", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.background_id == 0 { missing.push("background_id".to_string()); }
+    missing
   }
 
-  pub fn background_id(&self) -> isize { self.background_id }
+  pub fn background_id(&self) -> i64 { self.background_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDRemoveBackgroundBuilder {
-  inner: RemoveBackground
+  inner: RemoveBackground,
+  set: [bool; 1]
 }
 
 impl RTDRemoveBackgroundBuilder {
   pub fn build(&self) -> RemoveBackground { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveBackground> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("background_id".to_string()); }
+    crate::types::_common::check_missing("removeBackground", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+  pub fn background_id(&mut self, background_id: i64) -> &mut Self {
     self.inner.background_id = background_id;
+    self.set[0] = true;
     self
   }
 
@@ -19191,7 +24347,9 @@ impl RObject for RemoveChatActionBar {
 
 
 
-impl RFunction for RemoveChatActionBar {}
+impl RFunction for RemoveChatActionBar {
+  type Response = Ok;
+}
 
 impl RemoveChatActionBar {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19199,7 +24357,16 @@ impl RemoveChatActionBar {
     let mut inner = RemoveChatActionBar::default();
     inner.td_name = "removeChatActionBar".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveChatActionBarBuilder { inner }
+    RTDRemoveChatActionBarBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeChatActionBar", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -19208,15 +24375,25 @@ impl RemoveChatActionBar {
 
 #[doc(hidden)]
 pub struct RTDRemoveChatActionBarBuilder {
-  inner: RemoveChatActionBar
+  inner: RemoveChatActionBar,
+  set: [bool; 1]
 }
 
 impl RTDRemoveChatActionBarBuilder {
   pub fn build(&self) -> RemoveChatActionBar { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveChatActionBar> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("removeChatActionBar", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -19259,7 +24436,9 @@ impl RObject for RemoveContacts {
 
 
 
-impl RFunction for RemoveContacts {}
+impl RFunction for RemoveContacts {
+  type Response = Ok;
+}
 
 impl RemoveContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19270,6 +24449,11 @@ impl RemoveContacts {
     RTDRemoveContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
 
 }
@@ -19282,6 +24466,9 @@ pub struct RTDRemoveContactsBuilder {
 impl RTDRemoveContactsBuilder {
   pub fn build(&self) -> RemoveContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveContacts> { Ok(self.build()) }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -19327,7 +24514,9 @@ impl RObject for RemoveFavoriteSticker {
 
 
 
-impl RFunction for RemoveFavoriteSticker {}
+impl RFunction for RemoveFavoriteSticker {
+  type Response = Ok;
+}
 
 impl RemoveFavoriteSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19338,6 +24527,15 @@ impl RemoveFavoriteSticker {
     RTDRemoveFavoriteStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeFavoriteSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
 }
@@ -19350,6 +24548,14 @@ pub struct RTDRemoveFavoriteStickerBuilder {
 impl RTDRemoveFavoriteStickerBuilder {
   pub fn build(&self) -> RemoveFavoriteSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveFavoriteSticker> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("removeFavoriteSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -19397,7 +24603,9 @@ impl RObject for RemoveNotification {
 
 
 
-impl RFunction for RemoveNotification {}
+impl RFunction for RemoveNotification {
+  type Response = Ok;
+}
 
 impl RemoveNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19405,7 +24613,17 @@ impl RemoveNotification {
     let mut inner = RemoveNotification::default();
     inner.td_name = "removeNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveNotificationBuilder { inner }
+    RTDRemoveNotificationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.notification_group_id == 0 { missing.push("notification_group_id".to_string()); }
+    if self.notification_id == 0 { missing.push("notification_id".to_string()); }
+    missing
   }
 
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
@@ -19416,21 +24634,33 @@ impl RemoveNotification {
 
 #[doc(hidden)]
 pub struct RTDRemoveNotificationBuilder {
-  inner: RemoveNotification
+  inner: RemoveNotification,
+  set: [bool; 2]
 }
 
 impl RTDRemoveNotificationBuilder {
   pub fn build(&self) -> RemoveNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("notification_group_id".to_string()); }
+    if !self.set[1] { missing.push("notification_id".to_string()); }
+    crate::types::_common::check_missing("removeNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn notification_id(&mut self, notification_id: i64) -> &mut Self {
     self.inner.notification_id = notification_id;
+    self.set[1] = true;
     self
   }
 
@@ -19475,7 +24705,9 @@ impl RObject for RemoveNotificationGroup {
 
 
 
-impl RFunction for RemoveNotificationGroup {}
+impl RFunction for RemoveNotificationGroup {
+  type Response = Ok;
+}
 
 impl RemoveNotificationGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19483,7 +24715,17 @@ impl RemoveNotificationGroup {
     let mut inner = RemoveNotificationGroup::default();
     inner.td_name = "removeNotificationGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveNotificationGroupBuilder { inner }
+    RTDRemoveNotificationGroupBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeNotificationGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.notification_group_id == 0 { missing.push("notification_group_id".to_string()); }
+    if self.max_notification_id == 0 { missing.push("max_notification_id".to_string()); }
+    missing
   }
 
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
@@ -19494,21 +24736,33 @@ impl RemoveNotificationGroup {
 
 #[doc(hidden)]
 pub struct RTDRemoveNotificationGroupBuilder {
-  inner: RemoveNotificationGroup
+  inner: RemoveNotificationGroup,
+  set: [bool; 2]
 }
 
 impl RTDRemoveNotificationGroupBuilder {
   pub fn build(&self) -> RemoveNotificationGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveNotificationGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("notification_group_id".to_string()); }
+    if !self.set[1] { missing.push("max_notification_id".to_string()); }
+    crate::types::_common::check_missing("removeNotificationGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn max_notification_id(&mut self, max_notification_id: i64) -> &mut Self {
     self.inner.max_notification_id = max_notification_id;
+    self.set[1] = true;
     self
   }
 
@@ -19551,7 +24805,9 @@ impl RObject for RemoveProxy {
 
 
 
-impl RFunction for RemoveProxy {}
+impl RFunction for RemoveProxy {
+  type Response = Ok;
+}
 
 impl RemoveProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19559,7 +24815,16 @@ impl RemoveProxy {
     let mut inner = RemoveProxy::default();
     inner.td_name = "removeProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveProxyBuilder { inner }
+    RTDRemoveProxyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.proxy_id == 0 { missing.push("proxy_id".to_string()); }
+    missing
   }
 
   pub fn proxy_id(&self) -> i64 { self.proxy_id }
@@ -19568,15 +24833,25 @@ impl RemoveProxy {
 
 #[doc(hidden)]
 pub struct RTDRemoveProxyBuilder {
-  inner: RemoveProxy
+  inner: RemoveProxy,
+  set: [bool; 1]
 }
 
 impl RTDRemoveProxyBuilder {
   pub fn build(&self) -> RemoveProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("proxy_id".to_string()); }
+    crate::types::_common::check_missing("removeProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn proxy_id(&mut self, proxy_id: i64) -> &mut Self {
     self.inner.proxy_id = proxy_id;
+    self.set[0] = true;
     self
   }
 
@@ -19619,7 +24894,9 @@ impl RObject for RemoveRecentHashtag {
 
 
 
-impl RFunction for RemoveRecentHashtag {}
+impl RFunction for RemoveRecentHashtag {
+  type Response = Ok;
+}
 
 impl RemoveRecentHashtag {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19627,7 +24904,16 @@ impl RemoveRecentHashtag {
     let mut inner = RemoveRecentHashtag::default();
     inner.td_name = "removeRecentHashtag".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveRecentHashtagBuilder { inner }
+    RTDRemoveRecentHashtagBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeRecentHashtag", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.hashtag.is_empty() { missing.push("hashtag".to_string()); }
+    missing
   }
 
   pub fn hashtag(&self) -> &String { &self.hashtag }
@@ -19636,15 +24922,25 @@ impl RemoveRecentHashtag {
 
 #[doc(hidden)]
 pub struct RTDRemoveRecentHashtagBuilder {
-  inner: RemoveRecentHashtag
+  inner: RemoveRecentHashtag,
+  set: [bool; 1]
 }
 
 impl RTDRemoveRecentHashtagBuilder {
   pub fn build(&self) -> RemoveRecentHashtag { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveRecentHashtag> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("hashtag".to_string()); }
+    crate::types::_common::check_missing("removeRecentHashtag", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn hashtag<T: AsRef<str>>(&mut self, hashtag: T) -> &mut Self {
     self.inner.hashtag = hashtag.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -19689,7 +24985,9 @@ impl RObject for RemoveRecentSticker {
 
 
 
-impl RFunction for RemoveRecentSticker {}
+impl RFunction for RemoveRecentSticker {
+  type Response = Ok;
+}
 
 impl RemoveRecentSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19700,6 +24998,15 @@ impl RemoveRecentSticker {
     RTDRemoveRecentStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeRecentSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -19714,6 +25021,14 @@ pub struct RTDRemoveRecentStickerBuilder {
 impl RTDRemoveRecentStickerBuilder {
   pub fn build(&self) -> RemoveRecentSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveRecentSticker> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("removeRecentSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -19765,7 +25080,9 @@ impl RObject for RemoveRecentlyFoundChat {
 
 
 
-impl RFunction for RemoveRecentlyFoundChat {}
+impl RFunction for RemoveRecentlyFoundChat {
+  type Response = Ok;
+}
 
 impl RemoveRecentlyFoundChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19773,7 +25090,16 @@ impl RemoveRecentlyFoundChat {
     let mut inner = RemoveRecentlyFoundChat::default();
     inner.td_name = "removeRecentlyFoundChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveRecentlyFoundChatBuilder { inner }
+    RTDRemoveRecentlyFoundChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeRecentlyFoundChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -19782,15 +25108,25 @@ impl RemoveRecentlyFoundChat {
 
 #[doc(hidden)]
 pub struct RTDRemoveRecentlyFoundChatBuilder {
-  inner: RemoveRecentlyFoundChat
+  inner: RemoveRecentlyFoundChat,
+  set: [bool; 1]
 }
 
 impl RTDRemoveRecentlyFoundChatBuilder {
   pub fn build(&self) -> RemoveRecentlyFoundChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveRecentlyFoundChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("removeRecentlyFoundChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -19833,7 +25169,9 @@ impl RObject for RemoveSavedAnimation {
 
 
 
-impl RFunction for RemoveSavedAnimation {}
+impl RFunction for RemoveSavedAnimation {
+  type Response = Ok;
+}
 
 impl RemoveSavedAnimation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19844,6 +25182,15 @@ impl RemoveSavedAnimation {
     RTDRemoveSavedAnimationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeSavedAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    missing
+  }
+
   pub fn animation(&self) -> &InputFile { &self.animation }
 
 }
@@ -19856,6 +25203,14 @@ pub struct RTDRemoveSavedAnimationBuilder {
 impl RTDRemoveSavedAnimationBuilder {
   pub fn build(&self) -> RemoveSavedAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveSavedAnimation> {
+    let mut missing = vec![];
+    if self.inner.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    crate::types::_common::check_missing("removeSavedAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -19901,7 +25256,9 @@ impl RObject for RemoveStickerFromSet {
 
 
 
-impl RFunction for RemoveStickerFromSet {}
+impl RFunction for RemoveStickerFromSet {
+  type Response = Ok;
+}
 
 impl RemoveStickerFromSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19912,6 +25269,15 @@ impl RemoveStickerFromSet {
     RTDRemoveStickerFromSetBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeStickerFromSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
   pub fn sticker(&self) -> &InputFile { &self.sticker }
 
 }
@@ -19924,6 +25290,14 @@ pub struct RTDRemoveStickerFromSetBuilder {
 impl RTDRemoveStickerFromSetBuilder {
   pub fn build(&self) -> RemoveStickerFromSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveStickerFromSet> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("removeStickerFromSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -19971,7 +25345,9 @@ impl RObject for RemoveTopChat {
 
 
 
-impl RFunction for RemoveTopChat {}
+impl RFunction for RemoveTopChat {
+  type Response = Ok;
+}
 
 impl RemoveTopChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -19979,7 +25355,17 @@ impl RemoveTopChat {
     let mut inner = RemoveTopChat::default();
     inner.td_name = "removeTopChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRemoveTopChatBuilder { inner }
+    RTDRemoveTopChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("removeTopChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.category._is_default() { missing.push("category".to_string()); } else { missing.extend(crate::types::_common::missing_in("category", self.category._missing_fields())); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn category(&self) -> &TopChatCategory { &self.category }
@@ -19990,12 +25376,22 @@ impl RemoveTopChat {
 
 #[doc(hidden)]
 pub struct RTDRemoveTopChatBuilder {
-  inner: RemoveTopChat
+  inner: RemoveTopChat,
+  set: [bool; 1]
 }
 
 impl RTDRemoveTopChatBuilder {
   pub fn build(&self) -> RemoveTopChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoveTopChat> {
+    let mut missing = vec![];
+    if self.inner.category._is_default() { missing.push("category".to_string()); } else { missing.extend(crate::types::_common::missing_in("category", self.inner.category._missing_fields())); }
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("removeTopChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn category<T: AsRef<TopChatCategory>>(&mut self, category: T) -> &mut Self {
     self.inner.category = category.as_ref().clone();
@@ -20005,6 +25401,7 @@ impl RTDRemoveTopChatBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -20047,7 +25444,9 @@ impl RObject for ReorderChatFilters {
 
 
 
-impl RFunction for ReorderChatFilters {}
+impl RFunction for ReorderChatFilters {
+  type Response = Ok;
+}
 
 impl ReorderChatFilters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20058,6 +25457,11 @@ impl ReorderChatFilters {
     RTDReorderChatFiltersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("reorderChatFilters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_filter_ids(&self) -> &Vec<i64> { &self.chat_filter_ids }
 
 }
@@ -20070,6 +25474,9 @@ pub struct RTDReorderChatFiltersBuilder {
 impl RTDReorderChatFiltersBuilder {
   pub fn build(&self) -> ReorderChatFilters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReorderChatFilters> { Ok(self.build()) }
+
    
   pub fn chat_filter_ids(&mut self, chat_filter_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_filter_ids = chat_filter_ids;
@@ -20104,7 +25511,7 @@ pub struct ReorderInstalledStickerSets {
   /// Pass true to change the order of mask sticker sets; pass false to change the order of ordinary sticker sets
   is_masks: bool,
   /// Identifiers of installed sticker sets in the new correct order
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64")] sticker_set_ids: Vec<i64>,
   
 }
 
@@ -20117,7 +25524,9 @@ impl RObject for ReorderInstalledStickerSets {
 
 
 
-impl RFunction for ReorderInstalledStickerSets {}
+impl RFunction for ReorderInstalledStickerSets {
+  type Response = Ok;
+}
 
 impl ReorderInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20128,9 +25537,14 @@ impl ReorderInstalledStickerSets {
     RTDReorderInstalledStickerSetsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("reorderInstalledStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -20142,6 +25556,9 @@ pub struct RTDReorderInstalledStickerSetsBuilder {
 impl RTDReorderInstalledStickerSetsBuilder {
   pub fn build(&self) -> ReorderInstalledStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReorderInstalledStickerSets> { Ok(self.build()) }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -20149,7 +25566,7 @@ impl RTDReorderInstalledStickerSetsBuilder {
   }
 
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -20193,7 +25610,9 @@ impl RObject for ReplacePermanentChatInviteLink {
 
 
 
-impl RFunction for ReplacePermanentChatInviteLink {}
+impl RFunction for ReplacePermanentChatInviteLink {
+  type Response = ChatInviteLink;
+}
 
 impl ReplacePermanentChatInviteLink {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20201,7 +25620,16 @@ impl ReplacePermanentChatInviteLink {
     let mut inner = ReplacePermanentChatInviteLink::default();
     inner.td_name = "replacePermanentChatInviteLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDReplacePermanentChatInviteLinkBuilder { inner }
+    RTDReplacePermanentChatInviteLinkBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("replacePermanentChatInviteLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -20210,15 +25638,25 @@ impl ReplacePermanentChatInviteLink {
 
 #[doc(hidden)]
 pub struct RTDReplacePermanentChatInviteLinkBuilder {
-  inner: ReplacePermanentChatInviteLink
+  inner: ReplacePermanentChatInviteLink,
+  set: [bool; 1]
 }
 
 impl RTDReplacePermanentChatInviteLinkBuilder {
   pub fn build(&self) -> ReplacePermanentChatInviteLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReplacePermanentChatInviteLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("replacePermanentChatInviteLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -20265,7 +25703,9 @@ impl RObject for ReportChat {
 
 
 
-impl RFunction for ReportChat {}
+impl RFunction for ReportChat {
+  type Response = Ok;
+}
 
 impl ReportChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20273,7 +25713,17 @@ impl ReportChat {
     let mut inner = ReportChat::default();
     inner.td_name = "reportChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDReportChatBuilder { inner }
+    RTDReportChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("reportChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.reason._is_default() { missing.push("reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("reason", self.reason._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -20286,15 +25736,26 @@ impl ReportChat {
 
 #[doc(hidden)]
 pub struct RTDReportChatBuilder {
-  inner: ReportChat
+  inner: ReportChat,
+  set: [bool; 1]
 }
 
 impl RTDReportChatBuilder {
   pub fn build(&self) -> ReportChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReportChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.reason._is_default() { missing.push("reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("reason", self.inner.reason._missing_fields())); }
+    crate::types::_common::check_missing("reportChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -20353,7 +25814,9 @@ impl RObject for ReportSupergroupSpam {
 
 
 
-impl RFunction for ReportSupergroupSpam {}
+impl RFunction for ReportSupergroupSpam {
+  type Response = Ok;
+}
 
 impl ReportSupergroupSpam {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20361,7 +25824,17 @@ impl ReportSupergroupSpam {
     let mut inner = ReportSupergroupSpam::default();
     inner.td_name = "reportSupergroupSpam".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDReportSupergroupSpamBuilder { inner }
+    RTDReportSupergroupSpamBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("reportSupergroupSpam", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -20374,21 +25847,33 @@ impl ReportSupergroupSpam {
 
 #[doc(hidden)]
 pub struct RTDReportSupergroupSpamBuilder {
-  inner: ReportSupergroupSpam
+  inner: ReportSupergroupSpam,
+  set: [bool; 2]
 }
 
 impl RTDReportSupergroupSpamBuilder {
   pub fn build(&self) -> ReportSupergroupSpam { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReportSupergroupSpam> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("reportSupergroupSpam", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -20435,7 +25920,9 @@ impl RObject for RequestAuthenticationPasswordRecovery {
 
 
 
-impl RFunction for RequestAuthenticationPasswordRecovery {}
+impl RFunction for RequestAuthenticationPasswordRecovery {
+  type Response = Ok;
+}
 
 impl RequestAuthenticationPasswordRecovery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20446,6 +25933,11 @@ impl RequestAuthenticationPasswordRecovery {
     RTDRequestAuthenticationPasswordRecoveryBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("requestAuthenticationPasswordRecovery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20456,6 +25948,9 @@ pub struct RTDRequestAuthenticationPasswordRecoveryBuilder {
 impl RTDRequestAuthenticationPasswordRecoveryBuilder {
   pub fn build(&self) -> RequestAuthenticationPasswordRecovery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RequestAuthenticationPasswordRecovery> { Ok(self.build()) }
+
 }
 
 impl AsRef<RequestAuthenticationPasswordRecovery> for RequestAuthenticationPasswordRecovery {
@@ -20493,7 +25988,9 @@ impl RObject for RequestPasswordRecovery {
 
 
 
-impl RFunction for RequestPasswordRecovery {}
+impl RFunction for RequestPasswordRecovery {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl RequestPasswordRecovery {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20504,6 +26001,11 @@ impl RequestPasswordRecovery {
     RTDRequestPasswordRecoveryBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("requestPasswordRecovery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20514,6 +26016,9 @@ pub struct RTDRequestPasswordRecoveryBuilder {
 impl RTDRequestPasswordRecoveryBuilder {
   pub fn build(&self) -> RequestPasswordRecovery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RequestPasswordRecovery> { Ok(self.build()) }
+
 }
 
 impl AsRef<RequestPasswordRecovery> for RequestPasswordRecovery {
@@ -20553,7 +26058,9 @@ impl RObject for RequestQrCodeAuthentication {
 
 
 
-impl RFunction for RequestQrCodeAuthentication {}
+impl RFunction for RequestQrCodeAuthentication {
+  type Response = Ok;
+}
 
 impl RequestQrCodeAuthentication {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20564,6 +26071,11 @@ impl RequestQrCodeAuthentication {
     RTDRequestQrCodeAuthenticationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("requestQrCodeAuthentication", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn other_user_ids(&self) -> &Vec<i64> { &self.other_user_ids }
 
 }
@@ -20576,6 +26088,9 @@ pub struct RTDRequestQrCodeAuthenticationBuilder {
 impl RTDRequestQrCodeAuthenticationBuilder {
   pub fn build(&self) -> RequestQrCodeAuthentication { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RequestQrCodeAuthentication> { Ok(self.build()) }
+
    
   pub fn other_user_ids(&mut self, other_user_ids: Vec<i64>) -> &mut Self {
     self.inner.other_user_ids = other_user_ids;
@@ -20619,7 +26134,9 @@ impl RObject for ResendAuthenticationCode {
 
 
 
-impl RFunction for ResendAuthenticationCode {}
+impl RFunction for ResendAuthenticationCode {
+  type Response = Ok;
+}
 
 impl ResendAuthenticationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20630,6 +26147,11 @@ impl ResendAuthenticationCode {
     RTDResendAuthenticationCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendAuthenticationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20640,6 +26162,9 @@ pub struct RTDResendAuthenticationCodeBuilder {
 impl RTDResendAuthenticationCodeBuilder {
   pub fn build(&self) -> ResendAuthenticationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendAuthenticationCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendAuthenticationCode> for ResendAuthenticationCode {
@@ -20677,7 +26202,9 @@ impl RObject for ResendChangePhoneNumberCode {
 
 
 
-impl RFunction for ResendChangePhoneNumberCode {}
+impl RFunction for ResendChangePhoneNumberCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendChangePhoneNumberCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20688,6 +26215,11 @@ impl ResendChangePhoneNumberCode {
     RTDResendChangePhoneNumberCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendChangePhoneNumberCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20698,6 +26230,9 @@ pub struct RTDResendChangePhoneNumberCodeBuilder {
 impl RTDResendChangePhoneNumberCodeBuilder {
   pub fn build(&self) -> ResendChangePhoneNumberCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendChangePhoneNumberCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendChangePhoneNumberCode> for ResendChangePhoneNumberCode {
@@ -20735,7 +26270,9 @@ impl RObject for ResendEmailAddressVerificationCode {
 
 
 
-impl RFunction for ResendEmailAddressVerificationCode {}
+impl RFunction for ResendEmailAddressVerificationCode {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl ResendEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20746,6 +26283,11 @@ impl ResendEmailAddressVerificationCode {
     RTDResendEmailAddressVerificationCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendEmailAddressVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20756,6 +26298,9 @@ pub struct RTDResendEmailAddressVerificationCodeBuilder {
 impl RTDResendEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> ResendEmailAddressVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendEmailAddressVerificationCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendEmailAddressVerificationCode> for ResendEmailAddressVerificationCode {
@@ -20797,7 +26342,9 @@ impl RObject for ResendMessages {
 
 
 
-impl RFunction for ResendMessages {}
+impl RFunction for ResendMessages {
+  type Response = Messages;
+}
 
 impl ResendMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20805,7 +26352,16 @@ impl ResendMessages {
     let mut inner = ResendMessages::default();
     inner.td_name = "resendMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDResendMessagesBuilder { inner }
+    RTDResendMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -20816,15 +26372,25 @@ impl ResendMessages {
 
 #[doc(hidden)]
 pub struct RTDResendMessagesBuilder {
-  inner: ResendMessages
+  inner: ResendMessages,
+  set: [bool; 1]
 }
 
 impl RTDResendMessagesBuilder {
   pub fn build(&self) -> ResendMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("resendMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -20871,7 +26437,9 @@ impl RObject for ResendPhoneNumberConfirmationCode {
 
 
 
-impl RFunction for ResendPhoneNumberConfirmationCode {}
+impl RFunction for ResendPhoneNumberConfirmationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20882,6 +26450,11 @@ impl ResendPhoneNumberConfirmationCode {
     RTDResendPhoneNumberConfirmationCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendPhoneNumberConfirmationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20892,6 +26465,9 @@ pub struct RTDResendPhoneNumberConfirmationCodeBuilder {
 impl RTDResendPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> ResendPhoneNumberConfirmationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendPhoneNumberConfirmationCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendPhoneNumberConfirmationCode> for ResendPhoneNumberConfirmationCode {
@@ -20929,7 +26505,9 @@ impl RObject for ResendPhoneNumberVerificationCode {
 
 
 
-impl RFunction for ResendPhoneNumberVerificationCode {}
+impl RFunction for ResendPhoneNumberVerificationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl ResendPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20940,6 +26518,11 @@ impl ResendPhoneNumberVerificationCode {
     RTDResendPhoneNumberVerificationCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendPhoneNumberVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -20950,6 +26533,9 @@ pub struct RTDResendPhoneNumberVerificationCodeBuilder {
 impl RTDResendPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> ResendPhoneNumberVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendPhoneNumberVerificationCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendPhoneNumberVerificationCode> for ResendPhoneNumberVerificationCode {
@@ -20987,7 +26573,9 @@ impl RObject for ResendRecoveryEmailAddressCode {
 
 
 
-impl RFunction for ResendRecoveryEmailAddressCode {}
+impl RFunction for ResendRecoveryEmailAddressCode {
+  type Response = PasswordState;
+}
 
 impl ResendRecoveryEmailAddressCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -20998,6 +26586,11 @@ impl ResendRecoveryEmailAddressCode {
     RTDResendRecoveryEmailAddressCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resendRecoveryEmailAddressCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -21008,6 +26601,9 @@ pub struct RTDResendRecoveryEmailAddressCodeBuilder {
 impl RTDResendRecoveryEmailAddressCodeBuilder {
   pub fn build(&self) -> ResendRecoveryEmailAddressCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResendRecoveryEmailAddressCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResendRecoveryEmailAddressCode> for ResendRecoveryEmailAddressCode {
@@ -21045,7 +26641,9 @@ impl RObject for ResetAllNotificationSettings {
 
 
 
-impl RFunction for ResetAllNotificationSettings {}
+impl RFunction for ResetAllNotificationSettings {
+  type Response = Ok;
+}
 
 impl ResetAllNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21056,6 +26654,11 @@ impl ResetAllNotificationSettings {
     RTDResetAllNotificationSettingsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resetAllNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -21066,6 +26669,9 @@ pub struct RTDResetAllNotificationSettingsBuilder {
 impl RTDResetAllNotificationSettingsBuilder {
   pub fn build(&self) -> ResetAllNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResetAllNotificationSettings> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResetAllNotificationSettings> for ResetAllNotificationSettings {
@@ -21103,7 +26709,9 @@ impl RObject for ResetBackgrounds {
 
 
 
-impl RFunction for ResetBackgrounds {}
+impl RFunction for ResetBackgrounds {
+  type Response = Ok;
+}
 
 impl ResetBackgrounds {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21114,6 +26722,11 @@ impl ResetBackgrounds {
     RTDResetBackgroundsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resetBackgrounds", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -21124,6 +26737,9 @@ pub struct RTDResetBackgroundsBuilder {
 impl RTDResetBackgroundsBuilder {
   pub fn build(&self) -> ResetBackgrounds { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResetBackgrounds> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResetBackgrounds> for ResetBackgrounds {
@@ -21161,7 +26777,9 @@ impl RObject for ResetNetworkStatistics {
 
 
 
-impl RFunction for ResetNetworkStatistics {}
+impl RFunction for ResetNetworkStatistics {
+  type Response = Ok;
+}
 
 impl ResetNetworkStatistics {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21172,6 +26790,11 @@ impl ResetNetworkStatistics {
     RTDResetNetworkStatisticsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("resetNetworkStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -21182,6 +26805,9 @@ pub struct RTDResetNetworkStatisticsBuilder {
 impl RTDResetNetworkStatisticsBuilder {
   pub fn build(&self) -> ResetNetworkStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ResetNetworkStatistics> { Ok(self.build()) }
+
 }
 
 impl AsRef<ResetNetworkStatistics> for ResetNetworkStatistics {
@@ -21225,7 +26851,9 @@ impl RObject for SaveApplicationLogEvent {
 
 
 
-impl RFunction for SaveApplicationLogEvent {}
+impl RFunction for SaveApplicationLogEvent {
+  type Response = Ok;
+}
 
 impl SaveApplicationLogEvent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21233,7 +26861,18 @@ impl SaveApplicationLogEvent {
     let mut inner = SaveApplicationLogEvent::default();
     inner.td_name = "saveApplicationLogEvent".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSaveApplicationLogEventBuilder { inner }
+    RTDSaveApplicationLogEventBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("saveApplicationLogEvent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.data._is_default() { missing.push("data".to_string()); } else { missing.extend(crate::types::_common::missing_in("data", self.data._missing_fields())); }
+    missing
   }
 
   pub fn type_(&self) -> &String { &self.type_ }
@@ -21246,21 +26885,34 @@ impl SaveApplicationLogEvent {
 
 #[doc(hidden)]
 pub struct RTDSaveApplicationLogEventBuilder {
-  inner: SaveApplicationLogEvent
+  inner: SaveApplicationLogEvent,
+  set: [bool; 2]
 }
 
 impl RTDSaveApplicationLogEventBuilder {
   pub fn build(&self) -> SaveApplicationLogEvent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SaveApplicationLogEvent> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("type".to_string()); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if self.inner.data._is_default() { missing.push("data".to_string()); } else { missing.extend(crate::types::_common::missing_in("data", self.inner.data._missing_fields())); }
+    crate::types::_common::check_missing("saveApplicationLogEvent", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
@@ -21309,7 +26961,9 @@ impl RObject for SearchBackground {
 
 
 
-impl RFunction for SearchBackground {}
+impl RFunction for SearchBackground {
+  type Response = Background;
+}
 
 impl SearchBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21317,7 +26971,16 @@ impl SearchBackground {
     let mut inner = SearchBackground::default();
     inner.td_name = "searchBackground".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchBackgroundBuilder { inner }
+    RTDSearchBackgroundBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchBackground", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -21326,15 +26989,25 @@ impl SearchBackground {
 
 #[doc(hidden)]
 pub struct RTDSearchBackgroundBuilder {
-  inner: SearchBackground
+  inner: SearchBackground,
+  set: [bool; 1]
 }
 
 impl RTDSearchBackgroundBuilder {
   pub fn build(&self) -> SearchBackground { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchBackground> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("searchBackground", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -21381,7 +27054,9 @@ impl RObject for SearchCallMessages {
 
 
 
-impl RFunction for SearchCallMessages {}
+impl RFunction for SearchCallMessages {
+  type Response = Messages;
+}
 
 impl SearchCallMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21389,7 +27064,17 @@ impl SearchCallMessages {
     let mut inner = SearchCallMessages::default();
     inner.td_name = "searchCallMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchCallMessagesBuilder { inner }
+    RTDSearchCallMessagesBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchCallMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.from_message_id == 0 { missing.push("from_message_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn from_message_id(&self) -> i64 { self.from_message_id }
@@ -21402,21 +27087,33 @@ impl SearchCallMessages {
 
 #[doc(hidden)]
 pub struct RTDSearchCallMessagesBuilder {
-  inner: SearchCallMessages
+  inner: SearchCallMessages,
+  set: [bool; 2]
 }
 
 impl RTDSearchCallMessagesBuilder {
   pub fn build(&self) -> SearchCallMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchCallMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("from_message_id".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchCallMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
     self.inner.from_message_id = from_message_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -21471,7 +27168,9 @@ impl RObject for SearchChatMembers {
 
 
 
-impl RFunction for SearchChatMembers {}
+impl RFunction for SearchChatMembers {
+  type Response = ChatMembers;
+}
 
 impl SearchChatMembers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21479,7 +27178,19 @@ impl SearchChatMembers {
     let mut inner = SearchChatMembers::default();
     inner.td_name = "searchChatMembers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatMembersBuilder { inner }
+    RTDSearchChatMembersBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChatMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -21494,27 +27205,42 @@ impl SearchChatMembers {
 
 #[doc(hidden)]
 pub struct RTDSearchChatMembersBuilder {
-  inner: SearchChatMembers
+  inner: SearchChatMembers,
+  set: [bool; 3]
 }
 
 impl RTDSearchChatMembersBuilder {
   pub fn build(&self) -> SearchChatMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChatMembers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("query".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("searchChatMembers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -21577,7 +27303,9 @@ impl RObject for SearchChatMessages {
 
 
 
-impl RFunction for SearchChatMessages {}
+impl RFunction for SearchChatMessages {
+  type Response = Messages;
+}
 
 impl SearchChatMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21585,7 +27313,22 @@ impl SearchChatMessages {
     let mut inner = SearchChatMessages::default();
     inner.td_name = "searchChatMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatMessagesBuilder { inner }
+    RTDSearchChatMessagesBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChatMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.sender._missing_fields())); }
+    if self.from_message_id == 0 { missing.push("from_message_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -21608,21 +27351,38 @@ impl SearchChatMessages {
 
 #[doc(hidden)]
 pub struct RTDSearchChatMessagesBuilder {
-  inner: SearchChatMessages
+  inner: SearchChatMessages,
+  set: [bool; 5]
 }
 
 impl RTDSearchChatMessagesBuilder {
   pub fn build(&self) -> SearchChatMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChatMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("query".to_string()); }
+    if self.inner.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.inner.sender._missing_fields())); }
+    if !self.set[2] { missing.push("from_message_id".to_string()); }
+    if !self.set[3] { missing.push("offset".to_string()); }
+    if !self.set[4] { missing.push("limit".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("searchChatMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -21635,18 +27395,21 @@ impl RTDSearchChatMessagesBuilder {
    
   pub fn from_message_id(&mut self, from_message_id: i64) -> &mut Self {
     self.inner.from_message_id = from_message_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[3] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[4] = true;
     self
   }
 
@@ -21703,7 +27466,9 @@ impl RObject for SearchChatRecentLocationMessages {
 
 
 
-impl RFunction for SearchChatRecentLocationMessages {}
+impl RFunction for SearchChatRecentLocationMessages {
+  type Response = Messages;
+}
 
 impl SearchChatRecentLocationMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21711,7 +27476,17 @@ impl SearchChatRecentLocationMessages {
     let mut inner = SearchChatRecentLocationMessages::default();
     inner.td_name = "searchChatRecentLocationMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatRecentLocationMessagesBuilder { inner }
+    RTDSearchChatRecentLocationMessagesBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChatRecentLocationMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -21722,21 +27497,33 @@ impl SearchChatRecentLocationMessages {
 
 #[doc(hidden)]
 pub struct RTDSearchChatRecentLocationMessagesBuilder {
-  inner: SearchChatRecentLocationMessages
+  inner: SearchChatRecentLocationMessages,
+  set: [bool; 2]
 }
 
 impl RTDSearchChatRecentLocationMessagesBuilder {
   pub fn build(&self) -> SearchChatRecentLocationMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChatRecentLocationMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchChatRecentLocationMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -21781,7 +27568,9 @@ impl RObject for SearchChats {
 
 
 
-impl RFunction for SearchChats {}
+impl RFunction for SearchChats {
+  type Response = Chats;
+}
 
 impl SearchChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21789,7 +27578,17 @@ impl SearchChats {
     let mut inner = SearchChats::default();
     inner.td_name = "searchChats".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatsBuilder { inner }
+    RTDSearchChatsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -21800,21 +27599,33 @@ impl SearchChats {
 
 #[doc(hidden)]
 pub struct RTDSearchChatsBuilder {
-  inner: SearchChats
+  inner: SearchChats,
+  set: [bool; 2]
 }
 
 impl RTDSearchChatsBuilder {
   pub fn build(&self) -> SearchChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChats> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -21857,7 +27668,9 @@ impl RObject for SearchChatsNearby {
 
 
 
-impl RFunction for SearchChatsNearby {}
+impl RFunction for SearchChatsNearby {
+  type Response = ChatsNearby;
+}
 
 impl SearchChatsNearby {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21865,7 +27678,16 @@ impl SearchChatsNearby {
     let mut inner = SearchChatsNearby::default();
     inner.td_name = "searchChatsNearby".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatsNearbyBuilder { inner }
+    RTDSearchChatsNearbyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChatsNearby", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -21874,15 +27696,25 @@ impl SearchChatsNearby {
 
 #[doc(hidden)]
 pub struct RTDSearchChatsNearbyBuilder {
-  inner: SearchChatsNearby
+  inner: SearchChatsNearby,
+  set: [bool; 1]
 }
 
 impl RTDSearchChatsNearbyBuilder {
   pub fn build(&self) -> SearchChatsNearby { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChatsNearby> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    crate::types::_common::check_missing("searchChatsNearby", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -21927,7 +27759,9 @@ impl RObject for SearchChatsOnServer {
 
 
 
-impl RFunction for SearchChatsOnServer {}
+impl RFunction for SearchChatsOnServer {
+  type Response = Chats;
+}
 
 impl SearchChatsOnServer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -21935,7 +27769,17 @@ impl SearchChatsOnServer {
     let mut inner = SearchChatsOnServer::default();
     inner.td_name = "searchChatsOnServer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchChatsOnServerBuilder { inner }
+    RTDSearchChatsOnServerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchChatsOnServer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -21946,21 +27790,33 @@ impl SearchChatsOnServer {
 
 #[doc(hidden)]
 pub struct RTDSearchChatsOnServerBuilder {
-  inner: SearchChatsOnServer
+  inner: SearchChatsOnServer,
+  set: [bool; 2]
 }
 
 impl RTDSearchChatsOnServerBuilder {
   pub fn build(&self) -> SearchChatsOnServer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchChatsOnServer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchChatsOnServer", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -22005,7 +27861,9 @@ impl RObject for SearchContacts {
 
 
 
-impl RFunction for SearchContacts {}
+impl RFunction for SearchContacts {
+  type Response = Users;
+}
 
 impl SearchContacts {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22013,7 +27871,16 @@ impl SearchContacts {
     let mut inner = SearchContacts::default();
     inner.td_name = "searchContacts".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchContactsBuilder { inner }
+    RTDSearchContactsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -22024,12 +27891,21 @@ impl SearchContacts {
 
 #[doc(hidden)]
 pub struct RTDSearchContactsBuilder {
-  inner: SearchContacts
+  inner: SearchContacts,
+  set: [bool; 1]
 }
 
 impl RTDSearchContactsBuilder {
   pub fn build(&self) -> SearchContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchContacts> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchContacts", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
@@ -22039,6 +27915,7 @@ impl RTDSearchContactsBuilder {
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[0] = true;
     self
   }
 
@@ -22085,7 +27962,9 @@ impl RObject for SearchEmojis {
 
 
 
-impl RFunction for SearchEmojis {}
+impl RFunction for SearchEmojis {
+  type Response = Emojis;
+}
 
 impl SearchEmojis {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22093,7 +27972,16 @@ impl SearchEmojis {
     let mut inner = SearchEmojis::default();
     inner.td_name = "searchEmojis".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchEmojisBuilder { inner }
+    RTDSearchEmojisBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchEmojis", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -22106,15 +27994,25 @@ impl SearchEmojis {
 
 #[doc(hidden)]
 pub struct RTDSearchEmojisBuilder {
-  inner: SearchEmojis
+  inner: SearchEmojis,
+  set: [bool; 1]
 }
 
 impl RTDSearchEmojisBuilder {
   pub fn build(&self) -> SearchEmojis { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchEmojis> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("searchEmojis", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -22171,7 +28069,9 @@ impl RObject for SearchHashtags {
 
 
 
-impl RFunction for SearchHashtags {}
+impl RFunction for SearchHashtags {
+  type Response = Hashtags;
+}
 
 impl SearchHashtags {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22179,7 +28079,17 @@ impl SearchHashtags {
     let mut inner = SearchHashtags::default();
     inner.td_name = "searchHashtags".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchHashtagsBuilder { inner }
+    RTDSearchHashtagsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchHashtags", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.prefix.is_empty() { missing.push("prefix".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn prefix(&self) -> &String { &self.prefix }
@@ -22190,21 +28100,33 @@ impl SearchHashtags {
 
 #[doc(hidden)]
 pub struct RTDSearchHashtagsBuilder {
-  inner: SearchHashtags
+  inner: SearchHashtags,
+  set: [bool; 2]
 }
 
 impl RTDSearchHashtagsBuilder {
   pub fn build(&self) -> SearchHashtags { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchHashtags> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("prefix".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchHashtags", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn prefix<T: AsRef<str>>(&mut self, prefix: T) -> &mut Self {
     self.inner.prefix = prefix.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -22251,7 +28173,9 @@ impl RObject for SearchInstalledStickerSets {
 
 
 
-impl RFunction for SearchInstalledStickerSets {}
+impl RFunction for SearchInstalledStickerSets {
+  type Response = StickerSets;
+}
 
 impl SearchInstalledStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22259,7 +28183,17 @@ impl SearchInstalledStickerSets {
     let mut inner = SearchInstalledStickerSets::default();
     inner.td_name = "searchInstalledStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchInstalledStickerSetsBuilder { inner }
+    RTDSearchInstalledStickerSetsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchInstalledStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn is_masks(&self) -> bool { self.is_masks }
@@ -22272,12 +28206,22 @@ impl SearchInstalledStickerSets {
 
 #[doc(hidden)]
 pub struct RTDSearchInstalledStickerSetsBuilder {
-  inner: SearchInstalledStickerSets
+  inner: SearchInstalledStickerSets,
+  set: [bool; 2]
 }
 
 impl RTDSearchInstalledStickerSetsBuilder {
   pub fn build(&self) -> SearchInstalledStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchInstalledStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchInstalledStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -22287,12 +28231,14 @@ impl RTDSearchInstalledStickerSetsBuilder {
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -22351,7 +28297,9 @@ impl RObject for SearchMessages {
 
 
 
-impl RFunction for SearchMessages {}
+impl RFunction for SearchMessages {
+  type Response = Messages;
+}
 
 impl SearchMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22359,7 +28307,20 @@ impl SearchMessages {
     let mut inner = SearchMessages::default();
     inner.td_name = "searchMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchMessagesBuilder { inner }
+    RTDSearchMessagesBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.offset_date == 0 { missing.push("offset_date".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
@@ -22384,12 +28345,25 @@ impl SearchMessages {
 
 #[doc(hidden)]
 pub struct RTDSearchMessagesBuilder {
-  inner: SearchMessages
+  inner: SearchMessages,
+  set: [bool; 3]
 }
 
 impl RTDSearchMessagesBuilder {
   pub fn build(&self) -> SearchMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessages> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    if !self.set[0] { missing.push("query".to_string()); }
+    if !self.set[1] { missing.push("offset_date".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("searchMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -22399,12 +28373,14 @@ impl RTDSearchMessagesBuilder {
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset_date(&mut self, offset_date: i64) -> &mut Self {
     self.inner.offset_date = offset_date;
+    self.set[1] = true;
     self
   }
 
@@ -22423,6 +28399,7 @@ impl RTDSearchMessagesBuilder {
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -22483,7 +28460,9 @@ impl RObject for SearchPublicChat {
 
 
 
-impl RFunction for SearchPublicChat {}
+impl RFunction for SearchPublicChat {
+  type Response = Chat;
+}
 
 impl SearchPublicChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22491,7 +28470,16 @@ impl SearchPublicChat {
     let mut inner = SearchPublicChat::default();
     inner.td_name = "searchPublicChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchPublicChatBuilder { inner }
+    RTDSearchPublicChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchPublicChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    missing
   }
 
   pub fn username(&self) -> &String { &self.username }
@@ -22500,15 +28488,25 @@ impl SearchPublicChat {
 
 #[doc(hidden)]
 pub struct RTDSearchPublicChatBuilder {
-  inner: SearchPublicChat
+  inner: SearchPublicChat,
+  set: [bool; 1]
 }
 
 impl RTDSearchPublicChatBuilder {
   pub fn build(&self) -> SearchPublicChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchPublicChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("username".to_string()); }
+    crate::types::_common::check_missing("searchPublicChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -22551,7 +28549,9 @@ impl RObject for SearchPublicChats {
 
 
 
-impl RFunction for SearchPublicChats {}
+impl RFunction for SearchPublicChats {
+  type Response = Chats;
+}
 
 impl SearchPublicChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22559,7 +28559,16 @@ impl SearchPublicChats {
     let mut inner = SearchPublicChats::default();
     inner.td_name = "searchPublicChats".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchPublicChatsBuilder { inner }
+    RTDSearchPublicChatsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchPublicChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -22568,15 +28577,25 @@ impl SearchPublicChats {
 
 #[doc(hidden)]
 pub struct RTDSearchPublicChatsBuilder {
-  inner: SearchPublicChats
+  inner: SearchPublicChats,
+  set: [bool; 1]
 }
 
 impl RTDSearchPublicChatsBuilder {
   pub fn build(&self) -> SearchPublicChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchPublicChats> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("searchPublicChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -22627,7 +28646,9 @@ impl RObject for SearchSecretMessages {
 
 
 
-impl RFunction for SearchSecretMessages {}
+impl RFunction for SearchSecretMessages {
+  type Response = FoundMessages;
+}
 
 impl SearchSecretMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22635,7 +28656,19 @@ impl SearchSecretMessages {
     let mut inner = SearchSecretMessages::default();
     inner.td_name = "searchSecretMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchSecretMessagesBuilder { inner }
+    RTDSearchSecretMessagesBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchSecretMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.offset.is_empty() { missing.push("offset".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    if self.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -22652,15 +28685,28 @@ impl SearchSecretMessages {
 
 #[doc(hidden)]
 pub struct RTDSearchSecretMessagesBuilder {
-  inner: SearchSecretMessages
+  inner: SearchSecretMessages,
+  set: [bool; 3]
 }
 
 impl RTDSearchSecretMessagesBuilder {
   pub fn build(&self) -> SearchSecretMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchSecretMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    if !self.set[2] { missing.push("limit".to_string()); }
+    if self.inner.filter._is_default() { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    crate::types::_common::check_missing("searchSecretMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -22673,12 +28719,14 @@ impl RTDSearchSecretMessagesBuilder {
    
   pub fn offset<T: AsRef<str>>(&mut self, offset: T) -> &mut Self {
     self.inner.offset = offset.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[2] = true;
     self
   }
 
@@ -22727,7 +28775,9 @@ impl RObject for SearchStickerSet {
 
 
 
-impl RFunction for SearchStickerSet {}
+impl RFunction for SearchStickerSet {
+  type Response = StickerSet;
+}
 
 impl SearchStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22735,7 +28785,16 @@ impl SearchStickerSet {
     let mut inner = SearchStickerSet::default();
     inner.td_name = "searchStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchStickerSetBuilder { inner }
+    RTDSearchStickerSetBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -22744,15 +28803,25 @@ impl SearchStickerSet {
 
 #[doc(hidden)]
 pub struct RTDSearchStickerSetBuilder {
-  inner: SearchStickerSet
+  inner: SearchStickerSet,
+  set: [bool; 1]
 }
 
 impl RTDSearchStickerSetBuilder {
   pub fn build(&self) -> SearchStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("searchStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -22795,7 +28864,9 @@ impl RObject for SearchStickerSets {
 
 
 
-impl RFunction for SearchStickerSets {}
+impl RFunction for SearchStickerSets {
+  type Response = StickerSets;
+}
 
 impl SearchStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22803,7 +28874,16 @@ impl SearchStickerSets {
     let mut inner = SearchStickerSets::default();
     inner.td_name = "searchStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchStickerSetsBuilder { inner }
+    RTDSearchStickerSetsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -22812,15 +28892,25 @@ impl SearchStickerSets {
 
 #[doc(hidden)]
 pub struct RTDSearchStickerSetsBuilder {
-  inner: SearchStickerSets
+  inner: SearchStickerSets,
+  set: [bool; 1]
 }
 
 impl RTDSearchStickerSetsBuilder {
   pub fn build(&self) -> SearchStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("searchStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -22865,7 +28955,9 @@ impl RObject for SearchStickers {
 
 
 
-impl RFunction for SearchStickers {}
+impl RFunction for SearchStickers {
+  type Response = Stickers;
+}
 
 impl SearchStickers {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22873,7 +28965,17 @@ impl SearchStickers {
     let mut inner = SearchStickers::default();
     inner.td_name = "searchStickers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSearchStickersBuilder { inner }
+    RTDSearchStickersBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.emoji.is_empty() { missing.push("emoji".to_string()); }
+    if self.limit == 0 { missing.push("limit".to_string()); }
+    missing
   }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -22884,21 +28986,33 @@ impl SearchStickers {
 
 #[doc(hidden)]
 pub struct RTDSearchStickersBuilder {
-  inner: SearchStickers
+  inner: SearchStickers,
+  set: [bool; 2]
 }
 
 impl RTDSearchStickersBuilder {
   pub fn build(&self) -> SearchStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchStickers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("emoji".to_string()); }
+    if !self.set[1] { missing.push("limit".to_string()); }
+    crate::types::_common::check_missing("searchStickers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn limit(&mut self, limit: i64) -> &mut Self {
     self.inner.limit = limit;
+    self.set[1] = true;
     self
   }
 
@@ -22945,7 +29059,9 @@ impl RObject for SendBotStartMessage {
 
 
 
-impl RFunction for SendBotStartMessage {}
+impl RFunction for SendBotStartMessage {
+  type Response = Message;
+}
 
 impl SendBotStartMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -22953,7 +29069,18 @@ impl SendBotStartMessage {
     let mut inner = SendBotStartMessage::default();
     inner.td_name = "sendBotStartMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendBotStartMessageBuilder { inner }
+    RTDSendBotStartMessageBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendBotStartMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.parameter.is_empty() { missing.push("parameter".to_string()); }
+    missing
   }
 
   pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
@@ -22966,27 +29093,41 @@ impl SendBotStartMessage {
 
 #[doc(hidden)]
 pub struct RTDSendBotStartMessageBuilder {
-  inner: SendBotStartMessage
+  inner: SendBotStartMessage,
+  set: [bool; 3]
 }
 
 impl RTDSendBotStartMessageBuilder {
   pub fn build(&self) -> SendBotStartMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendBotStartMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bot_user_id".to_string()); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if !self.set[2] { missing.push("parameter".to_string()); }
+    crate::types::_common::check_missing("sendBotStartMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn parameter<T: AsRef<str>>(&mut self, parameter: T) -> &mut Self {
     self.inner.parameter = parameter.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -23031,7 +29172,9 @@ impl RObject for SendCallDebugInformation {
 
 
 
-impl RFunction for SendCallDebugInformation {}
+impl RFunction for SendCallDebugInformation {
+  type Response = Ok;
+}
 
 impl SendCallDebugInformation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23039,7 +29182,17 @@ impl SendCallDebugInformation {
     let mut inner = SendCallDebugInformation::default();
     inner.td_name = "sendCallDebugInformation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendCallDebugInformationBuilder { inner }
+    RTDSendCallDebugInformationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendCallDebugInformation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if self.debug_information.is_empty() { missing.push("debug_information".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -23050,21 +29203,33 @@ impl SendCallDebugInformation {
 
 #[doc(hidden)]
 pub struct RTDSendCallDebugInformationBuilder {
-  inner: SendCallDebugInformation
+  inner: SendCallDebugInformation,
+  set: [bool; 2]
 }
 
 impl RTDSendCallDebugInformationBuilder {
   pub fn build(&self) -> SendCallDebugInformation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendCallDebugInformation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("debug_information".to_string()); }
+    crate::types::_common::check_missing("sendCallDebugInformation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn debug_information<T: AsRef<str>>(&mut self, debug_information: T) -> &mut Self {
     self.inner.debug_information = debug_information.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -23113,7 +29278,9 @@ impl RObject for SendCallRating {
 
 
 
-impl RFunction for SendCallRating {}
+impl RFunction for SendCallRating {
+  type Response = Ok;
+}
 
 impl SendCallRating {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23121,7 +29288,18 @@ impl SendCallRating {
     let mut inner = SendCallRating::default();
     inner.td_name = "sendCallRating".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendCallRatingBuilder { inner }
+    RTDSendCallRatingBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendCallRating", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if self.rating == 0 { missing.push("rating".to_string()); }
+    if self.comment.is_empty() { missing.push("comment".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -23136,27 +29314,41 @@ impl SendCallRating {
 
 #[doc(hidden)]
 pub struct RTDSendCallRatingBuilder {
-  inner: SendCallRating
+  inner: SendCallRating,
+  set: [bool; 3]
 }
 
 impl RTDSendCallRatingBuilder {
   pub fn build(&self) -> SendCallRating { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendCallRating> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("rating".to_string()); }
+    if !self.set[2] { missing.push("comment".to_string()); }
+    crate::types::_common::check_missing("sendCallRating", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn rating(&mut self, rating: i64) -> &mut Self {
     self.inner.rating = rating;
+    self.set[1] = true;
     self
   }
 
    
   pub fn comment<T: AsRef<str>>(&mut self, comment: T) -> &mut Self {
     self.inner.comment = comment.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -23207,7 +29399,9 @@ impl RObject for SendCallSignalingData {
 
 
 
-impl RFunction for SendCallSignalingData {}
+impl RFunction for SendCallSignalingData {
+  type Response = Ok;
+}
 
 impl SendCallSignalingData {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23215,7 +29409,17 @@ impl SendCallSignalingData {
     let mut inner = SendCallSignalingData::default();
     inner.td_name = "sendCallSignalingData".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendCallSignalingDataBuilder { inner }
+    RTDSendCallSignalingDataBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendCallSignalingData", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -23226,21 +29430,33 @@ impl SendCallSignalingData {
 
 #[doc(hidden)]
 pub struct RTDSendCallSignalingDataBuilder {
-  inner: SendCallSignalingData
+  inner: SendCallSignalingData,
+  set: [bool; 2]
 }
 
 impl RTDSendCallSignalingDataBuilder {
   pub fn build(&self) -> SendCallSignalingData { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendCallSignalingData> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("sendCallSignalingData", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -23287,7 +29503,9 @@ impl RObject for SendChatAction {
 
 
 
-impl RFunction for SendChatAction {}
+impl RFunction for SendChatAction {
+  type Response = Ok;
+}
 
 impl SendChatAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23295,7 +29513,17 @@ impl SendChatAction {
     let mut inner = SendChatAction::default();
     inner.td_name = "sendChatAction".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendChatActionBuilder { inner }
+    RTDSendChatActionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendChatAction", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.action._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23308,15 +29536,26 @@ impl SendChatAction {
 
 #[doc(hidden)]
 pub struct RTDSendChatActionBuilder {
-  inner: SendChatAction
+  inner: SendChatAction,
+  set: [bool; 1]
 }
 
 impl RTDSendChatActionBuilder {
   pub fn build(&self) -> SendChatAction { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendChatAction> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.inner.action._missing_fields())); }
+    crate::types::_common::check_missing("sendChatAction", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -23371,7 +29610,9 @@ impl RObject for SendChatScreenshotTakenNotification {
 
 
 
-impl RFunction for SendChatScreenshotTakenNotification {}
+impl RFunction for SendChatScreenshotTakenNotification {
+  type Response = Ok;
+}
 
 impl SendChatScreenshotTakenNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23379,7 +29620,16 @@ impl SendChatScreenshotTakenNotification {
     let mut inner = SendChatScreenshotTakenNotification::default();
     inner.td_name = "sendChatScreenshotTakenNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendChatScreenshotTakenNotificationBuilder { inner }
+    RTDSendChatScreenshotTakenNotificationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendChatScreenshotTakenNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23388,15 +29638,25 @@ impl SendChatScreenshotTakenNotification {
 
 #[doc(hidden)]
 pub struct RTDSendChatScreenshotTakenNotificationBuilder {
-  inner: SendChatScreenshotTakenNotification
+  inner: SendChatScreenshotTakenNotification,
+  set: [bool; 1]
 }
 
 impl RTDSendChatScreenshotTakenNotificationBuilder {
   pub fn build(&self) -> SendChatScreenshotTakenNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendChatScreenshotTakenNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("sendChatScreenshotTakenNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -23441,7 +29701,9 @@ impl RObject for SendChatSetTtlMessage {
 
 
 
-impl RFunction for SendChatSetTtlMessage {}
+impl RFunction for SendChatSetTtlMessage {
+  type Response = Message;
+}
 
 impl SendChatSetTtlMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23449,7 +29711,17 @@ impl SendChatSetTtlMessage {
     let mut inner = SendChatSetTtlMessage::default();
     inner.td_name = "sendChatSetTtlMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendChatSetTtlMessageBuilder { inner }
+    RTDSendChatSetTtlMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendChatSetTtlMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23460,21 +29732,33 @@ impl SendChatSetTtlMessage {
 
 #[doc(hidden)]
 pub struct RTDSendChatSetTtlMessageBuilder {
-  inner: SendChatSetTtlMessage
+  inner: SendChatSetTtlMessage,
+  set: [bool; 2]
 }
 
 impl RTDSendChatSetTtlMessageBuilder {
   pub fn build(&self) -> SendChatSetTtlMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendChatSetTtlMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("ttl".to_string()); }
+    crate::types::_common::check_missing("sendChatSetTtlMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[1] = true;
     self
   }
 
@@ -23519,7 +29803,9 @@ impl RObject for SendCustomRequest {
 
 
 
-impl RFunction for SendCustomRequest {}
+impl RFunction for SendCustomRequest {
+  type Response = CustomRequestResult;
+}
 
 impl SendCustomRequest {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23527,7 +29813,17 @@ impl SendCustomRequest {
     let mut inner = SendCustomRequest::default();
     inner.td_name = "sendCustomRequest".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendCustomRequestBuilder { inner }
+    RTDSendCustomRequestBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendCustomRequest", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.method.is_empty() { missing.push("method".to_string()); }
+    if self.parameters.is_empty() { missing.push("parameters".to_string()); }
+    missing
   }
 
   pub fn method(&self) -> &String { &self.method }
@@ -23538,21 +29834,33 @@ impl SendCustomRequest {
 
 #[doc(hidden)]
 pub struct RTDSendCustomRequestBuilder {
-  inner: SendCustomRequest
+  inner: SendCustomRequest,
+  set: [bool; 2]
 }
 
 impl RTDSendCustomRequestBuilder {
   pub fn build(&self) -> SendCustomRequest { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendCustomRequest> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("method".to_string()); }
+    if !self.set[1] { missing.push("parameters".to_string()); }
+    crate::types::_common::check_missing("sendCustomRequest", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn method<T: AsRef<str>>(&mut self, method: T) -> &mut Self {
     self.inner.method = method.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn parameters<T: AsRef<str>>(&mut self, parameters: T) -> &mut Self {
     self.inner.parameters = parameters.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -23595,7 +29903,9 @@ impl RObject for SendEmailAddressVerificationCode {
 
 
 
-impl RFunction for SendEmailAddressVerificationCode {}
+impl RFunction for SendEmailAddressVerificationCode {
+  type Response = EmailAddressAuthenticationCodeInfo;
+}
 
 impl SendEmailAddressVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23603,7 +29913,16 @@ impl SendEmailAddressVerificationCode {
     let mut inner = SendEmailAddressVerificationCode::default();
     inner.td_name = "sendEmailAddressVerificationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendEmailAddressVerificationCodeBuilder { inner }
+    RTDSendEmailAddressVerificationCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendEmailAddressVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.email_address.is_empty() { missing.push("email_address".to_string()); }
+    missing
   }
 
   pub fn email_address(&self) -> &String { &self.email_address }
@@ -23612,15 +29931,25 @@ impl SendEmailAddressVerificationCode {
 
 #[doc(hidden)]
 pub struct RTDSendEmailAddressVerificationCodeBuilder {
-  inner: SendEmailAddressVerificationCode
+  inner: SendEmailAddressVerificationCode,
+  set: [bool; 1]
 }
 
 impl RTDSendEmailAddressVerificationCodeBuilder {
   pub fn build(&self) -> SendEmailAddressVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendEmailAddressVerificationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("email_address".to_string()); }
+    crate::types::_common::check_missing("sendEmailAddressVerificationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -23658,7 +29987,7 @@ pub struct SendInlineQueryResultMessage {
   /// Options to be used to send the message
   options: MessageSendOptions,
   /// Identifier of the inline query
-  query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] query_id: i64,
   /// Identifier of the inline result
   result_id: String,
   /// If true, there will be no mention of a bot, via which the message is sent. Can be used only for bots GetOption("animation_search_bot_username"), GetOption("photo_search_bot_username") and GetOption("venue_search_bot_username")
@@ -23675,7 +30004,9 @@ impl RObject for SendInlineQueryResultMessage {
 
 
 
-impl RFunction for SendInlineQueryResultMessage {}
+impl RFunction for SendInlineQueryResultMessage {
+  type Response = Message;
+}
 
 impl SendInlineQueryResultMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23683,7 +30014,18 @@ impl SendInlineQueryResultMessage {
     let mut inner = SendInlineQueryResultMessage::default();
     inner.td_name = "sendInlineQueryResultMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendInlineQueryResultMessageBuilder { inner }
+    RTDSendInlineQueryResultMessageBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendInlineQueryResultMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.query_id == 0 { missing.push("query_id".to_string()); }
+    if self.result_id.is_empty() { missing.push("result_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23694,7 +30036,7 @@ impl SendInlineQueryResultMessage {
 
   pub fn options(&self) -> &MessageSendOptions { &self.options }
 
-  pub fn query_id(&self) -> isize { self.query_id }
+  pub fn query_id(&self) -> i64 { self.query_id }
 
   pub fn result_id(&self) -> &String { &self.result_id }
 
@@ -23704,15 +30046,27 @@ impl SendInlineQueryResultMessage {
 
 #[doc(hidden)]
 pub struct RTDSendInlineQueryResultMessageBuilder {
-  inner: SendInlineQueryResultMessage
+  inner: SendInlineQueryResultMessage,
+  set: [bool; 3]
 }
 
 impl RTDSendInlineQueryResultMessageBuilder {
   pub fn build(&self) -> SendInlineQueryResultMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendInlineQueryResultMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("query_id".to_string()); }
+    if !self.set[2] { missing.push("result_id".to_string()); }
+    crate::types::_common::check_missing("sendInlineQueryResultMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -23735,14 +30089,16 @@ impl RTDSendInlineQueryResultMessageBuilder {
   }
 
    
-  pub fn query_id(&mut self, query_id: isize) -> &mut Self {
+  pub fn query_id(&mut self, query_id: i64) -> &mut Self {
     self.inner.query_id = query_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn result_id<T: AsRef<str>>(&mut self, result_id: T) -> &mut Self {
     self.inner.result_id = result_id.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -23801,7 +30157,9 @@ impl RObject for SendMessage {
 
 
 
-impl RFunction for SendMessage {}
+impl RFunction for SendMessage {
+  type Response = Message;
+}
 
 impl SendMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23809,7 +30167,17 @@ impl SendMessage {
     let mut inner = SendMessage::default();
     inner.td_name = "sendMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendMessageBuilder { inner }
+    RTDSendMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23828,15 +30196,26 @@ impl SendMessage {
 
 #[doc(hidden)]
 pub struct RTDSendMessageBuilder {
-  inner: SendMessage
+  inner: SendMessage,
+  set: [bool; 1]
 }
 
 impl RTDSendMessageBuilder {
   pub fn build(&self) -> SendMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("sendMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -23917,7 +30296,9 @@ impl RObject for SendMessageAlbum {
 
 
 
-impl RFunction for SendMessageAlbum {}
+impl RFunction for SendMessageAlbum {
+  type Response = Messages;
+}
 
 impl SendMessageAlbum {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -23925,7 +30306,16 @@ impl SendMessageAlbum {
     let mut inner = SendMessageAlbum::default();
     inner.td_name = "sendMessageAlbum".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendMessageAlbumBuilder { inner }
+    RTDSendMessageAlbumBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendMessageAlbum", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -23942,15 +30332,25 @@ impl SendMessageAlbum {
 
 #[doc(hidden)]
 pub struct RTDSendMessageAlbumBuilder {
-  inner: SendMessageAlbum
+  inner: SendMessageAlbum,
+  set: [bool; 1]
 }
 
 impl RTDSendMessageAlbumBuilder {
   pub fn build(&self) -> SendMessageAlbum { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendMessageAlbum> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("sendMessageAlbum", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -24019,7 +30419,9 @@ impl RObject for SendPassportAuthorizationForm {
 
 
 
-impl RFunction for SendPassportAuthorizationForm {}
+impl RFunction for SendPassportAuthorizationForm {
+  type Response = Ok;
+}
 
 impl SendPassportAuthorizationForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24027,7 +30429,16 @@ impl SendPassportAuthorizationForm {
     let mut inner = SendPassportAuthorizationForm::default();
     inner.td_name = "sendPassportAuthorizationForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendPassportAuthorizationFormBuilder { inner }
+    RTDSendPassportAuthorizationFormBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendPassportAuthorizationForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.autorization_form_id == 0 { missing.push("autorization_form_id".to_string()); }
+    missing
   }
 
   pub fn autorization_form_id(&self) -> i64 { self.autorization_form_id }
@@ -24038,15 +30449,25 @@ impl SendPassportAuthorizationForm {
 
 #[doc(hidden)]
 pub struct RTDSendPassportAuthorizationFormBuilder {
-  inner: SendPassportAuthorizationForm
+  inner: SendPassportAuthorizationForm,
+  set: [bool; 1]
 }
 
 impl RTDSendPassportAuthorizationFormBuilder {
   pub fn build(&self) -> SendPassportAuthorizationForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendPassportAuthorizationForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("autorization_form_id".to_string()); }
+    crate::types::_common::check_missing("sendPassportAuthorizationForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn autorization_form_id(&mut self, autorization_form_id: i64) -> &mut Self {
     self.inner.autorization_form_id = autorization_form_id;
+    self.set[0] = true;
     self
   }
 
@@ -24103,7 +30524,9 @@ impl RObject for SendPaymentForm {
 
 
 
-impl RFunction for SendPaymentForm {}
+impl RFunction for SendPaymentForm {
+  type Response = PaymentResult;
+}
 
 impl SendPaymentForm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24111,7 +30534,20 @@ impl SendPaymentForm {
     let mut inner = SendPaymentForm::default();
     inner.td_name = "sendPaymentForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendPaymentFormBuilder { inner }
+    RTDSendPaymentFormBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendPaymentForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.order_info_id.is_empty() { missing.push("order_info_id".to_string()); }
+    if self.shipping_option_id.is_empty() { missing.push("shipping_option_id".to_string()); }
+    if self.credentials._is_default() { missing.push("credentials".to_string()); } else { missing.extend(crate::types::_common::missing_in("credentials", self.credentials._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -24128,33 +30564,50 @@ impl SendPaymentForm {
 
 #[doc(hidden)]
 pub struct RTDSendPaymentFormBuilder {
-  inner: SendPaymentForm
+  inner: SendPaymentForm,
+  set: [bool; 4]
 }
 
 impl RTDSendPaymentFormBuilder {
   pub fn build(&self) -> SendPaymentForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendPaymentForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("order_info_id".to_string()); }
+    if !self.set[3] { missing.push("shipping_option_id".to_string()); }
+    if self.inner.credentials._is_default() { missing.push("credentials".to_string()); } else { missing.extend(crate::types::_common::missing_in("credentials", self.inner.credentials._missing_fields())); }
+    crate::types::_common::check_missing("sendPaymentForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn order_info_id<T: AsRef<str>>(&mut self, order_info_id: T) -> &mut Self {
     self.inner.order_info_id = order_info_id.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn shipping_option_id<T: AsRef<str>>(&mut self, shipping_option_id: T) -> &mut Self {
     self.inner.shipping_option_id = shipping_option_id.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -24207,7 +30660,9 @@ impl RObject for SendPhoneNumberConfirmationCode {
 
 
 
-impl RFunction for SendPhoneNumberConfirmationCode {}
+impl RFunction for SendPhoneNumberConfirmationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberConfirmationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24215,7 +30670,18 @@ impl SendPhoneNumberConfirmationCode {
     let mut inner = SendPhoneNumberConfirmationCode::default();
     inner.td_name = "sendPhoneNumberConfirmationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendPhoneNumberConfirmationCodeBuilder { inner }
+    RTDSendPhoneNumberConfirmationCodeBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendPhoneNumberConfirmationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.hash.is_empty() { missing.push("hash".to_string()); }
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if false { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.settings._missing_fields())); }
+    missing
   }
 
   pub fn hash(&self) -> &String { &self.hash }
@@ -24228,27 +30694,41 @@ impl SendPhoneNumberConfirmationCode {
 
 #[doc(hidden)]
 pub struct RTDSendPhoneNumberConfirmationCodeBuilder {
-  inner: SendPhoneNumberConfirmationCode
+  inner: SendPhoneNumberConfirmationCode,
+  set: [bool; 3]
 }
 
 impl RTDSendPhoneNumberConfirmationCodeBuilder {
   pub fn build(&self) -> SendPhoneNumberConfirmationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendPhoneNumberConfirmationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("hash".to_string()); }
+    if !self.set[1] { missing.push("phone_number".to_string()); }
+    if !self.set[2] { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.inner.settings._missing_fields())); }
+    crate::types::_common::check_missing("sendPhoneNumberConfirmationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn settings<T: AsRef<PhoneNumberAuthenticationSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -24293,7 +30773,9 @@ impl RObject for SendPhoneNumberVerificationCode {
 
 
 
-impl RFunction for SendPhoneNumberVerificationCode {}
+impl RFunction for SendPhoneNumberVerificationCode {
+  type Response = AuthenticationCodeInfo;
+}
 
 impl SendPhoneNumberVerificationCode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24301,7 +30783,17 @@ impl SendPhoneNumberVerificationCode {
     let mut inner = SendPhoneNumberVerificationCode::default();
     inner.td_name = "sendPhoneNumberVerificationCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSendPhoneNumberVerificationCodeBuilder { inner }
+    RTDSendPhoneNumberVerificationCodeBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sendPhoneNumberVerificationCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if false { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.settings._missing_fields())); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -24312,21 +30804,33 @@ impl SendPhoneNumberVerificationCode {
 
 #[doc(hidden)]
 pub struct RTDSendPhoneNumberVerificationCodeBuilder {
-  inner: SendPhoneNumberVerificationCode
+  inner: SendPhoneNumberVerificationCode,
+  set: [bool; 2]
 }
 
 impl RTDSendPhoneNumberVerificationCodeBuilder {
   pub fn build(&self) -> SendPhoneNumberVerificationCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SendPhoneNumberVerificationCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    if !self.set[1] { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.inner.settings._missing_fields())); }
+    crate::types::_common::check_missing("sendPhoneNumberVerificationCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn settings<T: AsRef<PhoneNumberAuthenticationSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -24369,7 +30873,9 @@ impl RObject for SetAccountTtl {
 
 
 
-impl RFunction for SetAccountTtl {}
+impl RFunction for SetAccountTtl {
+  type Response = Ok;
+}
 
 impl SetAccountTtl {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24377,7 +30883,16 @@ impl SetAccountTtl {
     let mut inner = SetAccountTtl::default();
     inner.td_name = "setAccountTtl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetAccountTtlBuilder { inner }
+    RTDSetAccountTtlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setAccountTtl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("ttl".to_string()); } else { missing.extend(crate::types::_common::missing_in("ttl", self.ttl._missing_fields())); }
+    missing
   }
 
   pub fn ttl(&self) -> &AccountTtl { &self.ttl }
@@ -24386,15 +30901,25 @@ impl SetAccountTtl {
 
 #[doc(hidden)]
 pub struct RTDSetAccountTtlBuilder {
-  inner: SetAccountTtl
+  inner: SetAccountTtl,
+  set: [bool; 1]
 }
 
 impl RTDSetAccountTtlBuilder {
   pub fn build(&self) -> SetAccountTtl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetAccountTtl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("ttl".to_string()); } else { missing.extend(crate::types::_common::missing_in("ttl", self.inner.ttl._missing_fields())); }
+    crate::types::_common::check_missing("setAccountTtl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn ttl<T: AsRef<AccountTtl>>(&mut self, ttl: T) -> &mut Self {
     self.inner.ttl = ttl.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -24437,7 +30962,9 @@ impl RObject for SetAlarm {
 
 
 
-impl RFunction for SetAlarm {}
+impl RFunction for SetAlarm {
+  type Response = Ok;
+}
 
 impl SetAlarm {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24445,7 +30972,16 @@ impl SetAlarm {
     let mut inner = SetAlarm::default();
     inner.td_name = "setAlarm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetAlarmBuilder { inner }
+    RTDSetAlarmBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setAlarm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.seconds == 0.0 { missing.push("seconds".to_string()); }
+    missing
   }
 
   pub fn seconds(&self) -> f32 { self.seconds }
@@ -24454,15 +30990,25 @@ impl SetAlarm {
 
 #[doc(hidden)]
 pub struct RTDSetAlarmBuilder {
-  inner: SetAlarm
+  inner: SetAlarm,
+  set: [bool; 1]
 }
 
 impl RTDSetAlarmBuilder {
   pub fn build(&self) -> SetAlarm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetAlarm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("seconds".to_string()); }
+    crate::types::_common::check_missing("setAlarm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn seconds(&mut self, seconds: f32) -> &mut Self {
     self.inner.seconds = seconds;
+    self.set[0] = true;
     self
   }
 
@@ -24507,7 +31053,9 @@ impl RObject for SetAuthenticationPhoneNumber {
 
 
 
-impl RFunction for SetAuthenticationPhoneNumber {}
+impl RFunction for SetAuthenticationPhoneNumber {
+  type Response = Ok;
+}
 
 impl SetAuthenticationPhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24515,7 +31063,17 @@ impl SetAuthenticationPhoneNumber {
     let mut inner = SetAuthenticationPhoneNumber::default();
     inner.td_name = "setAuthenticationPhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetAuthenticationPhoneNumberBuilder { inner }
+    RTDSetAuthenticationPhoneNumberBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setAuthenticationPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if false { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.settings._missing_fields())); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -24526,21 +31084,33 @@ impl SetAuthenticationPhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDSetAuthenticationPhoneNumberBuilder {
-  inner: SetAuthenticationPhoneNumber
+  inner: SetAuthenticationPhoneNumber,
+  set: [bool; 2]
 }
 
 impl RTDSetAuthenticationPhoneNumberBuilder {
   pub fn build(&self) -> SetAuthenticationPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetAuthenticationPhoneNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    if !self.set[1] { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.inner.settings._missing_fields())); }
+    crate::types::_common::check_missing("setAuthenticationPhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn settings<T: AsRef<PhoneNumberAuthenticationSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -24585,7 +31155,9 @@ impl RObject for SetAutoDownloadSettings {
 
 
 
-impl RFunction for SetAutoDownloadSettings {}
+impl RFunction for SetAutoDownloadSettings {
+  type Response = Ok;
+}
 
 impl SetAutoDownloadSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24593,7 +31165,17 @@ impl SetAutoDownloadSettings {
     let mut inner = SetAutoDownloadSettings::default();
     inner.td_name = "setAutoDownloadSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetAutoDownloadSettingsBuilder { inner }
+    RTDSetAutoDownloadSettingsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setAutoDownloadSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.settings._missing_fields())); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn settings(&self) -> &AutoDownloadSettings { &self.settings }
@@ -24604,15 +31186,26 @@ impl SetAutoDownloadSettings {
 
 #[doc(hidden)]
 pub struct RTDSetAutoDownloadSettingsBuilder {
-  inner: SetAutoDownloadSettings
+  inner: SetAutoDownloadSettings,
+  set: [bool; 1]
 }
 
 impl RTDSetAutoDownloadSettingsBuilder {
   pub fn build(&self) -> SetAutoDownloadSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetAutoDownloadSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("settings", self.inner.settings._missing_fields())); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("setAutoDownloadSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn settings<T: AsRef<AutoDownloadSettings>>(&mut self, settings: T) -> &mut Self {
     self.inner.settings = settings.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -24665,7 +31258,9 @@ impl RObject for SetBackground {
 
 
 
-impl RFunction for SetBackground {}
+impl RFunction for SetBackground {
+  type Response = Background;
+}
 
 impl SetBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24676,6 +31271,16 @@ impl SetBackground {
     RTDSetBackgroundBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setBackground", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.background._is_default() { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.background._missing_fields())); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn background(&self) -> &InputBackground { &self.background }
 
   pub fn type_(&self) -> &BackgroundType { &self.type_ }
@@ -24692,6 +31297,15 @@ pub struct RTDSetBackgroundBuilder {
 impl RTDSetBackgroundBuilder {
   pub fn build(&self) -> SetBackground { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetBackground> {
+    let mut missing = vec![];
+    if self.inner.background._is_default() { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.inner.background._missing_fields())); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("setBackground", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn background<T: AsRef<InputBackground>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -24749,7 +31363,9 @@ impl RObject for SetBio {
 
 
 
-impl RFunction for SetBio {}
+impl RFunction for SetBio {
+  type Response = Ok;
+}
 
 impl SetBio {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24757,7 +31373,16 @@ impl SetBio {
     let mut inner = SetBio::default();
     inner.td_name = "setBio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetBioBuilder { inner }
+    RTDSetBioBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setBio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bio.is_empty() { missing.push("bio".to_string()); }
+    missing
   }
 
   pub fn bio(&self) -> &String { &self.bio }
@@ -24766,15 +31391,25 @@ impl SetBio {
 
 #[doc(hidden)]
 pub struct RTDSetBioBuilder {
-  inner: SetBio
+  inner: SetBio,
+  set: [bool; 1]
 }
 
 impl RTDSetBioBuilder {
   pub fn build(&self) -> SetBio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetBio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bio".to_string()); }
+    crate::types::_common::check_missing("setBio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bio<T: AsRef<str>>(&mut self, bio: T) -> &mut Self {
     self.inner.bio = bio.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -24819,7 +31454,9 @@ impl RObject for SetBotUpdatesStatus {
 
 
 
-impl RFunction for SetBotUpdatesStatus {}
+impl RFunction for SetBotUpdatesStatus {
+  type Response = Ok;
+}
 
 impl SetBotUpdatesStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24827,7 +31464,17 @@ impl SetBotUpdatesStatus {
     let mut inner = SetBotUpdatesStatus::default();
     inner.td_name = "setBotUpdatesStatus".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetBotUpdatesStatusBuilder { inner }
+    RTDSetBotUpdatesStatusBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setBotUpdatesStatus", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.pending_update_count == 0 { missing.push("pending_update_count".to_string()); }
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    missing
   }
 
   pub fn pending_update_count(&self) -> i64 { self.pending_update_count }
@@ -24838,21 +31485,33 @@ impl SetBotUpdatesStatus {
 
 #[doc(hidden)]
 pub struct RTDSetBotUpdatesStatusBuilder {
-  inner: SetBotUpdatesStatus
+  inner: SetBotUpdatesStatus,
+  set: [bool; 2]
 }
 
 impl RTDSetBotUpdatesStatusBuilder {
   pub fn build(&self) -> SetBotUpdatesStatus { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetBotUpdatesStatus> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("pending_update_count".to_string()); }
+    if !self.set[1] { missing.push("error_message".to_string()); }
+    crate::types::_common::check_missing("setBotUpdatesStatus", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn pending_update_count(&mut self, pending_update_count: i64) -> &mut Self {
     self.inner.pending_update_count = pending_update_count;
+    self.set[0] = true;
     self
   }
 
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -24897,7 +31556,9 @@ impl RObject for SetChatClientData {
 
 
 
-impl RFunction for SetChatClientData {}
+impl RFunction for SetChatClientData {
+  type Response = Ok;
+}
 
 impl SetChatClientData {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24905,7 +31566,17 @@ impl SetChatClientData {
     let mut inner = SetChatClientData::default();
     inner.td_name = "setChatClientData".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatClientDataBuilder { inner }
+    RTDSetChatClientDataBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatClientData", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.client_data.is_empty() { missing.push("client_data".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -24916,21 +31587,33 @@ impl SetChatClientData {
 
 #[doc(hidden)]
 pub struct RTDSetChatClientDataBuilder {
-  inner: SetChatClientData
+  inner: SetChatClientData,
+  set: [bool; 2]
 }
 
 impl RTDSetChatClientDataBuilder {
   pub fn build(&self) -> SetChatClientData { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatClientData> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("client_data".to_string()); }
+    crate::types::_common::check_missing("setChatClientData", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn client_data<T: AsRef<str>>(&mut self, client_data: T) -> &mut Self {
     self.inner.client_data = client_data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -24975,7 +31658,9 @@ impl RObject for SetChatDescription {
 
 
 
-impl RFunction for SetChatDescription {}
+impl RFunction for SetChatDescription {
+  type Response = Ok;
+}
 
 impl SetChatDescription {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -24983,7 +31668,17 @@ impl SetChatDescription {
     let mut inner = SetChatDescription::default();
     inner.td_name = "setChatDescription".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatDescriptionBuilder { inner }
+    RTDSetChatDescriptionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatDescription", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -24994,21 +31689,33 @@ impl SetChatDescription {
 
 #[doc(hidden)]
 pub struct RTDSetChatDescriptionBuilder {
-  inner: SetChatDescription
+  inner: SetChatDescription,
+  set: [bool; 2]
 }
 
 impl RTDSetChatDescriptionBuilder {
   pub fn build(&self) -> SetChatDescription { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatDescription> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("setChatDescription", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -25053,7 +31760,9 @@ impl RObject for SetChatDiscussionGroup {
 
 
 
-impl RFunction for SetChatDiscussionGroup {}
+impl RFunction for SetChatDiscussionGroup {
+  type Response = Ok;
+}
 
 impl SetChatDiscussionGroup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25061,7 +31770,16 @@ impl SetChatDiscussionGroup {
     let mut inner = SetChatDiscussionGroup::default();
     inner.td_name = "setChatDiscussionGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatDiscussionGroupBuilder { inner }
+    RTDSetChatDiscussionGroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatDiscussionGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.discussion_chat_id == 0 { missing.push("discussion_chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25072,12 +31790,21 @@ impl SetChatDiscussionGroup {
 
 #[doc(hidden)]
 pub struct RTDSetChatDiscussionGroupBuilder {
-  inner: SetChatDiscussionGroup
+  inner: SetChatDiscussionGroup,
+  set: [bool; 1]
 }
 
 impl RTDSetChatDiscussionGroupBuilder {
   pub fn build(&self) -> SetChatDiscussionGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatDiscussionGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("discussion_chat_id".to_string()); }
+    crate::types::_common::check_missing("setChatDiscussionGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -25087,6 +31814,7 @@ impl RTDSetChatDiscussionGroupBuilder {
    
   pub fn discussion_chat_id(&mut self, discussion_chat_id: i64) -> &mut Self {
     self.inner.discussion_chat_id = discussion_chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -25133,7 +31861,9 @@ impl RObject for SetChatDraftMessage {
 
 
 
-impl RFunction for SetChatDraftMessage {}
+impl RFunction for SetChatDraftMessage {
+  type Response = Ok;
+}
 
 impl SetChatDraftMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25141,7 +31871,16 @@ impl SetChatDraftMessage {
     let mut inner = SetChatDraftMessage::default();
     inner.td_name = "setChatDraftMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatDraftMessageBuilder { inner }
+    RTDSetChatDraftMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatDraftMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25154,15 +31893,25 @@ impl SetChatDraftMessage {
 
 #[doc(hidden)]
 pub struct RTDSetChatDraftMessageBuilder {
-  inner: SetChatDraftMessage
+  inner: SetChatDraftMessage,
+  set: [bool; 1]
 }
 
 impl RTDSetChatDraftMessageBuilder {
   pub fn build(&self) -> SetChatDraftMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatDraftMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("setChatDraftMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -25219,7 +31968,9 @@ impl RObject for SetChatLocation {
 
 
 
-impl RFunction for SetChatLocation {}
+impl RFunction for SetChatLocation {
+  type Response = Ok;
+}
 
 impl SetChatLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25227,7 +31978,17 @@ impl SetChatLocation {
     let mut inner = SetChatLocation::default();
     inner.td_name = "setChatLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatLocationBuilder { inner }
+    RTDSetChatLocationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25238,21 +31999,33 @@ impl SetChatLocation {
 
 #[doc(hidden)]
 pub struct RTDSetChatLocationBuilder {
-  inner: SetChatLocation
+  inner: SetChatLocation,
+  set: [bool; 2]
 }
 
 impl RTDSetChatLocationBuilder {
   pub fn build(&self) -> SetChatLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    crate::types::_common::check_missing("setChatLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn location<T: AsRef<ChatLocation>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -25299,7 +32072,9 @@ impl RObject for SetChatMemberStatus {
 
 
 
-impl RFunction for SetChatMemberStatus {}
+impl RFunction for SetChatMemberStatus {
+  type Response = Ok;
+}
 
 impl SetChatMemberStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25307,7 +32082,18 @@ impl SetChatMemberStatus {
     let mut inner = SetChatMemberStatus::default();
     inner.td_name = "setChatMemberStatus".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatMemberStatusBuilder { inner }
+    RTDSetChatMemberStatusBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatMemberStatus", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25320,21 +32106,34 @@ impl SetChatMemberStatus {
 
 #[doc(hidden)]
 pub struct RTDSetChatMemberStatusBuilder {
-  inner: SetChatMemberStatus
+  inner: SetChatMemberStatus,
+  set: [bool; 2]
 }
 
 impl RTDSetChatMemberStatusBuilder {
   pub fn build(&self) -> SetChatMemberStatus { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatMemberStatus> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    crate::types::_common::check_missing("setChatMemberStatus", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -25385,7 +32184,9 @@ impl RObject for SetChatNotificationSettings {
 
 
 
-impl RFunction for SetChatNotificationSettings {}
+impl RFunction for SetChatNotificationSettings {
+  type Response = Ok;
+}
 
 impl SetChatNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25393,7 +32194,17 @@ impl SetChatNotificationSettings {
     let mut inner = SetChatNotificationSettings::default();
     inner.td_name = "setChatNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatNotificationSettingsBuilder { inner }
+    RTDSetChatNotificationSettingsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.notification_settings._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25404,21 +32215,33 @@ impl SetChatNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDSetChatNotificationSettingsBuilder {
-  inner: SetChatNotificationSettings
+  inner: SetChatNotificationSettings,
+  set: [bool; 2]
 }
 
 impl RTDSetChatNotificationSettingsBuilder {
   pub fn build(&self) -> SetChatNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatNotificationSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.inner.notification_settings._missing_fields())); }
+    crate::types::_common::check_missing("setChatNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn notification_settings<T: AsRef<ChatNotificationSettings>>(&mut self, notification_settings: T) -> &mut Self {
     self.inner.notification_settings = notification_settings.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -25463,7 +32286,9 @@ impl RObject for SetChatPermissions {
 
 
 
-impl RFunction for SetChatPermissions {}
+impl RFunction for SetChatPermissions {
+  type Response = Ok;
+}
 
 impl SetChatPermissions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25471,7 +32296,17 @@ impl SetChatPermissions {
     let mut inner = SetChatPermissions::default();
     inner.td_name = "setChatPermissions".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatPermissionsBuilder { inner }
+    RTDSetChatPermissionsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatPermissions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.permissions._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25482,21 +32317,33 @@ impl SetChatPermissions {
 
 #[doc(hidden)]
 pub struct RTDSetChatPermissionsBuilder {
-  inner: SetChatPermissions
+  inner: SetChatPermissions,
+  set: [bool; 2]
 }
 
 impl RTDSetChatPermissionsBuilder {
   pub fn build(&self) -> SetChatPermissions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatPermissions> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.inner.permissions._missing_fields())); }
+    crate::types::_common::check_missing("setChatPermissions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
     self.inner.permissions = permissions.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -25541,7 +32388,9 @@ impl RObject for SetChatPhoto {
 
 
 
-impl RFunction for SetChatPhoto {}
+impl RFunction for SetChatPhoto {
+  type Response = Ok;
+}
 
 impl SetChatPhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25549,7 +32398,17 @@ impl SetChatPhoto {
     let mut inner = SetChatPhoto::default();
     inner.td_name = "setChatPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatPhotoBuilder { inner }
+    RTDSetChatPhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25560,15 +32419,26 @@ impl SetChatPhoto {
 
 #[doc(hidden)]
 pub struct RTDSetChatPhotoBuilder {
-  inner: SetChatPhoto
+  inner: SetChatPhoto,
+  set: [bool; 1]
 }
 
 impl RTDSetChatPhotoBuilder {
   pub fn build(&self) -> SetChatPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if self.inner.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    crate::types::_common::check_missing("setChatPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -25619,7 +32489,9 @@ impl RObject for SetChatSlowModeDelay {
 
 
 
-impl RFunction for SetChatSlowModeDelay {}
+impl RFunction for SetChatSlowModeDelay {
+  type Response = Ok;
+}
 
 impl SetChatSlowModeDelay {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25627,7 +32499,17 @@ impl SetChatSlowModeDelay {
     let mut inner = SetChatSlowModeDelay::default();
     inner.td_name = "setChatSlowModeDelay".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatSlowModeDelayBuilder { inner }
+    RTDSetChatSlowModeDelayBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatSlowModeDelay", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.slow_mode_delay == 0 { missing.push("slow_mode_delay".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25638,21 +32520,33 @@ impl SetChatSlowModeDelay {
 
 #[doc(hidden)]
 pub struct RTDSetChatSlowModeDelayBuilder {
-  inner: SetChatSlowModeDelay
+  inner: SetChatSlowModeDelay,
+  set: [bool; 2]
 }
 
 impl RTDSetChatSlowModeDelayBuilder {
   pub fn build(&self) -> SetChatSlowModeDelay { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatSlowModeDelay> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("slow_mode_delay".to_string()); }
+    crate::types::_common::check_missing("setChatSlowModeDelay", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn slow_mode_delay(&mut self, slow_mode_delay: i64) -> &mut Self {
     self.inner.slow_mode_delay = slow_mode_delay;
+    self.set[1] = true;
     self
   }
 
@@ -25697,7 +32591,9 @@ impl RObject for SetChatTitle {
 
 
 
-impl RFunction for SetChatTitle {}
+impl RFunction for SetChatTitle {
+  type Response = Ok;
+}
 
 impl SetChatTitle {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25705,7 +32601,17 @@ impl SetChatTitle {
     let mut inner = SetChatTitle::default();
     inner.td_name = "setChatTitle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetChatTitleBuilder { inner }
+    RTDSetChatTitleBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setChatTitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -25716,21 +32622,33 @@ impl SetChatTitle {
 
 #[doc(hidden)]
 pub struct RTDSetChatTitleBuilder {
-  inner: SetChatTitle
+  inner: SetChatTitle,
+  set: [bool; 2]
 }
 
 impl RTDSetChatTitleBuilder {
   pub fn build(&self) -> SetChatTitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetChatTitle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("setChatTitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -25773,7 +32691,9 @@ impl RObject for SetCommands {
 
 
 
-impl RFunction for SetCommands {}
+impl RFunction for SetCommands {
+  type Response = Ok;
+}
 
 impl SetCommands {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25784,6 +32704,11 @@ impl SetCommands {
     RTDSetCommandsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setCommands", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn commands(&self) -> &Vec<BotCommand> { &self.commands }
 
 }
@@ -25796,6 +32721,9 @@ pub struct RTDSetCommandsBuilder {
 impl RTDSetCommandsBuilder {
   pub fn build(&self) -> SetCommands { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetCommands> { Ok(self.build()) }
+
    
   pub fn commands(&mut self, commands: Vec<BotCommand>) -> &mut Self {
     self.inner.commands = commands;
@@ -25843,7 +32771,9 @@ impl RObject for SetCustomLanguagePack {
 
 
 
-impl RFunction for SetCustomLanguagePack {}
+impl RFunction for SetCustomLanguagePack {
+  type Response = Ok;
+}
 
 impl SetCustomLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25851,7 +32781,16 @@ impl SetCustomLanguagePack {
     let mut inner = SetCustomLanguagePack::default();
     inner.td_name = "setCustomLanguagePack".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetCustomLanguagePackBuilder { inner }
+    RTDSetCustomLanguagePackBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setCustomLanguagePack", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.info._missing_fields())); }
+    missing
   }
 
   pub fn info(&self) -> &LanguagePackInfo { &self.info }
@@ -25862,15 +32801,25 @@ impl SetCustomLanguagePack {
 
 #[doc(hidden)]
 pub struct RTDSetCustomLanguagePackBuilder {
-  inner: SetCustomLanguagePack
+  inner: SetCustomLanguagePack,
+  set: [bool; 1]
 }
 
 impl RTDSetCustomLanguagePackBuilder {
   pub fn build(&self) -> SetCustomLanguagePack { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetCustomLanguagePack> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.inner.info._missing_fields())); }
+    crate::types::_common::check_missing("setCustomLanguagePack", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn info<T: AsRef<LanguagePackInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -25921,7 +32870,9 @@ impl RObject for SetCustomLanguagePackString {
 
 
 
-impl RFunction for SetCustomLanguagePackString {}
+impl RFunction for SetCustomLanguagePackString {
+  type Response = Ok;
+}
 
 impl SetCustomLanguagePackString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -25929,7 +32880,17 @@ impl SetCustomLanguagePackString {
     let mut inner = SetCustomLanguagePackString::default();
     inner.td_name = "setCustomLanguagePackString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetCustomLanguagePackStringBuilder { inner }
+    RTDSetCustomLanguagePackStringBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setCustomLanguagePackString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    if false { missing.push("new_string".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_string", self.new_string._missing_fields())); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -25940,21 +32901,33 @@ impl SetCustomLanguagePackString {
 
 #[doc(hidden)]
 pub struct RTDSetCustomLanguagePackStringBuilder {
-  inner: SetCustomLanguagePackString
+  inner: SetCustomLanguagePackString,
+  set: [bool; 2]
 }
 
 impl RTDSetCustomLanguagePackStringBuilder {
   pub fn build(&self) -> SetCustomLanguagePackString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetCustomLanguagePackString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    if !self.set[1] { missing.push("new_string".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_string", self.inner.new_string._missing_fields())); }
+    crate::types::_common::check_missing("setCustomLanguagePackString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_string<T: AsRef<LanguagePackString>>(&mut self, new_string: T) -> &mut Self {
     self.inner.new_string = new_string.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -25997,7 +32970,9 @@ impl RObject for SetDatabaseEncryptionKey {
 
 
 
-impl RFunction for SetDatabaseEncryptionKey {}
+impl RFunction for SetDatabaseEncryptionKey {
+  type Response = Ok;
+}
 
 impl SetDatabaseEncryptionKey {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26005,7 +32980,16 @@ impl SetDatabaseEncryptionKey {
     let mut inner = SetDatabaseEncryptionKey::default();
     inner.td_name = "setDatabaseEncryptionKey".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetDatabaseEncryptionKeyBuilder { inner }
+    RTDSetDatabaseEncryptionKeyBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setDatabaseEncryptionKey", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.new_encryption_key.is_empty() { missing.push("new_encryption_key".to_string()); }
+    missing
   }
 
   pub fn new_encryption_key(&self) -> &String { &self.new_encryption_key }
@@ -26014,15 +32998,25 @@ impl SetDatabaseEncryptionKey {
 
 #[doc(hidden)]
 pub struct RTDSetDatabaseEncryptionKeyBuilder {
-  inner: SetDatabaseEncryptionKey
+  inner: SetDatabaseEncryptionKey,
+  set: [bool; 1]
 }
 
 impl RTDSetDatabaseEncryptionKeyBuilder {
   pub fn build(&self) -> SetDatabaseEncryptionKey { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetDatabaseEncryptionKey> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("new_encryption_key".to_string()); }
+    crate::types::_common::check_missing("setDatabaseEncryptionKey", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn new_encryption_key<T: AsRef<str>>(&mut self, new_encryption_key: T) -> &mut Self {
     self.inner.new_encryption_key = new_encryption_key.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -26052,7 +33046,7 @@ pub struct SetFileGenerationProgress {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   /// Expected size of the generated file, in bytes; 0 if unknown
   expected_size: i64,
   /// The number of bytes already generated
@@ -26069,7 +33063,9 @@ impl RObject for SetFileGenerationProgress {
 
 
 
-impl RFunction for SetFileGenerationProgress {}
+impl RFunction for SetFileGenerationProgress {
+  type Response = Ok;
+}
 
 impl SetFileGenerationProgress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26077,10 +33073,20 @@ impl SetFileGenerationProgress {
     let mut inner = SetFileGenerationProgress::default();
     inner.td_name = "setFileGenerationProgress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetFileGenerationProgressBuilder { inner }
+    RTDSetFileGenerationProgressBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setFileGenerationProgress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.generation_id == 0 { missing.push("generation_id".to_string()); }
+    if self.local_prefix_size == 0 { missing.push("local_prefix_size".to_string()); }
+    missing
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn expected_size(&self) -> i64 { self.expected_size }
 
@@ -26090,15 +33096,26 @@ impl SetFileGenerationProgress {
 
 #[doc(hidden)]
 pub struct RTDSetFileGenerationProgressBuilder {
-  inner: SetFileGenerationProgress
+  inner: SetFileGenerationProgress,
+  set: [bool; 2]
 }
 
 impl RTDSetFileGenerationProgressBuilder {
   pub fn build(&self) -> SetFileGenerationProgress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetFileGenerationProgress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("generation_id".to_string()); }
+    if !self.set[1] { missing.push("local_prefix_size".to_string()); }
+    crate::types::_common::check_missing("setFileGenerationProgress", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
+    self.set[0] = true;
     self
   }
 
@@ -26111,6 +33128,7 @@ impl RTDSetFileGenerationProgressBuilder {
    
   pub fn local_prefix_size(&mut self, local_prefix_size: i64) -> &mut Self {
     self.inner.local_prefix_size = local_prefix_size;
+    self.set[1] = true;
     self
   }
 
@@ -26163,7 +33181,9 @@ impl RObject for SetGameScore {
 
 
 
-impl RFunction for SetGameScore {}
+impl RFunction for SetGameScore {
+  type Response = Message;
+}
 
 impl SetGameScore {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26171,7 +33191,19 @@ impl SetGameScore {
     let mut inner = SetGameScore::default();
     inner.td_name = "setGameScore".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetGameScoreBuilder { inner }
+    RTDSetGameScoreBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setGameScore", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.score == 0 { missing.push("score".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -26190,21 +33222,35 @@ impl SetGameScore {
 
 #[doc(hidden)]
 pub struct RTDSetGameScoreBuilder {
-  inner: SetGameScore
+  inner: SetGameScore,
+  set: [bool; 4]
 }
 
 impl RTDSetGameScoreBuilder {
   pub fn build(&self) -> SetGameScore { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetGameScore> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("user_id".to_string()); }
+    if !self.set[3] { missing.push("score".to_string()); }
+    crate::types::_common::check_missing("setGameScore", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -26217,12 +33263,14 @@ impl RTDSetGameScoreBuilder {
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn score(&mut self, score: i64) -> &mut Self {
     self.inner.score = score;
+    self.set[3] = true;
     self
   }
 
@@ -26275,7 +33323,9 @@ impl RObject for SetGroupCallParticipantIsSpeaking {
 
 
 
-impl RFunction for SetGroupCallParticipantIsSpeaking {}
+impl RFunction for SetGroupCallParticipantIsSpeaking {
+  type Response = Ok;
+}
 
 impl SetGroupCallParticipantIsSpeaking {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26283,7 +33333,16 @@ impl SetGroupCallParticipantIsSpeaking {
     let mut inner = SetGroupCallParticipantIsSpeaking::default();
     inner.td_name = "setGroupCallParticipantIsSpeaking".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetGroupCallParticipantIsSpeakingBuilder { inner }
+    RTDSetGroupCallParticipantIsSpeakingBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setGroupCallParticipantIsSpeaking", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -26296,15 +33355,25 @@ impl SetGroupCallParticipantIsSpeaking {
 
 #[doc(hidden)]
 pub struct RTDSetGroupCallParticipantIsSpeakingBuilder {
-  inner: SetGroupCallParticipantIsSpeaking
+  inner: SetGroupCallParticipantIsSpeaking,
+  set: [bool; 1]
 }
 
 impl RTDSetGroupCallParticipantIsSpeakingBuilder {
   pub fn build(&self) -> SetGroupCallParticipantIsSpeaking { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetGroupCallParticipantIsSpeaking> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("setGroupCallParticipantIsSpeaking", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -26363,7 +33432,9 @@ impl RObject for SetGroupCallParticipantVolumeLevel {
 
 
 
-impl RFunction for SetGroupCallParticipantVolumeLevel {}
+impl RFunction for SetGroupCallParticipantVolumeLevel {
+  type Response = Ok;
+}
 
 impl SetGroupCallParticipantVolumeLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26371,7 +33442,18 @@ impl SetGroupCallParticipantVolumeLevel {
     let mut inner = SetGroupCallParticipantVolumeLevel::default();
     inner.td_name = "setGroupCallParticipantVolumeLevel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetGroupCallParticipantVolumeLevelBuilder { inner }
+    RTDSetGroupCallParticipantVolumeLevelBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setGroupCallParticipantVolumeLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.volume_level == 0 { missing.push("volume_level".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -26384,27 +33466,41 @@ impl SetGroupCallParticipantVolumeLevel {
 
 #[doc(hidden)]
 pub struct RTDSetGroupCallParticipantVolumeLevelBuilder {
-  inner: SetGroupCallParticipantVolumeLevel
+  inner: SetGroupCallParticipantVolumeLevel,
+  set: [bool; 3]
 }
 
 impl RTDSetGroupCallParticipantVolumeLevelBuilder {
   pub fn build(&self) -> SetGroupCallParticipantVolumeLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetGroupCallParticipantVolumeLevel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if !self.set[2] { missing.push("volume_level".to_string()); }
+    crate::types::_common::check_missing("setGroupCallParticipantVolumeLevel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn volume_level(&mut self, volume_level: i64) -> &mut Self {
     self.inner.volume_level = volume_level;
+    self.set[2] = true;
     self
   }
 
@@ -26455,7 +33551,9 @@ impl RObject for SetInlineGameScore {
 
 
 
-impl RFunction for SetInlineGameScore {}
+impl RFunction for SetInlineGameScore {
+  type Response = Ok;
+}
 
 impl SetInlineGameScore {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26463,7 +33561,18 @@ impl SetInlineGameScore {
     let mut inner = SetInlineGameScore::default();
     inner.td_name = "setInlineGameScore".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetInlineGameScoreBuilder { inner }
+    RTDSetInlineGameScoreBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setInlineGameScore", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.score == 0 { missing.push("score".to_string()); }
+    missing
   }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
@@ -26480,15 +33589,27 @@ impl SetInlineGameScore {
 
 #[doc(hidden)]
 pub struct RTDSetInlineGameScoreBuilder {
-  inner: SetInlineGameScore
+  inner: SetInlineGameScore,
+  set: [bool; 3]
 }
 
 impl RTDSetInlineGameScoreBuilder {
   pub fn build(&self) -> SetInlineGameScore { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetInlineGameScore> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_message_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if !self.set[2] { missing.push("score".to_string()); }
+    crate::types::_common::check_missing("setInlineGameScore", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -26501,12 +33622,14 @@ impl RTDSetInlineGameScoreBuilder {
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn score(&mut self, score: i64) -> &mut Self {
     self.inner.score = score;
+    self.set[2] = true;
     self
   }
 
@@ -26555,7 +33678,9 @@ impl RObject for SetLocation {
 
 
 
-impl RFunction for SetLocation {}
+impl RFunction for SetLocation {
+  type Response = Ok;
+}
 
 impl SetLocation {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26563,7 +33688,16 @@ impl SetLocation {
     let mut inner = SetLocation::default();
     inner.td_name = "setLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetLocationBuilder { inner }
+    RTDSetLocationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -26572,15 +33706,25 @@ impl SetLocation {
 
 #[doc(hidden)]
 pub struct RTDSetLocationBuilder {
-  inner: SetLocation
+  inner: SetLocation,
+  set: [bool; 1]
 }
 
 impl RTDSetLocationBuilder {
   pub fn build(&self) -> SetLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    crate::types::_common::check_missing("setLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -26623,7 +33767,9 @@ impl RObject for SetLogStream {
 
 
 
-impl RFunction for SetLogStream {}
+impl RFunction for SetLogStream {
+  type Response = Ok;
+}
 
 impl SetLogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26634,6 +33780,15 @@ impl SetLogStream {
     RTDSetLogStreamBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setLogStream", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.log_stream._is_default() { missing.push("log_stream".to_string()); } else { missing.extend(crate::types::_common::missing_in("log_stream", self.log_stream._missing_fields())); }
+    missing
+  }
+
   pub fn log_stream(&self) -> &LogStream { &self.log_stream }
 
 }
@@ -26646,6 +33801,14 @@ pub struct RTDSetLogStreamBuilder {
 impl RTDSetLogStreamBuilder {
   pub fn build(&self) -> SetLogStream { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetLogStream> {
+    let mut missing = vec![];
+    if self.inner.log_stream._is_default() { missing.push("log_stream".to_string()); } else { missing.extend(crate::types::_common::missing_in("log_stream", self.inner.log_stream._missing_fields())); }
+    crate::types::_common::check_missing("setLogStream", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn log_stream<T: AsRef<LogStream>>(&mut self, log_stream: T) -> &mut Self {
     self.inner.log_stream = log_stream.as_ref().clone();
@@ -26693,7 +33856,9 @@ impl RObject for SetLogTagVerbosityLevel {
 
 
 
-impl RFunction for SetLogTagVerbosityLevel {}
+impl RFunction for SetLogTagVerbosityLevel {
+  type Response = Ok;
+}
 
 impl SetLogTagVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26701,7 +33866,17 @@ impl SetLogTagVerbosityLevel {
     let mut inner = SetLogTagVerbosityLevel::default();
     inner.td_name = "setLogTagVerbosityLevel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetLogTagVerbosityLevelBuilder { inner }
+    RTDSetLogTagVerbosityLevelBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setLogTagVerbosityLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.tag.is_empty() { missing.push("tag".to_string()); }
+    if self.new_verbosity_level == 0 { missing.push("new_verbosity_level".to_string()); }
+    missing
   }
 
   pub fn tag(&self) -> &String { &self.tag }
@@ -26712,21 +33887,33 @@ impl SetLogTagVerbosityLevel {
 
 #[doc(hidden)]
 pub struct RTDSetLogTagVerbosityLevelBuilder {
-  inner: SetLogTagVerbosityLevel
+  inner: SetLogTagVerbosityLevel,
+  set: [bool; 2]
 }
 
 impl RTDSetLogTagVerbosityLevelBuilder {
   pub fn build(&self) -> SetLogTagVerbosityLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetLogTagVerbosityLevel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("tag".to_string()); }
+    if !self.set[1] { missing.push("new_verbosity_level".to_string()); }
+    crate::types::_common::check_missing("setLogTagVerbosityLevel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn tag<T: AsRef<str>>(&mut self, tag: T) -> &mut Self {
     self.inner.tag = tag.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_verbosity_level(&mut self, new_verbosity_level: i64) -> &mut Self {
     self.inner.new_verbosity_level = new_verbosity_level;
+    self.set[1] = true;
     self
   }
 
@@ -26769,7 +33956,9 @@ impl RObject for SetLogVerbosityLevel {
 
 
 
-impl RFunction for SetLogVerbosityLevel {}
+impl RFunction for SetLogVerbosityLevel {
+  type Response = Ok;
+}
 
 impl SetLogVerbosityLevel {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26777,7 +33966,16 @@ impl SetLogVerbosityLevel {
     let mut inner = SetLogVerbosityLevel::default();
     inner.td_name = "setLogVerbosityLevel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetLogVerbosityLevelBuilder { inner }
+    RTDSetLogVerbosityLevelBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setLogVerbosityLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.new_verbosity_level == 0 { missing.push("new_verbosity_level".to_string()); }
+    missing
   }
 
   pub fn new_verbosity_level(&self) -> i64 { self.new_verbosity_level }
@@ -26786,15 +33984,25 @@ impl SetLogVerbosityLevel {
 
 #[doc(hidden)]
 pub struct RTDSetLogVerbosityLevelBuilder {
-  inner: SetLogVerbosityLevel
+  inner: SetLogVerbosityLevel,
+  set: [bool; 1]
 }
 
 impl RTDSetLogVerbosityLevelBuilder {
   pub fn build(&self) -> SetLogVerbosityLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetLogVerbosityLevel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("new_verbosity_level".to_string()); }
+    crate::types::_common::check_missing("setLogVerbosityLevel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn new_verbosity_level(&mut self, new_verbosity_level: i64) -> &mut Self {
     self.inner.new_verbosity_level = new_verbosity_level;
+    self.set[0] = true;
     self
   }
 
@@ -26839,7 +34047,9 @@ impl RObject for SetName {
 
 
 
-impl RFunction for SetName {}
+impl RFunction for SetName {
+  type Response = Ok;
+}
 
 impl SetName {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26847,7 +34057,17 @@ impl SetName {
     let mut inner = SetName::default();
     inner.td_name = "setName".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetNameBuilder { inner }
+    RTDSetNameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setName", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.first_name.is_empty() { missing.push("first_name".to_string()); }
+    if self.last_name.is_empty() { missing.push("last_name".to_string()); }
+    missing
   }
 
   pub fn first_name(&self) -> &String { &self.first_name }
@@ -26858,21 +34078,33 @@ impl SetName {
 
 #[doc(hidden)]
 pub struct RTDSetNameBuilder {
-  inner: SetName
+  inner: SetName,
+  set: [bool; 2]
 }
 
 impl RTDSetNameBuilder {
   pub fn build(&self) -> SetName { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetName> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("first_name".to_string()); }
+    if !self.set[1] { missing.push("last_name".to_string()); }
+    crate::types::_common::check_missing("setName", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
     self.inner.last_name = last_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -26915,7 +34147,9 @@ impl RObject for SetNetworkType {
 
 
 
-impl RFunction for SetNetworkType {}
+impl RFunction for SetNetworkType {
+  type Response = Ok;
+}
 
 impl SetNetworkType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26926,6 +34160,15 @@ impl SetNetworkType {
     RTDSetNetworkTypeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setNetworkType", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn type_(&self) -> &NetworkType { &self.type_ }
 
 }
@@ -26938,6 +34181,14 @@ pub struct RTDSetNetworkTypeBuilder {
 impl RTDSetNetworkTypeBuilder {
   pub fn build(&self) -> SetNetworkType { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetNetworkType> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("setNetworkType", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<NetworkType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -26985,7 +34236,9 @@ impl RObject for SetOption {
 
 
 
-impl RFunction for SetOption {}
+impl RFunction for SetOption {
+  type Response = Ok;
+}
 
 impl SetOption {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -26993,7 +34246,17 @@ impl SetOption {
     let mut inner = SetOption::default();
     inner.td_name = "setOption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetOptionBuilder { inner }
+    RTDSetOptionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setOption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.value._missing_fields())); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -27004,15 +34267,26 @@ impl SetOption {
 
 #[doc(hidden)]
 pub struct RTDSetOptionBuilder {
-  inner: SetOption
+  inner: SetOption,
+  set: [bool; 1]
 }
 
 impl RTDSetOptionBuilder {
   pub fn build(&self) -> SetOption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetOption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    if self.inner.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.inner.value._missing_fields())); }
+    crate::types::_common::check_missing("setOption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -27061,9 +34335,11 @@ impl RObject for SetPassportElement {
 }
 
 
-impl TDPassportElement for SetPassportElement {}
 
-impl RFunction for SetPassportElement {}
+
+impl RFunction for SetPassportElement {
+  type Response = PassportElement;
+}
 
 impl SetPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27071,7 +34347,17 @@ impl SetPassportElement {
     let mut inner = SetPassportElement::default();
     inner.td_name = "setPassportElement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetPassportElementBuilder { inner }
+    RTDSetPassportElementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setPassportElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.element._is_default() { missing.push("element".to_string()); } else { missing.extend(crate::types::_common::missing_in("element", self.element._missing_fields())); }
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn element(&self) -> &InputPassportElement { &self.element }
@@ -27082,12 +34368,22 @@ impl SetPassportElement {
 
 #[doc(hidden)]
 pub struct RTDSetPassportElementBuilder {
-  inner: SetPassportElement
+  inner: SetPassportElement,
+  set: [bool; 1]
 }
 
 impl RTDSetPassportElementBuilder {
   pub fn build(&self) -> SetPassportElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetPassportElement> {
+    let mut missing = vec![];
+    if self.inner.element._is_default() { missing.push("element".to_string()); } else { missing.extend(crate::types::_common::missing_in("element", self.inner.element._missing_fields())); }
+    if !self.set[0] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("setPassportElement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn element<T: AsRef<InputPassportElement>>(&mut self, element: T) -> &mut Self {
     self.inner.element = element.as_ref().clone();
@@ -27097,6 +34393,7 @@ impl RTDSetPassportElementBuilder {
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -27141,7 +34438,9 @@ impl RObject for SetPassportElementErrors {
 
 
 
-impl RFunction for SetPassportElementErrors {}
+impl RFunction for SetPassportElementErrors {
+  type Response = Ok;
+}
 
 impl SetPassportElementErrors {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27149,7 +34448,16 @@ impl SetPassportElementErrors {
     let mut inner = SetPassportElementErrors::default();
     inner.td_name = "setPassportElementErrors".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetPassportElementErrorsBuilder { inner }
+    RTDSetPassportElementErrorsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setPassportElementErrors", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -27160,15 +34468,25 @@ impl SetPassportElementErrors {
 
 #[doc(hidden)]
 pub struct RTDSetPassportElementErrorsBuilder {
-  inner: SetPassportElementErrors
+  inner: SetPassportElementErrors,
+  set: [bool; 1]
 }
 
 impl RTDSetPassportElementErrorsBuilder {
   pub fn build(&self) -> SetPassportElementErrors { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetPassportElementErrors> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("setPassportElementErrors", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -27225,7 +34543,9 @@ impl RObject for SetPassword {
 
 
 
-impl RFunction for SetPassword {}
+impl RFunction for SetPassword {
+  type Response = PasswordState;
+}
 
 impl SetPassword {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27233,7 +34553,16 @@ impl SetPassword {
     let mut inner = SetPassword::default();
     inner.td_name = "setPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetPasswordBuilder { inner }
+    RTDSetPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.old_password.is_empty() { missing.push("old_password".to_string()); }
+    missing
   }
 
   pub fn old_password(&self) -> &String { &self.old_password }
@@ -27250,15 +34579,25 @@ impl SetPassword {
 
 #[doc(hidden)]
 pub struct RTDSetPasswordBuilder {
-  inner: SetPassword
+  inner: SetPassword,
+  set: [bool; 1]
 }
 
 impl RTDSetPasswordBuilder {
   pub fn build(&self) -> SetPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("old_password".to_string()); }
+    crate::types::_common::check_missing("setPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn old_password<T: AsRef<str>>(&mut self, old_password: T) -> &mut Self {
     self.inner.old_password = old_password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -27327,7 +34666,9 @@ impl RObject for SetPinnedChats {
 
 
 
-impl RFunction for SetPinnedChats {}
+impl RFunction for SetPinnedChats {
+  type Response = Ok;
+}
 
 impl SetPinnedChats {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27338,6 +34679,15 @@ impl SetPinnedChats {
     RTDSetPinnedChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setPinnedChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    missing
+  }
+
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
 
   pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
@@ -27352,6 +34702,14 @@ pub struct RTDSetPinnedChatsBuilder {
 impl RTDSetPinnedChatsBuilder {
   pub fn build(&self) -> SetPinnedChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetPinnedChats> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    crate::types::_common::check_missing("setPinnedChats", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -27407,7 +34765,9 @@ impl RObject for SetPollAnswer {
 
 
 
-impl RFunction for SetPollAnswer {}
+impl RFunction for SetPollAnswer {
+  type Response = Ok;
+}
 
 impl SetPollAnswer {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27415,7 +34775,17 @@ impl SetPollAnswer {
     let mut inner = SetPollAnswer::default();
     inner.td_name = "setPollAnswer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetPollAnswerBuilder { inner }
+    RTDSetPollAnswerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setPollAnswer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -27428,21 +34798,33 @@ impl SetPollAnswer {
 
 #[doc(hidden)]
 pub struct RTDSetPollAnswerBuilder {
-  inner: SetPollAnswer
+  inner: SetPollAnswer,
+  set: [bool; 2]
 }
 
 impl RTDSetPollAnswerBuilder {
   pub fn build(&self) -> SetPollAnswer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetPollAnswer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("setPollAnswer", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -27491,7 +34873,9 @@ impl RObject for SetProfilePhoto {
 
 
 
-impl RFunction for SetProfilePhoto {}
+impl RFunction for SetProfilePhoto {
+  type Response = Ok;
+}
 
 impl SetProfilePhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27502,6 +34886,15 @@ impl SetProfilePhoto {
     RTDSetProfilePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setProfilePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    missing
+  }
+
   pub fn photo(&self) -> &InputChatPhoto { &self.photo }
 
 }
@@ -27514,6 +34907,14 @@ pub struct RTDSetProfilePhotoBuilder {
 impl RTDSetProfilePhotoBuilder {
   pub fn build(&self) -> SetProfilePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetProfilePhoto> {
+    let mut missing = vec![];
+    if self.inner.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    crate::types::_common::check_missing("setProfilePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<InputChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -27561,7 +34962,9 @@ impl RObject for SetRecoveryEmailAddress {
 
 
 
-impl RFunction for SetRecoveryEmailAddress {}
+impl RFunction for SetRecoveryEmailAddress {
+  type Response = PasswordState;
+}
 
 impl SetRecoveryEmailAddress {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27569,7 +34972,17 @@ impl SetRecoveryEmailAddress {
     let mut inner = SetRecoveryEmailAddress::default();
     inner.td_name = "setRecoveryEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetRecoveryEmailAddressBuilder { inner }
+    RTDSetRecoveryEmailAddressBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setRecoveryEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    if self.new_recovery_email_address.is_empty() { missing.push("new_recovery_email_address".to_string()); }
+    missing
   }
 
   pub fn password(&self) -> &String { &self.password }
@@ -27580,21 +34993,33 @@ impl SetRecoveryEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDSetRecoveryEmailAddressBuilder {
-  inner: SetRecoveryEmailAddress
+  inner: SetRecoveryEmailAddress,
+  set: [bool; 2]
 }
 
 impl RTDSetRecoveryEmailAddressBuilder {
   pub fn build(&self) -> SetRecoveryEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetRecoveryEmailAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("password".to_string()); }
+    if !self.set[1] { missing.push("new_recovery_email_address".to_string()); }
+    crate::types::_common::check_missing("setRecoveryEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn new_recovery_email_address<T: AsRef<str>>(&mut self, new_recovery_email_address: T) -> &mut Self {
     self.inner.new_recovery_email_address = new_recovery_email_address.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -27639,7 +35064,9 @@ impl RObject for SetScopeNotificationSettings {
 
 
 
-impl RFunction for SetScopeNotificationSettings {}
+impl RFunction for SetScopeNotificationSettings {
+  type Response = Ok;
+}
 
 impl SetScopeNotificationSettings {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27647,7 +35074,17 @@ impl SetScopeNotificationSettings {
     let mut inner = SetScopeNotificationSettings::default();
     inner.td_name = "setScopeNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetScopeNotificationSettingsBuilder { inner }
+    RTDSetScopeNotificationSettingsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setScopeNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.scope._missing_fields())); }
+    if false { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.notification_settings._missing_fields())); }
+    missing
   }
 
   pub fn scope(&self) -> &NotificationSettingsScope { &self.scope }
@@ -27658,12 +35095,22 @@ impl SetScopeNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDSetScopeNotificationSettingsBuilder {
-  inner: SetScopeNotificationSettings
+  inner: SetScopeNotificationSettings,
+  set: [bool; 1]
 }
 
 impl RTDSetScopeNotificationSettingsBuilder {
   pub fn build(&self) -> SetScopeNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetScopeNotificationSettings> {
+    let mut missing = vec![];
+    if self.inner.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.inner.scope._missing_fields())); }
+    if !self.set[0] { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.inner.notification_settings._missing_fields())); }
+    crate::types::_common::check_missing("setScopeNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -27673,6 +35120,7 @@ impl RTDSetScopeNotificationSettingsBuilder {
    
   pub fn notification_settings<T: AsRef<ScopeNotificationSettings>>(&mut self, notification_settings: T) -> &mut Self {
     self.inner.notification_settings = notification_settings.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -27717,7 +35165,9 @@ impl RObject for SetStickerPositionInSet {
 
 
 
-impl RFunction for SetStickerPositionInSet {}
+impl RFunction for SetStickerPositionInSet {
+  type Response = Ok;
+}
 
 impl SetStickerPositionInSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27725,7 +35175,17 @@ impl SetStickerPositionInSet {
     let mut inner = SetStickerPositionInSet::default();
     inner.td_name = "setStickerPositionInSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetStickerPositionInSetBuilder { inner }
+    RTDSetStickerPositionInSetBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setStickerPositionInSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    if self.position == 0 { missing.push("position".to_string()); }
+    missing
   }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -27736,12 +35196,22 @@ impl SetStickerPositionInSet {
 
 #[doc(hidden)]
 pub struct RTDSetStickerPositionInSetBuilder {
-  inner: SetStickerPositionInSet
+  inner: SetStickerPositionInSet,
+  set: [bool; 1]
 }
 
 impl RTDSetStickerPositionInSetBuilder {
   pub fn build(&self) -> SetStickerPositionInSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetStickerPositionInSet> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    if !self.set[0] { missing.push("position".to_string()); }
+    crate::types::_common::check_missing("setStickerPositionInSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -27751,6 +35221,7 @@ impl RTDSetStickerPositionInSetBuilder {
    
   pub fn position(&mut self, position: i64) -> &mut Self {
     self.inner.position = position;
+    self.set[0] = true;
     self
   }
 
@@ -27797,7 +35268,9 @@ impl RObject for SetStickerSetThumbnail {
 
 
 
-impl RFunction for SetStickerSetThumbnail {}
+impl RFunction for SetStickerSetThumbnail {
+  type Response = StickerSet;
+}
 
 impl SetStickerSetThumbnail {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27805,7 +35278,18 @@ impl SetStickerSetThumbnail {
     let mut inner = SetStickerSetThumbnail::default();
     inner.td_name = "setStickerSetThumbnail".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetStickerSetThumbnailBuilder { inner }
+    RTDSetStickerSetThumbnailBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setStickerSetThumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.thumbnail._is_default() { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -27818,21 +35302,34 @@ impl SetStickerSetThumbnail {
 
 #[doc(hidden)]
 pub struct RTDSetStickerSetThumbnailBuilder {
-  inner: SetStickerSetThumbnail
+  inner: SetStickerSetThumbnail,
+  set: [bool; 2]
 }
 
 impl RTDSetStickerSetThumbnailBuilder {
   pub fn build(&self) -> SetStickerSetThumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetStickerSetThumbnail> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("name".to_string()); }
+    if self.inner.thumbnail._is_default() { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    crate::types::_common::check_missing("setStickerSetThumbnail", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -27870,7 +35367,7 @@ pub struct SetSupergroupStickerSet {
   /// Identifier of the supergroup
   supergroup_id: i64,
   /// New value of the supergroup sticker set identifier. Use 0 to remove the supergroup sticker set
-  sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] sticker_set_id: i64,
   
 }
 
@@ -27883,7 +35380,9 @@ impl RObject for SetSupergroupStickerSet {
 
 
 
-impl RFunction for SetSupergroupStickerSet {}
+impl RFunction for SetSupergroupStickerSet {
+  type Response = Ok;
+}
 
 impl SetSupergroupStickerSet {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27891,32 +35390,54 @@ impl SetSupergroupStickerSet {
     let mut inner = SetSupergroupStickerSet::default();
     inner.td_name = "setSupergroupStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetSupergroupStickerSetBuilder { inner }
+    RTDSetSupergroupStickerSetBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setSupergroupStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    if self.sticker_set_id == 0 { missing.push("sticker_set_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDSetSupergroupStickerSetBuilder {
-  inner: SetSupergroupStickerSet
+  inner: SetSupergroupStickerSet,
+  set: [bool; 2]
 }
 
 impl RTDSetSupergroupStickerSetBuilder {
   pub fn build(&self) -> SetSupergroupStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetSupergroupStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    if !self.set[1] { missing.push("sticker_set_id".to_string()); }
+    crate::types::_common::check_missing("setSupergroupStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
+    self.set[1] = true;
     self
   }
 
@@ -27961,7 +35482,9 @@ impl RObject for SetSupergroupUsername {
 
 
 
-impl RFunction for SetSupergroupUsername {}
+impl RFunction for SetSupergroupUsername {
+  type Response = Ok;
+}
 
 impl SetSupergroupUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -27969,7 +35492,17 @@ impl SetSupergroupUsername {
     let mut inner = SetSupergroupUsername::default();
     inner.td_name = "setSupergroupUsername".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetSupergroupUsernameBuilder { inner }
+    RTDSetSupergroupUsernameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setSupergroupUsername", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -27980,21 +35513,33 @@ impl SetSupergroupUsername {
 
 #[doc(hidden)]
 pub struct RTDSetSupergroupUsernameBuilder {
-  inner: SetSupergroupUsername
+  inner: SetSupergroupUsername,
+  set: [bool; 2]
 }
 
 impl RTDSetSupergroupUsernameBuilder {
   pub fn build(&self) -> SetSupergroupUsername { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetSupergroupUsername> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    if !self.set[1] { missing.push("username".to_string()); }
+    crate::types::_common::check_missing("setSupergroupUsername", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -28037,7 +35582,9 @@ impl RObject for SetTdlibParameters {
 
 
 
-impl RFunction for SetTdlibParameters {}
+impl RFunction for SetTdlibParameters {
+  type Response = Ok;
+}
 
 impl SetTdlibParameters {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28045,7 +35592,16 @@ impl SetTdlibParameters {
     let mut inner = SetTdlibParameters::default();
     inner.td_name = "setTdlibParameters".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetTdlibParametersBuilder { inner }
+    RTDSetTdlibParametersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setTdlibParameters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("parameters".to_string()); } else { missing.extend(crate::types::_common::missing_in("parameters", self.parameters._missing_fields())); }
+    missing
   }
 
   pub fn parameters(&self) -> &TdlibParameters { &self.parameters }
@@ -28054,15 +35610,25 @@ impl SetTdlibParameters {
 
 #[doc(hidden)]
 pub struct RTDSetTdlibParametersBuilder {
-  inner: SetTdlibParameters
+  inner: SetTdlibParameters,
+  set: [bool; 1]
 }
 
 impl RTDSetTdlibParametersBuilder {
   pub fn build(&self) -> SetTdlibParameters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetTdlibParameters> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("parameters".to_string()); } else { missing.extend(crate::types::_common::missing_in("parameters", self.inner.parameters._missing_fields())); }
+    crate::types::_common::check_missing("setTdlibParameters", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn parameters<T: AsRef<TdlibParameters>>(&mut self, parameters: T) -> &mut Self {
     self.inner.parameters = parameters.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -28107,7 +35673,9 @@ impl RObject for SetUserPrivacySettingRules {
 
 
 
-impl RFunction for SetUserPrivacySettingRules {}
+impl RFunction for SetUserPrivacySettingRules {
+  type Response = Ok;
+}
 
 impl SetUserPrivacySettingRules {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28115,7 +35683,17 @@ impl SetUserPrivacySettingRules {
     let mut inner = SetUserPrivacySettingRules::default();
     inner.td_name = "setUserPrivacySettingRules".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetUserPrivacySettingRulesBuilder { inner }
+    RTDSetUserPrivacySettingRulesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setUserPrivacySettingRules", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.setting._missing_fields())); }
+    if false { missing.push("rules".to_string()); } else { missing.extend(crate::types::_common::missing_in("rules", self.rules._missing_fields())); }
+    missing
   }
 
   pub fn setting(&self) -> &UserPrivacySetting { &self.setting }
@@ -28126,12 +35704,22 @@ impl SetUserPrivacySettingRules {
 
 #[doc(hidden)]
 pub struct RTDSetUserPrivacySettingRulesBuilder {
-  inner: SetUserPrivacySettingRules
+  inner: SetUserPrivacySettingRules,
+  set: [bool; 1]
 }
 
 impl RTDSetUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> SetUserPrivacySettingRules { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetUserPrivacySettingRules> {
+    let mut missing = vec![];
+    if self.inner.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.inner.setting._missing_fields())); }
+    if !self.set[0] { missing.push("rules".to_string()); } else { missing.extend(crate::types::_common::missing_in("rules", self.inner.rules._missing_fields())); }
+    crate::types::_common::check_missing("setUserPrivacySettingRules", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -28141,6 +35729,7 @@ impl RTDSetUserPrivacySettingRulesBuilder {
    
   pub fn rules<T: AsRef<UserPrivacySettingRules>>(&mut self, rules: T) -> &mut Self {
     self.inner.rules = rules.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -28183,7 +35772,9 @@ impl RObject for SetUsername {
 
 
 
-impl RFunction for SetUsername {}
+impl RFunction for SetUsername {
+  type Response = Ok;
+}
 
 impl SetUsername {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28191,7 +35782,16 @@ impl SetUsername {
     let mut inner = SetUsername::default();
     inner.td_name = "setUsername".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSetUsernameBuilder { inner }
+    RTDSetUsernameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("setUsername", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    missing
   }
 
   pub fn username(&self) -> &String { &self.username }
@@ -28200,15 +35800,25 @@ impl SetUsername {
 
 #[doc(hidden)]
 pub struct RTDSetUsernameBuilder {
-  inner: SetUsername
+  inner: SetUsername,
+  set: [bool; 1]
 }
 
 impl RTDSetUsernameBuilder {
   pub fn build(&self) -> SetUsername { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SetUsername> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("username".to_string()); }
+    crate::types::_common::check_missing("setUsername", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -28251,7 +35861,9 @@ impl RObject for SharePhoneNumber {
 
 
 
-impl RFunction for SharePhoneNumber {}
+impl RFunction for SharePhoneNumber {
+  type Response = Ok;
+}
 
 impl SharePhoneNumber {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28259,7 +35871,16 @@ impl SharePhoneNumber {
     let mut inner = SharePhoneNumber::default();
     inner.td_name = "sharePhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSharePhoneNumberBuilder { inner }
+    RTDSharePhoneNumberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sharePhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -28268,15 +35889,25 @@ impl SharePhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDSharePhoneNumberBuilder {
-  inner: SharePhoneNumber
+  inner: SharePhoneNumber,
+  set: [bool; 1]
 }
 
 impl RTDSharePhoneNumberBuilder {
   pub fn build(&self) -> SharePhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SharePhoneNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("sharePhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -28323,7 +35954,9 @@ impl RObject for StopPoll {
 
 
 
-impl RFunction for StopPoll {}
+impl RFunction for StopPoll {
+  type Response = Ok;
+}
 
 impl StopPoll {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28331,7 +35964,17 @@ impl StopPoll {
     let mut inner = StopPoll::default();
     inner.td_name = "stopPoll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStopPollBuilder { inner }
+    RTDStopPollBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("stopPoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -28344,21 +35987,33 @@ impl StopPoll {
 
 #[doc(hidden)]
 pub struct RTDStopPollBuilder {
-  inner: StopPoll
+  inner: StopPoll,
+  set: [bool; 2]
 }
 
 impl RTDStopPollBuilder {
   pub fn build(&self) -> StopPoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StopPoll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("stopPoll", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -28407,7 +36062,9 @@ impl RObject for SynchronizeLanguagePack {
 
 
 
-impl RFunction for SynchronizeLanguagePack {}
+impl RFunction for SynchronizeLanguagePack {
+  type Response = Ok;
+}
 
 impl SynchronizeLanguagePack {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28415,7 +36072,16 @@ impl SynchronizeLanguagePack {
     let mut inner = SynchronizeLanguagePack::default();
     inner.td_name = "synchronizeLanguagePack".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSynchronizeLanguagePackBuilder { inner }
+    RTDSynchronizeLanguagePackBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("synchronizeLanguagePack", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn language_pack_id(&self) -> &String { &self.language_pack_id }
@@ -28424,15 +36090,25 @@ impl SynchronizeLanguagePack {
 
 #[doc(hidden)]
 pub struct RTDSynchronizeLanguagePackBuilder {
-  inner: SynchronizeLanguagePack
+  inner: SynchronizeLanguagePack,
+  set: [bool; 1]
 }
 
 impl RTDSynchronizeLanguagePackBuilder {
   pub fn build(&self) -> SynchronizeLanguagePack { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SynchronizeLanguagePack> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("synchronizeLanguagePack", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -28473,7 +36149,9 @@ impl RObject for TerminateAllOtherSessions {
 
 
 
-impl RFunction for TerminateAllOtherSessions {}
+impl RFunction for TerminateAllOtherSessions {
+  type Response = Ok;
+}
 
 impl TerminateAllOtherSessions {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28484,6 +36162,11 @@ impl TerminateAllOtherSessions {
     RTDTerminateAllOtherSessionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("terminateAllOtherSessions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -28494,6 +36177,9 @@ pub struct RTDTerminateAllOtherSessionsBuilder {
 impl RTDTerminateAllOtherSessionsBuilder {
   pub fn build(&self) -> TerminateAllOtherSessions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TerminateAllOtherSessions> { Ok(self.build()) }
+
 }
 
 impl AsRef<TerminateAllOtherSessions> for TerminateAllOtherSessions {
@@ -28520,7 +36206,7 @@ pub struct TerminateSession {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Session identifier
-  session_id: isize,
+  #[serde(with = "crate::types::_common::int64")] session_id: i64,
   
 }
 
@@ -28533,7 +36219,9 @@ impl RObject for TerminateSession {
 
 
 
-impl RFunction for TerminateSession {}
+impl RFunction for TerminateSession {
+  type Response = Ok;
+}
 
 impl TerminateSession {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28541,24 +36229,43 @@ impl TerminateSession {
     let mut inner = TerminateSession::default();
     inner.td_name = "terminateSession".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTerminateSessionBuilder { inner }
+    RTDTerminateSessionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("terminateSession", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.session_id == 0 { missing.push("session_id".to_string()); }
+    missing
   }
 
-  pub fn session_id(&self) -> isize { self.session_id }
+  pub fn session_id(&self) -> i64 { self.session_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDTerminateSessionBuilder {
-  inner: TerminateSession
+  inner: TerminateSession,
+  set: [bool; 1]
 }
 
 impl RTDTerminateSessionBuilder {
   pub fn build(&self) -> TerminateSession { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TerminateSession> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("session_id".to_string()); }
+    crate::types::_common::check_missing("terminateSession", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn session_id(&mut self, session_id: isize) -> &mut Self {
+  pub fn session_id(&mut self, session_id: i64) -> &mut Self {
     self.inner.session_id = session_id;
+    self.set[0] = true;
     self
   }
 
@@ -28601,7 +36308,9 @@ impl RObject for TestCallBytes {
 
 
 
-impl RFunction for TestCallBytes {}
+impl RFunction for TestCallBytes {
+  type Response = TestBytes;
+}
 
 impl TestCallBytes {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28609,7 +36318,16 @@ impl TestCallBytes {
     let mut inner = TestCallBytes::default();
     inner.td_name = "testCallBytes".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestCallBytesBuilder { inner }
+    RTDTestCallBytesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallBytes", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.x.is_empty() { missing.push("x".to_string()); }
+    missing
   }
 
   pub fn x(&self) -> &String { &self.x }
@@ -28618,15 +36336,25 @@ impl TestCallBytes {
 
 #[doc(hidden)]
 pub struct RTDTestCallBytesBuilder {
-  inner: TestCallBytes
+  inner: TestCallBytes,
+  set: [bool; 1]
 }
 
 impl RTDTestCallBytesBuilder {
   pub fn build(&self) -> TestCallBytes { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallBytes> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("x".to_string()); }
+    crate::types::_common::check_missing("testCallBytes", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn x<T: AsRef<str>>(&mut self, x: T) -> &mut Self {
     self.inner.x = x.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -28667,7 +36395,9 @@ impl RObject for TestCallEmpty {
 
 
 
-impl RFunction for TestCallEmpty {}
+impl RFunction for TestCallEmpty {
+  type Response = Ok;
+}
 
 impl TestCallEmpty {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28678,6 +36408,11 @@ impl TestCallEmpty {
     RTDTestCallEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -28688,6 +36423,9 @@ pub struct RTDTestCallEmptyBuilder {
 impl RTDTestCallEmptyBuilder {
   pub fn build(&self) -> TestCallEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<TestCallEmpty> for TestCallEmpty {
@@ -28727,7 +36465,9 @@ impl RObject for TestCallString {
 
 
 
-impl RFunction for TestCallString {}
+impl RFunction for TestCallString {
+  type Response = TestString;
+}
 
 impl TestCallString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28735,7 +36475,16 @@ impl TestCallString {
     let mut inner = TestCallString::default();
     inner.td_name = "testCallString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestCallStringBuilder { inner }
+    RTDTestCallStringBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.x.is_empty() { missing.push("x".to_string()); }
+    missing
   }
 
   pub fn x(&self) -> &String { &self.x }
@@ -28744,15 +36493,25 @@ impl TestCallString {
 
 #[doc(hidden)]
 pub struct RTDTestCallStringBuilder {
-  inner: TestCallString
+  inner: TestCallString,
+  set: [bool; 1]
 }
 
 impl RTDTestCallStringBuilder {
   pub fn build(&self) -> TestCallString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("x".to_string()); }
+    crate::types::_common::check_missing("testCallString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn x<T: AsRef<str>>(&mut self, x: T) -> &mut Self {
     self.inner.x = x.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -28795,7 +36554,9 @@ impl RObject for TestCallVectorInt {
 
 
 
-impl RFunction for TestCallVectorInt {}
+impl RFunction for TestCallVectorInt {
+  type Response = TestVectorInt;
+}
 
 impl TestCallVectorInt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28806,6 +36567,11 @@ impl TestCallVectorInt {
     RTDTestCallVectorIntBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallVectorInt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn x(&self) -> &Vec<i64> { &self.x }
 
 }
@@ -28818,6 +36584,9 @@ pub struct RTDTestCallVectorIntBuilder {
 impl RTDTestCallVectorIntBuilder {
   pub fn build(&self) -> TestCallVectorInt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallVectorInt> { Ok(self.build()) }
+
    
   pub fn x(&mut self, x: Vec<i64>) -> &mut Self {
     self.inner.x = x;
@@ -28863,7 +36632,9 @@ impl RObject for TestCallVectorIntObject {
 
 
 
-impl RFunction for TestCallVectorIntObject {}
+impl RFunction for TestCallVectorIntObject {
+  type Response = TestVectorIntObject;
+}
 
 impl TestCallVectorIntObject {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28874,6 +36645,11 @@ impl TestCallVectorIntObject {
     RTDTestCallVectorIntObjectBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallVectorIntObject", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn x(&self) -> &Vec<TestInt> { &self.x }
 
 }
@@ -28886,6 +36662,9 @@ pub struct RTDTestCallVectorIntObjectBuilder {
 impl RTDTestCallVectorIntObjectBuilder {
   pub fn build(&self) -> TestCallVectorIntObject { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallVectorIntObject> { Ok(self.build()) }
+
    
   pub fn x(&mut self, x: Vec<TestInt>) -> &mut Self {
     self.inner.x = x;
@@ -28931,7 +36710,9 @@ impl RObject for TestCallVectorString {
 
 
 
-impl RFunction for TestCallVectorString {}
+impl RFunction for TestCallVectorString {
+  type Response = TestVectorString;
+}
 
 impl TestCallVectorString {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -28942,6 +36723,11 @@ impl TestCallVectorString {
     RTDTestCallVectorStringBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallVectorString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn x(&self) -> &Vec<String> { &self.x }
 
 }
@@ -28954,6 +36740,9 @@ pub struct RTDTestCallVectorStringBuilder {
 impl RTDTestCallVectorStringBuilder {
   pub fn build(&self) -> TestCallVectorString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallVectorString> { Ok(self.build()) }
+
    
   pub fn x(&mut self, x: Vec<String>) -> &mut Self {
     self.inner.x = x;
@@ -28999,7 +36788,9 @@ impl RObject for TestCallVectorStringObject {
 
 
 
-impl RFunction for TestCallVectorStringObject {}
+impl RFunction for TestCallVectorStringObject {
+  type Response = TestVectorStringObject;
+}
 
 impl TestCallVectorStringObject {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29010,6 +36801,11 @@ impl TestCallVectorStringObject {
     RTDTestCallVectorStringObjectBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testCallVectorStringObject", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn x(&self) -> &Vec<TestString> { &self.x }
 
 }
@@ -29022,6 +36818,9 @@ pub struct RTDTestCallVectorStringObjectBuilder {
 impl RTDTestCallVectorStringObjectBuilder {
   pub fn build(&self) -> TestCallVectorStringObject { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestCallVectorStringObject> { Ok(self.build()) }
+
    
   pub fn x(&mut self, x: Vec<TestString>) -> &mut Self {
     self.inner.x = x;
@@ -29065,7 +36864,9 @@ impl RObject for TestGetDifference {
 
 
 
-impl RFunction for TestGetDifference {}
+impl RFunction for TestGetDifference {
+  type Response = Ok;
+}
 
 impl TestGetDifference {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29076,6 +36877,11 @@ impl TestGetDifference {
     RTDTestGetDifferenceBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testGetDifference", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -29086,6 +36892,9 @@ pub struct RTDTestGetDifferenceBuilder {
 impl RTDTestGetDifferenceBuilder {
   pub fn build(&self) -> TestGetDifference { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestGetDifference> { Ok(self.build()) }
+
 }
 
 impl AsRef<TestGetDifference> for TestGetDifference {
@@ -29123,7 +36932,9 @@ impl RObject for TestNetwork {
 
 
 
-impl RFunction for TestNetwork {}
+impl RFunction for TestNetwork {
+  type Response = Ok;
+}
 
 impl TestNetwork {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29134,6 +36945,11 @@ impl TestNetwork {
     RTDTestNetworkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testNetwork", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -29144,6 +36960,9 @@ pub struct RTDTestNetworkBuilder {
 impl RTDTestNetworkBuilder {
   pub fn build(&self) -> TestNetwork { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestNetwork> { Ok(self.build()) }
+
 }
 
 impl AsRef<TestNetwork> for TestNetwork {
@@ -29191,7 +37010,9 @@ impl RObject for TestProxy {
 
 
 
-impl RFunction for TestProxy {}
+impl RFunction for TestProxy {
+  type Response = Ok;
+}
 
 impl TestProxy {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29199,7 +37020,20 @@ impl TestProxy {
     let mut inner = TestProxy::default();
     inner.td_name = "testProxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestProxyBuilder { inner }
+    RTDTestProxyBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testProxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.server.is_empty() { missing.push("server".to_string()); }
+    if self.port == 0 { missing.push("port".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.dc_id == 0 { missing.push("dc_id".to_string()); }
+    if self.timeout == 0.0 { missing.push("timeout".to_string()); }
+    missing
   }
 
   pub fn server(&self) -> &String { &self.server }
@@ -29216,21 +37050,36 @@ impl TestProxy {
 
 #[doc(hidden)]
 pub struct RTDTestProxyBuilder {
-  inner: TestProxy
+  inner: TestProxy,
+  set: [bool; 4]
 }
 
 impl RTDTestProxyBuilder {
   pub fn build(&self) -> TestProxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestProxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("server".to_string()); }
+    if !self.set[1] { missing.push("port".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[2] { missing.push("dc_id".to_string()); }
+    if !self.set[3] { missing.push("timeout".to_string()); }
+    crate::types::_common::check_missing("testProxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn port(&mut self, port: i64) -> &mut Self {
     self.inner.port = port;
+    self.set[1] = true;
     self
   }
 
@@ -29243,12 +37092,14 @@ impl RTDTestProxyBuilder {
    
   pub fn dc_id(&mut self, dc_id: i64) -> &mut Self {
     self.inner.dc_id = dc_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn timeout(&mut self, timeout: f32) -> &mut Self {
     self.inner.timeout = timeout;
+    self.set[3] = true;
     self
   }
 
@@ -29291,7 +37142,9 @@ impl RObject for TestReturnError {
 
 
 
-impl RFunction for TestReturnError {}
+impl RFunction for TestReturnError {
+  type Response = Error;
+}
 
 impl TestReturnError {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29299,7 +37152,16 @@ impl TestReturnError {
     let mut inner = TestReturnError::default();
     inner.td_name = "testReturnError".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestReturnErrorBuilder { inner }
+    RTDTestReturnErrorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testReturnError", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("error".to_string()); } else { missing.extend(crate::types::_common::missing_in("error", self.error._missing_fields())); }
+    missing
   }
 
   pub fn error(&self) -> &Error { &self.error }
@@ -29308,15 +37170,25 @@ impl TestReturnError {
 
 #[doc(hidden)]
 pub struct RTDTestReturnErrorBuilder {
-  inner: TestReturnError
+  inner: TestReturnError,
+  set: [bool; 1]
 }
 
 impl RTDTestReturnErrorBuilder {
   pub fn build(&self) -> TestReturnError { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestReturnError> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("error".to_string()); } else { missing.extend(crate::types::_common::missing_in("error", self.inner.error._missing_fields())); }
+    crate::types::_common::check_missing("testReturnError", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
     self.inner.error = error.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -29359,7 +37231,9 @@ impl RObject for TestSquareInt {
 
 
 
-impl RFunction for TestSquareInt {}
+impl RFunction for TestSquareInt {
+  type Response = TestInt;
+}
 
 impl TestSquareInt {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29367,7 +37241,16 @@ impl TestSquareInt {
     let mut inner = TestSquareInt::default();
     inner.td_name = "testSquareInt".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestSquareIntBuilder { inner }
+    RTDTestSquareIntBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testSquareInt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.x == 0 { missing.push("x".to_string()); }
+    missing
   }
 
   pub fn x(&self) -> i64 { self.x }
@@ -29376,15 +37259,25 @@ impl TestSquareInt {
 
 #[doc(hidden)]
 pub struct RTDTestSquareIntBuilder {
-  inner: TestSquareInt
+  inner: TestSquareInt,
+  set: [bool; 1]
 }
 
 impl RTDTestSquareIntBuilder {
   pub fn build(&self) -> TestSquareInt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestSquareInt> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("x".to_string()); }
+    crate::types::_common::check_missing("testSquareInt", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn x(&mut self, x: i64) -> &mut Self {
     self.inner.x = x;
+    self.set[0] = true;
     self
   }
 
@@ -29423,9 +37316,11 @@ impl RObject for TestUseUpdate {
 }
 
 
-impl TDUpdate for TestUseUpdate {}
 
-impl RFunction for TestUseUpdate {}
+
+impl RFunction for TestUseUpdate {
+  type Response = Update;
+}
 
 impl TestUseUpdate {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29436,6 +37331,11 @@ impl TestUseUpdate {
     RTDTestUseUpdateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testUseUpdate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -29446,6 +37346,9 @@ pub struct RTDTestUseUpdateBuilder {
 impl RTDTestUseUpdateBuilder {
   pub fn build(&self) -> TestUseUpdate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestUseUpdate> { Ok(self.build()) }
+
 }
 
 impl AsRef<TestUseUpdate> for TestUseUpdate {
@@ -29487,7 +37390,9 @@ impl RObject for ToggleChatDefaultDisableNotification {
 
 
 
-impl RFunction for ToggleChatDefaultDisableNotification {}
+impl RFunction for ToggleChatDefaultDisableNotification {
+  type Response = Ok;
+}
 
 impl ToggleChatDefaultDisableNotification {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29495,7 +37400,16 @@ impl ToggleChatDefaultDisableNotification {
     let mut inner = ToggleChatDefaultDisableNotification::default();
     inner.td_name = "toggleChatDefaultDisableNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleChatDefaultDisableNotificationBuilder { inner }
+    RTDToggleChatDefaultDisableNotificationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleChatDefaultDisableNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -29506,15 +37420,25 @@ impl ToggleChatDefaultDisableNotification {
 
 #[doc(hidden)]
 pub struct RTDToggleChatDefaultDisableNotificationBuilder {
-  inner: ToggleChatDefaultDisableNotification
+  inner: ToggleChatDefaultDisableNotification,
+  set: [bool; 1]
 }
 
 impl RTDToggleChatDefaultDisableNotificationBuilder {
   pub fn build(&self) -> ToggleChatDefaultDisableNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleChatDefaultDisableNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("toggleChatDefaultDisableNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -29565,7 +37489,9 @@ impl RObject for ToggleChatIsMarkedAsUnread {
 
 
 
-impl RFunction for ToggleChatIsMarkedAsUnread {}
+impl RFunction for ToggleChatIsMarkedAsUnread {
+  type Response = Ok;
+}
 
 impl ToggleChatIsMarkedAsUnread {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29573,7 +37499,16 @@ impl ToggleChatIsMarkedAsUnread {
     let mut inner = ToggleChatIsMarkedAsUnread::default();
     inner.td_name = "toggleChatIsMarkedAsUnread".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleChatIsMarkedAsUnreadBuilder { inner }
+    RTDToggleChatIsMarkedAsUnreadBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleChatIsMarkedAsUnread", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -29584,15 +37519,25 @@ impl ToggleChatIsMarkedAsUnread {
 
 #[doc(hidden)]
 pub struct RTDToggleChatIsMarkedAsUnreadBuilder {
-  inner: ToggleChatIsMarkedAsUnread
+  inner: ToggleChatIsMarkedAsUnread,
+  set: [bool; 1]
 }
 
 impl RTDToggleChatIsMarkedAsUnreadBuilder {
   pub fn build(&self) -> ToggleChatIsMarkedAsUnread { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleChatIsMarkedAsUnread> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("toggleChatIsMarkedAsUnread", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -29645,7 +37590,9 @@ impl RObject for ToggleChatIsPinned {
 
 
 
-impl RFunction for ToggleChatIsPinned {}
+impl RFunction for ToggleChatIsPinned {
+  type Response = Ok;
+}
 
 impl ToggleChatIsPinned {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29653,7 +37600,17 @@ impl ToggleChatIsPinned {
     let mut inner = ToggleChatIsPinned::default();
     inner.td_name = "toggleChatIsPinned".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleChatIsPinnedBuilder { inner }
+    RTDToggleChatIsPinnedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleChatIsPinned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
@@ -29666,12 +37623,22 @@ impl ToggleChatIsPinned {
 
 #[doc(hidden)]
 pub struct RTDToggleChatIsPinnedBuilder {
-  inner: ToggleChatIsPinned
+  inner: ToggleChatIsPinned,
+  set: [bool; 1]
 }
 
 impl RTDToggleChatIsPinnedBuilder {
   pub fn build(&self) -> ToggleChatIsPinned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleChatIsPinned> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("toggleChatIsPinned", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -29681,6 +37648,7 @@ impl RTDToggleChatIsPinnedBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -29731,7 +37699,9 @@ impl RObject for ToggleGroupCallMuteNewParticipants {
 
 
 
-impl RFunction for ToggleGroupCallMuteNewParticipants {}
+impl RFunction for ToggleGroupCallMuteNewParticipants {
+  type Response = Ok;
+}
 
 impl ToggleGroupCallMuteNewParticipants {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29739,7 +37709,16 @@ impl ToggleGroupCallMuteNewParticipants {
     let mut inner = ToggleGroupCallMuteNewParticipants::default();
     inner.td_name = "toggleGroupCallMuteNewParticipants".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleGroupCallMuteNewParticipantsBuilder { inner }
+    RTDToggleGroupCallMuteNewParticipantsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleGroupCallMuteNewParticipants", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -29750,15 +37729,25 @@ impl ToggleGroupCallMuteNewParticipants {
 
 #[doc(hidden)]
 pub struct RTDToggleGroupCallMuteNewParticipantsBuilder {
-  inner: ToggleGroupCallMuteNewParticipants
+  inner: ToggleGroupCallMuteNewParticipants,
+  set: [bool; 1]
 }
 
 impl RTDToggleGroupCallMuteNewParticipantsBuilder {
   pub fn build(&self) -> ToggleGroupCallMuteNewParticipants { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleGroupCallMuteNewParticipants> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("toggleGroupCallMuteNewParticipants", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -29811,7 +37800,9 @@ impl RObject for ToggleGroupCallParticipantIsMuted {
 
 
 
-impl RFunction for ToggleGroupCallParticipantIsMuted {}
+impl RFunction for ToggleGroupCallParticipantIsMuted {
+  type Response = Ok;
+}
 
 impl ToggleGroupCallParticipantIsMuted {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29819,7 +37810,17 @@ impl ToggleGroupCallParticipantIsMuted {
     let mut inner = ToggleGroupCallParticipantIsMuted::default();
     inner.td_name = "toggleGroupCallParticipantIsMuted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleGroupCallParticipantIsMutedBuilder { inner }
+    RTDToggleGroupCallParticipantIsMutedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleGroupCallParticipantIsMuted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -29832,21 +37833,33 @@ impl ToggleGroupCallParticipantIsMuted {
 
 #[doc(hidden)]
 pub struct RTDToggleGroupCallParticipantIsMutedBuilder {
-  inner: ToggleGroupCallParticipantIsMuted
+  inner: ToggleGroupCallParticipantIsMuted,
+  set: [bool; 2]
 }
 
 impl RTDToggleGroupCallParticipantIsMutedBuilder {
   pub fn build(&self) -> ToggleGroupCallParticipantIsMuted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleGroupCallParticipantIsMuted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("toggleGroupCallParticipantIsMuted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -29897,7 +37910,9 @@ impl RObject for ToggleMessageSenderIsBlocked {
 
 
 
-impl RFunction for ToggleMessageSenderIsBlocked {}
+impl RFunction for ToggleMessageSenderIsBlocked {
+  type Response = Ok;
+}
 
 impl ToggleMessageSenderIsBlocked {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29908,6 +37923,15 @@ impl ToggleMessageSenderIsBlocked {
     RTDToggleMessageSenderIsBlockedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleMessageSenderIsBlocked", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.sender._missing_fields())); }
+    missing
+  }
+
   pub fn sender(&self) -> &MessageSender { &self.sender }
 
   pub fn is_blocked(&self) -> bool { self.is_blocked }
@@ -29922,6 +37946,14 @@ pub struct RTDToggleMessageSenderIsBlockedBuilder {
 impl RTDToggleMessageSenderIsBlockedBuilder {
   pub fn build(&self) -> ToggleMessageSenderIsBlocked { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleMessageSenderIsBlocked> {
+    let mut missing = vec![];
+    if self.inner.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.inner.sender._missing_fields())); }
+    crate::types::_common::check_missing("toggleMessageSenderIsBlocked", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender<T: AsRef<MessageSender>>(&mut self, sender: T) -> &mut Self {
     self.inner.sender = sender.as_ref().clone();
@@ -29975,7 +38007,9 @@ impl RObject for ToggleSupergroupIsAllHistoryAvailable {
 
 
 
-impl RFunction for ToggleSupergroupIsAllHistoryAvailable {}
+impl RFunction for ToggleSupergroupIsAllHistoryAvailable {
+  type Response = Ok;
+}
 
 impl ToggleSupergroupIsAllHistoryAvailable {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -29983,7 +38017,16 @@ impl ToggleSupergroupIsAllHistoryAvailable {
     let mut inner = ToggleSupergroupIsAllHistoryAvailable::default();
     inner.td_name = "toggleSupergroupIsAllHistoryAvailable".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleSupergroupIsAllHistoryAvailableBuilder { inner }
+    RTDToggleSupergroupIsAllHistoryAvailableBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleSupergroupIsAllHistoryAvailable", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -29994,15 +38037,25 @@ impl ToggleSupergroupIsAllHistoryAvailable {
 
 #[doc(hidden)]
 pub struct RTDToggleSupergroupIsAllHistoryAvailableBuilder {
-  inner: ToggleSupergroupIsAllHistoryAvailable
+  inner: ToggleSupergroupIsAllHistoryAvailable,
+  set: [bool; 1]
 }
 
 impl RTDToggleSupergroupIsAllHistoryAvailableBuilder {
   pub fn build(&self) -> ToggleSupergroupIsAllHistoryAvailable { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleSupergroupIsAllHistoryAvailable> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("toggleSupergroupIsAllHistoryAvailable", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -30053,7 +38106,9 @@ impl RObject for ToggleSupergroupSignMessages {
 
 
 
-impl RFunction for ToggleSupergroupSignMessages {}
+impl RFunction for ToggleSupergroupSignMessages {
+  type Response = Ok;
+}
 
 impl ToggleSupergroupSignMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30061,7 +38116,16 @@ impl ToggleSupergroupSignMessages {
     let mut inner = ToggleSupergroupSignMessages::default();
     inner.td_name = "toggleSupergroupSignMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDToggleSupergroupSignMessagesBuilder { inner }
+    RTDToggleSupergroupSignMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("toggleSupergroupSignMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -30072,15 +38136,25 @@ impl ToggleSupergroupSignMessages {
 
 #[doc(hidden)]
 pub struct RTDToggleSupergroupSignMessagesBuilder {
-  inner: ToggleSupergroupSignMessages
+  inner: ToggleSupergroupSignMessages,
+  set: [bool; 1]
 }
 
 impl RTDToggleSupergroupSignMessagesBuilder {
   pub fn build(&self) -> ToggleSupergroupSignMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ToggleSupergroupSignMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("toggleSupergroupSignMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -30133,7 +38207,9 @@ impl RObject for TransferChatOwnership {
 
 
 
-impl RFunction for TransferChatOwnership {}
+impl RFunction for TransferChatOwnership {
+  type Response = Ok;
+}
 
 impl TransferChatOwnership {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30141,7 +38217,18 @@ impl TransferChatOwnership {
     let mut inner = TransferChatOwnership::default();
     inner.td_name = "transferChatOwnership".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTransferChatOwnershipBuilder { inner }
+    RTDTransferChatOwnershipBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("transferChatOwnership", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.password.is_empty() { missing.push("password".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30154,27 +38241,41 @@ impl TransferChatOwnership {
 
 #[doc(hidden)]
 pub struct RTDTransferChatOwnershipBuilder {
-  inner: TransferChatOwnership
+  inner: TransferChatOwnership,
+  set: [bool; 3]
 }
 
 impl RTDTransferChatOwnershipBuilder {
   pub fn build(&self) -> TransferChatOwnership { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TransferChatOwnership> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if !self.set[2] { missing.push("password".to_string()); }
+    crate::types::_common::check_missing("transferChatOwnership", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn password<T: AsRef<str>>(&mut self, password: T) -> &mut Self {
     self.inner.password = password.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -30217,7 +38318,9 @@ impl RObject for UnpinAllChatMessages {
 
 
 
-impl RFunction for UnpinAllChatMessages {}
+impl RFunction for UnpinAllChatMessages {
+  type Response = Ok;
+}
 
 impl UnpinAllChatMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30225,7 +38328,16 @@ impl UnpinAllChatMessages {
     let mut inner = UnpinAllChatMessages::default();
     inner.td_name = "unpinAllChatMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUnpinAllChatMessagesBuilder { inner }
+    RTDUnpinAllChatMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("unpinAllChatMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30234,15 +38346,25 @@ impl UnpinAllChatMessages {
 
 #[doc(hidden)]
 pub struct RTDUnpinAllChatMessagesBuilder {
-  inner: UnpinAllChatMessages
+  inner: UnpinAllChatMessages,
+  set: [bool; 1]
 }
 
 impl RTDUnpinAllChatMessagesBuilder {
   pub fn build(&self) -> UnpinAllChatMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UnpinAllChatMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("unpinAllChatMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -30287,7 +38409,9 @@ impl RObject for UnpinChatMessage {
 
 
 
-impl RFunction for UnpinChatMessage {}
+impl RFunction for UnpinChatMessage {
+  type Response = Ok;
+}
 
 impl UnpinChatMessage {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30295,7 +38419,17 @@ impl UnpinChatMessage {
     let mut inner = UnpinChatMessage::default();
     inner.td_name = "unpinChatMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUnpinChatMessageBuilder { inner }
+    RTDUnpinChatMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("unpinChatMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30306,21 +38440,33 @@ impl UnpinChatMessage {
 
 #[doc(hidden)]
 pub struct RTDUnpinChatMessageBuilder {
-  inner: UnpinChatMessage
+  inner: UnpinChatMessage,
+  set: [bool; 2]
 }
 
 impl RTDUnpinChatMessageBuilder {
   pub fn build(&self) -> UnpinChatMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UnpinChatMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("unpinChatMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -30363,7 +38509,9 @@ impl RObject for UpgradeBasicGroupChatToSupergroupChat {
 
 
 
-impl RFunction for UpgradeBasicGroupChatToSupergroupChat {}
+impl RFunction for UpgradeBasicGroupChatToSupergroupChat {
+  type Response = Chat;
+}
 
 impl UpgradeBasicGroupChatToSupergroupChat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30371,7 +38519,16 @@ impl UpgradeBasicGroupChatToSupergroupChat {
     let mut inner = UpgradeBasicGroupChatToSupergroupChat::default();
     inner.td_name = "upgradeBasicGroupChatToSupergroupChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpgradeBasicGroupChatToSupergroupChatBuilder { inner }
+    RTDUpgradeBasicGroupChatToSupergroupChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("upgradeBasicGroupChatToSupergroupChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30380,15 +38537,25 @@ impl UpgradeBasicGroupChatToSupergroupChat {
 
 #[doc(hidden)]
 pub struct RTDUpgradeBasicGroupChatToSupergroupChatBuilder {
-  inner: UpgradeBasicGroupChatToSupergroupChat
+  inner: UpgradeBasicGroupChatToSupergroupChat,
+  set: [bool; 1]
 }
 
 impl RTDUpgradeBasicGroupChatToSupergroupChatBuilder {
   pub fn build(&self) -> UpgradeBasicGroupChatToSupergroupChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpgradeBasicGroupChatToSupergroupChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("upgradeBasicGroupChatToSupergroupChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -30435,7 +38602,9 @@ impl RObject for UploadFile {
 
 
 
-impl RFunction for UploadFile {}
+impl RFunction for UploadFile {
+  type Response = File;
+}
 
 impl UploadFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30443,7 +38612,18 @@ impl UploadFile {
     let mut inner = UploadFile::default();
     inner.td_name = "uploadFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUploadFileBuilder { inner }
+    RTDUploadFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("uploadFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file._is_default() { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.file._missing_fields())); }
+    if self.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.file_type._missing_fields())); }
+    if self.priority == 0 { missing.push("priority".to_string()); }
+    missing
   }
 
   pub fn file(&self) -> &InputFile { &self.file }
@@ -30456,12 +38636,23 @@ impl UploadFile {
 
 #[doc(hidden)]
 pub struct RTDUploadFileBuilder {
-  inner: UploadFile
+  inner: UploadFile,
+  set: [bool; 1]
 }
 
 impl RTDUploadFileBuilder {
   pub fn build(&self) -> UploadFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UploadFile> {
+    let mut missing = vec![];
+    if self.inner.file._is_default() { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.inner.file._missing_fields())); }
+    if self.inner.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.inner.file_type._missing_fields())); }
+    if !self.set[0] { missing.push("priority".to_string()); }
+    crate::types::_common::check_missing("uploadFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file<T: AsRef<InputFile>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
@@ -30477,6 +38668,7 @@ impl RTDUploadFileBuilder {
    
   pub fn priority(&mut self, priority: i64) -> &mut Self {
     self.inner.priority = priority;
+    self.set[0] = true;
     self
   }
 
@@ -30521,7 +38713,9 @@ impl RObject for UploadStickerFile {
 
 
 
-impl RFunction for UploadStickerFile {}
+impl RFunction for UploadStickerFile {
+  type Response = File;
+}
 
 impl UploadStickerFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30529,7 +38723,17 @@ impl UploadStickerFile {
     let mut inner = UploadStickerFile::default();
     inner.td_name = "uploadStickerFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUploadStickerFileBuilder { inner }
+    RTDUploadStickerFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("uploadStickerFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.png_sticker._is_default() { missing.push("png_sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("png_sticker", self.png_sticker._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -30540,15 +38744,26 @@ impl UploadStickerFile {
 
 #[doc(hidden)]
 pub struct RTDUploadStickerFileBuilder {
-  inner: UploadStickerFile
+  inner: UploadStickerFile,
+  set: [bool; 1]
 }
 
 impl RTDUploadStickerFileBuilder {
   pub fn build(&self) -> UploadStickerFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UploadStickerFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if self.inner.png_sticker._is_default() { missing.push("png_sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("png_sticker", self.inner.png_sticker._missing_fields())); }
+    crate::types::_common::check_missing("uploadStickerFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -30603,7 +38818,9 @@ impl RObject for ValidateOrderInfo {
 
 
 
-impl RFunction for ValidateOrderInfo {}
+impl RFunction for ValidateOrderInfo {
+  type Response = ValidatedOrderInfo;
+}
 
 impl ValidateOrderInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30611,7 +38828,18 @@ impl ValidateOrderInfo {
     let mut inner = ValidateOrderInfo::default();
     inner.td_name = "validateOrderInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDValidateOrderInfoBuilder { inner }
+    RTDValidateOrderInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("validateOrderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if false { missing.push("order_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("order_info", self.order_info._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30626,27 +38854,41 @@ impl ValidateOrderInfo {
 
 #[doc(hidden)]
 pub struct RTDValidateOrderInfoBuilder {
-  inner: ValidateOrderInfo
+  inner: ValidateOrderInfo,
+  set: [bool; 3]
 }
 
 impl RTDValidateOrderInfoBuilder {
   pub fn build(&self) -> ValidateOrderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ValidateOrderInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("order_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("order_info", self.inner.order_info._missing_fields())); }
+    crate::types::_common::check_missing("validateOrderInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn order_info<T: AsRef<OrderInfo>>(&mut self, order_info: T) -> &mut Self {
     self.inner.order_info = order_info.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -30701,7 +38943,9 @@ impl RObject for ViewMessages {
 
 
 
-impl RFunction for ViewMessages {}
+impl RFunction for ViewMessages {
+  type Response = Ok;
+}
 
 impl ViewMessages {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30709,7 +38953,16 @@ impl ViewMessages {
     let mut inner = ViewMessages::default();
     inner.td_name = "viewMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDViewMessagesBuilder { inner }
+    RTDViewMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("viewMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -30724,15 +38977,25 @@ impl ViewMessages {
 
 #[doc(hidden)]
 pub struct RTDViewMessagesBuilder {
-  inner: ViewMessages
+  inner: ViewMessages,
+  set: [bool; 1]
 }
 
 impl RTDViewMessagesBuilder {
   pub fn build(&self) -> ViewMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ViewMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("viewMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -30780,7 +39043,7 @@ pub struct ViewTrendingStickerSets {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifiers of viewed trending sticker sets
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64")] sticker_set_ids: Vec<i64>,
   
 }
 
@@ -30793,7 +39056,9 @@ impl RObject for ViewTrendingStickerSets {
 
 
 
-impl RFunction for ViewTrendingStickerSets {}
+impl RFunction for ViewTrendingStickerSets {
+  type Response = Ok;
+}
 
 impl ViewTrendingStickerSets {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30804,7 +39069,12 @@ impl ViewTrendingStickerSets {
     RTDViewTrendingStickerSetsBuilder { inner }
   }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("viewTrendingStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -30816,8 +39086,11 @@ pub struct RTDViewTrendingStickerSetsBuilder {
 impl RTDViewTrendingStickerSetsBuilder {
   pub fn build(&self) -> ViewTrendingStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ViewTrendingStickerSets> { Ok(self.build()) }
+
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -30848,7 +39121,7 @@ pub struct WriteGeneratedFilePart {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The identifier of the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   /// The offset from which to write the data to the file
   offset: i64,
   /// The data to write
@@ -30865,7 +39138,9 @@ impl RObject for WriteGeneratedFilePart {
 
 
 
-impl RFunction for WriteGeneratedFilePart {}
+impl RFunction for WriteGeneratedFilePart {
+  type Response = Ok;
+}
 
 impl WriteGeneratedFilePart {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
@@ -30873,10 +39148,21 @@ impl WriteGeneratedFilePart {
     let mut inner = WriteGeneratedFilePart::default();
     inner.td_name = "writeGeneratedFilePart".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDWriteGeneratedFilePartBuilder { inner }
+    RTDWriteGeneratedFilePartBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("writeGeneratedFilePart", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.generation_id == 0 { missing.push("generation_id".to_string()); }
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn offset(&self) -> i64 { self.offset }
 
@@ -30886,27 +39172,41 @@ impl WriteGeneratedFilePart {
 
 #[doc(hidden)]
 pub struct RTDWriteGeneratedFilePartBuilder {
-  inner: WriteGeneratedFilePart
+  inner: WriteGeneratedFilePart,
+  set: [bool; 3]
 }
 
 impl RTDWriteGeneratedFilePartBuilder {
   pub fn build(&self) -> WriteGeneratedFilePart { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<WriteGeneratedFilePart> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("generation_id".to_string()); }
+    if !self.set[1] { missing.push("offset".to_string()); }
+    if !self.set[2] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("writeGeneratedFilePart", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[1] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
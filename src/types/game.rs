@@ -16,7 +16,7 @@ pub struct Game {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Game ID
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}
   short_name: String,
   /// Game title
@@ -46,10 +46,24 @@ impl Game {
     let mut inner = Game::default();
     inner.td_name = "game".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGameBuilder { inner }
+    RTDGameBuilder { inner, set: [false; 6] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("game", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.short_name.is_empty() { missing.push("short_name".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if false { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn short_name(&self) -> &String { &self.short_name }
 
@@ -67,45 +81,65 @@ impl Game {
 
 #[doc(hidden)]
 pub struct RTDGameBuilder {
-  inner: Game
+  inner: Game,
+  set: [bool; 6]
 }
 
 impl RTDGameBuilder {
   pub fn build(&self) -> Game { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Game> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("short_name".to_string()); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[4] { missing.push("description".to_string()); }
+    if !self.set[5] { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    crate::types::_common::check_missing("game", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn short_name<T: AsRef<str>>(&mut self, short_name: T) -> &mut Self {
     self.inner.short_name = short_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
+    self.set[5] = true;
     self
   }
 
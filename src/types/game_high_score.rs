@@ -38,7 +38,18 @@ impl GameHighScore {
     let mut inner = GameHighScore::default();
     inner.td_name = "gameHighScore".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGameHighScoreBuilder { inner }
+    RTDGameHighScoreBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("gameHighScore", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.position == 0 { missing.push("position".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.score == 0 { missing.push("score".to_string()); }
+    missing
   }
 
   pub fn position(&self) -> i64 { self.position }
@@ -51,27 +62,41 @@ impl GameHighScore {
 
 #[doc(hidden)]
 pub struct RTDGameHighScoreBuilder {
-  inner: GameHighScore
+  inner: GameHighScore,
+  set: [bool; 3]
 }
 
 impl RTDGameHighScoreBuilder {
   pub fn build(&self) -> GameHighScore { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GameHighScore> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("position".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if !self.set[2] { missing.push("score".to_string()); }
+    crate::types::_common::check_missing("gameHighScore", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn position(&mut self, position: i64) -> &mut Self {
     self.inner.position = position;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn score(&mut self, score: i64) -> &mut Self {
     self.inner.score = score;
+    self.set[2] = true;
     self
   }
 
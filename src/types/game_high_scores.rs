@@ -37,6 +37,11 @@ impl GameHighScores {
     RTDGameHighScoresBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("gameHighScores", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn scores(&self) -> &Vec<GameHighScore> { &self.scores }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDGameHighScoresBuilder {
 impl RTDGameHighScoresBuilder {
   pub fn build(&self) -> GameHighScores { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GameHighScores> { Ok(self.build()) }
+
    
   pub fn scores(&mut self, scores: Vec<GameHighScore>) -> &mut Self {
     self.inner.scores = scores;
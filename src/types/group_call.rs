@@ -56,7 +56,18 @@ impl GroupCall {
     let mut inner = GroupCall::default();
     inner.td_name = "groupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallBuilder { inner }
+    RTDGroupCallBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.participant_count == 0 { missing.push("participant_count".to_string()); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -87,15 +98,27 @@ impl GroupCall {
 
 #[doc(hidden)]
 pub struct RTDGroupCallBuilder {
-  inner: GroupCall
+  inner: GroupCall,
+  set: [bool; 3]
 }
 
 impl RTDGroupCallBuilder {
   pub fn build(&self) -> GroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("participant_count".to_string()); }
+    if !self.set[2] { missing.push("duration".to_string()); }
+    crate::types::_common::check_missing("groupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -132,6 +155,7 @@ impl RTDGroupCallBuilder {
    
   pub fn participant_count(&mut self, participant_count: i64) -> &mut Self {
     self.inner.participant_count = participant_count;
+    self.set[1] = true;
     self
   }
 
@@ -162,6 +186,7 @@ impl RTDGroupCallBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[2] = true;
     self
   }
 
@@ -34,7 +34,16 @@ impl GroupCallId {
     let mut inner = GroupCallId::default();
     inner.td_name = "groupCallId".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallIdBuilder { inner }
+    RTDGroupCallIdBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallId", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -43,15 +52,25 @@ impl GroupCallId {
 
 #[doc(hidden)]
 pub struct RTDGroupCallIdBuilder {
-  inner: GroupCallId
+  inner: GroupCallId,
+  set: [bool; 1]
 }
 
 impl RTDGroupCallIdBuilder {
   pub fn build(&self) -> GroupCallId { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallId> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    crate::types::_common::check_missing("groupCallId", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl GroupCallJoinResponse {
     let mut inner = GroupCallJoinResponse::default();
     inner.td_name = "groupCallJoinResponse".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallJoinResponseBuilder { inner }
+    RTDGroupCallJoinResponseBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallJoinResponse", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.payload._missing_fields())); }
+    missing
   }
 
   pub fn payload(&self) -> &GroupCallPayload { &self.payload }
@@ -47,15 +56,25 @@ impl GroupCallJoinResponse {
 
 #[doc(hidden)]
 pub struct RTDGroupCallJoinResponseBuilder {
-  inner: GroupCallJoinResponse
+  inner: GroupCallJoinResponse,
+  set: [bool; 1]
 }
 
 impl RTDGroupCallJoinResponseBuilder {
   pub fn build(&self) -> GroupCallJoinResponse { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallJoinResponse> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.inner.payload._missing_fields())); }
+    crate::types::_common::check_missing("groupCallJoinResponse", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn payload<T: AsRef<GroupCallPayload>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -58,7 +58,28 @@ impl GroupCallJoinResponseCandidate {
     let mut inner = GroupCallJoinResponseCandidate::default();
     inner.td_name = "groupCallJoinResponseCandidate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallJoinResponseCandidateBuilder { inner }
+    RTDGroupCallJoinResponseCandidateBuilder { inner, set: [false; 13] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallJoinResponseCandidate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.port.is_empty() { missing.push("port".to_string()); }
+    if self.protocol.is_empty() { missing.push("protocol".to_string()); }
+    if self.network.is_empty() { missing.push("network".to_string()); }
+    if self.generation.is_empty() { missing.push("generation".to_string()); }
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.component.is_empty() { missing.push("component".to_string()); }
+    if self.foundation.is_empty() { missing.push("foundation".to_string()); }
+    if self.priority.is_empty() { missing.push("priority".to_string()); }
+    if self.ip.is_empty() { missing.push("ip".to_string()); }
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if self.tcp_type.is_empty() { missing.push("tcp_type".to_string()); }
+    if self.rel_addr.is_empty() { missing.push("rel_addr".to_string()); }
+    if self.rel_port.is_empty() { missing.push("rel_port".to_string()); }
+    missing
   }
 
   pub fn port(&self) -> &String { &self.port }
@@ -91,87 +112,121 @@ impl GroupCallJoinResponseCandidate {
 
 #[doc(hidden)]
 pub struct RTDGroupCallJoinResponseCandidateBuilder {
-  inner: GroupCallJoinResponseCandidate
+  inner: GroupCallJoinResponseCandidate,
+  set: [bool; 13]
 }
 
 impl RTDGroupCallJoinResponseCandidateBuilder {
   pub fn build(&self) -> GroupCallJoinResponseCandidate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallJoinResponseCandidate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("port".to_string()); }
+    if !self.set[1] { missing.push("protocol".to_string()); }
+    if !self.set[2] { missing.push("network".to_string()); }
+    if !self.set[3] { missing.push("generation".to_string()); }
+    if !self.set[4] { missing.push("id".to_string()); }
+    if !self.set[5] { missing.push("component".to_string()); }
+    if !self.set[6] { missing.push("foundation".to_string()); }
+    if !self.set[7] { missing.push("priority".to_string()); }
+    if !self.set[8] { missing.push("ip".to_string()); }
+    if !self.set[9] { missing.push("type".to_string()); }
+    if !self.set[10] { missing.push("tcp_type".to_string()); }
+    if !self.set[11] { missing.push("rel_addr".to_string()); }
+    if !self.set[12] { missing.push("rel_port".to_string()); }
+    crate::types::_common::check_missing("groupCallJoinResponseCandidate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn port<T: AsRef<str>>(&mut self, port: T) -> &mut Self {
     self.inner.port = port.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn protocol<T: AsRef<str>>(&mut self, protocol: T) -> &mut Self {
     self.inner.protocol = protocol.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn network<T: AsRef<str>>(&mut self, network: T) -> &mut Self {
     self.inner.network = network.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn generation<T: AsRef<str>>(&mut self, generation: T) -> &mut Self {
     self.inner.generation = generation.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn component<T: AsRef<str>>(&mut self, component: T) -> &mut Self {
     self.inner.component = component.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn foundation<T: AsRef<str>>(&mut self, foundation: T) -> &mut Self {
     self.inner.foundation = foundation.as_ref().to_string();
+    self.set[6] = true;
     self
   }
 
    
   pub fn priority<T: AsRef<str>>(&mut self, priority: T) -> &mut Self {
     self.inner.priority = priority.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
    
   pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
     self.inner.ip = ip.as_ref().to_string();
+    self.set[8] = true;
     self
   }
 
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[9] = true;
     self
   }
 
    
   pub fn tcp_type<T: AsRef<str>>(&mut self, tcp_type: T) -> &mut Self {
     self.inner.tcp_type = tcp_type.as_ref().to_string();
+    self.set[10] = true;
     self
   }
 
    
   pub fn rel_addr<T: AsRef<str>>(&mut self, rel_addr: T) -> &mut Self {
     self.inner.rel_addr = rel_addr.as_ref().to_string();
+    self.set[11] = true;
     self
   }
 
    
   pub fn rel_port<T: AsRef<str>>(&mut self, rel_port: T) -> &mut Self {
     self.inner.rel_port = rel_port.as_ref().to_string();
+    self.set[12] = true;
     self
   }
 
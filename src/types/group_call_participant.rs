@@ -38,7 +38,7 @@ pub struct GroupCallParticipant {
   /// Participant's volume level; 1-20000 in hundreds of percents
   volume_level: i64,
   /// User's order in the group call participant list. The bigger is order, the higher is user in the list. If order is 0, the user must be removed from the participant list
-  order: isize,
+  #[serde(with = "crate::types::_common::int64")] order: i64,
   
 }
 
@@ -56,7 +56,19 @@ impl GroupCallParticipant {
     let mut inner = GroupCallParticipant::default();
     inner.td_name = "groupCallParticipant".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallParticipantBuilder { inner }
+    RTDGroupCallParticipantBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallParticipant", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.source == 0 { missing.push("source".to_string()); }
+    if self.volume_level == 0 { missing.push("volume_level".to_string()); }
+    if self.order == 0 { missing.push("order".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -81,27 +93,41 @@ impl GroupCallParticipant {
 
   pub fn volume_level(&self) -> i64 { self.volume_level }
 
-  pub fn order(&self) -> isize { self.order }
+  pub fn order(&self) -> i64 { self.order }
 
 }
 
 #[doc(hidden)]
 pub struct RTDGroupCallParticipantBuilder {
-  inner: GroupCallParticipant
+  inner: GroupCallParticipant,
+  set: [bool; 4]
 }
 
 impl RTDGroupCallParticipantBuilder {
   pub fn build(&self) -> GroupCallParticipant { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallParticipant> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("source".to_string()); }
+    if !self.set[2] { missing.push("volume_level".to_string()); }
+    if !self.set[3] { missing.push("order".to_string()); }
+    crate::types::_common::check_missing("groupCallParticipant", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn source(&mut self, source: i64) -> &mut Self {
     self.inner.source = source;
+    self.set[1] = true;
     self
   }
 
@@ -156,12 +182,14 @@ impl RTDGroupCallParticipantBuilder {
    
   pub fn volume_level(&mut self, volume_level: i64) -> &mut Self {
     self.inner.volume_level = volume_level;
+    self.set[2] = true;
     self
   }
 
    
-  pub fn order(&mut self, order: isize) -> &mut Self {
+  pub fn order(&mut self, order: i64) -> &mut Self {
     self.inner.order = order;
+    self.set[3] = true;
     self
   }
 
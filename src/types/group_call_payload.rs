@@ -38,7 +38,17 @@ impl GroupCallPayload {
     let mut inner = GroupCallPayload::default();
     inner.td_name = "groupCallPayload".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallPayloadBuilder { inner }
+    RTDGroupCallPayloadBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallPayload", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.ufrag.is_empty() { missing.push("ufrag".to_string()); }
+    if self.pwd.is_empty() { missing.push("pwd".to_string()); }
+    missing
   }
 
   pub fn ufrag(&self) -> &String { &self.ufrag }
@@ -51,21 +61,33 @@ impl GroupCallPayload {
 
 #[doc(hidden)]
 pub struct RTDGroupCallPayloadBuilder {
-  inner: GroupCallPayload
+  inner: GroupCallPayload,
+  set: [bool; 2]
 }
 
 impl RTDGroupCallPayloadBuilder {
   pub fn build(&self) -> GroupCallPayload { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallPayload> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("ufrag".to_string()); }
+    if !self.set[1] { missing.push("pwd".to_string()); }
+    crate::types::_common::check_missing("groupCallPayload", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn ufrag<T: AsRef<str>>(&mut self, ufrag: T) -> &mut Self {
     self.inner.ufrag = ufrag.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn pwd<T: AsRef<str>>(&mut self, pwd: T) -> &mut Self {
     self.inner.pwd = pwd.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
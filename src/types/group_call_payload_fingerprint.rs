@@ -38,7 +38,18 @@ impl GroupCallPayloadFingerprint {
     let mut inner = GroupCallPayloadFingerprint::default();
     inner.td_name = "groupCallPayloadFingerprint".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallPayloadFingerprintBuilder { inner }
+    RTDGroupCallPayloadFingerprintBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallPayloadFingerprint", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.hash.is_empty() { missing.push("hash".to_string()); }
+    if self.setup.is_empty() { missing.push("setup".to_string()); }
+    if self.fingerprint.is_empty() { missing.push("fingerprint".to_string()); }
+    missing
   }
 
   pub fn hash(&self) -> &String { &self.hash }
@@ -51,27 +62,41 @@ impl GroupCallPayloadFingerprint {
 
 #[doc(hidden)]
 pub struct RTDGroupCallPayloadFingerprintBuilder {
-  inner: GroupCallPayloadFingerprint
+  inner: GroupCallPayloadFingerprint,
+  set: [bool; 3]
 }
 
 impl RTDGroupCallPayloadFingerprintBuilder {
   pub fn build(&self) -> GroupCallPayloadFingerprint { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallPayloadFingerprint> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("hash".to_string()); }
+    if !self.set[1] { missing.push("setup".to_string()); }
+    if !self.set[2] { missing.push("fingerprint".to_string()); }
+    crate::types::_common::check_missing("groupCallPayloadFingerprint", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn hash<T: AsRef<str>>(&mut self, hash: T) -> &mut Self {
     self.inner.hash = hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn setup<T: AsRef<str>>(&mut self, setup: T) -> &mut Self {
     self.inner.setup = setup.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn fingerprint<T: AsRef<str>>(&mut self, fingerprint: T) -> &mut Self {
     self.inner.fingerprint = fingerprint.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl GroupCallRecentSpeaker {
     let mut inner = GroupCallRecentSpeaker::default();
     inner.td_name = "groupCallRecentSpeaker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDGroupCallRecentSpeakerBuilder { inner }
+    RTDGroupCallRecentSpeakerBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("groupCallRecentSpeaker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -47,15 +56,25 @@ impl GroupCallRecentSpeaker {
 
 #[doc(hidden)]
 pub struct RTDGroupCallRecentSpeakerBuilder {
-  inner: GroupCallRecentSpeaker
+  inner: GroupCallRecentSpeaker,
+  set: [bool; 1]
 }
 
 impl RTDGroupCallRecentSpeakerBuilder {
   pub fn build(&self) -> GroupCallRecentSpeaker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<GroupCallRecentSpeaker> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("groupCallRecentSpeaker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
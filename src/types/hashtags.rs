@@ -37,6 +37,11 @@ impl Hashtags {
     RTDHashtagsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("hashtags", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn hashtags(&self) -> &Vec<String> { &self.hashtags }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDHashtagsBuilder {
 impl RTDHashtagsBuilder {
   pub fn build(&self) -> Hashtags { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Hashtags> { Ok(self.build()) }
+
    
   pub fn hashtags(&mut self, hashtags: Vec<String>) -> &mut Self {
     self.inner.hashtags = hashtags;
@@ -34,7 +34,16 @@ impl HttpUrl {
     let mut inner = HttpUrl::default();
     inner.td_name = "httpUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDHttpUrlBuilder { inner }
+    RTDHttpUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("httpUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -43,15 +52,25 @@ impl HttpUrl {
 
 #[doc(hidden)]
 pub struct RTDHttpUrlBuilder {
-  inner: HttpUrl
+  inner: HttpUrl,
+  set: [bool; 1]
 }
 
 impl RTDHttpUrlBuilder {
   pub fn build(&self) -> HttpUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<HttpUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("httpUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
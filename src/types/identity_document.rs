@@ -44,7 +44,18 @@ impl IdentityDocument {
     let mut inner = IdentityDocument::default();
     inner.td_name = "identityDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDIdentityDocumentBuilder { inner }
+    RTDIdentityDocumentBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("identityDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.number.is_empty() { missing.push("number".to_string()); }
+    if false { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.front_side._missing_fields())); }
+    if false { missing.push("reverse_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("reverse_side", self.reverse_side._missing_fields())); }
+    missing
   }
 
   pub fn number(&self) -> &String { &self.number }
@@ -63,15 +74,27 @@ impl IdentityDocument {
 
 #[doc(hidden)]
 pub struct RTDIdentityDocumentBuilder {
-  inner: IdentityDocument
+  inner: IdentityDocument,
+  set: [bool; 3]
 }
 
 impl RTDIdentityDocumentBuilder {
   pub fn build(&self) -> IdentityDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<IdentityDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("number".to_string()); }
+    if !self.set[1] { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.inner.front_side._missing_fields())); }
+    if !self.set[2] { missing.push("reverse_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("reverse_side", self.inner.reverse_side._missing_fields())); }
+    crate::types::_common::check_missing("identityDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
     self.inner.number = number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -84,12 +107,14 @@ impl RTDIdentityDocumentBuilder {
    
   pub fn front_side<T: AsRef<DatedFile>>(&mut self, front_side: T) -> &mut Self {
     self.inner.front_side = front_side.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn reverse_side<T: AsRef<DatedFile>>(&mut self, reverse_side: T) -> &mut Self {
     self.inner.reverse_side = reverse_side.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
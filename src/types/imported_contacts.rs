@@ -39,6 +39,11 @@ impl ImportedContacts {
     RTDImportedContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("importedContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
 
   pub fn importer_count(&self) -> &Vec<i64> { &self.importer_count }
@@ -53,6 +58,9 @@ pub struct RTDImportedContactsBuilder {
 impl RTDImportedContactsBuilder {
   pub fn build(&self) -> ImportedContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ImportedContacts> { Ok(self.build()) }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
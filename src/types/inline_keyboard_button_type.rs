@@ -19,6 +19,9 @@ pub trait TDInlineKeyboardButtonType: Debug + RObject {}
 #[serde(untagged)]
 pub enum InlineKeyboardButtonType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice
   Buy(InlineKeyboardButtonTypeBuy),
   /// A button that sends a callback query to a bot
@@ -42,7 +45,6 @@ impl Default for InlineKeyboardButtonType {
 
 impl<'de> Deserialize<'de> for InlineKeyboardButtonType {
   fn deserialize<D>(deserializer: D) -> Result<InlineKeyboardButtonType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InlineKeyboardButtonType,
       (inlineKeyboardButtonTypeBuy, Buy);
@@ -80,6 +82,7 @@ impl RObject for InlineKeyboardButtonType {
       InlineKeyboardButtonType::LoginUrl(t) => t.extra(),
       InlineKeyboardButtonType::SwitchInline(t) => t.extra(),
       InlineKeyboardButtonType::Url(t) => t.extra(),
+      InlineKeyboardButtonType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -90,6 +93,18 @@ impl RObject for InlineKeyboardButtonType {
 impl InlineKeyboardButtonType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineKeyboardButtonType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InlineKeyboardButtonType::Buy(t) => t._missing_fields(),
+      InlineKeyboardButtonType::Callback(t) => t._missing_fields(),
+      InlineKeyboardButtonType::CallbackGame(t) => t._missing_fields(),
+      InlineKeyboardButtonType::CallbackWithPassword(t) => t._missing_fields(),
+      InlineKeyboardButtonType::LoginUrl(t) => t._missing_fields(),
+      InlineKeyboardButtonType::SwitchInline(t) => t._missing_fields(),
+      InlineKeyboardButtonType::Url(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_buy(&self) -> bool { if let InlineKeyboardButtonType::Buy(_) = self { true } else { false } }
   pub fn is_callback(&self) -> bool { if let InlineKeyboardButtonType::Callback(_) = self { true } else { false } }
@@ -137,6 +152,34 @@ impl AsRef<InlineKeyboardButtonType> for InlineKeyboardButtonType {
   fn as_ref(&self) -> &InlineKeyboardButtonType { self }
 }
 
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeBuy {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::Buy(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeCallback {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::Callback(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeCallbackGame {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::CallbackGame(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeCallbackWithPassword {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::CallbackWithPassword(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeLoginUrl {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::LoginUrl(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeSwitchInline {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::SwitchInline(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineKeyboardButtonType> for InlineKeyboardButtonTypeUrl {
+  fn from_class(class: &InlineKeyboardButtonType) -> Option<&Self> { match class { InlineKeyboardButtonType::Url(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -175,6 +218,11 @@ impl InlineKeyboardButtonTypeBuy {
     RTDInlineKeyboardButtonTypeBuyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeBuy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +233,9 @@ pub struct RTDInlineKeyboardButtonTypeBuyBuilder {
 impl RTDInlineKeyboardButtonTypeBuyBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeBuy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeBuy> { Ok(self.build()) }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeBuy> for InlineKeyboardButtonTypeBuy {
@@ -232,7 +283,16 @@ impl InlineKeyboardButtonTypeCallback {
     let mut inner = InlineKeyboardButtonTypeCallback::default();
     inner.td_name = "inlineKeyboardButtonTypeCallback".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineKeyboardButtonTypeCallbackBuilder { inner }
+    RTDInlineKeyboardButtonTypeCallbackBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeCallback", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -241,15 +301,25 @@ impl InlineKeyboardButtonTypeCallback {
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeCallbackBuilder {
-  inner: InlineKeyboardButtonTypeCallback
+  inner: InlineKeyboardButtonTypeCallback,
+  set: [bool; 1]
 }
 
 impl RTDInlineKeyboardButtonTypeCallbackBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallback { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeCallback> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("inlineKeyboardButtonTypeCallback", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -301,6 +371,11 @@ impl InlineKeyboardButtonTypeCallbackGame {
     RTDInlineKeyboardButtonTypeCallbackGameBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeCallbackGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -311,6 +386,9 @@ pub struct RTDInlineKeyboardButtonTypeCallbackGameBuilder {
 impl RTDInlineKeyboardButtonTypeCallbackGameBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallbackGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeCallbackGame> { Ok(self.build()) }
+
 }
 
 impl AsRef<InlineKeyboardButtonTypeCallbackGame> for InlineKeyboardButtonTypeCallbackGame {
@@ -358,7 +436,16 @@ impl InlineKeyboardButtonTypeCallbackWithPassword {
     let mut inner = InlineKeyboardButtonTypeCallbackWithPassword::default();
     inner.td_name = "inlineKeyboardButtonTypeCallbackWithPassword".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder { inner }
+    RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeCallbackWithPassword", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -367,15 +454,25 @@ impl InlineKeyboardButtonTypeCallbackWithPassword {
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder {
-  inner: InlineKeyboardButtonTypeCallbackWithPassword
+  inner: InlineKeyboardButtonTypeCallbackWithPassword,
+  set: [bool; 1]
 }
 
 impl RTDInlineKeyboardButtonTypeCallbackWithPasswordBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeCallbackWithPassword { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeCallbackWithPassword> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("inlineKeyboardButtonTypeCallbackWithPassword", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -430,7 +527,18 @@ impl InlineKeyboardButtonTypeLoginUrl {
     let mut inner = InlineKeyboardButtonTypeLoginUrl::default();
     inner.td_name = "inlineKeyboardButtonTypeLoginUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineKeyboardButtonTypeLoginUrlBuilder { inner }
+    RTDInlineKeyboardButtonTypeLoginUrlBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeLoginUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.forward_text.is_empty() { missing.push("forward_text".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -443,27 +551,41 @@ impl InlineKeyboardButtonTypeLoginUrl {
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeLoginUrlBuilder {
-  inner: InlineKeyboardButtonTypeLoginUrl
+  inner: InlineKeyboardButtonTypeLoginUrl,
+  set: [bool; 3]
 }
 
 impl RTDInlineKeyboardButtonTypeLoginUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeLoginUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeLoginUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("id".to_string()); }
+    if !self.set[2] { missing.push("forward_text".to_string()); }
+    crate::types::_common::check_missing("inlineKeyboardButtonTypeLoginUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn forward_text<T: AsRef<str>>(&mut self, forward_text: T) -> &mut Self {
     self.inner.forward_text = forward_text.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -516,7 +638,16 @@ impl InlineKeyboardButtonTypeSwitchInline {
     let mut inner = InlineKeyboardButtonTypeSwitchInline::default();
     inner.td_name = "inlineKeyboardButtonTypeSwitchInline".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineKeyboardButtonTypeSwitchInlineBuilder { inner }
+    RTDInlineKeyboardButtonTypeSwitchInlineBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeSwitchInline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -527,15 +658,25 @@ impl InlineKeyboardButtonTypeSwitchInline {
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
-  inner: InlineKeyboardButtonTypeSwitchInline
+  inner: InlineKeyboardButtonTypeSwitchInline,
+  set: [bool; 1]
 }
 
 impl RTDInlineKeyboardButtonTypeSwitchInlineBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeSwitchInline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeSwitchInline> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("inlineKeyboardButtonTypeSwitchInline", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -592,7 +733,16 @@ impl InlineKeyboardButtonTypeUrl {
     let mut inner = InlineKeyboardButtonTypeUrl::default();
     inner.td_name = "inlineKeyboardButtonTypeUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineKeyboardButtonTypeUrlBuilder { inner }
+    RTDInlineKeyboardButtonTypeUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineKeyboardButtonTypeUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -601,15 +751,25 @@ impl InlineKeyboardButtonTypeUrl {
 
 #[doc(hidden)]
 pub struct RTDInlineKeyboardButtonTypeUrlBuilder {
-  inner: InlineKeyboardButtonTypeUrl
+  inner: InlineKeyboardButtonTypeUrl,
+  set: [bool; 1]
 }
 
 impl RTDInlineKeyboardButtonTypeUrlBuilder {
   pub fn build(&self) -> InlineKeyboardButtonTypeUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineKeyboardButtonTypeUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("inlineKeyboardButtonTypeUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
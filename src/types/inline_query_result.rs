@@ -19,6 +19,9 @@ pub trait TDInlineQueryResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum InlineQueryResult {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents an animation file
   Animation(InlineQueryResultAnimation),
   /// Represents a link to an article or web page
@@ -52,7 +55,6 @@ impl Default for InlineQueryResult {
 
 impl<'de> Deserialize<'de> for InlineQueryResult {
   fn deserialize<D>(deserializer: D) -> Result<InlineQueryResult, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InlineQueryResult,
       (inlineQueryResultAnimation, Animation);
@@ -105,6 +107,7 @@ impl RObject for InlineQueryResult {
       InlineQueryResult::Venue(t) => t.extra(),
       InlineQueryResult::Video(t) => t.extra(),
       InlineQueryResult::VoiceNote(t) => t.extra(),
+      InlineQueryResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -115,6 +118,23 @@ impl RObject for InlineQueryResult {
 impl InlineQueryResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InlineQueryResult::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InlineQueryResult::Animation(t) => t._missing_fields(),
+      InlineQueryResult::Article(t) => t._missing_fields(),
+      InlineQueryResult::Audio(t) => t._missing_fields(),
+      InlineQueryResult::Contact(t) => t._missing_fields(),
+      InlineQueryResult::Document(t) => t._missing_fields(),
+      InlineQueryResult::Game(t) => t._missing_fields(),
+      InlineQueryResult::Location(t) => t._missing_fields(),
+      InlineQueryResult::Photo(t) => t._missing_fields(),
+      InlineQueryResult::Sticker(t) => t._missing_fields(),
+      InlineQueryResult::Venue(t) => t._missing_fields(),
+      InlineQueryResult::Video(t) => t._missing_fields(),
+      InlineQueryResult::VoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let InlineQueryResult::Animation(_) = self { true } else { false } }
   pub fn is_article(&self) -> bool { if let InlineQueryResult::Article(_) = self { true } else { false } }
@@ -187,6 +207,54 @@ impl AsRef<InlineQueryResult> for InlineQueryResult {
   fn as_ref(&self) -> &InlineQueryResult { self }
 }
 
+impl RVariant<InlineQueryResult> for InlineQueryResultAnimation {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultArticle {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Article(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultAudio {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultContact {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Contact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultDocument {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Document(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultGame {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Game(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultLocation {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Location(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultPhoto {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultSticker {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Sticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultVenue {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Venue(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultVideo {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InlineQueryResult> for InlineQueryResultVoiceNote {
+  fn from_class(class: &InlineQueryResult) -> Option<&Self> { match class { InlineQueryResult::VoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -228,7 +296,18 @@ impl InlineQueryResultAnimation {
     let mut inner = InlineQueryResultAnimation::default();
     inner.td_name = "inlineQueryResultAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultAnimationBuilder { inner }
+    RTDInlineQueryResultAnimationBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -241,27 +320,41 @@ impl InlineQueryResultAnimation {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultAnimationBuilder {
-  inner: InlineQueryResultAnimation
+  inner: InlineQueryResultAnimation,
+  set: [bool; 3]
 }
 
 impl RTDInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InlineQueryResultAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultAnimation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -322,7 +415,19 @@ impl InlineQueryResultArticle {
     let mut inner = InlineQueryResultArticle::default();
     inner.td_name = "inlineQueryResultArticle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultArticleBuilder { inner }
+    RTDInlineQueryResultArticleBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultArticle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -341,21 +446,35 @@ impl InlineQueryResultArticle {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultArticleBuilder {
-  inner: InlineQueryResultArticle
+  inner: InlineQueryResultArticle,
+  set: [bool; 4]
 }
 
 impl RTDInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InlineQueryResultArticle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultArticle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultArticle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -368,12 +487,14 @@ impl RTDInlineQueryResultArticleBuilder {
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -432,7 +553,17 @@ impl InlineQueryResultAudio {
     let mut inner = InlineQueryResultAudio::default();
     inner.td_name = "inlineQueryResultAudio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultAudioBuilder { inner }
+    RTDInlineQueryResultAudioBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.audio._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -443,21 +574,33 @@ impl InlineQueryResultAudio {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultAudioBuilder {
-  inner: InlineQueryResultAudio
+  inner: InlineQueryResultAudio,
+  set: [bool; 2]
 }
 
 impl RTDInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InlineQueryResultAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultAudio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.inner.audio._missing_fields())); }
+    crate::types::_common::check_missing("inlineQueryResultAudio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -512,7 +655,17 @@ impl InlineQueryResultContact {
     let mut inner = InlineQueryResultContact::default();
     inner.td_name = "inlineQueryResultContact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultContactBuilder { inner }
+    RTDInlineQueryResultContactBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.contact._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -525,21 +678,33 @@ impl InlineQueryResultContact {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultContactBuilder {
-  inner: InlineQueryResultContact
+  inner: InlineQueryResultContact,
+  set: [bool; 2]
 }
 
 impl RTDInlineQueryResultContactBuilder {
   pub fn build(&self) -> InlineQueryResultContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultContact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.inner.contact._missing_fields())); }
+    crate::types::_common::check_missing("inlineQueryResultContact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -602,7 +767,19 @@ impl InlineQueryResultDocument {
     let mut inner = InlineQueryResultDocument::default();
     inner.td_name = "inlineQueryResultDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultDocumentBuilder { inner }
+    RTDInlineQueryResultDocumentBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.document._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -617,33 +794,49 @@ impl InlineQueryResultDocument {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultDocumentBuilder {
-  inner: InlineQueryResultDocument
+  inner: InlineQueryResultDocument,
+  set: [bool; 4]
 }
 
 impl RTDInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InlineQueryResultDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.inner.document._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -696,7 +889,17 @@ impl InlineQueryResultGame {
     let mut inner = InlineQueryResultGame::default();
     inner.td_name = "inlineQueryResultGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultGameBuilder { inner }
+    RTDInlineQueryResultGameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("game".to_string()); } else { missing.extend(crate::types::_common::missing_in("game", self.game._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -707,21 +910,33 @@ impl InlineQueryResultGame {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultGameBuilder {
-  inner: InlineQueryResultGame
+  inner: InlineQueryResultGame,
+  set: [bool; 2]
 }
 
 impl RTDInlineQueryResultGameBuilder {
   pub fn build(&self) -> InlineQueryResultGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("game".to_string()); } else { missing.extend(crate::types::_common::missing_in("game", self.inner.game._missing_fields())); }
+    crate::types::_common::check_missing("inlineQueryResultGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
     self.inner.game = game.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -778,7 +993,18 @@ impl InlineQueryResultLocation {
     let mut inner = InlineQueryResultLocation::default();
     inner.td_name = "inlineQueryResultLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultLocationBuilder { inner }
+    RTDInlineQueryResultLocationBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -793,27 +1019,41 @@ impl InlineQueryResultLocation {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultLocationBuilder {
-  inner: InlineQueryResultLocation
+  inner: InlineQueryResultLocation,
+  set: [bool; 3]
 }
 
 impl RTDInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InlineQueryResultLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -876,7 +1116,19 @@ impl InlineQueryResultPhoto {
     let mut inner = InlineQueryResultPhoto::default();
     inner.td_name = "inlineQueryResultPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultPhotoBuilder { inner }
+    RTDInlineQueryResultPhotoBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -891,33 +1143,49 @@ impl InlineQueryResultPhoto {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultPhotoBuilder {
-  inner: InlineQueryResultPhoto
+  inner: InlineQueryResultPhoto,
+  set: [bool; 4]
 }
 
 impl RTDInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InlineQueryResultPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -970,7 +1238,17 @@ impl InlineQueryResultSticker {
     let mut inner = InlineQueryResultSticker::default();
     inner.td_name = "inlineQueryResultSticker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultStickerBuilder { inner }
+    RTDInlineQueryResultStickerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -981,21 +1259,33 @@ impl InlineQueryResultSticker {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultStickerBuilder {
-  inner: InlineQueryResultSticker
+  inner: InlineQueryResultSticker,
+  set: [bool; 2]
 }
 
 impl RTDInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InlineQueryResultSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultSticker> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("inlineQueryResultSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1050,7 +1340,17 @@ impl InlineQueryResultVenue {
     let mut inner = InlineQueryResultVenue::default();
     inner.td_name = "inlineQueryResultVenue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultVenueBuilder { inner }
+    RTDInlineQueryResultVenueBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultVenue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.venue._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1063,21 +1363,33 @@ impl InlineQueryResultVenue {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVenueBuilder {
-  inner: InlineQueryResultVenue
+  inner: InlineQueryResultVenue,
+  set: [bool; 2]
 }
 
 impl RTDInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InlineQueryResultVenue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultVenue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.inner.venue._missing_fields())); }
+    crate::types::_common::check_missing("inlineQueryResultVenue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1140,7 +1452,19 @@ impl InlineQueryResultVideo {
     let mut inner = InlineQueryResultVideo::default();
     inner.td_name = "inlineQueryResultVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultVideoBuilder { inner }
+    RTDInlineQueryResultVideoBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.video._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1155,33 +1479,49 @@ impl InlineQueryResultVideo {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVideoBuilder {
-  inner: InlineQueryResultVideo
+  inner: InlineQueryResultVideo,
+  set: [bool; 4]
 }
 
 impl RTDInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InlineQueryResultVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.inner.video._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -1236,7 +1576,18 @@ impl InlineQueryResultVoiceNote {
     let mut inner = InlineQueryResultVoiceNote::default();
     inner.td_name = "inlineQueryResultVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultVoiceNoteBuilder { inner }
+    RTDInlineQueryResultVoiceNoteBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResultVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.voice_note._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1249,27 +1600,41 @@ impl InlineQueryResultVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultVoiceNoteBuilder {
-  inner: InlineQueryResultVoiceNote
+  inner: InlineQueryResultVoiceNote,
+  set: [bool; 3]
 }
 
 impl RTDInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InlineQueryResultVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResultVoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.inner.voice_note._missing_fields())); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResultVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -16,7 +16,7 @@ pub struct InlineQueryResults {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique identifier of the inline query
-  inline_query_id: isize,
+  #[serde(with = "crate::types::_common::int64")] inline_query_id: i64,
   /// The offset for the next request. If empty, there are no more results
   next_offset: String,
   /// Results of the query
@@ -42,10 +42,21 @@ impl InlineQueryResults {
     let mut inner = InlineQueryResults::default();
     inner.td_name = "inlineQueryResults".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInlineQueryResultsBuilder { inner }
+    RTDInlineQueryResultsBuilder { inner, set: [false; 3] }
   }
 
-  pub fn inline_query_id(&self) -> isize { self.inline_query_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inlineQueryResults", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_query_id == 0 { missing.push("inline_query_id".to_string()); }
+    if self.switch_pm_text.is_empty() { missing.push("switch_pm_text".to_string()); }
+    if self.switch_pm_parameter.is_empty() { missing.push("switch_pm_parameter".to_string()); }
+    missing
+  }
+
+  pub fn inline_query_id(&self) -> i64 { self.inline_query_id }
 
   pub fn next_offset(&self) -> &String { &self.next_offset }
 
@@ -59,15 +70,27 @@ impl InlineQueryResults {
 
 #[doc(hidden)]
 pub struct RTDInlineQueryResultsBuilder {
-  inner: InlineQueryResults
+  inner: InlineQueryResults,
+  set: [bool; 3]
 }
 
 impl RTDInlineQueryResultsBuilder {
   pub fn build(&self) -> InlineQueryResults { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InlineQueryResults> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_query_id".to_string()); }
+    if !self.set[1] { missing.push("switch_pm_text".to_string()); }
+    if !self.set[2] { missing.push("switch_pm_parameter".to_string()); }
+    crate::types::_common::check_missing("inlineQueryResults", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn inline_query_id(&mut self, inline_query_id: isize) -> &mut Self {
+  pub fn inline_query_id(&mut self, inline_query_id: i64) -> &mut Self {
     self.inner.inline_query_id = inline_query_id;
+    self.set[0] = true;
     self
   }
 
@@ -86,12 +109,14 @@ impl RTDInlineQueryResultsBuilder {
    
   pub fn switch_pm_text<T: AsRef<str>>(&mut self, switch_pm_text: T) -> &mut Self {
     self.inner.switch_pm_text = switch_pm_text.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn switch_pm_parameter<T: AsRef<str>>(&mut self, switch_pm_parameter: T) -> &mut Self {
     self.inner.switch_pm_parameter = switch_pm_parameter.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
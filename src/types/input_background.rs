@@ -19,6 +19,9 @@ pub trait TDInputBackground: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputBackground {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A background from a local file
   Local(InputBackgroundLocal),
   /// A background from the server
@@ -32,7 +35,6 @@ impl Default for InputBackground {
 
 impl<'de> Deserialize<'de> for InputBackground {
   fn deserialize<D>(deserializer: D) -> Result<InputBackground, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputBackground,
       (inputBackgroundLocal, Local);
@@ -55,6 +57,7 @@ impl RObject for InputBackground {
     match self {
       InputBackground::Local(t) => t.extra(),
       InputBackground::Remote(t) => t.extra(),
+      InputBackground::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for InputBackground {
 impl InputBackground {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputBackground::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputBackground::Local(t) => t._missing_fields(),
+      InputBackground::Remote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_local(&self) -> bool { if let InputBackground::Local(_) = self { true } else { false } }
   pub fn is_remote(&self) -> bool { if let InputBackground::Remote(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<InputBackground> for InputBackground {
   fn as_ref(&self) -> &InputBackground { self }
 }
 
+impl RVariant<InputBackground> for InputBackgroundLocal {
+  fn from_class(class: &InputBackground) -> Option<&Self> { match class { InputBackground::Local(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputBackground> for InputBackgroundRemote {
+  fn from_class(class: &InputBackground) -> Option<&Self> { match class { InputBackground::Remote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -127,6 +145,15 @@ impl InputBackgroundLocal {
     RTDInputBackgroundLocalBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputBackgroundLocal", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.background._is_default() { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.background._missing_fields())); }
+    missing
+  }
+
   pub fn background(&self) -> &InputFile { &self.background }
 
 }
@@ -139,6 +166,14 @@ pub struct RTDInputBackgroundLocalBuilder {
 impl RTDInputBackgroundLocalBuilder {
   pub fn build(&self) -> InputBackgroundLocal { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputBackgroundLocal> {
+    let mut missing = vec![];
+    if self.inner.background._is_default() { missing.push("background".to_string()); } else { missing.extend(crate::types::_common::missing_in("background", self.inner.background._missing_fields())); }
+    crate::types::_common::check_missing("inputBackgroundLocal", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn background<T: AsRef<InputFile>>(&mut self, background: T) -> &mut Self {
     self.inner.background = background.as_ref().clone();
@@ -171,7 +206,7 @@ pub struct InputBackgroundRemote {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The background identifier
-  background_id: isize,
+  #[serde(with = "crate::types::_common::int64")] background_id: i64,
   
 }
 
@@ -192,24 +227,43 @@ impl InputBackgroundRemote {
     let mut inner = InputBackgroundRemote::default();
     inner.td_name = "inputBackgroundRemote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputBackgroundRemoteBuilder { inner }
+    RTDInputBackgroundRemoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputBackgroundRemote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.background_id == 0 { missing.push("background_id".to_string()); }
+    missing
   }
 
-  pub fn background_id(&self) -> isize { self.background_id }
+  pub fn background_id(&self) -> i64 { self.background_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDInputBackgroundRemoteBuilder {
-  inner: InputBackgroundRemote
+  inner: InputBackgroundRemote,
+  set: [bool; 1]
 }
 
 impl RTDInputBackgroundRemoteBuilder {
   pub fn build(&self) -> InputBackgroundRemote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputBackgroundRemote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("background_id".to_string()); }
+    crate::types::_common::check_missing("inputBackgroundRemote", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn background_id(&mut self, background_id: isize) -> &mut Self {
+  pub fn background_id(&mut self, background_id: i64) -> &mut Self {
     self.inner.background_id = background_id;
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDInputChatPhoto: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputChatPhoto {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation in MPEG4 format; must be square, at most 10 seconds long, have width between 160 and 800 and be at most 2MB in size
   Animation(InputChatPhotoAnimation),
   /// A previously used profile photo of the current user
@@ -34,7 +37,6 @@ impl Default for InputChatPhoto {
 
 impl<'de> Deserialize<'de> for InputChatPhoto {
   fn deserialize<D>(deserializer: D) -> Result<InputChatPhoto, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputChatPhoto,
       (inputChatPhotoAnimation, Animation);
@@ -60,6 +62,7 @@ impl RObject for InputChatPhoto {
       InputChatPhoto::Animation(t) => t.extra(),
       InputChatPhoto::Previous(t) => t.extra(),
       InputChatPhoto::Static(t) => t.extra(),
+      InputChatPhoto::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for InputChatPhoto {
 impl InputChatPhoto {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputChatPhoto::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputChatPhoto::Animation(t) => t._missing_fields(),
+      InputChatPhoto::Previous(t) => t._missing_fields(),
+      InputChatPhoto::Static(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let InputChatPhoto::Animation(_) = self { true } else { false } }
   pub fn is_previous(&self) -> bool { if let InputChatPhoto::Previous(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<InputChatPhoto> for InputChatPhoto {
   fn as_ref(&self) -> &InputChatPhoto { self }
 }
 
+impl RVariant<InputChatPhoto> for InputChatPhotoAnimation {
+  fn from_class(class: &InputChatPhoto) -> Option<&Self> { match class { InputChatPhoto::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputChatPhoto> for InputChatPhotoPrevious {
+  fn from_class(class: &InputChatPhoto) -> Option<&Self> { match class { InputChatPhoto::Previous(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputChatPhoto> for InputChatPhotoStatic {
+  fn from_class(class: &InputChatPhoto) -> Option<&Self> { match class { InputChatPhoto::Static(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -136,7 +159,17 @@ impl InputChatPhotoAnimation {
     let mut inner = InputChatPhotoAnimation::default();
     inner.td_name = "inputChatPhotoAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputChatPhotoAnimationBuilder { inner }
+    RTDInputChatPhotoAnimationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputChatPhotoAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    if self.main_frame_timestamp == 0.0 { missing.push("main_frame_timestamp".to_string()); }
+    missing
   }
 
   pub fn animation(&self) -> &InputFile { &self.animation }
@@ -147,12 +180,22 @@ impl InputChatPhotoAnimation {
 
 #[doc(hidden)]
 pub struct RTDInputChatPhotoAnimationBuilder {
-  inner: InputChatPhotoAnimation
+  inner: InputChatPhotoAnimation,
+  set: [bool; 1]
 }
 
 impl RTDInputChatPhotoAnimationBuilder {
   pub fn build(&self) -> InputChatPhotoAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputChatPhotoAnimation> {
+    let mut missing = vec![];
+    if self.inner.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    if !self.set[0] { missing.push("main_frame_timestamp".to_string()); }
+    crate::types::_common::check_missing("inputChatPhotoAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -162,6 +205,7 @@ impl RTDInputChatPhotoAnimationBuilder {
    
   pub fn main_frame_timestamp(&mut self, main_frame_timestamp: f32) -> &mut Self {
     self.inner.main_frame_timestamp = main_frame_timestamp;
+    self.set[0] = true;
     self
   }
 
@@ -191,7 +235,7 @@ pub struct InputChatPhotoPrevious {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the profile photo to reuse
-  chat_photo_id: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_photo_id: i64,
   
 }
 
@@ -212,24 +256,43 @@ impl InputChatPhotoPrevious {
     let mut inner = InputChatPhotoPrevious::default();
     inner.td_name = "inputChatPhotoPrevious".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputChatPhotoPreviousBuilder { inner }
+    RTDInputChatPhotoPreviousBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputChatPhotoPrevious", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_photo_id == 0 { missing.push("chat_photo_id".to_string()); }
+    missing
   }
 
-  pub fn chat_photo_id(&self) -> isize { self.chat_photo_id }
+  pub fn chat_photo_id(&self) -> i64 { self.chat_photo_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDInputChatPhotoPreviousBuilder {
-  inner: InputChatPhotoPrevious
+  inner: InputChatPhotoPrevious,
+  set: [bool; 1]
 }
 
 impl RTDInputChatPhotoPreviousBuilder {
   pub fn build(&self) -> InputChatPhotoPrevious { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputChatPhotoPrevious> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_photo_id".to_string()); }
+    crate::types::_common::check_missing("inputChatPhotoPrevious", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn chat_photo_id(&mut self, chat_photo_id: isize) -> &mut Self {
+  pub fn chat_photo_id(&mut self, chat_photo_id: i64) -> &mut Self {
     self.inner.chat_photo_id = chat_photo_id;
+    self.set[0] = true;
     self
   }
 
@@ -283,6 +346,15 @@ impl InputChatPhotoStatic {
     RTDInputChatPhotoStaticBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputChatPhotoStatic", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    missing
+  }
+
   pub fn photo(&self) -> &InputFile { &self.photo }
 
 }
@@ -295,6 +367,14 @@ pub struct RTDInputChatPhotoStaticBuilder {
 impl RTDInputChatPhotoStaticBuilder {
   pub fn build(&self) -> InputChatPhotoStatic { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputChatPhotoStatic> {
+    let mut missing = vec![];
+    if self.inner.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    crate::types::_common::check_missing("inputChatPhotoStatic", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -19,6 +19,9 @@ pub trait TDInputCredentials: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputCredentials {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Applies if a user enters new credentials using Apple Pay
   ApplePay(InputCredentialsApplePay),
   /// Applies if a user enters new credentials using Google Pay
@@ -36,7 +39,6 @@ impl Default for InputCredentials {
 
 impl<'de> Deserialize<'de> for InputCredentials {
   fn deserialize<D>(deserializer: D) -> Result<InputCredentials, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputCredentials,
       (inputCredentialsApplePay, ApplePay);
@@ -65,6 +67,7 @@ impl RObject for InputCredentials {
       InputCredentials::GooglePay(t) => t.extra(),
       InputCredentials::New(t) => t.extra(),
       InputCredentials::Saved(t) => t.extra(),
+      InputCredentials::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for InputCredentials {
 impl InputCredentials {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputCredentials::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputCredentials::ApplePay(t) => t._missing_fields(),
+      InputCredentials::GooglePay(t) => t._missing_fields(),
+      InputCredentials::New(t) => t._missing_fields(),
+      InputCredentials::Saved(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_apple_pay(&self) -> bool { if let InputCredentials::ApplePay(_) = self { true } else { false } }
   pub fn is_google_pay(&self) -> bool { if let InputCredentials::GooglePay(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<InputCredentials> for InputCredentials {
   fn as_ref(&self) -> &InputCredentials { self }
 }
 
+impl RVariant<InputCredentials> for InputCredentialsApplePay {
+  fn from_class(class: &InputCredentials) -> Option<&Self> { match class { InputCredentials::ApplePay(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputCredentials> for InputCredentialsGooglePay {
+  fn from_class(class: &InputCredentials) -> Option<&Self> { match class { InputCredentials::GooglePay(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputCredentials> for InputCredentialsNew {
+  fn from_class(class: &InputCredentials) -> Option<&Self> { match class { InputCredentials::New(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputCredentials> for InputCredentialsSaved {
+  fn from_class(class: &InputCredentials) -> Option<&Self> { match class { InputCredentials::Saved(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +172,16 @@ impl InputCredentialsApplePay {
     let mut inner = InputCredentialsApplePay::default();
     inner.td_name = "inputCredentialsApplePay".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputCredentialsApplePayBuilder { inner }
+    RTDInputCredentialsApplePayBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputCredentialsApplePay", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -153,15 +190,25 @@ impl InputCredentialsApplePay {
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsApplePayBuilder {
-  inner: InputCredentialsApplePay
+  inner: InputCredentialsApplePay,
+  set: [bool; 1]
 }
 
 impl RTDInputCredentialsApplePayBuilder {
   pub fn build(&self) -> InputCredentialsApplePay { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputCredentialsApplePay> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("inputCredentialsApplePay", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -212,7 +259,16 @@ impl InputCredentialsGooglePay {
     let mut inner = InputCredentialsGooglePay::default();
     inner.td_name = "inputCredentialsGooglePay".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputCredentialsGooglePayBuilder { inner }
+    RTDInputCredentialsGooglePayBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputCredentialsGooglePay", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -221,15 +277,25 @@ impl InputCredentialsGooglePay {
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsGooglePayBuilder {
-  inner: InputCredentialsGooglePay
+  inner: InputCredentialsGooglePay,
+  set: [bool; 1]
 }
 
 impl RTDInputCredentialsGooglePayBuilder {
   pub fn build(&self) -> InputCredentialsGooglePay { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputCredentialsGooglePay> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("inputCredentialsGooglePay", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -282,7 +348,16 @@ impl InputCredentialsNew {
     let mut inner = InputCredentialsNew::default();
     inner.td_name = "inputCredentialsNew".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputCredentialsNewBuilder { inner }
+    RTDInputCredentialsNewBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputCredentialsNew", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn data(&self) -> &String { &self.data }
@@ -293,15 +368,25 @@ impl InputCredentialsNew {
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsNewBuilder {
-  inner: InputCredentialsNew
+  inner: InputCredentialsNew,
+  set: [bool; 1]
 }
 
 impl RTDInputCredentialsNewBuilder {
   pub fn build(&self) -> InputCredentialsNew { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputCredentialsNew> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("inputCredentialsNew", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -358,7 +443,16 @@ impl InputCredentialsSaved {
     let mut inner = InputCredentialsSaved::default();
     inner.td_name = "inputCredentialsSaved".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputCredentialsSavedBuilder { inner }
+    RTDInputCredentialsSavedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputCredentialsSaved", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.saved_credentials_id.is_empty() { missing.push("saved_credentials_id".to_string()); }
+    missing
   }
 
   pub fn saved_credentials_id(&self) -> &String { &self.saved_credentials_id }
@@ -367,15 +461,25 @@ impl InputCredentialsSaved {
 
 #[doc(hidden)]
 pub struct RTDInputCredentialsSavedBuilder {
-  inner: InputCredentialsSaved
+  inner: InputCredentialsSaved,
+  set: [bool; 1]
 }
 
 impl RTDInputCredentialsSavedBuilder {
   pub fn build(&self) -> InputCredentialsSaved { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputCredentialsSaved> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("saved_credentials_id".to_string()); }
+    crate::types::_common::check_missing("inputCredentialsSaved", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn saved_credentials_id<T: AsRef<str>>(&mut self, saved_credentials_id: T) -> &mut Self {
     self.inner.saved_credentials_id = saved_credentials_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
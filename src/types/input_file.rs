@@ -19,6 +19,9 @@ pub trait TDInputFile: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputFile {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A file generated by the application
   Generated(InputFileGenerated),
   /// A file defined by its unique ID
@@ -36,7 +39,6 @@ impl Default for InputFile {
 
 impl<'de> Deserialize<'de> for InputFile {
   fn deserialize<D>(deserializer: D) -> Result<InputFile, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputFile,
       (inputFileGenerated, Generated);
@@ -65,6 +67,7 @@ impl RObject for InputFile {
       InputFile::Id(t) => t.extra(),
       InputFile::Local(t) => t.extra(),
       InputFile::Remote(t) => t.extra(),
+      InputFile::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for InputFile {
 impl InputFile {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputFile::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputFile::Generated(t) => t._missing_fields(),
+      InputFile::Id(t) => t._missing_fields(),
+      InputFile::Local(t) => t._missing_fields(),
+      InputFile::Remote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_generated(&self) -> bool { if let InputFile::Generated(_) = self { true } else { false } }
   pub fn is_id(&self) -> bool { if let InputFile::Id(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<InputFile> for InputFile {
   fn as_ref(&self) -> &InputFile { self }
 }
 
+impl RVariant<InputFile> for InputFileGenerated {
+  fn from_class(class: &InputFile) -> Option<&Self> { match class { InputFile::Generated(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputFile> for InputFileId {
+  fn from_class(class: &InputFile) -> Option<&Self> { match class { InputFile::Id(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputFile> for InputFileLocal {
+  fn from_class(class: &InputFile) -> Option<&Self> { match class { InputFile::Local(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputFile> for InputFileRemote {
+  fn from_class(class: &InputFile) -> Option<&Self> { match class { InputFile::Remote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -148,7 +176,16 @@ impl InputFileGenerated {
     let mut inner = InputFileGenerated::default();
     inner.td_name = "inputFileGenerated".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputFileGeneratedBuilder { inner }
+    RTDInputFileGeneratedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputFileGenerated", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.conversion.is_empty() { missing.push("conversion".to_string()); }
+    missing
   }
 
   pub fn original_path(&self) -> &String { &self.original_path }
@@ -161,12 +198,21 @@ impl InputFileGenerated {
 
 #[doc(hidden)]
 pub struct RTDInputFileGeneratedBuilder {
-  inner: InputFileGenerated
+  inner: InputFileGenerated,
+  set: [bool; 1]
 }
 
 impl RTDInputFileGeneratedBuilder {
   pub fn build(&self) -> InputFileGenerated { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputFileGenerated> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("conversion".to_string()); }
+    crate::types::_common::check_missing("inputFileGenerated", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn original_path<T: AsRef<str>>(&mut self, original_path: T) -> &mut Self {
     self.inner.original_path = original_path.as_ref().to_string();
@@ -176,6 +222,7 @@ impl RTDInputFileGeneratedBuilder {
    
   pub fn conversion<T: AsRef<str>>(&mut self, conversion: T) -> &mut Self {
     self.inner.conversion = conversion.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -232,7 +279,16 @@ impl InputFileId {
     let mut inner = InputFileId::default();
     inner.td_name = "inputFileId".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputFileIdBuilder { inner }
+    RTDInputFileIdBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputFileId", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -241,15 +297,25 @@ impl InputFileId {
 
 #[doc(hidden)]
 pub struct RTDInputFileIdBuilder {
-  inner: InputFileId
+  inner: InputFileId,
+  set: [bool; 1]
 }
 
 impl RTDInputFileIdBuilder {
   pub fn build(&self) -> InputFileId { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputFileId> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    crate::types::_common::check_missing("inputFileId", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -300,7 +366,16 @@ impl InputFileLocal {
     let mut inner = InputFileLocal::default();
     inner.td_name = "inputFileLocal".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputFileLocalBuilder { inner }
+    RTDInputFileLocalBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputFileLocal", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.path.is_empty() { missing.push("path".to_string()); }
+    missing
   }
 
   pub fn path(&self) -> &String { &self.path }
@@ -309,15 +384,25 @@ impl InputFileLocal {
 
 #[doc(hidden)]
 pub struct RTDInputFileLocalBuilder {
-  inner: InputFileLocal
+  inner: InputFileLocal,
+  set: [bool; 1]
 }
 
 impl RTDInputFileLocalBuilder {
   pub fn build(&self) -> InputFileLocal { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputFileLocal> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("path".to_string()); }
+    crate::types::_common::check_missing("inputFileLocal", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -368,7 +453,16 @@ impl InputFileRemote {
     let mut inner = InputFileRemote::default();
     inner.td_name = "inputFileRemote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputFileRemoteBuilder { inner }
+    RTDInputFileRemoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputFileRemote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -377,15 +471,25 @@ impl InputFileRemote {
 
 #[doc(hidden)]
 pub struct RTDInputFileRemoteBuilder {
-  inner: InputFileRemote
+  inner: InputFileRemote,
+  set: [bool; 1]
 }
 
 impl RTDInputFileRemoteBuilder {
   pub fn build(&self) -> InputFileRemote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputFileRemote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    crate::types::_common::check_missing("inputFileRemote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -44,7 +44,20 @@ impl InputIdentityDocument {
     let mut inner = InputIdentityDocument::default();
     inner.td_name = "inputIdentityDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputIdentityDocumentBuilder { inner }
+    RTDInputIdentityDocumentBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputIdentityDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.number.is_empty() { missing.push("number".to_string()); }
+    if false { missing.push("expiry_date".to_string()); } else { missing.extend(crate::types::_common::missing_in("expiry_date", self.expiry_date._missing_fields())); }
+    if self.front_side._is_default() { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.front_side._missing_fields())); }
+    if self.reverse_side._is_default() { missing.push("reverse_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("reverse_side", self.reverse_side._missing_fields())); }
+    if self.selfie._is_default() { missing.push("selfie".to_string()); } else { missing.extend(crate::types::_common::missing_in("selfie", self.selfie._missing_fields())); }
+    missing
   }
 
   pub fn number(&self) -> &String { &self.number }
@@ -63,21 +76,36 @@ impl InputIdentityDocument {
 
 #[doc(hidden)]
 pub struct RTDInputIdentityDocumentBuilder {
-  inner: InputIdentityDocument
+  inner: InputIdentityDocument,
+  set: [bool; 2]
 }
 
 impl RTDInputIdentityDocumentBuilder {
   pub fn build(&self) -> InputIdentityDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputIdentityDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("number".to_string()); }
+    if !self.set[1] { missing.push("expiry_date".to_string()); } else { missing.extend(crate::types::_common::missing_in("expiry_date", self.inner.expiry_date._missing_fields())); }
+    if self.inner.front_side._is_default() { missing.push("front_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("front_side", self.inner.front_side._missing_fields())); }
+    if self.inner.reverse_side._is_default() { missing.push("reverse_side".to_string()); } else { missing.extend(crate::types::_common::missing_in("reverse_side", self.inner.reverse_side._missing_fields())); }
+    if self.inner.selfie._is_default() { missing.push("selfie".to_string()); } else { missing.extend(crate::types::_common::missing_in("selfie", self.inner.selfie._missing_fields())); }
+    crate::types::_common::check_missing("inputIdentityDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn number<T: AsRef<str>>(&mut self, number: T) -> &mut Self {
     self.inner.number = number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn expiry_date<T: AsRef<Date>>(&mut self, expiry_date: T) -> &mut Self {
     self.inner.expiry_date = expiry_date.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
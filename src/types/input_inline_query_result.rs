@@ -19,6 +19,9 @@ pub trait TDInputInlineQueryResult: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputInlineQueryResult {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a link to an animated GIF or an animated (i.e. without sound) H.264/MPEG-4 AVC video
   Animation(InputInlineQueryResultAnimation),
   /// Represents a link to an article or web page
@@ -52,7 +55,6 @@ impl Default for InputInlineQueryResult {
 
 impl<'de> Deserialize<'de> for InputInlineQueryResult {
   fn deserialize<D>(deserializer: D) -> Result<InputInlineQueryResult, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputInlineQueryResult,
       (inputInlineQueryResultAnimation, Animation);
@@ -105,6 +107,7 @@ impl RObject for InputInlineQueryResult {
       InputInlineQueryResult::Venue(t) => t.extra(),
       InputInlineQueryResult::Video(t) => t.extra(),
       InputInlineQueryResult::VoiceNote(t) => t.extra(),
+      InputInlineQueryResult::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -115,6 +118,23 @@ impl RObject for InputInlineQueryResult {
 impl InputInlineQueryResult {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputInlineQueryResult::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputInlineQueryResult::Animation(t) => t._missing_fields(),
+      InputInlineQueryResult::Article(t) => t._missing_fields(),
+      InputInlineQueryResult::Audio(t) => t._missing_fields(),
+      InputInlineQueryResult::Contact(t) => t._missing_fields(),
+      InputInlineQueryResult::Document(t) => t._missing_fields(),
+      InputInlineQueryResult::Game(t) => t._missing_fields(),
+      InputInlineQueryResult::Location(t) => t._missing_fields(),
+      InputInlineQueryResult::Photo(t) => t._missing_fields(),
+      InputInlineQueryResult::Sticker(t) => t._missing_fields(),
+      InputInlineQueryResult::Venue(t) => t._missing_fields(),
+      InputInlineQueryResult::Video(t) => t._missing_fields(),
+      InputInlineQueryResult::VoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let InputInlineQueryResult::Animation(_) = self { true } else { false } }
   pub fn is_article(&self) -> bool { if let InputInlineQueryResult::Article(_) = self { true } else { false } }
@@ -187,6 +207,54 @@ impl AsRef<InputInlineQueryResult> for InputInlineQueryResult {
   fn as_ref(&self) -> &InputInlineQueryResult { self }
 }
 
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultAnimation {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultArticle {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Article(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultAudio {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultContact {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Contact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultDocument {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Document(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultGame {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Game(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultLocation {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Location(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultPhoto {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultSticker {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Sticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultVenue {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Venue(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultVideo {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputInlineQueryResult> for InputInlineQueryResultVoiceNote {
+  fn from_class(class: &InputInlineQueryResult) -> Option<&Self> { match class { InputInlineQueryResult::VoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -244,7 +312,26 @@ impl InputInlineQueryResultAnimation {
     let mut inner = InputInlineQueryResultAnimation::default();
     inner.td_name = "inputInlineQueryResultAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultAnimationBuilder { inner }
+    RTDInputInlineQueryResultAnimationBuilder { inner, set: [false; 9] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_mime_type.is_empty() { missing.push("thumbnail_mime_type".to_string()); }
+    if self.video_url.is_empty() { missing.push("video_url".to_string()); }
+    if self.video_mime_type.is_empty() { missing.push("video_mime_type".to_string()); }
+    if self.video_duration == 0 { missing.push("video_duration".to_string()); }
+    if self.video_width == 0 { missing.push("video_width".to_string()); }
+    if self.video_height == 0 { missing.push("video_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -273,63 +360,91 @@ impl InputInlineQueryResultAnimation {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultAnimationBuilder {
-  inner: InputInlineQueryResultAnimation
+  inner: InputInlineQueryResultAnimation,
+  set: [bool; 9]
 }
 
 impl RTDInputInlineQueryResultAnimationBuilder {
   pub fn build(&self) -> InputInlineQueryResultAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultAnimation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[3] { missing.push("thumbnail_mime_type".to_string()); }
+    if !self.set[4] { missing.push("video_url".to_string()); }
+    if !self.set[5] { missing.push("video_mime_type".to_string()); }
+    if !self.set[6] { missing.push("video_duration".to_string()); }
+    if !self.set[7] { missing.push("video_width".to_string()); }
+    if !self.set[8] { missing.push("video_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn thumbnail_mime_type<T: AsRef<str>>(&mut self, thumbnail_mime_type: T) -> &mut Self {
     self.inner.thumbnail_mime_type = thumbnail_mime_type.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn video_url<T: AsRef<str>>(&mut self, video_url: T) -> &mut Self {
     self.inner.video_url = video_url.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn video_mime_type<T: AsRef<str>>(&mut self, video_mime_type: T) -> &mut Self {
     self.inner.video_mime_type = video_mime_type.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn video_duration(&mut self, video_duration: i64) -> &mut Self {
     self.inner.video_duration = video_duration;
+    self.set[6] = true;
     self
   }
 
    
   pub fn video_width(&mut self, video_width: i64) -> &mut Self {
     self.inner.video_width = video_width;
+    self.set[7] = true;
     self
   }
 
    
   pub fn video_height(&mut self, video_height: i64) -> &mut Self {
     self.inner.video_height = video_height;
+    self.set[8] = true;
     self
   }
 
@@ -410,7 +525,24 @@ impl InputInlineQueryResultArticle {
     let mut inner = InputInlineQueryResultArticle::default();
     inner.td_name = "inputInlineQueryResultArticle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultArticleBuilder { inner }
+    RTDInputInlineQueryResultArticleBuilder { inner, set: [false; 7] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultArticle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_width == 0 { missing.push("thumbnail_width".to_string()); }
+    if self.thumbnail_height == 0 { missing.push("thumbnail_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -437,21 +569,40 @@ impl InputInlineQueryResultArticle {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultArticleBuilder {
-  inner: InputInlineQueryResultArticle
+  inner: InputInlineQueryResultArticle,
+  set: [bool; 7]
 }
 
 impl RTDInputInlineQueryResultArticleBuilder {
   pub fn build(&self) -> InputInlineQueryResultArticle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultArticle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("description".to_string()); }
+    if !self.set[4] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[5] { missing.push("thumbnail_width".to_string()); }
+    if !self.set[6] { missing.push("thumbnail_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultArticle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -464,30 +615,35 @@ impl RTDInputInlineQueryResultArticleBuilder {
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
     self.inner.thumbnail_width = thumbnail_width;
+    self.set[5] = true;
     self
   }
 
    
   pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
     self.inner.thumbnail_height = thumbnail_height;
+    self.set[6] = true;
     self
   }
 
@@ -562,7 +718,22 @@ impl InputInlineQueryResultAudio {
     let mut inner = InputInlineQueryResultAudio::default();
     inner.td_name = "inputInlineQueryResultAudio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultAudioBuilder { inner }
+    RTDInputInlineQueryResultAudioBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.performer.is_empty() { missing.push("performer".to_string()); }
+    if self.audio_url.is_empty() { missing.push("audio_url".to_string()); }
+    if self.audio_duration == 0 { missing.push("audio_duration".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -583,39 +754,59 @@ impl InputInlineQueryResultAudio {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultAudioBuilder {
-  inner: InputInlineQueryResultAudio
+  inner: InputInlineQueryResultAudio,
+  set: [bool; 5]
 }
 
 impl RTDInputInlineQueryResultAudioBuilder {
   pub fn build(&self) -> InputInlineQueryResultAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultAudio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("performer".to_string()); }
+    if !self.set[3] { missing.push("audio_url".to_string()); }
+    if !self.set[4] { missing.push("audio_duration".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultAudio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn performer<T: AsRef<str>>(&mut self, performer: T) -> &mut Self {
     self.inner.performer = performer.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn audio_url<T: AsRef<str>>(&mut self, audio_url: T) -> &mut Self {
     self.inner.audio_url = audio_url.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn audio_duration(&mut self, audio_duration: i64) -> &mut Self {
     self.inner.audio_duration = audio_duration;
+    self.set[4] = true;
     self
   }
 
@@ -690,7 +881,22 @@ impl InputInlineQueryResultContact {
     let mut inner = InputInlineQueryResultContact::default();
     inner.td_name = "inputInlineQueryResultContact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultContactBuilder { inner }
+    RTDInputInlineQueryResultContactBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.contact._missing_fields())); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_width == 0 { missing.push("thumbnail_width".to_string()); }
+    if self.thumbnail_height == 0 { missing.push("thumbnail_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -711,39 +917,59 @@ impl InputInlineQueryResultContact {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultContactBuilder {
-  inner: InputInlineQueryResultContact
+  inner: InputInlineQueryResultContact,
+  set: [bool; 5]
 }
 
 impl RTDInputInlineQueryResultContactBuilder {
   pub fn build(&self) -> InputInlineQueryResultContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultContact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.inner.contact._missing_fields())); }
+    if !self.set[2] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[3] { missing.push("thumbnail_width".to_string()); }
+    if !self.set[4] { missing.push("thumbnail_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultContact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
     self.inner.thumbnail_width = thumbnail_width;
+    self.set[3] = true;
     self
   }
 
    
   pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
     self.inner.thumbnail_height = thumbnail_height;
+    self.set[4] = true;
     self
   }
 
@@ -824,7 +1050,25 @@ impl InputInlineQueryResultDocument {
     let mut inner = InputInlineQueryResultDocument::default();
     inner.td_name = "inputInlineQueryResultDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultDocumentBuilder { inner }
+    RTDInputInlineQueryResultDocumentBuilder { inner, set: [false; 8] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.document_url.is_empty() { missing.push("document_url".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_width == 0 { missing.push("thumbnail_width".to_string()); }
+    if self.thumbnail_height == 0 { missing.push("thumbnail_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -851,57 +1095,83 @@ impl InputInlineQueryResultDocument {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultDocumentBuilder {
-  inner: InputInlineQueryResultDocument
+  inner: InputInlineQueryResultDocument,
+  set: [bool; 8]
 }
 
 impl RTDInputInlineQueryResultDocumentBuilder {
   pub fn build(&self) -> InputInlineQueryResultDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("description".to_string()); }
+    if !self.set[3] { missing.push("document_url".to_string()); }
+    if !self.set[4] { missing.push("mime_type".to_string()); }
+    if !self.set[5] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[6] { missing.push("thumbnail_width".to_string()); }
+    if !self.set[7] { missing.push("thumbnail_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn document_url<T: AsRef<str>>(&mut self, document_url: T) -> &mut Self {
     self.inner.document_url = document_url.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
     self.inner.thumbnail_width = thumbnail_width;
+    self.set[6] = true;
     self
   }
 
    
   pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
     self.inner.thumbnail_height = thumbnail_height;
+    self.set[7] = true;
     self
   }
 
@@ -968,7 +1238,18 @@ impl InputInlineQueryResultGame {
     let mut inner = InputInlineQueryResultGame::default();
     inner.td_name = "inputInlineQueryResultGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultGameBuilder { inner }
+    RTDInputInlineQueryResultGameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.game_short_name.is_empty() { missing.push("game_short_name".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -981,21 +1262,34 @@ impl InputInlineQueryResultGame {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultGameBuilder {
-  inner: InputInlineQueryResultGame
+  inner: InputInlineQueryResultGame,
+  set: [bool; 2]
 }
 
 impl RTDInputInlineQueryResultGameBuilder {
   pub fn build(&self) -> InputInlineQueryResultGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("game_short_name".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
     self.inner.game_short_name = game_short_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1068,7 +1362,24 @@ impl InputInlineQueryResultLocation {
     let mut inner = InputInlineQueryResultLocation::default();
     inner.td_name = "inputInlineQueryResultLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultLocationBuilder { inner }
+    RTDInputInlineQueryResultLocationBuilder { inner, set: [false; 7] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.live_period == 0 { missing.push("live_period".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_width == 0 { missing.push("thumbnail_width".to_string()); }
+    if self.thumbnail_height == 0 { missing.push("thumbnail_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1093,51 +1404,75 @@ impl InputInlineQueryResultLocation {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultLocationBuilder {
-  inner: InputInlineQueryResultLocation
+  inner: InputInlineQueryResultLocation,
+  set: [bool; 7]
 }
 
 impl RTDInputInlineQueryResultLocationBuilder {
   pub fn build(&self) -> InputInlineQueryResultLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[2] { missing.push("live_period".to_string()); }
+    if !self.set[3] { missing.push("title".to_string()); }
+    if !self.set[4] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[5] { missing.push("thumbnail_width".to_string()); }
+    if !self.set[6] { missing.push("thumbnail_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn live_period(&mut self, live_period: i64) -> &mut Self {
     self.inner.live_period = live_period;
+    self.set[2] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
     self.inner.thumbnail_width = thumbnail_width;
+    self.set[5] = true;
     self
   }
 
    
   pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
     self.inner.thumbnail_height = thumbnail_height;
+    self.set[6] = true;
     self
   }
 
@@ -1216,7 +1551,24 @@ impl InputInlineQueryResultPhoto {
     let mut inner = InputInlineQueryResultPhoto::default();
     inner.td_name = "inputInlineQueryResultPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultPhotoBuilder { inner }
+    RTDInputInlineQueryResultPhotoBuilder { inner, set: [false; 7] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.photo_url.is_empty() { missing.push("photo_url".to_string()); }
+    if self.photo_width == 0 { missing.push("photo_width".to_string()); }
+    if self.photo_height == 0 { missing.push("photo_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1241,51 +1593,75 @@ impl InputInlineQueryResultPhoto {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultPhotoBuilder {
-  inner: InputInlineQueryResultPhoto
+  inner: InputInlineQueryResultPhoto,
+  set: [bool; 7]
 }
 
 impl RTDInputInlineQueryResultPhotoBuilder {
   pub fn build(&self) -> InputInlineQueryResultPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("description".to_string()); }
+    if !self.set[3] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[4] { missing.push("photo_url".to_string()); }
+    if !self.set[5] { missing.push("photo_width".to_string()); }
+    if !self.set[6] { missing.push("photo_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn photo_url<T: AsRef<str>>(&mut self, photo_url: T) -> &mut Self {
     self.inner.photo_url = photo_url.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn photo_width(&mut self, photo_width: i64) -> &mut Self {
     self.inner.photo_width = photo_width;
+    self.set[5] = true;
     self
   }
 
    
   pub fn photo_height(&mut self, photo_height: i64) -> &mut Self {
     self.inner.photo_height = photo_height;
+    self.set[6] = true;
     self
   }
 
@@ -1360,7 +1736,22 @@ impl InputInlineQueryResultSticker {
     let mut inner = InputInlineQueryResultSticker::default();
     inner.td_name = "inputInlineQueryResultSticker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultStickerBuilder { inner }
+    RTDInputInlineQueryResultStickerBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.sticker_url.is_empty() { missing.push("sticker_url".to_string()); }
+    if self.sticker_width == 0 { missing.push("sticker_width".to_string()); }
+    if self.sticker_height == 0 { missing.push("sticker_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1381,39 +1772,59 @@ impl InputInlineQueryResultSticker {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultStickerBuilder {
-  inner: InputInlineQueryResultSticker
+  inner: InputInlineQueryResultSticker,
+  set: [bool; 5]
 }
 
 impl RTDInputInlineQueryResultStickerBuilder {
   pub fn build(&self) -> InputInlineQueryResultSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultSticker> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[2] { missing.push("sticker_url".to_string()); }
+    if !self.set[3] { missing.push("sticker_width".to_string()); }
+    if !self.set[4] { missing.push("sticker_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn sticker_url<T: AsRef<str>>(&mut self, sticker_url: T) -> &mut Self {
     self.inner.sticker_url = sticker_url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn sticker_width(&mut self, sticker_width: i64) -> &mut Self {
     self.inner.sticker_width = sticker_width;
+    self.set[3] = true;
     self
   }
 
    
   pub fn sticker_height(&mut self, sticker_height: i64) -> &mut Self {
     self.inner.sticker_height = sticker_height;
+    self.set[4] = true;
     self
   }
 
@@ -1488,7 +1899,22 @@ impl InputInlineQueryResultVenue {
     let mut inner = InputInlineQueryResultVenue::default();
     inner.td_name = "inputInlineQueryResultVenue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultVenueBuilder { inner }
+    RTDInputInlineQueryResultVenueBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultVenue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if false { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.venue._missing_fields())); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.thumbnail_width == 0 { missing.push("thumbnail_width".to_string()); }
+    if self.thumbnail_height == 0 { missing.push("thumbnail_height".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1509,39 +1935,59 @@ impl InputInlineQueryResultVenue {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVenueBuilder {
-  inner: InputInlineQueryResultVenue
+  inner: InputInlineQueryResultVenue,
+  set: [bool; 5]
 }
 
 impl RTDInputInlineQueryResultVenueBuilder {
   pub fn build(&self) -> InputInlineQueryResultVenue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVenue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.inner.venue._missing_fields())); }
+    if !self.set[2] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[3] { missing.push("thumbnail_width".to_string()); }
+    if !self.set[4] { missing.push("thumbnail_height".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultVenue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn thumbnail_width(&mut self, thumbnail_width: i64) -> &mut Self {
     self.inner.thumbnail_width = thumbnail_width;
+    self.set[3] = true;
     self
   }
 
    
   pub fn thumbnail_height(&mut self, thumbnail_height: i64) -> &mut Self {
     self.inner.thumbnail_height = thumbnail_height;
+    self.set[4] = true;
     self
   }
 
@@ -1624,7 +2070,26 @@ impl InputInlineQueryResultVideo {
     let mut inner = InputInlineQueryResultVideo::default();
     inner.td_name = "inputInlineQueryResultVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultVideoBuilder { inner }
+    RTDInputInlineQueryResultVideoBuilder { inner, set: [false; 9] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.thumbnail_url.is_empty() { missing.push("thumbnail_url".to_string()); }
+    if self.video_url.is_empty() { missing.push("video_url".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if self.video_width == 0 { missing.push("video_width".to_string()); }
+    if self.video_height == 0 { missing.push("video_height".to_string()); }
+    if self.video_duration == 0 { missing.push("video_duration".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1653,63 +2118,91 @@ impl InputInlineQueryResultVideo {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVideoBuilder {
-  inner: InputInlineQueryResultVideo
+  inner: InputInlineQueryResultVideo,
+  set: [bool; 9]
 }
 
 impl RTDInputInlineQueryResultVideoBuilder {
   pub fn build(&self) -> InputInlineQueryResultVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("description".to_string()); }
+    if !self.set[3] { missing.push("thumbnail_url".to_string()); }
+    if !self.set[4] { missing.push("video_url".to_string()); }
+    if !self.set[5] { missing.push("mime_type".to_string()); }
+    if !self.set[6] { missing.push("video_width".to_string()); }
+    if !self.set[7] { missing.push("video_height".to_string()); }
+    if !self.set[8] { missing.push("video_duration".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn thumbnail_url<T: AsRef<str>>(&mut self, thumbnail_url: T) -> &mut Self {
     self.inner.thumbnail_url = thumbnail_url.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn video_url<T: AsRef<str>>(&mut self, video_url: T) -> &mut Self {
     self.inner.video_url = video_url.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn video_width(&mut self, video_width: i64) -> &mut Self {
     self.inner.video_width = video_width;
+    self.set[6] = true;
     self
   }
 
    
   pub fn video_height(&mut self, video_height: i64) -> &mut Self {
     self.inner.video_height = video_height;
+    self.set[7] = true;
     self
   }
 
    
   pub fn video_duration(&mut self, video_duration: i64) -> &mut Self {
     self.inner.video_duration = video_duration;
+    self.set[8] = true;
     self
   }
 
@@ -1782,7 +2275,21 @@ impl InputInlineQueryResultVoiceNote {
     let mut inner = InputInlineQueryResultVoiceNote::default();
     inner.td_name = "inputInlineQueryResultVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputInlineQueryResultVoiceNoteBuilder { inner }
+    RTDInputInlineQueryResultVoiceNoteBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputInlineQueryResultVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.voice_note_url.is_empty() { missing.push("voice_note_url".to_string()); }
+    if self.voice_note_duration == 0 { missing.push("voice_note_duration".to_string()); }
+    if self.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.reply_markup._missing_fields())); }
+    if self.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.input_message_content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -1801,33 +2308,51 @@ impl InputInlineQueryResultVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDInputInlineQueryResultVoiceNoteBuilder {
-  inner: InputInlineQueryResultVoiceNote
+  inner: InputInlineQueryResultVoiceNote,
+  set: [bool; 4]
 }
 
 impl RTDInputInlineQueryResultVoiceNoteBuilder {
   pub fn build(&self) -> InputInlineQueryResultVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputInlineQueryResultVoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("voice_note_url".to_string()); }
+    if !self.set[3] { missing.push("voice_note_duration".to_string()); }
+    if self.inner.reply_markup._is_default() { missing.push("reply_markup".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_markup", self.inner.reply_markup._missing_fields())); }
+    if self.inner.input_message_content._is_default() { missing.push("input_message_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("input_message_content", self.inner.input_message_content._missing_fields())); }
+    crate::types::_common::check_missing("inputInlineQueryResultVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn voice_note_url<T: AsRef<str>>(&mut self, voice_note_url: T) -> &mut Self {
     self.inner.voice_note_url = voice_note_url.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn voice_note_duration(&mut self, voice_note_duration: i64) -> &mut Self {
     self.inner.voice_note_duration = voice_note_duration;
+    self.set[3] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDInputMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputMessageContent {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   InputMessageAnimation(InputMessageAnimation),
   /// An audio message
@@ -62,7 +65,6 @@ impl Default for InputMessageContent {
 
 impl<'de> Deserialize<'de> for InputMessageContent {
   fn deserialize<D>(deserializer: D) -> Result<InputMessageContent, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputMessageContent,
       (inputMessageAnimation, InputMessageAnimation);
@@ -130,6 +132,7 @@ impl RObject for InputMessageContent {
       InputMessageContent::InputMessageVideo(t) => t.extra(),
       InputMessageContent::InputMessageVideoNote(t) => t.extra(),
       InputMessageContent::InputMessageVoiceNote(t) => t.extra(),
+      InputMessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -140,6 +143,28 @@ impl RObject for InputMessageContent {
 impl InputMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputMessageContent::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputMessageContent::InputMessageAnimation(t) => t._missing_fields(),
+      InputMessageContent::InputMessageAudio(t) => t._missing_fields(),
+      InputMessageContent::InputMessageContact(t) => t._missing_fields(),
+      InputMessageContent::InputMessageDice(t) => t._missing_fields(),
+      InputMessageContent::InputMessageDocument(t) => t._missing_fields(),
+      InputMessageContent::InputMessageForwarded(t) => t._missing_fields(),
+      InputMessageContent::InputMessageGame(t) => t._missing_fields(),
+      InputMessageContent::InputMessageInvoice(t) => t._missing_fields(),
+      InputMessageContent::InputMessageLocation(t) => t._missing_fields(),
+      InputMessageContent::InputMessagePhoto(t) => t._missing_fields(),
+      InputMessageContent::InputMessagePoll(t) => t._missing_fields(),
+      InputMessageContent::InputMessageSticker(t) => t._missing_fields(),
+      InputMessageContent::InputMessageText(t) => t._missing_fields(),
+      InputMessageContent::InputMessageVenue(t) => t._missing_fields(),
+      InputMessageContent::InputMessageVideo(t) => t._missing_fields(),
+      InputMessageContent::InputMessageVideoNote(t) => t._missing_fields(),
+      InputMessageContent::InputMessageVoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_input_message_animation(&self) -> bool { if let InputMessageContent::InputMessageAnimation(_) = self { true } else { false } }
   pub fn is_input_message_audio(&self) -> bool { if let InputMessageContent::InputMessageAudio(_) = self { true } else { false } }
@@ -237,6 +262,74 @@ impl AsRef<InputMessageContent> for InputMessageContent {
   fn as_ref(&self) -> &InputMessageContent { self }
 }
 
+impl RVariant<InputMessageContent> for InputMessageAnimation {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageAnimation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageAudio {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageAudio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageContact {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageContact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageDice {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageDice(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageDocument {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageDocument(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageForwarded {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageForwarded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageGame {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageGame(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageInvoice {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageInvoice(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageLocation {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessagePhoto {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessagePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessagePoll {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessagePoll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageSticker {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageSticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageText {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageText(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageVenue {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageVenue(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageVideo {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageVideoNote {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageVideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputMessageContent> for InputMessageVoiceNote {
+  fn from_class(class: &InputMessageContent) -> Option<&Self> { match class { InputMessageContent::InputMessageVoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -286,7 +379,21 @@ impl InputMessageAnimation {
     let mut inner = InputMessageAnimation::default();
     inner.td_name = "inputMessageAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageAnimationBuilder { inner }
+    RTDInputMessageAnimationBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn animation(&self) -> &InputFile { &self.animation }
@@ -307,12 +414,26 @@ impl InputMessageAnimation {
 
 #[doc(hidden)]
 pub struct RTDInputMessageAnimationBuilder {
-  inner: InputMessageAnimation
+  inner: InputMessageAnimation,
+  set: [bool; 5]
 }
 
 impl RTDInputMessageAnimationBuilder {
   pub fn build(&self) -> InputMessageAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageAnimation> {
+    let mut missing = vec![];
+    if self.inner.animation._is_default() { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("duration".to_string()); }
+    if !self.set[2] { missing.push("width".to_string()); }
+    if !self.set[3] { missing.push("height".to_string()); }
+    if !self.set[4] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<InputFile>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
@@ -322,6 +443,7 @@ impl RTDInputMessageAnimationBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -334,24 +456,28 @@ impl RTDInputMessageAnimationBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[1] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[2] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[3] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -412,7 +538,21 @@ impl InputMessageAudio {
     let mut inner = InputMessageAudio::default();
     inner.td_name = "inputMessageAudio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageAudioBuilder { inner }
+    RTDInputMessageAudioBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.audio._is_default() { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.audio._missing_fields())); }
+    if false { missing.push("album_cover_thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("album_cover_thumbnail", self.album_cover_thumbnail._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.performer.is_empty() { missing.push("performer".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn audio(&self) -> &InputFile { &self.audio }
@@ -431,12 +571,26 @@ impl InputMessageAudio {
 
 #[doc(hidden)]
 pub struct RTDInputMessageAudioBuilder {
-  inner: InputMessageAudio
+  inner: InputMessageAudio,
+  set: [bool; 5]
 }
 
 impl RTDInputMessageAudioBuilder {
   pub fn build(&self) -> InputMessageAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageAudio> {
+    let mut missing = vec![];
+    if self.inner.audio._is_default() { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.inner.audio._missing_fields())); }
+    if !self.set[0] { missing.push("album_cover_thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("album_cover_thumbnail", self.inner.album_cover_thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("duration".to_string()); }
+    if !self.set[2] { missing.push("title".to_string()); }
+    if !self.set[3] { missing.push("performer".to_string()); }
+    if !self.set[4] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageAudio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn audio<T: AsRef<InputFile>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
@@ -446,30 +600,35 @@ impl RTDInputMessageAudioBuilder {
    
   pub fn album_cover_thumbnail<T: AsRef<InputThumbnail>>(&mut self, album_cover_thumbnail: T) -> &mut Self {
     self.inner.album_cover_thumbnail = album_cover_thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[1] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn performer<T: AsRef<str>>(&mut self, performer: T) -> &mut Self {
     self.inner.performer = performer.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -520,7 +679,16 @@ impl InputMessageContact {
     let mut inner = InputMessageContact::default();
     inner.td_name = "inputMessageContact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageContactBuilder { inner }
+    RTDInputMessageContactBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.contact._missing_fields())); }
+    missing
   }
 
   pub fn contact(&self) -> &Contact { &self.contact }
@@ -529,15 +697,25 @@ impl InputMessageContact {
 
 #[doc(hidden)]
 pub struct RTDInputMessageContactBuilder {
-  inner: InputMessageContact
+  inner: InputMessageContact,
+  set: [bool; 1]
 }
 
 impl RTDInputMessageContactBuilder {
   pub fn build(&self) -> InputMessageContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageContact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.inner.contact._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageContact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -590,7 +768,16 @@ impl InputMessageDice {
     let mut inner = InputMessageDice::default();
     inner.td_name = "inputMessageDice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageDiceBuilder { inner }
+    RTDInputMessageDiceBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageDice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.emoji.is_empty() { missing.push("emoji".to_string()); }
+    missing
   }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -601,15 +788,25 @@ impl InputMessageDice {
 
 #[doc(hidden)]
 pub struct RTDInputMessageDiceBuilder {
-  inner: InputMessageDice
+  inner: InputMessageDice,
+  set: [bool; 1]
 }
 
 impl RTDInputMessageDiceBuilder {
   pub fn build(&self) -> InputMessageDice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageDice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("emoji".to_string()); }
+    crate::types::_common::check_missing("inputMessageDice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -672,7 +869,18 @@ impl InputMessageDocument {
     let mut inner = InputMessageDocument::default();
     inner.td_name = "inputMessageDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageDocumentBuilder { inner }
+    RTDInputMessageDocumentBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.document._is_default() { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.document._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn document(&self) -> &InputFile { &self.document }
@@ -687,12 +895,23 @@ impl InputMessageDocument {
 
 #[doc(hidden)]
 pub struct RTDInputMessageDocumentBuilder {
-  inner: InputMessageDocument
+  inner: InputMessageDocument,
+  set: [bool; 2]
 }
 
 impl RTDInputMessageDocumentBuilder {
   pub fn build(&self) -> InputMessageDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageDocument> {
+    let mut missing = vec![];
+    if self.inner.document._is_default() { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.inner.document._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn document<T: AsRef<InputFile>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
@@ -702,6 +921,7 @@ impl RTDInputMessageDocumentBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -714,6 +934,7 @@ impl RTDInputMessageDocumentBuilder {
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -770,7 +991,18 @@ impl InputMessageForwarded {
     let mut inner = InputMessageForwarded::default();
     inner.td_name = "inputMessageForwarded".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageForwardedBuilder { inner }
+    RTDInputMessageForwardedBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageForwarded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.from_chat_id == 0 { missing.push("from_chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if false { missing.push("copy_options".to_string()); } else { missing.extend(crate::types::_common::missing_in("copy_options", self.copy_options._missing_fields())); }
+    missing
   }
 
   pub fn from_chat_id(&self) -> i64 { self.from_chat_id }
@@ -785,21 +1017,34 @@ impl InputMessageForwarded {
 
 #[doc(hidden)]
 pub struct RTDInputMessageForwardedBuilder {
-  inner: InputMessageForwarded
+  inner: InputMessageForwarded,
+  set: [bool; 3]
 }
 
 impl RTDInputMessageForwardedBuilder {
   pub fn build(&self) -> InputMessageForwarded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageForwarded> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("from_chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("copy_options".to_string()); } else { missing.extend(crate::types::_common::missing_in("copy_options", self.inner.copy_options._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageForwarded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn from_chat_id(&mut self, from_chat_id: i64) -> &mut Self {
     self.inner.from_chat_id = from_chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -812,6 +1057,7 @@ impl RTDInputMessageForwardedBuilder {
    
   pub fn copy_options<T: AsRef<MessageCopyOptions>>(&mut self, copy_options: T) -> &mut Self {
     self.inner.copy_options = copy_options.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -864,7 +1110,17 @@ impl InputMessageGame {
     let mut inner = InputMessageGame::default();
     inner.td_name = "inputMessageGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageGameBuilder { inner }
+    RTDInputMessageGameBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    if self.game_short_name.is_empty() { missing.push("game_short_name".to_string()); }
+    missing
   }
 
   pub fn bot_user_id(&self) -> i64 { self.bot_user_id }
@@ -875,21 +1131,33 @@ impl InputMessageGame {
 
 #[doc(hidden)]
 pub struct RTDInputMessageGameBuilder {
-  inner: InputMessageGame
+  inner: InputMessageGame,
+  set: [bool; 2]
 }
 
 impl RTDInputMessageGameBuilder {
   pub fn build(&self) -> InputMessageGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bot_user_id".to_string()); }
+    if !self.set[1] { missing.push("game_short_name".to_string()); }
+    crate::types::_common::check_missing("inputMessageGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn game_short_name<T: AsRef<str>>(&mut self, game_short_name: T) -> &mut Self {
     self.inner.game_short_name = game_short_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -960,7 +1228,26 @@ impl InputMessageInvoice {
     let mut inner = InputMessageInvoice::default();
     inner.td_name = "inputMessageInvoice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageInvoiceBuilder { inner }
+    RTDInputMessageInvoiceBuilder { inner, set: [false; 11] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageInvoice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.invoice._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.photo_url.is_empty() { missing.push("photo_url".to_string()); }
+    if self.photo_size == 0 { missing.push("photo_size".to_string()); }
+    if self.photo_width == 0 { missing.push("photo_width".to_string()); }
+    if self.photo_height == 0 { missing.push("photo_height".to_string()); }
+    if self.payload.is_empty() { missing.push("payload".to_string()); }
+    if self.provider_token.is_empty() { missing.push("provider_token".to_string()); }
+    if self.provider_data.is_empty() { missing.push("provider_data".to_string()); }
+    if self.start_parameter.is_empty() { missing.push("start_parameter".to_string()); }
+    missing
   }
 
   pub fn invoice(&self) -> &Invoice { &self.invoice }
@@ -989,75 +1276,105 @@ impl InputMessageInvoice {
 
 #[doc(hidden)]
 pub struct RTDInputMessageInvoiceBuilder {
-  inner: InputMessageInvoice
+  inner: InputMessageInvoice,
+  set: [bool; 11]
 }
 
 impl RTDInputMessageInvoiceBuilder {
   pub fn build(&self) -> InputMessageInvoice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageInvoice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.inner.invoice._missing_fields())); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("description".to_string()); }
+    if !self.set[3] { missing.push("photo_url".to_string()); }
+    if !self.set[4] { missing.push("photo_size".to_string()); }
+    if !self.set[5] { missing.push("photo_width".to_string()); }
+    if !self.set[6] { missing.push("photo_height".to_string()); }
+    if !self.set[7] { missing.push("payload".to_string()); }
+    if !self.set[8] { missing.push("provider_token".to_string()); }
+    if !self.set[9] { missing.push("provider_data".to_string()); }
+    if !self.set[10] { missing.push("start_parameter".to_string()); }
+    crate::types::_common::check_missing("inputMessageInvoice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn photo_url<T: AsRef<str>>(&mut self, photo_url: T) -> &mut Self {
     self.inner.photo_url = photo_url.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn photo_size(&mut self, photo_size: i64) -> &mut Self {
     self.inner.photo_size = photo_size;
+    self.set[4] = true;
     self
   }
 
    
   pub fn photo_width(&mut self, photo_width: i64) -> &mut Self {
     self.inner.photo_width = photo_width;
+    self.set[5] = true;
     self
   }
 
    
   pub fn photo_height(&mut self, photo_height: i64) -> &mut Self {
     self.inner.photo_height = photo_height;
+    self.set[6] = true;
     self
   }
 
    
   pub fn payload<T: AsRef<str>>(&mut self, payload: T) -> &mut Self {
     self.inner.payload = payload.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
    
   pub fn provider_token<T: AsRef<str>>(&mut self, provider_token: T) -> &mut Self {
     self.inner.provider_token = provider_token.as_ref().to_string();
+    self.set[8] = true;
     self
   }
 
    
   pub fn provider_data<T: AsRef<str>>(&mut self, provider_data: T) -> &mut Self {
     self.inner.provider_data = provider_data.as_ref().to_string();
+    self.set[9] = true;
     self
   }
 
    
   pub fn start_parameter<T: AsRef<str>>(&mut self, start_parameter: T) -> &mut Self {
     self.inner.start_parameter = start_parameter.as_ref().to_string();
+    self.set[10] = true;
     self
   }
 
@@ -1114,7 +1431,17 @@ impl InputMessageLocation {
     let mut inner = InputMessageLocation::default();
     inner.td_name = "inputMessageLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageLocationBuilder { inner }
+    RTDInputMessageLocationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.live_period == 0 { missing.push("live_period".to_string()); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -1129,21 +1456,33 @@ impl InputMessageLocation {
 
 #[doc(hidden)]
 pub struct RTDInputMessageLocationBuilder {
-  inner: InputMessageLocation
+  inner: InputMessageLocation,
+  set: [bool; 2]
 }
 
 impl RTDInputMessageLocationBuilder {
   pub fn build(&self) -> InputMessageLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("live_period".to_string()); }
+    crate::types::_common::check_missing("inputMessageLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn live_period(&mut self, live_period: i64) -> &mut Self {
     self.inner.live_period = live_period;
+    self.set[1] = true;
     self
   }
 
@@ -1218,7 +1557,21 @@ impl InputMessagePhoto {
     let mut inner = InputMessagePhoto::default();
     inner.td_name = "inputMessagePhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessagePhotoBuilder { inner }
+    RTDInputMessagePhotoBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessagePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &InputFile { &self.photo }
@@ -1239,12 +1592,26 @@ impl InputMessagePhoto {
 
 #[doc(hidden)]
 pub struct RTDInputMessagePhotoBuilder {
-  inner: InputMessagePhoto
+  inner: InputMessagePhoto,
+  set: [bool; 5]
 }
 
 impl RTDInputMessagePhotoBuilder {
   pub fn build(&self) -> InputMessagePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessagePhoto> {
+    let mut missing = vec![];
+    if self.inner.photo._is_default() { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("width".to_string()); }
+    if !self.set[2] { missing.push("height".to_string()); }
+    if !self.set[3] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    if !self.set[4] { missing.push("ttl".to_string()); }
+    crate::types::_common::check_missing("inputMessagePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<InputFile>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
@@ -1254,6 +1621,7 @@ impl RTDInputMessagePhotoBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1266,24 +1634,28 @@ impl RTDInputMessagePhotoBuilder {
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[1] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[2] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[4] = true;
     self
   }
 
@@ -1346,7 +1718,19 @@ impl InputMessagePoll {
     let mut inner = InputMessagePoll::default();
     inner.td_name = "inputMessagePoll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessagePollBuilder { inner }
+    RTDInputMessagePollBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessagePoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.question.is_empty() { missing.push("question".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.open_period == 0 { missing.push("open_period".to_string()); }
+    if self.close_date == 0 { missing.push("close_date".to_string()); }
+    missing
   }
 
   pub fn question(&self) -> &String { &self.question }
@@ -1367,15 +1751,28 @@ impl InputMessagePoll {
 
 #[doc(hidden)]
 pub struct RTDInputMessagePollBuilder {
-  inner: InputMessagePoll
+  inner: InputMessagePoll,
+  set: [bool; 3]
 }
 
 impl RTDInputMessagePollBuilder {
   pub fn build(&self) -> InputMessagePoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessagePoll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("question".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("open_period".to_string()); }
+    if !self.set[2] { missing.push("close_date".to_string()); }
+    crate::types::_common::check_missing("inputMessagePoll", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1400,12 +1797,14 @@ impl RTDInputMessagePollBuilder {
    
   pub fn open_period(&mut self, open_period: i64) -> &mut Self {
     self.inner.open_period = open_period;
+    self.set[1] = true;
     self
   }
 
    
   pub fn close_date(&mut self, close_date: i64) -> &mut Self {
     self.inner.close_date = close_date;
+    self.set[2] = true;
     self
   }
 
@@ -1470,7 +1869,20 @@ impl InputMessageSticker {
     let mut inner = InputMessageSticker::default();
     inner.td_name = "inputMessageSticker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageStickerBuilder { inner }
+    RTDInputMessageStickerBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if self.emoji.is_empty() { missing.push("emoji".to_string()); }
+    missing
   }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -1487,12 +1899,25 @@ impl InputMessageSticker {
 
 #[doc(hidden)]
 pub struct RTDInputMessageStickerBuilder {
-  inner: InputMessageSticker
+  inner: InputMessageSticker,
+  set: [bool; 4]
 }
 
 impl RTDInputMessageStickerBuilder {
   pub fn build(&self) -> InputMessageSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageSticker> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("width".to_string()); }
+    if !self.set[2] { missing.push("height".to_string()); }
+    if !self.set[3] { missing.push("emoji".to_string()); }
+    crate::types::_common::check_missing("inputMessageSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -1502,24 +1927,28 @@ impl RTDInputMessageStickerBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[1] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[2] = true;
     self
   }
 
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -1574,7 +2003,16 @@ impl InputMessageText {
     let mut inner = InputMessageText::default();
     inner.td_name = "inputMessageText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageTextBuilder { inner }
+    RTDInputMessageTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -1587,15 +2025,25 @@ impl InputMessageText {
 
 #[doc(hidden)]
 pub struct RTDInputMessageTextBuilder {
-  inner: InputMessageText
+  inner: InputMessageText,
+  set: [bool; 1]
 }
 
 impl RTDInputMessageTextBuilder {
   pub fn build(&self) -> InputMessageText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1658,7 +2106,16 @@ impl InputMessageVenue {
     let mut inner = InputMessageVenue::default();
     inner.td_name = "inputMessageVenue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageVenueBuilder { inner }
+    RTDInputMessageVenueBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageVenue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.venue._missing_fields())); }
+    missing
   }
 
   pub fn venue(&self) -> &Venue { &self.venue }
@@ -1667,15 +2124,25 @@ impl InputMessageVenue {
 
 #[doc(hidden)]
 pub struct RTDInputMessageVenueBuilder {
-  inner: InputMessageVenue
+  inner: InputMessageVenue,
+  set: [bool; 1]
 }
 
 impl RTDInputMessageVenueBuilder {
   pub fn build(&self) -> InputMessageVenue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageVenue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.inner.venue._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageVenue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1742,7 +2209,22 @@ impl InputMessageVideo {
     let mut inner = InputMessageVideo::default();
     inner.td_name = "inputMessageVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageVideoBuilder { inner }
+    RTDInputMessageVideoBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.video._is_default() { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.video._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    missing
   }
 
   pub fn video(&self) -> &InputFile { &self.video }
@@ -1767,12 +2249,27 @@ impl InputMessageVideo {
 
 #[doc(hidden)]
 pub struct RTDInputMessageVideoBuilder {
-  inner: InputMessageVideo
+  inner: InputMessageVideo,
+  set: [bool; 6]
 }
 
 impl RTDInputMessageVideoBuilder {
   pub fn build(&self) -> InputMessageVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageVideo> {
+    let mut missing = vec![];
+    if self.inner.video._is_default() { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.inner.video._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("duration".to_string()); }
+    if !self.set[2] { missing.push("width".to_string()); }
+    if !self.set[3] { missing.push("height".to_string()); }
+    if !self.set[4] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    if !self.set[5] { missing.push("ttl".to_string()); }
+    crate::types::_common::check_missing("inputMessageVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video<T: AsRef<InputFile>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
@@ -1782,6 +2279,7 @@ impl RTDInputMessageVideoBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1794,18 +2292,21 @@ impl RTDInputMessageVideoBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[1] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[2] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[3] = true;
     self
   }
 
@@ -1818,12 +2319,14 @@ impl RTDInputMessageVideoBuilder {
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[5] = true;
     self
   }
 
@@ -1880,7 +2383,19 @@ impl InputMessageVideoNote {
     let mut inner = InputMessageVideoNote::default();
     inner.td_name = "inputMessageVideoNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageVideoNoteBuilder { inner }
+    RTDInputMessageVideoNoteBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.video_note._is_default() { missing.push("video_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("video_note", self.video_note._missing_fields())); }
+    if false { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.length == 0 { missing.push("length".to_string()); }
+    missing
   }
 
   pub fn video_note(&self) -> &InputFile { &self.video_note }
@@ -1895,12 +2410,24 @@ impl InputMessageVideoNote {
 
 #[doc(hidden)]
 pub struct RTDInputMessageVideoNoteBuilder {
-  inner: InputMessageVideoNote
+  inner: InputMessageVideoNote,
+  set: [bool; 3]
 }
 
 impl RTDInputMessageVideoNoteBuilder {
   pub fn build(&self) -> InputMessageVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageVideoNote> {
+    let mut missing = vec![];
+    if self.inner.video_note._is_default() { missing.push("video_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("video_note", self.inner.video_note._missing_fields())); }
+    if !self.set[0] { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    if !self.set[1] { missing.push("duration".to_string()); }
+    if !self.set[2] { missing.push("length".to_string()); }
+    crate::types::_common::check_missing("inputMessageVideoNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video_note<T: AsRef<InputFile>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
@@ -1910,18 +2437,21 @@ impl RTDInputMessageVideoNoteBuilder {
    
   pub fn thumbnail<T: AsRef<InputThumbnail>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[1] = true;
     self
   }
 
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[2] = true;
     self
   }
 
@@ -1978,7 +2508,19 @@ impl InputMessageVoiceNote {
     let mut inner = InputMessageVoiceNote::default();
     inner.td_name = "inputMessageVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputMessageVoiceNoteBuilder { inner }
+    RTDInputMessageVoiceNoteBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputMessageVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.voice_note._is_default() { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.voice_note._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.waveform.is_empty() { missing.push("waveform".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn voice_note(&self) -> &InputFile { &self.voice_note }
@@ -1993,12 +2535,24 @@ impl InputMessageVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDInputMessageVoiceNoteBuilder {
-  inner: InputMessageVoiceNote
+  inner: InputMessageVoiceNote,
+  set: [bool; 3]
 }
 
 impl RTDInputMessageVoiceNoteBuilder {
   pub fn build(&self) -> InputMessageVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputMessageVoiceNote> {
+    let mut missing = vec![];
+    if self.inner.voice_note._is_default() { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.inner.voice_note._missing_fields())); }
+    if !self.set[0] { missing.push("duration".to_string()); }
+    if !self.set[1] { missing.push("waveform".to_string()); }
+    if !self.set[2] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("inputMessageVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn voice_note<T: AsRef<InputFile>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
@@ -2008,18 +2562,21 @@ impl RTDInputMessageVoiceNoteBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
    
   pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
     self.inner.waveform = waveform.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDInputPassportElement: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputPassportElement {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element to be saved containing the user's address
   Address(InputPassportElementAddress),
   /// A Telegram Passport element to be saved containing the user's bank statement
@@ -54,7 +57,6 @@ impl Default for InputPassportElement {
 
 impl<'de> Deserialize<'de> for InputPassportElement {
   fn deserialize<D>(deserializer: D) -> Result<InputPassportElement, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputPassportElement,
       (inputPassportElementAddress, Address);
@@ -110,6 +112,7 @@ impl RObject for InputPassportElement {
       InputPassportElement::RentalAgreement(t) => t.extra(),
       InputPassportElement::TemporaryRegistration(t) => t.extra(),
       InputPassportElement::UtilityBill(t) => t.extra(),
+      InputPassportElement::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -120,6 +123,24 @@ impl RObject for InputPassportElement {
 impl InputPassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElement::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputPassportElement::Address(t) => t._missing_fields(),
+      InputPassportElement::BankStatement(t) => t._missing_fields(),
+      InputPassportElement::DriverLicense(t) => t._missing_fields(),
+      InputPassportElement::EmailAddress(t) => t._missing_fields(),
+      InputPassportElement::IdentityCard(t) => t._missing_fields(),
+      InputPassportElement::InternalPassport(t) => t._missing_fields(),
+      InputPassportElement::Passport(t) => t._missing_fields(),
+      InputPassportElement::PassportRegistration(t) => t._missing_fields(),
+      InputPassportElement::PersonalDetails(t) => t._missing_fields(),
+      InputPassportElement::PhoneNumber(t) => t._missing_fields(),
+      InputPassportElement::RentalAgreement(t) => t._missing_fields(),
+      InputPassportElement::TemporaryRegistration(t) => t._missing_fields(),
+      InputPassportElement::UtilityBill(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_address(&self) -> bool { if let InputPassportElement::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let InputPassportElement::BankStatement(_) = self { true } else { false } }
@@ -197,6 +218,58 @@ impl AsRef<InputPassportElement> for InputPassportElement {
   fn as_ref(&self) -> &InputPassportElement { self }
 }
 
+impl RVariant<InputPassportElement> for InputPassportElementAddress {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::Address(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementBankStatement {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::BankStatement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementDriverLicense {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::DriverLicense(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementEmailAddress {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::EmailAddress(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementIdentityCard {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::IdentityCard(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementInternalPassport {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::InternalPassport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementPassport {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::Passport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementPassportRegistration {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::PassportRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementPersonalDetails {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::PersonalDetails(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementPhoneNumber {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::PhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementRentalAgreement {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::RentalAgreement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementTemporaryRegistration {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::TemporaryRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElement> for InputPassportElementUtilityBill {
+  fn from_class(class: &InputPassportElement) -> Option<&Self> { match class { InputPassportElement::UtilityBill(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -234,7 +307,16 @@ impl InputPassportElementAddress {
     let mut inner = InputPassportElementAddress::default();
     inner.td_name = "inputPassportElementAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementAddressBuilder { inner }
+    RTDInputPassportElementAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("address".to_string()); } else { missing.extend(crate::types::_common::missing_in("address", self.address._missing_fields())); }
+    missing
   }
 
   pub fn address(&self) -> &Address { &self.address }
@@ -243,15 +325,25 @@ impl InputPassportElementAddress {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementAddressBuilder {
-  inner: InputPassportElementAddress
+  inner: InputPassportElementAddress,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementAddressBuilder {
   pub fn build(&self) -> InputPassportElementAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("address".to_string()); } else { missing.extend(crate::types::_common::missing_in("address", self.inner.address._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -302,7 +394,16 @@ impl InputPassportElementBankStatement {
     let mut inner = InputPassportElementBankStatement::default();
     inner.td_name = "inputPassportElementBankStatement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementBankStatementBuilder { inner }
+    RTDInputPassportElementBankStatementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementBankStatement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("bank_statement".to_string()); } else { missing.extend(crate::types::_common::missing_in("bank_statement", self.bank_statement._missing_fields())); }
+    missing
   }
 
   pub fn bank_statement(&self) -> &InputPersonalDocument { &self.bank_statement }
@@ -311,15 +412,25 @@ impl InputPassportElementBankStatement {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementBankStatementBuilder {
-  inner: InputPassportElementBankStatement
+  inner: InputPassportElementBankStatement,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementBankStatementBuilder {
   pub fn build(&self) -> InputPassportElementBankStatement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementBankStatement> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bank_statement".to_string()); } else { missing.extend(crate::types::_common::missing_in("bank_statement", self.inner.bank_statement._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementBankStatement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bank_statement<T: AsRef<InputPersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -370,7 +481,16 @@ impl InputPassportElementDriverLicense {
     let mut inner = InputPassportElementDriverLicense::default();
     inner.td_name = "inputPassportElementDriverLicense".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementDriverLicenseBuilder { inner }
+    RTDInputPassportElementDriverLicenseBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementDriverLicense", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("driver_license".to_string()); } else { missing.extend(crate::types::_common::missing_in("driver_license", self.driver_license._missing_fields())); }
+    missing
   }
 
   pub fn driver_license(&self) -> &InputIdentityDocument { &self.driver_license }
@@ -379,15 +499,25 @@ impl InputPassportElementDriverLicense {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementDriverLicenseBuilder {
-  inner: InputPassportElementDriverLicense
+  inner: InputPassportElementDriverLicense,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> InputPassportElementDriverLicense { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementDriverLicense> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("driver_license".to_string()); } else { missing.extend(crate::types::_common::missing_in("driver_license", self.inner.driver_license._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementDriverLicense", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn driver_license<T: AsRef<InputIdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -438,7 +568,16 @@ impl InputPassportElementEmailAddress {
     let mut inner = InputPassportElementEmailAddress::default();
     inner.td_name = "inputPassportElementEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementEmailAddressBuilder { inner }
+    RTDInputPassportElementEmailAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.email_address.is_empty() { missing.push("email_address".to_string()); }
+    missing
   }
 
   pub fn email_address(&self) -> &String { &self.email_address }
@@ -447,15 +586,25 @@ impl InputPassportElementEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementEmailAddressBuilder {
-  inner: InputPassportElementEmailAddress
+  inner: InputPassportElementEmailAddress,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementEmailAddressBuilder {
   pub fn build(&self) -> InputPassportElementEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementEmailAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("email_address".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -506,7 +655,16 @@ impl InputPassportElementIdentityCard {
     let mut inner = InputPassportElementIdentityCard::default();
     inner.td_name = "inputPassportElementIdentityCard".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementIdentityCardBuilder { inner }
+    RTDInputPassportElementIdentityCardBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementIdentityCard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("identity_card".to_string()); } else { missing.extend(crate::types::_common::missing_in("identity_card", self.identity_card._missing_fields())); }
+    missing
   }
 
   pub fn identity_card(&self) -> &InputIdentityDocument { &self.identity_card }
@@ -515,15 +673,25 @@ impl InputPassportElementIdentityCard {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementIdentityCardBuilder {
-  inner: InputPassportElementIdentityCard
+  inner: InputPassportElementIdentityCard,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementIdentityCardBuilder {
   pub fn build(&self) -> InputPassportElementIdentityCard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementIdentityCard> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("identity_card".to_string()); } else { missing.extend(crate::types::_common::missing_in("identity_card", self.inner.identity_card._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementIdentityCard", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn identity_card<T: AsRef<InputIdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -574,7 +742,16 @@ impl InputPassportElementInternalPassport {
     let mut inner = InputPassportElementInternalPassport::default();
     inner.td_name = "inputPassportElementInternalPassport".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementInternalPassportBuilder { inner }
+    RTDInputPassportElementInternalPassportBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementInternalPassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("internal_passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("internal_passport", self.internal_passport._missing_fields())); }
+    missing
   }
 
   pub fn internal_passport(&self) -> &InputIdentityDocument { &self.internal_passport }
@@ -583,15 +760,25 @@ impl InputPassportElementInternalPassport {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementInternalPassportBuilder {
-  inner: InputPassportElementInternalPassport
+  inner: InputPassportElementInternalPassport,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementInternalPassportBuilder {
   pub fn build(&self) -> InputPassportElementInternalPassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementInternalPassport> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("internal_passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("internal_passport", self.inner.internal_passport._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementInternalPassport", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn internal_passport<T: AsRef<InputIdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -642,7 +829,16 @@ impl InputPassportElementPassport {
     let mut inner = InputPassportElementPassport::default();
     inner.td_name = "inputPassportElementPassport".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementPassportBuilder { inner }
+    RTDInputPassportElementPassportBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementPassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport", self.passport._missing_fields())); }
+    missing
   }
 
   pub fn passport(&self) -> &InputIdentityDocument { &self.passport }
@@ -651,15 +847,25 @@ impl InputPassportElementPassport {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPassportBuilder {
-  inner: InputPassportElementPassport
+  inner: InputPassportElementPassport,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementPassportBuilder {
   pub fn build(&self) -> InputPassportElementPassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementPassport> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport", self.inner.passport._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementPassport", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn passport<T: AsRef<InputIdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -710,7 +916,16 @@ impl InputPassportElementPassportRegistration {
     let mut inner = InputPassportElementPassportRegistration::default();
     inner.td_name = "inputPassportElementPassportRegistration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementPassportRegistrationBuilder { inner }
+    RTDInputPassportElementPassportRegistrationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementPassportRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("passport_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport_registration", self.passport_registration._missing_fields())); }
+    missing
   }
 
   pub fn passport_registration(&self) -> &InputPersonalDocument { &self.passport_registration }
@@ -719,15 +934,25 @@ impl InputPassportElementPassportRegistration {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPassportRegistrationBuilder {
-  inner: InputPassportElementPassportRegistration
+  inner: InputPassportElementPassportRegistration,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementPassportRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementPassportRegistration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("passport_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport_registration", self.inner.passport_registration._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementPassportRegistration", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn passport_registration<T: AsRef<InputPersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -778,7 +1003,16 @@ impl InputPassportElementPersonalDetails {
     let mut inner = InputPassportElementPersonalDetails::default();
     inner.td_name = "inputPassportElementPersonalDetails".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementPersonalDetailsBuilder { inner }
+    RTDInputPassportElementPersonalDetailsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementPersonalDetails", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("personal_details".to_string()); } else { missing.extend(crate::types::_common::missing_in("personal_details", self.personal_details._missing_fields())); }
+    missing
   }
 
   pub fn personal_details(&self) -> &PersonalDetails { &self.personal_details }
@@ -787,15 +1021,25 @@ impl InputPassportElementPersonalDetails {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPersonalDetailsBuilder {
-  inner: InputPassportElementPersonalDetails
+  inner: InputPassportElementPersonalDetails,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> InputPassportElementPersonalDetails { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementPersonalDetails> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("personal_details".to_string()); } else { missing.extend(crate::types::_common::missing_in("personal_details", self.inner.personal_details._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementPersonalDetails", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -846,7 +1090,16 @@ impl InputPassportElementPhoneNumber {
     let mut inner = InputPassportElementPhoneNumber::default();
     inner.td_name = "inputPassportElementPhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementPhoneNumberBuilder { inner }
+    RTDInputPassportElementPhoneNumberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -855,15 +1108,25 @@ impl InputPassportElementPhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementPhoneNumberBuilder {
-  inner: InputPassportElementPhoneNumber
+  inner: InputPassportElementPhoneNumber,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> InputPassportElementPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementPhoneNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementPhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -914,7 +1177,16 @@ impl InputPassportElementRentalAgreement {
     let mut inner = InputPassportElementRentalAgreement::default();
     inner.td_name = "inputPassportElementRentalAgreement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementRentalAgreementBuilder { inner }
+    RTDInputPassportElementRentalAgreementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementRentalAgreement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("rental_agreement".to_string()); } else { missing.extend(crate::types::_common::missing_in("rental_agreement", self.rental_agreement._missing_fields())); }
+    missing
   }
 
   pub fn rental_agreement(&self) -> &InputPersonalDocument { &self.rental_agreement }
@@ -923,15 +1195,25 @@ impl InputPassportElementRentalAgreement {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementRentalAgreementBuilder {
-  inner: InputPassportElementRentalAgreement
+  inner: InputPassportElementRentalAgreement,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> InputPassportElementRentalAgreement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementRentalAgreement> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("rental_agreement".to_string()); } else { missing.extend(crate::types::_common::missing_in("rental_agreement", self.inner.rental_agreement._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementRentalAgreement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn rental_agreement<T: AsRef<InputPersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -982,7 +1264,16 @@ impl InputPassportElementTemporaryRegistration {
     let mut inner = InputPassportElementTemporaryRegistration::default();
     inner.td_name = "inputPassportElementTemporaryRegistration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementTemporaryRegistrationBuilder { inner }
+    RTDInputPassportElementTemporaryRegistrationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementTemporaryRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("temporary_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("temporary_registration", self.temporary_registration._missing_fields())); }
+    missing
   }
 
   pub fn temporary_registration(&self) -> &InputPersonalDocument { &self.temporary_registration }
@@ -991,15 +1282,25 @@ impl InputPassportElementTemporaryRegistration {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementTemporaryRegistrationBuilder {
-  inner: InputPassportElementTemporaryRegistration
+  inner: InputPassportElementTemporaryRegistration,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> InputPassportElementTemporaryRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementTemporaryRegistration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("temporary_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("temporary_registration", self.inner.temporary_registration._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementTemporaryRegistration", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn temporary_registration<T: AsRef<InputPersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1050,7 +1351,16 @@ impl InputPassportElementUtilityBill {
     let mut inner = InputPassportElementUtilityBill::default();
     inner.td_name = "inputPassportElementUtilityBill".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementUtilityBillBuilder { inner }
+    RTDInputPassportElementUtilityBillBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementUtilityBill", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("utility_bill".to_string()); } else { missing.extend(crate::types::_common::missing_in("utility_bill", self.utility_bill._missing_fields())); }
+    missing
   }
 
   pub fn utility_bill(&self) -> &InputPersonalDocument { &self.utility_bill }
@@ -1059,15 +1369,25 @@ impl InputPassportElementUtilityBill {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementUtilityBillBuilder {
-  inner: InputPassportElementUtilityBill
+  inner: InputPassportElementUtilityBill,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementUtilityBillBuilder {
   pub fn build(&self) -> InputPassportElementUtilityBill { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementUtilityBill> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("utility_bill".to_string()); } else { missing.extend(crate::types::_common::missing_in("utility_bill", self.inner.utility_bill._missing_fields())); }
+    crate::types::_common::check_missing("inputPassportElementUtilityBill", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn utility_bill<T: AsRef<InputPersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDInputPassportElementErrorSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputPassportElementErrorSource {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A data field contains an error. The error is considered resolved when the field's value changes
   DataField(InputPassportElementErrorSourceDataField),
   /// The file contains an error. The error is considered resolved when the file changes
@@ -46,7 +49,6 @@ impl Default for InputPassportElementErrorSource {
 
 impl<'de> Deserialize<'de> for InputPassportElementErrorSource {
   fn deserialize<D>(deserializer: D) -> Result<InputPassportElementErrorSource, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputPassportElementErrorSource,
       (inputPassportElementErrorSourceDataField, DataField);
@@ -90,6 +92,7 @@ impl RObject for InputPassportElementErrorSource {
       InputPassportElementErrorSource::TranslationFile(t) => t.extra(),
       InputPassportElementErrorSource::TranslationFiles(t) => t.extra(),
       InputPassportElementErrorSource::Unspecified(t) => t.extra(),
+      InputPassportElementErrorSource::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -100,6 +103,20 @@ impl RObject for InputPassportElementErrorSource {
 impl InputPassportElementErrorSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputPassportElementErrorSource::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputPassportElementErrorSource::DataField(t) => t._missing_fields(),
+      InputPassportElementErrorSource::File(t) => t._missing_fields(),
+      InputPassportElementErrorSource::Files(t) => t._missing_fields(),
+      InputPassportElementErrorSource::FrontSide(t) => t._missing_fields(),
+      InputPassportElementErrorSource::ReverseSide(t) => t._missing_fields(),
+      InputPassportElementErrorSource::Selfie(t) => t._missing_fields(),
+      InputPassportElementErrorSource::TranslationFile(t) => t._missing_fields(),
+      InputPassportElementErrorSource::TranslationFiles(t) => t._missing_fields(),
+      InputPassportElementErrorSource::Unspecified(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_data_field(&self) -> bool { if let InputPassportElementErrorSource::DataField(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let InputPassportElementErrorSource::File(_) = self { true } else { false } }
@@ -157,6 +174,42 @@ impl AsRef<InputPassportElementErrorSource> for InputPassportElementErrorSource
   fn as_ref(&self) -> &InputPassportElementErrorSource { self }
 }
 
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceDataField {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::DataField(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceFile {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::File(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceFiles {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::Files(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceFrontSide {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::FrontSide(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceReverseSide {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::ReverseSide(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceSelfie {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::Selfie(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceTranslationFile {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::TranslationFile(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceTranslationFiles {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::TranslationFiles(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputPassportElementErrorSource> for InputPassportElementErrorSourceUnspecified {
+  fn from_class(class: &InputPassportElementErrorSource) -> Option<&Self> { match class { InputPassportElementErrorSource::Unspecified(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -196,7 +249,17 @@ impl InputPassportElementErrorSourceDataField {
     let mut inner = InputPassportElementErrorSourceDataField::default();
     inner.td_name = "inputPassportElementErrorSourceDataField".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceDataFieldBuilder { inner }
+    RTDInputPassportElementErrorSourceDataFieldBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceDataField", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.field_name.is_empty() { missing.push("field_name".to_string()); }
+    if self.data_hash.is_empty() { missing.push("data_hash".to_string()); }
+    missing
   }
 
   pub fn field_name(&self) -> &String { &self.field_name }
@@ -207,21 +270,33 @@ impl InputPassportElementErrorSourceDataField {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceDataFieldBuilder {
-  inner: InputPassportElementErrorSourceDataField
+  inner: InputPassportElementErrorSourceDataField,
+  set: [bool; 2]
 }
 
 impl RTDInputPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceDataField { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceDataField> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("field_name".to_string()); }
+    if !self.set[1] { missing.push("data_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceDataField", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn data_hash<T: AsRef<str>>(&mut self, data_hash: T) -> &mut Self {
     self.inner.data_hash = data_hash.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -272,7 +347,16 @@ impl InputPassportElementErrorSourceFile {
     let mut inner = InputPassportElementErrorSourceFile::default();
     inner.td_name = "inputPassportElementErrorSourceFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceFileBuilder { inner }
+    RTDInputPassportElementErrorSourceFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_hash.is_empty() { missing.push("file_hash".to_string()); }
+    missing
   }
 
   pub fn file_hash(&self) -> &String { &self.file_hash }
@@ -281,15 +365,25 @@ impl InputPassportElementErrorSourceFile {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceFileBuilder {
-  inner: InputPassportElementErrorSourceFile
+  inner: InputPassportElementErrorSourceFile,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
     self.inner.file_hash = file_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -343,6 +437,11 @@ impl InputPassportElementErrorSourceFiles {
     RTDInputPassportElementErrorSourceFilesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceFiles", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
 
 }
@@ -355,6 +454,9 @@ pub struct RTDInputPassportElementErrorSourceFilesBuilder {
 impl RTDInputPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFiles { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFiles> { Ok(self.build()) }
+
    
   pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
@@ -408,7 +510,16 @@ impl InputPassportElementErrorSourceFrontSide {
     let mut inner = InputPassportElementErrorSourceFrontSide::default();
     inner.td_name = "inputPassportElementErrorSourceFrontSide".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceFrontSideBuilder { inner }
+    RTDInputPassportElementErrorSourceFrontSideBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceFrontSide", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_hash.is_empty() { missing.push("file_hash".to_string()); }
+    missing
   }
 
   pub fn file_hash(&self) -> &String { &self.file_hash }
@@ -417,15 +528,25 @@ impl InputPassportElementErrorSourceFrontSide {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceFrontSideBuilder {
-  inner: InputPassportElementErrorSourceFrontSide
+  inner: InputPassportElementErrorSourceFrontSide,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceFrontSide> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceFrontSide", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
     self.inner.file_hash = file_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -476,7 +597,16 @@ impl InputPassportElementErrorSourceReverseSide {
     let mut inner = InputPassportElementErrorSourceReverseSide::default();
     inner.td_name = "inputPassportElementErrorSourceReverseSide".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceReverseSideBuilder { inner }
+    RTDInputPassportElementErrorSourceReverseSideBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceReverseSide", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_hash.is_empty() { missing.push("file_hash".to_string()); }
+    missing
   }
 
   pub fn file_hash(&self) -> &String { &self.file_hash }
@@ -485,15 +615,25 @@ impl InputPassportElementErrorSourceReverseSide {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceReverseSideBuilder {
-  inner: InputPassportElementErrorSourceReverseSide
+  inner: InputPassportElementErrorSourceReverseSide,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceReverseSide> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceReverseSide", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
     self.inner.file_hash = file_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -544,7 +684,16 @@ impl InputPassportElementErrorSourceSelfie {
     let mut inner = InputPassportElementErrorSourceSelfie::default();
     inner.td_name = "inputPassportElementErrorSourceSelfie".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceSelfieBuilder { inner }
+    RTDInputPassportElementErrorSourceSelfieBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceSelfie", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_hash.is_empty() { missing.push("file_hash".to_string()); }
+    missing
   }
 
   pub fn file_hash(&self) -> &String { &self.file_hash }
@@ -553,15 +702,25 @@ impl InputPassportElementErrorSourceSelfie {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceSelfieBuilder {
-  inner: InputPassportElementErrorSourceSelfie
+  inner: InputPassportElementErrorSourceSelfie,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceSelfie { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceSelfie> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceSelfie", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
     self.inner.file_hash = file_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -612,7 +771,16 @@ impl InputPassportElementErrorSourceTranslationFile {
     let mut inner = InputPassportElementErrorSourceTranslationFile::default();
     inner.td_name = "inputPassportElementErrorSourceTranslationFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceTranslationFileBuilder { inner }
+    RTDInputPassportElementErrorSourceTranslationFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceTranslationFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_hash.is_empty() { missing.push("file_hash".to_string()); }
+    missing
   }
 
   pub fn file_hash(&self) -> &String { &self.file_hash }
@@ -621,15 +789,25 @@ impl InputPassportElementErrorSourceTranslationFile {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceTranslationFileBuilder {
-  inner: InputPassportElementErrorSourceTranslationFile
+  inner: InputPassportElementErrorSourceTranslationFile,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceTranslationFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceTranslationFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_hash<T: AsRef<str>>(&mut self, file_hash: T) -> &mut Self {
     self.inner.file_hash = file_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -683,6 +861,11 @@ impl InputPassportElementErrorSourceTranslationFiles {
     RTDInputPassportElementErrorSourceTranslationFilesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceTranslationFiles", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn file_hashes(&self) -> &Vec<String> { &self.file_hashes }
 
 }
@@ -695,6 +878,9 @@ pub struct RTDInputPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDInputPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceTranslationFiles> { Ok(self.build()) }
+
    
   pub fn file_hashes(&mut self, file_hashes: Vec<String>) -> &mut Self {
     self.inner.file_hashes = file_hashes;
@@ -748,7 +934,16 @@ impl InputPassportElementErrorSourceUnspecified {
     let mut inner = InputPassportElementErrorSourceUnspecified::default();
     inner.td_name = "inputPassportElementErrorSourceUnspecified".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputPassportElementErrorSourceUnspecifiedBuilder { inner }
+    RTDInputPassportElementErrorSourceUnspecifiedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPassportElementErrorSourceUnspecified", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.element_hash.is_empty() { missing.push("element_hash".to_string()); }
+    missing
   }
 
   pub fn element_hash(&self) -> &String { &self.element_hash }
@@ -757,15 +952,25 @@ impl InputPassportElementErrorSourceUnspecified {
 
 #[doc(hidden)]
 pub struct RTDInputPassportElementErrorSourceUnspecifiedBuilder {
-  inner: InputPassportElementErrorSourceUnspecified
+  inner: InputPassportElementErrorSourceUnspecified,
+  set: [bool; 1]
 }
 
 impl RTDInputPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> InputPassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPassportElementErrorSourceUnspecified> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("element_hash".to_string()); }
+    crate::types::_common::check_missing("inputPassportElementErrorSourceUnspecified", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn element_hash<T: AsRef<str>>(&mut self, element_hash: T) -> &mut Self {
     self.inner.element_hash = element_hash.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -39,6 +39,11 @@ impl InputPersonalDocument {
     RTDInputPersonalDocumentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputPersonalDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn files(&self) -> &Vec<InputFile> { &self.files }
 
   pub fn translation(&self) -> &Vec<InputFile> { &self.translation }
@@ -53,6 +58,9 @@ pub struct RTDInputPersonalDocumentBuilder {
 impl RTDInputPersonalDocumentBuilder {
   pub fn build(&self) -> InputPersonalDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputPersonalDocument> { Ok(self.build()) }
+
    
   pub fn files(&mut self, files: Vec<InputFile>) -> &mut Self {
     self.inner.files = files;
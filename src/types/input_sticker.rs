@@ -19,6 +19,9 @@ pub trait TDInputSticker: Debug + RObject {}
 #[serde(untagged)]
 pub enum InputSticker {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animated sticker in TGS format
   Animated(InputStickerAnimated),
   /// A static sticker in PNG format, which will be converted to WEBP server-side
@@ -32,7 +35,6 @@ impl Default for InputSticker {
 
 impl<'de> Deserialize<'de> for InputSticker {
   fn deserialize<D>(deserializer: D) -> Result<InputSticker, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       InputSticker,
       (inputStickerAnimated, Animated);
@@ -55,6 +57,7 @@ impl RObject for InputSticker {
     match self {
       InputSticker::Animated(t) => t.extra(),
       InputSticker::Static(t) => t.extra(),
+      InputSticker::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for InputSticker {
 impl InputSticker {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let InputSticker::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      InputSticker::Animated(t) => t._missing_fields(),
+      InputSticker::Static(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animated(&self) -> bool { if let InputSticker::Animated(_) = self { true } else { false } }
   pub fn is_static(&self) -> bool { if let InputSticker::Static(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<InputSticker> for InputSticker {
   fn as_ref(&self) -> &InputSticker { self }
 }
 
+impl RVariant<InputSticker> for InputStickerAnimated {
+  fn from_class(class: &InputSticker) -> Option<&Self> { match class { InputSticker::Animated(t) => Some(t), _ => None } }
+}
+
+impl RVariant<InputSticker> for InputStickerStatic {
+  fn from_class(class: &InputSticker) -> Option<&Self> { match class { InputSticker::Static(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -126,7 +144,17 @@ impl InputStickerAnimated {
     let mut inner = InputStickerAnimated::default();
     inner.td_name = "inputStickerAnimated".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputStickerAnimatedBuilder { inner }
+    RTDInputStickerAnimatedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputStickerAnimated", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    if self.emojis.is_empty() { missing.push("emojis".to_string()); }
+    missing
   }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -137,12 +165,22 @@ impl InputStickerAnimated {
 
 #[doc(hidden)]
 pub struct RTDInputStickerAnimatedBuilder {
-  inner: InputStickerAnimated
+  inner: InputStickerAnimated,
+  set: [bool; 1]
 }
 
 impl RTDInputStickerAnimatedBuilder {
   pub fn build(&self) -> InputStickerAnimated { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputStickerAnimated> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    if !self.set[0] { missing.push("emojis".to_string()); }
+    crate::types::_common::check_missing("inputStickerAnimated", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -152,6 +190,7 @@ impl RTDInputStickerAnimatedBuilder {
    
   pub fn emojis<T: AsRef<str>>(&mut self, emojis: T) -> &mut Self {
     self.inner.emojis = emojis.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -206,7 +245,17 @@ impl InputStickerStatic {
     let mut inner = InputStickerStatic::default();
     inner.td_name = "inputStickerStatic".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInputStickerStaticBuilder { inner }
+    RTDInputStickerStaticBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputStickerStatic", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    if self.emojis.is_empty() { missing.push("emojis".to_string()); }
+    missing
   }
 
   pub fn sticker(&self) -> &InputFile { &self.sticker }
@@ -219,12 +268,22 @@ impl InputStickerStatic {
 
 #[doc(hidden)]
 pub struct RTDInputStickerStaticBuilder {
-  inner: InputStickerStatic
+  inner: InputStickerStatic,
+  set: [bool; 1]
 }
 
 impl RTDInputStickerStaticBuilder {
   pub fn build(&self) -> InputStickerStatic { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputStickerStatic> {
+    let mut missing = vec![];
+    if self.inner.sticker._is_default() { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    if !self.set[0] { missing.push("emojis".to_string()); }
+    crate::types::_common::check_missing("inputStickerStatic", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<InputFile>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
@@ -234,6 +293,7 @@ impl RTDInputStickerStaticBuilder {
    
   pub fn emojis<T: AsRef<str>>(&mut self, emojis: T) -> &mut Self {
     self.inner.emojis = emojis.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -41,6 +41,15 @@ impl InputThumbnail {
     RTDInputThumbnailBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("inputThumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.thumbnail._is_default() { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.thumbnail._missing_fields())); }
+    missing
+  }
+
   pub fn thumbnail(&self) -> &InputFile { &self.thumbnail }
 
   pub fn width(&self) -> i64 { self.width }
@@ -57,6 +66,14 @@ pub struct RTDInputThumbnailBuilder {
 impl RTDInputThumbnailBuilder {
   pub fn build(&self) -> InputThumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<InputThumbnail> {
+    let mut missing = vec![];
+    if self.inner.thumbnail._is_default() { missing.push("thumbnail".to_string()); } else { missing.extend(crate::types::_common::missing_in("thumbnail", self.inner.thumbnail._missing_fields())); }
+    crate::types::_common::check_missing("inputThumbnail", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn thumbnail<T: AsRef<InputFile>>(&mut self, thumbnail: T) -> &mut Self {
     self.inner.thumbnail = thumbnail.as_ref().clone();
@@ -52,7 +52,16 @@ impl Invoice {
     let mut inner = Invoice::default();
     inner.td_name = "invoice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDInvoiceBuilder { inner }
+    RTDInvoiceBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("invoice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.currency.is_empty() { missing.push("currency".to_string()); }
+    missing
   }
 
   pub fn currency(&self) -> &String { &self.currency }
@@ -79,15 +88,25 @@ impl Invoice {
 
 #[doc(hidden)]
 pub struct RTDInvoiceBuilder {
-  inner: Invoice
+  inner: Invoice,
+  set: [bool; 1]
 }
 
 impl RTDInvoiceBuilder {
   pub fn build(&self) -> Invoice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Invoice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("currency".to_string()); }
+    crate::types::_common::check_missing("invoice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -19,10 +19,9 @@ pub trait TDJsonValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum JsonValue {
   #[doc(hidden)] _Default(()),
-  /// Returns application config, provided by the server. Can be called before authorization
-  GetApplicationConfig(GetApplicationConfig),
-  /// Converts a JSON-serialized string to corresponding JsonValue object. Can be called synchronously
-  GetJsonValue(GetJsonValue),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a JSON array
   Array(JsonValueArray),
   /// Represents a boolean JSON value
@@ -44,11 +43,8 @@ impl Default for JsonValue {
 
 impl<'de> Deserialize<'de> for JsonValue {
   fn deserialize<D>(deserializer: D) -> Result<JsonValue, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       JsonValue,
-      (getApplicationConfig, GetApplicationConfig);
-      (getJsonValue, GetJsonValue);
       (jsonValueArray, Array);
       (jsonValueBoolean, Boolean);
       (jsonValueNull, Null);
@@ -63,8 +59,6 @@ impl<'de> Deserialize<'de> for JsonValue {
 impl RObject for JsonValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      JsonValue::GetApplicationConfig(t) => t.td_name(),
-      JsonValue::GetJsonValue(t) => t.td_name(),
       JsonValue::Array(t) => t.td_name(),
       JsonValue::Boolean(t) => t.td_name(),
       JsonValue::Null(t) => t.td_name(),
@@ -77,14 +71,13 @@ impl RObject for JsonValue {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      JsonValue::GetApplicationConfig(t) => t.extra(),
-      JsonValue::GetJsonValue(t) => t.extra(),
       JsonValue::Array(t) => t.extra(),
       JsonValue::Boolean(t) => t.extra(),
       JsonValue::Null(t) => t.extra(),
       JsonValue::Number(t) => t.extra(),
       JsonValue::Object(t) => t.extra(),
       JsonValue::String(t) => t.extra(),
+      JsonValue::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -95,9 +88,18 @@ impl RObject for JsonValue {
 impl JsonValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let JsonValue::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      JsonValue::Array(t) => t._missing_fields(),
+      JsonValue::Boolean(t) => t._missing_fields(),
+      JsonValue::Null(t) => t._missing_fields(),
+      JsonValue::Number(t) => t._missing_fields(),
+      JsonValue::Object(t) => t._missing_fields(),
+      JsonValue::String(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_application_config(&self) -> bool { if let JsonValue::GetApplicationConfig(_) = self { true } else { false } }
-  pub fn is_get_json_value(&self) -> bool { if let JsonValue::GetJsonValue(_) = self { true } else { false } }
   pub fn is_array(&self) -> bool { if let JsonValue::Array(_) = self { true } else { false } }
   pub fn is_boolean(&self) -> bool { if let JsonValue::Boolean(_) = self { true } else { false } }
   pub fn is_null(&self) -> bool { if let JsonValue::Null(_) = self { true } else { false } }
@@ -105,8 +107,6 @@ impl JsonValue {
   pub fn is_object(&self) -> bool { if let JsonValue::Object(_) = self { true } else { false } }
   pub fn is_string(&self) -> bool { if let JsonValue::String(_) = self { true } else { false } }
 
-  pub fn on_get_application_config<F: FnOnce(&GetApplicationConfig)>(&self, fnc: F) -> &Self { if let JsonValue::GetApplicationConfig(t) = self { fnc(t) }; self }
-  pub fn on_get_json_value<F: FnOnce(&GetJsonValue)>(&self, fnc: F) -> &Self { if let JsonValue::GetJsonValue(t) = self { fnc(t) }; self }
   pub fn on_array<F: FnOnce(&JsonValueArray)>(&self, fnc: F) -> &Self { if let JsonValue::Array(t) = self { fnc(t) }; self }
   pub fn on_boolean<F: FnOnce(&JsonValueBoolean)>(&self, fnc: F) -> &Self { if let JsonValue::Boolean(t) = self { fnc(t) }; self }
   pub fn on_null<F: FnOnce(&JsonValueNull)>(&self, fnc: F) -> &Self { if let JsonValue::Null(t) = self { fnc(t) }; self }
@@ -114,8 +114,6 @@ impl JsonValue {
   pub fn on_object<F: FnOnce(&JsonValueObject)>(&self, fnc: F) -> &Self { if let JsonValue::Object(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&JsonValueString)>(&self, fnc: F) -> &Self { if let JsonValue::String(t) = self { fnc(t) }; self }
 
-  pub fn as_get_application_config(&self) -> Option<&GetApplicationConfig> { if let JsonValue::GetApplicationConfig(t) = self { return Some(t) } None }
-  pub fn as_get_json_value(&self) -> Option<&GetJsonValue> { if let JsonValue::GetJsonValue(t) = self { return Some(t) } None }
   pub fn as_array(&self) -> Option<&JsonValueArray> { if let JsonValue::Array(t) = self { return Some(t) } None }
   pub fn as_boolean(&self) -> Option<&JsonValueBoolean> { if let JsonValue::Boolean(t) = self { return Some(t) } None }
   pub fn as_null(&self) -> Option<&JsonValueNull> { if let JsonValue::Null(t) = self { return Some(t) } None }
@@ -125,10 +123,6 @@ impl JsonValue {
 
 
 
-  pub fn get_application_config<T: AsRef<GetApplicationConfig>>(t: T) -> Self { JsonValue::GetApplicationConfig(t.as_ref().clone()) }
-
-  pub fn get_json_value<T: AsRef<GetJsonValue>>(t: T) -> Self { JsonValue::GetJsonValue(t.as_ref().clone()) }
-
   pub fn array<T: AsRef<JsonValueArray>>(t: T) -> Self { JsonValue::Array(t.as_ref().clone()) }
 
   pub fn boolean<T: AsRef<JsonValueBoolean>>(t: T) -> Self { JsonValue::Boolean(t.as_ref().clone()) }
@@ -147,6 +141,30 @@ impl AsRef<JsonValue> for JsonValue {
   fn as_ref(&self) -> &JsonValue { self }
 }
 
+impl RVariant<JsonValue> for JsonValueArray {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::Array(t) => Some(t), _ => None } }
+}
+
+impl RVariant<JsonValue> for JsonValueBoolean {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::Boolean(t) => Some(t), _ => None } }
+}
+
+impl RVariant<JsonValue> for JsonValueNull {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::Null(t) => Some(t), _ => None } }
+}
+
+impl RVariant<JsonValue> for JsonValueNumber {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::Number(t) => Some(t), _ => None } }
+}
+
+impl RVariant<JsonValue> for JsonValueObject {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::Object(t) => Some(t), _ => None } }
+}
+
+impl RVariant<JsonValue> for JsonValueString {
+  fn from_class(class: &JsonValue) -> Option<&Self> { match class { JsonValue::String(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -187,6 +205,11 @@ impl JsonValueArray {
     RTDJsonValueArrayBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueArray", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn values(&self) -> &Vec<JsonValue> { &self.values }
 
 }
@@ -199,6 +222,9 @@ pub struct RTDJsonValueArrayBuilder {
 impl RTDJsonValueArrayBuilder {
   pub fn build(&self) -> JsonValueArray { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueArray> { Ok(self.build()) }
+
    
   pub fn values(&mut self, values: Vec<JsonValue>) -> &mut Self {
     self.inner.values = values;
@@ -255,6 +281,11 @@ impl JsonValueBoolean {
     RTDJsonValueBooleanBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueBoolean", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> bool { self.value }
 
 }
@@ -267,6 +298,9 @@ pub struct RTDJsonValueBooleanBuilder {
 impl RTDJsonValueBooleanBuilder {
   pub fn build(&self) -> JsonValueBoolean { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueBoolean> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -321,6 +355,11 @@ impl JsonValueNull {
     RTDJsonValueNullBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueNull", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -331,6 +370,9 @@ pub struct RTDJsonValueNullBuilder {
 impl RTDJsonValueNullBuilder {
   pub fn build(&self) -> JsonValueNull { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueNull> { Ok(self.build()) }
+
 }
 
 impl AsRef<JsonValueNull> for JsonValueNull {
@@ -378,7 +420,16 @@ impl JsonValueNumber {
     let mut inner = JsonValueNumber::default();
     inner.td_name = "jsonValueNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDJsonValueNumberBuilder { inner }
+    RTDJsonValueNumberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value == 0.0 { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> f32 { self.value }
@@ -387,15 +438,25 @@ impl JsonValueNumber {
 
 #[doc(hidden)]
 pub struct RTDJsonValueNumberBuilder {
-  inner: JsonValueNumber
+  inner: JsonValueNumber,
+  set: [bool; 1]
 }
 
 impl RTDJsonValueNumberBuilder {
   pub fn build(&self) -> JsonValueNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("jsonValueNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value(&mut self, value: f32) -> &mut Self {
     self.inner.value = value;
+    self.set[0] = true;
     self
   }
 
@@ -449,6 +510,11 @@ impl JsonValueObject {
     RTDJsonValueObjectBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueObject", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn members(&self) -> &Vec<JsonObjectMember> { &self.members }
 
 }
@@ -461,6 +527,9 @@ pub struct RTDJsonValueObjectBuilder {
 impl RTDJsonValueObjectBuilder {
   pub fn build(&self) -> JsonValueObject { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueObject> { Ok(self.build()) }
+
    
   pub fn members(&mut self, members: Vec<JsonObjectMember>) -> &mut Self {
     self.inner.members = members;
@@ -514,7 +583,16 @@ impl JsonValueString {
     let mut inner = JsonValueString::default();
     inner.td_name = "jsonValueString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDJsonValueStringBuilder { inner }
+    RTDJsonValueStringBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("jsonValueString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> &String { &self.value }
@@ -523,15 +601,25 @@ impl JsonValueString {
 
 #[doc(hidden)]
 pub struct RTDJsonValueStringBuilder {
-  inner: JsonValueString
+  inner: JsonValueString,
+  set: [bool; 1]
 }
 
 impl RTDJsonValueStringBuilder {
   pub fn build(&self) -> JsonValueString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<JsonValueString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("jsonValueString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl KeyboardButton {
     let mut inner = KeyboardButton::default();
     inner.td_name = "keyboardButton".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDKeyboardButtonBuilder { inner }
+    RTDKeyboardButtonBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("keyboardButton", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -47,15 +57,26 @@ impl KeyboardButton {
 
 #[doc(hidden)]
 pub struct RTDKeyboardButtonBuilder {
-  inner: KeyboardButton
+  inner: KeyboardButton,
+  set: [bool; 1]
 }
 
 impl RTDKeyboardButtonBuilder {
   pub fn build(&self) -> KeyboardButton { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<KeyboardButton> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("keyboardButton", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
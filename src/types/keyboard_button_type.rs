@@ -19,6 +19,9 @@ pub trait TDKeyboardButtonType: Debug + RObject {}
 #[serde(untagged)]
 pub enum KeyboardButtonType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A button that sends the user's location when pressed; available only in private chats
   RequestLocation(KeyboardButtonTypeRequestLocation),
   /// A button that sends the user's phone number when pressed; available only in private chats
@@ -36,7 +39,6 @@ impl Default for KeyboardButtonType {
 
 impl<'de> Deserialize<'de> for KeyboardButtonType {
   fn deserialize<D>(deserializer: D) -> Result<KeyboardButtonType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       KeyboardButtonType,
       (keyboardButtonTypeRequestLocation, RequestLocation);
@@ -65,6 +67,7 @@ impl RObject for KeyboardButtonType {
       KeyboardButtonType::RequestPhoneNumber(t) => t.extra(),
       KeyboardButtonType::RequestPoll(t) => t.extra(),
       KeyboardButtonType::Text(t) => t.extra(),
+      KeyboardButtonType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for KeyboardButtonType {
 impl KeyboardButtonType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let KeyboardButtonType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      KeyboardButtonType::RequestLocation(t) => t._missing_fields(),
+      KeyboardButtonType::RequestPhoneNumber(t) => t._missing_fields(),
+      KeyboardButtonType::RequestPoll(t) => t._missing_fields(),
+      KeyboardButtonType::Text(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_request_location(&self) -> bool { if let KeyboardButtonType::RequestLocation(_) = self { true } else { false } }
   pub fn is_request_phone_number(&self) -> bool { if let KeyboardButtonType::RequestPhoneNumber(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<KeyboardButtonType> for KeyboardButtonType {
   fn as_ref(&self) -> &KeyboardButtonType { self }
 }
 
+impl RVariant<KeyboardButtonType> for KeyboardButtonTypeRequestLocation {
+  fn from_class(class: &KeyboardButtonType) -> Option<&Self> { match class { KeyboardButtonType::RequestLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<KeyboardButtonType> for KeyboardButtonTypeRequestPhoneNumber {
+  fn from_class(class: &KeyboardButtonType) -> Option<&Self> { match class { KeyboardButtonType::RequestPhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<KeyboardButtonType> for KeyboardButtonTypeRequestPoll {
+  fn from_class(class: &KeyboardButtonType) -> Option<&Self> { match class { KeyboardButtonType::RequestPoll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<KeyboardButtonType> for KeyboardButtonTypeText {
+  fn from_class(class: &KeyboardButtonType) -> Option<&Self> { match class { KeyboardButtonType::Text(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -145,6 +173,11 @@ impl KeyboardButtonTypeRequestLocation {
     RTDKeyboardButtonTypeRequestLocationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("keyboardButtonTypeRequestLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +188,9 @@ pub struct RTDKeyboardButtonTypeRequestLocationBuilder {
 impl RTDKeyboardButtonTypeRequestLocationBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestLocation> { Ok(self.build()) }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestLocation> for KeyboardButtonTypeRequestLocation {
@@ -203,6 +239,11 @@ impl KeyboardButtonTypeRequestPhoneNumber {
     RTDKeyboardButtonTypeRequestPhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("keyboardButtonTypeRequestPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +254,9 @@ pub struct RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
 impl RTDKeyboardButtonTypeRequestPhoneNumberBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestPhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<KeyboardButtonTypeRequestPhoneNumber> for KeyboardButtonTypeRequestPhoneNumber {
@@ -265,6 +309,11 @@ impl KeyboardButtonTypeRequestPoll {
     RTDKeyboardButtonTypeRequestPollBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("keyboardButtonTypeRequestPoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn force_regular(&self) -> bool { self.force_regular }
 
   pub fn force_quiz(&self) -> bool { self.force_quiz }
@@ -279,6 +328,9 @@ pub struct RTDKeyboardButtonTypeRequestPollBuilder {
 impl RTDKeyboardButtonTypeRequestPollBuilder {
   pub fn build(&self) -> KeyboardButtonTypeRequestPoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeRequestPoll> { Ok(self.build()) }
+
    
   pub fn force_regular(&mut self, force_regular: bool) -> &mut Self {
     self.inner.force_regular = force_regular;
@@ -339,6 +391,11 @@ impl KeyboardButtonTypeText {
     RTDKeyboardButtonTypeTextBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("keyboardButtonTypeText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +406,9 @@ pub struct RTDKeyboardButtonTypeTextBuilder {
 impl RTDKeyboardButtonTypeTextBuilder {
   pub fn build(&self) -> KeyboardButtonTypeText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<KeyboardButtonTypeText> { Ok(self.build()) }
+
 }
 
 impl AsRef<KeyboardButtonTypeText> for KeyboardButtonTypeText {
@@ -36,7 +36,17 @@ impl LabeledPricePart {
     let mut inner = LabeledPricePart::default();
     inner.td_name = "labeledPricePart".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLabeledPricePartBuilder { inner }
+    RTDLabeledPricePartBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("labeledPricePart", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.label.is_empty() { missing.push("label".to_string()); }
+    if self.amount == 0 { missing.push("amount".to_string()); }
+    missing
   }
 
   pub fn label(&self) -> &String { &self.label }
@@ -47,21 +57,33 @@ impl LabeledPricePart {
 
 #[doc(hidden)]
 pub struct RTDLabeledPricePartBuilder {
-  inner: LabeledPricePart
+  inner: LabeledPricePart,
+  set: [bool; 2]
 }
 
 impl RTDLabeledPricePartBuilder {
   pub fn build(&self) -> LabeledPricePart { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LabeledPricePart> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("label".to_string()); }
+    if !self.set[1] { missing.push("amount".to_string()); }
+    crate::types::_common::check_missing("labeledPricePart", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
     self.inner.label = label.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn amount(&mut self, amount: i64) -> &mut Self {
     self.inner.amount = amount;
+    self.set[1] = true;
     self
   }
 
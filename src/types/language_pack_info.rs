@@ -58,7 +58,23 @@ impl LanguagePackInfo {
     let mut inner = LanguagePackInfo::default();
     inner.td_name = "languagePackInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLanguagePackInfoBuilder { inner }
+    RTDLanguagePackInfoBuilder { inner, set: [false; 8] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.native_name.is_empty() { missing.push("native_name".to_string()); }
+    if self.plural_code.is_empty() { missing.push("plural_code".to_string()); }
+    if self.total_string_count == 0 { missing.push("total_string_count".to_string()); }
+    if self.translated_string_count == 0 { missing.push("translated_string_count".to_string()); }
+    if self.local_string_count == 0 { missing.push("local_string_count".to_string()); }
+    if self.translation_url.is_empty() { missing.push("translation_url".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -91,15 +107,32 @@ impl LanguagePackInfo {
 
 #[doc(hidden)]
 pub struct RTDLanguagePackInfoBuilder {
-  inner: LanguagePackInfo
+  inner: LanguagePackInfo,
+  set: [bool; 8]
 }
 
 impl RTDLanguagePackInfoBuilder {
   pub fn build(&self) -> LanguagePackInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("name".to_string()); }
+    if !self.set[2] { missing.push("native_name".to_string()); }
+    if !self.set[3] { missing.push("plural_code".to_string()); }
+    if !self.set[4] { missing.push("total_string_count".to_string()); }
+    if !self.set[5] { missing.push("translated_string_count".to_string()); }
+    if !self.set[6] { missing.push("local_string_count".to_string()); }
+    if !self.set[7] { missing.push("translation_url".to_string()); }
+    crate::types::_common::check_missing("languagePackInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -112,18 +145,21 @@ impl RTDLanguagePackInfoBuilder {
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn native_name<T: AsRef<str>>(&mut self, native_name: T) -> &mut Self {
     self.inner.native_name = native_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn plural_code<T: AsRef<str>>(&mut self, plural_code: T) -> &mut Self {
     self.inner.plural_code = plural_code.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -154,24 +190,28 @@ impl RTDLanguagePackInfoBuilder {
    
   pub fn total_string_count(&mut self, total_string_count: i64) -> &mut Self {
     self.inner.total_string_count = total_string_count;
+    self.set[4] = true;
     self
   }
 
    
   pub fn translated_string_count(&mut self, translated_string_count: i64) -> &mut Self {
     self.inner.translated_string_count = translated_string_count;
+    self.set[5] = true;
     self
   }
 
    
   pub fn local_string_count(&mut self, local_string_count: i64) -> &mut Self {
     self.inner.local_string_count = local_string_count;
+    self.set[6] = true;
     self
   }
 
    
   pub fn translation_url<T: AsRef<str>>(&mut self, translation_url: T) -> &mut Self {
     self.inner.translation_url = translation_url.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
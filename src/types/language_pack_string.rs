@@ -36,7 +36,17 @@ impl LanguagePackString {
     let mut inner = LanguagePackString::default();
     inner.td_name = "languagePackString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLanguagePackStringBuilder { inner }
+    RTDLanguagePackStringBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.key.is_empty() { missing.push("key".to_string()); }
+    if self.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.value._missing_fields())); }
+    missing
   }
 
   pub fn key(&self) -> &String { &self.key }
@@ -47,15 +57,26 @@ impl LanguagePackString {
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringBuilder {
-  inner: LanguagePackString
+  inner: LanguagePackString,
+  set: [bool; 1]
 }
 
 impl RTDLanguagePackStringBuilder {
   pub fn build(&self) -> LanguagePackString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("key".to_string()); }
+    if self.inner.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.inner.value._missing_fields())); }
+    crate::types::_common::check_missing("languagePackString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn key<T: AsRef<str>>(&mut self, key: T) -> &mut Self {
     self.inner.key = key.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
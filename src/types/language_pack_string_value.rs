@@ -19,8 +19,9 @@ pub trait TDLanguagePackStringValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum LanguagePackStringValue {
   #[doc(hidden)] _Default(()),
-  /// Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. Can be called synchronously
-  GetLanguagePackString(GetLanguagePackString),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A deleted language pack string, the value should be taken from the built-in english language pack
   Deleted(LanguagePackStringValueDeleted),
   /// An ordinary language pack string
@@ -36,10 +37,8 @@ impl Default for LanguagePackStringValue {
 
 impl<'de> Deserialize<'de> for LanguagePackStringValue {
   fn deserialize<D>(deserializer: D) -> Result<LanguagePackStringValue, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       LanguagePackStringValue,
-      (getLanguagePackString, GetLanguagePackString);
       (languagePackStringValueDeleted, Deleted);
       (languagePackStringValueOrdinary, Ordinary);
       (languagePackStringValuePluralized, Pluralized);
@@ -51,7 +50,6 @@ impl<'de> Deserialize<'de> for LanguagePackStringValue {
 impl RObject for LanguagePackStringValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LanguagePackStringValue::GetLanguagePackString(t) => t.td_name(),
       LanguagePackStringValue::Deleted(t) => t.td_name(),
       LanguagePackStringValue::Ordinary(t) => t.td_name(),
       LanguagePackStringValue::Pluralized(t) => t.td_name(),
@@ -61,10 +59,10 @@ impl RObject for LanguagePackStringValue {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      LanguagePackStringValue::GetLanguagePackString(t) => t.extra(),
       LanguagePackStringValue::Deleted(t) => t.extra(),
       LanguagePackStringValue::Ordinary(t) => t.extra(),
       LanguagePackStringValue::Pluralized(t) => t.extra(),
+      LanguagePackStringValue::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,26 +73,29 @@ impl RObject for LanguagePackStringValue {
 impl LanguagePackStringValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LanguagePackStringValue::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      LanguagePackStringValue::Deleted(t) => t._missing_fields(),
+      LanguagePackStringValue::Ordinary(t) => t._missing_fields(),
+      LanguagePackStringValue::Pluralized(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_language_pack_string(&self) -> bool { if let LanguagePackStringValue::GetLanguagePackString(_) = self { true } else { false } }
   pub fn is_deleted(&self) -> bool { if let LanguagePackStringValue::Deleted(_) = self { true } else { false } }
   pub fn is_ordinary(&self) -> bool { if let LanguagePackStringValue::Ordinary(_) = self { true } else { false } }
   pub fn is_pluralized(&self) -> bool { if let LanguagePackStringValue::Pluralized(_) = self { true } else { false } }
 
-  pub fn on_get_language_pack_string<F: FnOnce(&GetLanguagePackString)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::GetLanguagePackString(t) = self { fnc(t) }; self }
   pub fn on_deleted<F: FnOnce(&LanguagePackStringValueDeleted)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Deleted(t) = self { fnc(t) }; self }
   pub fn on_ordinary<F: FnOnce(&LanguagePackStringValueOrdinary)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Ordinary(t) = self { fnc(t) }; self }
   pub fn on_pluralized<F: FnOnce(&LanguagePackStringValuePluralized)>(&self, fnc: F) -> &Self { if let LanguagePackStringValue::Pluralized(t) = self { fnc(t) }; self }
 
-  pub fn as_get_language_pack_string(&self) -> Option<&GetLanguagePackString> { if let LanguagePackStringValue::GetLanguagePackString(t) = self { return Some(t) } None }
   pub fn as_deleted(&self) -> Option<&LanguagePackStringValueDeleted> { if let LanguagePackStringValue::Deleted(t) = self { return Some(t) } None }
   pub fn as_ordinary(&self) -> Option<&LanguagePackStringValueOrdinary> { if let LanguagePackStringValue::Ordinary(t) = self { return Some(t) } None }
   pub fn as_pluralized(&self) -> Option<&LanguagePackStringValuePluralized> { if let LanguagePackStringValue::Pluralized(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_language_pack_string<T: AsRef<GetLanguagePackString>>(t: T) -> Self { LanguagePackStringValue::GetLanguagePackString(t.as_ref().clone()) }
-
   pub fn deleted<T: AsRef<LanguagePackStringValueDeleted>>(t: T) -> Self { LanguagePackStringValue::Deleted(t.as_ref().clone()) }
 
   pub fn ordinary<T: AsRef<LanguagePackStringValueOrdinary>>(t: T) -> Self { LanguagePackStringValue::Ordinary(t.as_ref().clone()) }
@@ -107,6 +108,18 @@ impl AsRef<LanguagePackStringValue> for LanguagePackStringValue {
   fn as_ref(&self) -> &LanguagePackStringValue { self }
 }
 
+impl RVariant<LanguagePackStringValue> for LanguagePackStringValueDeleted {
+  fn from_class(class: &LanguagePackStringValue) -> Option<&Self> { match class { LanguagePackStringValue::Deleted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<LanguagePackStringValue> for LanguagePackStringValueOrdinary {
+  fn from_class(class: &LanguagePackStringValue) -> Option<&Self> { match class { LanguagePackStringValue::Ordinary(t) => Some(t), _ => None } }
+}
+
+impl RVariant<LanguagePackStringValue> for LanguagePackStringValuePluralized {
+  fn from_class(class: &LanguagePackStringValue) -> Option<&Self> { match class { LanguagePackStringValue::Pluralized(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -145,6 +158,11 @@ impl LanguagePackStringValueDeleted {
     RTDLanguagePackStringValueDeletedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackStringValueDeleted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +173,9 @@ pub struct RTDLanguagePackStringValueDeletedBuilder {
 impl RTDLanguagePackStringValueDeletedBuilder {
   pub fn build(&self) -> LanguagePackStringValueDeleted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackStringValueDeleted> { Ok(self.build()) }
+
 }
 
 impl AsRef<LanguagePackStringValueDeleted> for LanguagePackStringValueDeleted {
@@ -202,7 +223,16 @@ impl LanguagePackStringValueOrdinary {
     let mut inner = LanguagePackStringValueOrdinary::default();
     inner.td_name = "languagePackStringValueOrdinary".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLanguagePackStringValueOrdinaryBuilder { inner }
+    RTDLanguagePackStringValueOrdinaryBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackStringValueOrdinary", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> &String { &self.value }
@@ -211,15 +241,25 @@ impl LanguagePackStringValueOrdinary {
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringValueOrdinaryBuilder {
-  inner: LanguagePackStringValueOrdinary
+  inner: LanguagePackStringValueOrdinary,
+  set: [bool; 1]
 }
 
 impl RTDLanguagePackStringValueOrdinaryBuilder {
   pub fn build(&self) -> LanguagePackStringValueOrdinary { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackStringValueOrdinary> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("languagePackStringValueOrdinary", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -280,7 +320,21 @@ impl LanguagePackStringValuePluralized {
     let mut inner = LanguagePackStringValuePluralized::default();
     inner.td_name = "languagePackStringValuePluralized".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLanguagePackStringValuePluralizedBuilder { inner }
+    RTDLanguagePackStringValuePluralizedBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackStringValuePluralized", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.zero_value.is_empty() { missing.push("zero_value".to_string()); }
+    if self.one_value.is_empty() { missing.push("one_value".to_string()); }
+    if self.two_value.is_empty() { missing.push("two_value".to_string()); }
+    if self.few_value.is_empty() { missing.push("few_value".to_string()); }
+    if self.many_value.is_empty() { missing.push("many_value".to_string()); }
+    if self.other_value.is_empty() { missing.push("other_value".to_string()); }
+    missing
   }
 
   pub fn zero_value(&self) -> &String { &self.zero_value }
@@ -299,45 +353,65 @@ impl LanguagePackStringValuePluralized {
 
 #[doc(hidden)]
 pub struct RTDLanguagePackStringValuePluralizedBuilder {
-  inner: LanguagePackStringValuePluralized
+  inner: LanguagePackStringValuePluralized,
+  set: [bool; 6]
 }
 
 impl RTDLanguagePackStringValuePluralizedBuilder {
   pub fn build(&self) -> LanguagePackStringValuePluralized { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackStringValuePluralized> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("zero_value".to_string()); }
+    if !self.set[1] { missing.push("one_value".to_string()); }
+    if !self.set[2] { missing.push("two_value".to_string()); }
+    if !self.set[3] { missing.push("few_value".to_string()); }
+    if !self.set[4] { missing.push("many_value".to_string()); }
+    if !self.set[5] { missing.push("other_value".to_string()); }
+    crate::types::_common::check_missing("languagePackStringValuePluralized", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn zero_value<T: AsRef<str>>(&mut self, zero_value: T) -> &mut Self {
     self.inner.zero_value = zero_value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn one_value<T: AsRef<str>>(&mut self, one_value: T) -> &mut Self {
     self.inner.one_value = one_value.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn two_value<T: AsRef<str>>(&mut self, two_value: T) -> &mut Self {
     self.inner.two_value = two_value.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn few_value<T: AsRef<str>>(&mut self, few_value: T) -> &mut Self {
     self.inner.few_value = few_value.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn many_value<T: AsRef<str>>(&mut self, many_value: T) -> &mut Self {
     self.inner.many_value = many_value.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn other_value<T: AsRef<str>>(&mut self, other_value: T) -> &mut Self {
     self.inner.other_value = other_value.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl LanguagePackStrings {
     RTDLanguagePackStringsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("languagePackStrings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn strings(&self) -> &Vec<LanguagePackString> { &self.strings }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDLanguagePackStringsBuilder {
 impl RTDLanguagePackStringsBuilder {
   pub fn build(&self) -> LanguagePackStrings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LanguagePackStrings> { Ok(self.build()) }
+
    
   pub fn strings(&mut self, strings: Vec<LanguagePackString>) -> &mut Self {
     self.inner.strings = strings;
@@ -48,7 +48,18 @@ impl LocalFile {
     let mut inner = LocalFile::default();
     inner.td_name = "localFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLocalFileBuilder { inner }
+    RTDLocalFileBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("localFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.download_offset == 0 { missing.push("download_offset".to_string()); }
+    if self.downloaded_prefix_size == 0 { missing.push("downloaded_prefix_size".to_string()); }
+    if self.downloaded_size == 0 { missing.push("downloaded_size".to_string()); }
+    missing
   }
 
   pub fn path(&self) -> &String { &self.path }
@@ -71,12 +82,23 @@ impl LocalFile {
 
 #[doc(hidden)]
 pub struct RTDLocalFileBuilder {
-  inner: LocalFile
+  inner: LocalFile,
+  set: [bool; 3]
 }
 
 impl RTDLocalFileBuilder {
   pub fn build(&self) -> LocalFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LocalFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("download_offset".to_string()); }
+    if !self.set[1] { missing.push("downloaded_prefix_size".to_string()); }
+    if !self.set[2] { missing.push("downloaded_size".to_string()); }
+    crate::types::_common::check_missing("localFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
@@ -110,18 +132,21 @@ impl RTDLocalFileBuilder {
    
   pub fn download_offset(&mut self, download_offset: i64) -> &mut Self {
     self.inner.download_offset = download_offset;
+    self.set[0] = true;
     self
   }
 
    
   pub fn downloaded_prefix_size(&mut self, downloaded_prefix_size: i64) -> &mut Self {
     self.inner.downloaded_prefix_size = downloaded_prefix_size;
+    self.set[1] = true;
     self
   }
 
    
   pub fn downloaded_size(&mut self, downloaded_size: i64) -> &mut Self {
     self.inner.downloaded_size = downloaded_size;
+    self.set[2] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl LocalizationTargetInfo {
     RTDLocalizationTargetInfoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("localizationTargetInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn language_packs(&self) -> &Vec<LanguagePackInfo> { &self.language_packs }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDLocalizationTargetInfoBuilder {
 impl RTDLocalizationTargetInfoBuilder {
   pub fn build(&self) -> LocalizationTargetInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LocalizationTargetInfo> { Ok(self.build()) }
+
    
   pub fn language_packs(&mut self, language_packs: Vec<LanguagePackInfo>) -> &mut Self {
     self.inner.language_packs = language_packs;
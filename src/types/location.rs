@@ -38,7 +38,17 @@ impl Location {
     let mut inner = Location::default();
     inner.td_name = "location".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLocationBuilder { inner }
+    RTDLocationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("location", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.latitude == 0.0 { missing.push("latitude".to_string()); }
+    if self.longitude == 0.0 { missing.push("longitude".to_string()); }
+    missing
   }
 
   pub fn latitude(&self) -> f32 { self.latitude }
@@ -51,21 +61,33 @@ impl Location {
 
 #[doc(hidden)]
 pub struct RTDLocationBuilder {
-  inner: Location
+  inner: Location,
+  set: [bool; 2]
 }
 
 impl RTDLocationBuilder {
   pub fn build(&self) -> Location { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Location> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("latitude".to_string()); }
+    if !self.set[1] { missing.push("longitude".to_string()); }
+    crate::types::_common::check_missing("location", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn latitude(&mut self, latitude: f32) -> &mut Self {
     self.inner.latitude = latitude;
+    self.set[0] = true;
     self
   }
 
    
   pub fn longitude(&mut self, longitude: f32) -> &mut Self {
     self.inner.longitude = longitude;
+    self.set[1] = true;
     self
   }
 
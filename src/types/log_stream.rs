@@ -19,8 +19,9 @@ pub trait TDLogStream: Debug + RObject {}
 #[serde(untagged)]
 pub enum LogStream {
   #[doc(hidden)] _Default(()),
-  /// Returns information about currently used log stream for internal logging of TDLib. Can be called synchronously
-  GetLogStream(GetLogStream),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The log is written to stderr or an OS specific log
   Default(LogStreamDefault),
   /// The log is written nowhere
@@ -36,10 +37,8 @@ impl Default for LogStream {
 
 impl<'de> Deserialize<'de> for LogStream {
   fn deserialize<D>(deserializer: D) -> Result<LogStream, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       LogStream,
-      (getLogStream, GetLogStream);
       (logStreamDefault, Default);
       (logStreamEmpty, Empty);
       (logStreamFile, File);
@@ -51,7 +50,6 @@ impl<'de> Deserialize<'de> for LogStream {
 impl RObject for LogStream {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LogStream::GetLogStream(t) => t.td_name(),
       LogStream::Default(t) => t.td_name(),
       LogStream::Empty(t) => t.td_name(),
       LogStream::File(t) => t.td_name(),
@@ -61,10 +59,10 @@ impl RObject for LogStream {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      LogStream::GetLogStream(t) => t.extra(),
       LogStream::Default(t) => t.extra(),
       LogStream::Empty(t) => t.extra(),
       LogStream::File(t) => t.extra(),
+      LogStream::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,26 +73,29 @@ impl RObject for LogStream {
 impl LogStream {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LogStream::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      LogStream::Default(t) => t._missing_fields(),
+      LogStream::Empty(t) => t._missing_fields(),
+      LogStream::File(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_log_stream(&self) -> bool { if let LogStream::GetLogStream(_) = self { true } else { false } }
   pub fn is_default(&self) -> bool { if let LogStream::Default(_) = self { true } else { false } }
   pub fn is_empty(&self) -> bool { if let LogStream::Empty(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let LogStream::File(_) = self { true } else { false } }
 
-  pub fn on_get_log_stream<F: FnOnce(&GetLogStream)>(&self, fnc: F) -> &Self { if let LogStream::GetLogStream(t) = self { fnc(t) }; self }
   pub fn on_default<F: FnOnce(&LogStreamDefault)>(&self, fnc: F) -> &Self { if let LogStream::Default(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&LogStreamEmpty)>(&self, fnc: F) -> &Self { if let LogStream::Empty(t) = self { fnc(t) }; self }
   pub fn on_file<F: FnOnce(&LogStreamFile)>(&self, fnc: F) -> &Self { if let LogStream::File(t) = self { fnc(t) }; self }
 
-  pub fn as_get_log_stream(&self) -> Option<&GetLogStream> { if let LogStream::GetLogStream(t) = self { return Some(t) } None }
   pub fn as_default(&self) -> Option<&LogStreamDefault> { if let LogStream::Default(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&LogStreamEmpty> { if let LogStream::Empty(t) = self { return Some(t) } None }
   pub fn as_file(&self) -> Option<&LogStreamFile> { if let LogStream::File(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_log_stream<T: AsRef<GetLogStream>>(t: T) -> Self { LogStream::GetLogStream(t.as_ref().clone()) }
-
   pub fn default<T: AsRef<LogStreamDefault>>(t: T) -> Self { LogStream::Default(t.as_ref().clone()) }
 
   pub fn empty<T: AsRef<LogStreamEmpty>>(t: T) -> Self { LogStream::Empty(t.as_ref().clone()) }
@@ -107,6 +108,18 @@ impl AsRef<LogStream> for LogStream {
   fn as_ref(&self) -> &LogStream { self }
 }
 
+impl RVariant<LogStream> for LogStreamDefault {
+  fn from_class(class: &LogStream) -> Option<&Self> { match class { LogStream::Default(t) => Some(t), _ => None } }
+}
+
+impl RVariant<LogStream> for LogStreamEmpty {
+  fn from_class(class: &LogStream) -> Option<&Self> { match class { LogStream::Empty(t) => Some(t), _ => None } }
+}
+
+impl RVariant<LogStream> for LogStreamFile {
+  fn from_class(class: &LogStream) -> Option<&Self> { match class { LogStream::File(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -145,6 +158,11 @@ impl LogStreamDefault {
     RTDLogStreamDefaultBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logStreamDefault", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +173,9 @@ pub struct RTDLogStreamDefaultBuilder {
 impl RTDLogStreamDefaultBuilder {
   pub fn build(&self) -> LogStreamDefault { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogStreamDefault> { Ok(self.build()) }
+
 }
 
 impl AsRef<LogStreamDefault> for LogStreamDefault {
@@ -203,6 +224,11 @@ impl LogStreamEmpty {
     RTDLogStreamEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logStreamEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +239,9 @@ pub struct RTDLogStreamEmptyBuilder {
 impl RTDLogStreamEmptyBuilder {
   pub fn build(&self) -> LogStreamEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogStreamEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<LogStreamEmpty> for LogStreamEmpty {
@@ -264,7 +293,17 @@ impl LogStreamFile {
     let mut inner = LogStreamFile::default();
     inner.td_name = "logStreamFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLogStreamFileBuilder { inner }
+    RTDLogStreamFileBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logStreamFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.path.is_empty() { missing.push("path".to_string()); }
+    if self.max_file_size == 0 { missing.push("max_file_size".to_string()); }
+    missing
   }
 
   pub fn path(&self) -> &String { &self.path }
@@ -277,21 +316,33 @@ impl LogStreamFile {
 
 #[doc(hidden)]
 pub struct RTDLogStreamFileBuilder {
-  inner: LogStreamFile
+  inner: LogStreamFile,
+  set: [bool; 2]
 }
 
 impl RTDLogStreamFileBuilder {
   pub fn build(&self) -> LogStreamFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogStreamFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("path".to_string()); }
+    if !self.set[1] { missing.push("max_file_size".to_string()); }
+    crate::types::_common::check_missing("logStreamFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn path<T: AsRef<str>>(&mut self, path: T) -> &mut Self {
     self.inner.path = path.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn max_file_size(&mut self, max_file_size: i64) -> &mut Self {
     self.inner.max_file_size = max_file_size;
+    self.set[1] = true;
     self
   }
 
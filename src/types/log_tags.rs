@@ -37,6 +37,11 @@ impl LogTags {
     RTDLogTagsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logTags", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn tags(&self) -> &Vec<String> { &self.tags }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDLogTagsBuilder {
 impl RTDLogTagsBuilder {
   pub fn build(&self) -> LogTags { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogTags> { Ok(self.build()) }
+
    
   pub fn tags(&mut self, tags: Vec<String>) -> &mut Self {
     self.inner.tags = tags;
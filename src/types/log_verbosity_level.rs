@@ -34,7 +34,16 @@ impl LogVerbosityLevel {
     let mut inner = LogVerbosityLevel::default();
     inner.td_name = "logVerbosityLevel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLogVerbosityLevelBuilder { inner }
+    RTDLogVerbosityLevelBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("logVerbosityLevel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.verbosity_level == 0 { missing.push("verbosity_level".to_string()); }
+    missing
   }
 
   pub fn verbosity_level(&self) -> i64 { self.verbosity_level }
@@ -43,15 +52,25 @@ impl LogVerbosityLevel {
 
 #[doc(hidden)]
 pub struct RTDLogVerbosityLevelBuilder {
-  inner: LogVerbosityLevel
+  inner: LogVerbosityLevel,
+  set: [bool; 1]
 }
 
 impl RTDLogVerbosityLevelBuilder {
   pub fn build(&self) -> LogVerbosityLevel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LogVerbosityLevel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("verbosity_level".to_string()); }
+    crate::types::_common::check_missing("logVerbosityLevel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn verbosity_level(&mut self, verbosity_level: i64) -> &mut Self {
     self.inner.verbosity_level = verbosity_level;
+    self.set[0] = true;
     self
   }
 
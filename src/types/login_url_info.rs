@@ -19,8 +19,9 @@ pub trait TDLoginUrlInfo: Debug + RObject {}
 #[serde(untagged)]
 pub enum LoginUrlInfo {
   #[doc(hidden)] _Default(()),
-  /// Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button
-  GetLoginUrlInfo(GetLoginUrlInfo),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An HTTP url needs to be open
   Open(LoginUrlInfoOpen),
   /// An authorization confirmation dialog needs to be shown to the user
@@ -34,10 +35,8 @@ impl Default for LoginUrlInfo {
 
 impl<'de> Deserialize<'de> for LoginUrlInfo {
   fn deserialize<D>(deserializer: D) -> Result<LoginUrlInfo, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       LoginUrlInfo,
-      (getLoginUrlInfo, GetLoginUrlInfo);
       (loginUrlInfoOpen, Open);
       (loginUrlInfoRequestConfirmation, RequestConfirmation);
 
@@ -48,7 +47,6 @@ impl<'de> Deserialize<'de> for LoginUrlInfo {
 impl RObject for LoginUrlInfo {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      LoginUrlInfo::GetLoginUrlInfo(t) => t.td_name(),
       LoginUrlInfo::Open(t) => t.td_name(),
       LoginUrlInfo::RequestConfirmation(t) => t.td_name(),
 
@@ -57,9 +55,9 @@ impl RObject for LoginUrlInfo {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      LoginUrlInfo::GetLoginUrlInfo(t) => t.extra(),
       LoginUrlInfo::Open(t) => t.extra(),
       LoginUrlInfo::RequestConfirmation(t) => t.extra(),
+      LoginUrlInfo::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,23 +68,25 @@ impl RObject for LoginUrlInfo {
 impl LoginUrlInfo {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let LoginUrlInfo::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      LoginUrlInfo::Open(t) => t._missing_fields(),
+      LoginUrlInfo::RequestConfirmation(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_login_url_info(&self) -> bool { if let LoginUrlInfo::GetLoginUrlInfo(_) = self { true } else { false } }
   pub fn is_open(&self) -> bool { if let LoginUrlInfo::Open(_) = self { true } else { false } }
   pub fn is_request_confirmation(&self) -> bool { if let LoginUrlInfo::RequestConfirmation(_) = self { true } else { false } }
 
-  pub fn on_get_login_url_info<F: FnOnce(&GetLoginUrlInfo)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { fnc(t) }; self }
   pub fn on_open<F: FnOnce(&LoginUrlInfoOpen)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::Open(t) = self { fnc(t) }; self }
   pub fn on_request_confirmation<F: FnOnce(&LoginUrlInfoRequestConfirmation)>(&self, fnc: F) -> &Self { if let LoginUrlInfo::RequestConfirmation(t) = self { fnc(t) }; self }
 
-  pub fn as_get_login_url_info(&self) -> Option<&GetLoginUrlInfo> { if let LoginUrlInfo::GetLoginUrlInfo(t) = self { return Some(t) } None }
   pub fn as_open(&self) -> Option<&LoginUrlInfoOpen> { if let LoginUrlInfo::Open(t) = self { return Some(t) } None }
   pub fn as_request_confirmation(&self) -> Option<&LoginUrlInfoRequestConfirmation> { if let LoginUrlInfo::RequestConfirmation(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_login_url_info<T: AsRef<GetLoginUrlInfo>>(t: T) -> Self { LoginUrlInfo::GetLoginUrlInfo(t.as_ref().clone()) }
-
   pub fn open<T: AsRef<LoginUrlInfoOpen>>(t: T) -> Self { LoginUrlInfo::Open(t.as_ref().clone()) }
 
   pub fn request_confirmation<T: AsRef<LoginUrlInfoRequestConfirmation>>(t: T) -> Self { LoginUrlInfo::RequestConfirmation(t.as_ref().clone()) }
@@ -97,6 +97,14 @@ impl AsRef<LoginUrlInfo> for LoginUrlInfo {
   fn as_ref(&self) -> &LoginUrlInfo { self }
 }
 
+impl RVariant<LoginUrlInfo> for LoginUrlInfoOpen {
+  fn from_class(class: &LoginUrlInfo) -> Option<&Self> { match class { LoginUrlInfo::Open(t) => Some(t), _ => None } }
+}
+
+impl RVariant<LoginUrlInfo> for LoginUrlInfoRequestConfirmation {
+  fn from_class(class: &LoginUrlInfo) -> Option<&Self> { match class { LoginUrlInfo::RequestConfirmation(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -136,7 +144,16 @@ impl LoginUrlInfoOpen {
     let mut inner = LoginUrlInfoOpen::default();
     inner.td_name = "loginUrlInfoOpen".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLoginUrlInfoOpenBuilder { inner }
+    RTDLoginUrlInfoOpenBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("loginUrlInfoOpen", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -147,15 +164,25 @@ impl LoginUrlInfoOpen {
 
 #[doc(hidden)]
 pub struct RTDLoginUrlInfoOpenBuilder {
-  inner: LoginUrlInfoOpen
+  inner: LoginUrlInfoOpen,
+  set: [bool; 1]
 }
 
 impl RTDLoginUrlInfoOpenBuilder {
   pub fn build(&self) -> LoginUrlInfoOpen { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LoginUrlInfoOpen> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("loginUrlInfoOpen", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -218,7 +245,18 @@ impl LoginUrlInfoRequestConfirmation {
     let mut inner = LoginUrlInfoRequestConfirmation::default();
     inner.td_name = "loginUrlInfoRequestConfirmation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDLoginUrlInfoRequestConfirmationBuilder { inner }
+    RTDLoginUrlInfoRequestConfirmationBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("loginUrlInfoRequestConfirmation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.domain.is_empty() { missing.push("domain".to_string()); }
+    if self.bot_user_id == 0 { missing.push("bot_user_id".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -233,27 +271,41 @@ impl LoginUrlInfoRequestConfirmation {
 
 #[doc(hidden)]
 pub struct RTDLoginUrlInfoRequestConfirmationBuilder {
-  inner: LoginUrlInfoRequestConfirmation
+  inner: LoginUrlInfoRequestConfirmation,
+  set: [bool; 3]
 }
 
 impl RTDLoginUrlInfoRequestConfirmationBuilder {
   pub fn build(&self) -> LoginUrlInfoRequestConfirmation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<LoginUrlInfoRequestConfirmation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("domain".to_string()); }
+    if !self.set[2] { missing.push("bot_user_id".to_string()); }
+    crate::types::_common::check_missing("loginUrlInfoRequestConfirmation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn domain<T: AsRef<str>>(&mut self, domain: T) -> &mut Self {
     self.inner.domain = domain.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn bot_user_id(&mut self, bot_user_id: i64) -> &mut Self {
     self.inner.bot_user_id = bot_user_id;
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDMaskPoint: Debug + RObject {}
 #[serde(untagged)]
 pub enum MaskPoint {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A mask should be placed relatively to the chin
   Chin(MaskPointChin),
   /// A mask should be placed relatively to the eyes
@@ -36,7 +39,6 @@ impl Default for MaskPoint {
 
 impl<'de> Deserialize<'de> for MaskPoint {
   fn deserialize<D>(deserializer: D) -> Result<MaskPoint, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MaskPoint,
       (maskPointChin, Chin);
@@ -65,6 +67,7 @@ impl RObject for MaskPoint {
       MaskPoint::Eyes(t) => t.extra(),
       MaskPoint::Forehead(t) => t.extra(),
       MaskPoint::Mouth(t) => t.extra(),
+      MaskPoint::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for MaskPoint {
 impl MaskPoint {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MaskPoint::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MaskPoint::Chin(t) => t._missing_fields(),
+      MaskPoint::Eyes(t) => t._missing_fields(),
+      MaskPoint::Forehead(t) => t._missing_fields(),
+      MaskPoint::Mouth(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_chin(&self) -> bool { if let MaskPoint::Chin(_) = self { true } else { false } }
   pub fn is_eyes(&self) -> bool { if let MaskPoint::Eyes(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<MaskPoint> for MaskPoint {
   fn as_ref(&self) -> &MaskPoint { self }
 }
 
+impl RVariant<MaskPoint> for MaskPointChin {
+  fn from_class(class: &MaskPoint) -> Option<&Self> { match class { MaskPoint::Chin(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MaskPoint> for MaskPointEyes {
+  fn from_class(class: &MaskPoint) -> Option<&Self> { match class { MaskPoint::Eyes(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MaskPoint> for MaskPointForehead {
+  fn from_class(class: &MaskPoint) -> Option<&Self> { match class { MaskPoint::Forehead(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MaskPoint> for MaskPointMouth {
+  fn from_class(class: &MaskPoint) -> Option<&Self> { match class { MaskPoint::Mouth(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -145,6 +173,11 @@ impl MaskPointChin {
     RTDMaskPointChinBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("maskPointChin", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +188,9 @@ pub struct RTDMaskPointChinBuilder {
 impl RTDMaskPointChinBuilder {
   pub fn build(&self) -> MaskPointChin { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MaskPointChin> { Ok(self.build()) }
+
 }
 
 impl AsRef<MaskPointChin> for MaskPointChin {
@@ -203,6 +239,11 @@ impl MaskPointEyes {
     RTDMaskPointEyesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("maskPointEyes", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +254,9 @@ pub struct RTDMaskPointEyesBuilder {
 impl RTDMaskPointEyesBuilder {
   pub fn build(&self) -> MaskPointEyes { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MaskPointEyes> { Ok(self.build()) }
+
 }
 
 impl AsRef<MaskPointEyes> for MaskPointEyes {
@@ -261,6 +305,11 @@ impl MaskPointForehead {
     RTDMaskPointForeheadBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("maskPointForehead", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +320,9 @@ pub struct RTDMaskPointForeheadBuilder {
 impl RTDMaskPointForeheadBuilder {
   pub fn build(&self) -> MaskPointForehead { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MaskPointForehead> { Ok(self.build()) }
+
 }
 
 impl AsRef<MaskPointForehead> for MaskPointForehead {
@@ -319,6 +371,11 @@ impl MaskPointMouth {
     RTDMaskPointMouthBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("maskPointMouth", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -329,6 +386,9 @@ pub struct RTDMaskPointMouthBuilder {
 impl RTDMaskPointMouthBuilder {
   pub fn build(&self) -> MaskPointMouth { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MaskPointMouth> { Ok(self.build()) }
+
 }
 
 impl AsRef<MaskPointMouth> for MaskPointMouth {
@@ -40,7 +40,19 @@ impl MaskPosition {
     let mut inner = MaskPosition::default();
     inner.td_name = "maskPosition".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMaskPositionBuilder { inner }
+    RTDMaskPositionBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("maskPosition", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.point._is_default() { missing.push("point".to_string()); } else { missing.extend(crate::types::_common::missing_in("point", self.point._missing_fields())); }
+    if self.x_shift == 0.0 { missing.push("x_shift".to_string()); }
+    if self.y_shift == 0.0 { missing.push("y_shift".to_string()); }
+    if self.scale == 0.0 { missing.push("scale".to_string()); }
+    missing
   }
 
   pub fn point(&self) -> &MaskPoint { &self.point }
@@ -55,12 +67,24 @@ impl MaskPosition {
 
 #[doc(hidden)]
 pub struct RTDMaskPositionBuilder {
-  inner: MaskPosition
+  inner: MaskPosition,
+  set: [bool; 3]
 }
 
 impl RTDMaskPositionBuilder {
   pub fn build(&self) -> MaskPosition { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MaskPosition> {
+    let mut missing = vec![];
+    if self.inner.point._is_default() { missing.push("point".to_string()); } else { missing.extend(crate::types::_common::missing_in("point", self.inner.point._missing_fields())); }
+    if !self.set[0] { missing.push("x_shift".to_string()); }
+    if !self.set[1] { missing.push("y_shift".to_string()); }
+    if !self.set[2] { missing.push("scale".to_string()); }
+    crate::types::_common::check_missing("maskPosition", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn point<T: AsRef<MaskPoint>>(&mut self, point: T) -> &mut Self {
     self.inner.point = point.as_ref().clone();
@@ -70,18 +94,21 @@ impl RTDMaskPositionBuilder {
    
   pub fn x_shift(&mut self, x_shift: f32) -> &mut Self {
     self.inner.x_shift = x_shift;
+    self.set[0] = true;
     self
   }
 
    
   pub fn y_shift(&mut self, y_shift: f32) -> &mut Self {
     self.inner.y_shift = y_shift;
+    self.set[1] = true;
     self
   }
 
    
   pub fn scale(&mut self, scale: f32) -> &mut Self {
     self.inner.scale = scale;
+    self.set[2] = true;
     self
   }
 
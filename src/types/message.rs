@@ -68,7 +68,7 @@ pub struct Message {
   /// For channel posts and anonymous group messages, optional author signature
   author_signature: String,
   /// Unique identifier of an album this message belongs to. Only audios, documents, photos and videos can be grouped together in albums
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] media_album_id: isize,
+  #[serde(with = "crate::types::_common::int64")] media_album_id: i64,
   /// If non-empty, contains a human-readable description of the reason why access to this message must be restricted
   restriction_reason: String,
   /// Content of the message
@@ -92,7 +92,29 @@ impl Message {
     let mut inner = Message::default();
     inner.td_name = "message".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageBuilder { inner }
+    RTDMessageBuilder { inner, set: [false; 12] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("message", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.sender._missing_fields())); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.edit_date == 0 { missing.push("edit_date".to_string()); }
+    if self.reply_in_chat_id == 0 { missing.push("reply_in_chat_id".to_string()); }
+    if self.reply_to_message_id == 0 { missing.push("reply_to_message_id".to_string()); }
+    if self.message_thread_id == 0 { missing.push("message_thread_id".to_string()); }
+    if self.ttl_expires_in == 0.0 { missing.push("ttl_expires_in".to_string()); }
+    if self.via_bot_user_id == 0 { missing.push("via_bot_user_id".to_string()); }
+    if self.author_signature.is_empty() { missing.push("author_signature".to_string()); }
+    if self.media_album_id == 0 { missing.push("media_album_id".to_string()); }
+    if self.restriction_reason.is_empty() { missing.push("restriction_reason".to_string()); }
+    if self.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.content._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -147,7 +169,7 @@ impl Message {
 
   pub fn author_signature(&self) -> &String { &self.author_signature }
 
-  pub fn media_album_id(&self) -> isize { self.media_album_id }
+  pub fn media_album_id(&self) -> i64 { self.media_album_id }
 
   pub fn restriction_reason(&self) -> &String { &self.restriction_reason }
 
@@ -159,15 +181,38 @@ impl Message {
 
 #[doc(hidden)]
 pub struct RTDMessageBuilder {
-  inner: Message
+  inner: Message,
+  set: [bool; 12]
 }
 
 impl RTDMessageBuilder {
   pub fn build(&self) -> Message { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Message> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if self.inner.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.inner.sender._missing_fields())); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if !self.set[2] { missing.push("date".to_string()); }
+    if !self.set[3] { missing.push("edit_date".to_string()); }
+    if !self.set[4] { missing.push("reply_in_chat_id".to_string()); }
+    if !self.set[5] { missing.push("reply_to_message_id".to_string()); }
+    if !self.set[6] { missing.push("message_thread_id".to_string()); }
+    if !self.set[7] { missing.push("ttl_expires_in".to_string()); }
+    if !self.set[8] { missing.push("via_bot_user_id".to_string()); }
+    if !self.set[9] { missing.push("author_signature".to_string()); }
+    if !self.set[10] { missing.push("media_album_id".to_string()); }
+    if !self.set[11] { missing.push("restriction_reason".to_string()); }
+    if self.inner.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.inner.content._missing_fields())); }
+    crate::types::_common::check_missing("message", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -180,6 +225,7 @@ impl RTDMessageBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
@@ -258,12 +304,14 @@ impl RTDMessageBuilder {
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[2] = true;
     self
   }
 
    
   pub fn edit_date(&mut self, edit_date: i64) -> &mut Self {
     self.inner.edit_date = edit_date;
+    self.set[3] = true;
     self
   }
 
@@ -282,18 +330,21 @@ impl RTDMessageBuilder {
    
   pub fn reply_in_chat_id(&mut self, reply_in_chat_id: i64) -> &mut Self {
     self.inner.reply_in_chat_id = reply_in_chat_id;
+    self.set[4] = true;
     self
   }
 
    
   pub fn reply_to_message_id(&mut self, reply_to_message_id: i64) -> &mut Self {
     self.inner.reply_to_message_id = reply_to_message_id;
+    self.set[5] = true;
     self
   }
 
    
   pub fn message_thread_id(&mut self, message_thread_id: i64) -> &mut Self {
     self.inner.message_thread_id = message_thread_id;
+    self.set[6] = true;
     self
   }
 
@@ -306,30 +357,35 @@ impl RTDMessageBuilder {
    
   pub fn ttl_expires_in(&mut self, ttl_expires_in: f32) -> &mut Self {
     self.inner.ttl_expires_in = ttl_expires_in;
+    self.set[7] = true;
     self
   }
 
    
   pub fn via_bot_user_id(&mut self, via_bot_user_id: i64) -> &mut Self {
     self.inner.via_bot_user_id = via_bot_user_id;
+    self.set[8] = true;
     self
   }
 
    
   pub fn author_signature<T: AsRef<str>>(&mut self, author_signature: T) -> &mut Self {
     self.inner.author_signature = author_signature.as_ref().to_string();
+    self.set[9] = true;
     self
   }
 
    
-  pub fn media_album_id(&mut self, media_album_id: isize) -> &mut Self {
+  pub fn media_album_id(&mut self, media_album_id: i64) -> &mut Self {
     self.inner.media_album_id = media_album_id;
+    self.set[10] = true;
     self
   }
 
    
   pub fn restriction_reason<T: AsRef<str>>(&mut self, restriction_reason: T) -> &mut Self {
     self.inner.restriction_reason = restriction_reason.as_ref().to_string();
+    self.set[11] = true;
     self
   }
 
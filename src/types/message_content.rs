@@ -19,6 +19,9 @@ pub trait TDMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageContent {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   MessageAnimation(MessageAnimation),
   /// An audio message
@@ -118,7 +121,6 @@ impl Default for MessageContent {
 
 impl<'de> Deserialize<'de> for MessageContent {
   fn deserialize<D>(deserializer: D) -> Result<MessageContent, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageContent,
       (messageAnimation, MessageAnimation);
@@ -270,6 +272,7 @@ impl RObject for MessageContent {
       MessageContent::MessageVoiceChatStarted(t) => t.extra(),
       MessageContent::MessageVoiceNote(t) => t.extra(),
       MessageContent::MessageWebsiteConnected(t) => t.extra(),
+      MessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -280,6 +283,56 @@ impl RObject for MessageContent {
 impl MessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageContent::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageContent::MessageAnimation(t) => t._missing_fields(),
+      MessageContent::MessageAudio(t) => t._missing_fields(),
+      MessageContent::MessageBasicGroupChatCreate(t) => t._missing_fields(),
+      MessageContent::MessageCall(t) => t._missing_fields(),
+      MessageContent::MessageChatAddMembers(t) => t._missing_fields(),
+      MessageContent::MessageChatChangePhoto(t) => t._missing_fields(),
+      MessageContent::MessageChatChangeTitle(t) => t._missing_fields(),
+      MessageContent::MessageChatDeleteMember(t) => t._missing_fields(),
+      MessageContent::MessageChatDeletePhoto(t) => t._missing_fields(),
+      MessageContent::MessageChatJoinByLink(t) => t._missing_fields(),
+      MessageContent::MessageChatSetTtl(t) => t._missing_fields(),
+      MessageContent::MessageChatUpgradeFrom(t) => t._missing_fields(),
+      MessageContent::MessageChatUpgradeTo(t) => t._missing_fields(),
+      MessageContent::MessageContact(t) => t._missing_fields(),
+      MessageContent::MessageContactRegistered(t) => t._missing_fields(),
+      MessageContent::MessageCustomServiceAction(t) => t._missing_fields(),
+      MessageContent::MessageDice(t) => t._missing_fields(),
+      MessageContent::MessageDocument(t) => t._missing_fields(),
+      MessageContent::MessageExpiredPhoto(t) => t._missing_fields(),
+      MessageContent::MessageExpiredVideo(t) => t._missing_fields(),
+      MessageContent::MessageGame(t) => t._missing_fields(),
+      MessageContent::MessageGameScore(t) => t._missing_fields(),
+      MessageContent::MessageInviteVoiceChatParticipants(t) => t._missing_fields(),
+      MessageContent::MessageInvoice(t) => t._missing_fields(),
+      MessageContent::MessageLocation(t) => t._missing_fields(),
+      MessageContent::MessagePassportDataReceived(t) => t._missing_fields(),
+      MessageContent::MessagePassportDataSent(t) => t._missing_fields(),
+      MessageContent::MessagePaymentSuccessful(t) => t._missing_fields(),
+      MessageContent::MessagePaymentSuccessfulBot(t) => t._missing_fields(),
+      MessageContent::MessagePhoto(t) => t._missing_fields(),
+      MessageContent::MessagePinMessage(t) => t._missing_fields(),
+      MessageContent::MessagePoll(t) => t._missing_fields(),
+      MessageContent::MessageProximityAlertTriggered(t) => t._missing_fields(),
+      MessageContent::MessageScreenshotTaken(t) => t._missing_fields(),
+      MessageContent::MessageSticker(t) => t._missing_fields(),
+      MessageContent::MessageSupergroupChatCreate(t) => t._missing_fields(),
+      MessageContent::MessageText(t) => t._missing_fields(),
+      MessageContent::MessageUnsupported(t) => t._missing_fields(),
+      MessageContent::MessageVenue(t) => t._missing_fields(),
+      MessageContent::MessageVideo(t) => t._missing_fields(),
+      MessageContent::MessageVideoNote(t) => t._missing_fields(),
+      MessageContent::MessageVoiceChatEnded(t) => t._missing_fields(),
+      MessageContent::MessageVoiceChatStarted(t) => t._missing_fields(),
+      MessageContent::MessageVoiceNote(t) => t._missing_fields(),
+      MessageContent::MessageWebsiteConnected(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_message_animation(&self) -> bool { if let MessageContent::MessageAnimation(_) = self { true } else { false } }
   pub fn is_message_audio(&self) -> bool { if let MessageContent::MessageAudio(_) = self { true } else { false } }
@@ -517,6 +570,186 @@ impl AsRef<MessageContent> for MessageContent {
   fn as_ref(&self) -> &MessageContent { self }
 }
 
+impl RVariant<MessageContent> for MessageAnimation {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageAnimation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageAudio {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageAudio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageBasicGroupChatCreate {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageBasicGroupChatCreate(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageCall {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageCall(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatAddMembers {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatAddMembers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatChangePhoto {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatChangePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatChangeTitle {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatChangeTitle(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatDeleteMember {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatDeleteMember(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatDeletePhoto {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatDeletePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatJoinByLink {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatJoinByLink(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatSetTtl {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatSetTtl(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatUpgradeFrom {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatUpgradeFrom(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageChatUpgradeTo {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageChatUpgradeTo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageContact {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageContact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageContactRegistered {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageContactRegistered(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageCustomServiceAction {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageCustomServiceAction(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageDice {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageDice(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageDocument {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageDocument(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageExpiredPhoto {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageExpiredPhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageExpiredVideo {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageExpiredVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageGame {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageGame(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageGameScore {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageGameScore(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageInviteVoiceChatParticipants {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageInviteVoiceChatParticipants(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageInvoice {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageInvoice(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageLocation {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageLocation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePassportDataReceived {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePassportDataReceived(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePassportDataSent {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePassportDataSent(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePaymentSuccessful {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePaymentSuccessful(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePaymentSuccessfulBot {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePaymentSuccessfulBot(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePhoto {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePinMessage {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePinMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessagePoll {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessagePoll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageProximityAlertTriggered {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageProximityAlertTriggered(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageScreenshotTaken {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageScreenshotTaken(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageSticker {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageSticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageSupergroupChatCreate {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageSupergroupChatCreate(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageText {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageText(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageUnsupported {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageUnsupported(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVenue {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVenue(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVideo {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVideoNote {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVoiceChatEnded {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVoiceChatEnded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVoiceChatStarted {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVoiceChatStarted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageVoiceNote {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageVoiceNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageContent> for MessageWebsiteConnected {
+  fn from_class(class: &MessageContent) -> Option<&Self> { match class { MessageContent::MessageWebsiteConnected(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -558,7 +791,17 @@ impl MessageAnimation {
     let mut inner = MessageAnimation::default();
     inner.td_name = "messageAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageAnimationBuilder { inner }
+    RTDMessageAnimationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.animation._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn animation(&self) -> &Animation { &self.animation }
@@ -571,21 +814,33 @@ impl MessageAnimation {
 
 #[doc(hidden)]
 pub struct RTDMessageAnimationBuilder {
-  inner: MessageAnimation
+  inner: MessageAnimation,
+  set: [bool; 2]
 }
 
 impl RTDMessageAnimationBuilder {
   pub fn build(&self) -> MessageAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageAnimation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("animation".to_string()); } else { missing.extend(crate::types::_common::missing_in("animation", self.inner.animation._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messageAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = animation.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -644,7 +899,17 @@ impl MessageAudio {
     let mut inner = MessageAudio::default();
     inner.td_name = "messageAudio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageAudioBuilder { inner }
+    RTDMessageAudioBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.audio._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn audio(&self) -> &Audio { &self.audio }
@@ -655,21 +920,33 @@ impl MessageAudio {
 
 #[doc(hidden)]
 pub struct RTDMessageAudioBuilder {
-  inner: MessageAudio
+  inner: MessageAudio,
+  set: [bool; 2]
 }
 
 impl RTDMessageAudioBuilder {
   pub fn build(&self) -> MessageAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageAudio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("audio".to_string()); } else { missing.extend(crate::types::_common::missing_in("audio", self.inner.audio._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messageAudio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = audio.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -722,7 +999,16 @@ impl MessageBasicGroupChatCreate {
     let mut inner = MessageBasicGroupChatCreate::default();
     inner.td_name = "messageBasicGroupChatCreate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageBasicGroupChatCreateBuilder { inner }
+    RTDMessageBasicGroupChatCreateBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageBasicGroupChatCreate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -733,15 +1019,25 @@ impl MessageBasicGroupChatCreate {
 
 #[doc(hidden)]
 pub struct RTDMessageBasicGroupChatCreateBuilder {
-  inner: MessageBasicGroupChatCreate
+  inner: MessageBasicGroupChatCreate,
+  set: [bool; 1]
 }
 
 impl RTDMessageBasicGroupChatCreateBuilder {
   pub fn build(&self) -> MessageBasicGroupChatCreate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageBasicGroupChatCreate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("messageBasicGroupChatCreate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -802,7 +1098,17 @@ impl MessageCall {
     let mut inner = MessageCall::default();
     inner.td_name = "messageCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageCallBuilder { inner }
+    RTDMessageCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.discard_reason._is_default() { missing.push("discard_reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("discard_reason", self.discard_reason._missing_fields())); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    missing
   }
 
   pub fn is_video(&self) -> bool { self.is_video }
@@ -815,12 +1121,22 @@ impl MessageCall {
 
 #[doc(hidden)]
 pub struct RTDMessageCallBuilder {
-  inner: MessageCall
+  inner: MessageCall,
+  set: [bool; 1]
 }
 
 impl RTDMessageCallBuilder {
   pub fn build(&self) -> MessageCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageCall> {
+    let mut missing = vec![];
+    if self.inner.discard_reason._is_default() { missing.push("discard_reason".to_string()); } else { missing.extend(crate::types::_common::missing_in("discard_reason", self.inner.discard_reason._missing_fields())); }
+    if !self.set[0] { missing.push("duration".to_string()); }
+    crate::types::_common::check_missing("messageCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_video(&mut self, is_video: bool) -> &mut Self {
     self.inner.is_video = is_video;
@@ -836,6 +1152,7 @@ impl RTDMessageCallBuilder {
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
@@ -889,6 +1206,11 @@ impl MessageChatAddMembers {
     RTDMessageChatAddMembersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatAddMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn member_user_ids(&self) -> &Vec<i64> { &self.member_user_ids }
 
 }
@@ -901,6 +1223,9 @@ pub struct RTDMessageChatAddMembersBuilder {
 impl RTDMessageChatAddMembersBuilder {
   pub fn build(&self) -> MessageChatAddMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatAddMembers> { Ok(self.build()) }
+
    
   pub fn member_user_ids(&mut self, member_user_ids: Vec<i64>) -> &mut Self {
     self.inner.member_user_ids = member_user_ids;
@@ -954,7 +1279,16 @@ impl MessageChatChangePhoto {
     let mut inner = MessageChatChangePhoto::default();
     inner.td_name = "messageChatChangePhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatChangePhotoBuilder { inner }
+    RTDMessageChatChangePhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatChangePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    missing
   }
 
   pub fn photo(&self) -> &ChatPhoto { &self.photo }
@@ -963,15 +1297,25 @@ impl MessageChatChangePhoto {
 
 #[doc(hidden)]
 pub struct RTDMessageChatChangePhotoBuilder {
-  inner: MessageChatChangePhoto
+  inner: MessageChatChangePhoto,
+  set: [bool; 1]
 }
 
 impl RTDMessageChatChangePhotoBuilder {
   pub fn build(&self) -> MessageChatChangePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatChangePhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    crate::types::_common::check_missing("messageChatChangePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1022,7 +1366,16 @@ impl MessageChatChangeTitle {
     let mut inner = MessageChatChangeTitle::default();
     inner.td_name = "messageChatChangeTitle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatChangeTitleBuilder { inner }
+    RTDMessageChatChangeTitleBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatChangeTitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1031,15 +1384,25 @@ impl MessageChatChangeTitle {
 
 #[doc(hidden)]
 pub struct RTDMessageChatChangeTitleBuilder {
-  inner: MessageChatChangeTitle
+  inner: MessageChatChangeTitle,
+  set: [bool; 1]
 }
 
 impl RTDMessageChatChangeTitleBuilder {
   pub fn build(&self) -> MessageChatChangeTitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatChangeTitle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("messageChatChangeTitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1090,7 +1453,16 @@ impl MessageChatDeleteMember {
     let mut inner = MessageChatDeleteMember::default();
     inner.td_name = "messageChatDeleteMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatDeleteMemberBuilder { inner }
+    RTDMessageChatDeleteMemberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatDeleteMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -1099,15 +1471,25 @@ impl MessageChatDeleteMember {
 
 #[doc(hidden)]
 pub struct RTDMessageChatDeleteMemberBuilder {
-  inner: MessageChatDeleteMember
+  inner: MessageChatDeleteMember,
+  set: [bool; 1]
 }
 
 impl RTDMessageChatDeleteMemberBuilder {
   pub fn build(&self) -> MessageChatDeleteMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatDeleteMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("messageChatDeleteMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -1159,6 +1541,11 @@ impl MessageChatDeletePhoto {
     RTDMessageChatDeletePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatDeletePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1169,6 +1556,9 @@ pub struct RTDMessageChatDeletePhotoBuilder {
 impl RTDMessageChatDeletePhotoBuilder {
   pub fn build(&self) -> MessageChatDeletePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatDeletePhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageChatDeletePhoto> for MessageChatDeletePhoto {
@@ -1217,6 +1607,11 @@ impl MessageChatJoinByLink {
     RTDMessageChatJoinByLinkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatJoinByLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1227,6 +1622,9 @@ pub struct RTDMessageChatJoinByLinkBuilder {
 impl RTDMessageChatJoinByLinkBuilder {
   pub fn build(&self) -> MessageChatJoinByLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatJoinByLink> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageChatJoinByLink> for MessageChatJoinByLink {
@@ -1274,7 +1672,16 @@ impl MessageChatSetTtl {
     let mut inner = MessageChatSetTtl::default();
     inner.td_name = "messageChatSetTtl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatSetTtlBuilder { inner }
+    RTDMessageChatSetTtlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatSetTtl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    missing
   }
 
   pub fn ttl(&self) -> i64 { self.ttl }
@@ -1283,15 +1690,25 @@ impl MessageChatSetTtl {
 
 #[doc(hidden)]
 pub struct RTDMessageChatSetTtlBuilder {
-  inner: MessageChatSetTtl
+  inner: MessageChatSetTtl,
+  set: [bool; 1]
 }
 
 impl RTDMessageChatSetTtlBuilder {
   pub fn build(&self) -> MessageChatSetTtl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatSetTtl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("ttl".to_string()); }
+    crate::types::_common::check_missing("messageChatSetTtl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[0] = true;
     self
   }
 
@@ -1344,7 +1761,17 @@ impl MessageChatUpgradeFrom {
     let mut inner = MessageChatUpgradeFrom::default();
     inner.td_name = "messageChatUpgradeFrom".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatUpgradeFromBuilder { inner }
+    RTDMessageChatUpgradeFromBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatUpgradeFrom", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1355,21 +1782,33 @@ impl MessageChatUpgradeFrom {
 
 #[doc(hidden)]
 pub struct RTDMessageChatUpgradeFromBuilder {
-  inner: MessageChatUpgradeFrom
+  inner: MessageChatUpgradeFrom,
+  set: [bool; 2]
 }
 
 impl RTDMessageChatUpgradeFromBuilder {
   pub fn build(&self) -> MessageChatUpgradeFrom { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatUpgradeFrom> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    if !self.set[1] { missing.push("basic_group_id".to_string()); }
+    crate::types::_common::check_missing("messageChatUpgradeFrom", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[1] = true;
     self
   }
 
@@ -1420,7 +1859,16 @@ impl MessageChatUpgradeTo {
     let mut inner = MessageChatUpgradeTo::default();
     inner.td_name = "messageChatUpgradeTo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageChatUpgradeToBuilder { inner }
+    RTDMessageChatUpgradeToBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageChatUpgradeTo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -1429,15 +1877,25 @@ impl MessageChatUpgradeTo {
 
 #[doc(hidden)]
 pub struct RTDMessageChatUpgradeToBuilder {
-  inner: MessageChatUpgradeTo
+  inner: MessageChatUpgradeTo,
+  set: [bool; 1]
 }
 
 impl RTDMessageChatUpgradeToBuilder {
   pub fn build(&self) -> MessageChatUpgradeTo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageChatUpgradeTo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("messageChatUpgradeTo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -1488,7 +1946,16 @@ impl MessageContact {
     let mut inner = MessageContact::default();
     inner.td_name = "messageContact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageContactBuilder { inner }
+    RTDMessageContactBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.contact._missing_fields())); }
+    missing
   }
 
   pub fn contact(&self) -> &Contact { &self.contact }
@@ -1497,15 +1964,25 @@ impl MessageContact {
 
 #[doc(hidden)]
 pub struct RTDMessageContactBuilder {
-  inner: MessageContact
+  inner: MessageContact,
+  set: [bool; 1]
 }
 
 impl RTDMessageContactBuilder {
   pub fn build(&self) -> MessageContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageContact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("contact".to_string()); } else { missing.extend(crate::types::_common::missing_in("contact", self.inner.contact._missing_fields())); }
+    crate::types::_common::check_missing("messageContact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn contact<T: AsRef<Contact>>(&mut self, contact: T) -> &mut Self {
     self.inner.contact = contact.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1557,6 +2034,11 @@ impl MessageContactRegistered {
     RTDMessageContactRegisteredBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageContactRegistered", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1567,6 +2049,9 @@ pub struct RTDMessageContactRegisteredBuilder {
 impl RTDMessageContactRegisteredBuilder {
   pub fn build(&self) -> MessageContactRegistered { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageContactRegistered> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageContactRegistered> for MessageContactRegistered {
@@ -1614,7 +2099,16 @@ impl MessageCustomServiceAction {
     let mut inner = MessageCustomServiceAction::default();
     inner.td_name = "messageCustomServiceAction".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageCustomServiceActionBuilder { inner }
+    RTDMessageCustomServiceActionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageCustomServiceAction", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -1623,15 +2117,25 @@ impl MessageCustomServiceAction {
 
 #[doc(hidden)]
 pub struct RTDMessageCustomServiceActionBuilder {
-  inner: MessageCustomServiceAction
+  inner: MessageCustomServiceAction,
+  set: [bool; 1]
 }
 
 impl RTDMessageCustomServiceActionBuilder {
   pub fn build(&self) -> MessageCustomServiceAction { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageCustomServiceAction> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("messageCustomServiceAction", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1690,7 +2194,18 @@ impl MessageDice {
     let mut inner = MessageDice::default();
     inner.td_name = "messageDice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageDiceBuilder { inner }
+    RTDMessageDiceBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageDice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.emoji.is_empty() { missing.push("emoji".to_string()); }
+    if self.value == 0 { missing.push("value".to_string()); }
+    if self.success_animation_frame_number == 0 { missing.push("success_animation_frame_number".to_string()); }
+    missing
   }
 
   pub fn initial_state(&self) -> &Option<DiceStickers> { &self.initial_state }
@@ -1707,12 +2222,23 @@ impl MessageDice {
 
 #[doc(hidden)]
 pub struct RTDMessageDiceBuilder {
-  inner: MessageDice
+  inner: MessageDice,
+  set: [bool; 3]
 }
 
 impl RTDMessageDiceBuilder {
   pub fn build(&self) -> MessageDice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageDice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("emoji".to_string()); }
+    if !self.set[1] { missing.push("value".to_string()); }
+    if !self.set[2] { missing.push("success_animation_frame_number".to_string()); }
+    crate::types::_common::check_missing("messageDice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn initial_state<T: AsRef<DiceStickers>>(&mut self, initial_state: T) -> &mut Self {
     self.inner.initial_state = Some(initial_state.as_ref().clone());
@@ -1728,18 +2254,21 @@ impl RTDMessageDiceBuilder {
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
+    self.set[1] = true;
     self
   }
 
    
   pub fn success_animation_frame_number(&mut self, success_animation_frame_number: i64) -> &mut Self {
     self.inner.success_animation_frame_number = success_animation_frame_number;
+    self.set[2] = true;
     self
   }
 
@@ -1792,7 +2321,17 @@ impl MessageDocument {
     let mut inner = MessageDocument::default();
     inner.td_name = "messageDocument".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageDocumentBuilder { inner }
+    RTDMessageDocumentBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.document._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn document(&self) -> &Document { &self.document }
@@ -1803,21 +2342,33 @@ impl MessageDocument {
 
 #[doc(hidden)]
 pub struct RTDMessageDocumentBuilder {
-  inner: MessageDocument
+  inner: MessageDocument,
+  set: [bool; 2]
 }
 
 impl RTDMessageDocumentBuilder {
   pub fn build(&self) -> MessageDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageDocument> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.inner.document._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messageDocument", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1869,6 +2420,11 @@ impl MessageExpiredPhoto {
     RTDMessageExpiredPhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageExpiredPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1879,6 +2435,9 @@ pub struct RTDMessageExpiredPhotoBuilder {
 impl RTDMessageExpiredPhotoBuilder {
   pub fn build(&self) -> MessageExpiredPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageExpiredPhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageExpiredPhoto> for MessageExpiredPhoto {
@@ -1927,6 +2486,11 @@ impl MessageExpiredVideo {
     RTDMessageExpiredVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageExpiredVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1937,6 +2501,9 @@ pub struct RTDMessageExpiredVideoBuilder {
 impl RTDMessageExpiredVideoBuilder {
   pub fn build(&self) -> MessageExpiredVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageExpiredVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageExpiredVideo> for MessageExpiredVideo {
@@ -1984,7 +2551,16 @@ impl MessageGame {
     let mut inner = MessageGame::default();
     inner.td_name = "messageGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageGameBuilder { inner }
+    RTDMessageGameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("game".to_string()); } else { missing.extend(crate::types::_common::missing_in("game", self.game._missing_fields())); }
+    missing
   }
 
   pub fn game(&self) -> &Game { &self.game }
@@ -1993,15 +2569,25 @@ impl MessageGame {
 
 #[doc(hidden)]
 pub struct RTDMessageGameBuilder {
-  inner: MessageGame
+  inner: MessageGame,
+  set: [bool; 1]
 }
 
 impl RTDMessageGameBuilder {
   pub fn build(&self) -> MessageGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("game".to_string()); } else { missing.extend(crate::types::_common::missing_in("game", self.inner.game._missing_fields())); }
+    crate::types::_common::check_missing("messageGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn game<T: AsRef<Game>>(&mut self, game: T) -> &mut Self {
     self.inner.game = game.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -2033,7 +2619,7 @@ pub struct MessageGameScore {
   /// Identifier of the message with the game, can be an identifier of a deleted message
   game_message_id: i64,
   /// Identifier of the game; may be different from the games presented in the message with the game
-  game_id: isize,
+  #[serde(with = "crate::types::_common::int64")] game_id: i64,
   /// New score
   score: i64,
   
@@ -2056,12 +2642,23 @@ impl MessageGameScore {
     let mut inner = MessageGameScore::default();
     inner.td_name = "messageGameScore".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageGameScoreBuilder { inner }
+    RTDMessageGameScoreBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageGameScore", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.game_message_id == 0 { missing.push("game_message_id".to_string()); }
+    if self.game_id == 0 { missing.push("game_id".to_string()); }
+    if self.score == 0 { missing.push("score".to_string()); }
+    missing
   }
 
   pub fn game_message_id(&self) -> i64 { self.game_message_id }
 
-  pub fn game_id(&self) -> isize { self.game_id }
+  pub fn game_id(&self) -> i64 { self.game_id }
 
   pub fn score(&self) -> i64 { self.score }
 
@@ -2069,27 +2666,41 @@ impl MessageGameScore {
 
 #[doc(hidden)]
 pub struct RTDMessageGameScoreBuilder {
-  inner: MessageGameScore
+  inner: MessageGameScore,
+  set: [bool; 3]
 }
 
 impl RTDMessageGameScoreBuilder {
   pub fn build(&self) -> MessageGameScore { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageGameScore> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("game_message_id".to_string()); }
+    if !self.set[1] { missing.push("game_id".to_string()); }
+    if !self.set[2] { missing.push("score".to_string()); }
+    crate::types::_common::check_missing("messageGameScore", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn game_message_id(&mut self, game_message_id: i64) -> &mut Self {
     self.inner.game_message_id = game_message_id;
+    self.set[0] = true;
     self
   }
 
    
-  pub fn game_id(&mut self, game_id: isize) -> &mut Self {
+  pub fn game_id(&mut self, game_id: i64) -> &mut Self {
     self.inner.game_id = game_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn score(&mut self, score: i64) -> &mut Self {
     self.inner.score = score;
+    self.set[2] = true;
     self
   }
 
@@ -2142,7 +2753,16 @@ impl MessageInviteVoiceChatParticipants {
     let mut inner = MessageInviteVoiceChatParticipants::default();
     inner.td_name = "messageInviteVoiceChatParticipants".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageInviteVoiceChatParticipantsBuilder { inner }
+    RTDMessageInviteVoiceChatParticipantsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageInviteVoiceChatParticipants", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -2153,15 +2773,25 @@ impl MessageInviteVoiceChatParticipants {
 
 #[doc(hidden)]
 pub struct RTDMessageInviteVoiceChatParticipantsBuilder {
-  inner: MessageInviteVoiceChatParticipants
+  inner: MessageInviteVoiceChatParticipants,
+  set: [bool; 1]
 }
 
 impl RTDMessageInviteVoiceChatParticipantsBuilder {
   pub fn build(&self) -> MessageInviteVoiceChatParticipants { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageInviteVoiceChatParticipants> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("messageInviteVoiceChatParticipants", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -2234,7 +2864,21 @@ impl MessageInvoice {
     let mut inner = MessageInvoice::default();
     inner.td_name = "messageInvoice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageInvoiceBuilder { inner }
+    RTDMessageInvoiceBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageInvoice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.currency.is_empty() { missing.push("currency".to_string()); }
+    if self.total_amount == 0 { missing.push("total_amount".to_string()); }
+    if self.start_parameter.is_empty() { missing.push("start_parameter".to_string()); }
+    if self.receipt_message_id == 0 { missing.push("receipt_message_id".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -2259,21 +2903,37 @@ impl MessageInvoice {
 
 #[doc(hidden)]
 pub struct RTDMessageInvoiceBuilder {
-  inner: MessageInvoice
+  inner: MessageInvoice,
+  set: [bool; 6]
 }
 
 impl RTDMessageInvoiceBuilder {
   pub fn build(&self) -> MessageInvoice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageInvoice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    if !self.set[2] { missing.push("currency".to_string()); }
+    if !self.set[3] { missing.push("total_amount".to_string()); }
+    if !self.set[4] { missing.push("start_parameter".to_string()); }
+    if !self.set[5] { missing.push("receipt_message_id".to_string()); }
+    crate::types::_common::check_missing("messageInvoice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -2286,18 +2946,21 @@ impl RTDMessageInvoiceBuilder {
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
     self.inner.total_amount = total_amount;
+    self.set[3] = true;
     self
   }
 
    
   pub fn start_parameter<T: AsRef<str>>(&mut self, start_parameter: T) -> &mut Self {
     self.inner.start_parameter = start_parameter.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -2316,6 +2979,7 @@ impl RTDMessageInvoiceBuilder {
    
   pub fn receipt_message_id(&mut self, receipt_message_id: i64) -> &mut Self {
     self.inner.receipt_message_id = receipt_message_id;
+    self.set[5] = true;
     self
   }
 
@@ -2374,7 +3038,19 @@ impl MessageLocation {
     let mut inner = MessageLocation::default();
     inner.td_name = "messageLocation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageLocationBuilder { inner }
+    RTDMessageLocationBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.live_period == 0 { missing.push("live_period".to_string()); }
+    if self.expires_in == 0 { missing.push("expires_in".to_string()); }
+    if self.heading == 0 { missing.push("heading".to_string()); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -2391,33 +3067,49 @@ impl MessageLocation {
 
 #[doc(hidden)]
 pub struct RTDMessageLocationBuilder {
-  inner: MessageLocation
+  inner: MessageLocation,
+  set: [bool; 4]
 }
 
 impl RTDMessageLocationBuilder {
   pub fn build(&self) -> MessageLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageLocation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("live_period".to_string()); }
+    if !self.set[2] { missing.push("expires_in".to_string()); }
+    if !self.set[3] { missing.push("heading".to_string()); }
+    crate::types::_common::check_missing("messageLocation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn live_period(&mut self, live_period: i64) -> &mut Self {
     self.inner.live_period = live_period;
+    self.set[1] = true;
     self
   }
 
    
   pub fn expires_in(&mut self, expires_in: i64) -> &mut Self {
     self.inner.expires_in = expires_in;
+    self.set[2] = true;
     self
   }
 
    
   pub fn heading(&mut self, heading: i64) -> &mut Self {
     self.inner.heading = heading;
+    self.set[3] = true;
     self
   }
 
@@ -2476,7 +3168,16 @@ impl MessagePassportDataReceived {
     let mut inner = MessagePassportDataReceived::default();
     inner.td_name = "messagePassportDataReceived".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessagePassportDataReceivedBuilder { inner }
+    RTDMessagePassportDataReceivedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePassportDataReceived", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("credentials".to_string()); } else { missing.extend(crate::types::_common::missing_in("credentials", self.credentials._missing_fields())); }
+    missing
   }
 
   pub fn elements(&self) -> &Vec<EncryptedPassportElement> { &self.elements }
@@ -2487,12 +3188,21 @@ impl MessagePassportDataReceived {
 
 #[doc(hidden)]
 pub struct RTDMessagePassportDataReceivedBuilder {
-  inner: MessagePassportDataReceived
+  inner: MessagePassportDataReceived,
+  set: [bool; 1]
 }
 
 impl RTDMessagePassportDataReceivedBuilder {
   pub fn build(&self) -> MessagePassportDataReceived { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePassportDataReceived> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("credentials".to_string()); } else { missing.extend(crate::types::_common::missing_in("credentials", self.inner.credentials._missing_fields())); }
+    crate::types::_common::check_missing("messagePassportDataReceived", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn elements(&mut self, elements: Vec<EncryptedPassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -2502,6 +3212,7 @@ impl RTDMessagePassportDataReceivedBuilder {
    
   pub fn credentials<T: AsRef<EncryptedCredentials>>(&mut self, credentials: T) -> &mut Self {
     self.inner.credentials = credentials.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -2555,6 +3266,11 @@ impl MessagePassportDataSent {
     RTDMessagePassportDataSentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePassportDataSent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn types(&self) -> &Vec<PassportElementType> { &self.types }
 
 }
@@ -2567,6 +3283,9 @@ pub struct RTDMessagePassportDataSentBuilder {
 impl RTDMessagePassportDataSentBuilder {
   pub fn build(&self) -> MessagePassportDataSent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePassportDataSent> { Ok(self.build()) }
+
    
   pub fn types(&mut self, types: Vec<PassportElementType>) -> &mut Self {
     self.inner.types = types;
@@ -2624,7 +3343,18 @@ impl MessagePaymentSuccessful {
     let mut inner = MessagePaymentSuccessful::default();
     inner.td_name = "messagePaymentSuccessful".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessagePaymentSuccessfulBuilder { inner }
+    RTDMessagePaymentSuccessfulBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePaymentSuccessful", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.invoice_message_id == 0 { missing.push("invoice_message_id".to_string()); }
+    if self.currency.is_empty() { missing.push("currency".to_string()); }
+    if self.total_amount == 0 { missing.push("total_amount".to_string()); }
+    missing
   }
 
   pub fn invoice_message_id(&self) -> i64 { self.invoice_message_id }
@@ -2637,27 +3367,41 @@ impl MessagePaymentSuccessful {
 
 #[doc(hidden)]
 pub struct RTDMessagePaymentSuccessfulBuilder {
-  inner: MessagePaymentSuccessful
+  inner: MessagePaymentSuccessful,
+  set: [bool; 3]
 }
 
 impl RTDMessagePaymentSuccessfulBuilder {
   pub fn build(&self) -> MessagePaymentSuccessful { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePaymentSuccessful> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invoice_message_id".to_string()); }
+    if !self.set[1] { missing.push("currency".to_string()); }
+    if !self.set[2] { missing.push("total_amount".to_string()); }
+    crate::types::_common::check_missing("messagePaymentSuccessful", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
     self.inner.invoice_message_id = invoice_message_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
     self.inner.total_amount = total_amount;
+    self.set[2] = true;
     self
   }
 
@@ -2722,7 +3466,21 @@ impl MessagePaymentSuccessfulBot {
     let mut inner = MessagePaymentSuccessfulBot::default();
     inner.td_name = "messagePaymentSuccessfulBot".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessagePaymentSuccessfulBotBuilder { inner }
+    RTDMessagePaymentSuccessfulBotBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePaymentSuccessfulBot", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.invoice_message_id == 0 { missing.push("invoice_message_id".to_string()); }
+    if self.currency.is_empty() { missing.push("currency".to_string()); }
+    if self.total_amount == 0 { missing.push("total_amount".to_string()); }
+    if self.invoice_payload.is_empty() { missing.push("invoice_payload".to_string()); }
+    if self.telegram_payment_charge_id.is_empty() { missing.push("telegram_payment_charge_id".to_string()); }
+    if self.provider_payment_charge_id.is_empty() { missing.push("provider_payment_charge_id".to_string()); }
+    missing
   }
 
   pub fn invoice_message_id(&self) -> i64 { self.invoice_message_id }
@@ -2745,33 +3503,51 @@ impl MessagePaymentSuccessfulBot {
 
 #[doc(hidden)]
 pub struct RTDMessagePaymentSuccessfulBotBuilder {
-  inner: MessagePaymentSuccessfulBot
+  inner: MessagePaymentSuccessfulBot,
+  set: [bool; 6]
 }
 
 impl RTDMessagePaymentSuccessfulBotBuilder {
   pub fn build(&self) -> MessagePaymentSuccessfulBot { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePaymentSuccessfulBot> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invoice_message_id".to_string()); }
+    if !self.set[1] { missing.push("currency".to_string()); }
+    if !self.set[2] { missing.push("total_amount".to_string()); }
+    if !self.set[3] { missing.push("invoice_payload".to_string()); }
+    if !self.set[4] { missing.push("telegram_payment_charge_id".to_string()); }
+    if !self.set[5] { missing.push("provider_payment_charge_id".to_string()); }
+    crate::types::_common::check_missing("messagePaymentSuccessfulBot", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invoice_message_id(&mut self, invoice_message_id: i64) -> &mut Self {
     self.inner.invoice_message_id = invoice_message_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
     self.inner.total_amount = total_amount;
+    self.set[2] = true;
     self
   }
 
    
   pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
     self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -2790,12 +3566,14 @@ impl RTDMessagePaymentSuccessfulBotBuilder {
    
   pub fn telegram_payment_charge_id<T: AsRef<str>>(&mut self, telegram_payment_charge_id: T) -> &mut Self {
     self.inner.telegram_payment_charge_id = telegram_payment_charge_id.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn provider_payment_charge_id<T: AsRef<str>>(&mut self, provider_payment_charge_id: T) -> &mut Self {
     self.inner.provider_payment_charge_id = provider_payment_charge_id.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
@@ -2850,7 +3628,17 @@ impl MessagePhoto {
     let mut inner = MessagePhoto::default();
     inner.td_name = "messagePhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessagePhotoBuilder { inner }
+    RTDMessagePhotoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn photo(&self) -> &Photo { &self.photo }
@@ -2863,21 +3651,33 @@ impl MessagePhoto {
 
 #[doc(hidden)]
 pub struct RTDMessagePhotoBuilder {
-  inner: MessagePhoto
+  inner: MessagePhoto,
+  set: [bool; 2]
 }
 
 impl RTDMessagePhotoBuilder {
   pub fn build(&self) -> MessagePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messagePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -2937,6 +3737,11 @@ impl MessagePinMessage {
     RTDMessagePinMessageBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePinMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn message_id(&self) -> i64 { self.message_id }
 
 }
@@ -2949,6 +3754,9 @@ pub struct RTDMessagePinMessageBuilder {
 impl RTDMessagePinMessageBuilder {
   pub fn build(&self) -> MessagePinMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePinMessage> { Ok(self.build()) }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
@@ -3002,7 +3810,16 @@ impl MessagePoll {
     let mut inner = MessagePoll::default();
     inner.td_name = "messagePoll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessagePollBuilder { inner }
+    RTDMessagePollBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messagePoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("poll".to_string()); } else { missing.extend(crate::types::_common::missing_in("poll", self.poll._missing_fields())); }
+    missing
   }
 
   pub fn poll(&self) -> &Poll { &self.poll }
@@ -3011,15 +3828,25 @@ impl MessagePoll {
 
 #[doc(hidden)]
 pub struct RTDMessagePollBuilder {
-  inner: MessagePoll
+  inner: MessagePoll,
+  set: [bool; 1]
 }
 
 impl RTDMessagePollBuilder {
   pub fn build(&self) -> MessagePoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessagePoll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("poll".to_string()); } else { missing.extend(crate::types::_common::missing_in("poll", self.inner.poll._missing_fields())); }
+    crate::types::_common::check_missing("messagePoll", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3074,7 +3901,18 @@ impl MessageProximityAlertTriggered {
     let mut inner = MessageProximityAlertTriggered::default();
     inner.td_name = "messageProximityAlertTriggered".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageProximityAlertTriggeredBuilder { inner }
+    RTDMessageProximityAlertTriggeredBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageProximityAlertTriggered", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.traveler._is_default() { missing.push("traveler".to_string()); } else { missing.extend(crate::types::_common::missing_in("traveler", self.traveler._missing_fields())); }
+    if self.watcher._is_default() { missing.push("watcher".to_string()); } else { missing.extend(crate::types::_common::missing_in("watcher", self.watcher._missing_fields())); }
+    if self.distance == 0 { missing.push("distance".to_string()); }
+    missing
   }
 
   pub fn traveler(&self) -> &MessageSender { &self.traveler }
@@ -3087,12 +3925,23 @@ impl MessageProximityAlertTriggered {
 
 #[doc(hidden)]
 pub struct RTDMessageProximityAlertTriggeredBuilder {
-  inner: MessageProximityAlertTriggered
+  inner: MessageProximityAlertTriggered,
+  set: [bool; 1]
 }
 
 impl RTDMessageProximityAlertTriggeredBuilder {
   pub fn build(&self) -> MessageProximityAlertTriggered { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageProximityAlertTriggered> {
+    let mut missing = vec![];
+    if self.inner.traveler._is_default() { missing.push("traveler".to_string()); } else { missing.extend(crate::types::_common::missing_in("traveler", self.inner.traveler._missing_fields())); }
+    if self.inner.watcher._is_default() { missing.push("watcher".to_string()); } else { missing.extend(crate::types::_common::missing_in("watcher", self.inner.watcher._missing_fields())); }
+    if !self.set[0] { missing.push("distance".to_string()); }
+    crate::types::_common::check_missing("messageProximityAlertTriggered", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn traveler<T: AsRef<MessageSender>>(&mut self, traveler: T) -> &mut Self {
     self.inner.traveler = traveler.as_ref().clone();
@@ -3108,6 +3957,7 @@ impl RTDMessageProximityAlertTriggeredBuilder {
    
   pub fn distance(&mut self, distance: i64) -> &mut Self {
     self.inner.distance = distance;
+    self.set[0] = true;
     self
   }
 
@@ -3159,6 +4009,11 @@ impl MessageScreenshotTaken {
     RTDMessageScreenshotTakenBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageScreenshotTaken", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -3169,6 +4024,9 @@ pub struct RTDMessageScreenshotTakenBuilder {
 impl RTDMessageScreenshotTakenBuilder {
   pub fn build(&self) -> MessageScreenshotTaken { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageScreenshotTaken> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageScreenshotTaken> for MessageScreenshotTaken {
@@ -3216,7 +4074,16 @@ impl MessageSticker {
     let mut inner = MessageSticker::default();
     inner.td_name = "messageSticker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageStickerBuilder { inner }
+    RTDMessageStickerBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
   }
 
   pub fn sticker(&self) -> &Sticker { &self.sticker }
@@ -3225,15 +4092,25 @@ impl MessageSticker {
 
 #[doc(hidden)]
 pub struct RTDMessageStickerBuilder {
-  inner: MessageSticker
+  inner: MessageSticker,
+  set: [bool; 1]
 }
 
 impl RTDMessageStickerBuilder {
   pub fn build(&self) -> MessageSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSticker> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("messageSticker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3284,7 +4161,16 @@ impl MessageSupergroupChatCreate {
     let mut inner = MessageSupergroupChatCreate::default();
     inner.td_name = "messageSupergroupChatCreate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageSupergroupChatCreateBuilder { inner }
+    RTDMessageSupergroupChatCreateBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSupergroupChatCreate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -3293,15 +4179,25 @@ impl MessageSupergroupChatCreate {
 
 #[doc(hidden)]
 pub struct RTDMessageSupergroupChatCreateBuilder {
-  inner: MessageSupergroupChatCreate
+  inner: MessageSupergroupChatCreate,
+  set: [bool; 1]
 }
 
 impl RTDMessageSupergroupChatCreateBuilder {
   pub fn build(&self) -> MessageSupergroupChatCreate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSupergroupChatCreate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("messageSupergroupChatCreate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -3354,7 +4250,16 @@ impl MessageText {
     let mut inner = MessageText::default();
     inner.td_name = "messageText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageTextBuilder { inner }
+    RTDMessageTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -3365,15 +4270,25 @@ impl MessageText {
 
 #[doc(hidden)]
 pub struct RTDMessageTextBuilder {
-  inner: MessageText
+  inner: MessageText,
+  set: [bool; 1]
 }
 
 impl RTDMessageTextBuilder {
   pub fn build(&self) -> MessageText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("messageText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3431,6 +4346,11 @@ impl MessageUnsupported {
     RTDMessageUnsupportedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageUnsupported", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -3441,6 +4361,9 @@ pub struct RTDMessageUnsupportedBuilder {
 impl RTDMessageUnsupportedBuilder {
   pub fn build(&self) -> MessageUnsupported { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageUnsupported> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageUnsupported> for MessageUnsupported {
@@ -3488,7 +4411,16 @@ impl MessageVenue {
     let mut inner = MessageVenue::default();
     inner.td_name = "messageVenue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVenueBuilder { inner }
+    RTDMessageVenueBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVenue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.venue._missing_fields())); }
+    missing
   }
 
   pub fn venue(&self) -> &Venue { &self.venue }
@@ -3497,15 +4429,25 @@ impl MessageVenue {
 
 #[doc(hidden)]
 pub struct RTDMessageVenueBuilder {
-  inner: MessageVenue
+  inner: MessageVenue,
+  set: [bool; 1]
 }
 
 impl RTDMessageVenueBuilder {
   pub fn build(&self) -> MessageVenue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVenue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("venue".to_string()); } else { missing.extend(crate::types::_common::missing_in("venue", self.inner.venue._missing_fields())); }
+    crate::types::_common::check_missing("messageVenue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn venue<T: AsRef<Venue>>(&mut self, venue: T) -> &mut Self {
     self.inner.venue = venue.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3560,7 +4502,17 @@ impl MessageVideo {
     let mut inner = MessageVideo::default();
     inner.td_name = "messageVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVideoBuilder { inner }
+    RTDMessageVideoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.video._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn video(&self) -> &Video { &self.video }
@@ -3573,21 +4525,33 @@ impl MessageVideo {
 
 #[doc(hidden)]
 pub struct RTDMessageVideoBuilder {
-  inner: MessageVideo
+  inner: MessageVideo,
+  set: [bool; 2]
 }
 
 impl RTDMessageVideoBuilder {
   pub fn build(&self) -> MessageVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.inner.video._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messageVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -3648,7 +4612,16 @@ impl MessageVideoNote {
     let mut inner = MessageVideoNote::default();
     inner.td_name = "messageVideoNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVideoNoteBuilder { inner }
+    RTDMessageVideoNoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("video_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("video_note", self.video_note._missing_fields())); }
+    missing
   }
 
   pub fn video_note(&self) -> &VideoNote { &self.video_note }
@@ -3661,15 +4634,25 @@ impl MessageVideoNote {
 
 #[doc(hidden)]
 pub struct RTDMessageVideoNoteBuilder {
-  inner: MessageVideoNote
+  inner: MessageVideoNote,
+  set: [bool; 1]
 }
 
 impl RTDMessageVideoNoteBuilder {
   pub fn build(&self) -> MessageVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVideoNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("video_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("video_note", self.inner.video_note._missing_fields())); }
+    crate::types::_common::check_missing("messageVideoNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = video_note.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3732,7 +4715,16 @@ impl MessageVoiceChatEnded {
     let mut inner = MessageVoiceChatEnded::default();
     inner.td_name = "messageVoiceChatEnded".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVoiceChatEndedBuilder { inner }
+    RTDMessageVoiceChatEndedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVoiceChatEnded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    missing
   }
 
   pub fn duration(&self) -> i64 { self.duration }
@@ -3741,15 +4733,25 @@ impl MessageVoiceChatEnded {
 
 #[doc(hidden)]
 pub struct RTDMessageVoiceChatEndedBuilder {
-  inner: MessageVoiceChatEnded
+  inner: MessageVoiceChatEnded,
+  set: [bool; 1]
 }
 
 impl RTDMessageVoiceChatEndedBuilder {
   pub fn build(&self) -> MessageVoiceChatEnded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVoiceChatEnded> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("duration".to_string()); }
+    crate::types::_common::check_missing("messageVoiceChatEnded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
@@ -3800,7 +4802,16 @@ impl MessageVoiceChatStarted {
     let mut inner = MessageVoiceChatStarted::default();
     inner.td_name = "messageVoiceChatStarted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVoiceChatStartedBuilder { inner }
+    RTDMessageVoiceChatStartedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVoiceChatStarted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -3809,15 +4820,25 @@ impl MessageVoiceChatStarted {
 
 #[doc(hidden)]
 pub struct RTDMessageVoiceChatStartedBuilder {
-  inner: MessageVoiceChatStarted
+  inner: MessageVoiceChatStarted,
+  set: [bool; 1]
 }
 
 impl RTDMessageVoiceChatStartedBuilder {
   pub fn build(&self) -> MessageVoiceChatStarted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVoiceChatStarted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    crate::types::_common::check_missing("messageVoiceChatStarted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
@@ -3872,7 +4893,17 @@ impl MessageVoiceNote {
     let mut inner = MessageVoiceNote::default();
     inner.td_name = "messageVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageVoiceNoteBuilder { inner }
+    RTDMessageVoiceNoteBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.voice_note._missing_fields())); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn voice_note(&self) -> &VoiceNote { &self.voice_note }
@@ -3885,21 +4916,33 @@ impl MessageVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDMessageVoiceNoteBuilder {
-  inner: MessageVoiceNote
+  inner: MessageVoiceNote,
+  set: [bool; 2]
 }
 
 impl RTDMessageVoiceNoteBuilder {
   pub fn build(&self) -> MessageVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageVoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("voice_note".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice_note", self.inner.voice_note._missing_fields())); }
+    if !self.set[1] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("messageVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = voice_note.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<FormattedText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -3956,7 +4999,16 @@ impl MessageWebsiteConnected {
     let mut inner = MessageWebsiteConnected::default();
     inner.td_name = "messageWebsiteConnected".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageWebsiteConnectedBuilder { inner }
+    RTDMessageWebsiteConnectedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageWebsiteConnected", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.domain_name.is_empty() { missing.push("domain_name".to_string()); }
+    missing
   }
 
   pub fn domain_name(&self) -> &String { &self.domain_name }
@@ -3965,15 +5017,25 @@ impl MessageWebsiteConnected {
 
 #[doc(hidden)]
 pub struct RTDMessageWebsiteConnectedBuilder {
-  inner: MessageWebsiteConnected
+  inner: MessageWebsiteConnected,
+  set: [bool; 1]
 }
 
 impl RTDMessageWebsiteConnectedBuilder {
   pub fn build(&self) -> MessageWebsiteConnected { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageWebsiteConnected> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("domain_name".to_string()); }
+    crate::types::_common::check_missing("messageWebsiteConnected", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn domain_name<T: AsRef<str>>(&mut self, domain_name: T) -> &mut Self {
     self.inner.domain_name = domain_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
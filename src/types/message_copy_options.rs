@@ -38,7 +38,16 @@ impl MessageCopyOptions {
     let mut inner = MessageCopyOptions::default();
     inner.td_name = "messageCopyOptions".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageCopyOptionsBuilder { inner }
+    RTDMessageCopyOptionsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageCopyOptions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("new_caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_caption", self.new_caption._missing_fields())); }
+    missing
   }
 
   pub fn send_copy(&self) -> bool { self.send_copy }
@@ -51,12 +60,21 @@ impl MessageCopyOptions {
 
 #[doc(hidden)]
 pub struct RTDMessageCopyOptionsBuilder {
-  inner: MessageCopyOptions
+  inner: MessageCopyOptions,
+  set: [bool; 1]
 }
 
 impl RTDMessageCopyOptionsBuilder {
   pub fn build(&self) -> MessageCopyOptions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageCopyOptions> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("new_caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_caption", self.inner.new_caption._missing_fields())); }
+    crate::types::_common::check_missing("messageCopyOptions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn send_copy(&mut self, send_copy: bool) -> &mut Self {
     self.inner.send_copy = send_copy;
@@ -72,6 +90,7 @@ impl RTDMessageCopyOptionsBuilder {
    
   pub fn new_caption<T: AsRef<FormattedText>>(&mut self, new_caption: T) -> &mut Self {
     self.inner.new_caption = new_caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -19,14 +19,15 @@ pub trait TDMessageFileType: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageFileType {
   #[doc(hidden)] _Default(()),
-  /// Returns information about a file with messages exported from another app
-  GetMessageFileType(GetMessageFileType),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The messages was exported from a group chat
   Group(MessageFileTypeGroup),
   /// The messages was exported from a private chat
   Private(MessageFileTypePrivate),
   /// The messages was exported from a chat of unknown type
-  Unknown(MessageFileTypeUnknown),
+  MessageFileTypeUnknown(MessageFileTypeUnknown),
 
 }
 
@@ -36,13 +37,11 @@ impl Default for MessageFileType {
 
 impl<'de> Deserialize<'de> for MessageFileType {
   fn deserialize<D>(deserializer: D) -> Result<MessageFileType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageFileType,
-      (getMessageFileType, GetMessageFileType);
       (messageFileTypeGroup, Group);
       (messageFileTypePrivate, Private);
-      (messageFileTypeUnknown, Unknown);
+      (messageFileTypeUnknown, MessageFileTypeUnknown);
 
     )(deserializer)
   }
@@ -51,20 +50,19 @@ impl<'de> Deserialize<'de> for MessageFileType {
 impl RObject for MessageFileType {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      MessageFileType::GetMessageFileType(t) => t.td_name(),
       MessageFileType::Group(t) => t.td_name(),
       MessageFileType::Private(t) => t.td_name(),
-      MessageFileType::Unknown(t) => t.td_name(),
+      MessageFileType::MessageFileTypeUnknown(t) => t.td_name(),
 
       _ => "-1",
     }
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      MessageFileType::GetMessageFileType(t) => t.extra(),
       MessageFileType::Group(t) => t.extra(),
       MessageFileType::Private(t) => t.extra(),
-      MessageFileType::Unknown(t) => t.extra(),
+      MessageFileType::MessageFileTypeUnknown(t) => t.extra(),
+      MessageFileType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,31 +73,34 @@ impl RObject for MessageFileType {
 impl MessageFileType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageFileType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageFileType::Group(t) => t._missing_fields(),
+      MessageFileType::Private(t) => t._missing_fields(),
+      MessageFileType::MessageFileTypeUnknown(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_message_file_type(&self) -> bool { if let MessageFileType::GetMessageFileType(_) = self { true } else { false } }
   pub fn is_group(&self) -> bool { if let MessageFileType::Group(_) = self { true } else { false } }
   pub fn is_private(&self) -> bool { if let MessageFileType::Private(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let MessageFileType::Unknown(_) = self { true } else { false } }
+  pub fn is_message_file_type_unknown(&self) -> bool { if let MessageFileType::MessageFileTypeUnknown(_) = self { true } else { false } }
 
-  pub fn on_get_message_file_type<F: FnOnce(&GetMessageFileType)>(&self, fnc: F) -> &Self { if let MessageFileType::GetMessageFileType(t) = self { fnc(t) }; self }
   pub fn on_group<F: FnOnce(&MessageFileTypeGroup)>(&self, fnc: F) -> &Self { if let MessageFileType::Group(t) = self { fnc(t) }; self }
   pub fn on_private<F: FnOnce(&MessageFileTypePrivate)>(&self, fnc: F) -> &Self { if let MessageFileType::Private(t) = self { fnc(t) }; self }
-  pub fn on_unknown<F: FnOnce(&MessageFileTypeUnknown)>(&self, fnc: F) -> &Self { if let MessageFileType::Unknown(t) = self { fnc(t) }; self }
+  pub fn on_message_file_type_unknown<F: FnOnce(&MessageFileTypeUnknown)>(&self, fnc: F) -> &Self { if let MessageFileType::MessageFileTypeUnknown(t) = self { fnc(t) }; self }
 
-  pub fn as_get_message_file_type(&self) -> Option<&GetMessageFileType> { if let MessageFileType::GetMessageFileType(t) = self { return Some(t) } None }
   pub fn as_group(&self) -> Option<&MessageFileTypeGroup> { if let MessageFileType::Group(t) = self { return Some(t) } None }
   pub fn as_private(&self) -> Option<&MessageFileTypePrivate> { if let MessageFileType::Private(t) = self { return Some(t) } None }
-  pub fn as_unknown(&self) -> Option<&MessageFileTypeUnknown> { if let MessageFileType::Unknown(t) = self { return Some(t) } None }
+  pub fn as_message_file_type_unknown(&self) -> Option<&MessageFileTypeUnknown> { if let MessageFileType::MessageFileTypeUnknown(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_message_file_type<T: AsRef<GetMessageFileType>>(t: T) -> Self { MessageFileType::GetMessageFileType(t.as_ref().clone()) }
-
   pub fn group<T: AsRef<MessageFileTypeGroup>>(t: T) -> Self { MessageFileType::Group(t.as_ref().clone()) }
 
   pub fn private<T: AsRef<MessageFileTypePrivate>>(t: T) -> Self { MessageFileType::Private(t.as_ref().clone()) }
 
-  pub fn unknown<T: AsRef<MessageFileTypeUnknown>>(t: T) -> Self { MessageFileType::Unknown(t.as_ref().clone()) }
+  pub fn message_file_type_unknown<T: AsRef<MessageFileTypeUnknown>>(t: T) -> Self { MessageFileType::MessageFileTypeUnknown(t.as_ref().clone()) }
 
 }
 
@@ -107,6 +108,18 @@ impl AsRef<MessageFileType> for MessageFileType {
   fn as_ref(&self) -> &MessageFileType { self }
 }
 
+impl RVariant<MessageFileType> for MessageFileTypeGroup {
+  fn from_class(class: &MessageFileType) -> Option<&Self> { match class { MessageFileType::Group(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageFileType> for MessageFileTypePrivate {
+  fn from_class(class: &MessageFileType) -> Option<&Self> { match class { MessageFileType::Private(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageFileType> for MessageFileTypeUnknown {
+  fn from_class(class: &MessageFileType) -> Option<&Self> { match class { MessageFileType::MessageFileTypeUnknown(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -147,6 +160,11 @@ impl MessageFileTypeGroup {
     RTDMessageFileTypeGroupBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageFileTypeGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn title(&self) -> &String { &self.title }
 
 }
@@ -159,6 +177,9 @@ pub struct RTDMessageFileTypeGroupBuilder {
 impl RTDMessageFileTypeGroupBuilder {
   pub fn build(&self) -> MessageFileTypeGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageFileTypeGroup> { Ok(self.build()) }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
@@ -215,6 +236,11 @@ impl MessageFileTypePrivate {
     RTDMessageFileTypePrivateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageFileTypePrivate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn name(&self) -> &String { &self.name }
 
 }
@@ -227,6 +253,9 @@ pub struct RTDMessageFileTypePrivateBuilder {
 impl RTDMessageFileTypePrivateBuilder {
   pub fn build(&self) -> MessageFileTypePrivate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageFileTypePrivate> { Ok(self.build()) }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
@@ -281,6 +310,11 @@ impl MessageFileTypeUnknown {
     RTDMessageFileTypeUnknownBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageFileTypeUnknown", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +325,9 @@ pub struct RTDMessageFileTypeUnknownBuilder {
 impl RTDMessageFileTypeUnknownBuilder {
   pub fn build(&self) -> MessageFileTypeUnknown { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageFileTypeUnknown> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageFileTypeUnknown> for MessageFileTypeUnknown {
@@ -42,7 +42,18 @@ impl MessageForwardInfo {
     let mut inner = MessageForwardInfo::default();
     inner.td_name = "messageForwardInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardInfoBuilder { inner }
+    RTDMessageForwardInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.origin._is_default() { missing.push("origin".to_string()); } else { missing.extend(crate::types::_common::missing_in("origin", self.origin._missing_fields())); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.public_service_announcement_type.is_empty() { missing.push("public_service_announcement_type".to_string()); }
+    missing
   }
 
   pub fn origin(&self) -> &MessageForwardOrigin { &self.origin }
@@ -59,12 +70,23 @@ impl MessageForwardInfo {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardInfoBuilder {
-  inner: MessageForwardInfo
+  inner: MessageForwardInfo,
+  set: [bool; 2]
 }
 
 impl RTDMessageForwardInfoBuilder {
   pub fn build(&self) -> MessageForwardInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardInfo> {
+    let mut missing = vec![];
+    if self.inner.origin._is_default() { missing.push("origin".to_string()); } else { missing.extend(crate::types::_common::missing_in("origin", self.inner.origin._missing_fields())); }
+    if !self.set[0] { missing.push("date".to_string()); }
+    if !self.set[1] { missing.push("public_service_announcement_type".to_string()); }
+    crate::types::_common::check_missing("messageForwardInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn origin<T: AsRef<MessageForwardOrigin>>(&mut self, origin: T) -> &mut Self {
     self.inner.origin = origin.as_ref().clone();
@@ -74,12 +96,14 @@ impl RTDMessageForwardInfoBuilder {
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[0] = true;
     self
   }
 
    
   pub fn public_service_announcement_type<T: AsRef<str>>(&mut self, public_service_announcement_type: T) -> &mut Self {
     self.inner.public_service_announcement_type = public_service_announcement_type.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
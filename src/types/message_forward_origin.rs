@@ -19,6 +19,9 @@ pub trait TDMessageForwardOrigin: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageForwardOrigin {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message was originally a post in a channel
   Channel(MessageForwardOriginChannel),
   /// The message was originally sent by an anonymous chat administrator on behalf of the chat
@@ -38,7 +41,6 @@ impl Default for MessageForwardOrigin {
 
 impl<'de> Deserialize<'de> for MessageForwardOrigin {
   fn deserialize<D>(deserializer: D) -> Result<MessageForwardOrigin, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageForwardOrigin,
       (messageForwardOriginChannel, Channel);
@@ -70,6 +72,7 @@ impl RObject for MessageForwardOrigin {
       MessageForwardOrigin::HiddenUser(t) => t.extra(),
       MessageForwardOrigin::MessageImport(t) => t.extra(),
       MessageForwardOrigin::User(t) => t.extra(),
+      MessageForwardOrigin::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,6 +83,16 @@ impl RObject for MessageForwardOrigin {
 impl MessageForwardOrigin {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageForwardOrigin::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageForwardOrigin::Channel(t) => t._missing_fields(),
+      MessageForwardOrigin::Chat(t) => t._missing_fields(),
+      MessageForwardOrigin::HiddenUser(t) => t._missing_fields(),
+      MessageForwardOrigin::MessageImport(t) => t._missing_fields(),
+      MessageForwardOrigin::User(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_channel(&self) -> bool { if let MessageForwardOrigin::Channel(_) = self { true } else { false } }
   pub fn is_chat(&self) -> bool { if let MessageForwardOrigin::Chat(_) = self { true } else { false } }
@@ -117,6 +130,26 @@ impl AsRef<MessageForwardOrigin> for MessageForwardOrigin {
   fn as_ref(&self) -> &MessageForwardOrigin { self }
 }
 
+impl RVariant<MessageForwardOrigin> for MessageForwardOriginChannel {
+  fn from_class(class: &MessageForwardOrigin) -> Option<&Self> { match class { MessageForwardOrigin::Channel(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageForwardOrigin> for MessageForwardOriginChat {
+  fn from_class(class: &MessageForwardOrigin) -> Option<&Self> { match class { MessageForwardOrigin::Chat(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageForwardOrigin> for MessageForwardOriginHiddenUser {
+  fn from_class(class: &MessageForwardOrigin) -> Option<&Self> { match class { MessageForwardOrigin::HiddenUser(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageForwardOrigin> for MessageForwardOriginMessageImport {
+  fn from_class(class: &MessageForwardOrigin) -> Option<&Self> { match class { MessageForwardOrigin::MessageImport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageForwardOrigin> for MessageForwardOriginUser {
+  fn from_class(class: &MessageForwardOrigin) -> Option<&Self> { match class { MessageForwardOrigin::User(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -158,7 +191,18 @@ impl MessageForwardOriginChannel {
     let mut inner = MessageForwardOriginChannel::default();
     inner.td_name = "messageForwardOriginChannel".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardOriginChannelBuilder { inner }
+    RTDMessageForwardOriginChannelBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardOriginChannel", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.author_signature.is_empty() { missing.push("author_signature".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -171,27 +215,41 @@ impl MessageForwardOriginChannel {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginChannelBuilder {
-  inner: MessageForwardOriginChannel
+  inner: MessageForwardOriginChannel,
+  set: [bool; 3]
 }
 
 impl RTDMessageForwardOriginChannelBuilder {
   pub fn build(&self) -> MessageForwardOriginChannel { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardOriginChannel> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("author_signature".to_string()); }
+    crate::types::_common::check_missing("messageForwardOriginChannel", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn author_signature<T: AsRef<str>>(&mut self, author_signature: T) -> &mut Self {
     self.inner.author_signature = author_signature.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -244,7 +302,17 @@ impl MessageForwardOriginChat {
     let mut inner = MessageForwardOriginChat::default();
     inner.td_name = "messageForwardOriginChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardOriginChatBuilder { inner }
+    RTDMessageForwardOriginChatBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardOriginChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender_chat_id == 0 { missing.push("sender_chat_id".to_string()); }
+    if self.author_signature.is_empty() { missing.push("author_signature".to_string()); }
+    missing
   }
 
   pub fn sender_chat_id(&self) -> i64 { self.sender_chat_id }
@@ -255,21 +323,33 @@ impl MessageForwardOriginChat {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginChatBuilder {
-  inner: MessageForwardOriginChat
+  inner: MessageForwardOriginChat,
+  set: [bool; 2]
 }
 
 impl RTDMessageForwardOriginChatBuilder {
   pub fn build(&self) -> MessageForwardOriginChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardOriginChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sender_chat_id".to_string()); }
+    if !self.set[1] { missing.push("author_signature".to_string()); }
+    crate::types::_common::check_missing("messageForwardOriginChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender_chat_id(&mut self, sender_chat_id: i64) -> &mut Self {
     self.inner.sender_chat_id = sender_chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn author_signature<T: AsRef<str>>(&mut self, author_signature: T) -> &mut Self {
     self.inner.author_signature = author_signature.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -320,7 +400,16 @@ impl MessageForwardOriginHiddenUser {
     let mut inner = MessageForwardOriginHiddenUser::default();
     inner.td_name = "messageForwardOriginHiddenUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardOriginHiddenUserBuilder { inner }
+    RTDMessageForwardOriginHiddenUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardOriginHiddenUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender_name.is_empty() { missing.push("sender_name".to_string()); }
+    missing
   }
 
   pub fn sender_name(&self) -> &String { &self.sender_name }
@@ -329,15 +418,25 @@ impl MessageForwardOriginHiddenUser {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginHiddenUserBuilder {
-  inner: MessageForwardOriginHiddenUser
+  inner: MessageForwardOriginHiddenUser,
+  set: [bool; 1]
 }
 
 impl RTDMessageForwardOriginHiddenUserBuilder {
   pub fn build(&self) -> MessageForwardOriginHiddenUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardOriginHiddenUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sender_name".to_string()); }
+    crate::types::_common::check_missing("messageForwardOriginHiddenUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -388,7 +487,16 @@ impl MessageForwardOriginMessageImport {
     let mut inner = MessageForwardOriginMessageImport::default();
     inner.td_name = "messageForwardOriginMessageImport".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardOriginMessageImportBuilder { inner }
+    RTDMessageForwardOriginMessageImportBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardOriginMessageImport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender_name.is_empty() { missing.push("sender_name".to_string()); }
+    missing
   }
 
   pub fn sender_name(&self) -> &String { &self.sender_name }
@@ -397,15 +505,25 @@ impl MessageForwardOriginMessageImport {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginMessageImportBuilder {
-  inner: MessageForwardOriginMessageImport
+  inner: MessageForwardOriginMessageImport,
+  set: [bool; 1]
 }
 
 impl RTDMessageForwardOriginMessageImportBuilder {
   pub fn build(&self) -> MessageForwardOriginMessageImport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardOriginMessageImport> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sender_name".to_string()); }
+    crate::types::_common::check_missing("messageForwardOriginMessageImport", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -456,7 +574,16 @@ impl MessageForwardOriginUser {
     let mut inner = MessageForwardOriginUser::default();
     inner.td_name = "messageForwardOriginUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageForwardOriginUserBuilder { inner }
+    RTDMessageForwardOriginUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageForwardOriginUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    missing
   }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
@@ -465,15 +592,25 @@ impl MessageForwardOriginUser {
 
 #[doc(hidden)]
 pub struct RTDMessageForwardOriginUserBuilder {
-  inner: MessageForwardOriginUser
+  inner: MessageForwardOriginUser,
+  set: [bool; 1]
 }
 
 impl RTDMessageForwardOriginUserBuilder {
   pub fn build(&self) -> MessageForwardOriginUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageForwardOriginUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sender_user_id".to_string()); }
+    crate::types::_common::check_missing("messageForwardOriginUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[0] = true;
     self
   }
 
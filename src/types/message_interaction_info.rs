@@ -38,7 +38,17 @@ impl MessageInteractionInfo {
     let mut inner = MessageInteractionInfo::default();
     inner.td_name = "messageInteractionInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageInteractionInfoBuilder { inner }
+    RTDMessageInteractionInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageInteractionInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.view_count == 0 { missing.push("view_count".to_string()); }
+    if self.forward_count == 0 { missing.push("forward_count".to_string()); }
+    missing
   }
 
   pub fn view_count(&self) -> i64 { self.view_count }
@@ -51,21 +61,33 @@ impl MessageInteractionInfo {
 
 #[doc(hidden)]
 pub struct RTDMessageInteractionInfoBuilder {
-  inner: MessageInteractionInfo
+  inner: MessageInteractionInfo,
+  set: [bool; 2]
 }
 
 impl RTDMessageInteractionInfoBuilder {
   pub fn build(&self) -> MessageInteractionInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageInteractionInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("view_count".to_string()); }
+    if !self.set[1] { missing.push("forward_count".to_string()); }
+    crate::types::_common::check_missing("messageInteractionInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn view_count(&mut self, view_count: i64) -> &mut Self {
     self.inner.view_count = view_count;
+    self.set[0] = true;
     self
   }
 
    
   pub fn forward_count(&mut self, forward_count: i64) -> &mut Self {
     self.inner.forward_count = forward_count;
+    self.set[1] = true;
     self
   }
 
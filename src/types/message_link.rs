@@ -36,7 +36,16 @@ impl MessageLink {
     let mut inner = MessageLink::default();
     inner.td_name = "messageLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageLinkBuilder { inner }
+    RTDMessageLinkBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.link.is_empty() { missing.push("link".to_string()); }
+    missing
   }
 
   pub fn link(&self) -> &String { &self.link }
@@ -47,15 +56,25 @@ impl MessageLink {
 
 #[doc(hidden)]
 pub struct RTDMessageLinkBuilder {
-  inner: MessageLink
+  inner: MessageLink,
+  set: [bool; 1]
 }
 
 impl RTDMessageLinkBuilder {
   pub fn build(&self) -> MessageLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("link".to_string()); }
+    crate::types::_common::check_missing("messageLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn link<T: AsRef<str>>(&mut self, link: T) -> &mut Self {
     self.inner.link = link.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
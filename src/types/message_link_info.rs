@@ -42,7 +42,16 @@ impl MessageLinkInfo {
     let mut inner = MessageLinkInfo::default();
     inner.td_name = "messageLinkInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageLinkInfoBuilder { inner }
+    RTDMessageLinkInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageLinkInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn is_public(&self) -> bool { self.is_public }
@@ -59,12 +68,21 @@ impl MessageLinkInfo {
 
 #[doc(hidden)]
 pub struct RTDMessageLinkInfoBuilder {
-  inner: MessageLinkInfo
+  inner: MessageLinkInfo,
+  set: [bool; 1]
 }
 
 impl RTDMessageLinkInfoBuilder {
   pub fn build(&self) -> MessageLinkInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageLinkInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("messageLinkInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn is_public(&mut self, is_public: bool) -> &mut Self {
     self.inner.is_public = is_public;
@@ -74,6 +92,7 @@ impl RTDMessageLinkInfoBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -42,7 +42,19 @@ impl MessageReplyInfo {
     let mut inner = MessageReplyInfo::default();
     inner.td_name = "messageReplyInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageReplyInfoBuilder { inner }
+    RTDMessageReplyInfoBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageReplyInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.reply_count == 0 { missing.push("reply_count".to_string()); }
+    if self.last_read_inbox_message_id == 0 { missing.push("last_read_inbox_message_id".to_string()); }
+    if self.last_read_outbox_message_id == 0 { missing.push("last_read_outbox_message_id".to_string()); }
+    if self.last_message_id == 0 { missing.push("last_message_id".to_string()); }
+    missing
   }
 
   pub fn reply_count(&self) -> i64 { self.reply_count }
@@ -59,15 +71,28 @@ impl MessageReplyInfo {
 
 #[doc(hidden)]
 pub struct RTDMessageReplyInfoBuilder {
-  inner: MessageReplyInfo
+  inner: MessageReplyInfo,
+  set: [bool; 4]
 }
 
 impl RTDMessageReplyInfoBuilder {
   pub fn build(&self) -> MessageReplyInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageReplyInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("reply_count".to_string()); }
+    if !self.set[1] { missing.push("last_read_inbox_message_id".to_string()); }
+    if !self.set[2] { missing.push("last_read_outbox_message_id".to_string()); }
+    if !self.set[3] { missing.push("last_message_id".to_string()); }
+    crate::types::_common::check_missing("messageReplyInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn reply_count(&mut self, reply_count: i64) -> &mut Self {
     self.inner.reply_count = reply_count;
+    self.set[0] = true;
     self
   }
 
@@ -80,18 +105,21 @@ impl RTDMessageReplyInfoBuilder {
    
   pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn last_message_id(&mut self, last_message_id: i64) -> &mut Self {
     self.inner.last_message_id = last_message_id;
+    self.set[3] = true;
     self
   }
 
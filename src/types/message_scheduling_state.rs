@@ -19,6 +19,9 @@ pub trait TDMessageSchedulingState: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageSchedulingState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message will be sent at the specified date
   SendAtDate(MessageSchedulingStateSendAtDate),
   /// The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known
@@ -32,7 +35,6 @@ impl Default for MessageSchedulingState {
 
 impl<'de> Deserialize<'de> for MessageSchedulingState {
   fn deserialize<D>(deserializer: D) -> Result<MessageSchedulingState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageSchedulingState,
       (messageSchedulingStateSendAtDate, SendAtDate);
@@ -55,6 +57,7 @@ impl RObject for MessageSchedulingState {
     match self {
       MessageSchedulingState::SendAtDate(t) => t.extra(),
       MessageSchedulingState::SendWhenOnline(t) => t.extra(),
+      MessageSchedulingState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for MessageSchedulingState {
 impl MessageSchedulingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSchedulingState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageSchedulingState::SendAtDate(t) => t._missing_fields(),
+      MessageSchedulingState::SendWhenOnline(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_send_at_date(&self) -> bool { if let MessageSchedulingState::SendAtDate(_) = self { true } else { false } }
   pub fn is_send_when_online(&self) -> bool { if let MessageSchedulingState::SendWhenOnline(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<MessageSchedulingState> for MessageSchedulingState {
   fn as_ref(&self) -> &MessageSchedulingState { self }
 }
 
+impl RVariant<MessageSchedulingState> for MessageSchedulingStateSendAtDate {
+  fn from_class(class: &MessageSchedulingState) -> Option<&Self> { match class { MessageSchedulingState::SendAtDate(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageSchedulingState> for MessageSchedulingStateSendWhenOnline {
+  fn from_class(class: &MessageSchedulingState) -> Option<&Self> { match class { MessageSchedulingState::SendWhenOnline(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -124,7 +142,16 @@ impl MessageSchedulingStateSendAtDate {
     let mut inner = MessageSchedulingStateSendAtDate::default();
     inner.td_name = "messageSchedulingStateSendAtDate".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageSchedulingStateSendAtDateBuilder { inner }
+    RTDMessageSchedulingStateSendAtDateBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSchedulingStateSendAtDate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.send_date == 0 { missing.push("send_date".to_string()); }
+    missing
   }
 
   pub fn send_date(&self) -> i64 { self.send_date }
@@ -133,15 +160,25 @@ impl MessageSchedulingStateSendAtDate {
 
 #[doc(hidden)]
 pub struct RTDMessageSchedulingStateSendAtDateBuilder {
-  inner: MessageSchedulingStateSendAtDate
+  inner: MessageSchedulingStateSendAtDate,
+  set: [bool; 1]
 }
 
 impl RTDMessageSchedulingStateSendAtDateBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendAtDate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSchedulingStateSendAtDate> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("send_date".to_string()); }
+    crate::types::_common::check_missing("messageSchedulingStateSendAtDate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn send_date(&mut self, send_date: i64) -> &mut Self {
     self.inner.send_date = send_date;
+    self.set[0] = true;
     self
   }
 
@@ -193,6 +230,11 @@ impl MessageSchedulingStateSendWhenOnline {
     RTDMessageSchedulingStateSendWhenOnlineBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSchedulingStateSendWhenOnline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +245,9 @@ pub struct RTDMessageSchedulingStateSendWhenOnlineBuilder {
 impl RTDMessageSchedulingStateSendWhenOnlineBuilder {
   pub fn build(&self) -> MessageSchedulingStateSendWhenOnline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSchedulingStateSendWhenOnline> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageSchedulingStateSendWhenOnline> for MessageSchedulingStateSendWhenOnline {
@@ -41,6 +41,15 @@ impl MessageSendOptions {
     RTDMessageSendOptionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSendOptions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.scheduling_state._is_default() { missing.push("scheduling_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("scheduling_state", self.scheduling_state._missing_fields())); }
+    missing
+  }
+
   pub fn disable_notification(&self) -> bool { self.disable_notification }
 
   pub fn from_background(&self) -> bool { self.from_background }
@@ -57,6 +66,14 @@ pub struct RTDMessageSendOptionsBuilder {
 impl RTDMessageSendOptionsBuilder {
   pub fn build(&self) -> MessageSendOptions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSendOptions> {
+    let mut missing = vec![];
+    if self.inner.scheduling_state._is_default() { missing.push("scheduling_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("scheduling_state", self.inner.scheduling_state._missing_fields())); }
+    crate::types::_common::check_missing("messageSendOptions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn disable_notification(&mut self, disable_notification: bool) -> &mut Self {
     self.inner.disable_notification = disable_notification;
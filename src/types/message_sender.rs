@@ -19,6 +19,9 @@ pub trait TDMessageSender: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageSender {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message was sent on behalf of a chat
   Chat(MessageSenderChat),
   /// The message was sent by a known user
@@ -32,7 +35,6 @@ impl Default for MessageSender {
 
 impl<'de> Deserialize<'de> for MessageSender {
   fn deserialize<D>(deserializer: D) -> Result<MessageSender, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageSender,
       (messageSenderChat, Chat);
@@ -55,6 +57,7 @@ impl RObject for MessageSender {
     match self {
       MessageSender::Chat(t) => t.extra(),
       MessageSender::User(t) => t.extra(),
+      MessageSender::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for MessageSender {
 impl MessageSender {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSender::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageSender::Chat(t) => t._missing_fields(),
+      MessageSender::User(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_chat(&self) -> bool { if let MessageSender::Chat(_) = self { true } else { false } }
   pub fn is_user(&self) -> bool { if let MessageSender::User(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<MessageSender> for MessageSender {
   fn as_ref(&self) -> &MessageSender { self }
 }
 
+impl RVariant<MessageSender> for MessageSenderChat {
+  fn from_class(class: &MessageSender) -> Option<&Self> { match class { MessageSender::Chat(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageSender> for MessageSenderUser {
+  fn from_class(class: &MessageSender) -> Option<&Self> { match class { MessageSender::User(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -124,7 +142,16 @@ impl MessageSenderChat {
     let mut inner = MessageSenderChat::default();
     inner.td_name = "messageSenderChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageSenderChatBuilder { inner }
+    RTDMessageSenderChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSenderChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -133,15 +160,25 @@ impl MessageSenderChat {
 
 #[doc(hidden)]
 pub struct RTDMessageSenderChatBuilder {
-  inner: MessageSenderChat
+  inner: MessageSenderChat,
+  set: [bool; 1]
 }
 
 impl RTDMessageSenderChatBuilder {
   pub fn build(&self) -> MessageSenderChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSenderChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("messageSenderChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -192,7 +229,16 @@ impl MessageSenderUser {
     let mut inner = MessageSenderUser::default();
     inner.td_name = "messageSenderUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageSenderUserBuilder { inner }
+    RTDMessageSenderUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSenderUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -201,15 +247,25 @@ impl MessageSenderUser {
 
 #[doc(hidden)]
 pub struct RTDMessageSenderUserBuilder {
-  inner: MessageSenderUser
+  inner: MessageSenderUser,
+  set: [bool; 1]
 }
 
 impl RTDMessageSenderUserBuilder {
   pub fn build(&self) -> MessageSenderUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSenderUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("messageSenderUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDMessageSendingState: Debug + RObject {}
 #[serde(untagged)]
 pub enum MessageSendingState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The message failed to be sent
   Failed(MessageSendingStateFailed),
   /// The message is being sent now, but has not yet been delivered to the server
@@ -32,7 +35,6 @@ impl Default for MessageSendingState {
 
 impl<'de> Deserialize<'de> for MessageSendingState {
   fn deserialize<D>(deserializer: D) -> Result<MessageSendingState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       MessageSendingState,
       (messageSendingStateFailed, Failed);
@@ -55,6 +57,7 @@ impl RObject for MessageSendingState {
     match self {
       MessageSendingState::Failed(t) => t.extra(),
       MessageSendingState::Pending(t) => t.extra(),
+      MessageSendingState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for MessageSendingState {
 impl MessageSendingState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let MessageSendingState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      MessageSendingState::Failed(t) => t._missing_fields(),
+      MessageSendingState::Pending(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_failed(&self) -> bool { if let MessageSendingState::Failed(_) = self { true } else { false } }
   pub fn is_pending(&self) -> bool { if let MessageSendingState::Pending(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<MessageSendingState> for MessageSendingState {
   fn as_ref(&self) -> &MessageSendingState { self }
 }
 
+impl RVariant<MessageSendingState> for MessageSendingStateFailed {
+  fn from_class(class: &MessageSendingState) -> Option<&Self> { match class { MessageSendingState::Failed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<MessageSendingState> for MessageSendingStatePending {
+  fn from_class(class: &MessageSendingState) -> Option<&Self> { match class { MessageSendingState::Pending(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -130,7 +148,17 @@ impl MessageSendingStateFailed {
     let mut inner = MessageSendingStateFailed::default();
     inner.td_name = "messageSendingStateFailed".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageSendingStateFailedBuilder { inner }
+    RTDMessageSendingStateFailedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSendingStateFailed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    if self.retry_after == 0.0 { missing.push("retry_after".to_string()); }
+    missing
   }
 
   pub fn error_code(&self) -> i64 { self.error_code }
@@ -145,12 +173,22 @@ impl MessageSendingStateFailed {
 
 #[doc(hidden)]
 pub struct RTDMessageSendingStateFailedBuilder {
-  inner: MessageSendingStateFailed
+  inner: MessageSendingStateFailed,
+  set: [bool; 2]
 }
 
 impl RTDMessageSendingStateFailedBuilder {
   pub fn build(&self) -> MessageSendingStateFailed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSendingStateFailed> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("error_message".to_string()); }
+    if !self.set[1] { missing.push("retry_after".to_string()); }
+    crate::types::_common::check_missing("messageSendingStateFailed", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn error_code(&mut self, error_code: i64) -> &mut Self {
     self.inner.error_code = error_code;
@@ -160,6 +198,7 @@ impl RTDMessageSendingStateFailedBuilder {
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -172,6 +211,7 @@ impl RTDMessageSendingStateFailedBuilder {
    
   pub fn retry_after(&mut self, retry_after: f32) -> &mut Self {
     self.inner.retry_after = retry_after;
+    self.set[1] = true;
     self
   }
 
@@ -223,6 +263,11 @@ impl MessageSendingStatePending {
     RTDMessageSendingStatePendingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageSendingStatePending", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +278,9 @@ pub struct RTDMessageSendingStatePendingBuilder {
 impl RTDMessageSendingStatePendingBuilder {
   pub fn build(&self) -> MessageSendingStatePending { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageSendingStatePending> { Ok(self.build()) }
+
 }
 
 impl AsRef<MessageSendingStatePending> for MessageSendingStatePending {
@@ -37,6 +37,15 @@ impl MessageStatistics {
     RTDMessageStatisticsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_interaction_graph._is_default() { missing.push("message_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_interaction_graph", self.message_interaction_graph._missing_fields())); }
+    missing
+  }
+
   pub fn message_interaction_graph(&self) -> &StatisticalGraph { &self.message_interaction_graph }
 
 }
@@ -49,6 +58,14 @@ pub struct RTDMessageStatisticsBuilder {
 impl RTDMessageStatisticsBuilder {
   pub fn build(&self) -> MessageStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageStatistics> {
+    let mut missing = vec![];
+    if self.inner.message_interaction_graph._is_default() { missing.push("message_interaction_graph".to_string()); } else { missing.extend(crate::types::_common::missing_in("message_interaction_graph", self.inner.message_interaction_graph._missing_fields())); }
+    crate::types::_common::check_missing("messageStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_interaction_graph<T: AsRef<StatisticalGraph>>(&mut self, message_interaction_graph: T) -> &mut Self {
     self.inner.message_interaction_graph = message_interaction_graph.as_ref().clone();
@@ -42,7 +42,18 @@ impl MessageThreadInfo {
     let mut inner = MessageThreadInfo::default();
     inner.td_name = "messageThreadInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMessageThreadInfoBuilder { inner }
+    RTDMessageThreadInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("messageThreadInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_thread_id == 0 { missing.push("message_thread_id".to_string()); }
+    if false { missing.push("reply_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_info", self.reply_info._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -59,27 +70,41 @@ impl MessageThreadInfo {
 
 #[doc(hidden)]
 pub struct RTDMessageThreadInfoBuilder {
-  inner: MessageThreadInfo
+  inner: MessageThreadInfo,
+  set: [bool; 3]
 }
 
 impl RTDMessageThreadInfoBuilder {
   pub fn build(&self) -> MessageThreadInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<MessageThreadInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_thread_id".to_string()); }
+    if !self.set[2] { missing.push("reply_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("reply_info", self.inner.reply_info._missing_fields())); }
+    crate::types::_common::check_missing("messageThreadInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_thread_id(&mut self, message_thread_id: i64) -> &mut Self {
     self.inner.message_thread_id = message_thread_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn reply_info<T: AsRef<MessageReplyInfo>>(&mut self, reply_info: T) -> &mut Self {
     self.inner.reply_info = reply_info.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
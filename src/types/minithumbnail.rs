@@ -38,7 +38,18 @@ impl Minithumbnail {
     let mut inner = Minithumbnail::default();
     inner.td_name = "minithumbnail".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDMinithumbnailBuilder { inner }
+    RTDMinithumbnailBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("minithumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn width(&self) -> i64 { self.width }
@@ -51,27 +62,41 @@ impl Minithumbnail {
 
 #[doc(hidden)]
 pub struct RTDMinithumbnailBuilder {
-  inner: Minithumbnail
+  inner: Minithumbnail,
+  set: [bool; 3]
 }
 
 impl RTDMinithumbnailBuilder {
   pub fn build(&self) -> Minithumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Minithumbnail> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("width".to_string()); }
+    if !self.set[1] { missing.push("height".to_string()); }
+    if !self.set[2] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("minithumbnail", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[0] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[1] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
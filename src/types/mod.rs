@@ -2,13 +2,15 @@
 pub use self::_common::{
   RObject,
   RFunction,
+  RVariant,
   detect_td_type,
   detect_td_type_and_extra,
   from_json,
+  parse_response,
   TdType,
 };
 
-#[macro_use] mod _common;
+#[macro_use] pub(crate) mod _common;
 
 pub use self::account_ttl::*;
 pub use self::address::*;
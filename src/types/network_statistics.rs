@@ -36,7 +36,16 @@ impl NetworkStatistics {
     let mut inner = NetworkStatistics::default();
     inner.td_name = "networkStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNetworkStatisticsBuilder { inner }
+    RTDNetworkStatisticsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.since_date == 0 { missing.push("since_date".to_string()); }
+    missing
   }
 
   pub fn since_date(&self) -> i64 { self.since_date }
@@ -47,15 +56,25 @@ impl NetworkStatistics {
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsBuilder {
-  inner: NetworkStatistics
+  inner: NetworkStatistics,
+  set: [bool; 1]
 }
 
 impl RTDNetworkStatisticsBuilder {
   pub fn build(&self) -> NetworkStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("since_date".to_string()); }
+    crate::types::_common::check_missing("networkStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn since_date(&mut self, since_date: i64) -> &mut Self {
     self.inner.since_date = since_date;
+    self.set[0] = true;
     self
   }
 
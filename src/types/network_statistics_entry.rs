@@ -19,6 +19,9 @@ pub trait TDNetworkStatisticsEntry: Debug + RObject {}
 #[serde(untagged)]
 pub enum NetworkStatisticsEntry {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Contains information about the total amount of data that was used for calls
   Call(NetworkStatisticsEntryCall),
   /// Contains information about the total amount of data that was used to send and receive files
@@ -32,7 +35,6 @@ impl Default for NetworkStatisticsEntry {
 
 impl<'de> Deserialize<'de> for NetworkStatisticsEntry {
   fn deserialize<D>(deserializer: D) -> Result<NetworkStatisticsEntry, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       NetworkStatisticsEntry,
       (networkStatisticsEntryCall, Call);
@@ -55,6 +57,7 @@ impl RObject for NetworkStatisticsEntry {
     match self {
       NetworkStatisticsEntry::Call(t) => t.extra(),
       NetworkStatisticsEntry::File(t) => t.extra(),
+      NetworkStatisticsEntry::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for NetworkStatisticsEntry {
 impl NetworkStatisticsEntry {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkStatisticsEntry::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      NetworkStatisticsEntry::Call(t) => t._missing_fields(),
+      NetworkStatisticsEntry::File(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_call(&self) -> bool { if let NetworkStatisticsEntry::Call(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let NetworkStatisticsEntry::File(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<NetworkStatisticsEntry> for NetworkStatisticsEntry {
   fn as_ref(&self) -> &NetworkStatisticsEntry { self }
 }
 
+impl RVariant<NetworkStatisticsEntry> for NetworkStatisticsEntryCall {
+  fn from_class(class: &NetworkStatisticsEntry) -> Option<&Self> { match class { NetworkStatisticsEntry::Call(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NetworkStatisticsEntry> for NetworkStatisticsEntryFile {
+  fn from_class(class: &NetworkStatisticsEntry) -> Option<&Self> { match class { NetworkStatisticsEntry::File(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -130,7 +148,19 @@ impl NetworkStatisticsEntryCall {
     let mut inner = NetworkStatisticsEntryCall::default();
     inner.td_name = "networkStatisticsEntryCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNetworkStatisticsEntryCallBuilder { inner }
+    RTDNetworkStatisticsEntryCallBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkStatisticsEntryCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.network_type._is_default() { missing.push("network_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("network_type", self.network_type._missing_fields())); }
+    if self.sent_bytes == 0 { missing.push("sent_bytes".to_string()); }
+    if self.received_bytes == 0 { missing.push("received_bytes".to_string()); }
+    if self.duration == 0.0 { missing.push("duration".to_string()); }
+    missing
   }
 
   pub fn network_type(&self) -> &NetworkType { &self.network_type }
@@ -145,12 +175,24 @@ impl NetworkStatisticsEntryCall {
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsEntryCallBuilder {
-  inner: NetworkStatisticsEntryCall
+  inner: NetworkStatisticsEntryCall,
+  set: [bool; 3]
 }
 
 impl RTDNetworkStatisticsEntryCallBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkStatisticsEntryCall> {
+    let mut missing = vec![];
+    if self.inner.network_type._is_default() { missing.push("network_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("network_type", self.inner.network_type._missing_fields())); }
+    if !self.set[0] { missing.push("sent_bytes".to_string()); }
+    if !self.set[1] { missing.push("received_bytes".to_string()); }
+    if !self.set[2] { missing.push("duration".to_string()); }
+    crate::types::_common::check_missing("networkStatisticsEntryCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn network_type<T: AsRef<NetworkType>>(&mut self, network_type: T) -> &mut Self {
     self.inner.network_type = network_type.as_ref().clone();
@@ -160,18 +202,21 @@ impl RTDNetworkStatisticsEntryCallBuilder {
    
   pub fn sent_bytes(&mut self, sent_bytes: i64) -> &mut Self {
     self.inner.sent_bytes = sent_bytes;
+    self.set[0] = true;
     self
   }
 
    
   pub fn received_bytes(&mut self, received_bytes: i64) -> &mut Self {
     self.inner.received_bytes = received_bytes;
+    self.set[1] = true;
     self
   }
 
    
   pub fn duration(&mut self, duration: f32) -> &mut Self {
     self.inner.duration = duration;
+    self.set[2] = true;
     self
   }
 
@@ -228,7 +273,19 @@ impl NetworkStatisticsEntryFile {
     let mut inner = NetworkStatisticsEntryFile::default();
     inner.td_name = "networkStatisticsEntryFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNetworkStatisticsEntryFileBuilder { inner }
+    RTDNetworkStatisticsEntryFileBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkStatisticsEntryFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.file_type._missing_fields())); }
+    if self.network_type._is_default() { missing.push("network_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("network_type", self.network_type._missing_fields())); }
+    if self.sent_bytes == 0 { missing.push("sent_bytes".to_string()); }
+    if self.received_bytes == 0 { missing.push("received_bytes".to_string()); }
+    missing
   }
 
   pub fn file_type(&self) -> &FileType { &self.file_type }
@@ -243,12 +300,24 @@ impl NetworkStatisticsEntryFile {
 
 #[doc(hidden)]
 pub struct RTDNetworkStatisticsEntryFileBuilder {
-  inner: NetworkStatisticsEntryFile
+  inner: NetworkStatisticsEntryFile,
+  set: [bool; 2]
 }
 
 impl RTDNetworkStatisticsEntryFileBuilder {
   pub fn build(&self) -> NetworkStatisticsEntryFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkStatisticsEntryFile> {
+    let mut missing = vec![];
+    if self.inner.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.inner.file_type._missing_fields())); }
+    if self.inner.network_type._is_default() { missing.push("network_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("network_type", self.inner.network_type._missing_fields())); }
+    if !self.set[0] { missing.push("sent_bytes".to_string()); }
+    if !self.set[1] { missing.push("received_bytes".to_string()); }
+    crate::types::_common::check_missing("networkStatisticsEntryFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
@@ -264,12 +333,14 @@ impl RTDNetworkStatisticsEntryFileBuilder {
    
   pub fn sent_bytes(&mut self, sent_bytes: i64) -> &mut Self {
     self.inner.sent_bytes = sent_bytes;
+    self.set[0] = true;
     self
   }
 
    
   pub fn received_bytes(&mut self, received_bytes: i64) -> &mut Self {
     self.inner.received_bytes = received_bytes;
+    self.set[1] = true;
     self
   }
 
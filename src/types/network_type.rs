@@ -19,6 +19,9 @@ pub trait TDNetworkType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NetworkType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A mobile network
   Mobile(NetworkTypeMobile),
   /// A mobile roaming network
@@ -38,7 +41,6 @@ impl Default for NetworkType {
 
 impl<'de> Deserialize<'de> for NetworkType {
   fn deserialize<D>(deserializer: D) -> Result<NetworkType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       NetworkType,
       (networkTypeMobile, Mobile);
@@ -70,6 +72,7 @@ impl RObject for NetworkType {
       NetworkType::None(t) => t.extra(),
       NetworkType::Other(t) => t.extra(),
       NetworkType::WiFi(t) => t.extra(),
+      NetworkType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,6 +83,16 @@ impl RObject for NetworkType {
 impl NetworkType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NetworkType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      NetworkType::Mobile(t) => t._missing_fields(),
+      NetworkType::MobileRoaming(t) => t._missing_fields(),
+      NetworkType::None(t) => t._missing_fields(),
+      NetworkType::Other(t) => t._missing_fields(),
+      NetworkType::WiFi(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_mobile(&self) -> bool { if let NetworkType::Mobile(_) = self { true } else { false } }
   pub fn is_mobile_roaming(&self) -> bool { if let NetworkType::MobileRoaming(_) = self { true } else { false } }
@@ -117,6 +130,26 @@ impl AsRef<NetworkType> for NetworkType {
   fn as_ref(&self) -> &NetworkType { self }
 }
 
+impl RVariant<NetworkType> for NetworkTypeMobile {
+  fn from_class(class: &NetworkType) -> Option<&Self> { match class { NetworkType::Mobile(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NetworkType> for NetworkTypeMobileRoaming {
+  fn from_class(class: &NetworkType) -> Option<&Self> { match class { NetworkType::MobileRoaming(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NetworkType> for NetworkTypeNone {
+  fn from_class(class: &NetworkType) -> Option<&Self> { match class { NetworkType::None(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NetworkType> for NetworkTypeOther {
+  fn from_class(class: &NetworkType) -> Option<&Self> { match class { NetworkType::Other(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NetworkType> for NetworkTypeWiFi {
+  fn from_class(class: &NetworkType) -> Option<&Self> { match class { NetworkType::WiFi(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -155,6 +188,11 @@ impl NetworkTypeMobile {
     RTDNetworkTypeMobileBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkTypeMobile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -165,6 +203,9 @@ pub struct RTDNetworkTypeMobileBuilder {
 impl RTDNetworkTypeMobileBuilder {
   pub fn build(&self) -> NetworkTypeMobile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkTypeMobile> { Ok(self.build()) }
+
 }
 
 impl AsRef<NetworkTypeMobile> for NetworkTypeMobile {
@@ -213,6 +254,11 @@ impl NetworkTypeMobileRoaming {
     RTDNetworkTypeMobileRoamingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkTypeMobileRoaming", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -223,6 +269,9 @@ pub struct RTDNetworkTypeMobileRoamingBuilder {
 impl RTDNetworkTypeMobileRoamingBuilder {
   pub fn build(&self) -> NetworkTypeMobileRoaming { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkTypeMobileRoaming> { Ok(self.build()) }
+
 }
 
 impl AsRef<NetworkTypeMobileRoaming> for NetworkTypeMobileRoaming {
@@ -271,6 +320,11 @@ impl NetworkTypeNone {
     RTDNetworkTypeNoneBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkTypeNone", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -281,6 +335,9 @@ pub struct RTDNetworkTypeNoneBuilder {
 impl RTDNetworkTypeNoneBuilder {
   pub fn build(&self) -> NetworkTypeNone { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkTypeNone> { Ok(self.build()) }
+
 }
 
 impl AsRef<NetworkTypeNone> for NetworkTypeNone {
@@ -329,6 +386,11 @@ impl NetworkTypeOther {
     RTDNetworkTypeOtherBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkTypeOther", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -339,6 +401,9 @@ pub struct RTDNetworkTypeOtherBuilder {
 impl RTDNetworkTypeOtherBuilder {
   pub fn build(&self) -> NetworkTypeOther { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkTypeOther> { Ok(self.build()) }
+
 }
 
 impl AsRef<NetworkTypeOther> for NetworkTypeOther {
@@ -387,6 +452,11 @@ impl NetworkTypeWiFi {
     RTDNetworkTypeWiFiBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("networkTypeWiFi", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -397,6 +467,9 @@ pub struct RTDNetworkTypeWiFiBuilder {
 impl RTDNetworkTypeWiFiBuilder {
   pub fn build(&self) -> NetworkTypeWiFi { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NetworkTypeWiFi> { Ok(self.build()) }
+
 }
 
 impl AsRef<NetworkTypeWiFi> for NetworkTypeWiFi {
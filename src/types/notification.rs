@@ -40,7 +40,18 @@ impl Notification {
     let mut inner = Notification::default();
     inner.td_name = "notification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNotificationBuilder { inner }
+    RTDNotificationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -55,21 +66,34 @@ impl Notification {
 
 #[doc(hidden)]
 pub struct RTDNotificationBuilder {
-  inner: Notification
+  inner: Notification,
+  set: [bool; 2]
 }
 
 impl RTDNotificationBuilder {
   pub fn build(&self) -> Notification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Notification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("date".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("notification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[1] = true;
     self
   }
 
@@ -42,7 +42,19 @@ impl NotificationGroup {
     let mut inner = NotificationGroup::default();
     inner.td_name = "notificationGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNotificationGroupBuilder { inner }
+    RTDNotificationGroupBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -59,15 +71,28 @@ impl NotificationGroup {
 
 #[doc(hidden)]
 pub struct RTDNotificationGroupBuilder {
-  inner: NotificationGroup
+  inner: NotificationGroup,
+  set: [bool; 3]
 }
 
 impl RTDNotificationGroupBuilder {
   pub fn build(&self) -> NotificationGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if !self.set[2] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("notificationGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -80,12 +105,14 @@ impl RTDNotificationGroupBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDNotificationGroupType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationGroupType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A group containing notifications of type notificationTypeNewCall
   Calls(NotificationGroupTypeCalls),
   /// A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message
@@ -36,7 +39,6 @@ impl Default for NotificationGroupType {
 
 impl<'de> Deserialize<'de> for NotificationGroupType {
   fn deserialize<D>(deserializer: D) -> Result<NotificationGroupType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       NotificationGroupType,
       (notificationGroupTypeCalls, Calls);
@@ -65,6 +67,7 @@ impl RObject for NotificationGroupType {
       NotificationGroupType::Mentions(t) => t.extra(),
       NotificationGroupType::Messages(t) => t.extra(),
       NotificationGroupType::SecretChat(t) => t.extra(),
+      NotificationGroupType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for NotificationGroupType {
 impl NotificationGroupType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationGroupType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      NotificationGroupType::Calls(t) => t._missing_fields(),
+      NotificationGroupType::Mentions(t) => t._missing_fields(),
+      NotificationGroupType::Messages(t) => t._missing_fields(),
+      NotificationGroupType::SecretChat(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_calls(&self) -> bool { if let NotificationGroupType::Calls(_) = self { true } else { false } }
   pub fn is_mentions(&self) -> bool { if let NotificationGroupType::Mentions(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<NotificationGroupType> for NotificationGroupType {
   fn as_ref(&self) -> &NotificationGroupType { self }
 }
 
+impl RVariant<NotificationGroupType> for NotificationGroupTypeCalls {
+  fn from_class(class: &NotificationGroupType) -> Option<&Self> { match class { NotificationGroupType::Calls(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationGroupType> for NotificationGroupTypeMentions {
+  fn from_class(class: &NotificationGroupType) -> Option<&Self> { match class { NotificationGroupType::Mentions(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationGroupType> for NotificationGroupTypeMessages {
+  fn from_class(class: &NotificationGroupType) -> Option<&Self> { match class { NotificationGroupType::Messages(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationGroupType> for NotificationGroupTypeSecretChat {
+  fn from_class(class: &NotificationGroupType) -> Option<&Self> { match class { NotificationGroupType::SecretChat(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -145,6 +173,11 @@ impl NotificationGroupTypeCalls {
     RTDNotificationGroupTypeCallsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationGroupTypeCalls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -155,6 +188,9 @@ pub struct RTDNotificationGroupTypeCallsBuilder {
 impl RTDNotificationGroupTypeCallsBuilder {
   pub fn build(&self) -> NotificationGroupTypeCalls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationGroupTypeCalls> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationGroupTypeCalls> for NotificationGroupTypeCalls {
@@ -203,6 +239,11 @@ impl NotificationGroupTypeMentions {
     RTDNotificationGroupTypeMentionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationGroupTypeMentions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -213,6 +254,9 @@ pub struct RTDNotificationGroupTypeMentionsBuilder {
 impl RTDNotificationGroupTypeMentionsBuilder {
   pub fn build(&self) -> NotificationGroupTypeMentions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationGroupTypeMentions> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationGroupTypeMentions> for NotificationGroupTypeMentions {
@@ -261,6 +305,11 @@ impl NotificationGroupTypeMessages {
     RTDNotificationGroupTypeMessagesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationGroupTypeMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -271,6 +320,9 @@ pub struct RTDNotificationGroupTypeMessagesBuilder {
 impl RTDNotificationGroupTypeMessagesBuilder {
   pub fn build(&self) -> NotificationGroupTypeMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationGroupTypeMessages> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationGroupTypeMessages> for NotificationGroupTypeMessages {
@@ -319,6 +371,11 @@ impl NotificationGroupTypeSecretChat {
     RTDNotificationGroupTypeSecretChatBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationGroupTypeSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -329,6 +386,9 @@ pub struct RTDNotificationGroupTypeSecretChatBuilder {
 impl RTDNotificationGroupTypeSecretChatBuilder {
   pub fn build(&self) -> NotificationGroupTypeSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationGroupTypeSecretChat> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationGroupTypeSecretChat> for NotificationGroupTypeSecretChat {
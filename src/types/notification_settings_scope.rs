@@ -19,6 +19,9 @@ pub trait TDNotificationSettingsScope: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationSettingsScope {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Notification settings applied to all channels when the corresponding chat setting has a default value
   ChannelChats(NotificationSettingsScopeChannelChats),
   /// Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value
@@ -34,7 +37,6 @@ impl Default for NotificationSettingsScope {
 
 impl<'de> Deserialize<'de> for NotificationSettingsScope {
   fn deserialize<D>(deserializer: D) -> Result<NotificationSettingsScope, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       NotificationSettingsScope,
       (notificationSettingsScopeChannelChats, ChannelChats);
@@ -60,6 +62,7 @@ impl RObject for NotificationSettingsScope {
       NotificationSettingsScope::ChannelChats(t) => t.extra(),
       NotificationSettingsScope::GroupChats(t) => t.extra(),
       NotificationSettingsScope::PrivateChats(t) => t.extra(),
+      NotificationSettingsScope::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for NotificationSettingsScope {
 impl NotificationSettingsScope {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationSettingsScope::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      NotificationSettingsScope::ChannelChats(t) => t._missing_fields(),
+      NotificationSettingsScope::GroupChats(t) => t._missing_fields(),
+      NotificationSettingsScope::PrivateChats(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_channel_chats(&self) -> bool { if let NotificationSettingsScope::ChannelChats(_) = self { true } else { false } }
   pub fn is_group_chats(&self) -> bool { if let NotificationSettingsScope::GroupChats(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<NotificationSettingsScope> for NotificationSettingsScope {
   fn as_ref(&self) -> &NotificationSettingsScope { self }
 }
 
+impl RVariant<NotificationSettingsScope> for NotificationSettingsScopeChannelChats {
+  fn from_class(class: &NotificationSettingsScope) -> Option<&Self> { match class { NotificationSettingsScope::ChannelChats(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationSettingsScope> for NotificationSettingsScopeGroupChats {
+  fn from_class(class: &NotificationSettingsScope) -> Option<&Self> { match class { NotificationSettingsScope::GroupChats(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationSettingsScope> for NotificationSettingsScopePrivateChats {
+  fn from_class(class: &NotificationSettingsScope) -> Option<&Self> { match class { NotificationSettingsScope::PrivateChats(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl NotificationSettingsScopeChannelChats {
     RTDNotificationSettingsScopeChannelChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationSettingsScopeChannelChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDNotificationSettingsScopeChannelChatsBuilder {
 impl RTDNotificationSettingsScopeChannelChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeChannelChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationSettingsScopeChannelChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationSettingsScopeChannelChats> for NotificationSettingsScopeChannelChats {
@@ -193,6 +224,11 @@ impl NotificationSettingsScopeGroupChats {
     RTDNotificationSettingsScopeGroupChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationSettingsScopeGroupChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,9 @@ pub struct RTDNotificationSettingsScopeGroupChatsBuilder {
 impl RTDNotificationSettingsScopeGroupChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopeGroupChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationSettingsScopeGroupChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationSettingsScopeGroupChats> for NotificationSettingsScopeGroupChats {
@@ -251,6 +290,11 @@ impl NotificationSettingsScopePrivateChats {
     RTDNotificationSettingsScopePrivateChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationSettingsScopePrivateChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +305,9 @@ pub struct RTDNotificationSettingsScopePrivateChatsBuilder {
 impl RTDNotificationSettingsScopePrivateChatsBuilder {
   pub fn build(&self) -> NotificationSettingsScopePrivateChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationSettingsScopePrivateChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationSettingsScopePrivateChats> for NotificationSettingsScopePrivateChats {
@@ -19,6 +19,9 @@ pub trait TDNotificationType: Debug + RObject {}
 #[serde(untagged)]
 pub enum NotificationType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// New call was received
   NewCall(NotificationTypeNewCall),
   /// New message was received
@@ -36,7 +39,6 @@ impl Default for NotificationType {
 
 impl<'de> Deserialize<'de> for NotificationType {
   fn deserialize<D>(deserializer: D) -> Result<NotificationType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       NotificationType,
       (notificationTypeNewCall, NewCall);
@@ -65,6 +67,7 @@ impl RObject for NotificationType {
       NotificationType::NewMessage(t) => t.extra(),
       NotificationType::NewPushMessage(t) => t.extra(),
       NotificationType::NewSecretChat(t) => t.extra(),
+      NotificationType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for NotificationType {
 impl NotificationType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let NotificationType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      NotificationType::NewCall(t) => t._missing_fields(),
+      NotificationType::NewMessage(t) => t._missing_fields(),
+      NotificationType::NewPushMessage(t) => t._missing_fields(),
+      NotificationType::NewSecretChat(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_new_call(&self) -> bool { if let NotificationType::NewCall(_) = self { true } else { false } }
   pub fn is_new_message(&self) -> bool { if let NotificationType::NewMessage(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<NotificationType> for NotificationType {
   fn as_ref(&self) -> &NotificationType { self }
 }
 
+impl RVariant<NotificationType> for NotificationTypeNewCall {
+  fn from_class(class: &NotificationType) -> Option<&Self> { match class { NotificationType::NewCall(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationType> for NotificationTypeNewMessage {
+  fn from_class(class: &NotificationType) -> Option<&Self> { match class { NotificationType::NewMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationType> for NotificationTypeNewPushMessage {
+  fn from_class(class: &NotificationType) -> Option<&Self> { match class { NotificationType::NewPushMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<NotificationType> for NotificationTypeNewSecretChat {
+  fn from_class(class: &NotificationType) -> Option<&Self> { match class { NotificationType::NewSecretChat(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +172,16 @@ impl NotificationTypeNewCall {
     let mut inner = NotificationTypeNewCall::default();
     inner.td_name = "notificationTypeNewCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNotificationTypeNewCallBuilder { inner }
+    RTDNotificationTypeNewCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationTypeNewCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -153,15 +190,25 @@ impl NotificationTypeNewCall {
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewCallBuilder {
-  inner: NotificationTypeNewCall
+  inner: NotificationTypeNewCall,
+  set: [bool; 1]
 }
 
 impl RTDNotificationTypeNewCallBuilder {
   pub fn build(&self) -> NotificationTypeNewCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationTypeNewCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    crate::types::_common::check_missing("notificationTypeNewCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
@@ -212,7 +259,16 @@ impl NotificationTypeNewMessage {
     let mut inner = NotificationTypeNewMessage::default();
     inner.td_name = "notificationTypeNewMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNotificationTypeNewMessageBuilder { inner }
+    RTDNotificationTypeNewMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationTypeNewMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -221,15 +277,25 @@ impl NotificationTypeNewMessage {
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewMessageBuilder {
-  inner: NotificationTypeNewMessage
+  inner: NotificationTypeNewMessage,
+  set: [bool; 1]
 }
 
 impl RTDNotificationTypeNewMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationTypeNewMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("notificationTypeNewMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -288,7 +354,19 @@ impl NotificationTypeNewPushMessage {
     let mut inner = NotificationTypeNewPushMessage::default();
     inner.td_name = "notificationTypeNewPushMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDNotificationTypeNewPushMessageBuilder { inner }
+    RTDNotificationTypeNewPushMessageBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationTypeNewPushMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.sender._missing_fields())); }
+    if self.sender_name.is_empty() { missing.push("sender_name".to_string()); }
+    if self.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.content._missing_fields())); }
+    missing
   }
 
   pub fn message_id(&self) -> i64 { self.message_id }
@@ -305,15 +383,28 @@ impl NotificationTypeNewPushMessage {
 
 #[doc(hidden)]
 pub struct RTDNotificationTypeNewPushMessageBuilder {
-  inner: NotificationTypeNewPushMessage
+  inner: NotificationTypeNewPushMessage,
+  set: [bool; 2]
 }
 
 impl RTDNotificationTypeNewPushMessageBuilder {
   pub fn build(&self) -> NotificationTypeNewPushMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationTypeNewPushMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message_id".to_string()); }
+    if self.inner.sender._is_default() { missing.push("sender".to_string()); } else { missing.extend(crate::types::_common::missing_in("sender", self.inner.sender._missing_fields())); }
+    if !self.set[1] { missing.push("sender_name".to_string()); }
+    if self.inner.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.inner.content._missing_fields())); }
+    crate::types::_common::check_missing("notificationTypeNewPushMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[0] = true;
     self
   }
 
@@ -326,6 +417,7 @@ impl RTDNotificationTypeNewPushMessageBuilder {
    
   pub fn sender_name<T: AsRef<str>>(&mut self, sender_name: T) -> &mut Self {
     self.inner.sender_name = sender_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -389,6 +481,11 @@ impl NotificationTypeNewSecretChat {
     RTDNotificationTypeNewSecretChatBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("notificationTypeNewSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -399,6 +496,9 @@ pub struct RTDNotificationTypeNewSecretChatBuilder {
 impl RTDNotificationTypeNewSecretChatBuilder {
   pub fn build(&self) -> NotificationTypeNewSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<NotificationTypeNewSecretChat> { Ok(self.build()) }
+
 }
 
 impl AsRef<NotificationTypeNewSecretChat> for NotificationTypeNewSecretChat {
@@ -35,6 +35,11 @@ impl Ok {
     RTDOkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("ok", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -45,6 +50,9 @@ pub struct RTDOkBuilder {
 impl RTDOkBuilder {
   pub fn build(&self) -> Ok { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Ok> { Ok(self.build()) }
+
 }
 
 impl AsRef<Ok> for Ok {
@@ -19,8 +19,9 @@ pub trait TDOptionValue: Debug + RObject {}
 #[serde(untagged)]
 pub enum OptionValue {
   #[doc(hidden)] _Default(()),
-  /// Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization
-  GetOption(GetOption),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Represents a boolean option
   Boolean(OptionValueBoolean),
   /// Represents an unknown option or an option which has a default value
@@ -38,10 +39,8 @@ impl Default for OptionValue {
 
 impl<'de> Deserialize<'de> for OptionValue {
   fn deserialize<D>(deserializer: D) -> Result<OptionValue, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       OptionValue,
-      (getOption, GetOption);
       (optionValueBoolean, Boolean);
       (optionValueEmpty, Empty);
       (optionValueInteger, Integer);
@@ -54,7 +53,6 @@ impl<'de> Deserialize<'de> for OptionValue {
 impl RObject for OptionValue {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      OptionValue::GetOption(t) => t.td_name(),
       OptionValue::Boolean(t) => t.td_name(),
       OptionValue::Empty(t) => t.td_name(),
       OptionValue::Integer(t) => t.td_name(),
@@ -65,11 +63,11 @@ impl RObject for OptionValue {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      OptionValue::GetOption(t) => t.extra(),
       OptionValue::Boolean(t) => t.extra(),
       OptionValue::Empty(t) => t.extra(),
       OptionValue::Integer(t) => t.extra(),
       OptionValue::String(t) => t.extra(),
+      OptionValue::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -80,20 +78,26 @@ impl RObject for OptionValue {
 impl OptionValue {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let OptionValue::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      OptionValue::Boolean(t) => t._missing_fields(),
+      OptionValue::Empty(t) => t._missing_fields(),
+      OptionValue::Integer(t) => t._missing_fields(),
+      OptionValue::String(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_option(&self) -> bool { if let OptionValue::GetOption(_) = self { true } else { false } }
   pub fn is_boolean(&self) -> bool { if let OptionValue::Boolean(_) = self { true } else { false } }
   pub fn is_empty(&self) -> bool { if let OptionValue::Empty(_) = self { true } else { false } }
   pub fn is_integer(&self) -> bool { if let OptionValue::Integer(_) = self { true } else { false } }
   pub fn is_string(&self) -> bool { if let OptionValue::String(_) = self { true } else { false } }
 
-  pub fn on_get_option<F: FnOnce(&GetOption)>(&self, fnc: F) -> &Self { if let OptionValue::GetOption(t) = self { fnc(t) }; self }
   pub fn on_boolean<F: FnOnce(&OptionValueBoolean)>(&self, fnc: F) -> &Self { if let OptionValue::Boolean(t) = self { fnc(t) }; self }
   pub fn on_empty<F: FnOnce(&OptionValueEmpty)>(&self, fnc: F) -> &Self { if let OptionValue::Empty(t) = self { fnc(t) }; self }
   pub fn on_integer<F: FnOnce(&OptionValueInteger)>(&self, fnc: F) -> &Self { if let OptionValue::Integer(t) = self { fnc(t) }; self }
   pub fn on_string<F: FnOnce(&OptionValueString)>(&self, fnc: F) -> &Self { if let OptionValue::String(t) = self { fnc(t) }; self }
 
-  pub fn as_get_option(&self) -> Option<&GetOption> { if let OptionValue::GetOption(t) = self { return Some(t) } None }
   pub fn as_boolean(&self) -> Option<&OptionValueBoolean> { if let OptionValue::Boolean(t) = self { return Some(t) } None }
   pub fn as_empty(&self) -> Option<&OptionValueEmpty> { if let OptionValue::Empty(t) = self { return Some(t) } None }
   pub fn as_integer(&self) -> Option<&OptionValueInteger> { if let OptionValue::Integer(t) = self { return Some(t) } None }
@@ -101,8 +105,6 @@ impl OptionValue {
 
 
 
-  pub fn get_option<T: AsRef<GetOption>>(t: T) -> Self { OptionValue::GetOption(t.as_ref().clone()) }
-
   pub fn boolean<T: AsRef<OptionValueBoolean>>(t: T) -> Self { OptionValue::Boolean(t.as_ref().clone()) }
 
   pub fn empty<T: AsRef<OptionValueEmpty>>(t: T) -> Self { OptionValue::Empty(t.as_ref().clone()) }
@@ -117,6 +119,22 @@ impl AsRef<OptionValue> for OptionValue {
   fn as_ref(&self) -> &OptionValue { self }
 }
 
+impl RVariant<OptionValue> for OptionValueBoolean {
+  fn from_class(class: &OptionValue) -> Option<&Self> { match class { OptionValue::Boolean(t) => Some(t), _ => None } }
+}
+
+impl RVariant<OptionValue> for OptionValueEmpty {
+  fn from_class(class: &OptionValue) -> Option<&Self> { match class { OptionValue::Empty(t) => Some(t), _ => None } }
+}
+
+impl RVariant<OptionValue> for OptionValueInteger {
+  fn from_class(class: &OptionValue) -> Option<&Self> { match class { OptionValue::Integer(t) => Some(t), _ => None } }
+}
+
+impl RVariant<OptionValue> for OptionValueString {
+  fn from_class(class: &OptionValue) -> Option<&Self> { match class { OptionValue::String(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -157,6 +175,11 @@ impl OptionValueBoolean {
     RTDOptionValueBooleanBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("optionValueBoolean", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> bool { self.value }
 
 }
@@ -169,6 +192,9 @@ pub struct RTDOptionValueBooleanBuilder {
 impl RTDOptionValueBooleanBuilder {
   pub fn build(&self) -> OptionValueBoolean { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OptionValueBoolean> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: bool) -> &mut Self {
     self.inner.value = value;
@@ -223,6 +249,11 @@ impl OptionValueEmpty {
     RTDOptionValueEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("optionValueEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +264,9 @@ pub struct RTDOptionValueEmptyBuilder {
 impl RTDOptionValueEmptyBuilder {
   pub fn build(&self) -> OptionValueEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OptionValueEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<OptionValueEmpty> for OptionValueEmpty {
@@ -259,7 +293,7 @@ pub struct OptionValueInteger {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The value of the option
-  value: isize,
+  #[serde(with = "crate::types::_common::int64")] value: i64,
   
 }
 
@@ -280,24 +314,43 @@ impl OptionValueInteger {
     let mut inner = OptionValueInteger::default();
     inner.td_name = "optionValueInteger".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOptionValueIntegerBuilder { inner }
+    RTDOptionValueIntegerBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("optionValueInteger", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value == 0 { missing.push("value".to_string()); }
+    missing
   }
 
-  pub fn value(&self) -> isize { self.value }
+  pub fn value(&self) -> i64 { self.value }
 
 }
 
 #[doc(hidden)]
 pub struct RTDOptionValueIntegerBuilder {
-  inner: OptionValueInteger
+  inner: OptionValueInteger,
+  set: [bool; 1]
 }
 
 impl RTDOptionValueIntegerBuilder {
   pub fn build(&self) -> OptionValueInteger { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OptionValueInteger> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("optionValueInteger", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn value(&mut self, value: isize) -> &mut Self {
+  pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
+    self.set[0] = true;
     self
   }
 
@@ -348,7 +401,16 @@ impl OptionValueString {
     let mut inner = OptionValueString::default();
     inner.td_name = "optionValueString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOptionValueStringBuilder { inner }
+    RTDOptionValueStringBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("optionValueString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> &String { &self.value }
@@ -357,15 +419,25 @@ impl OptionValueString {
 
 #[doc(hidden)]
 pub struct RTDOptionValueStringBuilder {
-  inner: OptionValueString
+  inner: OptionValueString,
+  set: [bool; 1]
 }
 
 impl RTDOptionValueStringBuilder {
   pub fn build(&self) -> OptionValueString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OptionValueString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("optionValueString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -40,7 +40,18 @@ impl OrderInfo {
     let mut inner = OrderInfo::default();
     inner.td_name = "orderInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDOrderInfoBuilder { inner }
+    RTDOrderInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("orderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if self.email_address.is_empty() { missing.push("email_address".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -55,27 +66,41 @@ impl OrderInfo {
 
 #[doc(hidden)]
 pub struct RTDOrderInfoBuilder {
-  inner: OrderInfo
+  inner: OrderInfo,
+  set: [bool; 3]
 }
 
 impl RTDOrderInfoBuilder {
   pub fn build(&self) -> OrderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<OrderInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    if !self.set[1] { missing.push("phone_number".to_string()); }
+    if !self.set[2] { missing.push("email_address".to_string()); }
+    crate::types::_common::check_missing("orderInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDPageBlock: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlock {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor
   Anchor(PageBlockAnchor),
   /// An animation
@@ -86,7 +89,6 @@ impl Default for PageBlock {
 
 impl<'de> Deserialize<'de> for PageBlock {
   fn deserialize<D>(deserializer: D) -> Result<PageBlock, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PageBlock,
       (pageBlockAnchor, Anchor);
@@ -190,6 +192,7 @@ impl RObject for PageBlock {
       PageBlock::Title(t) => t.extra(),
       PageBlock::Video(t) => t.extra(),
       PageBlock::VoiceNote(t) => t.extra(),
+      PageBlock::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -200,6 +203,40 @@ impl RObject for PageBlock {
 impl PageBlock {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlock::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PageBlock::Anchor(t) => t._missing_fields(),
+      PageBlock::Animation(t) => t._missing_fields(),
+      PageBlock::Audio(t) => t._missing_fields(),
+      PageBlock::AuthorDate(t) => t._missing_fields(),
+      PageBlock::BlockQuote(t) => t._missing_fields(),
+      PageBlock::ChatLink(t) => t._missing_fields(),
+      PageBlock::Collage(t) => t._missing_fields(),
+      PageBlock::Cover(t) => t._missing_fields(),
+      PageBlock::Details(t) => t._missing_fields(),
+      PageBlock::Divider(t) => t._missing_fields(),
+      PageBlock::Embedded(t) => t._missing_fields(),
+      PageBlock::EmbeddedPost(t) => t._missing_fields(),
+      PageBlock::Footer(t) => t._missing_fields(),
+      PageBlock::Header(t) => t._missing_fields(),
+      PageBlock::Kicker(t) => t._missing_fields(),
+      PageBlock::List(t) => t._missing_fields(),
+      PageBlock::Map(t) => t._missing_fields(),
+      PageBlock::Paragraph(t) => t._missing_fields(),
+      PageBlock::Photo(t) => t._missing_fields(),
+      PageBlock::Preformatted(t) => t._missing_fields(),
+      PageBlock::PullQuote(t) => t._missing_fields(),
+      PageBlock::RelatedArticles(t) => t._missing_fields(),
+      PageBlock::Slideshow(t) => t._missing_fields(),
+      PageBlock::Subheader(t) => t._missing_fields(),
+      PageBlock::Subtitle(t) => t._missing_fields(),
+      PageBlock::Table(t) => t._missing_fields(),
+      PageBlock::Title(t) => t._missing_fields(),
+      PageBlock::Video(t) => t._missing_fields(),
+      PageBlock::VoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_anchor(&self) -> bool { if let PageBlock::Anchor(_) = self { true } else { false } }
   pub fn is_animation(&self) -> bool { if let PageBlock::Animation(_) = self { true } else { false } }
@@ -357,6 +394,122 @@ impl AsRef<PageBlock> for PageBlock {
   fn as_ref(&self) -> &PageBlock { self }
 }
 
+impl RVariant<PageBlock> for PageBlockAnchor {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Anchor(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockAnimation {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockAudio {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockAuthorDate {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::AuthorDate(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockBlockQuote {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::BlockQuote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockChatLink {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::ChatLink(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockCollage {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Collage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockCover {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Cover(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockDetails {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Details(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockDivider {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Divider(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockEmbedded {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Embedded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockEmbeddedPost {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::EmbeddedPost(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockFooter {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Footer(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockHeader {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Header(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockKicker {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Kicker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockList {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::List(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockMap {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Map(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockParagraph {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Paragraph(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockPhoto {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockPreformatted {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Preformatted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockPullQuote {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::PullQuote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockRelatedArticles {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::RelatedArticles(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockSlideshow {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Slideshow(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockSubheader {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Subheader(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockSubtitle {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Subtitle(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockTable {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Table(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockTitle {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Title(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockVideo {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlock> for PageBlockVoiceNote {
+  fn from_class(class: &PageBlock) -> Option<&Self> { match class { PageBlock::VoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -394,7 +547,16 @@ impl PageBlockAnchor {
     let mut inner = PageBlockAnchor::default();
     inner.td_name = "pageBlockAnchor".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockAnchorBuilder { inner }
+    RTDPageBlockAnchorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockAnchor", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -403,15 +565,25 @@ impl PageBlockAnchor {
 
 #[doc(hidden)]
 pub struct RTDPageBlockAnchorBuilder {
-  inner: PageBlockAnchor
+  inner: PageBlockAnchor,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockAnchorBuilder {
   pub fn build(&self) -> PageBlockAnchor { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockAnchor> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("pageBlockAnchor", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -466,7 +638,16 @@ impl PageBlockAnimation {
     let mut inner = PageBlockAnimation::default();
     inner.td_name = "pageBlockAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockAnimationBuilder { inner }
+    RTDPageBlockAnimationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn animation(&self) -> &Option<Animation> { &self.animation }
@@ -479,12 +660,21 @@ impl PageBlockAnimation {
 
 #[doc(hidden)]
 pub struct RTDPageBlockAnimationBuilder {
-  inner: PageBlockAnimation
+  inner: PageBlockAnimation,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockAnimationBuilder {
   pub fn build(&self) -> PageBlockAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockAnimation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -494,6 +684,7 @@ impl RTDPageBlockAnimationBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -552,7 +743,16 @@ impl PageBlockAudio {
     let mut inner = PageBlockAudio::default();
     inner.td_name = "pageBlockAudio".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockAudioBuilder { inner }
+    RTDPageBlockAudioBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn audio(&self) -> &Option<Audio> { &self.audio }
@@ -563,12 +763,21 @@ impl PageBlockAudio {
 
 #[doc(hidden)]
 pub struct RTDPageBlockAudioBuilder {
-  inner: PageBlockAudio
+  inner: PageBlockAudio,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockAudioBuilder {
   pub fn build(&self) -> PageBlockAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockAudio> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockAudio", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -578,6 +787,7 @@ impl RTDPageBlockAudioBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -633,6 +843,15 @@ impl PageBlockAuthorDate {
     RTDPageBlockAuthorDateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockAuthorDate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.author._is_default() { missing.push("author".to_string()); } else { missing.extend(crate::types::_common::missing_in("author", self.author._missing_fields())); }
+    missing
+  }
+
   pub fn author(&self) -> &RichText { &self.author }
 
   pub fn publish_date(&self) -> i64 { self.publish_date }
@@ -647,6 +866,14 @@ pub struct RTDPageBlockAuthorDateBuilder {
 impl RTDPageBlockAuthorDateBuilder {
   pub fn build(&self) -> PageBlockAuthorDate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockAuthorDate> {
+    let mut missing = vec![];
+    if self.inner.author._is_default() { missing.push("author".to_string()); } else { missing.extend(crate::types::_common::missing_in("author", self.inner.author._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockAuthorDate", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn author<T: AsRef<RichText>>(&mut self, author: T) -> &mut Self {
     self.inner.author = author.as_ref().clone();
@@ -711,6 +938,16 @@ impl PageBlockBlockQuote {
     RTDPageBlockBlockQuoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockBlockQuote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.credit._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -725,6 +962,15 @@ pub struct RTDPageBlockBlockQuoteBuilder {
 impl RTDPageBlockBlockQuoteBuilder {
   pub fn build(&self) -> PageBlockBlockQuote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockBlockQuote> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if self.inner.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.inner.credit._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockBlockQuote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -788,7 +1034,17 @@ impl PageBlockChatLink {
     let mut inner = PageBlockChatLink::default();
     inner.td_name = "pageBlockChatLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockChatLinkBuilder { inner }
+    RTDPageBlockChatLinkBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockChatLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -801,15 +1057,26 @@ impl PageBlockChatLink {
 
 #[doc(hidden)]
 pub struct RTDPageBlockChatLinkBuilder {
-  inner: PageBlockChatLink
+  inner: PageBlockChatLink,
+  set: [bool; 2]
 }
 
 impl RTDPageBlockChatLinkBuilder {
   pub fn build(&self) -> PageBlockChatLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockChatLink> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    if !self.set[1] { missing.push("username".to_string()); }
+    crate::types::_common::check_missing("pageBlockChatLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -822,6 +1089,7 @@ impl RTDPageBlockChatLinkBuilder {
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -874,7 +1142,16 @@ impl PageBlockCollage {
     let mut inner = PageBlockCollage::default();
     inner.td_name = "pageBlockCollage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockCollageBuilder { inner }
+    RTDPageBlockCollageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockCollage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -885,12 +1162,21 @@ impl PageBlockCollage {
 
 #[doc(hidden)]
 pub struct RTDPageBlockCollageBuilder {
-  inner: PageBlockCollage
+  inner: PageBlockCollage,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockCollageBuilder {
   pub fn build(&self) -> PageBlockCollage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockCollage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockCollage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -900,6 +1186,7 @@ impl RTDPageBlockCollageBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -953,6 +1240,15 @@ impl PageBlockCover {
     RTDPageBlockCoverBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockCover", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.cover._is_default() { missing.push("cover".to_string()); } else { missing.extend(crate::types::_common::missing_in("cover", self.cover._missing_fields())); }
+    missing
+  }
+
   pub fn cover(&self) -> &Box<PageBlock> { &self.cover }
 
 }
@@ -965,6 +1261,14 @@ pub struct RTDPageBlockCoverBuilder {
 impl RTDPageBlockCoverBuilder {
   pub fn build(&self) -> PageBlockCover { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockCover> {
+    let mut missing = vec![];
+    if self.inner.cover._is_default() { missing.push("cover".to_string()); } else { missing.extend(crate::types::_common::missing_in("cover", self.inner.cover._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockCover", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn cover<T: AsRef<Box<PageBlock>>>(&mut self, cover: T) -> &mut Self {
     self.inner.cover = cover.as_ref().clone();
@@ -1025,6 +1329,15 @@ impl PageBlockDetails {
     RTDPageBlockDetailsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockDetails", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.header._missing_fields())); }
+    missing
+  }
+
   pub fn header(&self) -> &RichText { &self.header }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -1041,6 +1354,14 @@ pub struct RTDPageBlockDetailsBuilder {
 impl RTDPageBlockDetailsBuilder {
   pub fn build(&self) -> PageBlockDetails { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockDetails> {
+    let mut missing = vec![];
+    if self.inner.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.inner.header._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockDetails", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1107,6 +1428,11 @@ impl PageBlockDivider {
     RTDPageBlockDividerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockDivider", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1117,6 +1443,9 @@ pub struct RTDPageBlockDividerBuilder {
 impl RTDPageBlockDividerBuilder {
   pub fn build(&self) -> PageBlockDivider { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockDivider> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockDivider> for PageBlockDivider {
@@ -1178,7 +1507,18 @@ impl PageBlockEmbedded {
     let mut inner = PageBlockEmbedded::default();
     inner.td_name = "pageBlockEmbedded".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockEmbeddedBuilder { inner }
+    RTDPageBlockEmbeddedBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockEmbedded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.html.is_empty() { missing.push("html".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -1201,21 +1541,34 @@ impl PageBlockEmbedded {
 
 #[doc(hidden)]
 pub struct RTDPageBlockEmbeddedBuilder {
-  inner: PageBlockEmbedded
+  inner: PageBlockEmbedded,
+  set: [bool; 3]
 }
 
 impl RTDPageBlockEmbeddedBuilder {
   pub fn build(&self) -> PageBlockEmbedded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockEmbedded> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("html".to_string()); }
+    if !self.set[2] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockEmbedded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn html<T: AsRef<str>>(&mut self, html: T) -> &mut Self {
     self.inner.html = html.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1240,6 +1593,7 @@ impl RTDPageBlockEmbeddedBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -1312,7 +1666,18 @@ impl PageBlockEmbeddedPost {
     let mut inner = PageBlockEmbeddedPost::default();
     inner.td_name = "pageBlockEmbeddedPost".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockEmbeddedPostBuilder { inner }
+    RTDPageBlockEmbeddedPostBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockEmbeddedPost", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.author.is_empty() { missing.push("author".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -1331,21 +1696,34 @@ impl PageBlockEmbeddedPost {
 
 #[doc(hidden)]
 pub struct RTDPageBlockEmbeddedPostBuilder {
-  inner: PageBlockEmbeddedPost
+  inner: PageBlockEmbeddedPost,
+  set: [bool; 3]
 }
 
 impl RTDPageBlockEmbeddedPostBuilder {
   pub fn build(&self) -> PageBlockEmbeddedPost { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockEmbeddedPost> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("author".to_string()); }
+    if !self.set[2] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockEmbeddedPost", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn author<T: AsRef<str>>(&mut self, author: T) -> &mut Self {
     self.inner.author = author.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1370,6 +1748,7 @@ impl RTDPageBlockEmbeddedPostBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -1423,6 +1802,15 @@ impl PageBlockFooter {
     RTDPageBlockFooterBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockFooter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.footer._is_default() { missing.push("footer".to_string()); } else { missing.extend(crate::types::_common::missing_in("footer", self.footer._missing_fields())); }
+    missing
+  }
+
   pub fn footer(&self) -> &RichText { &self.footer }
 
 }
@@ -1435,6 +1823,14 @@ pub struct RTDPageBlockFooterBuilder {
 impl RTDPageBlockFooterBuilder {
   pub fn build(&self) -> PageBlockFooter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockFooter> {
+    let mut missing = vec![];
+    if self.inner.footer._is_default() { missing.push("footer".to_string()); } else { missing.extend(crate::types::_common::missing_in("footer", self.inner.footer._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockFooter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn footer<T: AsRef<RichText>>(&mut self, footer: T) -> &mut Self {
     self.inner.footer = footer.as_ref().clone();
@@ -1491,6 +1887,15 @@ impl PageBlockHeader {
     RTDPageBlockHeaderBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockHeader", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.header._missing_fields())); }
+    missing
+  }
+
   pub fn header(&self) -> &RichText { &self.header }
 
 }
@@ -1503,6 +1908,14 @@ pub struct RTDPageBlockHeaderBuilder {
 impl RTDPageBlockHeaderBuilder {
   pub fn build(&self) -> PageBlockHeader { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockHeader> {
+    let mut missing = vec![];
+    if self.inner.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.inner.header._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockHeader", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -1559,6 +1972,15 @@ impl PageBlockKicker {
     RTDPageBlockKickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockKicker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.kicker._is_default() { missing.push("kicker".to_string()); } else { missing.extend(crate::types::_common::missing_in("kicker", self.kicker._missing_fields())); }
+    missing
+  }
+
   pub fn kicker(&self) -> &RichText { &self.kicker }
 
 }
@@ -1571,6 +1993,14 @@ pub struct RTDPageBlockKickerBuilder {
 impl RTDPageBlockKickerBuilder {
   pub fn build(&self) -> PageBlockKicker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockKicker> {
+    let mut missing = vec![];
+    if self.inner.kicker._is_default() { missing.push("kicker".to_string()); } else { missing.extend(crate::types::_common::missing_in("kicker", self.inner.kicker._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockKicker", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn kicker<T: AsRef<RichText>>(&mut self, kicker: T) -> &mut Self {
     self.inner.kicker = kicker.as_ref().clone();
@@ -1627,6 +2057,11 @@ impl PageBlockList {
     RTDPageBlockListBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockList", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn items(&self) -> &Vec<PageBlockListItem> { &self.items }
 
 }
@@ -1639,6 +2074,9 @@ pub struct RTDPageBlockListBuilder {
 impl RTDPageBlockListBuilder {
   pub fn build(&self) -> PageBlockList { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockList> { Ok(self.build()) }
+
    
   pub fn items(&mut self, items: Vec<PageBlockListItem>) -> &mut Self {
     self.inner.items = items;
@@ -1700,7 +2138,20 @@ impl PageBlockMap {
     let mut inner = PageBlockMap::default();
     inner.td_name = "pageBlockMap".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockMapBuilder { inner }
+    RTDPageBlockMapBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockMap", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.zoom == 0 { missing.push("zoom".to_string()); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -1717,39 +2168,57 @@ impl PageBlockMap {
 
 #[doc(hidden)]
 pub struct RTDPageBlockMapBuilder {
-  inner: PageBlockMap
+  inner: PageBlockMap,
+  set: [bool; 5]
 }
 
 impl RTDPageBlockMapBuilder {
   pub fn build(&self) -> PageBlockMap { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockMap> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("zoom".to_string()); }
+    if !self.set[2] { missing.push("width".to_string()); }
+    if !self.set[3] { missing.push("height".to_string()); }
+    if !self.set[4] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockMap", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn zoom(&mut self, zoom: i64) -> &mut Self {
     self.inner.zoom = zoom;
+    self.set[1] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[2] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[3] = true;
     self
   }
 
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[4] = true;
     self
   }
 
@@ -1803,6 +2272,15 @@ impl PageBlockParagraph {
     RTDPageBlockParagraphBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockParagraph", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &RichText { &self.text }
 
 }
@@ -1815,6 +2293,14 @@ pub struct RTDPageBlockParagraphBuilder {
 impl RTDPageBlockParagraphBuilder {
   pub fn build(&self) -> PageBlockParagraph { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockParagraph> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockParagraph", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1872,7 +2358,17 @@ impl PageBlockPhoto {
     let mut inner = PageBlockPhoto::default();
     inner.td_name = "pageBlockPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockPhotoBuilder { inner }
+    RTDPageBlockPhotoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &Option<Photo> { &self.photo }
@@ -1885,12 +2381,22 @@ impl PageBlockPhoto {
 
 #[doc(hidden)]
 pub struct RTDPageBlockPhotoBuilder {
-  inner: PageBlockPhoto
+  inner: PageBlockPhoto,
+  set: [bool; 2]
 }
 
 impl RTDPageBlockPhotoBuilder {
   pub fn build(&self) -> PageBlockPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("pageBlockPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1900,12 +2406,14 @@ impl RTDPageBlockPhotoBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1958,7 +2466,17 @@ impl PageBlockPreformatted {
     let mut inner = PageBlockPreformatted::default();
     inner.td_name = "pageBlockPreformatted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockPreformattedBuilder { inner }
+    RTDPageBlockPreformattedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockPreformatted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.language.is_empty() { missing.push("language".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &RichText { &self.text }
@@ -1969,12 +2487,22 @@ impl PageBlockPreformatted {
 
 #[doc(hidden)]
 pub struct RTDPageBlockPreformattedBuilder {
-  inner: PageBlockPreformatted
+  inner: PageBlockPreformatted,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockPreformattedBuilder {
   pub fn build(&self) -> PageBlockPreformatted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockPreformatted> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("language".to_string()); }
+    crate::types::_common::check_missing("pageBlockPreformatted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1984,6 +2512,7 @@ impl RTDPageBlockPreformattedBuilder {
    
   pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
     self.inner.language = language.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2039,6 +2568,16 @@ impl PageBlockPullQuote {
     RTDPageBlockPullQuoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockPullQuote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.credit._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -2053,6 +2592,15 @@ pub struct RTDPageBlockPullQuoteBuilder {
 impl RTDPageBlockPullQuoteBuilder {
   pub fn build(&self) -> PageBlockPullQuote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockPullQuote> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if self.inner.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.inner.credit._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockPullQuote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -2117,6 +2665,15 @@ impl PageBlockRelatedArticles {
     RTDPageBlockRelatedArticlesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockRelatedArticles", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.header._missing_fields())); }
+    missing
+  }
+
   pub fn header(&self) -> &RichText { &self.header }
 
   pub fn articles(&self) -> &Vec<PageBlockRelatedArticle> { &self.articles }
@@ -2131,6 +2688,14 @@ pub struct RTDPageBlockRelatedArticlesBuilder {
 impl RTDPageBlockRelatedArticlesBuilder {
   pub fn build(&self) -> PageBlockRelatedArticles { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockRelatedArticles> {
+    let mut missing = vec![];
+    if self.inner.header._is_default() { missing.push("header".to_string()); } else { missing.extend(crate::types::_common::missing_in("header", self.inner.header._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockRelatedArticles", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn header<T: AsRef<RichText>>(&mut self, header: T) -> &mut Self {
     self.inner.header = header.as_ref().clone();
@@ -2192,7 +2757,16 @@ impl PageBlockSlideshow {
     let mut inner = PageBlockSlideshow::default();
     inner.td_name = "pageBlockSlideshow".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockSlideshowBuilder { inner }
+    RTDPageBlockSlideshowBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockSlideshow", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -2203,12 +2777,21 @@ impl PageBlockSlideshow {
 
 #[doc(hidden)]
 pub struct RTDPageBlockSlideshowBuilder {
-  inner: PageBlockSlideshow
+  inner: PageBlockSlideshow,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockSlideshowBuilder {
   pub fn build(&self) -> PageBlockSlideshow { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockSlideshow> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockSlideshow", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -2218,6 +2801,7 @@ impl RTDPageBlockSlideshowBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -2271,6 +2855,15 @@ impl PageBlockSubheader {
     RTDPageBlockSubheaderBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockSubheader", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.subheader._is_default() { missing.push("subheader".to_string()); } else { missing.extend(crate::types::_common::missing_in("subheader", self.subheader._missing_fields())); }
+    missing
+  }
+
   pub fn subheader(&self) -> &RichText { &self.subheader }
 
 }
@@ -2283,6 +2876,14 @@ pub struct RTDPageBlockSubheaderBuilder {
 impl RTDPageBlockSubheaderBuilder {
   pub fn build(&self) -> PageBlockSubheader { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockSubheader> {
+    let mut missing = vec![];
+    if self.inner.subheader._is_default() { missing.push("subheader".to_string()); } else { missing.extend(crate::types::_common::missing_in("subheader", self.inner.subheader._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockSubheader", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn subheader<T: AsRef<RichText>>(&mut self, subheader: T) -> &mut Self {
     self.inner.subheader = subheader.as_ref().clone();
@@ -2339,6 +2940,15 @@ impl PageBlockSubtitle {
     RTDPageBlockSubtitleBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockSubtitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.subtitle._is_default() { missing.push("subtitle".to_string()); } else { missing.extend(crate::types::_common::missing_in("subtitle", self.subtitle._missing_fields())); }
+    missing
+  }
+
   pub fn subtitle(&self) -> &RichText { &self.subtitle }
 
 }
@@ -2351,6 +2961,14 @@ pub struct RTDPageBlockSubtitleBuilder {
 impl RTDPageBlockSubtitleBuilder {
   pub fn build(&self) -> PageBlockSubtitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockSubtitle> {
+    let mut missing = vec![];
+    if self.inner.subtitle._is_default() { missing.push("subtitle".to_string()); } else { missing.extend(crate::types::_common::missing_in("subtitle", self.inner.subtitle._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockSubtitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn subtitle<T: AsRef<RichText>>(&mut self, subtitle: T) -> &mut Self {
     self.inner.subtitle = subtitle.as_ref().clone();
@@ -2413,6 +3031,15 @@ impl PageBlockTable {
     RTDPageBlockTableBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockTable", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.caption._is_default() { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
+  }
+
   pub fn caption(&self) -> &RichText { &self.caption }
 
   pub fn cells(&self) -> &Vec<Vec<PageBlockTableCell>> { &self.cells }
@@ -2431,6 +3058,14 @@ pub struct RTDPageBlockTableBuilder {
 impl RTDPageBlockTableBuilder {
   pub fn build(&self) -> PageBlockTable { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockTable> {
+    let mut missing = vec![];
+    if self.inner.caption._is_default() { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockTable", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn caption<T: AsRef<RichText>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
@@ -2505,6 +3140,15 @@ impl PageBlockTitle {
     RTDPageBlockTitleBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockTitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title._is_default() { missing.push("title".to_string()); } else { missing.extend(crate::types::_common::missing_in("title", self.title._missing_fields())); }
+    missing
+  }
+
   pub fn title(&self) -> &RichText { &self.title }
 
 }
@@ -2517,6 +3161,14 @@ pub struct RTDPageBlockTitleBuilder {
 impl RTDPageBlockTitleBuilder {
   pub fn build(&self) -> PageBlockTitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockTitle> {
+    let mut missing = vec![];
+    if self.inner.title._is_default() { missing.push("title".to_string()); } else { missing.extend(crate::types::_common::missing_in("title", self.inner.title._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockTitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<RichText>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().clone();
@@ -2576,7 +3228,16 @@ impl PageBlockVideo {
     let mut inner = PageBlockVideo::default();
     inner.td_name = "pageBlockVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockVideoBuilder { inner }
+    RTDPageBlockVideoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn video(&self) -> &Option<Video> { &self.video }
@@ -2591,12 +3252,21 @@ impl PageBlockVideo {
 
 #[doc(hidden)]
 pub struct RTDPageBlockVideoBuilder {
-  inner: PageBlockVideo
+  inner: PageBlockVideo,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockVideoBuilder {
   pub fn build(&self) -> PageBlockVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2606,6 +3276,7 @@ impl RTDPageBlockVideoBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -2670,7 +3341,16 @@ impl PageBlockVoiceNote {
     let mut inner = PageBlockVoiceNote::default();
     inner.td_name = "pageBlockVoiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockVoiceNoteBuilder { inner }
+    RTDPageBlockVoiceNoteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.caption._missing_fields())); }
+    missing
   }
 
   pub fn voice_note(&self) -> &Option<VoiceNote> { &self.voice_note }
@@ -2681,12 +3361,21 @@ impl PageBlockVoiceNote {
 
 #[doc(hidden)]
 pub struct RTDPageBlockVoiceNoteBuilder {
-  inner: PageBlockVoiceNote
+  inner: PageBlockVoiceNote,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockVoiceNoteBuilder {
   pub fn build(&self) -> PageBlockVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockVoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); } else { missing.extend(crate::types::_common::missing_in("caption", self.inner.caption._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockVoiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -2696,6 +3385,7 @@ impl RTDPageBlockVoiceNoteBuilder {
    
   pub fn caption<T: AsRef<PageBlockCaption>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
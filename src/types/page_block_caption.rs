@@ -39,6 +39,16 @@ impl PageBlockCaption {
     RTDPageBlockCaptionBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockCaption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.credit._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &RichText { &self.text }
 
   pub fn credit(&self) -> &RichText { &self.credit }
@@ -53,6 +63,15 @@ pub struct RTDPageBlockCaptionBuilder {
 impl RTDPageBlockCaptionBuilder {
   pub fn build(&self) -> PageBlockCaption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockCaption> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if self.inner.credit._is_default() { missing.push("credit".to_string()); } else { missing.extend(crate::types::_common::missing_in("credit", self.inner.credit._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockCaption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
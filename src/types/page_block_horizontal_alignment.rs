@@ -19,6 +19,9 @@ pub trait TDPageBlockHorizontalAlignment: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlockHorizontalAlignment {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The content should be center-aligned
   Center(PageBlockHorizontalAlignmentCenter),
   /// The content should be left-aligned
@@ -34,7 +37,6 @@ impl Default for PageBlockHorizontalAlignment {
 
 impl<'de> Deserialize<'de> for PageBlockHorizontalAlignment {
   fn deserialize<D>(deserializer: D) -> Result<PageBlockHorizontalAlignment, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PageBlockHorizontalAlignment,
       (pageBlockHorizontalAlignmentCenter, Center);
@@ -60,6 +62,7 @@ impl RObject for PageBlockHorizontalAlignment {
       PageBlockHorizontalAlignment::Center(t) => t.extra(),
       PageBlockHorizontalAlignment::Left(t) => t.extra(),
       PageBlockHorizontalAlignment::Right(t) => t.extra(),
+      PageBlockHorizontalAlignment::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for PageBlockHorizontalAlignment {
 impl PageBlockHorizontalAlignment {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockHorizontalAlignment::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PageBlockHorizontalAlignment::Center(t) => t._missing_fields(),
+      PageBlockHorizontalAlignment::Left(t) => t._missing_fields(),
+      PageBlockHorizontalAlignment::Right(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_center(&self) -> bool { if let PageBlockHorizontalAlignment::Center(_) = self { true } else { false } }
   pub fn is_left(&self) -> bool { if let PageBlockHorizontalAlignment::Left(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignment {
   fn as_ref(&self) -> &PageBlockHorizontalAlignment { self }
 }
 
+impl RVariant<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignmentCenter {
+  fn from_class(class: &PageBlockHorizontalAlignment) -> Option<&Self> { match class { PageBlockHorizontalAlignment::Center(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignmentLeft {
+  fn from_class(class: &PageBlockHorizontalAlignment) -> Option<&Self> { match class { PageBlockHorizontalAlignment::Left(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlockHorizontalAlignment> for PageBlockHorizontalAlignmentRight {
+  fn from_class(class: &PageBlockHorizontalAlignment) -> Option<&Self> { match class { PageBlockHorizontalAlignment::Right(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl PageBlockHorizontalAlignmentCenter {
     RTDPageBlockHorizontalAlignmentCenterBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockHorizontalAlignmentCenter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDPageBlockHorizontalAlignmentCenterBuilder {
 impl RTDPageBlockHorizontalAlignmentCenterBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentCenter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentCenter> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentCenter> for PageBlockHorizontalAlignmentCenter {
@@ -193,6 +224,11 @@ impl PageBlockHorizontalAlignmentLeft {
     RTDPageBlockHorizontalAlignmentLeftBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockHorizontalAlignmentLeft", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,9 @@ pub struct RTDPageBlockHorizontalAlignmentLeftBuilder {
 impl RTDPageBlockHorizontalAlignmentLeftBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentLeft { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentLeft> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentLeft> for PageBlockHorizontalAlignmentLeft {
@@ -251,6 +290,11 @@ impl PageBlockHorizontalAlignmentRight {
     RTDPageBlockHorizontalAlignmentRightBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockHorizontalAlignmentRight", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +305,9 @@ pub struct RTDPageBlockHorizontalAlignmentRightBuilder {
 impl RTDPageBlockHorizontalAlignmentRightBuilder {
   pub fn build(&self) -> PageBlockHorizontalAlignmentRight { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockHorizontalAlignmentRight> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockHorizontalAlignmentRight> for PageBlockHorizontalAlignmentRight {
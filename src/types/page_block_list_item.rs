@@ -36,7 +36,16 @@ impl PageBlockListItem {
     let mut inner = PageBlockListItem::default();
     inner.td_name = "pageBlockListItem".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockListItemBuilder { inner }
+    RTDPageBlockListItemBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockListItem", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.label.is_empty() { missing.push("label".to_string()); }
+    missing
   }
 
   pub fn label(&self) -> &String { &self.label }
@@ -47,15 +56,25 @@ impl PageBlockListItem {
 
 #[doc(hidden)]
 pub struct RTDPageBlockListItemBuilder {
-  inner: PageBlockListItem
+  inner: PageBlockListItem,
+  set: [bool; 1]
 }
 
 impl RTDPageBlockListItemBuilder {
   pub fn build(&self) -> PageBlockListItem { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockListItem> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("label".to_string()); }
+    crate::types::_common::check_missing("pageBlockListItem", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn label<T: AsRef<str>>(&mut self, label: T) -> &mut Self {
     self.inner.label = label.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
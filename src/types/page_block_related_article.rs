@@ -44,7 +44,17 @@ impl PageBlockRelatedArticle {
     let mut inner = PageBlockRelatedArticle::default();
     inner.td_name = "pageBlockRelatedArticle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockRelatedArticleBuilder { inner }
+    RTDPageBlockRelatedArticleBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockRelatedArticle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -63,15 +73,26 @@ impl PageBlockRelatedArticle {
 
 #[doc(hidden)]
 pub struct RTDPageBlockRelatedArticleBuilder {
-  inner: PageBlockRelatedArticle
+  inner: PageBlockRelatedArticle,
+  set: [bool; 2]
 }
 
 impl RTDPageBlockRelatedArticleBuilder {
   pub fn build(&self) -> PageBlockRelatedArticle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockRelatedArticle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("pageBlockRelatedArticle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -84,6 +105,7 @@ impl RTDPageBlockRelatedArticleBuilder {
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
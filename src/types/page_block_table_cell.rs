@@ -44,7 +44,19 @@ impl PageBlockTableCell {
     let mut inner = PageBlockTableCell::default();
     inner.td_name = "pageBlockTableCell".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPageBlockTableCellBuilder { inner }
+    RTDPageBlockTableCellBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockTableCell", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.colspan == 0 { missing.push("colspan".to_string()); }
+    if self.rowspan == 0 { missing.push("rowspan".to_string()); }
+    if self.align._is_default() { missing.push("align".to_string()); } else { missing.extend(crate::types::_common::missing_in("align", self.align._missing_fields())); }
+    if self.valign._is_default() { missing.push("valign".to_string()); } else { missing.extend(crate::types::_common::missing_in("valign", self.valign._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &Option<RichText> { &self.text }
@@ -63,12 +75,24 @@ impl PageBlockTableCell {
 
 #[doc(hidden)]
 pub struct RTDPageBlockTableCellBuilder {
-  inner: PageBlockTableCell
+  inner: PageBlockTableCell,
+  set: [bool; 2]
 }
 
 impl RTDPageBlockTableCellBuilder {
   pub fn build(&self) -> PageBlockTableCell { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockTableCell> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("colspan".to_string()); }
+    if !self.set[1] { missing.push("rowspan".to_string()); }
+    if self.inner.align._is_default() { missing.push("align".to_string()); } else { missing.extend(crate::types::_common::missing_in("align", self.inner.align._missing_fields())); }
+    if self.inner.valign._is_default() { missing.push("valign".to_string()); } else { missing.extend(crate::types::_common::missing_in("valign", self.inner.valign._missing_fields())); }
+    crate::types::_common::check_missing("pageBlockTableCell", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<RichText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = Some(text.as_ref().clone());
@@ -84,12 +108,14 @@ impl RTDPageBlockTableCellBuilder {
    
   pub fn colspan(&mut self, colspan: i64) -> &mut Self {
     self.inner.colspan = colspan;
+    self.set[0] = true;
     self
   }
 
    
   pub fn rowspan(&mut self, rowspan: i64) -> &mut Self {
     self.inner.rowspan = rowspan;
+    self.set[1] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDPageBlockVerticalAlignment: Debug + RObject {}
 #[serde(untagged)]
 pub enum PageBlockVerticalAlignment {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The content should be bottom-aligned
   Bottom(PageBlockVerticalAlignmentBottom),
   /// The content should be middle-aligned
@@ -34,7 +37,6 @@ impl Default for PageBlockVerticalAlignment {
 
 impl<'de> Deserialize<'de> for PageBlockVerticalAlignment {
   fn deserialize<D>(deserializer: D) -> Result<PageBlockVerticalAlignment, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PageBlockVerticalAlignment,
       (pageBlockVerticalAlignmentBottom, Bottom);
@@ -60,6 +62,7 @@ impl RObject for PageBlockVerticalAlignment {
       PageBlockVerticalAlignment::Bottom(t) => t.extra(),
       PageBlockVerticalAlignment::Middle(t) => t.extra(),
       PageBlockVerticalAlignment::Top(t) => t.extra(),
+      PageBlockVerticalAlignment::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for PageBlockVerticalAlignment {
 impl PageBlockVerticalAlignment {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PageBlockVerticalAlignment::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PageBlockVerticalAlignment::Bottom(t) => t._missing_fields(),
+      PageBlockVerticalAlignment::Middle(t) => t._missing_fields(),
+      PageBlockVerticalAlignment::Top(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_bottom(&self) -> bool { if let PageBlockVerticalAlignment::Bottom(_) = self { true } else { false } }
   pub fn is_middle(&self) -> bool { if let PageBlockVerticalAlignment::Middle(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<PageBlockVerticalAlignment> for PageBlockVerticalAlignment {
   fn as_ref(&self) -> &PageBlockVerticalAlignment { self }
 }
 
+impl RVariant<PageBlockVerticalAlignment> for PageBlockVerticalAlignmentBottom {
+  fn from_class(class: &PageBlockVerticalAlignment) -> Option<&Self> { match class { PageBlockVerticalAlignment::Bottom(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlockVerticalAlignment> for PageBlockVerticalAlignmentMiddle {
+  fn from_class(class: &PageBlockVerticalAlignment) -> Option<&Self> { match class { PageBlockVerticalAlignment::Middle(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PageBlockVerticalAlignment> for PageBlockVerticalAlignmentTop {
+  fn from_class(class: &PageBlockVerticalAlignment) -> Option<&Self> { match class { PageBlockVerticalAlignment::Top(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl PageBlockVerticalAlignmentBottom {
     RTDPageBlockVerticalAlignmentBottomBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockVerticalAlignmentBottom", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDPageBlockVerticalAlignmentBottomBuilder {
 impl RTDPageBlockVerticalAlignmentBottomBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentBottom { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentBottom> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentBottom> for PageBlockVerticalAlignmentBottom {
@@ -193,6 +224,11 @@ impl PageBlockVerticalAlignmentMiddle {
     RTDPageBlockVerticalAlignmentMiddleBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockVerticalAlignmentMiddle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,9 @@ pub struct RTDPageBlockVerticalAlignmentMiddleBuilder {
 impl RTDPageBlockVerticalAlignmentMiddleBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentMiddle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentMiddle> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentMiddle> for PageBlockVerticalAlignmentMiddle {
@@ -251,6 +290,11 @@ impl PageBlockVerticalAlignmentTop {
     RTDPageBlockVerticalAlignmentTopBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pageBlockVerticalAlignmentTop", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +305,9 @@ pub struct RTDPageBlockVerticalAlignmentTopBuilder {
 impl RTDPageBlockVerticalAlignmentTopBuilder {
   pub fn build(&self) -> PageBlockVerticalAlignmentTop { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PageBlockVerticalAlignmentTop> { Ok(self.build()) }
+
 }
 
 impl AsRef<PageBlockVerticalAlignmentTop> for PageBlockVerticalAlignmentTop {
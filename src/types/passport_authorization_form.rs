@@ -38,7 +38,16 @@ impl PassportAuthorizationForm {
     let mut inner = PassportAuthorizationForm::default();
     inner.td_name = "passportAuthorizationForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportAuthorizationFormBuilder { inner }
+    RTDPassportAuthorizationFormBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportAuthorizationForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -51,15 +60,25 @@ impl PassportAuthorizationForm {
 
 #[doc(hidden)]
 pub struct RTDPassportAuthorizationFormBuilder {
-  inner: PassportAuthorizationForm
+  inner: PassportAuthorizationForm,
+  set: [bool; 1]
 }
 
 impl RTDPassportAuthorizationFormBuilder {
   pub fn build(&self) -> PassportAuthorizationForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportAuthorizationForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    crate::types::_common::check_missing("passportAuthorizationForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
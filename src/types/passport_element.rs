@@ -19,8 +19,9 @@ pub trait TDPassportElement: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElement {
   #[doc(hidden)] _Default(()),
-  /// Returns one of the available Telegram Passport elements
-  GetPassportElement(GetPassportElement),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element containing the user's address
   Address(PassportElementAddress),
   /// A Telegram Passport element containing the user's bank statement
@@ -47,8 +48,6 @@ pub enum PassportElement {
   TemporaryRegistration(PassportElementTemporaryRegistration),
   /// A Telegram Passport element containing the user's utility bill
   UtilityBill(PassportElementUtilityBill),
-  /// Adds an element to the user's Telegram Passport. May return an error with a message "PHONE_VERIFICATION_NEEDED" or "EMAIL_VERIFICATION_NEEDED" if the chosen phone number or the chosen email address must be verified first
-  SetPassportElement(SetPassportElement),
 
 }
 
@@ -58,10 +57,8 @@ impl Default for PassportElement {
 
 impl<'de> Deserialize<'de> for PassportElement {
   fn deserialize<D>(deserializer: D) -> Result<PassportElement, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PassportElement,
-      (getPassportElement, GetPassportElement);
       (passportElementAddress, Address);
       (passportElementBankStatement, BankStatement);
       (passportElementDriverLicense, DriverLicense);
@@ -75,7 +72,6 @@ impl<'de> Deserialize<'de> for PassportElement {
       (passportElementRentalAgreement, RentalAgreement);
       (passportElementTemporaryRegistration, TemporaryRegistration);
       (passportElementUtilityBill, UtilityBill);
-      (setPassportElement, SetPassportElement);
 
     )(deserializer)
   }
@@ -84,7 +80,6 @@ impl<'de> Deserialize<'de> for PassportElement {
 impl RObject for PassportElement {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      PassportElement::GetPassportElement(t) => t.td_name(),
       PassportElement::Address(t) => t.td_name(),
       PassportElement::BankStatement(t) => t.td_name(),
       PassportElement::DriverLicense(t) => t.td_name(),
@@ -98,14 +93,12 @@ impl RObject for PassportElement {
       PassportElement::RentalAgreement(t) => t.td_name(),
       PassportElement::TemporaryRegistration(t) => t.td_name(),
       PassportElement::UtilityBill(t) => t.td_name(),
-      PassportElement::SetPassportElement(t) => t.td_name(),
 
       _ => "-1",
     }
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      PassportElement::GetPassportElement(t) => t.extra(),
       PassportElement::Address(t) => t.extra(),
       PassportElement::BankStatement(t) => t.extra(),
       PassportElement::DriverLicense(t) => t.extra(),
@@ -119,7 +112,7 @@ impl RObject for PassportElement {
       PassportElement::RentalAgreement(t) => t.extra(),
       PassportElement::TemporaryRegistration(t) => t.extra(),
       PassportElement::UtilityBill(t) => t.extra(),
-      PassportElement::SetPassportElement(t) => t.extra(),
+      PassportElement::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -130,8 +123,25 @@ impl RObject for PassportElement {
 impl PassportElement {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElement::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PassportElement::Address(t) => t._missing_fields(),
+      PassportElement::BankStatement(t) => t._missing_fields(),
+      PassportElement::DriverLicense(t) => t._missing_fields(),
+      PassportElement::EmailAddress(t) => t._missing_fields(),
+      PassportElement::IdentityCard(t) => t._missing_fields(),
+      PassportElement::InternalPassport(t) => t._missing_fields(),
+      PassportElement::Passport(t) => t._missing_fields(),
+      PassportElement::PassportRegistration(t) => t._missing_fields(),
+      PassportElement::PersonalDetails(t) => t._missing_fields(),
+      PassportElement::PhoneNumber(t) => t._missing_fields(),
+      PassportElement::RentalAgreement(t) => t._missing_fields(),
+      PassportElement::TemporaryRegistration(t) => t._missing_fields(),
+      PassportElement::UtilityBill(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_passport_element(&self) -> bool { if let PassportElement::GetPassportElement(_) = self { true } else { false } }
   pub fn is_address(&self) -> bool { if let PassportElement::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let PassportElement::BankStatement(_) = self { true } else { false } }
   pub fn is_driver_license(&self) -> bool { if let PassportElement::DriverLicense(_) = self { true } else { false } }
@@ -145,9 +155,7 @@ impl PassportElement {
   pub fn is_rental_agreement(&self) -> bool { if let PassportElement::RentalAgreement(_) = self { true } else { false } }
   pub fn is_temporary_registration(&self) -> bool { if let PassportElement::TemporaryRegistration(_) = self { true } else { false } }
   pub fn is_utility_bill(&self) -> bool { if let PassportElement::UtilityBill(_) = self { true } else { false } }
-  pub fn is_set_passport_element(&self) -> bool { if let PassportElement::SetPassportElement(_) = self { true } else { false } }
 
-  pub fn on_get_passport_element<F: FnOnce(&GetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::GetPassportElement(t) = self { fnc(t) }; self }
   pub fn on_address<F: FnOnce(&PassportElementAddress)>(&self, fnc: F) -> &Self { if let PassportElement::Address(t) = self { fnc(t) }; self }
   pub fn on_bank_statement<F: FnOnce(&PassportElementBankStatement)>(&self, fnc: F) -> &Self { if let PassportElement::BankStatement(t) = self { fnc(t) }; self }
   pub fn on_driver_license<F: FnOnce(&PassportElementDriverLicense)>(&self, fnc: F) -> &Self { if let PassportElement::DriverLicense(t) = self { fnc(t) }; self }
@@ -161,9 +169,7 @@ impl PassportElement {
   pub fn on_rental_agreement<F: FnOnce(&PassportElementRentalAgreement)>(&self, fnc: F) -> &Self { if let PassportElement::RentalAgreement(t) = self { fnc(t) }; self }
   pub fn on_temporary_registration<F: FnOnce(&PassportElementTemporaryRegistration)>(&self, fnc: F) -> &Self { if let PassportElement::TemporaryRegistration(t) = self { fnc(t) }; self }
   pub fn on_utility_bill<F: FnOnce(&PassportElementUtilityBill)>(&self, fnc: F) -> &Self { if let PassportElement::UtilityBill(t) = self { fnc(t) }; self }
-  pub fn on_set_passport_element<F: FnOnce(&SetPassportElement)>(&self, fnc: F) -> &Self { if let PassportElement::SetPassportElement(t) = self { fnc(t) }; self }
 
-  pub fn as_get_passport_element(&self) -> Option<&GetPassportElement> { if let PassportElement::GetPassportElement(t) = self { return Some(t) } None }
   pub fn as_address(&self) -> Option<&PassportElementAddress> { if let PassportElement::Address(t) = self { return Some(t) } None }
   pub fn as_bank_statement(&self) -> Option<&PassportElementBankStatement> { if let PassportElement::BankStatement(t) = self { return Some(t) } None }
   pub fn as_driver_license(&self) -> Option<&PassportElementDriverLicense> { if let PassportElement::DriverLicense(t) = self { return Some(t) } None }
@@ -177,12 +183,9 @@ impl PassportElement {
   pub fn as_rental_agreement(&self) -> Option<&PassportElementRentalAgreement> { if let PassportElement::RentalAgreement(t) = self { return Some(t) } None }
   pub fn as_temporary_registration(&self) -> Option<&PassportElementTemporaryRegistration> { if let PassportElement::TemporaryRegistration(t) = self { return Some(t) } None }
   pub fn as_utility_bill(&self) -> Option<&PassportElementUtilityBill> { if let PassportElement::UtilityBill(t) = self { return Some(t) } None }
-  pub fn as_set_passport_element(&self) -> Option<&SetPassportElement> { if let PassportElement::SetPassportElement(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_passport_element<T: AsRef<GetPassportElement>>(t: T) -> Self { PassportElement::GetPassportElement(t.as_ref().clone()) }
-
   pub fn address<T: AsRef<PassportElementAddress>>(t: T) -> Self { PassportElement::Address(t.as_ref().clone()) }
 
   pub fn bank_statement<T: AsRef<PassportElementBankStatement>>(t: T) -> Self { PassportElement::BankStatement(t.as_ref().clone()) }
@@ -209,14 +212,64 @@ impl PassportElement {
 
   pub fn utility_bill<T: AsRef<PassportElementUtilityBill>>(t: T) -> Self { PassportElement::UtilityBill(t.as_ref().clone()) }
 
-  pub fn set_passport_element<T: AsRef<SetPassportElement>>(t: T) -> Self { PassportElement::SetPassportElement(t.as_ref().clone()) }
-
 }
 
 impl AsRef<PassportElement> for PassportElement {
   fn as_ref(&self) -> &PassportElement { self }
 }
 
+impl RVariant<PassportElement> for PassportElementAddress {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::Address(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementBankStatement {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::BankStatement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementDriverLicense {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::DriverLicense(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementEmailAddress {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::EmailAddress(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementIdentityCard {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::IdentityCard(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementInternalPassport {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::InternalPassport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementPassport {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::Passport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementPassportRegistration {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::PassportRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementPersonalDetails {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::PersonalDetails(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementPhoneNumber {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::PhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementRentalAgreement {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::RentalAgreement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementTemporaryRegistration {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::TemporaryRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElement> for PassportElementUtilityBill {
+  fn from_class(class: &PassportElement) -> Option<&Self> { match class { PassportElement::UtilityBill(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -254,7 +307,16 @@ impl PassportElementAddress {
     let mut inner = PassportElementAddress::default();
     inner.td_name = "passportElementAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementAddressBuilder { inner }
+    RTDPassportElementAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("address".to_string()); } else { missing.extend(crate::types::_common::missing_in("address", self.address._missing_fields())); }
+    missing
   }
 
   pub fn address(&self) -> &Address { &self.address }
@@ -263,15 +325,25 @@ impl PassportElementAddress {
 
 #[doc(hidden)]
 pub struct RTDPassportElementAddressBuilder {
-  inner: PassportElementAddress
+  inner: PassportElementAddress,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementAddressBuilder {
   pub fn build(&self) -> PassportElementAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("address".to_string()); } else { missing.extend(crate::types::_common::missing_in("address", self.inner.address._missing_fields())); }
+    crate::types::_common::check_missing("passportElementAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn address<T: AsRef<Address>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -322,7 +394,16 @@ impl PassportElementBankStatement {
     let mut inner = PassportElementBankStatement::default();
     inner.td_name = "passportElementBankStatement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementBankStatementBuilder { inner }
+    RTDPassportElementBankStatementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementBankStatement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("bank_statement".to_string()); } else { missing.extend(crate::types::_common::missing_in("bank_statement", self.bank_statement._missing_fields())); }
+    missing
   }
 
   pub fn bank_statement(&self) -> &PersonalDocument { &self.bank_statement }
@@ -331,15 +412,25 @@ impl PassportElementBankStatement {
 
 #[doc(hidden)]
 pub struct RTDPassportElementBankStatementBuilder {
-  inner: PassportElementBankStatement
+  inner: PassportElementBankStatement,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementBankStatementBuilder {
   pub fn build(&self) -> PassportElementBankStatement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementBankStatement> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bank_statement".to_string()); } else { missing.extend(crate::types::_common::missing_in("bank_statement", self.inner.bank_statement._missing_fields())); }
+    crate::types::_common::check_missing("passportElementBankStatement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn bank_statement<T: AsRef<PersonalDocument>>(&mut self, bank_statement: T) -> &mut Self {
     self.inner.bank_statement = bank_statement.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -390,7 +481,16 @@ impl PassportElementDriverLicense {
     let mut inner = PassportElementDriverLicense::default();
     inner.td_name = "passportElementDriverLicense".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementDriverLicenseBuilder { inner }
+    RTDPassportElementDriverLicenseBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementDriverLicense", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("driver_license".to_string()); } else { missing.extend(crate::types::_common::missing_in("driver_license", self.driver_license._missing_fields())); }
+    missing
   }
 
   pub fn driver_license(&self) -> &IdentityDocument { &self.driver_license }
@@ -399,15 +499,25 @@ impl PassportElementDriverLicense {
 
 #[doc(hidden)]
 pub struct RTDPassportElementDriverLicenseBuilder {
-  inner: PassportElementDriverLicense
+  inner: PassportElementDriverLicense,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementDriverLicense { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementDriverLicense> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("driver_license".to_string()); } else { missing.extend(crate::types::_common::missing_in("driver_license", self.inner.driver_license._missing_fields())); }
+    crate::types::_common::check_missing("passportElementDriverLicense", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn driver_license<T: AsRef<IdentityDocument>>(&mut self, driver_license: T) -> &mut Self {
     self.inner.driver_license = driver_license.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -458,7 +568,16 @@ impl PassportElementEmailAddress {
     let mut inner = PassportElementEmailAddress::default();
     inner.td_name = "passportElementEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementEmailAddressBuilder { inner }
+    RTDPassportElementEmailAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.email_address.is_empty() { missing.push("email_address".to_string()); }
+    missing
   }
 
   pub fn email_address(&self) -> &String { &self.email_address }
@@ -467,15 +586,25 @@ impl PassportElementEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDPassportElementEmailAddressBuilder {
-  inner: PassportElementEmailAddress
+  inner: PassportElementEmailAddress,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementEmailAddressBuilder {
   pub fn build(&self) -> PassportElementEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementEmailAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("email_address".to_string()); }
+    crate::types::_common::check_missing("passportElementEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -526,7 +655,16 @@ impl PassportElementIdentityCard {
     let mut inner = PassportElementIdentityCard::default();
     inner.td_name = "passportElementIdentityCard".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementIdentityCardBuilder { inner }
+    RTDPassportElementIdentityCardBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementIdentityCard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("identity_card".to_string()); } else { missing.extend(crate::types::_common::missing_in("identity_card", self.identity_card._missing_fields())); }
+    missing
   }
 
   pub fn identity_card(&self) -> &IdentityDocument { &self.identity_card }
@@ -535,15 +673,25 @@ impl PassportElementIdentityCard {
 
 #[doc(hidden)]
 pub struct RTDPassportElementIdentityCardBuilder {
-  inner: PassportElementIdentityCard
+  inner: PassportElementIdentityCard,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementIdentityCardBuilder {
   pub fn build(&self) -> PassportElementIdentityCard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementIdentityCard> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("identity_card".to_string()); } else { missing.extend(crate::types::_common::missing_in("identity_card", self.inner.identity_card._missing_fields())); }
+    crate::types::_common::check_missing("passportElementIdentityCard", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn identity_card<T: AsRef<IdentityDocument>>(&mut self, identity_card: T) -> &mut Self {
     self.inner.identity_card = identity_card.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -594,7 +742,16 @@ impl PassportElementInternalPassport {
     let mut inner = PassportElementInternalPassport::default();
     inner.td_name = "passportElementInternalPassport".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementInternalPassportBuilder { inner }
+    RTDPassportElementInternalPassportBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementInternalPassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("internal_passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("internal_passport", self.internal_passport._missing_fields())); }
+    missing
   }
 
   pub fn internal_passport(&self) -> &IdentityDocument { &self.internal_passport }
@@ -603,15 +760,25 @@ impl PassportElementInternalPassport {
 
 #[doc(hidden)]
 pub struct RTDPassportElementInternalPassportBuilder {
-  inner: PassportElementInternalPassport
+  inner: PassportElementInternalPassport,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementInternalPassportBuilder {
   pub fn build(&self) -> PassportElementInternalPassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementInternalPassport> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("internal_passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("internal_passport", self.inner.internal_passport._missing_fields())); }
+    crate::types::_common::check_missing("passportElementInternalPassport", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn internal_passport<T: AsRef<IdentityDocument>>(&mut self, internal_passport: T) -> &mut Self {
     self.inner.internal_passport = internal_passport.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -662,7 +829,16 @@ impl PassportElementPassport {
     let mut inner = PassportElementPassport::default();
     inner.td_name = "passportElementPassport".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementPassportBuilder { inner }
+    RTDPassportElementPassportBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementPassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport", self.passport._missing_fields())); }
+    missing
   }
 
   pub fn passport(&self) -> &IdentityDocument { &self.passport }
@@ -671,15 +847,25 @@ impl PassportElementPassport {
 
 #[doc(hidden)]
 pub struct RTDPassportElementPassportBuilder {
-  inner: PassportElementPassport
+  inner: PassportElementPassport,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementPassportBuilder {
   pub fn build(&self) -> PassportElementPassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementPassport> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("passport".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport", self.inner.passport._missing_fields())); }
+    crate::types::_common::check_missing("passportElementPassport", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn passport<T: AsRef<IdentityDocument>>(&mut self, passport: T) -> &mut Self {
     self.inner.passport = passport.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -730,7 +916,16 @@ impl PassportElementPassportRegistration {
     let mut inner = PassportElementPassportRegistration::default();
     inner.td_name = "passportElementPassportRegistration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementPassportRegistrationBuilder { inner }
+    RTDPassportElementPassportRegistrationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementPassportRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("passport_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport_registration", self.passport_registration._missing_fields())); }
+    missing
   }
 
   pub fn passport_registration(&self) -> &PersonalDocument { &self.passport_registration }
@@ -739,15 +934,25 @@ impl PassportElementPassportRegistration {
 
 #[doc(hidden)]
 pub struct RTDPassportElementPassportRegistrationBuilder {
-  inner: PassportElementPassportRegistration
+  inner: PassportElementPassportRegistration,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementPassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementPassportRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementPassportRegistration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("passport_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("passport_registration", self.inner.passport_registration._missing_fields())); }
+    crate::types::_common::check_missing("passportElementPassportRegistration", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn passport_registration<T: AsRef<PersonalDocument>>(&mut self, passport_registration: T) -> &mut Self {
     self.inner.passport_registration = passport_registration.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -798,7 +1003,16 @@ impl PassportElementPersonalDetails {
     let mut inner = PassportElementPersonalDetails::default();
     inner.td_name = "passportElementPersonalDetails".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementPersonalDetailsBuilder { inner }
+    RTDPassportElementPersonalDetailsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementPersonalDetails", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("personal_details".to_string()); } else { missing.extend(crate::types::_common::missing_in("personal_details", self.personal_details._missing_fields())); }
+    missing
   }
 
   pub fn personal_details(&self) -> &PersonalDetails { &self.personal_details }
@@ -807,15 +1021,25 @@ impl PassportElementPersonalDetails {
 
 #[doc(hidden)]
 pub struct RTDPassportElementPersonalDetailsBuilder {
-  inner: PassportElementPersonalDetails
+  inner: PassportElementPersonalDetails,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementPersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementPersonalDetails { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementPersonalDetails> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("personal_details".to_string()); } else { missing.extend(crate::types::_common::missing_in("personal_details", self.inner.personal_details._missing_fields())); }
+    crate::types::_common::check_missing("passportElementPersonalDetails", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn personal_details<T: AsRef<PersonalDetails>>(&mut self, personal_details: T) -> &mut Self {
     self.inner.personal_details = personal_details.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -866,7 +1090,16 @@ impl PassportElementPhoneNumber {
     let mut inner = PassportElementPhoneNumber::default();
     inner.td_name = "passportElementPhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementPhoneNumberBuilder { inner }
+    RTDPassportElementPhoneNumberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    missing
   }
 
   pub fn phone_number(&self) -> &String { &self.phone_number }
@@ -875,15 +1108,25 @@ impl PassportElementPhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDPassportElementPhoneNumberBuilder {
-  inner: PassportElementPhoneNumber
+  inner: PassportElementPhoneNumber,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementPhoneNumberBuilder {
   pub fn build(&self) -> PassportElementPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementPhoneNumber> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    crate::types::_common::check_missing("passportElementPhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -934,7 +1177,16 @@ impl PassportElementRentalAgreement {
     let mut inner = PassportElementRentalAgreement::default();
     inner.td_name = "passportElementRentalAgreement".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementRentalAgreementBuilder { inner }
+    RTDPassportElementRentalAgreementBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementRentalAgreement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("rental_agreement".to_string()); } else { missing.extend(crate::types::_common::missing_in("rental_agreement", self.rental_agreement._missing_fields())); }
+    missing
   }
 
   pub fn rental_agreement(&self) -> &PersonalDocument { &self.rental_agreement }
@@ -943,15 +1195,25 @@ impl PassportElementRentalAgreement {
 
 #[doc(hidden)]
 pub struct RTDPassportElementRentalAgreementBuilder {
-  inner: PassportElementRentalAgreement
+  inner: PassportElementRentalAgreement,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementRentalAgreement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementRentalAgreement> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("rental_agreement".to_string()); } else { missing.extend(crate::types::_common::missing_in("rental_agreement", self.inner.rental_agreement._missing_fields())); }
+    crate::types::_common::check_missing("passportElementRentalAgreement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn rental_agreement<T: AsRef<PersonalDocument>>(&mut self, rental_agreement: T) -> &mut Self {
     self.inner.rental_agreement = rental_agreement.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1002,7 +1264,16 @@ impl PassportElementTemporaryRegistration {
     let mut inner = PassportElementTemporaryRegistration::default();
     inner.td_name = "passportElementTemporaryRegistration".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementTemporaryRegistrationBuilder { inner }
+    RTDPassportElementTemporaryRegistrationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTemporaryRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("temporary_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("temporary_registration", self.temporary_registration._missing_fields())); }
+    missing
   }
 
   pub fn temporary_registration(&self) -> &PersonalDocument { &self.temporary_registration }
@@ -1011,15 +1282,25 @@ impl PassportElementTemporaryRegistration {
 
 #[doc(hidden)]
 pub struct RTDPassportElementTemporaryRegistrationBuilder {
-  inner: PassportElementTemporaryRegistration
+  inner: PassportElementTemporaryRegistration,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTemporaryRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTemporaryRegistration> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("temporary_registration".to_string()); } else { missing.extend(crate::types::_common::missing_in("temporary_registration", self.inner.temporary_registration._missing_fields())); }
+    crate::types::_common::check_missing("passportElementTemporaryRegistration", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn temporary_registration<T: AsRef<PersonalDocument>>(&mut self, temporary_registration: T) -> &mut Self {
     self.inner.temporary_registration = temporary_registration.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1070,7 +1351,16 @@ impl PassportElementUtilityBill {
     let mut inner = PassportElementUtilityBill::default();
     inner.td_name = "passportElementUtilityBill".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementUtilityBillBuilder { inner }
+    RTDPassportElementUtilityBillBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementUtilityBill", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("utility_bill".to_string()); } else { missing.extend(crate::types::_common::missing_in("utility_bill", self.utility_bill._missing_fields())); }
+    missing
   }
 
   pub fn utility_bill(&self) -> &PersonalDocument { &self.utility_bill }
@@ -1079,15 +1369,25 @@ impl PassportElementUtilityBill {
 
 #[doc(hidden)]
 pub struct RTDPassportElementUtilityBillBuilder {
-  inner: PassportElementUtilityBill
+  inner: PassportElementUtilityBill,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementUtilityBillBuilder {
   pub fn build(&self) -> PassportElementUtilityBill { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementUtilityBill> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("utility_bill".to_string()); } else { missing.extend(crate::types::_common::missing_in("utility_bill", self.inner.utility_bill._missing_fields())); }
+    crate::types::_common::check_missing("passportElementUtilityBill", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn utility_bill<T: AsRef<PersonalDocument>>(&mut self, utility_bill: T) -> &mut Self {
     self.inner.utility_bill = utility_bill.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
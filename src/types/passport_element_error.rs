@@ -38,7 +38,18 @@ impl PassportElementError {
     let mut inner = PassportElementError::default();
     inner.td_name = "passportElementError".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementErrorBuilder { inner }
+    RTDPassportElementErrorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementError", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.message.is_empty() { missing.push("message".to_string()); }
+    if self.source._is_default() { missing.push("source".to_string()); } else { missing.extend(crate::types::_common::missing_in("source", self.source._missing_fields())); }
+    missing
   }
 
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
@@ -51,12 +62,23 @@ impl PassportElementError {
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorBuilder {
-  inner: PassportElementError
+  inner: PassportElementError,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementErrorBuilder {
   pub fn build(&self) -> PassportElementError { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementError> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[0] { missing.push("message".to_string()); }
+    if self.inner.source._is_default() { missing.push("source".to_string()); } else { missing.extend(crate::types::_common::missing_in("source", self.inner.source._missing_fields())); }
+    crate::types::_common::check_missing("passportElementError", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
@@ -66,6 +88,7 @@ impl RTDPassportElementErrorBuilder {
    
   pub fn message<T: AsRef<str>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDPassportElementErrorSource: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElementErrorSource {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// One of the data fields contains an error. The error will be considered resolved when the value of the field changes
   DataField(PassportElementErrorSourceDataField),
   /// The file contains an error. The error will be considered resolved when the file changes
@@ -46,7 +49,6 @@ impl Default for PassportElementErrorSource {
 
 impl<'de> Deserialize<'de> for PassportElementErrorSource {
   fn deserialize<D>(deserializer: D) -> Result<PassportElementErrorSource, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PassportElementErrorSource,
       (passportElementErrorSourceDataField, DataField);
@@ -90,6 +92,7 @@ impl RObject for PassportElementErrorSource {
       PassportElementErrorSource::TranslationFile(t) => t.extra(),
       PassportElementErrorSource::TranslationFiles(t) => t.extra(),
       PassportElementErrorSource::Unspecified(t) => t.extra(),
+      PassportElementErrorSource::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -100,6 +103,20 @@ impl RObject for PassportElementErrorSource {
 impl PassportElementErrorSource {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementErrorSource::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PassportElementErrorSource::DataField(t) => t._missing_fields(),
+      PassportElementErrorSource::File(t) => t._missing_fields(),
+      PassportElementErrorSource::Files(t) => t._missing_fields(),
+      PassportElementErrorSource::FrontSide(t) => t._missing_fields(),
+      PassportElementErrorSource::ReverseSide(t) => t._missing_fields(),
+      PassportElementErrorSource::Selfie(t) => t._missing_fields(),
+      PassportElementErrorSource::TranslationFile(t) => t._missing_fields(),
+      PassportElementErrorSource::TranslationFiles(t) => t._missing_fields(),
+      PassportElementErrorSource::Unspecified(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_data_field(&self) -> bool { if let PassportElementErrorSource::DataField(_) = self { true } else { false } }
   pub fn is_file(&self) -> bool { if let PassportElementErrorSource::File(_) = self { true } else { false } }
@@ -157,6 +174,42 @@ impl AsRef<PassportElementErrorSource> for PassportElementErrorSource {
   fn as_ref(&self) -> &PassportElementErrorSource { self }
 }
 
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceDataField {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::DataField(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceFile {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::File(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceFiles {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::Files(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceFrontSide {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::FrontSide(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceReverseSide {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::ReverseSide(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceSelfie {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::Selfie(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceTranslationFile {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::TranslationFile(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceTranslationFiles {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::TranslationFiles(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementErrorSource> for PassportElementErrorSourceUnspecified {
+  fn from_class(class: &PassportElementErrorSource) -> Option<&Self> { match class { PassportElementErrorSource::Unspecified(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -194,7 +247,16 @@ impl PassportElementErrorSourceDataField {
     let mut inner = PassportElementErrorSourceDataField::default();
     inner.td_name = "passportElementErrorSourceDataField".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementErrorSourceDataFieldBuilder { inner }
+    RTDPassportElementErrorSourceDataFieldBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceDataField", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.field_name.is_empty() { missing.push("field_name".to_string()); }
+    missing
   }
 
   pub fn field_name(&self) -> &String { &self.field_name }
@@ -203,15 +265,25 @@ impl PassportElementErrorSourceDataField {
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceDataFieldBuilder {
-  inner: PassportElementErrorSourceDataField
+  inner: PassportElementErrorSourceDataField,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementErrorSourceDataFieldBuilder {
   pub fn build(&self) -> PassportElementErrorSourceDataField { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceDataField> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("field_name".to_string()); }
+    crate::types::_common::check_missing("passportElementErrorSourceDataField", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn field_name<T: AsRef<str>>(&mut self, field_name: T) -> &mut Self {
     self.inner.field_name = field_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -262,7 +334,16 @@ impl PassportElementErrorSourceFile {
     let mut inner = PassportElementErrorSourceFile::default();
     inner.td_name = "passportElementErrorSourceFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementErrorSourceFileBuilder { inner }
+    RTDPassportElementErrorSourceFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_index == 0 { missing.push("file_index".to_string()); }
+    missing
   }
 
   pub fn file_index(&self) -> i64 { self.file_index }
@@ -271,15 +352,25 @@ impl PassportElementErrorSourceFile {
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceFileBuilder {
-  inner: PassportElementErrorSourceFile
+  inner: PassportElementErrorSourceFile,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementErrorSourceFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_index".to_string()); }
+    crate::types::_common::check_missing("passportElementErrorSourceFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
+    self.set[0] = true;
     self
   }
 
@@ -331,6 +422,11 @@ impl PassportElementErrorSourceFiles {
     RTDPassportElementErrorSourceFilesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceFiles", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -341,6 +437,9 @@ pub struct RTDPassportElementErrorSourceFilesBuilder {
 impl RTDPassportElementErrorSourceFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFiles { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFiles> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceFiles> for PassportElementErrorSourceFiles {
@@ -389,6 +488,11 @@ impl PassportElementErrorSourceFrontSide {
     RTDPassportElementErrorSourceFrontSideBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceFrontSide", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -399,6 +503,9 @@ pub struct RTDPassportElementErrorSourceFrontSideBuilder {
 impl RTDPassportElementErrorSourceFrontSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceFrontSide { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceFrontSide> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceFrontSide> for PassportElementErrorSourceFrontSide {
@@ -447,6 +554,11 @@ impl PassportElementErrorSourceReverseSide {
     RTDPassportElementErrorSourceReverseSideBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceReverseSide", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -457,6 +569,9 @@ pub struct RTDPassportElementErrorSourceReverseSideBuilder {
 impl RTDPassportElementErrorSourceReverseSideBuilder {
   pub fn build(&self) -> PassportElementErrorSourceReverseSide { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceReverseSide> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceReverseSide> for PassportElementErrorSourceReverseSide {
@@ -505,6 +620,11 @@ impl PassportElementErrorSourceSelfie {
     RTDPassportElementErrorSourceSelfieBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceSelfie", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -515,6 +635,9 @@ pub struct RTDPassportElementErrorSourceSelfieBuilder {
 impl RTDPassportElementErrorSourceSelfieBuilder {
   pub fn build(&self) -> PassportElementErrorSourceSelfie { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceSelfie> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceSelfie> for PassportElementErrorSourceSelfie {
@@ -562,7 +685,16 @@ impl PassportElementErrorSourceTranslationFile {
     let mut inner = PassportElementErrorSourceTranslationFile::default();
     inner.td_name = "passportElementErrorSourceTranslationFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPassportElementErrorSourceTranslationFileBuilder { inner }
+    RTDPassportElementErrorSourceTranslationFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceTranslationFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_index == 0 { missing.push("file_index".to_string()); }
+    missing
   }
 
   pub fn file_index(&self) -> i64 { self.file_index }
@@ -571,15 +703,25 @@ impl PassportElementErrorSourceTranslationFile {
 
 #[doc(hidden)]
 pub struct RTDPassportElementErrorSourceTranslationFileBuilder {
-  inner: PassportElementErrorSourceTranslationFile
+  inner: PassportElementErrorSourceTranslationFile,
+  set: [bool; 1]
 }
 
 impl RTDPassportElementErrorSourceTranslationFileBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceTranslationFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file_index".to_string()); }
+    crate::types::_common::check_missing("passportElementErrorSourceTranslationFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_index(&mut self, file_index: i64) -> &mut Self {
     self.inner.file_index = file_index;
+    self.set[0] = true;
     self
   }
 
@@ -631,6 +773,11 @@ impl PassportElementErrorSourceTranslationFiles {
     RTDPassportElementErrorSourceTranslationFilesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceTranslationFiles", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -641,6 +788,9 @@ pub struct RTDPassportElementErrorSourceTranslationFilesBuilder {
 impl RTDPassportElementErrorSourceTranslationFilesBuilder {
   pub fn build(&self) -> PassportElementErrorSourceTranslationFiles { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceTranslationFiles> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceTranslationFiles> for PassportElementErrorSourceTranslationFiles {
@@ -689,6 +839,11 @@ impl PassportElementErrorSourceUnspecified {
     RTDPassportElementErrorSourceUnspecifiedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementErrorSourceUnspecified", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -699,6 +854,9 @@ pub struct RTDPassportElementErrorSourceUnspecifiedBuilder {
 impl RTDPassportElementErrorSourceUnspecifiedBuilder {
   pub fn build(&self) -> PassportElementErrorSourceUnspecified { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementErrorSourceUnspecified> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementErrorSourceUnspecified> for PassportElementErrorSourceUnspecified {
@@ -19,6 +19,9 @@ pub trait TDPassportElementType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PassportElementType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A Telegram Passport element containing the user's address
   Address(PassportElementTypeAddress),
   /// A Telegram Passport element containing the user's bank statement
@@ -54,7 +57,6 @@ impl Default for PassportElementType {
 
 impl<'de> Deserialize<'de> for PassportElementType {
   fn deserialize<D>(deserializer: D) -> Result<PassportElementType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PassportElementType,
       (passportElementTypeAddress, Address);
@@ -110,6 +112,7 @@ impl RObject for PassportElementType {
       PassportElementType::RentalAgreement(t) => t.extra(),
       PassportElementType::TemporaryRegistration(t) => t.extra(),
       PassportElementType::UtilityBill(t) => t.extra(),
+      PassportElementType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -120,6 +123,24 @@ impl RObject for PassportElementType {
 impl PassportElementType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PassportElementType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PassportElementType::Address(t) => t._missing_fields(),
+      PassportElementType::BankStatement(t) => t._missing_fields(),
+      PassportElementType::DriverLicense(t) => t._missing_fields(),
+      PassportElementType::EmailAddress(t) => t._missing_fields(),
+      PassportElementType::IdentityCard(t) => t._missing_fields(),
+      PassportElementType::InternalPassport(t) => t._missing_fields(),
+      PassportElementType::Passport(t) => t._missing_fields(),
+      PassportElementType::PassportRegistration(t) => t._missing_fields(),
+      PassportElementType::PersonalDetails(t) => t._missing_fields(),
+      PassportElementType::PhoneNumber(t) => t._missing_fields(),
+      PassportElementType::RentalAgreement(t) => t._missing_fields(),
+      PassportElementType::TemporaryRegistration(t) => t._missing_fields(),
+      PassportElementType::UtilityBill(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_address(&self) -> bool { if let PassportElementType::Address(_) = self { true } else { false } }
   pub fn is_bank_statement(&self) -> bool { if let PassportElementType::BankStatement(_) = self { true } else { false } }
@@ -197,6 +218,58 @@ impl AsRef<PassportElementType> for PassportElementType {
   fn as_ref(&self) -> &PassportElementType { self }
 }
 
+impl RVariant<PassportElementType> for PassportElementTypeAddress {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::Address(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeBankStatement {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::BankStatement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeDriverLicense {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::DriverLicense(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeEmailAddress {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::EmailAddress(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeIdentityCard {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::IdentityCard(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeInternalPassport {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::InternalPassport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypePassport {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::Passport(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypePassportRegistration {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::PassportRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypePersonalDetails {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::PersonalDetails(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypePhoneNumber {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::PhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeRentalAgreement {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::RentalAgreement(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeTemporaryRegistration {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::TemporaryRegistration(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PassportElementType> for PassportElementTypeUtilityBill {
+  fn from_class(class: &PassportElementType) -> Option<&Self> { match class { PassportElementType::UtilityBill(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -235,6 +308,11 @@ impl PassportElementTypeAddress {
     RTDPassportElementTypeAddressBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -245,6 +323,9 @@ pub struct RTDPassportElementTypeAddressBuilder {
 impl RTDPassportElementTypeAddressBuilder {
   pub fn build(&self) -> PassportElementTypeAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeAddress> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeAddress> for PassportElementTypeAddress {
@@ -293,6 +374,11 @@ impl PassportElementTypeBankStatement {
     RTDPassportElementTypeBankStatementBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeBankStatement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -303,6 +389,9 @@ pub struct RTDPassportElementTypeBankStatementBuilder {
 impl RTDPassportElementTypeBankStatementBuilder {
   pub fn build(&self) -> PassportElementTypeBankStatement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeBankStatement> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeBankStatement> for PassportElementTypeBankStatement {
@@ -351,6 +440,11 @@ impl PassportElementTypeDriverLicense {
     RTDPassportElementTypeDriverLicenseBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeDriverLicense", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -361,6 +455,9 @@ pub struct RTDPassportElementTypeDriverLicenseBuilder {
 impl RTDPassportElementTypeDriverLicenseBuilder {
   pub fn build(&self) -> PassportElementTypeDriverLicense { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeDriverLicense> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeDriverLicense> for PassportElementTypeDriverLicense {
@@ -409,6 +506,11 @@ impl PassportElementTypeEmailAddress {
     RTDPassportElementTypeEmailAddressBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -419,6 +521,9 @@ pub struct RTDPassportElementTypeEmailAddressBuilder {
 impl RTDPassportElementTypeEmailAddressBuilder {
   pub fn build(&self) -> PassportElementTypeEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeEmailAddress> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeEmailAddress> for PassportElementTypeEmailAddress {
@@ -467,6 +572,11 @@ impl PassportElementTypeIdentityCard {
     RTDPassportElementTypeIdentityCardBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeIdentityCard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -477,6 +587,9 @@ pub struct RTDPassportElementTypeIdentityCardBuilder {
 impl RTDPassportElementTypeIdentityCardBuilder {
   pub fn build(&self) -> PassportElementTypeIdentityCard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeIdentityCard> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeIdentityCard> for PassportElementTypeIdentityCard {
@@ -525,6 +638,11 @@ impl PassportElementTypeInternalPassport {
     RTDPassportElementTypeInternalPassportBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeInternalPassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -535,6 +653,9 @@ pub struct RTDPassportElementTypeInternalPassportBuilder {
 impl RTDPassportElementTypeInternalPassportBuilder {
   pub fn build(&self) -> PassportElementTypeInternalPassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeInternalPassport> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeInternalPassport> for PassportElementTypeInternalPassport {
@@ -583,6 +704,11 @@ impl PassportElementTypePassport {
     RTDPassportElementTypePassportBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypePassport", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -593,6 +719,9 @@ pub struct RTDPassportElementTypePassportBuilder {
 impl RTDPassportElementTypePassportBuilder {
   pub fn build(&self) -> PassportElementTypePassport { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypePassport> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypePassport> for PassportElementTypePassport {
@@ -641,6 +770,11 @@ impl PassportElementTypePassportRegistration {
     RTDPassportElementTypePassportRegistrationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypePassportRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -651,6 +785,9 @@ pub struct RTDPassportElementTypePassportRegistrationBuilder {
 impl RTDPassportElementTypePassportRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypePassportRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypePassportRegistration> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypePassportRegistration> for PassportElementTypePassportRegistration {
@@ -699,6 +836,11 @@ impl PassportElementTypePersonalDetails {
     RTDPassportElementTypePersonalDetailsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypePersonalDetails", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -709,6 +851,9 @@ pub struct RTDPassportElementTypePersonalDetailsBuilder {
 impl RTDPassportElementTypePersonalDetailsBuilder {
   pub fn build(&self) -> PassportElementTypePersonalDetails { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypePersonalDetails> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypePersonalDetails> for PassportElementTypePersonalDetails {
@@ -757,6 +902,11 @@ impl PassportElementTypePhoneNumber {
     RTDPassportElementTypePhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypePhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -767,6 +917,9 @@ pub struct RTDPassportElementTypePhoneNumberBuilder {
 impl RTDPassportElementTypePhoneNumberBuilder {
   pub fn build(&self) -> PassportElementTypePhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypePhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypePhoneNumber> for PassportElementTypePhoneNumber {
@@ -815,6 +968,11 @@ impl PassportElementTypeRentalAgreement {
     RTDPassportElementTypeRentalAgreementBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeRentalAgreement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -825,6 +983,9 @@ pub struct RTDPassportElementTypeRentalAgreementBuilder {
 impl RTDPassportElementTypeRentalAgreementBuilder {
   pub fn build(&self) -> PassportElementTypeRentalAgreement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeRentalAgreement> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeRentalAgreement> for PassportElementTypeRentalAgreement {
@@ -873,6 +1034,11 @@ impl PassportElementTypeTemporaryRegistration {
     RTDPassportElementTypeTemporaryRegistrationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeTemporaryRegistration", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -883,6 +1049,9 @@ pub struct RTDPassportElementTypeTemporaryRegistrationBuilder {
 impl RTDPassportElementTypeTemporaryRegistrationBuilder {
   pub fn build(&self) -> PassportElementTypeTemporaryRegistration { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeTemporaryRegistration> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeTemporaryRegistration> for PassportElementTypeTemporaryRegistration {
@@ -931,6 +1100,11 @@ impl PassportElementTypeUtilityBill {
     RTDPassportElementTypeUtilityBillBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementTypeUtilityBill", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -941,6 +1115,9 @@ pub struct RTDPassportElementTypeUtilityBillBuilder {
 impl RTDPassportElementTypeUtilityBillBuilder {
   pub fn build(&self) -> PassportElementTypeUtilityBill { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementTypeUtilityBill> { Ok(self.build()) }
+
 }
 
 impl AsRef<PassportElementTypeUtilityBill> for PassportElementTypeUtilityBill {
@@ -37,6 +37,11 @@ impl PassportElements {
     RTDPassportElementsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElements", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn elements(&self) -> &Vec<PassportElement> { &self.elements }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDPassportElementsBuilder {
 impl RTDPassportElementsBuilder {
   pub fn build(&self) -> PassportElements { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElements> { Ok(self.build()) }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
@@ -39,6 +39,11 @@ impl PassportElementsWithErrors {
     RTDPassportElementsWithErrorsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportElementsWithErrors", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn elements(&self) -> &Vec<PassportElement> { &self.elements }
 
   pub fn errors(&self) -> &Vec<PassportElementError> { &self.errors }
@@ -53,6 +58,9 @@ pub struct RTDPassportElementsWithErrorsBuilder {
 impl RTDPassportElementsWithErrorsBuilder {
   pub fn build(&self) -> PassportElementsWithErrors { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportElementsWithErrors> { Ok(self.build()) }
+
    
   pub fn elements(&mut self, elements: Vec<PassportElement>) -> &mut Self {
     self.inner.elements = elements;
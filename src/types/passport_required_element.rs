@@ -37,6 +37,11 @@ impl PassportRequiredElement {
     RTDPassportRequiredElementBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportRequiredElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn suitable_elements(&self) -> &Vec<PassportSuitableElement> { &self.suitable_elements }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDPassportRequiredElementBuilder {
 impl RTDPassportRequiredElementBuilder {
   pub fn build(&self) -> PassportRequiredElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportRequiredElement> { Ok(self.build()) }
+
    
   pub fn suitable_elements(&mut self, suitable_elements: Vec<PassportSuitableElement>) -> &mut Self {
     self.inner.suitable_elements = suitable_elements;
@@ -43,6 +43,15 @@ impl PassportSuitableElement {
     RTDPassportSuitableElementBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passportSuitableElement", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
+  }
+
   pub fn type_(&self) -> &PassportElementType { &self.type_ }
 
   pub fn is_selfie_required(&self) -> bool { self.is_selfie_required }
@@ -61,6 +70,14 @@ pub struct RTDPassportSuitableElementBuilder {
 impl RTDPassportSuitableElementBuilder {
   pub fn build(&self) -> PassportSuitableElement { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PassportSuitableElement> {
+    let mut missing = vec![];
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("passportSuitableElement", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<PassportElementType>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().clone();
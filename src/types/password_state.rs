@@ -45,6 +45,11 @@ impl PasswordState {
     RTDPasswordStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("passwordState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn has_password(&self) -> bool { self.has_password }
 
   pub fn password_hint(&self) -> &String { &self.password_hint }
@@ -65,6 +70,9 @@ pub struct RTDPasswordStateBuilder {
 impl RTDPasswordStateBuilder {
   pub fn build(&self) -> PasswordState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PasswordState> { Ok(self.build()) }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
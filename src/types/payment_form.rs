@@ -46,7 +46,17 @@ impl PaymentForm {
     let mut inner = PaymentForm::default();
     inner.td_name = "paymentForm".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPaymentFormBuilder { inner }
+    RTDPaymentFormBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("paymentForm", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.invoice._missing_fields())); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn invoice(&self) -> &Invoice { &self.invoice }
@@ -67,21 +77,33 @@ impl PaymentForm {
 
 #[doc(hidden)]
 pub struct RTDPaymentFormBuilder {
-  inner: PaymentForm
+  inner: PaymentForm,
+  set: [bool; 2]
 }
 
 impl RTDPaymentFormBuilder {
   pub fn build(&self) -> PaymentForm { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PaymentForm> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.inner.invoice._missing_fields())); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("paymentForm", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
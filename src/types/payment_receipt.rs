@@ -44,7 +44,19 @@ impl PaymentReceipt {
     let mut inner = PaymentReceipt::default();
     inner.td_name = "paymentReceipt".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPaymentReceiptBuilder { inner }
+    RTDPaymentReceiptBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("paymentReceipt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.payments_provider_user_id == 0 { missing.push("payments_provider_user_id".to_string()); }
+    if false { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.invoice._missing_fields())); }
+    if self.credentials_title.is_empty() { missing.push("credentials_title".to_string()); }
+    missing
   }
 
   pub fn date(&self) -> i64 { self.date }
@@ -63,27 +75,42 @@ impl PaymentReceipt {
 
 #[doc(hidden)]
 pub struct RTDPaymentReceiptBuilder {
-  inner: PaymentReceipt
+  inner: PaymentReceipt,
+  set: [bool; 4]
 }
 
 impl RTDPaymentReceiptBuilder {
   pub fn build(&self) -> PaymentReceipt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PaymentReceipt> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("date".to_string()); }
+    if !self.set[1] { missing.push("payments_provider_user_id".to_string()); }
+    if !self.set[2] { missing.push("invoice".to_string()); } else { missing.extend(crate::types::_common::missing_in("invoice", self.inner.invoice._missing_fields())); }
+    if !self.set[3] { missing.push("credentials_title".to_string()); }
+    crate::types::_common::check_missing("paymentReceipt", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[0] = true;
     self
   }
 
    
   pub fn payments_provider_user_id(&mut self, payments_provider_user_id: i64) -> &mut Self {
     self.inner.payments_provider_user_id = payments_provider_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn invoice<T: AsRef<Invoice>>(&mut self, invoice: T) -> &mut Self {
     self.inner.invoice = invoice.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -102,6 +129,7 @@ impl RTDPaymentReceiptBuilder {
    
   pub fn credentials_title<T: AsRef<str>>(&mut self, credentials_title: T) -> &mut Self {
     self.inner.credentials_title = credentials_title.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl PaymentResult {
     let mut inner = PaymentResult::default();
     inner.td_name = "paymentResult".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPaymentResultBuilder { inner }
+    RTDPaymentResultBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("paymentResult", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.verification_url.is_empty() { missing.push("verification_url".to_string()); }
+    missing
   }
 
   pub fn success(&self) -> bool { self.success }
@@ -47,12 +56,21 @@ impl PaymentResult {
 
 #[doc(hidden)]
 pub struct RTDPaymentResultBuilder {
-  inner: PaymentResult
+  inner: PaymentResult,
+  set: [bool; 1]
 }
 
 impl RTDPaymentResultBuilder {
   pub fn build(&self) -> PaymentResult { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PaymentResult> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("verification_url".to_string()); }
+    crate::types::_common::check_missing("paymentResult", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn success(&mut self, success: bool) -> &mut Self {
     self.inner.success = success;
@@ -62,6 +80,7 @@ impl RTDPaymentResultBuilder {
    
   pub fn verification_url<T: AsRef<str>>(&mut self, verification_url: T) -> &mut Self {
     self.inner.verification_url = verification_url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -40,7 +40,16 @@ impl PaymentsProviderStripe {
     let mut inner = PaymentsProviderStripe::default();
     inner.td_name = "paymentsProviderStripe".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPaymentsProviderStripeBuilder { inner }
+    RTDPaymentsProviderStripeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("paymentsProviderStripe", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.publishable_key.is_empty() { missing.push("publishable_key".to_string()); }
+    missing
   }
 
   pub fn publishable_key(&self) -> &String { &self.publishable_key }
@@ -55,15 +64,25 @@ impl PaymentsProviderStripe {
 
 #[doc(hidden)]
 pub struct RTDPaymentsProviderStripeBuilder {
-  inner: PaymentsProviderStripe
+  inner: PaymentsProviderStripe,
+  set: [bool; 1]
 }
 
 impl RTDPaymentsProviderStripeBuilder {
   pub fn build(&self) -> PaymentsProviderStripe { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PaymentsProviderStripe> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("publishable_key".to_string()); }
+    crate::types::_common::check_missing("paymentsProviderStripe", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn publishable_key<T: AsRef<str>>(&mut self, publishable_key: T) -> &mut Self {
     self.inner.publishable_key = publishable_key.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
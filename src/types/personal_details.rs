@@ -52,7 +52,25 @@ impl PersonalDetails {
     let mut inner = PersonalDetails::default();
     inner.td_name = "personalDetails".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPersonalDetailsBuilder { inner }
+    RTDPersonalDetailsBuilder { inner, set: [false; 10] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("personalDetails", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.first_name.is_empty() { missing.push("first_name".to_string()); }
+    if self.middle_name.is_empty() { missing.push("middle_name".to_string()); }
+    if self.last_name.is_empty() { missing.push("last_name".to_string()); }
+    if self.native_first_name.is_empty() { missing.push("native_first_name".to_string()); }
+    if self.native_middle_name.is_empty() { missing.push("native_middle_name".to_string()); }
+    if self.native_last_name.is_empty() { missing.push("native_last_name".to_string()); }
+    if false { missing.push("birthdate".to_string()); } else { missing.extend(crate::types::_common::missing_in("birthdate", self.birthdate._missing_fields())); }
+    if self.gender.is_empty() { missing.push("gender".to_string()); }
+    if self.country_code.is_empty() { missing.push("country_code".to_string()); }
+    if self.residence_country_code.is_empty() { missing.push("residence_country_code".to_string()); }
+    missing
   }
 
   pub fn first_name(&self) -> &String { &self.first_name }
@@ -79,69 +97,97 @@ impl PersonalDetails {
 
 #[doc(hidden)]
 pub struct RTDPersonalDetailsBuilder {
-  inner: PersonalDetails
+  inner: PersonalDetails,
+  set: [bool; 10]
 }
 
 impl RTDPersonalDetailsBuilder {
   pub fn build(&self) -> PersonalDetails { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PersonalDetails> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("first_name".to_string()); }
+    if !self.set[1] { missing.push("middle_name".to_string()); }
+    if !self.set[2] { missing.push("last_name".to_string()); }
+    if !self.set[3] { missing.push("native_first_name".to_string()); }
+    if !self.set[4] { missing.push("native_middle_name".to_string()); }
+    if !self.set[5] { missing.push("native_last_name".to_string()); }
+    if !self.set[6] { missing.push("birthdate".to_string()); } else { missing.extend(crate::types::_common::missing_in("birthdate", self.inner.birthdate._missing_fields())); }
+    if !self.set[7] { missing.push("gender".to_string()); }
+    if !self.set[8] { missing.push("country_code".to_string()); }
+    if !self.set[9] { missing.push("residence_country_code".to_string()); }
+    crate::types::_common::check_missing("personalDetails", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn middle_name<T: AsRef<str>>(&mut self, middle_name: T) -> &mut Self {
     self.inner.middle_name = middle_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
     self.inner.last_name = last_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn native_first_name<T: AsRef<str>>(&mut self, native_first_name: T) -> &mut Self {
     self.inner.native_first_name = native_first_name.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn native_middle_name<T: AsRef<str>>(&mut self, native_middle_name: T) -> &mut Self {
     self.inner.native_middle_name = native_middle_name.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn native_last_name<T: AsRef<str>>(&mut self, native_last_name: T) -> &mut Self {
     self.inner.native_last_name = native_last_name.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn birthdate<T: AsRef<Date>>(&mut self, birthdate: T) -> &mut Self {
     self.inner.birthdate = birthdate.as_ref().clone();
+    self.set[6] = true;
     self
   }
 
    
   pub fn gender<T: AsRef<str>>(&mut self, gender: T) -> &mut Self {
     self.inner.gender = gender.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
    
   pub fn country_code<T: AsRef<str>>(&mut self, country_code: T) -> &mut Self {
     self.inner.country_code = country_code.as_ref().to_string();
+    self.set[8] = true;
     self
   }
 
    
   pub fn residence_country_code<T: AsRef<str>>(&mut self, residence_country_code: T) -> &mut Self {
     self.inner.residence_country_code = residence_country_code.as_ref().to_string();
+    self.set[9] = true;
     self
   }
 
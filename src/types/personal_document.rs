@@ -39,6 +39,11 @@ impl PersonalDocument {
     RTDPersonalDocumentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("personalDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn files(&self) -> &Vec<DatedFile> { &self.files }
 
   pub fn translation(&self) -> &Vec<DatedFile> { &self.translation }
@@ -53,6 +58,9 @@ pub struct RTDPersonalDocumentBuilder {
 impl RTDPersonalDocumentBuilder {
   pub fn build(&self) -> PersonalDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PersonalDocument> { Ok(self.build()) }
+
    
   pub fn files(&mut self, files: Vec<DatedFile>) -> &mut Self {
     self.inner.files = files;
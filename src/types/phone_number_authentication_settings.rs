@@ -41,6 +41,11 @@ impl PhoneNumberAuthenticationSettings {
     RTDPhoneNumberAuthenticationSettingsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("phoneNumberAuthenticationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn allow_flash_call(&self) -> bool { self.allow_flash_call }
 
   pub fn is_current_phone_number(&self) -> bool { self.is_current_phone_number }
@@ -57,6 +62,9 @@ pub struct RTDPhoneNumberAuthenticationSettingsBuilder {
 impl RTDPhoneNumberAuthenticationSettingsBuilder {
   pub fn build(&self) -> PhoneNumberAuthenticationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PhoneNumberAuthenticationSettings> { Ok(self.build()) }
+
    
   pub fn allow_flash_call(&mut self, allow_flash_call: bool) -> &mut Self {
     self.inner.allow_flash_call = allow_flash_call;
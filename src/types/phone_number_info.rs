@@ -38,7 +38,17 @@ impl PhoneNumberInfo {
     let mut inner = PhoneNumberInfo::default();
     inner.td_name = "phoneNumberInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPhoneNumberInfoBuilder { inner }
+    RTDPhoneNumberInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("phoneNumberInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.country_calling_code.is_empty() { missing.push("country_calling_code".to_string()); }
+    if self.formatted_phone_number.is_empty() { missing.push("formatted_phone_number".to_string()); }
+    missing
   }
 
   pub fn country(&self) -> &Option<CountryInfo> { &self.country }
@@ -51,12 +61,22 @@ impl PhoneNumberInfo {
 
 #[doc(hidden)]
 pub struct RTDPhoneNumberInfoBuilder {
-  inner: PhoneNumberInfo
+  inner: PhoneNumberInfo,
+  set: [bool; 2]
 }
 
 impl RTDPhoneNumberInfoBuilder {
   pub fn build(&self) -> PhoneNumberInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PhoneNumberInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("country_calling_code".to_string()); }
+    if !self.set[1] { missing.push("formatted_phone_number".to_string()); }
+    crate::types::_common::check_missing("phoneNumberInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn country<T: AsRef<CountryInfo>>(&mut self, country: T) -> &mut Self {
     self.inner.country = Some(country.as_ref().clone());
@@ -66,12 +86,14 @@ impl RTDPhoneNumberInfoBuilder {
    
   pub fn country_calling_code<T: AsRef<str>>(&mut self, country_calling_code: T) -> &mut Self {
     self.inner.country_calling_code = country_calling_code.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn formatted_phone_number<T: AsRef<str>>(&mut self, formatted_phone_number: T) -> &mut Self {
     self.inner.formatted_phone_number = formatted_phone_number.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
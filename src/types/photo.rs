@@ -41,6 +41,11 @@ impl Photo {
     RTDPhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("photo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn has_stickers(&self) -> bool { self.has_stickers }
 
   pub fn minithumbnail(&self) -> &Option<Minithumbnail> { &self.minithumbnail }
@@ -57,6 +62,9 @@ pub struct RTDPhotoBuilder {
 impl RTDPhotoBuilder {
   pub fn build(&self) -> Photo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Photo> { Ok(self.build()) }
+
    
   pub fn has_stickers(&mut self, has_stickers: bool) -> &mut Self {
     self.inner.has_stickers = has_stickers;
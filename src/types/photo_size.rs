@@ -42,7 +42,19 @@ impl PhotoSize {
     let mut inner = PhotoSize::default();
     inner.td_name = "photoSize".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPhotoSizeBuilder { inner }
+    RTDPhotoSizeBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("photoSize", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if false { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.photo._missing_fields())); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    missing
   }
 
   pub fn type_(&self) -> &String { &self.type_ }
@@ -59,33 +71,49 @@ impl PhotoSize {
 
 #[doc(hidden)]
 pub struct RTDPhotoSizeBuilder {
-  inner: PhotoSize
+  inner: PhotoSize,
+  set: [bool; 4]
 }
 
 impl RTDPhotoSizeBuilder {
   pub fn build(&self) -> PhotoSize { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PhotoSize> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("type".to_string()); }
+    if !self.set[1] { missing.push("photo".to_string()); } else { missing.extend(crate::types::_common::missing_in("photo", self.inner.photo._missing_fields())); }
+    if !self.set[2] { missing.push("width".to_string()); }
+    if !self.set[3] { missing.push("height".to_string()); }
+    crate::types::_common::check_missing("photoSize", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn photo<T: AsRef<File>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = photo.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[2] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[3] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl Point {
     let mut inner = Point::default();
     inner.td_name = "point".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPointBuilder { inner }
+    RTDPointBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("point", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.x == 0.0 { missing.push("x".to_string()); }
+    if self.y == 0.0 { missing.push("y".to_string()); }
+    missing
   }
 
   pub fn x(&self) -> f32 { self.x }
@@ -47,21 +57,33 @@ impl Point {
 
 #[doc(hidden)]
 pub struct RTDPointBuilder {
-  inner: Point
+  inner: Point,
+  set: [bool; 2]
 }
 
 impl RTDPointBuilder {
   pub fn build(&self) -> Point { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Point> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("x".to_string()); }
+    if !self.set[1] { missing.push("y".to_string()); }
+    crate::types::_common::check_missing("point", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn x(&mut self, x: f32) -> &mut Self {
     self.inner.x = x;
+    self.set[0] = true;
     self
   }
 
    
   pub fn y(&mut self, y: f32) -> &mut Self {
     self.inner.y = y;
+    self.set[1] = true;
     self
   }
 
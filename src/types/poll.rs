@@ -16,7 +16,7 @@ pub struct Poll {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique poll identifier
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Poll question; 1-300 characters
   question: String,
   /// List of poll answer options
@@ -52,10 +52,24 @@ impl Poll {
     let mut inner = Poll::default();
     inner.td_name = "poll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPollBuilder { inner }
+    RTDPollBuilder { inner, set: [false; 5] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("poll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.question.is_empty() { missing.push("question".to_string()); }
+    if self.total_voter_count == 0 { missing.push("total_voter_count".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.open_period == 0 { missing.push("open_period".to_string()); }
+    if self.close_date == 0 { missing.push("close_date".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn question(&self) -> &String { &self.question }
 
@@ -79,21 +93,37 @@ impl Poll {
 
 #[doc(hidden)]
 pub struct RTDPollBuilder {
-  inner: Poll
+  inner: Poll,
+  set: [bool; 5]
 }
 
 impl RTDPollBuilder {
   pub fn build(&self) -> Poll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Poll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("question".to_string()); }
+    if !self.set[2] { missing.push("total_voter_count".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[3] { missing.push("open_period".to_string()); }
+    if !self.set[4] { missing.push("close_date".to_string()); }
+    crate::types::_common::check_missing("poll", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -106,6 +136,7 @@ impl RTDPollBuilder {
    
   pub fn total_voter_count(&mut self, total_voter_count: i64) -> &mut Self {
     self.inner.total_voter_count = total_voter_count;
+    self.set[2] = true;
     self
   }
 
@@ -130,12 +161,14 @@ impl RTDPollBuilder {
    
   pub fn open_period(&mut self, open_period: i64) -> &mut Self {
     self.inner.open_period = open_period;
+    self.set[3] = true;
     self
   }
 
    
   pub fn close_date(&mut self, close_date: i64) -> &mut Self {
     self.inner.close_date = close_date;
+    self.set[4] = true;
     self
   }
 
@@ -42,7 +42,18 @@ impl PollOption {
     let mut inner = PollOption::default();
     inner.td_name = "pollOption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPollOptionBuilder { inner }
+    RTDPollOptionBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pollOption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    if self.voter_count == 0 { missing.push("voter_count".to_string()); }
+    if self.vote_percentage == 0 { missing.push("vote_percentage".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -59,27 +70,41 @@ impl PollOption {
 
 #[doc(hidden)]
 pub struct RTDPollOptionBuilder {
-  inner: PollOption
+  inner: PollOption,
+  set: [bool; 3]
 }
 
 impl RTDPollOptionBuilder {
   pub fn build(&self) -> PollOption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PollOption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    if !self.set[1] { missing.push("voter_count".to_string()); }
+    if !self.set[2] { missing.push("vote_percentage".to_string()); }
+    crate::types::_common::check_missing("pollOption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn voter_count(&mut self, voter_count: i64) -> &mut Self {
     self.inner.voter_count = voter_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn vote_percentage(&mut self, vote_percentage: i64) -> &mut Self {
     self.inner.vote_percentage = vote_percentage;
+    self.set[2] = true;
     self
   }
 
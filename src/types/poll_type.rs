@@ -19,6 +19,9 @@ pub trait TDPollType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PollType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A poll in quiz mode, which has exactly one correct answer option and can be answered only once
   Quiz(PollTypeQuiz),
   /// A regular poll
@@ -32,7 +35,6 @@ impl Default for PollType {
 
 impl<'de> Deserialize<'de> for PollType {
   fn deserialize<D>(deserializer: D) -> Result<PollType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PollType,
       (pollTypeQuiz, Quiz);
@@ -55,6 +57,7 @@ impl RObject for PollType {
     match self {
       PollType::Quiz(t) => t.extra(),
       PollType::Regular(t) => t.extra(),
+      PollType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for PollType {
 impl PollType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PollType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PollType::Quiz(t) => t._missing_fields(),
+      PollType::Regular(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_quiz(&self) -> bool { if let PollType::Quiz(_) = self { true } else { false } }
   pub fn is_regular(&self) -> bool { if let PollType::Regular(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<PollType> for PollType {
   fn as_ref(&self) -> &PollType { self }
 }
 
+impl RVariant<PollType> for PollTypeQuiz {
+  fn from_class(class: &PollType) -> Option<&Self> { match class { PollType::Quiz(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PollType> for PollTypeRegular {
+  fn from_class(class: &PollType) -> Option<&Self> { match class { PollType::Regular(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -126,7 +144,17 @@ impl PollTypeQuiz {
     let mut inner = PollTypeQuiz::default();
     inner.td_name = "pollTypeQuiz".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPollTypeQuizBuilder { inner }
+    RTDPollTypeQuizBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pollTypeQuiz", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.correct_option_id == 0 { missing.push("correct_option_id".to_string()); }
+    if false { missing.push("explanation".to_string()); } else { missing.extend(crate::types::_common::missing_in("explanation", self.explanation._missing_fields())); }
+    missing
   }
 
   pub fn correct_option_id(&self) -> i64 { self.correct_option_id }
@@ -137,21 +165,33 @@ impl PollTypeQuiz {
 
 #[doc(hidden)]
 pub struct RTDPollTypeQuizBuilder {
-  inner: PollTypeQuiz
+  inner: PollTypeQuiz,
+  set: [bool; 2]
 }
 
 impl RTDPollTypeQuizBuilder {
   pub fn build(&self) -> PollTypeQuiz { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PollTypeQuiz> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("correct_option_id".to_string()); }
+    if !self.set[1] { missing.push("explanation".to_string()); } else { missing.extend(crate::types::_common::missing_in("explanation", self.inner.explanation._missing_fields())); }
+    crate::types::_common::check_missing("pollTypeQuiz", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn correct_option_id(&mut self, correct_option_id: i64) -> &mut Self {
     self.inner.correct_option_id = correct_option_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn explanation<T: AsRef<FormattedText>>(&mut self, explanation: T) -> &mut Self {
     self.inner.explanation = explanation.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -205,6 +245,11 @@ impl PollTypeRegular {
     RTDPollTypeRegularBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pollTypeRegular", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn allow_multiple_answers(&self) -> bool { self.allow_multiple_answers }
 
 }
@@ -217,6 +262,9 @@ pub struct RTDPollTypeRegularBuilder {
 impl RTDPollTypeRegularBuilder {
   pub fn build(&self) -> PollTypeRegular { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PollTypeRegular> { Ok(self.build()) }
+
    
   pub fn allow_multiple_answers(&mut self, allow_multiple_answers: bool) -> &mut Self {
     self.inner.allow_multiple_answers = allow_multiple_answers;
@@ -16,7 +16,7 @@ pub struct ProfilePhoto {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of user profile photos
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// A small (160x160) user profile photo. The file can be downloaded only before the photo is changed
   small: File,
   /// A big (640x640) user profile photo. The file can be downloaded only before the photo is changed
@@ -40,10 +40,21 @@ impl ProfilePhoto {
     let mut inner = ProfilePhoto::default();
     inner.td_name = "profilePhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDProfilePhotoBuilder { inner }
+    RTDProfilePhotoBuilder { inner, set: [false; 3] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("profilePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if false { missing.push("small".to_string()); } else { missing.extend(crate::types::_common::missing_in("small", self.small._missing_fields())); }
+    if false { missing.push("big".to_string()); } else { missing.extend(crate::types::_common::missing_in("big", self.big._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn small(&self) -> &File { &self.small }
 
@@ -55,27 +66,41 @@ impl ProfilePhoto {
 
 #[doc(hidden)]
 pub struct RTDProfilePhotoBuilder {
-  inner: ProfilePhoto
+  inner: ProfilePhoto,
+  set: [bool; 3]
 }
 
 impl RTDProfilePhotoBuilder {
   pub fn build(&self) -> ProfilePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ProfilePhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("small".to_string()); } else { missing.extend(crate::types::_common::missing_in("small", self.inner.small._missing_fields())); }
+    if !self.set[2] { missing.push("big".to_string()); } else { missing.extend(crate::types::_common::missing_in("big", self.inner.big._missing_fields())); }
+    crate::types::_common::check_missing("profilePhoto", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn small<T: AsRef<File>>(&mut self, small: T) -> &mut Self {
     self.inner.small = small.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn big<T: AsRef<File>>(&mut self, big: T) -> &mut Self {
     self.inner.big = big.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
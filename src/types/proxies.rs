@@ -37,6 +37,11 @@ impl Proxies {
     RTDProxiesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("proxies", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn proxies(&self) -> &Vec<Proxy> { &self.proxies }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDProxiesBuilder {
 impl RTDProxiesBuilder {
   pub fn build(&self) -> Proxies { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Proxies> { Ok(self.build()) }
+
    
   pub fn proxies(&mut self, proxies: Vec<Proxy>) -> &mut Self {
     self.inner.proxies = proxies;
@@ -44,7 +44,19 @@ impl Proxy {
     let mut inner = Proxy::default();
     inner.td_name = "proxy".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDProxyBuilder { inner }
+    RTDProxyBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("proxy", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.server.is_empty() { missing.push("server".to_string()); }
+    if self.port == 0 { missing.push("port".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -63,27 +75,42 @@ impl Proxy {
 
 #[doc(hidden)]
 pub struct RTDProxyBuilder {
-  inner: Proxy
+  inner: Proxy,
+  set: [bool; 3]
 }
 
 impl RTDProxyBuilder {
   pub fn build(&self) -> Proxy { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Proxy> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("server".to_string()); }
+    if !self.set[2] { missing.push("port".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("proxy", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn server<T: AsRef<str>>(&mut self, server: T) -> &mut Self {
     self.inner.server = server.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn port(&mut self, port: i64) -> &mut Self {
     self.inner.port = port;
+    self.set[2] = true;
     self
   }
 
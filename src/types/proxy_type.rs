@@ -19,6 +19,9 @@ pub trait TDProxyType: Debug + RObject {}
 #[serde(untagged)]
 pub enum ProxyType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A HTTP transparent proxy server
   Http(ProxyTypeHttp),
   /// An MTProto proxy server
@@ -34,7 +37,6 @@ impl Default for ProxyType {
 
 impl<'de> Deserialize<'de> for ProxyType {
   fn deserialize<D>(deserializer: D) -> Result<ProxyType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ProxyType,
       (proxyTypeHttp, Http);
@@ -60,6 +62,7 @@ impl RObject for ProxyType {
       ProxyType::Http(t) => t.extra(),
       ProxyType::Mtproto(t) => t.extra(),
       ProxyType::Socks5(t) => t.extra(),
+      ProxyType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for ProxyType {
 impl ProxyType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ProxyType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ProxyType::Http(t) => t._missing_fields(),
+      ProxyType::Mtproto(t) => t._missing_fields(),
+      ProxyType::Socks5(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_http(&self) -> bool { if let ProxyType::Http(_) = self { true } else { false } }
   pub fn is_mtproto(&self) -> bool { if let ProxyType::Mtproto(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<ProxyType> for ProxyType {
   fn as_ref(&self) -> &ProxyType { self }
 }
 
+impl RVariant<ProxyType> for ProxyTypeHttp {
+  fn from_class(class: &ProxyType) -> Option<&Self> { match class { ProxyType::Http(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ProxyType> for ProxyTypeMtproto {
+  fn from_class(class: &ProxyType) -> Option<&Self> { match class { ProxyType::Mtproto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ProxyType> for ProxyTypeSocks5 {
+  fn from_class(class: &ProxyType) -> Option<&Self> { match class { ProxyType::Socks5(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -141,6 +164,11 @@ impl ProxyTypeHttp {
     RTDProxyTypeHttpBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("proxyTypeHttp", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn username(&self) -> &String { &self.username }
 
   pub fn password(&self) -> &String { &self.password }
@@ -157,6 +185,9 @@ pub struct RTDProxyTypeHttpBuilder {
 impl RTDProxyTypeHttpBuilder {
   pub fn build(&self) -> ProxyTypeHttp { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ProxyTypeHttp> { Ok(self.build()) }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -222,7 +253,16 @@ impl ProxyTypeMtproto {
     let mut inner = ProxyTypeMtproto::default();
     inner.td_name = "proxyTypeMtproto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDProxyTypeMtprotoBuilder { inner }
+    RTDProxyTypeMtprotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("proxyTypeMtproto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.secret.is_empty() { missing.push("secret".to_string()); }
+    missing
   }
 
   pub fn secret(&self) -> &String { &self.secret }
@@ -231,15 +271,25 @@ impl ProxyTypeMtproto {
 
 #[doc(hidden)]
 pub struct RTDProxyTypeMtprotoBuilder {
-  inner: ProxyTypeMtproto
+  inner: ProxyTypeMtproto,
+  set: [bool; 1]
 }
 
 impl RTDProxyTypeMtprotoBuilder {
   pub fn build(&self) -> ProxyTypeMtproto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ProxyTypeMtproto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret".to_string()); }
+    crate::types::_common::check_missing("proxyTypeMtproto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret<T: AsRef<str>>(&mut self, secret: T) -> &mut Self {
     self.inner.secret = secret.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -295,6 +345,11 @@ impl ProxyTypeSocks5 {
     RTDProxyTypeSocks5Builder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("proxyTypeSocks5", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn username(&self) -> &String { &self.username }
 
   pub fn password(&self) -> &String { &self.password }
@@ -309,6 +364,9 @@ pub struct RTDProxyTypeSocks5Builder {
 impl RTDProxyTypeSocks5Builder {
   pub fn build(&self) -> ProxyTypeSocks5 { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ProxyTypeSocks5> { Ok(self.build()) }
+
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
@@ -19,6 +19,9 @@ pub trait TDPublicChatType: Debug + RObject {}
 #[serde(untagged)]
 pub enum PublicChatType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The chat is public, because it has username
   HasUsername(PublicChatTypeHasUsername),
   /// The chat is public, because it is a location-based supergroup
@@ -32,7 +35,6 @@ impl Default for PublicChatType {
 
 impl<'de> Deserialize<'de> for PublicChatType {
   fn deserialize<D>(deserializer: D) -> Result<PublicChatType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PublicChatType,
       (publicChatTypeHasUsername, HasUsername);
@@ -55,6 +57,7 @@ impl RObject for PublicChatType {
     match self {
       PublicChatType::HasUsername(t) => t.extra(),
       PublicChatType::IsLocationBased(t) => t.extra(),
+      PublicChatType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for PublicChatType {
 impl PublicChatType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PublicChatType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PublicChatType::HasUsername(t) => t._missing_fields(),
+      PublicChatType::IsLocationBased(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_has_username(&self) -> bool { if let PublicChatType::HasUsername(_) = self { true } else { false } }
   pub fn is_is_location_based(&self) -> bool { if let PublicChatType::IsLocationBased(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<PublicChatType> for PublicChatType {
   fn as_ref(&self) -> &PublicChatType { self }
 }
 
+impl RVariant<PublicChatType> for PublicChatTypeHasUsername {
+  fn from_class(class: &PublicChatType) -> Option<&Self> { match class { PublicChatType::HasUsername(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PublicChatType> for PublicChatTypeIsLocationBased {
+  fn from_class(class: &PublicChatType) -> Option<&Self> { match class { PublicChatType::IsLocationBased(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -125,6 +143,11 @@ impl PublicChatTypeHasUsername {
     RTDPublicChatTypeHasUsernameBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("publicChatTypeHasUsername", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +158,9 @@ pub struct RTDPublicChatTypeHasUsernameBuilder {
 impl RTDPublicChatTypeHasUsernameBuilder {
   pub fn build(&self) -> PublicChatTypeHasUsername { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PublicChatTypeHasUsername> { Ok(self.build()) }
+
 }
 
 impl AsRef<PublicChatTypeHasUsername> for PublicChatTypeHasUsername {
@@ -183,6 +209,11 @@ impl PublicChatTypeIsLocationBased {
     RTDPublicChatTypeIsLocationBasedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("publicChatTypeIsLocationBased", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -193,6 +224,9 @@ pub struct RTDPublicChatTypeIsLocationBasedBuilder {
 impl RTDPublicChatTypeIsLocationBasedBuilder {
   pub fn build(&self) -> PublicChatTypeIsLocationBased { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PublicChatTypeIsLocationBased> { Ok(self.build()) }
+
 }
 
 impl AsRef<PublicChatTypeIsLocationBased> for PublicChatTypeIsLocationBased {
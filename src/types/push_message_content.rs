@@ -19,6 +19,9 @@ pub trait TDPushMessageContent: Debug + RObject {}
 #[serde(untagged)]
 pub enum PushMessageContent {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An animation message (GIF-style).
   Animation(PushMessageContentAnimation),
   /// An audio message
@@ -80,7 +83,6 @@ impl Default for PushMessageContent {
 
 impl<'de> Deserialize<'de> for PushMessageContent {
   fn deserialize<D>(deserializer: D) -> Result<PushMessageContent, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       PushMessageContent,
       (pushMessageContentAnimation, Animation);
@@ -175,6 +177,7 @@ impl RObject for PushMessageContent {
       PushMessageContent::Video(t) => t.extra(),
       PushMessageContent::VideoNote(t) => t.extra(),
       PushMessageContent::VoiceNote(t) => t.extra(),
+      PushMessageContent::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -185,6 +188,37 @@ impl RObject for PushMessageContent {
 impl PushMessageContent {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let PushMessageContent::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      PushMessageContent::Animation(t) => t._missing_fields(),
+      PushMessageContent::Audio(t) => t._missing_fields(),
+      PushMessageContent::BasicGroupChatCreate(t) => t._missing_fields(),
+      PushMessageContent::ChatAddMembers(t) => t._missing_fields(),
+      PushMessageContent::ChatChangePhoto(t) => t._missing_fields(),
+      PushMessageContent::ChatChangeTitle(t) => t._missing_fields(),
+      PushMessageContent::ChatDeleteMember(t) => t._missing_fields(),
+      PushMessageContent::ChatJoinByLink(t) => t._missing_fields(),
+      PushMessageContent::Contact(t) => t._missing_fields(),
+      PushMessageContent::ContactRegistered(t) => t._missing_fields(),
+      PushMessageContent::Document(t) => t._missing_fields(),
+      PushMessageContent::Game(t) => t._missing_fields(),
+      PushMessageContent::GameScore(t) => t._missing_fields(),
+      PushMessageContent::Hidden(t) => t._missing_fields(),
+      PushMessageContent::Invoice(t) => t._missing_fields(),
+      PushMessageContent::Location(t) => t._missing_fields(),
+      PushMessageContent::MediaAlbum(t) => t._missing_fields(),
+      PushMessageContent::MessageForwards(t) => t._missing_fields(),
+      PushMessageContent::Photo(t) => t._missing_fields(),
+      PushMessageContent::Poll(t) => t._missing_fields(),
+      PushMessageContent::ScreenshotTaken(t) => t._missing_fields(),
+      PushMessageContent::Sticker(t) => t._missing_fields(),
+      PushMessageContent::Text(t) => t._missing_fields(),
+      PushMessageContent::Video(t) => t._missing_fields(),
+      PushMessageContent::VideoNote(t) => t._missing_fields(),
+      PushMessageContent::VoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let PushMessageContent::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let PushMessageContent::Audio(_) = self { true } else { false } }
@@ -327,6 +361,110 @@ impl AsRef<PushMessageContent> for PushMessageContent {
   fn as_ref(&self) -> &PushMessageContent { self }
 }
 
+impl RVariant<PushMessageContent> for PushMessageContentAnimation {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentAudio {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentBasicGroupChatCreate {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::BasicGroupChatCreate(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentChatAddMembers {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ChatAddMembers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentChatChangePhoto {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ChatChangePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentChatChangeTitle {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ChatChangeTitle(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentChatDeleteMember {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ChatDeleteMember(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentChatJoinByLink {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ChatJoinByLink(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentContact {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Contact(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentContactRegistered {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ContactRegistered(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentDocument {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Document(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentGame {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Game(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentGameScore {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::GameScore(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentHidden {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Hidden(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentInvoice {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Invoice(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentLocation {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Location(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentMediaAlbum {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::MediaAlbum(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentMessageForwards {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::MessageForwards(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentPhoto {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentPoll {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Poll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentScreenshotTaken {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::ScreenshotTaken(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentSticker {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Sticker(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentText {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Text(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentVideo {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentVideoNote {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::VideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<PushMessageContent> for PushMessageContentVoiceNote {
+  fn from_class(class: &PushMessageContent) -> Option<&Self> { match class { PushMessageContent::VoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -368,7 +506,16 @@ impl PushMessageContentAnimation {
     let mut inner = PushMessageContentAnimation::default();
     inner.td_name = "pushMessageContentAnimation".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentAnimationBuilder { inner }
+    RTDPushMessageContentAnimationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.caption.is_empty() { missing.push("caption".to_string()); }
+    missing
   }
 
   pub fn animation(&self) -> &Option<Animation> { &self.animation }
@@ -381,12 +528,21 @@ impl PushMessageContentAnimation {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentAnimationBuilder {
-  inner: PushMessageContentAnimation
+  inner: PushMessageContentAnimation,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentAnimationBuilder {
   pub fn build(&self) -> PushMessageContentAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentAnimation> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentAnimation", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn animation<T: AsRef<Animation>>(&mut self, animation: T) -> &mut Self {
     self.inner.animation = Some(animation.as_ref().clone());
@@ -396,6 +552,7 @@ impl RTDPushMessageContentAnimationBuilder {
    
   pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -457,6 +614,11 @@ impl PushMessageContentAudio {
     RTDPushMessageContentAudioBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn audio(&self) -> &Option<Audio> { &self.audio }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -471,6 +633,9 @@ pub struct RTDPushMessageContentAudioBuilder {
 impl RTDPushMessageContentAudioBuilder {
   pub fn build(&self) -> PushMessageContentAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentAudio> { Ok(self.build()) }
+
    
   pub fn audio<T: AsRef<Audio>>(&mut self, audio: T) -> &mut Self {
     self.inner.audio = Some(audio.as_ref().clone());
@@ -531,6 +696,11 @@ impl PushMessageContentBasicGroupChatCreate {
     RTDPushMessageContentBasicGroupChatCreateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentBasicGroupChatCreate", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -541,6 +711,9 @@ pub struct RTDPushMessageContentBasicGroupChatCreateBuilder {
 impl RTDPushMessageContentBasicGroupChatCreateBuilder {
   pub fn build(&self) -> PushMessageContentBasicGroupChatCreate { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentBasicGroupChatCreate> { Ok(self.build()) }
+
 }
 
 impl AsRef<PushMessageContentBasicGroupChatCreate> for PushMessageContentBasicGroupChatCreate {
@@ -592,7 +765,16 @@ impl PushMessageContentChatAddMembers {
     let mut inner = PushMessageContentChatAddMembers::default();
     inner.td_name = "pushMessageContentChatAddMembers".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentChatAddMembersBuilder { inner }
+    RTDPushMessageContentChatAddMembersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentChatAddMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.member_name.is_empty() { missing.push("member_name".to_string()); }
+    missing
   }
 
   pub fn member_name(&self) -> &String { &self.member_name }
@@ -605,15 +787,25 @@ impl PushMessageContentChatAddMembers {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatAddMembersBuilder {
-  inner: PushMessageContentChatAddMembers
+  inner: PushMessageContentChatAddMembers,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentChatAddMembersBuilder {
   pub fn build(&self) -> PushMessageContentChatAddMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentChatAddMembers> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("member_name".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentChatAddMembers", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -677,6 +869,11 @@ impl PushMessageContentChatChangePhoto {
     RTDPushMessageContentChatChangePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentChatChangePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -687,6 +884,9 @@ pub struct RTDPushMessageContentChatChangePhotoBuilder {
 impl RTDPushMessageContentChatChangePhotoBuilder {
   pub fn build(&self) -> PushMessageContentChatChangePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentChatChangePhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<PushMessageContentChatChangePhoto> for PushMessageContentChatChangePhoto {
@@ -734,7 +934,16 @@ impl PushMessageContentChatChangeTitle {
     let mut inner = PushMessageContentChatChangeTitle::default();
     inner.td_name = "pushMessageContentChatChangeTitle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentChatChangeTitleBuilder { inner }
+    RTDPushMessageContentChatChangeTitleBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentChatChangeTitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -743,15 +952,25 @@ impl PushMessageContentChatChangeTitle {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatChangeTitleBuilder {
-  inner: PushMessageContentChatChangeTitle
+  inner: PushMessageContentChatChangeTitle,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentChatChangeTitleBuilder {
   pub fn build(&self) -> PushMessageContentChatChangeTitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentChatChangeTitle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentChatChangeTitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -806,7 +1025,16 @@ impl PushMessageContentChatDeleteMember {
     let mut inner = PushMessageContentChatDeleteMember::default();
     inner.td_name = "pushMessageContentChatDeleteMember".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentChatDeleteMemberBuilder { inner }
+    RTDPushMessageContentChatDeleteMemberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentChatDeleteMember", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.member_name.is_empty() { missing.push("member_name".to_string()); }
+    missing
   }
 
   pub fn member_name(&self) -> &String { &self.member_name }
@@ -819,15 +1047,25 @@ impl PushMessageContentChatDeleteMember {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentChatDeleteMemberBuilder {
-  inner: PushMessageContentChatDeleteMember
+  inner: PushMessageContentChatDeleteMember,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentChatDeleteMemberBuilder {
   pub fn build(&self) -> PushMessageContentChatDeleteMember { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentChatDeleteMember> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("member_name".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentChatDeleteMember", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn member_name<T: AsRef<str>>(&mut self, member_name: T) -> &mut Self {
     self.inner.member_name = member_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -891,6 +1129,11 @@ impl PushMessageContentChatJoinByLink {
     RTDPushMessageContentChatJoinByLinkBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentChatJoinByLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -901,6 +1144,9 @@ pub struct RTDPushMessageContentChatJoinByLinkBuilder {
 impl RTDPushMessageContentChatJoinByLinkBuilder {
   pub fn build(&self) -> PushMessageContentChatJoinByLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentChatJoinByLink> { Ok(self.build()) }
+
 }
 
 impl AsRef<PushMessageContentChatJoinByLink> for PushMessageContentChatJoinByLink {
@@ -950,7 +1196,16 @@ impl PushMessageContentContact {
     let mut inner = PushMessageContentContact::default();
     inner.td_name = "pushMessageContentContact".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentContactBuilder { inner }
+    RTDPushMessageContentContactBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentContact", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -961,15 +1216,25 @@ impl PushMessageContentContact {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentContactBuilder {
-  inner: PushMessageContentContact
+  inner: PushMessageContentContact,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentContactBuilder {
   pub fn build(&self) -> PushMessageContentContact { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentContact> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentContact", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1027,6 +1292,11 @@ impl PushMessageContentContactRegistered {
     RTDPushMessageContentContactRegisteredBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentContactRegistered", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1037,6 +1307,9 @@ pub struct RTDPushMessageContentContactRegisteredBuilder {
 impl RTDPushMessageContentContactRegisteredBuilder {
   pub fn build(&self) -> PushMessageContentContactRegistered { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentContactRegistered> { Ok(self.build()) }
+
 }
 
 impl AsRef<PushMessageContentContactRegistered> for PushMessageContentContactRegistered {
@@ -1089,6 +1362,11 @@ impl PushMessageContentDocument {
     RTDPushMessageContentDocumentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn document(&self) -> &Option<Document> { &self.document }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1103,6 +1381,9 @@ pub struct RTDPushMessageContentDocumentBuilder {
 impl RTDPushMessageContentDocumentBuilder {
   pub fn build(&self) -> PushMessageContentDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentDocument> { Ok(self.build()) }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = Some(document.as_ref().clone());
@@ -1164,7 +1445,16 @@ impl PushMessageContentGame {
     let mut inner = PushMessageContentGame::default();
     inner.td_name = "pushMessageContentGame".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentGameBuilder { inner }
+    RTDPushMessageContentGameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentGame", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1175,15 +1465,25 @@ impl PushMessageContentGame {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentGameBuilder {
-  inner: PushMessageContentGame
+  inner: PushMessageContentGame,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentGameBuilder {
   pub fn build(&self) -> PushMessageContentGame { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentGame> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentGame", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1244,7 +1544,17 @@ impl PushMessageContentGameScore {
     let mut inner = PushMessageContentGameScore::default();
     inner.td_name = "pushMessageContentGameScore".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentGameScoreBuilder { inner }
+    RTDPushMessageContentGameScoreBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentGameScore", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.score == 0 { missing.push("score".to_string()); }
+    missing
   }
 
   pub fn title(&self) -> &String { &self.title }
@@ -1257,21 +1567,33 @@ impl PushMessageContentGameScore {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentGameScoreBuilder {
-  inner: PushMessageContentGameScore
+  inner: PushMessageContentGameScore,
+  set: [bool; 2]
 }
 
 impl RTDPushMessageContentGameScoreBuilder {
   pub fn build(&self) -> PushMessageContentGameScore { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentGameScore> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("title".to_string()); }
+    if !self.set[1] { missing.push("score".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentGameScore", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn score(&mut self, score: i64) -> &mut Self {
     self.inner.score = score;
+    self.set[1] = true;
     self
   }
 
@@ -1331,6 +1653,11 @@ impl PushMessageContentHidden {
     RTDPushMessageContentHiddenBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentHidden", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_pinned(&self) -> bool { self.is_pinned }
 
 }
@@ -1343,6 +1670,9 @@ pub struct RTDPushMessageContentHiddenBuilder {
 impl RTDPushMessageContentHiddenBuilder {
   pub fn build(&self) -> PushMessageContentHidden { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentHidden> { Ok(self.build()) }
+
    
   pub fn is_pinned(&mut self, is_pinned: bool) -> &mut Self {
     self.inner.is_pinned = is_pinned;
@@ -1398,7 +1728,16 @@ impl PushMessageContentInvoice {
     let mut inner = PushMessageContentInvoice::default();
     inner.td_name = "pushMessageContentInvoice".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentInvoiceBuilder { inner }
+    RTDPushMessageContentInvoiceBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentInvoice", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.price.is_empty() { missing.push("price".to_string()); }
+    missing
   }
 
   pub fn price(&self) -> &String { &self.price }
@@ -1409,15 +1748,25 @@ impl PushMessageContentInvoice {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentInvoiceBuilder {
-  inner: PushMessageContentInvoice
+  inner: PushMessageContentInvoice,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentInvoiceBuilder {
   pub fn build(&self) -> PushMessageContentInvoice { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentInvoice> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("price".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentInvoice", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn price<T: AsRef<str>>(&mut self, price: T) -> &mut Self {
     self.inner.price = price.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1479,6 +1828,11 @@ impl PushMessageContentLocation {
     RTDPushMessageContentLocationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentLocation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_live(&self) -> bool { self.is_live }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -1493,6 +1847,9 @@ pub struct RTDPushMessageContentLocationBuilder {
 impl RTDPushMessageContentLocationBuilder {
   pub fn build(&self) -> PushMessageContentLocation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentLocation> { Ok(self.build()) }
+
    
   pub fn is_live(&mut self, is_live: bool) -> &mut Self {
     self.inner.is_live = is_live;
@@ -1560,7 +1917,16 @@ impl PushMessageContentMediaAlbum {
     let mut inner = PushMessageContentMediaAlbum::default();
     inner.td_name = "pushMessageContentMediaAlbum".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentMediaAlbumBuilder { inner }
+    RTDPushMessageContentMediaAlbumBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentMediaAlbum", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn total_count(&self) -> i64 { self.total_count }
@@ -1577,15 +1943,25 @@ impl PushMessageContentMediaAlbum {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentMediaAlbumBuilder {
-  inner: PushMessageContentMediaAlbum
+  inner: PushMessageContentMediaAlbum,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentMediaAlbumBuilder {
   pub fn build(&self) -> PushMessageContentMediaAlbum { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentMediaAlbum> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentMediaAlbum", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[0] = true;
     self
   }
 
@@ -1660,7 +2036,16 @@ impl PushMessageContentMessageForwards {
     let mut inner = PushMessageContentMessageForwards::default();
     inner.td_name = "pushMessageContentMessageForwards".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentMessageForwardsBuilder { inner }
+    RTDPushMessageContentMessageForwardsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentMessageForwards", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn total_count(&self) -> i64 { self.total_count }
@@ -1669,15 +2054,25 @@ impl PushMessageContentMessageForwards {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentMessageForwardsBuilder {
-  inner: PushMessageContentMessageForwards
+  inner: PushMessageContentMessageForwards,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentMessageForwardsBuilder {
   pub fn build(&self) -> PushMessageContentMessageForwards { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentMessageForwards> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentMessageForwards", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[0] = true;
     self
   }
 
@@ -1734,7 +2129,16 @@ impl PushMessageContentPhoto {
     let mut inner = PushMessageContentPhoto::default();
     inner.td_name = "pushMessageContentPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentPhotoBuilder { inner }
+    RTDPushMessageContentPhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.caption.is_empty() { missing.push("caption".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &Option<Photo> { &self.photo }
@@ -1749,12 +2153,21 @@ impl PushMessageContentPhoto {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentPhotoBuilder {
-  inner: PushMessageContentPhoto
+  inner: PushMessageContentPhoto,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentPhotoBuilder {
   pub fn build(&self) -> PushMessageContentPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<Photo>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -1764,6 +2177,7 @@ impl RTDPushMessageContentPhotoBuilder {
    
   pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1830,7 +2244,16 @@ impl PushMessageContentPoll {
     let mut inner = PushMessageContentPoll::default();
     inner.td_name = "pushMessageContentPoll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentPollBuilder { inner }
+    RTDPushMessageContentPollBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentPoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.question.is_empty() { missing.push("question".to_string()); }
+    missing
   }
 
   pub fn question(&self) -> &String { &self.question }
@@ -1843,15 +2266,25 @@ impl PushMessageContentPoll {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentPollBuilder {
-  inner: PushMessageContentPoll
+  inner: PushMessageContentPoll,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentPollBuilder {
   pub fn build(&self) -> PushMessageContentPoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentPoll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("question".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentPoll", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn question<T: AsRef<str>>(&mut self, question: T) -> &mut Self {
     self.inner.question = question.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1915,6 +2348,11 @@ impl PushMessageContentScreenshotTaken {
     RTDPushMessageContentScreenshotTakenBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentScreenshotTaken", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1925,6 +2363,9 @@ pub struct RTDPushMessageContentScreenshotTakenBuilder {
 impl RTDPushMessageContentScreenshotTakenBuilder {
   pub fn build(&self) -> PushMessageContentScreenshotTaken { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentScreenshotTaken> { Ok(self.build()) }
+
 }
 
 impl AsRef<PushMessageContentScreenshotTaken> for PushMessageContentScreenshotTaken {
@@ -1979,6 +2420,11 @@ impl PushMessageContentSticker {
     RTDPushMessageContentStickerBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentSticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn sticker(&self) -> &Option<Sticker> { &self.sticker }
 
   pub fn emoji(&self) -> &String { &self.emoji }
@@ -1995,6 +2441,9 @@ pub struct RTDPushMessageContentStickerBuilder {
 impl RTDPushMessageContentStickerBuilder {
   pub fn build(&self) -> PushMessageContentSticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentSticker> { Ok(self.build()) }
+
    
   pub fn sticker<T: AsRef<Sticker>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = Some(sticker.as_ref().clone());
@@ -2062,7 +2511,16 @@ impl PushMessageContentText {
     let mut inner = PushMessageContentText::default();
     inner.td_name = "pushMessageContentText".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentTextBuilder { inner }
+    RTDPushMessageContentTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentText", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -2073,15 +2531,25 @@ impl PushMessageContentText {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentTextBuilder {
-  inner: PushMessageContentText
+  inner: PushMessageContentText,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentTextBuilder {
   pub fn build(&self) -> PushMessageContentText { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentText> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentText", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2144,7 +2612,16 @@ impl PushMessageContentVideo {
     let mut inner = PushMessageContentVideo::default();
     inner.td_name = "pushMessageContentVideo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushMessageContentVideoBuilder { inner }
+    RTDPushMessageContentVideoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.caption.is_empty() { missing.push("caption".to_string()); }
+    missing
   }
 
   pub fn video(&self) -> &Option<Video> { &self.video }
@@ -2159,12 +2636,21 @@ impl PushMessageContentVideo {
 
 #[doc(hidden)]
 pub struct RTDPushMessageContentVideoBuilder {
-  inner: PushMessageContentVideo
+  inner: PushMessageContentVideo,
+  set: [bool; 1]
 }
 
 impl RTDPushMessageContentVideoBuilder {
   pub fn build(&self) -> PushMessageContentVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentVideo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("caption".to_string()); }
+    crate::types::_common::check_missing("pushMessageContentVideo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn video<T: AsRef<Video>>(&mut self, video: T) -> &mut Self {
     self.inner.video = Some(video.as_ref().clone());
@@ -2174,6 +2660,7 @@ impl RTDPushMessageContentVideoBuilder {
    
   pub fn caption<T: AsRef<str>>(&mut self, caption: T) -> &mut Self {
     self.inner.caption = caption.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -2241,6 +2728,11 @@ impl PushMessageContentVideoNote {
     RTDPushMessageContentVideoNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn video_note(&self) -> &Option<VideoNote> { &self.video_note }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -2255,6 +2747,9 @@ pub struct RTDPushMessageContentVideoNoteBuilder {
 impl RTDPushMessageContentVideoNoteBuilder {
   pub fn build(&self) -> PushMessageContentVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentVideoNote> { Ok(self.build()) }
+
    
   pub fn video_note<T: AsRef<VideoNote>>(&mut self, video_note: T) -> &mut Self {
     self.inner.video_note = Some(video_note.as_ref().clone());
@@ -2319,6 +2814,11 @@ impl PushMessageContentVoiceNote {
     RTDPushMessageContentVoiceNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushMessageContentVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn voice_note(&self) -> &Option<VoiceNote> { &self.voice_note }
 
   pub fn is_pinned(&self) -> bool { self.is_pinned }
@@ -2333,6 +2833,9 @@ pub struct RTDPushMessageContentVoiceNoteBuilder {
 impl RTDPushMessageContentVoiceNoteBuilder {
   pub fn build(&self) -> PushMessageContentVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushMessageContentVoiceNote> { Ok(self.build()) }
+
    
   pub fn voice_note<T: AsRef<VoiceNote>>(&mut self, voice_note: T) -> &mut Self {
     self.inner.voice_note = Some(voice_note.as_ref().clone());
@@ -16,7 +16,7 @@ pub struct PushReceiverId {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The globally unique identifier of push notification subscription
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   
 }
 
@@ -34,24 +34,43 @@ impl PushReceiverId {
     let mut inner = PushReceiverId::default();
     inner.td_name = "pushReceiverId".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDPushReceiverIdBuilder { inner }
+    RTDPushReceiverIdBuilder { inner, set: [false; 1] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("pushReceiverId", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDPushReceiverIdBuilder {
-  inner: PushReceiverId
+  inner: PushReceiverId,
+  set: [bool; 1]
 }
 
 impl RTDPushReceiverIdBuilder {
   pub fn build(&self) -> PushReceiverId { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<PushReceiverId> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    crate::types::_common::check_missing("pushReceiverId", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
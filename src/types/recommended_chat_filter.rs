@@ -36,7 +36,17 @@ impl RecommendedChatFilter {
     let mut inner = RecommendedChatFilter::default();
     inner.td_name = "recommendedChatFilter".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRecommendedChatFilterBuilder { inner }
+    RTDRecommendedChatFilterBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("recommendedChatFilter", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.filter._missing_fields())); }
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    missing
   }
 
   pub fn filter(&self) -> &ChatFilter { &self.filter }
@@ -47,21 +57,33 @@ impl RecommendedChatFilter {
 
 #[doc(hidden)]
 pub struct RTDRecommendedChatFilterBuilder {
-  inner: RecommendedChatFilter
+  inner: RecommendedChatFilter,
+  set: [bool; 2]
 }
 
 impl RTDRecommendedChatFilterBuilder {
   pub fn build(&self) -> RecommendedChatFilter { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RecommendedChatFilter> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("filter".to_string()); } else { missing.extend(crate::types::_common::missing_in("filter", self.inner.filter._missing_fields())); }
+    if !self.set[1] { missing.push("description".to_string()); }
+    crate::types::_common::check_missing("recommendedChatFilter", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn filter<T: AsRef<ChatFilter>>(&mut self, filter: T) -> &mut Self {
     self.inner.filter = filter.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
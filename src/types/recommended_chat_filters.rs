@@ -37,6 +37,11 @@ impl RecommendedChatFilters {
     RTDRecommendedChatFiltersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("recommendedChatFilters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_filters(&self) -> &Vec<RecommendedChatFilter> { &self.chat_filters }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDRecommendedChatFiltersBuilder {
 impl RTDRecommendedChatFiltersBuilder {
   pub fn build(&self) -> RecommendedChatFilters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RecommendedChatFilters> { Ok(self.build()) }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<RecommendedChatFilter>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -34,7 +34,16 @@ impl RecoveryEmailAddress {
     let mut inner = RecoveryEmailAddress::default();
     inner.td_name = "recoveryEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRecoveryEmailAddressBuilder { inner }
+    RTDRecoveryEmailAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("recoveryEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.recovery_email_address.is_empty() { missing.push("recovery_email_address".to_string()); }
+    missing
   }
 
   pub fn recovery_email_address(&self) -> &String { &self.recovery_email_address }
@@ -43,15 +52,25 @@ impl RecoveryEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDRecoveryEmailAddressBuilder {
-  inner: RecoveryEmailAddress
+  inner: RecoveryEmailAddress,
+  set: [bool; 1]
 }
 
 impl RTDRecoveryEmailAddressBuilder {
   pub fn build(&self) -> RecoveryEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RecoveryEmailAddress> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("recovery_email_address".to_string()); }
+    crate::types::_common::check_missing("recoveryEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn recovery_email_address<T: AsRef<str>>(&mut self, recovery_email_address: T) -> &mut Self {
     self.inner.recovery_email_address = recovery_email_address.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
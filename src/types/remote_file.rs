@@ -45,6 +45,11 @@ impl RemoteFile {
     RTDRemoteFileBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("remoteFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn id(&self) -> &String { &self.id }
 
   pub fn unique_id(&self) -> &String { &self.unique_id }
@@ -65,6 +70,9 @@ pub struct RTDRemoteFileBuilder {
 impl RTDRemoteFileBuilder {
   pub fn build(&self) -> RemoteFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RemoteFile> { Ok(self.build()) }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
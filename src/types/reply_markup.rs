@@ -19,6 +19,9 @@ pub trait TDReplyMarkup: Debug + RObject {}
 #[serde(untagged)]
 pub enum ReplyMarkup {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Instructs application to force a reply to this message
   ForceReply(ReplyMarkupForceReply),
   /// Contains an inline keyboard layout
@@ -36,7 +39,6 @@ impl Default for ReplyMarkup {
 
 impl<'de> Deserialize<'de> for ReplyMarkup {
   fn deserialize<D>(deserializer: D) -> Result<ReplyMarkup, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ReplyMarkup,
       (replyMarkupForceReply, ForceReply);
@@ -65,6 +67,7 @@ impl RObject for ReplyMarkup {
       ReplyMarkup::InlineKeyboard(t) => t.extra(),
       ReplyMarkup::RemoveKeyboard(t) => t.extra(),
       ReplyMarkup::ShowKeyboard(t) => t.extra(),
+      ReplyMarkup::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for ReplyMarkup {
 impl ReplyMarkup {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ReplyMarkup::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ReplyMarkup::ForceReply(t) => t._missing_fields(),
+      ReplyMarkup::InlineKeyboard(t) => t._missing_fields(),
+      ReplyMarkup::RemoveKeyboard(t) => t._missing_fields(),
+      ReplyMarkup::ShowKeyboard(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_force_reply(&self) -> bool { if let ReplyMarkup::ForceReply(_) = self { true } else { false } }
   pub fn is_inline_keyboard(&self) -> bool { if let ReplyMarkup::InlineKeyboard(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<ReplyMarkup> for ReplyMarkup {
   fn as_ref(&self) -> &ReplyMarkup { self }
 }
 
+impl RVariant<ReplyMarkup> for ReplyMarkupForceReply {
+  fn from_class(class: &ReplyMarkup) -> Option<&Self> { match class { ReplyMarkup::ForceReply(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ReplyMarkup> for ReplyMarkupInlineKeyboard {
+  fn from_class(class: &ReplyMarkup) -> Option<&Self> { match class { ReplyMarkup::InlineKeyboard(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ReplyMarkup> for ReplyMarkupRemoveKeyboard {
+  fn from_class(class: &ReplyMarkup) -> Option<&Self> { match class { ReplyMarkup::RemoveKeyboard(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ReplyMarkup> for ReplyMarkupShowKeyboard {
+  fn from_class(class: &ReplyMarkup) -> Option<&Self> { match class { ReplyMarkup::ShowKeyboard(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -147,6 +175,11 @@ impl ReplyMarkupForceReply {
     RTDReplyMarkupForceReplyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("replyMarkupForceReply", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_personal(&self) -> bool { self.is_personal }
 
 }
@@ -159,6 +192,9 @@ pub struct RTDReplyMarkupForceReplyBuilder {
 impl RTDReplyMarkupForceReplyBuilder {
   pub fn build(&self) -> ReplyMarkupForceReply { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupForceReply> { Ok(self.build()) }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -215,6 +251,11 @@ impl ReplyMarkupInlineKeyboard {
     RTDReplyMarkupInlineKeyboardBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("replyMarkupInlineKeyboard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn rows(&self) -> &Vec<Vec<InlineKeyboardButton>> { &self.rows }
 
 }
@@ -227,6 +268,9 @@ pub struct RTDReplyMarkupInlineKeyboardBuilder {
 impl RTDReplyMarkupInlineKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupInlineKeyboard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupInlineKeyboard> { Ok(self.build()) }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<InlineKeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
@@ -283,6 +327,11 @@ impl ReplyMarkupRemoveKeyboard {
     RTDReplyMarkupRemoveKeyboardBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("replyMarkupRemoveKeyboard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_personal(&self) -> bool { self.is_personal }
 
 }
@@ -295,6 +344,9 @@ pub struct RTDReplyMarkupRemoveKeyboardBuilder {
 impl RTDReplyMarkupRemoveKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupRemoveKeyboard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupRemoveKeyboard> { Ok(self.build()) }
+
    
   pub fn is_personal(&mut self, is_personal: bool) -> &mut Self {
     self.inner.is_personal = is_personal;
@@ -357,6 +409,11 @@ impl ReplyMarkupShowKeyboard {
     RTDReplyMarkupShowKeyboardBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("replyMarkupShowKeyboard", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn rows(&self) -> &Vec<Vec<KeyboardButton>> { &self.rows }
 
   pub fn resize_keyboard(&self) -> bool { self.resize_keyboard }
@@ -375,6 +432,9 @@ pub struct RTDReplyMarkupShowKeyboardBuilder {
 impl RTDReplyMarkupShowKeyboardBuilder {
   pub fn build(&self) -> ReplyMarkupShowKeyboard { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ReplyMarkupShowKeyboard> { Ok(self.build()) }
+
    
   pub fn rows(&mut self, rows: Vec<Vec<KeyboardButton>>) -> &mut Self {
     self.inner.rows = rows;
@@ -19,6 +19,9 @@ pub trait TDRichText: Debug + RObject {}
 #[serde(untagged)]
 pub enum RichText {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// An anchor
   Anchor(RichTextAnchor),
   /// A link to an anchor on the same web page
@@ -62,7 +65,6 @@ impl Default for RichText {
 
 impl<'de> Deserialize<'de> for RichText {
   fn deserialize<D>(deserializer: D) -> Result<RichText, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       RichText,
       (richTextAnchor, Anchor);
@@ -130,6 +132,7 @@ impl RObject for RichText {
       RichText::Underline(t) => t.extra(),
       RichText::Url(t) => t.extra(),
       RichText::RichTexts(t) => t.extra(),
+      RichText::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -140,6 +143,28 @@ impl RObject for RichText {
 impl RichText {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let RichText::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      RichText::Anchor(t) => t._missing_fields(),
+      RichText::AnchorLink(t) => t._missing_fields(),
+      RichText::Bold(t) => t._missing_fields(),
+      RichText::EmailAddress(t) => t._missing_fields(),
+      RichText::Fixed(t) => t._missing_fields(),
+      RichText::Icon(t) => t._missing_fields(),
+      RichText::Italic(t) => t._missing_fields(),
+      RichText::Marked(t) => t._missing_fields(),
+      RichText::PhoneNumber(t) => t._missing_fields(),
+      RichText::Plain(t) => t._missing_fields(),
+      RichText::Reference(t) => t._missing_fields(),
+      RichText::Strikethrough(t) => t._missing_fields(),
+      RichText::Subscript(t) => t._missing_fields(),
+      RichText::Superscript(t) => t._missing_fields(),
+      RichText::Underline(t) => t._missing_fields(),
+      RichText::Url(t) => t._missing_fields(),
+      RichText::RichTexts(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_anchor(&self) -> bool { if let RichText::Anchor(_) = self { true } else { false } }
   pub fn is_anchor_link(&self) -> bool { if let RichText::AnchorLink(_) = self { true } else { false } }
@@ -237,6 +262,74 @@ impl AsRef<RichText> for RichText {
   fn as_ref(&self) -> &RichText { self }
 }
 
+impl RVariant<RichText> for RichTextAnchor {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Anchor(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextAnchorLink {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::AnchorLink(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextBold {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Bold(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextEmailAddress {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::EmailAddress(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextFixed {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Fixed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextIcon {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Icon(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextItalic {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Italic(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextMarked {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Marked(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextPhoneNumber {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::PhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextPlain {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Plain(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextReference {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Reference(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextStrikethrough {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Strikethrough(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextSubscript {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Subscript(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextSuperscript {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Superscript(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextUnderline {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Underline(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTextUrl {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::Url(t) => Some(t), _ => None } }
+}
+
+impl RVariant<RichText> for RichTexts {
+  fn from_class(class: &RichText) -> Option<&Self> { match class { RichText::RichTexts(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -274,7 +367,16 @@ impl RichTextAnchor {
     let mut inner = RichTextAnchor::default();
     inner.td_name = "richTextAnchor".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextAnchorBuilder { inner }
+    RTDRichTextAnchorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextAnchor", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -283,15 +385,25 @@ impl RichTextAnchor {
 
 #[doc(hidden)]
 pub struct RTDRichTextAnchorBuilder {
-  inner: RichTextAnchor
+  inner: RichTextAnchor,
+  set: [bool; 1]
 }
 
 impl RTDRichTextAnchorBuilder {
   pub fn build(&self) -> RichTextAnchor { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextAnchor> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("richTextAnchor", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -346,7 +458,18 @@ impl RichTextAnchorLink {
     let mut inner = RichTextAnchorLink::default();
     inner.td_name = "richTextAnchorLink".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextAnchorLinkBuilder { inner }
+    RTDRichTextAnchorLinkBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextAnchorLink", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.anchor_name.is_empty() { missing.push("anchor_name".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &Box<RichText> { &self.text }
@@ -359,12 +482,23 @@ impl RichTextAnchorLink {
 
 #[doc(hidden)]
 pub struct RTDRichTextAnchorLinkBuilder {
-  inner: RichTextAnchorLink
+  inner: RichTextAnchorLink,
+  set: [bool; 2]
 }
 
 impl RTDRichTextAnchorLinkBuilder {
   pub fn build(&self) -> RichTextAnchorLink { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextAnchorLink> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("anchor_name".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("richTextAnchorLink", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -374,12 +508,14 @@ impl RTDRichTextAnchorLinkBuilder {
    
   pub fn anchor_name<T: AsRef<str>>(&mut self, anchor_name: T) -> &mut Self {
     self.inner.anchor_name = anchor_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -433,6 +569,15 @@ impl RichTextBold {
     RTDRichTextBoldBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextBold", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -445,6 +590,14 @@ pub struct RTDRichTextBoldBuilder {
 impl RTDRichTextBoldBuilder {
   pub fn build(&self) -> RichTextBold { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextBold> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextBold", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -500,7 +653,17 @@ impl RichTextEmailAddress {
     let mut inner = RichTextEmailAddress::default();
     inner.td_name = "richTextEmailAddress".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextEmailAddressBuilder { inner }
+    RTDRichTextEmailAddressBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.email_address.is_empty() { missing.push("email_address".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &Box<RichText> { &self.text }
@@ -511,12 +674,22 @@ impl RichTextEmailAddress {
 
 #[doc(hidden)]
 pub struct RTDRichTextEmailAddressBuilder {
-  inner: RichTextEmailAddress
+  inner: RichTextEmailAddress,
+  set: [bool; 1]
 }
 
 impl RTDRichTextEmailAddressBuilder {
   pub fn build(&self) -> RichTextEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextEmailAddress> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("email_address".to_string()); }
+    crate::types::_common::check_missing("richTextEmailAddress", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -526,6 +699,7 @@ impl RTDRichTextEmailAddressBuilder {
    
   pub fn email_address<T: AsRef<str>>(&mut self, email_address: T) -> &mut Self {
     self.inner.email_address = email_address.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -579,6 +753,15 @@ impl RichTextFixed {
     RTDRichTextFixedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextFixed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -591,6 +774,14 @@ pub struct RTDRichTextFixedBuilder {
 impl RTDRichTextFixedBuilder {
   pub fn build(&self) -> RichTextFixed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextFixed> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextFixed", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -648,7 +839,16 @@ impl RichTextIcon {
     let mut inner = RichTextIcon::default();
     inner.td_name = "richTextIcon".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextIconBuilder { inner }
+    RTDRichTextIconBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextIcon", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.document._missing_fields())); }
+    missing
   }
 
   pub fn document(&self) -> &Document { &self.document }
@@ -661,15 +861,25 @@ impl RichTextIcon {
 
 #[doc(hidden)]
 pub struct RTDRichTextIconBuilder {
-  inner: RichTextIcon
+  inner: RichTextIcon,
+  set: [bool; 1]
 }
 
 impl RTDRichTextIconBuilder {
   pub fn build(&self) -> RichTextIcon { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextIcon> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("document".to_string()); } else { missing.extend(crate::types::_common::missing_in("document", self.inner.document._missing_fields())); }
+    crate::types::_common::check_missing("richTextIcon", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn document<T: AsRef<Document>>(&mut self, document: T) -> &mut Self {
     self.inner.document = document.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -735,6 +945,15 @@ impl RichTextItalic {
     RTDRichTextItalicBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextItalic", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -747,6 +966,14 @@ pub struct RTDRichTextItalicBuilder {
 impl RTDRichTextItalicBuilder {
   pub fn build(&self) -> RichTextItalic { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextItalic> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextItalic", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -803,6 +1030,15 @@ impl RichTextMarked {
     RTDRichTextMarkedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextMarked", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -815,6 +1051,14 @@ pub struct RTDRichTextMarkedBuilder {
 impl RTDRichTextMarkedBuilder {
   pub fn build(&self) -> RichTextMarked { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextMarked> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextMarked", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -870,7 +1114,17 @@ impl RichTextPhoneNumber {
     let mut inner = RichTextPhoneNumber::default();
     inner.td_name = "richTextPhoneNumber".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextPhoneNumberBuilder { inner }
+    RTDRichTextPhoneNumberBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &Box<RichText> { &self.text }
@@ -881,12 +1135,22 @@ impl RichTextPhoneNumber {
 
 #[doc(hidden)]
 pub struct RTDRichTextPhoneNumberBuilder {
-  inner: RichTextPhoneNumber
+  inner: RichTextPhoneNumber,
+  set: [bool; 1]
 }
 
 impl RTDRichTextPhoneNumberBuilder {
   pub fn build(&self) -> RichTextPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextPhoneNumber> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("phone_number".to_string()); }
+    crate::types::_common::check_missing("richTextPhoneNumber", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -896,6 +1160,7 @@ impl RTDRichTextPhoneNumberBuilder {
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -925,7 +1190,7 @@ pub struct RichTextPlain {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Text
-  text: Box<RichText>,
+  text: String,
   
 }
 
@@ -946,24 +1211,43 @@ impl RichTextPlain {
     let mut inner = RichTextPlain::default();
     inner.td_name = "richTextPlain".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextPlainBuilder { inner }
+    RTDRichTextPlainBuilder { inner, set: [false; 1] }
   }
 
-  pub fn text(&self) -> &Box<RichText> { &self.text }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextPlain", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
+  }
+
+  pub fn text(&self) -> &String { &self.text }
 
 }
 
 #[doc(hidden)]
 pub struct RTDRichTextPlainBuilder {
-  inner: RichTextPlain
+  inner: RichTextPlain,
+  set: [bool; 1]
 }
 
 impl RTDRichTextPlainBuilder {
   pub fn build(&self) -> RichTextPlain { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextPlain> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("richTextPlain", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
-    self.inner.text = text.as_ref().clone();
+  pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
+    self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1018,7 +1302,18 @@ impl RichTextReference {
     let mut inner = RichTextReference::default();
     inner.td_name = "richTextReference".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextReferenceBuilder { inner }
+    RTDRichTextReferenceBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextReference", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.anchor_name.is_empty() { missing.push("anchor_name".to_string()); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &Box<RichText> { &self.text }
@@ -1031,12 +1326,23 @@ impl RichTextReference {
 
 #[doc(hidden)]
 pub struct RTDRichTextReferenceBuilder {
-  inner: RichTextReference
+  inner: RichTextReference,
+  set: [bool; 2]
 }
 
 impl RTDRichTextReferenceBuilder {
   pub fn build(&self) -> RichTextReference { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextReference> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("anchor_name".to_string()); }
+    if !self.set[1] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("richTextReference", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1046,12 +1352,14 @@ impl RTDRichTextReferenceBuilder {
    
   pub fn anchor_name<T: AsRef<str>>(&mut self, anchor_name: T) -> &mut Self {
     self.inner.anchor_name = anchor_name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -1105,6 +1413,15 @@ impl RichTextStrikethrough {
     RTDRichTextStrikethroughBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextStrikethrough", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1117,6 +1434,14 @@ pub struct RTDRichTextStrikethroughBuilder {
 impl RTDRichTextStrikethroughBuilder {
   pub fn build(&self) -> RichTextStrikethrough { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextStrikethrough> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextStrikethrough", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1173,6 +1498,15 @@ impl RichTextSubscript {
     RTDRichTextSubscriptBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextSubscript", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1185,6 +1519,14 @@ pub struct RTDRichTextSubscriptBuilder {
 impl RTDRichTextSubscriptBuilder {
   pub fn build(&self) -> RichTextSubscript { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextSubscript> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextSubscript", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1241,6 +1583,15 @@ impl RichTextSuperscript {
     RTDRichTextSuperscriptBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextSuperscript", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1253,6 +1604,14 @@ pub struct RTDRichTextSuperscriptBuilder {
 impl RTDRichTextSuperscriptBuilder {
   pub fn build(&self) -> RichTextSuperscript { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextSuperscript> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextSuperscript", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1309,6 +1668,15 @@ impl RichTextUnderline {
     RTDRichTextUnderlineBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextUnderline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
+  }
+
   pub fn text(&self) -> &Box<RichText> { &self.text }
 
 }
@@ -1321,6 +1689,14 @@ pub struct RTDRichTextUnderlineBuilder {
 impl RTDRichTextUnderlineBuilder {
   pub fn build(&self) -> RichTextUnderline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextUnderline> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("richTextUnderline", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1378,7 +1754,17 @@ impl RichTextUrl {
     let mut inner = RichTextUrl::default();
     inner.td_name = "richTextUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDRichTextUrlBuilder { inner }
+    RTDRichTextUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTextUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &Box<RichText> { &self.text }
@@ -1391,12 +1777,22 @@ impl RichTextUrl {
 
 #[doc(hidden)]
 pub struct RTDRichTextUrlBuilder {
-  inner: RichTextUrl
+  inner: RichTextUrl,
+  set: [bool; 1]
 }
 
 impl RTDRichTextUrlBuilder {
   pub fn build(&self) -> RichTextUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTextUrl> {
+    let mut missing = vec![];
+    if self.inner.text._is_default() { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("richTextUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<Box<RichText>>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
@@ -1406,6 +1802,7 @@ impl RTDRichTextUrlBuilder {
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1465,6 +1862,11 @@ impl RichTexts {
     RTDRichTextsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("richTexts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn texts(&self) -> &Vec<RichText> { &self.texts }
 
 }
@@ -1477,6 +1879,9 @@ pub struct RTDRichTextsBuilder {
 impl RTDRichTextsBuilder {
   pub fn build(&self) -> RichTexts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<RichTexts> { Ok(self.build()) }
+
    
   pub fn texts(&mut self, texts: Vec<RichText>) -> &mut Self {
     self.inner.texts = texts;
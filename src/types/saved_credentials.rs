@@ -36,7 +36,17 @@ impl SavedCredentials {
     let mut inner = SavedCredentials::default();
     inner.td_name = "savedCredentials".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSavedCredentialsBuilder { inner }
+    RTDSavedCredentialsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("savedCredentials", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -47,21 +57,33 @@ impl SavedCredentials {
 
 #[doc(hidden)]
 pub struct RTDSavedCredentialsBuilder {
-  inner: SavedCredentials
+  inner: SavedCredentials,
+  set: [bool; 2]
 }
 
 impl RTDSavedCredentialsBuilder {
   pub fn build(&self) -> SavedCredentials { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SavedCredentials> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("savedCredentials", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
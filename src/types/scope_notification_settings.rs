@@ -42,7 +42,17 @@ impl ScopeNotificationSettings {
     let mut inner = ScopeNotificationSettings::default();
     inner.td_name = "scopeNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDScopeNotificationSettingsBuilder { inner }
+    RTDScopeNotificationSettingsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("scopeNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.mute_for == 0 { missing.push("mute_for".to_string()); }
+    if self.sound.is_empty() { missing.push("sound".to_string()); }
+    missing
   }
 
   pub fn mute_for(&self) -> i64 { self.mute_for }
@@ -59,21 +69,33 @@ impl ScopeNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDScopeNotificationSettingsBuilder {
-  inner: ScopeNotificationSettings
+  inner: ScopeNotificationSettings,
+  set: [bool; 2]
 }
 
 impl RTDScopeNotificationSettingsBuilder {
   pub fn build(&self) -> ScopeNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ScopeNotificationSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("mute_for".to_string()); }
+    if !self.set[1] { missing.push("sound".to_string()); }
+    crate::types::_common::check_missing("scopeNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn mute_for(&mut self, mute_for: i64) -> &mut Self {
     self.inner.mute_for = mute_for;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sound<T: AsRef<str>>(&mut self, sound: T) -> &mut Self {
     self.inner.sound = sound.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
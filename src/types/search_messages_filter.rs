@@ -19,6 +19,9 @@ pub trait TDSearchMessagesFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum SearchMessagesFilter {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns only animation messages
   Animation(SearchMessagesFilterAnimation),
   /// Returns only audio messages
@@ -64,7 +67,6 @@ impl Default for SearchMessagesFilter {
 
 impl<'de> Deserialize<'de> for SearchMessagesFilter {
   fn deserialize<D>(deserializer: D) -> Result<SearchMessagesFilter, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       SearchMessagesFilter,
       (searchMessagesFilterAnimation, Animation);
@@ -135,6 +137,7 @@ impl RObject for SearchMessagesFilter {
       SearchMessagesFilter::VideoNote(t) => t.extra(),
       SearchMessagesFilter::VoiceAndVideoNote(t) => t.extra(),
       SearchMessagesFilter::VoiceNote(t) => t.extra(),
+      SearchMessagesFilter::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -145,6 +148,29 @@ impl RObject for SearchMessagesFilter {
 impl SearchMessagesFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SearchMessagesFilter::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      SearchMessagesFilter::Animation(t) => t._missing_fields(),
+      SearchMessagesFilter::Audio(t) => t._missing_fields(),
+      SearchMessagesFilter::Call(t) => t._missing_fields(),
+      SearchMessagesFilter::ChatPhoto(t) => t._missing_fields(),
+      SearchMessagesFilter::Document(t) => t._missing_fields(),
+      SearchMessagesFilter::Empty(t) => t._missing_fields(),
+      SearchMessagesFilter::FailedToSend(t) => t._missing_fields(),
+      SearchMessagesFilter::Mention(t) => t._missing_fields(),
+      SearchMessagesFilter::MissedCall(t) => t._missing_fields(),
+      SearchMessagesFilter::Photo(t) => t._missing_fields(),
+      SearchMessagesFilter::PhotoAndVideo(t) => t._missing_fields(),
+      SearchMessagesFilter::Pinned(t) => t._missing_fields(),
+      SearchMessagesFilter::UnreadMention(t) => t._missing_fields(),
+      SearchMessagesFilter::Url(t) => t._missing_fields(),
+      SearchMessagesFilter::Video(t) => t._missing_fields(),
+      SearchMessagesFilter::VideoNote(t) => t._missing_fields(),
+      SearchMessagesFilter::VoiceAndVideoNote(t) => t._missing_fields(),
+      SearchMessagesFilter::VoiceNote(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_animation(&self) -> bool { if let SearchMessagesFilter::Animation(_) = self { true } else { false } }
   pub fn is_audio(&self) -> bool { if let SearchMessagesFilter::Audio(_) = self { true } else { false } }
@@ -247,6 +273,78 @@ impl AsRef<SearchMessagesFilter> for SearchMessagesFilter {
   fn as_ref(&self) -> &SearchMessagesFilter { self }
 }
 
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterAnimation {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Animation(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterAudio {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Audio(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterCall {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Call(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterChatPhoto {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::ChatPhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterDocument {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Document(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterEmpty {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Empty(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterFailedToSend {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::FailedToSend(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterMention {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Mention(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterMissedCall {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::MissedCall(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterPhoto {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Photo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterPhotoAndVideo {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::PhotoAndVideo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterPinned {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Pinned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterUnreadMention {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::UnreadMention(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterUrl {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Url(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterVideo {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::Video(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterVideoNote {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::VideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterVoiceAndVideoNote {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::VoiceAndVideoNote(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SearchMessagesFilter> for SearchMessagesFilterVoiceNote {
+  fn from_class(class: &SearchMessagesFilter) -> Option<&Self> { match class { SearchMessagesFilter::VoiceNote(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -285,6 +383,11 @@ impl SearchMessagesFilterAnimation {
     RTDSearchMessagesFilterAnimationBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterAnimation", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -295,6 +398,9 @@ pub struct RTDSearchMessagesFilterAnimationBuilder {
 impl RTDSearchMessagesFilterAnimationBuilder {
   pub fn build(&self) -> SearchMessagesFilterAnimation { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterAnimation> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterAnimation> for SearchMessagesFilterAnimation {
@@ -343,6 +449,11 @@ impl SearchMessagesFilterAudio {
     RTDSearchMessagesFilterAudioBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterAudio", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -353,6 +464,9 @@ pub struct RTDSearchMessagesFilterAudioBuilder {
 impl RTDSearchMessagesFilterAudioBuilder {
   pub fn build(&self) -> SearchMessagesFilterAudio { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterAudio> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterAudio> for SearchMessagesFilterAudio {
@@ -401,6 +515,11 @@ impl SearchMessagesFilterCall {
     RTDSearchMessagesFilterCallBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -411,6 +530,9 @@ pub struct RTDSearchMessagesFilterCallBuilder {
 impl RTDSearchMessagesFilterCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterCall> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterCall> for SearchMessagesFilterCall {
@@ -459,6 +581,11 @@ impl SearchMessagesFilterChatPhoto {
     RTDSearchMessagesFilterChatPhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterChatPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -469,6 +596,9 @@ pub struct RTDSearchMessagesFilterChatPhotoBuilder {
 impl RTDSearchMessagesFilterChatPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterChatPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterChatPhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterChatPhoto> for SearchMessagesFilterChatPhoto {
@@ -517,6 +647,11 @@ impl SearchMessagesFilterDocument {
     RTDSearchMessagesFilterDocumentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterDocument", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -527,6 +662,9 @@ pub struct RTDSearchMessagesFilterDocumentBuilder {
 impl RTDSearchMessagesFilterDocumentBuilder {
   pub fn build(&self) -> SearchMessagesFilterDocument { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterDocument> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterDocument> for SearchMessagesFilterDocument {
@@ -575,6 +713,11 @@ impl SearchMessagesFilterEmpty {
     RTDSearchMessagesFilterEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -585,6 +728,9 @@ pub struct RTDSearchMessagesFilterEmptyBuilder {
 impl RTDSearchMessagesFilterEmptyBuilder {
   pub fn build(&self) -> SearchMessagesFilterEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterEmpty> for SearchMessagesFilterEmpty {
@@ -633,6 +779,11 @@ impl SearchMessagesFilterFailedToSend {
     RTDSearchMessagesFilterFailedToSendBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterFailedToSend", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -643,6 +794,9 @@ pub struct RTDSearchMessagesFilterFailedToSendBuilder {
 impl RTDSearchMessagesFilterFailedToSendBuilder {
   pub fn build(&self) -> SearchMessagesFilterFailedToSend { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterFailedToSend> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterFailedToSend> for SearchMessagesFilterFailedToSend {
@@ -691,6 +845,11 @@ impl SearchMessagesFilterMention {
     RTDSearchMessagesFilterMentionBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterMention", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -701,6 +860,9 @@ pub struct RTDSearchMessagesFilterMentionBuilder {
 impl RTDSearchMessagesFilterMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterMention { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterMention> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterMention> for SearchMessagesFilterMention {
@@ -749,6 +911,11 @@ impl SearchMessagesFilterMissedCall {
     RTDSearchMessagesFilterMissedCallBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterMissedCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -759,6 +926,9 @@ pub struct RTDSearchMessagesFilterMissedCallBuilder {
 impl RTDSearchMessagesFilterMissedCallBuilder {
   pub fn build(&self) -> SearchMessagesFilterMissedCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterMissedCall> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterMissedCall> for SearchMessagesFilterMissedCall {
@@ -807,6 +977,11 @@ impl SearchMessagesFilterPhoto {
     RTDSearchMessagesFilterPhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -817,6 +992,9 @@ pub struct RTDSearchMessagesFilterPhotoBuilder {
 impl RTDSearchMessagesFilterPhotoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterPhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterPhoto> for SearchMessagesFilterPhoto {
@@ -865,6 +1043,11 @@ impl SearchMessagesFilterPhotoAndVideo {
     RTDSearchMessagesFilterPhotoAndVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterPhotoAndVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -875,6 +1058,9 @@ pub struct RTDSearchMessagesFilterPhotoAndVideoBuilder {
 impl RTDSearchMessagesFilterPhotoAndVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterPhotoAndVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterPhotoAndVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterPhotoAndVideo> for SearchMessagesFilterPhotoAndVideo {
@@ -923,6 +1109,11 @@ impl SearchMessagesFilterPinned {
     RTDSearchMessagesFilterPinnedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterPinned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -933,6 +1124,9 @@ pub struct RTDSearchMessagesFilterPinnedBuilder {
 impl RTDSearchMessagesFilterPinnedBuilder {
   pub fn build(&self) -> SearchMessagesFilterPinned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterPinned> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterPinned> for SearchMessagesFilterPinned {
@@ -981,6 +1175,11 @@ impl SearchMessagesFilterUnreadMention {
     RTDSearchMessagesFilterUnreadMentionBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterUnreadMention", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -991,6 +1190,9 @@ pub struct RTDSearchMessagesFilterUnreadMentionBuilder {
 impl RTDSearchMessagesFilterUnreadMentionBuilder {
   pub fn build(&self) -> SearchMessagesFilterUnreadMention { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterUnreadMention> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterUnreadMention> for SearchMessagesFilterUnreadMention {
@@ -1039,6 +1241,11 @@ impl SearchMessagesFilterUrl {
     RTDSearchMessagesFilterUrlBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1049,6 +1256,9 @@ pub struct RTDSearchMessagesFilterUrlBuilder {
 impl RTDSearchMessagesFilterUrlBuilder {
   pub fn build(&self) -> SearchMessagesFilterUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterUrl> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterUrl> for SearchMessagesFilterUrl {
@@ -1097,6 +1307,11 @@ impl SearchMessagesFilterVideo {
     RTDSearchMessagesFilterVideoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterVideo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1107,6 +1322,9 @@ pub struct RTDSearchMessagesFilterVideoBuilder {
 impl RTDSearchMessagesFilterVideoBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVideo> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterVideo> for SearchMessagesFilterVideo {
@@ -1155,6 +1373,11 @@ impl SearchMessagesFilterVideoNote {
     RTDSearchMessagesFilterVideoNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1165,6 +1388,9 @@ pub struct RTDSearchMessagesFilterVideoNoteBuilder {
 impl RTDSearchMessagesFilterVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVideoNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterVideoNote> for SearchMessagesFilterVideoNote {
@@ -1213,6 +1439,11 @@ impl SearchMessagesFilterVoiceAndVideoNote {
     RTDSearchMessagesFilterVoiceAndVideoNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterVoiceAndVideoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1223,6 +1454,9 @@ pub struct RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
 impl RTDSearchMessagesFilterVoiceAndVideoNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceAndVideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVoiceAndVideoNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceAndVideoNote> for SearchMessagesFilterVoiceAndVideoNote {
@@ -1271,6 +1505,11 @@ impl SearchMessagesFilterVoiceNote {
     RTDSearchMessagesFilterVoiceNoteBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("searchMessagesFilterVoiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1281,6 +1520,9 @@ pub struct RTDSearchMessagesFilterVoiceNoteBuilder {
 impl RTDSearchMessagesFilterVoiceNoteBuilder {
   pub fn build(&self) -> SearchMessagesFilterVoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SearchMessagesFilterVoiceNote> { Ok(self.build()) }
+
 }
 
 impl AsRef<SearchMessagesFilterVoiceNote> for SearchMessagesFilterVoiceNote {
@@ -34,7 +34,16 @@ impl Seconds {
     let mut inner = Seconds::default();
     inner.td_name = "seconds".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSecondsBuilder { inner }
+    RTDSecondsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("seconds", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.seconds == 0.0 { missing.push("seconds".to_string()); }
+    missing
   }
 
   pub fn seconds(&self) -> f32 { self.seconds }
@@ -43,15 +52,25 @@ impl Seconds {
 
 #[doc(hidden)]
 pub struct RTDSecondsBuilder {
-  inner: Seconds
+  inner: Seconds,
+  set: [bool; 1]
 }
 
 impl RTDSecondsBuilder {
   pub fn build(&self) -> Seconds { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Seconds> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("seconds".to_string()); }
+    crate::types::_common::check_missing("seconds", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn seconds(&mut self, seconds: f32) -> &mut Self {
     self.inner.seconds = seconds;
+    self.set[0] = true;
     self
   }
 
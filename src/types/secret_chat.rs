@@ -46,7 +46,21 @@ impl SecretChat {
     let mut inner = SecretChat::default();
     inner.td_name = "secretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSecretChatBuilder { inner }
+    RTDSecretChatBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("secretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.state._missing_fields())); }
+    if self.ttl == 0 { missing.push("ttl".to_string()); }
+    if self.key_hash.is_empty() { missing.push("key_hash".to_string()); }
+    if self.layer == 0 { missing.push("layer".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -67,21 +81,37 @@ impl SecretChat {
 
 #[doc(hidden)]
 pub struct RTDSecretChatBuilder {
-  inner: SecretChat
+  inner: SecretChat,
+  set: [bool; 5]
 }
 
 impl RTDSecretChatBuilder {
   pub fn build(&self) -> SecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if self.inner.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.inner.state._missing_fields())); }
+    if !self.set[2] { missing.push("ttl".to_string()); }
+    if !self.set[3] { missing.push("key_hash".to_string()); }
+    if !self.set[4] { missing.push("layer".to_string()); }
+    crate::types::_common::check_missing("secretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -100,18 +130,21 @@ impl RTDSecretChatBuilder {
    
   pub fn ttl(&mut self, ttl: i64) -> &mut Self {
     self.inner.ttl = ttl;
+    self.set[2] = true;
     self
   }
 
    
   pub fn key_hash<T: AsRef<str>>(&mut self, key_hash: T) -> &mut Self {
     self.inner.key_hash = key_hash.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn layer(&mut self, layer: i64) -> &mut Self {
     self.inner.layer = layer;
+    self.set[4] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDSecretChatState: Debug + RObject {}
 #[serde(untagged)]
 pub enum SecretChatState {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The secret chat is closed
   Closed(SecretChatStateClosed),
   /// The secret chat is not yet created; waiting for the other user to get online
@@ -34,7 +37,6 @@ impl Default for SecretChatState {
 
 impl<'de> Deserialize<'de> for SecretChatState {
   fn deserialize<D>(deserializer: D) -> Result<SecretChatState, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       SecretChatState,
       (secretChatStateClosed, Closed);
@@ -60,6 +62,7 @@ impl RObject for SecretChatState {
       SecretChatState::Closed(t) => t.extra(),
       SecretChatState::Pending(t) => t.extra(),
       SecretChatState::Ready(t) => t.extra(),
+      SecretChatState::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for SecretChatState {
 impl SecretChatState {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SecretChatState::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      SecretChatState::Closed(t) => t._missing_fields(),
+      SecretChatState::Pending(t) => t._missing_fields(),
+      SecretChatState::Ready(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_closed(&self) -> bool { if let SecretChatState::Closed(_) = self { true } else { false } }
   pub fn is_pending(&self) -> bool { if let SecretChatState::Pending(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<SecretChatState> for SecretChatState {
   fn as_ref(&self) -> &SecretChatState { self }
 }
 
+impl RVariant<SecretChatState> for SecretChatStateClosed {
+  fn from_class(class: &SecretChatState) -> Option<&Self> { match class { SecretChatState::Closed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SecretChatState> for SecretChatStatePending {
+  fn from_class(class: &SecretChatState) -> Option<&Self> { match class { SecretChatState::Pending(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SecretChatState> for SecretChatStateReady {
+  fn from_class(class: &SecretChatState) -> Option<&Self> { match class { SecretChatState::Ready(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl SecretChatStateClosed {
     RTDSecretChatStateClosedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("secretChatStateClosed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDSecretChatStateClosedBuilder {
 impl RTDSecretChatStateClosedBuilder {
   pub fn build(&self) -> SecretChatStateClosed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SecretChatStateClosed> { Ok(self.build()) }
+
 }
 
 impl AsRef<SecretChatStateClosed> for SecretChatStateClosed {
@@ -193,6 +224,11 @@ impl SecretChatStatePending {
     RTDSecretChatStatePendingBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("secretChatStatePending", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,9 @@ pub struct RTDSecretChatStatePendingBuilder {
 impl RTDSecretChatStatePendingBuilder {
   pub fn build(&self) -> SecretChatStatePending { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SecretChatStatePending> { Ok(self.build()) }
+
 }
 
 impl AsRef<SecretChatStatePending> for SecretChatStatePending {
@@ -251,6 +290,11 @@ impl SecretChatStateReady {
     RTDSecretChatStateReadyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("secretChatStateReady", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +305,9 @@ pub struct RTDSecretChatStateReadyBuilder {
 impl RTDSecretChatStateReadyBuilder {
   pub fn build(&self) -> SecretChatStateReady { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SecretChatStateReady> { Ok(self.build()) }
+
 }
 
 impl AsRef<SecretChatStateReady> for SecretChatStateReady {
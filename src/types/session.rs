@@ -16,7 +16,7 @@ pub struct Session {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Session identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// True, if this session is the current session
   is_current: bool,
   /// True, if a password is needed to complete authorization of the session
@@ -62,10 +62,30 @@ impl Session {
     let mut inner = Session::default();
     inner.td_name = "session".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSessionBuilder { inner }
+    RTDSessionBuilder { inner, set: [false; 12] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("session", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.api_id == 0 { missing.push("api_id".to_string()); }
+    if self.application_name.is_empty() { missing.push("application_name".to_string()); }
+    if self.application_version.is_empty() { missing.push("application_version".to_string()); }
+    if self.device_model.is_empty() { missing.push("device_model".to_string()); }
+    if self.platform.is_empty() { missing.push("platform".to_string()); }
+    if self.system_version.is_empty() { missing.push("system_version".to_string()); }
+    if self.log_in_date == 0 { missing.push("log_in_date".to_string()); }
+    if self.last_active_date == 0 { missing.push("last_active_date".to_string()); }
+    if self.ip.is_empty() { missing.push("ip".to_string()); }
+    if self.country.is_empty() { missing.push("country".to_string()); }
+    if self.region.is_empty() { missing.push("region".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn is_current(&self) -> bool { self.is_current }
 
@@ -99,15 +119,36 @@ impl Session {
 
 #[doc(hidden)]
 pub struct RTDSessionBuilder {
-  inner: Session
+  inner: Session,
+  set: [bool; 12]
 }
 
 impl RTDSessionBuilder {
   pub fn build(&self) -> Session { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Session> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("api_id".to_string()); }
+    if !self.set[2] { missing.push("application_name".to_string()); }
+    if !self.set[3] { missing.push("application_version".to_string()); }
+    if !self.set[4] { missing.push("device_model".to_string()); }
+    if !self.set[5] { missing.push("platform".to_string()); }
+    if !self.set[6] { missing.push("system_version".to_string()); }
+    if !self.set[7] { missing.push("log_in_date".to_string()); }
+    if !self.set[8] { missing.push("last_active_date".to_string()); }
+    if !self.set[9] { missing.push("ip".to_string()); }
+    if !self.set[10] { missing.push("country".to_string()); }
+    if !self.set[11] { missing.push("region".to_string()); }
+    crate::types::_common::check_missing("session", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
@@ -126,18 +167,21 @@ impl RTDSessionBuilder {
    
   pub fn api_id(&mut self, api_id: i64) -> &mut Self {
     self.inner.api_id = api_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn application_name<T: AsRef<str>>(&mut self, application_name: T) -> &mut Self {
     self.inner.application_name = application_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn application_version<T: AsRef<str>>(&mut self, application_version: T) -> &mut Self {
     self.inner.application_version = application_version.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -150,48 +194,56 @@ impl RTDSessionBuilder {
    
   pub fn device_model<T: AsRef<str>>(&mut self, device_model: T) -> &mut Self {
     self.inner.device_model = device_model.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn platform<T: AsRef<str>>(&mut self, platform: T) -> &mut Self {
     self.inner.platform = platform.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
    
   pub fn system_version<T: AsRef<str>>(&mut self, system_version: T) -> &mut Self {
     self.inner.system_version = system_version.as_ref().to_string();
+    self.set[6] = true;
     self
   }
 
    
   pub fn log_in_date(&mut self, log_in_date: i64) -> &mut Self {
     self.inner.log_in_date = log_in_date;
+    self.set[7] = true;
     self
   }
 
    
   pub fn last_active_date(&mut self, last_active_date: i64) -> &mut Self {
     self.inner.last_active_date = last_active_date;
+    self.set[8] = true;
     self
   }
 
    
   pub fn ip<T: AsRef<str>>(&mut self, ip: T) -> &mut Self {
     self.inner.ip = ip.as_ref().to_string();
+    self.set[9] = true;
     self
   }
 
    
   pub fn country<T: AsRef<str>>(&mut self, country: T) -> &mut Self {
     self.inner.country = country.as_ref().to_string();
+    self.set[10] = true;
     self
   }
 
    
   pub fn region<T: AsRef<str>>(&mut self, region: T) -> &mut Self {
     self.inner.region = region.as_ref().to_string();
+    self.set[11] = true;
     self
   }
 
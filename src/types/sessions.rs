@@ -37,6 +37,11 @@ impl Sessions {
     RTDSessionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sessions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn sessions(&self) -> &Vec<Session> { &self.sessions }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDSessionsBuilder {
 impl RTDSessionsBuilder {
   pub fn build(&self) -> Sessions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Sessions> { Ok(self.build()) }
+
    
   pub fn sessions(&mut self, sessions: Vec<Session>) -> &mut Self {
     self.inner.sessions = sessions;
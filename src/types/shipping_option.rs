@@ -38,7 +38,17 @@ impl ShippingOption {
     let mut inner = ShippingOption::default();
     inner.td_name = "shippingOption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDShippingOptionBuilder { inner }
+    RTDShippingOptionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("shippingOption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> &String { &self.id }
@@ -51,21 +61,33 @@ impl ShippingOption {
 
 #[doc(hidden)]
 pub struct RTDShippingOptionBuilder {
-  inner: ShippingOption
+  inner: ShippingOption,
+  set: [bool; 2]
 }
 
 impl RTDShippingOptionBuilder {
   pub fn build(&self) -> ShippingOption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ShippingOption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("shippingOption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -19,8 +19,9 @@ pub trait TDStatisticalGraph: Debug + RObject {}
 #[serde(untagged)]
 pub enum StatisticalGraph {
   #[doc(hidden)] _Default(()),
-  /// Loads an asynchronous or a zoomed in statistical graph
-  GetStatisticalGraph(GetStatisticalGraph),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The graph data to be asynchronously loaded through getStatisticalGraph
   Async(StatisticalGraphAsync),
   /// A graph data
@@ -36,10 +37,8 @@ impl Default for StatisticalGraph {
 
 impl<'de> Deserialize<'de> for StatisticalGraph {
   fn deserialize<D>(deserializer: D) -> Result<StatisticalGraph, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       StatisticalGraph,
-      (getStatisticalGraph, GetStatisticalGraph);
       (statisticalGraphAsync, Async);
       (statisticalGraphData, Data);
       (statisticalGraphError, Error);
@@ -51,7 +50,6 @@ impl<'de> Deserialize<'de> for StatisticalGraph {
 impl RObject for StatisticalGraph {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      StatisticalGraph::GetStatisticalGraph(t) => t.td_name(),
       StatisticalGraph::Async(t) => t.td_name(),
       StatisticalGraph::Data(t) => t.td_name(),
       StatisticalGraph::Error(t) => t.td_name(),
@@ -61,10 +59,10 @@ impl RObject for StatisticalGraph {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      StatisticalGraph::GetStatisticalGraph(t) => t.extra(),
       StatisticalGraph::Async(t) => t.extra(),
       StatisticalGraph::Data(t) => t.extra(),
       StatisticalGraph::Error(t) => t.extra(),
+      StatisticalGraph::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,26 +73,29 @@ impl RObject for StatisticalGraph {
 impl StatisticalGraph {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let StatisticalGraph::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      StatisticalGraph::Async(t) => t._missing_fields(),
+      StatisticalGraph::Data(t) => t._missing_fields(),
+      StatisticalGraph::Error(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_get_statistical_graph(&self) -> bool { if let StatisticalGraph::GetStatisticalGraph(_) = self { true } else { false } }
   pub fn is_async(&self) -> bool { if let StatisticalGraph::Async(_) = self { true } else { false } }
   pub fn is_data(&self) -> bool { if let StatisticalGraph::Data(_) = self { true } else { false } }
   pub fn is_error(&self) -> bool { if let StatisticalGraph::Error(_) = self { true } else { false } }
 
-  pub fn on_get_statistical_graph<F: FnOnce(&GetStatisticalGraph)>(&self, fnc: F) -> &Self { if let StatisticalGraph::GetStatisticalGraph(t) = self { fnc(t) }; self }
   pub fn on_async<F: FnOnce(&StatisticalGraphAsync)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Async(t) = self { fnc(t) }; self }
   pub fn on_data<F: FnOnce(&StatisticalGraphData)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Data(t) = self { fnc(t) }; self }
   pub fn on_error<F: FnOnce(&StatisticalGraphError)>(&self, fnc: F) -> &Self { if let StatisticalGraph::Error(t) = self { fnc(t) }; self }
 
-  pub fn as_get_statistical_graph(&self) -> Option<&GetStatisticalGraph> { if let StatisticalGraph::GetStatisticalGraph(t) = self { return Some(t) } None }
   pub fn as_async(&self) -> Option<&StatisticalGraphAsync> { if let StatisticalGraph::Async(t) = self { return Some(t) } None }
   pub fn as_data(&self) -> Option<&StatisticalGraphData> { if let StatisticalGraph::Data(t) = self { return Some(t) } None }
   pub fn as_error(&self) -> Option<&StatisticalGraphError> { if let StatisticalGraph::Error(t) = self { return Some(t) } None }
 
 
 
-  pub fn get_statistical_graph<T: AsRef<GetStatisticalGraph>>(t: T) -> Self { StatisticalGraph::GetStatisticalGraph(t.as_ref().clone()) }
-
   pub fn async_<T: AsRef<StatisticalGraphAsync>>(t: T) -> Self { StatisticalGraph::Async(t.as_ref().clone()) }
 
   pub fn data<T: AsRef<StatisticalGraphData>>(t: T) -> Self { StatisticalGraph::Data(t.as_ref().clone()) }
@@ -107,6 +108,18 @@ impl AsRef<StatisticalGraph> for StatisticalGraph {
   fn as_ref(&self) -> &StatisticalGraph { self }
 }
 
+impl RVariant<StatisticalGraph> for StatisticalGraphAsync {
+  fn from_class(class: &StatisticalGraph) -> Option<&Self> { match class { StatisticalGraph::Async(t) => Some(t), _ => None } }
+}
+
+impl RVariant<StatisticalGraph> for StatisticalGraphData {
+  fn from_class(class: &StatisticalGraph) -> Option<&Self> { match class { StatisticalGraph::Data(t) => Some(t), _ => None } }
+}
+
+impl RVariant<StatisticalGraph> for StatisticalGraphError {
+  fn from_class(class: &StatisticalGraph) -> Option<&Self> { match class { StatisticalGraph::Error(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +157,16 @@ impl StatisticalGraphAsync {
     let mut inner = StatisticalGraphAsync::default();
     inner.td_name = "statisticalGraphAsync".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStatisticalGraphAsyncBuilder { inner }
+    RTDStatisticalGraphAsyncBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("statisticalGraphAsync", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.token.is_empty() { missing.push("token".to_string()); }
+    missing
   }
 
   pub fn token(&self) -> &String { &self.token }
@@ -153,15 +175,25 @@ impl StatisticalGraphAsync {
 
 #[doc(hidden)]
 pub struct RTDStatisticalGraphAsyncBuilder {
-  inner: StatisticalGraphAsync
+  inner: StatisticalGraphAsync,
+  set: [bool; 1]
 }
 
 impl RTDStatisticalGraphAsyncBuilder {
   pub fn build(&self) -> StatisticalGraphAsync { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StatisticalGraphAsync> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("token".to_string()); }
+    crate::types::_common::check_missing("statisticalGraphAsync", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn token<T: AsRef<str>>(&mut self, token: T) -> &mut Self {
     self.inner.token = token.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -214,7 +246,17 @@ impl StatisticalGraphData {
     let mut inner = StatisticalGraphData::default();
     inner.td_name = "statisticalGraphData".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStatisticalGraphDataBuilder { inner }
+    RTDStatisticalGraphDataBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("statisticalGraphData", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.json_data.is_empty() { missing.push("json_data".to_string()); }
+    if self.zoom_token.is_empty() { missing.push("zoom_token".to_string()); }
+    missing
   }
 
   pub fn json_data(&self) -> &String { &self.json_data }
@@ -225,21 +267,33 @@ impl StatisticalGraphData {
 
 #[doc(hidden)]
 pub struct RTDStatisticalGraphDataBuilder {
-  inner: StatisticalGraphData
+  inner: StatisticalGraphData,
+  set: [bool; 2]
 }
 
 impl RTDStatisticalGraphDataBuilder {
   pub fn build(&self) -> StatisticalGraphData { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StatisticalGraphData> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("json_data".to_string()); }
+    if !self.set[1] { missing.push("zoom_token".to_string()); }
+    crate::types::_common::check_missing("statisticalGraphData", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn json_data<T: AsRef<str>>(&mut self, json_data: T) -> &mut Self {
     self.inner.json_data = json_data.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn zoom_token<T: AsRef<str>>(&mut self, zoom_token: T) -> &mut Self {
     self.inner.zoom_token = zoom_token.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -290,7 +344,16 @@ impl StatisticalGraphError {
     let mut inner = StatisticalGraphError::default();
     inner.td_name = "statisticalGraphError".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStatisticalGraphErrorBuilder { inner }
+    RTDStatisticalGraphErrorBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("statisticalGraphError", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    missing
   }
 
   pub fn error_message(&self) -> &String { &self.error_message }
@@ -299,15 +362,25 @@ impl StatisticalGraphError {
 
 #[doc(hidden)]
 pub struct RTDStatisticalGraphErrorBuilder {
-  inner: StatisticalGraphError
+  inner: StatisticalGraphError,
+  set: [bool; 1]
 }
 
 impl RTDStatisticalGraphErrorBuilder {
   pub fn build(&self) -> StatisticalGraphError { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StatisticalGraphError> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("error_message".to_string()); }
+    crate::types::_common::check_missing("statisticalGraphError", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -38,7 +38,18 @@ impl StatisticalValue {
     let mut inner = StatisticalValue::default();
     inner.td_name = "statisticalValue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStatisticalValueBuilder { inner }
+    RTDStatisticalValueBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("statisticalValue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value == 0.0 { missing.push("value".to_string()); }
+    if self.previous_value == 0.0 { missing.push("previous_value".to_string()); }
+    if self.growth_rate_percentage == 0.0 { missing.push("growth_rate_percentage".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> f32 { self.value }
@@ -51,27 +62,41 @@ impl StatisticalValue {
 
 #[doc(hidden)]
 pub struct RTDStatisticalValueBuilder {
-  inner: StatisticalValue
+  inner: StatisticalValue,
+  set: [bool; 3]
 }
 
 impl RTDStatisticalValueBuilder {
   pub fn build(&self) -> StatisticalValue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StatisticalValue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    if !self.set[1] { missing.push("previous_value".to_string()); }
+    if !self.set[2] { missing.push("growth_rate_percentage".to_string()); }
+    crate::types::_common::check_missing("statisticalValue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value(&mut self, value: f32) -> &mut Self {
     self.inner.value = value;
+    self.set[0] = true;
     self
   }
 
    
   pub fn previous_value(&mut self, previous_value: f32) -> &mut Self {
     self.inner.previous_value = previous_value;
+    self.set[1] = true;
     self
   }
 
    
   pub fn growth_rate_percentage(&mut self, growth_rate_percentage: f32) -> &mut Self {
     self.inner.growth_rate_percentage = growth_rate_percentage;
+    self.set[2] = true;
     self
   }
 
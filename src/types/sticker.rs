@@ -16,7 +16,7 @@ pub struct Sticker {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The identifier of the sticker set to which the sticker belongs; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] set_id: i64,
   /// Sticker width; as defined by the sender
   width: i64,
   /// Sticker height; as defined by the sender
@@ -52,10 +52,22 @@ impl Sticker {
     let mut inner = Sticker::default();
     inner.td_name = "sticker".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStickerBuilder { inner }
+    RTDStickerBuilder { inner, set: [false; 4] }
   }
 
-  pub fn set_id(&self) -> isize { self.set_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("sticker", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if self.emoji.is_empty() { missing.push("emoji".to_string()); }
+    if false { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.sticker._missing_fields())); }
+    missing
+  }
+
+  pub fn set_id(&self) -> i64 { self.set_id }
 
   pub fn width(&self) -> i64 { self.width }
 
@@ -79,14 +91,26 @@ impl Sticker {
 
 #[doc(hidden)]
 pub struct RTDStickerBuilder {
-  inner: Sticker
+  inner: Sticker,
+  set: [bool; 4]
 }
 
 impl RTDStickerBuilder {
   pub fn build(&self) -> Sticker { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Sticker> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("width".to_string()); }
+    if !self.set[1] { missing.push("height".to_string()); }
+    if !self.set[2] { missing.push("emoji".to_string()); }
+    if !self.set[3] { missing.push("sticker".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker", self.inner.sticker._missing_fields())); }
+    crate::types::_common::check_missing("sticker", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn set_id(&mut self, set_id: isize) -> &mut Self {
+  pub fn set_id(&mut self, set_id: i64) -> &mut Self {
     self.inner.set_id = set_id;
     self
   }
@@ -94,18 +118,21 @@ impl RTDStickerBuilder {
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[0] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[1] = true;
     self
   }
 
    
   pub fn emoji<T: AsRef<str>>(&mut self, emoji: T) -> &mut Self {
     self.inner.emoji = emoji.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -142,6 +169,7 @@ impl RTDStickerBuilder {
    
   pub fn sticker<T: AsRef<File>>(&mut self, sticker: T) -> &mut Self {
     self.inner.sticker = sticker.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
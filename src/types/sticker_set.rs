@@ -16,7 +16,7 @@ pub struct StickerSet {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the sticker set
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Title of the sticker set
   title: String,
   /// Name of the sticker set
@@ -58,10 +58,21 @@ impl StickerSet {
     let mut inner = StickerSet::default();
     inner.td_name = "stickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStickerSetBuilder { inner }
+    RTDStickerSetBuilder { inner, set: [false; 3] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("stickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn title(&self) -> &String { &self.title }
 
@@ -91,27 +102,41 @@ impl StickerSet {
 
 #[doc(hidden)]
 pub struct RTDStickerSetBuilder {
-  inner: StickerSet
+  inner: StickerSet,
+  set: [bool; 3]
 }
 
 impl RTDStickerSetBuilder {
   pub fn build(&self) -> StickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("name".to_string()); }
+    crate::types::_common::check_missing("stickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
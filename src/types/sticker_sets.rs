@@ -36,7 +36,16 @@ impl StickerSets {
     let mut inner = StickerSets::default();
     inner.td_name = "stickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStickerSetsBuilder { inner }
+    RTDStickerSetsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("stickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn total_count(&self) -> i64 { self.total_count }
@@ -47,15 +56,25 @@ impl StickerSets {
 
 #[doc(hidden)]
 pub struct RTDStickerSetsBuilder {
-  inner: StickerSets
+  inner: StickerSets,
+  set: [bool; 1]
 }
 
 impl RTDStickerSetsBuilder {
   pub fn build(&self) -> StickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("stickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[0] = true;
     self
   }
 
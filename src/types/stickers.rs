@@ -37,6 +37,11 @@ impl Stickers {
     RTDStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("stickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn stickers(&self) -> &Vec<Sticker> { &self.stickers }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDStickersBuilder {
 impl RTDStickersBuilder {
   pub fn build(&self) -> Stickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Stickers> { Ok(self.build()) }
+
    
   pub fn stickers(&mut self, stickers: Vec<Sticker>) -> &mut Self {
     self.inner.stickers = stickers;
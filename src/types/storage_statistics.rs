@@ -38,7 +38,17 @@ impl StorageStatistics {
     let mut inner = StorageStatistics::default();
     inner.td_name = "storageStatistics".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStorageStatisticsBuilder { inner }
+    RTDStorageStatisticsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("storageStatistics", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.size == 0 { missing.push("size".to_string()); }
+    if self.count == 0 { missing.push("count".to_string()); }
+    missing
   }
 
   pub fn size(&self) -> i64 { self.size }
@@ -51,21 +61,33 @@ impl StorageStatistics {
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsBuilder {
-  inner: StorageStatistics
+  inner: StorageStatistics,
+  set: [bool; 2]
 }
 
 impl RTDStorageStatisticsBuilder {
   pub fn build(&self) -> StorageStatistics { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StorageStatistics> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("size".to_string()); }
+    if !self.set[1] { missing.push("count".to_string()); }
+    crate::types::_common::check_missing("storageStatistics", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
+    self.set[1] = true;
     self
   }
 
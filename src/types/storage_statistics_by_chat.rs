@@ -40,7 +40,17 @@ impl StorageStatisticsByChat {
     let mut inner = StorageStatisticsByChat::default();
     inner.td_name = "storageStatisticsByChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStorageStatisticsByChatBuilder { inner }
+    RTDStorageStatisticsByChatBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("storageStatisticsByChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.size == 0 { missing.push("size".to_string()); }
+    if self.count == 0 { missing.push("count".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -55,12 +65,22 @@ impl StorageStatisticsByChat {
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsByChatBuilder {
-  inner: StorageStatisticsByChat
+  inner: StorageStatisticsByChat,
+  set: [bool; 2]
 }
 
 impl RTDStorageStatisticsByChatBuilder {
   pub fn build(&self) -> StorageStatisticsByChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StorageStatisticsByChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("size".to_string()); }
+    if !self.set[1] { missing.push("count".to_string()); }
+    crate::types::_common::check_missing("storageStatisticsByChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
@@ -70,12 +90,14 @@ impl RTDStorageStatisticsByChatBuilder {
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
+    self.set[1] = true;
     self
   }
 
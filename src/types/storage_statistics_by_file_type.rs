@@ -38,7 +38,18 @@ impl StorageStatisticsByFileType {
     let mut inner = StorageStatisticsByFileType::default();
     inner.td_name = "storageStatisticsByFileType".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStorageStatisticsByFileTypeBuilder { inner }
+    RTDStorageStatisticsByFileTypeBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("storageStatisticsByFileType", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.file_type._missing_fields())); }
+    if self.size == 0 { missing.push("size".to_string()); }
+    if self.count == 0 { missing.push("count".to_string()); }
+    missing
   }
 
   pub fn file_type(&self) -> &FileType { &self.file_type }
@@ -51,12 +62,23 @@ impl StorageStatisticsByFileType {
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsByFileTypeBuilder {
-  inner: StorageStatisticsByFileType
+  inner: StorageStatisticsByFileType,
+  set: [bool; 2]
 }
 
 impl RTDStorageStatisticsByFileTypeBuilder {
   pub fn build(&self) -> StorageStatisticsByFileType { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StorageStatisticsByFileType> {
+    let mut missing = vec![];
+    if self.inner.file_type._is_default() { missing.push("file_type".to_string()); } else { missing.extend(crate::types::_common::missing_in("file_type", self.inner.file_type._missing_fields())); }
+    if !self.set[0] { missing.push("size".to_string()); }
+    if !self.set[1] { missing.push("count".to_string()); }
+    crate::types::_common::check_missing("storageStatisticsByFileType", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file_type<T: AsRef<FileType>>(&mut self, file_type: T) -> &mut Self {
     self.inner.file_type = file_type.as_ref().clone();
@@ -66,12 +88,14 @@ impl RTDStorageStatisticsByFileTypeBuilder {
    
   pub fn size(&mut self, size: i64) -> &mut Self {
     self.inner.size = size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn count(&mut self, count: i64) -> &mut Self {
     self.inner.count = count;
+    self.set[1] = true;
     self
   }
 
@@ -42,7 +42,20 @@ impl StorageStatisticsFast {
     let mut inner = StorageStatisticsFast::default();
     inner.td_name = "storageStatisticsFast".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDStorageStatisticsFastBuilder { inner }
+    RTDStorageStatisticsFastBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("storageStatisticsFast", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.files_size == 0 { missing.push("files_size".to_string()); }
+    if self.file_count == 0 { missing.push("file_count".to_string()); }
+    if self.database_size == 0 { missing.push("database_size".to_string()); }
+    if self.language_pack_database_size == 0 { missing.push("language_pack_database_size".to_string()); }
+    if self.log_size == 0 { missing.push("log_size".to_string()); }
+    missing
   }
 
   pub fn files_size(&self) -> i64 { self.files_size }
@@ -59,39 +72,57 @@ impl StorageStatisticsFast {
 
 #[doc(hidden)]
 pub struct RTDStorageStatisticsFastBuilder {
-  inner: StorageStatisticsFast
+  inner: StorageStatisticsFast,
+  set: [bool; 5]
 }
 
 impl RTDStorageStatisticsFastBuilder {
   pub fn build(&self) -> StorageStatisticsFast { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<StorageStatisticsFast> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("files_size".to_string()); }
+    if !self.set[1] { missing.push("file_count".to_string()); }
+    if !self.set[2] { missing.push("database_size".to_string()); }
+    if !self.set[3] { missing.push("language_pack_database_size".to_string()); }
+    if !self.set[4] { missing.push("log_size".to_string()); }
+    crate::types::_common::check_missing("storageStatisticsFast", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn files_size(&mut self, files_size: i64) -> &mut Self {
     self.inner.files_size = files_size;
+    self.set[0] = true;
     self
   }
 
    
   pub fn file_count(&mut self, file_count: i64) -> &mut Self {
     self.inner.file_count = file_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn database_size(&mut self, database_size: i64) -> &mut Self {
     self.inner.database_size = database_size;
+    self.set[2] = true;
     self
   }
 
    
   pub fn language_pack_database_size(&mut self, language_pack_database_size: i64) -> &mut Self {
     self.inner.language_pack_database_size = language_pack_database_size;
+    self.set[3] = true;
     self
   }
 
    
   pub fn log_size(&mut self, log_size: i64) -> &mut Self {
     self.inner.log_size = log_size;
+    self.set[4] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDSuggestedAction: Debug + RObject {}
 #[serde(untagged)]
 pub enum SuggestedAction {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Suggests the user to check authorization phone number and change the phone number if it is inaccessible
   CheckPhoneNumber(SuggestedActionCheckPhoneNumber),
   /// Suggests the user to enable "archive_and_mute_new_chats_from_unknown_users" option
@@ -34,7 +37,6 @@ impl Default for SuggestedAction {
 
 impl<'de> Deserialize<'de> for SuggestedAction {
   fn deserialize<D>(deserializer: D) -> Result<SuggestedAction, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       SuggestedAction,
       (suggestedActionCheckPhoneNumber, CheckPhoneNumber);
@@ -60,6 +62,7 @@ impl RObject for SuggestedAction {
       SuggestedAction::CheckPhoneNumber(t) => t.extra(),
       SuggestedAction::EnableArchiveAndMuteNewChats(t) => t.extra(),
       SuggestedAction::SeeTicksHint(t) => t.extra(),
+      SuggestedAction::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -70,6 +73,14 @@ impl RObject for SuggestedAction {
 impl SuggestedAction {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SuggestedAction::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      SuggestedAction::CheckPhoneNumber(t) => t._missing_fields(),
+      SuggestedAction::EnableArchiveAndMuteNewChats(t) => t._missing_fields(),
+      SuggestedAction::SeeTicksHint(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_check_phone_number(&self) -> bool { if let SuggestedAction::CheckPhoneNumber(_) = self { true } else { false } }
   pub fn is_enable_archive_and_mute_new_chats(&self) -> bool { if let SuggestedAction::EnableArchiveAndMuteNewChats(_) = self { true } else { false } }
@@ -97,6 +108,18 @@ impl AsRef<SuggestedAction> for SuggestedAction {
   fn as_ref(&self) -> &SuggestedAction { self }
 }
 
+impl RVariant<SuggestedAction> for SuggestedActionCheckPhoneNumber {
+  fn from_class(class: &SuggestedAction) -> Option<&Self> { match class { SuggestedAction::CheckPhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SuggestedAction> for SuggestedActionEnableArchiveAndMuteNewChats {
+  fn from_class(class: &SuggestedAction) -> Option<&Self> { match class { SuggestedAction::EnableArchiveAndMuteNewChats(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SuggestedAction> for SuggestedActionSeeTicksHint {
+  fn from_class(class: &SuggestedAction) -> Option<&Self> { match class { SuggestedAction::SeeTicksHint(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -135,6 +158,11 @@ impl SuggestedActionCheckPhoneNumber {
     RTDSuggestedActionCheckPhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("suggestedActionCheckPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -145,6 +173,9 @@ pub struct RTDSuggestedActionCheckPhoneNumberBuilder {
 impl RTDSuggestedActionCheckPhoneNumberBuilder {
   pub fn build(&self) -> SuggestedActionCheckPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SuggestedActionCheckPhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<SuggestedActionCheckPhoneNumber> for SuggestedActionCheckPhoneNumber {
@@ -193,6 +224,11 @@ impl SuggestedActionEnableArchiveAndMuteNewChats {
     RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("suggestedActionEnableArchiveAndMuteNewChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -203,6 +239,9 @@ pub struct RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
 impl RTDSuggestedActionEnableArchiveAndMuteNewChatsBuilder {
   pub fn build(&self) -> SuggestedActionEnableArchiveAndMuteNewChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SuggestedActionEnableArchiveAndMuteNewChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<SuggestedActionEnableArchiveAndMuteNewChats> for SuggestedActionEnableArchiveAndMuteNewChats {
@@ -251,6 +290,11 @@ impl SuggestedActionSeeTicksHint {
     RTDSuggestedActionSeeTicksHintBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("suggestedActionSeeTicksHint", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -261,6 +305,9 @@ pub struct RTDSuggestedActionSeeTicksHintBuilder {
 impl RTDSuggestedActionSeeTicksHintBuilder {
   pub fn build(&self) -> SuggestedActionSeeTicksHint { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SuggestedActionSeeTicksHint> { Ok(self.build()) }
+
 }
 
 impl AsRef<SuggestedActionSeeTicksHint> for SuggestedActionSeeTicksHint {
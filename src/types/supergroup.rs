@@ -60,7 +60,20 @@ impl Supergroup {
     let mut inner = Supergroup::default();
     inner.td_name = "supergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupBuilder { inner }
+    RTDSupergroupBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    if self.date == 0 { missing.push("date".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    if self.restriction_reason.is_empty() { missing.push("restriction_reason".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -95,27 +108,43 @@ impl Supergroup {
 
 #[doc(hidden)]
 pub struct RTDSupergroupBuilder {
-  inner: Supergroup
+  inner: Supergroup,
+  set: [bool; 4]
 }
 
 impl RTDSupergroupBuilder {
   pub fn build(&self) -> Supergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Supergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("username".to_string()); }
+    if !self.set[2] { missing.push("date".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    if !self.set[3] { missing.push("restriction_reason".to_string()); }
+    crate::types::_common::check_missing("supergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn date(&mut self, date: i64) -> &mut Self {
     self.inner.date = date;
+    self.set[2] = true;
     self
   }
 
@@ -170,6 +199,7 @@ impl RTDSupergroupBuilder {
    
   pub fn restriction_reason<T: AsRef<str>>(&mut self, restriction_reason: T) -> &mut Self {
     self.inner.restriction_reason = restriction_reason.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -46,7 +46,7 @@ pub struct SupergroupFullInfo {
   /// True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators
   is_all_history_available: bool,
   /// Identifier of the supergroup sticker set; 0 if none
-  #[serde(deserialize_with = "serde_aux::field_attributes::deserialize_number_from_string")] sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] sticker_set_id: i64,
   /// Location to which the supergroup is connected; may be null
   location: Option<ChatLocation>,
   /// Permanent invite link for this chat; may be null. For chat administrators with can_invite_users right only
@@ -72,7 +72,18 @@ impl SupergroupFullInfo {
     let mut inner = SupergroupFullInfo::default();
     inner.td_name = "supergroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupFullInfoBuilder { inner }
+    RTDSupergroupFullInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.description.is_empty() { missing.push("description".to_string()); }
+    if self.slow_mode_delay == 0 { missing.push("slow_mode_delay".to_string()); }
+    if self.slow_mode_delay_expires_in == 0.0 { missing.push("slow_mode_delay_expires_in".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
@@ -105,7 +116,7 @@ impl SupergroupFullInfo {
 
   pub fn is_all_history_available(&self) -> bool { self.is_all_history_available }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
   pub fn location(&self) -> &Option<ChatLocation> { &self.location }
 
@@ -119,12 +130,23 @@ impl SupergroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDSupergroupFullInfoBuilder {
-  inner: SupergroupFullInfo
+  inner: SupergroupFullInfo,
+  set: [bool; 3]
 }
 
 impl RTDSupergroupFullInfoBuilder {
   pub fn build(&self) -> SupergroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("description".to_string()); }
+    if !self.set[1] { missing.push("slow_mode_delay".to_string()); }
+    if !self.set[2] { missing.push("slow_mode_delay_expires_in".to_string()); }
+    crate::types::_common::check_missing("supergroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -134,6 +156,7 @@ impl RTDSupergroupFullInfoBuilder {
    
   pub fn description<T: AsRef<str>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -170,12 +193,14 @@ impl RTDSupergroupFullInfoBuilder {
    
   pub fn slow_mode_delay(&mut self, slow_mode_delay: i64) -> &mut Self {
     self.inner.slow_mode_delay = slow_mode_delay;
+    self.set[1] = true;
     self
   }
 
    
   pub fn slow_mode_delay_expires_in(&mut self, slow_mode_delay_expires_in: f32) -> &mut Self {
     self.inner.slow_mode_delay_expires_in = slow_mode_delay_expires_in;
+    self.set[2] = true;
     self
   }
 
@@ -216,7 +241,7 @@ impl RTDSupergroupFullInfoBuilder {
   }
 
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
     self
   }
@@ -19,6 +19,9 @@ pub trait TDSupergroupMembersFilter: Debug + RObject {}
 #[serde(untagged)]
 pub enum SupergroupMembersFilter {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Returns the owner and administrators
   Administrators(SupergroupMembersFilterAdministrators),
   /// Returns users banned from the supergroup or channel; can be used only by administrators
@@ -44,7 +47,6 @@ impl Default for SupergroupMembersFilter {
 
 impl<'de> Deserialize<'de> for SupergroupMembersFilter {
   fn deserialize<D>(deserializer: D) -> Result<SupergroupMembersFilter, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       SupergroupMembersFilter,
       (supergroupMembersFilterAdministrators, Administrators);
@@ -85,6 +87,7 @@ impl RObject for SupergroupMembersFilter {
       SupergroupMembersFilter::Recent(t) => t.extra(),
       SupergroupMembersFilter::Restricted(t) => t.extra(),
       SupergroupMembersFilter::Search(t) => t.extra(),
+      SupergroupMembersFilter::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -95,6 +98,19 @@ impl RObject for SupergroupMembersFilter {
 impl SupergroupMembersFilter {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let SupergroupMembersFilter::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      SupergroupMembersFilter::Administrators(t) => t._missing_fields(),
+      SupergroupMembersFilter::Banned(t) => t._missing_fields(),
+      SupergroupMembersFilter::Bots(t) => t._missing_fields(),
+      SupergroupMembersFilter::Contacts(t) => t._missing_fields(),
+      SupergroupMembersFilter::Mention(t) => t._missing_fields(),
+      SupergroupMembersFilter::Recent(t) => t._missing_fields(),
+      SupergroupMembersFilter::Restricted(t) => t._missing_fields(),
+      SupergroupMembersFilter::Search(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_administrators(&self) -> bool { if let SupergroupMembersFilter::Administrators(_) = self { true } else { false } }
   pub fn is_banned(&self) -> bool { if let SupergroupMembersFilter::Banned(_) = self { true } else { false } }
@@ -147,6 +163,38 @@ impl AsRef<SupergroupMembersFilter> for SupergroupMembersFilter {
   fn as_ref(&self) -> &SupergroupMembersFilter { self }
 }
 
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterAdministrators {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Administrators(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterBanned {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Banned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterBots {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Bots(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterContacts {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Contacts(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterMention {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Mention(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterRecent {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Recent(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterRestricted {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Restricted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<SupergroupMembersFilter> for SupergroupMembersFilterSearch {
+  fn from_class(class: &SupergroupMembersFilter) -> Option<&Self> { match class { SupergroupMembersFilter::Search(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -185,6 +233,11 @@ impl SupergroupMembersFilterAdministrators {
     RTDSupergroupMembersFilterAdministratorsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterAdministrators", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +248,9 @@ pub struct RTDSupergroupMembersFilterAdministratorsBuilder {
 impl RTDSupergroupMembersFilterAdministratorsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterAdministrators { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterAdministrators> { Ok(self.build()) }
+
 }
 
 impl AsRef<SupergroupMembersFilterAdministrators> for SupergroupMembersFilterAdministrators {
@@ -242,7 +298,16 @@ impl SupergroupMembersFilterBanned {
     let mut inner = SupergroupMembersFilterBanned::default();
     inner.td_name = "supergroupMembersFilterBanned".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupMembersFilterBannedBuilder { inner }
+    RTDSupergroupMembersFilterBannedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterBanned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -251,15 +316,25 @@ impl SupergroupMembersFilterBanned {
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterBannedBuilder {
-  inner: SupergroupMembersFilterBanned
+  inner: SupergroupMembersFilterBanned,
+  set: [bool; 1]
 }
 
 impl RTDSupergroupMembersFilterBannedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBanned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterBanned> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("supergroupMembersFilterBanned", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -311,6 +386,11 @@ impl SupergroupMembersFilterBots {
     RTDSupergroupMembersFilterBotsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterBots", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +401,9 @@ pub struct RTDSupergroupMembersFilterBotsBuilder {
 impl RTDSupergroupMembersFilterBotsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterBots { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterBots> { Ok(self.build()) }
+
 }
 
 impl AsRef<SupergroupMembersFilterBots> for SupergroupMembersFilterBots {
@@ -368,7 +451,16 @@ impl SupergroupMembersFilterContacts {
     let mut inner = SupergroupMembersFilterContacts::default();
     inner.td_name = "supergroupMembersFilterContacts".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupMembersFilterContactsBuilder { inner }
+    RTDSupergroupMembersFilterContactsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -377,15 +469,25 @@ impl SupergroupMembersFilterContacts {
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterContactsBuilder {
-  inner: SupergroupMembersFilterContacts
+  inner: SupergroupMembersFilterContacts,
+  set: [bool; 1]
 }
 
 impl RTDSupergroupMembersFilterContactsBuilder {
   pub fn build(&self) -> SupergroupMembersFilterContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterContacts> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("supergroupMembersFilterContacts", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -438,7 +540,17 @@ impl SupergroupMembersFilterMention {
     let mut inner = SupergroupMembersFilterMention::default();
     inner.td_name = "supergroupMembersFilterMention".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupMembersFilterMentionBuilder { inner }
+    RTDSupergroupMembersFilterMentionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterMention", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.message_thread_id == 0 { missing.push("message_thread_id".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -449,21 +561,33 @@ impl SupergroupMembersFilterMention {
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterMentionBuilder {
-  inner: SupergroupMembersFilterMention
+  inner: SupergroupMembersFilterMention,
+  set: [bool; 2]
 }
 
 impl RTDSupergroupMembersFilterMentionBuilder {
   pub fn build(&self) -> SupergroupMembersFilterMention { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterMention> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    if !self.set[1] { missing.push("message_thread_id".to_string()); }
+    crate::types::_common::check_missing("supergroupMembersFilterMention", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_thread_id(&mut self, message_thread_id: i64) -> &mut Self {
     self.inner.message_thread_id = message_thread_id;
+    self.set[1] = true;
     self
   }
 
@@ -515,6 +639,11 @@ impl SupergroupMembersFilterRecent {
     RTDSupergroupMembersFilterRecentBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterRecent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -525,6 +654,9 @@ pub struct RTDSupergroupMembersFilterRecentBuilder {
 impl RTDSupergroupMembersFilterRecentBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRecent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterRecent> { Ok(self.build()) }
+
 }
 
 impl AsRef<SupergroupMembersFilterRecent> for SupergroupMembersFilterRecent {
@@ -572,7 +704,16 @@ impl SupergroupMembersFilterRestricted {
     let mut inner = SupergroupMembersFilterRestricted::default();
     inner.td_name = "supergroupMembersFilterRestricted".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupMembersFilterRestrictedBuilder { inner }
+    RTDSupergroupMembersFilterRestrictedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterRestricted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -581,15 +722,25 @@ impl SupergroupMembersFilterRestricted {
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterRestrictedBuilder {
-  inner: SupergroupMembersFilterRestricted
+  inner: SupergroupMembersFilterRestricted,
+  set: [bool; 1]
 }
 
 impl RTDSupergroupMembersFilterRestrictedBuilder {
   pub fn build(&self) -> SupergroupMembersFilterRestricted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterRestricted> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("supergroupMembersFilterRestricted", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -640,7 +791,16 @@ impl SupergroupMembersFilterSearch {
     let mut inner = SupergroupMembersFilterSearch::default();
     inner.td_name = "supergroupMembersFilterSearch".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDSupergroupMembersFilterSearchBuilder { inner }
+    RTDSupergroupMembersFilterSearchBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("supergroupMembersFilterSearch", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    missing
   }
 
   pub fn query(&self) -> &String { &self.query }
@@ -649,15 +809,25 @@ impl SupergroupMembersFilterSearch {
 
 #[doc(hidden)]
 pub struct RTDSupergroupMembersFilterSearchBuilder {
-  inner: SupergroupMembersFilterSearch
+  inner: SupergroupMembersFilterSearch,
+  set: [bool; 1]
 }
 
 impl RTDSupergroupMembersFilterSearchBuilder {
   pub fn build(&self) -> SupergroupMembersFilterSearch { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<SupergroupMembersFilterSearch> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("query".to_string()); }
+    crate::types::_common::check_missing("supergroupMembersFilterSearch", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -36,7 +36,17 @@ impl TMeUrl {
     let mut inner = TMeUrl::default();
     inner.td_name = "tMeUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTMeUrlBuilder { inner }
+    RTDTMeUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -47,15 +57,26 @@ impl TMeUrl {
 
 #[doc(hidden)]
 pub struct RTDTMeUrlBuilder {
-  inner: TMeUrl
+  inner: TMeUrl,
+  set: [bool; 1]
 }
 
 impl RTDTMeUrlBuilder {
   pub fn build(&self) -> TMeUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("tMeUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDTMeUrlType: Debug + RObject {}
 #[serde(untagged)]
 pub enum TMeUrlType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A chat invite link
   ChatInvite(TMeUrlTypeChatInvite),
   /// A URL linking to a sticker set
@@ -36,7 +39,6 @@ impl Default for TMeUrlType {
 
 impl<'de> Deserialize<'de> for TMeUrlType {
   fn deserialize<D>(deserializer: D) -> Result<TMeUrlType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       TMeUrlType,
       (tMeUrlTypeChatInvite, ChatInvite);
@@ -65,6 +67,7 @@ impl RObject for TMeUrlType {
       TMeUrlType::StickerSet(t) => t.extra(),
       TMeUrlType::Supergroup(t) => t.extra(),
       TMeUrlType::User(t) => t.extra(),
+      TMeUrlType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,6 +78,15 @@ impl RObject for TMeUrlType {
 impl TMeUrlType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TMeUrlType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      TMeUrlType::ChatInvite(t) => t._missing_fields(),
+      TMeUrlType::StickerSet(t) => t._missing_fields(),
+      TMeUrlType::Supergroup(t) => t._missing_fields(),
+      TMeUrlType::User(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_chat_invite(&self) -> bool { if let TMeUrlType::ChatInvite(_) = self { true } else { false } }
   pub fn is_sticker_set(&self) -> bool { if let TMeUrlType::StickerSet(_) = self { true } else { false } }
@@ -107,6 +119,22 @@ impl AsRef<TMeUrlType> for TMeUrlType {
   fn as_ref(&self) -> &TMeUrlType { self }
 }
 
+impl RVariant<TMeUrlType> for TMeUrlTypeChatInvite {
+  fn from_class(class: &TMeUrlType) -> Option<&Self> { match class { TMeUrlType::ChatInvite(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TMeUrlType> for TMeUrlTypeStickerSet {
+  fn from_class(class: &TMeUrlType) -> Option<&Self> { match class { TMeUrlType::StickerSet(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TMeUrlType> for TMeUrlTypeSupergroup {
+  fn from_class(class: &TMeUrlType) -> Option<&Self> { match class { TMeUrlType::Supergroup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TMeUrlType> for TMeUrlTypeUser {
+  fn from_class(class: &TMeUrlType) -> Option<&Self> { match class { TMeUrlType::User(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -144,7 +172,16 @@ impl TMeUrlTypeChatInvite {
     let mut inner = TMeUrlTypeChatInvite::default();
     inner.td_name = "tMeUrlTypeChatInvite".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTMeUrlTypeChatInviteBuilder { inner }
+    RTDTMeUrlTypeChatInviteBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrlTypeChatInvite", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.info._missing_fields())); }
+    missing
   }
 
   pub fn info(&self) -> &ChatInviteLinkInfo { &self.info }
@@ -153,15 +190,25 @@ impl TMeUrlTypeChatInvite {
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeChatInviteBuilder {
-  inner: TMeUrlTypeChatInvite
+  inner: TMeUrlTypeChatInvite,
+  set: [bool; 1]
 }
 
 impl RTDTMeUrlTypeChatInviteBuilder {
   pub fn build(&self) -> TMeUrlTypeChatInvite { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrlTypeChatInvite> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("info".to_string()); } else { missing.extend(crate::types::_common::missing_in("info", self.inner.info._missing_fields())); }
+    crate::types::_common::check_missing("tMeUrlTypeChatInvite", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn info<T: AsRef<ChatInviteLinkInfo>>(&mut self, info: T) -> &mut Self {
     self.inner.info = info.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -191,7 +238,7 @@ pub struct TMeUrlTypeStickerSet {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Identifier of the sticker set
-  sticker_set_id: isize,
+  #[serde(with = "crate::types::_common::int64")] sticker_set_id: i64,
   
 }
 
@@ -212,24 +259,43 @@ impl TMeUrlTypeStickerSet {
     let mut inner = TMeUrlTypeStickerSet::default();
     inner.td_name = "tMeUrlTypeStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTMeUrlTypeStickerSetBuilder { inner }
+    RTDTMeUrlTypeStickerSetBuilder { inner, set: [false; 1] }
   }
 
-  pub fn sticker_set_id(&self) -> isize { self.sticker_set_id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrlTypeStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sticker_set_id == 0 { missing.push("sticker_set_id".to_string()); }
+    missing
+  }
+
+  pub fn sticker_set_id(&self) -> i64 { self.sticker_set_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeStickerSetBuilder {
-  inner: TMeUrlTypeStickerSet
+  inner: TMeUrlTypeStickerSet,
+  set: [bool; 1]
 }
 
 impl RTDTMeUrlTypeStickerSetBuilder {
   pub fn build(&self) -> TMeUrlTypeStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrlTypeStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sticker_set_id".to_string()); }
+    crate::types::_common::check_missing("tMeUrlTypeStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn sticker_set_id(&mut self, sticker_set_id: isize) -> &mut Self {
+  pub fn sticker_set_id(&mut self, sticker_set_id: i64) -> &mut Self {
     self.inner.sticker_set_id = sticker_set_id;
+    self.set[0] = true;
     self
   }
 
@@ -280,7 +346,16 @@ impl TMeUrlTypeSupergroup {
     let mut inner = TMeUrlTypeSupergroup::default();
     inner.td_name = "tMeUrlTypeSupergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTMeUrlTypeSupergroupBuilder { inner }
+    RTDTMeUrlTypeSupergroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrlTypeSupergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -289,15 +364,25 @@ impl TMeUrlTypeSupergroup {
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeSupergroupBuilder {
-  inner: TMeUrlTypeSupergroup
+  inner: TMeUrlTypeSupergroup,
+  set: [bool; 1]
 }
 
 impl RTDTMeUrlTypeSupergroupBuilder {
   pub fn build(&self) -> TMeUrlTypeSupergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrlTypeSupergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    crate::types::_common::check_missing("tMeUrlTypeSupergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
@@ -348,7 +433,16 @@ impl TMeUrlTypeUser {
     let mut inner = TMeUrlTypeUser::default();
     inner.td_name = "tMeUrlTypeUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTMeUrlTypeUserBuilder { inner }
+    RTDTMeUrlTypeUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrlTypeUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -357,15 +451,25 @@ impl TMeUrlTypeUser {
 
 #[doc(hidden)]
 pub struct RTDTMeUrlTypeUserBuilder {
-  inner: TMeUrlTypeUser
+  inner: TMeUrlTypeUser,
+  set: [bool; 1]
 }
 
 impl RTDTMeUrlTypeUserBuilder {
   pub fn build(&self) -> TMeUrlTypeUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrlTypeUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("tMeUrlTypeUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl TMeUrls {
     RTDTMeUrlsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tMeUrls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn urls(&self) -> &Vec<TMeUrl> { &self.urls }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTMeUrlsBuilder {
 impl RTDTMeUrlsBuilder {
   pub fn build(&self) -> TMeUrls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TMeUrls> { Ok(self.build()) }
+
    
   pub fn urls(&mut self, urls: Vec<TMeUrl>) -> &mut Self {
     self.inner.urls = urls;
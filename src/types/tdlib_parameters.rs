@@ -62,7 +62,21 @@ impl TdlibParameters {
     let mut inner = TdlibParameters::default();
     inner.td_name = "tdlibParameters".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTdlibParametersBuilder { inner }
+    RTDTdlibParametersBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("tdlibParameters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.database_directory.is_empty() { missing.push("database_directory".to_string()); }
+    if self.api_id == 0 { missing.push("api_id".to_string()); }
+    if self.api_hash.is_empty() { missing.push("api_hash".to_string()); }
+    if self.system_language_code.is_empty() { missing.push("system_language_code".to_string()); }
+    if self.device_model.is_empty() { missing.push("device_model".to_string()); }
+    if self.application_version.is_empty() { missing.push("application_version".to_string()); }
+    missing
   }
 
   pub fn use_test_dc(&self) -> bool { self.use_test_dc }
@@ -99,12 +113,26 @@ impl TdlibParameters {
 
 #[doc(hidden)]
 pub struct RTDTdlibParametersBuilder {
-  inner: TdlibParameters
+  inner: TdlibParameters,
+  set: [bool; 6]
 }
 
 impl RTDTdlibParametersBuilder {
   pub fn build(&self) -> TdlibParameters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TdlibParameters> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("database_directory".to_string()); }
+    if !self.set[1] { missing.push("api_id".to_string()); }
+    if !self.set[2] { missing.push("api_hash".to_string()); }
+    if !self.set[3] { missing.push("system_language_code".to_string()); }
+    if !self.set[4] { missing.push("device_model".to_string()); }
+    if !self.set[5] { missing.push("application_version".to_string()); }
+    crate::types::_common::check_missing("tdlibParameters", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn use_test_dc(&mut self, use_test_dc: bool) -> &mut Self {
     self.inner.use_test_dc = use_test_dc;
@@ -114,6 +142,7 @@ impl RTDTdlibParametersBuilder {
    
   pub fn database_directory<T: AsRef<str>>(&mut self, database_directory: T) -> &mut Self {
     self.inner.database_directory = database_directory.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -150,24 +179,28 @@ impl RTDTdlibParametersBuilder {
    
   pub fn api_id(&mut self, api_id: i64) -> &mut Self {
     self.inner.api_id = api_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn api_hash<T: AsRef<str>>(&mut self, api_hash: T) -> &mut Self {
     self.inner.api_hash = api_hash.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn system_language_code<T: AsRef<str>>(&mut self, system_language_code: T) -> &mut Self {
     self.inner.system_language_code = system_language_code.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn device_model<T: AsRef<str>>(&mut self, device_model: T) -> &mut Self {
     self.inner.device_model = device_model.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -180,6 +213,7 @@ impl RTDTdlibParametersBuilder {
    
   pub fn application_version<T: AsRef<str>>(&mut self, application_version: T) -> &mut Self {
     self.inner.application_version = application_version.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl TemporaryPasswordState {
     let mut inner = TemporaryPasswordState::default();
     inner.td_name = "temporaryPasswordState".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTemporaryPasswordStateBuilder { inner }
+    RTDTemporaryPasswordStateBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("temporaryPasswordState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.valid_for == 0 { missing.push("valid_for".to_string()); }
+    missing
   }
 
   pub fn has_password(&self) -> bool { self.has_password }
@@ -47,12 +56,21 @@ impl TemporaryPasswordState {
 
 #[doc(hidden)]
 pub struct RTDTemporaryPasswordStateBuilder {
-  inner: TemporaryPasswordState
+  inner: TemporaryPasswordState,
+  set: [bool; 1]
 }
 
 impl RTDTemporaryPasswordStateBuilder {
   pub fn build(&self) -> TemporaryPasswordState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TemporaryPasswordState> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("valid_for".to_string()); }
+    crate::types::_common::check_missing("temporaryPasswordState", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn has_password(&mut self, has_password: bool) -> &mut Self {
     self.inner.has_password = has_password;
@@ -62,6 +80,7 @@ impl RTDTemporaryPasswordStateBuilder {
    
   pub fn valid_for(&mut self, valid_for: i64) -> &mut Self {
     self.inner.valid_for = valid_for;
+    self.set[0] = true;
     self
   }
 
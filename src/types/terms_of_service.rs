@@ -38,7 +38,16 @@ impl TermsOfService {
     let mut inner = TermsOfService::default();
     inner.td_name = "termsOfService".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTermsOfServiceBuilder { inner }
+    RTDTermsOfServiceBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("termsOfService", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.text._missing_fields())); }
+    missing
   }
 
   pub fn text(&self) -> &FormattedText { &self.text }
@@ -51,15 +60,25 @@ impl TermsOfService {
 
 #[doc(hidden)]
 pub struct RTDTermsOfServiceBuilder {
-  inner: TermsOfService
+  inner: TermsOfService,
+  set: [bool; 1]
 }
 
 impl RTDTermsOfServiceBuilder {
   pub fn build(&self) -> TermsOfService { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TermsOfService> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); } else { missing.extend(crate::types::_common::missing_in("text", self.inner.text._missing_fields())); }
+    crate::types::_common::check_missing("termsOfService", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<FormattedText>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
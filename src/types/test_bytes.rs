@@ -34,7 +34,16 @@ impl TestBytes {
     let mut inner = TestBytes::default();
     inner.td_name = "testBytes".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestBytesBuilder { inner }
+    RTDTestBytesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testBytes", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> &String { &self.value }
@@ -43,15 +52,25 @@ impl TestBytes {
 
 #[doc(hidden)]
 pub struct RTDTestBytesBuilder {
-  inner: TestBytes
+  inner: TestBytes,
+  set: [bool; 1]
 }
 
 impl RTDTestBytesBuilder {
   pub fn build(&self) -> TestBytes { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestBytes> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("testBytes", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -34,7 +34,16 @@ impl TestInt {
     let mut inner = TestInt::default();
     inner.td_name = "testInt".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestIntBuilder { inner }
+    RTDTestIntBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testInt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value == 0 { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> i64 { self.value }
@@ -43,15 +52,25 @@ impl TestInt {
 
 #[doc(hidden)]
 pub struct RTDTestIntBuilder {
-  inner: TestInt
+  inner: TestInt,
+  set: [bool; 1]
 }
 
 impl RTDTestIntBuilder {
   pub fn build(&self) -> TestInt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestInt> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("testInt", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value(&mut self, value: i64) -> &mut Self {
     self.inner.value = value;
+    self.set[0] = true;
     self
   }
 
@@ -34,7 +34,16 @@ impl TestString {
     let mut inner = TestString::default();
     inner.td_name = "testString".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTestStringBuilder { inner }
+    RTDTestStringBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.value.is_empty() { missing.push("value".to_string()); }
+    missing
   }
 
   pub fn value(&self) -> &String { &self.value }
@@ -43,15 +52,25 @@ impl TestString {
 
 #[doc(hidden)]
 pub struct RTDTestStringBuilder {
-  inner: TestString
+  inner: TestString,
+  set: [bool; 1]
 }
 
 impl RTDTestStringBuilder {
   pub fn build(&self) -> TestString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestString> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("value".to_string()); }
+    crate::types::_common::check_missing("testString", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn value<T: AsRef<str>>(&mut self, value: T) -> &mut Self {
     self.inner.value = value.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
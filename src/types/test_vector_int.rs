@@ -37,6 +37,11 @@ impl TestVectorInt {
     RTDTestVectorIntBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testVectorInt", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> &Vec<i64> { &self.value }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTestVectorIntBuilder {
 impl RTDTestVectorIntBuilder {
   pub fn build(&self) -> TestVectorInt { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestVectorInt> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: Vec<i64>) -> &mut Self {
     self.inner.value = value;
@@ -37,6 +37,11 @@ impl TestVectorIntObject {
     RTDTestVectorIntObjectBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testVectorIntObject", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> &Vec<TestInt> { &self.value }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTestVectorIntObjectBuilder {
 impl RTDTestVectorIntObjectBuilder {
   pub fn build(&self) -> TestVectorIntObject { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestVectorIntObject> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: Vec<TestInt>) -> &mut Self {
     self.inner.value = value;
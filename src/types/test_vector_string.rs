@@ -37,6 +37,11 @@ impl TestVectorString {
     RTDTestVectorStringBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testVectorString", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> &Vec<String> { &self.value }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTestVectorStringBuilder {
 impl RTDTestVectorStringBuilder {
   pub fn build(&self) -> TestVectorString { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestVectorString> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: Vec<String>) -> &mut Self {
     self.inner.value = value;
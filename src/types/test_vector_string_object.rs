@@ -37,6 +37,11 @@ impl TestVectorStringObject {
     RTDTestVectorStringObjectBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("testVectorStringObject", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn value(&self) -> &Vec<TestString> { &self.value }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTestVectorStringObjectBuilder {
 impl RTDTestVectorStringObjectBuilder {
   pub fn build(&self) -> TestVectorStringObject { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TestVectorStringObject> { Ok(self.build()) }
+
    
   pub fn value(&mut self, value: Vec<TestString>) -> &mut Self {
     self.inner.value = value;
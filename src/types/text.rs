@@ -34,7 +34,16 @@ impl Text {
     let mut inner = Text::default();
     inner.td_name = "text".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextBuilder { inner }
+    RTDTextBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("text", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.text.is_empty() { missing.push("text".to_string()); }
+    missing
   }
 
   pub fn text(&self) -> &String { &self.text }
@@ -43,15 +52,25 @@ impl Text {
 
 #[doc(hidden)]
 pub struct RTDTextBuilder {
-  inner: Text
+  inner: Text,
+  set: [bool; 1]
 }
 
 impl RTDTextBuilder {
   pub fn build(&self) -> Text { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Text> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("text".to_string()); }
+    crate::types::_common::check_missing("text", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn text<T: AsRef<str>>(&mut self, text: T) -> &mut Self {
     self.inner.text = text.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -37,6 +37,11 @@ impl TextEntities {
     RTDTextEntitiesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntities", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn entities(&self) -> &Vec<TextEntity> { &self.entities }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDTextEntitiesBuilder {
 impl RTDTextEntitiesBuilder {
   pub fn build(&self) -> TextEntities { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntities> { Ok(self.build()) }
+
    
   pub fn entities(&mut self, entities: Vec<TextEntity>) -> &mut Self {
     self.inner.entities = entities;
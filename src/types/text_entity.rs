@@ -38,7 +38,18 @@ impl TextEntity {
     let mut inner = TextEntity::default();
     inner.td_name = "textEntity".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextEntityBuilder { inner }
+    RTDTextEntityBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntity", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.offset == 0 { missing.push("offset".to_string()); }
+    if self.length == 0 { missing.push("length".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    missing
   }
 
   pub fn offset(&self) -> i64 { self.offset }
@@ -51,21 +62,34 @@ impl TextEntity {
 
 #[doc(hidden)]
 pub struct RTDTextEntityBuilder {
-  inner: TextEntity
+  inner: TextEntity,
+  set: [bool; 2]
 }
 
 impl RTDTextEntityBuilder {
   pub fn build(&self) -> TextEntity { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntity> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("offset".to_string()); }
+    if !self.set[1] { missing.push("length".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    crate::types::_common::check_missing("textEntity", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn offset(&mut self, offset: i64) -> &mut Self {
     self.inner.offset = offset;
+    self.set[0] = true;
     self
   }
 
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[1] = true;
     self
   }
 
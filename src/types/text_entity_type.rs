@@ -19,6 +19,9 @@ pub trait TDTextEntityType: Debug + RObject {}
 #[serde(untagged)]
 pub enum TextEntityType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A bank card number. The getBankCardInfo method can be used to get information about the bank card
   BankCardNumber(TextEntityTypeBankCardNumber),
   /// A bold text
@@ -62,7 +65,6 @@ impl Default for TextEntityType {
 
 impl<'de> Deserialize<'de> for TextEntityType {
   fn deserialize<D>(deserializer: D) -> Result<TextEntityType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       TextEntityType,
       (textEntityTypeBankCardNumber, BankCardNumber);
@@ -130,6 +132,7 @@ impl RObject for TextEntityType {
       TextEntityType::TextUrl(t) => t.extra(),
       TextEntityType::Underline(t) => t.extra(),
       TextEntityType::Url(t) => t.extra(),
+      TextEntityType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -140,6 +143,28 @@ impl RObject for TextEntityType {
 impl TextEntityType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextEntityType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      TextEntityType::BankCardNumber(t) => t._missing_fields(),
+      TextEntityType::Bold(t) => t._missing_fields(),
+      TextEntityType::BotCommand(t) => t._missing_fields(),
+      TextEntityType::Cashtag(t) => t._missing_fields(),
+      TextEntityType::Code(t) => t._missing_fields(),
+      TextEntityType::EmailAddress(t) => t._missing_fields(),
+      TextEntityType::Hashtag(t) => t._missing_fields(),
+      TextEntityType::Italic(t) => t._missing_fields(),
+      TextEntityType::Mention(t) => t._missing_fields(),
+      TextEntityType::MentionName(t) => t._missing_fields(),
+      TextEntityType::PhoneNumber(t) => t._missing_fields(),
+      TextEntityType::Pre(t) => t._missing_fields(),
+      TextEntityType::PreCode(t) => t._missing_fields(),
+      TextEntityType::Strikethrough(t) => t._missing_fields(),
+      TextEntityType::TextUrl(t) => t._missing_fields(),
+      TextEntityType::Underline(t) => t._missing_fields(),
+      TextEntityType::Url(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_bank_card_number(&self) -> bool { if let TextEntityType::BankCardNumber(_) = self { true } else { false } }
   pub fn is_bold(&self) -> bool { if let TextEntityType::Bold(_) = self { true } else { false } }
@@ -237,6 +262,74 @@ impl AsRef<TextEntityType> for TextEntityType {
   fn as_ref(&self) -> &TextEntityType { self }
 }
 
+impl RVariant<TextEntityType> for TextEntityTypeBankCardNumber {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::BankCardNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeBold {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Bold(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeBotCommand {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::BotCommand(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeCashtag {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Cashtag(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeCode {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Code(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeEmailAddress {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::EmailAddress(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeHashtag {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Hashtag(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeItalic {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Italic(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeMention {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Mention(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeMentionName {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::MentionName(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypePhoneNumber {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::PhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypePre {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Pre(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypePreCode {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::PreCode(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeStrikethrough {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Strikethrough(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeTextUrl {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::TextUrl(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeUnderline {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Underline(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextEntityType> for TextEntityTypeUrl {
+  fn from_class(class: &TextEntityType) -> Option<&Self> { match class { TextEntityType::Url(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -275,6 +368,11 @@ impl TextEntityTypeBankCardNumber {
     RTDTextEntityTypeBankCardNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeBankCardNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -285,6 +383,9 @@ pub struct RTDTextEntityTypeBankCardNumberBuilder {
 impl RTDTextEntityTypeBankCardNumberBuilder {
   pub fn build(&self) -> TextEntityTypeBankCardNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeBankCardNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeBankCardNumber> for TextEntityTypeBankCardNumber {
@@ -333,6 +434,11 @@ impl TextEntityTypeBold {
     RTDTextEntityTypeBoldBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeBold", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -343,6 +449,9 @@ pub struct RTDTextEntityTypeBoldBuilder {
 impl RTDTextEntityTypeBoldBuilder {
   pub fn build(&self) -> TextEntityTypeBold { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeBold> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeBold> for TextEntityTypeBold {
@@ -391,6 +500,11 @@ impl TextEntityTypeBotCommand {
     RTDTextEntityTypeBotCommandBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeBotCommand", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -401,6 +515,9 @@ pub struct RTDTextEntityTypeBotCommandBuilder {
 impl RTDTextEntityTypeBotCommandBuilder {
   pub fn build(&self) -> TextEntityTypeBotCommand { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeBotCommand> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeBotCommand> for TextEntityTypeBotCommand {
@@ -449,6 +566,11 @@ impl TextEntityTypeCashtag {
     RTDTextEntityTypeCashtagBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeCashtag", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -459,6 +581,9 @@ pub struct RTDTextEntityTypeCashtagBuilder {
 impl RTDTextEntityTypeCashtagBuilder {
   pub fn build(&self) -> TextEntityTypeCashtag { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeCashtag> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeCashtag> for TextEntityTypeCashtag {
@@ -507,6 +632,11 @@ impl TextEntityTypeCode {
     RTDTextEntityTypeCodeBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -517,6 +647,9 @@ pub struct RTDTextEntityTypeCodeBuilder {
 impl RTDTextEntityTypeCodeBuilder {
   pub fn build(&self) -> TextEntityTypeCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeCode> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeCode> for TextEntityTypeCode {
@@ -565,6 +698,11 @@ impl TextEntityTypeEmailAddress {
     RTDTextEntityTypeEmailAddressBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeEmailAddress", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -575,6 +713,9 @@ pub struct RTDTextEntityTypeEmailAddressBuilder {
 impl RTDTextEntityTypeEmailAddressBuilder {
   pub fn build(&self) -> TextEntityTypeEmailAddress { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeEmailAddress> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeEmailAddress> for TextEntityTypeEmailAddress {
@@ -623,6 +764,11 @@ impl TextEntityTypeHashtag {
     RTDTextEntityTypeHashtagBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeHashtag", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -633,6 +779,9 @@ pub struct RTDTextEntityTypeHashtagBuilder {
 impl RTDTextEntityTypeHashtagBuilder {
   pub fn build(&self) -> TextEntityTypeHashtag { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeHashtag> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeHashtag> for TextEntityTypeHashtag {
@@ -681,6 +830,11 @@ impl TextEntityTypeItalic {
     RTDTextEntityTypeItalicBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeItalic", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -691,6 +845,9 @@ pub struct RTDTextEntityTypeItalicBuilder {
 impl RTDTextEntityTypeItalicBuilder {
   pub fn build(&self) -> TextEntityTypeItalic { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeItalic> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeItalic> for TextEntityTypeItalic {
@@ -739,6 +896,11 @@ impl TextEntityTypeMention {
     RTDTextEntityTypeMentionBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeMention", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -749,6 +911,9 @@ pub struct RTDTextEntityTypeMentionBuilder {
 impl RTDTextEntityTypeMentionBuilder {
   pub fn build(&self) -> TextEntityTypeMention { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeMention> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeMention> for TextEntityTypeMention {
@@ -796,7 +961,16 @@ impl TextEntityTypeMentionName {
     let mut inner = TextEntityTypeMentionName::default();
     inner.td_name = "textEntityTypeMentionName".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextEntityTypeMentionNameBuilder { inner }
+    RTDTextEntityTypeMentionNameBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeMentionName", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -805,15 +979,25 @@ impl TextEntityTypeMentionName {
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeMentionNameBuilder {
-  inner: TextEntityTypeMentionName
+  inner: TextEntityTypeMentionName,
+  set: [bool; 1]
 }
 
 impl RTDTextEntityTypeMentionNameBuilder {
   pub fn build(&self) -> TextEntityTypeMentionName { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeMentionName> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("textEntityTypeMentionName", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -865,6 +1049,11 @@ impl TextEntityTypePhoneNumber {
     RTDTextEntityTypePhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypePhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -875,6 +1064,9 @@ pub struct RTDTextEntityTypePhoneNumberBuilder {
 impl RTDTextEntityTypePhoneNumberBuilder {
   pub fn build(&self) -> TextEntityTypePhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypePhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypePhoneNumber> for TextEntityTypePhoneNumber {
@@ -923,6 +1115,11 @@ impl TextEntityTypePre {
     RTDTextEntityTypePreBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypePre", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -933,6 +1130,9 @@ pub struct RTDTextEntityTypePreBuilder {
 impl RTDTextEntityTypePreBuilder {
   pub fn build(&self) -> TextEntityTypePre { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypePre> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypePre> for TextEntityTypePre {
@@ -980,7 +1180,16 @@ impl TextEntityTypePreCode {
     let mut inner = TextEntityTypePreCode::default();
     inner.td_name = "textEntityTypePreCode".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextEntityTypePreCodeBuilder { inner }
+    RTDTextEntityTypePreCodeBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypePreCode", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.language.is_empty() { missing.push("language".to_string()); }
+    missing
   }
 
   pub fn language(&self) -> &String { &self.language }
@@ -989,15 +1198,25 @@ impl TextEntityTypePreCode {
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypePreCodeBuilder {
-  inner: TextEntityTypePreCode
+  inner: TextEntityTypePreCode,
+  set: [bool; 1]
 }
 
 impl RTDTextEntityTypePreCodeBuilder {
   pub fn build(&self) -> TextEntityTypePreCode { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypePreCode> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("language".to_string()); }
+    crate::types::_common::check_missing("textEntityTypePreCode", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn language<T: AsRef<str>>(&mut self, language: T) -> &mut Self {
     self.inner.language = language.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1049,6 +1268,11 @@ impl TextEntityTypeStrikethrough {
     RTDTextEntityTypeStrikethroughBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeStrikethrough", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1059,6 +1283,9 @@ pub struct RTDTextEntityTypeStrikethroughBuilder {
 impl RTDTextEntityTypeStrikethroughBuilder {
   pub fn build(&self) -> TextEntityTypeStrikethrough { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeStrikethrough> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeStrikethrough> for TextEntityTypeStrikethrough {
@@ -1106,7 +1333,16 @@ impl TextEntityTypeTextUrl {
     let mut inner = TextEntityTypeTextUrl::default();
     inner.td_name = "textEntityTypeTextUrl".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextEntityTypeTextUrlBuilder { inner }
+    RTDTextEntityTypeTextUrlBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeTextUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -1115,15 +1351,25 @@ impl TextEntityTypeTextUrl {
 
 #[doc(hidden)]
 pub struct RTDTextEntityTypeTextUrlBuilder {
-  inner: TextEntityTypeTextUrl
+  inner: TextEntityTypeTextUrl,
+  set: [bool; 1]
 }
 
 impl RTDTextEntityTypeTextUrlBuilder {
   pub fn build(&self) -> TextEntityTypeTextUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeTextUrl> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    crate::types::_common::check_missing("textEntityTypeTextUrl", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1175,6 +1421,11 @@ impl TextEntityTypeUnderline {
     RTDTextEntityTypeUnderlineBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeUnderline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1185,6 +1436,9 @@ pub struct RTDTextEntityTypeUnderlineBuilder {
 impl RTDTextEntityTypeUnderlineBuilder {
   pub fn build(&self) -> TextEntityTypeUnderline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeUnderline> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeUnderline> for TextEntityTypeUnderline {
@@ -1233,6 +1487,11 @@ impl TextEntityTypeUrl {
     RTDTextEntityTypeUrlBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textEntityTypeUrl", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -1243,6 +1502,9 @@ pub struct RTDTextEntityTypeUrlBuilder {
 impl RTDTextEntityTypeUrlBuilder {
   pub fn build(&self) -> TextEntityTypeUrl { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextEntityTypeUrl> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextEntityTypeUrl> for TextEntityTypeUrl {
@@ -19,6 +19,9 @@ pub trait TDTextParseMode: Debug + RObject {}
 #[serde(untagged)]
 pub enum TextParseMode {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The text uses HTML-style formatting. The same as Telegram Bot API "HTML" parse mode
   HTML(TextParseModeHTML),
   /// The text uses Markdown-style formatting
@@ -32,7 +35,6 @@ impl Default for TextParseMode {
 
 impl<'de> Deserialize<'de> for TextParseMode {
   fn deserialize<D>(deserializer: D) -> Result<TextParseMode, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       TextParseMode,
       (textParseModeHTML, HTML);
@@ -55,6 +57,7 @@ impl RObject for TextParseMode {
     match self {
       TextParseMode::HTML(t) => t.extra(),
       TextParseMode::Markdown(t) => t.extra(),
+      TextParseMode::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for TextParseMode {
 impl TextParseMode {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TextParseMode::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      TextParseMode::HTML(t) => t._missing_fields(),
+      TextParseMode::Markdown(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_h_t_m_l(&self) -> bool { if let TextParseMode::HTML(_) = self { true } else { false } }
   pub fn is_markdown(&self) -> bool { if let TextParseMode::Markdown(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<TextParseMode> for TextParseMode {
   fn as_ref(&self) -> &TextParseMode { self }
 }
 
+impl RVariant<TextParseMode> for TextParseModeHTML {
+  fn from_class(class: &TextParseMode) -> Option<&Self> { match class { TextParseMode::HTML(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TextParseMode> for TextParseModeMarkdown {
+  fn from_class(class: &TextParseMode) -> Option<&Self> { match class { TextParseMode::Markdown(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -125,6 +143,11 @@ impl TextParseModeHTML {
     RTDTextParseModeHTMLBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textParseModeHTML", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -135,6 +158,9 @@ pub struct RTDTextParseModeHTMLBuilder {
 impl RTDTextParseModeHTMLBuilder {
   pub fn build(&self) -> TextParseModeHTML { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextParseModeHTML> { Ok(self.build()) }
+
 }
 
 impl AsRef<TextParseModeHTML> for TextParseModeHTML {
@@ -182,7 +208,16 @@ impl TextParseModeMarkdown {
     let mut inner = TextParseModeMarkdown::default();
     inner.td_name = "textParseModeMarkdown".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDTextParseModeMarkdownBuilder { inner }
+    RTDTextParseModeMarkdownBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("textParseModeMarkdown", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.version == 0 { missing.push("version".to_string()); }
+    missing
   }
 
   pub fn version(&self) -> i64 { self.version }
@@ -191,15 +226,25 @@ impl TextParseModeMarkdown {
 
 #[doc(hidden)]
 pub struct RTDTextParseModeMarkdownBuilder {
-  inner: TextParseModeMarkdown
+  inner: TextParseModeMarkdown,
+  set: [bool; 1]
 }
 
 impl RTDTextParseModeMarkdownBuilder {
   pub fn build(&self) -> TextParseModeMarkdown { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TextParseModeMarkdown> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("version".to_string()); }
+    crate::types::_common::check_missing("textParseModeMarkdown", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn version(&mut self, version: i64) -> &mut Self {
     self.inner.version = version;
+    self.set[0] = true;
     self
   }
 
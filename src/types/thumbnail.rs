@@ -40,7 +40,19 @@ impl Thumbnail {
     let mut inner = Thumbnail::default();
     inner.td_name = "thumbnail".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDThumbnailBuilder { inner }
+    RTDThumbnailBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnail", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.format._is_default() { missing.push("format".to_string()); } else { missing.extend(crate::types::_common::missing_in("format", self.format._missing_fields())); }
+    if self.width == 0 { missing.push("width".to_string()); }
+    if self.height == 0 { missing.push("height".to_string()); }
+    if false { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.file._missing_fields())); }
+    missing
   }
 
   pub fn format(&self) -> &ThumbnailFormat { &self.format }
@@ -55,12 +67,24 @@ impl Thumbnail {
 
 #[doc(hidden)]
 pub struct RTDThumbnailBuilder {
-  inner: Thumbnail
+  inner: Thumbnail,
+  set: [bool; 3]
 }
 
 impl RTDThumbnailBuilder {
   pub fn build(&self) -> Thumbnail { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Thumbnail> {
+    let mut missing = vec![];
+    if self.inner.format._is_default() { missing.push("format".to_string()); } else { missing.extend(crate::types::_common::missing_in("format", self.inner.format._missing_fields())); }
+    if !self.set[0] { missing.push("width".to_string()); }
+    if !self.set[1] { missing.push("height".to_string()); }
+    if !self.set[2] { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.inner.file._missing_fields())); }
+    crate::types::_common::check_missing("thumbnail", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn format<T: AsRef<ThumbnailFormat>>(&mut self, format: T) -> &mut Self {
     self.inner.format = format.as_ref().clone();
@@ -70,18 +94,21 @@ impl RTDThumbnailBuilder {
    
   pub fn width(&mut self, width: i64) -> &mut Self {
     self.inner.width = width;
+    self.set[0] = true;
     self
   }
 
    
   pub fn height(&mut self, height: i64) -> &mut Self {
     self.inner.height = height;
+    self.set[1] = true;
     self
   }
 
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDThumbnailFormat: Debug + RObject {}
 #[serde(untagged)]
 pub enum ThumbnailFormat {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The thumbnail is in static GIF format. It will be used only for some bot inline results
   Gif(ThumbnailFormatGif),
   /// The thumbnail is in JPEG format
@@ -40,7 +43,6 @@ impl Default for ThumbnailFormat {
 
 impl<'de> Deserialize<'de> for ThumbnailFormat {
   fn deserialize<D>(deserializer: D) -> Result<ThumbnailFormat, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       ThumbnailFormat,
       (thumbnailFormatGif, Gif);
@@ -75,6 +77,7 @@ impl RObject for ThumbnailFormat {
       ThumbnailFormat::Png(t) => t.extra(),
       ThumbnailFormat::Tgs(t) => t.extra(),
       ThumbnailFormat::Webp(t) => t.extra(),
+      ThumbnailFormat::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,6 +88,17 @@ impl RObject for ThumbnailFormat {
 impl ThumbnailFormat {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let ThumbnailFormat::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      ThumbnailFormat::Gif(t) => t._missing_fields(),
+      ThumbnailFormat::Jpeg(t) => t._missing_fields(),
+      ThumbnailFormat::Mpeg4(t) => t._missing_fields(),
+      ThumbnailFormat::Png(t) => t._missing_fields(),
+      ThumbnailFormat::Tgs(t) => t._missing_fields(),
+      ThumbnailFormat::Webp(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_gif(&self) -> bool { if let ThumbnailFormat::Gif(_) = self { true } else { false } }
   pub fn is_jpeg(&self) -> bool { if let ThumbnailFormat::Jpeg(_) = self { true } else { false } }
@@ -127,6 +141,30 @@ impl AsRef<ThumbnailFormat> for ThumbnailFormat {
   fn as_ref(&self) -> &ThumbnailFormat { self }
 }
 
+impl RVariant<ThumbnailFormat> for ThumbnailFormatGif {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Gif(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ThumbnailFormat> for ThumbnailFormatJpeg {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Jpeg(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ThumbnailFormat> for ThumbnailFormatMpeg4 {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Mpeg4(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ThumbnailFormat> for ThumbnailFormatPng {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Png(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ThumbnailFormat> for ThumbnailFormatTgs {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Tgs(t) => Some(t), _ => None } }
+}
+
+impl RVariant<ThumbnailFormat> for ThumbnailFormatWebp {
+  fn from_class(class: &ThumbnailFormat) -> Option<&Self> { match class { ThumbnailFormat::Webp(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -165,6 +203,11 @@ impl ThumbnailFormatGif {
     RTDThumbnailFormatGifBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatGif", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +218,9 @@ pub struct RTDThumbnailFormatGifBuilder {
 impl RTDThumbnailFormatGifBuilder {
   pub fn build(&self) -> ThumbnailFormatGif { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatGif> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatGif> for ThumbnailFormatGif {
@@ -223,6 +269,11 @@ impl ThumbnailFormatJpeg {
     RTDThumbnailFormatJpegBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatJpeg", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +284,9 @@ pub struct RTDThumbnailFormatJpegBuilder {
 impl RTDThumbnailFormatJpegBuilder {
   pub fn build(&self) -> ThumbnailFormatJpeg { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatJpeg> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatJpeg> for ThumbnailFormatJpeg {
@@ -281,6 +335,11 @@ impl ThumbnailFormatMpeg4 {
     RTDThumbnailFormatMpeg4Builder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatMpeg4", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +350,9 @@ pub struct RTDThumbnailFormatMpeg4Builder {
 impl RTDThumbnailFormatMpeg4Builder {
   pub fn build(&self) -> ThumbnailFormatMpeg4 { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatMpeg4> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatMpeg4> for ThumbnailFormatMpeg4 {
@@ -339,6 +401,11 @@ impl ThumbnailFormatPng {
     RTDThumbnailFormatPngBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatPng", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -349,6 +416,9 @@ pub struct RTDThumbnailFormatPngBuilder {
 impl RTDThumbnailFormatPngBuilder {
   pub fn build(&self) -> ThumbnailFormatPng { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatPng> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatPng> for ThumbnailFormatPng {
@@ -397,6 +467,11 @@ impl ThumbnailFormatTgs {
     RTDThumbnailFormatTgsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatTgs", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -407,6 +482,9 @@ pub struct RTDThumbnailFormatTgsBuilder {
 impl RTDThumbnailFormatTgsBuilder {
   pub fn build(&self) -> ThumbnailFormatTgs { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatTgs> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatTgs> for ThumbnailFormatTgs {
@@ -455,6 +533,11 @@ impl ThumbnailFormatWebp {
     RTDThumbnailFormatWebpBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("thumbnailFormatWebp", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -465,6 +548,9 @@ pub struct RTDThumbnailFormatWebpBuilder {
 impl RTDThumbnailFormatWebpBuilder {
   pub fn build(&self) -> ThumbnailFormatWebp { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ThumbnailFormatWebp> { Ok(self.build()) }
+
 }
 
 impl AsRef<ThumbnailFormatWebp> for ThumbnailFormatWebp {
@@ -19,6 +19,9 @@ pub trait TDTopChatCategory: Debug + RObject {}
 #[serde(untagged)]
 pub enum TopChatCategory {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A category containing frequently used private chats with bot users
   Bots(TopChatCategoryBots),
   /// A category containing frequently used chats used for calls
@@ -42,7 +45,6 @@ impl Default for TopChatCategory {
 
 impl<'de> Deserialize<'de> for TopChatCategory {
   fn deserialize<D>(deserializer: D) -> Result<TopChatCategory, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       TopChatCategory,
       (topChatCategoryBots, Bots);
@@ -80,6 +82,7 @@ impl RObject for TopChatCategory {
       TopChatCategory::Groups(t) => t.extra(),
       TopChatCategory::InlineBots(t) => t.extra(),
       TopChatCategory::Users(t) => t.extra(),
+      TopChatCategory::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -90,6 +93,18 @@ impl RObject for TopChatCategory {
 impl TopChatCategory {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let TopChatCategory::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      TopChatCategory::Bots(t) => t._missing_fields(),
+      TopChatCategory::Calls(t) => t._missing_fields(),
+      TopChatCategory::Channels(t) => t._missing_fields(),
+      TopChatCategory::ForwardChats(t) => t._missing_fields(),
+      TopChatCategory::Groups(t) => t._missing_fields(),
+      TopChatCategory::InlineBots(t) => t._missing_fields(),
+      TopChatCategory::Users(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_bots(&self) -> bool { if let TopChatCategory::Bots(_) = self { true } else { false } }
   pub fn is_calls(&self) -> bool { if let TopChatCategory::Calls(_) = self { true } else { false } }
@@ -137,6 +152,34 @@ impl AsRef<TopChatCategory> for TopChatCategory {
   fn as_ref(&self) -> &TopChatCategory { self }
 }
 
+impl RVariant<TopChatCategory> for TopChatCategoryBots {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::Bots(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryCalls {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::Calls(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryChannels {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::Channels(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryForwardChats {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::ForwardChats(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryGroups {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::Groups(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryInlineBots {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::InlineBots(t) => Some(t), _ => None } }
+}
+
+impl RVariant<TopChatCategory> for TopChatCategoryUsers {
+  fn from_class(class: &TopChatCategory) -> Option<&Self> { match class { TopChatCategory::Users(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -175,6 +218,11 @@ impl TopChatCategoryBots {
     RTDTopChatCategoryBotsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryBots", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -185,6 +233,9 @@ pub struct RTDTopChatCategoryBotsBuilder {
 impl RTDTopChatCategoryBotsBuilder {
   pub fn build(&self) -> TopChatCategoryBots { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryBots> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryBots> for TopChatCategoryBots {
@@ -233,6 +284,11 @@ impl TopChatCategoryCalls {
     RTDTopChatCategoryCallsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryCalls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -243,6 +299,9 @@ pub struct RTDTopChatCategoryCallsBuilder {
 impl RTDTopChatCategoryCallsBuilder {
   pub fn build(&self) -> TopChatCategoryCalls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryCalls> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryCalls> for TopChatCategoryCalls {
@@ -291,6 +350,11 @@ impl TopChatCategoryChannels {
     RTDTopChatCategoryChannelsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryChannels", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -301,6 +365,9 @@ pub struct RTDTopChatCategoryChannelsBuilder {
 impl RTDTopChatCategoryChannelsBuilder {
   pub fn build(&self) -> TopChatCategoryChannels { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryChannels> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryChannels> for TopChatCategoryChannels {
@@ -349,6 +416,11 @@ impl TopChatCategoryForwardChats {
     RTDTopChatCategoryForwardChatsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryForwardChats", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -359,6 +431,9 @@ pub struct RTDTopChatCategoryForwardChatsBuilder {
 impl RTDTopChatCategoryForwardChatsBuilder {
   pub fn build(&self) -> TopChatCategoryForwardChats { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryForwardChats> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryForwardChats> for TopChatCategoryForwardChats {
@@ -407,6 +482,11 @@ impl TopChatCategoryGroups {
     RTDTopChatCategoryGroupsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryGroups", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -417,6 +497,9 @@ pub struct RTDTopChatCategoryGroupsBuilder {
 impl RTDTopChatCategoryGroupsBuilder {
   pub fn build(&self) -> TopChatCategoryGroups { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryGroups> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryGroups> for TopChatCategoryGroups {
@@ -465,6 +548,11 @@ impl TopChatCategoryInlineBots {
     RTDTopChatCategoryInlineBotsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryInlineBots", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -475,6 +563,9 @@ pub struct RTDTopChatCategoryInlineBotsBuilder {
 impl RTDTopChatCategoryInlineBotsBuilder {
   pub fn build(&self) -> TopChatCategoryInlineBots { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryInlineBots> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryInlineBots> for TopChatCategoryInlineBots {
@@ -523,6 +614,11 @@ impl TopChatCategoryUsers {
     RTDTopChatCategoryUsersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("topChatCategoryUsers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -533,6 +629,9 @@ pub struct RTDTopChatCategoryUsersBuilder {
 impl RTDTopChatCategoryUsersBuilder {
   pub fn build(&self) -> TopChatCategoryUsers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<TopChatCategoryUsers> { Ok(self.build()) }
+
 }
 
 impl AsRef<TopChatCategoryUsers> for TopChatCategoryUsers {
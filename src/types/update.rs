@@ -19,8 +19,9 @@ pub trait TDUpdate: Debug + RObject {}
 #[serde(untagged)]
 pub enum Update {
   #[doc(hidden)] _Default(()),
-  /// Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization
-  TestUseUpdate(TestUseUpdate),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update
   ActiveNotifications(UpdateActiveNotifications),
   /// The parameters of animation search through GetOption("animation_search_bot_username") bot has changed
@@ -196,10 +197,8 @@ impl Default for Update {
 
 impl<'de> Deserialize<'de> for Update {
   fn deserialize<D>(deserializer: D) -> Result<Update, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       Update,
-      (testUseUpdate, TestUseUpdate);
       (updateActiveNotifications, ActiveNotifications);
       (updateAnimationSearchParameters, AnimationSearchParameters);
       (updateAuthorizationState, AuthorizationState);
@@ -291,7 +290,6 @@ impl<'de> Deserialize<'de> for Update {
 impl RObject for Update {
   #[doc(hidden)] fn td_name(&self) -> &'static str {
     match self {
-      Update::TestUseUpdate(t) => t.td_name(),
       Update::ActiveNotifications(t) => t.td_name(),
       Update::AnimationSearchParameters(t) => t.td_name(),
       Update::AuthorizationState(t) => t.td_name(),
@@ -381,7 +379,6 @@ impl RObject for Update {
   }
   #[doc(hidden)] fn extra(&self) -> Option<String> {
     match self {
-      Update::TestUseUpdate(t) => t.extra(),
       Update::ActiveNotifications(t) => t.extra(),
       Update::AnimationSearchParameters(t) => t.extra(),
       Update::AuthorizationState(t) => t.extra(),
@@ -465,6 +462,7 @@ impl RObject for Update {
       Update::UserPrivacySettingRules(t) => t.extra(),
       Update::UserStatus(t) => t.extra(),
       Update::UsersNearby(t) => t.extra(),
+      Update::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -475,8 +473,95 @@ impl RObject for Update {
 impl Update {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let Update::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      Update::ActiveNotifications(t) => t._missing_fields(),
+      Update::AnimationSearchParameters(t) => t._missing_fields(),
+      Update::AuthorizationState(t) => t._missing_fields(),
+      Update::BasicGroup(t) => t._missing_fields(),
+      Update::BasicGroupFullInfo(t) => t._missing_fields(),
+      Update::Call(t) => t._missing_fields(),
+      Update::ChatActionBar(t) => t._missing_fields(),
+      Update::ChatDefaultDisableNotification(t) => t._missing_fields(),
+      Update::ChatDraftMessage(t) => t._missing_fields(),
+      Update::ChatFilters(t) => t._missing_fields(),
+      Update::ChatHasScheduledMessages(t) => t._missing_fields(),
+      Update::ChatIsBlocked(t) => t._missing_fields(),
+      Update::ChatIsMarkedAsUnread(t) => t._missing_fields(),
+      Update::ChatLastMessage(t) => t._missing_fields(),
+      Update::ChatNotificationSettings(t) => t._missing_fields(),
+      Update::ChatOnlineMemberCount(t) => t._missing_fields(),
+      Update::ChatPermissions(t) => t._missing_fields(),
+      Update::ChatPhoto(t) => t._missing_fields(),
+      Update::ChatPosition(t) => t._missing_fields(),
+      Update::ChatReadInbox(t) => t._missing_fields(),
+      Update::ChatReadOutbox(t) => t._missing_fields(),
+      Update::ChatReplyMarkup(t) => t._missing_fields(),
+      Update::ChatTitle(t) => t._missing_fields(),
+      Update::ChatUnreadMentionCount(t) => t._missing_fields(),
+      Update::ChatVoiceChat(t) => t._missing_fields(),
+      Update::ConnectionState(t) => t._missing_fields(),
+      Update::DeleteMessages(t) => t._missing_fields(),
+      Update::DiceEmojis(t) => t._missing_fields(),
+      Update::FavoriteStickers(t) => t._missing_fields(),
+      Update::File(t) => t._missing_fields(),
+      Update::FileGenerationStart(t) => t._missing_fields(),
+      Update::FileGenerationStop(t) => t._missing_fields(),
+      Update::GroupCall(t) => t._missing_fields(),
+      Update::GroupCallParticipant(t) => t._missing_fields(),
+      Update::HavePendingNotifications(t) => t._missing_fields(),
+      Update::InstalledStickerSets(t) => t._missing_fields(),
+      Update::LanguagePackStrings(t) => t._missing_fields(),
+      Update::MessageContent(t) => t._missing_fields(),
+      Update::MessageContentOpened(t) => t._missing_fields(),
+      Update::MessageEdited(t) => t._missing_fields(),
+      Update::MessageInteractionInfo(t) => t._missing_fields(),
+      Update::MessageIsPinned(t) => t._missing_fields(),
+      Update::MessageLiveLocationViewed(t) => t._missing_fields(),
+      Update::MessageMentionRead(t) => t._missing_fields(),
+      Update::MessageSendAcknowledged(t) => t._missing_fields(),
+      Update::MessageSendFailed(t) => t._missing_fields(),
+      Update::MessageSendSucceeded(t) => t._missing_fields(),
+      Update::NewCallSignalingData(t) => t._missing_fields(),
+      Update::NewCallbackQuery(t) => t._missing_fields(),
+      Update::NewChat(t) => t._missing_fields(),
+      Update::NewChosenInlineResult(t) => t._missing_fields(),
+      Update::NewCustomEvent(t) => t._missing_fields(),
+      Update::NewCustomQuery(t) => t._missing_fields(),
+      Update::NewInlineCallbackQuery(t) => t._missing_fields(),
+      Update::NewInlineQuery(t) => t._missing_fields(),
+      Update::NewMessage(t) => t._missing_fields(),
+      Update::NewPreCheckoutQuery(t) => t._missing_fields(),
+      Update::NewShippingQuery(t) => t._missing_fields(),
+      Update::Notification(t) => t._missing_fields(),
+      Update::NotificationGroup(t) => t._missing_fields(),
+      Update::Option(t) => t._missing_fields(),
+      Update::Poll(t) => t._missing_fields(),
+      Update::PollAnswer(t) => t._missing_fields(),
+      Update::RecentStickers(t) => t._missing_fields(),
+      Update::SavedAnimations(t) => t._missing_fields(),
+      Update::ScopeNotificationSettings(t) => t._missing_fields(),
+      Update::SecretChat(t) => t._missing_fields(),
+      Update::SelectedBackground(t) => t._missing_fields(),
+      Update::ServiceNotification(t) => t._missing_fields(),
+      Update::StickerSet(t) => t._missing_fields(),
+      Update::SuggestedActions(t) => t._missing_fields(),
+      Update::Supergroup(t) => t._missing_fields(),
+      Update::SupergroupFullInfo(t) => t._missing_fields(),
+      Update::TermsOfService(t) => t._missing_fields(),
+      Update::TrendingStickerSets(t) => t._missing_fields(),
+      Update::UnreadChatCount(t) => t._missing_fields(),
+      Update::UnreadMessageCount(t) => t._missing_fields(),
+      Update::User(t) => t._missing_fields(),
+      Update::UserChatAction(t) => t._missing_fields(),
+      Update::UserFullInfo(t) => t._missing_fields(),
+      Update::UserPrivacySettingRules(t) => t._missing_fields(),
+      Update::UserStatus(t) => t._missing_fields(),
+      Update::UsersNearby(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
-  pub fn is_test_use_update(&self) -> bool { if let Update::TestUseUpdate(_) = self { true } else { false } }
   pub fn is_active_notifications(&self) -> bool { if let Update::ActiveNotifications(_) = self { true } else { false } }
   pub fn is_animation_search_parameters(&self) -> bool { if let Update::AnimationSearchParameters(_) = self { true } else { false } }
   pub fn is_authorization_state(&self) -> bool { if let Update::AuthorizationState(_) = self { true } else { false } }
@@ -561,7 +646,6 @@ impl Update {
   pub fn is_user_status(&self) -> bool { if let Update::UserStatus(_) = self { true } else { false } }
   pub fn is_users_nearby(&self) -> bool { if let Update::UsersNearby(_) = self { true } else { false } }
 
-  pub fn on_test_use_update<F: FnOnce(&TestUseUpdate)>(&self, fnc: F) -> &Self { if let Update::TestUseUpdate(t) = self { fnc(t) }; self }
   pub fn on_active_notifications<F: FnOnce(&UpdateActiveNotifications)>(&self, fnc: F) -> &Self { if let Update::ActiveNotifications(t) = self { fnc(t) }; self }
   pub fn on_animation_search_parameters<F: FnOnce(&UpdateAnimationSearchParameters)>(&self, fnc: F) -> &Self { if let Update::AnimationSearchParameters(t) = self { fnc(t) }; self }
   pub fn on_authorization_state<F: FnOnce(&UpdateAuthorizationState)>(&self, fnc: F) -> &Self { if let Update::AuthorizationState(t) = self { fnc(t) }; self }
@@ -646,7 +730,6 @@ impl Update {
   pub fn on_user_status<F: FnOnce(&UpdateUserStatus)>(&self, fnc: F) -> &Self { if let Update::UserStatus(t) = self { fnc(t) }; self }
   pub fn on_users_nearby<F: FnOnce(&UpdateUsersNearby)>(&self, fnc: F) -> &Self { if let Update::UsersNearby(t) = self { fnc(t) }; self }
 
-  pub fn as_test_use_update(&self) -> Option<&TestUseUpdate> { if let Update::TestUseUpdate(t) = self { return Some(t) } None }
   pub fn as_active_notifications(&self) -> Option<&UpdateActiveNotifications> { if let Update::ActiveNotifications(t) = self { return Some(t) } None }
   pub fn as_animation_search_parameters(&self) -> Option<&UpdateAnimationSearchParameters> { if let Update::AnimationSearchParameters(t) = self { return Some(t) } None }
   pub fn as_authorization_state(&self) -> Option<&UpdateAuthorizationState> { if let Update::AuthorizationState(t) = self { return Some(t) } None }
@@ -733,8 +816,6 @@ impl Update {
 
 
 
-  pub fn test_use_update<T: AsRef<TestUseUpdate>>(t: T) -> Self { Update::TestUseUpdate(t.as_ref().clone()) }
-
   pub fn active_notifications<T: AsRef<UpdateActiveNotifications>>(t: T) -> Self { Update::ActiveNotifications(t.as_ref().clone()) }
 
   pub fn animation_search_parameters<T: AsRef<UpdateAnimationSearchParameters>>(t: T) -> Self { Update::AnimationSearchParameters(t.as_ref().clone()) }
@@ -907,6 +988,338 @@ impl AsRef<Update> for Update {
   fn as_ref(&self) -> &Update { self }
 }
 
+impl RVariant<Update> for UpdateActiveNotifications {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ActiveNotifications(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateAnimationSearchParameters {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::AnimationSearchParameters(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateAuthorizationState {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::AuthorizationState(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateBasicGroup {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::BasicGroup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateBasicGroupFullInfo {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::BasicGroupFullInfo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateCall {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::Call(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatActionBar {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatActionBar(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatDefaultDisableNotification {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatDefaultDisableNotification(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatDraftMessage {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatDraftMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatFilters {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatFilters(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatHasScheduledMessages {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatHasScheduledMessages(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatIsBlocked {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatIsBlocked(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatIsMarkedAsUnread {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatIsMarkedAsUnread(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatLastMessage {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatLastMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatNotificationSettings {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatNotificationSettings(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatOnlineMemberCount {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatOnlineMemberCount(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatPermissions {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatPermissions(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatPhoto {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatPhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatPosition {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatPosition(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatReadInbox {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatReadInbox(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatReadOutbox {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatReadOutbox(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatReplyMarkup {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatReplyMarkup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatTitle {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatTitle(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatUnreadMentionCount {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatUnreadMentionCount(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateChatVoiceChat {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ChatVoiceChat(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateConnectionState {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ConnectionState(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateDeleteMessages {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::DeleteMessages(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateDiceEmojis {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::DiceEmojis(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateFavoriteStickers {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::FavoriteStickers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateFile {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::File(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateFileGenerationStart {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::FileGenerationStart(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateFileGenerationStop {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::FileGenerationStop(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateGroupCall {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::GroupCall(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateGroupCallParticipant {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::GroupCallParticipant(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateHavePendingNotifications {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::HavePendingNotifications(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateInstalledStickerSets {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::InstalledStickerSets(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateLanguagePackStrings {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::LanguagePackStrings(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageContent {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageContent(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageContentOpened {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageContentOpened(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageEdited {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageEdited(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageInteractionInfo {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageInteractionInfo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageIsPinned {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageIsPinned(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageLiveLocationViewed {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageLiveLocationViewed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageMentionRead {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageMentionRead(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageSendAcknowledged {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageSendAcknowledged(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageSendFailed {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageSendFailed(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateMessageSendSucceeded {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::MessageSendSucceeded(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewCallSignalingData {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewCallSignalingData(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewCallbackQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewCallbackQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewChat {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewChat(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewChosenInlineResult {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewChosenInlineResult(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewCustomEvent {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewCustomEvent(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewCustomQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewCustomQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewInlineCallbackQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewInlineCallbackQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewInlineQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewInlineQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewMessage {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewMessage(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewPreCheckoutQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewPreCheckoutQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNewShippingQuery {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NewShippingQuery(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNotification {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::Notification(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateNotificationGroup {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::NotificationGroup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateOption {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::Option(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdatePoll {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::Poll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdatePollAnswer {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::PollAnswer(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateRecentStickers {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::RecentStickers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSavedAnimations {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::SavedAnimations(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateScopeNotificationSettings {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ScopeNotificationSettings(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSecretChat {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::SecretChat(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSelectedBackground {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::SelectedBackground(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateServiceNotification {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::ServiceNotification(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateStickerSet {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::StickerSet(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSuggestedActions {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::SuggestedActions(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSupergroup {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::Supergroup(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateSupergroupFullInfo {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::SupergroupFullInfo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateTermsOfService {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::TermsOfService(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateTrendingStickerSets {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::TrendingStickerSets(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUnreadChatCount {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UnreadChatCount(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUnreadMessageCount {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UnreadMessageCount(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUser {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::User(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUserChatAction {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UserChatAction(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUserFullInfo {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UserFullInfo(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUserPrivacySettingRules {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UserPrivacySettingRules(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUserStatus {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UserStatus(t) => Some(t), _ => None } }
+}
+
+impl RVariant<Update> for UpdateUsersNearby {
+  fn from_class(class: &Update) -> Option<&Self> { match class { Update::UsersNearby(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -947,6 +1360,11 @@ impl UpdateActiveNotifications {
     RTDUpdateActiveNotificationsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateActiveNotifications", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn groups(&self) -> &Vec<NotificationGroup> { &self.groups }
 
 }
@@ -959,6 +1377,9 @@ pub struct RTDUpdateActiveNotificationsBuilder {
 impl RTDUpdateActiveNotificationsBuilder {
   pub fn build(&self) -> UpdateActiveNotifications { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateActiveNotifications> { Ok(self.build()) }
+
    
   pub fn groups(&mut self, groups: Vec<NotificationGroup>) -> &mut Self {
     self.inner.groups = groups;
@@ -1014,7 +1435,16 @@ impl UpdateAnimationSearchParameters {
     let mut inner = UpdateAnimationSearchParameters::default();
     inner.td_name = "updateAnimationSearchParameters".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateAnimationSearchParametersBuilder { inner }
+    RTDUpdateAnimationSearchParametersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateAnimationSearchParameters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.provider.is_empty() { missing.push("provider".to_string()); }
+    missing
   }
 
   pub fn provider(&self) -> &String { &self.provider }
@@ -1025,15 +1455,25 @@ impl UpdateAnimationSearchParameters {
 
 #[doc(hidden)]
 pub struct RTDUpdateAnimationSearchParametersBuilder {
-  inner: UpdateAnimationSearchParameters
+  inner: UpdateAnimationSearchParameters,
+  set: [bool; 1]
 }
 
 impl RTDUpdateAnimationSearchParametersBuilder {
   pub fn build(&self) -> UpdateAnimationSearchParameters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateAnimationSearchParameters> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("provider".to_string()); }
+    crate::types::_common::check_missing("updateAnimationSearchParameters", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn provider<T: AsRef<str>>(&mut self, provider: T) -> &mut Self {
     self.inner.provider = provider.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -1093,6 +1533,15 @@ impl UpdateAuthorizationState {
     RTDUpdateAuthorizationStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateAuthorizationState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.authorization_state._is_default() { missing.push("authorization_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("authorization_state", self.authorization_state._missing_fields())); }
+    missing
+  }
+
   pub fn authorization_state(&self) -> &AuthorizationState { &self.authorization_state }
 
 }
@@ -1105,6 +1554,14 @@ pub struct RTDUpdateAuthorizationStateBuilder {
 impl RTDUpdateAuthorizationStateBuilder {
   pub fn build(&self) -> UpdateAuthorizationState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateAuthorizationState> {
+    let mut missing = vec![];
+    if self.inner.authorization_state._is_default() { missing.push("authorization_state".to_string()); } else { missing.extend(crate::types::_common::missing_in("authorization_state", self.inner.authorization_state._missing_fields())); }
+    crate::types::_common::check_missing("updateAuthorizationState", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn authorization_state<T: AsRef<AuthorizationState>>(&mut self, authorization_state: T) -> &mut Self {
     self.inner.authorization_state = authorization_state.as_ref().clone();
@@ -1158,7 +1615,16 @@ impl UpdateBasicGroup {
     let mut inner = UpdateBasicGroup::default();
     inner.td_name = "updateBasicGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateBasicGroupBuilder { inner }
+    RTDUpdateBasicGroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateBasicGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("basic_group".to_string()); } else { missing.extend(crate::types::_common::missing_in("basic_group", self.basic_group._missing_fields())); }
+    missing
   }
 
   pub fn basic_group(&self) -> &BasicGroup { &self.basic_group }
@@ -1167,15 +1633,25 @@ impl UpdateBasicGroup {
 
 #[doc(hidden)]
 pub struct RTDUpdateBasicGroupBuilder {
-  inner: UpdateBasicGroup
+  inner: UpdateBasicGroup,
+  set: [bool; 1]
 }
 
 impl RTDUpdateBasicGroupBuilder {
   pub fn build(&self) -> UpdateBasicGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateBasicGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group".to_string()); } else { missing.extend(crate::types::_common::missing_in("basic_group", self.inner.basic_group._missing_fields())); }
+    crate::types::_common::check_missing("updateBasicGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group<T: AsRef<BasicGroup>>(&mut self, basic_group: T) -> &mut Self {
     self.inner.basic_group = basic_group.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1228,7 +1704,17 @@ impl UpdateBasicGroupFullInfo {
     let mut inner = UpdateBasicGroupFullInfo::default();
     inner.td_name = "updateBasicGroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateBasicGroupFullInfoBuilder { inner }
+    RTDUpdateBasicGroupFullInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateBasicGroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.basic_group_id == 0 { missing.push("basic_group_id".to_string()); }
+    if false { missing.push("basic_group_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("basic_group_full_info", self.basic_group_full_info._missing_fields())); }
+    missing
   }
 
   pub fn basic_group_id(&self) -> i64 { self.basic_group_id }
@@ -1239,21 +1725,33 @@ impl UpdateBasicGroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDUpdateBasicGroupFullInfoBuilder {
-  inner: UpdateBasicGroupFullInfo
+  inner: UpdateBasicGroupFullInfo,
+  set: [bool; 2]
 }
 
 impl RTDUpdateBasicGroupFullInfoBuilder {
   pub fn build(&self) -> UpdateBasicGroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateBasicGroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("basic_group_id".to_string()); }
+    if !self.set[1] { missing.push("basic_group_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("basic_group_full_info", self.inner.basic_group_full_info._missing_fields())); }
+    crate::types::_common::check_missing("updateBasicGroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn basic_group_id(&mut self, basic_group_id: i64) -> &mut Self {
     self.inner.basic_group_id = basic_group_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn basic_group_full_info<T: AsRef<BasicGroupFullInfo>>(&mut self, basic_group_full_info: T) -> &mut Self {
     self.inner.basic_group_full_info = basic_group_full_info.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -1304,7 +1802,16 @@ impl UpdateCall {
     let mut inner = UpdateCall::default();
     inner.td_name = "updateCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateCallBuilder { inner }
+    RTDUpdateCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("call".to_string()); } else { missing.extend(crate::types::_common::missing_in("call", self.call._missing_fields())); }
+    missing
   }
 
   pub fn call(&self) -> &Call { &self.call }
@@ -1313,15 +1820,25 @@ impl UpdateCall {
 
 #[doc(hidden)]
 pub struct RTDUpdateCallBuilder {
-  inner: UpdateCall
+  inner: UpdateCall,
+  set: [bool; 1]
 }
 
 impl RTDUpdateCallBuilder {
   pub fn build(&self) -> UpdateCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call".to_string()); } else { missing.extend(crate::types::_common::missing_in("call", self.inner.call._missing_fields())); }
+    crate::types::_common::check_missing("updateCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call<T: AsRef<Call>>(&mut self, call: T) -> &mut Self {
     self.inner.call = call.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -1374,7 +1891,16 @@ impl UpdateChatActionBar {
     let mut inner = UpdateChatActionBar::default();
     inner.td_name = "updateChatActionBar".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatActionBarBuilder { inner }
+    RTDUpdateChatActionBarBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatActionBar", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1385,15 +1911,25 @@ impl UpdateChatActionBar {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatActionBarBuilder {
-  inner: UpdateChatActionBar
+  inner: UpdateChatActionBar,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatActionBarBuilder {
   pub fn build(&self) -> UpdateChatActionBar { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatActionBar> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatActionBar", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1452,7 +1988,16 @@ impl UpdateChatDefaultDisableNotification {
     let mut inner = UpdateChatDefaultDisableNotification::default();
     inner.td_name = "updateChatDefaultDisableNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatDefaultDisableNotificationBuilder { inner }
+    RTDUpdateChatDefaultDisableNotificationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatDefaultDisableNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1463,15 +2008,25 @@ impl UpdateChatDefaultDisableNotification {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatDefaultDisableNotificationBuilder {
-  inner: UpdateChatDefaultDisableNotification
+  inner: UpdateChatDefaultDisableNotification,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatDefaultDisableNotificationBuilder {
   pub fn build(&self) -> UpdateChatDefaultDisableNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatDefaultDisableNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatDefaultDisableNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1532,7 +2087,16 @@ impl UpdateChatDraftMessage {
     let mut inner = UpdateChatDraftMessage::default();
     inner.td_name = "updateChatDraftMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatDraftMessageBuilder { inner }
+    RTDUpdateChatDraftMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatDraftMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1545,15 +2109,25 @@ impl UpdateChatDraftMessage {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatDraftMessageBuilder {
-  inner: UpdateChatDraftMessage
+  inner: UpdateChatDraftMessage,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatDraftMessageBuilder {
   pub fn build(&self) -> UpdateChatDraftMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatDraftMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatDraftMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1619,6 +2193,11 @@ impl UpdateChatFilters {
     RTDUpdateChatFiltersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatFilters", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_filters(&self) -> &Vec<ChatFilterInfo> { &self.chat_filters }
 
 }
@@ -1631,6 +2210,9 @@ pub struct RTDUpdateChatFiltersBuilder {
 impl RTDUpdateChatFiltersBuilder {
   pub fn build(&self) -> UpdateChatFilters { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatFilters> { Ok(self.build()) }
+
    
   pub fn chat_filters(&mut self, chat_filters: Vec<ChatFilterInfo>) -> &mut Self {
     self.inner.chat_filters = chat_filters;
@@ -1686,7 +2268,16 @@ impl UpdateChatHasScheduledMessages {
     let mut inner = UpdateChatHasScheduledMessages::default();
     inner.td_name = "updateChatHasScheduledMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatHasScheduledMessagesBuilder { inner }
+    RTDUpdateChatHasScheduledMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatHasScheduledMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1697,15 +2288,25 @@ impl UpdateChatHasScheduledMessages {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatHasScheduledMessagesBuilder {
-  inner: UpdateChatHasScheduledMessages
+  inner: UpdateChatHasScheduledMessages,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatHasScheduledMessagesBuilder {
   pub fn build(&self) -> UpdateChatHasScheduledMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatHasScheduledMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatHasScheduledMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1764,7 +2365,16 @@ impl UpdateChatIsBlocked {
     let mut inner = UpdateChatIsBlocked::default();
     inner.td_name = "updateChatIsBlocked".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatIsBlockedBuilder { inner }
+    RTDUpdateChatIsBlockedBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatIsBlocked", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1775,15 +2385,25 @@ impl UpdateChatIsBlocked {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatIsBlockedBuilder {
-  inner: UpdateChatIsBlocked
+  inner: UpdateChatIsBlocked,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatIsBlockedBuilder {
   pub fn build(&self) -> UpdateChatIsBlocked { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatIsBlocked> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatIsBlocked", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1842,7 +2462,16 @@ impl UpdateChatIsMarkedAsUnread {
     let mut inner = UpdateChatIsMarkedAsUnread::default();
     inner.td_name = "updateChatIsMarkedAsUnread".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatIsMarkedAsUnreadBuilder { inner }
+    RTDUpdateChatIsMarkedAsUnreadBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatIsMarkedAsUnread", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1853,15 +2482,25 @@ impl UpdateChatIsMarkedAsUnread {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatIsMarkedAsUnreadBuilder {
-  inner: UpdateChatIsMarkedAsUnread
+  inner: UpdateChatIsMarkedAsUnread,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatIsMarkedAsUnreadBuilder {
   pub fn build(&self) -> UpdateChatIsMarkedAsUnread { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatIsMarkedAsUnread> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatIsMarkedAsUnread", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -1922,7 +2561,16 @@ impl UpdateChatLastMessage {
     let mut inner = UpdateChatLastMessage::default();
     inner.td_name = "updateChatLastMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatLastMessageBuilder { inner }
+    RTDUpdateChatLastMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatLastMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -1935,15 +2583,25 @@ impl UpdateChatLastMessage {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatLastMessageBuilder {
-  inner: UpdateChatLastMessage
+  inner: UpdateChatLastMessage,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatLastMessageBuilder {
   pub fn build(&self) -> UpdateChatLastMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatLastMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatLastMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -2008,7 +2666,17 @@ impl UpdateChatNotificationSettings {
     let mut inner = UpdateChatNotificationSettings::default();
     inner.td_name = "updateChatNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatNotificationSettingsBuilder { inner }
+    RTDUpdateChatNotificationSettingsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.notification_settings._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2019,21 +2687,33 @@ impl UpdateChatNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatNotificationSettingsBuilder {
-  inner: UpdateChatNotificationSettings
+  inner: UpdateChatNotificationSettings,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateChatNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatNotificationSettings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.inner.notification_settings._missing_fields())); }
+    crate::types::_common::check_missing("updateChatNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn notification_settings<T: AsRef<ChatNotificationSettings>>(&mut self, notification_settings: T) -> &mut Self {
     self.inner.notification_settings = notification_settings.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -2086,7 +2766,16 @@ impl UpdateChatOnlineMemberCount {
     let mut inner = UpdateChatOnlineMemberCount::default();
     inner.td_name = "updateChatOnlineMemberCount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatOnlineMemberCountBuilder { inner }
+    RTDUpdateChatOnlineMemberCountBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatOnlineMemberCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2097,15 +2786,25 @@ impl UpdateChatOnlineMemberCount {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatOnlineMemberCountBuilder {
-  inner: UpdateChatOnlineMemberCount
+  inner: UpdateChatOnlineMemberCount,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatOnlineMemberCountBuilder {
   pub fn build(&self) -> UpdateChatOnlineMemberCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatOnlineMemberCount> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatOnlineMemberCount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -2164,7 +2863,17 @@ impl UpdateChatPermissions {
     let mut inner = UpdateChatPermissions::default();
     inner.td_name = "updateChatPermissions".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatPermissionsBuilder { inner }
+    RTDUpdateChatPermissionsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatPermissions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.permissions._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2175,21 +2884,33 @@ impl UpdateChatPermissions {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPermissionsBuilder {
-  inner: UpdateChatPermissions
+  inner: UpdateChatPermissions,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatPermissionsBuilder {
   pub fn build(&self) -> UpdateChatPermissions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatPermissions> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("permissions".to_string()); } else { missing.extend(crate::types::_common::missing_in("permissions", self.inner.permissions._missing_fields())); }
+    crate::types::_common::check_missing("updateChatPermissions", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn permissions<T: AsRef<ChatPermissions>>(&mut self, permissions: T) -> &mut Self {
     self.inner.permissions = permissions.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -2242,7 +2963,16 @@ impl UpdateChatPhoto {
     let mut inner = UpdateChatPhoto::default();
     inner.td_name = "updateChatPhoto".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatPhotoBuilder { inner }
+    RTDUpdateChatPhotoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatPhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2253,15 +2983,25 @@ impl UpdateChatPhoto {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPhotoBuilder {
-  inner: UpdateChatPhoto
+  inner: UpdateChatPhoto,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatPhotoBuilder {
   pub fn build(&self) -> UpdateChatPhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatPhoto> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatPhoto", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -2320,7 +3060,17 @@ impl UpdateChatPosition {
     let mut inner = UpdateChatPosition::default();
     inner.td_name = "updateChatPosition".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatPositionBuilder { inner }
+    RTDUpdateChatPositionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatPosition", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if false { missing.push("position".to_string()); } else { missing.extend(crate::types::_common::missing_in("position", self.position._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2331,21 +3081,33 @@ impl UpdateChatPosition {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatPositionBuilder {
-  inner: UpdateChatPosition
+  inner: UpdateChatPosition,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatPositionBuilder {
   pub fn build(&self) -> UpdateChatPosition { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatPosition> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("position".to_string()); } else { missing.extend(crate::types::_common::missing_in("position", self.inner.position._missing_fields())); }
+    crate::types::_common::check_missing("updateChatPosition", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn position<T: AsRef<ChatPosition>>(&mut self, position: T) -> &mut Self {
     self.inner.position = position.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -2400,7 +3162,18 @@ impl UpdateChatReadInbox {
     let mut inner = UpdateChatReadInbox::default();
     inner.td_name = "updateChatReadInbox".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatReadInboxBuilder { inner }
+    RTDUpdateChatReadInboxBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatReadInbox", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.last_read_inbox_message_id == 0 { missing.push("last_read_inbox_message_id".to_string()); }
+    if self.unread_count == 0 { missing.push("unread_count".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2413,27 +3186,41 @@ impl UpdateChatReadInbox {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReadInboxBuilder {
-  inner: UpdateChatReadInbox
+  inner: UpdateChatReadInbox,
+  set: [bool; 3]
 }
 
 impl RTDUpdateChatReadInboxBuilder {
   pub fn build(&self) -> UpdateChatReadInbox { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatReadInbox> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("last_read_inbox_message_id".to_string()); }
+    if !self.set[2] { missing.push("unread_count".to_string()); }
+    crate::types::_common::check_missing("updateChatReadInbox", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn last_read_inbox_message_id(&mut self, last_read_inbox_message_id: i64) -> &mut Self {
     self.inner.last_read_inbox_message_id = last_read_inbox_message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
     self.inner.unread_count = unread_count;
+    self.set[2] = true;
     self
   }
 
@@ -2486,7 +3273,17 @@ impl UpdateChatReadOutbox {
     let mut inner = UpdateChatReadOutbox::default();
     inner.td_name = "updateChatReadOutbox".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatReadOutboxBuilder { inner }
+    RTDUpdateChatReadOutboxBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatReadOutbox", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.last_read_outbox_message_id == 0 { missing.push("last_read_outbox_message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2497,21 +3294,33 @@ impl UpdateChatReadOutbox {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReadOutboxBuilder {
-  inner: UpdateChatReadOutbox
+  inner: UpdateChatReadOutbox,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatReadOutboxBuilder {
   pub fn build(&self) -> UpdateChatReadOutbox { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatReadOutbox> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("last_read_outbox_message_id".to_string()); }
+    crate::types::_common::check_missing("updateChatReadOutbox", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn last_read_outbox_message_id(&mut self, last_read_outbox_message_id: i64) -> &mut Self {
     self.inner.last_read_outbox_message_id = last_read_outbox_message_id;
+    self.set[1] = true;
     self
   }
 
@@ -2564,7 +3373,16 @@ impl UpdateChatReplyMarkup {
     let mut inner = UpdateChatReplyMarkup::default();
     inner.td_name = "updateChatReplyMarkup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatReplyMarkupBuilder { inner }
+    RTDUpdateChatReplyMarkupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatReplyMarkup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2575,15 +3393,25 @@ impl UpdateChatReplyMarkup {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatReplyMarkupBuilder {
-  inner: UpdateChatReplyMarkup
+  inner: UpdateChatReplyMarkup,
+  set: [bool; 1]
 }
 
 impl RTDUpdateChatReplyMarkupBuilder {
   pub fn build(&self) -> UpdateChatReplyMarkup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatReplyMarkup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateChatReplyMarkup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -2642,7 +3470,17 @@ impl UpdateChatTitle {
     let mut inner = UpdateChatTitle::default();
     inner.td_name = "updateChatTitle".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatTitleBuilder { inner }
+    RTDUpdateChatTitleBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatTitle", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2653,21 +3491,33 @@ impl UpdateChatTitle {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatTitleBuilder {
-  inner: UpdateChatTitle
+  inner: UpdateChatTitle,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatTitleBuilder {
   pub fn build(&self) -> UpdateChatTitle { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatTitle> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    crate::types::_common::check_missing("updateChatTitle", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -2720,7 +3570,17 @@ impl UpdateChatUnreadMentionCount {
     let mut inner = UpdateChatUnreadMentionCount::default();
     inner.td_name = "updateChatUnreadMentionCount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatUnreadMentionCountBuilder { inner }
+    RTDUpdateChatUnreadMentionCountBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatUnreadMentionCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.unread_mention_count == 0 { missing.push("unread_mention_count".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2731,21 +3591,33 @@ impl UpdateChatUnreadMentionCount {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatUnreadMentionCountBuilder {
-  inner: UpdateChatUnreadMentionCount
+  inner: UpdateChatUnreadMentionCount,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatUnreadMentionCountBuilder {
   pub fn build(&self) -> UpdateChatUnreadMentionCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatUnreadMentionCount> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("unread_mention_count".to_string()); }
+    crate::types::_common::check_missing("updateChatUnreadMentionCount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
     self.inner.unread_mention_count = unread_mention_count;
+    self.set[1] = true;
     self
   }
 
@@ -2800,7 +3672,17 @@ impl UpdateChatVoiceChat {
     let mut inner = UpdateChatVoiceChat::default();
     inner.td_name = "updateChatVoiceChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateChatVoiceChatBuilder { inner }
+    RTDUpdateChatVoiceChatBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateChatVoiceChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.voice_chat_group_call_id == 0 { missing.push("voice_chat_group_call_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2813,21 +3695,33 @@ impl UpdateChatVoiceChat {
 
 #[doc(hidden)]
 pub struct RTDUpdateChatVoiceChatBuilder {
-  inner: UpdateChatVoiceChat
+  inner: UpdateChatVoiceChat,
+  set: [bool; 2]
 }
 
 impl RTDUpdateChatVoiceChatBuilder {
   pub fn build(&self) -> UpdateChatVoiceChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateChatVoiceChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("voice_chat_group_call_id".to_string()); }
+    crate::types::_common::check_missing("updateChatVoiceChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn voice_chat_group_call_id(&mut self, voice_chat_group_call_id: i64) -> &mut Self {
     self.inner.voice_chat_group_call_id = voice_chat_group_call_id;
+    self.set[1] = true;
     self
   }
 
@@ -2887,6 +3781,15 @@ impl UpdateConnectionState {
     RTDUpdateConnectionStateBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateConnectionState", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.state._missing_fields())); }
+    missing
+  }
+
   pub fn state(&self) -> &ConnectionState { &self.state }
 
 }
@@ -2899,6 +3802,14 @@ pub struct RTDUpdateConnectionStateBuilder {
 impl RTDUpdateConnectionStateBuilder {
   pub fn build(&self) -> UpdateConnectionState { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateConnectionState> {
+    let mut missing = vec![];
+    if self.inner.state._is_default() { missing.push("state".to_string()); } else { missing.extend(crate::types::_common::missing_in("state", self.inner.state._missing_fields())); }
+    crate::types::_common::check_missing("updateConnectionState", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn state<T: AsRef<ConnectionState>>(&mut self, state: T) -> &mut Self {
     self.inner.state = state.as_ref().clone();
@@ -2958,7 +3869,16 @@ impl UpdateDeleteMessages {
     let mut inner = UpdateDeleteMessages::default();
     inner.td_name = "updateDeleteMessages".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateDeleteMessagesBuilder { inner }
+    RTDUpdateDeleteMessagesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateDeleteMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -2973,15 +3893,25 @@ impl UpdateDeleteMessages {
 
 #[doc(hidden)]
 pub struct RTDUpdateDeleteMessagesBuilder {
-  inner: UpdateDeleteMessages
+  inner: UpdateDeleteMessages,
+  set: [bool; 1]
 }
 
 impl RTDUpdateDeleteMessagesBuilder {
   pub fn build(&self) -> UpdateDeleteMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateDeleteMessages> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    crate::types::_common::check_missing("updateDeleteMessages", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -3053,6 +3983,11 @@ impl UpdateDiceEmojis {
     RTDUpdateDiceEmojisBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateDiceEmojis", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn emojis(&self) -> &Vec<String> { &self.emojis }
 
 }
@@ -3065,6 +4000,9 @@ pub struct RTDUpdateDiceEmojisBuilder {
 impl RTDUpdateDiceEmojisBuilder {
   pub fn build(&self) -> UpdateDiceEmojis { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateDiceEmojis> { Ok(self.build()) }
+
    
   pub fn emojis(&mut self, emojis: Vec<String>) -> &mut Self {
     self.inner.emojis = emojis;
@@ -3121,6 +4059,11 @@ impl UpdateFavoriteStickers {
     RTDUpdateFavoriteStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateFavoriteStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn sticker_ids(&self) -> &Vec<i64> { &self.sticker_ids }
 
 }
@@ -3133,6 +4076,9 @@ pub struct RTDUpdateFavoriteStickersBuilder {
 impl RTDUpdateFavoriteStickersBuilder {
   pub fn build(&self) -> UpdateFavoriteStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateFavoriteStickers> { Ok(self.build()) }
+
    
   pub fn sticker_ids(&mut self, sticker_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_ids = sticker_ids;
@@ -3186,7 +4132,16 @@ impl UpdateFile {
     let mut inner = UpdateFile::default();
     inner.td_name = "updateFile".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateFileBuilder { inner }
+    RTDUpdateFileBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateFile", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.file._missing_fields())); }
+    missing
   }
 
   pub fn file(&self) -> &File { &self.file }
@@ -3195,15 +4150,25 @@ impl UpdateFile {
 
 #[doc(hidden)]
 pub struct RTDUpdateFileBuilder {
-  inner: UpdateFile
+  inner: UpdateFile,
+  set: [bool; 1]
 }
 
 impl RTDUpdateFileBuilder {
   pub fn build(&self) -> UpdateFile { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateFile> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("file".to_string()); } else { missing.extend(crate::types::_common::missing_in("file", self.inner.file._missing_fields())); }
+    crate::types::_common::check_missing("updateFile", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn file<T: AsRef<File>>(&mut self, file: T) -> &mut Self {
     self.inner.file = file.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3233,7 +4198,7 @@ pub struct UpdateFileGenerationStart {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   /// The path to a file from which a new file is generated; may be empty
   original_path: String,
   /// The path to a file that should be created and where the new file should be generated
@@ -3260,10 +4225,21 @@ impl UpdateFileGenerationStart {
     let mut inner = UpdateFileGenerationStart::default();
     inner.td_name = "updateFileGenerationStart".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateFileGenerationStartBuilder { inner }
+    RTDUpdateFileGenerationStartBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateFileGenerationStart", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.generation_id == 0 { missing.push("generation_id".to_string()); }
+    if self.destination_path.is_empty() { missing.push("destination_path".to_string()); }
+    if self.conversion.is_empty() { missing.push("conversion".to_string()); }
+    missing
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
   pub fn original_path(&self) -> &String { &self.original_path }
 
@@ -3275,15 +4251,27 @@ impl UpdateFileGenerationStart {
 
 #[doc(hidden)]
 pub struct RTDUpdateFileGenerationStartBuilder {
-  inner: UpdateFileGenerationStart
+  inner: UpdateFileGenerationStart,
+  set: [bool; 3]
 }
 
 impl RTDUpdateFileGenerationStartBuilder {
   pub fn build(&self) -> UpdateFileGenerationStart { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateFileGenerationStart> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("generation_id".to_string()); }
+    if !self.set[1] { missing.push("destination_path".to_string()); }
+    if !self.set[2] { missing.push("conversion".to_string()); }
+    crate::types::_common::check_missing("updateFileGenerationStart", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
+    self.set[0] = true;
     self
   }
 
@@ -3296,12 +4284,14 @@ impl RTDUpdateFileGenerationStartBuilder {
    
   pub fn destination_path<T: AsRef<str>>(&mut self, destination_path: T) -> &mut Self {
     self.inner.destination_path = destination_path.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn conversion<T: AsRef<str>>(&mut self, conversion: T) -> &mut Self {
     self.inner.conversion = conversion.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
@@ -3331,7 +4321,7 @@ pub struct UpdateFileGenerationStop {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique identifier for the generation process
-  generation_id: isize,
+  #[serde(with = "crate::types::_common::int64")] generation_id: i64,
   
 }
 
@@ -3352,24 +4342,43 @@ impl UpdateFileGenerationStop {
     let mut inner = UpdateFileGenerationStop::default();
     inner.td_name = "updateFileGenerationStop".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateFileGenerationStopBuilder { inner }
+    RTDUpdateFileGenerationStopBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateFileGenerationStop", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.generation_id == 0 { missing.push("generation_id".to_string()); }
+    missing
   }
 
-  pub fn generation_id(&self) -> isize { self.generation_id }
+  pub fn generation_id(&self) -> i64 { self.generation_id }
 
 }
 
 #[doc(hidden)]
 pub struct RTDUpdateFileGenerationStopBuilder {
-  inner: UpdateFileGenerationStop
+  inner: UpdateFileGenerationStop,
+  set: [bool; 1]
 }
 
 impl RTDUpdateFileGenerationStopBuilder {
   pub fn build(&self) -> UpdateFileGenerationStop { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateFileGenerationStop> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("generation_id".to_string()); }
+    crate::types::_common::check_missing("updateFileGenerationStop", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn generation_id(&mut self, generation_id: isize) -> &mut Self {
+  pub fn generation_id(&mut self, generation_id: i64) -> &mut Self {
     self.inner.generation_id = generation_id;
+    self.set[0] = true;
     self
   }
 
@@ -3420,7 +4429,16 @@ impl UpdateGroupCall {
     let mut inner = UpdateGroupCall::default();
     inner.td_name = "updateGroupCall".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateGroupCallBuilder { inner }
+    RTDUpdateGroupCallBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateGroupCall", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("group_call".to_string()); } else { missing.extend(crate::types::_common::missing_in("group_call", self.group_call._missing_fields())); }
+    missing
   }
 
   pub fn group_call(&self) -> &GroupCall { &self.group_call }
@@ -3429,15 +4447,25 @@ impl UpdateGroupCall {
 
 #[doc(hidden)]
 pub struct RTDUpdateGroupCallBuilder {
-  inner: UpdateGroupCall
+  inner: UpdateGroupCall,
+  set: [bool; 1]
 }
 
 impl RTDUpdateGroupCallBuilder {
   pub fn build(&self) -> UpdateGroupCall { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateGroupCall> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call".to_string()); } else { missing.extend(crate::types::_common::missing_in("group_call", self.inner.group_call._missing_fields())); }
+    crate::types::_common::check_missing("updateGroupCall", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call<T: AsRef<GroupCall>>(&mut self, group_call: T) -> &mut Self {
     self.inner.group_call = group_call.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -3490,7 +4518,17 @@ impl UpdateGroupCallParticipant {
     let mut inner = UpdateGroupCallParticipant::default();
     inner.td_name = "updateGroupCallParticipant".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateGroupCallParticipantBuilder { inner }
+    RTDUpdateGroupCallParticipantBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateGroupCallParticipant", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.group_call_id == 0 { missing.push("group_call_id".to_string()); }
+    if false { missing.push("participant".to_string()); } else { missing.extend(crate::types::_common::missing_in("participant", self.participant._missing_fields())); }
+    missing
   }
 
   pub fn group_call_id(&self) -> i64 { self.group_call_id }
@@ -3501,21 +4539,33 @@ impl UpdateGroupCallParticipant {
 
 #[doc(hidden)]
 pub struct RTDUpdateGroupCallParticipantBuilder {
-  inner: UpdateGroupCallParticipant
+  inner: UpdateGroupCallParticipant,
+  set: [bool; 2]
 }
 
 impl RTDUpdateGroupCallParticipantBuilder {
   pub fn build(&self) -> UpdateGroupCallParticipant { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateGroupCallParticipant> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("group_call_id".to_string()); }
+    if !self.set[1] { missing.push("participant".to_string()); } else { missing.extend(crate::types::_common::missing_in("participant", self.inner.participant._missing_fields())); }
+    crate::types::_common::check_missing("updateGroupCallParticipant", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn group_call_id(&mut self, group_call_id: i64) -> &mut Self {
     self.inner.group_call_id = group_call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn participant<T: AsRef<GroupCallParticipant>>(&mut self, participant: T) -> &mut Self {
     self.inner.participant = participant.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -3571,6 +4621,11 @@ impl UpdateHavePendingNotifications {
     RTDUpdateHavePendingNotificationsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateHavePendingNotifications", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn have_delayed_notifications(&self) -> bool { self.have_delayed_notifications }
 
   pub fn have_unreceived_notifications(&self) -> bool { self.have_unreceived_notifications }
@@ -3585,6 +4640,9 @@ pub struct RTDUpdateHavePendingNotificationsBuilder {
 impl RTDUpdateHavePendingNotificationsBuilder {
   pub fn build(&self) -> UpdateHavePendingNotifications { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateHavePendingNotifications> { Ok(self.build()) }
+
    
   pub fn have_delayed_notifications(&mut self, have_delayed_notifications: bool) -> &mut Self {
     self.inner.have_delayed_notifications = have_delayed_notifications;
@@ -3625,7 +4683,7 @@ pub struct UpdateInstalledStickerSets {
   /// True, if the list of installed mask sticker sets was updated
   is_masks: bool,
   /// The new list of installed ordinary sticker sets
-  sticker_set_ids: Vec<isize>,
+  #[serde(with = "crate::types::_common::vec_int64")] sticker_set_ids: Vec<i64>,
   
 }
 
@@ -3649,9 +4707,14 @@ impl UpdateInstalledStickerSets {
     RTDUpdateInstalledStickerSetsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateInstalledStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_masks(&self) -> bool { self.is_masks }
 
-  pub fn sticker_set_ids(&self) -> &Vec<isize> { &self.sticker_set_ids }
+  pub fn sticker_set_ids(&self) -> &Vec<i64> { &self.sticker_set_ids }
 
 }
 
@@ -3663,6 +4726,9 @@ pub struct RTDUpdateInstalledStickerSetsBuilder {
 impl RTDUpdateInstalledStickerSetsBuilder {
   pub fn build(&self) -> UpdateInstalledStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateInstalledStickerSets> { Ok(self.build()) }
+
    
   pub fn is_masks(&mut self, is_masks: bool) -> &mut Self {
     self.inner.is_masks = is_masks;
@@ -3670,7 +4736,7 @@ impl RTDUpdateInstalledStickerSetsBuilder {
   }
 
    
-  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<isize>) -> &mut Self {
+  pub fn sticker_set_ids(&mut self, sticker_set_ids: Vec<i64>) -> &mut Self {
     self.inner.sticker_set_ids = sticker_set_ids;
     self
   }
@@ -3726,7 +4792,17 @@ impl UpdateLanguagePackStrings {
     let mut inner = UpdateLanguagePackStrings::default();
     inner.td_name = "updateLanguagePackStrings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateLanguagePackStringsBuilder { inner }
+    RTDUpdateLanguagePackStringsBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateLanguagePackStrings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.localization_target.is_empty() { missing.push("localization_target".to_string()); }
+    if self.language_pack_id.is_empty() { missing.push("language_pack_id".to_string()); }
+    missing
   }
 
   pub fn localization_target(&self) -> &String { &self.localization_target }
@@ -3739,21 +4815,33 @@ impl UpdateLanguagePackStrings {
 
 #[doc(hidden)]
 pub struct RTDUpdateLanguagePackStringsBuilder {
-  inner: UpdateLanguagePackStrings
+  inner: UpdateLanguagePackStrings,
+  set: [bool; 2]
 }
 
 impl RTDUpdateLanguagePackStringsBuilder {
   pub fn build(&self) -> UpdateLanguagePackStrings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateLanguagePackStrings> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("localization_target".to_string()); }
+    if !self.set[1] { missing.push("language_pack_id".to_string()); }
+    crate::types::_common::check_missing("updateLanguagePackStrings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn localization_target<T: AsRef<str>>(&mut self, localization_target: T) -> &mut Self {
     self.inner.localization_target = localization_target.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn language_pack_id<T: AsRef<str>>(&mut self, language_pack_id: T) -> &mut Self {
     self.inner.language_pack_id = language_pack_id.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -3814,7 +4902,18 @@ impl UpdateMessageContent {
     let mut inner = UpdateMessageContent::default();
     inner.td_name = "updateMessageContent".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageContentBuilder { inner }
+    RTDUpdateMessageContentBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageContent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.new_content._is_default() { missing.push("new_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_content", self.new_content._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -3827,21 +4926,34 @@ impl UpdateMessageContent {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageContentBuilder {
-  inner: UpdateMessageContent
+  inner: UpdateMessageContent,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageContentBuilder {
   pub fn build(&self) -> UpdateMessageContent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageContent> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if self.inner.new_content._is_default() { missing.push("new_content".to_string()); } else { missing.extend(crate::types::_common::missing_in("new_content", self.inner.new_content._missing_fields())); }
+    crate::types::_common::check_missing("updateMessageContent", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -3900,7 +5012,17 @@ impl UpdateMessageContentOpened {
     let mut inner = UpdateMessageContentOpened::default();
     inner.td_name = "updateMessageContentOpened".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageContentOpenedBuilder { inner }
+    RTDUpdateMessageContentOpenedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageContentOpened", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -3911,21 +5033,33 @@ impl UpdateMessageContentOpened {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageContentOpenedBuilder {
-  inner: UpdateMessageContentOpened
+  inner: UpdateMessageContentOpened,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageContentOpenedBuilder {
   pub fn build(&self) -> UpdateMessageContentOpened { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageContentOpened> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageContentOpened", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -3982,7 +5116,18 @@ impl UpdateMessageEdited {
     let mut inner = UpdateMessageEdited::default();
     inner.td_name = "updateMessageEdited".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageEditedBuilder { inner }
+    RTDUpdateMessageEditedBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageEdited", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.edit_date == 0 { missing.push("edit_date".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -3997,27 +5142,41 @@ impl UpdateMessageEdited {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageEditedBuilder {
-  inner: UpdateMessageEdited
+  inner: UpdateMessageEdited,
+  set: [bool; 3]
 }
 
 impl RTDUpdateMessageEditedBuilder {
   pub fn build(&self) -> UpdateMessageEdited { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageEdited> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("edit_date".to_string()); }
+    crate::types::_common::check_missing("updateMessageEdited", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn edit_date(&mut self, edit_date: i64) -> &mut Self {
     self.inner.edit_date = edit_date;
+    self.set[2] = true;
     self
   }
 
@@ -4078,7 +5237,17 @@ impl UpdateMessageInteractionInfo {
     let mut inner = UpdateMessageInteractionInfo::default();
     inner.td_name = "updateMessageInteractionInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageInteractionInfoBuilder { inner }
+    RTDUpdateMessageInteractionInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageInteractionInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4091,21 +5260,33 @@ impl UpdateMessageInteractionInfo {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageInteractionInfoBuilder {
-  inner: UpdateMessageInteractionInfo
+  inner: UpdateMessageInteractionInfo,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageInteractionInfoBuilder {
   pub fn build(&self) -> UpdateMessageInteractionInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageInteractionInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageInteractionInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -4166,7 +5347,17 @@ impl UpdateMessageIsPinned {
     let mut inner = UpdateMessageIsPinned::default();
     inner.td_name = "updateMessageIsPinned".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageIsPinnedBuilder { inner }
+    RTDUpdateMessageIsPinnedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageIsPinned", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4179,21 +5370,33 @@ impl UpdateMessageIsPinned {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageIsPinnedBuilder {
-  inner: UpdateMessageIsPinned
+  inner: UpdateMessageIsPinned,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageIsPinnedBuilder {
   pub fn build(&self) -> UpdateMessageIsPinned { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageIsPinned> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageIsPinned", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -4252,7 +5455,17 @@ impl UpdateMessageLiveLocationViewed {
     let mut inner = UpdateMessageLiveLocationViewed::default();
     inner.td_name = "updateMessageLiveLocationViewed".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageLiveLocationViewedBuilder { inner }
+    RTDUpdateMessageLiveLocationViewedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageLiveLocationViewed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4263,21 +5476,33 @@ impl UpdateMessageLiveLocationViewed {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageLiveLocationViewedBuilder {
-  inner: UpdateMessageLiveLocationViewed
+  inner: UpdateMessageLiveLocationViewed,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageLiveLocationViewedBuilder {
   pub fn build(&self) -> UpdateMessageLiveLocationViewed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageLiveLocationViewed> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageLiveLocationViewed", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -4332,7 +5557,18 @@ impl UpdateMessageMentionRead {
     let mut inner = UpdateMessageMentionRead::default();
     inner.td_name = "updateMessageMentionRead".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageMentionReadBuilder { inner }
+    RTDUpdateMessageMentionReadBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageMentionRead", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.unread_mention_count == 0 { missing.push("unread_mention_count".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4345,27 +5581,41 @@ impl UpdateMessageMentionRead {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageMentionReadBuilder {
-  inner: UpdateMessageMentionRead
+  inner: UpdateMessageMentionRead,
+  set: [bool; 3]
 }
 
 impl RTDUpdateMessageMentionReadBuilder {
   pub fn build(&self) -> UpdateMessageMentionRead { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageMentionRead> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    if !self.set[2] { missing.push("unread_mention_count".to_string()); }
+    crate::types::_common::check_missing("updateMessageMentionRead", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn unread_mention_count(&mut self, unread_mention_count: i64) -> &mut Self {
     self.inner.unread_mention_count = unread_mention_count;
+    self.set[2] = true;
     self
   }
 
@@ -4418,7 +5668,17 @@ impl UpdateMessageSendAcknowledged {
     let mut inner = UpdateMessageSendAcknowledged::default();
     inner.td_name = "updateMessageSendAcknowledged".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageSendAcknowledgedBuilder { inner }
+    RTDUpdateMessageSendAcknowledgedBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageSendAcknowledged", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -4429,21 +5689,33 @@ impl UpdateMessageSendAcknowledged {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendAcknowledgedBuilder {
-  inner: UpdateMessageSendAcknowledged
+  inner: UpdateMessageSendAcknowledged,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageSendAcknowledgedBuilder {
   pub fn build(&self) -> UpdateMessageSendAcknowledged { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageSendAcknowledged> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageSendAcknowledged", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[1] = true;
     self
   }
 
@@ -4500,7 +5772,19 @@ impl UpdateMessageSendFailed {
     let mut inner = UpdateMessageSendFailed::default();
     inner.td_name = "updateMessageSendFailed".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageSendFailedBuilder { inner }
+    RTDUpdateMessageSendFailedBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageSendFailed", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    if self.old_message_id == 0 { missing.push("old_message_id".to_string()); }
+    if self.error_code == 0 { missing.push("error_code".to_string()); }
+    if self.error_message.is_empty() { missing.push("error_message".to_string()); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -4515,33 +5799,49 @@ impl UpdateMessageSendFailed {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendFailedBuilder {
-  inner: UpdateMessageSendFailed
+  inner: UpdateMessageSendFailed,
+  set: [bool; 4]
 }
 
 impl RTDUpdateMessageSendFailedBuilder {
   pub fn build(&self) -> UpdateMessageSendFailed { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageSendFailed> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    if !self.set[1] { missing.push("old_message_id".to_string()); }
+    if !self.set[2] { missing.push("error_code".to_string()); }
+    if !self.set[3] { missing.push("error_message".to_string()); }
+    crate::types::_common::check_missing("updateMessageSendFailed", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
     self.inner.old_message_id = old_message_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn error_code(&mut self, error_code: i64) -> &mut Self {
     self.inner.error_code = error_code;
+    self.set[2] = true;
     self
   }
 
    
   pub fn error_message<T: AsRef<str>>(&mut self, error_message: T) -> &mut Self {
     self.inner.error_message = error_message.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -4594,7 +5894,17 @@ impl UpdateMessageSendSucceeded {
     let mut inner = UpdateMessageSendSucceeded::default();
     inner.td_name = "updateMessageSendSucceeded".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateMessageSendSucceededBuilder { inner }
+    RTDUpdateMessageSendSucceededBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateMessageSendSucceeded", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    if self.old_message_id == 0 { missing.push("old_message_id".to_string()); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -4605,21 +5915,33 @@ impl UpdateMessageSendSucceeded {
 
 #[doc(hidden)]
 pub struct RTDUpdateMessageSendSucceededBuilder {
-  inner: UpdateMessageSendSucceeded
+  inner: UpdateMessageSendSucceeded,
+  set: [bool; 2]
 }
 
 impl RTDUpdateMessageSendSucceededBuilder {
   pub fn build(&self) -> UpdateMessageSendSucceeded { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateMessageSendSucceeded> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    if !self.set[1] { missing.push("old_message_id".to_string()); }
+    crate::types::_common::check_missing("updateMessageSendSucceeded", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn old_message_id(&mut self, old_message_id: i64) -> &mut Self {
     self.inner.old_message_id = old_message_id;
+    self.set[1] = true;
     self
   }
 
@@ -4672,7 +5994,17 @@ impl UpdateNewCallSignalingData {
     let mut inner = UpdateNewCallSignalingData::default();
     inner.td_name = "updateNewCallSignalingData".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewCallSignalingDataBuilder { inner }
+    RTDUpdateNewCallSignalingDataBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewCallSignalingData", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.call_id == 0 { missing.push("call_id".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    missing
   }
 
   pub fn call_id(&self) -> i64 { self.call_id }
@@ -4683,21 +6015,33 @@ impl UpdateNewCallSignalingData {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCallSignalingDataBuilder {
-  inner: UpdateNewCallSignalingData
+  inner: UpdateNewCallSignalingData,
+  set: [bool; 2]
 }
 
 impl RTDUpdateNewCallSignalingDataBuilder {
   pub fn build(&self) -> UpdateNewCallSignalingData { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewCallSignalingData> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("call_id".to_string()); }
+    if !self.set[1] { missing.push("data".to_string()); }
+    crate::types::_common::check_missing("updateNewCallSignalingData", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn call_id(&mut self, call_id: i64) -> &mut Self {
     self.inner.call_id = call_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
@@ -4727,7 +6071,7 @@ pub struct UpdateNewCallbackQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Identifier of the chat where the query was sent
@@ -4735,7 +6079,7 @@ pub struct UpdateNewCallbackQuery {
   /// Identifier of the message, from which the query originated
   message_id: i64,
   /// Identifier that uniquely corresponds to the chat to which the message was sent
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
@@ -4758,10 +6102,24 @@ impl UpdateNewCallbackQuery {
     let mut inner = UpdateNewCallbackQuery::default();
     inner.td_name = "updateNewCallbackQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewCallbackQueryBuilder { inner }
+    RTDUpdateNewCallbackQueryBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewCallbackQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.message_id == 0 { missing.push("message_id".to_string()); }
+    if self.chat_instance == 0 { missing.push("chat_instance".to_string()); }
+    if self.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.payload._missing_fields())); }
+    missing
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -4769,7 +6127,7 @@ impl UpdateNewCallbackQuery {
 
   pub fn message_id(&self) -> i64 { self.message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -4777,39 +6135,58 @@ impl UpdateNewCallbackQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCallbackQueryBuilder {
-  inner: UpdateNewCallbackQuery
+  inner: UpdateNewCallbackQuery,
+  set: [bool; 5]
 }
 
 impl RTDUpdateNewCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewCallbackQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewCallbackQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sender_user_id".to_string()); }
+    if !self.set[2] { missing.push("chat_id".to_string()); }
+    if !self.set[3] { missing.push("message_id".to_string()); }
+    if !self.set[4] { missing.push("chat_instance".to_string()); }
+    if self.inner.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.inner.payload._missing_fields())); }
+    crate::types::_common::check_missing("updateNewCallbackQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[2] = true;
     self
   }
 
    
   pub fn message_id(&mut self, message_id: i64) -> &mut Self {
     self.inner.message_id = message_id;
+    self.set[3] = true;
     self
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
+    self.set[4] = true;
     self
   }
 
@@ -4866,7 +6243,16 @@ impl UpdateNewChat {
     let mut inner = UpdateNewChat::default();
     inner.td_name = "updateNewChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewChatBuilder { inner }
+    RTDUpdateNewChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("chat".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat", self.chat._missing_fields())); }
+    missing
   }
 
   pub fn chat(&self) -> &Chat { &self.chat }
@@ -4875,15 +6261,25 @@ impl UpdateNewChat {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewChatBuilder {
-  inner: UpdateNewChat
+  inner: UpdateNewChat,
+  set: [bool; 1]
 }
 
 impl RTDUpdateNewChatBuilder {
   pub fn build(&self) -> UpdateNewChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat", self.inner.chat._missing_fields())); }
+    crate::types::_common::check_missing("updateNewChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat<T: AsRef<Chat>>(&mut self, chat: T) -> &mut Self {
     self.inner.chat = chat.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -4942,7 +6338,19 @@ impl UpdateNewChosenInlineResult {
     let mut inner = UpdateNewChosenInlineResult::default();
     inner.td_name = "updateNewChosenInlineResult".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewChosenInlineResultBuilder { inner }
+    RTDUpdateNewChosenInlineResultBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewChosenInlineResult", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.result_id.is_empty() { missing.push("result_id".to_string()); }
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    missing
   }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
@@ -4959,15 +6367,28 @@ impl UpdateNewChosenInlineResult {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewChosenInlineResultBuilder {
-  inner: UpdateNewChosenInlineResult
+  inner: UpdateNewChosenInlineResult,
+  set: [bool; 4]
 }
 
 impl RTDUpdateNewChosenInlineResultBuilder {
   pub fn build(&self) -> UpdateNewChosenInlineResult { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewChosenInlineResult> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sender_user_id".to_string()); }
+    if !self.set[1] { missing.push("query".to_string()); }
+    if !self.set[2] { missing.push("result_id".to_string()); }
+    if !self.set[3] { missing.push("inline_message_id".to_string()); }
+    crate::types::_common::check_missing("updateNewChosenInlineResult", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[0] = true;
     self
   }
 
@@ -4980,18 +6401,21 @@ impl RTDUpdateNewChosenInlineResultBuilder {
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn result_id<T: AsRef<str>>(&mut self, result_id: T) -> &mut Self {
     self.inner.result_id = result_id.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -5042,7 +6466,16 @@ impl UpdateNewCustomEvent {
     let mut inner = UpdateNewCustomEvent::default();
     inner.td_name = "updateNewCustomEvent".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewCustomEventBuilder { inner }
+    RTDUpdateNewCustomEventBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewCustomEvent", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.event.is_empty() { missing.push("event".to_string()); }
+    missing
   }
 
   pub fn event(&self) -> &String { &self.event }
@@ -5051,15 +6484,25 @@ impl UpdateNewCustomEvent {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCustomEventBuilder {
-  inner: UpdateNewCustomEvent
+  inner: UpdateNewCustomEvent,
+  set: [bool; 1]
 }
 
 impl RTDUpdateNewCustomEventBuilder {
   pub fn build(&self) -> UpdateNewCustomEvent { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewCustomEvent> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("event".to_string()); }
+    crate::types::_common::check_missing("updateNewCustomEvent", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn event<T: AsRef<str>>(&mut self, event: T) -> &mut Self {
     self.inner.event = event.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -5089,7 +6532,7 @@ pub struct UpdateNewCustomQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// The query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// JSON-serialized query data
   data: String,
   /// Query timeout
@@ -5114,10 +6557,21 @@ impl UpdateNewCustomQuery {
     let mut inner = UpdateNewCustomQuery::default();
     inner.td_name = "updateNewCustomQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewCustomQueryBuilder { inner }
+    RTDUpdateNewCustomQueryBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewCustomQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.data.is_empty() { missing.push("data".to_string()); }
+    if self.timeout == 0 { missing.push("timeout".to_string()); }
+    missing
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn data(&self) -> &String { &self.data }
 
@@ -5127,27 +6581,41 @@ impl UpdateNewCustomQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewCustomQueryBuilder {
-  inner: UpdateNewCustomQuery
+  inner: UpdateNewCustomQuery,
+  set: [bool; 3]
 }
 
 impl RTDUpdateNewCustomQueryBuilder {
   pub fn build(&self) -> UpdateNewCustomQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewCustomQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("data".to_string()); }
+    if !self.set[2] { missing.push("timeout".to_string()); }
+    crate::types::_common::check_missing("updateNewCustomQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn data<T: AsRef<str>>(&mut self, data: T) -> &mut Self {
     self.inner.data = data.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn timeout(&mut self, timeout: i64) -> &mut Self {
     self.inner.timeout = timeout;
+    self.set[2] = true;
     self
   }
 
@@ -5177,13 +6645,13 @@ pub struct UpdateNewInlineCallbackQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Identifier of the inline message, from which the query originated
   inline_message_id: String,
   /// An identifier uniquely corresponding to the chat a message was sent to
-  chat_instance: isize,
+  #[serde(with = "crate::types::_common::int64")] chat_instance: i64,
   /// Query payload
   payload: CallbackQueryPayload,
   
@@ -5206,16 +6674,29 @@ impl UpdateNewInlineCallbackQuery {
     let mut inner = UpdateNewInlineCallbackQuery::default();
     inner.td_name = "updateNewInlineCallbackQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewInlineCallbackQueryBuilder { inner }
+    RTDUpdateNewInlineCallbackQueryBuilder { inner, set: [false; 4] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewInlineCallbackQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.inline_message_id.is_empty() { missing.push("inline_message_id".to_string()); }
+    if self.chat_instance == 0 { missing.push("chat_instance".to_string()); }
+    if self.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.payload._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
   pub fn inline_message_id(&self) -> &String { &self.inline_message_id }
 
-  pub fn chat_instance(&self) -> isize { self.chat_instance }
+  pub fn chat_instance(&self) -> i64 { self.chat_instance }
 
   pub fn payload(&self) -> &CallbackQueryPayload { &self.payload }
 
@@ -5223,33 +6704,50 @@ impl UpdateNewInlineCallbackQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewInlineCallbackQueryBuilder {
-  inner: UpdateNewInlineCallbackQuery
+  inner: UpdateNewInlineCallbackQuery,
+  set: [bool; 4]
 }
 
 impl RTDUpdateNewInlineCallbackQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineCallbackQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewInlineCallbackQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sender_user_id".to_string()); }
+    if !self.set[2] { missing.push("inline_message_id".to_string()); }
+    if !self.set[3] { missing.push("chat_instance".to_string()); }
+    if self.inner.payload._is_default() { missing.push("payload".to_string()); } else { missing.extend(crate::types::_common::missing_in("payload", self.inner.payload._missing_fields())); }
+    crate::types::_common::check_missing("updateNewInlineCallbackQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn inline_message_id<T: AsRef<str>>(&mut self, inline_message_id: T) -> &mut Self {
     self.inner.inline_message_id = inline_message_id.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
-  pub fn chat_instance(&mut self, chat_instance: isize) -> &mut Self {
+  pub fn chat_instance(&mut self, chat_instance: i64) -> &mut Self {
     self.inner.chat_instance = chat_instance;
+    self.set[3] = true;
     self
   }
 
@@ -5285,7 +6783,7 @@ pub struct UpdateNewInlineQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// User location; may be null
@@ -5316,10 +6814,22 @@ impl UpdateNewInlineQuery {
     let mut inner = UpdateNewInlineQuery::default();
     inner.td_name = "updateNewInlineQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewInlineQueryBuilder { inner }
+    RTDUpdateNewInlineQueryBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewInlineQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.query.is_empty() { missing.push("query".to_string()); }
+    if self.offset.is_empty() { missing.push("offset".to_string()); }
+    missing
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -5335,21 +6845,35 @@ impl UpdateNewInlineQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewInlineQueryBuilder {
-  inner: UpdateNewInlineQuery
+  inner: UpdateNewInlineQuery,
+  set: [bool; 4]
 }
 
 impl RTDUpdateNewInlineQueryBuilder {
   pub fn build(&self) -> UpdateNewInlineQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewInlineQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sender_user_id".to_string()); }
+    if !self.set[2] { missing.push("query".to_string()); }
+    if !self.set[3] { missing.push("offset".to_string()); }
+    crate::types::_common::check_missing("updateNewInlineQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[1] = true;
     self
   }
 
@@ -5368,12 +6892,14 @@ impl RTDUpdateNewInlineQueryBuilder {
    
   pub fn query<T: AsRef<str>>(&mut self, query: T) -> &mut Self {
     self.inner.query = query.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn offset<T: AsRef<str>>(&mut self, offset: T) -> &mut Self {
     self.inner.offset = offset.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
@@ -5424,7 +6950,16 @@ impl UpdateNewMessage {
     let mut inner = UpdateNewMessage::default();
     inner.td_name = "updateNewMessage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewMessageBuilder { inner }
+    RTDUpdateNewMessageBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewMessage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.message._missing_fields())); }
+    missing
   }
 
   pub fn message(&self) -> &Message { &self.message }
@@ -5433,15 +6968,25 @@ impl UpdateNewMessage {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewMessageBuilder {
-  inner: UpdateNewMessage
+  inner: UpdateNewMessage,
+  set: [bool; 1]
 }
 
 impl RTDUpdateNewMessageBuilder {
   pub fn build(&self) -> UpdateNewMessage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewMessage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("message".to_string()); } else { missing.extend(crate::types::_common::missing_in("message", self.inner.message._missing_fields())); }
+    crate::types::_common::check_missing("updateNewMessage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn message<T: AsRef<Message>>(&mut self, message: T) -> &mut Self {
     self.inner.message = message.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -5471,7 +7016,7 @@ pub struct UpdateNewPreCheckoutQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Currency for the product price
@@ -5504,10 +7049,23 @@ impl UpdateNewPreCheckoutQuery {
     let mut inner = UpdateNewPreCheckoutQuery::default();
     inner.td_name = "updateNewPreCheckoutQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewPreCheckoutQueryBuilder { inner }
+    RTDUpdateNewPreCheckoutQueryBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewPreCheckoutQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.currency.is_empty() { missing.push("currency".to_string()); }
+    if self.total_amount == 0 { missing.push("total_amount".to_string()); }
+    if self.invoice_payload.is_empty() { missing.push("invoice_payload".to_string()); }
+    missing
   }
 
-  pub fn id(&self) -> isize { self.id }
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -5525,39 +7083,57 @@ impl UpdateNewPreCheckoutQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewPreCheckoutQueryBuilder {
-  inner: UpdateNewPreCheckoutQuery
+  inner: UpdateNewPreCheckoutQuery,
+  set: [bool; 5]
 }
 
 impl RTDUpdateNewPreCheckoutQueryBuilder {
   pub fn build(&self) -> UpdateNewPreCheckoutQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewPreCheckoutQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sender_user_id".to_string()); }
+    if !self.set[2] { missing.push("currency".to_string()); }
+    if !self.set[3] { missing.push("total_amount".to_string()); }
+    if !self.set[4] { missing.push("invoice_payload".to_string()); }
+    crate::types::_common::check_missing("updateNewPreCheckoutQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn currency<T: AsRef<str>>(&mut self, currency: T) -> &mut Self {
     self.inner.currency = currency.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn total_amount(&mut self, total_amount: i64) -> &mut Self {
     self.inner.total_amount = total_amount;
+    self.set[3] = true;
     self
   }
 
    
   pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
     self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -5599,7 +7175,7 @@ pub struct UpdateNewShippingQuery {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique query identifier
-  id: isize,
+  #[serde(with = "crate::types::_common::int64")] id: i64,
   /// Identifier of the user who sent the query
   sender_user_id: i64,
   /// Invoice payload
@@ -5626,10 +7202,22 @@ impl UpdateNewShippingQuery {
     let mut inner = UpdateNewShippingQuery::default();
     inner.td_name = "updateNewShippingQuery".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNewShippingQueryBuilder { inner }
+    RTDUpdateNewShippingQueryBuilder { inner, set: [false; 4] }
   }
 
-  pub fn id(&self) -> isize { self.id }
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNewShippingQuery", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.sender_user_id == 0 { missing.push("sender_user_id".to_string()); }
+    if self.invoice_payload.is_empty() { missing.push("invoice_payload".to_string()); }
+    if false { missing.push("shipping_address".to_string()); } else { missing.extend(crate::types::_common::missing_in("shipping_address", self.shipping_address._missing_fields())); }
+    missing
+  }
+
+  pub fn id(&self) -> i64 { self.id }
 
   pub fn sender_user_id(&self) -> i64 { self.sender_user_id }
 
@@ -5641,33 +7229,49 @@ impl UpdateNewShippingQuery {
 
 #[doc(hidden)]
 pub struct RTDUpdateNewShippingQueryBuilder {
-  inner: UpdateNewShippingQuery
+  inner: UpdateNewShippingQuery,
+  set: [bool; 4]
 }
 
 impl RTDUpdateNewShippingQueryBuilder {
   pub fn build(&self) -> UpdateNewShippingQuery { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNewShippingQuery> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("sender_user_id".to_string()); }
+    if !self.set[2] { missing.push("invoice_payload".to_string()); }
+    if !self.set[3] { missing.push("shipping_address".to_string()); } else { missing.extend(crate::types::_common::missing_in("shipping_address", self.inner.shipping_address._missing_fields())); }
+    crate::types::_common::check_missing("updateNewShippingQuery", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn id(&mut self, id: isize) -> &mut Self {
+  pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn sender_user_id(&mut self, sender_user_id: i64) -> &mut Self {
     self.inner.sender_user_id = sender_user_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn invoice_payload<T: AsRef<str>>(&mut self, invoice_payload: T) -> &mut Self {
     self.inner.invoice_payload = invoice_payload.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn shipping_address<T: AsRef<Address>>(&mut self, shipping_address: T) -> &mut Self {
     self.inner.shipping_address = shipping_address.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
@@ -5720,7 +7324,17 @@ impl UpdateNotification {
     let mut inner = UpdateNotification::default();
     inner.td_name = "updateNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNotificationBuilder { inner }
+    RTDUpdateNotificationBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.notification_group_id == 0 { missing.push("notification_group_id".to_string()); }
+    if false { missing.push("notification".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification", self.notification._missing_fields())); }
+    missing
   }
 
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
@@ -5731,21 +7345,33 @@ impl UpdateNotification {
 
 #[doc(hidden)]
 pub struct RTDUpdateNotificationBuilder {
-  inner: UpdateNotification
+  inner: UpdateNotification,
+  set: [bool; 2]
 }
 
 impl RTDUpdateNotificationBuilder {
   pub fn build(&self) -> UpdateNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("notification_group_id".to_string()); }
+    if !self.set[1] { missing.push("notification".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification", self.inner.notification._missing_fields())); }
+    crate::types::_common::check_missing("updateNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn notification<T: AsRef<Notification>>(&mut self, notification: T) -> &mut Self {
     self.inner.notification = notification.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -5810,7 +7436,20 @@ impl UpdateNotificationGroup {
     let mut inner = UpdateNotificationGroup::default();
     inner.td_name = "updateNotificationGroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateNotificationGroupBuilder { inner }
+    RTDUpdateNotificationGroupBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateNotificationGroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.notification_group_id == 0 { missing.push("notification_group_id".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.notification_settings_chat_id == 0 { missing.push("notification_settings_chat_id".to_string()); }
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn notification_group_id(&self) -> i64 { self.notification_group_id }
@@ -5833,15 +7472,29 @@ impl UpdateNotificationGroup {
 
 #[doc(hidden)]
 pub struct RTDUpdateNotificationGroupBuilder {
-  inner: UpdateNotificationGroup
+  inner: UpdateNotificationGroup,
+  set: [bool; 4]
 }
 
 impl RTDUpdateNotificationGroupBuilder {
   pub fn build(&self) -> UpdateNotificationGroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateNotificationGroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("notification_group_id".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[1] { missing.push("chat_id".to_string()); }
+    if !self.set[2] { missing.push("notification_settings_chat_id".to_string()); }
+    if !self.set[3] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("updateNotificationGroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn notification_group_id(&mut self, notification_group_id: i64) -> &mut Self {
     self.inner.notification_group_id = notification_group_id;
+    self.set[0] = true;
     self
   }
 
@@ -5854,12 +7507,14 @@ impl RTDUpdateNotificationGroupBuilder {
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[1] = true;
     self
   }
 
    
   pub fn notification_settings_chat_id(&mut self, notification_settings_chat_id: i64) -> &mut Self {
     self.inner.notification_settings_chat_id = notification_settings_chat_id;
+    self.set[2] = true;
     self
   }
 
@@ -5872,6 +7527,7 @@ impl RTDUpdateNotificationGroupBuilder {
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[3] = true;
     self
   }
 
@@ -5936,7 +7592,17 @@ impl UpdateOption {
     let mut inner = UpdateOption::default();
     inner.td_name = "updateOption".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateOptionBuilder { inner }
+    RTDUpdateOptionBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateOption", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.name.is_empty() { missing.push("name".to_string()); }
+    if self.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.value._missing_fields())); }
+    missing
   }
 
   pub fn name(&self) -> &String { &self.name }
@@ -5947,15 +7613,26 @@ impl UpdateOption {
 
 #[doc(hidden)]
 pub struct RTDUpdateOptionBuilder {
-  inner: UpdateOption
+  inner: UpdateOption,
+  set: [bool; 1]
 }
 
 impl RTDUpdateOptionBuilder {
   pub fn build(&self) -> UpdateOption { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateOption> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("name".to_string()); }
+    if self.inner.value._is_default() { missing.push("value".to_string()); } else { missing.extend(crate::types::_common::missing_in("value", self.inner.value._missing_fields())); }
+    crate::types::_common::check_missing("updateOption", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn name<T: AsRef<str>>(&mut self, name: T) -> &mut Self {
     self.inner.name = name.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6012,7 +7689,16 @@ impl UpdatePoll {
     let mut inner = UpdatePoll::default();
     inner.td_name = "updatePoll".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdatePollBuilder { inner }
+    RTDUpdatePollBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updatePoll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("poll".to_string()); } else { missing.extend(crate::types::_common::missing_in("poll", self.poll._missing_fields())); }
+    missing
   }
 
   pub fn poll(&self) -> &Poll { &self.poll }
@@ -6021,15 +7707,25 @@ impl UpdatePoll {
 
 #[doc(hidden)]
 pub struct RTDUpdatePollBuilder {
-  inner: UpdatePoll
+  inner: UpdatePoll,
+  set: [bool; 1]
 }
 
 impl RTDUpdatePollBuilder {
   pub fn build(&self) -> UpdatePoll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdatePoll> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("poll".to_string()); } else { missing.extend(crate::types::_common::missing_in("poll", self.inner.poll._missing_fields())); }
+    crate::types::_common::check_missing("updatePoll", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn poll<T: AsRef<Poll>>(&mut self, poll: T) -> &mut Self {
     self.inner.poll = poll.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6059,7 +7755,7 @@ pub struct UpdatePollAnswer {
   #[serde(rename(serialize = "@extra", deserialize = "@extra"))]
   extra: Option<String>,
   /// Unique poll identifier
-  poll_id: isize,
+  #[serde(with = "crate::types::_common::int64")] poll_id: i64,
   /// The user, who changed the answer to the poll
   user_id: i64,
   /// 0-based identifiers of answer options, chosen by the user
@@ -6084,10 +7780,20 @@ impl UpdatePollAnswer {
     let mut inner = UpdatePollAnswer::default();
     inner.td_name = "updatePollAnswer".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdatePollAnswerBuilder { inner }
+    RTDUpdatePollAnswerBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updatePollAnswer", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.poll_id == 0 { missing.push("poll_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    missing
   }
 
-  pub fn poll_id(&self) -> isize { self.poll_id }
+  pub fn poll_id(&self) -> i64 { self.poll_id }
 
   pub fn user_id(&self) -> i64 { self.user_id }
 
@@ -6097,21 +7803,33 @@ impl UpdatePollAnswer {
 
 #[doc(hidden)]
 pub struct RTDUpdatePollAnswerBuilder {
-  inner: UpdatePollAnswer
+  inner: UpdatePollAnswer,
+  set: [bool; 2]
 }
 
 impl RTDUpdatePollAnswerBuilder {
   pub fn build(&self) -> UpdatePollAnswer { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdatePollAnswer> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("poll_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    crate::types::_common::check_missing("updatePollAnswer", missing)?;
+    Ok(self.build())
+  }
+
    
-  pub fn poll_id(&mut self, poll_id: isize) -> &mut Self {
+  pub fn poll_id(&mut self, poll_id: i64) -> &mut Self {
     self.inner.poll_id = poll_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -6173,6 +7891,11 @@ impl UpdateRecentStickers {
     RTDUpdateRecentStickersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateRecentStickers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn is_attached(&self) -> bool { self.is_attached }
 
   pub fn sticker_ids(&self) -> &Vec<i64> { &self.sticker_ids }
@@ -6187,6 +7910,9 @@ pub struct RTDUpdateRecentStickersBuilder {
 impl RTDUpdateRecentStickersBuilder {
   pub fn build(&self) -> UpdateRecentStickers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateRecentStickers> { Ok(self.build()) }
+
    
   pub fn is_attached(&mut self, is_attached: bool) -> &mut Self {
     self.inner.is_attached = is_attached;
@@ -6249,6 +7975,11 @@ impl UpdateSavedAnimations {
     RTDUpdateSavedAnimationsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSavedAnimations", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn animation_ids(&self) -> &Vec<i64> { &self.animation_ids }
 
 }
@@ -6261,6 +7992,9 @@ pub struct RTDUpdateSavedAnimationsBuilder {
 impl RTDUpdateSavedAnimationsBuilder {
   pub fn build(&self) -> UpdateSavedAnimations { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSavedAnimations> { Ok(self.build()) }
+
    
   pub fn animation_ids(&mut self, animation_ids: Vec<i64>) -> &mut Self {
     self.inner.animation_ids = animation_ids;
@@ -6316,7 +8050,17 @@ impl UpdateScopeNotificationSettings {
     let mut inner = UpdateScopeNotificationSettings::default();
     inner.td_name = "updateScopeNotificationSettings".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateScopeNotificationSettingsBuilder { inner }
+    RTDUpdateScopeNotificationSettingsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateScopeNotificationSettings", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.scope._missing_fields())); }
+    if false { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.notification_settings._missing_fields())); }
+    missing
   }
 
   pub fn scope(&self) -> &NotificationSettingsScope { &self.scope }
@@ -6327,12 +8071,22 @@ impl UpdateScopeNotificationSettings {
 
 #[doc(hidden)]
 pub struct RTDUpdateScopeNotificationSettingsBuilder {
-  inner: UpdateScopeNotificationSettings
+  inner: UpdateScopeNotificationSettings,
+  set: [bool; 1]
 }
 
 impl RTDUpdateScopeNotificationSettingsBuilder {
   pub fn build(&self) -> UpdateScopeNotificationSettings { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateScopeNotificationSettings> {
+    let mut missing = vec![];
+    if self.inner.scope._is_default() { missing.push("scope".to_string()); } else { missing.extend(crate::types::_common::missing_in("scope", self.inner.scope._missing_fields())); }
+    if !self.set[0] { missing.push("notification_settings".to_string()); } else { missing.extend(crate::types::_common::missing_in("notification_settings", self.inner.notification_settings._missing_fields())); }
+    crate::types::_common::check_missing("updateScopeNotificationSettings", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn scope<T: AsRef<NotificationSettingsScope>>(&mut self, scope: T) -> &mut Self {
     self.inner.scope = scope.as_ref().clone();
@@ -6342,6 +8096,7 @@ impl RTDUpdateScopeNotificationSettingsBuilder {
    
   pub fn notification_settings<T: AsRef<ScopeNotificationSettings>>(&mut self, notification_settings: T) -> &mut Self {
     self.inner.notification_settings = notification_settings.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6392,7 +8147,16 @@ impl UpdateSecretChat {
     let mut inner = UpdateSecretChat::default();
     inner.td_name = "updateSecretChat".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateSecretChatBuilder { inner }
+    RTDUpdateSecretChatBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSecretChat", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("secret_chat".to_string()); } else { missing.extend(crate::types::_common::missing_in("secret_chat", self.secret_chat._missing_fields())); }
+    missing
   }
 
   pub fn secret_chat(&self) -> &SecretChat { &self.secret_chat }
@@ -6401,15 +8165,25 @@ impl UpdateSecretChat {
 
 #[doc(hidden)]
 pub struct RTDUpdateSecretChatBuilder {
-  inner: UpdateSecretChat
+  inner: UpdateSecretChat,
+  set: [bool; 1]
 }
 
 impl RTDUpdateSecretChatBuilder {
   pub fn build(&self) -> UpdateSecretChat { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSecretChat> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("secret_chat".to_string()); } else { missing.extend(crate::types::_common::missing_in("secret_chat", self.inner.secret_chat._missing_fields())); }
+    crate::types::_common::check_missing("updateSecretChat", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn secret_chat<T: AsRef<SecretChat>>(&mut self, secret_chat: T) -> &mut Self {
     self.inner.secret_chat = secret_chat.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6465,6 +8239,11 @@ impl UpdateSelectedBackground {
     RTDUpdateSelectedBackgroundBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSelectedBackground", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn for_dark_theme(&self) -> bool { self.for_dark_theme }
 
   pub fn background(&self) -> &Option<Background> { &self.background }
@@ -6479,6 +8258,9 @@ pub struct RTDUpdateSelectedBackgroundBuilder {
 impl RTDUpdateSelectedBackgroundBuilder {
   pub fn build(&self) -> UpdateSelectedBackground { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSelectedBackground> { Ok(self.build()) }
+
    
   pub fn for_dark_theme(&mut self, for_dark_theme: bool) -> &mut Self {
     self.inner.for_dark_theme = for_dark_theme;
@@ -6540,7 +8322,17 @@ impl UpdateServiceNotification {
     let mut inner = UpdateServiceNotification::default();
     inner.td_name = "updateServiceNotification".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateServiceNotificationBuilder { inner }
+    RTDUpdateServiceNotificationBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateServiceNotification", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if self.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.content._missing_fields())); }
+    missing
   }
 
   pub fn type_(&self) -> &String { &self.type_ }
@@ -6551,15 +8343,26 @@ impl UpdateServiceNotification {
 
 #[doc(hidden)]
 pub struct RTDUpdateServiceNotificationBuilder {
-  inner: UpdateServiceNotification
+  inner: UpdateServiceNotification,
+  set: [bool; 1]
 }
 
 impl RTDUpdateServiceNotificationBuilder {
   pub fn build(&self) -> UpdateServiceNotification { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateServiceNotification> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("type".to_string()); }
+    if self.inner.content._is_default() { missing.push("content".to_string()); } else { missing.extend(crate::types::_common::missing_in("content", self.inner.content._missing_fields())); }
+    crate::types::_common::check_missing("updateServiceNotification", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -6616,7 +8419,16 @@ impl UpdateStickerSet {
     let mut inner = UpdateStickerSet::default();
     inner.td_name = "updateStickerSet".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateStickerSetBuilder { inner }
+    RTDUpdateStickerSetBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateStickerSet", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("sticker_set".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker_set", self.sticker_set._missing_fields())); }
+    missing
   }
 
   pub fn sticker_set(&self) -> &StickerSet { &self.sticker_set }
@@ -6625,15 +8437,25 @@ impl UpdateStickerSet {
 
 #[doc(hidden)]
 pub struct RTDUpdateStickerSetBuilder {
-  inner: UpdateStickerSet
+  inner: UpdateStickerSet,
+  set: [bool; 1]
 }
 
 impl RTDUpdateStickerSetBuilder {
   pub fn build(&self) -> UpdateStickerSet { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateStickerSet> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sticker_set".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker_set", self.inner.sticker_set._missing_fields())); }
+    crate::types::_common::check_missing("updateStickerSet", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker_set<T: AsRef<StickerSet>>(&mut self, sticker_set: T) -> &mut Self {
     self.inner.sticker_set = sticker_set.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6689,6 +8511,11 @@ impl UpdateSuggestedActions {
     RTDUpdateSuggestedActionsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSuggestedActions", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn added_actions(&self) -> &Vec<SuggestedAction> { &self.added_actions }
 
   pub fn removed_actions(&self) -> &Vec<SuggestedAction> { &self.removed_actions }
@@ -6703,6 +8530,9 @@ pub struct RTDUpdateSuggestedActionsBuilder {
 impl RTDUpdateSuggestedActionsBuilder {
   pub fn build(&self) -> UpdateSuggestedActions { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSuggestedActions> { Ok(self.build()) }
+
    
   pub fn added_actions(&mut self, added_actions: Vec<SuggestedAction>) -> &mut Self {
     self.inner.added_actions = added_actions;
@@ -6762,7 +8592,16 @@ impl UpdateSupergroup {
     let mut inner = UpdateSupergroup::default();
     inner.td_name = "updateSupergroup".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateSupergroupBuilder { inner }
+    RTDUpdateSupergroupBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSupergroup", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("supergroup".to_string()); } else { missing.extend(crate::types::_common::missing_in("supergroup", self.supergroup._missing_fields())); }
+    missing
   }
 
   pub fn supergroup(&self) -> &Supergroup { &self.supergroup }
@@ -6771,15 +8610,25 @@ impl UpdateSupergroup {
 
 #[doc(hidden)]
 pub struct RTDUpdateSupergroupBuilder {
-  inner: UpdateSupergroup
+  inner: UpdateSupergroup,
+  set: [bool; 1]
 }
 
 impl RTDUpdateSupergroupBuilder {
   pub fn build(&self) -> UpdateSupergroup { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSupergroup> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup".to_string()); } else { missing.extend(crate::types::_common::missing_in("supergroup", self.inner.supergroup._missing_fields())); }
+    crate::types::_common::check_missing("updateSupergroup", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup<T: AsRef<Supergroup>>(&mut self, supergroup: T) -> &mut Self {
     self.inner.supergroup = supergroup.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -6832,7 +8681,17 @@ impl UpdateSupergroupFullInfo {
     let mut inner = UpdateSupergroupFullInfo::default();
     inner.td_name = "updateSupergroupFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateSupergroupFullInfoBuilder { inner }
+    RTDUpdateSupergroupFullInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateSupergroupFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.supergroup_id == 0 { missing.push("supergroup_id".to_string()); }
+    if false { missing.push("supergroup_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("supergroup_full_info", self.supergroup_full_info._missing_fields())); }
+    missing
   }
 
   pub fn supergroup_id(&self) -> i64 { self.supergroup_id }
@@ -6843,21 +8702,33 @@ impl UpdateSupergroupFullInfo {
 
 #[doc(hidden)]
 pub struct RTDUpdateSupergroupFullInfoBuilder {
-  inner: UpdateSupergroupFullInfo
+  inner: UpdateSupergroupFullInfo,
+  set: [bool; 2]
 }
 
 impl RTDUpdateSupergroupFullInfoBuilder {
   pub fn build(&self) -> UpdateSupergroupFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateSupergroupFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("supergroup_id".to_string()); }
+    if !self.set[1] { missing.push("supergroup_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("supergroup_full_info", self.inner.supergroup_full_info._missing_fields())); }
+    crate::types::_common::check_missing("updateSupergroupFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn supergroup_id(&mut self, supergroup_id: i64) -> &mut Self {
     self.inner.supergroup_id = supergroup_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn supergroup_full_info<T: AsRef<SupergroupFullInfo>>(&mut self, supergroup_full_info: T) -> &mut Self {
     self.inner.supergroup_full_info = supergroup_full_info.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -6910,7 +8781,17 @@ impl UpdateTermsOfService {
     let mut inner = UpdateTermsOfService::default();
     inner.td_name = "updateTermsOfService".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateTermsOfServiceBuilder { inner }
+    RTDUpdateTermsOfServiceBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateTermsOfService", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.terms_of_service_id.is_empty() { missing.push("terms_of_service_id".to_string()); }
+    if false { missing.push("terms_of_service".to_string()); } else { missing.extend(crate::types::_common::missing_in("terms_of_service", self.terms_of_service._missing_fields())); }
+    missing
   }
 
   pub fn terms_of_service_id(&self) -> &String { &self.terms_of_service_id }
@@ -6921,21 +8802,33 @@ impl UpdateTermsOfService {
 
 #[doc(hidden)]
 pub struct RTDUpdateTermsOfServiceBuilder {
-  inner: UpdateTermsOfService
+  inner: UpdateTermsOfService,
+  set: [bool; 2]
 }
 
 impl RTDUpdateTermsOfServiceBuilder {
   pub fn build(&self) -> UpdateTermsOfService { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateTermsOfService> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("terms_of_service_id".to_string()); }
+    if !self.set[1] { missing.push("terms_of_service".to_string()); } else { missing.extend(crate::types::_common::missing_in("terms_of_service", self.inner.terms_of_service._missing_fields())); }
+    crate::types::_common::check_missing("updateTermsOfService", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn terms_of_service_id<T: AsRef<str>>(&mut self, terms_of_service_id: T) -> &mut Self {
     self.inner.terms_of_service_id = terms_of_service_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn terms_of_service<T: AsRef<TermsOfService>>(&mut self, terms_of_service: T) -> &mut Self {
     self.inner.terms_of_service = terms_of_service.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -6986,7 +8879,16 @@ impl UpdateTrendingStickerSets {
     let mut inner = UpdateTrendingStickerSets::default();
     inner.td_name = "updateTrendingStickerSets".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateTrendingStickerSetsBuilder { inner }
+    RTDUpdateTrendingStickerSetsBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateTrendingStickerSets", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("sticker_sets".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker_sets", self.sticker_sets._missing_fields())); }
+    missing
   }
 
   pub fn sticker_sets(&self) -> &StickerSets { &self.sticker_sets }
@@ -6995,15 +8897,25 @@ impl UpdateTrendingStickerSets {
 
 #[doc(hidden)]
 pub struct RTDUpdateTrendingStickerSetsBuilder {
-  inner: UpdateTrendingStickerSets
+  inner: UpdateTrendingStickerSets,
+  set: [bool; 1]
 }
 
 impl RTDUpdateTrendingStickerSetsBuilder {
   pub fn build(&self) -> UpdateTrendingStickerSets { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateTrendingStickerSets> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("sticker_sets".to_string()); } else { missing.extend(crate::types::_common::missing_in("sticker_sets", self.inner.sticker_sets._missing_fields())); }
+    crate::types::_common::check_missing("updateTrendingStickerSets", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn sticker_sets<T: AsRef<StickerSets>>(&mut self, sticker_sets: T) -> &mut Self {
     self.inner.sticker_sets = sticker_sets.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -7064,7 +8976,21 @@ impl UpdateUnreadChatCount {
     let mut inner = UpdateUnreadChatCount::default();
     inner.td_name = "updateUnreadChatCount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUnreadChatCountBuilder { inner }
+    RTDUpdateUnreadChatCountBuilder { inner, set: [false; 5] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUnreadChatCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    if self.unread_count == 0 { missing.push("unread_count".to_string()); }
+    if self.unread_unmuted_count == 0 { missing.push("unread_unmuted_count".to_string()); }
+    if self.marked_as_unread_count == 0 { missing.push("marked_as_unread_count".to_string()); }
+    if self.marked_as_unread_unmuted_count == 0 { missing.push("marked_as_unread_unmuted_count".to_string()); }
+    missing
   }
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
@@ -7083,12 +9009,26 @@ impl UpdateUnreadChatCount {
 
 #[doc(hidden)]
 pub struct RTDUpdateUnreadChatCountBuilder {
-  inner: UpdateUnreadChatCount
+  inner: UpdateUnreadChatCount,
+  set: [bool; 5]
 }
 
 impl RTDUpdateUnreadChatCountBuilder {
   pub fn build(&self) -> UpdateUnreadChatCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUnreadChatCount> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    if !self.set[0] { missing.push("total_count".to_string()); }
+    if !self.set[1] { missing.push("unread_count".to_string()); }
+    if !self.set[2] { missing.push("unread_unmuted_count".to_string()); }
+    if !self.set[3] { missing.push("marked_as_unread_count".to_string()); }
+    if !self.set[4] { missing.push("marked_as_unread_unmuted_count".to_string()); }
+    crate::types::_common::check_missing("updateUnreadChatCount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -7098,30 +9038,35 @@ impl RTDUpdateUnreadChatCountBuilder {
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[0] = true;
     self
   }
 
    
   pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
     self.inner.unread_count = unread_count;
+    self.set[1] = true;
     self
   }
 
    
   pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i64) -> &mut Self {
     self.inner.unread_unmuted_count = unread_unmuted_count;
+    self.set[2] = true;
     self
   }
 
    
   pub fn marked_as_unread_count(&mut self, marked_as_unread_count: i64) -> &mut Self {
     self.inner.marked_as_unread_count = marked_as_unread_count;
+    self.set[3] = true;
     self
   }
 
    
   pub fn marked_as_unread_unmuted_count(&mut self, marked_as_unread_unmuted_count: i64) -> &mut Self {
     self.inner.marked_as_unread_unmuted_count = marked_as_unread_unmuted_count;
+    self.set[4] = true;
     self
   }
 
@@ -7176,7 +9121,18 @@ impl UpdateUnreadMessageCount {
     let mut inner = UpdateUnreadMessageCount::default();
     inner.td_name = "updateUnreadMessageCount".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUnreadMessageCountBuilder { inner }
+    RTDUpdateUnreadMessageCountBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUnreadMessageCount", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.chat_list._missing_fields())); }
+    if self.unread_count == 0 { missing.push("unread_count".to_string()); }
+    if self.unread_unmuted_count == 0 { missing.push("unread_unmuted_count".to_string()); }
+    missing
   }
 
   pub fn chat_list(&self) -> &ChatList { &self.chat_list }
@@ -7189,12 +9145,23 @@ impl UpdateUnreadMessageCount {
 
 #[doc(hidden)]
 pub struct RTDUpdateUnreadMessageCountBuilder {
-  inner: UpdateUnreadMessageCount
+  inner: UpdateUnreadMessageCount,
+  set: [bool; 2]
 }
 
 impl RTDUpdateUnreadMessageCountBuilder {
   pub fn build(&self) -> UpdateUnreadMessageCount { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUnreadMessageCount> {
+    let mut missing = vec![];
+    if self.inner.chat_list._is_default() { missing.push("chat_list".to_string()); } else { missing.extend(crate::types::_common::missing_in("chat_list", self.inner.chat_list._missing_fields())); }
+    if !self.set[0] { missing.push("unread_count".to_string()); }
+    if !self.set[1] { missing.push("unread_unmuted_count".to_string()); }
+    crate::types::_common::check_missing("updateUnreadMessageCount", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_list<T: AsRef<ChatList>>(&mut self, chat_list: T) -> &mut Self {
     self.inner.chat_list = chat_list.as_ref().clone();
@@ -7204,12 +9171,14 @@ impl RTDUpdateUnreadMessageCountBuilder {
    
   pub fn unread_count(&mut self, unread_count: i64) -> &mut Self {
     self.inner.unread_count = unread_count;
+    self.set[0] = true;
     self
   }
 
    
   pub fn unread_unmuted_count(&mut self, unread_unmuted_count: i64) -> &mut Self {
     self.inner.unread_unmuted_count = unread_unmuted_count;
+    self.set[1] = true;
     self
   }
 
@@ -7260,7 +9229,16 @@ impl UpdateUser {
     let mut inner = UpdateUser::default();
     inner.td_name = "updateUser".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUserBuilder { inner }
+    RTDUpdateUserBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUser", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("user".to_string()); } else { missing.extend(crate::types::_common::missing_in("user", self.user._missing_fields())); }
+    missing
   }
 
   pub fn user(&self) -> &User { &self.user }
@@ -7269,15 +9247,25 @@ impl UpdateUser {
 
 #[doc(hidden)]
 pub struct RTDUpdateUserBuilder {
-  inner: UpdateUser
+  inner: UpdateUser,
+  set: [bool; 1]
 }
 
 impl RTDUpdateUserBuilder {
   pub fn build(&self) -> UpdateUser { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUser> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user".to_string()); } else { missing.extend(crate::types::_common::missing_in("user", self.inner.user._missing_fields())); }
+    crate::types::_common::check_missing("updateUser", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user<T: AsRef<User>>(&mut self, user: T) -> &mut Self {
     self.inner.user = user.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -7334,7 +9322,18 @@ impl UpdateUserChatAction {
     let mut inner = UpdateUserChatAction::default();
     inner.td_name = "updateUserChatAction".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUserChatActionBuilder { inner }
+    RTDUpdateUserChatActionBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUserChatAction", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.chat_id == 0 { missing.push("chat_id".to_string()); }
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.action._missing_fields())); }
+    missing
   }
 
   pub fn chat_id(&self) -> i64 { self.chat_id }
@@ -7349,15 +9348,27 @@ impl UpdateUserChatAction {
 
 #[doc(hidden)]
 pub struct RTDUpdateUserChatActionBuilder {
-  inner: UpdateUserChatAction
+  inner: UpdateUserChatAction,
+  set: [bool; 2]
 }
 
 impl RTDUpdateUserChatActionBuilder {
   pub fn build(&self) -> UpdateUserChatAction { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUserChatAction> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("chat_id".to_string()); }
+    if !self.set[1] { missing.push("user_id".to_string()); }
+    if self.inner.action._is_default() { missing.push("action".to_string()); } else { missing.extend(crate::types::_common::missing_in("action", self.inner.action._missing_fields())); }
+    crate::types::_common::check_missing("updateUserChatAction", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn chat_id(&mut self, chat_id: i64) -> &mut Self {
     self.inner.chat_id = chat_id;
+    self.set[0] = true;
     self
   }
 
@@ -7370,6 +9381,7 @@ impl RTDUpdateUserChatActionBuilder {
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[1] = true;
     self
   }
 
@@ -7428,7 +9440,17 @@ impl UpdateUserFullInfo {
     let mut inner = UpdateUserFullInfo::default();
     inner.td_name = "updateUserFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUserFullInfoBuilder { inner }
+    RTDUpdateUserFullInfoBuilder { inner, set: [false; 2] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUserFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if false { missing.push("user_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("user_full_info", self.user_full_info._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -7439,21 +9461,33 @@ impl UpdateUserFullInfo {
 
 #[doc(hidden)]
 pub struct RTDUpdateUserFullInfoBuilder {
-  inner: UpdateUserFullInfo
+  inner: UpdateUserFullInfo,
+  set: [bool; 2]
 }
 
 impl RTDUpdateUserFullInfoBuilder {
   pub fn build(&self) -> UpdateUserFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUserFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if !self.set[1] { missing.push("user_full_info".to_string()); } else { missing.extend(crate::types::_common::missing_in("user_full_info", self.inner.user_full_info._missing_fields())); }
+    crate::types::_common::check_missing("updateUserFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn user_full_info<T: AsRef<UserFullInfo>>(&mut self, user_full_info: T) -> &mut Self {
     self.inner.user_full_info = user_full_info.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
@@ -7506,7 +9540,17 @@ impl UpdateUserPrivacySettingRules {
     let mut inner = UpdateUserPrivacySettingRules::default();
     inner.td_name = "updateUserPrivacySettingRules".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUserPrivacySettingRulesBuilder { inner }
+    RTDUpdateUserPrivacySettingRulesBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUserPrivacySettingRules", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.setting._missing_fields())); }
+    if false { missing.push("rules".to_string()); } else { missing.extend(crate::types::_common::missing_in("rules", self.rules._missing_fields())); }
+    missing
   }
 
   pub fn setting(&self) -> &UserPrivacySetting { &self.setting }
@@ -7517,12 +9561,22 @@ impl UpdateUserPrivacySettingRules {
 
 #[doc(hidden)]
 pub struct RTDUpdateUserPrivacySettingRulesBuilder {
-  inner: UpdateUserPrivacySettingRules
+  inner: UpdateUserPrivacySettingRules,
+  set: [bool; 1]
 }
 
 impl RTDUpdateUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UpdateUserPrivacySettingRules { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUserPrivacySettingRules> {
+    let mut missing = vec![];
+    if self.inner.setting._is_default() { missing.push("setting".to_string()); } else { missing.extend(crate::types::_common::missing_in("setting", self.inner.setting._missing_fields())); }
+    if !self.set[0] { missing.push("rules".to_string()); } else { missing.extend(crate::types::_common::missing_in("rules", self.inner.rules._missing_fields())); }
+    crate::types::_common::check_missing("updateUserPrivacySettingRules", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn setting<T: AsRef<UserPrivacySetting>>(&mut self, setting: T) -> &mut Self {
     self.inner.setting = setting.as_ref().clone();
@@ -7532,6 +9586,7 @@ impl RTDUpdateUserPrivacySettingRulesBuilder {
    
   pub fn rules<T: AsRef<UserPrivacySettingRules>>(&mut self, rules: T) -> &mut Self {
     self.inner.rules = rules.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -7584,7 +9639,17 @@ impl UpdateUserStatus {
     let mut inner = UpdateUserStatus::default();
     inner.td_name = "updateUserStatus".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUpdateUserStatusBuilder { inner }
+    RTDUpdateUserStatusBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUserStatus", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.user_id == 0 { missing.push("user_id".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    missing
   }
 
   pub fn user_id(&self) -> i64 { self.user_id }
@@ -7595,15 +9660,26 @@ impl UpdateUserStatus {
 
 #[doc(hidden)]
 pub struct RTDUpdateUserStatusBuilder {
-  inner: UpdateUserStatus
+  inner: UpdateUserStatus,
+  set: [bool; 1]
 }
 
 impl RTDUpdateUserStatusBuilder {
   pub fn build(&self) -> UpdateUserStatus { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUserStatus> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("user_id".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    crate::types::_common::check_missing("updateUserStatus", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn user_id(&mut self, user_id: i64) -> &mut Self {
     self.inner.user_id = user_id;
+    self.set[0] = true;
     self
   }
 
@@ -7663,6 +9739,11 @@ impl UpdateUsersNearby {
     RTDUpdateUsersNearbyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updateUsersNearby", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn users_nearby(&self) -> &Vec<ChatNearby> { &self.users_nearby }
 
 }
@@ -7675,6 +9756,9 @@ pub struct RTDUpdateUsersNearbyBuilder {
 impl RTDUpdateUsersNearbyBuilder {
   pub fn build(&self) -> UpdateUsersNearby { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UpdateUsersNearby> { Ok(self.build()) }
+
    
   pub fn users_nearby(&mut self, users_nearby: Vec<ChatNearby>) -> &mut Self {
     self.inner.users_nearby = users_nearby;
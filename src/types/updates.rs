@@ -37,6 +37,11 @@ impl Updates {
     RTDUpdatesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("updates", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn updates(&self) -> &Vec<Update> { &self.updates }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDUpdatesBuilder {
 impl RTDUpdatesBuilder {
   pub fn build(&self) -> Updates { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Updates> { Ok(self.build()) }
+
    
   pub fn updates(&mut self, updates: Vec<Update>) -> &mut Self {
     self.inner.updates = updates;
@@ -66,7 +66,24 @@ impl User {
     let mut inner = User::default();
     inner.td_name = "user".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUserBuilder { inner }
+    RTDUserBuilder { inner, set: [false; 7] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("user", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.id == 0 { missing.push("id".to_string()); }
+    if self.first_name.is_empty() { missing.push("first_name".to_string()); }
+    if self.last_name.is_empty() { missing.push("last_name".to_string()); }
+    if self.username.is_empty() { missing.push("username".to_string()); }
+    if self.phone_number.is_empty() { missing.push("phone_number".to_string()); }
+    if self.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.status._missing_fields())); }
+    if self.restriction_reason.is_empty() { missing.push("restriction_reason".to_string()); }
+    if self.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.type_._missing_fields())); }
+    if self.language_code.is_empty() { missing.push("language_code".to_string()); }
+    missing
   }
 
   pub fn id(&self) -> i64 { self.id }
@@ -103,43 +120,99 @@ impl User {
 
   pub fn language_code(&self) -> &String { &self.language_code }
 
+  pub fn id_mut(&mut self) -> &mut i64 { &mut self.id }
+
+  pub fn first_name_mut(&mut self) -> &mut String { &mut self.first_name }
+
+  pub fn last_name_mut(&mut self) -> &mut String { &mut self.last_name }
+
+  pub fn username_mut(&mut self) -> &mut String { &mut self.username }
+
+  pub fn phone_number_mut(&mut self) -> &mut String { &mut self.phone_number }
+
+  pub fn status_mut(&mut self) -> &mut UserStatus { &mut self.status }
+
+  pub fn profile_photo_mut(&mut self) -> &mut Option<ProfilePhoto> { &mut self.profile_photo }
+
+  pub fn is_contact_mut(&mut self) -> &mut bool { &mut self.is_contact }
+
+  pub fn is_mutual_contact_mut(&mut self) -> &mut bool { &mut self.is_mutual_contact }
+
+  pub fn is_verified_mut(&mut self) -> &mut bool { &mut self.is_verified }
+
+  pub fn is_support_mut(&mut self) -> &mut bool { &mut self.is_support }
+
+  pub fn restriction_reason_mut(&mut self) -> &mut String { &mut self.restriction_reason }
+
+  pub fn is_scam_mut(&mut self) -> &mut bool { &mut self.is_scam }
+
+  pub fn is_fake_mut(&mut self) -> &mut bool { &mut self.is_fake }
+
+  pub fn have_access_mut(&mut self) -> &mut bool { &mut self.have_access }
+
+  pub fn type_mut(&mut self) -> &mut UserType { &mut self.type_ }
+
+  pub fn language_code_mut(&mut self) -> &mut String { &mut self.language_code }
+
 }
 
 #[doc(hidden)]
 pub struct RTDUserBuilder {
-  inner: User
+  inner: User,
+  set: [bool; 7]
 }
 
 impl RTDUserBuilder {
   pub fn build(&self) -> User { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<User> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("id".to_string()); }
+    if !self.set[1] { missing.push("first_name".to_string()); }
+    if !self.set[2] { missing.push("last_name".to_string()); }
+    if !self.set[3] { missing.push("username".to_string()); }
+    if !self.set[4] { missing.push("phone_number".to_string()); }
+    if self.inner.status._is_default() { missing.push("status".to_string()); } else { missing.extend(crate::types::_common::missing_in("status", self.inner.status._missing_fields())); }
+    if !self.set[5] { missing.push("restriction_reason".to_string()); }
+    if self.inner.type_._is_default() { missing.push("type".to_string()); } else { missing.extend(crate::types::_common::missing_in("type", self.inner.type_._missing_fields())); }
+    if !self.set[6] { missing.push("language_code".to_string()); }
+    crate::types::_common::check_missing("user", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn id(&mut self, id: i64) -> &mut Self {
     self.inner.id = id;
+    self.set[0] = true;
     self
   }
 
    
   pub fn first_name<T: AsRef<str>>(&mut self, first_name: T) -> &mut Self {
     self.inner.first_name = first_name.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn last_name<T: AsRef<str>>(&mut self, last_name: T) -> &mut Self {
     self.inner.last_name = last_name.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn username<T: AsRef<str>>(&mut self, username: T) -> &mut Self {
     self.inner.username = username.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn phone_number<T: AsRef<str>>(&mut self, phone_number: T) -> &mut Self {
     self.inner.phone_number = phone_number.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
@@ -182,6 +255,7 @@ impl RTDUserBuilder {
    
   pub fn restriction_reason<T: AsRef<str>>(&mut self, restriction_reason: T) -> &mut Self {
     self.inner.restriction_reason = restriction_reason.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
@@ -212,6 +286,7 @@ impl RTDUserBuilder {
    
   pub fn language_code<T: AsRef<str>>(&mut self, language_code: T) -> &mut Self {
     self.inner.language_code = language_code.as_ref().to_string();
+    self.set[6] = true;
     self
   }
 
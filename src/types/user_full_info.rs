@@ -52,7 +52,18 @@ impl UserFullInfo {
     let mut inner = UserFullInfo::default();
     inner.td_name = "userFullInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUserFullInfoBuilder { inner }
+    RTDUserFullInfoBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userFullInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.bio.is_empty() { missing.push("bio".to_string()); }
+    if self.share_text.is_empty() { missing.push("share_text".to_string()); }
+    if self.group_in_common_count == 0 { missing.push("group_in_common_count".to_string()); }
+    missing
   }
 
   pub fn photo(&self) -> &Option<ChatPhoto> { &self.photo }
@@ -79,12 +90,23 @@ impl UserFullInfo {
 
 #[doc(hidden)]
 pub struct RTDUserFullInfoBuilder {
-  inner: UserFullInfo
+  inner: UserFullInfo,
+  set: [bool; 3]
 }
 
 impl RTDUserFullInfoBuilder {
   pub fn build(&self) -> UserFullInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserFullInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("bio".to_string()); }
+    if !self.set[1] { missing.push("share_text".to_string()); }
+    if !self.set[2] { missing.push("group_in_common_count".to_string()); }
+    crate::types::_common::check_missing("userFullInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn photo<T: AsRef<ChatPhoto>>(&mut self, photo: T) -> &mut Self {
     self.inner.photo = Some(photo.as_ref().clone());
@@ -124,18 +146,21 @@ impl RTDUserFullInfoBuilder {
    
   pub fn bio<T: AsRef<str>>(&mut self, bio: T) -> &mut Self {
     self.inner.bio = bio.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn share_text<T: AsRef<str>>(&mut self, share_text: T) -> &mut Self {
     self.inner.share_text = share_text.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn group_in_common_count(&mut self, group_in_common_count: i64) -> &mut Self {
     self.inner.group_in_common_count = group_in_common_count;
+    self.set[2] = true;
     self
   }
 
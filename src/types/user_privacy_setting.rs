@@ -19,6 +19,9 @@ pub trait TDUserPrivacySetting: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserPrivacySetting {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A privacy setting for managing whether the user can be called
   AllowCalls(UserPrivacySettingAllowCalls),
   /// A privacy setting for managing whether the user can be invited to chats
@@ -44,7 +47,6 @@ impl Default for UserPrivacySetting {
 
 impl<'de> Deserialize<'de> for UserPrivacySetting {
   fn deserialize<D>(deserializer: D) -> Result<UserPrivacySetting, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       UserPrivacySetting,
       (userPrivacySettingAllowCalls, AllowCalls);
@@ -85,6 +87,7 @@ impl RObject for UserPrivacySetting {
       UserPrivacySetting::ShowPhoneNumber(t) => t.extra(),
       UserPrivacySetting::ShowProfilePhoto(t) => t.extra(),
       UserPrivacySetting::ShowStatus(t) => t.extra(),
+      UserPrivacySetting::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -95,6 +98,19 @@ impl RObject for UserPrivacySetting {
 impl UserPrivacySetting {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySetting::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      UserPrivacySetting::AllowCalls(t) => t._missing_fields(),
+      UserPrivacySetting::AllowChatInvites(t) => t._missing_fields(),
+      UserPrivacySetting::AllowFindingByPhoneNumber(t) => t._missing_fields(),
+      UserPrivacySetting::AllowPeerToPeerCalls(t) => t._missing_fields(),
+      UserPrivacySetting::ShowLinkInForwardedMessages(t) => t._missing_fields(),
+      UserPrivacySetting::ShowPhoneNumber(t) => t._missing_fields(),
+      UserPrivacySetting::ShowProfilePhoto(t) => t._missing_fields(),
+      UserPrivacySetting::ShowStatus(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_allow_calls(&self) -> bool { if let UserPrivacySetting::AllowCalls(_) = self { true } else { false } }
   pub fn is_allow_chat_invites(&self) -> bool { if let UserPrivacySetting::AllowChatInvites(_) = self { true } else { false } }
@@ -147,6 +163,38 @@ impl AsRef<UserPrivacySetting> for UserPrivacySetting {
   fn as_ref(&self) -> &UserPrivacySetting { self }
 }
 
+impl RVariant<UserPrivacySetting> for UserPrivacySettingAllowCalls {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::AllowCalls(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingAllowChatInvites {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::AllowChatInvites(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingAllowFindingByPhoneNumber {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::AllowFindingByPhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingAllowPeerToPeerCalls {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::AllowPeerToPeerCalls(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingShowLinkInForwardedMessages {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::ShowLinkInForwardedMessages(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingShowPhoneNumber {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::ShowPhoneNumber(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingShowProfilePhoto {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::ShowProfilePhoto(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySetting> for UserPrivacySettingShowStatus {
+  fn from_class(class: &UserPrivacySetting) -> Option<&Self> { match class { UserPrivacySetting::ShowStatus(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -185,6 +233,11 @@ impl UserPrivacySettingAllowCalls {
     RTDUserPrivacySettingAllowCallsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingAllowCalls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +248,9 @@ pub struct RTDUserPrivacySettingAllowCallsBuilder {
 impl RTDUserPrivacySettingAllowCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowCalls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowCalls> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingAllowCalls> for UserPrivacySettingAllowCalls {
@@ -243,6 +299,11 @@ impl UserPrivacySettingAllowChatInvites {
     RTDUserPrivacySettingAllowChatInvitesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingAllowChatInvites", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -253,6 +314,9 @@ pub struct RTDUserPrivacySettingAllowChatInvitesBuilder {
 impl RTDUserPrivacySettingAllowChatInvitesBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowChatInvites { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowChatInvites> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingAllowChatInvites> for UserPrivacySettingAllowChatInvites {
@@ -301,6 +365,11 @@ impl UserPrivacySettingAllowFindingByPhoneNumber {
     RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingAllowFindingByPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -311,6 +380,9 @@ pub struct RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
 impl RTDUserPrivacySettingAllowFindingByPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowFindingByPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowFindingByPhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingAllowFindingByPhoneNumber> for UserPrivacySettingAllowFindingByPhoneNumber {
@@ -359,6 +431,11 @@ impl UserPrivacySettingAllowPeerToPeerCalls {
     RTDUserPrivacySettingAllowPeerToPeerCallsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingAllowPeerToPeerCalls", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -369,6 +446,9 @@ pub struct RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
 impl RTDUserPrivacySettingAllowPeerToPeerCallsBuilder {
   pub fn build(&self) -> UserPrivacySettingAllowPeerToPeerCalls { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingAllowPeerToPeerCalls> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingAllowPeerToPeerCalls> for UserPrivacySettingAllowPeerToPeerCalls {
@@ -417,6 +497,11 @@ impl UserPrivacySettingShowLinkInForwardedMessages {
     RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingShowLinkInForwardedMessages", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -427,6 +512,9 @@ pub struct RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
 impl RTDUserPrivacySettingShowLinkInForwardedMessagesBuilder {
   pub fn build(&self) -> UserPrivacySettingShowLinkInForwardedMessages { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowLinkInForwardedMessages> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingShowLinkInForwardedMessages> for UserPrivacySettingShowLinkInForwardedMessages {
@@ -475,6 +563,11 @@ impl UserPrivacySettingShowPhoneNumber {
     RTDUserPrivacySettingShowPhoneNumberBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingShowPhoneNumber", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -485,6 +578,9 @@ pub struct RTDUserPrivacySettingShowPhoneNumberBuilder {
 impl RTDUserPrivacySettingShowPhoneNumberBuilder {
   pub fn build(&self) -> UserPrivacySettingShowPhoneNumber { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowPhoneNumber> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingShowPhoneNumber> for UserPrivacySettingShowPhoneNumber {
@@ -533,6 +629,11 @@ impl UserPrivacySettingShowProfilePhoto {
     RTDUserPrivacySettingShowProfilePhotoBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingShowProfilePhoto", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -543,6 +644,9 @@ pub struct RTDUserPrivacySettingShowProfilePhotoBuilder {
 impl RTDUserPrivacySettingShowProfilePhotoBuilder {
   pub fn build(&self) -> UserPrivacySettingShowProfilePhoto { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowProfilePhoto> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingShowProfilePhoto> for UserPrivacySettingShowProfilePhoto {
@@ -591,6 +695,11 @@ impl UserPrivacySettingShowStatus {
     RTDUserPrivacySettingShowStatusBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingShowStatus", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -601,6 +710,9 @@ pub struct RTDUserPrivacySettingShowStatusBuilder {
 impl RTDUserPrivacySettingShowStatusBuilder {
   pub fn build(&self) -> UserPrivacySettingShowStatus { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingShowStatus> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingShowStatus> for UserPrivacySettingShowStatus {
@@ -19,6 +19,9 @@ pub trait TDUserPrivacySettingRule: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserPrivacySettingRule {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A rule to allow all users to do something
   AllowAll(UserPrivacySettingRuleAllowAll),
   /// A rule to allow all members of certain specified basic groups and supergroups to doing something
@@ -44,7 +47,6 @@ impl Default for UserPrivacySettingRule {
 
 impl<'de> Deserialize<'de> for UserPrivacySettingRule {
   fn deserialize<D>(deserializer: D) -> Result<UserPrivacySettingRule, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       UserPrivacySettingRule,
       (userPrivacySettingRuleAllowAll, AllowAll);
@@ -85,6 +87,7 @@ impl RObject for UserPrivacySettingRule {
       UserPrivacySettingRule::RestrictChatMembers(t) => t.extra(),
       UserPrivacySettingRule::RestrictContacts(t) => t.extra(),
       UserPrivacySettingRule::RestrictUsers(t) => t.extra(),
+      UserPrivacySettingRule::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -95,6 +98,19 @@ impl RObject for UserPrivacySettingRule {
 impl UserPrivacySettingRule {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserPrivacySettingRule::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      UserPrivacySettingRule::AllowAll(t) => t._missing_fields(),
+      UserPrivacySettingRule::AllowChatMembers(t) => t._missing_fields(),
+      UserPrivacySettingRule::AllowContacts(t) => t._missing_fields(),
+      UserPrivacySettingRule::AllowUsers(t) => t._missing_fields(),
+      UserPrivacySettingRule::RestrictAll(t) => t._missing_fields(),
+      UserPrivacySettingRule::RestrictChatMembers(t) => t._missing_fields(),
+      UserPrivacySettingRule::RestrictContacts(t) => t._missing_fields(),
+      UserPrivacySettingRule::RestrictUsers(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_allow_all(&self) -> bool { if let UserPrivacySettingRule::AllowAll(_) = self { true } else { false } }
   pub fn is_allow_chat_members(&self) -> bool { if let UserPrivacySettingRule::AllowChatMembers(_) = self { true } else { false } }
@@ -147,6 +163,38 @@ impl AsRef<UserPrivacySettingRule> for UserPrivacySettingRule {
   fn as_ref(&self) -> &UserPrivacySettingRule { self }
 }
 
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleAllowAll {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::AllowAll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleAllowChatMembers {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::AllowChatMembers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleAllowContacts {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::AllowContacts(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleAllowUsers {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::AllowUsers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleRestrictAll {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::RestrictAll(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleRestrictChatMembers {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::RestrictChatMembers(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleRestrictContacts {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::RestrictContacts(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserPrivacySettingRule> for UserPrivacySettingRuleRestrictUsers {
+  fn from_class(class: &UserPrivacySettingRule) -> Option<&Self> { match class { UserPrivacySettingRule::RestrictUsers(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -185,6 +233,11 @@ impl UserPrivacySettingRuleAllowAll {
     RTDUserPrivacySettingRuleAllowAllBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleAllowAll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -195,6 +248,9 @@ pub struct RTDUserPrivacySettingRuleAllowAllBuilder {
 impl RTDUserPrivacySettingRuleAllowAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowAll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowAll> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowAll> for UserPrivacySettingRuleAllowAll {
@@ -245,6 +301,11 @@ impl UserPrivacySettingRuleAllowChatMembers {
     RTDUserPrivacySettingRuleAllowChatMembersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleAllowChatMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
 
 }
@@ -257,6 +318,9 @@ pub struct RTDUserPrivacySettingRuleAllowChatMembersBuilder {
 impl RTDUserPrivacySettingRuleAllowChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowChatMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowChatMembers> { Ok(self.build()) }
+
    
   pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
@@ -311,6 +375,11 @@ impl UserPrivacySettingRuleAllowContacts {
     RTDUserPrivacySettingRuleAllowContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleAllowContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +390,9 @@ pub struct RTDUserPrivacySettingRuleAllowContactsBuilder {
 impl RTDUserPrivacySettingRuleAllowContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowContacts> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingRuleAllowContacts> for UserPrivacySettingRuleAllowContacts {
@@ -371,6 +443,11 @@ impl UserPrivacySettingRuleAllowUsers {
     RTDUserPrivacySettingRuleAllowUsersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleAllowUsers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
 
 }
@@ -383,6 +460,9 @@ pub struct RTDUserPrivacySettingRuleAllowUsersBuilder {
 impl RTDUserPrivacySettingRuleAllowUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleAllowUsers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleAllowUsers> { Ok(self.build()) }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
@@ -437,6 +517,11 @@ impl UserPrivacySettingRuleRestrictAll {
     RTDUserPrivacySettingRuleRestrictAllBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleRestrictAll", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -447,6 +532,9 @@ pub struct RTDUserPrivacySettingRuleRestrictAllBuilder {
 impl RTDUserPrivacySettingRuleRestrictAllBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictAll { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictAll> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictAll> for UserPrivacySettingRuleRestrictAll {
@@ -497,6 +585,11 @@ impl UserPrivacySettingRuleRestrictChatMembers {
     RTDUserPrivacySettingRuleRestrictChatMembersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleRestrictChatMembers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn chat_ids(&self) -> &Vec<i64> { &self.chat_ids }
 
 }
@@ -509,6 +602,9 @@ pub struct RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
 impl RTDUserPrivacySettingRuleRestrictChatMembersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictChatMembers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictChatMembers> { Ok(self.build()) }
+
    
   pub fn chat_ids(&mut self, chat_ids: Vec<i64>) -> &mut Self {
     self.inner.chat_ids = chat_ids;
@@ -563,6 +659,11 @@ impl UserPrivacySettingRuleRestrictContacts {
     RTDUserPrivacySettingRuleRestrictContactsBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleRestrictContacts", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -573,6 +674,9 @@ pub struct RTDUserPrivacySettingRuleRestrictContactsBuilder {
 impl RTDUserPrivacySettingRuleRestrictContactsBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictContacts { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictContacts> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserPrivacySettingRuleRestrictContacts> for UserPrivacySettingRuleRestrictContacts {
@@ -623,6 +727,11 @@ impl UserPrivacySettingRuleRestrictUsers {
     RTDUserPrivacySettingRuleRestrictUsersBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRuleRestrictUsers", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn user_ids(&self) -> &Vec<i64> { &self.user_ids }
 
 }
@@ -635,6 +744,9 @@ pub struct RTDUserPrivacySettingRuleRestrictUsersBuilder {
 impl RTDUserPrivacySettingRuleRestrictUsersBuilder {
   pub fn build(&self) -> UserPrivacySettingRuleRestrictUsers { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRuleRestrictUsers> { Ok(self.build()) }
+
    
   pub fn user_ids(&mut self, user_ids: Vec<i64>) -> &mut Self {
     self.inner.user_ids = user_ids;
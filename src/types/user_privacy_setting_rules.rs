@@ -37,6 +37,11 @@ impl UserPrivacySettingRules {
     RTDUserPrivacySettingRulesBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userPrivacySettingRules", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
   pub fn rules(&self) -> &Vec<UserPrivacySettingRule> { &self.rules }
 
 }
@@ -49,6 +54,9 @@ pub struct RTDUserPrivacySettingRulesBuilder {
 impl RTDUserPrivacySettingRulesBuilder {
   pub fn build(&self) -> UserPrivacySettingRules { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserPrivacySettingRules> { Ok(self.build()) }
+
    
   pub fn rules(&mut self, rules: Vec<UserPrivacySettingRule>) -> &mut Self {
     self.inner.rules = rules;
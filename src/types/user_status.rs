@@ -19,6 +19,9 @@ pub trait TDUserStatus: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserStatus {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// The user status was never changed
   Empty(UserStatusEmpty),
   /// The user is offline, but was online last month
@@ -40,7 +43,6 @@ impl Default for UserStatus {
 
 impl<'de> Deserialize<'de> for UserStatus {
   fn deserialize<D>(deserializer: D) -> Result<UserStatus, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       UserStatus,
       (userStatusEmpty, Empty);
@@ -75,6 +77,7 @@ impl RObject for UserStatus {
       UserStatus::Offline(t) => t.extra(),
       UserStatus::Online(t) => t.extra(),
       UserStatus::Recently(t) => t.extra(),
+      UserStatus::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -85,6 +88,17 @@ impl RObject for UserStatus {
 impl UserStatus {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserStatus::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      UserStatus::Empty(t) => t._missing_fields(),
+      UserStatus::LastMonth(t) => t._missing_fields(),
+      UserStatus::LastWeek(t) => t._missing_fields(),
+      UserStatus::Offline(t) => t._missing_fields(),
+      UserStatus::Online(t) => t._missing_fields(),
+      UserStatus::Recently(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_empty(&self) -> bool { if let UserStatus::Empty(_) = self { true } else { false } }
   pub fn is_last_month(&self) -> bool { if let UserStatus::LastMonth(_) = self { true } else { false } }
@@ -127,6 +141,30 @@ impl AsRef<UserStatus> for UserStatus {
   fn as_ref(&self) -> &UserStatus { self }
 }
 
+impl RVariant<UserStatus> for UserStatusEmpty {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::Empty(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserStatus> for UserStatusLastMonth {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::LastMonth(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserStatus> for UserStatusLastWeek {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::LastWeek(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserStatus> for UserStatusOffline {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::Offline(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserStatus> for UserStatusOnline {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::Online(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserStatus> for UserStatusRecently {
+  fn from_class(class: &UserStatus) -> Option<&Self> { match class { UserStatus::Recently(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -165,6 +203,11 @@ impl UserStatusEmpty {
     RTDUserStatusEmptyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusEmpty", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -175,6 +218,9 @@ pub struct RTDUserStatusEmptyBuilder {
 impl RTDUserStatusEmptyBuilder {
   pub fn build(&self) -> UserStatusEmpty { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusEmpty> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserStatusEmpty> for UserStatusEmpty {
@@ -223,6 +269,11 @@ impl UserStatusLastMonth {
     RTDUserStatusLastMonthBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusLastMonth", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -233,6 +284,9 @@ pub struct RTDUserStatusLastMonthBuilder {
 impl RTDUserStatusLastMonthBuilder {
   pub fn build(&self) -> UserStatusLastMonth { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusLastMonth> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserStatusLastMonth> for UserStatusLastMonth {
@@ -281,6 +335,11 @@ impl UserStatusLastWeek {
     RTDUserStatusLastWeekBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusLastWeek", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -291,6 +350,9 @@ pub struct RTDUserStatusLastWeekBuilder {
 impl RTDUserStatusLastWeekBuilder {
   pub fn build(&self) -> UserStatusLastWeek { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusLastWeek> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserStatusLastWeek> for UserStatusLastWeek {
@@ -338,7 +400,16 @@ impl UserStatusOffline {
     let mut inner = UserStatusOffline::default();
     inner.td_name = "userStatusOffline".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUserStatusOfflineBuilder { inner }
+    RTDUserStatusOfflineBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusOffline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.was_online == 0 { missing.push("was_online".to_string()); }
+    missing
   }
 
   pub fn was_online(&self) -> i64 { self.was_online }
@@ -347,15 +418,25 @@ impl UserStatusOffline {
 
 #[doc(hidden)]
 pub struct RTDUserStatusOfflineBuilder {
-  inner: UserStatusOffline
+  inner: UserStatusOffline,
+  set: [bool; 1]
 }
 
 impl RTDUserStatusOfflineBuilder {
   pub fn build(&self) -> UserStatusOffline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusOffline> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("was_online".to_string()); }
+    crate::types::_common::check_missing("userStatusOffline", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn was_online(&mut self, was_online: i64) -> &mut Self {
     self.inner.was_online = was_online;
+    self.set[0] = true;
     self
   }
 
@@ -406,7 +487,16 @@ impl UserStatusOnline {
     let mut inner = UserStatusOnline::default();
     inner.td_name = "userStatusOnline".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUserStatusOnlineBuilder { inner }
+    RTDUserStatusOnlineBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusOnline", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.expires == 0 { missing.push("expires".to_string()); }
+    missing
   }
 
   pub fn expires(&self) -> i64 { self.expires }
@@ -415,15 +505,25 @@ impl UserStatusOnline {
 
 #[doc(hidden)]
 pub struct RTDUserStatusOnlineBuilder {
-  inner: UserStatusOnline
+  inner: UserStatusOnline,
+  set: [bool; 1]
 }
 
 impl RTDUserStatusOnlineBuilder {
   pub fn build(&self) -> UserStatusOnline { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusOnline> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("expires".to_string()); }
+    crate::types::_common::check_missing("userStatusOnline", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn expires(&mut self, expires: i64) -> &mut Self {
     self.inner.expires = expires;
+    self.set[0] = true;
     self
   }
 
@@ -475,6 +575,11 @@ impl UserStatusRecently {
     RTDUserStatusRecentlyBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userStatusRecently", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -485,6 +590,9 @@ pub struct RTDUserStatusRecentlyBuilder {
 impl RTDUserStatusRecentlyBuilder {
   pub fn build(&self) -> UserStatusRecently { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserStatusRecently> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserStatusRecently> for UserStatusRecently {
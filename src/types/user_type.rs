@@ -19,6 +19,9 @@ pub trait TDUserType: Debug + RObject {}
 #[serde(untagged)]
 pub enum UserType {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A bot (see https://core.telegram.org/bots)
   Bot(UserTypeBot),
   /// A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user
@@ -26,7 +29,7 @@ pub enum UserType {
   /// A regular user
   Regular(UserTypeRegular),
   /// No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type
-  Unknown(UserTypeUnknown),
+  UserTypeUnknown(UserTypeUnknown),
 
 }
 
@@ -36,13 +39,12 @@ impl Default for UserType {
 
 impl<'de> Deserialize<'de> for UserType {
   fn deserialize<D>(deserializer: D) -> Result<UserType, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       UserType,
       (userTypeBot, Bot);
       (userTypeDeleted, Deleted);
       (userTypeRegular, Regular);
-      (userTypeUnknown, Unknown);
+      (userTypeUnknown, UserTypeUnknown);
 
     )(deserializer)
   }
@@ -54,7 +56,7 @@ impl RObject for UserType {
       UserType::Bot(t) => t.td_name(),
       UserType::Deleted(t) => t.td_name(),
       UserType::Regular(t) => t.td_name(),
-      UserType::Unknown(t) => t.td_name(),
+      UserType::UserTypeUnknown(t) => t.td_name(),
 
       _ => "-1",
     }
@@ -64,7 +66,8 @@ impl RObject for UserType {
       UserType::Bot(t) => t.extra(),
       UserType::Deleted(t) => t.extra(),
       UserType::Regular(t) => t.extra(),
-      UserType::Unknown(t) => t.extra(),
+      UserType::UserTypeUnknown(t) => t.extra(),
+      UserType::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -75,21 +78,30 @@ impl RObject for UserType {
 impl UserType {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let UserType::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      UserType::Bot(t) => t._missing_fields(),
+      UserType::Deleted(t) => t._missing_fields(),
+      UserType::Regular(t) => t._missing_fields(),
+      UserType::UserTypeUnknown(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_bot(&self) -> bool { if let UserType::Bot(_) = self { true } else { false } }
   pub fn is_deleted(&self) -> bool { if let UserType::Deleted(_) = self { true } else { false } }
   pub fn is_regular(&self) -> bool { if let UserType::Regular(_) = self { true } else { false } }
-  pub fn is_unknown(&self) -> bool { if let UserType::Unknown(_) = self { true } else { false } }
+  pub fn is_user_type_unknown(&self) -> bool { if let UserType::UserTypeUnknown(_) = self { true } else { false } }
 
   pub fn on_bot<F: FnOnce(&UserTypeBot)>(&self, fnc: F) -> &Self { if let UserType::Bot(t) = self { fnc(t) }; self }
   pub fn on_deleted<F: FnOnce(&UserTypeDeleted)>(&self, fnc: F) -> &Self { if let UserType::Deleted(t) = self { fnc(t) }; self }
   pub fn on_regular<F: FnOnce(&UserTypeRegular)>(&self, fnc: F) -> &Self { if let UserType::Regular(t) = self { fnc(t) }; self }
-  pub fn on_unknown<F: FnOnce(&UserTypeUnknown)>(&self, fnc: F) -> &Self { if let UserType::Unknown(t) = self { fnc(t) }; self }
+  pub fn on_user_type_unknown<F: FnOnce(&UserTypeUnknown)>(&self, fnc: F) -> &Self { if let UserType::UserTypeUnknown(t) = self { fnc(t) }; self }
 
   pub fn as_bot(&self) -> Option<&UserTypeBot> { if let UserType::Bot(t) = self { return Some(t) } None }
   pub fn as_deleted(&self) -> Option<&UserTypeDeleted> { if let UserType::Deleted(t) = self { return Some(t) } None }
   pub fn as_regular(&self) -> Option<&UserTypeRegular> { if let UserType::Regular(t) = self { return Some(t) } None }
-  pub fn as_unknown(&self) -> Option<&UserTypeUnknown> { if let UserType::Unknown(t) = self { return Some(t) } None }
+  pub fn as_user_type_unknown(&self) -> Option<&UserTypeUnknown> { if let UserType::UserTypeUnknown(t) = self { return Some(t) } None }
 
 
 
@@ -99,7 +111,7 @@ impl UserType {
 
   pub fn regular<T: AsRef<UserTypeRegular>>(t: T) -> Self { UserType::Regular(t.as_ref().clone()) }
 
-  pub fn unknown<T: AsRef<UserTypeUnknown>>(t: T) -> Self { UserType::Unknown(t.as_ref().clone()) }
+  pub fn user_type_unknown<T: AsRef<UserTypeUnknown>>(t: T) -> Self { UserType::UserTypeUnknown(t.as_ref().clone()) }
 
 }
 
@@ -107,6 +119,22 @@ impl AsRef<UserType> for UserType {
   fn as_ref(&self) -> &UserType { self }
 }
 
+impl RVariant<UserType> for UserTypeBot {
+  fn from_class(class: &UserType) -> Option<&Self> { match class { UserType::Bot(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserType> for UserTypeDeleted {
+  fn from_class(class: &UserType) -> Option<&Self> { match class { UserType::Deleted(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserType> for UserTypeRegular {
+  fn from_class(class: &UserType) -> Option<&Self> { match class { UserType::Regular(t) => Some(t), _ => None } }
+}
+
+impl RVariant<UserType> for UserTypeUnknown {
+  fn from_class(class: &UserType) -> Option<&Self> { match class { UserType::UserTypeUnknown(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -152,7 +180,16 @@ impl UserTypeBot {
     let mut inner = UserTypeBot::default();
     inner.td_name = "userTypeBot".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUserTypeBotBuilder { inner }
+    RTDUserTypeBotBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userTypeBot", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.inline_query_placeholder.is_empty() { missing.push("inline_query_placeholder".to_string()); }
+    missing
   }
 
   pub fn can_join_groups(&self) -> bool { self.can_join_groups }
@@ -169,12 +206,21 @@ impl UserTypeBot {
 
 #[doc(hidden)]
 pub struct RTDUserTypeBotBuilder {
-  inner: UserTypeBot
+  inner: UserTypeBot,
+  set: [bool; 1]
 }
 
 impl RTDUserTypeBotBuilder {
   pub fn build(&self) -> UserTypeBot { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserTypeBot> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("inline_query_placeholder".to_string()); }
+    crate::types::_common::check_missing("userTypeBot", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn can_join_groups(&mut self, can_join_groups: bool) -> &mut Self {
     self.inner.can_join_groups = can_join_groups;
@@ -196,6 +242,7 @@ impl RTDUserTypeBotBuilder {
    
   pub fn inline_query_placeholder<T: AsRef<str>>(&mut self, inline_query_placeholder: T) -> &mut Self {
     self.inner.inline_query_placeholder = inline_query_placeholder.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -253,6 +300,11 @@ impl UserTypeDeleted {
     RTDUserTypeDeletedBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userTypeDeleted", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -263,6 +315,9 @@ pub struct RTDUserTypeDeletedBuilder {
 impl RTDUserTypeDeletedBuilder {
   pub fn build(&self) -> UserTypeDeleted { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserTypeDeleted> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserTypeDeleted> for UserTypeDeleted {
@@ -311,6 +366,11 @@ impl UserTypeRegular {
     RTDUserTypeRegularBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userTypeRegular", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -321,6 +381,9 @@ pub struct RTDUserTypeRegularBuilder {
 impl RTDUserTypeRegularBuilder {
   pub fn build(&self) -> UserTypeRegular { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserTypeRegular> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserTypeRegular> for UserTypeRegular {
@@ -369,6 +432,11 @@ impl UserTypeUnknown {
     RTDUserTypeUnknownBuilder { inner }
   }
 
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("userTypeUnknown", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }
+
 }
 
 #[doc(hidden)]
@@ -379,6 +447,9 @@ pub struct RTDUserTypeUnknownBuilder {
 impl RTDUserTypeUnknownBuilder {
   pub fn build(&self) -> UserTypeUnknown { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<UserTypeUnknown> { Ok(self.build()) }
+
 }
 
 impl AsRef<UserTypeUnknown> for UserTypeUnknown {
@@ -36,7 +36,16 @@ impl Users {
     let mut inner = Users::default();
     inner.td_name = "users".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDUsersBuilder { inner }
+    RTDUsersBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("users", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.total_count == 0 { missing.push("total_count".to_string()); }
+    missing
   }
 
   pub fn total_count(&self) -> i64 { self.total_count }
@@ -47,15 +56,25 @@ impl Users {
 
 #[doc(hidden)]
 pub struct RTDUsersBuilder {
-  inner: Users
+  inner: Users,
+  set: [bool; 1]
 }
 
 impl RTDUsersBuilder {
   pub fn build(&self) -> Users { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Users> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("total_count".to_string()); }
+    crate::types::_common::check_missing("users", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn total_count(&mut self, total_count: i64) -> &mut Self {
     self.inner.total_count = total_count;
+    self.set[0] = true;
     self
   }
 
@@ -36,7 +36,16 @@ impl ValidatedOrderInfo {
     let mut inner = ValidatedOrderInfo::default();
     inner.td_name = "validatedOrderInfo".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDValidatedOrderInfoBuilder { inner }
+    RTDValidatedOrderInfoBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("validatedOrderInfo", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.order_info_id.is_empty() { missing.push("order_info_id".to_string()); }
+    missing
   }
 
   pub fn order_info_id(&self) -> &String { &self.order_info_id }
@@ -47,15 +56,25 @@ impl ValidatedOrderInfo {
 
 #[doc(hidden)]
 pub struct RTDValidatedOrderInfoBuilder {
-  inner: ValidatedOrderInfo
+  inner: ValidatedOrderInfo,
+  set: [bool; 1]
 }
 
 impl RTDValidatedOrderInfoBuilder {
   pub fn build(&self) -> ValidatedOrderInfo { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<ValidatedOrderInfo> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("order_info_id".to_string()); }
+    crate::types::_common::check_missing("validatedOrderInfo", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn order_info_id<T: AsRef<str>>(&mut self, order_info_id: T) -> &mut Self {
     self.inner.order_info_id = order_info_id.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
@@ -19,6 +19,9 @@ pub trait TDVectorPathCommand: Debug + RObject {}
 #[serde(untagged)]
 pub enum VectorPathCommand {
   #[doc(hidden)] _Default(()),
+  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = "crate::types::_common::serialize_unknown")]
+  Unknown { td_type: String, raw: serde_json::Value },
   /// A cubic Bézier curve to a given point
   CubicBezierCurve(VectorPathCommandCubicBezierCurve),
   /// A straight line to a given point
@@ -32,7 +35,6 @@ impl Default for VectorPathCommand {
 
 impl<'de> Deserialize<'de> for VectorPathCommand {
   fn deserialize<D>(deserializer: D) -> Result<VectorPathCommand, D::Error> where D: Deserializer<'de> {
-    use serde::de::Error;
     rtd_enum_deserialize!(
       VectorPathCommand,
       (vectorPathCommandCubicBezierCurve, CubicBezierCurve);
@@ -55,6 +57,7 @@ impl RObject for VectorPathCommand {
     match self {
       VectorPathCommand::CubicBezierCurve(t) => t.extra(),
       VectorPathCommand::Line(t) => t.extra(),
+      VectorPathCommand::Unknown { raw, .. } => raw.get("@extra").and_then(|e| e.as_str()).map(|e| e.to_string()),
 
       _ => None,
     }
@@ -65,6 +68,13 @@ impl RObject for VectorPathCommand {
 impl VectorPathCommand {
   pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }
   #[doc(hidden)] pub fn _is_default(&self) -> bool { if let VectorPathCommand::_Default(_) = self { true } else { false } }
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    match self {
+      VectorPathCommand::CubicBezierCurve(t) => t._missing_fields(),
+      VectorPathCommand::Line(t) => t._missing_fields(),
+      _ => vec![],
+    }
+  }
 
   pub fn is_cubic_bezier_curve(&self) -> bool { if let VectorPathCommand::CubicBezierCurve(_) = self { true } else { false } }
   pub fn is_line(&self) -> bool { if let VectorPathCommand::Line(_) = self { true } else { false } }
@@ -87,6 +97,14 @@ impl AsRef<VectorPathCommand> for VectorPathCommand {
   fn as_ref(&self) -> &VectorPathCommand { self }
 }
 
+impl RVariant<VectorPathCommand> for VectorPathCommandCubicBezierCurve {
+  fn from_class(class: &VectorPathCommand) -> Option<&Self> { match class { VectorPathCommand::CubicBezierCurve(t) => Some(t), _ => None } }
+}
+
+impl RVariant<VectorPathCommand> for VectorPathCommandLine {
+  fn from_class(class: &VectorPathCommand) -> Option<&Self> { match class { VectorPathCommand::Line(t) => Some(t), _ => None } }
+}
+
 
 
 
@@ -128,7 +146,18 @@ impl VectorPathCommandCubicBezierCurve {
     let mut inner = VectorPathCommandCubicBezierCurve::default();
     inner.td_name = "vectorPathCommandCubicBezierCurve".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDVectorPathCommandCubicBezierCurveBuilder { inner }
+    RTDVectorPathCommandCubicBezierCurveBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("vectorPathCommandCubicBezierCurve", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("start_control_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("start_control_point", self.start_control_point._missing_fields())); }
+    if false { missing.push("end_control_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_control_point", self.end_control_point._missing_fields())); }
+    if false { missing.push("end_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_point", self.end_point._missing_fields())); }
+    missing
   }
 
   pub fn start_control_point(&self) -> &Point { &self.start_control_point }
@@ -141,27 +170,41 @@ impl VectorPathCommandCubicBezierCurve {
 
 #[doc(hidden)]
 pub struct RTDVectorPathCommandCubicBezierCurveBuilder {
-  inner: VectorPathCommandCubicBezierCurve
+  inner: VectorPathCommandCubicBezierCurve,
+  set: [bool; 3]
 }
 
 impl RTDVectorPathCommandCubicBezierCurveBuilder {
   pub fn build(&self) -> VectorPathCommandCubicBezierCurve { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<VectorPathCommandCubicBezierCurve> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("start_control_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("start_control_point", self.inner.start_control_point._missing_fields())); }
+    if !self.set[1] { missing.push("end_control_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_control_point", self.inner.end_control_point._missing_fields())); }
+    if !self.set[2] { missing.push("end_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_point", self.inner.end_point._missing_fields())); }
+    crate::types::_common::check_missing("vectorPathCommandCubicBezierCurve", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn start_control_point<T: AsRef<Point>>(&mut self, start_control_point: T) -> &mut Self {
     self.inner.start_control_point = start_control_point.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn end_control_point<T: AsRef<Point>>(&mut self, end_control_point: T) -> &mut Self {
     self.inner.end_control_point = end_control_point.as_ref().clone();
+    self.set[1] = true;
     self
   }
 
    
   pub fn end_point<T: AsRef<Point>>(&mut self, end_point: T) -> &mut Self {
     self.inner.end_point = end_point.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
@@ -212,7 +255,16 @@ impl VectorPathCommandLine {
     let mut inner = VectorPathCommandLine::default();
     inner.td_name = "vectorPathCommandLine".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDVectorPathCommandLineBuilder { inner }
+    RTDVectorPathCommandLineBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("vectorPathCommandLine", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("end_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_point", self.end_point._missing_fields())); }
+    missing
   }
 
   pub fn end_point(&self) -> &Point { &self.end_point }
@@ -221,15 +273,25 @@ impl VectorPathCommandLine {
 
 #[doc(hidden)]
 pub struct RTDVectorPathCommandLineBuilder {
-  inner: VectorPathCommandLine
+  inner: VectorPathCommandLine,
+  set: [bool; 1]
 }
 
 impl RTDVectorPathCommandLineBuilder {
   pub fn build(&self) -> VectorPathCommandLine { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<VectorPathCommandLine> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("end_point".to_string()); } else { missing.extend(crate::types::_common::missing_in("end_point", self.inner.end_point._missing_fields())); }
+    crate::types::_common::check_missing("vectorPathCommandLine", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn end_point<T: AsRef<Point>>(&mut self, end_point: T) -> &mut Self {
     self.inner.end_point = end_point.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
@@ -44,7 +44,21 @@ impl Venue {
     let mut inner = Venue::default();
     inner.td_name = "venue".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDVenueBuilder { inner }
+    RTDVenueBuilder { inner, set: [false; 6] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("venue", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if false { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.location._missing_fields())); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if self.address.is_empty() { missing.push("address".to_string()); }
+    if self.provider.is_empty() { missing.push("provider".to_string()); }
+    if self.id.is_empty() { missing.push("id".to_string()); }
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    missing
   }
 
   pub fn location(&self) -> &Location { &self.location }
@@ -63,45 +77,65 @@ impl Venue {
 
 #[doc(hidden)]
 pub struct RTDVenueBuilder {
-  inner: Venue
+  inner: Venue,
+  set: [bool; 6]
 }
 
 impl RTDVenueBuilder {
   pub fn build(&self) -> Venue { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<Venue> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("location".to_string()); } else { missing.extend(crate::types::_common::missing_in("location", self.inner.location._missing_fields())); }
+    if !self.set[1] { missing.push("title".to_string()); }
+    if !self.set[2] { missing.push("address".to_string()); }
+    if !self.set[3] { missing.push("provider".to_string()); }
+    if !self.set[4] { missing.push("id".to_string()); }
+    if !self.set[5] { missing.push("type".to_string()); }
+    crate::types::_common::check_missing("venue", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn location<T: AsRef<Location>>(&mut self, location: T) -> &mut Self {
     self.inner.location = location.as_ref().clone();
+    self.set[0] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn address<T: AsRef<str>>(&mut self, address: T) -> &mut Self {
     self.inner.address = address.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn provider<T: AsRef<str>>(&mut self, provider: T) -> &mut Self {
     self.inner.provider = provider.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn id<T: AsRef<str>>(&mut self, id: T) -> &mut Self {
     self.inner.id = id.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[5] = true;
     self
   }
 
@@ -42,7 +42,18 @@ impl VideoNote {
     let mut inner = VideoNote::default();
     inner.td_name = "videoNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDVideoNoteBuilder { inner }
+    RTDVideoNoteBuilder { inner, set: [false; 3] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("videoNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.length == 0 { missing.push("length".to_string()); }
+    if false { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.video._missing_fields())); }
+    missing
   }
 
   pub fn duration(&self) -> i64 { self.duration }
@@ -59,21 +70,34 @@ impl VideoNote {
 
 #[doc(hidden)]
 pub struct RTDVideoNoteBuilder {
-  inner: VideoNote
+  inner: VideoNote,
+  set: [bool; 3]
 }
 
 impl RTDVideoNoteBuilder {
   pub fn build(&self) -> VideoNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<VideoNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("duration".to_string()); }
+    if !self.set[1] { missing.push("length".to_string()); }
+    if !self.set[2] { missing.push("video".to_string()); } else { missing.extend(crate::types::_common::missing_in("video", self.inner.video._missing_fields())); }
+    crate::types::_common::check_missing("videoNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
    
   pub fn length(&mut self, length: i64) -> &mut Self {
     self.inner.length = length;
+    self.set[1] = true;
     self
   }
 
@@ -92,6 +116,7 @@ impl RTDVideoNoteBuilder {
    
   pub fn video<T: AsRef<File>>(&mut self, video: T) -> &mut Self {
     self.inner.video = video.as_ref().clone();
+    self.set[2] = true;
     self
   }
 
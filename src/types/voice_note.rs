@@ -40,7 +40,19 @@ impl VoiceNote {
     let mut inner = VoiceNote::default();
     inner.td_name = "voiceNote".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDVoiceNoteBuilder { inner }
+    RTDVoiceNoteBuilder { inner, set: [false; 4] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("voiceNote", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.waveform.is_empty() { missing.push("waveform".to_string()); }
+    if self.mime_type.is_empty() { missing.push("mime_type".to_string()); }
+    if false { missing.push("voice".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice", self.voice._missing_fields())); }
+    missing
   }
 
   pub fn duration(&self) -> i64 { self.duration }
@@ -55,33 +67,49 @@ impl VoiceNote {
 
 #[doc(hidden)]
 pub struct RTDVoiceNoteBuilder {
-  inner: VoiceNote
+  inner: VoiceNote,
+  set: [bool; 4]
 }
 
 impl RTDVoiceNoteBuilder {
   pub fn build(&self) -> VoiceNote { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<VoiceNote> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("duration".to_string()); }
+    if !self.set[1] { missing.push("waveform".to_string()); }
+    if !self.set[2] { missing.push("mime_type".to_string()); }
+    if !self.set[3] { missing.push("voice".to_string()); } else { missing.extend(crate::types::_common::missing_in("voice", self.inner.voice._missing_fields())); }
+    crate::types::_common::check_missing("voiceNote", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[0] = true;
     self
   }
 
    
   pub fn waveform<T: AsRef<str>>(&mut self, waveform: T) -> &mut Self {
     self.inner.waveform = waveform.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn mime_type<T: AsRef<str>>(&mut self, mime_type: T) -> &mut Self {
     self.inner.mime_type = mime_type.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn voice<T: AsRef<File>>(&mut self, voice: T) -> &mut Self {
     self.inner.voice = voice.as_ref().clone();
+    self.set[3] = true;
     self
   }
 
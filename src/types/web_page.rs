@@ -74,7 +74,27 @@ impl WebPage {
     let mut inner = WebPage::default();
     inner.td_name = "webPage".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDWebPageBuilder { inner }
+    RTDWebPageBuilder { inner, set: [false; 12] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("webPage", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.url.is_empty() { missing.push("url".to_string()); }
+    if self.display_url.is_empty() { missing.push("display_url".to_string()); }
+    if self.type_.is_empty() { missing.push("type".to_string()); }
+    if self.site_name.is_empty() { missing.push("site_name".to_string()); }
+    if self.title.is_empty() { missing.push("title".to_string()); }
+    if false { missing.push("description".to_string()); } else { missing.extend(crate::types::_common::missing_in("description", self.description._missing_fields())); }
+    if self.embed_url.is_empty() { missing.push("embed_url".to_string()); }
+    if self.embed_type.is_empty() { missing.push("embed_type".to_string()); }
+    if self.embed_width == 0 { missing.push("embed_width".to_string()); }
+    if self.embed_height == 0 { missing.push("embed_height".to_string()); }
+    if self.duration == 0 { missing.push("duration".to_string()); }
+    if self.author.is_empty() { missing.push("author".to_string()); }
+    missing
   }
 
   pub fn url(&self) -> &String { &self.url }
@@ -123,45 +143,71 @@ impl WebPage {
 
 #[doc(hidden)]
 pub struct RTDWebPageBuilder {
-  inner: WebPage
+  inner: WebPage,
+  set: [bool; 12]
 }
 
 impl RTDWebPageBuilder {
   pub fn build(&self) -> WebPage { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<WebPage> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("url".to_string()); }
+    if !self.set[1] { missing.push("display_url".to_string()); }
+    if !self.set[2] { missing.push("type".to_string()); }
+    if !self.set[3] { missing.push("site_name".to_string()); }
+    if !self.set[4] { missing.push("title".to_string()); }
+    if !self.set[5] { missing.push("description".to_string()); } else { missing.extend(crate::types::_common::missing_in("description", self.inner.description._missing_fields())); }
+    if !self.set[6] { missing.push("embed_url".to_string()); }
+    if !self.set[7] { missing.push("embed_type".to_string()); }
+    if !self.set[8] { missing.push("embed_width".to_string()); }
+    if !self.set[9] { missing.push("embed_height".to_string()); }
+    if !self.set[10] { missing.push("duration".to_string()); }
+    if !self.set[11] { missing.push("author".to_string()); }
+    crate::types::_common::check_missing("webPage", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn url<T: AsRef<str>>(&mut self, url: T) -> &mut Self {
     self.inner.url = url.as_ref().to_string();
+    self.set[0] = true;
     self
   }
 
    
   pub fn display_url<T: AsRef<str>>(&mut self, display_url: T) -> &mut Self {
     self.inner.display_url = display_url.as_ref().to_string();
+    self.set[1] = true;
     self
   }
 
    
   pub fn type_<T: AsRef<str>>(&mut self, type_: T) -> &mut Self {
     self.inner.type_ = type_.as_ref().to_string();
+    self.set[2] = true;
     self
   }
 
    
   pub fn site_name<T: AsRef<str>>(&mut self, site_name: T) -> &mut Self {
     self.inner.site_name = site_name.as_ref().to_string();
+    self.set[3] = true;
     self
   }
 
    
   pub fn title<T: AsRef<str>>(&mut self, title: T) -> &mut Self {
     self.inner.title = title.as_ref().to_string();
+    self.set[4] = true;
     self
   }
 
    
   pub fn description<T: AsRef<FormattedText>>(&mut self, description: T) -> &mut Self {
     self.inner.description = description.as_ref().clone();
+    self.set[5] = true;
     self
   }
 
@@ -174,36 +220,42 @@ impl RTDWebPageBuilder {
    
   pub fn embed_url<T: AsRef<str>>(&mut self, embed_url: T) -> &mut Self {
     self.inner.embed_url = embed_url.as_ref().to_string();
+    self.set[6] = true;
     self
   }
 
    
   pub fn embed_type<T: AsRef<str>>(&mut self, embed_type: T) -> &mut Self {
     self.inner.embed_type = embed_type.as_ref().to_string();
+    self.set[7] = true;
     self
   }
 
    
   pub fn embed_width(&mut self, embed_width: i64) -> &mut Self {
     self.inner.embed_width = embed_width;
+    self.set[8] = true;
     self
   }
 
    
   pub fn embed_height(&mut self, embed_height: i64) -> &mut Self {
     self.inner.embed_height = embed_height;
+    self.set[9] = true;
     self
   }
 
    
   pub fn duration(&mut self, duration: i64) -> &mut Self {
     self.inner.duration = duration;
+    self.set[10] = true;
     self
   }
 
    
   pub fn author<T: AsRef<str>>(&mut self, author: T) -> &mut Self {
     self.inner.author = author.as_ref().to_string();
+    self.set[11] = true;
     self
   }
 
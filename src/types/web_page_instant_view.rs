@@ -42,7 +42,16 @@ impl WebPageInstantView {
     let mut inner = WebPageInstantView::default();
     inner.td_name = "webPageInstantView".to_string();
     inner.extra = Some(Uuid::new_v4().to_string());
-    RTDWebPageInstantViewBuilder { inner }
+    RTDWebPageInstantViewBuilder { inner, set: [false; 1] }
+  }
+
+  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside
+  pub fn validate(&self) -> RTDResult<()> { crate::types::_common::check_missing("webPageInstantView", self._missing_fields()) }
+
+  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {
+    let mut missing = vec![];
+    if self.version == 0 { missing.push("version".to_string()); }
+    missing
   }
 
   pub fn page_blocks(&self) -> &Vec<PageBlock> { &self.page_blocks }
@@ -59,12 +68,21 @@ impl WebPageInstantView {
 
 #[doc(hidden)]
 pub struct RTDWebPageInstantViewBuilder {
-  inner: WebPageInstantView
+  inner: WebPageInstantView,
+  set: [bool; 1]
 }
 
 impl RTDWebPageInstantViewBuilder {
   pub fn build(&self) -> WebPageInstantView { self.inner.clone() }
 
+  /// Build, or fail with every required field which was not set, and those of the objects inside
+  pub fn try_build(&self) -> RTDResult<WebPageInstantView> {
+    let mut missing = vec![];
+    if !self.set[0] { missing.push("version".to_string()); }
+    crate::types::_common::check_missing("webPageInstantView", missing)?;
+    Ok(self.build())
+  }
+
    
   pub fn page_blocks(&mut self, page_blocks: Vec<PageBlock>) -> &mut Self {
     self.inner.page_blocks = page_blocks;
@@ -80,6 +98,7 @@ impl RTDWebPageInstantViewBuilder {
    
   pub fn version(&mut self, version: i64) -> &mut Self {
     self.inner.version = version;
+    self.set[0] = true;
     self
   }
 
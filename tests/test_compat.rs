@@ -0,0 +1,68 @@
+use rtdlib::compat::*;
+use rtdlib::errors::*;
+use rtdlib::types::*;
+
+/// The objects of the `version` fixture by case
+fn fixtures(version: TdVersion) -> Vec<(String, serde_json::Value)> {
+  let jsonl = match version.to_string().as_str() {
+    "1.6.0" => include_str!("fixtures/compat/1.6.0.jsonl"),
+    "1.7.0" => include_str!("fixtures/compat/1.7.0.jsonl"),
+    "1.8.0" => include_str!("fixtures/compat/1.8.0.jsonl"),
+    version => panic!("No fixtures of {}", version),
+  };
+  jsonl.lines()
+    .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+    .map(|line| (line["case"].as_str().unwrap().to_string(), line["object"].clone()))
+    .collect()
+}
+
+/// Parse `object` with the type of its `@type`
+fn parse(object: &serde_json::Value) -> RTDResult<()> {
+  let json = object.to_string();
+  match object["@type"].as_str().unwrap() {
+    "chatMember" => ChatMember::from_json(json).map(|_| ()),
+    "getChatMember" => GetChatMember::from_json(json).map(|_| ()),
+    "sendChatAction" => SendChatAction::from_json(json).map(|_| ()),
+    _ => match Update::from_json(json)? {
+      Update::Unknown { td_type, .. } => panic!("Unknown update {}", td_type),
+      _ => Ok(()),
+    },
+  }
+}
+
+#[test]
+fn test_schema_fixtures_parse() {
+  for (case, object) in fixtures(SCHEMA_VERSION) {
+    assert!(parse(&object).is_ok(), "{}", case);
+  }
+}
+
+#[test]
+fn test_adjacent_versions() {
+  let schema = fixtures(SCHEMA_VERSION);
+  for version in [TdVersion(1, 6, 0), TdVersion(1, 8, 0)] {
+    let fixtures = fixtures(version);
+    assert_eq!(fixtures.len(), schema.len());
+    for ((case, object), (_, expected)) in fixtures.into_iter().zip(&schema) {
+      let mut translated = object.clone();
+      translate(&mut translated, version, SCHEMA_VERSION);
+      assert_eq!(&translated, expected, "{} from {}", case, version);
+      assert!(parse(&translated).is_ok(), "{} from {}", case, version);
+
+      let mut translated = expected.clone();
+      translate(&mut translated, SCHEMA_VERSION, version);
+      assert_eq!(translated, object, "{} to {}", case, version);
+    }
+  }
+}
+
+#[test]
+fn test_sender_of_older_version() {
+  let message = fixtures(TdVersion(1, 6, 0)).into_iter().find(|(case, _)| case == "channel_post").unwrap().1;
+  let json = translate_json(&message.to_string(), TdVersion(1, 6, 0), SCHEMA_VERSION);
+  let update = match Update::from_json(json).unwrap() {
+    Update::NewMessage(update) => update,
+    update => panic!("{:?}", update),
+  };
+  assert!(matches!(update.message().sender(), MessageSender::Chat(sender) if sender.chat_id() == -1001));
+}
@@ -160,3 +160,183 @@ fn test_json_td_type() {
 
 }
 
+
+#[test]
+fn test_parse_response() {
+  let json_user = r#"{"@type":"user","id":743550508,"first_name":"Jo","last_name":"ho","username":"laocaimi","phone_number":"","status":{"@type":"userStatusEmpty"},"is_contact":false,"is_mutual_contact":false,"is_verified":false,"is_support":false,"restriction_reason":"","is_scam":false,"is_fake":false,"have_access":true,"type":{"@type":"userTypeRegular"},"language_code":"","@extra":"b51afb43-ea2a-45be-afa5-3957482206b3"}"#;
+  let user = rtdlib::types::parse_response::<GetMe, _>(json_user).expect("Json fail").expect("Td error");
+  assert_eq!(user.id(), 743550508);
+  assert_eq!(user.first_name(), &"Jo".to_string());
+
+  let json_error = r#"{"@type":"error","code":400,"message":"Chat not found","@extra":"b51afb43-ea2a-45be-afa5-3957482206b3"}"#;
+  let error = rtdlib::types::parse_response::<GetChat, _>(json_error).expect("Json fail").expect_err("Not td error");
+  assert_eq!(error.code(), 400);
+
+  let json_ok = r#"{"@type":"ok","@extra":"b51afb43-ea2a-45be-afa5-3957482206b3"}"#;
+  let ok = rtdlib::types::parse_response::<DeleteMessages, _>(json_ok).expect("Json fail");
+  assert!(ok.is_ok());
+}
+
+#[test]
+fn test_int64_string() {
+  let json = r#"{"@type":"session","id":"-5341628974238512345","is_current":true,"is_password_pending":false,"api_id":6,"application_name":"rtdlib","application_version":"0.7.0","is_official_application":false,"device_model":"pc","platform":"linux","system_version":"","log_in_date":1600000000,"last_active_date":1600000000,"ip":"127.0.0.1","country":"","region":""}"#;
+  let session = Session::from_json(json).expect("Json fail");
+  assert_eq!(session.id(), -5341628974238512345);
+  assert!(session.to_json().unwrap().contains(r#""id":"-5341628974238512345""#));
+
+  let number = Session::from_json(json.replace(r#""-5341628974238512345""#, "12")).expect("Json fail");
+  assert_eq!(number.id(), 12);
+
+  let fnc = GetAttachedStickerSets::builder().file_id(1).build();
+  assert!(fnc.to_json().unwrap().contains(r#""file_id":1"#));
+  let fnc = ReorderInstalledStickerSets::builder().sticker_set_ids(vec![1, 9223372036854775807]).build();
+  assert!(fnc.to_json().unwrap().contains(r#""sticker_set_ids":["1","9223372036854775807"]"#));
+}
+
+#[test]
+fn test_unknown_type() {
+  let json = r#"{"@type":"messageFutureContent","@extra":"b51afb43-ea2a-45be-afa5-3957482206b3","text":"hi"}"#;
+  let content = MessageContent::from_json(json).expect("Json fail");
+  match &content {
+    MessageContent::Unknown { td_type, raw } => {
+      assert_eq!(td_type, "messageFutureContent");
+      assert_eq!(raw["text"], "hi");
+    }
+    _ => panic!("not unknown: {:?}", content),
+  }
+  assert_eq!(content.extra(), Some("b51afb43-ea2a-45be-afa5-3957482206b3".to_string()));
+  let value: serde_json::Value = serde_json::from_str(&content.to_json().unwrap()).unwrap();
+  assert_eq!(value, serde_json::from_str::<serde_json::Value>(json).unwrap());
+
+  let update = r#"{"@type":"updateMessageContent","chat_id":1,"message_id":2,"new_content":{"@type":"messageFutureContent","text":"hi"}}"#;
+  let update = UpdateMessageContent::from_json(update).expect("Json fail");
+  assert!(update.to_json().unwrap().contains(r#""new_content":{"@type":"messageFutureContent","text":"hi"}"#));
+
+  let file_type = FileType::from_json(r#"{"@type":"fileTypeUnknown"}"#).expect("Json fail");
+  assert!(file_type.is_file_type_unknown());
+}
+
+#[test]
+fn test_td_type_dispatch() {
+  let option = TdType::from_json(r#"{"@type":"optionValueString","value":"1.7.0"}"#).expect("Json fail");
+  match option {
+    TdType::OptionValue(OptionValue::String(v)) => assert_eq!(v.value(), "1.7.0"),
+    _ => panic!("from_json failed: {:?}", option),
+  }
+
+  let state = TdType::from_json(r#"{"@type":"authorizationStateReady"}"#).expect("Json fail");
+  assert!(matches!(state, TdType::AuthorizationState(AuthorizationState::Ready(_))));
+
+  let error = TdType::from_json(r#"{"@type":"error","code":400,"message":"Chat not found"}"#).expect("Json fail");
+  assert!(matches!(error, TdType::Error(_)));
+
+  let update = TdType::from_json(r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.7.0"}}"#).expect("Json fail");
+  assert!(matches!(update, TdType::UpdateOption(_)));
+
+  let unknown = TdType::from_json(r#"{"@type":"updateFromTheFuture","value":1}"#).expect("Json fail");
+  match unknown {
+    TdType::Unknown { td_type, raw } => {
+      assert_eq!(td_type, "updateFromTheFuture");
+      assert_eq!(raw["value"], 1);
+    }
+    _ => panic!("from_json failed: {:?}", unknown),
+  }
+}
+
+#[test]
+fn test_function_not_result() {
+  let fnc = GetOption::builder().name("version").build();
+  let option = OptionValue::from_json(fnc.to_json().unwrap()).expect("Json fail");
+  assert!(matches!(option, OptionValue::Unknown { .. }));
+
+  let reply = r#"{"@type":"optionValueString","value":"1.7.0"}"#;
+  let option: OptionValue = parse_response::<GetOption, _>(reply).expect("Json fail").expect("Td error");
+  assert!(option.is_string());
+}
+
+#[test]
+fn test_type_not_first() {
+  let content = MessageContent::from_json(r#"{"text":"hi","@type":"messageFutureContent"}"#).expect("Json fail");
+  assert!(matches!(&content, MessageContent::Unknown { td_type, .. } if td_type == "messageFutureContent"));
+
+  let state = AuthorizationState::from_json(r#"{"@extra":"1","@type":"authorizationStateReady"}"#).expect("Json fail");
+  assert!(state.is_ready());
+  assert_eq!(state.extra(), Some("1".to_string()));
+
+  let value: serde_json::Value = serde_json::from_str(r#"{"@type":"optionValueInteger","value":"7"}"#).unwrap();
+  let option: OptionValue = serde_json::from_value(value).expect("Json fail");
+  assert!(option.is_integer());
+}
+
+#[test]
+fn test_borrowed_message() {
+  use rtdlib::borrowed;
+
+  let updates = include_str!("../benches/synthetic_updates.jsonl");
+  for line in updates.lines().filter(|line| line.starts_with(r#"{"@type":"updateNewMessage""#)) {
+    let update: borrowed::UpdateNewMessage = serde_json::from_str(line).expect("Json fail");
+    let owned = UpdateNewMessage::from_json(line).expect("Json fail");
+    let message = update.message();
+    assert_eq!(message.id(), owned.message().id());
+    assert_eq!(message.chat_id(), owned.message().chat_id());
+    let text = match owned.message().content() {
+      MessageContent::MessageText(t) => t.text().text().clone(),
+      _ => panic!("not text: {:?}", owned),
+    };
+    assert_eq!(message.content().text(), Some(&text[..]));
+  }
+
+  let json = r#"{"@type":"formattedText","text":"hi \"there\"","entities":[]}"#;
+  let text: borrowed::FormattedText = serde_json::from_str(json).expect("Json fail");
+  assert_eq!(text.text(), r#"hi "there""#);
+  assert_eq!(text.into_owned().text(), r#"hi "there""#);
+
+  let json = r#"{"@type":"messageText","text":{"@type":"formattedText","text":"hi","entities":[]}}"#;
+  let content: borrowed::MessageContent = serde_json::from_str(json).expect("Json fail");
+  assert_eq!(content.text(), Some("hi"));
+  assert!(content.into_owned().is_message_text());
+  let photo = r#"{"@type":"messageExpiredPhoto"}"#;
+  let content: borrowed::MessageContent = serde_json::from_str(photo).expect("Json fail");
+  match content {
+    borrowed::MessageContent::Other(c) => assert!(c.is_message_expired_photo()),
+    _ => panic!("not other: {:?}", content),
+  }
+}
+
+#[test]
+fn test_builder_try_build() {
+  let error = TdlibParameters::builder().use_test_dc(true).api_id(123).try_build().unwrap_err();
+  assert_eq!(error.to_string(), "Missing fields of tdlibParameters: database_directory, api_hash, system_language_code, device_model, application_version");
+  let parameters = TdlibParameters::builder()
+    .database_directory("/tmp/td")
+    .api_id(123)
+    .api_hash("abc")
+    .system_language_code("en")
+    .device_model("Desktop")
+    .application_version("1.0")
+    .try_build()
+    .unwrap();
+  assert!(parameters.validate().is_ok());
+  assert!(SetTdlibParameters::builder().parameters(parameters).try_build().is_ok());
+  assert!(SetTdlibParameters::builder().try_build().is_err());
+
+  // objects set in a builder are checked by value, `0` and empty fields are missing
+  let error = SetTdlibParameters::builder().parameters(TdlibParameters::builder().api_hash("abc").build()).try_build().unwrap_err();
+  assert_eq!(error.to_string(), "Missing fields of setTdlibParameters: parameters.database_directory, parameters.api_id, \
+    parameters.system_language_code, parameters.device_model, parameters.application_version");
+  assert!(TdlibParameters::builder().build().validate().is_err());
+
+  // an abstract class is missing until it is one of its constructors, fields which may be null stay optional
+  let mut builder = SendMessage::builder();
+  builder.chat_id(1).input_message_content(InputMessageContent::default());
+  match builder.try_build() {
+    Err(rtdlib::errors::RTDError::MissingFields { td_type, fields }) => {
+      assert_eq!(td_type, "sendMessage");
+      assert_eq!(fields, vec!["input_message_content"]);
+    }
+    result => panic!("{:?}", result),
+  }
+  let text = InputMessageText::builder().text(FormattedText::builder().text("hi")).build();
+  assert!(builder.input_message_content(InputMessageContent::input_message_text(text)).try_build().is_ok());
+  assert!(GetMe::builder().try_build().is_ok());
+}
@@ -0,0 +1,57 @@
+use rtdlib::text::*;
+use rtdlib::types::*;
+
+/// Json of `text` without the random `@extra` of builders
+fn normalize(text: &FormattedText) -> serde_json::Value {
+  fn strip(value: &mut serde_json::Value) {
+    match value {
+      serde_json::Value::Object(map) => {
+        map.remove("@extra");
+        map.values_mut().for_each(strip);
+      }
+      serde_json::Value::Array(values) => values.iter_mut().for_each(strip),
+      _ => {}
+    }
+  }
+  let mut value = serde_json::to_value(text).unwrap();
+  strip(&mut value);
+  value
+}
+
+fn fixtures() -> Vec<serde_json::Value> {
+  include_str!("fixtures/markup.jsonl").lines()
+    .filter(|line| !line.trim().is_empty())
+    .map(|line| serde_json::from_str(line).unwrap())
+    .collect()
+}
+
+#[test]
+fn test_markup_fixtures() {
+  for fixture in fixtures() {
+    let markup = fixture["markup"].as_str().unwrap();
+    let parsed = match fixture["mode"].as_str().unwrap() {
+      "markdown_v2" => parse_markdown_v2(markup),
+      "html" => parse_html(markup),
+      mode => panic!("unknown mode {}", mode),
+    };
+    match (parsed, fixture.get("expected")) {
+      (Ok(text), Some(expected)) => {
+        let expected = FormattedText::from_json(expected.to_string()).unwrap();
+        assert_eq!(normalize(&text), normalize(&expected), "{}", markup);
+      }
+      (Err(e), None) => assert_eq!(e.to_string(), fixture["error"].as_str().unwrap(), "{}", markup),
+      (parsed, _) => panic!("{}: unexpected {:?}", markup, parsed.map(|text| normalize(&text))),
+    }
+  }
+}
+
+#[test]
+fn test_render_round_trip() {
+  for fixture in fixtures().iter().filter(|fixture| fixture.get("expected").is_some()) {
+    let text = FormattedText::from_json(fixture["expected"].to_string()).unwrap();
+    let markdown = text.to_markdown_v2();
+    assert_eq!(normalize(&parse_markdown_v2(&markdown).unwrap()), normalize(&text), "{}", markdown);
+    let html = text.to_html();
+    assert_eq!(normalize(&parse_html(&html).unwrap()), normalize(&text), "{}", html);
+  }
+}
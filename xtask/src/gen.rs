@@ -0,0 +1,525 @@
+//! Render `src/types` from a parsed schema
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::tl::{Definition, Field, Schema, TlType};
+
+const COMMON_TEMPLATE: &str = include_str!("../templates/_common.rs.tpl");
+const MOD_TEMPLATE: &str = include_str!("../templates/mod.rs.tpl");
+
+const FILE_HEADER: &str = "\nuse crate::types::*;\nuse crate::errors::*;\nuse uuid::Uuid;\n";
+
+/// Abstract enums keep objects whose `@type` is not in the schema instead of failing
+const UNKNOWN_VARIANT: &str = "  /// An object whose `@type` is unknown to this version, `raw` is written back unchanged by `to_json`
+  #[serde(serialize_with = \"crate::types::_common::serialize_unknown\")]
+  Unknown { td_type: String, raw: serde_json::Value },
+";
+
+/// Fields tdlib may leave empty although their description does not say `may be null`
+const NULLABLE_FIELDS: &[(&str, &str)] = &[
+  ("chat", "positions"),
+  ("chatPhotoInfo", "has_animation"),
+  ("finishFileGeneration", "error"),
+  ("profilePhoto", "has_animation"),
+  ("updateChatLastMessage", "positions"),
+];
+
+/// Fields described as `may be null` which tdlib always sends
+const REQUIRED_FIELDS: &[(&str, &str)] = &[
+  ("setOption", "value"),
+  ("updateOption", "value"),
+];
+
+/// Fields of functions tdlib accepts as null although their description does not say `may be null`
+const OPTIONAL_ARGUMENTS: &[&str] = &[
+  "options",
+  "reply_markup",
+];
+
+/// Fields `try_build` requires although tdlib has a default for them, the working directory is no place for a database
+const REQUIRED_ARGUMENTS: &[(&str, &str)] = &[
+  ("tdlibParameters", "database_directory"),
+];
+
+/// Descriptions saying the default value of a field means something, such fields are not required by `try_build`
+const DEFAULT_PHRASES: &[&str] = &["0 if", "or 0", "if not 0", "pass 0", "if empty", "can be empty", "may be empty"];
+
+/// Objects the application keeps up to date from updates, their fields can be changed in place by `<field>_mut`
+const UPDATED_TYPES: &[&str] = &[
+  "chat",
+  "user",
+];
+
+/// Rust type of a field
+#[derive(Debug, Clone, PartialEq)]
+enum RustType {
+  I64,
+  F32,
+  Bool,
+  String,
+  Vec(Box<RustType>),
+  Option(Box<RustType>),
+  Box(Box<RustType>),
+  Object(String),
+}
+
+impl RustType {
+  fn render(&self) -> String {
+    match self {
+      RustType::I64 => "i64".to_string(),
+      RustType::F32 => "f32".to_string(),
+      RustType::Bool => "bool".to_string(),
+      RustType::String => "String".to_string(),
+      RustType::Vec(t) => format!("Vec<{}>", t.render()),
+      RustType::Option(t) => format!("Option<{}>", t.render()),
+      RustType::Box(t) => format!("Box<{}>", t.render()),
+      RustType::Object(name) => name.clone(),
+    }
+  }
+
+  fn is_copy(&self) -> bool {
+    matches!(self, RustType::I64 | RustType::F32 | RustType::Bool)
+  }
+}
+
+/// `chatTypePrivate` -> `ChatTypePrivate`
+pub fn rust_name(td_name: &str) -> String {
+  let mut chars = td_name.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+    None => String::new(),
+  }
+}
+
+/// `ChatTypePrivate` -> `chat_type_private`
+pub fn snake_name(name: &str) -> String {
+  let mut snake = String::new();
+  for (i, c) in name.chars().enumerate() {
+    if c.is_uppercase() {
+      if i != 0 { snake.push('_') }
+      snake.extend(c.to_lowercase());
+    } else {
+      snake.push(c);
+    }
+  }
+  snake
+}
+
+fn tl_type(type_: &TlType) -> RustType {
+  match type_ {
+    TlType::Int32 | TlType::Int53 | TlType::Int64 => RustType::I64,
+    TlType::Double => RustType::F32,
+    TlType::String | TlType::Bytes => RustType::String,
+    TlType::Bool => RustType::Bool,
+    TlType::Vector(inner) => RustType::Vec(Box::new(tl_type(inner))),
+    TlType::Object(name) => RustType::Object(rust_name(name)),
+  }
+}
+
+const KEYWORDS: &[&str] = &[
+  "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn", "for",
+  "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "static", "struct",
+  "super", "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+  "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Append `_` to names which are rust keywords
+fn escape_keyword(name: String) -> String {
+  if KEYWORDS.contains(&&name[..]) { format!("{}_", name) } else { name }
+}
+
+fn field_name(field: &Field) -> String {
+  escape_keyword(field.name.clone())
+}
+
+/// Everything needed to render a schema, definitions are keyed by rust name
+struct Model<'a> {
+  schema: &'a Schema,
+  /// abstract class -> rust names of its constructors
+  members: BTreeMap<String, Vec<String>>,
+  definitions: HashMap<String, &'a Definition>,
+  functions: BTreeSet<String>,
+}
+
+impl<'a> Model<'a> {
+  fn new(schema: &'a Schema) -> Self {
+    let mut members: BTreeMap<String, Vec<String>> = schema.classes.keys().map(|c| (c.clone(), vec![])).collect();
+    let mut definitions = HashMap::new();
+    let mut functions = BTreeSet::new();
+    for definition in &schema.types {
+      let name = rust_name(&definition.name);
+      if let Some(members) = members.get_mut(&definition.result) {
+        members.push(name.clone());
+      }
+      definitions.insert(name, definition);
+    }
+    for definition in &schema.functions {
+      let name = rust_name(&definition.name);
+      functions.insert(name.clone());
+      definitions.insert(name, definition);
+    }
+    members.values_mut().for_each(|m| m.sort());
+    Model { schema, members, definitions, functions }
+  }
+
+  fn is_class(&self, name: &str) -> bool {
+    self.members.contains_key(name)
+  }
+
+  /// Whether `try_build` fails when the field is not set. A flag, a list, or a field whose default value means
+  /// something, has a valid value when it is not set.
+  fn is_required(&self, owner: &Definition, field: &Field, type_: &RustType) -> bool {
+    let doc = field.doc.to_lowercase();
+    match type_ {
+      _ if REQUIRED_ARGUMENTS.contains(&(&owner.name[..], &field.name[..])) => true,
+      RustType::Option(_) | RustType::Bool | RustType::Vec(_) => false,
+      _ if self.is_function(&rust_name(&owner.name)) && OPTIONAL_ARGUMENTS.contains(&&field.name[..]) => false,
+      _ => !DEFAULT_PHRASES.iter().any(|phrase| doc.contains(phrase)),
+    }
+  }
+
+  /// Whether the field holds an abstract class, which is unset while it is `_Default`
+  fn is_class_type(&self, type_: &RustType) -> bool {
+    match type_ {
+      RustType::Object(name) => self.is_class(name),
+      RustType::Box(inner) => self.is_class_type(inner),
+      _ => false,
+    }
+  }
+
+  fn is_function(&self, name: &str) -> bool {
+    self.functions.contains(name)
+  }
+
+  /// Class the type belongs to, functions belong to no class, their result is `RFunction::Response`
+  fn class_of(&self, name: &str) -> Option<&str> {
+    let result = &self.definitions[name].result;
+    if self.is_class(result) && !self.is_function(name) { Some(result) } else { None }
+  }
+
+  fn field_type(&self, owner: &Definition, field: &Field) -> RustType {
+    let nullable = field.doc.contains("may be null");
+    let key = (&owner.name[..], &field.name[..]);
+    let mut type_ = match &field.type_ {
+      // a class containing itself needs indirection
+      TlType::Object(name) if *name == owner.result && self.is_class(name) => {
+        RustType::Box(Box::new(RustType::Object(rust_name(name))))
+      }
+      type_ => tl_type(type_),
+    };
+    if let (true, RustType::Vec(inner)) = (nullable, &type_) {
+      return RustType::Vec(Box::new(RustType::Option(inner.clone())));
+    }
+    if NULLABLE_FIELDS.contains(&key) || (nullable && !REQUIRED_FIELDS.contains(&key)) {
+      type_ = RustType::Option(Box::new(type_));
+    }
+    type_
+  }
+
+  /// Enum variant of a class member, the class prefix is removed unless it clashes with `Unknown`
+  fn variant_name(&self, class: &str, member: &str) -> String {
+    match member.strip_prefix(class) {
+      Some(rest) if rest.starts_with(char::is_uppercase) && rest != "Unknown" => rest.to_string(),
+      _ => member.to_string(),
+    }
+  }
+}
+
+/// Render every file of `src/types`, file name -> content
+pub fn generate(schema: &Schema) -> BTreeMap<String, String> {
+  let model = Model::new(schema);
+  let mut files = BTreeMap::new();
+
+  for (class, members) in &model.members {
+    let mut out = String::from(FILE_HEADER);
+    out.push_str("\n\n\n\n");
+    render_class(&mut out, &model, class, members);
+    for member in members {
+      render_struct(&mut out, &model, member);
+    }
+    files.insert(format!("{}.rs", snake_name(class)), out);
+  }
+
+  for definition in &schema.types {
+    let name = rust_name(&definition.name);
+    if model.class_of(&name).is_some() {
+      continue;
+    }
+    let mut out = String::from(FILE_HEADER);
+    render_struct(&mut out, &model, &name);
+    files.insert(format!("{}.rs", snake_name(&name)), out);
+  }
+
+  let mut out = String::from(FILE_HEADER);
+  for name in &model.functions {
+    render_struct(&mut out, &model, name);
+  }
+  files.insert("functions.rs".to_string(), out);
+
+  let modules: Vec<String> = files.keys().map(|f| f.trim_end_matches(".rs").to_string()).collect();
+  files.insert("mod.rs".to_string(), render_mod(&modules));
+  files.insert("_common.rs".to_string(), render_common(&model));
+  files
+}
+
+fn render_class(out: &mut String, model: &Model, class: &str, members: &[String]) {
+  let doc = &model.schema.classes[class];
+  let variants: Vec<(String, &String)> = members.iter().map(|m| (model.variant_name(class, m), m)).collect();
+
+  out.push_str("use std::fmt::Debug;\nuse serde::de::{Deserialize, Deserializer};\n\n\n\n");
+  out.push_str(&format!("/// TRAIT | {}\npub trait TD{}: Debug + RObject {{}}\n\n", doc, class));
+  out.push_str(&format!("/// {}\n#[derive(Debug, Clone, Serialize)]\n#[serde(untagged)]\npub enum {} {{\n", doc, class));
+  out.push_str("  #[doc(hidden)] _Default(()),\n");
+  out.push_str(UNKNOWN_VARIANT);
+  for (variant, member) in &variants {
+    out.push_str(&format!("  /// {}\n  {}({}),\n", model.definitions[*member].doc, variant, member));
+  }
+  out.push_str("\n}\n\n");
+
+  out.push_str(&format!("impl Default for {c} {{\n  fn default() -> Self {{ {c}::_Default(()) }}\n}}\n\n", c = class));
+
+  out.push_str(&format!("impl<'de> Deserialize<'de> for {c} {{\n  fn deserialize<D>(deserializer: D) -> Result<{c}, D::Error> where D: Deserializer<'de> {{\n", c = class));
+  out.push_str(&format!("    rtd_enum_deserialize!(\n      {},\n", class));
+  for (variant, member) in &variants {
+    out.push_str(&format!("      ({}, {});\n", model.definitions[*member].name, variant));
+  }
+  out.push_str("\n    )(deserializer)\n  }\n}\n\n");
+
+  out.push_str(&format!("impl RObject for {} {{\n  #[doc(hidden)] fn td_name(&self) -> &'static str {{\n    match self {{\n", class));
+  for (variant, _) in &variants {
+    out.push_str(&format!("      {}::{}(t) => t.td_name(),\n", class, variant));
+  }
+  out.push_str("\n      _ => \"-1\",\n    }\n  }\n  #[doc(hidden)] fn extra(&self) -> Option<String> {\n    match self {\n");
+  for (variant, _) in &variants {
+    out.push_str(&format!("      {}::{}(t) => t.extra(),\n", class, variant));
+  }
+  out.push_str(&format!("      {}::Unknown {{ raw, .. }} => raw.get(\"@extra\").and_then(|e| e.as_str()).map(|e| e.to_string()),\n", class));
+  out.push_str("\n      _ => None,\n    }\n  }\n  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }\n}\n\n");
+
+  out.push_str(&format!("impl {} {{\n", class));
+  out.push_str("  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }\n");
+  out.push_str(&format!("  #[doc(hidden)] pub fn _is_default(&self) -> bool {{ if let {}::_Default(_) = self {{ true }} else {{ false }} }}\n", class));
+  out.push_str("  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {\n    match self {\n");
+  for (variant, _) in &variants {
+    out.push_str(&format!("      {}::{}(t) => t._missing_fields(),\n", class, variant));
+  }
+  out.push_str("      _ => vec![],\n    }\n  }\n\n");
+  for (variant, _) in &variants {
+    out.push_str(&format!("  pub fn is_{}(&self) -> bool {{ if let {}::{}(_) = self {{ true }} else {{ false }} }}\n", snake_name(variant), class, variant));
+  }
+  out.push('\n');
+  for (variant, member) in &variants {
+    out.push_str(&format!("  pub fn on_{}<F: FnOnce(&{})>(&self, fnc: F) -> &Self {{ if let {}::{}(t) = self {{ fnc(t) }}; self }}\n", snake_name(variant), member, class, variant));
+  }
+  out.push('\n');
+  for (variant, member) in &variants {
+    out.push_str(&format!("  pub fn as_{}(&self) -> Option<&{}> {{ if let {}::{}(t) = self {{ return Some(t) }} None }}\n", snake_name(variant), member, class, variant));
+  }
+  out.push_str("\n\n\n");
+  for (variant, member) in &variants {
+    out.push_str(&format!("  pub fn {}<T: AsRef<{}>>(t: T) -> Self {{ {}::{}(t.as_ref().clone()) }}\n\n", escape_keyword(snake_name(variant)), member, class, variant));
+  }
+  out.push_str("}\n\n");
+  out.push_str(&format!("impl AsRef<{c}> for {c} {{\n  fn as_ref(&self) -> &{c} {{ self }}\n}}\n\n", c = class));
+  for (variant, member) in &variants {
+    out.push_str(&format!("impl RVariant<{c}> for {m} {{\n  fn from_class(class: &{c}) -> Option<&Self> {{ match class {{ {c}::{v}(t) => Some(t), _ => None }} }}\n}}\n\n", c = class, m = member, v = variant));
+  }
+  out.push_str("\n\n");
+}
+
+fn render_struct(out: &mut String, model: &Model, name: &str) {
+  let definition = model.definitions[name];
+  let builder = format!("RTD{}Builder", name);
+  let fields: Vec<(String, RustType, &Field)> = definition.fields.iter()
+    .map(|f| (field_name(f), model.field_type(definition, f), f))
+    .collect();
+
+  out.push_str("\n\n\n\n");
+  out.push_str(&format!("/// {}\n#[derive(Debug, Clone, Default, Serialize, Deserialize)]\npub struct {} {{\n", definition.doc, name));
+  out.push_str("  #[doc(hidden)]\n  #[serde(rename(serialize = \"@type\", deserialize = \"@type\"))]\n  td_name: String,\n");
+  out.push_str("  #[doc(hidden)]\n  #[serde(rename(serialize = \"@extra\", deserialize = \"@extra\"))]\n  extra: Option<String>,\n");
+  for (field, type_, tl) in &fields {
+    out.push_str(&format!("  /// {}\n  {}{}: {},\n", tl.doc, serde_attribute(tl), field, type_.render()));
+  }
+  out.push_str("  \n}\n\n");
+
+  out.push_str(&format!("impl RObject for {} {{\n", name));
+  out.push_str(&format!("  #[doc(hidden)] fn td_name(&self) -> &'static str {{ \"{}\" }}\n", definition.name));
+  out.push_str("  #[doc(hidden)] fn extra(&self) -> Option<String> { self.extra.clone() }\n");
+  out.push_str("  fn to_json(&self) -> RTDResult<String> { Ok(serde_json::to_string(self)?) }\n}\n");
+  if model.is_function(name) {
+    out.push_str(&format!("\n\n\n\nimpl RFunction for {} {{\n  type Response = {};\n}}\n\n", name, definition.result));
+  } else if let Some(class) = model.class_of(name) {
+    out.push_str(&format!("\n\nimpl TD{} for {} {{}}\n\n\n\n", class, name));
+  } else {
+    out.push_str("\n\n\n");
+  }
+
+  out.push_str(&format!("impl {} {{\n", name));
+  out.push_str("  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }\n");
+  out.push_str(&format!("  pub fn builder() -> {} {{\n    let mut inner = {}::default();\n", builder, name));
+  let required: Vec<&(String, RustType, &Field)> = fields.iter().filter(|(_, type_, tl)| model.is_required(definition, tl, type_)).collect();
+  // index of each required field the builder tracks in `set`, an abstract class is unset while it is `_Default`
+  let tracked: Vec<&str> = required.iter().filter(|(_, type_, _)| !model.is_class_type(type_)).map(|(field, _, _)| &field[..]).collect();
+  let set = if tracked.is_empty() { String::new() } else { format!(", set: [false; {}]", tracked.len()) };
+  out.push_str(&format!("    inner.td_name = \"{}\".to_string();\n    inner.extra = Some(Uuid::new_v4().to_string());\n    {} {{ inner{} }}\n  }}\n\n", definition.name, builder, set));
+  render_validate(out, model, definition, &required);
+  for (field, type_, _) in &fields {
+    if type_.is_copy() {
+      out.push_str(&format!("  pub fn {f}(&self) -> {t} {{ self.{f} }}\n\n", f = field, t = type_.render()));
+    } else {
+      out.push_str(&format!("  pub fn {f}(&self) -> &{t} {{ &self.{f} }}\n\n", f = field, t = type_.render()));
+    }
+  }
+  if UPDATED_TYPES.contains(&&definition.name[..]) {
+    for (field, type_, _) in &fields {
+      out.push_str(&format!("  pub fn {}_mut(&mut self) -> &mut {} {{ &mut self.{} }}\n\n", field.trim_end_matches('_'), type_.render(), field));
+    }
+  }
+  out.push_str("}\n\n");
+
+  let set = if tracked.is_empty() { String::new() } else { format!(",\n  set: [bool; {}]", tracked.len()) };
+  out.push_str(&format!("#[doc(hidden)]\npub struct {} {{\n  inner: {}{}\n}}\n\n", builder, name, set));
+  out.push_str(&format!("impl {} {{\n  pub fn build(&self) -> {} {{ self.inner.clone() }}\n\n", builder, name));
+  render_try_build(out, definition, name, &required, &tracked);
+  for (field, type_, _) in &fields {
+    let (generic, param, value) = setter(field, type_);
+    let set = match tracked.iter().position(|tracked| tracked == field) {
+      Some(index) => format!("    self.set[{}] = true;\n", index),
+      None => String::new(),
+    };
+    out.push_str(&format!("   \n  pub fn {f}{}(&mut self, {f}: {}) -> &mut Self {{\n    self.inner.{f} = {};\n{}    self\n  }}\n\n", generic, param, value, set, f = field));
+  }
+  out.push_str("}\n\n");
+
+  out.push_str(&format!("impl AsRef<{n}> for {n} {{\n  fn as_ref(&self) -> &{n} {{ self }}\n}}\n\n", n = name));
+  out.push_str(&format!("impl AsRef<{n}> for {b} {{\n  fn as_ref(&self) -> &{n} {{ &self.inner }}\n}}\n\n\n\n", n = name, b = builder));
+}
+
+/// Check of a required field of an object: its path is missing when `check` holds, or it is an object with
+/// missing fields
+fn missing_check(field: &str, type_: &RustType, tl: &Field, unset: String) -> String {
+  let nested = format!("missing.extend(crate::types::_common::missing_in(\"{}\", self.{}._missing_fields()));", tl.name, field);
+  match type_ {
+    RustType::Object(_) | RustType::Box(_) => format!("    if {} {{ missing.push(\"{}\".to_string()); }} else {{ {} }}\n", unset, tl.name, nested),
+    _ => format!("    if {} {{ missing.push(\"{}\".to_string()); }}\n", unset, tl.name),
+  }
+}
+
+/// `validate` of an object: a required field is missing when it is `0`, empty or `_Default`, objects are checked
+/// with their fields
+fn render_validate(out: &mut String, model: &Model, definition: &Definition, required: &[&(String, RustType, &Field)]) {
+  out.push_str("  /// Fail with every required field which is `0`, empty or not set, and those of the objects inside\n");
+  out.push_str(&format!("  pub fn validate(&self) -> RTDResult<()> {{ crate::types::_common::check_missing(\"{}\", self._missing_fields()) }}\n\n", definition.name));
+  if required.is_empty() {
+    out.push_str("  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> { vec![] }\n\n");
+    return;
+  }
+  out.push_str("  #[doc(hidden)] pub fn _missing_fields(&self) -> Vec<String> {\n    let mut missing = vec![];\n");
+  for (field, type_, tl) in required {
+    let unset = match type_ {
+      RustType::String => format!("self.{}.is_empty()", field),
+      RustType::I64 => format!("self.{} == 0", field),
+      RustType::F32 => format!("self.{} == 0.0", field),
+      _ if model.is_class_type(type_) => format!("self.{}._is_default()", field),
+      _ => "false".to_string(),
+    };
+    out.push_str(&missing_check(field, type_, tl, unset));
+  }
+  out.push_str("    missing\n  }\n\n");
+}
+
+/// `try_build`, required fields must be set, objects must have their own required fields, and a field of an abstract
+/// class must be one of its constructors
+fn render_try_build(out: &mut String, definition: &Definition, name: &str, required: &[&(String, RustType, &Field)], tracked: &[&str]) {
+  out.push_str("  /// Build, or fail with every required field which was not set, and those of the objects inside\n");
+  if required.is_empty() {
+    out.push_str(&format!("  pub fn try_build(&self) -> RTDResult<{}> {{ Ok(self.build()) }}\n\n", name));
+    return;
+  }
+  out.push_str(&format!("  pub fn try_build(&self) -> RTDResult<{}> {{\n    let mut missing = vec![];\n", name));
+  for (field, type_, tl) in required {
+    let unset = match tracked.iter().position(|tracked| tracked == field) {
+      Some(index) => format!("!self.set[{}]", index),
+      None => format!("self.inner.{}._is_default()", field),
+    };
+    out.push_str(&missing_check(&format!("inner.{}", field), type_, tl, unset));
+  }
+  out.push_str(&format!("    crate::types::_common::check_missing(\"{}\", missing)?;\n    Ok(self.build())\n  }}\n\n", definition.name));
+}
+
+fn serde_attribute(field: &Field) -> &'static str {
+  match (&field.name[..], &field.type_) {
+    ("type", _) => "#[serde(rename(serialize = \"type\", deserialize = \"type\"))] ",
+    (_, TlType::Int64) => "#[serde(with = \"crate::types::_common::int64\")] ",
+    (_, TlType::Vector(inner)) if **inner == TlType::Int64 => "#[serde(with = \"crate::types::_common::vec_int64\")] ",
+    _ => "",
+  }
+}
+
+/// Generic bound, parameter type and assigned expression of a builder setter
+fn setter(field: &str, type_: &RustType) -> (String, String, String) {
+  let generic = |bound: String| format!("<T: {}>", bound);
+  match type_ {
+    RustType::String => (generic("AsRef<str>".to_string()), "T".to_string(), format!("{}.as_ref().to_string()", field)),
+    RustType::Object(_) | RustType::Box(_) => (generic(format!("AsRef<{}>", type_.render())), "T".to_string(), format!("{}.as_ref().clone()", field)),
+    RustType::Option(inner) => match &**inner {
+      RustType::String => (generic("AsRef<str>".to_string()), "T".to_string(), format!("Some({}.as_ref().to_string())", field)),
+      RustType::Object(_) => (generic(format!("AsRef<{}>", inner.render())), "T".to_string(), format!("Some({}.as_ref().clone())", field)),
+      inner => (String::new(), inner.render(), format!("Some({})", field)),
+    },
+    _ => (String::new(), type_.render(), field.to_string()),
+  }
+}
+
+fn render_mod(modules: &[String]) -> String {
+  let mut generated = String::new();
+  for module in modules {
+    generated.push_str(&format!("pub use self::{}::*;\n", module));
+  }
+  generated.push_str("\n\n\n\n");
+  for module in modules {
+    generated.push_str(&format!("mod {};\n", module));
+  }
+  generated.push_str("\n\n");
+  MOD_TEMPLATE.replace("{{generated}}", &generated)
+}
+
+fn render_common(model: &Model) -> String {
+  let mut generated = String::new();
+  for class in model.members.keys() {
+    let generic = class.to_uppercase();
+    generated.push_str(&format!("impl<'a, {g}: TD{c}> TD{c} for &'a {g} {{}}\n", g = generic, c = class));
+    generated.push_str(&format!("impl<'a, {g}: TD{c}> TD{c} for &'a mut {g} {{}}\n\n", g = generic, c = class));
+  }
+
+  // every update, then every object a function can return, an abstract class is matched by each of its constructors
+  let updates: Vec<(String, String)> = model.members.get("Update").into_iter().flatten()
+    .map(|name| (model.definitions[name].name.clone(), name.clone()))
+    .collect();
+  let results: BTreeSet<&String> = model.schema.functions.iter().map(|f| &f.result).filter(|r| *r != "Update").collect();
+  let (classes, objects): (Vec<&String>, Vec<&String>) = results.into_iter().partition(|r| model.is_class(r));
+  let class_routes = classes.iter().flat_map(|class| {
+    model.members[*class].iter().map(move |member| (model.definitions[member].name.clone(), class.to_string()))
+  });
+  let object_routes = objects.iter().map(|name| (model.definitions[*name].name.clone(), name.to_string()));
+  let routes: Vec<(String, String)> = class_routes.chain(object_routes).collect();
+
+  generated.push_str("\n/// Any object tdlib sends: an update, or the reply to a function\n");
+  generated.push_str("#[derive(Debug, Clone)]\npub enum TdType {\n");
+  updates.iter().for_each(|(_, name)| generated.push_str(&format!("  {n}({n}),\n", n = name)));
+  generated.push('\n');
+  classes.iter().chain(objects.iter()).for_each(|name| generated.push_str(&format!("  {n}({n}),\n", n = name)));
+  generated.push_str("  /// An object whose `@type` is unknown to this version\n  Unknown { td_type: String, raw: serde_json::Value },\n");
+  generated.push_str("\n}\n");
+
+  generated.push_str("impl<'de> Deserialize<'de> for TdType {\n");
+  generated.push_str("fn deserialize<D>(deserializer: D) -> Result<TdType, D::Error> where D: Deserializer<'de> {\n");
+  generated.push_str("    rtd_enum_deserialize!(\n      TdType,\n");
+  updates.iter().for_each(|(td_name, name)| generated.push_str(&format!("  ({}, {});\n", td_name, name)));
+  generated.push('\n');
+  routes.iter().for_each(|(td_name, name)| generated.push_str(&format!("  ({}, {});\n", td_name, name)));
+  generated.push_str("\n )(deserializer)\n\n }\n}\n\n");
+  generated.push_str("impl TdType {\n  pub fn from_json<S: AsRef<str>>(json: S) -> RTDResult<Self> { Ok(serde_json::from_str(json.as_ref())?) }\n}\n");
+  COMMON_TEMPLATE.replace("{{generated}}", &generated)
+}
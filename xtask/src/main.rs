@@ -0,0 +1,83 @@
+//! Development tasks, run with `cargo xtask <task>`
+//!
+//! - `codegen [--check] [--schema <td_api.tl>] [--out <dir>]`: rebuild `src/types` from the tdlib schema, with
+//!   `--check` nothing is written and the command fails if `src/types` is out of date.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod gen;
+mod tl;
+
+fn root() -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Files of `out` which differ from the generated ones, or are generated but missing
+fn outdated(files: &std::collections::BTreeMap<String, String>, out: &Path) -> Vec<String> {
+  files.iter()
+    .filter(|(name, content)| fs::read_to_string(out.join(name)).ok().as_ref() != Some(*content))
+    .map(|(name, _)| name.clone())
+    .collect()
+}
+
+fn codegen(args: &[String]) -> Result<(), String> {
+  let mut schema = root().join("schema").join("td_api.tl");
+  let mut out = root().join("src").join("types");
+  let mut check = false;
+  let mut args = args.iter();
+  while let Some(arg) = args.next() {
+    match &arg[..] {
+      "--check" => check = true,
+      "--schema" => schema = args.next().ok_or("Missing value of --schema")?.into(),
+      "--out" => out = args.next().ok_or("Missing value of --out")?.into(),
+      _ => return Err(format!("Unknown argument `{}`", arg)),
+    }
+  }
+
+  let source = fs::read_to_string(&schema).map_err(|e| format!("Can not read {}: {}", schema.display(), e))?;
+  let files = gen::generate(&tl::parse(&source)?);
+  let outdated = outdated(&files, &out);
+  if check {
+    if outdated.is_empty() {
+      return Ok(());
+    }
+    return Err(format!("{} is out of date, run `cargo xtask codegen`: {}", out.display(), outdated.join(", ")));
+  }
+  fs::create_dir_all(&out).map_err(|e| e.to_string())?;
+  for name in &outdated {
+    fs::write(out.join(name), &files[name]).map_err(|e| format!("Can not write {}: {}", name, e))?;
+  }
+  println!("{} files generated, {} changed", files.len(), outdated.len());
+  Ok(())
+}
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let result = match args.first().map(|s| &s[..]) {
+    Some("codegen") => codegen(&args[1..]),
+    _ => Err("Usage: cargo xtask codegen [--check] [--schema <td_api.tl>] [--out <dir>]".to_string()),
+  };
+  if let Err(e) = result {
+    eprintln!("{}", e);
+    process::exit(1);
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use super::*;
+
+  #[test]
+  fn test_types_up_to_date() {
+    let source = fs::read_to_string(root().join("schema").join("td_api.tl")).unwrap();
+    let files = gen::generate(&tl::parse(&source).unwrap());
+    let outdated = outdated(&files, &root().join("src").join("types"));
+    assert!(outdated.is_empty(), "src/types is out of date, run `cargo xtask codegen`: {:?}", outdated);
+  }
+}
@@ -0,0 +1,213 @@
+//! Parser of the tdlib `td_api.tl` schema
+
+use std::collections::HashMap;
+
+/// Types declared by the tl language itself, they are mapped to rust types directly
+const BUILTINS: &[&str] = &["double", "string", "int32", "int53", "int64", "bytes", "boolFalse", "boolTrue", "vector"];
+
+/// Type of a field, as written in the schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlType {
+  Int32,
+  Int53,
+  Int64,
+  Double,
+  String,
+  Bytes,
+  Bool,
+  Vector(Box<TlType>),
+  /// A constructor (`chatPhoto`) or an abstract class (`ChatType`)
+  Object(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+  pub name: String,
+  pub doc: String,
+  pub type_: TlType,
+}
+
+/// A constructor or a function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition {
+  pub name: String,
+  pub doc: String,
+  pub fields: Vec<Field>,
+  pub result: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Schema {
+  /// Abstract classes declared by `//@class`, name -> description
+  pub classes: HashMap<String, String>,
+  pub types: Vec<Definition>,
+  pub functions: Vec<Definition>,
+}
+
+impl TlType {
+  fn parse(text: &str) -> Result<TlType, String> {
+    Ok(match text {
+      "int32" => TlType::Int32,
+      "int53" => TlType::Int53,
+      "int64" => TlType::Int64,
+      "double" => TlType::Double,
+      "string" => TlType::String,
+      "bytes" => TlType::Bytes,
+      "Bool" => TlType::Bool,
+      _ => {
+        let vector = text.strip_prefix("vector<").or_else(|| text.strip_prefix("Vector<"));
+        match vector {
+          Some(inner) => match inner.strip_suffix('>') {
+            Some(inner) => TlType::Vector(Box::new(TlType::parse(inner)?)),
+            None => return Err(format!("Unclosed vector type `{}`", text)),
+          },
+          None => TlType::Object(text.to_string()),
+        }
+      }
+    })
+  }
+}
+
+/// Split `@tag text @tag text` into pairs, a tag starts with `@` at the beginning or after whitespace
+fn parse_tags(comment: &str) -> Vec<(String, String)> {
+  let mut starts = vec![];
+  let mut previous = ' ';
+  for (i, c) in comment.char_indices() {
+    if c == '@' && previous.is_whitespace() {
+      starts.push(i);
+    }
+    previous = c;
+  }
+  starts.iter().enumerate().filter_map(|(n, &start)| {
+    let end = starts.get(n + 1).copied().unwrap_or(comment.len());
+    let tag = &comment[start + 1..end];
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = &tag[..name_end];
+    if name.is_empty() { return None }
+    Some((name.to_string(), tag[name_end..].trim().to_string()))
+  }).collect()
+}
+
+fn parse_definition(line: &str, comment: &str) -> Result<Definition, String> {
+  let body = line.trim_end_matches(';');
+  let (left, result) = match body.split_once('=') {
+    Some(pair) => pair,
+    None => return Err(format!("Missing `=` in `{}`", line)),
+  };
+  let mut words = left.split_whitespace();
+  let name = match words.next() {
+    Some(name) => name.to_string(),
+    None => return Err(format!("Missing name in `{}`", line)),
+  };
+  let tags = parse_tags(comment);
+  let tag = |key: &str| tags.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone());
+  let mut fields = vec![];
+  for word in words {
+    let (field, type_) = match word.split_once(':') {
+      Some(pair) => pair,
+      None => return Err(format!("Bad field `{}` of `{}`", word, name)),
+    };
+    // `param_` prefixed docs are used when the field name collides with a tag name
+    let doc = tag(field).or_else(|| tag(&format!("param_{}", field))).unwrap_or_default();
+    fields.push(Field { name: field.to_string(), doc, type_: TlType::parse(type_)? });
+  }
+  Ok(Definition {
+    name,
+    doc: tag("description").unwrap_or_default(),
+    fields,
+    result: result.trim().to_string(),
+  })
+}
+
+/// Parse `td_api.tl` source
+pub fn parse(source: &str) -> Result<Schema, String> {
+  let mut schema = Schema::default();
+  let mut is_function = false;
+  let mut comment = String::new();
+  for line in source.lines().map(|line| line.trim()) {
+    if line.is_empty() {
+      continue;
+    }
+    if line == "---functions---" {
+      is_function = true;
+      comment.clear();
+      continue;
+    }
+    if line.starts_with("---") {
+      continue;
+    }
+    if let Some(text) = line.strip_prefix("//") {
+      let text = text.strip_prefix('-').unwrap_or(text);
+      if text.trim_start().starts_with("@class") {
+        let tags = parse_tags(text);
+        if let Some((_, class)) = tags.first() {
+          let doc = tags.iter().find(|(k, _)| k == "description").map(|(_, v)| v.clone()).unwrap_or_default();
+          schema.classes.insert(class.clone(), doc);
+        }
+        continue;
+      }
+      comment.push(' ');
+      comment.push_str(text);
+      continue;
+    }
+    let name = line.split_whitespace().next().unwrap_or_default();
+    if BUILTINS.contains(&name) {
+      comment.clear();
+      continue;
+    }
+    let definition = parse_definition(line, &comment)?;
+    comment.clear();
+    if is_function {
+      schema.functions.push(definition);
+    } else {
+      schema.types.push(definition);
+    }
+  }
+  Ok(schema)
+}
+
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SOURCE: &str = r#"
+double ? = Double;
+vector {t:Type} # [ t ] = Vector t;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description Contains a list of messages @total_count Approximate total count of messages found
+//-@messages List of messages; messages may be null
+messages total_count:int32 messages:vector<message> = Messages;
+
+---functions---
+
+//@description Returns a chat @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+"#;
+
+  #[test]
+  fn test_parse() {
+    let schema = parse(SOURCE).unwrap();
+    assert_eq!(schema.classes.get("ChatType").map(|s| &s[..]), Some("Describes the type of a chat"));
+    assert_eq!(schema.types.len(), 2);
+    assert_eq!(schema.types[0].name, "chatTypePrivate");
+    assert_eq!(schema.types[0].result, "ChatType");
+    assert_eq!(schema.types[0].fields[0], Field { name: "user_id".to_string(), doc: "User identifier".to_string(), type_: TlType::Int32 });
+    assert_eq!(schema.types[1].fields[1].doc, "List of messages; messages may be null");
+    assert_eq!(schema.types[1].fields[1].type_, TlType::Vector(Box::new(TlType::Object("message".to_string()))));
+    assert_eq!(schema.functions.len(), 1);
+    assert_eq!(schema.functions[0].doc, "Returns a chat");
+    assert_eq!(schema.functions[0].fields[0].type_, TlType::Int53);
+  }
+
+  #[test]
+  fn test_parse_error() {
+    assert!(parse("chat id:int53 Chat;").is_err());
+    assert!(parse("chat id = Chat;").is_err());
+  }
+}
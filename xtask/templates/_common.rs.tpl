@@ -0,0 +1,391 @@
+use std::borrow::Cow;
+use std::fmt::{self, Debug};
+
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, Visitor};
+use serde::de::value::{BorrowedStrDeserializer, StringDeserializer};
+
+use crate::errors::*;
+use crate::types::*;
+
+macro_rules! rtd_enum_deserialize {
+  ($type_name:ident, $(($td_name:ident, $enum_item:ident));*;) => {
+    // example json
+    // {"@type":"authorizationStateWaitEncryptionKey","is_encrypted":false}
+    |deserializer: D| -> Result<$type_name, D::Error> {
+      struct EnumVisitor;
+
+      impl<'de> serde::de::Visitor<'de> for EnumVisitor {
+        type Value = $type_name;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+          write!(formatter, "a tdlib object of {}", stringify!($type_name))
+        }
+
+        fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<$type_name, A::Error> {
+          // read `@type` first, the variant is deserialized from the rest of the object
+          let tagged = crate::types::_common::Tagged::read(map)?;
+          Ok(match tagged.td_type() {
+            $(
+              stringify!($td_name) => $type_name::$enum_item(tagged.deserialize()?),
+            )*
+            // a type this version does not know, keep it so it can be sent back unchanged
+            _ => {
+              let (td_type, raw) = tagged.into_unknown()?;
+              $type_name::Unknown { td_type, raw }
+            }
+          })
+        }
+      }
+
+      deserializer.deserialize_map(EnumVisitor)
+    }
+  }
+}
+
+
+///// tuple enum is field
+//macro_rules! tuple_enum_is {
+//  ($enum_name:ident, $field:ident) => {
+//    |o: &$enum_name| {
+//      if let $enum_name::$field(_) = o { true } else { false }
+//    }
+//  };
+////  ($e:ident, $t:ident, $namespace:ident) => {
+////    Box::new(|t: &$e| {
+////      match t {
+////        $namespace::$e::$t(_) => true,
+////        _ => false
+////      }
+////    })
+////  };
+//}
+//
+//macro_rules! tuple_enum_on {
+//  ($enum_name:ident, $field:ident, $fnc:expr) => {
+//    |o: &$enum_name| {
+//      if let $enum_name::$field(t) = o { $fnc(t) }
+//    }
+//  };
+//}
+
+pub fn detect_td_type<S: AsRef<str>>(json: S) -> Option<String> {
+  let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
+  if let Err(_) = result { return None }
+  let value = result.unwrap();
+  value.as_object().map_or(None, |v| {
+    v.get("@type").map_or(None, |t| t.as_str().map_or(None, |t| {
+      Some(t.to_string())
+    }))
+  })
+}
+
+pub fn detect_td_type_and_extra<S: AsRef<str>>(json: S) -> (Option<String>, Option<String>) {
+  let result: Result<serde_json::Value, serde_json::Error> = serde_json::from_str::<serde_json::Value>(json.as_ref());
+  if let Err(_) = result { return (None, None) }
+  let value = result.unwrap();
+  let mut type_ = None;
+  let mut extra = None;
+  if let Some(map) = value.as_object() {
+    map.get("@type").map(|v| v.as_str().map(|t| type_.replace(t.to_string())));
+    map.get("@extra").map(|v| v.as_str().map(|t| extra.replace(t.to_string())));
+  }
+  (type_, extra)
+}
+
+pub fn from_json<'a, T>(json: &'a str) -> RTDResult<T> where T: serde::de::Deserialize<'a>, {
+  Ok(serde_json::from_str(json.as_ref())?)
+}
+
+/// A string borrowed from the json when it has no escapes
+pub(crate) struct CowStr<'de>(pub Cow<'de, str>);
+
+impl<'de> Deserialize<'de> for CowStr<'de> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<CowStr<'de>, D::Error> {
+    struct CowStrVisitor;
+
+    impl<'de> Visitor<'de> for CowStrVisitor {
+      type Value = CowStr<'de>;
+
+      fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+      }
+
+      fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<CowStr<'de>, E> { Ok(CowStr(Cow::Borrowed(value))) }
+
+      fn visit_str<E: de::Error>(self, value: &str) -> Result<CowStr<'de>, E> { Ok(CowStr(Cow::Owned(value.to_string()))) }
+
+      fn visit_string<E: de::Error>(self, value: String) -> Result<CowStr<'de>, E> { Ok(CowStr(Cow::Owned(value))) }
+    }
+
+    deserializer.deserialize_str(CowStrVisitor)
+  }
+}
+
+/// An object read by an abstract enum, its `@type` is known before the rest of the fields are read.
+///
+/// tdlib writes `@type` first, so the variant is deserialized straight from the input. An object with `@type`
+/// elsewhere is buffered into a `serde_json::Value` first.
+pub(crate) enum Tagged<'de, A> {
+  Streaming { td_type: Cow<'de, str>, map: A },
+  Buffered { td_type: String, object: serde_json::Map<String, serde_json::Value> },
+}
+
+impl<'de, A: MapAccess<'de>> Tagged<'de, A> {
+  pub fn read(mut map: A) -> Result<Self, A::Error> {
+    let first: Option<CowStr<'de>> = map.next_key()?;
+    if let Some(CowStr(key)) = &first {
+      if key == "@type" {
+        let td_type: CowStr<'de> = map.next_value()?;
+        return Ok(Tagged::Streaming { td_type: td_type.0, map });
+      }
+    }
+    let mut object = serde_json::Map::new();
+    if let Some(CowStr(key)) = first {
+      object.insert(key.into_owned(), map.next_value()?);
+    }
+    while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+      object.insert(key, value);
+    }
+    let td_type = match object.get("@type") {
+      Some(serde_json::Value::String(td_type)) => td_type.clone(),
+      Some(_) => return Err(de::Error::invalid_type(de::Unexpected::Other("non-string @type"), &"a string")),
+      None => return Err(de::Error::missing_field("@type")),
+    };
+    Ok(Tagged::Buffered { td_type, object })
+  }
+
+  pub fn td_type(&self) -> &str {
+    match self {
+      Tagged::Streaming { td_type, .. } => td_type,
+      Tagged::Buffered { td_type, .. } => td_type,
+    }
+  }
+
+  /// Deserialize the whole object, `@type` included, as `T`
+  pub fn deserialize<T: Deserialize<'de>>(self) -> Result<T, A::Error> {
+    match self {
+      Tagged::Streaming { td_type, map } => T::deserialize(Replay { td_type: Some(td_type), value: None, map }),
+      Tagged::Buffered { object, .. } => T::deserialize(serde_json::Value::Object(object)).map_err(de::Error::custom),
+    }
+  }
+
+  /// The `@type` and the whole object, for the `Unknown` variant
+  pub fn into_unknown(self) -> Result<(String, serde_json::Value), A::Error> {
+    match self {
+      Tagged::Streaming { td_type, mut map } => {
+        let mut object = serde_json::Map::new();
+        object.insert("@type".to_string(), serde_json::Value::String(td_type.to_string()));
+        while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+          object.insert(key, value);
+        }
+        Ok((td_type.into_owned(), serde_json::Value::Object(object)))
+      }
+      Tagged::Buffered { td_type, object } => Ok((td_type, serde_json::Value::Object(object))),
+    }
+  }
+}
+
+/// The rest of an object whose `@type` has been read, the `@type` entry is yielded again first
+struct Replay<'de, A> {
+  td_type: Option<Cow<'de, str>>,
+  value: Option<Cow<'de, str>>,
+  map: A,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Replay<'de, A> {
+  type Error = A::Error;
+
+  fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error> {
+    match self.td_type.take() {
+      Some(td_type) => {
+        self.value = Some(td_type);
+        seed.deserialize(BorrowedStrDeserializer::new("@type")).map(Some)
+      }
+      None => self.map.next_key_seed(seed),
+    }
+  }
+
+  fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, A::Error> {
+    match self.value.take() {
+      Some(Cow::Borrowed(td_type)) => seed.deserialize(BorrowedStrDeserializer::new(td_type)),
+      Some(Cow::Owned(td_type)) => seed.deserialize(StringDeserializer::new(td_type)),
+      None => self.map.next_value_seed(seed),
+    }
+  }
+
+  fn size_hint(&self) -> Option<usize> {
+    self.map.size_hint().map(|size| size + self.td_type.is_some() as usize)
+  }
+}
+
+impl<'de, A: MapAccess<'de>> Deserializer<'de> for Replay<'de, A> {
+  type Error = A::Error;
+
+  fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, A::Error> {
+    visitor.visit_map(self)
+  }
+
+  serde::forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit unit_struct
+    newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+  }
+}
+
+/// Write the `Unknown` variant of abstract enums as the object it was read from
+pub(crate) fn serialize_unknown<T, S: serde::Serializer>(_td_type: &T, raw: &serde_json::Value, serializer: S) -> Result<S::Ok, S::Error> {
+  serde::Serialize::serialize(raw, serializer)
+}
+
+/// The missing fields of the object `field`, as paths from the object holding it
+pub(crate) fn missing_in(field: &str, missing: Vec<String>) -> Vec<String> {
+  missing.into_iter().map(|m| format!("{}.{}", field, m)).collect()
+}
+
+/// Fail with `missing` fields of `td_type`, if any
+pub(crate) fn check_missing(td_type: &'static str, missing: Vec<String>) -> RTDResult<()> {
+  if missing.is_empty() { Ok(()) } else { Err(RTDError::MissingFields { td_type, fields: missing }) }
+}
+
+/// Parse a tdlib reply to `F` function, an `error` object will be returned as `Err`
+pub fn parse_response<F: RFunction, S: AsRef<str>>(json: S) -> RTDResult<Result<F::Response, Error>> {
+  let value: serde_json::Value = serde_json::from_str(json.as_ref())?;
+  let is_error = value.as_object()
+    .and_then(|map| map.get("@type"))
+    .and_then(|t| t.as_str())
+    == Some("error");
+  if is_error {
+    return Ok(Err(serde_json::from_value(value)?));
+  }
+  Ok(Ok(serde_json::from_value(value)?))
+}
+
+/// tdlib json interface encodes int64 as string, read it from a string or a number, write it as a string
+pub(crate) mod int64 {
+  use std::convert::TryFrom;
+  use std::fmt;
+
+  use serde::de::{self, Deserializer, Visitor};
+  use serde::ser::Serializer;
+
+  pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    deserializer.deserialize_any(Int64Visitor)
+  }
+
+  struct Int64Visitor;
+
+  impl<'de> Visitor<'de> for Int64Visitor {
+    type Value = i64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+      formatter.write_str("an int64 number or string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<i64, E> { Ok(value) }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<i64, E> {
+      i64::try_from(value).map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<i64, E> {
+      value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+  }
+}
+
+/// `vector<int64>` version of [`int64`](int64/index.html)
+pub(crate) mod vec_int64 {
+  use serde::de::{Deserialize, Deserializer};
+  use serde::ser::Serializer;
+
+  struct Int64(i64);
+
+  impl<'de> Deserialize<'de> for Int64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Int64, D::Error> {
+      super::int64::deserialize(deserializer).map(Int64)
+    }
+  }
+
+  pub fn serialize<S: Serializer>(values: &[i64], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|value| value.to_string()))
+  }
+
+  pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<i64>, D::Error> {
+    let values: Vec<Int64> = Deserialize::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|value| value.0).collect())
+  }
+}
+
+/// All tdlib type abstract class defined the same behavior
+pub trait RObject: Debug {
+  #[doc(hidden)]
+  fn td_name(&self) -> &'static str;
+  #[doc(hidden)]
+  fn extra(&self) -> Option<String>;
+  /// Return td type to json string
+  fn to_json(&self) -> RTDResult<String>;
+}
+
+/// All tdlib functions, `Response` is the object tdlib returns to this function
+pub trait RFunction: Debug + RObject {
+  type Response: RObject + serde::de::DeserializeOwned;
+}
+
+/// A constructor of the abstract class `C`, e.g. `UpdateNewMessage` of `Update`
+pub trait RVariant<C>: RObject + Sized {
+  /// The object in `class`, if it is this constructor
+  fn from_class(class: &C) -> Option<&Self>;
+}
+
+
+impl<'a, RObj: RObject> RObject for &'a RObj {
+  fn td_name(&self) -> &'static str { (*self).td_name() }
+  fn to_json(&self) -> RTDResult<String> { (*self).to_json() }
+  fn extra(&self) -> Option<String> { (*self).extra() }
+}
+
+impl<'a, RObj: RObject> RObject for &'a mut RObj {
+  fn td_name(&self) -> &'static str { (**self).td_name() }
+  fn to_json(&self) -> RTDResult<String> { (**self).to_json() }
+  fn extra(&self) -> Option<String> { (**self).extra() }
+}
+
+
+impl<'a, Fnc: RFunction> RFunction for &'a Fnc {
+  type Response = Fnc::Response;
+}
+impl<'a, Fnc: RFunction> RFunction for &'a mut Fnc {
+  type Response = Fnc::Response;
+}
+
+
+{{generated}}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::types::{TdType, from_json, UpdateAuthorizationState};
+
+  #[test]
+  fn test_deserialize_enum() {
+    match from_json::<UpdateAuthorizationState>(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#) {
+      Ok(t) => {},
+      Err(e) => {panic!("{}", e)}
+    };
+
+    match from_json::<TdType>(r#"{"@type":"updateAuthorizationState","authorization_state":{"@type":"authorizationStateWaitTdlibParameters"}}"#) {
+      Ok(t) => {
+        match t {
+          TdType::UpdateAuthorizationState(v) => {},
+          _ => panic!("from_json failed: {:?}", t)
+        }
+      },
+      Err(e) => {panic!("{}", e)}
+    };
+  }
+}
+
+
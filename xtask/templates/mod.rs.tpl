@@ -0,0 +1,15 @@
+
+pub use self::_common::{
+  RObject,
+  RFunction,
+  RVariant,
+  detect_td_type,
+  detect_td_type_and_extra,
+  from_json,
+  parse_response,
+  TdType,
+};
+
+#[macro_use] pub(crate) mod _common;
+
+{{generated}}
\ No newline at end of file